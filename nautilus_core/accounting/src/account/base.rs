@@ -16,11 +16,11 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-use nautilus_model::enums::{AccountType, LiquiditySide, OrderSide};
+use nautilus_model::enums::{AccountType, InstrumentClass, LiquiditySide, OrderSide};
 use nautilus_model::events::account::state::AccountState;
 use nautilus_model::events::order::filled::OrderFilled;
 use nautilus_model::identifiers::account_id::AccountId;
-use nautilus_model::instruments::Instrument;
+use nautilus_model::instruments::{betting, Instrument};
 use nautilus_model::position::Position;
 use nautilus_model::types::balance::AccountBalance;
 use nautilus_model::types::currency::Currency;
@@ -30,6 +30,8 @@ use nautilus_model::types::quantity::Quantity;
 use pyo3::prelude::*;
 use rust_decimal::prelude::ToPrimitive;
 
+use crate::event_log::AccountEventLog;
+
 #[derive(Debug)]
 #[cfg_attr(
     feature = "python",
@@ -45,6 +47,7 @@ pub struct BaseAccount {
     pub commissions: HashMap<Currency, f64>,
     pub balances: HashMap<Currency, AccountBalance>,
     pub balances_starting: HashMap<Currency, Money>,
+    pub event_log: AccountEventLog,
 }
 
 impl BaseAccount {
@@ -55,6 +58,8 @@ impl BaseAccount {
             balances_starting.insert(balance.currency, balance.total);
             balances.insert(balance.currency, *balance);
         });
+        let mut event_log = AccountEventLog::new(event.account_id);
+        event_log.append_state(&event)?;
         Ok(Self {
             id: event.account_id,
             account_type: event.account_type,
@@ -64,6 +69,7 @@ impl BaseAccount {
             commissions: HashMap::new(),
             balances,
             balances_starting,
+            event_log,
         })
     }
 
@@ -137,6 +143,9 @@ impl BaseAccount {
     }
 
     pub fn base_apply(&mut self, event: AccountState) {
+        self.event_log
+            .append_state(&event)
+            .expect("Cannot apply event for another account");
         self.update_balances(event.balances.clone());
         self.events.push(event);
     }
@@ -149,11 +158,23 @@ impl BaseAccount {
         price: Price,
         use_quote_for_inverse: Option<bool>,
     ) -> Result<Money> {
+        if instrument.instrument_class() == InstrumentClass::SportsBetting {
+            // Lock the bet liability: the stake for a back, or the layers stake for a lay
+            let liability = betting::liability(quantity, price, side);
+            return Ok(Money::new(
+                liability.to_f64().unwrap(),
+                instrument.quote_currency(),
+            )?);
+        }
+
         let base_currency = instrument
             .base_currency()
             .unwrap_or(instrument.quote_currency());
         let quote_currency = instrument.quote_currency();
         let notional: f64 = match side {
+            OrderSide::Buy | OrderSide::Sell if instrument.is_quanto() => instrument
+                .calculate_notional_value(quantity, price, use_quote_for_inverse)
+                .as_f64(),
             OrderSide::Buy => instrument
                 .calculate_notional_value(quantity, price, use_quote_for_inverse)
                 .as_f64(),
@@ -164,9 +185,11 @@ impl BaseAccount {
         let taker_fee = instrument.taker_fee().to_f64().unwrap();
         let locked: f64 = (notional * taker_fee).mul_add(2.0, notional);
 
-        // Handle inverse
+        // Handle inverse and quanto
         if instrument.is_inverse() && !use_quote_for_inverse.unwrap_or(false) {
             Ok(Money::new(locked, base_currency).unwrap())
+        } else if instrument.is_quanto() {
+            Ok(Money::new(locked, instrument.settlement_currency()).unwrap())
         } else if side == OrderSide::Buy {
             Ok(Money::new(locked, quote_currency).unwrap())
         } else if side == OrderSide::Sell {
@@ -190,6 +213,17 @@ impl BaseAccount {
         let fill_qty = position.map_or(fill.last_qty.as_f64(), |pos| {
             pos.quantity.as_f64().min(fill.last_qty.as_f64())
         });
+        if instrument.instrument_class() == InstrumentClass::SportsBetting {
+            // A matched bet commits its liability until the market is settled
+            let liability = betting::liability(
+                Quantity::new(fill_qty, fill.last_qty.precision)?,
+                fill.last_px,
+                fill.order_side,
+            );
+            let pnl = Money::new(-liability.to_f64().unwrap(), quote_currency)?;
+            return Ok(vec![pnl]);
+        }
+
         if fill.order_side == OrderSide::Buy {
             if let (Some(base_currency_value), None) = (base_currency, self.base_currency) {
                 pnls.insert(
@@ -242,6 +276,8 @@ impl BaseAccount {
         };
         if instrument.is_inverse() && !use_quote_for_inverse.unwrap_or(false) {
             Ok(Money::new(commission, instrument.base_currency().unwrap()).unwrap())
+        } else if instrument.is_quanto() {
+            Ok(Money::new(commission, instrument.settlement_currency()).unwrap())
         } else {
             Ok(Money::new(commission, instrument.quote_currency()).unwrap())
         }
@@ -196,6 +196,7 @@ mod tests {
     use nautilus_model::identifiers::account_id::AccountId;
     use nautilus_model::identifiers::position_id::PositionId;
     use nautilus_model::identifiers::strategy_id::StrategyId;
+    use nautilus_model::instruments::betting::BettingInstrument;
     use nautilus_model::instruments::crypto_perpetual::CryptoPerpetual;
     use nautilus_model::instruments::currency_pair::CurrencyPair;
     use nautilus_model::instruments::equity::Equity;
@@ -354,6 +355,23 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_apply_records_state_in_event_log(
+        mut cash_account_multi: CashAccount,
+        cash_account_state_multi_changed_btc: AccountState,
+    ) {
+        cash_account_multi.apply(cash_account_state_multi_changed_btc);
+        assert_eq!(cash_account_multi.event_log.len(), 4);
+        assert_eq!(
+            cash_account_multi.event_log.balance_at(Currency::BTC(), 0),
+            Some(Money::from("9 BTC"))
+        );
+        assert_eq!(
+            cash_account_multi.event_log.balance_at(Currency::ETH(), 0),
+            Some(Money::from("20 ETH"))
+        );
+    }
+
     #[rstest]
     fn test_calculate_balance_locked_buy(
         mut cash_account_million_usd: CashAccount,
@@ -405,6 +423,62 @@ mod tests {
         assert_eq!(balance_locked, Money::from("100 USD"));
     }
 
+    #[rstest]
+    #[case(OrderSide::Buy, "10.00 GBP")]
+    #[case(OrderSide::Sell, "25.00 GBP")]
+    fn test_calculate_balance_locked_betting_liability(
+        mut cash_account_million_usd: CashAccount,
+        betting: BettingInstrument,
+        #[case] side: OrderSide,
+        #[case] expected: &str,
+    ) {
+        let balance_locked = cash_account_million_usd
+            .calculate_balance_locked(
+                betting,
+                side,
+                Quantity::from("10.00"),
+                Price::from("3.50"),
+                None,
+            )
+            .unwrap();
+        assert_eq!(balance_locked, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_calculate_pnls_for_matched_lay_bet(
+        cash_account_million_usd: CashAccount,
+        mut order_factory: OrderFactory,
+        betting: BettingInstrument,
+    ) {
+        let order = order_factory.market(
+            betting.id,
+            OrderSide::Sell,
+            Quantity::from("10.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let fill = TestOrderEventStubs::order_filled::<MarketOrder, BettingInstrument>(
+            &order,
+            &betting,
+            Some(StrategyId::new("S-001").unwrap()),
+            None,
+            Some(PositionId::new("P-123456").unwrap()),
+            Some(Price::from("3.50")),
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&betting, fill).unwrap();
+        let pnls = cash_account_million_usd
+            .calculate_pnls(betting, fill, Some(position))
+            .unwrap();
+        assert_eq!(pnls, vec![Money::from("-25.00 GBP")]);
+    }
+
     #[rstest]
     fn test_calculate_pnls_for_single_currency_cash_account(
         cash_account_million_usd: CashAccount,
@@ -433,7 +507,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         let pnls = cash_account_million_usd
             .calculate_pnls(audusd_sim, fill, Some(position))
             .unwrap();
@@ -468,7 +542,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         let result1 = cash_account_multi
             .calculate_pnls(currency_pair_btcusdt, fill1, Some(position.clone()))
             .unwrap();
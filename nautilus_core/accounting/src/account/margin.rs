@@ -197,6 +197,8 @@ impl MarginAccount {
         let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
         if instrument.is_inverse() && !use_quote_for_inverse {
             Money::new(margin, instrument.base_currency().unwrap()).unwrap()
+        } else if instrument.is_quanto() {
+            Money::new(margin, instrument.settlement_currency()).unwrap()
         } else {
             Money::new(margin, instrument.quote_currency()).unwrap()
         }
@@ -223,6 +225,8 @@ impl MarginAccount {
         let use_quote_for_inverse = use_quote_for_inverse.unwrap_or(false);
         if instrument.is_inverse() && !use_quote_for_inverse {
             Money::new(margin, instrument.base_currency().unwrap()).unwrap()
+        } else if instrument.is_quanto() {
+            Money::new(margin, instrument.settlement_currency()).unwrap()
         } else {
             Money::new(margin, instrument.quote_currency()).unwrap()
         }
@@ -326,11 +330,23 @@ impl Account for MarginAccount {
     }
     fn calculate_pnls<T: Instrument>(
         &self,
-        instrument: T,
+        _instrument: T,
         fill: OrderFilled,
         position: Option<Position>,
     ) -> Result<Vec<Money>> {
-        self.base_calculate_pnls(instrument, fill, position)
+        // Only realized PnL affects a margin account balance, calculated by the position
+        // (which applies inverse contract math when the instrument is inverse)
+        let mut pnls = Vec::new();
+        if let Some(position) = position {
+            if position.entry != fill.order_side {
+                pnls.push(position.calculate_pnl(
+                    position.avg_px_open,
+                    fill.last_px.as_f64(),
+                    fill.last_qty,
+                ));
+            }
+        }
+        Ok(pnls)
     }
     fn calculate_commission<T: Instrument>(
         &self,
@@ -387,13 +403,21 @@ mod tests {
     use crate::account::margin::MarginAccount;
     use crate::account::stubs::*;
     use crate::account::Account;
+    use nautilus_common::factories::OrderFactory;
+    use nautilus_common::stubs::*;
+    use nautilus_model::enums::OrderSide;
     use nautilus_model::events::account::state::AccountState;
     use nautilus_model::events::account::stubs::*;
     use nautilus_model::identifiers::instrument_id::InstrumentId;
+    use nautilus_model::identifiers::position_id::PositionId;
+    use nautilus_model::identifiers::strategy_id::StrategyId;
     use nautilus_model::identifiers::stubs::*;
     use nautilus_model::instruments::crypto_perpetual::CryptoPerpetual;
     use nautilus_model::instruments::currency_pair::CurrencyPair;
     use nautilus_model::instruments::stubs::*;
+    use nautilus_model::orders::market::MarketOrder;
+    use nautilus_model::orders::stubs::TestOrderEventStubs;
+    use nautilus_model::position::Position;
     use nautilus_model::types::currency::Currency;
     use nautilus_model::types::money::Money;
     use nautilus_model::types::price::Price;
@@ -632,4 +656,67 @@ mod tests {
         );
         assert_eq!(result, Money::from("0.00042500 BTC"));
     }
+
+    #[rstest]
+    fn test_calculate_pnls_for_inverse_instrument(
+        margin_account: MarginAccount,
+        mut order_factory: OrderFactory,
+        xbtusd_bitmex: CryptoPerpetual,
+    ) {
+        let order1 = order_factory.market(
+            xbtusd_bitmex.id,
+            OrderSide::Sell,
+            Quantity::from(100_000),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let fill1 = TestOrderEventStubs::order_filled::<MarketOrder, CryptoPerpetual>(
+            &order1,
+            &xbtusd_bitmex,
+            Some(StrategyId::new("S-001").unwrap()),
+            None,
+            Some(PositionId::new("P-123456").unwrap()),
+            Some(Price::from("10000.0")),
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&xbtusd_bitmex, fill1).unwrap();
+        let order2 = order_factory.market(
+            xbtusd_bitmex.id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let fill2 = TestOrderEventStubs::order_filled::<MarketOrder, CryptoPerpetual>(
+            &order2,
+            &xbtusd_bitmex,
+            Some(StrategyId::new("S-001").unwrap()),
+            None,
+            Some(PositionId::new("P-123456").unwrap()),
+            Some(Price::from("11000.0")),
+            None,
+            None,
+            None,
+        );
+
+        // Opening fill realizes nothing, closing fill realizes inverse PnL in XBT
+        let opening_pnls = margin_account
+            .calculate_pnls(xbtusd_bitmex, fill1, None)
+            .unwrap();
+        let closing_pnls = margin_account
+            .calculate_pnls(xbtusd_bitmex, fill2, Some(position))
+            .unwrap();
+        assert!(opening_pnls.is_empty());
+        assert_eq!(closing_pnls, vec![Money::from("-0.90909091 BTC")]);
+    }
 }
@@ -0,0 +1,500 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An append-only log of account-affecting events.
+//!
+//! Balance deltas (fills, commissions, funding, financing and manual adjustments) are recorded
+//! between full balance snapshots taken from `AccountState` events. A historical balance is
+//! reconstructed from the latest snapshot at or before the requested time, plus any deltas
+//! recorded after it.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+
+use anyhow::Result;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::events::account::state::AccountState;
+use nautilus_model::events::order::filled::OrderFilled;
+use nautilus_model::identifiers::account_id::AccountId;
+use nautilus_model::identifiers::instrument_id::InstrumentId;
+use nautilus_model::types::currency::Currency;
+use nautilus_model::types::money::Money;
+
+/// The header row written by [`AccountEventLog::write_csv`].
+pub const CSV_HEADER: &str = "sequence,account_id,kind,currency,amount,reference,ts_event,ts_init";
+
+/// The kind of an [`AccountLogEntry`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountEventKind {
+    /// A balance snapshot, `amount` is the total balance for the currency.
+    State,
+    /// Realized cash flow from an order fill.
+    Fill,
+    /// Commission charged for an order fill.
+    Commission,
+    /// A funding payment (e.g. perpetual swap funding).
+    Funding,
+    /// A financing charge (e.g. borrow interest or overnight swap).
+    Financing,
+    /// A manual or venue-initiated balance adjustment.
+    Adjustment,
+}
+
+impl AccountEventKind {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::State => "STATE",
+            Self::Fill => "FILL",
+            Self::Commission => "COMMISSION",
+            Self::Funding => "FUNDING",
+            Self::Financing => "FINANCING",
+            Self::Adjustment => "ADJUSTMENT",
+        }
+    }
+}
+
+impl Display for AccountEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A single entry in an [`AccountEventLog`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountLogEntry {
+    /// The position of the entry in the log (starting from 1).
+    pub sequence: u64,
+    pub account_id: AccountId,
+    pub kind: AccountEventKind,
+    /// The balance delta, or the total balance for [`AccountEventKind::State`] entries.
+    pub amount: Money,
+    /// The originating identifier (trade ID, instrument ID or adjustment reason).
+    pub reference: Option<String>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl AccountLogEntry {
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.amount.currency
+    }
+
+    #[must_use]
+    pub fn is_snapshot(&self) -> bool {
+        self.kind == AccountEventKind::State
+    }
+}
+
+/// An append-only audit log of every event affecting an account's balances.
+///
+/// Entries can only be appended, never modified or removed, and are numbered sequentially in
+/// the order received. Events may arrive out of `ts_event` order, so queries by time consider
+/// entries ordered by `(ts_event, sequence)`.
+#[derive(Clone, Debug)]
+pub struct AccountEventLog {
+    account_id: AccountId,
+    entries: Vec<AccountLogEntry>,
+}
+
+impl AccountEventLog {
+    #[must_use]
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            entries: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[AccountLogEntry] {
+        &self.entries
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Appends a single entry to the log, returning its sequence number.
+    pub fn append(
+        &mut self,
+        kind: AccountEventKind,
+        amount: Money,
+        reference: Option<String>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> u64 {
+        let sequence = self.entries.len() as u64 + 1;
+        self.entries.push(AccountLogEntry {
+            sequence,
+            account_id: self.account_id,
+            kind,
+            amount,
+            reference,
+            ts_event,
+            ts_init,
+        });
+        sequence
+    }
+
+    /// Appends a balance snapshot entry for every balance in the given `event`.
+    pub fn append_state(&mut self, event: &AccountState) -> Result<()> {
+        self.check_account_id(event.account_id)?;
+        for balance in &event.balances {
+            self.append(
+                AccountEventKind::State,
+                balance.total,
+                Some(event.event_id.to_string()),
+                event.ts_event,
+                event.ts_init,
+            );
+        }
+        Ok(())
+    }
+
+    /// Appends the realized cash flows and commission for the given `fill`.
+    ///
+    /// The `pnls` are the per-currency balance changes as calculated by the account
+    /// (see `Account::calculate_pnls`).
+    pub fn append_fill(&mut self, fill: &OrderFilled, pnls: &[Money]) -> Result<()> {
+        self.check_account_id(fill.account_id)?;
+        let reference = fill.trade_id.to_string();
+        for pnl in pnls {
+            self.append(
+                AccountEventKind::Fill,
+                *pnl,
+                Some(reference.clone()),
+                fill.ts_event,
+                fill.ts_init,
+            );
+        }
+        if let Some(commission) = fill.commission {
+            if !commission.is_zero() {
+                self.append(
+                    AccountEventKind::Commission,
+                    -commission,
+                    Some(reference),
+                    fill.ts_event,
+                    fill.ts_init,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a funding payment (positive when received, negative when paid).
+    pub fn append_funding(
+        &mut self,
+        instrument_id: InstrumentId,
+        amount: Money,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> u64 {
+        self.append(
+            AccountEventKind::Funding,
+            amount,
+            Some(instrument_id.to_string()),
+            ts_event,
+            ts_init,
+        )
+    }
+
+    /// Appends a financing charge (positive when received, negative when paid).
+    pub fn append_financing(
+        &mut self,
+        instrument_id: InstrumentId,
+        amount: Money,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> u64 {
+        self.append(
+            AccountEventKind::Financing,
+            amount,
+            Some(instrument_id.to_string()),
+            ts_event,
+            ts_init,
+        )
+    }
+
+    /// Appends a balance adjustment with the given `reason`.
+    pub fn append_adjustment(
+        &mut self,
+        amount: Money,
+        reason: &str,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> u64 {
+        self.append(
+            AccountEventKind::Adjustment,
+            amount,
+            Some(reason.to_string()),
+            ts_event,
+            ts_init,
+        )
+    }
+
+    /// Returns the entries with `ts_event` within the given inclusive range, in time order.
+    #[must_use]
+    pub fn entries_between(&self, start: UnixNanos, end: UnixNanos) -> Vec<&AccountLogEntry> {
+        let mut entries: Vec<&AccountLogEntry> = self
+            .entries
+            .iter()
+            .filter(|e| e.ts_event >= start && e.ts_event <= end)
+            .collect();
+        entries.sort_by_key(|e| (e.ts_event, e.sequence));
+        entries
+    }
+
+    /// Reconstructs the total balance for the given `currency` as at `ts` (inclusive).
+    ///
+    /// Returns `None` if no entries for the currency were recorded at or before `ts`.
+    #[must_use]
+    pub fn balance_at(&self, currency: Currency, ts: UnixNanos) -> Option<Money> {
+        let mut balance: Option<Money> = None;
+        for entry in self.entries_between(0, ts) {
+            if entry.currency() != currency {
+                continue;
+            }
+            balance = Some(match (entry.kind, balance) {
+                (AccountEventKind::State, _) | (_, None) => entry.amount,
+                (_, Some(current)) => current + entry.amount,
+            });
+        }
+        balance
+    }
+
+    /// Reconstructs the total balances for every currency as at `ts` (inclusive).
+    #[must_use]
+    pub fn balances_at(&self, ts: UnixNanos) -> HashMap<Currency, Money> {
+        let mut balances: HashMap<Currency, Money> = HashMap::new();
+        for entry in self.entries_between(0, ts) {
+            let currency = entry.currency();
+            let balance = match (entry.kind, balances.get(&currency)) {
+                (AccountEventKind::State, _) | (_, None) => entry.amount,
+                (_, Some(current)) => *current + entry.amount,
+            };
+            balances.insert(currency, balance);
+        }
+        balances
+    }
+
+    /// Writes the full audit trail as CSV (with a header row) to the given `writer`.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "{CSV_HEADER}")?;
+        for entry in &self.entries {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{}",
+                entry.sequence,
+                csv_field(entry.account_id.value.as_str()),
+                entry.kind,
+                entry.currency().code,
+                entry.amount.as_decimal(),
+                csv_field(entry.reference.as_deref().unwrap_or_default()),
+                entry.ts_event,
+                entry.ts_init,
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn check_account_id(&self, account_id: AccountId) -> Result<()> {
+        if account_id != self.account_id {
+            anyhow::bail!(
+                "Event account ID {account_id} does not match log account ID {}",
+                self.account_id
+            );
+        }
+        Ok(())
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::events::account::stubs::*;
+    use nautilus_model::events::order::stubs::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn log_with_starting_balance(account_state: &AccountState) -> AccountEventLog {
+        let mut log = AccountEventLog::new(account_state.account_id);
+        log.append_state(account_state).unwrap();
+        log
+    }
+
+    #[rstest]
+    fn test_append_state_records_snapshot_per_balance(cash_account_state_multi: AccountState) {
+        let log = log_with_starting_balance(&cash_account_state_multi);
+
+        assert_eq!(log.len(), cash_account_state_multi.balances.len());
+        assert!(log.entries().iter().all(AccountLogEntry::is_snapshot));
+        assert_eq!(log.entries()[0].sequence, 1);
+    }
+
+    #[rstest]
+    fn test_append_state_with_wrong_account_id_errors(
+        cash_account_state_million_usd: AccountState,
+    ) {
+        let mut log = AccountEventLog::new(AccountId::new("OTHER-001").unwrap());
+
+        assert!(log.append_state(&cash_account_state_million_usd).is_err());
+        assert!(log.is_empty());
+    }
+
+    #[rstest]
+    fn test_append_fill_records_pnls_and_commission(order_filled: OrderFilled) {
+        let mut log = AccountEventLog::new(order_filled.account_id);
+        let pnls = vec![Money::from("-12342.1 USDT")];
+
+        log.append_fill(&order_filled, &pnls).unwrap();
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.entries()[0].kind, AccountEventKind::Fill);
+        assert_eq!(log.entries()[0].reference.as_deref(), Some("1"));
+        assert_eq!(log.entries()[1].kind, AccountEventKind::Commission);
+        assert_eq!(log.entries()[1].amount, Money::from("-12.2 USDT"));
+    }
+
+    #[rstest]
+    fn test_balance_at_reconstructs_history(cash_account_state_million_usd: AccountState) {
+        let mut log = log_with_starting_balance(&cash_account_state_million_usd);
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        log.append_funding(instrument_id, Money::from("-100 USD"), 10, 10);
+        log.append_financing(instrument_id, Money::from("-5 USD"), 20, 20);
+        log.append_adjustment(Money::from("1000 USD"), "deposit", 30, 30);
+
+        assert_eq!(
+            log.balance_at(Currency::USD(), 0),
+            Some(Money::from("1000000 USD"))
+        );
+        assert_eq!(
+            log.balance_at(Currency::USD(), 15),
+            Some(Money::from("999900 USD"))
+        );
+        assert_eq!(
+            log.balance_at(Currency::USD(), 25),
+            Some(Money::from("999895 USD"))
+        );
+        assert_eq!(
+            log.balance_at(Currency::USD(), 30),
+            Some(Money::from("1000895 USD"))
+        );
+        assert_eq!(log.balance_at(Currency::EUR(), 30), None);
+    }
+
+    #[rstest]
+    fn test_balance_at_resets_on_later_snapshot(cash_account_state_million_usd: AccountState) {
+        let mut log = log_with_starting_balance(&cash_account_state_million_usd);
+        log.append_adjustment(Money::from("-250 USD"), "withdrawal", 10, 10);
+        let mut snapshot = cash_account_state_million_usd.clone();
+        snapshot.balances[0].total = Money::from("500000 USD");
+        snapshot.ts_event = 20;
+        snapshot.ts_init = 20;
+        log.append_state(&snapshot).unwrap();
+
+        assert_eq!(
+            log.balance_at(Currency::USD(), 19),
+            Some(Money::from("999750 USD"))
+        );
+        assert_eq!(
+            log.balance_at(Currency::USD(), 20),
+            Some(Money::from("500000 USD"))
+        );
+    }
+
+    #[rstest]
+    fn test_balance_at_orders_late_entries_by_ts_event(
+        cash_account_state_million_usd: AccountState,
+    ) {
+        let mut log = log_with_starting_balance(&cash_account_state_million_usd);
+        let mut snapshot = cash_account_state_million_usd.clone();
+        snapshot.balances[0].total = Money::from("500000 USD");
+        snapshot.ts_event = 20;
+        log.append_state(&snapshot).unwrap();
+        // Arrives after the snapshot but occurred before it
+        log.append_adjustment(Money::from("-250 USD"), "late", 10, 30);
+
+        assert_eq!(
+            log.balance_at(Currency::USD(), 15),
+            Some(Money::from("999750 USD"))
+        );
+        assert_eq!(
+            log.balance_at(Currency::USD(), 20),
+            Some(Money::from("500000 USD"))
+        );
+        assert_eq!(log.entries().last().unwrap().sequence, 3);
+    }
+
+    #[rstest]
+    fn test_balances_at(cash_account_state_multi: AccountState) {
+        let mut log = log_with_starting_balance(&cash_account_state_multi);
+        log.append_adjustment(Money::from("1 BTC"), "transfer", 10, 10);
+
+        let before = log.balances_at(0);
+        let after = log.balances_at(10);
+
+        assert_eq!(before.len(), cash_account_state_multi.balances.len());
+        assert_eq!(
+            after[&Currency::BTC()],
+            before[&Currency::BTC()] + Money::from("1 BTC")
+        );
+        assert_eq!(after[&Currency::ETH()], before[&Currency::ETH()]);
+    }
+
+    #[rstest]
+    fn test_write_csv(cash_account_state_million_usd: AccountState) {
+        let mut log = log_with_starting_balance(&cash_account_state_million_usd);
+        log.append_adjustment(Money::from("-12.5 USD"), "fee, \"manual\"", 10, 11);
+        let mut buffer = Vec::new();
+
+        log.write_csv(&mut buffer).unwrap();
+
+        let csv = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert!(lines[1].starts_with("1,SIM-001,STATE,USD,1000000.00,"));
+        assert_eq!(
+            lines[2],
+            "2,SIM-001,ADJUSTMENT,USD,-12.50,\"fee, \"\"manual\"\"\",10,11"
+        );
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod account;
+pub mod event_log;
 #[cfg(test)]
 pub mod stubs;
 
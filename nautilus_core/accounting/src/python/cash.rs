@@ -20,6 +20,7 @@ use nautilus_model::enums::{LiquiditySide, OrderSide};
 use nautilus_model::events::account::state::AccountState;
 use nautilus_model::events::order::filled::OrderFilled;
 use nautilus_model::identifiers::account_id::AccountId;
+use nautilus_model::instruments::betting::BettingInstrument;
 use nautilus_model::instruments::crypto_future::CryptoFuture;
 use nautilus_model::instruments::crypto_perpetual::CryptoPerpetual;
 use nautilus_model::instruments::currency_pair::CurrencyPair;
@@ -155,7 +156,18 @@ impl CashAccount {
         let instrument_type = instrument
             .getattr(py, "instrument_type")?
             .extract::<String>(py)?;
-        if instrument_type == "CryptoFuture" {
+        if instrument_type == "BettingInstrument" {
+            let instrument_rust = instrument.extract::<BettingInstrument>(py)?;
+            Ok(self
+                .calculate_balance_locked(
+                    instrument_rust,
+                    side,
+                    quantity,
+                    price,
+                    use_quote_for_inverse,
+                )
+                .unwrap())
+        } else if instrument_type == "CryptoFuture" {
             let instrument_rust = instrument.extract::<CryptoFuture>(py)?;
             Ok(self
                 .calculate_balance_locked(
@@ -244,7 +256,18 @@ impl CashAccount {
         let instrument_type = instrument
             .getattr(py, "instrument_type")?
             .extract::<String>(py)?;
-        if instrument_type == "CryptoFuture" {
+        if instrument_type == "BettingInstrument" {
+            let instrument_rust = instrument.extract::<BettingInstrument>(py)?;
+            Ok(self
+                .calculate_commission(
+                    instrument_rust,
+                    last_qty,
+                    last_px,
+                    liquidity_side,
+                    use_quote_for_inverse,
+                )
+                .unwrap())
+        } else if instrument_type == "CryptoFuture" {
             let instrument_rust = instrument.extract::<CryptoFuture>(py)?;
             Ok(self
                 .calculate_commission(
@@ -328,7 +351,12 @@ impl CashAccount {
         let instrument_type = instrument
             .getattr(py, "instrument_type")?
             .extract::<String>(py)?;
-        if instrument_type == "CryptoFuture" {
+        if instrument_type == "BettingInstrument" {
+            let instrument_rust = instrument.extract::<BettingInstrument>(py)?;
+            Ok(self
+                .calculate_pnls(instrument_rust, fill, position)
+                .unwrap())
+        } else if instrument_type == "CryptoFuture" {
             let instrument_rust = instrument.extract::<CryptoFuture>(py)?;
             Ok(self
                 .calculate_pnls(instrument_rust, fill, position)
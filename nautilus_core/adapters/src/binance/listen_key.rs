@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Binance listen key lifecycle management.
+//!
+//! A user-data stream is opened with a listen key, which expires 60 minutes after it was
+//! created or last kept alive. The [`ListenKeyManager`] tracks the current key and returns
+//! the REST request due at each check, leaving the request itself to the venue client.
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, time::UnixNanos};
+use ustr::Ustr;
+
+/// The default interval between listen key keepalives (Binance recommends 30 minutes).
+pub const DEFAULT_KEEPALIVE_INTERVAL_NS: u64 = 30 * 60 * NANOSECONDS_IN_SECOND;
+
+/// The listen key REST request due for a user-data stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListenKeyAction {
+    /// Create a new listen key (`POST /fapi/v1/listenKey`) and reconnect the stream.
+    Create,
+    /// Keep the listen key alive (`PUT /fapi/v1/listenKey`).
+    Keepalive(Ustr),
+}
+
+/// Provides management of a Binance user-data stream listen key.
+#[derive(Clone, Debug)]
+pub struct ListenKeyManager {
+    keepalive_interval_ns: u64,
+    listen_key: Option<Ustr>,
+    last_keepalive_ns: UnixNanos,
+}
+
+impl Default for ListenKeyManager {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEEPALIVE_INTERVAL_NS)
+    }
+}
+
+impl ListenKeyManager {
+    #[must_use]
+    pub fn new(keepalive_interval_ns: u64) -> Self {
+        Self {
+            keepalive_interval_ns,
+            listen_key: None,
+            last_keepalive_ns: 0,
+        }
+    }
+
+    /// Returns the current listen key (if any).
+    #[must_use]
+    pub fn listen_key(&self) -> Option<Ustr> {
+        self.listen_key
+    }
+
+    /// Returns the time the next keepalive is due (if there is a listen key).
+    #[must_use]
+    pub fn next_keepalive_ns(&self) -> Option<UnixNanos> {
+        self.listen_key
+            .map(|_| self.last_keepalive_ns + self.keepalive_interval_ns)
+    }
+
+    /// Sets the listen key returned when it was created at `ts_now`.
+    pub fn set_listen_key(&mut self, listen_key: Ustr, ts_now: UnixNanos) {
+        self.listen_key = Some(listen_key);
+        self.last_keepalive_ns = ts_now;
+    }
+
+    /// Records a successful keepalive of the current listen key at `ts_now`.
+    pub fn on_keepalive(&mut self, ts_now: UnixNanos) {
+        self.last_keepalive_ns = ts_now;
+    }
+
+    /// Handles a failed keepalive, after which a new listen key is required.
+    pub fn on_keepalive_failed(&mut self) {
+        self.listen_key = None;
+    }
+
+    /// Handles a `listenKeyExpired` message for the given `listen_key`, returning whether
+    /// it was the current key (in which case a new listen key is required).
+    pub fn on_expired(&mut self, listen_key: &Ustr) -> bool {
+        if self.listen_key.as_ref() != Some(listen_key) {
+            return false; // Stale message for a replaced key
+        }
+        self.listen_key = None;
+        true
+    }
+
+    /// Returns the listen key request due at `ts_now` (if any).
+    #[must_use]
+    pub fn next_action(&self, ts_now: UnixNanos) -> Option<ListenKeyAction> {
+        match self.listen_key {
+            None => Some(ListenKeyAction::Create),
+            Some(listen_key) if ts_now >= self.last_keepalive_ns + self.keepalive_interval_ns => {
+                Some(ListenKeyAction::Keepalive(listen_key))
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_next_action_without_listen_key_is_create() {
+        let manager = ListenKeyManager::default();
+
+        assert_eq!(manager.next_action(0), Some(ListenKeyAction::Create));
+        assert_eq!(manager.next_keepalive_ns(), None);
+    }
+
+    #[rstest]
+    fn test_keepalive_due_after_interval() {
+        let mut manager = ListenKeyManager::new(100);
+        manager.set_listen_key(Ustr::from("abc"), 1_000);
+
+        assert_eq!(manager.next_action(1_099), None);
+        assert_eq!(
+            manager.next_action(1_100),
+            Some(ListenKeyAction::Keepalive(Ustr::from("abc")))
+        );
+
+        manager.on_keepalive(1_100);
+
+        assert_eq!(manager.next_action(1_150), None);
+        assert_eq!(manager.next_keepalive_ns(), Some(1_200));
+    }
+
+    #[rstest]
+    fn test_expired_current_key_requires_create() {
+        let mut manager = ListenKeyManager::new(100);
+        manager.set_listen_key(Ustr::from("abc"), 0);
+
+        assert!(manager.on_expired(&Ustr::from("abc")));
+        assert_eq!(manager.next_action(1), Some(ListenKeyAction::Create));
+    }
+
+    #[rstest]
+    fn test_expired_stale_key_is_ignored() {
+        let mut manager = ListenKeyManager::new(100);
+        manager.set_listen_key(Ustr::from("new"), 0);
+
+        assert!(!manager.on_expired(&Ustr::from("old")));
+        assert_eq!(manager.listen_key(), Some(Ustr::from("new")));
+    }
+
+    #[rstest]
+    fn test_failed_keepalive_requires_create() {
+        let mut manager = ListenKeyManager::new(100);
+        manager.set_listen_key(Ustr::from("abc"), 0);
+
+        manager.on_keepalive_failed();
+
+        assert_eq!(manager.next_action(1), Some(ListenKeyAction::Create));
+    }
+}
@@ -0,0 +1,242 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::Result;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide},
+    events::account::state::AccountState,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{balance::AccountBalance, money::Money},
+};
+use ustr::Ustr;
+
+use super::types::{BinanceAccountUpdateMsg, BinanceExecutionType, BinanceOrderTradeUpdateMsg};
+use crate::user_data::{
+    parse_currency, parse_f64, parse_millis, OrderUpdateKind, VenueFill, VenueOrderUpdate,
+};
+
+/// Returns the normalized order update for the given Binance `ORDER_TRADE_UPDATE`, or
+/// `None` for execution types which do not change the order status (such as amendments).
+pub fn parse_order_trade_update(
+    msg: &BinanceOrderTradeUpdateMsg,
+) -> Result<Option<VenueOrderUpdate>> {
+    let order = &msg.order;
+    let kind = match order.execution_type {
+        BinanceExecutionType::New => OrderUpdateKind::Accepted,
+        BinanceExecutionType::Rejected => {
+            // The user-data stream does not include a reject reason
+            OrderUpdateKind::Rejected(Ustr::from("Rejected by venue"))
+        }
+        BinanceExecutionType::Trade | BinanceExecutionType::Calculated => {
+            let commission = match &order.commission {
+                Some(commission) => Some(parse_f64(commission, "n")?),
+                None => None,
+            };
+            let commission_currency = match order.commission_asset {
+                Some(asset) => Some(parse_currency(asset.as_str())?),
+                None => None,
+            };
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::new(&order.trade_id.to_string())?,
+                last_qty: parse_f64(&order.last_filled_qty, "l")?,
+                last_px: parse_f64(&order.last_filled_price, "L")?,
+                liquidity_side: if order.is_maker {
+                    LiquiditySide::Maker
+                } else {
+                    LiquiditySide::Taker
+                },
+                commission,
+                commission_currency,
+            })
+        }
+        BinanceExecutionType::Canceled => OrderUpdateKind::Canceled,
+        BinanceExecutionType::Expired => OrderUpdateKind::Expired,
+        BinanceExecutionType::Amendment | BinanceExecutionType::Other => return Ok(None),
+    };
+
+    let client_order_id = if order.client_order_id.is_empty() {
+        None
+    } else {
+        Some(ClientOrderId::new(order.client_order_id.as_str())?)
+    };
+    let ts_event = match kind {
+        OrderUpdateKind::Filled(_) => parse_millis(order.trade_time),
+        _ => parse_millis(msg.transaction_time),
+    };
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id,
+        venue_order_id: VenueOrderId::new(&order.order_id.to_string())?,
+        kind,
+        ts_event,
+    }))
+}
+
+/// Returns the account state for the given Binance `ACCOUNT_UPDATE`.
+///
+/// The cross wallet balance is free, with any remainder of the wallet balance locked
+/// (such as isolated margin).
+pub fn parse_account_update(
+    msg: &BinanceAccountUpdateMsg,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> Result<AccountState> {
+    let mut balances = Vec::with_capacity(msg.data.balances.len());
+    for balance in &msg.data.balances {
+        let currency = parse_currency(balance.asset.as_str())?;
+        let wallet_balance = parse_f64(&balance.wallet_balance, "wb")?;
+        let cross_wallet_balance = parse_f64(&balance.cross_wallet_balance, "cw")?;
+        let total = Money::new(wallet_balance, currency)?;
+        let free = Money::new(cross_wallet_balance.min(wallet_balance), currency)?;
+        balances.push(AccountBalance::new(total, total - free, free)?);
+    }
+
+    AccountState::new(
+        account_id,
+        AccountType::Margin,
+        balances,
+        vec![],
+        true,
+        UUID4::new(),
+        parse_millis(msg.event_time),
+        ts_init,
+        None,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::currency::Currency;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::binance::types::BinanceUserDataMsg;
+
+    fn order_trade_update_json(execution_type: &str, order_status: &str) -> String {
+        format!(
+            r#"{{"e":"ORDER_TRADE_UPDATE","E":1700000000100,"T":1700000000099,"o":{{
+                "s":"ETHUSDT","c":"O-001","S":"BUY","o":"LIMIT","f":"GTC","q":"2","p":"2000.00",
+                "ap":"2000.00","sp":"0","x":"{execution_type}","X":"{order_status}","i":8886774,
+                "l":"1","z":"1","L":"2000.00","N":"USDT","n":"0.40","T":1700000000098,"t":12345,
+                "b":"0","a":"0","m":true,"R":false,"wt":"CONTRACT_PRICE","ot":"LIMIT","ps":"BOTH",
+                "cp":false,"rp":"0"}}}}"#
+        )
+    }
+
+    fn parse_update(json: &str) -> Option<VenueOrderUpdate> {
+        match serde_json::from_str::<BinanceUserDataMsg>(json).unwrap() {
+            BinanceUserDataMsg::OrderTradeUpdate(msg) => parse_order_trade_update(&msg).unwrap(),
+            other => panic!("unexpected message {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_parse_order_trade_update_trade() {
+        let update = parse_update(&order_trade_update_json("TRADE", "PARTIALLY_FILLED")).unwrap();
+
+        assert_eq!(update.client_order_id, Some(ClientOrderId::from("O-001")));
+        assert_eq!(update.venue_order_id, VenueOrderId::from("8886774"));
+        assert_eq!(update.ts_event, 1_700_000_000_098_000_000);
+        assert_eq!(
+            update.kind,
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::from("12345"),
+                last_qty: 1.0,
+                last_px: 2000.0,
+                liquidity_side: LiquiditySide::Maker,
+                commission: Some(0.40),
+                commission_currency: Some(Currency::from("USDT")),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("NEW", "NEW", Some(OrderUpdateKind::Accepted))]
+    #[case("CANCELED", "CANCELED", Some(OrderUpdateKind::Canceled))]
+    #[case("EXPIRED", "EXPIRED", Some(OrderUpdateKind::Expired))]
+    #[case(
+        "REJECTED",
+        "REJECTED",
+        Some(OrderUpdateKind::Rejected(Ustr::from("Rejected by venue")))
+    )]
+    #[case("AMENDMENT", "NEW", None)]
+    #[case("SOMETHING_NEW", "NEW", None)]
+    fn test_parse_order_trade_update_status(
+        #[case] execution_type: &str,
+        #[case] order_status: &str,
+        #[case] expected: Option<OrderUpdateKind>,
+    ) {
+        let update = parse_update(&order_trade_update_json(execution_type, order_status));
+
+        assert_eq!(update.map(|u| u.kind), expected);
+    }
+
+    #[rstest]
+    fn test_parse_order_trade_update_status_uses_transaction_time() {
+        let update = parse_update(&order_trade_update_json("CANCELED", "CANCELED")).unwrap();
+
+        assert_eq!(update.ts_event, 1_700_000_000_099_000_000);
+    }
+
+    #[rstest]
+    fn test_parse_account_update() {
+        let json = r#"{"e":"ACCOUNT_UPDATE","E":1700000000100,"T":1700000000099,"a":{"m":"ORDER",
+            "B":[{"a":"USDT","wb":"1000.50","cw":"900.25","bc":"0"}],
+            "P":[{"s":"ETHUSDT","pa":"1","ep":"2000.0","cr":"0","up":"0","mt":"cross","iw":"0","ps":"BOTH"}]}}"#;
+        let msg = match serde_json::from_str::<BinanceUserDataMsg>(json).unwrap() {
+            BinanceUserDataMsg::AccountUpdate(msg) => msg,
+            other => panic!("unexpected message {other:?}"),
+        };
+
+        let state = parse_account_update(&msg, AccountId::from("BINANCE-001"), 5).unwrap();
+
+        let usdt = Currency::from("USDT");
+        assert_eq!(state.account_id, AccountId::from("BINANCE-001"));
+        assert_eq!(state.account_type, AccountType::Margin);
+        assert_eq!(state.balances.len(), 1);
+        assert_eq!(state.balances[0].total, Money::new(1000.50, usdt).unwrap());
+        assert_eq!(state.balances[0].free, Money::new(900.25, usdt).unwrap());
+        assert_eq!(state.balances[0].locked, Money::new(100.25, usdt).unwrap());
+        assert_eq!(state.ts_event, 1_700_000_000_100_000_000);
+        assert_eq!(state.ts_init, 5);
+    }
+
+    #[rstest]
+    fn test_deserialize_listen_key_expired() {
+        let json = r#"{"e":"listenKeyExpired","E":"1700000000100","listenKey":"abc123"}"#;
+
+        let msg = serde_json::from_str::<BinanceUserDataMsg>(json).unwrap();
+
+        assert!(
+            matches!(msg, BinanceUserDataMsg::ListenKeyExpired(msg) if msg.listen_key == "abc123")
+        );
+    }
+
+    #[rstest]
+    fn test_deserialize_unhandled_message() {
+        let json = r#"{"e":"MARGIN_CALL","E":1700000000100,"cw":"3.16812045","p":[]}"#;
+
+        let msg = serde_json::from_str::<BinanceUserDataMsg>(json).unwrap();
+
+        assert!(matches!(msg, BinanceUserDataMsg::Other));
+    }
+}
@@ -0,0 +1,144 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents a Binance USD-M futures user-data stream message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "e")]
+pub enum BinanceUserDataMsg {
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(BinanceOrderTradeUpdateMsg),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(BinanceAccountUpdateMsg),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(BinanceListenKeyExpiredMsg),
+    /// Messages which are not handled (such as `MARGIN_CALL`).
+    #[serde(other)]
+    Other,
+}
+
+/// Represents a Binance `ORDER_TRADE_UPDATE` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceOrderTradeUpdateMsg {
+    /// The event time (UNIX milliseconds).
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// The transaction time (UNIX milliseconds).
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "o")]
+    pub order: BinanceOrderUpdate,
+}
+
+/// Represents the order of a Binance `ORDER_TRADE_UPDATE` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: Ustr,
+    #[serde(rename = "c")]
+    pub client_order_id: Ustr,
+    #[serde(rename = "S")]
+    pub side: Ustr,
+    #[serde(rename = "o")]
+    pub order_type: Ustr,
+    #[serde(rename = "q")]
+    pub original_qty: String,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "x")]
+    pub execution_type: BinanceExecutionType,
+    #[serde(rename = "X")]
+    pub order_status: Ustr,
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    #[serde(rename = "l")]
+    pub last_filled_qty: String,
+    #[serde(rename = "z")]
+    pub cum_filled_qty: String,
+    #[serde(rename = "L")]
+    pub last_filled_price: String,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<Ustr>,
+    #[serde(rename = "n")]
+    pub commission: Option<String>,
+    /// The trade time (UNIX milliseconds).
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+}
+
+/// The execution type of a Binance order update.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceExecutionType {
+    New,
+    Canceled,
+    /// A liquidation or auto-deleveraging (ADL) fill.
+    Calculated,
+    Expired,
+    Trade,
+    Amendment,
+    Rejected,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents a Binance `ACCOUNT_UPDATE` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceAccountUpdateMsg {
+    /// The event time (UNIX milliseconds).
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// The transaction time (UNIX milliseconds).
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "a")]
+    pub data: BinanceAccountUpdateData,
+}
+
+/// Represents the data of a Binance `ACCOUNT_UPDATE` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceAccountUpdateData {
+    /// The reason for the update (such as `ORDER` or `FUNDING_FEE`).
+    #[serde(rename = "m")]
+    pub reason: Ustr,
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceBalanceUpdate>,
+}
+
+/// Represents an asset balance of a Binance `ACCOUNT_UPDATE` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceBalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: Ustr,
+    #[serde(rename = "wb")]
+    pub wallet_balance: String,
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: String,
+}
+
+/// Represents a Binance `listenKeyExpired` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BinanceListenKeyExpiredMsg {
+    #[serde(rename = "listenKey")]
+    pub listen_key: Ustr,
+}
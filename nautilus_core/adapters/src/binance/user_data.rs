@@ -0,0 +1,201 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::{Context, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::events::order::event::OrderEvent;
+
+use super::{
+    listen_key::ListenKeyManager,
+    parsing::{parse_account_update, parse_order_trade_update},
+    types::BinanceUserDataMsg,
+};
+use crate::user_data::{UserDataDispatcher, UserDataEvent};
+
+/// Provides handling of Binance USD-M futures user-data stream messages.
+///
+/// Order and account updates are dispatched to the execution engine, and expiry of the
+/// current listen key is recorded so that the venue client creates a new one on its next
+/// [`ListenKeyManager::next_action`] check.
+pub struct BinanceUserDataHandler {
+    dispatcher: UserDataDispatcher,
+    pub listen_key: ListenKeyManager,
+}
+
+impl BinanceUserDataHandler {
+    #[must_use]
+    pub fn new(dispatcher: UserDataDispatcher, listen_key: ListenKeyManager) -> Self {
+        Self {
+            dispatcher,
+            listen_key,
+        }
+    }
+
+    /// Returns the user-data dispatcher for the handler.
+    #[must_use]
+    pub fn dispatcher(&self) -> &UserDataDispatcher {
+        &self.dispatcher
+    }
+
+    /// Handles the given user-data stream message `text`, returning the order event applied
+    /// by the execution engine (if any).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the message cannot be parsed or dispatched.
+    pub fn handle_message(&mut self, text: &str, ts_init: UnixNanos) -> Result<Option<OrderEvent>> {
+        let msg: BinanceUserDataMsg = serde_json::from_str(text)
+            .with_context(|| format!("Invalid user-data message {text}"))?;
+
+        match msg {
+            BinanceUserDataMsg::OrderTradeUpdate(msg) => match parse_order_trade_update(&msg)? {
+                Some(update) => self
+                    .dispatcher
+                    .dispatch(UserDataEvent::Order(update), ts_init),
+                None => Ok(None),
+            },
+            BinanceUserDataMsg::AccountUpdate(msg) => {
+                let state = parse_account_update(&msg, self.dispatcher.account_id(), ts_init)?;
+                self.dispatcher
+                    .dispatch(UserDataEvent::Account(state), ts_init)
+            }
+            BinanceUserDataMsg::ListenKeyExpired(msg) => {
+                if self.listen_key.on_expired(&msg.listen_key) {
+                    log::warn!("Listen key {} expired", msg.listen_key);
+                }
+                Ok(None)
+            }
+            BinanceUserDataMsg::Other => Ok(None),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::msgbus::MessageBus;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::engine::ExecutionEngine;
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus},
+        identifiers::{account_id::AccountId, client_order_id::ClientOrderId, trader_id::TraderId},
+        instruments::stubs::crypto_perpetual_ethusdt,
+        orders::{base::Order, stubs::TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::*;
+
+    #[fixture]
+    fn handler() -> (BinanceUserDataHandler, Rc<RefCell<ExecutionEngine>>) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let instrument = crypto_perpetual_ethusdt();
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(1),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        let mut engine = ExecutionEngine::new(msgbus.clone());
+        engine.add_instrument(Box::new(instrument));
+        engine.add_order(order.into()).unwrap();
+        let engine = Rc::new(RefCell::new(engine));
+        let dispatcher =
+            UserDataDispatcher::new(AccountId::from("BINANCE-001"), engine.clone(), msgbus);
+        let mut listen_key = ListenKeyManager::default();
+        listen_key.set_listen_key(Ustr::from("abc123"), 0);
+        (BinanceUserDataHandler::new(dispatcher, listen_key), engine)
+    }
+
+    fn order_trade_update(execution_type: &str, order_status: &str) -> String {
+        format!(
+            r#"{{"e":"ORDER_TRADE_UPDATE","E":1700000000100,"T":1700000000099,"o":{{
+                "s":"ETHUSDT","c":"O-001","S":"BUY","o":"MARKET","q":"1","p":"0","x":"{execution_type}",
+                "X":"{order_status}","i":8886774,"l":"1","z":"1","L":"2000.00","N":"USDT","n":"0.80",
+                "T":1700000000098,"t":12345,"m":false,"R":false}}}}"#
+        )
+    }
+
+    #[rstest]
+    fn test_handle_order_trade_updates(
+        handler: (BinanceUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, engine) = handler;
+
+        let accepted = handler
+            .handle_message(&order_trade_update("NEW", "NEW"), 1)
+            .unwrap();
+        let filled = handler
+            .handle_message(&order_trade_update("TRADE", "FILLED"), 2)
+            .unwrap();
+
+        assert!(matches!(accepted, Some(OrderEvent::OrderAccepted(_))));
+        assert!(matches!(filled, Some(OrderEvent::OrderFilled(_))));
+        let engine = engine.borrow();
+        let order = engine.order(&ClientOrderId::from("O-001")).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Filled);
+        assert_eq!(handler.dispatcher().order_event_count, 2);
+    }
+
+    #[rstest]
+    fn test_handle_account_update(handler: (BinanceUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, _) = handler;
+        let json = r#"{"e":"ACCOUNT_UPDATE","E":1700000000100,"T":1700000000099,"a":{"m":"ORDER",
+            "B":[{"a":"USDT","wb":"1000","cw":"1000","bc":"0"}],"P":[]}}"#;
+
+        let event = handler.handle_message(json, 1).unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(handler.dispatcher().account_state_count, 1);
+    }
+
+    #[rstest]
+    #[case("abc123", None)]
+    #[case("stale", Some(Ustr::from("abc123")))]
+    fn test_handle_listen_key_expired(
+        handler: (BinanceUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+        #[case] listen_key: &str,
+        #[case] expected: Option<Ustr>,
+    ) {
+        let (mut handler, _) = handler;
+        let json =
+            format!(r#"{{"e":"listenKeyExpired","E":"1700000000100","listenKey":"{listen_key}"}}"#);
+
+        handler.handle_message(&json, 1).unwrap();
+
+        assert_eq!(handler.listen_key.listen_key(), expected);
+    }
+
+    #[rstest]
+    fn test_handle_invalid_message_errors(
+        handler: (BinanceUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, _) = handler;
+
+        assert!(handler.handle_message("not json", 1).is_err());
+    }
+}
@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        delta::{OrderBookDelta, F_LAST, F_SNAPSHOT},
+        deltas::OrderBookDeltas,
+        order::BookOrder,
+    },
+    enums::{BookAction, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    types::{price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use super::types::{BitmexOrderBookL2Entry, BitmexSide, BitmexTableAction};
+use crate::user_data::parse_rfc3339;
+
+#[derive(Copy, Clone, Debug)]
+struct BookInstrument {
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+}
+
+/// Provides parsing of BitMEX `orderBookL2` table messages into order book deltas.
+///
+/// BitMEX keys each level by an ID which maps to a single price for the symbol, and does
+/// not guarantee the price is included on `update` or `delete` actions. The parser keeps
+/// the price of every live level ID (from the `partial` image and subsequent `insert`
+/// actions) so those actions can be resolved. Messages for a symbol received before its
+/// `partial` are skipped, as required by the BitMEX subscription protocol.
+#[derive(Debug, Default)]
+pub struct BitmexOrderBookL2Parser {
+    instruments: HashMap<Ustr, BookInstrument>,
+    levels: HashMap<Ustr, HashMap<u64, Price>>,
+}
+
+impl BitmexOrderBookL2Parser {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given `instrument`, keyed by its raw (BitMEX) symbol.
+    pub fn add_instrument(&mut self, instrument: &dyn Instrument) {
+        self.instruments.insert(
+            instrument.raw_symbol().value,
+            BookInstrument {
+                instrument_id: instrument.id(),
+                price_precision: instrument.price_precision(),
+                size_precision: instrument.size_precision(),
+            },
+        );
+    }
+
+    /// Returns the number of live levels for the given BitMEX `symbol` (or `None` if the
+    /// `partial` for the symbol has not been received).
+    #[must_use]
+    pub fn level_count(&self, symbol: &str) -> Option<usize> {
+        self.levels.get(&Ustr::from(symbol)).map(HashMap::len)
+    }
+
+    /// Returns the order book deltas for the given `orderBookL2` `action` and `entries`,
+    /// batched per instrument in the order first seen.
+    ///
+    /// A `partial` action starts each batch with a `CLEAR`, and the last delta of each
+    /// batch is flagged `F_LAST`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If an entry is for a symbol which has not been added.
+    /// - If an entry is missing its price or size, and the price cannot be resolved from
+    ///   its level ID.
+    pub fn parse(
+        &mut self,
+        action: BitmexTableAction,
+        entries: &[BitmexOrderBookL2Entry],
+        ts_init: UnixNanos,
+    ) -> Result<Vec<OrderBookDeltas>> {
+        let mut batches: IndexMap<Ustr, Vec<OrderBookDelta>> = IndexMap::new();
+
+        for entry in entries {
+            let Some(instrument) = self.instruments.get(&entry.symbol).copied() else {
+                bail!("Instrument for symbol {} not found", entry.symbol);
+            };
+            let ts_event = match &entry.timestamp {
+                Some(timestamp) => parse_rfc3339(timestamp)?,
+                None => ts_init,
+            };
+
+            if action == BitmexTableAction::Partial && !batches.contains_key(&entry.symbol) {
+                self.levels.insert(entry.symbol, HashMap::new());
+                batches.insert(
+                    entry.symbol,
+                    vec![OrderBookDelta::clear(
+                        instrument.instrument_id,
+                        0,
+                        ts_event,
+                        ts_init,
+                    )],
+                );
+            }
+
+            let Some(levels) = self.levels.get_mut(&entry.symbol) else {
+                log::debug!("Skipping {action:?} for {} before partial", entry.symbol);
+                continue;
+            };
+
+            let entry_price = entry
+                .price
+                .map(|price| Price::new(price, instrument.price_precision))
+                .transpose()?;
+            let price = match action {
+                BitmexTableAction::Partial | BitmexTableAction::Insert => {
+                    let price = entry_price.with_context(|| {
+                        format!("Level {} of {} has no price", entry.id, entry.symbol)
+                    })?;
+                    levels.insert(entry.id, price);
+                    Some(price)
+                }
+                BitmexTableAction::Update => entry_price.or_else(|| levels.get(&entry.id).copied()),
+                BitmexTableAction::Delete => levels.remove(&entry.id).or(entry_price),
+            }
+            .with_context(|| format!("Unknown level {} of {}", entry.id, entry.symbol))?;
+
+            let size = match action {
+                BitmexTableAction::Delete => Quantity::new(0.0, instrument.size_precision)?,
+                _ => {
+                    let size = entry.size.with_context(|| {
+                        format!("Level {} of {} has no size", entry.id, entry.symbol)
+                    })?;
+                    Quantity::new(size, instrument.size_precision)?
+                }
+            };
+
+            let side = match entry.side {
+                BitmexSide::Buy => OrderSide::Buy,
+                BitmexSide::Sell => OrderSide::Sell,
+            };
+            let (book_action, flags) = match action {
+                BitmexTableAction::Partial => (BookAction::Add, F_SNAPSHOT),
+                BitmexTableAction::Insert => (BookAction::Add, 0),
+                BitmexTableAction::Update => (BookAction::Update, 0),
+                BitmexTableAction::Delete => (BookAction::Delete, 0),
+            };
+
+            batches
+                .entry(entry.symbol)
+                .or_default()
+                .push(OrderBookDelta::new(
+                    instrument.instrument_id,
+                    book_action,
+                    BookOrder::new(side, price, size, entry.id),
+                    flags,
+                    0,
+                    ts_event,
+                    ts_init,
+                ));
+        }
+
+        Ok(batches
+            .into_values()
+            .map(|mut deltas| {
+                let last = deltas.last_mut().expect("batch has at least one delta");
+                last.flags |= F_LAST;
+                let (instrument_id, flags, ts_event) =
+                    (last.instrument_id, last.flags, last.ts_event);
+                OrderBookDeltas::new(instrument_id, deltas, flags, 0, ts_event, ts_init)
+            })
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::BookType,
+        instruments::{crypto_perpetual::CryptoPerpetual, stubs::xbtusd_bitmex},
+        orderbook::book::OrderBook,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::bitmex::types::BitmexWsTableMsg;
+
+    #[fixture]
+    fn parser(xbtusd_bitmex: CryptoPerpetual) -> BitmexOrderBookL2Parser {
+        let mut parser = BitmexOrderBookL2Parser::new();
+        parser.add_instrument(&xbtusd_bitmex);
+        parser
+    }
+
+    fn parse(parser: &mut BitmexOrderBookL2Parser, json: &str) -> Result<Vec<OrderBookDeltas>> {
+        let msg: BitmexWsTableMsg = serde_json::from_str(json).unwrap();
+        let entries: Vec<BitmexOrderBookL2Entry> = serde_json::from_value(msg.data).unwrap();
+        parser.parse(msg.action, &entries, 1)
+    }
+
+    const PARTIAL: &str = r#"{"table":"orderBookL2","action":"partial","keys":["symbol","id","side"],"data":[
+        {"symbol":"XBTUSD","id":8799499950,"side":"Sell","size":200,"price":50000.5,"timestamp":"2024-01-01T00:00:00.000Z"},
+        {"symbol":"XBTUSD","id":8799500000,"side":"Sell","size":100,"price":50000.0,"timestamp":"2024-01-01T00:00:00.000Z"},
+        {"symbol":"XBTUSD","id":8799500050,"side":"Buy","size":300,"price":49999.5,"timestamp":"2024-01-01T00:00:00.000Z"}]}"#;
+
+    #[rstest]
+    fn test_parse_partial(mut parser: BitmexOrderBookL2Parser) {
+        let batches = parse(&mut parser, PARTIAL).unwrap();
+
+        assert_eq!(batches.len(), 1);
+        let deltas = &batches[0].deltas;
+        assert_eq!(deltas.len(), 4);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert!(deltas[1..].iter().all(|d| d.action == BookAction::Add));
+        assert!(deltas.iter().all(|d| d.flags & F_SNAPSHOT == F_SNAPSHOT));
+        assert_eq!(deltas[3].flags & F_LAST, F_LAST);
+        assert_eq!(deltas[2].flags & F_LAST, 0);
+        assert_eq!(deltas[3].order.side, OrderSide::Buy);
+        assert_eq!(deltas[3].order.price, Price::from("49999.5"));
+        assert_eq!(deltas[3].order.size, Quantity::from(300));
+        assert_eq!(deltas[3].order.order_id, 8_799_500_050);
+        assert_eq!(
+            batches[0].instrument_id,
+            InstrumentId::from("BTCUSDT.BITMEX")
+        );
+        assert_eq!(batches[0].ts_event, 1_704_067_200_000_000_000);
+        assert_eq!(parser.level_count("XBTUSD"), Some(3));
+    }
+
+    #[rstest]
+    fn test_parse_update_and_delete_resolve_price_from_level_id(
+        mut parser: BitmexOrderBookL2Parser,
+    ) {
+        parse(&mut parser, PARTIAL).unwrap();
+        let update = r#"{"table":"orderBookL2","action":"update","data":[
+            {"symbol":"XBTUSD","id":8799500000,"side":"Sell","size":150,"timestamp":"2024-01-01T00:00:01.000Z"}]}"#;
+        let delete = r#"{"table":"orderBookL2","action":"delete","data":[
+            {"symbol":"XBTUSD","id":8799499950,"side":"Sell","timestamp":"2024-01-01T00:00:02.000Z"}]}"#;
+
+        let updated = parse(&mut parser, update).unwrap();
+        let deleted = parse(&mut parser, delete).unwrap();
+
+        let delta = &updated[0].deltas[0];
+        assert_eq!(delta.action, BookAction::Update);
+        assert_eq!(delta.order.price, Price::from("50000.0"));
+        assert_eq!(delta.order.size, Quantity::from(150));
+        assert_eq!(delta.flags, F_LAST);
+        let delta = &deleted[0].deltas[0];
+        assert_eq!(delta.action, BookAction::Delete);
+        assert_eq!(delta.order.price, Price::from("50000.5"));
+        assert_eq!(parser.level_count("XBTUSD"), Some(2));
+    }
+
+    #[rstest]
+    fn test_parse_insert_then_update_of_new_level(mut parser: BitmexOrderBookL2Parser) {
+        parse(&mut parser, PARTIAL).unwrap();
+        let insert = r#"{"table":"orderBookL2","action":"insert","data":[
+            {"symbol":"XBTUSD","id":8799500100,"side":"Buy","size":10,"price":49999.0}]}"#;
+        let update = r#"{"table":"orderBookL2","action":"update","data":[
+            {"symbol":"XBTUSD","id":8799500100,"side":"Buy","size":20}]}"#;
+
+        parse(&mut parser, insert).unwrap();
+        let updated = parse(&mut parser, update).unwrap();
+
+        assert_eq!(updated[0].deltas[0].order.price, Price::from("49999.0"));
+        assert_eq!(updated[0].ts_event, 1);
+    }
+
+    #[rstest]
+    fn test_parse_update_of_unknown_level_returns_error(mut parser: BitmexOrderBookL2Parser) {
+        parse(&mut parser, PARTIAL).unwrap();
+        let update = r#"{"table":"orderBookL2","action":"update","data":[
+            {"symbol":"XBTUSD","id":1,"side":"Buy","size":20}]}"#;
+
+        assert!(parse(&mut parser, update).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_before_partial_is_skipped(mut parser: BitmexOrderBookL2Parser) {
+        let update = r#"{"table":"orderBookL2","action":"update","data":[
+            {"symbol":"XBTUSD","id":8799500000,"side":"Sell","size":150}]}"#;
+
+        let batches = parse(&mut parser, update).unwrap();
+
+        assert!(batches.is_empty());
+        assert_eq!(parser.level_count("XBTUSD"), None);
+    }
+
+    #[rstest]
+    fn test_parse_unknown_symbol_returns_error(mut parser: BitmexOrderBookL2Parser) {
+        let partial = r#"{"table":"orderBookL2","action":"partial","data":[
+            {"symbol":"ETHUSD","id":1,"side":"Buy","size":1,"price":2000.0}]}"#;
+
+        assert!(parse(&mut parser, partial).is_err());
+    }
+
+    #[rstest]
+    fn test_deltas_applied_to_book(mut parser: BitmexOrderBookL2Parser) {
+        let delete = r#"{"table":"orderBookL2","action":"delete","data":[
+            {"symbol":"XBTUSD","id":8799500000,"side":"Sell"}]}"#;
+        let mut book = OrderBook::new(InstrumentId::from("BTCUSDT.BITMEX"), BookType::L2_MBP);
+
+        for json in [PARTIAL, delete] {
+            for batch in parse(&mut parser, json).unwrap() {
+                for delta in batch.deltas {
+                    book.apply_delta(delta);
+                }
+            }
+        }
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("49999.5")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("50000.5")));
+        assert_eq!(book.best_bid_size(), Some(Quantity::from(300)));
+    }
+}
@@ -0,0 +1,485 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide},
+    events::account::state::AccountState,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        symbol::Symbol, trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
+    },
+    instruments::crypto_perpetual::CryptoPerpetual,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+use ustr::Ustr;
+
+use super::types::{BitmexExecType, BitmexInstrument, BitmexWsExecution, BitmexWsMargin};
+use crate::user_data::{
+    parse_currency, parse_rfc3339, OrderUpdateKind, VenueFill, VenueOrderUpdate,
+};
+
+/// The venue identifier for BitMEX.
+pub const BITMEX_VENUE: &str = "BITMEX";
+
+/// The BitMEX instrument type code for perpetual contracts.
+const PERPETUAL_TYPE: &str = "FFWCSX";
+
+/// Returns the currency for the given BitMEX asset `code`.
+///
+/// BitMEX uses `XBT` for bitcoin, and denotes settlement currencies by their smallest
+/// unit (such as `XBt` for satoshis and `USDt` for micro-tethers).
+pub fn parse_asset(code: &str) -> Result<Currency> {
+    match code {
+        "XBT" | "XBt" => Ok(Currency::BTC()),
+        "USDt" => Ok(Currency::USDT()),
+        _ => parse_currency(&code.to_uppercase()),
+    }
+}
+
+fn settlement_unit_scale(code: &str) -> Result<Decimal> {
+    match code {
+        "XBt" => Ok(dec!(0.00000001)),
+        "USDt" => Ok(dec!(0.000001)),
+        _ => bail!("Unsupported settlement currency '{code}'"),
+    }
+}
+
+/// Returns the amount and currency for the given `amount` in the smallest unit of the
+/// BitMEX settlement currency `code`.
+pub fn parse_settlement_amount(amount: i64, code: &str) -> Result<(f64, Currency)> {
+    let value = Decimal::from(amount) * settlement_unit_scale(code)?;
+    let value = value
+        .to_f64()
+        .with_context(|| format!("Invalid settlement amount {amount}"))?;
+    Ok((value, parse_asset(code)?))
+}
+
+fn parse_decimal(value: f64, field: &str) -> Result<Decimal> {
+    // `f64` display is the shortest representation which round trips
+    Decimal::from_str(&value.to_string())
+        .map(|d| d.normalize())
+        .with_context(|| format!("Invalid `{field}` value {value}"))
+}
+
+fn parse_optional_decimal(value: Option<f64>, field: &str) -> Result<Decimal> {
+    value.map_or(Ok(Decimal::ZERO), |v| parse_decimal(v, field))
+}
+
+/// Returns the perpetual instrument for the given BitMEX instrument, or `None` if the
+/// instrument is not a perpetual contract.
+pub fn parse_instrument(
+    instrument: &BitmexInstrument,
+    ts_init: UnixNanos,
+) -> Result<Option<CryptoPerpetual>> {
+    if instrument.typ != PERPETUAL_TYPE {
+        return Ok(None);
+    }
+
+    let Some(settl_currency) = instrument.settl_currency else {
+        bail!(
+            "Instrument {} has no settlement currency",
+            instrument.symbol
+        );
+    };
+    let settlement_currency = parse_asset(&settl_currency)?;
+
+    let quanto_multiplier = if instrument.is_quanto {
+        let multiplier = instrument.multiplier.with_context(|| {
+            format!("Quanto instrument {} has no multiplier", instrument.symbol)
+        })?;
+        let multiplier = parse_decimal(multiplier.abs(), "multiplier")?;
+        Some((multiplier * settlement_unit_scale(&settl_currency)?).normalize())
+    } else {
+        None
+    };
+
+    let price_increment = parse_decimal(instrument.tick_size, "tickSize")?;
+    let size_increment = parse_decimal(instrument.lot_size, "lotSize")?;
+    let price_precision = price_increment.scale() as u8;
+    let size_precision = size_increment.scale() as u8;
+    let lot_size = Quantity::new(instrument.lot_size, size_precision)?;
+
+    let instrument = CryptoPerpetual::new(
+        InstrumentId::new(Symbol::new(&instrument.symbol)?, Venue::new(BITMEX_VENUE)?),
+        Symbol::new(&instrument.symbol)?,
+        parse_asset(&instrument.underlying)?,
+        parse_asset(&instrument.quote_currency)?,
+        settlement_currency,
+        instrument.is_inverse,
+        quanto_multiplier,
+        price_precision,
+        size_precision,
+        Price::new(instrument.tick_size, price_precision)?,
+        lot_size,
+        parse_optional_decimal(instrument.maker_fee, "makerFee")?,
+        parse_optional_decimal(instrument.taker_fee, "takerFee")?,
+        parse_optional_decimal(instrument.init_margin, "initMargin")?,
+        parse_optional_decimal(instrument.maint_margin, "maintMargin")?,
+        Some(lot_size),
+        instrument
+            .max_order_qty
+            .map(|qty| Quantity::new(qty, size_precision))
+            .transpose()?,
+        Some(lot_size),
+        None,
+        None,
+        instrument
+            .max_price
+            .map(|price| Price::new(price, price_precision))
+            .transpose()?,
+        None,
+        parse_rfc3339(&instrument.timestamp)?,
+        ts_init,
+    )?;
+
+    Ok(Some(instrument))
+}
+
+/// Returns the perpetual instruments for the given BitMEX `GET /instrument` response
+/// `json`, skipping instruments which are not perpetual contracts.
+pub fn parse_instruments(json: &str, ts_init: UnixNanos) -> Result<Vec<CryptoPerpetual>> {
+    let instruments: Vec<BitmexInstrument> =
+        serde_json::from_str(json).context("Invalid instrument response")?;
+
+    let mut perpetuals = Vec::new();
+    for instrument in &instruments {
+        match parse_instrument(instrument, ts_init)? {
+            Some(perpetual) => perpetuals.push(perpetual),
+            None => log::debug!(
+                "Skipping instrument {} of type {}",
+                instrument.symbol,
+                instrument.typ
+            ),
+        }
+    }
+    Ok(perpetuals)
+}
+
+fn parse_client_order_id(cl_ord_id: &str) -> Result<Option<ClientOrderId>> {
+    if cl_ord_id.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(ClientOrderId::new(cl_ord_id)?))
+}
+
+fn parse_liquidity_side(indicator: Option<Ustr>) -> LiquiditySide {
+    match indicator.as_deref() {
+        Some("AddedLiquidity") => LiquiditySide::Maker,
+        Some("RemovedLiquidity") => LiquiditySide::Taker,
+        _ => LiquiditySide::NoLiquiditySide,
+    }
+}
+
+/// Returns the normalized order update for the given BitMEX execution, or `None` for
+/// executions which do not change the order state (such as funding or restatements).
+///
+/// BitMEX reports the whole order lifecycle on the `execution` table, so the `order`
+/// table (which only includes changed fields on updates) is not required.
+pub fn parse_execution(execution: &BitmexWsExecution) -> Result<Option<VenueOrderUpdate>> {
+    let kind = match execution.exec_type {
+        BitmexExecType::New => OrderUpdateKind::Accepted,
+        BitmexExecType::Rejected => OrderUpdateKind::Rejected(
+            execution
+                .ord_rej_reason
+                .filter(|reason| !reason.is_empty())
+                .or(execution.text)
+                .unwrap_or_else(|| Ustr::from("UNKNOWN")),
+        ),
+        BitmexExecType::Trade => {
+            let commission = match (execution.exec_comm, execution.settl_currency) {
+                (Some(amount), Some(code)) => Some(parse_settlement_amount(amount, &code)?),
+                _ => None,
+            };
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::new(&execution.exec_id)?,
+                last_qty: execution
+                    .last_qty
+                    .context("Trade execution has no `lastQty`")?,
+                last_px: execution
+                    .last_px
+                    .context("Trade execution has no `lastPx`")?,
+                liquidity_side: parse_liquidity_side(execution.last_liquidity_ind),
+                commission: commission.map(|(amount, _)| amount),
+                commission_currency: commission.map(|(_, currency)| currency),
+            })
+        }
+        BitmexExecType::Canceled => OrderUpdateKind::Canceled,
+        BitmexExecType::Expired => OrderUpdateKind::Expired,
+        BitmexExecType::Replaced
+        | BitmexExecType::Restated
+        | BitmexExecType::TriggeredOrActivatedBySystem
+        | BitmexExecType::Funding
+        | BitmexExecType::Settlement
+        | BitmexExecType::Other => return Ok(None),
+    };
+
+    let ts_event = parse_rfc3339(
+        execution
+            .transact_time
+            .as_deref()
+            .unwrap_or(&execution.timestamp),
+    )?;
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id: parse_client_order_id(&execution.cl_ord_id)?,
+        venue_order_id: VenueOrderId::new(&execution.order_id)?,
+        kind,
+        ts_event,
+    }))
+}
+
+/// Returns the account state for the given (merged) BitMEX margin, or `None` if the
+/// balances have not yet been received.
+///
+/// The available margin is free, with the remainder of the wallet balance locked.
+pub fn parse_margin(
+    margin: &BitmexWsMargin,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> Result<Option<AccountState>> {
+    let (Some(wallet_balance), Some(available_margin)) =
+        (margin.wallet_balance, margin.available_margin)
+    else {
+        return Ok(None);
+    };
+
+    let (total, currency) = parse_settlement_amount(wallet_balance, &margin.currency)?;
+    let (available, _) = parse_settlement_amount(available_margin, &margin.currency)?;
+    let total = Money::new(total, currency)?;
+    let free = Money::new(available.clamp(0.0, total.as_f64().max(0.0)), currency)?;
+    let ts_event = match &margin.timestamp {
+        Some(timestamp) => parse_rfc3339(timestamp)?,
+        None => ts_init,
+    };
+
+    let state = AccountState::new(
+        account_id,
+        AccountType::Margin,
+        vec![AccountBalance::new(total, total - free, free)?],
+        vec![],
+        true,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        None,
+    )?;
+    Ok(Some(state))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const XBTUSD: &str = r#"{"symbol":"XBTUSD","rootSymbol":"XBT","state":"Open","typ":"FFWCSX",
+        "listing":"2016-05-13T12:00:00.000Z","expiry":null,"underlying":"XBT","quoteCurrency":"USD",
+        "positionCurrency":"USD","settlCurrency":"XBt","isQuanto":false,"isInverse":true,
+        "initMargin":0.01,"maintMargin":0.0035,"makerFee":-0.0001,"takerFee":0.00075,"tickSize":0.5,
+        "lotSize":100,"multiplier":-100000000,"maxOrderQty":10000000,"maxPrice":1000000,
+        "timestamp":"2024-01-01T00:00:00.000Z"}"#;
+
+    const ETHUSD: &str = r#"{"symbol":"ETHUSD","rootSymbol":"ETH","state":"Open","typ":"FFWCSX",
+        "underlying":"ETH","quoteCurrency":"USD","settlCurrency":"XBt","isQuanto":true,
+        "isInverse":false,"initMargin":0.02,"maintMargin":0.01,"makerFee":-0.0001,"takerFee":0.00075,
+        "tickSize":0.05,"lotSize":1,"multiplier":100,"maxOrderQty":10000000,"maxPrice":1000000,
+        "timestamp":"2024-01-01T00:00:00.000Z"}"#;
+
+    const XBTZ24: &str = r#"{"symbol":"XBTZ24","rootSymbol":"XBT","state":"Open","typ":"FFCCSX",
+        "underlying":"XBT","quoteCurrency":"USD","settlCurrency":"XBt","isQuanto":false,
+        "isInverse":true,"tickSize":0.5,"lotSize":100,"multiplier":-100000000,
+        "timestamp":"2024-01-01T00:00:00.000Z"}"#;
+
+    #[rstest]
+    #[case("XBT", "BTC")]
+    #[case("XBt", "BTC")]
+    #[case("USDt", "USDT")]
+    #[case("USD", "USD")]
+    #[case("eth", "ETH")]
+    fn test_parse_asset(#[case] code: &str, #[case] expected: &str) {
+        assert_eq!(parse_asset(code).unwrap(), Currency::from(expected));
+    }
+
+    #[rstest]
+    fn test_parse_settlement_amount() {
+        assert_eq!(
+            parse_settlement_amount(150_000_000, "XBt").unwrap(),
+            (1.5, Currency::BTC())
+        );
+        assert_eq!(
+            parse_settlement_amount(2_500_000, "USDt").unwrap(),
+            (2.5, Currency::USDT())
+        );
+        assert!(parse_settlement_amount(1, "LAMp").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_inverse_perpetual() {
+        let instrument: BitmexInstrument = serde_json::from_str(XBTUSD).unwrap();
+
+        let perpetual = parse_instrument(&instrument, 1).unwrap().unwrap();
+
+        assert_eq!(perpetual.id, InstrumentId::from("XBTUSD.BITMEX"));
+        assert_eq!(perpetual.base_currency, Currency::BTC());
+        assert_eq!(perpetual.quote_currency, Currency::USD());
+        assert_eq!(perpetual.settlement_currency, Currency::BTC());
+        assert!(perpetual.is_inverse);
+        assert_eq!(perpetual.price_precision, 1);
+        assert_eq!(perpetual.size_precision, 0);
+        assert_eq!(perpetual.price_increment, Price::from("0.5"));
+        assert_eq!(perpetual.size_increment, Quantity::from(100));
+        assert_eq!(perpetual.max_quantity, Some(Quantity::from(10_000_000)));
+        assert_eq!(perpetual.maker_fee, dec!(-0.0001));
+        assert_eq!(perpetual.taker_fee, dec!(0.00075));
+        assert_eq!(perpetual.margin_maint, dec!(0.0035));
+        assert_eq!(perpetual.ts_event, 1_704_067_200_000_000_000);
+        assert_eq!(perpetual.ts_init, 1);
+    }
+
+    #[rstest]
+    fn test_parse_quanto_perpetual() {
+        let instrument: BitmexInstrument = serde_json::from_str(ETHUSD).unwrap();
+
+        let perpetual = parse_instrument(&instrument, 1).unwrap().unwrap();
+
+        assert!(!perpetual.is_inverse);
+        assert_eq!(perpetual.settlement_currency, Currency::BTC());
+        assert_eq!(perpetual.quanto_multiplier, Some(dec!(0.000001)));
+        assert_eq!(perpetual.price_precision, 2);
+    }
+
+    #[rstest]
+    fn test_parse_instruments_skips_non_perpetuals() {
+        let json = format!("[{XBTUSD},{XBTZ24},{ETHUSD}]");
+
+        let perpetuals = parse_instruments(&json, 1).unwrap();
+
+        assert_eq!(
+            perpetuals.iter().map(|p| p.id).collect::<Vec<_>>(),
+            vec![
+                InstrumentId::from("XBTUSD.BITMEX"),
+                InstrumentId::from("ETHUSD.BITMEX"),
+            ]
+        );
+    }
+
+    fn execution(exec_type: &str) -> BitmexWsExecution {
+        serde_json::from_str(&format!(
+            r#"{{"execID":"0e1c4a0c-2c1e-8d8e-4c2c-4e3f3f2f7d53","orderID":"57fbf8a5-05a1-4c5e-8c7b-6e9a3c9a2a6b",
+                "clOrdID":"O-001","account":12345,"symbol":"XBTUSD","side":"Buy","lastQty":100,"lastPx":50000.5,
+                "ordStatus":"Filled","execType":"{exec_type}","lastLiquidityInd":"RemovedLiquidity",
+                "execComm":150,"settlCurrency":"XBt","ordRejReason":"","text":"Submitted via API.",
+                "transactTime":"2024-01-01T00:00:00.050Z","timestamp":"2024-01-01T00:00:00.060Z"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("New", Some(OrderUpdateKind::Accepted))]
+    #[case("Canceled", Some(OrderUpdateKind::Canceled))]
+    #[case("Expired", Some(OrderUpdateKind::Expired))]
+    #[case(
+        "Rejected",
+        Some(OrderUpdateKind::Rejected(Ustr::from("Submitted via API.")))
+    )]
+    #[case("Replaced", None)]
+    #[case("Funding", None)]
+    #[case("SomethingNew", None)]
+    fn test_parse_execution(#[case] exec_type: &str, #[case] expected: Option<OrderUpdateKind>) {
+        let update = parse_execution(&execution(exec_type)).unwrap();
+
+        assert_eq!(update.clone().map(|u| u.kind), expected);
+        if let Some(update) = update {
+            assert_eq!(update.client_order_id, Some(ClientOrderId::from("O-001")));
+            assert_eq!(update.ts_event, 1_704_067_200_050_000_000);
+        }
+    }
+
+    #[rstest]
+    fn test_parse_execution_trade() {
+        let update = parse_execution(&execution("Trade")).unwrap().unwrap();
+
+        assert_eq!(
+            update.venue_order_id,
+            VenueOrderId::from("57fbf8a5-05a1-4c5e-8c7b-6e9a3c9a2a6b")
+        );
+        assert_eq!(
+            update.kind,
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::from("0e1c4a0c-2c1e-8d8e-4c2c-4e3f3f2f7d53"),
+                last_qty: 100.0,
+                last_px: 50000.5,
+                liquidity_side: LiquiditySide::Taker,
+                commission: Some(0.0000015),
+                commission_currency: Some(Currency::BTC()),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_parse_execution_rejected_with_reason() {
+        let mut execution = execution("Rejected");
+        execution.ord_rej_reason = Some(Ustr::from("Account has insufficient Available Balance"));
+
+        let update = parse_execution(&execution).unwrap().unwrap();
+
+        assert_eq!(
+            update.kind,
+            OrderUpdateKind::Rejected(Ustr::from("Account has insufficient Available Balance"))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_margin() {
+        let margin: BitmexWsMargin = serde_json::from_str(
+            r#"{"account":12345,"currency":"XBt","walletBalance":100000000,
+                "availableMargin":80000000,"timestamp":"2024-01-01T00:00:00.000Z"}"#,
+        )
+        .unwrap();
+
+        let state = parse_margin(&margin, AccountId::from("BITMEX-001"), 2)
+            .unwrap()
+            .unwrap();
+
+        let btc = Currency::BTC();
+        assert_eq!(state.balances[0].total, Money::new(1.0, btc).unwrap());
+        assert_eq!(state.balances[0].free, Money::new(0.8, btc).unwrap());
+        assert_eq!(state.balances[0].locked, Money::new(0.2, btc).unwrap());
+        assert_eq!(state.ts_event, 1_704_067_200_000_000_000);
+        assert_eq!(state.ts_init, 2);
+    }
+
+    #[rstest]
+    fn test_parse_margin_without_balances() {
+        let margin: BitmexWsMargin =
+            serde_json::from_str(r#"{"account":12345,"currency":"XBt","availableMargin":1}"#)
+                .unwrap();
+
+        let state = parse_margin(&margin, AccountId::from("BITMEX-001"), 2).unwrap();
+
+        assert!(state.is_none());
+    }
+}
@@ -0,0 +1,194 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents a BitMEX instrument from the REST `GET /instrument` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexInstrument {
+    pub symbol: Ustr,
+    /// The underlying (base) asset, using BitMEX codes (such as `XBT`).
+    pub underlying: Ustr,
+    pub quote_currency: Ustr,
+    /// The settlement currency in its smallest unit (such as `XBt` satoshis or `USDt`).
+    pub settl_currency: Option<Ustr>,
+    /// The CFI-style instrument type code (`FFWCSX` for perpetual contracts).
+    pub typ: Ustr,
+    pub state: Ustr,
+    pub is_inverse: bool,
+    pub is_quanto: bool,
+    /// The settlement multiplier per contract, in the settlement currency's smallest unit.
+    pub multiplier: Option<f64>,
+    pub tick_size: f64,
+    pub lot_size: f64,
+    pub max_order_qty: Option<f64>,
+    pub max_price: Option<f64>,
+    pub maker_fee: Option<f64>,
+    pub taker_fee: Option<f64>,
+    pub init_margin: Option<f64>,
+    pub maint_margin: Option<f64>,
+    /// The last update time (ISO 8601).
+    pub timestamp: String,
+}
+
+/// Represents a BitMEX WebSocket message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BitmexWsMessage {
+    Table(BitmexWsTableMsg),
+    Error(BitmexWsErrorMsg),
+    Response(BitmexWsResponseMsg),
+    Info(BitmexWsInfoMsg),
+}
+
+/// Represents a BitMEX table message, with the data parsed according to the table.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitmexWsTableMsg {
+    pub table: Ustr,
+    pub action: BitmexTableAction,
+    pub data: serde_json::Value,
+}
+
+/// The action of a BitMEX table message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitmexTableAction {
+    /// The full table image, sent on subscription.
+    Partial,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Represents a BitMEX error message (such as a failed `authKeyExpires` or `subscribe`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitmexWsErrorMsg {
+    pub error: String,
+    pub status: Option<u16>,
+}
+
+/// Represents a BitMEX response to a request (such as `authKeyExpires` or `subscribe`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitmexWsResponseMsg {
+    pub success: bool,
+    pub subscribe: Option<Ustr>,
+}
+
+/// Represents the BitMEX welcome message sent on connection.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitmexWsInfoMsg {
+    pub info: String,
+}
+
+/// The side of a BitMEX order or book level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum BitmexSide {
+    Buy,
+    Sell,
+}
+
+/// Represents a level of a BitMEX `orderBookL2` table message.
+///
+/// Each level is keyed by `id`, which maps to a single price for the symbol. The price
+/// is only guaranteed to be included on `partial` and `insert` actions.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitmexOrderBookL2Entry {
+    pub symbol: Ustr,
+    pub id: u64,
+    pub side: BitmexSide,
+    /// The level size (not included on `delete` actions).
+    pub size: Option<f64>,
+    pub price: Option<f64>,
+    /// The level update time (ISO 8601).
+    pub timestamp: Option<String>,
+}
+
+/// Represents an execution of a BitMEX `execution` table message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexWsExecution {
+    #[serde(rename = "execID")]
+    pub exec_id: Ustr,
+    #[serde(rename = "orderID")]
+    pub order_id: Ustr,
+    /// The client order ID (empty if not set).
+    #[serde(rename = "clOrdID", default)]
+    pub cl_ord_id: Ustr,
+    pub symbol: Ustr,
+    pub exec_type: BitmexExecType,
+    pub last_qty: Option<f64>,
+    pub last_px: Option<f64>,
+    /// The liquidity indicator (`AddedLiquidity` or `RemovedLiquidity`) for trades.
+    pub last_liquidity_ind: Option<Ustr>,
+    /// The commission in the settlement currency's smallest unit (negative for rebates).
+    pub exec_comm: Option<i64>,
+    pub settl_currency: Option<Ustr>,
+    pub ord_rej_reason: Option<Ustr>,
+    pub text: Option<Ustr>,
+    /// The transaction time (ISO 8601).
+    pub transact_time: Option<String>,
+    /// The execution time (ISO 8601).
+    pub timestamp: String,
+}
+
+/// The execution type of a BitMEX execution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum BitmexExecType {
+    New,
+    Trade,
+    Canceled,
+    Rejected,
+    Expired,
+    Replaced,
+    Restated,
+    /// A stop or if-touched order which was triggered.
+    TriggeredOrActivatedBySystem,
+    Funding,
+    Settlement,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents the margin of a BitMEX `margin` table message.
+///
+/// Amounts are in the currency's smallest unit. BitMEX only includes changed fields on
+/// `update` actions, so margins must be merged with the previous state.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexWsMargin {
+    pub account: u64,
+    pub currency: Ustr,
+    pub wallet_balance: Option<i64>,
+    pub available_margin: Option<i64>,
+    /// The update time (ISO 8601).
+    pub timestamp: Option<String>,
+}
+
+impl BitmexWsMargin {
+    /// Merges the fields present in the given `update` into the margin.
+    pub fn merge(&mut self, update: &Self) {
+        if update.wallet_balance.is_some() {
+            self.wallet_balance = update.wallet_balance;
+        }
+        if update.available_margin.is_some() {
+            self.available_margin = update.available_margin;
+        }
+        if update.timestamp.is_some() {
+            self.timestamp.clone_from(&update.timestamp);
+        }
+    }
+}
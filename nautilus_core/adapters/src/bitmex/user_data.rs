@@ -0,0 +1,288 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::events::order::event::OrderEvent;
+use ustr::Ustr;
+
+use super::{
+    parsing::{parse_execution, parse_margin},
+    types::{BitmexTableAction, BitmexWsExecution, BitmexWsMargin, BitmexWsMessage},
+};
+use crate::user_data::{UserDataDispatcher, UserDataEvent};
+
+/// The interval between heartbeat pings (BitMEX recommends a ping if no message has
+/// been received within 5 seconds).
+pub const PING_INTERVAL_SECS: u64 = 5;
+
+/// The heartbeat ping message.
+pub const PING_MESSAGE: &str = "ping";
+
+/// The heartbeat pong message.
+pub const PONG_MESSAGE: &str = "pong";
+
+/// Provides handling of BitMEX private WebSocket messages.
+///
+/// Messages for the `execution` and `margin` tables are dispatched to the execution
+/// engine. Margins are merged with the previous state per currency, as BitMEX only
+/// includes changed fields on updates.
+pub struct BitmexUserDataHandler {
+    dispatcher: UserDataDispatcher,
+    margins: HashMap<Ustr, BitmexWsMargin>,
+}
+
+impl BitmexUserDataHandler {
+    #[must_use]
+    pub fn new(dispatcher: UserDataDispatcher) -> Self {
+        Self {
+            dispatcher,
+            margins: HashMap::new(),
+        }
+    }
+
+    /// Returns the user-data dispatcher for the handler.
+    #[must_use]
+    pub fn dispatcher(&self) -> &UserDataDispatcher {
+        &self.dispatcher
+    }
+
+    /// Handles the given private WebSocket message `text`, returning the order events
+    /// applied by the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message cannot be parsed or dispatched.
+    /// - If authentication or a subscription failed.
+    pub fn handle_message(&mut self, text: &str, ts_init: UnixNanos) -> Result<Vec<OrderEvent>> {
+        if text == PONG_MESSAGE {
+            return Ok(vec![]);
+        }
+
+        let msg: BitmexWsMessage = serde_json::from_str(text)
+            .with_context(|| format!("Invalid private WebSocket message {text}"))?;
+
+        let msg = match msg {
+            BitmexWsMessage::Table(msg) => msg,
+            BitmexWsMessage::Error(msg) => match msg.status {
+                Some(status) => bail!("Request failed ({status}): {}", msg.error),
+                None => bail!("Request failed: {}", msg.error),
+            },
+            BitmexWsMessage::Response(_) => return Ok(vec![]),
+            BitmexWsMessage::Info(msg) => {
+                log::debug!("{}", msg.info);
+                return Ok(vec![]);
+            }
+        };
+
+        let mut updates = Vec::new();
+        match msg.table.as_str() {
+            "execution" => {
+                let executions: Vec<BitmexWsExecution> = serde_json::from_value(msg.data)?;
+                for execution in &executions {
+                    updates.extend(parse_execution(execution)?);
+                }
+            }
+            "margin" => {
+                let margins: Vec<BitmexWsMargin> = serde_json::from_value(msg.data)?;
+                for margin in &margins {
+                    let margin = self.merge_margin(msg.action, margin);
+                    if let Some(state) =
+                        parse_margin(&margin, self.dispatcher.account_id(), ts_init)?
+                    {
+                        self.dispatcher
+                            .dispatch(UserDataEvent::Account(state), ts_init)?;
+                    }
+                }
+            }
+            _ => log::debug!("Skipping message for table {}", msg.table),
+        }
+
+        let mut events = Vec::with_capacity(updates.len());
+        for update in updates {
+            events.extend(
+                self.dispatcher
+                    .dispatch(UserDataEvent::Order(update), ts_init)?,
+            );
+        }
+        Ok(events)
+    }
+
+    fn merge_margin(
+        &mut self,
+        action: BitmexTableAction,
+        margin: &BitmexWsMargin,
+    ) -> BitmexWsMargin {
+        match action {
+            BitmexTableAction::Partial | BitmexTableAction::Insert => {
+                self.margins.insert(margin.currency, margin.clone());
+                margin.clone()
+            }
+            BitmexTableAction::Update => {
+                let merged = self
+                    .margins
+                    .entry(margin.currency)
+                    .or_insert_with(|| margin.clone());
+                merged.merge(margin);
+                merged.clone()
+            }
+            BitmexTableAction::Delete => {
+                self.margins.remove(&margin.currency);
+                BitmexWsMargin {
+                    wallet_balance: None,
+                    available_margin: None,
+                    ..margin.clone()
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::msgbus::MessageBus;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::engine::ExecutionEngine;
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus},
+        identifiers::{account_id::AccountId, client_order_id::ClientOrderId, trader_id::TraderId},
+        instruments::stubs::xbtusd_bitmex,
+        orders::{base::Order, stubs::TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn handler() -> (BitmexUserDataHandler, Rc<RefCell<ExecutionEngine>>) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let instrument = xbtusd_bitmex();
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(200),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        let mut engine = ExecutionEngine::new(msgbus.clone());
+        engine.add_instrument(Box::new(instrument));
+        engine.add_order(order.into()).unwrap();
+        let engine = Rc::new(RefCell::new(engine));
+        let dispatcher =
+            UserDataDispatcher::new(AccountId::from("BITMEX-001"), engine.clone(), msgbus);
+        (BitmexUserDataHandler::new(dispatcher), engine)
+    }
+
+    fn execution_msg(exec_id: &str, exec_type: &str) -> String {
+        format!(
+            r#"{{"table":"execution","action":"insert","data":[{{"execID":"{exec_id}",
+                "orderID":"57fbf8a5-05a1-4c5e-8c7b-6e9a3c9a2a6b","clOrdID":"O-001","account":12345,
+                "symbol":"XBTUSD","side":"Buy","lastQty":100,"lastPx":50000.5,"execType":"{exec_type}",
+                "lastLiquidityInd":"RemovedLiquidity","execComm":150,"settlCurrency":"XBt",
+                "transactTime":"2024-01-01T00:00:00.050Z","timestamp":"2024-01-01T00:00:00.060Z"}}]}}"#
+        )
+    }
+
+    #[rstest]
+    fn test_handle_executions(handler: (BitmexUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, engine) = handler;
+
+        let accepted = handler
+            .handle_message(&execution_msg("E-0", "New"), 1)
+            .unwrap();
+        let first = handler
+            .handle_message(&execution_msg("E-1", "Trade"), 2)
+            .unwrap();
+        let replayed = handler
+            .handle_message(&execution_msg("E-1", "Trade"), 3)
+            .unwrap();
+        let second = handler
+            .handle_message(&execution_msg("E-2", "Trade"), 4)
+            .unwrap();
+
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(first.len(), 1);
+        assert!(replayed.is_empty());
+        assert_eq!(second.len(), 1);
+        let engine = engine.borrow();
+        let order = engine.order(&ClientOrderId::from("O-001")).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Filled);
+    }
+
+    #[rstest]
+    fn test_handle_margin_merges_updates(
+        handler: (BitmexUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, _) = handler;
+        let partial = r#"{"table":"margin","action":"partial","data":[{"account":12345,"currency":"XBt",
+            "walletBalance":100000000,"availableMargin":80000000,"timestamp":"2024-01-01T00:00:00.000Z"}]}"#;
+        let update = r#"{"table":"margin","action":"update","data":[{"account":12345,"currency":"XBt",
+            "availableMargin":70000000,"timestamp":"2024-01-01T00:00:01.000Z"}]}"#;
+
+        handler.handle_message(partial, 1).unwrap();
+        handler.handle_message(update, 2).unwrap();
+
+        assert_eq!(handler.dispatcher().account_state_count, 2);
+        let margin = &handler.margins[&Ustr::from("XBt")];
+        assert_eq!(margin.wallet_balance, Some(100_000_000));
+        assert_eq!(margin.available_margin, Some(70_000_000));
+    }
+
+    #[rstest]
+    fn test_handle_margin_update_before_partial_is_not_dispatched(
+        handler: (BitmexUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, _) = handler;
+        let update = r#"{"table":"margin","action":"update","data":[{"account":12345,"currency":"XBt",
+            "availableMargin":70000000}]}"#;
+
+        handler.handle_message(update, 1).unwrap();
+
+        assert_eq!(handler.dispatcher().account_state_count, 0);
+    }
+
+    #[rstest]
+    fn test_handle_control_messages(
+        handler: (BitmexUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, _) = handler;
+        let info = r#"{"info":"Welcome to the BitMEX Realtime API.","version":"2.0.0",
+            "timestamp":"2024-01-01T00:00:00.000Z","docs":"https://www.bitmex.com/app/wsAPI"}"#;
+        let auth_ok = r#"{"success":true,"request":{"op":"authKeyExpires","args":["key",1704067260,"signature"]}}"#;
+        let subscribed = r#"{"success":true,"subscribe":"execution","request":{"op":"subscribe","args":["execution"]}}"#;
+        let auth_failed = r#"{"status":401,"error":"Signature not valid.","meta":{},
+            "request":{"op":"authKeyExpires","args":["key",1704067260,"signature"]}}"#;
+
+        assert!(handler.handle_message(info, 1).unwrap().is_empty());
+        assert!(handler.handle_message(auth_ok, 1).unwrap().is_empty());
+        assert!(handler.handle_message(subscribed, 1).unwrap().is_empty());
+        assert!(handler.handle_message(PONG_MESSAGE, 1).unwrap().is_empty());
+        assert!(handler.handle_message(auth_failed, 1).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::{Context, Result};
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide},
+    events::account::state::AccountState,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{balance::AccountBalance, money::Money},
+};
+
+use super::types::{
+    BybitExecType, BybitOrderStatus, BybitWsExecution, BybitWsOrder, BybitWsWallet,
+};
+use crate::user_data::{
+    parse_currency, parse_f64, parse_millis, OrderUpdateKind, VenueFill, VenueOrderUpdate,
+};
+
+fn parse_millis_str(value: &str, field: &str) -> Result<UnixNanos> {
+    let millis = value
+        .parse::<u64>()
+        .with_context(|| format!("Invalid `{field}` value '{value}'"))?;
+    Ok(parse_millis(millis))
+}
+
+fn parse_client_order_id(order_link_id: &str) -> Result<Option<ClientOrderId>> {
+    if order_link_id.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(ClientOrderId::new(order_link_id)?))
+}
+
+/// Returns the normalized order update for the given Bybit order, or `None` for statuses
+/// which are reported by other messages (fills are parsed from executions).
+pub fn parse_order(order: &BybitWsOrder) -> Result<Option<VenueOrderUpdate>> {
+    let kind = match order.order_status {
+        BybitOrderStatus::New | BybitOrderStatus::Untriggered => OrderUpdateKind::Accepted,
+        BybitOrderStatus::Rejected => OrderUpdateKind::Rejected(order.reject_reason),
+        BybitOrderStatus::Cancelled
+        | BybitOrderStatus::PartiallyFilledCanceled
+        | BybitOrderStatus::Deactivated => OrderUpdateKind::Canceled,
+        BybitOrderStatus::Created
+        | BybitOrderStatus::PartiallyFilled
+        | BybitOrderStatus::Filled
+        | BybitOrderStatus::Triggered
+        | BybitOrderStatus::Active
+        | BybitOrderStatus::Other => return Ok(None),
+    };
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id: parse_client_order_id(&order.order_link_id)?,
+        venue_order_id: VenueOrderId::new(&order.order_id)?,
+        kind,
+        ts_event: parse_millis_str(&order.updated_time, "updatedTime")?,
+    }))
+}
+
+/// Returns the normalized fill update for the given Bybit execution, or `None` for
+/// executions which are not order fills (such as funding).
+///
+/// Bybit charges fees in the settlement currency, which is not included in the message.
+pub fn parse_execution(execution: &BybitWsExecution) -> Result<Option<VenueOrderUpdate>> {
+    if !matches!(
+        execution.exec_type,
+        BybitExecType::Trade | BybitExecType::AdlTrade | BybitExecType::BustTrade
+    ) {
+        return Ok(None);
+    }
+
+    let fill = VenueFill {
+        trade_id: TradeId::new(&execution.exec_id)?,
+        last_qty: parse_f64(&execution.exec_qty, "execQty")?,
+        last_px: parse_f64(&execution.exec_price, "execPrice")?,
+        liquidity_side: if execution.is_maker {
+            LiquiditySide::Maker
+        } else {
+            LiquiditySide::Taker
+        },
+        commission: Some(parse_f64(&execution.exec_fee, "execFee")?),
+        commission_currency: None,
+    };
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id: parse_client_order_id(&execution.order_link_id)?,
+        venue_order_id: VenueOrderId::new(&execution.order_id)?,
+        kind: OrderUpdateKind::Filled(fill),
+        ts_event: parse_millis_str(&execution.exec_time, "execTime")?,
+    }))
+}
+
+/// Returns the account state for the given Bybit wallet.
+///
+/// The withdrawable balance is free, falling back to the wallet balance less the locked
+/// balance for unified accounts (which do not report it).
+pub fn parse_wallet(
+    wallet: &BybitWsWallet,
+    account_id: AccountId,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Result<AccountState> {
+    let mut balances = Vec::with_capacity(wallet.coin.len());
+    for coin in &wallet.coin {
+        let currency = parse_currency(coin.coin.as_str())?;
+        let wallet_balance = parse_f64(&coin.wallet_balance, "walletBalance")?;
+        let available = if coin.available_to_withdraw.is_empty() {
+            let locked = if coin.locked.is_empty() {
+                0.0
+            } else {
+                parse_f64(&coin.locked, "locked")?
+            };
+            wallet_balance - locked
+        } else {
+            parse_f64(&coin.available_to_withdraw, "availableToWithdraw")?
+        };
+        let total = Money::new(wallet_balance, currency)?;
+        let free = Money::new(available.clamp(0.0, wallet_balance.max(0.0)), currency)?;
+        balances.push(AccountBalance::new(total, total - free, free)?);
+    }
+
+    AccountState::new(
+        account_id,
+        AccountType::Margin,
+        balances,
+        vec![],
+        true,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        None,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::currency::Currency;
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    fn order(order_status: &str, reject_reason: &str) -> BybitWsOrder {
+        serde_json::from_str(&format!(
+            r#"{{"category":"linear","symbol":"ETHUSDT","orderId":"5cf98598-39a7-459e-97bf-76ca765ee020",
+                "side":"Buy","orderType":"Limit","price":"2000.00","qty":"2","timeInForce":"GTC",
+                "orderStatus":"{order_status}","orderLinkId":"O-001","rejectReason":"{reject_reason}",
+                "cumExecQty":"0","createdTime":"1700000000000","updatedTime":"1700000000050"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("New", "EC_NoError", Some(OrderUpdateKind::Accepted))]
+    #[case("Untriggered", "EC_NoError", Some(OrderUpdateKind::Accepted))]
+    #[case(
+        "Rejected",
+        "EC_InvalidPrice",
+        Some(OrderUpdateKind::Rejected(Ustr::from("EC_InvalidPrice")))
+    )]
+    #[case("Cancelled", "EC_NoError", Some(OrderUpdateKind::Canceled))]
+    #[case(
+        "PartiallyFilledCanceled",
+        "EC_NoError",
+        Some(OrderUpdateKind::Canceled)
+    )]
+    #[case("Deactivated", "EC_NoError", Some(OrderUpdateKind::Canceled))]
+    #[case("PartiallyFilled", "EC_NoError", None)]
+    #[case("Filled", "EC_NoError", None)]
+    #[case("SomethingNew", "EC_NoError", None)]
+    fn test_parse_order(
+        #[case] order_status: &str,
+        #[case] reject_reason: &str,
+        #[case] expected: Option<OrderUpdateKind>,
+    ) {
+        let update = parse_order(&order(order_status, reject_reason)).unwrap();
+
+        assert_eq!(update.clone().map(|u| u.kind), expected);
+        if let Some(update) = update {
+            assert_eq!(update.client_order_id, Some(ClientOrderId::from("O-001")));
+            assert_eq!(
+                update.venue_order_id,
+                VenueOrderId::from("5cf98598-39a7-459e-97bf-76ca765ee020")
+            );
+            assert_eq!(update.ts_event, 1_700_000_000_050_000_000);
+        }
+    }
+
+    #[rstest]
+    fn test_parse_order_without_order_link_id() {
+        let mut order = order("New", "EC_NoError");
+        order.order_link_id = Ustr::from("");
+
+        let update = parse_order(&order).unwrap().unwrap();
+
+        assert_eq!(update.client_order_id, None);
+    }
+
+    fn execution(exec_type: &str) -> BybitWsExecution {
+        serde_json::from_str(&format!(
+            r#"{{"category":"linear","symbol":"ETHUSDT","execFee":"0.6","execId":"7e2ae69c-4edf-5800-a352-893d52b446aa",
+                "execPrice":"2000.00","execQty":"1","execType":"{exec_type}","execValue":"2000","isMaker":false,
+                "feeRate":"0.0003","orderId":"5cf98598-39a7-459e-97bf-76ca765ee020","orderLinkId":"O-001",
+                "execTime":"1700000000060"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_execution_trade() {
+        let update = parse_execution(&execution("Trade")).unwrap().unwrap();
+
+        assert_eq!(update.client_order_id, Some(ClientOrderId::from("O-001")));
+        assert_eq!(update.ts_event, 1_700_000_000_060_000_000);
+        assert_eq!(
+            update.kind,
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::from("7e2ae69c-4edf-5800-a352-893d52b446aa"),
+                last_qty: 1.0,
+                last_px: 2000.0,
+                liquidity_side: LiquiditySide::Taker,
+                commission: Some(0.6),
+                commission_currency: None,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("AdlTrade", true)]
+    #[case("BustTrade", true)]
+    #[case("Funding", false)]
+    #[case("Settle", false)]
+    fn test_parse_execution_type(#[case] exec_type: &str, #[case] is_fill: bool) {
+        let update = parse_execution(&execution(exec_type)).unwrap();
+
+        assert_eq!(update.is_some(), is_fill);
+    }
+
+    #[rstest]
+    #[case("", "100.5", 899.5, 100.5)]
+    #[case("950", "0", 950.0, 50.0)]
+    #[case("", "", 1000.0, 0.0)]
+    fn test_parse_wallet(
+        #[case] available_to_withdraw: &str,
+        #[case] locked: &str,
+        #[case] expected_free: f64,
+        #[case] expected_locked: f64,
+    ) {
+        let wallet: BybitWsWallet = serde_json::from_str(&format!(
+            r#"{{"accountType":"UNIFIED","accountIMRate":"0","totalEquity":"1000","coin":[{{
+                "coin":"USDT","equity":"1000","walletBalance":"1000",
+                "availableToWithdraw":"{available_to_withdraw}","locked":"{locked}"}}]}}"#
+        ))
+        .unwrap();
+
+        let state = parse_wallet(&wallet, AccountId::from("BYBIT-001"), 10, 20).unwrap();
+
+        let usdt = Currency::from("USDT");
+        assert_eq!(state.account_id, AccountId::from("BYBIT-001"));
+        assert_eq!(state.balances[0].total, Money::new(1000.0, usdt).unwrap());
+        assert_eq!(
+            state.balances[0].free,
+            Money::new(expected_free, usdt).unwrap()
+        );
+        assert_eq!(
+            state.balances[0].locked,
+            Money::new(expected_locked, usdt).unwrap()
+        );
+        assert_eq!(state.ts_event, 10);
+        assert_eq!(state.ts_init, 20);
+    }
+}
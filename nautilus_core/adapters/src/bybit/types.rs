@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents a Bybit V5 private WebSocket message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BybitWsMessage {
+    Topic(BybitWsTopicMsg),
+    Op(BybitWsOpMsg),
+}
+
+/// Represents a Bybit topic message, with the data parsed according to the topic.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BybitWsTopicMsg {
+    pub topic: Ustr,
+    /// The creation time (UNIX milliseconds).
+    #[serde(rename = "creationTime")]
+    pub creation_time: u64,
+    pub data: serde_json::Value,
+}
+
+/// Represents a Bybit operation response (such as `auth`, `subscribe` or `pong`).
+#[derive(Clone, Debug, Deserialize)]
+pub struct BybitWsOpMsg {
+    pub op: Ustr,
+    pub success: Option<bool>,
+    #[serde(default)]
+    pub ret_msg: String,
+}
+
+/// Represents an order of a Bybit `order` topic message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsOrder {
+    pub category: Ustr,
+    pub symbol: Ustr,
+    pub order_id: Ustr,
+    /// The client order ID (empty if not set).
+    pub order_link_id: Ustr,
+    pub order_status: BybitOrderStatus,
+    /// The reject reason (`EC_NoError` unless rejected).
+    pub reject_reason: Ustr,
+    /// The update time (UNIX milliseconds).
+    pub updated_time: String,
+}
+
+/// The status of a Bybit order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum BybitOrderStatus {
+    Created,
+    New,
+    Rejected,
+    PartiallyFilled,
+    PartiallyFilledCanceled,
+    Filled,
+    Cancelled,
+    /// A conditional order which has not yet been triggered.
+    Untriggered,
+    Triggered,
+    /// A conditional order which was canceled before being triggered.
+    Deactivated,
+    Active,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents an execution of a Bybit `execution` topic message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsExecution {
+    pub category: Ustr,
+    pub symbol: Ustr,
+    pub order_id: Ustr,
+    /// The client order ID (empty if not set).
+    pub order_link_id: Ustr,
+    pub exec_id: Ustr,
+    pub exec_type: BybitExecType,
+    pub exec_qty: String,
+    pub exec_price: String,
+    pub exec_fee: String,
+    pub is_maker: bool,
+    /// The execution time (UNIX milliseconds).
+    pub exec_time: String,
+}
+
+/// The execution type of a Bybit execution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum BybitExecType {
+    Trade,
+    /// An auto-deleveraging (ADL) fill.
+    AdlTrade,
+    Funding,
+    /// A liquidation fill.
+    BustTrade,
+    Delivery,
+    Settle,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents a wallet of a Bybit `wallet` topic message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsWallet {
+    pub account_type: Ustr,
+    pub coin: Vec<BybitWsCoinBalance>,
+}
+
+/// Represents a coin balance of a Bybit wallet.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWsCoinBalance {
+    pub coin: Ustr,
+    pub wallet_balance: String,
+    /// The withdrawable balance (empty for unified accounts).
+    #[serde(default)]
+    pub available_to_withdraw: String,
+    #[serde(default)]
+    pub locked: String,
+}
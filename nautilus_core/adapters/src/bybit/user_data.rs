@@ -0,0 +1,217 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::events::order::event::OrderEvent;
+
+use super::{
+    parsing::{parse_execution, parse_order, parse_wallet},
+    types::{BybitWsExecution, BybitWsMessage, BybitWsOpMsg, BybitWsOrder, BybitWsWallet},
+};
+use crate::user_data::{parse_millis, UserDataDispatcher, UserDataEvent};
+
+/// The interval between heartbeat pings (Bybit closes idle connections after 10 minutes,
+/// and recommends a ping every 20 seconds).
+pub const PING_INTERVAL_SECS: u64 = 20;
+
+/// The heartbeat ping message.
+pub const PING_MESSAGE: &str = r#"{"op":"ping"}"#;
+
+/// Provides handling of Bybit V5 private WebSocket messages.
+///
+/// Messages for the `order`, `execution` and `wallet` topics are dispatched to the
+/// execution engine, fills being taken from executions only so that each is applied once.
+pub struct BybitUserDataHandler {
+    dispatcher: UserDataDispatcher,
+}
+
+impl BybitUserDataHandler {
+    #[must_use]
+    pub fn new(dispatcher: UserDataDispatcher) -> Self {
+        Self { dispatcher }
+    }
+
+    /// Returns the user-data dispatcher for the handler.
+    #[must_use]
+    pub fn dispatcher(&self) -> &UserDataDispatcher {
+        &self.dispatcher
+    }
+
+    /// Handles the given private WebSocket message `text`, returning the order events
+    /// applied by the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message cannot be parsed or dispatched.
+    /// - If authentication or a subscription failed.
+    pub fn handle_message(&mut self, text: &str, ts_init: UnixNanos) -> Result<Vec<OrderEvent>> {
+        let msg: BybitWsMessage = serde_json::from_str(text)
+            .with_context(|| format!("Invalid private WebSocket message {text}"))?;
+
+        let msg = match msg {
+            BybitWsMessage::Topic(msg) => msg,
+            BybitWsMessage::Op(msg) => {
+                Self::handle_op(&msg)?;
+                return Ok(vec![]);
+            }
+        };
+
+        let mut updates = Vec::new();
+        if msg.topic.starts_with("order") {
+            let orders: Vec<BybitWsOrder> = serde_json::from_value(msg.data)?;
+            for order in &orders {
+                updates.extend(parse_order(order)?);
+            }
+        } else if msg.topic.starts_with("execution") {
+            let executions: Vec<BybitWsExecution> = serde_json::from_value(msg.data)?;
+            for execution in &executions {
+                updates.extend(parse_execution(execution)?);
+            }
+        } else if msg.topic.starts_with("wallet") {
+            let wallets: Vec<BybitWsWallet> = serde_json::from_value(msg.data)?;
+            let ts_event = parse_millis(msg.creation_time);
+            for wallet in &wallets {
+                let state = parse_wallet(wallet, self.dispatcher.account_id(), ts_event, ts_init)?;
+                self.dispatcher
+                    .dispatch(UserDataEvent::Account(state), ts_init)?;
+            }
+        } else {
+            log::debug!("Skipping message for topic {}", msg.topic);
+        }
+
+        let mut events = Vec::with_capacity(updates.len());
+        for update in updates {
+            events.extend(
+                self.dispatcher
+                    .dispatch(UserDataEvent::Order(update), ts_init)?,
+            );
+        }
+        Ok(events)
+    }
+
+    fn handle_op(msg: &BybitWsOpMsg) -> Result<()> {
+        if msg.success == Some(false) {
+            bail!("Operation '{}' failed: {}", msg.op, msg.ret_msg);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::msgbus::MessageBus;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::engine::ExecutionEngine;
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus},
+        identifiers::{account_id::AccountId, client_order_id::ClientOrderId, trader_id::TraderId},
+        instruments::stubs::crypto_perpetual_ethusdt,
+        orders::{base::Order, stubs::TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn handler() -> (BybitUserDataHandler, Rc<RefCell<ExecutionEngine>>) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let instrument = crypto_perpetual_ethusdt();
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(2),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        let mut engine = ExecutionEngine::new(msgbus.clone());
+        engine.add_instrument(Box::new(instrument));
+        engine.add_order(order.into()).unwrap();
+        let engine = Rc::new(RefCell::new(engine));
+        let dispatcher =
+            UserDataDispatcher::new(AccountId::from("BYBIT-001"), engine.clone(), msgbus);
+        (BybitUserDataHandler::new(dispatcher), engine)
+    }
+
+    const ORDER_MSG: &str = r#"{"id":"5923240c6880ab-c59f-420b-9adb-3639adc9dd90","topic":"order",
+        "creationTime":1700000000051,"data":[{"category":"linear","symbol":"ETHUSDT",
+        "orderId":"5cf98598-39a7-459e-97bf-76ca765ee020","orderLinkId":"O-001","orderStatus":"New",
+        "rejectReason":"EC_NoError","updatedTime":"1700000000050"}]}"#;
+
+    const EXECUTION_MSG: &str = r#"{"id":"592324803b2785-26fa-4214-9963-bdd4727f07be","topic":"execution",
+        "creationTime":1700000000061,"data":[
+        {"category":"linear","symbol":"ETHUSDT","orderId":"5cf98598-39a7-459e-97bf-76ca765ee020",
+         "orderLinkId":"O-001","execId":"E-1","execType":"Trade","execQty":"1","execPrice":"2000.00",
+         "execFee":"0.6","isMaker":false,"execTime":"1700000000060"},
+        {"category":"linear","symbol":"ETHUSDT","orderId":"5cf98598-39a7-459e-97bf-76ca765ee020",
+         "orderLinkId":"O-001","execId":"E-2","execType":"Trade","execQty":"1","execPrice":"2000.50",
+         "execFee":"0.6","isMaker":false,"execTime":"1700000000060"}]}"#;
+
+    #[rstest]
+    fn test_handle_order_and_executions(
+        handler: (BybitUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, engine) = handler;
+
+        let accepted = handler.handle_message(ORDER_MSG, 1).unwrap();
+        let filled = handler.handle_message(EXECUTION_MSG, 2).unwrap();
+        let replayed = handler.handle_message(EXECUTION_MSG, 3).unwrap();
+
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(filled.len(), 2);
+        assert!(replayed.is_empty());
+        let engine = engine.borrow();
+        let order = engine.order(&ClientOrderId::from("O-001")).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Filled);
+    }
+
+    #[rstest]
+    fn test_handle_wallet(handler: (BybitUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, _) = handler;
+        let json = r#"{"id":"592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e","topic":"wallet",
+            "creationTime":1700000000070,"data":[{"accountType":"UNIFIED","coin":[
+            {"coin":"USDT","walletBalance":"1000","availableToWithdraw":"","locked":"0"}]}]}"#;
+
+        let events = handler.handle_message(json, 1).unwrap();
+
+        assert!(events.is_empty());
+        assert_eq!(handler.dispatcher().account_state_count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_op_messages(handler: (BybitUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, _) = handler;
+        let auth_ok =
+            r#"{"success":true,"ret_msg":"","op":"auth","conn_id":"cejreaspqfh3sjdnldmg-p"}"#;
+        let pong = r#"{"req_id":"","op":"pong","args":["1700000000000"],"conn_id":"cejreaspqfh3sjdnldmg-p"}"#;
+        let auth_failed = r#"{"success":false,"ret_msg":"Params Error","op":"auth","conn_id":"cejreaspqfh3sjdnldmg-p"}"#;
+
+        assert!(handler.handle_message(auth_ok, 1).unwrap().is_empty());
+        assert!(handler.handle_message(pong, 1).unwrap().is_empty());
+        assert!(handler.handle_message(auth_failed, 1).is_err());
+    }
+}
@@ -146,11 +146,11 @@ pub fn parse_cfi_iso10926(value: &str) -> Result<(Option<AssetClass>, Option<Ins
 
 pub fn parse_min_price_increment(value: i64, currency: Currency) -> Result<Price> {
     match value {
-        0 | i64::MAX => Price::new(
+        0 | i64::MAX => Ok(Price::new(
             10f64.powi(-i32::from(currency.precision)),
             currency.precision,
-        ),
-        _ => Price::from_raw(value, currency.precision),
+        )?),
+        _ => Ok(Price::from_raw(value, currency.precision)?),
     }
 }
 
@@ -179,7 +179,7 @@ pub fn parse_equity_v1(
 ) -> Result<Equity> {
     let currency = Currency::USD(); // TODO: Temporary hard coding of US equities for now
 
-    Equity::new(
+    Ok(Equity::new(
         instrument_id,
         instrument_id.symbol,
         None, // No ISIN available yet
@@ -193,7 +193,7 @@ pub fn parse_equity_v1(
         None,           // TBD
         record.ts_recv, // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
 
 pub fn parse_futures_contract_v1(
@@ -206,7 +206,7 @@ pub fn parse_futures_contract_v1(
     let underlying = unsafe { parse_raw_ptr_to_ustr(record.asset.as_ptr())? };
     let (asset_class, _) = parse_cfi_iso10926(&cfi_str)?;
 
-    FuturesContract::new(
+    Ok(FuturesContract::new(
         instrument_id,
         instrument_id.symbol,
         asset_class.unwrap_or(AssetClass::Commodity),
@@ -224,7 +224,7 @@ pub fn parse_futures_contract_v1(
         None,                   // TBD
         record.ts_recv,         // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
 
 pub fn parse_options_contract_v1(
@@ -244,7 +244,7 @@ pub fn parse_options_contract_v1(
     let underlying = unsafe { parse_raw_ptr_to_ustr(record.underlying.as_ptr())? };
     let currency = Currency::from_str(&currency_str)?;
 
-    OptionsContract::new(
+    Ok(OptionsContract::new(
         instrument_id,
         instrument_id.symbol,
         asset_class_opt.unwrap_or(AssetClass::Commodity),
@@ -264,7 +264,7 @@ pub fn parse_options_contract_v1(
         None,                   // TBD
         record.ts_recv,         // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
 
 #[must_use]
@@ -638,7 +638,7 @@ pub fn parse_equity(
 ) -> Result<Equity> {
     let currency = Currency::USD(); // TODO: Temporary hard coding of US equities for now
 
-    Equity::new(
+    Ok(Equity::new(
         instrument_id,
         instrument_id.symbol,
         None, // No ISIN available yet
@@ -652,7 +652,7 @@ pub fn parse_equity(
         None,           // TBD
         record.ts_recv, // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
 
 pub fn parse_futures_contract(
@@ -665,7 +665,7 @@ pub fn parse_futures_contract(
     let underlying = unsafe { parse_raw_ptr_to_ustr(record.asset.as_ptr())? };
     let (asset_class, _) = parse_cfi_iso10926(&cfi_str)?;
 
-    FuturesContract::new(
+    Ok(FuturesContract::new(
         instrument_id,
         instrument_id.symbol,
         asset_class.unwrap_or(AssetClass::Commodity),
@@ -683,7 +683,7 @@ pub fn parse_futures_contract(
         None,                   // TBD
         record.ts_recv,         // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
 
 pub fn parse_options_contract(
@@ -703,7 +703,7 @@ pub fn parse_options_contract(
     let underlying = unsafe { parse_raw_ptr_to_ustr(record.underlying.as_ptr())? };
     let currency = Currency::from_str(&currency_str)?;
 
-    OptionsContract::new(
+    Ok(OptionsContract::new(
         instrument_id,
         instrument_id.symbol,
         asset_class_opt.unwrap_or(AssetClass::Commodity),
@@ -723,5 +723,5 @@ pub fn parse_options_contract(
         None,                   // TBD
         record.ts_recv,         // More accurate and reliable timestamp
         ts_init,
-    )
+    )?)
 }
@@ -0,0 +1,397 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        delta::{OrderBookDelta, F_LAST, F_SNAPSHOT},
+        order::BookOrder,
+        trade::TradeTick,
+        Data,
+    },
+    enums::{AggressorSide, BookAction, OrderSide},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    instruments::Instrument,
+    types::{price::Price, quantity::Quantity},
+};
+use ustr::Ustr;
+
+use super::types::{
+    DydxOrderBookSnapshot, DydxOrderBookUpdate, DydxSide, DydxTrades, DydxWsChannelMsg,
+    DydxWsMessage,
+};
+use crate::user_data::{parse_f64, parse_rfc3339};
+
+/// The order book channel.
+pub const ORDERBOOK_CHANNEL: &str = "v4_orderbook";
+
+/// The trades channel.
+pub const TRADES_CHANNEL: &str = "v4_trades";
+
+#[derive(Copy, Clone, Debug)]
+struct MarketInstrument {
+    instrument_id: InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+}
+
+/// Provides handling of dYdX indexer WebSocket market data messages.
+///
+/// Order book snapshots are parsed into a `CLEAR` followed by an `ADD` per level, and
+/// updates into an `UPDATE` per level (a `DELETE` for a zero size), with the message ID
+/// as the delta sequence. Trades are parsed from updates only, as the subscribed message
+/// carries recent historical trades.
+#[derive(Debug, Default)]
+pub struct DydxMarketDataHandler {
+    instruments: HashMap<Ustr, MarketInstrument>,
+}
+
+impl DydxMarketDataHandler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given `instrument`, keyed by its raw (dYdX ticker) symbol.
+    pub fn add_instrument(&mut self, instrument: &dyn Instrument) {
+        self.instruments.insert(
+            instrument.raw_symbol().value,
+            MarketInstrument {
+                instrument_id: instrument.id(),
+                price_precision: instrument.price_precision(),
+                size_precision: instrument.size_precision(),
+            },
+        );
+    }
+
+    /// Handles the given WebSocket message `text`, returning the parsed data.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message cannot be parsed.
+    /// - If the message is for a ticker which has not been added.
+    /// - If the message is an error.
+    pub fn handle_message(&self, text: &str, ts_init: UnixNanos) -> Result<Vec<Data>> {
+        let msg: DydxWsMessage = serde_json::from_str(text)
+            .with_context(|| format!("Invalid WebSocket message {text}"))?;
+
+        match msg {
+            DydxWsMessage::Subscribed(msg) => self.handle_channel_msg(&msg, true, ts_init),
+            DydxWsMessage::ChannelData(msg) => self.handle_channel_msg(&msg, false, ts_init),
+            DydxWsMessage::ChannelBatchData(batch) => {
+                let mut data = Vec::new();
+                for contents in batch.contents {
+                    let msg = DydxWsChannelMsg {
+                        channel: batch.channel,
+                        id: batch.id,
+                        message_id: batch.message_id,
+                        contents,
+                    };
+                    data.extend(self.handle_channel_msg(&msg, false, ts_init)?);
+                }
+                Ok(data)
+            }
+            DydxWsMessage::Error(msg) => bail!("Request failed: {}", msg.message),
+            DydxWsMessage::Connected | DydxWsMessage::Unsubscribed => Ok(vec![]),
+        }
+    }
+
+    fn handle_channel_msg(
+        &self,
+        msg: &DydxWsChannelMsg,
+        is_snapshot: bool,
+        ts_init: UnixNanos,
+    ) -> Result<Vec<Data>> {
+        let Some(instrument) = self.instruments.get(&msg.id).copied() else {
+            bail!("Instrument for ticker {} not found", msg.id);
+        };
+
+        match msg.channel.as_str() {
+            ORDERBOOK_CHANNEL if is_snapshot => {
+                let snapshot: DydxOrderBookSnapshot = serde_json::from_value(msg.contents.clone())?;
+                let levels = snapshot
+                    .bids
+                    .iter()
+                    .map(|level| (OrderSide::Buy, &level.price, &level.size))
+                    .chain(
+                        snapshot
+                            .asks
+                            .iter()
+                            .map(|level| (OrderSide::Sell, &level.price, &level.size)),
+                    );
+                let mut deltas = vec![OrderBookDelta::clear(
+                    instrument.instrument_id,
+                    msg.message_id,
+                    ts_init,
+                    ts_init,
+                )];
+                for (side, price, size) in levels {
+                    deltas.push(parse_delta(
+                        &instrument,
+                        BookAction::Add,
+                        side,
+                        price,
+                        size,
+                        F_SNAPSHOT,
+                        msg.message_id,
+                        ts_init,
+                    )?);
+                }
+                Ok(finish_deltas(deltas))
+            }
+            ORDERBOOK_CHANNEL => {
+                let update: DydxOrderBookUpdate = serde_json::from_value(msg.contents.clone())?;
+                let levels = update
+                    .bids
+                    .iter()
+                    .map(|(price, size)| (OrderSide::Buy, price, size))
+                    .chain(
+                        update
+                            .asks
+                            .iter()
+                            .map(|(price, size)| (OrderSide::Sell, price, size)),
+                    );
+                let mut deltas = Vec::new();
+                for (side, price, size) in levels {
+                    let action = if parse_f64(size, "size")? == 0.0 {
+                        BookAction::Delete
+                    } else {
+                        BookAction::Update
+                    };
+                    deltas.push(parse_delta(
+                        &instrument,
+                        action,
+                        side,
+                        price,
+                        size,
+                        0,
+                        msg.message_id,
+                        ts_init,
+                    )?);
+                }
+                Ok(finish_deltas(deltas))
+            }
+            TRADES_CHANNEL if is_snapshot => Ok(vec![]),
+            TRADES_CHANNEL => {
+                let trades: DydxTrades = serde_json::from_value(msg.contents.clone())?;
+                let mut ticks = Vec::with_capacity(trades.trades.len());
+                for trade in &trades.trades {
+                    ticks.push(TradeTick::new(
+                        instrument.instrument_id,
+                        Price::new(
+                            parse_f64(&trade.price, "price")?,
+                            instrument.price_precision,
+                        )?,
+                        Quantity::new(parse_f64(&trade.size, "size")?, instrument.size_precision)?,
+                        match trade.side {
+                            DydxSide::Buy => AggressorSide::Buyer,
+                            DydxSide::Sell => AggressorSide::Seller,
+                        },
+                        TradeId::new(&trade.id)?,
+                        parse_rfc3339(&trade.created_at)?,
+                        ts_init,
+                    ));
+                }
+                // The indexer sends the most recent trade first
+                ticks.sort_by_key(|tick| tick.ts_event);
+                Ok(ticks.into_iter().map(Data::Trade).collect())
+            }
+            _ => {
+                log::debug!("Skipping message for channel {}", msg.channel);
+                Ok(vec![])
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_delta(
+    instrument: &MarketInstrument,
+    action: BookAction,
+    side: OrderSide,
+    price: &str,
+    size: &str,
+    flags: u8,
+    sequence: u64,
+    ts_init: UnixNanos,
+) -> Result<OrderBookDelta> {
+    let order = BookOrder::new(
+        side,
+        Price::new(parse_f64(price, "price")?, instrument.price_precision)?,
+        Quantity::new(parse_f64(size, "size")?, instrument.size_precision)?,
+        0,
+    );
+    // The indexer does not timestamp order book messages
+    Ok(OrderBookDelta::new(
+        instrument.instrument_id,
+        action,
+        order,
+        flags,
+        sequence,
+        ts_init,
+        ts_init,
+    ))
+}
+
+fn finish_deltas(mut deltas: Vec<OrderBookDelta>) -> Vec<Data> {
+    if let Some(last) = deltas.last_mut() {
+        last.flags |= F_LAST;
+    }
+    deltas.into_iter().map(Data::Delta).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::BookType, instruments::crypto_perpetual::CryptoPerpetual, orderbook::book::OrderBook,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::dydx::parsing::parse_perpetual_markets;
+
+    #[fixture]
+    fn btcusd() -> CryptoPerpetual {
+        let json = r#"{"markets":{"BTC-USD":{"clobPairId":"0","ticker":"BTC-USD","status":"ACTIVE",
+            "initialMarginFraction":"0.05","maintenanceMarginFraction":"0.03","atomicResolution":-10,
+            "quantumConversionExponent":-9,"tickSize":"1","stepSize":"0.0001",
+            "stepBaseQuantums":1000000,"subticksPerTick":100000}}}"#;
+        parse_perpetual_markets(json, 0).unwrap().remove(0)
+    }
+
+    #[fixture]
+    fn handler(btcusd: CryptoPerpetual) -> DydxMarketDataHandler {
+        let mut handler = DydxMarketDataHandler::new();
+        handler.add_instrument(&btcusd);
+        handler
+    }
+
+    const SNAPSHOT: &str = r#"{"type":"subscribed","connection_id":"c1","message_id":1,
+        "channel":"v4_orderbook","id":"BTC-USD","contents":{
+        "bids":[{"price":"64999","size":"1.5"},{"price":"64998","size":"2"}],
+        "asks":[{"price":"65001","size":"0.5"}]}}"#;
+
+    fn deltas(data: Vec<Data>) -> Vec<OrderBookDelta> {
+        data.into_iter()
+            .map(|d| match d {
+                Data::Delta(delta) => delta,
+                _ => panic!("expected delta"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_handle_orderbook_snapshot(handler: DydxMarketDataHandler) {
+        let deltas = deltas(handler.handle_message(SNAPSHOT, 5).unwrap());
+
+        assert_eq!(deltas.len(), 4);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert_eq!(
+            deltas[0].instrument_id,
+            InstrumentId::from("BTC-USD-PERP.DYDX")
+        );
+        assert!(deltas[1..].iter().all(|d| d.action == BookAction::Add));
+        assert_eq!(deltas[3].order.side, OrderSide::Sell);
+        assert_eq!(deltas[3].order.price, Price::from("65001"));
+        assert_eq!(deltas[3].order.size, Quantity::from("0.5000"));
+        assert_eq!(deltas[3].flags, F_SNAPSHOT | F_LAST);
+        assert!(deltas.iter().all(|d| d.sequence == 1 && d.ts_init == 5));
+    }
+
+    #[rstest]
+    fn test_handle_orderbook_updates_applied_to_book(handler: DydxMarketDataHandler) {
+        let update = r#"{"type":"channel_data","connection_id":"c1","message_id":2,
+            "channel":"v4_orderbook","id":"BTC-USD","version":"1.0.0",
+            "contents":{"bids":[["64999","0"],["64997","3"]],"asks":[["65001","0.75"]]}}"#;
+        let mut book = OrderBook::new(InstrumentId::from("BTC-USD-PERP.DYDX"), BookType::L2_MBP);
+
+        for json in [SNAPSHOT, update] {
+            for delta in deltas(handler.handle_message(json, 5).unwrap()) {
+                book.apply_delta(delta);
+            }
+        }
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("64998")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("65001")));
+        assert_eq!(book.best_ask_size(), Some(Quantity::from("0.7500")));
+    }
+
+    #[rstest]
+    fn test_handle_batched_orderbook_updates(handler: DydxMarketDataHandler) {
+        let batch = r#"{"type":"channel_batch_data","connection_id":"c1","message_id":3,
+            "channel":"v4_orderbook","id":"BTC-USD","version":"1.0.0","contents":[
+            {"bids":[["64999","1"]]},{"asks":[["65002","1"]]}]}"#;
+
+        let deltas = deltas(handler.handle_message(batch, 5).unwrap());
+
+        assert_eq!(deltas.len(), 2);
+        assert!(deltas.iter().all(|d| d.action == BookAction::Update));
+    }
+
+    #[rstest]
+    fn test_handle_trades(handler: DydxMarketDataHandler) {
+        let snapshot = r#"{"type":"subscribed","connection_id":"c1","message_id":1,
+            "channel":"v4_trades","id":"BTC-USD","contents":{"trades":[{"id":"T-0","side":"BUY",
+            "size":"0.1","price":"64000","type":"LIMIT","createdAt":"2024-01-01T00:00:00.000Z",
+            "createdAtHeight":"99"}]}}"#;
+        let update = r#"{"type":"channel_data","connection_id":"c1","message_id":2,
+            "channel":"v4_trades","id":"BTC-USD","version":"2.1.0","contents":{"trades":[
+            {"id":"T-2","side":"SELL","size":"0.2","price":"65000","type":"LIMIT",
+             "createdAt":"2024-01-01T00:00:02.000Z","createdAtHeight":"101"},
+            {"id":"T-1","side":"BUY","size":"0.1","price":"65001","type":"LIMIT",
+             "createdAt":"2024-01-01T00:00:01.000Z","createdAtHeight":"100"}]}}"#;
+
+        let historical = handler.handle_message(snapshot, 5).unwrap();
+        let data = handler.handle_message(update, 5).unwrap();
+
+        assert!(historical.is_empty());
+        let trades: Vec<TradeTick> = data
+            .into_iter()
+            .map(|d| match d {
+                Data::Trade(trade) => trade,
+                _ => panic!("expected trade"),
+            })
+            .collect();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].trade_id, TradeId::from("T-1"));
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[1].trade_id, TradeId::from("T-2"));
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[1].ts_event, 1_704_067_202_000_000_000);
+    }
+
+    #[rstest]
+    fn test_handle_unknown_ticker_returns_error(handler: DydxMarketDataHandler) {
+        let msg = SNAPSHOT.replace("BTC-USD", "ETH-USD");
+
+        assert!(handler.handle_message(&msg, 5).is_err());
+    }
+
+    #[rstest]
+    fn test_handle_control_messages(handler: DydxMarketDataHandler) {
+        let connected = r#"{"type":"connected","connection_id":"c1","message_id":0}"#;
+        let error = r#"{"type":"error","message":"Invalid subscribe message","connection_id":"c1","message_id":1}"#;
+
+        assert!(handler.handle_message(connected, 5).unwrap().is_empty());
+        assert!(handler.handle_message(error, 5).is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! dYdX v4 indexer parsing, for instruments, market data and subaccount (user-data)
+//! updates.
+//!
+//! dYdX v4 settles on-chain, so trading happens per subaccount (an address and
+//! subaccount number) and order sizes and prices are expressed in integer quantums and
+//! subticks for the market's CLOB pair.
+
+pub mod market_data;
+pub mod parsing;
+pub mod types;
+pub mod user_data;
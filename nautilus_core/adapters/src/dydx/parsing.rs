@@ -0,0 +1,479 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide},
+    events::account::state::AccountState,
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        symbol::Symbol, trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
+    },
+    instruments::crypto_perpetual::CryptoPerpetual,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+use super::types::{
+    DydxFill, DydxLiquidity, DydxOrder, DydxOrderStatus, DydxPerpetualMarket,
+    DydxPerpetualMarketsResponse, DydxSubaccount,
+};
+use crate::user_data::{
+    parse_currency, parse_f64, parse_rfc3339, OrderUpdateKind, VenueFill, VenueOrderUpdate,
+};
+
+/// The venue identifier for dYdX.
+pub const DYDX_VENUE: &str = "DYDX";
+
+/// The atomic resolution of the quote (USDC) asset.
+pub const QUOTE_ATOMIC_RESOLUTION: i32 = -6;
+
+fn parse_decimal(value: &str, field: &str) -> Result<Decimal> {
+    Decimal::from_str(value)
+        .map(|d| d.normalize())
+        .with_context(|| format!("Invalid `{field}` value '{value}'"))
+}
+
+fn pow10(exponent: i32) -> Decimal {
+    if exponent < 0 {
+        Decimal::new(1, exponent.unsigned_abs())
+    } else {
+        Decimal::from(10_u64.pow(exponent.unsigned_abs()))
+    }
+}
+
+fn decimal_to_integer(value: Decimal, increment: u64, field: &str) -> Result<u64> {
+    let integer = value
+        .to_u64()
+        .filter(|i| Decimal::from(*i) == value)
+        .with_context(|| format!("Invalid `{field}` {value}"))?;
+    if increment == 0 || integer % increment != 0 {
+        bail!("Invalid `{field}` {integer}, not a multiple of {increment}");
+    }
+    Ok(integer)
+}
+
+fn subticks_exponent(market: &DydxPerpetualMarket) -> i32 {
+    market.quantum_conversion_exponent - market.atomic_resolution + QUOTE_ATOMIC_RESOLUTION
+}
+
+/// Returns the instrument ID for the given dYdX perpetual market `ticker`.
+pub fn parse_instrument_id(ticker: &str) -> Result<InstrumentId> {
+    Ok(InstrumentId::new(
+        Symbol::new(&format!("{ticker}-PERP"))?,
+        Venue::new(DYDX_VENUE)?,
+    ))
+}
+
+/// Returns the account ID for the given dYdX subaccount.
+pub fn parse_account_id(address: &str, subaccount_number: u32) -> Result<AccountId> {
+    Ok(AccountId::new(&format!(
+        "{DYDX_VENUE}-{address}-{subaccount_number}"
+    ))?)
+}
+
+/// Returns the quantity for the given on-chain size in base `quantums` for the `market`.
+pub fn quantums_to_quantity(
+    quantums: u64,
+    market: &DydxPerpetualMarket,
+    size_precision: u8,
+) -> Result<Quantity> {
+    let value = Decimal::from(quantums) * pow10(market.atomic_resolution);
+    Ok(Quantity::new(
+        value.to_f64().context("Invalid quantums")?,
+        size_precision,
+    )?)
+}
+
+/// Returns the on-chain size in base quantums for the given `quantity` for the `market`.
+///
+/// # Errors
+///
+/// This function returns an error if the quantity is not a multiple of the market's
+/// step size.
+pub fn quantity_to_quantums(quantity: Quantity, market: &DydxPerpetualMarket) -> Result<u64> {
+    let quantums = quantity.as_decimal() / pow10(market.atomic_resolution);
+    decimal_to_integer(quantums, market.step_base_quantums, "quantums")
+}
+
+/// Returns the price for the given on-chain `subticks` for the `market`.
+pub fn subticks_to_price(
+    subticks: u64,
+    market: &DydxPerpetualMarket,
+    price_precision: u8,
+) -> Result<Price> {
+    let value = Decimal::from(subticks) * pow10(subticks_exponent(market));
+    Ok(Price::new(
+        value.to_f64().context("Invalid subticks")?,
+        price_precision,
+    )?)
+}
+
+/// Returns the on-chain subticks for the given `price` for the `market`.
+///
+/// # Errors
+///
+/// This function returns an error if the price is not a multiple of the market's tick
+/// size.
+pub fn price_to_subticks(price: Price, market: &DydxPerpetualMarket) -> Result<u64> {
+    let subticks = price.as_decimal() / pow10(subticks_exponent(market));
+    decimal_to_integer(subticks, market.subticks_per_tick, "subticks")
+}
+
+/// Returns the perpetual instrument for the given dYdX perpetual `market`.
+///
+/// Markets are quoted and settled in USDC. Fees depend on the account's fee tier, so are
+/// not included.
+pub fn parse_perpetual_market(
+    market: &DydxPerpetualMarket,
+    ts_init: UnixNanos,
+) -> Result<CryptoPerpetual> {
+    let Some((base, quote)) = market.ticker.split_once('-') else {
+        bail!("Invalid market ticker '{}'", market.ticker);
+    };
+
+    let price_increment = parse_decimal(&market.tick_size, "tickSize")?;
+    let size_increment = parse_decimal(&market.step_size, "stepSize")?;
+    let price_precision = price_increment.scale() as u8;
+    let size_precision = size_increment.scale() as u8;
+    let size_increment = Quantity::new(
+        size_increment.to_f64().context("Invalid `stepSize`")?,
+        size_precision,
+    )?;
+
+    Ok(CryptoPerpetual::new(
+        parse_instrument_id(&market.ticker)?,
+        Symbol::new(&market.ticker)?,
+        parse_currency(base)?,
+        parse_currency(quote)?,
+        Currency::USDC(),
+        false,
+        None,
+        price_precision,
+        size_precision,
+        Price::new(
+            price_increment.to_f64().context("Invalid `tickSize`")?,
+            price_precision,
+        )?,
+        size_increment,
+        Decimal::ZERO,
+        Decimal::ZERO,
+        parse_decimal(&market.initial_margin_fraction, "initialMarginFraction")?,
+        parse_decimal(
+            &market.maintenance_margin_fraction,
+            "maintenanceMarginFraction",
+        )?,
+        None,
+        None,
+        Some(size_increment),
+        None,
+        None,
+        None,
+        None,
+        ts_init,
+        ts_init,
+    )?)
+}
+
+/// Returns the perpetual instruments for the given dYdX `GET /v4/perpetualMarkets`
+/// response `json`, ordered by ticker.
+pub fn parse_perpetual_markets(json: &str, ts_init: UnixNanos) -> Result<Vec<CryptoPerpetual>> {
+    let response: DydxPerpetualMarketsResponse =
+        serde_json::from_str(json).context("Invalid perpetual markets response")?;
+
+    let mut markets: Vec<&DydxPerpetualMarket> = response.markets.values().collect();
+    markets.sort_by(|a, b| a.ticker.as_str().cmp(b.ticker.as_str()));
+
+    markets
+        .into_iter()
+        .map(|market| parse_perpetual_market(market, ts_init))
+        .collect()
+}
+
+/// Returns the normalized order update for the given dYdX order, or `None` for statuses
+/// which are reported by other messages (fills are parsed from fills).
+///
+/// dYdX orders carry the integer client ID assigned when placed, which is resolved to
+/// the client order ID through `client_order_ids`.
+pub fn parse_order(
+    order: &DydxOrder,
+    client_order_ids: &HashMap<u32, ClientOrderId>,
+    ts_init: UnixNanos,
+) -> Result<Option<VenueOrderUpdate>> {
+    let kind = match order.status {
+        DydxOrderStatus::Open
+        | DydxOrderStatus::BestEffortOpened
+        | DydxOrderStatus::Untriggered => OrderUpdateKind::Accepted,
+        DydxOrderStatus::Canceled | DydxOrderStatus::BestEffortCanceled => {
+            OrderUpdateKind::Canceled
+        }
+        DydxOrderStatus::Filled | DydxOrderStatus::Other => return Ok(None),
+    };
+
+    let client_id = order
+        .client_id
+        .parse::<u32>()
+        .with_context(|| format!("Invalid `clientId` value '{}'", order.client_id))?;
+    let ts_event = match &order.updated_at {
+        Some(updated_at) => parse_rfc3339(updated_at)?,
+        None => ts_init,
+    };
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id: client_order_ids.get(&client_id).copied(),
+        venue_order_id: VenueOrderId::new(&order.id)?,
+        kind,
+        ts_event,
+    }))
+}
+
+/// Returns the normalized fill update for the given dYdX fill, or `None` for fills
+/// without an order (such as liquidations of the subaccount).
+///
+/// Fees are charged in USDC.
+pub fn parse_fill(fill: &DydxFill) -> Result<Option<VenueOrderUpdate>> {
+    let Some(order_id) = fill.order_id else {
+        return Ok(None);
+    };
+
+    let venue_fill = VenueFill {
+        trade_id: TradeId::new(&fill.id)?,
+        last_qty: parse_f64(&fill.size, "size")?,
+        last_px: parse_f64(&fill.price, "price")?,
+        liquidity_side: match fill.liquidity {
+            DydxLiquidity::Maker => LiquiditySide::Maker,
+            DydxLiquidity::Taker => LiquiditySide::Taker,
+        },
+        commission: Some(parse_f64(&fill.fee, "fee")?),
+        commission_currency: Some(Currency::USDC()),
+    };
+
+    Ok(Some(VenueOrderUpdate {
+        client_order_id: None,
+        venue_order_id: VenueOrderId::new(&order_id)?,
+        kind: OrderUpdateKind::Filled(venue_fill),
+        ts_event: parse_rfc3339(&fill.created_at)?,
+    }))
+}
+
+/// Returns the account state for the given dYdX subaccount.
+///
+/// The subaccount equity (collateral plus unrealized PnL) is the total, with the free
+/// collateral free and the remainder locked as margin.
+pub fn parse_subaccount(
+    subaccount: &DydxSubaccount,
+    account_id: AccountId,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Result<AccountState> {
+    let currency = Currency::USDC();
+    let equity = parse_f64(&subaccount.equity, "equity")?;
+    let free_collateral = parse_f64(&subaccount.free_collateral, "freeCollateral")?;
+    let total = Money::new(equity, currency)?;
+    let free = Money::new(free_collateral.clamp(0.0, equity.max(0.0)), currency)?;
+
+    AccountState::new(
+        account_id,
+        AccountType::Margin,
+        vec![AccountBalance::new(total, total - free, free)?],
+        vec![],
+        true,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        None,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const MARKETS: &str = r#"{"markets":{
+        "ETH-USD":{"clobPairId":"1","ticker":"ETH-USD","status":"ACTIVE","oraclePrice":"3000.5",
+            "priceChange24H":"0","volume24H":"0","trades24H":0,"nextFundingRate":"0",
+            "initialMarginFraction":"0.05","maintenanceMarginFraction":"0.03","openInterest":"0",
+            "atomicResolution":-9,"quantumConversionExponent":-9,"tickSize":"0.1","stepSize":"0.001",
+            "stepBaseQuantums":1000000,"subticksPerTick":100000,"marketType":"CROSS"},
+        "BTC-USD":{"clobPairId":"0","ticker":"BTC-USD","status":"ACTIVE","oraclePrice":"65000",
+            "priceChange24H":"0","volume24H":"0","trades24H":0,"nextFundingRate":"0",
+            "initialMarginFraction":"0.05","maintenanceMarginFraction":"0.03","openInterest":"0",
+            "atomicResolution":-10,"quantumConversionExponent":-9,"tickSize":"1","stepSize":"0.0001",
+            "stepBaseQuantums":1000000,"subticksPerTick":100000,"marketType":"CROSS"}}}"#;
+
+    #[fixture]
+    fn btcusd() -> DydxPerpetualMarket {
+        let response: DydxPerpetualMarketsResponse = serde_json::from_str(MARKETS).unwrap();
+        response.markets["BTC-USD"].clone()
+    }
+
+    #[rstest]
+    fn test_parse_perpetual_markets() {
+        let perpetuals = parse_perpetual_markets(MARKETS, 1).unwrap();
+
+        assert_eq!(perpetuals.len(), 2);
+        let btc = &perpetuals[0];
+        assert_eq!(btc.id, InstrumentId::from("BTC-USD-PERP.DYDX"));
+        assert_eq!(btc.raw_symbol, Symbol::from("BTC-USD"));
+        assert_eq!(btc.base_currency, Currency::BTC());
+        assert_eq!(btc.quote_currency, Currency::USD());
+        assert_eq!(btc.settlement_currency, Currency::USDC());
+        assert!(!btc.is_inverse);
+        assert_eq!(btc.price_precision, 0);
+        assert_eq!(btc.size_precision, 4);
+        assert_eq!(btc.price_increment, Price::from("1"));
+        assert_eq!(btc.size_increment, Quantity::from("0.0001"));
+        assert_eq!(btc.margin_init, dec!(0.05));
+        assert_eq!(btc.margin_maint, dec!(0.03));
+        assert_eq!(perpetuals[1].id, InstrumentId::from("ETH-USD-PERP.DYDX"));
+        assert_eq!(perpetuals[1].price_precision, 1);
+    }
+
+    #[rstest]
+    fn test_quantums_round_trip(btcusd: DydxPerpetualMarket) {
+        let quantity = quantums_to_quantity(15_000_000, &btcusd, 4).unwrap();
+
+        assert_eq!(quantity, Quantity::from("0.0015"));
+        assert_eq!(quantity_to_quantums(quantity, &btcusd).unwrap(), 15_000_000);
+    }
+
+    #[rstest]
+    fn test_quantity_not_multiple_of_step_returns_error(btcusd: DydxPerpetualMarket) {
+        assert!(quantity_to_quantums(Quantity::from("0.00015"), &btcusd).is_err());
+    }
+
+    #[rstest]
+    fn test_subticks_round_trip(btcusd: DydxPerpetualMarket) {
+        let price = subticks_to_price(6_500_100_000, &btcusd, 0).unwrap();
+
+        assert_eq!(price, Price::from("65001"));
+        assert_eq!(price_to_subticks(price, &btcusd).unwrap(), 6_500_100_000);
+    }
+
+    #[rstest]
+    fn test_price_not_multiple_of_tick_returns_error(btcusd: DydxPerpetualMarket) {
+        assert!(price_to_subticks(Price::from("65001.5"), &btcusd).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_account_id() {
+        let account_id = parse_account_id("dydx1abc", 0).unwrap();
+
+        assert_eq!(account_id, AccountId::from("DYDX-dydx1abc-0"));
+    }
+
+    fn order(status: &str) -> DydxOrder {
+        serde_json::from_str(&format!(
+            r#"{{"id":"3f2c1e9a-1b8c-5f6e-9d2a-4c7b8e9f0a1b","subaccountId":"8586bcf6",
+                "clientId":"1234","clobPairId":"0","side":"BUY","size":"0.01","totalFilled":"0",
+                "price":"65000","type":"LIMIT","status":"{status}","timeInForce":"GTT","reduceOnly":false,
+                "orderFlags":"64","goodTilBlockTime":"2024-01-02T00:00:00.000Z","createdAtHeight":"100",
+                "clientMetadata":"0","ticker":"BTC-USD","updatedAt":"2024-01-01T00:00:00.050Z",
+                "updatedAtHeight":"101"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("OPEN", Some(OrderUpdateKind::Accepted))]
+    #[case("BEST_EFFORT_OPENED", Some(OrderUpdateKind::Accepted))]
+    #[case("UNTRIGGERED", Some(OrderUpdateKind::Accepted))]
+    #[case("CANCELED", Some(OrderUpdateKind::Canceled))]
+    #[case("BEST_EFFORT_CANCELED", Some(OrderUpdateKind::Canceled))]
+    #[case("FILLED", None)]
+    #[case("SOMETHING_NEW", None)]
+    fn test_parse_order(#[case] status: &str, #[case] expected: Option<OrderUpdateKind>) {
+        let client_order_ids = HashMap::from([(1234, ClientOrderId::from("O-001"))]);
+
+        let update = parse_order(&order(status), &client_order_ids, 1).unwrap();
+
+        assert_eq!(update.clone().map(|u| u.kind), expected);
+        if let Some(update) = update {
+            assert_eq!(update.client_order_id, Some(ClientOrderId::from("O-001")));
+            assert_eq!(
+                update.venue_order_id,
+                VenueOrderId::from("3f2c1e9a-1b8c-5f6e-9d2a-4c7b8e9f0a1b")
+            );
+            assert_eq!(update.ts_event, 1_704_067_200_050_000_000);
+        }
+    }
+
+    #[rstest]
+    fn test_parse_order_with_unregistered_client_id() {
+        let update = parse_order(&order("OPEN"), &HashMap::new(), 1)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(update.client_order_id, None);
+    }
+
+    #[rstest]
+    fn test_parse_fill() {
+        let fill: DydxFill = serde_json::from_str(
+            r#"{"id":"a8d2b7c4-6f0e-5d3a-9b1c-2e4f6a8b0c1d","subaccountId":"8586bcf6","side":"BUY",
+                "liquidity":"MAKER","type":"LIMIT","clobPairId":"0",
+                "orderId":"3f2c1e9a-1b8c-5f6e-9d2a-4c7b8e9f0a1b","size":"0.01","price":"65000",
+                "quoteAmount":"650","eventId":"0001","transactionHash":"ABC","createdAt":"2024-01-01T00:00:00.060Z",
+                "createdAtHeight":"102","ticker":"BTC-USD","fee":"-0.0715"}"#,
+        )
+        .unwrap();
+
+        let update = parse_fill(&fill).unwrap().unwrap();
+
+        assert_eq!(update.ts_event, 1_704_067_200_060_000_000);
+        assert_eq!(
+            update.kind,
+            OrderUpdateKind::Filled(VenueFill {
+                trade_id: TradeId::from("a8d2b7c4-6f0e-5d3a-9b1c-2e4f6a8b0c1d"),
+                last_qty: 0.01,
+                last_px: 65000.0,
+                liquidity_side: LiquiditySide::Maker,
+                commission: Some(-0.0715),
+                commission_currency: Some(Currency::USDC()),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_parse_subaccount() {
+        let subaccount: DydxSubaccount = serde_json::from_str(
+            r#"{"address":"dydx1abc","subaccountNumber":0,"equity":"1000.5","freeCollateral":"800.25",
+                "openPerpetualPositions":{},"assetPositions":{},"marginEnabled":true}"#,
+        )
+        .unwrap();
+        let account_id =
+            parse_account_id(&subaccount.address, subaccount.subaccount_number).unwrap();
+
+        let state = parse_subaccount(&subaccount, account_id, 10, 20).unwrap();
+
+        let usdc = Currency::USDC();
+        assert_eq!(state.account_id, AccountId::from("DYDX-dydx1abc-0"));
+        assert_eq!(state.balances[0].total, Money::new(1000.5, usdc).unwrap());
+        assert_eq!(state.balances[0].free, Money::new(800.25, usdc).unwrap());
+        assert_eq!(state.balances[0].locked, Money::new(200.25, usdc).unwrap());
+        assert_eq!(state.ts_event, 10);
+    }
+}
@@ -0,0 +1,236 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use ustr::Ustr;
+
+/// Represents the dYdX indexer REST `GET /v4/perpetualMarkets` response.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxPerpetualMarketsResponse {
+    pub markets: HashMap<String, DydxPerpetualMarket>,
+}
+
+/// Represents a dYdX perpetual market.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxPerpetualMarket {
+    pub ticker: Ustr,
+    /// The ID of the market's CLOB pair, as used by on-chain orders.
+    pub clob_pair_id: String,
+    pub status: DydxMarketStatus,
+    pub tick_size: String,
+    pub step_size: String,
+    pub initial_margin_fraction: String,
+    pub maintenance_margin_fraction: String,
+    /// The exponent converting base quantums to a size (size = quantums * 10^exponent).
+    pub atomic_resolution: i32,
+    /// The exponent used, with the atomic resolution, to convert subticks to a price.
+    pub quantum_conversion_exponent: i32,
+    pub step_base_quantums: u64,
+    pub subticks_per_tick: u64,
+}
+
+/// The status of a dYdX perpetual market.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DydxMarketStatus {
+    Active,
+    Paused,
+    CancelOnly,
+    PostOnly,
+    Initializing,
+    FinalSettlement,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents a dYdX indexer WebSocket message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DydxWsMessage {
+    Connected,
+    /// The initial state of a channel, sent on subscription.
+    Subscribed(DydxWsChannelMsg),
+    ChannelData(DydxWsChannelMsg),
+    /// The batched updates of a channel subscribed with `batched`.
+    ChannelBatchData(DydxWsChannelBatchMsg),
+    Unsubscribed,
+    Error(DydxWsErrorMsg),
+}
+
+/// Represents a dYdX channel message, with the contents parsed according to the channel.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxWsChannelMsg {
+    pub channel: Ustr,
+    /// The channel ID (the ticker for market channels, or `address/number` for
+    /// subaccount channels).
+    pub id: Ustr,
+    pub message_id: u64,
+    pub contents: serde_json::Value,
+}
+
+/// Represents a dYdX batched channel message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxWsChannelBatchMsg {
+    pub channel: Ustr,
+    pub id: Ustr,
+    pub message_id: u64,
+    pub contents: Vec<serde_json::Value>,
+}
+
+/// Represents a dYdX error message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxWsErrorMsg {
+    pub message: String,
+}
+
+/// Represents a price level of a dYdX `v4_orderbook` snapshot.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxBookLevel {
+    pub price: String,
+    pub size: String,
+}
+
+/// Represents the contents of a dYdX `v4_orderbook` subscribed (snapshot) message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxOrderBookSnapshot {
+    #[serde(default)]
+    pub bids: Vec<DydxBookLevel>,
+    #[serde(default)]
+    pub asks: Vec<DydxBookLevel>,
+}
+
+/// Represents the contents of a dYdX `v4_orderbook` update message, with levels as
+/// `[price, size]` pairs (a zero size removes the level).
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxOrderBookUpdate {
+    #[serde(default)]
+    pub bids: Vec<(String, String)>,
+    #[serde(default)]
+    pub asks: Vec<(String, String)>,
+}
+
+/// Represents the contents of a dYdX `v4_trades` message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxTrades {
+    pub trades: Vec<DydxTrade>,
+}
+
+/// Represents a trade of a dYdX `v4_trades` message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxTrade {
+    pub id: Ustr,
+    /// The taker (aggressor) side.
+    pub side: DydxSide,
+    pub size: String,
+    pub price: String,
+    /// The creation time (ISO 8601).
+    pub created_at: String,
+}
+
+/// The side of a dYdX order, fill or trade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DydxSide {
+    Buy,
+    Sell,
+}
+
+/// Represents a dYdX subaccount, from the REST
+/// `GET /v4/addresses/{address}/subaccountNumber/{number}` endpoint or the
+/// `v4_subaccounts` subscribed message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxSubaccount {
+    pub address: Ustr,
+    pub subaccount_number: u32,
+    pub equity: String,
+    pub free_collateral: String,
+}
+
+/// Represents the contents of a dYdX `v4_subaccounts` subscribed message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxSubaccountSnapshot {
+    pub subaccount: DydxSubaccount,
+    #[serde(default)]
+    pub orders: Vec<DydxOrder>,
+}
+
+/// Represents the contents of a dYdX `v4_subaccounts` update message.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DydxSubaccountUpdate {
+    #[serde(default)]
+    pub orders: Vec<DydxOrder>,
+    #[serde(default)]
+    pub fills: Vec<DydxFill>,
+}
+
+/// Represents an order of a dYdX `v4_subaccounts` message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxOrder {
+    pub id: Ustr,
+    /// The client ID (an unsigned 32-bit integer) assigned when the order was placed.
+    pub client_id: String,
+    pub ticker: Ustr,
+    pub status: DydxOrderStatus,
+    /// The update time (ISO 8601, not included for best-effort statuses).
+    pub updated_at: Option<String>,
+}
+
+/// The status of a dYdX order.
+///
+/// The `BEST_EFFORT_` statuses are reported optimistically for short-term orders, which
+/// are only held in the memory of validators until they are filled or expire.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DydxOrderStatus {
+    Open,
+    Filled,
+    Canceled,
+    BestEffortOpened,
+    BestEffortCanceled,
+    /// A conditional order which has not yet been triggered.
+    Untriggered,
+    #[serde(other)]
+    Other,
+}
+
+/// Represents a fill of a dYdX `v4_subaccounts` message.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DydxFill {
+    pub id: Ustr,
+    pub order_id: Option<Ustr>,
+    pub ticker: Ustr,
+    pub liquidity: DydxLiquidity,
+    pub size: String,
+    pub price: String,
+    /// The fee in USDC (negative for rebates).
+    pub fee: String,
+    /// The creation time (ISO 8601).
+    pub created_at: String,
+}
+
+/// The liquidity side of a dYdX fill.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DydxLiquidity {
+    Maker,
+    Taker,
+}
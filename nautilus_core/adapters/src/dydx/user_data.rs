@@ -0,0 +1,261 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    events::order::event::OrderEvent, identifiers::client_order_id::ClientOrderId,
+};
+
+use super::{
+    parsing::{parse_fill, parse_order, parse_subaccount},
+    types::{DydxSubaccountSnapshot, DydxSubaccountUpdate, DydxWsMessage},
+};
+use crate::user_data::{UserDataDispatcher, UserDataEvent, VenueOrderUpdate};
+
+/// The subaccounts channel.
+pub const SUBACCOUNTS_CHANNEL: &str = "v4_subaccounts";
+
+/// Provides handling of dYdX indexer `v4_subaccounts` WebSocket messages.
+///
+/// The subscribed message carries the subaccount (dispatched as an account state) and its
+/// open orders, and updates carry orders and fills. Fills are taken from fills only so
+/// that each is applied once.
+///
+/// dYdX orders are identified by an integer client ID, so the execution client must
+/// register the client ID assigned to each order it places with
+/// [`DydxUserDataHandler::register_client_id`].
+pub struct DydxUserDataHandler {
+    dispatcher: UserDataDispatcher,
+    client_order_ids: HashMap<u32, ClientOrderId>,
+}
+
+impl DydxUserDataHandler {
+    #[must_use]
+    pub fn new(dispatcher: UserDataDispatcher) -> Self {
+        Self {
+            dispatcher,
+            client_order_ids: HashMap::new(),
+        }
+    }
+
+    /// Returns the user-data dispatcher for the handler.
+    #[must_use]
+    pub fn dispatcher(&self) -> &UserDataDispatcher {
+        &self.dispatcher
+    }
+
+    /// Registers the dYdX `client_id` assigned to the order with the given
+    /// `client_order_id`.
+    pub fn register_client_id(&mut self, client_id: u32, client_order_id: ClientOrderId) {
+        self.client_order_ids.insert(client_id, client_order_id);
+    }
+
+    /// Handles the given WebSocket message `text`, returning the order events applied by
+    /// the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message cannot be parsed or dispatched.
+    /// - If the message is an error.
+    pub fn handle_message(&mut self, text: &str, ts_init: UnixNanos) -> Result<Vec<OrderEvent>> {
+        let msg: DydxWsMessage = serde_json::from_str(text)
+            .with_context(|| format!("Invalid WebSocket message {text}"))?;
+
+        let mut updates = Vec::new();
+        match msg {
+            DydxWsMessage::Subscribed(msg) if msg.channel.as_str() == SUBACCOUNTS_CHANNEL => {
+                let snapshot: DydxSubaccountSnapshot = serde_json::from_value(msg.contents)?;
+                let state = parse_subaccount(
+                    &snapshot.subaccount,
+                    self.dispatcher.account_id(),
+                    ts_init,
+                    ts_init,
+                )?;
+                self.dispatcher
+                    .dispatch(UserDataEvent::Account(state), ts_init)?;
+                let update = DydxSubaccountUpdate {
+                    orders: snapshot.orders,
+                    fills: vec![],
+                };
+                self.parse_update(&update, ts_init, &mut updates)?;
+            }
+            DydxWsMessage::ChannelData(msg) if msg.channel.as_str() == SUBACCOUNTS_CHANNEL => {
+                let update: DydxSubaccountUpdate = serde_json::from_value(msg.contents)?;
+                self.parse_update(&update, ts_init, &mut updates)?;
+            }
+            DydxWsMessage::ChannelBatchData(batch)
+                if batch.channel.as_str() == SUBACCOUNTS_CHANNEL =>
+            {
+                for contents in batch.contents {
+                    let update: DydxSubaccountUpdate = serde_json::from_value(contents)?;
+                    self.parse_update(&update, ts_init, &mut updates)?;
+                }
+            }
+            DydxWsMessage::Error(msg) => bail!("Request failed: {}", msg.message),
+            _ => log::debug!("Skipping message {text}"),
+        }
+
+        let mut events = Vec::with_capacity(updates.len());
+        for update in updates {
+            events.extend(
+                self.dispatcher
+                    .dispatch(UserDataEvent::Order(update), ts_init)?,
+            );
+        }
+        Ok(events)
+    }
+
+    fn parse_update(
+        &self,
+        update: &DydxSubaccountUpdate,
+        ts_init: UnixNanos,
+        updates: &mut Vec<VenueOrderUpdate>,
+    ) -> Result<()> {
+        for order in &update.orders {
+            updates.extend(parse_order(order, &self.client_order_ids, ts_init)?);
+        }
+        for fill in &update.fills {
+            updates.extend(parse_fill(fill)?);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use nautilus_common::msgbus::MessageBus;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_execution::engine::ExecutionEngine;
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus},
+        identifiers::trader_id::TraderId,
+        orders::{base::Order, stubs::TestOrderStubs},
+        types::quantity::Quantity,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::dydx::parsing::{parse_account_id, parse_perpetual_markets};
+
+    #[fixture]
+    fn handler() -> (DydxUserDataHandler, Rc<RefCell<ExecutionEngine>>) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let json = r#"{"markets":{"BTC-USD":{"clobPairId":"0","ticker":"BTC-USD","status":"ACTIVE",
+            "initialMarginFraction":"0.05","maintenanceMarginFraction":"0.03","atomicResolution":-10,
+            "quantumConversionExponent":-9,"tickSize":"1","stepSize":"0.0001",
+            "stepBaseQuantums":1000000,"subticksPerTick":100000}}}"#;
+        let instrument = parse_perpetual_markets(json, 0).unwrap().remove(0);
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from("0.0200"),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        let mut engine = ExecutionEngine::new(msgbus.clone());
+        engine.add_instrument(Box::new(instrument));
+        engine.add_order(order.into()).unwrap();
+        let engine = Rc::new(RefCell::new(engine));
+        let dispatcher = UserDataDispatcher::new(
+            parse_account_id("dydx1abc", 0).unwrap(),
+            engine.clone(),
+            msgbus,
+        );
+        let mut handler = DydxUserDataHandler::new(dispatcher);
+        handler.register_client_id(1234, ClientOrderId::from("O-001"));
+        (handler, engine)
+    }
+
+    const ORDER: &str = r#"{"id":"3f2c1e9a-1b8c-5f6e-9d2a-4c7b8e9f0a1b","subaccountId":"8586bcf6",
+        "clientId":"1234","clobPairId":"0","side":"BUY","size":"0.02","totalFilled":"0","price":"65000",
+        "type":"LIMIT","status":"OPEN","timeInForce":"GTT","ticker":"BTC-USD",
+        "updatedAt":"2024-01-01T00:00:00.050Z"}"#;
+
+    fn fill(id: &str) -> String {
+        format!(
+            r#"{{"id":"{id}","subaccountId":"8586bcf6","side":"BUY","liquidity":"TAKER","type":"LIMIT",
+                "clobPairId":"0","orderId":"3f2c1e9a-1b8c-5f6e-9d2a-4c7b8e9f0a1b","size":"0.01",
+                "price":"65000","quoteAmount":"650","createdAt":"2024-01-01T00:00:00.060Z",
+                "ticker":"BTC-USD","fee":"0.325"}}"#
+        )
+    }
+
+    #[rstest]
+    fn test_handle_subscribed_and_fills(
+        handler: (DydxUserDataHandler, Rc<RefCell<ExecutionEngine>>),
+    ) {
+        let (mut handler, engine) = handler;
+        let subscribed = format!(
+            r#"{{"type":"subscribed","connection_id":"c1","message_id":1,"channel":"v4_subaccounts",
+                "id":"dydx1abc/0","contents":{{"subaccount":{{"address":"dydx1abc","subaccountNumber":0,
+                "equity":"1000","freeCollateral":"900"}},"orders":[{ORDER}]}}}}"#
+        );
+        let fills = format!(
+            r#"{{"type":"channel_data","connection_id":"c1","message_id":2,"channel":"v4_subaccounts",
+                "id":"dydx1abc/0","version":"2.4.0","contents":{{"fills":[{},{}]}}}}"#,
+            fill("F-1"),
+            fill("F-2")
+        );
+
+        let accepted = handler.handle_message(&subscribed, 1).unwrap();
+        let filled = handler.handle_message(&fills, 2).unwrap();
+        let replayed = handler.handle_message(&fills, 3).unwrap();
+
+        assert_eq!(accepted.len(), 1);
+        assert_eq!(filled.len(), 2);
+        assert!(replayed.is_empty());
+        assert_eq!(handler.dispatcher().account_state_count, 1);
+        let engine = engine.borrow();
+        let order = engine.order(&ClientOrderId::from("O-001")).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Filled);
+    }
+
+    #[rstest]
+    fn test_handle_batched_updates(handler: (DydxUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, _) = handler;
+        let batch = format!(
+            r#"{{"type":"channel_batch_data","connection_id":"c1","message_id":2,
+                "channel":"v4_subaccounts","id":"dydx1abc/0","version":"2.4.0",
+                "contents":[{{"orders":[{ORDER}]}},{{"fills":[{}]}}]}}"#,
+            fill("F-1")
+        );
+
+        let events = handler.handle_message(&batch, 1).unwrap();
+
+        assert_eq!(events.len(), 2);
+    }
+
+    #[rstest]
+    fn test_handle_error(handler: (DydxUserDataHandler, Rc<RefCell<ExecutionEngine>>)) {
+        let (mut handler, _) = handler;
+        let error = r#"{"type":"error","message":"Invalid subaccount","connection_id":"c1","message_id":1}"#;
+
+        assert!(handler.handle_message(error, 1).is_err());
+    }
+}
@@ -13,5 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod binance;
+pub mod bitmex;
+pub mod bybit;
 #[cfg(feature = "databento")]
 pub mod databento;
+pub mod dydx;
+pub mod user_data;
@@ -0,0 +1,614 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Normalized venue user-data stream (private WebSocket) events, and their dispatch to
+//! the execution engine.
+//!
+//! Venue parsers convert their order and account messages into [`UserDataEvent`]s. The
+//! [`UserDataDispatcher`] then resolves each order update against the orders held by the
+//! [`ExecutionEngine`] to build the Nautilus order event, which the engine applies and
+//! publishes. Account states are published directly on the message bus.
+
+use std::{cell::RefCell, rc::Rc, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use chrono::DateTime;
+use nautilus_common::msgbus::MessageBus;
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, time::UnixNanos, uuid::UUID4};
+use nautilus_execution::{engine::ExecutionEngine, messages::account_state_topic};
+use nautilus_model::{
+    enums::{CurrencyType, LiquiditySide, OrderStatus},
+    events::{
+        account::state::AccountState,
+        order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEvent,
+            expired::OrderExpired, filled::OrderFilled, rejected::OrderRejected,
+        },
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, trade_id::TradeId,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orders::{any::OrderAny, base::Order},
+    types::{currency::Currency, money::Money},
+};
+use ustr::Ustr;
+
+/// The precision for currencies first seen in venue messages (such as commission assets).
+const UNKNOWN_CURRENCY_PRECISION: u8 = 8;
+
+/// Represents a fill reported by a venue user-data stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VenueFill {
+    pub trade_id: TradeId,
+    pub last_qty: f64,
+    pub last_px: f64,
+    pub liquidity_side: LiquiditySide,
+    /// The commission amount (if reported).
+    pub commission: Option<f64>,
+    /// The commission currency, if not the instrument's settlement currency.
+    pub commission_currency: Option<Currency>,
+}
+
+/// The kind of order update reported by a venue user-data stream.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrderUpdateKind {
+    Accepted,
+    Rejected(Ustr),
+    Filled(VenueFill),
+    Canceled,
+    Expired,
+}
+
+/// Represents an order update reported by a venue user-data stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VenueOrderUpdate {
+    /// The client order ID echoed by the venue (if any).
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub kind: OrderUpdateKind,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents an event parsed from a venue user-data stream.
+#[derive(Clone, Debug)]
+pub enum UserDataEvent {
+    Order(VenueOrderUpdate),
+    Account(AccountState),
+}
+
+/// Returns the currency for the given venue asset `code`, creating a crypto currency
+/// if the code is not already known.
+pub fn parse_currency(code: &str) -> Result<Currency> {
+    match Currency::from_str(code) {
+        Ok(currency) => Ok(currency),
+        Err(_) => Ok(Currency::new(
+            code,
+            UNKNOWN_CURRENCY_PRECISION,
+            0,
+            code,
+            CurrencyType::Crypto,
+        )?),
+    }
+}
+
+/// Returns the UNIX nanoseconds for the given venue UNIX milliseconds.
+#[must_use]
+pub fn parse_millis(millis: u64) -> UnixNanos {
+    millis * NANOSECONDS_IN_MILLISECOND
+}
+
+/// Returns the UNIX nanoseconds for the given venue RFC 3339 (ISO 8601) timestamp.
+pub fn parse_rfc3339(value: &str) -> Result<UnixNanos> {
+    let dt = DateTime::parse_from_rfc3339(value)
+        .with_context(|| format!("Invalid timestamp '{value}'"))?;
+    let nanos = dt
+        .timestamp_nanos_opt()
+        .with_context(|| format!("Timestamp '{value}' out of range"))?;
+    u64::try_from(nanos).with_context(|| format!("Timestamp '{value}' before UNIX epoch"))
+}
+
+/// Returns the `f64` for the given venue decimal string `value` of `field`.
+pub fn parse_f64(value: &str, field: &str) -> Result<f64> {
+    value
+        .parse::<f64>()
+        .with_context(|| format!("Invalid `{field}` value '{value}'"))
+}
+
+/// Returns the order event for the given venue `update` to the cached `order`, or `None`
+/// if the update has already been applied (such as a duplicate fill or an acceptance of
+/// an order which is already open).
+pub fn order_event_from_update(
+    update: &VenueOrderUpdate,
+    order: &OrderAny,
+    instrument: &dyn Instrument,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> Result<Option<OrderEvent>> {
+    let order = order.as_order();
+    let status = order.status();
+    let venue_order_id = Some(update.venue_order_id);
+
+    let event = match &update.kind {
+        OrderUpdateKind::Accepted => {
+            if !matches!(status, OrderStatus::Initialized | OrderStatus::Submitted) {
+                return Ok(None);
+            }
+            OrderEvent::OrderAccepted(OrderAccepted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                update.venue_order_id,
+                account_id,
+                UUID4::new(),
+                update.ts_event,
+                ts_init,
+                false,
+            )?)
+        }
+        OrderUpdateKind::Rejected(reason) => {
+            if !matches!(status, OrderStatus::Initialized | OrderStatus::Submitted) {
+                return Ok(None);
+            }
+            OrderEvent::OrderRejected(OrderRejected::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                account_id,
+                *reason,
+                UUID4::new(),
+                update.ts_event,
+                ts_init,
+                false,
+            )?)
+        }
+        OrderUpdateKind::Filled(fill) => {
+            if order.trade_ids().contains(&&fill.trade_id) {
+                return Ok(None);
+            }
+            let commission = match fill.commission {
+                Some(amount) => {
+                    let currency = fill
+                        .commission_currency
+                        .unwrap_or_else(|| instrument.settlement_currency());
+                    Some(Money::new(amount, currency)?)
+                }
+                None => None,
+            };
+            OrderEvent::OrderFilled(OrderFilled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                update.venue_order_id,
+                account_id,
+                fill.trade_id,
+                order.side(),
+                order.order_type(),
+                instrument.make_qty(fill.last_qty)?,
+                instrument.make_price(fill.last_px)?,
+                instrument.quote_currency(),
+                fill.liquidity_side,
+                UUID4::new(),
+                update.ts_event,
+                ts_init,
+                false,
+                None,
+                commission,
+            )?)
+        }
+        OrderUpdateKind::Canceled => {
+            if order.is_closed() {
+                return Ok(None);
+            }
+            OrderEvent::OrderCanceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                update.ts_event,
+                ts_init,
+                false,
+                venue_order_id,
+                Some(account_id),
+            )?)
+        }
+        OrderUpdateKind::Expired => {
+            if order.is_closed() {
+                return Ok(None);
+            }
+            OrderEvent::OrderExpired(OrderExpired::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                update.ts_event,
+                ts_init,
+                false,
+                venue_order_id,
+                Some(account_id),
+            )?)
+        }
+    };
+
+    Ok(Some(event))
+}
+
+/// Provides dispatch of venue user-data stream events to the execution engine.
+pub struct UserDataDispatcher {
+    account_id: AccountId,
+    engine: Rc<RefCell<ExecutionEngine>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    pub order_event_count: u64,
+    pub account_state_count: u64,
+}
+
+impl UserDataDispatcher {
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        Self {
+            account_id,
+            engine,
+            msgbus,
+            order_event_count: 0,
+            account_state_count: 0,
+        }
+    }
+
+    /// Returns the account ID for the dispatcher.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Dispatches the given `event`, returning the order event applied by the execution
+    /// engine (if any).
+    ///
+    /// Updates for orders which are not held by the engine (external orders) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument for an updated order has not been added to the engine.
+    /// - If the order event cannot be built from the update, or is rejected by the engine.
+    pub fn dispatch(
+        &mut self,
+        event: UserDataEvent,
+        ts_init: UnixNanos,
+    ) -> Result<Option<OrderEvent>> {
+        match event {
+            UserDataEvent::Order(update) => self.dispatch_order_update(&update, ts_init),
+            UserDataEvent::Account(state) => {
+                let topic = account_state_topic(&state.account_id);
+                self.msgbus.borrow_mut().publish(&topic, &state);
+                self.account_state_count += 1;
+                Ok(None)
+            }
+        }
+    }
+
+    fn dispatch_order_update(
+        &mut self,
+        update: &VenueOrderUpdate,
+        ts_init: UnixNanos,
+    ) -> Result<Option<OrderEvent>> {
+        let mut engine = self.engine.borrow_mut();
+
+        let client_order_id = update
+            .client_order_id
+            .filter(|client_order_id| engine.order(client_order_id).is_some())
+            .or_else(|| engine.client_order_id(&update.venue_order_id));
+        let Some(client_order_id) = client_order_id else {
+            log::debug!(
+                "Skipping update for external order {}",
+                update.venue_order_id
+            );
+            return Ok(None);
+        };
+
+        let event = {
+            let order = engine
+                .order(&client_order_id)
+                .expect("order for resolved client order ID");
+            let instrument_id = order.as_order().instrument_id();
+            let Some(instrument) = engine.instrument(&instrument_id) else {
+                bail!("Cannot apply update to order {client_order_id}: instrument {instrument_id} not found");
+            };
+            order_event_from_update(update, order, instrument, self.account_id, ts_init)?
+        };
+
+        if let Some(event) = &event {
+            engine.process(event)?;
+            self.order_event_count += 1;
+        }
+        Ok(event)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::testing::recording_handler;
+    use nautilus_model::{
+        enums::{AccountType, OrderSide},
+        identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+        instruments::{crypto_perpetual::CryptoPerpetual, stubs::crypto_perpetual_ethusdt},
+        orders::stubs::TestOrderStubs,
+        types::{balance::AccountBalance, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const ACCOUNT_ID: &str = "BINANCE-001";
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    #[fixture]
+    fn engine(
+        msgbus: Rc<RefCell<MessageBus>>,
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) -> Rc<RefCell<ExecutionEngine>> {
+        let mut engine = ExecutionEngine::new(msgbus);
+        let order = TestOrderStubs::market_order(
+            crypto_perpetual_ethusdt.id,
+            OrderSide::Buy,
+            Quantity::from(2),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        engine.add_instrument(Box::new(crypto_perpetual_ethusdt));
+        engine.add_order(order.into()).unwrap();
+        Rc::new(RefCell::new(engine))
+    }
+
+    fn dispatcher(
+        engine: &Rc<RefCell<ExecutionEngine>>,
+        msgbus: &Rc<RefCell<MessageBus>>,
+    ) -> UserDataDispatcher {
+        UserDataDispatcher::new(AccountId::from(ACCOUNT_ID), engine.clone(), msgbus.clone())
+    }
+
+    fn update(client_order_id: Option<&str>, kind: OrderUpdateKind) -> UserDataEvent {
+        UserDataEvent::Order(VenueOrderUpdate {
+            client_order_id: client_order_id.map(ClientOrderId::from),
+            venue_order_id: VenueOrderId::from("8886774"),
+            kind,
+            ts_event: 1,
+        })
+    }
+
+    fn fill(trade_id: &str, last_qty: f64) -> OrderUpdateKind {
+        OrderUpdateKind::Filled(VenueFill {
+            trade_id: TradeId::from(trade_id),
+            last_qty,
+            last_px: 2000.0,
+            liquidity_side: LiquiditySide::Taker,
+            commission: Some(0.8),
+            commission_currency: None,
+        })
+    }
+
+    fn status(engine: &Rc<RefCell<ExecutionEngine>>) -> OrderStatus {
+        engine
+            .borrow()
+            .order(&ClientOrderId::from("O-001"))
+            .unwrap()
+            .as_order()
+            .status()
+    }
+
+    #[rstest]
+    fn test_parse_rfc3339() {
+        assert_eq!(
+            parse_rfc3339("2024-01-01T00:00:00.123Z").unwrap(),
+            1_704_067_200_123_000_000
+        );
+        assert!(parse_rfc3339("2024-01-01").is_err());
+    }
+
+    #[rstest]
+    fn test_parse_currency_unknown_code() {
+        let currency = parse_currency("NEWCOIN").unwrap();
+
+        assert_eq!(currency.code.as_str(), "NEWCOIN");
+        assert_eq!(currency.precision, UNKNOWN_CURRENCY_PRECISION);
+        assert_eq!(currency.currency_type, CurrencyType::Crypto);
+    }
+
+    #[rstest]
+    fn test_dispatch_accepted_then_fills(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+
+        let accepted = dispatcher
+            .dispatch(update(Some("O-001"), OrderUpdateKind::Accepted), 2)
+            .unwrap();
+        let filled = dispatcher
+            .dispatch(update(Some("O-001"), fill("T-1", 1.0)), 3)
+            .unwrap();
+
+        assert!(matches!(accepted, Some(OrderEvent::OrderAccepted(_))));
+        let Some(OrderEvent::OrderFilled(filled)) = filled else {
+            panic!("expected fill, was {filled:?}");
+        };
+        assert_eq!(filled.last_qty, Quantity::from(1));
+        assert_eq!(filled.account_id, AccountId::from(ACCOUNT_ID));
+        assert_eq!(
+            filled.commission,
+            Some(Money::new(0.8, Currency::from("USDT")).unwrap())
+        );
+        assert_eq!(status(&engine), OrderStatus::PartiallyFilled);
+        assert_eq!(dispatcher.order_event_count, 2);
+    }
+
+    #[rstest]
+    fn test_dispatch_skips_duplicate_updates(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+        dispatcher
+            .dispatch(update(Some("O-001"), OrderUpdateKind::Accepted), 2)
+            .unwrap();
+        dispatcher
+            .dispatch(update(Some("O-001"), fill("T-1", 1.0)), 3)
+            .unwrap();
+
+        let accepted = dispatcher
+            .dispatch(update(Some("O-001"), OrderUpdateKind::Accepted), 4)
+            .unwrap();
+        let filled = dispatcher
+            .dispatch(update(Some("O-001"), fill("T-1", 1.0)), 5)
+            .unwrap();
+
+        assert!(accepted.is_none());
+        assert!(filled.is_none());
+        assert_eq!(status(&engine), OrderStatus::PartiallyFilled);
+        assert_eq!(dispatcher.order_event_count, 2);
+    }
+
+    #[rstest]
+    fn test_dispatch_resolves_order_by_venue_order_id(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+        dispatcher
+            .dispatch(update(Some("O-001"), OrderUpdateKind::Accepted), 2)
+            .unwrap();
+
+        let canceled = dispatcher
+            .dispatch(update(None, OrderUpdateKind::Canceled), 3)
+            .unwrap();
+
+        assert!(matches!(canceled, Some(OrderEvent::OrderCanceled(_))));
+        assert_eq!(status(&engine), OrderStatus::Canceled);
+    }
+
+    #[rstest]
+    fn test_dispatch_rejected(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+
+        let rejected = dispatcher
+            .dispatch(
+                update(
+                    Some("O-001"),
+                    OrderUpdateKind::Rejected(Ustr::from("Insufficient margin")),
+                ),
+                2,
+            )
+            .unwrap();
+
+        let Some(OrderEvent::OrderRejected(rejected)) = rejected else {
+            panic!("expected rejection, was {rejected:?}");
+        };
+        assert_eq!(rejected.reason, Ustr::from("Insufficient margin"));
+        assert_eq!(status(&engine), OrderStatus::Rejected);
+    }
+
+    #[rstest]
+    fn test_dispatch_skips_external_order(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+
+        let event = dispatcher
+            .dispatch(update(Some("EXTERNAL-1"), OrderUpdateKind::Accepted), 2)
+            .unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(status(&engine), OrderStatus::Initialized);
+        assert_eq!(dispatcher.order_event_count, 0);
+    }
+
+    #[rstest]
+    fn test_dispatch_without_instrument_errors(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut engine = ExecutionEngine::new(msgbus.clone());
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from(2),
+            Some(ClientOrderId::from("O-001")),
+            None,
+        );
+        engine.add_order(order.into()).unwrap();
+        let engine = Rc::new(RefCell::new(engine));
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+
+        let result = dispatcher.dispatch(update(Some("O-001"), fill("T-1", 1.0)), 2);
+
+        assert!(result.is_err());
+        assert_eq!(status(&engine), OrderStatus::Initialized);
+    }
+
+    #[rstest]
+    fn test_dispatch_account_state_publishes(
+        engine: Rc<RefCell<ExecutionEngine>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let (handler, received) = recording_handler::<AccountState>("account-state-handler");
+        msgbus
+            .borrow_mut()
+            .subscribe("events.account.*", handler, None);
+        let mut dispatcher = dispatcher(&engine, &msgbus);
+        let usdt = Money::new(1000.0, Currency::from("USDT")).unwrap();
+        let state = AccountState::new(
+            AccountId::from(ACCOUNT_ID),
+            AccountType::Margin,
+            vec![AccountBalance::new(usdt, Money::new(0.0, usdt.currency).unwrap(), usdt).unwrap()],
+            vec![],
+            true,
+            UUID4::new(),
+            1,
+            2,
+            None,
+        )
+        .unwrap();
+
+        let event = dispatcher
+            .dispatch(UserDataEvent::Account(state.clone()), 2)
+            .unwrap();
+
+        assert!(event.is_none());
+        assert_eq!(dispatcher.account_state_count, 1);
+        assert_eq!(*received.lock().unwrap(), vec![state]);
+    }
+}
@@ -0,0 +1,374 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Synthesizes quote and trade ticks from OHLC bars, for backtests where only bar data
+//! is available.
+//!
+//! Each bar is expanded into an intra-bar price path which starts at the open, visits both
+//! the high and the low, and ends at the close.
+
+use nautilus_core::{
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    time::UnixNanos,
+};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::{AggressorSide, BarAggregation},
+    identifiers::trade_id::TradeId,
+    types::{price::Price, quantity::Quantity},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The intra-bar price path used to expand a bar into ticks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntraBarPath {
+    /// Open, high, low, close.
+    OpenHighLowClose,
+    /// Open, low, high, close.
+    OpenLowHighClose,
+    /// Open, then whichever of the high or low is nearest the open, then the other, then close.
+    NearestExtremeFirst,
+    /// A Brownian bridge of `steps` increments from the open to the close, passing through
+    /// the high and the low (visited in nearest extreme first order at random times).
+    BrownianBridge { steps: usize },
+}
+
+/// Configuration for [`BarExpander`] instances.
+#[derive(Clone, Debug)]
+pub struct BarExpansionConfig {
+    /// The intra-bar price path.
+    pub path: IntraBarPath,
+    /// The quoted spread in price increments of the bar prices (the bid is the path price).
+    pub spread_ticks: u32,
+    /// If tick timestamps are distributed over the bar interval (for time bars),
+    /// otherwise every tick is stamped at the bar close.
+    pub distribute_timestamps: bool,
+    /// The seed for the random number generator used by stochastic paths.
+    pub seed: u64,
+}
+
+impl Default for BarExpansionConfig {
+    fn default() -> Self {
+        Self {
+            path: IntraBarPath::OpenHighLowClose,
+            spread_ticks: 0,
+            distribute_timestamps: false,
+            seed: 0,
+        }
+    }
+}
+
+/// Provides expansion of bars into synthetic quote and trade ticks.
+pub struct BarExpander {
+    pub config: BarExpansionConfig,
+    rng: StdRng,
+}
+
+impl BarExpander {
+    #[must_use]
+    pub fn new(config: BarExpansionConfig) -> Self {
+        let rng = StdRng::seed_from_u64(config.seed);
+        Self { config, rng }
+    }
+
+    /// Returns the intra-bar price path for the given `bar`, at the precision of the bar prices.
+    pub fn price_path(&mut self, bar: &Bar) -> Vec<Price> {
+        let high_first = match self.config.path {
+            IntraBarPath::OpenHighLowClose => true,
+            IntraBarPath::OpenLowHighClose => false,
+            IntraBarPath::NearestExtremeFirst | IntraBarPath::BrownianBridge { .. } => {
+                (bar.high.as_f64() - bar.open.as_f64()) <= (bar.open.as_f64() - bar.low.as_f64())
+            }
+        };
+        let (first, second) = if high_first {
+            (bar.high, bar.low)
+        } else {
+            (bar.low, bar.high)
+        };
+
+        match self.config.path {
+            IntraBarPath::BrownianBridge { steps } if steps >= 3 => {
+                self.brownian_bridge(bar, first, second, steps)
+            }
+            _ => vec![bar.open, first, second, bar.close],
+        }
+    }
+
+    /// Returns synthetic quote ticks for the given `bar`, one per point of the price path.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the ask price exceeds the maximum price.
+    pub fn quote_ticks(&mut self, bar: &Bar) -> Vec<QuoteTick> {
+        let prices = self.price_path(bar);
+        let timestamps = self.timestamps(bar, prices.len());
+        let sizes = split_volume(bar.volume, prices.len());
+        let precision = bar.open.precision;
+        let spread = f64::from(self.config.spread_ticks) * 10f64.powi(-i32::from(precision));
+
+        prices
+            .into_iter()
+            .zip(timestamps)
+            .zip(sizes)
+            .map(|((bid, ts_event), size)| {
+                let ask = Price::new(bid.as_f64() + spread, precision).unwrap();
+                QuoteTick {
+                    instrument_id: bar.bar_type.instrument_id,
+                    bid_price: bid,
+                    ask_price: ask,
+                    bid_size: size,
+                    ask_size: size,
+                    ts_event,
+                    ts_init: ts_event.max(bar.ts_init),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns synthetic trade ticks for the given `bar`, one per point of the price path,
+    /// which together trade the bar volume.
+    pub fn trade_ticks(&mut self, bar: &Bar) -> Vec<TradeTick> {
+        let prices = self.price_path(bar);
+        let timestamps = self.timestamps(bar, prices.len());
+        let sizes = split_volume(bar.volume, prices.len());
+
+        let mut last_px = bar.open;
+        prices
+            .into_iter()
+            .zip(timestamps)
+            .zip(sizes)
+            .enumerate()
+            .map(|(i, ((price, ts_event), size))| {
+                let aggressor_side = if price > last_px {
+                    AggressorSide::Buyer
+                } else if price < last_px {
+                    AggressorSide::Seller
+                } else {
+                    AggressorSide::NoAggressor
+                };
+                last_px = price;
+                TradeTick::new(
+                    bar.bar_type.instrument_id,
+                    price,
+                    size,
+                    aggressor_side,
+                    TradeId::from(format!("{}-{i:03}", bar.ts_event).as_str()),
+                    ts_event,
+                    ts_event.max(bar.ts_init),
+                )
+            })
+            .collect()
+    }
+
+    fn timestamps(&self, bar: &Bar, count: usize) -> Vec<UnixNanos> {
+        let interval = if self.config.distribute_timestamps {
+            bar_interval_ns(bar).unwrap_or(0)
+        } else {
+            0
+        };
+        if interval == 0 || count < 2 {
+            return vec![bar.ts_event; count];
+        }
+
+        // The first tick is at the bar open time and the last at the bar close
+        let ts_open = bar.ts_event.saturating_sub(interval);
+        (0..count)
+            .map(|i| ts_open + interval * i as u64 / (count - 1) as u64)
+            .collect()
+    }
+
+    fn brownian_bridge(
+        &mut self,
+        bar: &Bar,
+        first: Price,
+        second: Price,
+        steps: usize,
+    ) -> Vec<Price> {
+        // Times at which the extremes are visited, strictly between the open and the close
+        let t1 = self.rng.gen_range(1..steps - 1);
+        let t2 = self.rng.gen_range(t1 + 1..steps);
+        let anchors = [
+            (0, bar.open.as_f64()),
+            (t1, first.as_f64()),
+            (t2, second.as_f64()),
+            (steps, bar.close.as_f64()),
+        ];
+
+        let (low, high) = (bar.low.as_f64(), bar.high.as_f64());
+        let sigma = (high - low) / (steps as f64).sqrt();
+        let precision = bar.open.precision;
+
+        let mut path = vec![bar.open.as_f64()];
+        for window in anchors.windows(2) {
+            let ((t_a, v_a), (t_b, v_b)) = (window[0], window[1]);
+            let n = t_b - t_a;
+
+            // Random walk pinned at both ends: B(t) = W(t) - (t / n) * W(n)
+            let mut walk = vec![0.0; n + 1];
+            for t in 1..=n {
+                walk[t] = walk[t - 1] + sigma * self.standard_normal();
+            }
+            for t in 1..=n {
+                let frac = t as f64 / n as f64;
+                let bridge = walk[t] - frac * walk[n];
+                let value = v_a + (v_b - v_a) * frac + bridge;
+                path.push(value.clamp(low, high));
+            }
+        }
+
+        path.into_iter()
+            .map(|value| Price::new(value, precision).unwrap())
+            .collect()
+    }
+
+    /// Returns a standard normal sample (using the Box-Muller transform).
+    fn standard_normal(&mut self) -> f64 {
+        let u1: f64 = self.rng.gen_range(f64::EPSILON..1.0);
+        let u2: f64 = self.rng.gen();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+/// Returns the interval of the given time `bar` in nanoseconds (`None` for other
+/// aggregations, and for months which have no fixed interval).
+fn bar_interval_ns(bar: &Bar) -> Option<u64> {
+    let spec = bar.bar_type.spec;
+    let unit = match spec.aggregation {
+        BarAggregation::Millisecond => NANOSECONDS_IN_MILLISECOND,
+        BarAggregation::Second => NANOSECONDS_IN_SECOND,
+        BarAggregation::Minute => 60 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Hour => 3_600 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Day => 86_400 * NANOSECONDS_IN_SECOND,
+        BarAggregation::Week => 7 * 86_400 * NANOSECONDS_IN_SECOND,
+        _ => return None,
+    };
+    Some(unit * spec.step as u64)
+}
+
+/// Splits `volume` into `count` parts at the volume precision, with any remainder
+/// in the final part.
+fn split_volume(volume: Quantity, count: usize) -> Vec<Quantity> {
+    let precision = volume.precision;
+    let part = Quantity::new(volume.as_f64() / count as f64, precision).unwrap();
+    let mut sizes = vec![part; count];
+    if let Some(last) = sizes.last_mut() {
+        let allocated = part.as_f64() * (count - 1) as f64;
+        *last = Quantity::new((volume.as_f64() - allocated).max(0.0), precision).unwrap();
+    }
+    sizes
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::bar::{stubs::stub_bar, BarType};
+    use rstest::rstest;
+
+    use super::*;
+
+    const MINUTE: u64 = 60 * NANOSECONDS_IN_SECOND;
+
+    fn bar(open: &str, high: &str, low: &str, close: &str) -> Bar {
+        let bar_type: BarType = stub_bar().bar_type;
+        Bar::new(
+            bar_type,
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from("100000"),
+            10 * MINUTE,
+            10 * MINUTE,
+        )
+    }
+
+    fn expander(path: IntraBarPath) -> BarExpander {
+        BarExpander::new(BarExpansionConfig {
+            path,
+            ..Default::default()
+        })
+    }
+
+    #[rstest]
+    #[case(IntraBarPath::OpenHighLowClose, ["1.00010", "1.00050", "1.00000", "1.00030"])]
+    #[case(IntraBarPath::OpenLowHighClose, ["1.00010", "1.00000", "1.00050", "1.00030"])]
+    #[case(IntraBarPath::NearestExtremeFirst, ["1.00010", "1.00000", "1.00050", "1.00030"])]
+    fn test_heuristic_paths(#[case] path: IntraBarPath, #[case] expected: [&str; 4]) {
+        let bar = bar("1.00010", "1.00050", "1.00000", "1.00030");
+
+        let prices = expander(path).price_path(&bar);
+
+        assert_eq!(prices, expected.map(Price::from).to_vec());
+    }
+
+    #[rstest]
+    fn test_brownian_bridge_path_visits_extremes_within_range() {
+        let bar = bar("1.00010", "1.00050", "1.00000", "1.00030");
+        let path = IntraBarPath::BrownianBridge { steps: 20 };
+
+        let prices = expander(path).price_path(&bar);
+
+        assert_eq!(prices.len(), 21);
+        assert_eq!(prices.first(), Some(&bar.open));
+        assert_eq!(prices.last(), Some(&bar.close));
+        assert!(prices.contains(&bar.high));
+        assert!(prices.contains(&bar.low));
+        assert!(prices.iter().all(|p| *p >= bar.low && *p <= bar.high));
+        assert_eq!(expander(path).price_path(&bar), prices); // Deterministic for the seed
+    }
+
+    #[rstest]
+    fn test_trade_ticks_trade_bar_volume() {
+        let bar = bar("1.00010", "1.00050", "1.00000", "1.00030");
+        let mut expander = BarExpander::new(BarExpansionConfig {
+            path: IntraBarPath::BrownianBridge { steps: 7 },
+            ..Default::default()
+        });
+
+        let trades = expander.trade_ticks(&bar);
+
+        let volume: f64 = trades.iter().map(|t| t.size.as_f64()).sum();
+        assert_eq!(trades.len(), 8);
+        assert_eq!(volume, bar.volume.as_f64());
+        assert!(trades.iter().all(|t| t.ts_event == bar.ts_event));
+    }
+
+    #[rstest]
+    fn test_quote_ticks_with_spread_and_distributed_timestamps() {
+        let bar = bar("1.00010", "1.00050", "1.00000", "1.00030");
+        let mut expander = BarExpander::new(BarExpansionConfig {
+            spread_ticks: 2,
+            distribute_timestamps: true,
+            ..Default::default()
+        });
+
+        let quotes = expander.quote_ticks(&bar);
+
+        assert_eq!(quotes[1].bid_price, Price::from("1.00050"));
+        assert_eq!(quotes[1].ask_price, Price::from("1.00052"));
+        assert_eq!(
+            quotes.iter().map(|q| q.ts_event).collect::<Vec<_>>(),
+            vec![
+                9 * MINUTE,
+                9 * MINUTE + 20 * NANOSECONDS_IN_SECOND,
+                9 * MINUTE + 40 * NANOSECONDS_IN_SECOND,
+                10 * MINUTE
+            ]
+        );
+        assert!(quotes.iter().all(|q| q.ts_init == 10 * MINUTE));
+    }
+}
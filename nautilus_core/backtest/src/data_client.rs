@@ -0,0 +1,486 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A data client which fulfills historical data requests in a backtest, from pre-loaded data
+//! and an optional Parquet data catalog.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use anyhow::{anyhow, Result};
+use nautilus_core::uuid::UUID4;
+use nautilus_data::{
+    client::DataClient,
+    messages::{DataRequest, DataRequestType, DataResponse, DataSubscription, ResponseData},
+};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick, Data, HasTsInit},
+    identifiers::{client_id::ClientId, venue::Venue},
+    instruments::Instrument,
+};
+use nautilus_persistence::backend::session::DataBackendSession;
+
+/// The number of records decoded per batch when querying catalog files.
+const CATALOG_CHUNK_SIZE: usize = 10_000;
+
+/// Provides a data client for backtesting which responds to historical data requests
+/// synchronously from data loaded up front, and from the Parquet data catalog at the
+/// path given with [`BacktestDataClient::with_catalog`].
+///
+/// Catalog files are located using the `ParquetDataCatalog` layout of
+/// `<path>/data/<data type>/<identifier>/*.parquet`, and only the requested time range
+/// is read from them.
+///
+/// Streaming data is replayed by the backtest engine itself, so subscriptions are
+/// accepted without any further action.
+pub struct BacktestDataClient {
+    client_id: ClientId,
+    venue: Option<Venue>,
+    data: Vec<Data>,
+    instruments: Vec<Rc<dyn Instrument>>,
+    catalog_path: Option<PathBuf>,
+}
+
+impl BacktestDataClient {
+    /// Creates a new [`BacktestDataClient`] instance holding the given `data`.
+    ///
+    /// The data is sorted by `ts_init`, so it may be passed in any order, such as
+    /// directly from a `DataBackendSession` query result.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        venue: Option<Venue>,
+        data: impl IntoIterator<Item = Data>,
+    ) -> Self {
+        let mut data: Vec<Data> = data.into_iter().collect();
+        data.sort_by_key(HasTsInit::get_ts_init);
+        Self {
+            client_id,
+            venue,
+            data,
+            instruments: Vec::new(),
+            catalog_path: None,
+        }
+    }
+
+    /// Sets the root `path` of the Parquet data catalog to fulfill market data requests
+    /// from, in addition to any data held by the client.
+    #[must_use]
+    pub fn with_catalog(mut self, path: impl Into<PathBuf>) -> Self {
+        self.catalog_path = Some(path.into());
+        self
+    }
+
+    /// Adds the given `data`, maintaining `ts_init` order.
+    pub fn add_data(&mut self, data: impl IntoIterator<Item = Data>) {
+        self.data.extend(data);
+        self.data.sort_by_key(HasTsInit::get_ts_init);
+    }
+
+    /// Adds the given `instrument` to respond to instrument requests with.
+    pub fn add_instrument(&mut self, instrument: Rc<dyn Instrument>) {
+        self.instruments.push(instrument);
+    }
+
+    /// Returns the number of data items held by the client.
+    #[must_use]
+    pub fn data_count(&self) -> usize {
+        self.data.len()
+    }
+
+    fn query_data(&self, request: &DataRequest) -> Result<Vec<Data>> {
+        let mut data: Vec<Data> = self
+            .data
+            .iter()
+            .filter(|d| request.request_type.matches(d) && request.contains(d.get_ts_init()))
+            .cloned()
+            .collect();
+
+        if let Some(catalog_path) = &self.catalog_path {
+            data.extend(query_catalog(catalog_path, request)?);
+            data.sort_by_key(HasTsInit::get_ts_init);
+        }
+
+        // The limit keeps the most recent data, nearest the end of the range
+        if let Some(limit) = request.limit {
+            let excess = data.len().saturating_sub(limit);
+            data.drain(..excess);
+        }
+        Ok(data)
+    }
+
+    fn query_instruments(&self, request: &DataRequest, venue: Venue) -> Vec<Rc<dyn Instrument>> {
+        // Instruments remain valid after definition, so only the end of the range applies
+        self.instruments
+            .iter()
+            .filter(|i| i.venue() == venue && request.end.map_or(true, |end| i.ts_init() <= end))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Returns the data for the given `request` from the catalog files under `catalog_path`.
+fn query_catalog(catalog_path: &Path, request: &DataRequest) -> Result<Vec<Data>> {
+    let (data_dir, identifier) = match request.request_type {
+        DataRequestType::QuoteTicks(instrument_id) => ("quote_tick", instrument_id.to_string()),
+        DataRequestType::TradeTicks(instrument_id) => ("trade_tick", instrument_id.to_string()),
+        DataRequestType::Bars(bar_type) => ("bar", bar_type.to_string()),
+        DataRequestType::Instruments(_) => return Ok(Vec::new()),
+    };
+
+    // Directories are named with URI safe identifiers, as for the `ParquetDataCatalog`
+    let dir = catalog_path
+        .join("data")
+        .join(data_dir)
+        .join(identifier.replace('/', ""));
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map_or(false, |ext| ext == "parquet"))
+        .collect();
+    files.sort();
+
+    let mut session = DataBackendSession::new(CATALOG_CHUNK_SIZE);
+    for (i, file) in files.iter().enumerate() {
+        let file_path = file
+            .to_str()
+            .ok_or_else(|| anyhow!("Invalid catalog file path {file:?}"))?;
+        let table_name = format!("{data_dir}_{i}");
+        let mut sql_query = format!(
+            "SELECT * FROM {table_name} WHERE ts_init >= {}",
+            request.start.unwrap_or(0)
+        );
+        if let Some(end) = request.end {
+            sql_query.push_str(&format!(" AND ts_init <= {end}"));
+        }
+
+        match request.request_type {
+            DataRequestType::QuoteTicks(_) => {
+                session.add_file::<QuoteTick>(&table_name, file_path, Some(&sql_query))?;
+            }
+            DataRequestType::TradeTicks(_) => {
+                session.add_file::<TradeTick>(&table_name, file_path, Some(&sql_query))?;
+            }
+            DataRequestType::Bars(_) => {
+                session.add_file::<Bar>(&table_name, file_path, Some(&sql_query))?;
+            }
+            DataRequestType::Instruments(_) => unreachable!(),
+        }
+    }
+
+    // Files may hold other bar types or instruments, so still match on the request
+    Ok(session
+        .get_query_result()
+        .filter(|d| request.request_type.matches(d))
+        .collect())
+}
+
+impl DataClient for BacktestDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        self.venue
+    }
+
+    fn subscribe(&mut self, subscription: DataSubscription) -> Result<()> {
+        log::debug!("Subscribed to {subscription}");
+        Ok(())
+    }
+
+    fn unsubscribe(&mut self, subscription: DataSubscription) -> Result<()> {
+        log::debug!("Unsubscribed from {subscription}");
+        Ok(())
+    }
+
+    fn request(&mut self, request: &DataRequest) -> Result<Option<DataResponse>> {
+        let data = match request.request_type {
+            DataRequestType::Instruments(venue) => {
+                ResponseData::Instruments(self.query_instruments(request, venue))
+            }
+            _ => ResponseData::Data(self.query_data(request)?),
+        };
+        log::debug!(
+            "Fulfilled request {} for {} with {} items",
+            request.request_id,
+            request.request_type,
+            data.len()
+        );
+
+        Ok(Some(DataResponse::new(
+            request.request_id,
+            self.client_id,
+            data,
+            UUID4::new(),
+            request.ts_init,
+        )))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell};
+
+    use nautilus_common::{
+        handlers::MessageHandler, msgbus::MessageBus, testing::recording_handler_with,
+    };
+    use nautilus_data::{engine::DataEngine, messages::DataCommand};
+    use nautilus_model::{
+        data::bar::{stubs::stub_bar, Bar},
+        identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+        instruments::{
+            crypto_perpetual::CryptoPerpetual,
+            currency_pair::CurrencyPair,
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+        },
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::*;
+
+    fn bars(stub_bar: Bar, count: u64) -> Vec<Data> {
+        (1..=count)
+            .rev()
+            .map(|i| {
+                let mut bar = stub_bar;
+                bar.ts_event = i * 60_000_000_000;
+                bar.ts_init = bar.ts_event;
+                Data::Bar(bar)
+            })
+            .collect()
+    }
+
+    fn request(
+        request_type: DataRequestType,
+        start: Option<u64>,
+        end: Option<u64>,
+        limit: Option<usize>,
+    ) -> DataRequest {
+        DataRequest::new(None, None, request_type, start, end, limit, UUID4::new(), 0)
+    }
+
+    fn response_ts(response: &DataResponse) -> Vec<u64> {
+        match &response.data {
+            ResponseData::Data(data) => data.iter().map(HasTsInit::get_ts_init).collect(),
+            ResponseData::Instruments(_) => panic!("Expected data response"),
+        }
+    }
+
+    #[rstest]
+    fn test_request_bars_filters_by_range(stub_bar: Bar) {
+        let mut client = BacktestDataClient::new(ClientId::from("SIM"), None, bars(stub_bar, 5));
+        let req = request(
+            DataRequestType::Bars(stub_bar.bar_type),
+            Some(120_000_000_000),
+            Some(240_000_000_000),
+            None,
+        );
+
+        let response = client.request(&req).unwrap().unwrap();
+
+        assert_eq!(response.correlation_id, req.request_id);
+        assert_eq!(
+            response_ts(&response),
+            vec![120_000_000_000, 180_000_000_000, 240_000_000_000]
+        );
+    }
+
+    #[rstest]
+    fn test_request_bars_limit_keeps_most_recent(stub_bar: Bar) {
+        let mut client = BacktestDataClient::new(ClientId::from("SIM"), None, bars(stub_bar, 5));
+        let req = request(
+            DataRequestType::Bars(stub_bar.bar_type),
+            None,
+            None,
+            Some(2),
+        );
+
+        let response = client.request(&req).unwrap().unwrap();
+
+        assert_eq!(
+            response_ts(&response),
+            vec![240_000_000_000, 300_000_000_000]
+        );
+    }
+
+    #[rstest]
+    fn test_request_instruments_filters_by_venue(
+        audusd_sim: CurrencyPair,
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let audusd_id = audusd_sim.id;
+        let mut client = BacktestDataClient::new(ClientId::from("SIM"), None, Vec::new());
+        client.add_instrument(Rc::new(audusd_sim));
+        client.add_instrument(Rc::new(crypto_perpetual_ethusdt));
+        let req = request(
+            DataRequestType::Instruments(Venue::from("SIM")),
+            None,
+            None,
+            None,
+        );
+
+        let response = client.request(&req).unwrap().unwrap();
+
+        match response.data {
+            ResponseData::Instruments(instruments) => {
+                assert_eq!(instruments.len(), 1);
+                assert_eq!(instruments[0].id(), audusd_id);
+            }
+            ResponseData::Data(_) => panic!("Expected instruments response"),
+        }
+    }
+
+    #[rstest]
+    fn test_request_quotes_from_catalog() {
+        let catalog = tempfile::tempdir().unwrap();
+        let dir = catalog.path().join("data/quote_tick/EURUSD.SIM");
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(
+            "../../tests/test_data/nautilus/quotes.parquet",
+            dir.join("part-0.parquet"),
+        )
+        .unwrap();
+        let mut client = BacktestDataClient::new(ClientId::from("SIM"), None, Vec::new())
+            .with_catalog(catalog.path());
+        let instrument_id = InstrumentId::from("EUR/USD.SIM");
+
+        let all = client
+            .request(&request(
+                DataRequestType::QuoteTicks(instrument_id),
+                None,
+                None,
+                None,
+            ))
+            .unwrap()
+            .unwrap();
+        let limited = client
+            .request(&request(
+                DataRequestType::QuoteTicks(instrument_id),
+                None,
+                None,
+                Some(10),
+            ))
+            .unwrap()
+            .unwrap();
+        let other = client
+            .request(&request(
+                DataRequestType::QuoteTicks(InstrumentId::from("GBP/USD.SIM")),
+                None,
+                None,
+                None,
+            ))
+            .unwrap()
+            .unwrap();
+
+        let all_ts = response_ts(&all);
+        assert_eq!(all_ts.len(), 9500);
+        assert!(all_ts.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(response_ts(&limited), all_ts[all_ts.len() - 10..].to_vec());
+        assert!(other.data.is_empty());
+    }
+
+    #[rstest]
+    fn test_request_quotes_from_catalog_filters_by_range() {
+        let catalog = tempfile::tempdir().unwrap();
+        let dir = catalog.path().join("data/quote_tick/EURUSD.SIM");
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(
+            "../../tests/test_data/nautilus/quotes.parquet",
+            dir.join("part-0.parquet"),
+        )
+        .unwrap();
+        let mut client = BacktestDataClient::new(ClientId::from("SIM"), None, Vec::new())
+            .with_catalog(catalog.path());
+        let instrument_id = InstrumentId::from("EUR/USD.SIM");
+        let all_ts = response_ts(
+            &client
+                .request(&request(
+                    DataRequestType::QuoteTicks(instrument_id),
+                    None,
+                    None,
+                    None,
+                ))
+                .unwrap()
+                .unwrap(),
+        );
+        let (start, end) = (all_ts[100], all_ts[200]);
+
+        let response = client
+            .request(&request(
+                DataRequestType::QuoteTicks(instrument_id),
+                Some(start),
+                Some(end),
+                None,
+            ))
+            .unwrap()
+            .unwrap();
+
+        let expected: Vec<u64> = all_ts
+            .into_iter()
+            .filter(|ts| *ts >= start && *ts <= end)
+            .collect();
+        assert_eq!(response_ts(&response), expected);
+    }
+
+    #[rstest]
+    fn test_request_through_data_engine_delivers_response(stub_bar: Bar) {
+        let msgbus = Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )));
+        let (response_handler, received) =
+            recording_handler_with("response-handler", |m: &dyn Any| {
+                m.downcast_ref::<DataResponse>()
+                    .map(|response| response.data.len())
+            });
+        let req = request(DataRequestType::Bars(stub_bar.bar_type), None, None, None);
+        let endpoint = Ustr::from("DataEngine.request");
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.register(endpoint.as_str(), MessageHandler::new(endpoint, None));
+            let _ = msgbus.request_handler(&endpoint, req.request_id, response_handler);
+        }
+        let mut engine = DataEngine::new(msgbus.clone());
+        engine
+            .register_client(
+                Box::new(BacktestDataClient::new(
+                    ClientId::from("SIM"),
+                    Some(Venue::from("SIM")),
+                    bars(stub_bar, 3),
+                )),
+                None,
+            )
+            .unwrap();
+
+        engine.execute(DataCommand::Request(req)).unwrap();
+
+        assert_eq!(*received.lock().unwrap(), vec![3]);
+        assert!(engine.pending_requests().is_empty());
+        assert_eq!(engine.response_count, 1);
+    }
+}
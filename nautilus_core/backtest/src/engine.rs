@@ -43,11 +43,13 @@ impl TimeEventAccumulator {
     }
 
     /// Drain the accumulated time event handlers in sorted order (by the events `ts_event`).
+    ///
+    /// Handlers with the same `ts_event` keep the order they were accumulated in, which
+    /// is the order the clocks were advanced, then the timer name within each clock.
     pub fn drain(&mut self) -> Vec<TimeEventHandler> {
-        // stable sort is not necessary since there is no relation between
-        // events of the same clock. Only time based ordering is needed.
-        self.event_handlers
-            .sort_unstable_by_key(|v| v.event.ts_event);
+        // A stable sort is required so that events at the same timestamp are
+        // dispatched identically from run to run
+        self.event_handlers.sort_by_key(|v| v.event.ts_event);
         self.event_handlers.drain(..).collect()
     }
 }
@@ -166,4 +168,50 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    #[rstest]
+    fn test_accumulator_drain_preserves_order_of_tied_events() {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let py_list = PyList::empty(py);
+            let py_append = Py::from(py_list.getattr("append").unwrap());
+            let callback_ptr = py_append.as_ptr().cast::<pyo3::ffi::PyObject>();
+
+            let mut accumulator = TimeEventAccumulator::new();
+
+            let names = [
+                "CLOCK_1_B",
+                "CLOCK_1_A",
+                "CLOCK_2_A",
+                "CLOCK_0_Z",
+                "CLOCK_3_A",
+            ];
+            let ts = [200, 200, 100, 300, 200];
+            for (name, ts) in names.into_iter().zip(ts) {
+                let event = TimeEvent::new(Ustr::from(name), UUID4::new(), ts, ts).unwrap();
+                accumulator.event_handlers.push(TimeEventHandler {
+                    event,
+                    callback_ptr,
+                });
+            }
+
+            let drained: Vec<&str> = accumulator
+                .drain()
+                .iter()
+                .map(|h| h.event.name.as_str())
+                .collect();
+
+            assert_eq!(
+                drained,
+                [
+                    "CLOCK_2_A",
+                    "CLOCK_1_B",
+                    "CLOCK_1_A",
+                    "CLOCK_3_A",
+                    "CLOCK_0_Z"
+                ]
+            );
+        });
+    }
 }
@@ -13,4 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod bar_expansion;
+pub mod data_client;
 pub mod engine;
+pub mod matching_engine;
+pub mod sandbox;
+
+#[cfg(feature = "python")]
+pub mod python;
@@ -0,0 +1,3397 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_execution::messages::{BatchAtomicity, CancelOrder, ModifyOrder};
+use nautilus_model::{
+    data::{
+        bar::Bar,
+        delta::OrderBookDelta,
+        order::{BookOrder, OrderId},
+        quote::QuoteTick,
+        status::{InstrumentStatus, VenueStatus},
+        trade::TradeTick,
+    },
+    enums::{
+        AggressorSide, BookAction, BookType, LiquiditySide, MarketStatus, OmsType, OrderSide,
+        OrderStatus, OrderType, TimeInForce, TriggerType,
+    },
+    events::{
+        order::{
+            accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+            event::OrderEvent, expired::OrderExpired, filled::OrderFilled,
+            modify_rejected::OrderModifyRejected, rejected::OrderRejected,
+            triggered::OrderTriggered, updated::OrderUpdated,
+        },
+        position::{closed::PositionClosed, PositionEvent},
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue::Venue, venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orderbook::book::OrderBook,
+    orders::{any::OrderAny, base::Order},
+    position::Position,
+    trading_hours::TradingHours,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::prelude::ToPrimitive;
+use ustr::Ustr;
+
+use crate::bar_expansion::{BarExpander, BarExpansionConfig};
+
+/// Configuration for [`OrderMatchingEngine`] instances.
+#[derive(Clone, Debug)]
+pub struct OrderMatchingEngineConfig {
+    /// If bars should be processed by the matching engine (and move the market).
+    pub bar_execution: bool,
+    /// The expansion of bars into the trade ticks which move the market (if `bar_execution`).
+    pub bar_expansion: BarExpansionConfig,
+    /// If stop orders are rejected on submission if the trigger price is in the market.
+    pub reject_stop_orders: bool,
+    /// If orders with GTD time in force will be supported by the venue.
+    pub support_gtd_orders: bool,
+    /// If venue position IDs will be generated on order fills (`HEDGING` OMS only).
+    pub use_position_ids: bool,
+    /// The venue trading hours, used to schedule `AT_THE_OPEN` and `AT_THE_CLOSE` orders
+    /// and to reject orders submitted while the market is closed.
+    pub trading_hours: Option<TradingHours>,
+    /// The self-trade prevention (STP) mode applied when an order would trade against
+    /// a resting order from the same owner.
+    pub self_trade_prevention: SelfTradePrevention,
+    /// Whether orders are considered to have the same owner per trader or per strategy.
+    pub self_trade_scope: SelfTradeScope,
+    /// The atomicity of order list submissions and batch cancels at the venue.
+    pub order_list_atomicity: BatchAtomicity,
+    /// If passive limit orders join the back of the queue at their price and are only
+    /// filled by trades once the orders ahead of them have traded or been canceled
+    /// (applies to `L3_MBO` books, where individual orders are tracked).
+    pub queue_position: bool,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: true,
+            bar_expansion: BarExpansionConfig::default(),
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+            use_position_ids: true,
+            trading_hours: None,
+            self_trade_prevention: SelfTradePrevention::default(),
+            self_trade_scope: SelfTradeScope::default(),
+            order_list_atomicity: BatchAtomicity::default(),
+            queue_position: false,
+        }
+    }
+}
+
+/// The action taken when an aggressing order would trade against a resting order from
+/// the same owner, mirroring venue self-trade prevention (STP) behavior.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelfTradePrevention {
+    /// Self-trades are allowed.
+    #[default]
+    None,
+    /// The resting orders are canceled and the aggressing order continues to match.
+    CancelResting,
+    /// The aggressing order is canceled.
+    CancelAggressing,
+    /// Both the aggressing and the resting orders are canceled.
+    CancelBoth,
+    /// Both orders are decremented by the smaller quantity, canceling any order which
+    /// has no quantity remaining.
+    Decrement,
+}
+
+/// The scope within which two orders are considered to have the same owner for
+/// self-trade prevention.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SelfTradeScope {
+    /// Orders from the same trader.
+    #[default]
+    Trader,
+    /// Orders from the same trader and strategy.
+    Strategy,
+}
+
+/// An order queued for a session auction.
+#[derive(Clone, Copy, Debug)]
+struct AuctionOrder {
+    client_order_id: ClientOrderId,
+    ts_auction: UnixNanos,
+}
+
+/// The book orders queued ahead of a passive order at its price level.
+#[derive(Clone, Debug)]
+struct QueueAhead {
+    side: OrderSide,
+    price: Price,
+    order_ids: HashSet<OrderId>,
+}
+
+/// Provides an order matching engine for a single market (instrument) on a simulated venue.
+pub struct OrderMatchingEngine {
+    /// The venue for the matching engine.
+    pub venue: Venue,
+    /// The instrument for the matching engine.
+    pub instrument: Box<dyn Instrument>,
+    /// The instruments raw integer ID for the venue.
+    pub raw_id: u32,
+    /// The order book type for the matching engine.
+    pub book_type: BookType,
+    /// The order management system (OMS) type for the matching engine.
+    pub oms_type: OmsType,
+    /// The market status for the matching engine.
+    pub market_status: MarketStatus,
+    /// The config for the matching engine.
+    pub config: OrderMatchingEngineConfig,
+    book: OrderBook,
+    bar_expander: BarExpander,
+    orders: IndexMap<ClientOrderId, OrderAny>,
+    opening_auction: Vec<AuctionOrder>,
+    closing_auction: Vec<AuctionOrder>,
+    queues: IndexMap<ClientOrderId, QueueAhead>,
+    account_ids: HashMap<TraderId, AccountId>,
+    positions: IndexMap<PositionId, Position>,
+    last: Option<Price>,
+    mark: Option<Price>,
+    index: Option<Price>,
+    ts_last: UnixNanos,
+    is_expired: bool,
+    position_count: usize,
+    order_count: usize,
+    execution_count: usize,
+    events: Vec<OrderEvent>,
+    position_events: Vec<PositionEvent>,
+}
+
+impl OrderMatchingEngine {
+    /// Initializes a new [`OrderMatchingEngine`] instance.
+    #[must_use]
+    pub fn new(
+        instrument: Box<dyn Instrument>,
+        raw_id: u32,
+        book_type: BookType,
+        oms_type: OmsType,
+        config: OrderMatchingEngineConfig,
+    ) -> Self {
+        let book = OrderBook::new(instrument.id(), book_type);
+        let bar_expander = BarExpander::new(config.bar_expansion.clone());
+        Self {
+            venue: instrument.id().venue,
+            instrument,
+            raw_id,
+            book_type,
+            oms_type,
+            market_status: MarketStatus::Open,
+            config,
+            book,
+            bar_expander,
+            orders: IndexMap::new(),
+            opening_auction: Vec::new(),
+            closing_auction: Vec::new(),
+            queues: IndexMap::new(),
+            account_ids: HashMap::new(),
+            positions: IndexMap::new(),
+            last: None,
+            mark: None,
+            index: None,
+            ts_last: 0,
+            is_expired: false,
+            position_count: 0,
+            order_count: 0,
+            execution_count: 0,
+            events: Vec::new(),
+            position_events: Vec::new(),
+        }
+    }
+
+    /// Resets the matching engine to its initial state.
+    pub fn reset(&mut self) {
+        self.book.clear(0, 0);
+        self.bar_expander = BarExpander::new(self.config.bar_expansion.clone());
+        self.orders.clear();
+        self.opening_auction.clear();
+        self.closing_auction.clear();
+        self.queues.clear();
+        self.account_ids.clear();
+        self.positions.clear();
+        self.last = None;
+        self.mark = None;
+        self.index = None;
+        self.ts_last = 0;
+        self.is_expired = false;
+        self.market_status = MarketStatus::Open;
+        self.position_count = 0;
+        self.order_count = 0;
+        self.execution_count = 0;
+        self.events.clear();
+        self.position_events.clear();
+    }
+
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.book.best_bid_price()
+    }
+
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.book.best_ask_price()
+    }
+
+    #[must_use]
+    pub fn last_price(&self) -> Option<Price> {
+        self.last
+    }
+
+    #[must_use]
+    pub fn mark_price(&self) -> Option<Price> {
+        self.mark
+    }
+
+    #[must_use]
+    pub fn index_price(&self) -> Option<Price> {
+        self.index
+    }
+
+    #[must_use]
+    pub fn get_book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    #[must_use]
+    pub fn get_open_orders(&self) -> Vec<&OrderAny> {
+        self.orders.values().filter(|o| o.is_open()).collect()
+    }
+
+    #[must_use]
+    pub fn order_exists(&self, client_order_id: &ClientOrderId) -> bool {
+        self.orders.contains_key(client_order_id)
+    }
+
+    #[must_use]
+    pub fn get_open_positions(&self) -> Vec<&Position> {
+        self.positions.values().filter(|p| p.is_open()).collect()
+    }
+
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired
+    }
+
+    /// Drains the order events generated since the last call, in the order they occurred.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Drains the position events generated since the last call, in the order they occurred.
+    pub fn drain_position_events(&mut self) -> Vec<PositionEvent> {
+        self.position_events.drain(..).collect()
+    }
+
+    // -- DATA PROCESSING -------------------------------------------------------------------------
+
+    /// Process the venue market for the given quote tick.
+    pub fn process_quote_tick(&mut self, tick: &QuoteTick) {
+        let mid = (tick.bid_price.as_f64() + tick.ask_price.as_f64()) / 2.0;
+        let session_px = self.instrument.make_price(mid).ok();
+        self.run_auctions(tick.ts_event, session_px);
+
+        if self.book_type == BookType::L1_MBP {
+            self.book.update_quote_tick(tick);
+        }
+
+        self.iterate(tick.ts_event);
+    }
+
+    /// Process the venue market for the given trade tick.
+    pub fn process_trade_tick(&mut self, tick: &TradeTick) {
+        self.run_auctions(tick.ts_event, Some(tick.price));
+
+        if self.book_type == BookType::L1_MBP {
+            self.book.update_trade_tick(tick);
+        }
+        self.last = Some(tick.price);
+
+        self.iterate(tick.ts_event);
+        self.fill_queued_orders(tick);
+    }
+
+    /// Process the venue market for the given bar.
+    ///
+    /// The bar is converted to trade ticks along the configured intra-bar price path
+    /// (by default open, high, low, close) which move the market.
+    pub fn process_bar(&mut self, bar: &Bar) {
+        if !self.config.bar_execution || self.book_type != BookType::L1_MBP {
+            return;
+        }
+
+        for price in self.bar_expander.price_path(bar) {
+            self.execution_count += 1;
+            let trade_id = self.generate_id::<TradeId>(self.execution_count);
+            let tick = TradeTick::new(
+                bar.bar_type.instrument_id,
+                price,
+                bar.volume,
+                AggressorSide::NoAggressor,
+                trade_id,
+                bar.ts_event,
+                bar.ts_init,
+            );
+            self.process_trade_tick(&tick);
+        }
+    }
+
+    /// Process the given mark price update, triggering any orders using [`TriggerType::MarkPrice`].
+    pub fn process_mark_price(&mut self, price: Price, ts_event: UnixNanos) {
+        self.mark = Some(price);
+        self.iterate(ts_event);
+    }
+
+    /// Process the given index price update, triggering any orders using [`TriggerType::IndexPrice`].
+    pub fn process_index_price(&mut self, price: Price, ts_event: UnixNanos) {
+        self.index = Some(price);
+        self.iterate(ts_event);
+    }
+
+    /// Process the venue market for the given order book delta.
+    pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        let session_px = self
+            .book
+            .midpoint()
+            .and_then(|mid| self.instrument.make_price(mid).ok());
+        self.run_auctions(delta.ts_event, session_px);
+
+        if delta.action == BookAction::Update {
+            self.requeue_book_order(&delta);
+        }
+        self.book.apply_delta(delta);
+
+        self.iterate(delta.ts_event);
+    }
+
+    /// Iterate the matching engine to the given timestamp, matching any resting orders
+    /// against the current market and expiring orders which reached their expire time.
+    pub fn iterate(&mut self, ts_now: UnixNanos) {
+        self.ts_last = ts_now;
+        if self.check_instrument_expiration(ts_now) {
+            return;
+        }
+        self.update_market_status(ts_now);
+
+        // Closing auctions do not need new data, they execute at the last price before the close
+        self.run_closing_auction(ts_now);
+
+        let client_order_ids: Vec<ClientOrderId> = self.orders.keys().copied().collect();
+        for client_order_id in client_order_ids {
+            if self.is_queued_for_auction(&client_order_id) {
+                continue;
+            }
+
+            let (is_open, time_in_force, expire_time) = match self.orders.get(&client_order_id) {
+                Some(order) => (order.is_open(), order.time_in_force(), order.expire_time()),
+                None => continue,
+            };
+            if !is_open {
+                continue;
+            }
+
+            if self.config.support_gtd_orders && time_in_force == TimeInForce::Gtd {
+                if let Some(expire_time) = expire_time {
+                    if ts_now >= expire_time {
+                        self.expire_order(client_order_id);
+                        continue;
+                    }
+                }
+            }
+
+            if self.is_matching() {
+                self.match_order(client_order_id);
+            }
+        }
+    }
+
+    /// Settles the market if the instrument has reached its expiration, returning
+    /// whether the instrument is expired.
+    ///
+    /// On expiry all open orders are canceled and any open positions are closed out
+    /// by cash settlement at the last mark price, falling back to the last trade or
+    /// mid price when no mark price has been received.
+    fn check_instrument_expiration(&mut self, ts_now: UnixNanos) -> bool {
+        if self.is_expired {
+            return true;
+        }
+        match self.instrument.expiration_ns() {
+            Some(expiration_ns) if ts_now >= expiration_ns => {}
+            _ => return false,
+        }
+
+        log::info!("{} reached expiration", self.instrument.id());
+        self.is_expired = true;
+        self.market_status = MarketStatus::Closed;
+        self.opening_auction.clear();
+        self.closing_auction.clear();
+
+        let client_order_ids: Vec<ClientOrderId> = self.orders.keys().copied().collect();
+        for client_order_id in client_order_ids {
+            if self
+                .orders
+                .get(&client_order_id)
+                .map_or(false, |order| order.is_open())
+            {
+                self.cancel_order(client_order_id);
+            }
+        }
+
+        let position_ids: Vec<PositionId> = self
+            .positions
+            .values()
+            .filter(|p| p.is_open())
+            .map(|p| p.id)
+            .collect();
+        if position_ids.is_empty() {
+            return true;
+        }
+
+        match self.mark.or_else(|| self.last_session_price()) {
+            Some(settlement_px) => {
+                for position_id in position_ids {
+                    self.settle_position(position_id, settlement_px);
+                }
+            }
+            None => log::error!(
+                "Cannot settle positions for {}: no mark, last or mid price",
+                self.instrument.id()
+            ),
+        }
+        true
+    }
+
+    /// Opens or closes the market per the configured trading hours (if any).
+    ///
+    /// A status set by the venue within the session (such as a halt) is retained until
+    /// the venue transitions it again, or the session closes.
+    fn update_market_status(&mut self, ts_now: UnixNanos) {
+        if let Some(trading_hours) = &self.config.trading_hours {
+            if !trading_hours.is_open(ts_now) {
+                self.market_status = MarketStatus::Closed;
+            } else if self.market_status == MarketStatus::Closed {
+                self.market_status = MarketStatus::Open;
+            }
+        }
+    }
+
+    /// Returns whether orders are currently matched against the market.
+    ///
+    /// In the pre-open, an auction, a pause, a halt or while closed, working orders rest
+    /// without being matched until trading resumes.
+    fn is_matching(&self) -> bool {
+        !matches!(
+            self.market_status,
+            MarketStatus::PreOpen
+                | MarketStatus::Auction
+                | MarketStatus::Pause
+                | MarketStatus::Halt
+                | MarketStatus::Closed
+        )
+    }
+
+    /// Process the given instrument status update for the market.
+    pub fn process_instrument_status(&mut self, status: &InstrumentStatus) {
+        if status.instrument_id != self.instrument.id() {
+            log::warn!("Ignoring {status}: not for {}", self.instrument.id());
+            return;
+        }
+        self.process_market_status(status.status, status.ts_event);
+    }
+
+    /// Process the given venue status update, which applies to every market on the venue.
+    pub fn process_venue_status(&mut self, status: &VenueStatus) {
+        if status.venue != self.venue {
+            log::warn!("Ignoring {status}: not for {}", self.venue);
+            return;
+        }
+        self.process_market_status(status.status, status.ts_event);
+    }
+
+    /// Transitions the market to the given `status`.
+    ///
+    /// New orders are rejected while the market is halted or closed, and are accepted
+    /// but queued without matching in the pre-open, an auction or a pause. When trading
+    /// resumes, all resting orders (including queued market orders) are matched.
+    pub fn process_market_status(&mut self, status: MarketStatus, ts_event: UnixNanos) {
+        if status == self.market_status {
+            return;
+        }
+
+        log::info!(
+            "{} market status {} -> {status}",
+            self.instrument.id(),
+            self.market_status,
+        );
+        let was_matching = self.is_matching();
+        self.market_status = status;
+
+        if !was_matching && self.is_matching() {
+            self.iterate(ts_event);
+        }
+    }
+
+    // -- COMMAND HANDLING ------------------------------------------------------------------------
+
+    /// Process the given order which has been submitted to the venue.
+    pub fn process_order(&mut self, order: OrderAny, account_id: AccountId) {
+        let client_order_id = order.client_order_id();
+        if self.orders.contains_key(&client_order_id) {
+            return; // Already processed
+        }
+
+        self.account_ids.insert(order.trader_id(), account_id);
+        let time_in_force = order.time_in_force();
+        let order_type = order.order_type();
+        let rejection = self.check_order(&order);
+        self.orders.insert(client_order_id, order);
+
+        if let Some(reason) = rejection {
+            self.reject_order(client_order_id, &reason);
+            return;
+        }
+
+        if matches!(
+            time_in_force,
+            TimeInForce::AtTheOpen | TimeInForce::AtTheClose
+        ) {
+            self.process_auction_order(client_order_id, time_in_force);
+            return;
+        }
+
+        if !self.is_matching() {
+            self.queue_order(client_order_id);
+            return;
+        }
+
+        match order_type {
+            OrderType::Market | OrderType::MarketToLimit => {
+                self.process_market_order(client_order_id)
+            }
+            OrderType::Limit => self.process_limit_order(client_order_id),
+            OrderType::StopMarket | OrderType::StopLimit => self.accept_order(client_order_id),
+            _ => {
+                self.accept_order(client_order_id);
+                self.match_order(client_order_id);
+            }
+        }
+    }
+
+    /// Process the given list of orders which have been submitted to the venue together.
+    ///
+    /// With [`BatchAtomicity::AllOrNone`] every order in the list is rejected if any one
+    /// of them would be rejected, otherwise each order is processed independently.
+    pub fn process_order_list(&mut self, orders: Vec<OrderAny>, account_id: AccountId) {
+        if self.config.order_list_atomicity == BatchAtomicity::AllOrNone {
+            let rejection = orders.iter().find_map(|order| {
+                self.check_order(order).map(|reason| {
+                    format!("order list rejected, {}: {reason}", order.client_order_id())
+                })
+            });
+            if let Some(reason) = rejection {
+                for order in orders {
+                    let client_order_id = order.client_order_id();
+                    if self.orders.contains_key(&client_order_id) {
+                        continue; // Already processed
+                    }
+                    self.account_ids.insert(order.trader_id(), account_id);
+                    self.orders.insert(client_order_id, order);
+                    self.reject_order(client_order_id, &reason);
+                }
+                return;
+            }
+        }
+
+        for order in orders {
+            self.process_order(order, account_id);
+        }
+    }
+
+    /// Process the given cancel command, emitting a cancel reject if the order is not
+    /// known to the venue or is already closed.
+    pub fn process_cancel(&mut self, command: &CancelOrder) {
+        if !self.is_order_open(&command.client_order_id) {
+            let reason = format!("{} not found or not open", command.client_order_id);
+            self.reject_cancel(command, &reason);
+            return;
+        }
+        self.cancel_open_order(command.client_order_id);
+    }
+
+    /// Process the given modify command, amending any of the order's quantity, limit price
+    /// and trigger price (those given as `None` are unchanged).
+    ///
+    /// A limit price moved through the market fills the order as a taker, and a new trigger
+    /// price may trigger the order immediately. With queue positions, a passive order which
+    /// is repriced or increased in size loses its priority and rejoins the back of the queue.
+    /// A modify reject is emitted if the order is not known to the venue or is already closed.
+    pub fn process_modify(&mut self, command: &ModifyOrder) {
+        let client_order_id = command.client_order_id;
+        let (price, trigger_price) = (command.price, command.trigger_price);
+        let Some(order) = self.orders.get(&client_order_id).filter(|o| o.is_open()) else {
+            let reason = format!("{client_order_id} not found or not open");
+            self.reject_modify(command, &reason);
+            return;
+        };
+
+        let quantity = command.quantity.unwrap_or_else(|| order.quantity());
+        if let Some(reason) = self.check_modify(order, quantity, price, trigger_price) {
+            self.reject_modify(command, &reason);
+            return;
+        }
+
+        let side = order.side();
+        let loses_priority =
+            quantity > order.quantity() || price.map_or(false, |px| Some(px) != order.price());
+        self.update_order(client_order_id, quantity, price, trigger_price);
+
+        if self.is_queued_for_auction(&client_order_id) {
+            return;
+        }
+        if loses_priority && self.queues.contains_key(&client_order_id) {
+            if let Some(price) = self.orders[&client_order_id].price() {
+                self.join_queue(client_order_id, side, price);
+            }
+        }
+        if !self.is_matching() {
+            return;
+        }
+
+        let order = &self.orders[&client_order_id];
+        let is_limit_working = match order.order_type() {
+            OrderType::Limit | OrderType::MarketToLimit => true,
+            OrderType::StopLimit | OrderType::TrailingStopLimit | OrderType::LimitIfTouched => {
+                order.is_triggered()
+            }
+            _ => false,
+        };
+        let is_marketable = order
+            .price()
+            .map_or(false, |price| self.is_limit_matched(side, price));
+
+        if is_limit_working && is_marketable {
+            if let Some(price) = self.market_price(side) {
+                self.fill_order(client_order_id, price, LiquiditySide::Taker);
+            }
+        } else {
+            self.match_order(client_order_id);
+        }
+    }
+
+    /// Process the given batch of cancel commands, returning the number of orders canceled.
+    ///
+    /// With [`BatchAtomicity::AllOrNone`] no orders are canceled (and a cancel reject is
+    /// emitted for each) if any one of them is not open, otherwise a cancel reject is
+    /// emitted for each order which is not open.
+    pub fn process_batch_cancel(&mut self, cancels: &[CancelOrder]) -> usize {
+        let missing = cancels
+            .iter()
+            .find(|cancel| !self.is_order_open(&cancel.client_order_id))
+            .map(|cancel| cancel.client_order_id);
+
+        if self.config.order_list_atomicity == BatchAtomicity::AllOrNone {
+            if let Some(missing) = missing {
+                let reason = format!("batch cancel rejected, {missing}: not found or not open");
+                for cancel in cancels {
+                    self.reject_cancel(cancel, &reason);
+                }
+                return 0;
+            }
+        }
+
+        let mut canceled = 0;
+        for cancel in cancels {
+            if self.is_order_open(&cancel.client_order_id) {
+                canceled += 1;
+            }
+            self.process_cancel(cancel);
+        }
+        canceled
+    }
+
+    /// Cancels all open orders (such as when a cancel-on-disconnect is triggered),
+    /// returning the number of orders canceled.
+    pub fn process_cancel_all(&mut self) -> usize {
+        let open_ids: Vec<ClientOrderId> = self
+            .orders
+            .values()
+            .filter(|order| order.is_open())
+            .map(|order| order.client_order_id())
+            .collect();
+
+        for client_order_id in &open_ids {
+            self.cancel_open_order(*client_order_id);
+        }
+        open_ids.len()
+    }
+
+    /// Emits a cancel reject for the given command (e.g. for an order unknown to the venue).
+    pub fn reject_cancel(&mut self, command: &CancelOrder, reason: &str) {
+        let event = OrderCancelRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            self.command_venue_order_id(&command.client_order_id, command.venue_order_id),
+            self.account_ids.get(&command.trader_id).copied(),
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderCancelRejected(event));
+    }
+
+    /// Emits a modify reject for the given command.
+    pub fn reject_modify(&mut self, command: &ModifyOrder, reason: &str) {
+        let event = OrderModifyRejected::new(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            self.command_venue_order_id(&command.client_order_id, command.venue_order_id),
+            self.account_ids.get(&command.trader_id).copied(),
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderModifyRejected(event));
+    }
+
+    fn is_order_open(&self, client_order_id: &ClientOrderId) -> bool {
+        self.orders
+            .get(client_order_id)
+            .map_or(false, |order| order.is_open())
+    }
+
+    /// Returns the venue order ID assigned to a tracked order, otherwise the one given
+    /// on the command (if any).
+    fn command_venue_order_id(
+        &self,
+        client_order_id: &ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+    ) -> Option<VenueOrderId> {
+        self.orders
+            .get(client_order_id)
+            .and_then(|order| order.venue_order_id())
+            .or(venue_order_id)
+    }
+
+    // -- ORDER PROCESSING ------------------------------------------------------------------------
+
+    fn process_market_order(&mut self, client_order_id: ClientOrderId) {
+        let side = self.orders[&client_order_id].side();
+        match self.market_price(side) {
+            Some(price) => self.fill_order(client_order_id, price, LiquiditySide::Taker),
+            None => {
+                let reason = format!("no market for {}", self.instrument.id());
+                self.reject_order(client_order_id, &reason);
+            }
+        }
+    }
+
+    /// Returns the reason the venue would reject the given order on submission (if any).
+    fn check_order(&self, order: &OrderAny) -> Option<String> {
+        if self.is_expired {
+            return Some(format!("{} has expired", self.instrument.id()));
+        }
+
+        // Auction orders are queued for the session regardless of the market status
+        if matches!(
+            order.time_in_force(),
+            TimeInForce::AtTheOpen | TimeInForce::AtTheClose
+        ) {
+            return None;
+        }
+
+        match self.market_status {
+            MarketStatus::Closed => {
+                return Some(format!("market closed for {}", self.instrument.id()));
+            }
+            MarketStatus::Halt => {
+                return Some(format!("market halted for {}", self.instrument.id()));
+            }
+            _ => {}
+        }
+
+        let (side, order_type) = (order.side(), order.order_type());
+        if order.is_reduce_only() && self.reduce_only_qty(order).is_none() {
+            return Some(format!(
+                "REDUCE_ONLY {side} order would have increased position"
+            ));
+        }
+
+        match order_type {
+            OrderType::Limit => {
+                let price = order.price()?;
+                if order.is_post_only() && self.is_limit_matched(side, price) {
+                    return Some(format!(
+                        "POST_ONLY {side} order limit px of {price} would have been a TAKER: bid={}, ask={}",
+                        display_px(self.best_bid_price()),
+                        display_px(self.best_ask_price()),
+                    ));
+                }
+            }
+            OrderType::StopMarket | OrderType::StopLimit => {
+                let trigger_price = order.trigger_price()?;
+                let trigger_type = order.trigger_type().unwrap_or(TriggerType::Default);
+                if self.config.reject_stop_orders
+                    && self.is_stop_triggered(side, trigger_type, trigger_price)
+                {
+                    return Some(format!(
+                        "{order_type} {side} order stop px of {trigger_price} was in the market: bid={}, ask={}, last={}, mark={}, index={} (trigger_type={trigger_type})",
+                        display_px(self.best_bid_price()),
+                        display_px(self.best_ask_price()),
+                        display_px(self.last),
+                        display_px(self.mark),
+                        display_px(self.index),
+                    ));
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Returns the reason the venue would reject modifying the given order (if any).
+    fn check_modify(
+        &self,
+        order: &OrderAny,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> Option<String> {
+        let (side, order_type) = (order.side(), order.order_type());
+        if quantity <= order.filled_qty() {
+            return Some(format!(
+                "quantity {quantity} not greater than filled quantity {}",
+                order.filled_qty()
+            ));
+        }
+        if price.is_some() && order.price().is_none() {
+            return Some(format!("{order_type} order has no limit price to modify"));
+        }
+        if trigger_price.is_some() && order.trigger_price().is_none() {
+            return Some(format!("{order_type} order has no trigger price to modify"));
+        }
+
+        if let Some(price) = price {
+            let is_limit_working = !matches!(
+                order_type,
+                OrderType::StopLimit | OrderType::TrailingStopLimit | OrderType::LimitIfTouched
+            ) || order.is_triggered();
+            if order.is_post_only() && is_limit_working && self.is_limit_matched(side, price) {
+                return Some(format!(
+                    "POST_ONLY {side} order new limit px of {price} would have been a TAKER: bid={}, ask={}",
+                    display_px(self.best_bid_price()),
+                    display_px(self.best_ask_price()),
+                ));
+            }
+        }
+
+        if let Some(trigger_price) = trigger_price {
+            let is_stop_working = matches!(order_type, OrderType::StopMarket)
+                || (order_type == OrderType::StopLimit && !order.is_triggered());
+            let trigger_type = order.trigger_type().unwrap_or(TriggerType::Default);
+            if self.config.reject_stop_orders
+                && is_stop_working
+                && self.is_stop_triggered(side, trigger_type, trigger_price)
+            {
+                return Some(format!(
+                    "{order_type} {side} order new stop px of {trigger_price} was in the market: bid={}, ask={}, last={} (trigger_type={trigger_type})",
+                    display_px(self.best_bid_price()),
+                    display_px(self.best_ask_price()),
+                    display_px(self.last),
+                ));
+            }
+        }
+        None
+    }
+
+    /// Accepts the order to rest without matching until trading resumes.
+    fn queue_order(&mut self, client_order_id: ClientOrderId) {
+        self.accept_order(client_order_id);
+
+        let order = &self.orders[&client_order_id];
+        if let (OrderType::Limit, Some(price)) = (order.order_type(), order.price()) {
+            self.join_queue(client_order_id, order.side(), price);
+        }
+    }
+
+    fn process_limit_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let (side, price) = (order.side(), order.price());
+        let is_marketable = price.map_or(false, |price| self.is_limit_matched(side, price));
+
+        self.accept_order(client_order_id);
+
+        if is_marketable {
+            if let Some(price) = self.market_price(side) {
+                self.fill_order(client_order_id, price, LiquiditySide::Taker);
+            }
+        } else if let Some(price) = price {
+            self.join_queue(client_order_id, side, price);
+        }
+    }
+
+    // -- QUEUE POSITION --------------------------------------------------------------------------
+
+    /// Places the passive order at the back of the queue of book orders at its price.
+    ///
+    /// Queued orders are kept in the order they joined, so that own orders at the same
+    /// price are also queued behind each other.
+    fn join_queue(&mut self, client_order_id: ClientOrderId, side: OrderSide, price: Price) {
+        if !self.config.queue_position || self.book_type != BookType::L3_MBO {
+            return;
+        }
+        self.queues.shift_remove(&client_order_id);
+        let order_ids = self
+            .book
+            .orders_at(side, price)
+            .iter()
+            .map(|o| o.order_id)
+            .collect();
+        self.queues.insert(
+            client_order_id,
+            QueueAhead {
+                side,
+                price,
+                order_ids,
+            },
+        );
+    }
+
+    /// Moves a book order behind any queued passive orders if the update loses it
+    /// queue priority (an increase in size or a change of price).
+    fn requeue_book_order(&mut self, delta: &OrderBookDelta) {
+        if self.queues.is_empty() {
+            return;
+        }
+        let order = delta.order;
+        let loses_priority = self
+            .book
+            .get_order(order.side, order.order_id)
+            .map_or(false, |existing| {
+                order.size > existing.size || order.price != existing.price
+            });
+        if loses_priority {
+            for queue in self.queues.values_mut() {
+                queue.order_ids.remove(&order.order_id);
+            }
+        }
+    }
+
+    /// Returns the raw size of the book orders still queued ahead.
+    fn size_ahead_raw(&self, queue: &QueueAhead) -> u64 {
+        queue
+            .order_ids
+            .iter()
+            .filter_map(|&id| self.book.get_order(queue.side, id))
+            .filter(|o| o.price == queue.price)
+            .map(|o| o.size.raw)
+            .sum()
+    }
+
+    /// Fills queued passive orders from the volume of the given trade left over after
+    /// the orders ahead of them, or in full where the trade printed through their price.
+    ///
+    /// A trade is processed before the book deltas which remove the volume it consumed,
+    /// so the orders it traded against are still counted as ahead. Own orders which joined
+    /// the queue earlier at the same price are also ahead, and the volume of the trade is
+    /// used up as each queued order fills.
+    fn fill_queued_orders(&mut self, tick: &TradeTick) {
+        if !self.is_matching() {
+            return;
+        }
+
+        let mut fills = Vec::new();
+        let mut remaining_raw = tick.size.raw;
+        let mut own_ahead_raw: HashMap<(OrderSide, Price), u64> = HashMap::new();
+        for (client_order_id, queue) in &self.queues {
+            let is_through = match queue.side {
+                OrderSide::Buy => tick.price < queue.price,
+                _ => tick.price > queue.price,
+            };
+            if is_through {
+                fills.push((*client_order_id, queue.price, None));
+                continue;
+            }
+            if tick.price != queue.price {
+                continue;
+            }
+            let Some(leaves_raw) = self
+                .orders
+                .get(client_order_id)
+                .filter(|order| order.is_open())
+                .map(|order| order.leaves_qty().raw)
+            else {
+                continue;
+            };
+
+            let own_ahead = own_ahead_raw.entry((queue.side, queue.price)).or_insert(0);
+            let ahead_raw = self.size_ahead_raw(queue) + *own_ahead;
+            *own_ahead += leaves_raw;
+
+            let fill_raw = tick
+                .size
+                .raw
+                .saturating_sub(ahead_raw)
+                .min(remaining_raw)
+                .min(leaves_raw);
+            if fill_raw > 0 {
+                remaining_raw -= fill_raw;
+                let fill_qty = Quantity::from_raw(fill_raw, tick.size.precision).ok();
+                fills.push((*client_order_id, queue.price, fill_qty));
+            }
+        }
+
+        for (client_order_id, price, max_qty) in fills {
+            if self
+                .orders
+                .get(&client_order_id)
+                .map_or(false, |order| order.is_open())
+            {
+                self.fill_order_qty(client_order_id, price, max_qty, LiquiditySide::Maker);
+            }
+        }
+    }
+
+    fn process_auction_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        time_in_force: TimeInForce,
+    ) {
+        let Some(trading_hours) = &self.config.trading_hours else {
+            let reason = format!(
+                "{time_in_force} orders not supported: no trading hours configured for {}",
+                self.venue
+            );
+            self.reject_order(client_order_id, &reason);
+            return;
+        };
+
+        let ts_auction = if time_in_force == TimeInForce::AtTheOpen {
+            trading_hours.next_open(self.ts_last)
+        } else {
+            trading_hours.next_close(self.ts_last)
+        };
+        let Some(ts_auction) = ts_auction else {
+            let reason = format!("{time_in_force} order rejected: no upcoming session");
+            self.reject_order(client_order_id, &reason);
+            return;
+        };
+
+        let order_type = self.orders[&client_order_id].order_type();
+        if !matches!(order_type, OrderType::Market | OrderType::Limit) {
+            let reason = format!("{time_in_force} not supported for {order_type} orders");
+            self.reject_order(client_order_id, &reason);
+            return;
+        }
+
+        self.accept_order(client_order_id);
+
+        let auction_order = AuctionOrder {
+            client_order_id,
+            ts_auction,
+        };
+        if time_in_force == TimeInForce::AtTheOpen {
+            self.opening_auction.push(auction_order);
+        } else {
+            self.closing_auction.push(auction_order);
+        }
+    }
+
+    fn is_queued_for_auction(&self, client_order_id: &ClientOrderId) -> bool {
+        self.opening_auction
+            .iter()
+            .chain(self.closing_auction.iter())
+            .any(|a| a.client_order_id == *client_order_id)
+    }
+
+    /// Executes any queued auctions which are due at `ts_now`.
+    ///
+    /// Closing auctions execute at the last price *before* the incoming data point,
+    /// whereas opening auctions execute at the `session_px` of the first data point
+    /// at or after the open.
+    fn run_auctions(&mut self, ts_now: UnixNanos, session_px: Option<Price>) {
+        self.run_closing_auction(ts_now);
+
+        let due = take_due(&mut self.opening_auction, ts_now);
+        if due.is_empty() {
+            return;
+        }
+
+        match session_px {
+            Some(price) => {
+                for auction_order in due {
+                    self.fill_auction_order(auction_order.client_order_id, price);
+                }
+            }
+            None => self.opening_auction.extend(due), // No price discovered yet
+        }
+    }
+
+    fn run_closing_auction(&mut self, ts_now: UnixNanos) {
+        let Some(price) = self.last_session_price() else {
+            return;
+        };
+
+        for auction_order in take_due(&mut self.closing_auction, ts_now) {
+            self.fill_auction_order(auction_order.client_order_id, price);
+        }
+    }
+
+    fn last_session_price(&self) -> Option<Price> {
+        self.last.or_else(|| {
+            self.book
+                .midpoint()
+                .and_then(|mid| self.instrument.make_price(mid).ok())
+        })
+    }
+
+    fn fill_auction_order(&mut self, client_order_id: ClientOrderId, price: Price) {
+        let Some(order) = self.orders.get(&client_order_id) else {
+            return;
+        };
+        if !order.is_open() {
+            return;
+        }
+
+        let is_marketable = match (order.order_type(), order.price()) {
+            (OrderType::Limit, Some(limit_px)) => match order.side() {
+                OrderSide::Buy => price <= limit_px,
+                _ => price >= limit_px,
+            },
+            _ => true,
+        };
+
+        if is_marketable {
+            self.fill_order(client_order_id, price, LiquiditySide::Taker);
+        } else {
+            self.cancel_order(client_order_id);
+        }
+    }
+
+    // -- ORDER MATCHING --------------------------------------------------------------------------
+
+    fn match_order(&mut self, client_order_id: ClientOrderId) {
+        let Some(order) = self.orders.get(&client_order_id) else {
+            return;
+        };
+        if !order.is_open() {
+            return;
+        }
+
+        let side = order.side();
+        let order_type = order.order_type();
+        let price = order.price();
+        let trigger_price = order.trigger_price();
+        let trigger_type = order.trigger_type().unwrap_or(TriggerType::Default);
+        let is_triggered = order.is_triggered();
+        let is_post_only = order.is_post_only();
+
+        match order_type {
+            OrderType::Limit | OrderType::MarketToLimit => {
+                if let Some(price) = price {
+                    if self.is_limit_matched(side, price) {
+                        self.fill_order(client_order_id, price, LiquiditySide::Maker);
+                    }
+                }
+            }
+            OrderType::StopMarket | OrderType::TrailingStopMarket => {
+                if let Some(trigger_price) = trigger_price {
+                    if self.is_stop_triggered(side, trigger_type, trigger_price) {
+                        self.fill_market_on_trigger(client_order_id, side);
+                    }
+                }
+            }
+            OrderType::MarketIfTouched => {
+                if let Some(trigger_price) = trigger_price {
+                    if self.is_touch_triggered(side, trigger_type, trigger_price) {
+                        self.fill_market_on_trigger(client_order_id, side);
+                    }
+                }
+            }
+            OrderType::StopLimit | OrderType::TrailingStopLimit | OrderType::LimitIfTouched => {
+                if !is_triggered {
+                    let Some(trigger_price) = trigger_price else {
+                        return;
+                    };
+                    let triggered = if order_type == OrderType::LimitIfTouched {
+                        self.is_touch_triggered(side, trigger_type, trigger_price)
+                    } else {
+                        self.is_stop_triggered(side, trigger_type, trigger_price)
+                    };
+                    if !triggered {
+                        return;
+                    }
+                    self.trigger_order(client_order_id);
+
+                    // A limit which is marketable on trigger takes liquidity
+                    if let Some(price) = price {
+                        if self.is_limit_matched(side, price) {
+                            if is_post_only {
+                                let reason = format!(
+                                    "POST_ONLY {order_type} {side} order limit px of {price} would have been a TAKER on trigger",
+                                );
+                                self.reject_order(client_order_id, &reason);
+                                return;
+                            }
+                            if let Some(market_px) = self.market_price(side) {
+                                self.fill_order(client_order_id, market_px, LiquiditySide::Taker);
+                            }
+                        }
+                    }
+                } else if let Some(price) = price {
+                    if self.is_limit_matched(side, price) {
+                        self.fill_order(client_order_id, price, LiquiditySide::Maker);
+                    }
+                }
+            }
+            // Only rests while the market is not matching (e.g. queued through an auction)
+            OrderType::Market => self.fill_market_on_trigger(client_order_id, side),
+        }
+    }
+
+    fn fill_market_on_trigger(&mut self, client_order_id: ClientOrderId, side: OrderSide) {
+        if let Some(price) = self.market_price(side) {
+            self.fill_order(client_order_id, price, LiquiditySide::Taker);
+        }
+    }
+
+    /// Returns the price a marketable order on the given side would be filled at.
+    fn market_price(&self, side: OrderSide) -> Option<Price> {
+        match side {
+            OrderSide::Buy => self.best_ask_price(),
+            _ => self.best_bid_price(),
+        }
+    }
+
+    fn is_limit_matched(&self, side: OrderSide, price: Price) -> bool {
+        match side {
+            OrderSide::Buy => self.best_ask_price().map_or(false, |ask| ask <= price),
+            _ => self.best_bid_price().map_or(false, |bid| bid >= price),
+        }
+    }
+
+    /// Returns the reference prices an order on the given side is triggered off for the
+    /// given trigger type (any one of them reaching the trigger price triggers the order).
+    ///
+    /// The 'double' trigger types are evaluated against the current reference price only,
+    /// as the engine does not retain the previous bid/ask or last.
+    fn trigger_reference_prices(&self, side: OrderSide, trigger_type: TriggerType) -> Vec<Price> {
+        let prices = match trigger_type {
+            TriggerType::LastTrade | TriggerType::DoubleLast => vec![self.last],
+            TriggerType::LastOrBidAsk => vec![self.last, self.market_price(side)],
+            TriggerType::MidPoint => vec![self
+                .book
+                .midpoint()
+                .and_then(|mid| self.instrument.make_price(mid).ok())],
+            TriggerType::MarkPrice => vec![self.mark],
+            TriggerType::IndexPrice => vec![self.index],
+            TriggerType::NoTrigger
+            | TriggerType::Default
+            | TriggerType::BidAsk
+            | TriggerType::DoubleBidAsk => vec![self.market_price(side)],
+        };
+        prices.into_iter().flatten().collect()
+    }
+
+    fn is_stop_triggered(
+        &self,
+        side: OrderSide,
+        trigger_type: TriggerType,
+        trigger_price: Price,
+    ) -> bool {
+        self.trigger_reference_prices(side, trigger_type)
+            .into_iter()
+            .any(|px| match side {
+                OrderSide::Buy => px >= trigger_price,
+                _ => px <= trigger_price,
+            })
+    }
+
+    fn is_touch_triggered(
+        &self,
+        side: OrderSide,
+        trigger_type: TriggerType,
+        trigger_price: Price,
+    ) -> bool {
+        self.trigger_reference_prices(side, trigger_type)
+            .into_iter()
+            .any(|px| match side {
+                OrderSide::Buy => px <= trigger_price,
+                _ => px >= trigger_price,
+            })
+    }
+
+    // -- EVENT GENERATION ------------------------------------------------------------------------
+
+    fn generate_id<T: for<'a> From<&'a str>>(&self, count: usize) -> T {
+        T::from(format!("{}-{}-{count:03}", self.venue, self.raw_id).as_str())
+    }
+
+    fn account_id(&self, trader_id: &TraderId) -> AccountId {
+        *self
+            .account_ids
+            .get(trader_id)
+            .expect("No account ID registered for trader")
+    }
+
+    fn venue_order_id(&mut self, client_order_id: ClientOrderId) -> VenueOrderId {
+        if let Some(venue_order_id) = self.orders[&client_order_id].venue_order_id() {
+            return venue_order_id;
+        }
+        self.order_count += 1;
+        self.generate_id(self.order_count)
+    }
+
+    fn accept_order(&mut self, client_order_id: ClientOrderId) {
+        let venue_order_id = self.venue_order_id(client_order_id);
+        let order = &self.orders[&client_order_id];
+        let event = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            venue_order_id,
+            self.account_id(&order.trader_id()),
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderAccepted(event));
+    }
+
+    fn reject_order(&mut self, client_order_id: ClientOrderId, reason: &str) {
+        let order = &self.orders[&client_order_id];
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            self.account_id(&order.trader_id()),
+            Ustr::from(reason),
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderRejected(event));
+    }
+
+    /// Cancels the open order, removing it from any session auction it is queued for.
+    fn cancel_open_order(&mut self, client_order_id: ClientOrderId) {
+        self.opening_auction
+            .retain(|a| a.client_order_id != client_order_id);
+        self.closing_auction
+            .retain(|a| a.client_order_id != client_order_id);
+        self.cancel_order(client_order_id);
+    }
+
+    fn cancel_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(&order.trader_id())),
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderCanceled(event));
+    }
+
+    fn update_order_qty(&mut self, client_order_id: ClientOrderId, quantity: Quantity) {
+        self.update_order(client_order_id, quantity, None, None);
+    }
+
+    fn update_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) {
+        let order = &self.orders[&client_order_id];
+        let event = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            quantity,
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(&order.trader_id())),
+            price,
+            trigger_price,
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderUpdated(event));
+    }
+
+    fn expire_order(&mut self, client_order_id: ClientOrderId) {
+        let order = &self.orders[&client_order_id];
+        let event = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(&order.trader_id())),
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderExpired(event));
+    }
+
+    fn trigger_order(&mut self, client_order_id: ClientOrderId) {
+        let ts_last = self.ts_last;
+        let order = self.orders.get_mut(&client_order_id).unwrap();
+        order.set_triggered(ts_last);
+        let event = OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            UUID4::new(),
+            ts_last,
+            ts_last,
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderTriggered(event));
+    }
+
+    fn fill_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+    ) {
+        self.fill_order_qty(client_order_id, last_px, None, liquidity_side);
+    }
+
+    /// Fills the order at `last_px` for its leaves quantity, up to `max_qty` (if given).
+    ///
+    /// With an L2 or L3 book a taker fill walks the opposite side of the book level by level
+    /// (up to the limit price of the order, if any), filling at the price of each level. Any
+    /// quantity the book cannot fill remains working.
+    fn fill_order_qty(
+        &mut self,
+        client_order_id: ClientOrderId,
+        last_px: Price,
+        max_qty: Option<Quantity>,
+        liquidity_side: LiquiditySide,
+    ) {
+        if liquidity_side == LiquiditySide::Taker
+            && !self.prevent_self_trade(client_order_id, last_px)
+        {
+            return;
+        }
+
+        let order = &self.orders[&client_order_id];
+        let mut qty: Quantity = order.leaves_qty();
+        if let Some(max_qty) = max_qty {
+            qty = Quantity::from_raw(qty.raw.min(max_qty.raw), qty.precision).unwrap();
+        }
+        if order.is_reduce_only() {
+            // The position may have been reduced or closed since the order was accepted
+            match self.reduce_only_qty(order) {
+                Some(position_qty) => qty = qty.min(position_qty),
+                None => {
+                    self.cancel_order(client_order_id);
+                    return;
+                }
+            }
+        }
+
+        let fills = self.determine_fills(order, last_px, qty, liquidity_side);
+        if fills.is_empty() {
+            log::error!("Cannot fill order {client_order_id}: no fills from book");
+            return;
+        }
+
+        for (fill_px, fill_qty) in fills {
+            if !self.is_order_open(&client_order_id) {
+                break;
+            }
+            self.apply_fill(client_order_id, fill_px, fill_qty, liquidity_side);
+        }
+    }
+
+    /// Returns the projected fills for `qty` of the order, as the price and quantity of each.
+    ///
+    /// Mirrors `determine_market_price_and_volume` of the Cython matching engine: a taker
+    /// fill against an L2 or L3 book is simulated against the opposite side of the book
+    /// (an unfilled MARKET_TO_LIMIT order takes the top level only), otherwise the order
+    /// fills at `last_px`.
+    fn determine_fills(
+        &self,
+        order: &OrderAny,
+        last_px: Price,
+        qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) -> Vec<(Price, Quantity)> {
+        if liquidity_side != LiquiditySide::Taker || self.book_type == BookType::L1_MBP {
+            return vec![(last_px, qty)];
+        }
+
+        let side = order.side();
+        let price_precision = self.instrument.price_precision();
+        let limit_px = match order.order_type() {
+            OrderType::MarketToLimit if order.filled_qty().is_zero() => Some(last_px),
+            _ => order.price(),
+        }
+        .unwrap_or_else(|| match side {
+            OrderSide::Buy => Price::max(price_precision),
+            _ => Price::min(price_precision),
+        });
+        self.book
+            .simulate_fills(&BookOrder::new(side, limit_px, qty, 0))
+    }
+
+    /// Emits a fill of `last_qty` for the order at `last_px`.
+    fn apply_fill(
+        &mut self,
+        client_order_id: ClientOrderId,
+        last_px: Price,
+        last_qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) {
+        let venue_order_id = self.venue_order_id(client_order_id);
+        self.execution_count += 1;
+        let trade_id: TradeId = self.generate_id(self.execution_count);
+
+        let existing_position_id = self.orders[&client_order_id].position_id();
+        let position_id = if self.oms_type == OmsType::Hedging && self.config.use_position_ids {
+            existing_position_id.or_else(|| {
+                self.position_count += 1;
+                Some(self.generate_id::<PositionId>(self.position_count))
+            })
+        } else {
+            None
+        };
+
+        let order = &self.orders[&client_order_id];
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => self.instrument.maker_fee(),
+            _ => self.instrument.taker_fee(),
+        };
+        let notional = self
+            .instrument
+            .calculate_notional_value(last_qty, last_px, None);
+        let commission = Money::new(
+            notional.as_f64() * fee.to_f64().unwrap_or(0.0),
+            notional.currency,
+        )
+        .ok();
+
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            venue_order_id,
+            self.account_id(&order.trader_id()),
+            trade_id,
+            order.side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            self.instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            position_id,
+            commission,
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderFilled(event));
+    }
+
+    /// Closes out the given position with a settlement fill at `settlement_px`.
+    fn settle_position(&mut self, position_id: PositionId, settlement_px: Price) {
+        let position = &self.positions[&position_id];
+        let order_side = if position.is_long() {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+
+        self.order_count += 1;
+        self.execution_count += 1;
+        let client_order_id =
+            ClientOrderId::from(format!("EXPIRY-{}-{}", self.raw_id, self.order_count).as_str());
+        let venue_order_id: VenueOrderId = self.generate_id(self.order_count);
+        let trade_id: TradeId = self.generate_id(self.execution_count);
+
+        let position = &self.positions[&position_id];
+        let fill = OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            position.instrument_id,
+            client_order_id,
+            venue_order_id,
+            position.account_id,
+            trade_id,
+            order_side,
+            OrderType::Market,
+            position.quantity,
+            settlement_px,
+            self.instrument.quote_currency(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            self.ts_last,
+            self.ts_last,
+            false,
+            Some(position_id),
+            None, // Cash settlement is not subject to trading fees
+        )
+        .unwrap();
+        self.emit(OrderEvent::OrderFilled(fill));
+    }
+
+    /// Applies the fill to the position it belongs to, opening a new position if required.
+    ///
+    /// Without a venue assigned position ID, fills are netted per strategy.
+    fn update_position(&mut self, mut fill: OrderFilled) {
+        let position_id = fill
+            .position_id
+            .unwrap_or_else(|| netting_position_id(fill.instrument_id, fill.strategy_id));
+        fill.position_id = Some(position_id);
+
+        if let Some(position) = self.positions.get_mut(&position_id) {
+            position.apply(&fill);
+        } else {
+            match Position::new(self.instrument.as_ref(), fill) {
+                Ok(position) => {
+                    self.positions.insert(position_id, position);
+                }
+                Err(e) => {
+                    log::error!("Error opening position {position_id}: {e}");
+                    return;
+                }
+            }
+        }
+
+        let position = &self.positions[&position_id];
+        if position.is_closed() {
+            let event = PositionClosed::create(position, &fill, self.ts_last);
+            self.position_events
+                .push(PositionEvent::PositionClosed(event));
+        }
+
+        self.reduce_reduce_only_orders(position_id);
+    }
+
+    /// Applies the configured self-trade prevention to the aggressing order, returning
+    /// whether it may still trade at `last_px`.
+    fn prevent_self_trade(&mut self, client_order_id: ClientOrderId, last_px: Price) -> bool {
+        let mode = self.config.self_trade_prevention;
+        if mode == SelfTradePrevention::None {
+            return true;
+        }
+
+        let aggressor = &self.orders[&client_order_id];
+        let (trader_id, strategy_id) = (aggressor.trader_id(), aggressor.strategy_id());
+        let side = aggressor.side();
+        let same_strategy_only = self.config.self_trade_scope == SelfTradeScope::Strategy;
+
+        let resting_ids: Vec<ClientOrderId> = self
+            .orders
+            .values()
+            .filter(|o| {
+                o.is_open()
+                    && o.side() != side
+                    && o.trader_id() == trader_id
+                    && (!same_strategy_only || o.strategy_id() == strategy_id)
+                    && (o.trigger_price().is_none() || o.is_triggered())
+            })
+            .filter(|o| match (side, o.price()) {
+                (OrderSide::Buy, Some(price)) => price <= last_px,
+                (_, Some(price)) => price >= last_px,
+                (_, None) => false,
+            })
+            .map(|o| o.client_order_id())
+            .collect();
+
+        if resting_ids.is_empty() {
+            return true;
+        }
+
+        match mode {
+            SelfTradePrevention::None => true,
+            SelfTradePrevention::CancelResting => {
+                for resting_id in resting_ids {
+                    self.cancel_open_order(resting_id);
+                }
+                true
+            }
+            SelfTradePrevention::CancelAggressing => {
+                self.cancel_order(client_order_id);
+                false
+            }
+            SelfTradePrevention::CancelBoth => {
+                for resting_id in resting_ids {
+                    self.cancel_open_order(resting_id);
+                }
+                self.cancel_order(client_order_id);
+                false
+            }
+            SelfTradePrevention::Decrement => {
+                // Orders are only amended once working at the venue
+                if self.orders[&client_order_id].status() == OrderStatus::Submitted {
+                    self.accept_order(client_order_id);
+                }
+                for resting_id in resting_ids {
+                    let aggressor_qty = self.orders[&client_order_id].leaves_qty();
+                    let resting_qty = self.orders[&resting_id].leaves_qty();
+                    let qty = aggressor_qty.min(resting_qty);
+                    self.decrement_order(resting_id, qty);
+                    if !self.decrement_order(client_order_id, qty) {
+                        return false;
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    /// Decrements the open quantity of the order by `qty`, canceling the order if nothing
+    /// remains, and returns whether the order is still open.
+    fn decrement_order(&mut self, client_order_id: ClientOrderId, qty: Quantity) -> bool {
+        let order = &self.orders[&client_order_id];
+        if order.leaves_qty() <= qty {
+            self.cancel_open_order(client_order_id);
+            return false;
+        }
+        let quantity = order.quantity() - qty;
+        self.update_order_qty(client_order_id, quantity);
+        true
+    }
+
+    /// Returns the quantity a reduce-only order may still trade, or `None` if filling
+    /// the order would open or increase its position.
+    fn reduce_only_qty(&self, order: &OrderAny) -> Option<Quantity> {
+        let position = self.positions.get(&order_position_id(order))?;
+        let is_reducing = match order.side() {
+            OrderSide::Buy => position.is_short(),
+            _ => position.is_long(),
+        };
+        is_reducing.then_some(position.quantity)
+    }
+
+    /// Shrinks any open reduce-only orders for the position down to the position size,
+    /// canceling those which can no longer reduce it.
+    fn reduce_reduce_only_orders(&mut self, position_id: PositionId) {
+        let client_order_ids: Vec<ClientOrderId> = self
+            .orders
+            .values()
+            .filter(|o| o.is_open() && o.is_reduce_only() && order_position_id(o) == position_id)
+            .map(|o| o.client_order_id())
+            .collect();
+
+        for client_order_id in client_order_ids {
+            let order = &self.orders[&client_order_id];
+            match self.reduce_only_qty(order) {
+                Some(position_qty) if order.leaves_qty() > position_qty => {
+                    let quantity = order.filled_qty() + position_qty;
+                    self.update_order_qty(client_order_id, quantity);
+                }
+                Some(_) => {}
+                None => self.cancel_open_order(client_order_id),
+            }
+        }
+    }
+
+    /// Applies the event to the tracked order, then buffers it for the caller.
+    ///
+    /// Orders which are closed by the event are no longer tracked by the engine, and
+    /// fills are applied to the engine's positions.
+    fn emit(&mut self, event: OrderEvent) {
+        let client_order_id = event.client_order_id();
+        if let Some(order) = self.orders.get_mut(&client_order_id) {
+            if let Err(e) = order.apply(event.clone()) {
+                log::error!("Error applying event to order {client_order_id}: {e}");
+            }
+            if order.is_closed() {
+                self.orders.shift_remove(&client_order_id);
+                self.queues.shift_remove(&client_order_id);
+            }
+        }
+
+        let fill = match &event {
+            OrderEvent::OrderFilled(fill) => Some(*fill),
+            _ => None,
+        };
+        self.events.push(event);
+
+        if let Some(fill) = fill {
+            self.update_position(fill);
+        }
+    }
+}
+
+fn take_due(queue: &mut Vec<AuctionOrder>, ts_now: UnixNanos) -> Vec<AuctionOrder> {
+    let (due, pending): (Vec<_>, Vec<_>) = queue.drain(..).partition(|a| a.ts_auction <= ts_now);
+    *queue = pending;
+    due
+}
+
+fn netting_position_id(instrument_id: InstrumentId, strategy_id: StrategyId) -> PositionId {
+    PositionId::from(format!("{instrument_id}-{strategy_id}").as_str())
+}
+
+fn order_position_id(order: &OrderAny) -> PositionId {
+    order
+        .position_id()
+        .unwrap_or_else(|| netting_position_id(order.instrument_id(), order.strategy_id()))
+}
+
+fn display_px(price: Option<Price>) -> String {
+    price.map_or_else(|| "None".to_string(), |p| p.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveTime;
+    use chrono_tz::Tz;
+    use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
+    use nautilus_model::{
+        data::order::BookOrder,
+        enums::HaltReason,
+        events::order::{initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder},
+        instruments::{equity::Equity, futures_contract::FuturesContract, stubs::*},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const HOUR: u64 = 3_600 * NANOSECONDS_IN_SECOND;
+    const TUESDAY: u64 = 1_704_153_600 * NANOSECONDS_IN_SECOND; // 2024-01-02 00:00 UTC
+
+    fn engine(instrument: Equity, trading_hours: Option<TradingHours>) -> OrderMatchingEngine {
+        let config = OrderMatchingEngineConfig {
+            trading_hours,
+            ..Default::default()
+        };
+        OrderMatchingEngine::new(
+            Box::new(instrument),
+            1,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            config,
+        )
+    }
+
+    fn trading_hours() -> TradingHours {
+        TradingHours::weekdays(
+            NaiveTime::from_hms_opt(14, 30, 0).unwrap(),
+            NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+            vec![],
+            Tz::UTC,
+        )
+        .unwrap()
+    }
+
+    fn submitted_order(
+        instrument_id: InstrumentId,
+        order_type: OrderType,
+        side: OrderSide,
+        price: Option<&str>,
+        time_in_force: TimeInForce,
+    ) -> OrderAny {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(order_type)
+            .order_side(side)
+            .quantity(Quantity::from(100))
+            .price(price.map(Price::from))
+            .time_in_force(time_in_force)
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .instrument_id(instrument_id)
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn stop_order(
+        instrument_id: InstrumentId,
+        side: OrderSide,
+        trigger_price: &str,
+        trigger_type: TriggerType,
+    ) -> OrderAny {
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .order_type(OrderType::StopMarket)
+            .order_side(side)
+            .quantity(Quantity::from(100))
+            .trigger_price(Some(Price::from(trigger_price)))
+            .trigger_type(Some(trigger_type))
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .instrument_id(instrument_id)
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn flagged_order(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        quantity: i64,
+        price: Option<&str>,
+        post_only: bool,
+        reduce_only: bool,
+    ) -> OrderAny {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let order_type = match price {
+            Some(_) => OrderType::Limit,
+            None => OrderType::Market,
+        };
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(client_order_id)
+            .order_type(order_type)
+            .order_side(side)
+            .quantity(Quantity::from(quantity))
+            .price(price.map(Price::from))
+            .post_only(post_only)
+            .reduce_only(reduce_only)
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order
+    }
+
+    fn cancel(instrument_id: InstrumentId, client_order_id: ClientOrderId) -> CancelOrder {
+        CancelOrder::new(
+            TraderId::default(),
+            None,
+            StrategyId::default(),
+            instrument_id,
+            client_order_id,
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    fn modify(
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> ModifyOrder {
+        ModifyOrder::new(
+            TraderId::default(),
+            None,
+            StrategyId::default(),
+            instrument_id,
+            client_order_id,
+            None,
+            quantity,
+            price,
+            trigger_price,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: UnixNanos) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1_000),
+            Quantity::from(1_000),
+            ts,
+            ts,
+        )
+        .unwrap()
+    }
+
+    fn trade(instrument_id: InstrumentId, price: &str, ts: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(1_000),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts,
+            ts,
+        )
+    }
+
+    fn filled_px(events: &[OrderEvent]) -> Option<Price> {
+        events.iter().find_map(|e| match e {
+            OrderEvent::OrderFilled(fill) => Some(fill.last_px),
+            _ => None,
+        })
+    }
+
+    #[rstest]
+    fn test_market_order_rejected_with_no_market(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_limit_order_rests_then_fills_as_maker(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_trade_tick(&trade(instrument_id, "150.00", 0));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        assert_eq!(engine.get_open_orders().len(), 1);
+
+        engine.process_trade_tick(&trade(instrument_id, "148.50", 1));
+        let events = engine.drain_events();
+
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("149.00"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            _ => panic!("Expected fill"),
+        }
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_at_the_open_rejected_without_session(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::AtTheOpen,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_order_rejected_when_market_closed(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, Some(trading_hours()));
+        engine.process_trade_tick(&trade(instrument_id, "150.00", TUESDAY + 13 * HOUR));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(engine.market_status, MarketStatus::Closed);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_futures_expiry_settles_open_position(futures_contract_es: FuturesContract) {
+        let instrument_id = futures_contract_es.id;
+        let expiration_ns = futures_contract_es.expiration_ns;
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(futures_contract_es),
+            1,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            OrderMatchingEngineConfig::default(),
+        );
+        engine.process_trade_tick(&trade(instrument_id, "4200.00", expiration_ns - HOUR));
+        let market = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::Gtc,
+        );
+        let limit = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("4000.00"),
+            TimeInForce::Gtc,
+        );
+        let limit_id = limit.client_order_id();
+        engine.process_order(market, AccountId::from("SIM-001"));
+        engine.process_order(limit, AccountId::from("SIM-001"));
+        engine.process_trade_tick(&trade(instrument_id, "4210.00", expiration_ns - 1));
+        assert_eq!(engine.get_open_positions().len(), 1);
+        engine.drain_events();
+
+        engine.iterate(expiration_ns);
+        let events = engine.drain_events();
+        let position_events = engine.drain_position_events();
+
+        assert!(engine.is_expired());
+        assert!(!engine.order_exists(&limit_id));
+        assert!(engine.get_open_positions().is_empty());
+        assert!(matches!(events[0], OrderEvent::OrderCanceled(_)));
+        match &events[1] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.order_side, OrderSide::Sell);
+                assert_eq!(fill.last_px, Price::from("4210.00"));
+                assert_eq!(fill.last_qty, Quantity::from(100));
+            }
+            _ => panic!("Expected settlement fill"),
+        }
+        match &position_events[0] {
+            PositionEvent::PositionClosed(closed) => {
+                assert_eq!(closed.instrument_id, instrument_id);
+                assert_eq!(closed.avg_px_closed, 4210.0);
+            }
+            _ => panic!("Expected position closed"),
+        }
+    }
+
+    #[rstest]
+    fn test_futures_expiry_settles_at_mark_price(futures_contract_es: FuturesContract) {
+        let instrument_id = futures_contract_es.id;
+        let expiration_ns = futures_contract_es.expiration_ns;
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(futures_contract_es),
+            1,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            OrderMatchingEngineConfig::default(),
+        );
+        engine.process_trade_tick(&trade(instrument_id, "4200.00", expiration_ns - HOUR));
+        let market = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::Gtc,
+        );
+        engine.process_order(market, AccountId::from("SIM-001"));
+        engine.process_trade_tick(&trade(instrument_id, "4210.00", expiration_ns - 2));
+        engine.process_mark_price(Price::from("4205.25"), expiration_ns - 1);
+        engine.drain_events();
+
+        engine.iterate(expiration_ns);
+        let events = engine.drain_events();
+
+        assert!(engine.get_open_positions().is_empty());
+        match &events[0] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.order_side, OrderSide::Sell);
+                assert_eq!(fill.last_px, Price::from("4205.25"));
+            }
+            _ => panic!("Expected settlement fill"),
+        }
+    }
+
+    #[rstest]
+    fn test_order_rejected_after_expiry(futures_contract_es: FuturesContract) {
+        let instrument_id = futures_contract_es.id;
+        let expiration_ns = futures_contract_es.expiration_ns;
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(futures_contract_es),
+            1,
+            BookType::L1_MBP,
+            OmsType::Netting,
+            OrderMatchingEngineConfig::default(),
+        );
+        engine.iterate(expiration_ns);
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("4000.00"),
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_market_on_open_fills_at_first_price_of_session(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, Some(trading_hours()));
+        engine.process_trade_tick(&trade(instrument_id, "150.00", TUESDAY + 13 * HOUR));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::AtTheOpen,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_trade_tick(&trade(instrument_id, "150.50", TUESDAY + 14 * HOUR));
+        assert_eq!(filled_px(&engine.drain_events()), None);
+
+        engine.process_trade_tick(&trade(instrument_id, "151.00", TUESDAY + 15 * HOUR));
+        let events = engine.drain_events();
+
+        assert_eq!(filled_px(&events), Some(Price::from("151.00")));
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_market_on_close_fills_at_last_price_of_session(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, Some(trading_hours()));
+        engine.process_trade_tick(&trade(instrument_id, "150.00", TUESDAY + 15 * HOUR));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Sell,
+            None,
+            TimeInForce::AtTheClose,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_trade_tick(&trade(instrument_id, "152.00", TUESDAY + 20 * HOUR));
+        engine.process_trade_tick(&trade(instrument_id, "149.00", TUESDAY + 22 * HOUR));
+        let events = engine.drain_events();
+
+        assert_eq!(filled_px(&events), Some(Price::from("152.00")));
+    }
+
+    #[rstest]
+    fn test_limit_on_close_not_marketable_is_canceled(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, Some(trading_hours()));
+        engine.process_trade_tick(&trade(instrument_id, "150.00", TUESDAY + 15 * HOUR));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("140.00"),
+            TimeInForce::AtTheClose,
+        );
+        let client_order_id = order.client_order_id();
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.iterate(TUESDAY + 21 * HOUR);
+        let events = engine.drain_events();
+
+        assert!(matches!(events.last(), Some(OrderEvent::OrderCanceled(_))));
+        assert!(!engine.order_exists(&client_order_id));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, OrderEvent::OrderFilled(_)))
+                .count(),
+            0
+        );
+    }
+
+    #[rstest]
+    fn test_process_cancel_all_cancels_open_orders(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_trade_tick(&trade(instrument_id, "150.00", 0));
+        for (i, price) in ["149.00", "148.00"].iter().enumerate() {
+            let mut order = submitted_order(
+                instrument_id,
+                OrderType::Limit,
+                OrderSide::Buy,
+                Some(price),
+                TimeInForce::Gtc,
+            );
+            if let OrderAny::Limit(limit) = &mut order {
+                limit.client_order_id = ClientOrderId::from(format!("O-{i}").as_str());
+            }
+            engine.process_order(order, AccountId::from("SIM-001"));
+        }
+        assert_eq!(engine.get_open_orders().len(), 2);
+
+        let canceled = engine.process_cancel_all();
+        let events = engine.drain_events();
+
+        assert_eq!(canceled, 2);
+        assert!(engine.get_open_orders().is_empty());
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, OrderEvent::OrderCanceled(_)))
+                .count(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_stop_order_triggers_off_mark_price(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let order = stop_order(
+            instrument_id,
+            OrderSide::Sell,
+            "149.00",
+            TriggerType::MarkPrice,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_mark_price(Price::from("149.50"), 1);
+
+        // Bid trades through the stop but the mark price has not reached it
+        engine.process_quote_tick(&quote(instrument_id, "148.00", "148.10", 2));
+        assert!(filled_px(&engine.drain_events()).is_none());
+
+        engine.process_mark_price(Price::from("148.90"), 3);
+
+        assert_eq!(
+            filled_px(&engine.drain_events()),
+            Some(Price::from("148.00"))
+        );
+        assert_eq!(engine.mark_price(), Some(Price::from("148.90")));
+    }
+
+    #[rstest]
+    fn test_stop_order_triggers_off_last_trade(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let order = stop_order(
+            instrument_id,
+            OrderSide::Buy,
+            "150.05",
+            TriggerType::LastTrade,
+        );
+
+        // Ask is through the stop, so a bid/ask stop would have triggered immediately
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        assert!(filled_px(&events).is_none());
+
+        engine.process_trade_tick(&trade(instrument_id, "150.05", 1));
+
+        assert_eq!(
+            filled_px(&engine.drain_events()),
+            Some(Price::from("150.05"))
+        );
+    }
+
+    #[rstest]
+    fn test_stop_order_rejected_when_in_the_market_for_trigger_type(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        engine.process_index_price(Price::from("151.00"), 0);
+        let order = stop_order(
+            instrument_id,
+            OrderSide::Buy,
+            "150.50",
+            TriggerType::IndexPrice,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_post_only_order_rejected_when_marketable(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let order = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            Some("150.10"),
+            true,
+            false,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_rejected_with_no_position(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let order = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Sell,
+            100,
+            None,
+            false,
+            true,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderRejected(_)));
+    }
+
+    #[rstest]
+    fn test_reduce_only_fill_capped_at_position_size(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        let account_id = AccountId::from("SIM-001");
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let entry = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(entry, account_id);
+        engine.drain_events();
+
+        let exit = flagged_order(
+            instrument_id,
+            "O-2",
+            OrderSide::Sell,
+            150,
+            None,
+            false,
+            true,
+        );
+        engine.process_order(exit, account_id);
+        let events = engine.drain_events();
+
+        let fill_qty = events.iter().find_map(|e| match e {
+            OrderEvent::OrderFilled(fill) => Some(fill.last_qty),
+            _ => None,
+        });
+        assert_eq!(fill_qty, Some(Quantity::from(100)));
+        assert!(matches!(events.last(), Some(OrderEvent::OrderCanceled(_))));
+        assert!(engine.get_open_positions().is_empty());
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_shrinks_with_position(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        let account_id = AccountId::from("SIM-001");
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let entry = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(entry, account_id);
+        let take_profit = flagged_order(
+            instrument_id,
+            "O-2",
+            OrderSide::Sell,
+            100,
+            Some("160.00"),
+            false,
+            true,
+        );
+        engine.process_order(take_profit, account_id);
+        engine.drain_events();
+
+        let partial_exit = flagged_order(
+            instrument_id,
+            "O-3",
+            OrderSide::Sell,
+            60,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(partial_exit, account_id);
+        let events = engine.drain_events();
+
+        let updated_qty = events.iter().find_map(|e| match e {
+            OrderEvent::OrderUpdated(updated) => Some(updated.quantity),
+            _ => None,
+        });
+        assert_eq!(updated_qty, Some(Quantity::from(40)));
+        assert_eq!(engine.get_open_orders()[0].leaves_qty(), Quantity::from(40));
+
+        let final_exit = flagged_order(
+            instrument_id,
+            "O-4",
+            OrderSide::Sell,
+            40,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(final_exit, account_id);
+        let events = engine.drain_events();
+
+        assert!(matches!(events.last(), Some(OrderEvent::OrderCanceled(_))));
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    #[case(SelfTradePrevention::None, Some(100), true)]
+    #[case(SelfTradePrevention::CancelResting, Some(100), false)]
+    #[case(SelfTradePrevention::CancelAggressing, None, true)]
+    #[case(SelfTradePrevention::CancelBoth, None, false)]
+    fn test_self_trade_prevention_modes(
+        equity_aapl: Equity,
+        #[case] mode: SelfTradePrevention,
+        #[case] expected_fill_qty: Option<i64>,
+        #[case] expected_resting_open: bool,
+    ) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.config.self_trade_prevention = mode;
+        let account_id = AccountId::from("SIM-001");
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let resting = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Sell,
+            100,
+            Some("150.10"),
+            false,
+            false,
+        );
+        engine.process_order(resting, account_id);
+        engine.drain_events();
+
+        let aggressor = flagged_order(
+            instrument_id,
+            "O-2",
+            OrderSide::Buy,
+            100,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(aggressor, account_id);
+        let events = engine.drain_events();
+
+        let fill_qty = events.iter().find_map(|e| match e {
+            OrderEvent::OrderFilled(fill) => Some(fill.last_qty),
+            _ => None,
+        });
+        assert_eq!(fill_qty, expected_fill_qty.map(Quantity::from));
+        assert_eq!(
+            engine.order_exists(&ClientOrderId::from("O-1")),
+            expected_resting_open
+        );
+    }
+
+    #[rstest]
+    fn test_self_trade_prevention_decrement(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.config.self_trade_prevention = SelfTradePrevention::Decrement;
+        let account_id = AccountId::from("SIM-001");
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let resting = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Sell,
+            100,
+            Some("150.10"),
+            false,
+            false,
+        );
+        engine.process_order(resting, account_id);
+        engine.drain_events();
+
+        let aggressor = flagged_order(
+            instrument_id,
+            "O-2",
+            OrderSide::Buy,
+            150,
+            None,
+            false,
+            false,
+        );
+        engine.process_order(aggressor, account_id);
+        let events = engine.drain_events();
+
+        let fill_qty = events.iter().find_map(|e| match e {
+            OrderEvent::OrderFilled(fill) => Some(fill.last_qty),
+            _ => None,
+        });
+        assert_eq!(fill_qty, Some(Quantity::from(50)));
+        assert!(!engine.order_exists(&ClientOrderId::from("O-1")));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, OrderEvent::OrderUpdated(u) if u.quantity == Quantity::from(50))));
+    }
+
+    #[rstest]
+    fn test_passive_order_fills_after_queue_ahead(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let config = OrderMatchingEngineConfig {
+            queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl),
+            1,
+            BookType::L3_MBO,
+            OmsType::Netting,
+            config,
+        );
+        let delta = |action, side, price: &str, size: i64, order_id| {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            OrderBookDelta::new(instrument_id, action, order, 0, 0, 0, 0)
+        };
+        let trade = |size: i64| {
+            TradeTick::new(
+                instrument_id,
+                Price::from("149.00"),
+                Quantity::from(size),
+                AggressorSide::Seller,
+                TradeId::from("1"),
+                0,
+                0,
+            )
+        };
+        engine.process_order_book_delta(delta(BookAction::Add, OrderSide::Buy, "149.00", 300, 1));
+        engine.process_order_book_delta(delta(BookAction::Add, OrderSide::Buy, "149.00", 200, 2));
+        engine.process_order_book_delta(delta(BookAction::Add, OrderSide::Sell, "150.00", 500, 3));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+
+        // Trades against the orders ahead, followed by the deltas removing the volume
+        engine.process_trade_tick(&trade(400));
+        engine.process_order_book_delta(delta(
+            BookAction::Delete,
+            OrderSide::Buy,
+            "149.00",
+            300,
+            1,
+        ));
+        engine.process_order_book_delta(delta(
+            BookAction::Update,
+            OrderSide::Buy,
+            "149.00",
+            100,
+            2,
+        ));
+        assert!(filled_px(&engine.drain_events()).is_none());
+
+        // The remaining order ahead increases in size and loses its priority
+        engine.process_order_book_delta(delta(
+            BookAction::Update,
+            OrderSide::Buy,
+            "149.00",
+            300,
+            2,
+        ));
+        engine.process_trade_tick(&trade(60));
+        let events = engine.drain_events();
+
+        match &events[0] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("149.00"));
+                assert_eq!(fill.last_qty, Quantity::from(60));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            _ => panic!("Expected fill"),
+        }
+        assert_eq!(engine.get_open_orders()[0].leaves_qty(), Quantity::from(40));
+    }
+
+    #[rstest]
+    fn test_own_queued_orders_share_trade_volume(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let config = OrderMatchingEngineConfig {
+            queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl),
+            1,
+            BookType::L3_MBO,
+            OmsType::Netting,
+            config,
+        );
+        let add = |side, price: &str, size: i64, order_id| {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            OrderBookDelta::new(instrument_id, BookAction::Add, order, 0, 0, 0, 0)
+        };
+        engine.process_order_book_delta(add(OrderSide::Buy, "149.00", 100, 1));
+        engine.process_order_book_delta(add(OrderSide::Sell, "150.00", 500, 2));
+        for client_order_id in ["O-1", "O-2"] {
+            let order = flagged_order(
+                instrument_id,
+                client_order_id,
+                OrderSide::Buy,
+                100,
+                Some("149.00"),
+                false,
+                false,
+            );
+            engine.process_order(order, AccountId::from("SIM-001"));
+        }
+        engine.drain_events();
+
+        // 100 trades against the book order ahead, leaving 150 for both own orders in turn
+        engine.process_trade_tick(&TradeTick::new(
+            instrument_id,
+            Price::from("149.00"),
+            Quantity::from(250),
+            AggressorSide::Seller,
+            TradeId::from("1"),
+            0,
+            0,
+        ));
+        let fills: Vec<(ClientOrderId, Quantity)> = engine
+            .drain_events()
+            .iter()
+            .filter_map(|e| match e {
+                OrderEvent::OrderFilled(fill) => Some((fill.client_order_id, fill.last_qty)),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            fills,
+            vec![
+                (ClientOrderId::from("O-1"), Quantity::from(100)),
+                (ClientOrderId::from("O-2"), Quantity::from(50)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_modify_limit_price_through_market_fills_as_taker(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.00", 0));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+        let client_order_id = order.client_order_id();
+        engine.process_order(order, AccountId::from("SIM-001"));
+
+        engine.process_modify(&modify(
+            instrument_id,
+            client_order_id,
+            Some(Quantity::from(50)),
+            Some(Price::from("150.50")),
+            None,
+        ));
+        let events = engine.drain_events();
+
+        match &events[1] {
+            OrderEvent::OrderUpdated(updated) => {
+                assert_eq!(updated.quantity, Quantity::from(50));
+                assert_eq!(updated.price, Some(Price::from("150.50")));
+            }
+            _ => panic!("Expected update"),
+        }
+        match &events[2] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("150.00"));
+                assert_eq!(fill.last_qty, Quantity::from(50));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            _ => panic!("Expected fill"),
+        }
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_post_only_order_into_market_is_rejected(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.00", 0));
+        let order = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            Some("149.00"),
+            true,
+            false,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+
+        engine.process_modify(&modify(
+            instrument_id,
+            ClientOrderId::from("O-1"),
+            None,
+            Some(Price::from("150.00")),
+            None,
+        ));
+        let events = engine.drain_events();
+
+        assert!(matches!(events[1], OrderEvent::OrderModifyRejected(_)));
+        assert_eq!(
+            engine.get_open_orders()[0].price(),
+            Some(Price::from("149.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_modify_stop_trigger_price_into_market_is_rejected(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.00", 0));
+        let order = stop_order(instrument_id, OrderSide::Buy, "151.00", TriggerType::BidAsk);
+        let client_order_id = order.client_order_id();
+        engine.process_order(order, AccountId::from("SIM-001"));
+
+        engine.process_modify(&modify(
+            instrument_id,
+            client_order_id,
+            None,
+            None,
+            Some(Price::from("149.50")),
+        ));
+        engine.process_modify(&modify(
+            instrument_id,
+            client_order_id,
+            None,
+            Some(Price::from("151.00")),
+            None,
+        ));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEvent::OrderModifyRejected(_)));
+        assert!(matches!(events[2], OrderEvent::OrderModifyRejected(_)));
+        assert_eq!(
+            engine.get_open_orders()[0].trigger_price(),
+            Some(Price::from("151.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_modify_keeps_queue_priority_unless_repriced(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let config = OrderMatchingEngineConfig {
+            queue_position: true,
+            ..Default::default()
+        };
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl),
+            1,
+            BookType::L3_MBO,
+            OmsType::Netting,
+            config,
+        );
+        let add = |side, price: &str, size: i64, order_id| {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+            OrderBookDelta::new(instrument_id, BookAction::Add, order, 0, 0, 0, 0)
+        };
+        let trade = |price: &str, size: i64| {
+            TradeTick::new(
+                instrument_id,
+                Price::from(price),
+                Quantity::from(size),
+                AggressorSide::Seller,
+                TradeId::from("1"),
+                0,
+                0,
+            )
+        };
+        engine.process_order_book_delta(add(OrderSide::Buy, "149.00", 300, 1));
+        engine.process_order_book_delta(add(OrderSide::Buy, "148.00", 200, 2));
+        engine.process_order_book_delta(add(OrderSide::Sell, "150.00", 500, 3));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+        let client_order_id = order.client_order_id();
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_order_book_delta(add(OrderSide::Buy, "149.00", 200, 4));
+
+        // Reducing the quantity keeps the order ahead of the later book order
+        engine.process_modify(&modify(
+            instrument_id,
+            client_order_id,
+            Some(Quantity::from(80)),
+            None,
+            None,
+        ));
+        engine.process_trade_tick(&trade("149.00", 350));
+        let fills: Vec<Quantity> = engine
+            .drain_events()
+            .iter()
+            .filter_map(|e| match e {
+                OrderEvent::OrderFilled(fill) => Some(fill.last_qty),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills, vec![Quantity::from(50)]);
+
+        // Repricing joins the back of the queue at the new price
+        engine.process_modify(&modify(
+            instrument_id,
+            client_order_id,
+            None,
+            Some(Price::from("148.00")),
+            None,
+        ));
+        engine.process_trade_tick(&trade("148.00", 220));
+        let events = engine.drain_events();
+
+        match events.last() {
+            Some(OrderEvent::OrderFilled(fill)) => {
+                assert_eq!(fill.last_px, Price::from("148.00"));
+                assert_eq!(fill.last_qty, Quantity::from(20));
+            }
+            _ => panic!("Expected fill"),
+        }
+        assert_eq!(engine.get_open_orders()[0].leaves_qty(), Quantity::from(10));
+    }
+
+    #[rstest]
+    #[case(BatchAtomicity::BestEffort, 1)]
+    #[case(BatchAtomicity::AllOrNone, 2)]
+    fn test_process_order_list_atomicity(
+        equity_aapl: Equity,
+        #[case] atomicity: BatchAtomicity,
+        #[case] expected_rejected: usize,
+    ) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.config.order_list_atomicity = atomicity;
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let orders = vec![
+            flagged_order(
+                instrument_id,
+                "O-1",
+                OrderSide::Buy,
+                100,
+                Some("149.00"),
+                true,
+                false,
+            ),
+            // Marketable post-only order is rejected
+            flagged_order(
+                instrument_id,
+                "O-2",
+                OrderSide::Buy,
+                100,
+                Some("151.00"),
+                true,
+                false,
+            ),
+        ];
+
+        engine.process_order_list(orders, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        let rejected = events
+            .iter()
+            .filter(|e| matches!(e, OrderEvent::OrderRejected(_)))
+            .count();
+        assert_eq!(rejected, expected_rejected);
+        assert_eq!(engine.get_open_orders().len(), 2 - expected_rejected);
+    }
+
+    #[rstest]
+    #[case(BatchAtomicity::BestEffort, 1, 1)]
+    #[case(BatchAtomicity::AllOrNone, 0, 2)]
+    fn test_process_batch_cancel_atomicity(
+        equity_aapl: Equity,
+        #[case] atomicity: BatchAtomicity,
+        #[case] expected_canceled: usize,
+        #[case] expected_rejected: usize,
+    ) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.config.order_list_atomicity = atomicity;
+        engine.process_quote_tick(&quote(instrument_id, "150.00", "150.10", 0));
+        let order = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            Some("149.00"),
+            false,
+            false,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.drain_events();
+
+        let canceled = engine.process_batch_cancel(&[
+            cancel(instrument_id, ClientOrderId::from("O-1")),
+            cancel(instrument_id, ClientOrderId::from("O-UNKNOWN")),
+        ]);
+        let events = engine.drain_events();
+
+        assert_eq!(canceled, expected_canceled);
+        assert_eq!(engine.get_open_orders().len(), 1 - expected_canceled);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, OrderEvent::OrderCancelRejected(_)))
+                .count(),
+            expected_rejected
+        );
+    }
+
+    #[rstest]
+    fn test_order_rejected_while_halted(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.10", 0));
+        let status = InstrumentStatus::new(
+            instrument_id,
+            None,
+            MarketStatus::Halt,
+            Some(HaltReason::Volatility),
+            1,
+            1,
+        )
+        .unwrap();
+        engine.process_instrument_status(&status);
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let events = engine.drain_events();
+
+        assert_eq!(engine.market_status, MarketStatus::Halt);
+        match &events[0] {
+            OrderEvent::OrderRejected(rejected) => {
+                assert!(rejected.reason.as_str().starts_with("market halted"));
+            }
+            event => panic!("expected rejection, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_market_order_queued_in_auction_fills_on_open(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_venue_status(&VenueStatus::new(
+            instrument_id.venue,
+            MarketStatus::Auction,
+            0,
+            0,
+        ));
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.10", 1));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Market,
+            OrderSide::Buy,
+            None,
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_quote_tick(&quote(instrument_id, "149.95", "150.05", 2));
+        let queued = engine.drain_events();
+
+        assert_eq!(queued.len(), 1);
+        assert!(matches!(queued[0], OrderEvent::OrderAccepted(_)));
+
+        engine.process_market_status(MarketStatus::Open, 3);
+        let events = engine.drain_events();
+
+        assert_eq!(filled_px(&events), Some(Price::from("150.05")));
+        assert!(engine.get_open_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_resting_order_not_matched_while_halted(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_trade_tick(&trade(instrument_id, "150.00", 0));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.drain_events();
+
+        engine.process_market_status(MarketStatus::Halt, 1);
+        engine.process_trade_tick(&trade(instrument_id, "148.50", 2));
+
+        assert!(engine.drain_events().is_empty());
+        assert_eq!(engine.get_open_orders().len(), 1);
+
+        engine.process_market_status(MarketStatus::Reopen, 3);
+        let events = engine.drain_events();
+
+        assert_eq!(filled_px(&events), Some(Price::from("149.00")));
+    }
+
+    #[rstest]
+    fn test_status_for_other_instrument_ignored(equity_aapl: Equity) {
+        let mut engine = engine(equity_aapl, None);
+        let status = InstrumentStatus::new(
+            InstrumentId::from("MSFT.XNAS"),
+            None,
+            MarketStatus::Halt,
+            None,
+            0,
+            0,
+        )
+        .unwrap();
+
+        engine.process_instrument_status(&status);
+
+        assert_eq!(engine.market_status, MarketStatus::Open);
+    }
+
+    #[rstest]
+    fn test_resting_orders_not_matched_while_market_closed(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.10", 0));
+        let order = submitted_order(
+            instrument_id,
+            OrderType::Limit,
+            OrderSide::Buy,
+            Some("149.00"),
+            TimeInForce::Gtc,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.drain_events();
+
+        engine.process_market_status(MarketStatus::Closed, 1);
+        engine.process_trade_tick(&trade(instrument_id, "148.50", 2));
+
+        assert!(engine.drain_events().is_empty());
+        assert_eq!(engine.get_open_orders().len(), 1);
+
+        engine.process_market_status(MarketStatus::Open, 3);
+        let events = engine.drain_events();
+
+        assert_eq!(filled_px(&events), Some(Price::from("149.00")));
+    }
+
+    #[rstest]
+    fn test_cancel_rejected_for_unknown_or_closed_order(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+        engine.process_quote_tick(&quote(instrument_id, "149.90", "150.10", 0));
+        let order = flagged_order(
+            instrument_id,
+            "O-1",
+            OrderSide::Buy,
+            100,
+            Some("149.00"),
+            false,
+            false,
+        );
+        engine.process_order(order, AccountId::from("SIM-001"));
+        engine.process_cancel(&cancel(instrument_id, ClientOrderId::from("O-1")));
+        engine.drain_events();
+
+        engine.process_cancel(&cancel(instrument_id, ClientOrderId::from("O-1")));
+        engine.process_cancel(&cancel(instrument_id, ClientOrderId::from("O-UNKNOWN")));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            OrderEvent::OrderCancelRejected(rejected) => {
+                assert_eq!(rejected.client_order_id, ClientOrderId::from("O-1"));
+                assert_eq!(rejected.account_id, Some(AccountId::from("SIM-001")));
+            }
+            event => panic!("Expected cancel reject, was {event:?}"),
+        }
+        assert!(matches!(
+            &events[1],
+            OrderEvent::OrderCancelRejected(rejected)
+                if rejected.client_order_id == ClientOrderId::from("O-UNKNOWN")
+        ));
+    }
+
+    #[rstest]
+    fn test_modify_rejected_for_unknown_order(equity_aapl: Equity) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = engine(equity_aapl, None);
+
+        engine.process_modify(&modify(
+            instrument_id,
+            ClientOrderId::from("O-UNKNOWN"),
+            Some(Quantity::from(50)),
+            None,
+            None,
+        ));
+        let events = engine.drain_events();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEvent::OrderModifyRejected(_)));
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, None, vec![("150.00", 30), ("150.10", 50), ("150.20", 20)])]
+    #[case(OrderType::Limit, Some("150.10"), vec![("150.00", 30), ("150.10", 50)])]
+    fn test_taker_order_walks_book_levels(
+        equity_aapl: Equity,
+        #[case] order_type: OrderType,
+        #[case] price: Option<&str>,
+        #[case] expected: Vec<(&str, i64)>,
+    ) {
+        let instrument_id = equity_aapl.id;
+        let mut engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl),
+            1,
+            BookType::L2_MBP,
+            OmsType::Netting,
+            OrderMatchingEngineConfig::default(),
+        );
+        for (i, (price, size)) in [("150.00", 30), ("150.10", 50), ("150.20", 100)]
+            .into_iter()
+            .enumerate()
+        {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(size),
+                i as u64,
+            );
+            let delta = OrderBookDelta::new(instrument_id, BookAction::Add, order, 0, 0, 0, 0);
+            engine.process_order_book_delta(delta);
+        }
+        let order = submitted_order(
+            instrument_id,
+            order_type,
+            OrderSide::Buy,
+            price,
+            TimeInForce::Gtc,
+        );
+
+        engine.process_order(order, AccountId::from("SIM-001"));
+        let fills: Vec<(Price, Quantity)> = engine
+            .drain_events()
+            .iter()
+            .filter_map(|e| match e {
+                OrderEvent::OrderFilled(fill) => Some((fill.last_px, fill.last_qty)),
+                _ => None,
+            })
+            .collect();
+
+        let expected: Vec<(Price, Quantity)> = expected
+            .into_iter()
+            .map(|(px, qty)| (Price::from(px), Quantity::from(qty)))
+            .collect();
+        assert_eq!(fills, expected);
+    }
+}
@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    time::UnixNanos,
+    uuid::UUID4,
+};
+use nautilus_execution::{
+    client::ExecutionClient,
+    messages::{CancelOrder, ModifyOrder, SubmitOrder},
+};
+use nautilus_model::{
+    data::{bar::Bar, delta::OrderBookDelta, quote::QuoteTick, trade::TradeTick, Data},
+    enums::{AccountType, BookType, OmsType},
+    events::{
+        account::state::AccountState,
+        order::{event::OrderEvent, initialized::OrderInitialized},
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, strategy_id::StrategyId, trader_id::TraderId, venue::Venue,
+    },
+    instruments::{
+        betting::BettingInstrument, crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
+        currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
+        options_contract::OptionsContract, Instrument,
+    },
+    orders::any::OrderAny,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+use pyo3::prelude::*;
+
+use crate::sandbox::{SandboxExecutionClient, SandboxExecutionClientConfig};
+
+fn pyobject_to_instrument(py: Python, instrument: PyObject) -> PyResult<Box<dyn Instrument>> {
+    let instrument_type = instrument
+        .getattr(py, "instrument_type")?
+        .extract::<String>(py)?;
+    match instrument_type.as_str() {
+        "BettingInstrument" => Ok(Box::new(instrument.extract::<BettingInstrument>(py)?)),
+        "CryptoFuture" => Ok(Box::new(instrument.extract::<CryptoFuture>(py)?)),
+        "CryptoPerpetual" => Ok(Box::new(instrument.extract::<CryptoPerpetual>(py)?)),
+        "CurrencyPair" => Ok(Box::new(instrument.extract::<CurrencyPair>(py)?)),
+        "Equity" => Ok(Box::new(instrument.extract::<Equity>(py)?)),
+        "FuturesContract" => Ok(Box::new(instrument.extract::<FuturesContract>(py)?)),
+        "OptionsContract" => Ok(Box::new(instrument.extract::<OptionsContract>(py)?)),
+        _ => Err(to_pyvalue_err(format!(
+            "Unsupported instrument type {instrument_type}"
+        ))),
+    }
+}
+
+fn order_event_to_pyobject(py: Python, event: OrderEvent) -> PyObject {
+    match event {
+        OrderEvent::OrderInitialized(e) => e.into_py(py),
+        OrderEvent::OrderDenied(e) => e.into_py(py),
+        OrderEvent::OrderEmulated(e) => e.into_py(py),
+        OrderEvent::OrderReleased(e) => e.into_py(py),
+        OrderEvent::OrderSubmitted(e) => e.into_py(py),
+        OrderEvent::OrderAccepted(e) => e.into_py(py),
+        OrderEvent::OrderRejected(e) => e.into_py(py),
+        OrderEvent::OrderCanceled(e) => e.into_py(py),
+        OrderEvent::OrderExpired(e) => e.into_py(py),
+        OrderEvent::OrderTriggered(e) => e.into_py(py),
+        OrderEvent::OrderPendingUpdate(e) => e.into_py(py),
+        OrderEvent::OrderPendingCancel(e) => e.into_py(py),
+        OrderEvent::OrderModifyRejected(e) => e.into_py(py),
+        OrderEvent::OrderCancelRejected(e) => e.into_py(py),
+        OrderEvent::OrderUpdated(e) => e.into_py(py),
+        OrderEvent::OrderPartiallyFilled(e) | OrderEvent::OrderFilled(e) => e.into_py(py),
+    }
+}
+
+#[pymethods]
+impl SandboxExecutionClient {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        starting_balances: Vec<Money>,
+        oms_type: Option<OmsType>,
+        account_type: Option<AccountType>,
+        book_type: Option<BookType>,
+    ) -> Self {
+        let mut config = SandboxExecutionClientConfig::new(venue, starting_balances);
+        config.oms_type = oms_type.unwrap_or(config.oms_type);
+        config.account_type = account_type.unwrap_or(config.account_type);
+        config.book_type = book_type.unwrap_or(config.book_type);
+        Self::new(client_id, account_id, config)
+    }
+
+    #[getter]
+    #[pyo3(name = "client_id")]
+    fn py_client_id(&self) -> ClientId {
+        self.client_id()
+    }
+
+    #[getter]
+    #[pyo3(name = "account_id")]
+    fn py_account_id(&self) -> AccountId {
+        self.account_id()
+    }
+
+    #[pyo3(name = "add_instrument")]
+    fn py_add_instrument(&mut self, py: Python, instrument: PyObject) -> PyResult<()> {
+        let instrument = pyobject_to_instrument(py, instrument)?;
+        self.add_instrument(instrument).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "process_order_book_delta")]
+    fn py_process_order_book_delta(&mut self, delta: OrderBookDelta) {
+        self.process_data(&Data::Delta(delta));
+    }
+
+    #[pyo3(name = "process_quote_tick")]
+    fn py_process_quote_tick(&mut self, quote: QuoteTick) {
+        self.process_data(&Data::Quote(quote));
+    }
+
+    #[pyo3(name = "process_trade_tick")]
+    fn py_process_trade_tick(&mut self, trade: TradeTick) {
+        self.process_data(&Data::Trade(trade));
+    }
+
+    #[pyo3(name = "process_bar")]
+    fn py_process_bar(&mut self, bar: Bar) {
+        self.process_data(&Data::Bar(bar));
+    }
+
+    #[pyo3(name = "submit_order")]
+    fn py_submit_order(
+        &mut self,
+        trader_id: TraderId,
+        init: OrderInitialized,
+        ts_init: UnixNanos,
+    ) -> PyResult<()> {
+        let order = OrderAny::from(init);
+        let command = SubmitOrder::new(trader_id, None, order, UUID4::new(), ts_init);
+        self.submit_order(&command).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "modify_order")]
+    #[allow(clippy::too_many_arguments)]
+    fn py_modify_order(
+        &mut self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        ts_init: UnixNanos,
+    ) -> PyResult<()> {
+        let command = ModifyOrder::new(
+            trader_id,
+            None,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            None,
+            quantity,
+            price,
+            trigger_price,
+            UUID4::new(),
+            ts_init,
+        );
+        self.modify_order(&command).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "cancel_order")]
+    fn py_cancel_order(
+        &mut self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        ts_init: UnixNanos,
+    ) -> PyResult<()> {
+        let command = CancelOrder::new(
+            trader_id,
+            None,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            None,
+            UUID4::new(),
+            ts_init,
+        );
+        self.cancel_order(&command).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "drain_events")]
+    fn py_drain_events(&mut self, py: Python) -> Vec<PyObject> {
+        self.drain_events()
+            .into_iter()
+            .map(|event| order_event_to_pyobject(py, event))
+            .collect()
+    }
+
+    #[pyo3(name = "drain_account_states")]
+    fn py_drain_account_states(&mut self) -> Vec<AccountState> {
+        self.drain_account_states()
+    }
+
+    #[pyo3(name = "generate_account_state")]
+    fn py_generate_account_state(&self, ts_event: UnixNanos) -> PyResult<AccountState> {
+        self.generate_account_state(ts_event)
+            .map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "balances")]
+    fn py_balances(&self) -> Vec<Money> {
+        self.balances()
+    }
+}
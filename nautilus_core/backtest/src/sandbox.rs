@@ -0,0 +1,777 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An execution client for paper trading against live market data.
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_execution::{
+    client::ExecutionClient,
+    messages::{BatchCancelOrders, CancelOrder, ModifyOrder, SubmitOrder, SubmitOrderList},
+};
+use nautilus_model::{
+    data::Data,
+    enums::{AccountType, BookType, InstrumentClass, OmsType, OrderSide},
+    events::{
+        account::state::AccountState,
+        order::{
+            event::OrderEvent, filled::OrderFilled, rejected::OrderRejected,
+            submitted::OrderSubmitted,
+        },
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, instrument_id::InstrumentId, venue::Venue,
+    },
+    instruments::Instrument,
+    orders::{any::OrderAny, base::Order},
+    reports::{fill::FillReport, order::OrderStatusReport},
+    types::{balance::AccountBalance, currency::Currency, money::Money},
+};
+use rust_decimal::prelude::ToPrimitive;
+
+use ustr::Ustr;
+
+use crate::matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig};
+
+/// Configuration for [`SandboxExecutionClient`] instances.
+#[derive(Clone, Debug)]
+pub struct SandboxExecutionClientConfig {
+    /// The venue to simulate trading on.
+    pub venue: Venue,
+    /// The order management system (OMS) type for the simulated venue.
+    pub oms_type: OmsType,
+    /// The account type reported for the simulated account.
+    pub account_type: AccountType,
+    /// The order book type for the matching engines, which should match the live data.
+    pub book_type: BookType,
+    /// The starting balances for the simulated account.
+    pub starting_balances: Vec<Money>,
+    /// The config for each instruments matching engine.
+    pub matching_engine: OrderMatchingEngineConfig,
+}
+
+impl SandboxExecutionClientConfig {
+    /// Creates a new config for the given `venue` with a netting cash account and L1 books.
+    #[must_use]
+    pub fn new(venue: Venue, starting_balances: Vec<Money>) -> Self {
+        Self {
+            venue,
+            oms_type: OmsType::Netting,
+            account_type: AccountType::Cash,
+            book_type: BookType::L1_MBP,
+            starting_balances,
+            matching_engine: OrderMatchingEngineConfig::default(),
+        }
+    }
+}
+
+/// Provides an execution client for paper trading, which routes orders into an embedded
+/// [`OrderMatchingEngine`] per instrument rather than to the venue.
+///
+/// The live market data for the venue is passed to [`SandboxExecutionClient::process_data`]
+/// to move the simulated markets, so orders are filled against realistic books while the
+/// real account is never touched. Order events are collected as they are generated and
+/// passed on to the execution engine via [`SandboxExecutionClient::drain_events`], along
+/// with the simulated account state following each batch of fills via
+/// [`SandboxExecutionClient::drain_account_states`].
+///
+/// Spot orders are rejected unless the free balance (the total less the balance locked
+/// by other open orders) covers them, so spot balances never go negative.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.backtest", unsendable)
+)]
+pub struct SandboxExecutionClient {
+    client_id: ClientId,
+    account_id: AccountId,
+    config: SandboxExecutionClientConfig,
+    matching_engines: IndexMap<InstrumentId, OrderMatchingEngine>,
+    balances: IndexMap<Currency, Money>,
+    fill_reports: Vec<FillReport>,
+    events: Vec<OrderEvent>,
+    account_states: Vec<AccountState>,
+}
+
+impl SandboxExecutionClient {
+    /// Creates a new [`SandboxExecutionClient`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        config: SandboxExecutionClientConfig,
+    ) -> Self {
+        let balances = config
+            .starting_balances
+            .iter()
+            .map(|balance| (balance.currency, *balance))
+            .collect();
+        Self {
+            client_id,
+            account_id,
+            config,
+            matching_engines: IndexMap::new(),
+            balances,
+            fill_reports: Vec::new(),
+            events: Vec::new(),
+            account_states: Vec::new(),
+        }
+    }
+
+    /// Adds a matching engine for the given `instrument`, which must be for the sandbox venue.
+    pub fn add_instrument(&mut self, instrument: Box<dyn Instrument>) -> Result<()> {
+        let instrument_id = instrument.id();
+        if instrument_id.venue != self.config.venue {
+            bail!(
+                "Cannot add {instrument_id}: not for sandbox venue {}",
+                self.config.venue
+            );
+        }
+        if self.matching_engines.contains_key(&instrument_id) {
+            log::warn!("Instrument {instrument_id} already added");
+            return Ok(());
+        }
+
+        let raw_id = self.matching_engines.len() as u32 + 1;
+        let engine = OrderMatchingEngine::new(
+            instrument,
+            raw_id,
+            self.config.book_type,
+            self.config.oms_type,
+            self.config.matching_engine.clone(),
+        );
+        self.matching_engines.insert(instrument_id, engine);
+        Ok(())
+    }
+
+    /// Returns the matching engine for the given `instrument_id` (if added).
+    #[must_use]
+    pub fn matching_engine(&self, instrument_id: &InstrumentId) -> Option<&OrderMatchingEngine> {
+        self.matching_engines.get(instrument_id)
+    }
+
+    /// Process the given live market `data`, moving the simulated market for its instrument.
+    ///
+    /// Data for instruments which have not been added to the sandbox is ignored.
+    pub fn process_data(&mut self, data: &Data) {
+        let instrument_id = match data {
+            Data::Delta(delta) => delta.instrument_id,
+            Data::Depth10(depth) => depth.instrument_id,
+            Data::Quote(quote) => quote.instrument_id,
+            Data::Trade(trade) => trade.instrument_id,
+            Data::Bar(bar) => bar.bar_type.instrument_id,
+        };
+        let Some(engine) = self.matching_engines.get_mut(&instrument_id) else {
+            return;
+        };
+
+        match data {
+            Data::Delta(delta) => engine.process_order_book_delta(*delta),
+            Data::Quote(quote) => engine.process_quote_tick(quote),
+            Data::Trade(trade) => engine.process_trade_tick(trade),
+            Data::Bar(bar) => engine.process_bar(bar),
+            // The matching engines maintain their books from deltas, quotes and trades
+            Data::Depth10(_) => return,
+        }
+        self.collect_events(&instrument_id);
+    }
+
+    /// Drains the order events generated since the last call, in the order they occurred.
+    pub fn drain_events(&mut self) -> Vec<OrderEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// Drains the account states generated since the last call, one following each batch
+    /// of fills, in the order they occurred.
+    pub fn drain_account_states(&mut self) -> Vec<AccountState> {
+        self.account_states.drain(..).collect()
+    }
+
+    /// Returns the current simulated account balances.
+    #[must_use]
+    pub fn balances(&self) -> Vec<Money> {
+        self.balances.values().copied().collect()
+    }
+
+    /// Generates the current state of the simulated account.
+    ///
+    /// Spot fills exchange the base and quote currency balances, and commissions are
+    /// deducted for all fills. The profit and loss of positions in derivatives is not
+    /// settled into the balances, it is tracked by the portfolio as for a margin account.
+    pub fn generate_account_state(&self, ts_event: UnixNanos) -> Result<AccountState> {
+        let balances = self
+            .balances
+            .values()
+            .map(|total| AccountBalance::new(*total, Money::from_raw(0, total.currency), *total))
+            .collect::<Result<Vec<AccountBalance>, _>>()?;
+        AccountState::new(
+            self.account_id,
+            self.config.account_type,
+            balances,
+            vec![],
+            true,
+            UUID4::new(),
+            ts_event,
+            ts_event,
+            None,
+        )
+    }
+
+    fn matching_engine_mut(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> Result<&mut OrderMatchingEngine> {
+        match self.matching_engines.get_mut(instrument_id) {
+            Some(engine) => Ok(engine),
+            None => bail!("No matching engine for {instrument_id} in sandbox"),
+        }
+    }
+
+    fn submitted(&mut self, mut order: OrderAny, ts_init: UnixNanos) -> Result<OrderAny> {
+        let event = OrderEvent::OrderSubmitted(OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.account_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+        )?);
+        order.apply(event.clone())?;
+        self.events.push(event);
+        Ok(order)
+    }
+
+    fn rejected(&mut self, order: &OrderAny, reason: &str, ts_init: UnixNanos) -> Result<()> {
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            false,
+        )?;
+        self.events.push(OrderEvent::OrderRejected(event));
+        Ok(())
+    }
+
+    /// Returns the balance the given spot `order` requires while open: the quote notional
+    /// (including the taker fee) for a buy, or the base quantity for a sell.
+    ///
+    /// Returns `None` for orders which do not exchange balances, or market buys when there
+    /// is no market to value them at.
+    fn balance_required(&self, order: &OrderAny) -> Option<Money> {
+        let engine = self.matching_engines.get(&order.instrument_id())?;
+        let instrument = &engine.instrument;
+        if instrument.instrument_class() != InstrumentClass::Spot {
+            return None;
+        }
+
+        let quantity = order.leaves_qty();
+        match order.side() {
+            OrderSide::Buy => {
+                let price = order
+                    .price()
+                    .or_else(|| order.trigger_price())
+                    .or_else(|| engine.best_ask_price())?;
+                let notional = instrument.calculate_notional_value(quantity, price, None);
+                let fee = instrument.taker_fee().to_f64().unwrap_or(0.0).max(0.0);
+                Money::new(notional * (1.0 + fee), notional.currency).ok()
+            }
+            // Quantities and money share the same fixed-point scale
+            OrderSide::Sell => Some(Money::from_raw(
+                quantity.raw as i64,
+                instrument.base_currency()?,
+            )),
+            OrderSide::NoOrderSide => None,
+        }
+    }
+
+    /// Returns the reason the given spot `order` must be rejected for insufficient free
+    /// balance (if any).
+    fn check_balance(&self, order: &OrderAny) -> Option<String> {
+        let required = self.balance_required(order)?;
+        let zero = Money::from_raw(0, required.currency);
+        let locked = self
+            .matching_engines
+            .values()
+            .flat_map(OrderMatchingEngine::get_open_orders)
+            .filter_map(|open| self.balance_required(open))
+            .filter(|locked| locked.currency == required.currency)
+            .fold(zero, |total, locked| total + locked);
+        let total = self
+            .balances
+            .get(&required.currency)
+            .copied()
+            .unwrap_or(zero);
+        let free = total - locked;
+
+        if required > free {
+            Some(format!(
+                "Insufficient balance: {required} required, {free} free"
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn collect_events(&mut self, instrument_id: &InstrumentId) {
+        let Some(engine) = self.matching_engines.get_mut(instrument_id) else {
+            return;
+        };
+        let events = engine.drain_events();
+        let mut ts_last_fill = None;
+        for event in &events {
+            if let OrderEvent::OrderFilled(fill) = event {
+                self.apply_fill(fill);
+                ts_last_fill = Some(fill.ts_event);
+            }
+        }
+        self.events.extend(events);
+
+        if let Some(ts_event) = ts_last_fill {
+            match self.generate_account_state(ts_event) {
+                Ok(state) => self.account_states.push(state),
+                Err(e) => log::error!("Error generating sandbox account state: {e}"),
+            }
+        }
+    }
+
+    fn apply_fill(&mut self, fill: &OrderFilled) {
+        let commission = fill
+            .commission
+            .unwrap_or_else(|| Money::from_raw(0, fill.currency));
+        self.fill_reports.push(FillReport::new(
+            fill.account_id,
+            fill.instrument_id,
+            Some(fill.client_order_id),
+            fill.venue_order_id,
+            fill.position_id,
+            fill.trade_id,
+            fill.order_side,
+            fill.last_qty,
+            fill.last_px,
+            commission,
+            fill.liquidity_side,
+            UUID4::new(),
+            fill.ts_event,
+            fill.ts_init,
+        ));
+        self.adjust_balance(-commission);
+
+        let instrument = &self.matching_engines[&fill.instrument_id].instrument;
+        if instrument.instrument_class() != InstrumentClass::Spot {
+            return;
+        }
+        let Some(base_currency) = instrument.base_currency() else {
+            return;
+        };
+        // Quantities and money share the same fixed-point scale
+        let base_qty = Money::from_raw(fill.last_qty.raw as i64, base_currency);
+        let notional = instrument.calculate_notional_value(fill.last_qty, fill.last_px, None);
+        match fill.order_side {
+            OrderSide::Buy => {
+                self.adjust_balance(base_qty);
+                self.adjust_balance(-notional);
+            }
+            OrderSide::Sell => {
+                self.adjust_balance(-base_qty);
+                self.adjust_balance(notional);
+            }
+            OrderSide::NoOrderSide => {}
+        }
+    }
+
+    fn adjust_balance(&mut self, amount: Money) {
+        *self
+            .balances
+            .entry(amount.currency)
+            .or_insert_with(|| Money::from_raw(0, amount.currency)) += amount;
+    }
+}
+
+impl ExecutionClient for SandboxExecutionClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(self.config.venue)
+    }
+
+    fn submit_order(&mut self, command: &SubmitOrder) -> Result<()> {
+        let instrument_id = command.instrument_id();
+        self.matching_engine_mut(&instrument_id)?;
+        let order = self.submitted(command.order.clone(), command.ts_init)?;
+        if let Some(reason) = self.check_balance(&order) {
+            return self.rejected(&order, &reason, command.ts_init);
+        }
+        let account_id = self.account_id;
+        self.matching_engine_mut(&instrument_id)?
+            .process_order(order, account_id);
+        self.collect_events(&instrument_id);
+        Ok(())
+    }
+
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> Result<()> {
+        let instrument_id = command.instrument_id;
+        self.matching_engine_mut(&instrument_id)?;
+        let orders = command
+            .orders
+            .iter()
+            .map(|order| self.submitted(order.clone(), command.ts_init))
+            .collect::<Result<Vec<OrderAny>>>()?;
+        // The list is accepted or rejected as a whole
+        if let Some(reason) = orders.iter().find_map(|order| self.check_balance(order)) {
+            for order in &orders {
+                self.rejected(order, &reason, command.ts_init)?;
+            }
+            return Ok(());
+        }
+        let account_id = self.account_id;
+        self.matching_engine_mut(&instrument_id)?
+            .process_order_list(orders, account_id);
+        self.collect_events(&instrument_id);
+        Ok(())
+    }
+
+    fn modify_order(&mut self, command: &ModifyOrder) -> Result<()> {
+        self.matching_engine_mut(&command.instrument_id)?
+            .process_modify(command);
+        self.collect_events(&command.instrument_id);
+        Ok(())
+    }
+
+    fn cancel_order(&mut self, command: &CancelOrder) -> Result<()> {
+        self.matching_engine_mut(&command.instrument_id)?
+            .process_cancel(command);
+        self.collect_events(&command.instrument_id);
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> Result<()> {
+        self.matching_engine_mut(&command.instrument_id)?
+            .process_batch_cancel(&command.cancels);
+        self.collect_events(&command.instrument_id);
+        Ok(())
+    }
+
+    fn generate_order_status_reports(&self) -> Result<Vec<OrderStatusReport>> {
+        let mut reports = Vec::new();
+        for engine in self.matching_engines.values() {
+            for order in engine.get_open_orders() {
+                let Some(venue_order_id) = order.venue_order_id() else {
+                    continue; // Not yet accepted
+                };
+                let mut report = OrderStatusReport::new(
+                    self.account_id,
+                    order.instrument_id(),
+                    Some(order.client_order_id()),
+                    venue_order_id,
+                    order.side(),
+                    order.order_type(),
+                    order.time_in_force(),
+                    order.status(),
+                    order.quantity(),
+                    order.filled_qty(),
+                    UUID4::new(),
+                    order.ts_init(),
+                    order.ts_last(),
+                    order.ts_last(),
+                );
+                report.price = order.price();
+                report.trigger_price = order.trigger_price();
+                report.avg_px = order.avg_px();
+                reports.push(report);
+            }
+        }
+        Ok(reports)
+    }
+
+    fn generate_fill_reports(&self) -> Result<Vec<FillReport>> {
+        Ok(self.fill_reports.clone())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        enums::{OrderStatus, OrderType},
+        events::order::initialized::OrderInitializedBuilder,
+        identifiers::client_order_id::ClientOrderId,
+        instruments::{currency_pair::CurrencyPair, stubs::*},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn client_with_balance(audusd_sim: CurrencyPair, balance: &str) -> SandboxExecutionClient {
+        let config =
+            SandboxExecutionClientConfig::new(Venue::from("SIM"), vec![Money::from(balance)]);
+        let mut client = SandboxExecutionClient::new(
+            ClientId::from("SANDBOX"),
+            AccountId::from("SIM-001"),
+            config,
+        );
+        client.add_instrument(Box::new(audusd_sim)).unwrap();
+        client
+    }
+
+    #[fixture]
+    fn client(audusd_sim: CurrencyPair) -> SandboxExecutionClient {
+        client_with_balance(audusd_sim, "1000000 USD")
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> Data {
+        Data::Quote(
+            QuoteTick::new(
+                instrument_id,
+                Price::from(bid),
+                Price::from(ask),
+                Quantity::from(1_000_000),
+                Quantity::from(1_000_000),
+                1,
+                1,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submit(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        price: Option<&str>,
+    ) -> SubmitOrder {
+        let order_type = match price {
+            Some(_) => OrderType::Limit,
+            None => OrderType::Market,
+        };
+        let init = OrderInitializedBuilder::default()
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .order_type(order_type)
+            .order_side(side)
+            .quantity(Quantity::from(1_000))
+            .price(price.map(Price::from))
+            .build()
+            .unwrap();
+        let order = OrderAny::from(init);
+        SubmitOrder::new(order.trader_id(), None, order, UUID4::new(), 0)
+    }
+
+    fn cancel(command: &SubmitOrder) -> CancelOrder {
+        CancelOrder::new(
+            command.trader_id,
+            None,
+            command.strategy_id,
+            command.instrument_id(),
+            command.client_order_id(),
+            None,
+            UUID4::new(),
+            0,
+        )
+    }
+
+    #[rstest]
+    fn test_add_instrument_for_other_venue_errors(mut client: SandboxExecutionClient) {
+        let instrument = usdjpy_idealpro();
+
+        assert!(client.add_instrument(Box::new(instrument)).is_err());
+    }
+
+    #[rstest]
+    fn test_submit_order_for_unknown_instrument_errors(mut client: SandboxExecutionClient) {
+        let command = submit(
+            InstrumentId::from("GBP/USD.SIM"),
+            "O-1",
+            OrderSide::Buy,
+            None,
+        );
+
+        assert!(client.submit_order(&command).is_err());
+        assert!(client.drain_events().is_empty());
+    }
+
+    #[rstest]
+    fn test_market_order_fills_against_live_quote(
+        mut client: SandboxExecutionClient,
+        audusd_sim: CurrencyPair,
+    ) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Buy, None);
+
+        client.submit_order(&command).unwrap();
+
+        let events = client.drain_events();
+        assert!(matches!(
+            events.first(),
+            Some(OrderEvent::OrderSubmitted(_))
+        ));
+        let Some(OrderEvent::OrderFilled(fill)) = events.last() else {
+            panic!("Expected fill, was {events:?}");
+        };
+        assert_eq!(fill.account_id, AccountId::from("SIM-001"));
+        assert_eq!(fill.last_px, Price::from("0.80010"));
+        assert_eq!(fill.last_qty, Quantity::from(1_000));
+        assert_eq!(client.generate_fill_reports().unwrap().len(), 1);
+        assert!(client.drain_events().is_empty());
+    }
+
+    #[rstest]
+    fn test_fill_updates_simulated_balances(
+        mut client: SandboxExecutionClient,
+        audusd_sim: CurrencyPair,
+    ) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Buy, None);
+
+        client.submit_order(&command).unwrap();
+        let state = client.generate_account_state(1).unwrap();
+
+        // 1,000 AUD bought for 800.10 USD with a 0.02 USD commission
+        assert_eq!(
+            client.balances(),
+            vec![Money::from("999199.88 USD"), Money::from("1000 AUD")]
+        );
+        assert_eq!(state.account_id, AccountId::from("SIM-001"));
+        assert_eq!(state.balances.len(), 2);
+        assert_eq!(state.balances[0].free, Money::from("999199.88 USD"));
+    }
+
+    #[rstest]
+    fn test_account_state_generated_after_fill(
+        mut client: SandboxExecutionClient,
+        audusd_sim: CurrencyPair,
+    ) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Buy, Some("0.79000"));
+        client.submit_order(&command).unwrap();
+        assert!(client.drain_account_states().is_empty());
+
+        client.process_data(&quote(audusd_sim.id, "0.78990", "0.79000"));
+
+        let states = client.drain_account_states();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].balances.len(), 2);
+        assert_eq!(states[0].balances[1].total, Money::from("1000 AUD"));
+        assert!(client.drain_account_states().is_empty());
+    }
+
+    #[rstest]
+    fn test_sell_without_base_balance_is_rejected(
+        mut client: SandboxExecutionClient,
+        audusd_sim: CurrencyPair,
+    ) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Sell, None);
+
+        client.submit_order(&command).unwrap();
+
+        let events = client.drain_events();
+        assert!(matches!(
+            events.as_slice(),
+            [OrderEvent::OrderSubmitted(_), OrderEvent::OrderRejected(_)]
+        ));
+        assert_eq!(client.balances(), vec![Money::from("1000000 USD")]);
+        assert!(client.generate_fill_reports().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_buy_exceeding_free_balance_is_rejected(audusd_sim: CurrencyPair) {
+        let mut client = client_with_balance(audusd_sim.clone(), "1000 USD");
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+
+        // The first order locks 790 USD of the balance until it fills or is canceled
+        let first = submit(audusd_sim.id, "O-1", OrderSide::Buy, Some("0.79000"));
+        client.submit_order(&first).unwrap();
+        let second = submit(audusd_sim.id, "O-2", OrderSide::Buy, Some("0.79000"));
+        client.submit_order(&second).unwrap();
+
+        let events = client.drain_events();
+        assert!(matches!(events[1], OrderEvent::OrderAccepted(_)));
+        let Some(OrderEvent::OrderRejected(rejected)) = events.last() else {
+            panic!("Expected rejection, was {events:?}");
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-2"));
+        assert_eq!(client.generate_order_status_reports().unwrap().len(), 1);
+    }
+
+    #[rstest]
+    fn test_limit_order_rests_then_fills_when_market_moves(
+        mut client: SandboxExecutionClient,
+        audusd_sim: CurrencyPair,
+    ) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Buy, Some("0.79990"));
+
+        client.submit_order(&command).unwrap();
+        let reports = client.generate_order_status_reports().unwrap();
+        client.process_data(&quote(audusd_sim.id, "0.79980", "0.79990"));
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].order_status, OrderStatus::Accepted);
+        assert_eq!(reports[0].price, Some(Price::from("0.79990")));
+        let events = client.drain_events();
+        assert!(matches!(events[1], OrderEvent::OrderAccepted(_)));
+        assert!(matches!(events.last(), Some(OrderEvent::OrderFilled(_))));
+        assert!(client.generate_order_status_reports().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_cancel_order(mut client: SandboxExecutionClient, audusd_sim: CurrencyPair) {
+        client.process_data(&quote(audusd_sim.id, "0.80000", "0.80010"));
+        let command = submit(audusd_sim.id, "O-1", OrderSide::Buy, Some("0.79000"));
+        client.submit_order(&command).unwrap();
+
+        client.cancel_order(&cancel(&command)).unwrap();
+
+        let events = client.drain_events();
+        assert!(matches!(events.last(), Some(OrderEvent::OrderCanceled(_))));
+
+        // The order is closed so the venue rejects the cancel
+        client.cancel_order(&cancel(&command)).unwrap();
+        let events = client.drain_events();
+        assert!(matches!(
+            events.as_slice(),
+            [OrderEvent::OrderCancelRejected(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_data_for_unknown_instrument_is_ignored(mut client: SandboxExecutionClient) {
+        client.process_data(&quote(
+            InstrumentId::from("GBP/USD.SIM"),
+            "1.20000",
+            "1.20010",
+        ));
+
+        assert!(client.drain_events().is_empty());
+    }
+}
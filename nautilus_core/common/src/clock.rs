@@ -108,7 +108,13 @@ impl TestClock {
             .flat_map(|(_, timer)| timer.advance(to_time_ns))
             .collect();
 
-        timers.sort_by(|a, b| a.ts_event.cmp(&b.ts_event));
+        // Timers are held in a `HashMap` so iteration order varies between runs,
+        // the timer name breaks ties between events at the same `ts_event`
+        timers.sort_by(|a, b| {
+            a.ts_event
+                .cmp(&b.ts_event)
+                .then_with(|| a.name.as_str().cmp(b.name.as_str()))
+        });
         timers
     }
 
@@ -502,4 +508,35 @@ mod tests {
             assert_eq!(test_clock.get_time_ns(), 0);
         });
     }
+
+    #[rstest]
+    fn test_advance_time_orders_tied_events_by_timer_name(mut test_clock: TestClock) {
+        pyo3::prepare_freethreaded_python();
+
+        Python::with_gil(|py| {
+            let py_list = PyList::empty(py);
+            let py_append = Py::from(py_list.getattr("append").unwrap());
+            let handler = EventHandler::new(Some(py_append), None);
+            test_clock.register_default_handler(handler);
+
+            test_clock.set_timer_ns("TIMER_C", 2, 0, None, None);
+            test_clock.set_timer_ns("TIMER_A", 2, 0, None, None);
+            test_clock.set_timer_ns("TIMER_B", 1, 0, None, None);
+            let events = test_clock.advance_time(2, true);
+
+            let names: Vec<(UnixNanos, &str)> = events
+                .iter()
+                .map(|e| (e.ts_event, e.name.as_str()))
+                .collect();
+            assert_eq!(
+                names,
+                [
+                    (1, "TIMER_B"),
+                    (2, "TIMER_A"),
+                    (2, "TIMER_B"),
+                    (2, "TIMER_C"),
+                ]
+            );
+        });
+    }
 }
@@ -0,0 +1,480 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed configuration for running a node without Python.
+//!
+//! A [`NodeConfig`] is loaded from TOML or JSON and schema-checked on deserialization
+//! (unknown fields are rejected and values are parsed to model types), then validated
+//! as a whole so that misconfiguration is reported up front with the offending field.
+
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+use nautilus_model::{
+    enums::{AccountType, BookType, OmsType},
+    identifiers::{instrument_id::InstrumentId, trader_id::TraderId, venue::Venue},
+    types::{currency::Currency, money::Money},
+};
+use serde::{Deserialize, Serialize};
+
+/// Represents a rate limit of `limit` commands per `interval_ns`, configured in the
+/// `"<limit>/HH:MM:SS"` format (for example `"100/00:00:01"`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RateLimitSpec {
+    pub limit: usize,
+    pub interval_ns: u64,
+}
+
+impl FromStr for RateLimitSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("Invalid rate limit '{s}', expected format '<limit>/HH:MM:SS'");
+        let (limit, interval) = s.split_once('/').ok_or_else(err)?;
+        let limit: usize = limit.trim().parse().map_err(|_| err())?;
+
+        let parts: Vec<u64> = interval
+            .split(':')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| err())?;
+        let [hours, mins, secs] = parts[..] else {
+            return Err(err());
+        };
+        let interval_ns = (hours * 3_600 + mins * 60 + secs) * 1_000_000_000;
+
+        if limit == 0 || interval_ns == 0 {
+            return Err(format!(
+                "Invalid rate limit '{s}', limit and interval must be positive"
+            ));
+        }
+        Ok(Self { limit, interval_ns })
+    }
+}
+
+impl TryFrom<String> for RateLimitSpec {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RateLimitSpec> for String {
+    fn from(value: RateLimitSpec) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for RateLimitSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let secs = self.interval_ns / 1_000_000_000;
+        write!(
+            f,
+            "{}/{:02}:{:02}:{:02}",
+            self.limit,
+            secs / 3_600,
+            (secs / 60) % 60,
+            secs % 60
+        )
+    }
+}
+
+/// Configuration for the data engine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DataEngineConfig {
+    /// If time bar aggregators will build and emit bars with no new market updates.
+    pub time_bars_build_with_no_updates: bool,
+    /// If time bar aggregators will timestamp `ts_event` on bar close (else on bar open).
+    pub time_bars_timestamp_on_close: bool,
+    /// If data objects timestamp sequencing will be validated and handled.
+    pub validate_data_sequence: bool,
+    pub debug: bool,
+}
+
+impl Default for DataEngineConfig {
+    fn default() -> Self {
+        Self {
+            time_bars_build_with_no_updates: true,
+            time_bars_timestamp_on_close: true,
+            validate_data_sequence: false,
+            debug: false,
+        }
+    }
+}
+
+/// Configuration for the execution engine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExecEngineConfig {
+    /// If the cache should be loaded on initialization.
+    pub load_cache: bool,
+    /// If unleveraged spot/cash assets should generate positions.
+    pub allow_cash_positions: bool,
+    /// If the cached state should be reconciled with venue reports on startup.
+    pub reconciliation: bool,
+    /// The interval between cache snapshots (if snapshotting is enabled).
+    pub snapshot_interval_ns: Option<u64>,
+    pub debug: bool,
+}
+
+impl Default for ExecEngineConfig {
+    fn default() -> Self {
+        Self {
+            load_cache: true,
+            allow_cash_positions: true,
+            reconciliation: true,
+            snapshot_interval_ns: None,
+            debug: false,
+        }
+    }
+}
+
+/// Configuration for the risk engine.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RiskEngineConfig {
+    /// If all pre-trade risk checks and rate limits are bypassed (duplicate IDs are still checked).
+    pub bypass: bool,
+    /// The maximum rate of submit order commands.
+    pub max_order_submit_rate: RateLimitSpec,
+    /// The maximum rate of modify order commands.
+    pub max_order_modify_rate: RateLimitSpec,
+    /// The maximum notional value of an order per instrument.
+    pub max_notional_per_order: BTreeMap<InstrumentId, f64>,
+    pub debug: bool,
+}
+
+impl Default for RiskEngineConfig {
+    fn default() -> Self {
+        let rate = RateLimitSpec {
+            limit: 100,
+            interval_ns: 1_000_000_000,
+        };
+        Self {
+            bypass: false,
+            max_order_submit_rate: rate,
+            max_order_modify_rate: rate,
+            max_notional_per_order: BTreeMap::new(),
+            debug: false,
+        }
+    }
+}
+
+/// Configuration for a (simulated or live) trading venue.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VenueConfig {
+    pub name: Venue,
+    pub oms_type: OmsType,
+    pub account_type: AccountType,
+    pub starting_balances: Vec<Money>,
+    #[serde(default)]
+    pub base_currency: Option<Currency>,
+    #[serde(default = "default_leverage")]
+    pub default_leverage: f64,
+    #[serde(default)]
+    pub leverages: BTreeMap<InstrumentId, f64>,
+    #[serde(default = "default_book_type")]
+    pub book_type: BookType,
+    #[serde(default = "default_true")]
+    pub bar_execution: bool,
+    #[serde(default = "default_true")]
+    pub reject_stop_orders: bool,
+    #[serde(default = "default_true")]
+    pub support_gtd_orders: bool,
+    #[serde(default = "default_true")]
+    pub support_contingent_orders: bool,
+    #[serde(default = "default_true")]
+    pub use_reduce_only: bool,
+}
+
+fn default_leverage() -> f64 {
+    1.0
+}
+
+fn default_book_type() -> BookType {
+    BookType::L1_MBP
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Configuration for a trading strategy instance.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StrategyConfig {
+    /// The fully qualified name of the strategy.
+    pub strategy_path: String,
+    /// The unique order ID tag for the strategy.
+    #[serde(default)]
+    pub order_id_tag: Option<String>,
+    #[serde(default)]
+    pub oms_type: Option<OmsType>,
+    /// External orders for these instruments will be claimed by the strategy.
+    #[serde(default)]
+    pub external_order_claims: Vec<InstrumentId>,
+    #[serde(default)]
+    pub manage_contingent_orders: bool,
+    #[serde(default)]
+    pub manage_gtd_expiry: bool,
+    /// The strategy specific parameters.
+    #[serde(default)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Configuration for a trading node.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeConfig {
+    pub trader_id: TraderId,
+    #[serde(default)]
+    pub data_engine: DataEngineConfig,
+    #[serde(default)]
+    pub exec_engine: ExecEngineConfig,
+    #[serde(default)]
+    pub risk_engine: RiskEngineConfig,
+    #[serde(default)]
+    pub venues: Vec<VenueConfig>,
+    #[serde(default)]
+    pub strategies: Vec<StrategyConfig>,
+}
+
+impl NodeConfig {
+    /// Parses and validates a config from the given TOML string.
+    pub fn from_toml(s: &str) -> Result<Self> {
+        let config: Self = toml::from_str(s).context("Invalid TOML config")?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses and validates a config from the given JSON string.
+    pub fn from_json(s: &str) -> Result<Self> {
+        let config: Self = serde_json::from_str(s).context("Invalid JSON config")?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Loads and validates a config from the file at `path`, with the format determined
+    /// by its extension (`.toml` or `.json`).
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config {}", path.display()))?;
+        let result = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&contents),
+            Some("json") => Self::from_json(&contents),
+            _ => bail!(
+                "Unsupported config format for {}, expected `.toml` or `.json`",
+                path.display()
+            ),
+        };
+        result.with_context(|| format!("Failed to load config {}", path.display()))
+    }
+
+    /// Validates the config as a whole, reporting every problem found.
+    pub fn validate(&self) -> Result<()> {
+        let mut errors = Vec::new();
+
+        if self.exec_engine.snapshot_interval_ns == Some(0) {
+            errors.push("exec_engine.snapshot_interval_ns: must be positive".to_string());
+        }
+
+        for (instrument_id, notional) in &self.risk_engine.max_notional_per_order {
+            if notional.is_nan() || *notional <= 0.0 {
+                errors.push(format!(
+                    "risk_engine.max_notional_per_order.{instrument_id}: must be positive, was {notional}"
+                ));
+            }
+        }
+
+        let mut venues = Vec::new();
+        for (i, venue) in self.venues.iter().enumerate() {
+            let path = format!("venues[{i}] ({})", venue.name);
+            if venues.contains(&venue.name) {
+                errors.push(format!("{path}: duplicate venue"));
+            }
+            venues.push(venue.name);
+
+            if venue.starting_balances.is_empty() {
+                errors.push(format!("{path}.starting_balances: must not be empty"));
+            }
+            if venue.default_leverage.is_nan() || venue.default_leverage < 1.0 {
+                errors.push(format!(
+                    "{path}.default_leverage: must be at least 1.0, was {}",
+                    venue.default_leverage
+                ));
+            }
+            for (instrument_id, leverage) in &venue.leverages {
+                if instrument_id.venue != venue.name {
+                    errors.push(format!(
+                        "{path}.leverages.{instrument_id}: instrument is not for venue {}",
+                        venue.name
+                    ));
+                }
+                if leverage.is_nan() || *leverage < 1.0 {
+                    errors.push(format!(
+                        "{path}.leverages.{instrument_id}: must be at least 1.0, was {leverage}"
+                    ));
+                }
+            }
+            if venue.account_type == AccountType::Cash && venue.default_leverage != 1.0 {
+                errors.push(format!(
+                    "{path}.default_leverage: must be 1.0 for a `CASH` account"
+                ));
+            }
+        }
+
+        let mut tags = Vec::new();
+        for (i, strategy) in self.strategies.iter().enumerate() {
+            if let Some(tag) = &strategy.order_id_tag {
+                if tags.contains(&tag) {
+                    errors.push(format!(
+                        "strategies[{i}].order_id_tag: duplicate tag '{tag}'"
+                    ));
+                }
+                tags.push(tag);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            bail!("Invalid config:\n  {}", errors.join("\n  "))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const TOML_CONFIG: &str = r#"
+trader_id = "TRADER-001"
+
+[risk_engine]
+max_order_submit_rate = "50/00:00:01"
+max_notional_per_order = { "AUD/USD.SIM" = 1000000.0 }
+
+[[venues]]
+name = "SIM"
+oms_type = "NETTING"
+account_type = "MARGIN"
+starting_balances = ["1000000 USD"]
+default_leverage = 10.0
+
+[[strategies]]
+strategy_path = "examples.ema_cross:EMACross"
+order_id_tag = "001"
+external_order_claims = ["AUD/USD.SIM"]
+params = { fast_ema_period = 10, bar_type = "AUD/USD.SIM-1-MINUTE-BID-INTERNAL" }
+"#;
+
+    #[rstest]
+    fn test_rate_limit_spec_round_trip() {
+        let spec: RateLimitSpec = "100/00:01:30".parse().unwrap();
+
+        assert_eq!(spec.limit, 100);
+        assert_eq!(spec.interval_ns, 90_000_000_000);
+        assert_eq!(spec.to_string(), "100/00:01:30");
+    }
+
+    #[rstest]
+    #[case("100")]
+    #[case("100/00:01")]
+    #[case("0/00:00:01")]
+    #[case("10/00:00:00")]
+    #[case("x/00:00:01")]
+    fn test_rate_limit_spec_invalid(#[case] input: &str) {
+        assert!(input.parse::<RateLimitSpec>().is_err());
+    }
+
+    #[rstest]
+    fn test_node_config_from_toml() {
+        let config = NodeConfig::from_toml(TOML_CONFIG).unwrap();
+
+        assert_eq!(config.trader_id, TraderId::from("TRADER-001"));
+        assert_eq!(config.risk_engine.max_order_submit_rate.limit, 50);
+        assert_eq!(config.risk_engine.max_order_modify_rate.limit, 100);
+        assert_eq!(config.venues[0].oms_type, OmsType::Netting);
+        assert_eq!(config.venues[0].book_type, BookType::L1_MBP);
+        assert_eq!(
+            config.venues[0].starting_balances,
+            vec![Money::from("1000000 USD")]
+        );
+        assert_eq!(config.strategies[0].params["fast_ema_period"], 10);
+        assert!(config.exec_engine.load_cache);
+    }
+
+    #[rstest]
+    fn test_node_config_json_matches_toml() {
+        let config = NodeConfig::from_toml(TOML_CONFIG).unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(NodeConfig::from_json(&json).unwrap(), config);
+    }
+
+    #[rstest]
+    fn test_node_config_rejects_unknown_fields() {
+        let err = NodeConfig::from_json(r#"{"trader_id": "TRADER-001", "venue": []}"#).unwrap_err();
+
+        assert!(format!("{err:#}").contains("unknown field `venue`"));
+    }
+
+    #[rstest]
+    fn test_node_config_rejects_invalid_enum() {
+        let err = NodeConfig::from_json(
+            r#"{"trader_id": "TRADER-001", "venues": [{"name": "SIM", "oms_type": "NETING",
+            "account_type": "CASH", "starting_balances": ["1000 USD"]}]}"#,
+        )
+        .unwrap_err();
+
+        assert!(format!("{err:#}").contains("NETING"));
+    }
+
+    #[rstest]
+    fn test_validate_reports_all_errors() {
+        let config = NodeConfig::from_json(
+            r#"{"trader_id": "TRADER-001", "venues": [
+                {"name": "SIM", "oms_type": "HEDGING", "account_type": "CASH",
+                 "starting_balances": [], "default_leverage": 2.0,
+                 "leverages": {"AUD/USD.OTHER": 0.5}}
+            ]}"#,
+        );
+
+        let err = format!("{:#}", config.unwrap_err());
+        assert!(err.contains("venues[0] (SIM).starting_balances: must not be empty"));
+        assert!(err
+            .contains("venues[0] (SIM).leverages.AUD/USD.OTHER: instrument is not for venue SIM"));
+        assert!(err.contains("venues[0] (SIM).leverages.AUD/USD.OTHER: must be at least 1.0"));
+        assert!(err.contains("must be 1.0 for a `CASH` account"));
+    }
+
+    #[rstest]
+    fn test_from_file_unsupported_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, "").unwrap();
+
+        assert!(NodeConfig::from_file(&path).is_err());
+    }
+}
@@ -29,7 +29,8 @@ use nautilus_model::{
 use ustr::Ustr;
 
 use crate::generators::{
-    client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator,
+    client_order_id::{ClientOrderIdConstraints, ClientOrderIdFormat, ClientOrderIdGenerator},
+    order_list_id::OrderListIdGenerator,
 };
 
 #[repr(C)]
@@ -74,6 +75,22 @@ impl OrderFactory {
         self.order_id_generator.set_count(count);
     }
 
+    /// Sets the format and venue constraints for subsequently generated client order IDs.
+    pub fn set_client_order_id_format(
+        &mut self,
+        format: ClientOrderIdFormat,
+        constraints: ClientOrderIdConstraints,
+    ) {
+        self.order_id_generator = ClientOrderIdGenerator::new(
+            self.trader_id,
+            self.strategy_id,
+            self.order_id_generator.count(),
+            self.clock,
+        )
+        .with_format(format)
+        .with_constraints(constraints);
+    }
+
     pub fn set_order_list_id_count(&mut self, count: usize) {
         self.order_list_id_generator.set_count(count);
     }
@@ -148,7 +165,13 @@ pub mod tests {
     };
     use rstest::rstest;
 
-    use crate::{factories::OrderFactory, stubs::order_factory};
+    use crate::{
+        factories::OrderFactory,
+        generators::client_order_id::{
+            ClientOrderIdAlphabet, ClientOrderIdConstraints, ClientOrderIdFormat,
+        },
+        stubs::order_factory,
+    };
 
     #[rstest]
     fn test_generate_client_order_id(mut order_factory: OrderFactory) {
@@ -178,6 +201,19 @@ pub mod tests {
         );
     }
 
+    #[rstest]
+    fn test_set_client_order_id_format(mut order_factory: OrderFactory) {
+        order_factory.set_client_order_id_count(10);
+        order_factory.set_client_order_id_format(
+            ClientOrderIdFormat::Timestamped {
+                prefix: "N".to_string(),
+            },
+            ClientOrderIdConstraints::new(ClientOrderIdAlphabet::Alphanumeric, Some(36)),
+        );
+        let client_order_id = order_factory.generate_client_order_id();
+        assert_eq!(client_order_id, ClientOrderId::new("N011").unwrap());
+    }
+
     #[rstest]
     fn test_set_order_list_id_count(mut order_factory: OrderFactory) {
         order_factory.set_order_list_id_count(10);
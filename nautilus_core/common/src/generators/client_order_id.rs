@@ -13,19 +13,110 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::time::AtomicTime;
+use anyhow::bail;
+use nautilus_core::{time::AtomicTime, uuid::UUID4};
 use nautilus_model::identifiers::{
     client_order_id::ClientOrderId, strategy_id::StrategyId, trader_id::TraderId,
 };
 
 use super::get_datetime_tag;
 
+/// The format used by a [`ClientOrderIdGenerator`] to build new client order IDs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ClientOrderIdFormat {
+    /// `O-{YYYYMMDD}-{HHMM}-{trader_tag}-{strategy_tag}-{count}`.
+    #[default]
+    Sequential,
+    /// A random UUID v4, with or without the hyphens.
+    Uuid { hyphens: bool },
+    /// `{prefix}{unix_ms}{count}`, unique across restarts without restoring the count.
+    Timestamped { prefix: String },
+}
+
+/// The set of characters a venue accepts in a client order ID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClientOrderIdAlphabet {
+    /// Any characters valid for a `ClientOrderId`.
+    #[default]
+    Any,
+    /// ASCII letters and digits only.
+    Alphanumeric,
+    /// ASCII digits only.
+    Numeric,
+}
+
+impl ClientOrderIdAlphabet {
+    #[must_use]
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Alphanumeric => c.is_ascii_alphanumeric(),
+            Self::Numeric => c.is_ascii_digit(),
+        }
+    }
+}
+
+/// Venue specific constraints for client order IDs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientOrderIdConstraints {
+    pub alphabet: ClientOrderIdAlphabet,
+    pub max_length: Option<usize>,
+}
+
+impl ClientOrderIdConstraints {
+    #[must_use]
+    pub fn new(alphabet: ClientOrderIdAlphabet, max_length: Option<usize>) -> Self {
+        Self {
+            alphabet,
+            max_length,
+        }
+    }
+
+    /// Conforms the given `id` to the constraints.
+    ///
+    /// Characters outside the alphabet are removed, then the ID is truncated from the
+    /// left so that the trailing count (which makes generated IDs unique) is kept.
+    #[must_use]
+    pub fn apply(&self, id: &str) -> String {
+        let chars: Vec<char> = id.chars().filter(|c| self.alphabet.contains(*c)).collect();
+        let start = match self.max_length {
+            Some(max_length) if chars.len() > max_length => chars.len() - max_length,
+            _ => 0,
+        };
+        chars[start..].iter().collect()
+    }
+
+    /// Checks the given `id` satisfies the constraints.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `id` contains a character outside the alphabet.
+    /// - If `id` is longer than the maximum length.
+    pub fn validate(&self, id: &str) -> anyhow::Result<()> {
+        if let Some(c) = id.chars().find(|c| !self.alphabet.contains(*c)) {
+            bail!(
+                "Invalid client order ID '{id}': character '{c}' not in the {:?} alphabet",
+                self.alphabet
+            );
+        }
+        if let Some(max_length) = self.max_length {
+            if id.chars().count() > max_length {
+                bail!("Invalid client order ID '{id}': length exceeds maximum of {max_length}");
+            }
+        }
+        Ok(())
+    }
+}
+
 #[repr(C)]
 pub struct ClientOrderIdGenerator {
     clock: &'static AtomicTime,
     trader_id: TraderId,
     strategy_id: StrategyId,
     count: usize,
+    format: ClientOrderIdFormat,
+    constraints: ClientOrderIdConstraints,
 }
 
 impl ClientOrderIdGenerator {
@@ -41,9 +132,23 @@ impl ClientOrderIdGenerator {
             strategy_id,
             count: initial_count,
             clock,
+            format: ClientOrderIdFormat::default(),
+            constraints: ClientOrderIdConstraints::default(),
         }
     }
 
+    #[must_use]
+    pub fn with_format(mut self, format: ClientOrderIdFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub fn with_constraints(mut self, constraints: ClientOrderIdConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
     pub fn set_count(&mut self, count: usize) {
         self.count = count;
     }
@@ -57,16 +162,41 @@ impl ClientOrderIdGenerator {
         self.count
     }
 
+    #[must_use]
+    pub fn format(&self) -> &ClientOrderIdFormat {
+        &self.format
+    }
+
+    #[must_use]
+    pub fn constraints(&self) -> ClientOrderIdConstraints {
+        self.constraints
+    }
+
     pub fn generate(&mut self) -> ClientOrderId {
-        let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
-        let trader_tag = self.trader_id.get_tag();
-        let strategy_tag = self.strategy_id.get_tag();
         self.count += 1;
-        let id = format!(
-            "O-{}-{}-{}-{}",
-            datetime_tag, trader_tag, strategy_tag, self.count
-        );
-        ClientOrderId::from(id.as_str())
+        let id = match &self.format {
+            ClientOrderIdFormat::Sequential => {
+                let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
+                let trader_tag = self.trader_id.get_tag();
+                let strategy_tag = self.strategy_id.get_tag();
+                format!(
+                    "O-{}-{}-{}-{}",
+                    datetime_tag, trader_tag, strategy_tag, self.count
+                )
+            }
+            ClientOrderIdFormat::Uuid { hyphens } => {
+                let uuid = UUID4::new().to_string();
+                if *hyphens {
+                    uuid
+                } else {
+                    uuid.replace('-', "")
+                }
+            }
+            ClientOrderIdFormat::Timestamped { prefix } => {
+                format!("{}{}{}", prefix, self.clock.get_time_ms(), self.count)
+            }
+        };
+        ClientOrderId::from(self.constraints.apply(&id).as_str())
     }
 }
 
@@ -81,7 +211,10 @@ mod tests {
     };
     use rstest::rstest;
 
-    use crate::generators::client_order_id::ClientOrderIdGenerator;
+    use crate::generators::client_order_id::{
+        ClientOrderIdAlphabet, ClientOrderIdConstraints, ClientOrderIdFormat,
+        ClientOrderIdGenerator,
+    };
 
     fn get_client_order_id_generator(initial_count: Option<usize>) -> ClientOrderIdGenerator {
         let trader_id = TraderId::from("TRADER-001");
@@ -161,4 +294,60 @@ mod tests {
             ClientOrderId::new("O-19700101-0000-001-001-1").unwrap()
         );
     }
+
+    #[rstest]
+    fn test_generate_uuid_without_hyphens() {
+        let mut generator = get_client_order_id_generator(None)
+            .with_format(ClientOrderIdFormat::Uuid { hyphens: false });
+        let result1 = generator.generate();
+        let result2 = generator.generate();
+
+        assert_eq!(result1.value.as_str().len(), 32);
+        assert!(!result1.value.as_str().contains('-'));
+        assert_ne!(result1, result2);
+        assert_eq!(generator.count(), 2);
+    }
+
+    #[rstest]
+    fn test_generate_timestamped() {
+        let mut generator =
+            get_client_order_id_generator(Some(41)).with_format(ClientOrderIdFormat::Timestamped {
+                prefix: "N".to_string(),
+            });
+        let result = generator.generate();
+
+        assert_eq!(result, ClientOrderId::new("N042").unwrap());
+    }
+
+    #[rstest]
+    fn test_generate_with_constraints_keeps_trailing_count() {
+        let constraints = ClientOrderIdConstraints::new(ClientOrderIdAlphabet::Numeric, Some(12));
+        let mut generator = get_client_order_id_generator(Some(99)).with_constraints(constraints);
+        let result = generator.generate();
+
+        assert_eq!(result, ClientOrderId::new("000001001100").unwrap());
+        assert!(constraints.validate(result.value.as_str()).is_ok());
+    }
+
+    #[rstest]
+    #[case(ClientOrderIdAlphabet::Any, "O-1_a", true)]
+    #[case(ClientOrderIdAlphabet::Alphanumeric, "O1a", true)]
+    #[case(ClientOrderIdAlphabet::Alphanumeric, "O-1a", false)]
+    #[case(ClientOrderIdAlphabet::Numeric, "0123", true)]
+    #[case(ClientOrderIdAlphabet::Numeric, "01a3", false)]
+    fn test_constraints_validate_alphabet(
+        #[case] alphabet: ClientOrderIdAlphabet,
+        #[case] id: &str,
+        #[case] expected: bool,
+    ) {
+        let constraints = ClientOrderIdConstraints::new(alphabet, None);
+        assert_eq!(constraints.validate(id).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_constraints_validate_max_length() {
+        let constraints = ClientOrderIdConstraints::new(ClientOrderIdAlphabet::Any, Some(4));
+        assert!(constraints.validate("O-12").is_ok());
+        assert!(constraints.validate("O-123").is_err());
+    }
 }
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{fmt, sync::Arc};
+use std::{any::Any, fmt, sync::Arc};
 
 use nautilus_core::message::Message;
 use pyo3::{ffi, prelude::*};
@@ -42,6 +42,13 @@ pub struct SafeMessageCallback {
 unsafe impl Send for SafeMessageCallback {}
 unsafe impl Sync for SafeMessageCallback {}
 
+/// A callback for typed payloads published on the message bus (such as market data),
+/// which receivers downcast to the concrete type they expect.
+#[derive(Clone)]
+pub struct SafeAnyCallback {
+    pub callback: Arc<dyn Fn(&dyn Any) + Send + Sync>,
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct SafeTimeEventCallback {
@@ -60,6 +67,7 @@ unsafe impl Sync for SafeTimeEventCallback {}
 pub struct MessageHandler {
     pub handler_id: Ustr,
     _callback: Option<SafeMessageCallback>,
+    any_callback: Option<SafeAnyCallback>,
 }
 
 impl MessageHandler {
@@ -69,6 +77,24 @@ impl MessageHandler {
         Self {
             handler_id,
             _callback: callback,
+            any_callback: None,
+        }
+    }
+
+    /// Creates a new handler which receives typed payloads via [`MessageHandler::handle_any`].
+    #[must_use]
+    pub fn with_any_callback(handler_id: Ustr, callback: SafeAnyCallback) -> Self {
+        Self {
+            handler_id,
+            _callback: None,
+            any_callback: Some(callback),
+        }
+    }
+
+    /// Passes the given `message` to the handlers typed callback (if any).
+    pub fn handle_any(&self, message: &dyn Any) {
+        if let Some(any_callback) = &self.any_callback {
+            (any_callback.callback)(message);
         }
     }
 }
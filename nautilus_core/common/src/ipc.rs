@@ -0,0 +1,360 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A Unix domain socket transport for streaming message bus messages to other processes
+//! on the same host.
+//!
+//! The trading process binds a socket at the configured `ipc_path` and forwards every
+//! externally published message to each connected subscriber whose topic patterns match,
+//! without the buffering and round trip of a Redis stream.
+//!
+//! All frames are a little-endian `u32` length prefix followed by the bytes. On connect a
+//! subscriber sends one frame containing a JSON array of topic patterns (which may use
+//! the `*` and `?` wildcards), after which it receives a topic frame then a payload frame
+//! for each matching message.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind, Read, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::{Path, PathBuf},
+    sync::mpsc::{Receiver, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::trader_id::TraderId;
+use serde_json::Value;
+use ustr::Ustr;
+
+use crate::msgbus::{is_matching, BusMessage};
+
+const MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(10);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(1);
+const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Returns the socket path from the message bus `config`, defaulting to a path in the
+/// temporary directory named for the trader and instance.
+#[must_use]
+pub fn get_ipc_path(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: &HashMap<String, Value>,
+) -> PathBuf {
+    match config.get("ipc_path").and_then(Value::as_str) {
+        Some(path) if !path.is_empty() => PathBuf::from(path),
+        _ => std::env::temp_dir().join(format!("nautilus-{trader_id}-{instance_id}.sock")),
+    }
+}
+
+/// Runs the IPC transport until the message bus hangs up the channel.
+pub fn handle_messages_with_ipc(
+    rx: Receiver<BusMessage>,
+    trader_id: TraderId,
+    instance_id: UUID4,
+    config: HashMap<String, Value>,
+) {
+    let path = get_ipc_path(trader_id, instance_id, &config);
+    let mut server = match IpcServer::bind(&path) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!(
+                "Error binding message bus IPC socket {}: {e}",
+                path.display()
+            );
+            return;
+        }
+    };
+
+    let mut last_accept = Instant::now();
+    loop {
+        if last_accept.elapsed() >= ACCEPT_INTERVAL {
+            server.accept_pending();
+            last_accept = Instant::now();
+        }
+
+        match rx.recv_timeout(ACCEPT_INTERVAL) {
+            Ok(msg) => server.publish(&msg),
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break, // Channel hung up
+        }
+    }
+}
+
+struct IpcConnection {
+    stream: UnixStream,
+    patterns: Vec<Ustr>,
+}
+
+impl IpcConnection {
+    fn is_subscribed(&self, topic: &Ustr) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| is_matching(topic, pattern))
+    }
+}
+
+/// Provides the listening side of the IPC transport.
+pub struct IpcServer {
+    listener: UnixListener,
+    path: PathBuf,
+    connections: Vec<IpcConnection>,
+}
+
+impl IpcServer {
+    /// Binds a new [`IpcServer`] at the given socket `path`, replacing any stale socket.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the socket cannot be bound.
+    pub fn bind(path: &Path) -> io::Result<Self> {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            path: path.to_path_buf(),
+            connections: Vec::new(),
+        })
+    }
+
+    /// Returns the count of connected subscribers.
+    #[must_use]
+    pub fn subscriber_count(&self) -> usize {
+        self.connections.len()
+    }
+
+    /// Accepts all pending subscriber connections, reading their topic patterns.
+    pub fn accept_pending(&mut self) {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => match Self::handshake(stream) {
+                    Ok(connection) => self.connections.push(connection),
+                    Err(e) => eprintln!("Error accepting message bus IPC subscriber: {e}"),
+                },
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    eprintln!("Error accepting message bus IPC subscriber: {e}");
+                    break;
+                }
+            }
+        }
+    }
+
+    fn handshake(mut stream: UnixStream) -> io::Result<IpcConnection> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+
+        let frame = read_frame(&mut stream)?;
+        let patterns: Vec<String> = serde_json::from_slice(&frame)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+        Ok(IpcConnection {
+            stream,
+            patterns: patterns.iter().map(|p| Ustr::from(p)).collect(),
+        })
+    }
+
+    /// Sends the given `msg` to every subscriber with a matching topic pattern.
+    ///
+    /// Subscribers which have disconnected, or which cannot keep up within the write
+    /// timeout, are dropped.
+    pub fn publish(&mut self, msg: &BusMessage) {
+        let topic = Ustr::from(&msg.topic);
+        self.connections.retain_mut(|connection| {
+            if !connection.is_subscribed(&topic) {
+                return true;
+            }
+            let result = write_frame(&mut connection.stream, msg.topic.as_bytes())
+                .and_then(|()| write_frame(&mut connection.stream, &msg.payload));
+            result.is_ok()
+        });
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Provides a subscriber for messages published over the IPC transport.
+pub struct IpcSubscriber {
+    stream: UnixStream,
+}
+
+impl IpcSubscriber {
+    /// Connects to the IPC socket at `path`, subscribing to the given topic `patterns`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the socket cannot be connected to.
+    pub fn connect(path: &Path, patterns: &[&str]) -> io::Result<Self> {
+        let mut stream = UnixStream::connect(path)?;
+        let handshake =
+            serde_json::to_vec(patterns).map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+        write_frame(&mut stream, &handshake)?;
+
+        Ok(Self { stream })
+    }
+
+    /// Sets the timeout for [`IpcSubscriber::recv`] (blocks indefinitely if `None`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the timeout cannot be set on the socket.
+    pub fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.stream.set_read_timeout(timeout)
+    }
+
+    /// Receives the next message, blocking until one is available.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the socket is closed or a frame is invalid.
+    pub fn recv(&mut self) -> io::Result<BusMessage> {
+        let topic = String::from_utf8(read_frame(&mut self.stream)?)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        let payload = read_frame(&mut self.stream)?;
+
+        Ok(BusMessage { topic, payload })
+    }
+}
+
+fn write_frame(writer: &mut impl Write, bytes: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| io::Error::new(ErrorKind::InvalidInput, "Frame too large"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+fn read_frame(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes)?;
+
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("Frame length {len} exceeds maximum {MAX_FRAME_LEN}"),
+        ));
+    }
+
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn message(topic: &str, payload: &[u8]) -> BusMessage {
+        BusMessage {
+            topic: topic.to_string(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[rstest]
+    fn test_frame_roundtrip() {
+        let mut buffer = Vec::new();
+        write_frame(&mut buffer, b"hello").unwrap();
+
+        let result = read_frame(&mut buffer.as_slice()).unwrap();
+
+        assert_eq!(buffer.len(), 9);
+        assert_eq!(result, b"hello");
+    }
+
+    #[rstest]
+    fn test_read_frame_exceeding_max_length() {
+        let bytes = u32::MAX.to_le_bytes();
+        assert!(read_frame(&mut bytes.as_slice()).is_err());
+    }
+
+    #[rstest]
+    fn test_get_ipc_path() {
+        let trader_id = TraderId::from("TRADER-001");
+        let instance_id = UUID4::new();
+        let config = HashMap::from([("ipc_path".to_string(), Value::from("/tmp/bus.sock"))]);
+
+        assert_eq!(
+            get_ipc_path(trader_id, instance_id, &config),
+            PathBuf::from("/tmp/bus.sock")
+        );
+        assert!(get_ipc_path(trader_id, instance_id, &HashMap::new())
+            .ends_with(format!("nautilus-TRADER-001-{instance_id}.sock")));
+    }
+
+    #[rstest]
+    fn test_subscriber_receives_matching_messages() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.sock");
+        let mut server = IpcServer::bind(&path).unwrap();
+        let mut subscriber = IpcSubscriber::connect(&path, &["events.order.*"]).unwrap();
+        subscriber
+            .set_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+
+        server.accept_pending();
+        server.publish(&message("data.quotes.SIM.AUD/USD", b"quote"));
+        server.publish(&message("events.order.S-001", b"filled"));
+
+        let received = subscriber.recv().unwrap();
+        assert_eq!(server.subscriber_count(), 1);
+        assert_eq!(received.topic, "events.order.S-001");
+        assert_eq!(received.payload, b"filled");
+    }
+
+    #[rstest]
+    fn test_disconnected_subscriber_is_dropped() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.sock");
+        let mut server = IpcServer::bind(&path).unwrap();
+        let subscriber = IpcSubscriber::connect(&path, &["*"]).unwrap();
+        server.accept_pending();
+
+        drop(subscriber);
+        server.publish(&message("events.order.S-001", &[0u8; 1024]));
+        server.publish(&message("events.order.S-001", &[0u8; 1024]));
+
+        assert_eq!(server.subscriber_count(), 0);
+    }
+
+    #[rstest]
+    fn test_server_removes_socket_on_drop() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("msgbus.sock");
+        let server = IpcServer::bind(&path).unwrap();
+        assert!(path.exists());
+
+        drop(server);
+
+        assert!(!path.exists());
+    }
+}
@@ -14,12 +14,17 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod clock;
+pub mod config;
 pub mod enums;
 pub mod factories;
 pub mod generators;
 pub mod handlers;
+#[cfg(unix)]
+pub mod ipc;
 pub mod logging;
+pub mod monitor;
 pub mod msgbus;
+pub mod scheduler;
 pub mod testing;
 pub mod timer;
 
@@ -0,0 +1,344 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Heartbeat monitoring for live system components.
+//!
+//! Components (such as adapter clients) report heartbeats to a [`ComponentMonitor`], which
+//! is checked periodically. A component which misses too many heartbeat intervals is marked
+//! `DEGRADED` and then `FAULTED`, with each state change published on the message bus so
+//! that silent stalls are surfaced before orders start to fail.
+
+use std::{cell::RefCell, fmt::Display, rc::Rc};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{enums::TradingState, identifiers::component_id::ComponentId};
+
+use crate::{enums::ComponentState, msgbus::MessageBus};
+
+/// The message bus endpoint the monitor sends trading state commands to.
+pub const RISK_ENGINE_TRADING_STATE_ENDPOINT: &str = "RiskEngine.set_trading_state";
+
+/// Configuration for [`ComponentMonitor`] instances.
+#[derive(Clone, Debug)]
+pub struct ComponentMonitorConfig {
+    /// The interval at which components are expected to report heartbeats.
+    pub heartbeat_interval_ns: u64,
+    /// The number of missed intervals after which a component is `DEGRADED`.
+    pub degraded_after: u32,
+    /// The number of missed intervals after which a component is `FAULTED`.
+    pub faulted_after: u32,
+    /// If trading should be halted (via the risk engine) when any component faults.
+    pub halt_on_fault: bool,
+}
+
+impl Default for ComponentMonitorConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval_ns: 1_000_000_000,
+            degraded_after: 3,
+            faulted_after: 10,
+            halt_on_fault: false,
+        }
+    }
+}
+
+/// Represents a change in the monitored state of a component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComponentStateChanged {
+    pub component_id: ComponentId,
+    pub state: ComponentState,
+    pub previous_state: ComponentState,
+    pub missed_intervals: u32,
+    pub ts_event: UnixNanos,
+}
+
+impl Display for ComponentStateChanged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ComponentStateChanged(component_id={}, state={}, previous_state={}, missed_intervals={}, ts_event={})",
+            self.component_id,
+            self.state,
+            self.previous_state,
+            self.missed_intervals,
+            self.ts_event,
+        )
+    }
+}
+
+/// Returns the message bus topic state changes for the given `component_id` are published on.
+#[must_use]
+pub fn component_state_topic(component_id: &ComponentId) -> String {
+    format!("events.system.{component_id}")
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MonitoredComponent {
+    state: ComponentState,
+    last_heartbeat: UnixNanos,
+}
+
+/// Monitors the heartbeats of registered components.
+///
+/// The monitor holds no timer of its own, `check` should be called from a timer
+/// set at (or more frequently than) the heartbeat interval.
+pub struct ComponentMonitor {
+    config: ComponentMonitorConfig,
+    components: IndexMap<ComponentId, MonitoredComponent>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl ComponentMonitor {
+    #[must_use]
+    pub fn new(config: ComponentMonitorConfig, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        assert!(
+            config.heartbeat_interval_ns > 0,
+            "`heartbeat_interval_ns` must be positive"
+        );
+        assert!(
+            config.degraded_after <= config.faulted_after,
+            "`degraded_after` must not exceed `faulted_after`"
+        );
+        Self {
+            config,
+            components: IndexMap::new(),
+            msgbus,
+        }
+    }
+
+    /// Registers the component with the given `component_id`, treating registration
+    /// as its first heartbeat.
+    pub fn register(&mut self, component_id: ComponentId, ts_now: UnixNanos) -> Result<()> {
+        if self.components.contains_key(&component_id) {
+            bail!("Component {component_id} already registered");
+        }
+
+        self.components.insert(
+            component_id,
+            MonitoredComponent {
+                state: ComponentState::Running,
+                last_heartbeat: ts_now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Deregisters the component with the given `component_id`.
+    pub fn deregister(&mut self, component_id: &ComponentId) -> Result<()> {
+        if self.components.shift_remove(component_id).is_none() {
+            bail!("Component {component_id} not registered");
+        }
+        Ok(())
+    }
+
+    /// Returns the IDs of all registered components.
+    #[must_use]
+    pub fn components(&self) -> Vec<ComponentId> {
+        self.components.keys().copied().collect()
+    }
+
+    /// Returns the monitored state of the component with the given `component_id` (if registered).
+    #[must_use]
+    pub fn state(&self, component_id: &ComponentId) -> Option<ComponentState> {
+        self.components.get(component_id).map(|c| c.state)
+    }
+
+    /// Returns whether every registered component is `RUNNING`.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.components
+            .values()
+            .all(|c| c.state == ComponentState::Running)
+    }
+
+    /// Records a heartbeat for the component with the given `component_id`.
+    ///
+    /// A `DEGRADED` or `FAULTED` component which reports a heartbeat is restored to `RUNNING`.
+    pub fn heartbeat(&mut self, component_id: &ComponentId, ts_now: UnixNanos) -> Result<()> {
+        let Some(component) = self.components.get_mut(component_id) else {
+            bail!("Cannot record heartbeat: component {component_id} not registered");
+        };
+
+        component.last_heartbeat = ts_now;
+        if component.state != ComponentState::Running {
+            let event = ComponentStateChanged {
+                component_id: *component_id,
+                state: ComponentState::Running,
+                previous_state: component.state,
+                missed_intervals: 0,
+                ts_event: ts_now,
+            };
+            component.state = ComponentState::Running;
+            log::info!("Component {component_id} recovered");
+            self.publish(&event);
+        }
+        Ok(())
+    }
+
+    /// Checks all registered components for missed heartbeats as at `ts_now`, returning
+    /// any state changes (which are also published on the message bus).
+    pub fn check(&mut self, ts_now: UnixNanos) -> Vec<ComponentStateChanged> {
+        let mut events = Vec::new();
+        for (component_id, component) in &mut self.components {
+            let elapsed = ts_now.saturating_sub(component.last_heartbeat);
+            let missed =
+                u32::try_from(elapsed / self.config.heartbeat_interval_ns).unwrap_or(u32::MAX);
+
+            let state = if missed >= self.config.faulted_after {
+                ComponentState::Faulted
+            } else if missed >= self.config.degraded_after {
+                ComponentState::Degraded
+            } else {
+                continue;
+            };
+
+            if state == component.state {
+                continue;
+            }
+
+            events.push(ComponentStateChanged {
+                component_id: *component_id,
+                state,
+                previous_state: component.state,
+                missed_intervals: missed,
+                ts_event: ts_now,
+            });
+            component.state = state;
+        }
+
+        for event in &events {
+            log::warn!(
+                "Component {} {} after missing {} heartbeats",
+                event.component_id,
+                event.state,
+                event.missed_intervals
+            );
+            self.publish(event);
+        }
+
+        let faulted = events.iter().any(|e| e.state == ComponentState::Faulted);
+        if faulted && self.config.halt_on_fault {
+            log::error!("Halting trading on component fault");
+            self.msgbus
+                .borrow_mut()
+                .send(RISK_ENGINE_TRADING_STATE_ENDPOINT, &TradingState::Halted);
+        }
+
+        events
+    }
+
+    fn publish(&self, event: &ComponentStateChanged) {
+        let topic = component_state_topic(&event.component_id);
+        self.msgbus.borrow_mut().publish(&topic, event);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::identifiers::trader_id::TraderId;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::testing::recording_handler;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    #[rstest]
+    fn test_register_twice_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut monitor = ComponentMonitor::new(ComponentMonitorConfig::default(), msgbus);
+        let component_id = ComponentId::from("DataClient-BINANCE");
+        monitor.register(component_id, 0).unwrap();
+
+        assert!(monitor.register(component_id, 0).is_err());
+        assert_eq!(monitor.state(&component_id), Some(ComponentState::Running));
+    }
+
+    #[rstest]
+    fn test_missed_heartbeats_degrade_then_fault(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler::<ComponentStateChanged>("monitor-handler");
+        msgbus
+            .borrow_mut()
+            .subscribe("events.system.*", handler, None);
+        let mut monitor = ComponentMonitor::new(ComponentMonitorConfig::default(), msgbus);
+        let component_id = ComponentId::from("DataClient-BINANCE");
+        monitor.register(component_id, 0).unwrap();
+
+        assert!(monitor.check(2 * SECOND).is_empty());
+        let degraded = monitor.check(3 * SECOND);
+        assert!(monitor.check(4 * SECOND).is_empty());
+        let faulted = monitor.check(10 * SECOND);
+
+        assert_eq!(degraded.len(), 1);
+        assert_eq!(degraded[0].state, ComponentState::Degraded);
+        assert_eq!(degraded[0].previous_state, ComponentState::Running);
+        assert_eq!(faulted.len(), 1);
+        assert_eq!(faulted[0].state, ComponentState::Faulted);
+        assert_eq!(faulted[0].missed_intervals, 10);
+        assert!(!monitor.is_healthy());
+        assert_eq!(*received.lock().unwrap(), vec![degraded[0], faulted[0]]);
+    }
+
+    #[rstest]
+    fn test_heartbeat_recovers_component(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut monitor = ComponentMonitor::new(ComponentMonitorConfig::default(), msgbus);
+        let component_id = ComponentId::from("DataClient-BINANCE");
+        monitor.register(component_id, 0).unwrap();
+        monitor.check(5 * SECOND);
+
+        monitor.heartbeat(&component_id, 6 * SECOND).unwrap();
+
+        assert_eq!(monitor.state(&component_id), Some(ComponentState::Running));
+        assert!(monitor.is_healthy());
+        assert!(monitor.check(8 * SECOND).is_empty());
+    }
+
+    #[rstest]
+    fn test_fault_halts_trading_when_configured(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler::<TradingState>("risk-engine");
+        msgbus
+            .borrow_mut()
+            .register(RISK_ENGINE_TRADING_STATE_ENDPOINT, handler);
+        let config = ComponentMonitorConfig {
+            halt_on_fault: true,
+            ..Default::default()
+        };
+        let mut monitor = ComponentMonitor::new(config, msgbus);
+        monitor
+            .register(ComponentId::from("ExecClient-BINANCE"), 0)
+            .unwrap();
+
+        monitor.check(3 * SECOND);
+        assert!(received.lock().unwrap().is_empty());
+
+        monitor.check(10 * SECOND);
+        assert_eq!(*received.lock().unwrap(), vec![TradingState::Halted]);
+    }
+}
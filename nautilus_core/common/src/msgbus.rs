@@ -14,22 +14,34 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    any::Any,
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
-    sync::mpsc::{channel, Receiver, SendError, Sender},
+    sync::{
+        mpsc::{channel, Receiver, SendError, Sender},
+        Arc,
+    },
     thread,
 };
 
 use indexmap::IndexMap;
-use nautilus_core::uuid::UUID4;
+use nautilus_core::{
+    metrics::{self, Counter},
+    uuid::UUID4,
+};
 use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize};
 use serde_json;
 use ustr::Ustr;
 
+#[cfg(unix)]
+use crate::ipc::handle_messages_with_ipc;
 use crate::{handlers::MessageHandler, redis::handle_messages_with_redis};
 
+/// The maximum number of published topics to cache matching subscriptions for.
+const PUBLISH_CACHE_CAPACITY: usize = 10_000;
+
 // Represents a subscription to a particular topic.
 //
 // This is an internal class intended to be used by the message bus to organize
@@ -140,6 +152,11 @@ pub struct MessageBus {
     /// maps a pattern to all the handlers registered for it
     /// this is updated whenever a new subscription is created.
     patterns: IndexMap<Ustr, Vec<Subscription>>,
+    /// maps a published topic to its matching subscriptions in priority order,
+    /// so that publishing does not match and sort the subscriptions per message.
+    /// only topics with subscribers are cached, evicting the oldest topic at capacity.
+    /// this is invalidated whenever the subscriptions change.
+    publish_cache: IndexMap<Ustr, Vec<Subscription>>,
     /// handles a message or a request destined for a specific endpoint.
     endpoints: IndexMap<Ustr, MessageHandler>,
     /// Relates a request with a response
@@ -162,6 +179,8 @@ pub struct MessageBus {
     pub pub_count: u64,
     /// If the message bus is backed by a database.
     pub has_backing: bool,
+    published_counter: Arc<Counter>,
+    sent_counter: Arc<Counter>,
 }
 
 impl MessageBus {
@@ -174,9 +193,11 @@ impl MessageBus {
         config: Option<HashMap<String, serde_json::Value>>,
     ) -> Self {
         let config = config.unwrap_or_default();
-        let has_backing = config
-            .get("database")
-            .map_or(false, |v| v != &serde_json::Value::Null);
+        let has_backing = ["database", "ipc_path"].iter().any(|key| {
+            config
+                .get(*key)
+                .map_or(false, |v| v != &serde_json::Value::Null)
+        });
         let tx = if has_backing {
             let (tx, rx) = channel::<BusMessage>();
             thread::spawn(move || {
@@ -198,9 +219,20 @@ impl MessageBus {
             pub_count: 0,
             subscriptions: IndexMap::new(),
             patterns: IndexMap::new(),
+            publish_cache: IndexMap::new(),
             endpoints: IndexMap::new(),
             correlation_index: IndexMap::new(),
             has_backing,
+            published_counter: metrics::registry().counter(
+                "nautilus_msgbus_published_total",
+                "Messages published on the message bus",
+                &[],
+            ),
+            sent_counter: metrics::registry().counter(
+                "nautilus_msgbus_sent_total",
+                "Messages sent to message bus endpoints",
+                &[],
+            ),
         }
     }
 
@@ -304,12 +336,61 @@ impl MessageBus {
         matches.sort();
 
         self.subscriptions.insert(sub, matches);
+        self.publish_cache.clear();
     }
 
     /// Unsubscribes the given `handler` from the `topic`.
     pub fn unsubscribe(&mut self, topic: &str, handler: MessageHandler) {
         let sub = Subscription::new(Ustr::from(topic), handler, self.subscriptions.len(), None);
         self.subscriptions.shift_remove(&sub);
+        self.publish_cache.clear();
+    }
+
+    /// Publishes the `message` to all handlers subscribed to a pattern matching the
+    /// `topic`, in priority order.
+    pub fn publish(&mut self, topic: &str, message: &dyn Any) {
+        let topic = Ustr::from(topic);
+        if let Some(subs) = self.publish_cache.get(&topic) {
+            for sub in subs {
+                sub.handler.handle_any(message);
+            }
+        } else {
+            let mut subs: Vec<Subscription> = self
+                .subscriptions
+                .keys()
+                .filter(|sub| is_matching(&topic, &sub.topic))
+                .cloned()
+                .collect();
+            subs.sort();
+
+            for sub in &subs {
+                sub.handler.handle_any(message);
+            }
+
+            // Topics without subscribers are not cached, as publishers may use unbounded topics
+            if !subs.is_empty() {
+                if self.publish_cache.len() >= PUBLISH_CACHE_CAPACITY {
+                    self.publish_cache.shift_remove_index(0);
+                }
+                self.publish_cache.insert(topic, subs);
+            }
+        }
+
+        self.pub_count += 1;
+        if metrics::is_enabled() {
+            self.published_counter.inc();
+        }
+    }
+
+    /// Sends the `message` to the handler registered for the `endpoint` (if any).
+    pub fn send(&mut self, endpoint: &str, message: &dyn Any) {
+        if let Some(handler) = self.endpoints.get(&Ustr::from(endpoint)) {
+            handler.handle_any(message);
+            self.sent_count += 1;
+            if metrics::is_enabled() {
+                self.sent_counter.inc();
+            }
+        }
     }
 
     /// Returns the handler for the given `endpoint`.
@@ -408,6 +489,15 @@ impl MessageBus {
         instance_id: UUID4,
         config: HashMap<String, serde_json::Value>,
     ) {
+        #[cfg(unix)]
+        if config
+            .get("ipc_path")
+            .map_or(false, |v| v != &serde_json::Value::Null)
+        {
+            handle_messages_with_ipc(rx, trader_id, instance_id, config);
+            return;
+        }
+
         let database_config = config
             .get("database")
             .expect("No `MessageBusConfig` `database` config specified");
@@ -467,7 +557,10 @@ mod tests {
     use rstest::*;
 
     use super::*;
-    use crate::handlers::{MessageHandler, SafeMessageCallback};
+    use crate::{
+        handlers::{MessageHandler, SafeAnyCallback, SafeMessageCallback},
+        testing::recording_handler,
+    };
 
     fn stub_msgbus() -> MessageBus {
         MessageBus::new(TraderId::from("trader-001"), UUID4::new(), None, None)
@@ -661,6 +754,102 @@ mod tests {
         assert_eq!(subs[3].handler.handler_id, handler_id2);
     }
 
+    #[rstest]
+    fn test_publish_delivers_to_matching_handlers_in_priority_order() {
+        let mut msgbus = stub_msgbus();
+        let received: Arc<std::sync::Mutex<Vec<(&str, u64)>>> = Arc::default();
+
+        let make_handler = |id: &'static str| {
+            let received = received.clone();
+            let callback = SafeAnyCallback {
+                callback: Arc::new(move |m: &dyn Any| {
+                    if let Some(value) = m.downcast_ref::<u64>() {
+                        received.lock().unwrap().push((id, *value));
+                    }
+                }),
+            };
+            MessageHandler::with_any_callback(Ustr::from(id), callback)
+        };
+
+        msgbus.subscribe("data.quotes.*", make_handler("wildcard"), None);
+        msgbus.subscribe(
+            "data.quotes.BINANCE.ETHUSDT",
+            make_handler("exact"),
+            Some(5),
+        );
+        msgbus.subscribe("data.trades.*", make_handler("other"), None);
+
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &42_u64);
+
+        assert_eq!(msgbus.pub_count, 1);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![("exact", 42), ("wildcard", 42)]
+        );
+    }
+
+    #[rstest]
+    fn test_publish_caches_only_subscribed_topics() {
+        let mut msgbus = stub_msgbus();
+        let (handler, received) = recording_handler::<u64>("handler");
+        msgbus.subscribe("data.quotes.*", handler, None);
+
+        for i in 0..100 {
+            msgbus.publish(&format!("data.trades.{i}"), &1_u64);
+        }
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &2_u64);
+
+        assert_eq!(msgbus.pub_count, 101);
+        assert_eq!(msgbus.publish_cache.len(), 1);
+        assert_eq!(*received.lock().unwrap(), vec![2]);
+    }
+
+    #[rstest]
+    fn test_publish_and_send_record_metrics() {
+        metrics::enable();
+        let mut msgbus = stub_msgbus();
+        let published = metrics::registry().counter("nautilus_msgbus_published_total", "", &[]);
+        let sent = metrics::registry().counter("nautilus_msgbus_sent_total", "", &[]);
+        let (published_before, sent_before) = (published.get(), sent.get());
+
+        let (handler, _) = recording_handler::<u64>("handler");
+        msgbus.register("my-endpoint", handler);
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &1_u64);
+        msgbus.send("my-endpoint", &2_u64);
+
+        // Counters are process-wide, so other tests may also be recording
+        assert!(published.get() > published_before);
+        assert!(sent.get() > sent_before);
+    }
+
+    #[rstest]
+    fn test_publish_after_subscriptions_change() {
+        let mut msgbus = stub_msgbus();
+        let received: Arc<std::sync::Mutex<Vec<&str>>> = Arc::default();
+
+        let make_handler = |id: &'static str| {
+            let received = received.clone();
+            let callback = SafeAnyCallback {
+                callback: Arc::new(move |_: &dyn Any| {
+                    received.lock().unwrap().push(id);
+                }),
+            };
+            MessageHandler::with_any_callback(Ustr::from(id), callback)
+        };
+
+        msgbus.subscribe("data.quotes.*", make_handler("first"), None);
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &1_u64);
+        msgbus.subscribe("data.quotes.*", make_handler("second"), None);
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &2_u64);
+        msgbus.unsubscribe("data.quotes.*", make_handler("first"));
+        msgbus.publish("data.quotes.BINANCE.ETHUSDT", &3_u64);
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec!["first", "first", "second", "second"]
+        );
+    }
+
     #[rstest]
     #[case("*", "*", true)]
     #[case("a", "*", true)]
@@ -0,0 +1,709 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Scheduling of recurring tasks from timezone-aware cron expressions.
+//!
+//! A [`Scheduler`] holds named tasks (such as a daily rebalance at 16:00 venue time, or a
+//! weekly report), publishing a [`ScheduledTaskEvent`] on each task's message bus topic as it
+//! falls due. Schedules are evaluated in local wall time for their IANA timezone, so they
+//! stay aligned across DST transitions without any manual offset arithmetic.
+//!
+//! The scheduler state can be captured as a [`SchedulerSnapshot`] and restored after a
+//! restart, with any occurrence missed while the system was down fired once on the next check.
+
+use std::{cell::RefCell, fmt::Display, fs, path::Path, rc::Rc};
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use chrono_tz::Tz;
+use indexmap::IndexMap;
+use nautilus_core::{
+    datetime::{local_to_unix_nanos, parse_tz, unix_nanos_to_local},
+    time::UnixNanos,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::msgbus::MessageBus;
+
+/// The maximum number of days searched forward for the next occurrence of a schedule
+/// (long enough to reach the next 29 February).
+const MAX_SEARCH_DAYS: i64 = 366 * 8;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Returns the default message bus topic events for the scheduled task `name` are published on.
+#[must_use]
+pub fn scheduled_task_topic(name: &str) -> String {
+    format!("scheduler.{name}")
+}
+
+/// Represents a recurring schedule from a five field cron expression
+/// (`minute hour day-of-month month day-of-week`), evaluated in the local time of `tz`.
+///
+/// Each field accepts `*`, single values, ranges (`1-5`), steps (`*/15`, `0-30/10`) and
+/// comma separated lists, with months and weekdays also accepting three letter names
+/// (`JAN`, `MON`). Sunday is either `0` or `7`. As with standard cron, when both the
+/// day-of-month and day-of-week fields are restricted a day matching either will match.
+///
+/// Local times skipped by a DST transition fire at the first valid time after the gap, and
+/// local times repeated by a DST transition fire once (on their first occurrence).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expr: String,
+    tz: Tz,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    is_any_day_of_month: bool,
+    is_any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// Creates a new [`CronSchedule`] from the given cron `expr` in the timezone `tz`.
+    pub fn new(expr: &str, tz: Tz) -> Result<Self> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            bail!(
+                "Invalid cron expression '{expr}': expected 5 fields, was {}",
+                fields.len()
+            );
+        };
+
+        let mut days_of_week = parse_field(day_of_week, 0, 7, Some(&WEEKDAY_NAMES), 0)?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1; // Sunday as 7
+        }
+
+        Ok(Self {
+            expr: fields.join(" "),
+            tz,
+            minutes: parse_field(minute, 0, 59, None, 0)?,
+            hours: parse_field(hour, 0, 23, None, 0)?,
+            days_of_month: parse_field(day_of_month, 1, 31, None, 0)?,
+            months: parse_field(month, 1, 12, Some(&MONTH_NAMES), 1)?,
+            days_of_week,
+            is_any_day_of_month: day_of_month.starts_with('*'),
+            is_any_day_of_week: day_of_week.starts_with('*'),
+        })
+    }
+
+    /// Returns the cron expression for the schedule.
+    #[must_use]
+    pub fn expr(&self) -> &str {
+        &self.expr
+    }
+
+    /// Returns the timezone the schedule is evaluated in.
+    #[must_use]
+    pub fn tz(&self) -> Tz {
+        self.tz
+    }
+
+    /// Returns the next occurrence of the schedule strictly after `ts` (if any).
+    #[must_use]
+    pub fn next_after(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let local = unix_nanos_to_local(ts, self.tz).ok()?.naive_local();
+
+        // Candidates are whole minutes, starting from the minute following `ts`
+        let mut candidate = local.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = candidate + Duration::days(MAX_SEARCH_DAYS);
+
+        while candidate < limit {
+            if !has_bit(self.months, candidate.month()) {
+                let date = candidate.date();
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                candidate = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.is_matching_day(candidate.date()) {
+                candidate = candidate.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !has_bit(self.hours, candidate.hour()) {
+                candidate = candidate.with_minute(0)? + Duration::hours(1);
+                continue;
+            }
+            if has_bit(self.minutes, candidate.minute()) {
+                let next = local_to_unix_nanos(&candidate, self.tz).ok();
+                if let Some(next) = next.filter(|next| *next > ts) {
+                    return Some(next);
+                }
+            }
+            candidate += Duration::minutes(1);
+        }
+
+        None
+    }
+
+    fn is_matching_day(&self, date: NaiveDate) -> bool {
+        let is_dom_match = has_bit(self.days_of_month, date.day());
+        let is_dow_match = has_bit(self.days_of_week, date.weekday().num_days_from_sunday());
+
+        match (self.is_any_day_of_month, self.is_any_day_of_week) {
+            (true, true) => true,
+            (false, true) => is_dom_match,
+            (true, false) => is_dow_match,
+            (false, false) => is_dom_match || is_dow_match,
+        }
+    }
+}
+
+impl Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.expr, self.tz)
+    }
+}
+
+fn has_bit(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: Option<&[&str]>,
+    names_offset: u32,
+) -> Result<u64> {
+    let parse_value = |value: &str| -> Result<u32> {
+        if let Some(names) = names {
+            let upper = value.to_ascii_uppercase();
+            if let Some(pos) = names.iter().position(|name| *name == upper) {
+                return Ok(pos as u32 + names_offset);
+            }
+        }
+        value
+            .parse::<u32>()
+            .map_err(|_| anyhow!("Invalid cron value '{value}' in field '{field}'"))
+    };
+
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u32>()
+                    .map_err(|_| anyhow!("Invalid cron step '{step}' in field '{field}'"))?,
+            ),
+            None => (part, 1),
+        };
+        if step == 0 {
+            bail!("Invalid cron step of zero in field '{field}'");
+        }
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start)?, parse_value(end)?)
+        } else {
+            let value = parse_value(range)?;
+            // A stepped single value (`5/15`) runs to the end of the range
+            (value, if step > 1 { max } else { value })
+        };
+
+        if start < min || end > max || start > end {
+            bail!(
+                "Invalid cron range {start}-{end} in field '{field}', valid range is {min}-{max}"
+            );
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
+
+/// Represents a scheduled task falling due.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledTaskEvent {
+    /// The scheduled task name.
+    pub name: Ustr,
+    /// The UNIX timestamp (nanoseconds) the task was scheduled to fire.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the task was fired.
+    pub ts_init: UnixNanos,
+}
+
+impl Display for ScheduledTaskEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ScheduledTaskEvent(name={}, ts_event={}, ts_init={})",
+            self.name, self.ts_event, self.ts_init
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ScheduledTask {
+    topic: Ustr,
+    schedule: CronSchedule,
+    next_fire_ns: Option<UnixNanos>,
+    last_fire_ns: Option<UnixNanos>,
+}
+
+/// Represents the persisted state of a single scheduled task.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledTaskSnapshot {
+    pub name: String,
+    pub expr: String,
+    pub tz: String,
+    pub topic: String,
+    pub next_fire_ns: Option<UnixNanos>,
+    pub last_fire_ns: Option<UnixNanos>,
+}
+
+/// Represents the persisted state of a [`Scheduler`], for restoring after a restart.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchedulerSnapshot {
+    pub tasks: Vec<ScheduledTaskSnapshot>,
+}
+
+/// Fires named tasks on recurring cron schedules, publishing a [`ScheduledTaskEvent`] on
+/// the message bus for each task as it falls due.
+///
+/// The scheduler holds no timer of its own, `check` should be called from a timer set for
+/// the time returned by `next_fire_ns` (or more frequently). If several occurrences of a task
+/// are missed between checks they are coalesced into a single event.
+pub struct Scheduler {
+    tasks: IndexMap<Ustr, ScheduledTask>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl Scheduler {
+    #[must_use]
+    pub fn new(msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            tasks: IndexMap::new(),
+            msgbus,
+        }
+    }
+
+    /// Adds the task `name` fired on the given cron `expr` in the timezone `tz`, returning
+    /// the time of its first occurrence after `ts_now`.
+    ///
+    /// Events are published on `topic`, otherwise the default [`scheduled_task_topic`].
+    pub fn add_task(
+        &mut self,
+        name: &str,
+        expr: &str,
+        tz: Tz,
+        topic: Option<&str>,
+        ts_now: UnixNanos,
+    ) -> Result<UnixNanos> {
+        let name = Ustr::from(name);
+        if self.tasks.contains_key(&name) {
+            bail!("Scheduled task {name} already exists");
+        }
+
+        let schedule = CronSchedule::new(expr, tz)?;
+        let Some(next_fire_ns) = schedule.next_after(ts_now) else {
+            bail!("Scheduled task {name} has no future occurrences for {schedule}");
+        };
+
+        let topic = topic.map_or_else(|| Ustr::from(&scheduled_task_topic(&name)), Ustr::from);
+        log::info!("Added scheduled task {name} for {schedule} on '{topic}'");
+        self.tasks.insert(
+            name,
+            ScheduledTask {
+                topic,
+                schedule,
+                next_fire_ns: Some(next_fire_ns),
+                last_fire_ns: None,
+            },
+        );
+        Ok(next_fire_ns)
+    }
+
+    /// Removes the task `name`.
+    pub fn remove_task(&mut self, name: &str) -> Result<()> {
+        if self.tasks.shift_remove(&Ustr::from(name)).is_none() {
+            bail!("Scheduled task {name} not found");
+        }
+        Ok(())
+    }
+
+    /// Returns the names of all scheduled tasks.
+    #[must_use]
+    pub fn task_names(&self) -> Vec<Ustr> {
+        self.tasks.keys().copied().collect()
+    }
+
+    /// Returns the schedule for the task `name` (if found).
+    #[must_use]
+    pub fn schedule(&self, name: &str) -> Option<&CronSchedule> {
+        self.tasks.get(&Ustr::from(name)).map(|t| &t.schedule)
+    }
+
+    /// Returns the time the task `name` last fired (if found and fired).
+    #[must_use]
+    pub fn last_fire_ns(&self, name: &str) -> Option<UnixNanos> {
+        self.tasks
+            .get(&Ustr::from(name))
+            .and_then(|t| t.last_fire_ns)
+    }
+
+    /// Returns the earliest next fire time across all tasks, or for the task `name` if given.
+    #[must_use]
+    pub fn next_fire_ns(&self, name: Option<&str>) -> Option<UnixNanos> {
+        match name {
+            Some(name) => self
+                .tasks
+                .get(&Ustr::from(name))
+                .and_then(|t| t.next_fire_ns),
+            None => self.tasks.values().filter_map(|t| t.next_fire_ns).min(),
+        }
+    }
+
+    /// Checks all tasks as at `ts_now`, returning events for any which have fallen due
+    /// (which are also published on the message bus), in scheduled time order.
+    pub fn check(&mut self, ts_now: UnixNanos) -> Vec<ScheduledTaskEvent> {
+        let mut due = Vec::new();
+        for (name, task) in &mut self.tasks {
+            let Some(next_fire_ns) = task.next_fire_ns else {
+                continue;
+            };
+            if next_fire_ns > ts_now {
+                continue;
+            }
+
+            // Any further occurrences missed up to `ts_now` are coalesced into this event
+            task.next_fire_ns = task.schedule.next_after(ts_now);
+            task.last_fire_ns = Some(next_fire_ns);
+            if task.next_fire_ns.is_none() {
+                log::warn!("Scheduled task {name} has no further occurrences");
+            }
+
+            let event = ScheduledTaskEvent {
+                name: *name,
+                ts_event: next_fire_ns,
+                ts_init: ts_now,
+            };
+            due.push((task.topic, event));
+        }
+
+        due.sort_by_key(|(_, event)| event.ts_event);
+        for (topic, event) in &due {
+            log::debug!("Firing {event}");
+            self.msgbus.borrow_mut().publish(topic, event);
+        }
+
+        due.into_iter().map(|(_, event)| event).collect()
+    }
+
+    /// Returns a snapshot of the current scheduler state.
+    #[must_use]
+    pub fn snapshot(&self) -> SchedulerSnapshot {
+        SchedulerSnapshot {
+            tasks: self
+                .tasks
+                .iter()
+                .map(|(name, task)| ScheduledTaskSnapshot {
+                    name: name.to_string(),
+                    expr: task.schedule.expr().to_string(),
+                    tz: task.schedule.tz().name().to_string(),
+                    topic: task.topic.to_string(),
+                    next_fire_ns: task.next_fire_ns,
+                    last_fire_ns: task.last_fire_ns,
+                })
+                .collect(),
+        }
+    }
+
+    /// Restores the tasks from the given `snapshot`, replacing any existing tasks.
+    ///
+    /// Tasks keep their pending occurrence from the snapshot, so an occurrence which fell
+    /// due while the system was down will fire on the next `check`.
+    pub fn restore(&mut self, snapshot: &SchedulerSnapshot) -> Result<()> {
+        let mut tasks = IndexMap::with_capacity(snapshot.tasks.len());
+        for task in &snapshot.tasks {
+            let tz = parse_tz(&task.tz)?;
+            tasks.insert(
+                Ustr::from(&task.name),
+                ScheduledTask {
+                    topic: Ustr::from(&task.topic),
+                    schedule: CronSchedule::new(&task.expr, tz)?,
+                    next_fire_ns: task.next_fire_ns,
+                    last_fire_ns: task.last_fire_ns,
+                },
+            );
+        }
+
+        log::info!("Restored {} scheduled task(s)", tasks.len());
+        self.tasks = tasks;
+        Ok(())
+    }
+
+    /// Writes a snapshot of the current scheduler state as JSON to the file at `path`.
+    ///
+    /// The snapshot is written to a temporary file and then renamed, so an interrupted
+    /// write will not corrupt an existing snapshot.
+    pub fn save_snapshot<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(&self.snapshot())?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Restores the tasks from the JSON snapshot file at `path`, replacing any existing tasks.
+    pub fn load_snapshot<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let snapshot: SchedulerSnapshot = serde_json::from_slice(&fs::read(path)?)?;
+        self.restore(&snapshot)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono_tz::{America::New_York, Europe::London, UTC};
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::identifiers::trader_id::TraderId;
+    use rstest::{fixture, rstest};
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::testing::recording_handler;
+
+    fn ts(rfc3339: &str) -> UnixNanos {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap() as UnixNanos
+    }
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    #[rstest]
+    #[case("* * * *")]
+    #[case("* * * * * *")]
+    #[case("60 * * * *")]
+    #[case("* 24 * * *")]
+    #[case("* * 0 * *")]
+    #[case("* * * 13 *")]
+    #[case("* * * * 8")]
+    #[case("*/0 * * * *")]
+    #[case("5-1 * * * *")]
+    #[case("abc * * * *")]
+    #[case("* * * FOO *")]
+    fn test_invalid_expressions(#[case] expr: &str) {
+        assert!(CronSchedule::new(expr, UTC).is_err());
+    }
+
+    #[rstest]
+    #[case("*/15 * * * *", "2024-01-01T00:07:00Z", "2024-01-01T00:15:00Z")]
+    #[case("*/15 * * * *", "2024-01-01T00:15:00Z", "2024-01-01T00:30:00Z")]
+    #[case("0 16 * * *", "2024-01-01T16:00:30Z", "2024-01-02T16:00:00Z")]
+    #[case("30 9 * * MON-FRI", "2024-01-05T10:00:00Z", "2024-01-08T09:30:00Z")]
+    #[case("0 0 1 JAN,jul *", "2024-02-01T00:00:00Z", "2024-07-01T00:00:00Z")]
+    #[case("0 12 * * 7", "2024-01-01T00:00:00Z", "2024-01-07T12:00:00Z")]
+    #[case("5/20 8 * * *", "2024-01-01T08:30:00Z", "2024-01-01T08:45:00Z")]
+    #[case("0 0 29 2 *", "2024-03-01T00:00:00Z", "2028-02-29T00:00:00Z")]
+    fn test_next_after(#[case] expr: &str, #[case] after: &str, #[case] expected: &str) {
+        let schedule = CronSchedule::new(expr, UTC).unwrap();
+
+        assert_eq!(schedule.next_after(ts(after)), Some(ts(expected)));
+    }
+
+    #[rstest]
+    fn test_restricted_day_of_month_and_week_match_either() {
+        // 15th of the month or any Monday
+        let schedule = CronSchedule::new("0 0 15 * MON", UTC).unwrap();
+
+        let first = schedule.next_after(ts("2024-01-09T00:00:00Z")).unwrap();
+        let second = schedule.next_after(first).unwrap();
+
+        assert_eq!(first, ts("2024-01-15T00:00:00Z")); // Monday 15th
+        assert_eq!(second, ts("2024-01-22T00:00:00Z"));
+    }
+
+    #[rstest]
+    fn test_impossible_schedule_has_no_next_occurrence() {
+        let schedule = CronSchedule::new("0 0 30 2 *", UTC).unwrap();
+
+        assert_eq!(schedule.next_after(0), None);
+    }
+
+    #[rstest]
+    fn test_local_time_follows_dst_offset_changes() {
+        let schedule = CronSchedule::new("0 16 * * *", New_York).unwrap();
+
+        // EST (UTC-5) before the March transition, EDT (UTC-4) after
+        let winter = schedule.next_after(ts("2024-03-08T12:00:00Z")).unwrap();
+        let summer = schedule.next_after(ts("2024-03-11T12:00:00Z")).unwrap();
+
+        assert_eq!(winter, ts("2024-03-08T21:00:00Z"));
+        assert_eq!(summer, ts("2024-03-11T20:00:00Z"));
+    }
+
+    #[rstest]
+    fn test_local_time_skipped_by_dst_fires_after_gap() {
+        // 02:30 does not exist in New York on 2024-03-10 (clocks jump 02:00 -> 03:00)
+        let schedule = CronSchedule::new("30 2 * * *", New_York).unwrap();
+
+        let gap = schedule.next_after(ts("2024-03-10T05:00:00Z")).unwrap();
+        let next = schedule.next_after(gap).unwrap();
+
+        assert_eq!(gap, ts("2024-03-10T07:00:00Z")); // 03:00 EDT
+        assert_eq!(next, ts("2024-03-11T06:30:00Z"));
+    }
+
+    #[rstest]
+    fn test_local_time_repeated_by_dst_fires_once() {
+        // 01:30 occurs twice in London on 2024-10-27 (clocks fall back 02:00 -> 01:00)
+        let schedule = CronSchedule::new("30 1 * * *", London).unwrap();
+
+        let first = schedule.next_after(ts("2024-10-26T23:00:00Z")).unwrap();
+        let next = schedule.next_after(first).unwrap();
+
+        assert_eq!(first, ts("2024-10-27T00:30:00Z")); // 01:30 BST
+        assert_eq!(next, ts("2024-10-28T01:30:00Z")); // 01:30 GMT the following day
+    }
+
+    #[rstest]
+    fn test_add_task_twice_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut scheduler = Scheduler::new(msgbus);
+        scheduler
+            .add_task("rebalance", "0 16 * * *", New_York, None, 0)
+            .unwrap();
+
+        assert!(scheduler
+            .add_task("rebalance", "0 16 * * *", New_York, None, 0)
+            .is_err());
+        assert!(scheduler.remove_task("rebalance").is_ok());
+        assert!(scheduler.remove_task("rebalance").is_err());
+    }
+
+    #[rstest]
+    fn test_add_task_with_no_occurrences_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut scheduler = Scheduler::new(msgbus);
+
+        assert!(scheduler
+            .add_task("never", "0 0 31 4 *", UTC, None, 0)
+            .is_err());
+        assert!(scheduler.task_names().is_empty());
+    }
+
+    #[rstest]
+    fn test_check_publishes_due_tasks_in_order(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler::<ScheduledTaskEvent>("scheduler-handler");
+        msgbus.borrow_mut().subscribe("scheduler.*", handler, None);
+        let mut scheduler = Scheduler::new(msgbus);
+        let ts_now = ts("2024-01-01T00:00:00Z");
+        scheduler
+            .add_task("report", "0 17 * * *", UTC, None, ts_now)
+            .unwrap();
+        scheduler
+            .add_task("rebalance", "0 16 * * *", UTC, None, ts_now)
+            .unwrap();
+
+        assert_eq!(
+            scheduler.next_fire_ns(None),
+            Some(ts("2024-01-01T16:00:00Z"))
+        );
+        assert!(scheduler.check(ts("2024-01-01T15:59:59Z")).is_empty());
+        let events = scheduler.check(ts("2024-01-01T17:00:00Z"));
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].name, Ustr::from("rebalance"));
+        assert_eq!(events[0].ts_event, ts("2024-01-01T16:00:00Z"));
+        assert_eq!(events[1].name, Ustr::from("report"));
+        assert_eq!(events[1].ts_init, ts("2024-01-01T17:00:00Z"));
+        assert_eq!(*received.lock().unwrap(), events);
+        assert_eq!(
+            scheduler.next_fire_ns(Some("rebalance")),
+            Some(ts("2024-01-02T16:00:00Z"))
+        );
+        assert_eq!(
+            scheduler.last_fire_ns("rebalance"),
+            Some(ts("2024-01-01T16:00:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_check_coalesces_missed_occurrences(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut scheduler = Scheduler::new(msgbus);
+        scheduler
+            .add_task("poll", "*/5 * * * *", UTC, Some("custom.topic"), 0)
+            .unwrap();
+
+        let events = scheduler.check(ts("1970-01-01T01:02:00Z"));
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ts_event, ts("1970-01-01T00:05:00Z"));
+        assert_eq!(
+            scheduler.next_fire_ns(Some("poll")),
+            Some(ts("1970-01-01T01:05:00Z"))
+        );
+    }
+
+    #[rstest]
+    fn test_snapshot_restore_fires_occurrence_missed_during_downtime(
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("scheduler.json");
+        let mut scheduler = Scheduler::new(msgbus.clone());
+        scheduler
+            .add_task(
+                "rebalance",
+                "0 16 * * MON-FRI",
+                New_York,
+                None,
+                ts("2024-01-02T12:00:00Z"),
+            )
+            .unwrap();
+        scheduler.save_snapshot(&path).unwrap();
+
+        // Restart after the scheduled time has passed
+        let mut restored = Scheduler::new(msgbus);
+        restored.load_snapshot(&path).unwrap();
+        let events = restored.check(ts("2024-01-02T23:00:00Z"));
+
+        assert_eq!(restored.snapshot().tasks.len(), 1);
+        assert_eq!(
+            restored.schedule("rebalance"),
+            scheduler.schedule("rebalance")
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ts_event, ts("2024-01-02T21:00:00Z"));
+        assert_eq!(
+            restored.next_fire_ns(None),
+            Some(ts("2024-01-03T21:00:00Z"))
+        );
+    }
+}
@@ -14,10 +14,19 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    any::Any,
+    sync::{Arc, Mutex},
     thread,
     time::{Duration, Instant},
 };
 
+use ustr::Ustr;
+
+use crate::handlers::{MessageHandler, SafeAnyCallback};
+
+/// The messages recorded by a recording handler, shared with the test which inspects them.
+pub type RecordedMessages<T> = Arc<Mutex<Vec<T>>>;
+
 /// Repeatedly evaluates a condition with a delay until it becomes true or a timeout occurs.
 ///
 /// # Arguments
@@ -71,3 +80,34 @@ where
         thread::sleep(Duration::from_millis(100));
     }
 }
+
+/// Returns a message handler with the given `id` which records every message of type `T`
+/// it receives (ignoring other types), along with the recorded messages.
+#[must_use]
+pub fn recording_handler<T: Any + Clone + Send>(id: &str) -> (MessageHandler, RecordedMessages<T>) {
+    recording_handler_with(id, |message: &dyn Any| message.downcast_ref::<T>().cloned())
+}
+
+/// Returns a message handler with the given `id` which records the value returned by
+/// `record` for each message it receives (ignoring messages mapped to `None`), along
+/// with the recorded values.
+#[must_use]
+pub fn recording_handler_with<T, F>(id: &str, record: F) -> (MessageHandler, RecordedMessages<T>)
+where
+    T: Send + 'static,
+    F: Fn(&dyn Any) -> Option<T> + Send + Sync + 'static,
+{
+    let received: RecordedMessages<T> = Arc::default();
+    let recorder = received.clone();
+    let handler = MessageHandler::with_any_callback(
+        Ustr::from(id),
+        SafeAnyCallback {
+            callback: Arc::new(move |message: &dyn Any| {
+                if let Some(value) = record(message) {
+                    recorder.lock().unwrap().push(value);
+                }
+            }),
+        },
+    );
+    (handler, received)
+}
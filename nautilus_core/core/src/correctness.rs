@@ -52,6 +52,14 @@ pub fn check_u8_equal(lhs: u8, rhs: u8, lhs_param: &str, rhs_param: &str) -> Res
     Ok(())
 }
 
+/// Validates that `usize` values are equal.
+pub fn check_usize_equal(lhs: usize, rhs: usize, lhs_param: &str, rhs_param: &str) -> Result<()> {
+    if lhs != rhs {
+        bail!("{FAILED} '{lhs_param}' usize of {lhs} was not equal to '{rhs_param}' usize of {rhs}")
+    }
+    Ok(())
+}
+
 /// Validates that the `u8` value is in the inclusive range [`l`, `r`].
 pub fn check_u8_in_range_inclusive(value: u8, l: u8, r: u8, desc: &str) -> Result<()> {
     if value < l || value > r {
@@ -174,6 +182,30 @@ mod tests {
         assert!(check_u8_equal(lhs, rhs, lhs_param, rhs_param).is_ok());
     }
 
+    #[rstest]
+    #[case(0, 1, "left param", "right param")]
+    #[case(5, 3, "left param", "right param")]
+    fn test_usize_equal_when_invalid_values(
+        #[case] lhs: usize,
+        #[case] rhs: usize,
+        #[case] lhs_param: &str,
+        #[case] rhs_param: &str,
+    ) {
+        assert!(check_usize_equal(lhs, rhs, lhs_param, rhs_param).is_err());
+    }
+
+    #[rstest]
+    #[case(0, 0, "left param", "right param")]
+    #[case(1_000_000, 1_000_000, "left param", "right param")]
+    fn test_usize_equal_when_valid_values(
+        #[case] lhs: usize,
+        #[case] rhs: usize,
+        #[case] lhs_param: &str,
+        #[case] rhs_param: &str,
+    ) {
+        assert!(check_usize_equal(lhs, rhs, lhs_param, rhs_param).is_ok());
+    }
+
     #[rstest]
     #[case(0, 1, 2, "value")]
     #[case(3, 1, 2, "value")]
@@ -13,13 +13,18 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::time::{Duration, UNIX_EPOCH};
+use std::{
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use chrono::{
     prelude::{DateTime, Utc},
-    Datelike, NaiveDate, SecondsFormat, Weekday,
+    Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, SecondsFormat, TimeZone,
+    Timelike, Weekday,
 };
+use chrono_tz::Tz;
 
 use crate::time::UnixNanos;
 
@@ -28,6 +33,9 @@ pub const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
 pub const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
 pub const NANOSECONDS_IN_MICROSECOND: u64 = 1_000;
 
+/// The maximum length of a DST gap searched for the first valid local time after it.
+const MAX_DST_GAP_MINUTES: i64 = 180;
+
 pub const WEEKDAYS: [Weekday; 5] = [
     Weekday::Mon,
     Weekday::Tue,
@@ -127,11 +135,110 @@ pub fn is_within_last_24_hours(timestamp_ns: UnixNanos) -> Result<bool> {
     Ok(now.signed_duration_since(timestamp) <= chrono::Duration::days(1))
 }
 
+/// Parses the IANA timezone with the given `name` (such as `America/New_York`).
+pub fn parse_tz(name: &str) -> Result<Tz> {
+    Tz::from_str(name).map_err(|e| anyhow!("Invalid timezone '{name}': {e}"))
+}
+
+/// Converts a UNIX nanoseconds timestamp to the local wall time in the timezone `tz`.
+pub fn unix_nanos_to_local(timestamp_ns: UnixNanos, tz: Tz) -> Result<DateTime<Tz>> {
+    let seconds = timestamp_ns / NANOSECONDS_IN_SECOND;
+    let nanoseconds = (timestamp_ns % NANOSECONDS_IN_SECOND) as u32;
+    let timestamp = DateTime::from_timestamp(seconds as i64, nanoseconds)
+        .ok_or_else(|| anyhow!("Invalid timestamp {timestamp_ns}"))?;
+    Ok(timestamp.with_timezone(&tz))
+}
+
+/// Converts a UNIX nanoseconds timestamp to an ISO 8601 formatted string of the local
+/// wall time (including the UTC offset) in the timezone `tz`.
+pub fn unix_nanos_to_local_iso8601(timestamp_ns: UnixNanos, tz: Tz) -> Result<String> {
+    Ok(unix_nanos_to_local(timestamp_ns, tz)?.to_rfc3339_opts(SecondsFormat::Nanos, false))
+}
+
+/// Returns the UTC offset (seconds) of the timezone `tz` at the given UNIX nanoseconds timestamp.
+pub fn utc_offset_secs(timestamp_ns: UnixNanos, tz: Tz) -> Result<i32> {
+    Ok(unix_nanos_to_local(timestamp_ns, tz)?
+        .offset()
+        .fix()
+        .local_minus_utc())
+}
+
+/// Converts the `local` wall time in the timezone `tz` to UNIX nanoseconds.
+///
+/// A local time skipped by a DST transition resolves to the transition itself (the first
+/// valid local time after the gap), and a local time repeated by a DST transition resolves
+/// to its first occurrence.
+pub fn local_to_unix_nanos(local: &NaiveDateTime, tz: Tz) -> Result<UnixNanos> {
+    let dt = match tz.from_local_datetime(local) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            let start = local
+                .with_second(0)
+                .and_then(|dt| dt.with_nanosecond(0))
+                .ok_or_else(|| anyhow!("Invalid local time {local}"))?;
+            (1..=MAX_DST_GAP_MINUTES)
+                .find_map(|minutes| {
+                    tz.from_local_datetime(&(start + chrono::Duration::minutes(minutes)))
+                        .earliest()
+                })
+                .ok_or_else(|| anyhow!("No valid local time after {local} in {tz}"))?
+        }
+    };
+
+    let timestamp_ns = dt
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow!("Failed `timestamp_nanos_opt`"))?;
+    UnixNanos::try_from(timestamp_ns)
+        .map_err(|_| anyhow!("Local time {local} was before the UNIX epoch"))
+}
+
+/// Returns the next occurrence of the local wall `time` in the timezone `tz` strictly
+/// after the given UNIX nanoseconds timestamp.
+pub fn next_local_time_nanos(
+    timestamp_ns: UnixNanos,
+    tz: Tz,
+    time: NaiveTime,
+) -> Result<UnixNanos> {
+    let date = unix_nanos_to_local(timestamp_ns, tz)?.date_naive();
+    for days in 0..=1 {
+        let next = local_to_unix_nanos(&(date + chrono::Duration::days(days)).and_time(time), tz)?;
+        if next > timestamp_ns {
+            return Ok(next);
+        }
+    }
+    bail!("No occurrence of {time} in {tz} found after {timestamp_ns}")
+}
+
+/// Adds the given number of calendar `days` to the UNIX nanoseconds timestamp in the local
+/// time of `tz`, preserving the local wall time across DST transitions (so a day may be
+/// 23 or 25 hours rather than a fixed 24).
+pub fn add_local_days_nanos(timestamp_ns: UnixNanos, tz: Tz, days: i64) -> Result<UnixNanos> {
+    let local = unix_nanos_to_local(timestamp_ns, tz)?.naive_local();
+    let shifted = local
+        .checked_add_signed(chrono::Duration::days(days))
+        .ok_or_else(|| anyhow!("Overflow adding {days} days to {local}"))?;
+    local_to_unix_nanos(&shifted, tz)
+}
+
+/// Returns the duration (nanoseconds) of the local calendar day in the timezone `tz`
+/// containing the given UNIX nanoseconds timestamp.
+pub fn local_day_nanos(timestamp_ns: UnixNanos, tz: Tz) -> Result<u64> {
+    let date = unix_nanos_to_local(timestamp_ns, tz)?.date_naive();
+    let next_date = date
+        .succ_opt()
+        .ok_or_else(|| anyhow!("No day following {date}"))?;
+    let start = local_to_unix_nanos(&date.and_time(NaiveTime::MIN), tz)?;
+    let end = local_to_unix_nanos(&next_date.and_time(NaiveTime::MIN), tz)?;
+    Ok(end - start)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use chrono_tz::America::New_York;
     use rstest::rstest;
 
     use super::*;
@@ -259,6 +366,77 @@ mod tests {
         assert!(is_within_last_24_hours(now_ns as UnixNanos).unwrap());
     }
 
+    fn ts(rfc3339: &str) -> UnixNanos {
+        DateTime::parse_from_rfc3339(rfc3339)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap() as UnixNanos
+    }
+
+    #[rstest]
+    fn test_parse_tz() {
+        assert_eq!(parse_tz("America/New_York").unwrap(), New_York);
+        assert!(parse_tz("Mars/Olympus_Mons").is_err());
+    }
+
+    #[rstest]
+    #[case("2024-01-15T14:30:00Z", "2024-01-15T09:30:00.000000000-05:00", -18_000)]
+    #[case("2024-07-15T13:30:00Z", "2024-07-15T09:30:00.000000000-04:00", -14_400)]
+    fn test_unix_nanos_to_local(
+        #[case] utc: &str,
+        #[case] expected: &str,
+        #[case] expected_offset: i32,
+    ) {
+        assert_eq!(
+            unix_nanos_to_local_iso8601(ts(utc), New_York).unwrap(),
+            expected
+        );
+        assert_eq!(utc_offset_secs(ts(utc), New_York).unwrap(), expected_offset);
+    }
+
+    #[rstest]
+    #[case("2024-03-10T01:30:00", "2024-03-10T06:30:00Z")] // EST
+    #[case("2024-03-10T02:30:00", "2024-03-10T07:00:00Z")] // Skipped, resolves to 03:00 EDT
+    #[case("2024-11-03T01:30:00", "2024-11-03T05:30:00Z")] // Repeated, resolves to first (EDT)
+    #[case("2024-11-03T02:30:00", "2024-11-03T07:30:00Z")] // EST
+    fn test_local_to_unix_nanos_across_dst(#[case] local: &str, #[case] expected: &str) {
+        let local = NaiveDateTime::parse_from_str(local, "%Y-%m-%dT%H:%M:%S").unwrap();
+        assert_eq!(local_to_unix_nanos(&local, New_York).unwrap(), ts(expected));
+    }
+
+    #[rstest]
+    #[case("2024-03-08T20:00:00Z", "2024-03-08T21:00:00Z")] // Later today (EST)
+    #[case("2024-03-08T21:00:00Z", "2024-03-09T21:00:00Z")] // Strictly after
+    #[case("2024-03-09T22:00:00Z", "2024-03-10T20:00:00Z")] // Tomorrow (EDT)
+    fn test_next_local_time_nanos(#[case] after: &str, #[case] expected: &str) {
+        let close = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+        assert_eq!(
+            next_local_time_nanos(ts(after), New_York, close).unwrap(),
+            ts(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_add_local_days_nanos_preserves_wall_time_across_dst() {
+        let start = ts("2024-03-09T14:30:00Z"); // 09:30 EST
+        let result = add_local_days_nanos(start, New_York, 1).unwrap();
+
+        assert_eq!(result, ts("2024-03-10T13:30:00Z")); // 09:30 EDT
+        assert_eq!(result - start, 23 * 3_600 * NANOSECONDS_IN_SECOND);
+        assert_eq!(add_local_days_nanos(result, New_York, -1).unwrap(), start);
+    }
+
+    #[rstest]
+    #[case("2024-03-10T12:00:00Z", 23)]
+    #[case("2024-06-10T12:00:00Z", 24)]
+    #[case("2024-11-03T12:00:00Z", 25)]
+    fn test_local_day_nanos(#[case] timestamp: &str, #[case] expected_hours: u64) {
+        assert_eq!(
+            local_day_nanos(ts(timestamp), New_York).unwrap(),
+            expected_hours * 3_600 * NANOSECONDS_IN_SECOND
+        );
+    }
+
     #[rstest]
     fn test_is_within_last_24_hours_when_two_days_ago() {
         let past_ns = (Utc::now() - chrono::Duration::days(2))
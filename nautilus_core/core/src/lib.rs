@@ -16,6 +16,7 @@
 pub mod correctness;
 pub mod datetime;
 pub mod message;
+pub mod metrics;
 pub mod parsing;
 pub mod serialization;
 pub mod time;
@@ -0,0 +1,489 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An optional metrics subsystem exported in the Prometheus text format.
+//!
+//! Metrics are recorded against a process-wide [`MetricsRegistry`], and are disabled by
+//! default so that instrumented hot paths (the message bus, engines and network clients)
+//! only pay for an atomic load. Call [`enable`] at startup and [`serve`] to expose the
+//! registry on a `/metrics` HTTP endpoint for scraping.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    thread::{self, JoinHandle},
+};
+
+use anyhow::Result;
+
+/// The default histogram buckets for latencies (in seconds), from 100 microseconds to 10 seconds.
+pub const LATENCY_BUCKETS_SECS: [f64; 12] = [
+    0.0001, 0.00025, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.1, 0.5, 1.0, 10.0,
+];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static REGISTRY: OnceLock<MetricsRegistry> = OnceLock::new();
+
+/// Enables recording of metrics by instrumented components.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Disables recording of metrics by instrumented components.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Returns whether metrics recording is enabled.
+#[must_use]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Returns the global metrics registry.
+pub fn registry() -> &'static MetricsRegistry {
+    REGISTRY.get_or_init(MetricsRegistry::default)
+}
+
+/// Renders the global registry in the Prometheus text exposition format.
+#[must_use]
+pub fn render_global() -> String {
+    registry().render()
+}
+
+/// Increments the global counter `name` (with `labels`) by `value`, if metrics are enabled.
+pub fn inc_counter(name: &str, help: &str, labels: &[(&str, &str)], value: u64) {
+    if is_enabled() {
+        registry().counter(name, help, labels).inc_by(value);
+    }
+}
+
+/// Sets the global gauge `name` (with `labels`) to `value`, if metrics are enabled.
+pub fn set_gauge(name: &str, help: &str, labels: &[(&str, &str)], value: i64) {
+    if is_enabled() {
+        registry().gauge(name, help, labels).set(value);
+    }
+}
+
+/// Observes `secs` on the global latency histogram `name` (with `labels`), if metrics
+/// are enabled.
+pub fn observe_latency(name: &str, help: &str, labels: &[(&str, &str)], secs: f64) {
+    if is_enabled() {
+        registry()
+            .histogram(name, help, labels, &LATENCY_BUCKETS_SECS)
+            .observe(secs);
+    }
+}
+
+/// A monotonically increasing counter.
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: AtomicU64,
+}
+
+impl Counter {
+    pub fn inc(&self) {
+        self.inc_by(1);
+    }
+
+    pub fn inc_by(&self, value: u64) {
+        self.value.fetch_add(value, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// A value which may go up and down (such as a queue depth).
+#[derive(Debug, Default)]
+pub struct Gauge {
+    value: AtomicI64,
+}
+
+impl Gauge {
+    pub fn set(&self, value: i64) {
+        self.value.store(value, Ordering::Relaxed);
+    }
+
+    pub fn inc(&self) {
+        self.value.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dec(&self) {
+        self.value.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn get(&self) -> i64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// A histogram of observations counted into cumulative buckets.
+#[derive(Debug)]
+pub struct Histogram {
+    bounds: Vec<f64>,
+    counts: Vec<AtomicU64>,
+    state: Mutex<(u64, f64)>, // (count, sum)
+}
+
+impl Histogram {
+    /// Creates a new histogram with the given bucket upper `bounds` (in ascending order).
+    #[must_use]
+    pub fn new(bounds: &[f64]) -> Self {
+        assert!(
+            bounds.windows(2).all(|w| w[0] < w[1]),
+            "Histogram `bounds` must be strictly ascending"
+        );
+        Self {
+            bounds: bounds.to_vec(),
+            counts: bounds.iter().map(|_| AtomicU64::new(0)).collect(),
+            state: Mutex::new((0, 0.0)),
+        }
+    }
+
+    pub fn observe(&self, value: f64) {
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            if value <= *bound {
+                count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        let mut state = self.state.lock().unwrap();
+        state.0 += 1;
+        state.1 += value;
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.state.lock().unwrap().0
+    }
+
+    #[must_use]
+    pub fn sum(&self) -> f64 {
+        self.state.lock().unwrap().1
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Metric {
+    Counter(Arc<Counter>),
+    Gauge(Arc<Gauge>),
+    Histogram(Arc<Histogram>),
+}
+
+impl Metric {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Counter(_) => "counter",
+            Self::Gauge(_) => "gauge",
+            Self::Histogram(_) => "histogram",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MetricFamily {
+    help: String,
+    kind: &'static str,
+    series: BTreeMap<String, Metric>,
+}
+
+/// Provides a registry of named metrics, each of which may have many labelled series.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    families: Mutex<BTreeMap<String, MetricFamily>>,
+}
+
+impl MetricsRegistry {
+    /// Returns the counter `name` with `labels`, registering it if required.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is already registered as a different type of metric.
+    pub fn counter(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Counter> {
+        match self.get_or_insert(name, help, labels, || {
+            Metric::Counter(Arc::new(Counter::default()))
+        }) {
+            Metric::Counter(counter) => counter,
+            metric => panic!("Metric {name} already registered as a {}", metric.kind()),
+        }
+    }
+
+    /// Returns the gauge `name` with `labels`, registering it if required.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is already registered as a different type of metric.
+    pub fn gauge(&self, name: &str, help: &str, labels: &[(&str, &str)]) -> Arc<Gauge> {
+        match self.get_or_insert(name, help, labels, || {
+            Metric::Gauge(Arc::new(Gauge::default()))
+        }) {
+            Metric::Gauge(gauge) => gauge,
+            metric => panic!("Metric {name} already registered as a {}", metric.kind()),
+        }
+    }
+
+    /// Returns the histogram `name` with `labels`, registering it with `bounds` if required.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is already registered as a different type of metric.
+    pub fn histogram(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        bounds: &[f64],
+    ) -> Arc<Histogram> {
+        match self.get_or_insert(name, help, labels, || {
+            Metric::Histogram(Arc::new(Histogram::new(bounds)))
+        }) {
+            Metric::Histogram(histogram) => histogram,
+            metric => panic!("Metric {name} already registered as a {}", metric.kind()),
+        }
+    }
+
+    fn get_or_insert(
+        &self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        init: impl FnOnce() -> Metric,
+    ) -> Metric {
+        let key = format_labels(labels);
+        let mut families = self.families.lock().unwrap();
+        if let Some(metric) = families.get(name).and_then(|f| f.series.get(&key)) {
+            return metric.clone();
+        }
+
+        let metric = init();
+        let family = families
+            .entry(name.to_string())
+            .or_insert_with(|| MetricFamily {
+                help: help.to_string(),
+                kind: metric.kind(),
+                series: BTreeMap::new(),
+            });
+        assert_eq!(
+            family.kind,
+            metric.kind(),
+            "Metric {name} already registered as a {}",
+            family.kind
+        );
+        family.series.insert(key, metric.clone());
+        metric
+    }
+
+    /// Renders all registered metrics in the Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let families = self.families.lock().unwrap();
+        let mut out = String::new();
+        for (name, family) in families.iter() {
+            let _ = writeln!(out, "# HELP {name} {}", family.help);
+            let _ = writeln!(out, "# TYPE {name} {}", family.kind);
+            for (labels, metric) in &family.series {
+                match metric {
+                    Metric::Counter(counter) => {
+                        let _ = writeln!(out, "{name}{} {}", braced(labels), counter.get());
+                    }
+                    Metric::Gauge(gauge) => {
+                        let _ = writeln!(out, "{name}{} {}", braced(labels), gauge.get());
+                    }
+                    Metric::Histogram(histogram) => {
+                        render_histogram(&mut out, name, labels, histogram);
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+fn format_labels(labels: &[(&str, &str)]) -> String {
+    let mut labels = labels.to_vec();
+    labels.sort_unstable();
+    labels
+        .iter()
+        .map(|(key, value)| format!("{key}=\"{}\"", escape_label_value(value)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn braced(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{labels}}}")
+    }
+}
+
+fn render_histogram(out: &mut String, name: &str, labels: &str, histogram: &Histogram) {
+    let with_le = |le: &str| {
+        if labels.is_empty() {
+            format!("{{le=\"{le}\"}}")
+        } else {
+            format!("{{{labels},le=\"{le}\"}}")
+        }
+    };
+    for (bound, count) in histogram.bounds.iter().zip(&histogram.counts) {
+        let _ = writeln!(
+            out,
+            "{name}_bucket{} {}",
+            with_le(&bound.to_string()),
+            count.load(Ordering::Relaxed)
+        );
+    }
+    let (count, sum) = *histogram.state.lock().unwrap();
+    let _ = writeln!(out, "{name}_bucket{} {count}", with_le("+Inf"));
+    let _ = writeln!(out, "{name}_sum{} {sum}", braced(labels));
+    let _ = writeln!(out, "{name}_count{} {count}", braced(labels));
+}
+
+/// Serves the global registry on `http://{addr}/metrics` from a background thread.
+///
+/// Returns the bound address (useful when binding to port 0) and the server thread handle.
+pub fn serve<A: ToSocketAddrs>(addr: A) -> Result<(std::net::SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind(addr)?;
+    let local_addr = listener.local_addr()?;
+    let handle = thread::Builder::new()
+        .name("metrics-server".to_string())
+        .spawn(move || {
+            for stream in listener.incoming().flatten() {
+                // Scrape errors are the scrapers problem, the server keeps accepting
+                let _ = handle_request(stream);
+            }
+        })?;
+    Ok((local_addr, handle))
+}
+
+fn handle_request(mut stream: TcpStream) -> std::io::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    let (status, content_type, body) = match request_line.split_whitespace().nth(1) {
+        Some("/metrics") => ("200 OK", "text/plain; version=0.0.4", registry().render()),
+        _ => ("404 Not Found", "text/plain", "Not Found\n".to_string()),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_counter_series_by_labels() {
+        let registry = MetricsRegistry::default();
+        registry
+            .counter("events_total", "Events", &[("engine", "data")])
+            .inc();
+        registry
+            .counter("events_total", "Events", &[("engine", "data")])
+            .inc_by(2);
+        registry
+            .counter("events_total", "Events", &[("engine", "exec")])
+            .inc();
+
+        assert_eq!(
+            registry.render(),
+            "# HELP events_total Events\n\
+             # TYPE events_total counter\n\
+             events_total{engine=\"data\"} 3\n\
+             events_total{engine=\"exec\"} 1\n"
+        );
+    }
+
+    #[rstest]
+    fn test_gauge_and_escaped_labels() {
+        let registry = MetricsRegistry::default();
+        let gauge = registry.gauge("queue_depth", "Depth", &[("name", "a\"b")]);
+        gauge.set(5);
+        gauge.dec();
+
+        assert!(registry
+            .render()
+            .contains("queue_depth{name=\"a\\\"b\"} 4\n"));
+    }
+
+    #[rstest]
+    fn test_histogram_buckets_are_cumulative() {
+        let registry = MetricsRegistry::default();
+        let histogram = registry.histogram("latency_seconds", "Latency", &[], &[0.125, 1.0]);
+        histogram.observe(0.0625);
+        histogram.observe(0.5);
+        histogram.observe(2.0);
+
+        let rendered = registry.render();
+        assert!(rendered.contains("latency_seconds_bucket{le=\"0.125\"} 1\n"));
+        assert!(rendered.contains("latency_seconds_bucket{le=\"1\"} 2\n"));
+        assert!(rendered.contains("latency_seconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(rendered.contains("latency_seconds_sum 2.5625\n"));
+        assert_eq!(histogram.count(), 3);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "already registered as a counter")]
+    fn test_registering_different_kind_panics() {
+        let registry = MetricsRegistry::default();
+        registry.counter("metric", "Metric", &[]);
+        registry.gauge("metric", "Metric", &[("a", "b")]);
+    }
+
+    #[rstest]
+    fn test_serve_metrics_endpoint() {
+        registry()
+            .counter("test_scrapes_total", "Scrapes", &[])
+            .inc();
+        let (addr, _handle) = serve("127.0.0.1:0").unwrap();
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("test_scrapes_total 1\n"));
+    }
+}
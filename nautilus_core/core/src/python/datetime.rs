@@ -13,12 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use chrono::{NaiveDate, NaiveTime};
 use pyo3::prelude::*;
 
 use super::to_pyvalue_err;
 use crate::datetime::{
-    is_within_last_24_hours, last_weekday_nanos, micros_to_nanos, millis_to_nanos, nanos_to_micros,
-    nanos_to_millis, nanos_to_secs, secs_to_millis, secs_to_nanos, unix_nanos_to_iso8601,
+    add_local_days_nanos, is_within_last_24_hours, last_weekday_nanos, local_day_nanos,
+    local_to_unix_nanos, micros_to_nanos, millis_to_nanos, nanos_to_micros, nanos_to_millis,
+    nanos_to_secs, next_local_time_nanos, parse_tz, secs_to_millis, secs_to_nanos,
+    unix_nanos_to_iso8601, unix_nanos_to_local_iso8601, utc_offset_secs,
 };
 
 #[must_use]
@@ -78,3 +81,59 @@ pub fn py_last_weekday_nanos(year: i32, month: u32, day: u32) -> PyResult<u64> {
 pub fn py_is_within_last_24_hours(timestamp_ns: u64) -> PyResult<bool> {
     is_within_last_24_hours(timestamp_ns).map_err(to_pyvalue_err)
 }
+
+#[pyfunction(name = "unix_nanos_to_local_iso8601")]
+pub fn py_unix_nanos_to_local_iso8601(timestamp_ns: u64, tz: &str) -> PyResult<String> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    unix_nanos_to_local_iso8601(timestamp_ns, tz).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "utc_offset_secs")]
+pub fn py_utc_offset_secs(timestamp_ns: u64, tz: &str) -> PyResult<i32> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    utc_offset_secs(timestamp_ns, tz).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "local_to_unix_nanos")]
+pub fn py_local_to_unix_nanos(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    tz: &str,
+) -> PyResult<u64> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    let local = NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|date| date.and_hms_opt(hour, minute, second))
+        .ok_or_else(|| to_pyvalue_err("Invalid local date and time"))?;
+    local_to_unix_nanos(&local, tz).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "next_local_time_nanos")]
+#[pyo3(signature = (timestamp_ns, tz, hour, minute, second=0))]
+pub fn py_next_local_time_nanos(
+    timestamp_ns: u64,
+    tz: &str,
+    hour: u32,
+    minute: u32,
+    second: u32,
+) -> PyResult<u64> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    let time = NaiveTime::from_hms_opt(hour, minute, second)
+        .ok_or_else(|| to_pyvalue_err("Invalid local time"))?;
+    next_local_time_nanos(timestamp_ns, tz, time).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "add_local_days_nanos")]
+pub fn py_add_local_days_nanos(timestamp_ns: u64, tz: &str, days: i64) -> PyResult<u64> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    add_local_days_nanos(timestamp_ns, tz, days).map_err(to_pyvalue_err)
+}
+
+#[pyfunction(name = "local_day_nanos")]
+pub fn py_local_day_nanos(timestamp_ns: u64, tz: &str) -> PyResult<u64> {
+    let tz = parse_tz(tz).map_err(to_pyvalue_err)?;
+    local_day_nanos(timestamp_ns, tz).map_err(to_pyvalue_err)
+}
@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use pyo3::prelude::*;
+
+use super::to_pyruntime_err;
+use crate::metrics::{enable, render_global, serve};
+
+#[pyfunction(name = "enable_metrics")]
+pub fn py_enable_metrics() {
+    enable();
+}
+
+/// Serves the metrics registry on `http://{host}:{port}/metrics`, returning the bound port.
+#[pyfunction(name = "serve_metrics")]
+pub fn py_serve_metrics(host: &str, port: u16) -> PyResult<u16> {
+    let (addr, _handle) = serve((host, port)).map_err(to_pyruntime_err)?;
+    Ok(addr.port())
+}
+
+#[must_use]
+#[pyfunction(name = "render_metrics")]
+pub fn py_render_metrics() -> String {
+    render_global()
+}
@@ -24,6 +24,7 @@ use pyo3::{
 use crate::uuid::UUID4;
 pub mod casing;
 pub mod datetime;
+pub mod metrics;
 pub mod serialization;
 pub mod uuid;
 
@@ -62,5 +63,17 @@ pub fn core(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(datetime::py_unix_nanos_to_iso8601, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_last_weekday_nanos, m)?)?;
     m.add_function(wrap_pyfunction!(datetime::py_is_within_last_24_hours, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        datetime::py_unix_nanos_to_local_iso8601,
+        m
+    )?)?;
+    m.add_function(wrap_pyfunction!(datetime::py_utc_offset_secs, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime::py_local_to_unix_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime::py_next_local_time_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime::py_add_local_days_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(datetime::py_local_day_nanos, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_enable_metrics, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_serve_metrics, m)?)?;
+    m.add_function(wrap_pyfunction!(metrics::py_render_metrics, m)?)?;
     Ok(())
 }
@@ -0,0 +1,120 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Benchmarks for the data ingestion hot path, comparing pooled delta batching against
+//! allocating a new buffer per batch, and publishing through the data engine.
+
+use std::{cell::RefCell, rc::Rc};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nautilus_common::msgbus::MessageBus;
+use nautilus_core::uuid::UUID4;
+use nautilus_data::{engine::DataEngine, pool::DeltasBatcher};
+use nautilus_model::{
+    data::{
+        delta::{OrderBookDelta, F_LAST},
+        deltas::OrderBookDeltas,
+        quote::stubs::quote_tick_ethusdt_binance,
+        Data,
+    },
+    identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+};
+
+const BATCH_SIZE: usize = 10;
+const MESSAGES: usize = 100_000;
+
+fn stub_deltas() -> Vec<OrderBookDelta> {
+    let instrument_id = InstrumentId::from("AAPL.XNAS");
+    (0..MESSAGES)
+        .map(|i| {
+            let mut delta = OrderBookDelta::clear(instrument_id, i as u64, i as u64, i as u64);
+            if (i + 1) % BATCH_SIZE == 0 {
+                delta.flags |= F_LAST;
+            }
+            delta
+        })
+        .collect()
+}
+
+fn deltas_batching_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deltas_batching");
+    let deltas = stub_deltas();
+
+    group.bench_function("allocating", |b| {
+        b.iter(|| {
+            let mut buffer: Vec<OrderBookDelta> = Vec::new();
+            for delta in &deltas {
+                buffer.push(*delta);
+                if delta.flags & F_LAST != 0 {
+                    let batch = OrderBookDeltas::new(
+                        delta.instrument_id,
+                        std::mem::take(&mut buffer),
+                        delta.flags,
+                        delta.sequence,
+                        delta.ts_event,
+                        delta.ts_init,
+                    );
+                    black_box(&batch);
+                }
+            }
+        });
+    });
+
+    group.bench_function("pooled", |b| {
+        let mut batcher = DeltasBatcher::new(BATCH_SIZE, 64);
+        b.iter(|| {
+            for delta in &deltas {
+                if let Some(batch) = batcher.push(*delta) {
+                    black_box(&batch);
+                    batcher.recycle(batch);
+                }
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn engine_process_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("data_engine_process");
+    let msgbus = Rc::new(RefCell::new(MessageBus::new(
+        TraderId::from("trader-001"),
+        UUID4::new(),
+        None,
+        None,
+    )));
+    let mut engine = DataEngine::new(msgbus);
+    let quote = Data::Quote(quote_tick_ethusdt_binance());
+
+    group.bench_function("quotes", |b| {
+        b.iter(|| engine.process(black_box(&quote)));
+    });
+
+    let deltas = stub_deltas();
+    engine.set_buffer_deltas(BATCH_SIZE);
+
+    group.bench_function("buffered_deltas", |b| {
+        b.iter(|| {
+            for delta in &deltas {
+                engine.process(black_box(&Data::Delta(*delta)));
+            }
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, deltas_batching_bench, engine_process_bench);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::{bail, Result};
+use nautilus_model::identifiers::{client_id::ClientId, venue::Venue};
+
+use crate::messages::{DataRequest, DataResponse, DataSubscription};
+
+/// The interface for a client which connects the [`DataEngine`](crate::engine::DataEngine)
+/// to a market data source.
+///
+/// The engine only forwards the first subscribe and the last unsubscribe for any given
+/// subscription, so implementations do not need to track duplicate subscriptions.
+pub trait DataClient {
+    /// Returns the client ID.
+    fn client_id(&self) -> ClientId;
+
+    /// Returns the venue the client provides data for (if it is specific to a single venue).
+    fn venue(&self) -> Option<Venue>;
+
+    /// Subscribes to the given `subscription` at the data source.
+    fn subscribe(&mut self, subscription: DataSubscription) -> Result<()>;
+
+    /// Unsubscribes from the given `subscription` at the data source.
+    fn unsubscribe(&mut self, subscription: DataSubscription) -> Result<()>;
+
+    /// Requests historical data from the data source.
+    ///
+    /// Clients which can fulfill the request immediately (such as from a catalog during a
+    /// backtest) return the response directly, otherwise `None` is returned and the response
+    /// is passed to [`DataEngine::response`](crate::engine::DataEngine::response) on arrival.
+    fn request(&mut self, request: &DataRequest) -> Result<Option<DataResponse>> {
+        bail!(
+            "Data client {} does not support requests, was {}",
+            self.client_id(),
+            request.request_type
+        )
+    }
+}
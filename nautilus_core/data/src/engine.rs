@@ -0,0 +1,867 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The data engine which routes subscriptions and requests to data clients and publishes data.
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_common::msgbus::MessageBus;
+use nautilus_core::{
+    metrics::{self, Counter},
+    uuid::UUID4,
+};
+use nautilus_model::{
+    data::{
+        status::{InstrumentStatus, VenueStatus},
+        Data,
+    },
+    identifiers::{client_id::ClientId, venue::Venue},
+    orderbook::book::OrderBook,
+};
+use ustr::Ustr;
+
+use crate::{
+    client::DataClient,
+    messages::{
+        data_subscription, venue_status_topic, DataCommand, DataRequest, DataResponse,
+        DataSubscription, SubscriptionCommand,
+    },
+    pool::DeltasBatcher,
+    quality::{data_quality_topic, DataQualityConfig, DataQualityGuard, DataQualityWarning},
+};
+
+/// Provides a high-performance data engine for managing many data clients,
+/// and routing subscriptions and market data between clients and the message bus.
+///
+/// Subscriptions are reference counted per client, so that a data stream is only
+/// subscribed at the client on the first subscribe command and only unsubscribed
+/// once every subscriber has unsubscribed.
+///
+/// Historical data requests are routed in the same way as subscriptions, with the response
+/// passed to the handler registered on the message bus for the request ID.
+///
+/// If data quality checks are enabled, data is validated before it is published and any
+/// issues are published as warnings on the data quality topic for the instrument.
+///
+/// If delta buffering is enabled, order book deltas are published as pooled
+/// `OrderBookDeltas` batches (on the deltas topic) once the `F_LAST` flag is received.
+pub struct DataEngine {
+    clients: IndexMap<ClientId, Box<dyn DataClient>>,
+    default_client: Option<ClientId>,
+    routing_map: IndexMap<Venue, ClientId>,
+    subscriptions: IndexMap<ClientId, IndexMap<DataSubscription, usize>>,
+    pending_requests: IndexMap<UUID4, ClientId>,
+    quality_guard: Option<DataQualityGuard>,
+    deltas_batcher: Option<DeltasBatcher>,
+    topic_cache: IndexMap<DataSubscription, Ustr>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    pub command_count: u64,
+    pub data_count: u64,
+    pub response_count: u64,
+    data_counter: Arc<Counter>,
+}
+
+impl DataEngine {
+    #[must_use]
+    pub fn new(msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clients: IndexMap::new(),
+            default_client: None,
+            routing_map: IndexMap::new(),
+            subscriptions: IndexMap::new(),
+            pending_requests: IndexMap::new(),
+            quality_guard: None,
+            deltas_batcher: None,
+            topic_cache: IndexMap::new(),
+            msgbus,
+            command_count: 0,
+            data_count: 0,
+            response_count: 0,
+            data_counter: metrics::registry().counter(
+                "nautilus_data_engine_data_total",
+                "Data processed by the data engine",
+                &[],
+            ),
+        }
+    }
+
+    /// Returns the IDs of all registered clients.
+    #[must_use]
+    pub fn registered_clients(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    /// Returns the ID of the default client (if registered).
+    #[must_use]
+    pub fn default_client(&self) -> Option<ClientId> {
+        self.default_client
+    }
+
+    /// Registers the given data `client`, routing commands for `routing` venue to it
+    /// (if given, otherwise the clients own venue is used).
+    pub fn register_client(
+        &mut self,
+        client: Box<dyn DataClient>,
+        routing: Option<Venue>,
+    ) -> Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            bail!("Data client {client_id} already registered");
+        }
+
+        if let Some(venue) = routing.or_else(|| client.venue()) {
+            self.routing_map.insert(venue, client_id);
+        }
+
+        log::info!("Registered data client {client_id}");
+        self.clients.insert(client_id, client);
+        Ok(())
+    }
+
+    /// Registers the given data `client` as the default, used for commands which cannot
+    /// otherwise be routed to a client.
+    pub fn register_default_client(&mut self, client: Box<dyn DataClient>) -> Result<()> {
+        let client_id = client.client_id();
+        self.register_client(client, None)?;
+        self.default_client = Some(client_id);
+        Ok(())
+    }
+
+    /// Deregisters the client with the given `client_id`, returning it.
+    ///
+    /// Any subscriptions held against the client are discarded.
+    pub fn deregister_client(&mut self, client_id: &ClientId) -> Result<Box<dyn DataClient>> {
+        let client = match self.clients.shift_remove(client_id) {
+            Some(client) => client,
+            None => bail!("Data client {client_id} not registered"),
+        };
+
+        self.routing_map
+            .retain(|_, routed_id| routed_id != client_id);
+        self.subscriptions.shift_remove(client_id);
+        self.pending_requests
+            .retain(|_, pending_id| pending_id != client_id);
+        if self.default_client == Some(*client_id) {
+            self.default_client = None;
+        }
+
+        log::info!("Deregistered data client {client_id}");
+        Ok(client)
+    }
+
+    /// Returns all active subscriptions across all clients.
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<DataSubscription> {
+        let mut subscriptions: Vec<DataSubscription> = Vec::new();
+        for subscription in self.subscriptions.values().flat_map(IndexMap::keys) {
+            if !subscriptions.contains(subscription) {
+                subscriptions.push(*subscription);
+            }
+        }
+        subscriptions
+    }
+
+    /// Returns the active subscriptions for the given `client_id`.
+    #[must_use]
+    pub fn client_subscriptions(&self, client_id: &ClientId) -> Vec<DataSubscription> {
+        self.subscriptions
+            .get(client_id)
+            .map(|subs| subs.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of subscribers to `subscription` at the given `client_id`.
+    #[must_use]
+    pub fn subscriber_count(&self, client_id: &ClientId, subscription: &DataSubscription) -> usize {
+        self.subscriptions
+            .get(client_id)
+            .and_then(|subs| subs.get(subscription))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns whether any client holds the given `subscription`.
+    #[must_use]
+    pub fn is_subscribed(&self, subscription: &DataSubscription) -> bool {
+        self.subscriptions
+            .values()
+            .any(|subs| subs.contains_key(subscription))
+    }
+
+    /// Returns the IDs of requests awaiting a response from a client.
+    #[must_use]
+    pub fn pending_requests(&self) -> Vec<UUID4> {
+        self.pending_requests.keys().copied().collect()
+    }
+
+    /// Enables data quality checks for all processed data with the given `config`.
+    pub fn set_data_quality(&mut self, config: DataQualityConfig) {
+        self.quality_guard = Some(DataQualityGuard::new(config));
+    }
+
+    /// Returns the data quality guard (if data quality checks are enabled).
+    #[must_use]
+    pub fn data_quality(&self) -> Option<&DataQualityGuard> {
+        self.quality_guard.as_ref()
+    }
+
+    /// Enables buffering of order book deltas into batches, using pooled buffers with
+    /// the given initial `buffer_capacity` (in deltas).
+    pub fn set_buffer_deltas(&mut self, buffer_capacity: usize) {
+        self.deltas_batcher = Some(DeltasBatcher::new(buffer_capacity, 64));
+    }
+
+    /// Returns the order book deltas batcher (if delta buffering is enabled).
+    #[must_use]
+    pub fn deltas_batcher(&self) -> Option<&DeltasBatcher> {
+        self.deltas_batcher.as_ref()
+    }
+
+    /// Executes the given data `command`.
+    pub fn execute(&mut self, command: DataCommand) -> Result<()> {
+        self.command_count += 1;
+
+        match command {
+            DataCommand::Subscribe(cmd) => self.handle_subscribe(&cmd),
+            DataCommand::Unsubscribe(cmd) => self.handle_unsubscribe(&cmd),
+            DataCommand::Request(req) => self.handle_request(&req),
+        }
+    }
+
+    /// Processes the given `data`, publishing it on the message bus.
+    pub fn process(&mut self, data: &Data) {
+        self.data_count += 1;
+        if metrics::is_enabled() {
+            self.data_counter.inc();
+        }
+
+        let checked;
+        let data = match self.quality_guard.as_mut() {
+            Some(guard) => {
+                let check = guard.check(data);
+                for warning in &check.warnings {
+                    self.publish_quality_warning(warning);
+                }
+                match check.data {
+                    Some(data) => {
+                        checked = data;
+                        checked.as_ref()
+                    }
+                    None => return,
+                }
+            }
+            None => data,
+        };
+
+        let subscription = data_subscription(data);
+        let topic = *self
+            .topic_cache
+            .entry(subscription)
+            .or_insert_with(|| Ustr::from(&subscription.topic()));
+
+        if let (Some(batcher), Data::Delta(delta)) = (self.deltas_batcher.as_mut(), data) {
+            if let Some(deltas) = batcher.push(*delta) {
+                self.msgbus.borrow_mut().publish(&topic, &deltas);
+                batcher.recycle(deltas);
+            }
+            return;
+        }
+
+        self.msgbus.borrow_mut().publish(&topic, data);
+    }
+
+    /// Checks the given order `book` for crossing (if data quality checks are enabled),
+    /// publishing a warning if it is crossed.
+    ///
+    /// Called by the owner of the book (such as the cache) once an update is complete.
+    pub fn check_book(&mut self, book: &OrderBook) {
+        let Some(guard) = self.quality_guard.as_mut() else {
+            return;
+        };
+        if let Some(warning) = guard.check_book(book) {
+            self.publish_quality_warning(&warning);
+        }
+    }
+
+    /// Processes the given instrument `status` update, publishing it on the message bus.
+    pub fn process_instrument_status(&mut self, status: &InstrumentStatus) {
+        self.data_count += 1;
+        log::info!("{status}");
+
+        let topic = DataSubscription::InstrumentStatus(status.instrument_id).topic();
+        self.msgbus.borrow_mut().publish(&topic, status);
+    }
+
+    /// Processes the given venue `status` update, publishing it on the message bus.
+    pub fn process_venue_status(&mut self, status: &VenueStatus) {
+        self.data_count += 1;
+        log::info!("{status}");
+
+        let topic = venue_status_topic(&status.venue);
+        self.msgbus.borrow_mut().publish(&topic, status);
+    }
+
+    /// Handles the given `response`, passing it to the handler for its correlation ID.
+    pub fn response(&mut self, response: &DataResponse) {
+        self.response_count += 1;
+        self.pending_requests.shift_remove(&response.correlation_id);
+
+        let handler = self
+            .msgbus
+            .borrow_mut()
+            .response_handler(&response.correlation_id);
+
+        match handler {
+            Some(handler) => handler.handle_any(response),
+            None => log::error!(
+                "Cannot handle response: no handler for correlation ID {}",
+                response.correlation_id
+            ),
+        }
+    }
+
+    fn publish_quality_warning(&self, warning: &DataQualityWarning) {
+        log::warn!("{warning}");
+        metrics::inc_counter(
+            "nautilus_data_quality_issues_total",
+            "Data quality issues detected by the data engine",
+            &[("issue", warning.issue.as_str())],
+            1,
+        );
+        let topic = data_quality_topic(&warning.instrument_id);
+        self.msgbus.borrow_mut().publish(&topic, warning);
+    }
+
+    fn resolve_client(&self, client_id: Option<ClientId>, venue: Venue) -> Result<ClientId> {
+        if let Some(client_id) = client_id {
+            if self.clients.contains_key(&client_id) {
+                return Ok(client_id);
+            }
+            bail!("Data client {client_id} not registered");
+        }
+
+        if let Some(client_id) = self.routing_map.get(&venue) {
+            return Ok(*client_id);
+        }
+
+        match self.default_client {
+            Some(client_id) => Ok(client_id),
+            None => bail!("No data client to route command for venue {venue}"),
+        }
+    }
+
+    fn handle_subscribe(&mut self, cmd: &SubscriptionCommand) -> Result<()> {
+        let venue = cmd.venue.unwrap_or(cmd.subscription.instrument_id().venue);
+        let client_id = self.resolve_client(cmd.client_id, venue)?;
+        let subscription = cmd.subscription;
+
+        let count = self
+            .subscriptions
+            .entry(client_id)
+            .or_default()
+            .entry(subscription)
+            .or_insert(0);
+        *count += 1;
+
+        if *count > 1 {
+            log::debug!("Already subscribed to {subscription} at {client_id}, count={count}");
+            return Ok(());
+        }
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        if let Err(e) = client.subscribe(subscription) {
+            // Roll back so that a retry will subscribe at the client again
+            if let Some(subs) = self.subscriptions.get_mut(&client_id) {
+                subs.shift_remove(&subscription);
+            }
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, req: &DataRequest) -> Result<()> {
+        let venue = req.venue.unwrap_or(req.request_type.venue());
+        let client_id = self.resolve_client(req.client_id, venue)?;
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        let response = client.request(req)?;
+
+        match response {
+            Some(response) => self.response(&response),
+            None => {
+                self.pending_requests.insert(req.request_id, client_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe(&mut self, cmd: &SubscriptionCommand) -> Result<()> {
+        let venue = cmd.venue.unwrap_or(cmd.subscription.instrument_id().venue);
+        let client_id = self.resolve_client(cmd.client_id, venue)?;
+        let subscription = cmd.subscription;
+
+        let Some(count) = self
+            .subscriptions
+            .get_mut(&client_id)
+            .and_then(|subs| subs.get_mut(&subscription))
+        else {
+            log::warn!("Cannot unsubscribe from {subscription} at {client_id}: not subscribed");
+            return Ok(());
+        };
+
+        *count -= 1;
+        if *count > 0 {
+            return Ok(());
+        }
+
+        if let Some(subs) = self.subscriptions.get_mut(&client_id) {
+            subs.shift_remove(&subscription);
+        }
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        client.unsubscribe(subscription)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use nautilus_common::{handlers::MessageHandler, testing::recording_handler_with};
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::{
+            delta::{OrderBookDelta, F_LAST},
+            deltas::OrderBookDeltas,
+            quote::{stubs::quote_tick_ethusdt_binance, QuoteTick},
+        },
+        enums::MarketStatus,
+        identifiers::{instrument_id::InstrumentId, trader_id::TraderId},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::{
+        messages::{DataRequestType, ResponseData},
+        quality::{DataQualityPolicy, DataQualityWarning},
+    };
+
+    type CallLog = Rc<RefCell<Vec<String>>>;
+
+    struct MockDataClient {
+        client_id: ClientId,
+        venue: Option<Venue>,
+        calls: CallLog,
+    }
+
+    impl DataClient for MockDataClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn venue(&self) -> Option<Venue> {
+            self.venue
+        }
+
+        fn subscribe(&mut self, subscription: DataSubscription) -> Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:subscribe:{subscription}", self.client_id));
+            Ok(())
+        }
+
+        fn unsubscribe(&mut self, subscription: DataSubscription) -> Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:unsubscribe:{subscription}", self.client_id));
+            Ok(())
+        }
+
+        fn request(&mut self, request: &DataRequest) -> Result<Option<DataResponse>> {
+            self.calls.borrow_mut().push(format!(
+                "{}:request:{}",
+                self.client_id, request.request_type
+            ));
+            Ok(None)
+        }
+    }
+
+    fn mock_client(client_id: &str, venue: Option<&str>, calls: &CallLog) -> Box<dyn DataClient> {
+        Box::new(MockDataClient {
+            client_id: ClientId::from(client_id),
+            venue: venue.map(Venue::from),
+            calls: calls.clone(),
+        })
+    }
+
+    fn subscribe(subscription: DataSubscription) -> DataCommand {
+        DataCommand::Subscribe(SubscriptionCommand::new(
+            None,
+            None,
+            subscription,
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    fn unsubscribe(subscription: DataSubscription) -> DataCommand {
+        DataCommand::Unsubscribe(SubscriptionCommand::new(
+            None,
+            None,
+            subscription,
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    #[rstest]
+    fn test_register_client_twice_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+
+        let result = engine.register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None);
+
+        assert!(result.is_err());
+        assert_eq!(engine.registered_clients(), vec![ClientId::from("BINANCE")]);
+    }
+
+    #[rstest]
+    fn test_subscribe_is_reference_counted(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+        let client_id = ClientId::from("BINANCE");
+        let subscription = DataSubscription::QuoteTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        engine.execute(subscribe(subscription)).unwrap();
+        engine.execute(subscribe(subscription)).unwrap();
+        assert_eq!(engine.subscriber_count(&client_id, &subscription), 2);
+
+        engine.execute(unsubscribe(subscription)).unwrap();
+        assert!(engine.is_subscribed(&subscription));
+
+        engine.execute(unsubscribe(subscription)).unwrap();
+        assert!(!engine.is_subscribed(&subscription));
+        assert_eq!(engine.command_count, 4);
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "BINANCE:subscribe:QuoteTicks(ETHUSDT-PERP.BINANCE)",
+                "BINANCE:unsubscribe:QuoteTicks(ETHUSDT-PERP.BINANCE)",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_unsubscribe_when_not_subscribed_is_noop(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+        let subscription = DataSubscription::TradeTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        engine.execute(unsubscribe(subscription)).unwrap();
+
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_routing_by_venue_then_default(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+        engine
+            .register_default_client(mock_client("DATABENTO", None, &calls))
+            .unwrap();
+
+        let binance_sub = DataSubscription::QuoteTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let glbx_sub = DataSubscription::TradeTicks(InstrumentId::from("ESH4.GLBX"));
+        engine.execute(subscribe(binance_sub)).unwrap();
+        engine.execute(subscribe(glbx_sub)).unwrap();
+
+        assert_eq!(
+            engine.client_subscriptions(&ClientId::from("BINANCE")),
+            vec![binance_sub]
+        );
+        assert_eq!(
+            engine.client_subscriptions(&ClientId::from("DATABENTO")),
+            vec![glbx_sub]
+        );
+        assert_eq!(engine.subscriptions(), vec![binance_sub, glbx_sub]);
+    }
+
+    #[rstest]
+    fn test_subscribe_without_route_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut engine = DataEngine::new(msgbus);
+        let subscription = DataSubscription::QuoteTicks(InstrumentId::from("ESH4.GLBX"));
+
+        assert!(engine.execute(subscribe(subscription)).is_err());
+        assert!(!engine.is_subscribed(&subscription));
+    }
+
+    #[rstest]
+    fn test_deregister_client_discards_subscriptions(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+        let client_id = ClientId::from("BINANCE");
+        let subscription = DataSubscription::QuoteTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        engine.execute(subscribe(subscription)).unwrap();
+
+        let client = engine.deregister_client(&client_id).unwrap();
+
+        assert_eq!(client.client_id(), client_id);
+        assert!(engine.registered_clients().is_empty());
+        assert!(engine.subscriptions().is_empty());
+        assert!(engine.execute(subscribe(subscription)).is_err());
+    }
+
+    #[rstest]
+    fn test_process_publishes_to_topic_subscribers(
+        msgbus: Rc<RefCell<MessageBus>>,
+        quote_tick_ethusdt_binance: QuoteTick,
+    ) {
+        let (handler, received) = recording_handler_with("quote-handler", |m: &dyn Any| match m
+            .downcast_ref::<Data>()
+        {
+            Some(Data::Quote(quote)) => Some(*quote),
+            _ => None,
+        });
+        msgbus
+            .borrow_mut()
+            .subscribe("data.quotes.BINANCE.*", handler, None);
+        let mut engine = DataEngine::new(msgbus.clone());
+
+        engine.process(&Data::Quote(quote_tick_ethusdt_binance));
+
+        assert_eq!(engine.data_count, 1);
+        assert_eq!(msgbus.borrow().pub_count, 1);
+        assert_eq!(*received.lock().unwrap(), vec![quote_tick_ethusdt_binance]);
+    }
+
+    #[rstest]
+    fn test_process_buffered_deltas_publishes_batches(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler_with("deltas-handler", |m: &dyn Any| {
+            m.downcast_ref::<OrderBookDeltas>()
+                .map(|deltas| deltas.deltas.len())
+        });
+        msgbus
+            .borrow_mut()
+            .subscribe("data.book.deltas.XNAS.AAPL", handler, None);
+        let mut engine = DataEngine::new(msgbus.clone());
+        engine.set_buffer_deltas(16);
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+
+        for _ in 0..3 {
+            engine.process(&Data::Delta(OrderBookDelta::clear(instrument_id, 0, 1, 1)));
+            let mut last = OrderBookDelta::clear(instrument_id, 1, 2, 2);
+            last.flags = F_LAST;
+            engine.process(&Data::Delta(last));
+        }
+
+        let batcher = engine.deltas_batcher().unwrap();
+        assert_eq!(engine.data_count, 6);
+        assert_eq!(msgbus.borrow().pub_count, 3);
+        assert_eq!(*received.lock().unwrap(), vec![2, 2, 2]);
+        assert_eq!(batcher.pool().alloc_count, 2);
+    }
+
+    #[rstest]
+    fn test_process_status_publishes_to_status_topics(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler_with("status-handler", |m: &dyn Any| {
+            m.downcast_ref::<InstrumentStatus>()
+                .map(|status| status.status)
+                .or_else(|| m.downcast_ref::<VenueStatus>().map(|status| status.status))
+        });
+        msgbus
+            .borrow_mut()
+            .subscribe("data.status.GLBX*", handler, None);
+        let mut engine = DataEngine::new(msgbus.clone());
+        let instrument_status = InstrumentStatus::new(
+            InstrumentId::from("ESH4.GLBX"),
+            None,
+            MarketStatus::Auction,
+            None,
+            0,
+            0,
+        )
+        .unwrap();
+
+        engine.process_venue_status(&VenueStatus::new(
+            Venue::from("GLBX"),
+            MarketStatus::PreOpen,
+            0,
+            0,
+        ));
+        engine.process_instrument_status(&instrument_status);
+
+        assert_eq!(engine.data_count, 2);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![MarketStatus::PreOpen, MarketStatus::Auction]
+        );
+    }
+
+    #[rstest]
+    fn test_response_passed_to_correlated_handler(msgbus: Rc<RefCell<MessageBus>>) {
+        let (response_handler, received) =
+            recording_handler_with("response-handler", |m: &dyn Any| {
+                m.downcast_ref::<DataResponse>()
+                    .map(|response| response.correlation_id)
+            });
+        let endpoint = Ustr::from("DataEngine.request");
+        let request_id = UUID4::new();
+        {
+            let mut msgbus = msgbus.borrow_mut();
+            msgbus.register(endpoint.as_str(), MessageHandler::new(endpoint, None));
+            let _ = msgbus.request_handler(&endpoint, request_id, response_handler);
+        }
+        let mut engine = DataEngine::new(msgbus.clone());
+
+        let response = DataResponse {
+            correlation_id: request_id,
+            client_id: ClientId::from("BINANCE"),
+            data: ResponseData::Data(Vec::new()),
+            response_id: UUID4::new(),
+            ts_init: 0,
+        };
+        engine.response(&response);
+        engine.response(&response); // Correlation consumed, so not delivered twice
+
+        assert_eq!(engine.response_count, 2);
+        assert_eq!(*received.lock().unwrap(), vec![request_id]);
+        assert!(!msgbus.borrow().is_pending_response(&request_id));
+    }
+
+    #[rstest]
+    fn test_request_routed_to_client_and_pending_until_response(msgbus: Rc<RefCell<MessageBus>>) {
+        let calls = CallLog::default();
+        let mut engine = DataEngine::new(msgbus);
+        engine
+            .register_client(mock_client("BINANCE", Some("BINANCE"), &calls), None)
+            .unwrap();
+        let request = DataRequest::new(
+            None,
+            None,
+            DataRequestType::TradeTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE")),
+            Some(0),
+            Some(1_000),
+            None,
+            UUID4::new(),
+            0,
+        );
+
+        engine
+            .execute(DataCommand::Request(request.clone()))
+            .unwrap();
+        assert_eq!(engine.pending_requests(), vec![request.request_id]);
+        assert_eq!(
+            *calls.borrow(),
+            vec!["BINANCE:request:TradeTicks(ETHUSDT-PERP.BINANCE)"]
+        );
+
+        engine.response(&DataResponse::new(
+            request.request_id,
+            ClientId::from("BINANCE"),
+            ResponseData::Data(Vec::new()),
+            UUID4::new(),
+            0,
+        ));
+        assert!(engine.pending_requests().is_empty());
+    }
+
+    #[rstest]
+    fn test_request_without_route_fails(msgbus: Rc<RefCell<MessageBus>>) {
+        let mut engine = DataEngine::new(msgbus);
+        let request = DataRequest::new(
+            None,
+            None,
+            DataRequestType::Instruments(Venue::from("GLBX")),
+            None,
+            None,
+            None,
+            UUID4::new(),
+            0,
+        );
+
+        assert!(engine.execute(DataCommand::Request(request)).is_err());
+        assert!(engine.pending_requests().is_empty());
+    }
+
+    #[rstest]
+    fn test_process_drops_crossed_quote_and_publishes_warning(
+        msgbus: Rc<RefCell<MessageBus>>,
+        quote_tick_ethusdt_binance: QuoteTick,
+    ) {
+        let (handler, received) = recording_handler_with("all-handler", |m: &dyn Any| {
+            if let Some(warning) = m.downcast_ref::<DataQualityWarning>() {
+                Some(warning.issue.to_string())
+            } else {
+                m.downcast_ref::<Data>().map(|_| "DATA".to_string())
+            }
+        });
+        msgbus.borrow_mut().subscribe("data.*", handler, None);
+        let mut engine = DataEngine::new(msgbus.clone());
+        engine.set_data_quality(DataQualityConfig {
+            policy: DataQualityPolicy::Drop,
+            ..Default::default()
+        });
+        let crossed = QuoteTick {
+            bid_price: quote_tick_ethusdt_binance.ask_price,
+            ask_price: quote_tick_ethusdt_binance.bid_price,
+            ..quote_tick_ethusdt_binance
+        };
+
+        engine.process(&Data::Quote(crossed));
+        engine.process(&Data::Quote(quote_tick_ethusdt_binance));
+
+        assert_eq!(engine.data_quality().unwrap().issue_count, 1);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec!["CROSSED_QUOTE".to_string(), "DATA".to_string()]
+        );
+    }
+}
@@ -0,0 +1,389 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    fmt::{Debug, Display, Formatter},
+    rc::Rc,
+};
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{bar::BarType, Data},
+    identifiers::{client_id::ClientId, instrument_id::InstrumentId, venue::Venue},
+    instruments::Instrument,
+};
+
+/// Represents a market data stream which can be subscribed to via the data engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataSubscription {
+    Instrument(InstrumentId),
+    OrderBookDeltas(InstrumentId),
+    OrderBookDepth10(InstrumentId),
+    QuoteTicks(InstrumentId),
+    TradeTicks(InstrumentId),
+    Bars(BarType),
+    InstrumentStatus(InstrumentId),
+}
+
+impl DataSubscription {
+    /// Returns the instrument ID for the subscription.
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::Instrument(instrument_id)
+            | Self::OrderBookDeltas(instrument_id)
+            | Self::OrderBookDepth10(instrument_id)
+            | Self::QuoteTicks(instrument_id)
+            | Self::TradeTicks(instrument_id)
+            | Self::InstrumentStatus(instrument_id) => *instrument_id,
+            Self::Bars(bar_type) => bar_type.instrument_id,
+        }
+    }
+
+    /// Returns the message bus topic the subscribed data is published on.
+    #[must_use]
+    pub fn topic(&self) -> String {
+        let instrument_id = self.instrument_id();
+        let (venue, symbol) = (instrument_id.venue, instrument_id.symbol);
+        match self {
+            Self::Instrument(_) => format!("data.instrument.{venue}.{symbol}"),
+            Self::OrderBookDeltas(_) => format!("data.book.deltas.{venue}.{symbol}"),
+            Self::OrderBookDepth10(_) => format!("data.book.depth.{venue}.{symbol}"),
+            Self::QuoteTicks(_) => format!("data.quotes.{venue}.{symbol}"),
+            Self::TradeTicks(_) => format!("data.trades.{venue}.{symbol}"),
+            Self::Bars(bar_type) => format!("data.bars.{bar_type}"),
+            Self::InstrumentStatus(_) => format!("data.status.{venue}.{symbol}"),
+        }
+    }
+}
+
+impl Display for DataSubscription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Instrument(id) => write!(f, "Instrument({id})"),
+            Self::OrderBookDeltas(id) => write!(f, "OrderBookDeltas({id})"),
+            Self::OrderBookDepth10(id) => write!(f, "OrderBookDepth10({id})"),
+            Self::QuoteTicks(id) => write!(f, "QuoteTicks({id})"),
+            Self::TradeTicks(id) => write!(f, "TradeTicks({id})"),
+            Self::Bars(bar_type) => write!(f, "Bars({bar_type})"),
+            Self::InstrumentStatus(id) => write!(f, "InstrumentStatus({id})"),
+        }
+    }
+}
+
+/// Returns the data subscription the given `data` is published for.
+#[must_use]
+pub fn data_subscription(data: &Data) -> DataSubscription {
+    match data {
+        Data::Delta(delta) => DataSubscription::OrderBookDeltas(delta.instrument_id),
+        Data::Depth10(depth) => DataSubscription::OrderBookDepth10(depth.instrument_id),
+        Data::Quote(quote) => DataSubscription::QuoteTicks(quote.instrument_id),
+        Data::Trade(trade) => DataSubscription::TradeTicks(trade.instrument_id),
+        Data::Bar(bar) => DataSubscription::Bars(bar.bar_type),
+    }
+}
+
+/// Returns the message bus topic for the given `data`.
+#[must_use]
+pub fn data_topic(data: &Data) -> String {
+    data_subscription(data).topic()
+}
+
+/// Returns the message bus topic for status updates of the given `venue`.
+#[must_use]
+pub fn venue_status_topic(venue: &Venue) -> String {
+    format!("data.status.{venue}")
+}
+
+/// Represents a command to subscribe to, or unsubscribe from, a data stream.
+///
+/// The command is routed to `client_id` if given, otherwise to the client registered
+/// for `venue` (falling back to the default client).
+#[derive(Clone, Debug)]
+pub struct SubscriptionCommand {
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub subscription: DataSubscription,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl SubscriptionCommand {
+    #[must_use]
+    pub fn new(
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        subscription: DataSubscription,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            client_id,
+            venue,
+            subscription,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+/// Represents a type of historical data which can be requested via the data engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataRequestType {
+    Instruments(Venue),
+    QuoteTicks(InstrumentId),
+    TradeTicks(InstrumentId),
+    Bars(BarType),
+}
+
+impl DataRequestType {
+    /// Returns the venue the requested data originates from.
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        match self {
+            Self::Instruments(venue) => *venue,
+            Self::QuoteTicks(instrument_id) | Self::TradeTicks(instrument_id) => {
+                instrument_id.venue
+            }
+            Self::Bars(bar_type) => bar_type.instrument_id.venue,
+        }
+    }
+
+    /// Returns whether the given `data` is of the requested type.
+    ///
+    /// Instruments are not market data, so never match.
+    #[must_use]
+    pub fn matches(&self, data: &Data) -> bool {
+        match (self, data) {
+            (Self::QuoteTicks(instrument_id), Data::Quote(quote)) => {
+                quote.instrument_id == *instrument_id
+            }
+            (Self::TradeTicks(instrument_id), Data::Trade(trade)) => {
+                trade.instrument_id == *instrument_id
+            }
+            (Self::Bars(bar_type), Data::Bar(bar)) => bar.bar_type == *bar_type,
+            _ => false,
+        }
+    }
+}
+
+impl Display for DataRequestType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Instruments(venue) => write!(f, "Instruments({venue})"),
+            Self::QuoteTicks(id) => write!(f, "QuoteTicks({id})"),
+            Self::TradeTicks(id) => write!(f, "TradeTicks({id})"),
+            Self::Bars(bar_type) => write!(f, "Bars({bar_type})"),
+        }
+    }
+}
+
+/// Represents a request for historical data over an optional time range.
+///
+/// The request is routed in the same way as a [`SubscriptionCommand`], and the response
+/// is correlated back to the requester by `request_id`.
+#[derive(Clone, Debug)]
+pub struct DataRequest {
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub request_type: DataRequestType,
+    pub start: Option<UnixNanos>,
+    pub end: Option<UnixNanos>,
+    pub limit: Option<usize>,
+    pub request_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl DataRequest {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        request_type: DataRequestType,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+        request_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            client_id,
+            venue,
+            request_type,
+            start,
+            end,
+            limit,
+            request_id,
+            ts_init,
+        }
+    }
+
+    /// Returns whether `ts` falls within the requested time range (inclusive).
+    #[must_use]
+    pub fn contains(&self, ts: UnixNanos) -> bool {
+        self.start.map_or(true, |start| ts >= start) && self.end.map_or(true, |end| ts <= end)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum DataCommand {
+    Subscribe(SubscriptionCommand),
+    Unsubscribe(SubscriptionCommand),
+    Request(DataRequest),
+}
+
+/// Represents the payload of a [`DataResponse`].
+#[derive(Clone)]
+pub enum ResponseData {
+    Data(Vec<Data>),
+    Instruments(Vec<Rc<dyn Instrument>>),
+}
+
+impl ResponseData {
+    /// Returns the number of items in the response.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Data(data) => data.len(),
+            Self::Instruments(instruments) => instruments.len(),
+        }
+    }
+
+    /// Returns whether the response contains no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Debug for ResponseData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Data(data) => f.debug_tuple("Data").field(data).finish(),
+            Self::Instruments(instruments) => {
+                let ids: Vec<InstrumentId> = instruments.iter().map(|i| i.id()).collect();
+                f.debug_tuple("Instruments").field(&ids).finish()
+            }
+        }
+    }
+}
+
+/// Represents a response from a data client, correlated with the originating request.
+#[derive(Clone, Debug)]
+pub struct DataResponse {
+    pub correlation_id: UUID4,
+    pub client_id: ClientId,
+    pub data: ResponseData,
+    pub response_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl DataResponse {
+    #[must_use]
+    pub fn new(
+        correlation_id: UUID4,
+        client_id: ClientId,
+        data: ResponseData,
+        response_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            correlation_id,
+            client_id,
+            data,
+            response_id,
+            ts_init,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::data::{
+        quote::stubs::quote_tick_ethusdt_binance, quote::QuoteTick,
+        trade::stubs::stub_trade_tick_ethusdt_buyer, trade::TradeTick,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        DataSubscription::QuoteTicks(InstrumentId::from("ETHUSDT-PERP.BINANCE")),
+        "data.quotes.BINANCE.ETHUSDT-PERP"
+    )]
+    #[case(
+        DataSubscription::OrderBookDeltas(InstrumentId::from("ETHUSDT-PERP.BINANCE")),
+        "data.book.deltas.BINANCE.ETHUSDT-PERP"
+    )]
+    #[case(
+        DataSubscription::Bars(BarType::from_str("AUDUSD.SIM-1-MINUTE-LAST-EXTERNAL").unwrap()),
+        "data.bars.AUDUSD.SIM-1-MINUTE-LAST-EXTERNAL"
+    )]
+    fn test_subscription_topic(#[case] subscription: DataSubscription, #[case] expected: &str) {
+        assert_eq!(subscription.topic(), expected);
+    }
+
+    #[rstest]
+    fn test_data_topic(quote_tick_ethusdt_binance: QuoteTick) {
+        let data = Data::Quote(quote_tick_ethusdt_binance);
+        assert_eq!(data_topic(&data), "data.quotes.BINANCE.ETHUSDT-PERP");
+    }
+
+    #[rstest]
+    fn test_request_type_matches(
+        quote_tick_ethusdt_binance: QuoteTick,
+        stub_trade_tick_ethusdt_buyer: TradeTick,
+    ) {
+        let instrument_id = quote_tick_ethusdt_binance.instrument_id;
+        let quote = Data::Quote(quote_tick_ethusdt_binance);
+        let trade = Data::Trade(stub_trade_tick_ethusdt_buyer);
+
+        assert!(DataRequestType::QuoteTicks(instrument_id).matches(&quote));
+        assert!(!DataRequestType::QuoteTicks(instrument_id).matches(&trade));
+        assert!(!DataRequestType::TradeTicks(InstrumentId::from("ESH4.GLBX")).matches(&trade));
+        assert!(!DataRequestType::Instruments(instrument_id.venue).matches(&quote));
+    }
+
+    #[rstest]
+    #[case(None, None, 5, true)]
+    #[case(Some(5), Some(5), 5, true)]
+    #[case(Some(6), None, 5, false)]
+    #[case(None, Some(4), 5, false)]
+    fn test_request_contains(
+        #[case] start: Option<UnixNanos>,
+        #[case] end: Option<UnixNanos>,
+        #[case] ts: UnixNanos,
+        #[case] expected: bool,
+    ) {
+        let request = DataRequest::new(
+            None,
+            None,
+            DataRequestType::Instruments(Venue::from("BINANCE")),
+            start,
+            end,
+            None,
+            UUID4::new(),
+            0,
+        );
+        assert_eq!(request.contains(ts), expected);
+    }
+}
@@ -0,0 +1,474 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! ONNX model inference for signal generation.
+//!
+//! An [`OnnxSignalActor`] maintains a rolling window of feature vectors built from the bars
+//! and ticks it is given for an instrument. Once the window is full, each triggering update
+//! runs the model over the window and publishes the outputs as an [`OnnxPrediction`] on the
+//! message bus, keeping inference in-process for tick-level live strategies.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::{Display, Formatter},
+    path::Path,
+    rc::Rc,
+};
+
+use anyhow::{bail, Result};
+use nautilus_common::msgbus::MessageBus;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+use ort::{session::Session, value::Tensor};
+
+/// Represents a value taken from market data as a model input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputFeature {
+    BarOpen,
+    BarHigh,
+    BarLow,
+    BarClose,
+    BarVolume,
+    /// The close to close return of the latest bar.
+    BarReturn,
+    BidPrice,
+    AskPrice,
+    BidSize,
+    AskSize,
+    MidPrice,
+    Spread,
+    TradePrice,
+    TradeSize,
+}
+
+/// Represents a model which maps an input tensor to output values.
+pub trait InferenceModel {
+    /// Runs the model over the row-major `input` of the given `shape`, returning the
+    /// flattened first output.
+    fn predict(&mut self, input: Vec<f32>, shape: [usize; 2]) -> Result<Vec<f32>>;
+}
+
+/// Provides an ONNX Runtime session as an [`InferenceModel`].
+pub struct OnnxModel {
+    session: Session,
+}
+
+impl OnnxModel {
+    /// Loads the ONNX model at the given `path`.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let session = Session::builder()?.commit_from_file(path)?;
+        Ok(Self { session })
+    }
+}
+
+impl InferenceModel for OnnxModel {
+    fn predict(&mut self, input: Vec<f32>, shape: [usize; 2]) -> Result<Vec<f32>> {
+        let tensor = Tensor::from_array((shape, input))?;
+        let outputs = self.session.run(ort::inputs![tensor]?)?;
+        let (_, values) = outputs[0].try_extract_raw_tensor::<f32>()?;
+        Ok(values.to_vec())
+    }
+}
+
+/// Configuration for [`OnnxSignalActor`] instances.
+#[derive(Clone, Debug)]
+pub struct OnnxSignalConfig {
+    /// The instrument the model is run for.
+    pub instrument_id: InstrumentId,
+    /// The features of each input row, in model column order.
+    pub features: Vec<InputFeature>,
+    /// The number of rows in the input window (the model input is `[window, features]`).
+    pub window: usize,
+    /// The bar type which triggers inference, if any.
+    pub bar_type: Option<BarType>,
+    /// If each quote triggers inference.
+    pub infer_on_quotes: bool,
+    /// If each trade triggers inference.
+    pub infer_on_trades: bool,
+    /// The topic predictions are published on, defaulting to
+    /// `data.signals.onnx.{instrument_id}`.
+    pub topic: Option<String>,
+}
+
+/// Represents the model outputs for an instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnnxPrediction {
+    pub instrument_id: InstrumentId,
+    pub values: Vec<f32>,
+    /// The UNIX timestamp (nanoseconds) of the data which triggered the prediction.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) of the data which triggered the prediction was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Display for OnnxPrediction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OnnxPrediction(instrument_id={}, values={:?}, ts_event={})",
+            self.instrument_id, self.values, self.ts_event,
+        )
+    }
+}
+
+/// Runs an [`InferenceModel`] over a rolling window of market data features.
+///
+/// The actor is driven by its `handle_*` methods (called from the data handlers for the
+/// instrument), and publishes each prediction on its topic.
+pub struct OnnxSignalActor<M: InferenceModel> {
+    config: OnnxSignalConfig,
+    model: M,
+    msgbus: Rc<RefCell<MessageBus>>,
+    topic: String,
+    last_bar: Option<Bar>,
+    prev_close: Option<f64>,
+    last_quote: Option<QuoteTick>,
+    last_trade: Option<TradeTick>,
+    rows: VecDeque<Vec<f32>>,
+    prediction_count: usize,
+}
+
+impl OnnxSignalActor<OnnxModel> {
+    /// Creates a new actor running the ONNX model loaded from `model_path`.
+    pub fn from_file(
+        config: OnnxSignalConfig,
+        model_path: &Path,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Result<Self> {
+        Self::new(config, OnnxModel::from_file(model_path)?, msgbus)
+    }
+}
+
+impl<M: InferenceModel> OnnxSignalActor<M> {
+    pub fn new(
+        config: OnnxSignalConfig,
+        model: M,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Result<Self> {
+        if config.features.is_empty() {
+            bail!("Condition failed: `features` was empty");
+        }
+        if config.window == 0 {
+            bail!("Condition failed: `window` must be positive");
+        }
+        if config.bar_type.is_none() && !config.infer_on_quotes && !config.infer_on_trades {
+            bail!("Condition failed: no bar type, quotes or trades trigger inference");
+        }
+        if let Some(bar_type) = config.bar_type {
+            if bar_type.instrument_id != config.instrument_id {
+                bail!(
+                    "Condition failed: bar type {bar_type} is not for instrument {}",
+                    config.instrument_id
+                );
+            }
+        }
+
+        let topic = config
+            .topic
+            .clone()
+            .unwrap_or_else(|| format!("data.signals.onnx.{}", config.instrument_id));
+        Ok(Self {
+            rows: VecDeque::with_capacity(config.window),
+            config,
+            model,
+            msgbus,
+            topic,
+            last_bar: None,
+            prev_close: None,
+            last_quote: None,
+            last_trade: None,
+            prediction_count: 0,
+        })
+    }
+
+    /// Returns the topic predictions are published on.
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Returns the count of predictions published.
+    #[must_use]
+    pub fn prediction_count(&self) -> usize {
+        self.prediction_count
+    }
+
+    /// Returns whether the input window is full (so updates produce predictions).
+    #[must_use]
+    pub fn is_warm(&self) -> bool {
+        self.rows.len() == self.config.window
+    }
+
+    /// Handles the given `bar`, returning any prediction published.
+    pub fn handle_bar(&mut self, bar: &Bar) -> Result<Option<OnnxPrediction>> {
+        if Some(bar.bar_type) != self.config.bar_type {
+            return Ok(None);
+        }
+        self.prev_close = self.last_bar.map(|last| last.close.as_f64());
+        self.last_bar = Some(*bar);
+        self.update(bar.ts_event, bar.ts_init)
+    }
+
+    /// Handles the given `quote`, returning any prediction published.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> Result<Option<OnnxPrediction>> {
+        if quote.instrument_id != self.config.instrument_id {
+            return Ok(None);
+        }
+        self.last_quote = Some(*quote);
+        if !self.config.infer_on_quotes {
+            return Ok(None);
+        }
+        self.update(quote.ts_event, quote.ts_init)
+    }
+
+    /// Handles the given `trade`, returning any prediction published.
+    pub fn handle_trade(&mut self, trade: &TradeTick) -> Result<Option<OnnxPrediction>> {
+        if trade.instrument_id != self.config.instrument_id {
+            return Ok(None);
+        }
+        self.last_trade = Some(*trade);
+        if !self.config.infer_on_trades {
+            return Ok(None);
+        }
+        self.update(trade.ts_event, trade.ts_init)
+    }
+
+    pub fn reset(&mut self) {
+        self.last_bar = None;
+        self.prev_close = None;
+        self.last_quote = None;
+        self.last_trade = None;
+        self.rows.clear();
+        self.prediction_count = 0;
+    }
+
+    fn update(
+        &mut self,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Option<OnnxPrediction>> {
+        let Some(row) = self.feature_row() else {
+            return Ok(None); // Not all features have values yet
+        };
+        if self.rows.len() == self.config.window {
+            self.rows.pop_front();
+        }
+        self.rows.push_back(row);
+        if !self.is_warm() {
+            return Ok(None);
+        }
+
+        let input: Vec<f32> = self.rows.iter().flatten().copied().collect();
+        let shape = [self.config.window, self.config.features.len()];
+        let values = self.model.predict(input, shape)?;
+        let prediction = OnnxPrediction {
+            instrument_id: self.config.instrument_id,
+            values,
+            ts_event,
+            ts_init,
+        };
+        self.msgbus.borrow_mut().publish(&self.topic, &prediction);
+        self.prediction_count += 1;
+        Ok(Some(prediction))
+    }
+
+    fn feature_row(&self) -> Option<Vec<f32>> {
+        self.config
+            .features
+            .iter()
+            .map(|feature| self.feature_value(*feature).map(|value| value as f32))
+            .collect()
+    }
+
+    fn feature_value(&self, feature: InputFeature) -> Option<f64> {
+        let bar = self.last_bar.as_ref();
+        let quote = self.last_quote.as_ref();
+        let trade = self.last_trade.as_ref();
+        match feature {
+            InputFeature::BarOpen => bar.map(|bar| bar.open.as_f64()),
+            InputFeature::BarHigh => bar.map(|bar| bar.high.as_f64()),
+            InputFeature::BarLow => bar.map(|bar| bar.low.as_f64()),
+            InputFeature::BarClose => bar.map(|bar| bar.close.as_f64()),
+            InputFeature::BarVolume => bar.map(|bar| bar.volume.as_f64()),
+            InputFeature::BarReturn => {
+                let close = bar?.close.as_f64();
+                self.prev_close.map(|prev| close / prev - 1.0)
+            }
+            InputFeature::BidPrice => quote.map(|quote| quote.bid_price.as_f64()),
+            InputFeature::AskPrice => quote.map(|quote| quote.ask_price.as_f64()),
+            InputFeature::BidSize => quote.map(|quote| quote.bid_size.as_f64()),
+            InputFeature::AskSize => quote.map(|quote| quote.ask_size.as_f64()),
+            InputFeature::MidPrice => {
+                quote.map(|quote| (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0)
+            }
+            InputFeature::Spread => {
+                quote.map(|quote| quote.ask_price.as_f64() - quote.bid_price.as_f64())
+            }
+            InputFeature::TradePrice => trade.map(|trade| trade.price.as_f64()),
+            InputFeature::TradeSize => trade.map(|trade| trade.size.as_f64()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::testing::recording_handler;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::{bar::stubs::stub_bar, quote::stubs::quote_tick_ethusdt_binance},
+        identifiers::trader_id::TraderId,
+        types::price::Price,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    /// Returns the mean of each input column, recording the shapes it was given.
+    #[derive(Default)]
+    struct MeanModel {
+        shapes: Vec<[usize; 2]>,
+    }
+
+    impl InferenceModel for MeanModel {
+        fn predict(&mut self, input: Vec<f32>, shape: [usize; 2]) -> Result<Vec<f32>> {
+            self.shapes.push(shape);
+            let [rows, columns] = shape;
+            Ok((0..columns)
+                .map(|column| {
+                    (0..rows)
+                        .map(|row| input[row * columns + column])
+                        .sum::<f32>()
+                        / rows as f32
+                })
+                .collect())
+        }
+    }
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    fn bar_config(bar: &Bar, features: Vec<InputFeature>, window: usize) -> OnnxSignalConfig {
+        OnnxSignalConfig {
+            instrument_id: bar.bar_type.instrument_id,
+            features,
+            window,
+            bar_type: Some(bar.bar_type),
+            infer_on_quotes: false,
+            infer_on_trades: false,
+            topic: None,
+        }
+    }
+
+    fn bar_with_close(close: &str, ts: UnixNanos) -> Bar {
+        Bar {
+            close: Price::from(close),
+            ts_event: ts,
+            ts_init: ts,
+            ..stub_bar()
+        }
+    }
+
+    #[rstest]
+    fn test_config_without_trigger_is_invalid(msgbus: Rc<RefCell<MessageBus>>) {
+        let bar = stub_bar();
+        let config = OnnxSignalConfig {
+            bar_type: None,
+            ..bar_config(&bar, vec![InputFeature::BarClose], 1)
+        };
+
+        assert!(OnnxSignalActor::new(config, MeanModel::default(), msgbus).is_err());
+    }
+
+    #[rstest]
+    fn test_predictions_published_once_window_is_full(msgbus: Rc<RefCell<MessageBus>>) {
+        let (handler, received) = recording_handler::<OnnxPrediction>("onnx-handler");
+        msgbus
+            .borrow_mut()
+            .subscribe("data.signals.onnx.*", handler, None);
+        let first = bar_with_close("1.00000", 1);
+        let config = bar_config(&first, vec![InputFeature::BarClose], 2);
+        let mut actor = OnnxSignalActor::new(config, MeanModel::default(), msgbus).unwrap();
+
+        let warming = actor.handle_bar(&first).unwrap();
+        let prediction = actor.handle_bar(&bar_with_close("1.00010", 2)).unwrap();
+
+        assert!(warming.is_none());
+        assert!(actor.is_warm());
+        let prediction = prediction.unwrap();
+        assert!((prediction.values[0] - 1.000_05).abs() < 1e-6);
+        assert_eq!(prediction.ts_event, 2);
+        assert_eq!(actor.prediction_count(), 1);
+        assert_eq!(*received.lock().unwrap(), vec![prediction]);
+    }
+
+    #[rstest]
+    fn test_bars_wait_for_quote_features(msgbus: Rc<RefCell<MessageBus>>) {
+        let quote = quote_tick_ethusdt_binance();
+        let bar = Bar {
+            bar_type: BarType {
+                instrument_id: quote.instrument_id,
+                ..stub_bar().bar_type
+            },
+            ..stub_bar()
+        };
+        let config = bar_config(&bar, vec![InputFeature::BarClose, InputFeature::Spread], 1);
+        let mut actor = OnnxSignalActor::new(config, MeanModel::default(), msgbus).unwrap();
+
+        let before_quote = actor.handle_bar(&bar).unwrap();
+        actor.handle_quote(&quote).unwrap();
+        let after_quote = actor.handle_bar(&bar).unwrap();
+
+        assert!(before_quote.is_none());
+        assert_eq!(actor.model.shapes, vec![[1, 2]]);
+        let spread = (quote.ask_price.as_f64() - quote.bid_price.as_f64()) as f32;
+        assert_eq!(
+            after_quote.unwrap().values,
+            vec![bar.close.as_f64() as f32, spread]
+        );
+    }
+
+    #[rstest]
+    fn test_bar_return_needs_previous_close(msgbus: Rc<RefCell<MessageBus>>) {
+        let first = bar_with_close("1.00000", 1);
+        let config = bar_config(&first, vec![InputFeature::BarReturn], 1);
+        let mut actor = OnnxSignalActor::new(config, MeanModel::default(), msgbus).unwrap();
+
+        assert!(actor.handle_bar(&first).unwrap().is_none());
+        let prediction = actor
+            .handle_bar(&bar_with_close("1.10000", 2))
+            .unwrap()
+            .unwrap();
+
+        assert!((prediction.values[0] - 0.1).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,249 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reusable buffers for the data ingestion hot path.
+//!
+//! Batching order book deltas naively allocates a new `Vec` for every batch, which at
+//! millions of messages per second shows up as allocator pressure. The [`BufferPool`]
+//! keeps released buffers (with their capacity) for reuse, so that once warmed up the
+//! [`DeltasBatcher`] assembles batches without any heap allocation.
+
+use indexmap::IndexMap;
+use nautilus_model::{
+    data::{
+        delta::{OrderBookDelta, F_LAST},
+        deltas::OrderBookDeltas,
+    },
+    identifiers::instrument_id::InstrumentId,
+};
+
+/// Provides a pool of reusable `Vec<T>` buffers.
+///
+/// Buffers are cleared on release and keep their capacity. At most `max_pooled` buffers
+/// are retained, any further released buffers are dropped.
+#[derive(Debug)]
+pub struct BufferPool<T> {
+    buffers: Vec<Vec<T>>,
+    buffer_capacity: usize,
+    max_pooled: usize,
+    /// The count of buffers newly allocated by the pool.
+    pub alloc_count: u64,
+    /// The count of buffers reused from the pool.
+    pub reuse_count: u64,
+}
+
+impl<T> BufferPool<T> {
+    /// Creates a new [`BufferPool`] instance.
+    #[must_use]
+    pub fn new(buffer_capacity: usize, max_pooled: usize) -> Self {
+        Self {
+            buffers: Vec::with_capacity(max_pooled),
+            buffer_capacity,
+            max_pooled,
+            alloc_count: 0,
+            reuse_count: 0,
+        }
+    }
+
+    /// Returns the count of buffers currently available in the pool.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Acquires an empty buffer, reusing a pooled buffer if one is available.
+    pub fn acquire(&mut self) -> Vec<T> {
+        match self.buffers.pop() {
+            Some(buffer) => {
+                self.reuse_count += 1;
+                buffer
+            }
+            None => {
+                self.alloc_count += 1;
+                Vec::with_capacity(self.buffer_capacity)
+            }
+        }
+    }
+
+    /// Releases the given `buffer` back to the pool.
+    pub fn release(&mut self, mut buffer: Vec<T>) {
+        if self.buffers.len() < self.max_pooled {
+            buffer.clear();
+            self.buffers.push(buffer);
+        }
+    }
+}
+
+/// Assembles streamed `OrderBookDelta` updates into `OrderBookDeltas` batches using pooled
+/// buffers.
+///
+/// Deltas are accumulated per instrument until a delta with the `F_LAST` flag is pushed,
+/// which completes the batch. Completed batches should be passed back to
+/// [`DeltasBatcher::recycle`] once handled so their buffers are reused.
+#[derive(Debug)]
+pub struct DeltasBatcher {
+    pool: BufferPool<OrderBookDelta>,
+    pending: IndexMap<InstrumentId, Vec<OrderBookDelta>>,
+}
+
+impl DeltasBatcher {
+    /// Creates a new [`DeltasBatcher`] instance.
+    #[must_use]
+    pub fn new(buffer_capacity: usize, max_pooled: usize) -> Self {
+        Self {
+            pool: BufferPool::new(buffer_capacity, max_pooled),
+            pending: IndexMap::new(),
+        }
+    }
+
+    /// Returns the buffer pool for the batcher.
+    #[must_use]
+    pub fn pool(&self) -> &BufferPool<OrderBookDelta> {
+        &self.pool
+    }
+
+    /// Returns the count of deltas pending for the given `instrument_id`.
+    #[must_use]
+    pub fn pending_count(&self, instrument_id: &InstrumentId) -> usize {
+        self.pending.get(instrument_id).map_or(0, Vec::len)
+    }
+
+    /// Pushes the given `delta`, returning the completed batch if the delta is flagged
+    /// as the last in its event.
+    pub fn push(&mut self, delta: OrderBookDelta) -> Option<OrderBookDeltas> {
+        let instrument_id = delta.instrument_id;
+        let (flags, sequence, ts_event, ts_init) =
+            (delta.flags, delta.sequence, delta.ts_event, delta.ts_init);
+        let pool = &mut self.pool;
+        let buffer = self
+            .pending
+            .entry(instrument_id)
+            .or_insert_with(|| pool.acquire());
+        buffer.push(delta);
+
+        if flags & F_LAST == 0 {
+            return None;
+        }
+
+        let next = self.pool.acquire();
+        let deltas = std::mem::replace(buffer, next);
+        Some(OrderBookDeltas::new(
+            instrument_id,
+            deltas,
+            flags,
+            sequence,
+            ts_event,
+            ts_init,
+        ))
+    }
+
+    /// Returns the buffer of a handled batch to the pool.
+    pub fn recycle(&mut self, deltas: OrderBookDeltas) {
+        self.pool.release(deltas.deltas);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            delta::{OrderBookDelta, F_LAST},
+            order::BookOrder,
+        },
+        enums::{BookAction, OrderSide},
+        identifiers::instrument_id::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn delta(instrument_id: &str, flags: u8, sequence: u64) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from(instrument_id),
+            BookAction::Add,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.00"),
+                Quantity::from("10"),
+                sequence,
+            ),
+            flags,
+            sequence,
+            sequence,
+            sequence,
+        )
+    }
+
+    #[rstest]
+    fn test_pool_reuses_released_buffers() {
+        let mut pool: BufferPool<u64> = BufferPool::new(8, 2);
+
+        let mut buffer = pool.acquire();
+        buffer.extend([1, 2, 3]);
+        pool.release(buffer);
+        let buffer = pool.acquire();
+
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 8);
+        assert_eq!(pool.alloc_count, 1);
+        assert_eq!(pool.reuse_count, 1);
+    }
+
+    #[rstest]
+    fn test_pool_drops_buffers_beyond_max_pooled() {
+        let mut pool: BufferPool<u64> = BufferPool::new(8, 1);
+        let buffer1 = pool.acquire();
+        let buffer2 = pool.acquire();
+
+        pool.release(buffer1);
+        pool.release(buffer2);
+
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[rstest]
+    fn test_batcher_completes_batch_on_last_flag() {
+        let mut batcher = DeltasBatcher::new(16, 4);
+
+        assert!(batcher.push(delta("AAPL.XNAS", 0, 1)).is_none());
+        assert!(batcher.push(delta("MSFT.XNAS", 0, 2)).is_none());
+        let batch = batcher.push(delta("AAPL.XNAS", F_LAST, 3)).unwrap();
+
+        assert_eq!(batch.instrument_id, InstrumentId::from("AAPL.XNAS"));
+        assert_eq!(batch.deltas.len(), 2);
+        assert_eq!(batch.sequence, 3);
+        assert_eq!(batch.flags, F_LAST);
+        assert_eq!(batcher.pending_count(&InstrumentId::from("AAPL.XNAS")), 0);
+        assert_eq!(batcher.pending_count(&InstrumentId::from("MSFT.XNAS")), 1);
+    }
+
+    #[rstest]
+    fn test_batcher_reuses_recycled_buffers() {
+        let mut batcher = DeltasBatcher::new(16, 4);
+
+        for sequence in 0..100 {
+            let batch = batcher.push(delta("AAPL.XNAS", F_LAST, sequence)).unwrap();
+            batcher.recycle(batch);
+        }
+
+        // One buffer pending and one in the pool, regardless of the number of batches
+        assert_eq!(batcher.pool().alloc_count, 2);
+        assert_eq!(batcher.pool().reuse_count, 99);
+    }
+}
@@ -0,0 +1,510 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data quality guards which validate market data before it is published by the data engine.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::Data, identifiers::instrument_id::InstrumentId, orderbook::book::OrderBook,
+    types::price::Price,
+};
+
+use crate::messages::{data_subscription, DataSubscription};
+
+/// The action taken for data which fails validation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DataQualityPolicy {
+    /// The data is published along with a warning.
+    #[default]
+    Warn,
+    /// The data is dropped.
+    Drop,
+    /// The data is corrected where possible, otherwise it is dropped.
+    Correct,
+}
+
+/// Represents a data quality issue detected for an instrument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DataQualityIssue {
+    /// The quote bid is above the ask (a negative spread).
+    CrossedQuote,
+    /// The quote bid is equal to the ask.
+    LockedQuote,
+    /// The order book best bid is above the best ask.
+    CrossedBook,
+    /// The order book best bid is equal to the best ask.
+    LockedBook,
+    /// The data arrived later than the maximum latency after its event time.
+    StaleTimestamp,
+    /// The data event time is earlier than the previous data in the stream.
+    OutOfOrderTimestamp,
+    /// The order book delta sequence is earlier than the previous delta.
+    OutOfOrderSequence,
+}
+
+impl DataQualityIssue {
+    /// Returns whether data with the issue can be corrected (rather than dropped).
+    #[must_use]
+    pub fn is_correctable(&self) -> bool {
+        matches!(self, Self::OutOfOrderTimestamp)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CrossedQuote => "CROSSED_QUOTE",
+            Self::LockedQuote => "LOCKED_QUOTE",
+            Self::CrossedBook => "CROSSED_BOOK",
+            Self::LockedBook => "LOCKED_BOOK",
+            Self::StaleTimestamp => "STALE_TIMESTAMP",
+            Self::OutOfOrderTimestamp => "OUT_OF_ORDER_TIMESTAMP",
+            Self::OutOfOrderSequence => "OUT_OF_ORDER_SEQUENCE",
+        }
+    }
+}
+
+impl Display for DataQualityIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents a warning event for a data quality issue, published on the
+/// [`data_quality_topic`] for the instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataQualityWarning {
+    pub instrument_id: InstrumentId,
+    pub issue: DataQualityIssue,
+    pub message: String,
+    /// Whether the data was published (possibly corrected) despite the issue.
+    pub published: bool,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for DataQualityWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DataQualityWarning(instrument_id={}, issue={}, message='{}', published={})",
+            self.instrument_id, self.issue, self.message, self.published,
+        )
+    }
+}
+
+/// Returns the message bus topic data quality warnings for the given `instrument_id`
+/// are published on.
+#[must_use]
+pub fn data_quality_topic(instrument_id: &InstrumentId) -> String {
+    let (venue, symbol) = (instrument_id.venue, instrument_id.symbol);
+    format!("data.quality.{venue}.{symbol}")
+}
+
+/// Configuration for [`DataQualityGuard`] instances.
+#[derive(Clone, Debug)]
+pub struct DataQualityConfig {
+    /// The action taken for data which fails validation.
+    pub policy: DataQualityPolicy,
+    /// If locked quotes and books (bid equal to ask) are treated as an issue.
+    pub reject_locked: bool,
+    /// The maximum latency from `ts_event` to `ts_init` before data is considered stale.
+    pub max_latency_ns: Option<u64>,
+}
+
+impl Default for DataQualityConfig {
+    fn default() -> Self {
+        Self {
+            policy: DataQualityPolicy::default(),
+            reject_locked: false,
+            max_latency_ns: None,
+        }
+    }
+}
+
+/// The outcome of validating a single data point.
+#[derive(Clone, Debug)]
+pub struct DataQualityCheck<'a> {
+    /// The data to publish (`None` if it was dropped), only owned if it was corrected.
+    pub data: Option<Cow<'a, Data>>,
+    /// The warnings for any issues detected.
+    pub warnings: Vec<DataQualityWarning>,
+}
+
+/// Provides validation of market data streams per instrument, detecting crossed and
+/// locked books, stale timestamps and out-of-order data.
+///
+/// As dropping or altering an order book delta would corrupt every downstream book,
+/// issues with deltas are only ever warned about, whatever the policy. Crossing of a
+/// book built from deltas is checked against the book maintained by its owner (such as
+/// the cache) with [`DataQualityGuard::check_book`], rather than a copy held here.
+pub struct DataQualityGuard {
+    pub config: DataQualityConfig,
+    last_ts_event: HashMap<DataSubscription, UnixNanos>,
+    last_sequence: HashMap<InstrumentId, u64>,
+    pub issue_count: u64,
+}
+
+impl DataQualityGuard {
+    #[must_use]
+    pub fn new(config: DataQualityConfig) -> Self {
+        Self {
+            config,
+            last_ts_event: HashMap::new(),
+            last_sequence: HashMap::new(),
+            issue_count: 0,
+        }
+    }
+
+    /// Validates the given `data`, applying the configured policy to any issues detected.
+    pub fn check<'a>(&mut self, data: &'a Data) -> DataQualityCheck<'a> {
+        let (instrument_id, ts_event, ts_init) = data_meta(data);
+        let mut issues: Vec<(DataQualityIssue, String)> = Vec::new();
+
+        if let Some(max_latency_ns) = self.config.max_latency_ns {
+            let latency = ts_init.saturating_sub(ts_event);
+            if latency > max_latency_ns {
+                issues.push((
+                    DataQualityIssue::StaleTimestamp,
+                    format!("latency {latency}ns exceeds {max_latency_ns}ns"),
+                ));
+            }
+        }
+
+        let subscription = data_subscription(data);
+        let last_ts_event = self.last_ts_event.get(&subscription).copied();
+        if let Some(last) = last_ts_event {
+            if ts_event < *last {
+                issues.push((
+                    DataQualityIssue::OutOfOrderTimestamp,
+                    format!("ts_event {ts_event} before previous {last}"),
+                ));
+            }
+        }
+
+        match data {
+            Data::Quote(quote) => {
+                issues.extend(self.check_spread(quote.bid_price, quote.ask_price, false));
+            }
+            Data::Depth10(depth) => {
+                let (bid, ask) = (depth.bids[0], depth.asks[0]);
+                if bid.size.is_positive() && ask.size.is_positive() {
+                    issues.extend(self.check_spread(bid.price, ask.price, true));
+                }
+            }
+            Data::Delta(delta) => {
+                if let Some(last) = self.last_sequence.get(&delta.instrument_id) {
+                    if delta.sequence != 0 && delta.sequence < *last {
+                        issues.push((
+                            DataQualityIssue::OutOfOrderSequence,
+                            format!("sequence {} before previous {last}", delta.sequence),
+                        ));
+                    }
+                }
+            }
+            Data::Trade(_) | Data::Bar(_) => {}
+        }
+
+        // Deltas are only ever warned about
+        let policy = match data {
+            Data::Delta(_) => DataQualityPolicy::Warn,
+            _ => self.config.policy,
+        };
+
+        let mut data = Cow::Borrowed(data);
+        let mut publish = true;
+        for (issue, _) in &issues {
+            match policy {
+                DataQualityPolicy::Warn => {}
+                DataQualityPolicy::Drop => publish = false,
+                DataQualityPolicy::Correct if issue.is_correctable() => {
+                    if let Some(last) = last_ts_event {
+                        set_ts_event(data.to_mut(), last);
+                    }
+                }
+                DataQualityPolicy::Correct => publish = false,
+            }
+        }
+
+        let warnings: Vec<DataQualityWarning> = issues
+            .into_iter()
+            .map(|(issue, message)| DataQualityWarning {
+                instrument_id,
+                issue,
+                message,
+                published: publish,
+                ts_event,
+                ts_init,
+            })
+            .collect();
+
+        if !publish {
+            self.issue_count += warnings.len() as u64;
+            return DataQualityCheck {
+                data: None,
+                warnings,
+            };
+        }
+
+        let (_, ts_event, _) = data_meta(&data);
+        self.last_ts_event.insert(subscription, ts_event);
+
+        if let Data::Delta(delta) = data.as_ref() {
+            if delta.sequence != 0 {
+                self.last_sequence
+                    .insert(delta.instrument_id, delta.sequence);
+            }
+        }
+
+        self.issue_count += warnings.len() as u64;
+        DataQualityCheck {
+            data: Some(data),
+            warnings,
+        }
+    }
+
+    /// Checks the given `book` for crossing, returning a warning if it is crossed (or
+    /// locked, if configured).
+    ///
+    /// The book should be checked by its owner once an update is complete (after the
+    /// delta flagged `F_LAST` is applied). The book is never altered.
+    pub fn check_book(&mut self, book: &OrderBook) -> Option<DataQualityWarning> {
+        let (bid, ask) = (book.best_bid_price()?, book.best_ask_price()?);
+        let (issue, message) = self.check_spread(bid, ask, true)?;
+        self.issue_count += 1;
+        Some(DataQualityWarning {
+            instrument_id: book.instrument_id,
+            issue,
+            message,
+            published: true,
+            ts_event: book.ts_last,
+            ts_init: book.ts_last,
+        })
+    }
+
+    fn check_spread(
+        &self,
+        bid: Price,
+        ask: Price,
+        is_book: bool,
+    ) -> Option<(DataQualityIssue, String)> {
+        let (crossed, locked) = if is_book {
+            (DataQualityIssue::CrossedBook, DataQualityIssue::LockedBook)
+        } else {
+            (
+                DataQualityIssue::CrossedQuote,
+                DataQualityIssue::LockedQuote,
+            )
+        };
+
+        if bid > ask {
+            Some((crossed, format!("bid {bid} above ask {ask}")))
+        } else if bid == ask && self.config.reject_locked {
+            Some((locked, format!("bid {bid} equal to ask {ask}")))
+        } else {
+            None
+        }
+    }
+}
+
+fn data_meta(data: &Data) -> (InstrumentId, UnixNanos, UnixNanos) {
+    match data {
+        Data::Delta(d) => (d.instrument_id, d.ts_event, d.ts_init),
+        Data::Depth10(d) => (d.instrument_id, d.ts_event, d.ts_init),
+        Data::Quote(q) => (q.instrument_id, q.ts_event, q.ts_init),
+        Data::Trade(t) => (t.instrument_id, t.ts_event, t.ts_init),
+        Data::Bar(b) => (b.bar_type.instrument_id, b.ts_event, b.ts_init),
+    }
+}
+
+fn set_ts_event(data: &mut Data, ts_event: UnixNanos) {
+    match data {
+        Data::Delta(d) => d.ts_event = ts_event,
+        Data::Depth10(d) => d.ts_event = ts_event,
+        Data::Quote(q) => q.ts_event = ts_event,
+        Data::Trade(t) => t.ts_event = ts_event,
+        Data::Bar(b) => b.ts_event = ts_event,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            delta::{OrderBookDelta, F_LAST},
+            order::BookOrder,
+            quote::{stubs::quote_tick_ethusdt_binance, QuoteTick},
+        },
+        enums::{BookAction, BookType, OrderSide},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn guard(policy: DataQualityPolicy) -> DataQualityGuard {
+        DataQualityGuard::new(DataQualityConfig {
+            policy,
+            ..Default::default()
+        })
+    }
+
+    fn quote(bid: &str, ask: &str, ts_event: UnixNanos, ts_init: UnixNanos) -> Data {
+        Data::Quote(QuoteTick {
+            bid_price: Price::from(bid),
+            ask_price: Price::from(ask),
+            ts_event,
+            ts_init,
+            ..quote_tick_ethusdt_binance()
+        })
+    }
+
+    fn delta(side: OrderSide, price: &str, sequence: u64) -> Data {
+        Data::Delta(OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from("1.0"), 0),
+            F_LAST,
+            sequence,
+            sequence,
+            sequence,
+        ))
+    }
+
+    fn issues(check: &DataQualityCheck) -> Vec<DataQualityIssue> {
+        check.warnings.iter().map(|w| w.issue).collect()
+    }
+
+    #[rstest]
+    #[case(DataQualityPolicy::Warn, true)]
+    #[case(DataQualityPolicy::Drop, false)]
+    #[case(DataQualityPolicy::Correct, false)]
+    fn test_crossed_quote(#[case] policy: DataQualityPolicy, #[case] expected_published: bool) {
+        let mut guard = guard(policy);
+
+        let check = guard.check(&quote("10002.0000", "10001.0000", 0, 1));
+
+        assert_eq!(check.data.is_some(), expected_published);
+        assert_eq!(issues(&check), vec![DataQualityIssue::CrossedQuote]);
+        assert_eq!(check.warnings[0].published, expected_published);
+        assert_eq!(guard.issue_count, 1);
+    }
+
+    #[rstest]
+    #[case(false, vec![])]
+    #[case(true, vec![DataQualityIssue::LockedQuote])]
+    fn test_locked_quote(#[case] reject_locked: bool, #[case] expected: Vec<DataQualityIssue>) {
+        let mut guard = DataQualityGuard::new(DataQualityConfig {
+            reject_locked,
+            ..Default::default()
+        });
+
+        let check = guard.check(&quote("10001.0000", "10001.0000", 0, 1));
+
+        assert_eq!(issues(&check), expected);
+    }
+
+    #[rstest]
+    fn test_out_of_order_timestamp_corrected() {
+        let mut guard = guard(DataQualityPolicy::Correct);
+        guard.check(&quote("10000.0000", "10001.0000", 10, 11));
+
+        let check = guard.check(&quote("10000.0000", "10001.0000", 5, 12));
+
+        assert_eq!(issues(&check), vec![DataQualityIssue::OutOfOrderTimestamp]);
+        match check.data.as_deref() {
+            Some(Data::Quote(quote)) => assert_eq!(quote.ts_event, 10),
+            other => panic!("expected corrected quote, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_stale_timestamp_dropped_when_correcting() {
+        let mut guard = DataQualityGuard::new(DataQualityConfig {
+            policy: DataQualityPolicy::Correct,
+            max_latency_ns: Some(1_000),
+            ..Default::default()
+        });
+
+        let check = guard.check(&quote("10000.0000", "10001.0000", 0, 5_000));
+
+        assert!(check.data.is_none());
+        assert_eq!(issues(&check), vec![DataQualityIssue::StaleTimestamp]);
+    }
+
+    #[rstest]
+    #[case(DataQualityPolicy::Drop)]
+    #[case(DataQualityPolicy::Correct)]
+    fn test_out_of_order_delta_is_warned_and_published(#[case] policy: DataQualityPolicy) {
+        let mut guard = guard(policy);
+        let first = delta(OrderSide::Buy, "10000.0000", 2);
+        guard.check(&first);
+        let second = delta(OrderSide::Sell, "10001.0000", 1);
+
+        let check = guard.check(&second);
+
+        assert!(matches!(
+            check.data,
+            Some(Cow::Borrowed(Data::Delta(delta))) if delta.sequence == 1
+        ));
+        assert_eq!(
+            issues(&check),
+            vec![
+                DataQualityIssue::OutOfOrderTimestamp,
+                DataQualityIssue::OutOfOrderSequence,
+            ]
+        );
+        assert!(check.warnings.iter().all(|w| w.published));
+    }
+
+    #[rstest]
+    fn test_crossed_book_is_warned() {
+        let mut guard = guard(DataQualityPolicy::Drop);
+        let mut book = OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+        for data in [
+            delta(OrderSide::Buy, "10002.0000", 1),
+            delta(OrderSide::Sell, "10001.0000", 2),
+        ] {
+            if let Data::Delta(delta) = data {
+                book.apply_delta(delta);
+            }
+        }
+
+        let warning = guard.check_book(&book).unwrap();
+
+        assert_eq!(warning.issue, DataQualityIssue::CrossedBook);
+        assert!(warning.published);
+        assert_eq!(guard.issue_count, 1);
+    }
+
+    #[rstest]
+    fn test_valid_quote_is_borrowed() {
+        let mut guard = guard(DataQualityPolicy::Correct);
+        let data = quote("10000.0000", "10001.0000", 0, 1);
+
+        let check = guard.check(&data);
+
+        assert!(matches!(check.data, Some(Cow::Borrowed(_))));
+        assert!(check.warnings.is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use anyhow::{bail, Result};
+use nautilus_model::{
+    identifiers::{account_id::AccountId, client_id::ClientId, venue::Venue},
+    reports::{fill::FillReport, order::OrderStatusReport},
+};
+
+use crate::messages::{BatchCancelOrders, CancelOrder, ModifyOrder, SubmitOrder, SubmitOrderList};
+
+/// The interface for a client which connects the [`ExecutionEngine`](crate::engine::ExecutionEngine)
+/// to a trading venue.
+///
+/// Clients acknowledge commands by returning `Ok`, with the resulting order events passed
+/// back to the engine as they are received from the venue.
+pub trait ExecutionClient {
+    /// Returns the client ID.
+    fn client_id(&self) -> ClientId;
+
+    /// Returns the account ID for the client.
+    fn account_id(&self) -> AccountId;
+
+    /// Returns the venue the client trades on (if it is specific to a single venue).
+    fn venue(&self) -> Option<Venue>;
+
+    /// Submits the order contained in the given `command` to the venue.
+    fn submit_order(&mut self, command: &SubmitOrder) -> Result<()>;
+
+    /// Submits the orders in the given order list `command` to the venue as one batch.
+    ///
+    /// The default implementation submits each order individually, so clients for venues
+    /// with native batch submission should override it.
+    fn submit_order_list(&mut self, command: &SubmitOrderList) -> Result<()> {
+        for submit in command.split() {
+            self.submit_order(&submit)?;
+        }
+        Ok(())
+    }
+
+    /// Requests the venue modifies the order for the given `command`.
+    ///
+    /// The default implementation fails, as not every venue supports amending orders.
+    fn modify_order(&mut self, command: &ModifyOrder) -> Result<()> {
+        bail!(
+            "Cannot send {command}: modify not supported by {}",
+            self.client_id()
+        )
+    }
+
+    /// Requests the venue cancels the order for the given `command`.
+    fn cancel_order(&mut self, command: &CancelOrder) -> Result<()>;
+
+    /// Requests the venue cancels each of the orders for the given `command` as one batch.
+    ///
+    /// The default implementation cancels each order individually.
+    fn batch_cancel_orders(&mut self, command: &BatchCancelOrders) -> Result<()> {
+        for cancel in &command.cancels {
+            self.cancel_order(cancel)?;
+        }
+        Ok(())
+    }
+
+    /// Returns status reports for the orders currently known to the venue.
+    fn generate_order_status_reports(&self) -> Result<Vec<OrderStatusReport>>;
+
+    /// Returns reports for the fills currently known to the venue.
+    fn generate_fill_reports(&self) -> Result<Vec<FillReport>>;
+}
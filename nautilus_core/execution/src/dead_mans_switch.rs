@@ -0,0 +1,243 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A dead man's switch which cancels a venue's open orders on loss of connectivity.
+//!
+//! The switch tracks the heartbeats and connection state of each armed venue. Once a venue
+//! has been disconnected (or silent) for longer than the configured grace period, `check`
+//! returns it as triggered, and the caller cancels its open orders: via
+//! `ExecutionEngine::cancel_venue_orders` when live, or `OrderMatchingEngine::process_cancel_all`
+//! in a backtest.
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::identifiers::venue::Venue;
+
+/// Configuration for [`DeadMansSwitch`] instances.
+#[derive(Clone, Debug)]
+pub struct DeadMansSwitchConfig {
+    /// The time a venue may be disconnected (or miss heartbeats) before its orders are canceled.
+    pub grace_period_ns: u64,
+}
+
+impl Default for DeadMansSwitchConfig {
+    fn default() -> Self {
+        Self {
+            grace_period_ns: 5_000_000_000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct VenueSwitch {
+    last_heartbeat: UnixNanos,
+    disconnected_at: Option<UnixNanos>,
+    triggered: bool,
+}
+
+impl VenueSwitch {
+    fn is_expired(&self, ts_now: UnixNanos, grace_period_ns: u64) -> bool {
+        let since = self.disconnected_at.unwrap_or(self.last_heartbeat);
+        ts_now.saturating_sub(since) >= grace_period_ns
+    }
+}
+
+/// Provides a cancel-on-disconnect switch for many venues.
+///
+/// The switch triggers at most once per outage, it is re-armed when the venue reconnects
+/// or a heartbeat is received.
+pub struct DeadMansSwitch {
+    config: DeadMansSwitchConfig,
+    venues: IndexMap<Venue, VenueSwitch>,
+}
+
+impl DeadMansSwitch {
+    #[must_use]
+    pub fn new(config: DeadMansSwitchConfig) -> Self {
+        assert!(
+            config.grace_period_ns > 0,
+            "`grace_period_ns` must be positive"
+        );
+        Self {
+            config,
+            venues: IndexMap::new(),
+        }
+    }
+
+    /// Returns the venues the switch is armed for.
+    #[must_use]
+    pub fn venues(&self) -> Vec<Venue> {
+        self.venues.keys().copied().collect()
+    }
+
+    /// Returns whether the switch has triggered for the given `venue` (and not yet recovered).
+    #[must_use]
+    pub fn is_triggered(&self, venue: &Venue) -> bool {
+        self.venues.get(venue).is_some_and(|v| v.triggered)
+    }
+
+    /// Arms the switch for the given `venue`, treating arming as its first heartbeat.
+    pub fn arm(&mut self, venue: Venue, ts_now: UnixNanos) -> Result<()> {
+        if self.venues.contains_key(&venue) {
+            bail!("Dead man's switch already armed for {venue}");
+        }
+
+        self.venues.insert(
+            venue,
+            VenueSwitch {
+                last_heartbeat: ts_now,
+                disconnected_at: None,
+                triggered: false,
+            },
+        );
+        Ok(())
+    }
+
+    /// Disarms the switch for the given `venue`.
+    pub fn disarm(&mut self, venue: &Venue) -> Result<()> {
+        if self.venues.shift_remove(venue).is_none() {
+            bail!("Dead man's switch not armed for {venue}");
+        }
+        Ok(())
+    }
+
+    /// Records a heartbeat from the given `venue`.
+    pub fn heartbeat(&mut self, venue: &Venue, ts_now: UnixNanos) -> Result<()> {
+        let Some(switch) = self.venues.get_mut(venue) else {
+            bail!("Dead man's switch not armed for {venue}");
+        };
+        switch.last_heartbeat = ts_now;
+        if switch.disconnected_at.is_none() {
+            switch.triggered = false;
+        }
+        Ok(())
+    }
+
+    /// Records that the connection to the given `venue` was lost.
+    pub fn disconnected(&mut self, venue: &Venue, ts_now: UnixNanos) -> Result<()> {
+        let Some(switch) = self.venues.get_mut(venue) else {
+            bail!("Dead man's switch not armed for {venue}");
+        };
+        if switch.disconnected_at.is_none() {
+            switch.disconnected_at = Some(ts_now);
+        }
+        Ok(())
+    }
+
+    /// Records that the connection to the given `venue` was (re)established.
+    pub fn connected(&mut self, venue: &Venue, ts_now: UnixNanos) -> Result<()> {
+        let Some(switch) = self.venues.get_mut(venue) else {
+            bail!("Dead man's switch not armed for {venue}");
+        };
+        switch.disconnected_at = None;
+        switch.last_heartbeat = ts_now;
+        switch.triggered = false;
+        Ok(())
+    }
+
+    /// Checks all armed venues at `ts_now`, returning those for which the switch has
+    /// just triggered (and whose open orders should now be canceled).
+    pub fn check(&mut self, ts_now: UnixNanos) -> Vec<Venue> {
+        let grace_period_ns = self.config.grace_period_ns;
+        let mut triggered = Vec::new();
+        for (venue, switch) in &mut self.venues {
+            if switch.triggered || !switch.is_expired(ts_now, grace_period_ns) {
+                continue;
+            }
+
+            log::warn!("Dead man's switch triggered for {venue}: canceling open orders");
+            switch.triggered = true;
+            triggered.push(*venue);
+        }
+        triggered
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const GRACE: u64 = 1_000;
+
+    #[fixture]
+    fn venue() -> Venue {
+        Venue::from("SIM")
+    }
+
+    #[fixture]
+    fn switch(venue: Venue) -> DeadMansSwitch {
+        let mut switch = DeadMansSwitch::new(DeadMansSwitchConfig {
+            grace_period_ns: GRACE,
+        });
+        switch.arm(venue, 0).unwrap();
+        switch
+    }
+
+    #[rstest]
+    fn test_arm_twice_fails(mut switch: DeadMansSwitch, venue: Venue) {
+        assert!(switch.arm(venue, 0).is_err());
+        assert_eq!(switch.venues(), vec![venue]);
+    }
+
+    #[rstest]
+    fn test_heartbeats_keep_switch_alive(mut switch: DeadMansSwitch, venue: Venue) {
+        switch.heartbeat(&venue, 800).unwrap();
+
+        assert!(switch.check(1_500).is_empty());
+        assert!(!switch.is_triggered(&venue));
+    }
+
+    #[rstest]
+    fn test_missed_heartbeats_trigger_once(mut switch: DeadMansSwitch, venue: Venue) {
+        assert!(switch.check(999).is_empty());
+        assert_eq!(switch.check(1_000), vec![venue]);
+        assert!(switch.check(5_000).is_empty());
+        assert!(switch.is_triggered(&venue));
+
+        // Recovery re-arms the switch
+        switch.heartbeat(&venue, 5_000).unwrap();
+        assert!(!switch.is_triggered(&venue));
+        assert_eq!(switch.check(6_000), vec![venue]);
+    }
+
+    #[rstest]
+    fn test_disconnect_triggers_after_grace_period(mut switch: DeadMansSwitch, venue: Venue) {
+        switch.disconnected(&venue, 500).unwrap();
+        // Heartbeats are not expected while disconnected
+        switch.heartbeat(&venue, 1_000).unwrap();
+
+        assert!(switch.check(1_499).is_empty());
+        assert_eq!(switch.check(1_500), vec![venue]);
+
+        switch.connected(&venue, 2_000).unwrap();
+        assert!(!switch.is_triggered(&venue));
+        assert!(switch.check(2_500).is_empty());
+    }
+
+    #[rstest]
+    fn test_disarm(mut switch: DeadMansSwitch, venue: Venue) {
+        switch.disarm(&venue).unwrap();
+
+        assert!(switch.check(10_000).is_empty());
+        assert!(switch.disarm(&venue).is_err());
+        assert!(switch.heartbeat(&venue, 0).is_err());
+    }
+}
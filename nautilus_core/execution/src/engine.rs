@@ -0,0 +1,1955 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The execution engine which routes trading commands to execution clients and
+//! maintains order state from venue events.
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use nautilus_common::msgbus::MessageBus;
+use nautilus_core::{
+    metrics::{self, Counter, Gauge},
+    time::UnixNanos,
+    uuid::UUID4,
+};
+use nautilus_model::{
+    enums::OrderSide,
+    events::{
+        account::state::AccountState,
+        order::{
+            denied::OrderDenied, event::OrderEvent, modify_rejected::OrderModifyRejected,
+            pending_update::OrderPendingUpdate,
+        },
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, position_id::PositionId, venue::Venue,
+        venue_order_id::VenueOrderId,
+    },
+    instruments::Instrument,
+    orders::{any::OrderAny, base::Order},
+    position::Position,
+    reports::{
+        fill::FillReport,
+        order::OrderStatusReport,
+        reconciliation::{reconcile_fill, reconcile_order},
+    },
+};
+use ustr::Ustr;
+
+use crate::{
+    client::ExecutionClient,
+    messages::{
+        order_event_topic, BatchAtomicity, BatchCancelOrders, CancelAllOrders, CancelOrder,
+        ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand,
+    },
+    snapshot::CacheSnapshot,
+    throttler::{OrderThrottler, ThrottleOutcome},
+};
+
+/// Provides a high-performance execution engine for managing many execution clients,
+/// and maintaining the state of orders from the events received from venues.
+///
+/// All order state transitions pass through the engine, which applies each event to
+/// the cached order before publishing it on the message bus. On startup (or after a
+/// reconnect) the engine reconciles cached orders against the venue reports, inferring
+/// any events which were missed.
+pub struct ExecutionEngine {
+    clients: IndexMap<ClientId, Box<dyn ExecutionClient>>,
+    default_client: Option<ClientId>,
+    routing_map: IndexMap<Venue, ClientId>,
+    batch_atomicity: IndexMap<Venue, BatchAtomicity>,
+    instruments: IndexMap<InstrumentId, Box<dyn Instrument>>,
+    orders: IndexMap<ClientOrderId, OrderAny>,
+    venue_order_ids: IndexMap<VenueOrderId, ClientOrderId>,
+    positions: IndexMap<PositionId, Position>,
+    accounts: IndexMap<AccountId, AccountState>,
+    throttler: Option<OrderThrottler>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    pub command_count: u64,
+    pub event_count: u64,
+    pub report_count: u64,
+    command_counter: Arc<Counter>,
+    event_counter: Arc<Counter>,
+    buffered_gauge: Arc<Gauge>,
+}
+
+impl ExecutionEngine {
+    #[must_use]
+    pub fn new(msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            clients: IndexMap::new(),
+            default_client: None,
+            routing_map: IndexMap::new(),
+            batch_atomicity: IndexMap::new(),
+            instruments: IndexMap::new(),
+            orders: IndexMap::new(),
+            venue_order_ids: IndexMap::new(),
+            positions: IndexMap::new(),
+            accounts: IndexMap::new(),
+            throttler: None,
+            msgbus,
+            command_count: 0,
+            event_count: 0,
+            report_count: 0,
+            command_counter: metrics::registry().counter(
+                "nautilus_exec_engine_commands_total",
+                "Trading commands executed by the execution engine",
+                &[],
+            ),
+            event_counter: metrics::registry().counter(
+                "nautilus_exec_engine_events_total",
+                "Order events processed by the execution engine",
+                &[],
+            ),
+            buffered_gauge: metrics::registry().gauge(
+                "nautilus_exec_throttler_buffered",
+                "Trading commands buffered by the order throttler",
+                &[],
+            ),
+        }
+    }
+
+    /// Returns the IDs of all registered clients.
+    #[must_use]
+    pub fn registered_clients(&self) -> Vec<ClientId> {
+        self.clients.keys().copied().collect()
+    }
+
+    /// Returns the ID of the default client (if registered).
+    #[must_use]
+    pub fn default_client(&self) -> Option<ClientId> {
+        self.default_client
+    }
+
+    /// Registers the given execution `client`, routing commands for `routing` venue to it
+    /// (if given, otherwise the clients own venue is used).
+    pub fn register_client(
+        &mut self,
+        client: Box<dyn ExecutionClient>,
+        routing: Option<Venue>,
+    ) -> Result<()> {
+        let client_id = client.client_id();
+        if self.clients.contains_key(&client_id) {
+            bail!("Execution client {client_id} already registered");
+        }
+
+        if let Some(venue) = routing.or_else(|| client.venue()) {
+            self.routing_map.insert(venue, client_id);
+        }
+
+        log::info!("Registered execution client {client_id}");
+        self.clients.insert(client_id, client);
+        Ok(())
+    }
+
+    /// Registers the given execution `client` as the default, used for commands which
+    /// cannot otherwise be routed to a client.
+    pub fn register_default_client(&mut self, client: Box<dyn ExecutionClient>) -> Result<()> {
+        let client_id = client.client_id();
+        self.register_client(client, None)?;
+        self.default_client = Some(client_id);
+        Ok(())
+    }
+
+    /// Deregisters the client with the given `client_id`, returning it.
+    pub fn deregister_client(&mut self, client_id: &ClientId) -> Result<Box<dyn ExecutionClient>> {
+        let client = match self.clients.shift_remove(client_id) {
+            Some(client) => client,
+            None => bail!("Execution client {client_id} not registered"),
+        };
+
+        self.routing_map
+            .retain(|_, routed_id| routed_id != client_id);
+        if self.default_client == Some(*client_id) {
+            self.default_client = None;
+        }
+
+        log::info!("Deregistered execution client {client_id}");
+        Ok(client)
+    }
+
+    /// Sets the atomicity of order lists and batch cancels for the given `venue`, which
+    /// should match the venue's own batch semantics.
+    pub fn set_batch_atomicity(&mut self, venue: Venue, atomicity: BatchAtomicity) {
+        self.batch_atomicity.insert(venue, atomicity);
+    }
+
+    /// Returns the atomicity of order lists and batch cancels for the given `venue`
+    /// (best-effort unless set).
+    #[must_use]
+    pub fn batch_atomicity(&self, venue: &Venue) -> BatchAtomicity {
+        self.batch_atomicity.get(venue).copied().unwrap_or_default()
+    }
+
+    /// Adds the given `instrument`, which is required to reconcile fills for its orders.
+    pub fn add_instrument(&mut self, instrument: Box<dyn Instrument>) {
+        self.instruments.insert(instrument.id(), instrument);
+    }
+
+    /// Returns the instrument for the given `instrument_id` (if added).
+    #[must_use]
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<&dyn Instrument> {
+        self.instruments.get(instrument_id).map(Box::as_ref)
+    }
+
+    /// Adds the given `order` to the engine, so that its events can be applied.
+    ///
+    /// Orders are added automatically on submission, this is only required for orders
+    /// which were submitted before the engine started.
+    pub fn add_order(&mut self, order: OrderAny) -> Result<()> {
+        let client_order_id = order.as_order().client_order_id();
+        if self.orders.contains_key(&client_order_id) {
+            bail!("Order {client_order_id} already exists");
+        }
+
+        if let Some(venue_order_id) = order.as_order().venue_order_id() {
+            self.venue_order_ids.insert(venue_order_id, client_order_id);
+        }
+        self.orders.insert(client_order_id, order);
+        Ok(())
+    }
+
+    /// Returns the order for the given `client_order_id` (if found).
+    #[must_use]
+    pub fn order(&self, client_order_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.orders.get(client_order_id)
+    }
+
+    /// Returns the client order ID for the given `venue_order_id` (if found).
+    #[must_use]
+    pub fn client_order_id(&self, venue_order_id: &VenueOrderId) -> Option<ClientOrderId> {
+        self.venue_order_ids.get(venue_order_id).copied()
+    }
+
+    /// Returns all orders which are open at their venue.
+    #[must_use]
+    pub fn orders_open(&self) -> Vec<&OrderAny> {
+        self.orders
+            .values()
+            .filter(|order| order.as_order().is_open())
+            .collect()
+    }
+
+    /// Adds the given `position` to the engine.
+    pub fn add_position(&mut self, position: Position) -> Result<()> {
+        if self.positions.contains_key(&position.id) {
+            bail!("Position {} already exists", position.id);
+        }
+
+        self.positions.insert(position.id, position);
+        Ok(())
+    }
+
+    /// Returns the position for the given `position_id` (if found).
+    #[must_use]
+    pub fn position(&self, position_id: &PositionId) -> Option<&Position> {
+        self.positions.get(position_id)
+    }
+
+    /// Updates the state of the account from the given `state` event.
+    pub fn update_account(&mut self, state: AccountState) {
+        self.accounts.insert(state.account_id, state);
+    }
+
+    /// Returns the latest state of the account for the given `account_id` (if found).
+    #[must_use]
+    pub fn account(&self, account_id: &AccountId) -> Option<&AccountState> {
+        self.accounts.get(account_id)
+    }
+
+    /// Returns a snapshot of the orders, positions and account states held by the engine.
+    ///
+    /// Timers are owned by the clock rather than the engine, see [`CacheSnapshot::add_timers`].
+    #[must_use]
+    pub fn snapshot(&self, ts_snapshot: UnixNanos) -> CacheSnapshot {
+        let trader_id = self.msgbus.borrow().trader_id;
+        let mut snapshot = CacheSnapshot::new(trader_id, ts_snapshot);
+        for order in self.orders.values() {
+            snapshot.add_order(order);
+        }
+        snapshot.positions.extend(self.positions.values().cloned());
+        snapshot.accounts.extend(self.accounts.values().cloned());
+        snapshot
+    }
+
+    /// Restores the orders, positions and account states from the given `snapshot`,
+    /// returning the number of orders restored.
+    ///
+    /// Orders, positions and accounts already held by the engine are left as they are.
+    pub fn restore(&mut self, snapshot: &CacheSnapshot) -> Result<usize> {
+        let orders = snapshot.restore_orders()?;
+
+        let mut position_count = 0;
+        for position in &snapshot.positions {
+            if self.positions.contains_key(&position.id) {
+                log::warn!("Position {} already exists, not restoring", position.id);
+                continue;
+            }
+            self.positions.insert(position.id, position.clone());
+            position_count += 1;
+        }
+
+        let mut account_count = 0;
+        for state in &snapshot.accounts {
+            if self.accounts.contains_key(&state.account_id) {
+                log::warn!("Account {} already exists, not restoring", state.account_id);
+                continue;
+            }
+            self.accounts.insert(state.account_id, state.clone());
+            account_count += 1;
+        }
+
+        let mut count = 0;
+        for order in orders {
+            let client_order_id = order.as_order().client_order_id();
+            if self.orders.contains_key(&client_order_id) {
+                log::warn!("Order {client_order_id} already exists, not restoring");
+                continue;
+            }
+            for venue_order_id in order.as_order().venue_order_ids() {
+                self.venue_order_ids
+                    .insert(*venue_order_id, client_order_id);
+            }
+            self.orders.insert(client_order_id, order);
+            count += 1;
+        }
+
+        log::info!(
+            "Restored {count} order(s), {position_count} position(s) and {account_count} \
+             account(s) from snapshot"
+        );
+        Ok(count)
+    }
+
+    /// Sets the `throttler` which rate limits commands before they are sent to clients.
+    pub fn set_throttler(&mut self, throttler: OrderThrottler) {
+        self.throttler = Some(throttler);
+    }
+
+    /// Returns the throttler (if set).
+    #[must_use]
+    pub fn throttler(&self) -> Option<&OrderThrottler> {
+        self.throttler.as_ref()
+    }
+
+    /// Executes the given trading `command`.
+    ///
+    /// If a throttler is set, the command is first passed through it (using the commands
+    /// `ts_init` as the current time).
+    pub fn execute(&mut self, command: TradingCommand) -> Result<()> {
+        self.command_count += 1;
+        if metrics::is_enabled() {
+            self.command_counter.inc();
+        }
+
+        let Some(throttler) = self.throttler.as_mut() else {
+            return self.dispatch(command);
+        };
+
+        let ts_now = command.ts_init();
+        match throttler.send(command, ts_now) {
+            ThrottleOutcome::Ready(command) => self.dispatch(command),
+            ThrottleOutcome::Buffered => {
+                record_queue_depth(&self.buffered_gauge, throttler);
+                Ok(())
+            }
+            ThrottleOutcome::Dropped(command) => {
+                log::warn!("Dropped {command}: rate limit exceeded");
+                Ok(())
+            }
+            ThrottleOutcome::Rejected(command) => self.reject_throttled(command),
+        }
+    }
+
+    /// Sends any buffered commands which are now within the throttler rate limits.
+    pub fn process_throttled(&mut self, ts_now: UnixNanos) -> Result<()> {
+        let Some(throttler) = self.throttler.as_mut() else {
+            return Ok(());
+        };
+
+        let commands = throttler.drain(ts_now);
+        record_queue_depth(&self.buffered_gauge, throttler);
+
+        for command in commands {
+            if let Err(e) = self.dispatch(command) {
+                log::error!("Error sending throttled command: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Cancels all open (and in-flight) orders for the given `venue`, returning the number
+    /// of cancels sent.
+    ///
+    /// Cancels are dispatched directly to the client, bypassing any throttler, as this is
+    /// called when a dead man's switch triggers and there is no time to wait on rate limits.
+    pub fn cancel_venue_orders(&mut self, venue: Venue, ts_init: UnixNanos) -> Result<usize> {
+        let commands: Vec<CancelOrder> = self
+            .orders
+            .values()
+            .map(OrderAny::as_order)
+            .filter(|order| {
+                order.instrument_id().venue == venue && (order.is_open() || order.is_inflight())
+            })
+            .map(|order| {
+                CancelOrder::new(
+                    order.trader_id(),
+                    None,
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    order.venue_order_id(),
+                    UUID4::new(),
+                    ts_init,
+                )
+            })
+            .collect();
+
+        log::warn!("Canceling {} open order(s) for {venue}", commands.len());
+        let mut sent = 0;
+        for command in commands {
+            self.command_count += 1;
+            match self.handle_cancel_order(&command) {
+                Ok(()) => sent += 1,
+                Err(e) => log::error!("Error canceling order {}: {e}", command.client_order_id),
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Processes the given order `event`, applying it to the cached order and then
+    /// publishing it on the message bus.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order for the event is not found.
+    /// - If the event is not a valid transition from the current order status, in
+    ///   which case the order is left unchanged and the event is not published.
+    pub fn process(&mut self, event: &OrderEvent) -> Result<()> {
+        self.event_count += 1;
+        if metrics::is_enabled() {
+            self.event_counter.inc();
+        }
+
+        let client_order_id = event.client_order_id();
+        let Some(order) = self.orders.get_mut(&client_order_id) else {
+            bail!("Cannot apply event: order {client_order_id} not found");
+        };
+
+        let status = order.as_order().status();
+        if let Err(e) = order.as_order_mut().apply(event.clone()) {
+            bail!("Cannot apply event to order {client_order_id} with status {status}: {e}");
+        }
+
+        if let Some(venue_order_id) = order.as_order().venue_order_id() {
+            self.venue_order_ids.insert(venue_order_id, client_order_id);
+        }
+
+        if metrics::is_enabled() {
+            record_round_trip(order, event);
+        }
+
+        let topic = order_event_topic(&event.strategy_id());
+        self.msgbus.borrow_mut().publish(&topic, event);
+        Ok(())
+    }
+
+    /// Reconciles the cached orders against the latest reports from all registered clients,
+    /// returning the inferred events which were applied.
+    pub fn reconcile_state(&mut self, ts_init: UnixNanos) -> Result<Vec<OrderEvent>> {
+        let mut order_reports = Vec::new();
+        let mut fill_reports = Vec::new();
+        for client in self.clients.values() {
+            order_reports.extend(client.generate_order_status_reports()?);
+            fill_reports.extend(client.generate_fill_reports()?);
+        }
+
+        Ok(self.reconcile_reports(&order_reports, &fill_reports, ts_init))
+    }
+
+    /// Reconciles the cached orders against the given venue reports, returning the inferred
+    /// events which were applied.
+    ///
+    /// Events are inferred so that each order reaches the status and filled quantity
+    /// reported by the venue. Fill reports are applied individually where available, with
+    /// any remaining filled quantity applied as a single inferred fill at the reported
+    /// average price. Reports for orders which are not cached (external orders) are skipped.
+    pub fn reconcile_reports(
+        &mut self,
+        order_reports: &[OrderStatusReport],
+        fill_reports: &[FillReport],
+        ts_init: UnixNanos,
+    ) -> Vec<OrderEvent> {
+        let mut events = Vec::new();
+        let mut fills: Vec<&FillReport> = fill_reports.iter().collect();
+        fills.sort_by_key(|fill| fill.ts_event);
+
+        for report in order_reports {
+            self.report_count += 1;
+
+            let Some(client_order_id) =
+                self.resolve_order_id(report.client_order_id, &report.venue_order_id)
+            else {
+                log::warn!("Cannot reconcile {report}: order not found");
+                continue;
+            };
+
+            let (order_fills, remaining): (Vec<&FillReport>, Vec<&FillReport>) = fills
+                .into_iter()
+                .partition(|fill| fill.venue_order_id == report.venue_order_id);
+            fills = remaining;
+            let order_fills: Vec<FillReport> = order_fills.into_iter().copied().collect();
+
+            self.reconcile_order(client_order_id, report, &order_fills, ts_init, &mut events);
+        }
+
+        // Fills for orders which were not reported (typically because they are now closed)
+        for fill in fills {
+            self.report_count += 1;
+
+            let Some(client_order_id) =
+                self.resolve_order_id(fill.client_order_id, &fill.venue_order_id)
+            else {
+                log::warn!("Cannot reconcile {fill}: order not found");
+                continue;
+            };
+
+            self.reconcile_fill(client_order_id, fill, ts_init, &mut events);
+        }
+
+        events
+    }
+
+    fn resolve_client(&self, client_id: Option<ClientId>, venue: Venue) -> Result<ClientId> {
+        if let Some(client_id) = client_id {
+            if self.clients.contains_key(&client_id) {
+                return Ok(client_id);
+            }
+            bail!("Execution client {client_id} not registered");
+        }
+
+        if let Some(client_id) = self.routing_map.get(&venue) {
+            return Ok(*client_id);
+        }
+
+        match self.default_client {
+            Some(client_id) => Ok(client_id),
+            None => bail!("No execution client to route command for venue {venue}"),
+        }
+    }
+
+    fn resolve_order_id(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<ClientOrderId> {
+        client_order_id
+            .filter(|id| self.orders.contains_key(id))
+            .or_else(|| self.client_order_id(venue_order_id))
+    }
+
+    fn dispatch(&mut self, command: TradingCommand) -> Result<()> {
+        match command {
+            TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(&cmd),
+            TradingCommand::SubmitOrderList(cmd) => self.handle_submit_order_list(&cmd),
+            TradingCommand::ModifyOrder(cmd) => self.handle_modify_order(&cmd),
+            TradingCommand::CancelOrder(cmd) => self.handle_cancel_order(&cmd),
+            TradingCommand::CancelAllOrders(cmd) => self.handle_cancel_all_orders(&cmd),
+            TradingCommand::BatchCancelOrders(cmd) => self.handle_batch_cancel_orders(&cmd),
+        }
+    }
+
+    fn reject_throttled(&mut self, command: TradingCommand) -> Result<()> {
+        let (orders, ts_init) = match command {
+            TradingCommand::SubmitOrder(cmd) => (vec![cmd.order], cmd.ts_init),
+            TradingCommand::SubmitOrderList(cmd) => (cmd.orders, cmd.ts_init),
+            cmd => bail!("Cannot send {cmd}: rate limit exceeded"),
+        };
+
+        for order in orders {
+            let client_order_id = order.as_order().client_order_id();
+            self.add_order(order)?;
+            self.deny_order(client_order_id, "Rate limit exceeded", ts_init)?;
+        }
+        Ok(())
+    }
+
+    fn deny_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        reason: &str,
+        ts_init: UnixNanos,
+    ) -> Result<()> {
+        let order = self.orders[&client_order_id].as_order();
+        let denied = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_init,
+            ts_init,
+        )
+        .unwrap();
+        self.process(&OrderEvent::OrderDenied(denied))
+    }
+
+    fn handle_submit_order(&mut self, cmd: &SubmitOrder) -> Result<()> {
+        let client_id = self.resolve_client(cmd.client_id, cmd.instrument_id().venue)?;
+        self.add_order(cmd.order.clone())?;
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        if let Err(e) = client.submit_order(cmd) {
+            let reason = format!("Submit failed at {client_id}: {e}");
+            self.deny_order(cmd.client_order_id(), &reason, cmd.ts_init)?;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn handle_submit_order_list(&mut self, cmd: &SubmitOrderList) -> Result<()> {
+        let client_id = self.resolve_client(cmd.client_id, cmd.instrument_id.venue)?;
+
+        if self.batch_atomicity(&cmd.instrument_id.venue) == BatchAtomicity::BestEffort {
+            for submit in cmd.split() {
+                if let Err(e) = self.handle_submit_order(&submit) {
+                    log::error!("Error submitting {submit} from {cmd}: {e}");
+                }
+            }
+            return Ok(());
+        }
+
+        // All-or-none: the list is denied as a whole if any one order cannot be submitted
+        let mut client_order_ids: Vec<ClientOrderId> = Vec::with_capacity(cmd.orders.len());
+        let mut rejections: Vec<String> = Vec::new();
+        for order in &cmd.orders {
+            let order = order.as_order();
+            let client_order_id = order.client_order_id();
+            if self.orders.contains_key(&client_order_id)
+                || client_order_ids.contains(&client_order_id)
+            {
+                rejections.push(format!("duplicate order {client_order_id}"));
+                continue;
+            }
+            if order.instrument_id() != cmd.instrument_id {
+                rejections.push(format!(
+                    "order {client_order_id} is for {}, not {}",
+                    order.instrument_id(),
+                    cmd.instrument_id
+                ));
+            }
+            client_order_ids.push(client_order_id);
+        }
+
+        for order in &cmd.orders {
+            let client_order_id = order.as_order().client_order_id();
+            if client_order_ids.contains(&client_order_id)
+                && !self.orders.contains_key(&client_order_id)
+            {
+                self.add_order(order.clone())?;
+            }
+        }
+
+        let error = match rejections.first() {
+            Some(reason) => anyhow::anyhow!("Cannot submit {cmd}: {reason}"),
+            None => {
+                // SAFETY: Client was resolved from registered clients above
+                let client = self.clients.get_mut(&client_id).unwrap();
+                match client.submit_order_list(cmd) {
+                    Ok(()) => return Ok(()),
+                    Err(e) => e.context(format!("Submit failed at {client_id}")),
+                }
+            }
+        };
+
+        let reason = format!("Order list {} denied: {error:#}", cmd.order_list_id);
+        for client_order_id in client_order_ids {
+            self.deny_order(client_order_id, &reason, cmd.ts_init)?;
+        }
+        Err(error)
+    }
+
+    /// Marks the order as `PENDING_UPDATE` while the modify is in flight, with the venue
+    /// then completing the request with `OrderUpdated` (or `OrderModifyRejected`).
+    fn handle_modify_order(&mut self, cmd: &ModifyOrder) -> Result<()> {
+        let client_id = self.resolve_client(cmd.client_id, cmd.instrument_id.venue)?;
+
+        let Some(order) = self
+            .orders
+            .get(&cmd.client_order_id)
+            .map(OrderAny::as_order)
+        else {
+            bail!("Cannot modify order {}: not found", cmd.client_order_id);
+        };
+        if order.is_closed() || order.is_pending_cancel() {
+            log::warn!(
+                "Cannot modify order {}: status {}",
+                cmd.client_order_id,
+                order.status()
+            );
+            return Ok(());
+        }
+        let is_unchanged = cmd.quantity.map_or(true, |qty| qty == order.quantity())
+            && cmd.price.map_or(true, |px| Some(px) == order.price())
+            && cmd
+                .trigger_price
+                .map_or(true, |px| Some(px) == order.trigger_price());
+        if is_unchanged {
+            log::warn!(
+                "Cannot modify order {}: nothing to change",
+                cmd.client_order_id
+            );
+            return Ok(());
+        }
+
+        // SAFETY: Client was resolved from registered clients above
+        let account_id = self.clients[&client_id].account_id();
+        let pending_update = (!order.is_pending_update()).then(|| {
+            OrderPendingUpdate::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                cmd.client_order_id,
+                account_id,
+                UUID4::new(),
+                cmd.ts_init,
+                cmd.ts_init,
+                false,
+                order.venue_order_id(),
+            )
+            .unwrap()
+        });
+        if let Some(event) = pending_update {
+            self.process(&OrderEvent::OrderPendingUpdate(event))?;
+        }
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        if let Err(e) = client.modify_order(cmd) {
+            let reason = format!("Modify failed at {client_id}: {e}");
+            self.reject_modify(cmd, account_id, &reason)?;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    fn reject_modify(
+        &mut self,
+        cmd: &ModifyOrder,
+        account_id: AccountId,
+        reason: &str,
+    ) -> Result<()> {
+        let order = self.orders[&cmd.client_order_id].as_order();
+        let rejected = OrderModifyRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            cmd.client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            cmd.ts_init,
+            cmd.ts_init,
+            false,
+            order.venue_order_id(),
+            Some(account_id),
+        )
+        .unwrap();
+        self.process(&OrderEvent::OrderModifyRejected(rejected))
+    }
+
+    fn handle_cancel_order(&mut self, cmd: &CancelOrder) -> Result<()> {
+        let client_id = self.resolve_client(cmd.client_id, cmd.instrument_id.venue)?;
+
+        match self.orders.get(&cmd.client_order_id) {
+            Some(order) if order.as_order().is_closed() => {
+                log::warn!(
+                    "Cannot cancel order {}: already closed",
+                    cmd.client_order_id
+                );
+                return Ok(());
+            }
+            Some(_) => {}
+            None => bail!("Cannot cancel order {}: not found", cmd.client_order_id),
+        }
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        client.cancel_order(cmd)
+    }
+
+    fn handle_cancel_all_orders(&mut self, cmd: &CancelAllOrders) -> Result<()> {
+        let cancels: Vec<CancelOrder> = self
+            .orders
+            .values()
+            .map(OrderAny::as_order)
+            .filter(|order| {
+                order.instrument_id() == cmd.instrument_id
+                    && order.strategy_id() == cmd.strategy_id
+                    && (cmd.order_side == OrderSide::NoOrderSide || order.side() == cmd.order_side)
+                    && (order.is_open() || order.is_inflight())
+            })
+            .map(|order| {
+                CancelOrder::new(
+                    cmd.trader_id,
+                    cmd.client_id,
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    order.client_order_id(),
+                    order.venue_order_id(),
+                    cmd.command_id,
+                    cmd.ts_init,
+                )
+            })
+            .collect();
+
+        if cancels.is_empty() {
+            log::info!("No open orders to cancel for {cmd}");
+            return Ok(());
+        }
+
+        self.handle_batch_cancel_orders(&BatchCancelOrders::new(
+            cmd.trader_id,
+            cmd.client_id,
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cancels,
+            cmd.command_id,
+            cmd.ts_init,
+        ))
+    }
+
+    fn handle_batch_cancel_orders(&mut self, cmd: &BatchCancelOrders) -> Result<()> {
+        let client_id = self.resolve_client(cmd.client_id, cmd.instrument_id.venue)?;
+
+        let (cancels, invalid): (Vec<CancelOrder>, Vec<CancelOrder>) =
+            cmd.cancels.iter().copied().partition(|cancel| {
+                self.orders
+                    .get(&cancel.client_order_id)
+                    .map_or(false, |order| !order.as_order().is_closed())
+            });
+
+        if let Some(cancel) = invalid.first() {
+            if self.batch_atomicity(&cmd.instrument_id.venue) == BatchAtomicity::AllOrNone {
+                bail!(
+                    "Cannot send {cmd}: order {} not found or already closed",
+                    cancel.client_order_id
+                );
+            }
+            for cancel in &invalid {
+                log::warn!(
+                    "Cannot cancel order {}: not found or already closed",
+                    cancel.client_order_id
+                );
+            }
+        }
+
+        if cancels.is_empty() {
+            return Ok(());
+        }
+
+        let batch = BatchCancelOrders {
+            cancels,
+            ..cmd.clone()
+        };
+
+        // SAFETY: Client was resolved from registered clients above
+        let client = self.clients.get_mut(&client_id).unwrap();
+        client.batch_cancel_orders(&batch)
+    }
+
+    fn reconcile_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        ts_init: UnixNanos,
+        events: &mut Vec<OrderEvent>,
+    ) {
+        // SAFETY: Order ID was resolved from cached orders
+        let order = &self.orders[&client_order_id];
+        let instrument_id = order.as_order().instrument_id();
+        let Some(instrument) = self.instruments.get(&instrument_id) else {
+            log::error!("Cannot reconcile {report}: instrument {instrument_id} not found");
+            return;
+        };
+
+        let inferred = match reconcile_order(order, report, fills, instrument.as_ref(), ts_init) {
+            Ok(inferred) => inferred,
+            Err(e) => {
+                log::error!("Cannot reconcile {report}: {e}");
+                return;
+            }
+        };
+        if inferred.is_empty() {
+            log::debug!("Order {client_order_id} already reconciled");
+        }
+
+        for event in inferred {
+            if !self.apply_inferred(event, events) {
+                break;
+            }
+        }
+
+        let status = self.orders[&client_order_id].as_order().status();
+        if status != report.order_status {
+            log::warn!(
+                "Order {client_order_id} status {status} inconsistent with venue status {}",
+                report.order_status
+            );
+        }
+    }
+
+    fn reconcile_fill(
+        &mut self,
+        client_order_id: ClientOrderId,
+        fill: &FillReport,
+        ts_init: UnixNanos,
+        events: &mut Vec<OrderEvent>,
+    ) {
+        // SAFETY: Order ID was resolved from cached orders
+        let order = &self.orders[&client_order_id];
+        let instrument_id = order.as_order().instrument_id();
+        let Some(instrument) = self.instruments.get(&instrument_id) else {
+            log::error!("Cannot reconcile {fill}: instrument {instrument_id} not found");
+            return;
+        };
+
+        match reconcile_fill(order, fill, instrument.as_ref(), ts_init) {
+            Ok(Some(filled)) => {
+                self.apply_inferred(OrderEvent::OrderFilled(filled), events);
+            }
+            Ok(None) => log::debug!(
+                "Fill {} already applied to {client_order_id}",
+                fill.trade_id
+            ),
+            Err(e) => log::error!("Cannot reconcile {fill}: {e}"),
+        }
+    }
+
+    fn apply_inferred(&mut self, event: OrderEvent, events: &mut Vec<OrderEvent>) -> bool {
+        match self.process(&event) {
+            Ok(()) => {
+                events.push(event);
+                true
+            }
+            Err(e) => {
+                log::error!("Error reconciling order: {e}");
+                false
+            }
+        }
+    }
+}
+
+fn record_queue_depth(gauge: &Gauge, throttler: &OrderThrottler) {
+    if metrics::is_enabled() {
+        gauge.set(throttler.buffered_total() as i64);
+    }
+}
+
+/// Records the round-trip latency from submission to the venue's acknowledgement.
+fn record_round_trip(order: &OrderAny, event: &OrderEvent) {
+    if !matches!(
+        event,
+        OrderEvent::OrderAccepted(_) | OrderEvent::OrderRejected(_)
+    ) {
+        return;
+    }
+
+    let ts_submitted = order.as_order().events().iter().find_map(|e| match e {
+        OrderEvent::OrderSubmitted(submitted) => Some(submitted.ts_event),
+        _ => None,
+    });
+    if let Some(ts_submitted) = ts_submitted {
+        let venue = order.as_order().instrument_id().venue.to_string();
+        metrics::observe_latency(
+            "nautilus_order_round_trip_seconds",
+            "Latency from order submission to acceptance or rejection",
+            &[("venue", &venue)],
+            event.ts_event().saturating_sub(ts_submitted) as f64 / 1_000_000_000.0,
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::testing::recording_handler;
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, OrderStatus},
+        events::{
+            account::stubs::cash_account_state,
+            order::{accepted::OrderAccepted, submitted::OrderSubmitted, updated::OrderUpdated},
+        },
+        identifiers::{
+            account_id::AccountId, order_list_id::OrderListId, trade_id::TradeId,
+            trader_id::TraderId,
+        },
+        instruments::{currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::throttler::{
+        OrderThrottlerConfig, RateLimit, ThrottleLimits, ThrottleOverflowPolicy,
+    };
+
+    type CallLog = Rc<RefCell<Vec<String>>>;
+
+    struct MockExecutionClient {
+        client_id: ClientId,
+        venue: Option<Venue>,
+        calls: CallLog,
+        fail_submit: bool,
+        fail_modify: bool,
+        order_reports: Vec<OrderStatusReport>,
+        fill_reports: Vec<FillReport>,
+    }
+
+    impl ExecutionClient for MockExecutionClient {
+        fn client_id(&self) -> ClientId {
+            self.client_id
+        }
+
+        fn account_id(&self) -> AccountId {
+            AccountId::from("SIM-001")
+        }
+
+        fn venue(&self) -> Option<Venue> {
+            self.venue
+        }
+
+        fn submit_order(&mut self, command: &SubmitOrder) -> Result<()> {
+            if self.fail_submit {
+                bail!("connection lost");
+            }
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:{command}", self.client_id));
+            Ok(())
+        }
+
+        fn modify_order(&mut self, command: &ModifyOrder) -> Result<()> {
+            if self.fail_modify {
+                bail!("order not found at venue");
+            }
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:{command}", self.client_id));
+            Ok(())
+        }
+
+        fn cancel_order(&mut self, command: &CancelOrder) -> Result<()> {
+            self.calls
+                .borrow_mut()
+                .push(format!("{}:{command}", self.client_id));
+            Ok(())
+        }
+
+        fn generate_order_status_reports(&self) -> Result<Vec<OrderStatusReport>> {
+            Ok(self.order_reports.clone())
+        }
+
+        fn generate_fill_reports(&self) -> Result<Vec<FillReport>> {
+            Ok(self.fill_reports.clone())
+        }
+    }
+
+    fn mock_client(calls: &CallLog) -> MockExecutionClient {
+        MockExecutionClient {
+            client_id: ClientId::from("SIM"),
+            venue: Some(Venue::from("SIM")),
+            calls: calls.clone(),
+            fail_submit: false,
+            fail_modify: false,
+            order_reports: Vec::new(),
+            fill_reports: Vec::new(),
+        }
+    }
+
+    fn market_order(instrument: &CurrencyPair) -> OrderAny {
+        TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        )
+        .into()
+    }
+
+    fn order_list(instrument: &CurrencyPair, ids: &[&str]) -> Vec<OrderAny> {
+        ids.iter()
+            .map(|id| {
+                TestOrderStubs::market_order(
+                    instrument.id,
+                    OrderSide::Buy,
+                    Quantity::from(100_000),
+                    Some(ClientOrderId::from(*id)),
+                    None,
+                )
+                .into()
+            })
+            .collect()
+    }
+
+    fn submit_list(orders: Vec<OrderAny>) -> TradingCommand {
+        TradingCommand::SubmitOrderList(SubmitOrderList::new(
+            TraderId::from("TRADER-001"),
+            None,
+            OrderListId::from("OL-1"),
+            orders,
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    fn submit(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(SubmitOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            order.clone(),
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    fn submitted(order: &OrderAny) -> OrderEvent {
+        let order = order.as_order();
+        OrderEvent::OrderSubmitted(
+            OrderSubmitted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                AccountId::from("SIM-001"),
+                UUID4::new(),
+                1,
+                1,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn accepted(order: &OrderAny) -> OrderEvent {
+        let order = order.as_order();
+        OrderEvent::OrderAccepted(
+            OrderAccepted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::from("V-1"),
+                AccountId::from("SIM-001"),
+                UUID4::new(),
+                2,
+                2,
+                false,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn modify(order: &OrderAny, quantity: Quantity) -> TradingCommand {
+        let order = order.as_order();
+        TradingCommand::ModifyOrder(ModifyOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id(),
+            Some(quantity),
+            None,
+            None,
+            UUID4::new(),
+            3,
+        ))
+    }
+
+    fn status_report(
+        order: &OrderAny,
+        order_status: OrderStatus,
+        filled_qty: i64,
+    ) -> OrderStatusReport {
+        let order = order.as_order();
+        let mut report = OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            order.instrument_id(),
+            Some(order.client_order_id()),
+            VenueOrderId::from("V-1"),
+            order.side(),
+            order.order_type(),
+            order.time_in_force(),
+            order_status,
+            order.quantity(),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            2,
+            5,
+            10,
+        );
+        report.avg_px = Some(0.80001);
+        report
+    }
+
+    fn fill_report(trade_id: &str, last_qty: i64) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::from("V-1"),
+            None,
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("0.80000"),
+            Money::new(1.0, Currency::USD()).unwrap(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            3,
+            10,
+        )
+    }
+
+    #[fixture]
+    fn msgbus() -> Rc<RefCell<MessageBus>> {
+        Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        )))
+    }
+
+    #[fixture]
+    fn engine(msgbus: Rc<RefCell<MessageBus>>, audusd_sim: CurrencyPair) -> ExecutionEngine {
+        let mut engine = ExecutionEngine::new(msgbus);
+        engine.add_instrument(Box::new(audusd_sim));
+        engine
+    }
+
+    #[rstest]
+    fn test_submit_order_routes_to_venue_client(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let order = market_order(&audusd_sim);
+
+        engine.execute(submit(&order)).unwrap();
+
+        let client_order_id = order.as_order().client_order_id();
+        assert_eq!(engine.command_count, 1);
+        assert_eq!(
+            *calls.borrow(),
+            vec![format!(
+                "SIM:SubmitOrder(instrument_id=AUD/USD.SIM, client_order_id={client_order_id})"
+            )]
+        );
+        assert_eq!(
+            engine.order(&client_order_id).unwrap().as_order().status(),
+            OrderStatus::Initialized
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_failure_denies_order(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        let mut client = mock_client(&calls);
+        client.fail_submit = true;
+        engine.register_client(Box::new(client), None).unwrap();
+        let order = market_order(&audusd_sim);
+
+        let result = engine.execute(submit(&order));
+
+        assert!(result.is_err());
+        let order = engine.order(&order.as_order().client_order_id()).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Denied);
+    }
+
+    #[rstest]
+    fn test_submit_order_without_client_fails(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = market_order(&audusd_sim);
+
+        assert!(engine.execute(submit(&order)).is_err());
+        assert!(engine.order(&order.as_order().client_order_id()).is_none());
+    }
+
+    #[rstest]
+    fn test_process_applies_events_and_publishes(
+        msgbus: Rc<RefCell<MessageBus>>,
+        audusd_sim: CurrencyPair,
+    ) {
+        let (handler, received) = recording_handler::<OrderEvent>("order-event-handler");
+        msgbus
+            .borrow_mut()
+            .subscribe("events.order.*", handler, None);
+        let mut engine = ExecutionEngine::new(msgbus);
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        let client_order_id = order.as_order().client_order_id();
+        let cached = engine.order(&client_order_id).unwrap();
+        assert_eq!(cached.as_order().status(), OrderStatus::Accepted);
+        assert_eq!(
+            engine.client_order_id(&VenueOrderId::from("V-1")),
+            Some(client_order_id)
+        );
+        assert_eq!(engine.orders_open().len(), 1);
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[rstest]
+    fn test_process_invalid_transition_is_not_applied(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        let result = engine.process(&submitted(&order));
+
+        assert!(result.is_err());
+        let cached = engine.order(&order.as_order().client_order_id()).unwrap();
+        assert_eq!(cached.as_order().status(), OrderStatus::Accepted);
+        assert_eq!(cached.as_order().event_count(), 2);
+    }
+
+    #[rstest]
+    fn test_cancel_closed_order_is_not_routed(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        let report = status_report(&order, OrderStatus::Canceled, 0);
+        engine.reconcile_reports(&[report], &[], 10);
+        let order = order.as_order();
+        let cancel = CancelOrder::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            None,
+            UUID4::new(),
+            20,
+        );
+
+        engine.execute(TradingCommand::CancelOrder(cancel)).unwrap();
+
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_order_is_pending_update_until_updated(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        engine
+            .execute(modify(&order, Quantity::from(50_000)))
+            .unwrap();
+
+        let client_order_id = order.as_order().client_order_id();
+        assert_eq!(
+            *calls.borrow(),
+            vec![format!(
+                "SIM:ModifyOrder(instrument_id=AUD/USD.SIM, client_order_id={client_order_id}, quantity=50000, price=None, trigger_price=None)"
+            )]
+        );
+        let cached = engine.order(&client_order_id).unwrap().as_order();
+        assert_eq!(cached.status(), OrderStatus::PendingUpdate);
+        assert!(cached.is_inflight());
+
+        let order = order.as_order();
+        let updated = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            Quantity::from(50_000),
+            UUID4::new(),
+            4,
+            4,
+            false,
+            Some(VenueOrderId::from("V-1")),
+            Some(AccountId::from("SIM-001")),
+            None,
+            None,
+        )
+        .unwrap();
+        engine.process(&OrderEvent::OrderUpdated(updated)).unwrap();
+
+        let cached = engine.order(&client_order_id).unwrap().as_order();
+        assert_eq!(cached.status(), OrderStatus::Accepted);
+        assert_eq!(cached.quantity(), Quantity::from(50_000));
+    }
+
+    #[rstest]
+    fn test_modify_order_failure_rejects_modify(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        let mut client = mock_client(&calls);
+        client.fail_modify = true;
+        engine.register_client(Box::new(client), None).unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        let result = engine.execute(modify(&order, Quantity::from(50_000)));
+
+        assert!(result.is_err());
+        let cached = engine
+            .order(&order.as_order().client_order_id())
+            .unwrap()
+            .as_order();
+        assert_eq!(cached.status(), OrderStatus::Accepted);
+        assert_eq!(cached.quantity(), Quantity::from(100_000));
+        assert!(matches!(
+            cached.last_event(),
+            OrderEvent::OrderModifyRejected(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_modify_order_without_changes_is_not_routed(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        engine
+            .execute(modify(&order, Quantity::from(100_000)))
+            .unwrap();
+
+        assert!(calls.borrow().is_empty());
+        let cached = engine
+            .order(&order.as_order().client_order_id())
+            .unwrap()
+            .as_order();
+        assert_eq!(cached.status(), OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_reconcile_applies_fill_reports(mut engine: ExecutionEngine, audusd_sim: CurrencyPair) {
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        let report = status_report(&order, OrderStatus::PartiallyFilled, 40_000);
+        let fill = fill_report("T-1", 40_000);
+
+        let events = engine.reconcile_reports(&[report], &[fill], 10);
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        assert!(matches!(&events[1], OrderEvent::OrderFilled(e) if e.trade_id == fill.trade_id));
+        let cached = engine.order(&order.as_order().client_order_id()).unwrap();
+        assert_eq!(cached.as_order().status(), OrderStatus::PartiallyFilled);
+        assert_eq!(cached.as_order().filled_qty(), Quantity::from(40_000));
+        assert_eq!(engine.report_count, 1);
+    }
+
+    #[rstest]
+    fn test_reconcile_infers_missing_fill_and_cancel(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+        let report = status_report(&order, OrderStatus::Canceled, 50_000);
+
+        let events = engine.reconcile_reports(&[report], &[], 10);
+
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            OrderEvent::OrderFilled(fill) => {
+                assert_eq!(fill.last_qty, Quantity::from(50_000));
+                assert_eq!(fill.last_px, Price::from("0.80001"));
+                assert_eq!(fill.reconciliation, 1);
+            }
+            event => panic!("Expected inferred fill, was {event:?}"),
+        }
+        assert!(matches!(events[1], OrderEvent::OrderCanceled(_)));
+        let cached = engine.order(&order.as_order().client_order_id()).unwrap();
+        assert_eq!(cached.as_order().status(), OrderStatus::Canceled);
+        assert_eq!(cached.as_order().filled_qty(), Quantity::from(50_000));
+    }
+
+    #[rstest]
+    fn test_reconcile_consistent_order_generates_no_events(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+        let report = status_report(&order, OrderStatus::Accepted, 0);
+
+        let events = engine.reconcile_reports(&[report], &[], 10);
+
+        assert!(events.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_state_applies_fills_for_unreported_orders(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        let mut client = mock_client(&calls);
+        client.fill_reports = vec![fill_report("T-1", 60_000), fill_report("T-2", 40_000)];
+        engine.register_client(Box::new(client), None).unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        let events = engine.reconcile_state(10).unwrap();
+        // Fills which were already applied are not applied twice
+        let repeated = engine.reconcile_state(20).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert!(repeated.is_empty());
+        let cached = engine.order(&order.as_order().client_order_id()).unwrap();
+        assert_eq!(cached.as_order().status(), OrderStatus::Filled);
+        assert!(engine.orders_open().is_empty());
+    }
+
+    fn throttler(overflow_policy: ThrottleOverflowPolicy) -> OrderThrottler {
+        OrderThrottler::new(OrderThrottlerConfig {
+            limits: ThrottleLimits {
+                burst: RateLimit::new(1, 1_000_000_000),
+                sustained: RateLimit::new(10, 60_000_000_000),
+            },
+            venue_limits: IndexMap::new(),
+            overflow_policy,
+        })
+    }
+
+    fn market_orders(instrument: &CurrencyPair) -> (OrderAny, OrderAny) {
+        let order = |id: &str| -> OrderAny {
+            TestOrderStubs::market_order(
+                instrument.id,
+                OrderSide::Buy,
+                Quantity::from(100_000),
+                Some(ClientOrderId::from(id)),
+                None,
+            )
+            .into()
+        };
+        (order("O-1"), order("O-2"))
+    }
+
+    #[rstest]
+    fn test_throttled_submit_is_denied(mut engine: ExecutionEngine, audusd_sim: CurrencyPair) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        engine.set_throttler(throttler(ThrottleOverflowPolicy::Reject));
+        let (order1, order2) = market_orders(&audusd_sim);
+
+        engine.execute(submit(&order1)).unwrap();
+        engine.execute(submit(&order2)).unwrap();
+
+        assert_eq!(calls.borrow().len(), 1);
+        let denied = engine.order(&ClientOrderId::from("O-2")).unwrap();
+        assert_eq!(denied.as_order().status(), OrderStatus::Denied);
+    }
+
+    #[rstest]
+    fn test_throttled_submit_is_buffered(mut engine: ExecutionEngine, audusd_sim: CurrencyPair) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        engine.set_throttler(throttler(ThrottleOverflowPolicy::Buffer));
+        let (order1, order2) = market_orders(&audusd_sim);
+
+        engine.execute(submit(&order1)).unwrap();
+        engine.execute(submit(&order2)).unwrap();
+        assert_eq!(calls.borrow().len(), 1);
+        assert!(engine.order(&ClientOrderId::from("O-2")).is_none());
+
+        engine.process_throttled(1_000_000_000).unwrap();
+
+        assert_eq!(calls.borrow().len(), 2);
+        assert!(engine.order(&ClientOrderId::from("O-2")).is_some());
+    }
+
+    #[rstest]
+    #[case(ThrottleOverflowPolicy::Drop)]
+    #[case(ThrottleOverflowPolicy::Reject)]
+    fn test_throttled_cancel_is_buffered_regardless_of_overflow_policy(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+        #[case] overflow_policy: ThrottleOverflowPolicy,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let (order1, order2) = market_orders(&audusd_sim);
+        for order in [&order1, &order2] {
+            engine.add_order(order.clone()).unwrap();
+            engine.process(&submitted(order)).unwrap();
+            engine.process(&accepted(order)).unwrap();
+        }
+        engine.set_throttler(throttler(overflow_policy));
+        let cancel = |order: &OrderAny| {
+            let order = order.as_order();
+            TradingCommand::CancelOrder(CancelOrder::new(
+                order.trader_id(),
+                None,
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                None,
+                UUID4::new(),
+                0,
+            ))
+        };
+
+        engine.execute(cancel(&order1)).unwrap();
+        engine.execute(cancel(&order2)).unwrap();
+        assert_eq!(calls.borrow().len(), 1);
+        assert_eq!(engine.throttler().unwrap().buffered_total(), 1);
+
+        engine.process_throttled(1_000_000_000).unwrap();
+
+        assert_eq!(
+            calls.borrow().last().unwrap(),
+            "SIM:CancelOrder(instrument_id=AUD/USD.SIM, client_order_id=O-2)"
+        );
+        let order = engine.order(&ClientOrderId::from("O-2")).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_cancel_venue_orders_bypasses_throttler(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let (order1, order2) = market_orders(&audusd_sim);
+        for order in [&order1, &order2] {
+            engine.add_order(order.clone()).unwrap();
+            engine.process(&submitted(order)).unwrap();
+            engine.process(&accepted(order)).unwrap();
+        }
+        engine.set_throttler(throttler(ThrottleOverflowPolicy::Reject));
+
+        let sent = engine.cancel_venue_orders(audusd_sim.id.venue, 10).unwrap();
+
+        assert_eq!(sent, 2);
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "SIM:CancelOrder(instrument_id=AUD/USD.SIM, client_order_id=O-1)".to_string(),
+                "SIM:CancelOrder(instrument_id=AUD/USD.SIM, client_order_id=O-2)".to_string(),
+            ]
+        );
+        assert_eq!(engine.throttler().unwrap().sent_count, 0);
+    }
+
+    #[rstest]
+    fn test_cancel_venue_orders_ignores_other_venues(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+
+        let sent = engine
+            .cancel_venue_orders(Venue::from("OTHER"), 10)
+            .unwrap();
+
+        assert_eq!(sent, 0);
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_snapshot_and_restore_orders(mut engine: ExecutionEngine, audusd_sim: CurrencyPair) {
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        engine.process(&submitted(&order)).unwrap();
+        engine.process(&accepted(&order)).unwrap();
+
+        let snapshot = engine.snapshot(10);
+        let mut restored = ExecutionEngine::new(msgbus());
+        let count = restored.restore(&snapshot).unwrap();
+
+        let client_order_id = order.as_order().client_order_id();
+        assert_eq!(count, 1);
+        assert_eq!(snapshot.trader_id, TraderId::from("TRADER-001"));
+        assert_eq!(restored.orders_open().len(), 1);
+        assert_eq!(
+            restored.client_order_id(&VenueOrderId::from("V-1")),
+            Some(client_order_id)
+        );
+        assert_eq!(restored.restore(&snapshot).unwrap(), 0);
+    }
+
+    #[rstest]
+    fn test_snapshot_and_restore_positions_and_accounts(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+        cash_account_state: AccountState,
+    ) {
+        let order = market_order(&audusd_sim);
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            Some(TradeId::from("1")),
+            Some(PositionId::from("P-1")),
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&audusd_sim, fill).unwrap();
+        engine.add_position(position.clone()).unwrap();
+        engine.update_account(cash_account_state.clone());
+
+        let bytes = engine.snapshot(10).to_bytes().unwrap();
+        let snapshot = CacheSnapshot::from_bytes(&bytes).unwrap();
+        let mut restored = ExecutionEngine::new(msgbus());
+        restored.restore(&snapshot).unwrap();
+
+        let restored_position = restored.position(&PositionId::from("P-1")).unwrap();
+        assert_eq!(restored_position.quantity, position.quantity);
+        assert_eq!(restored_position.avg_px_open, position.avg_px_open);
+        assert_eq!(restored_position.trade_ids(), position.trade_ids());
+        let restored_account = restored.account(&cash_account_state.account_id).unwrap();
+        assert_eq!(restored_account.balances, cash_account_state.balances);
+        assert!(engine.add_position(position).is_err());
+    }
+
+    #[rstest]
+    fn test_submit_order_list_best_effort_skips_invalid_orders(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let orders = order_list(&audusd_sim, &["O-1", "O-2"]);
+        engine.add_order(orders[0].clone()).unwrap();
+
+        engine.execute(submit_list(orders)).unwrap();
+
+        assert_eq!(
+            *calls.borrow(),
+            vec!["SIM:SubmitOrder(instrument_id=AUD/USD.SIM, client_order_id=O-2)".to_string()]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_list_all_or_none_denies_whole_list(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        engine.set_batch_atomicity(Venue::from("SIM"), BatchAtomicity::AllOrNone);
+        let orders = order_list(&audusd_sim, &["O-1", "O-2", "O-3"]);
+        engine.add_order(orders[0].clone()).unwrap();
+
+        let result = engine.execute(submit_list(orders));
+
+        assert!(result.is_err());
+        assert!(calls.borrow().is_empty());
+        for id in ["O-2", "O-3"] {
+            let order = engine.order(&ClientOrderId::from(id)).unwrap();
+            assert_eq!(order.as_order().status(), OrderStatus::Denied);
+        }
+        let existing = engine.order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(existing.as_order().status(), OrderStatus::Initialized);
+    }
+
+    #[rstest]
+    fn test_submit_order_list_all_or_none_submits_list(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        engine.set_batch_atomicity(Venue::from("SIM"), BatchAtomicity::AllOrNone);
+
+        engine
+            .execute(submit_list(order_list(&audusd_sim, &["O-1", "O-2"])))
+            .unwrap();
+
+        assert_eq!(calls.borrow().len(), 2);
+        assert_eq!(engine.command_count, 1);
+    }
+
+    #[rstest]
+    fn test_cancel_all_orders_cancels_open_orders_for_side(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        let mut orders = order_list(&audusd_sim, &["O-1", "O-2"]);
+        orders.push(
+            TestOrderStubs::market_order(
+                audusd_sim.id,
+                OrderSide::Sell,
+                Quantity::from(100_000),
+                Some(ClientOrderId::from("O-3")),
+                None,
+            )
+            .into(),
+        );
+        for order in &orders {
+            engine.add_order(order.clone()).unwrap();
+            engine.process(&submitted(order)).unwrap();
+        }
+        let strategy_id = orders[0].as_order().strategy_id();
+
+        engine
+            .execute(TradingCommand::CancelAllOrders(CancelAllOrders::new(
+                TraderId::from("TRADER-001"),
+                None,
+                strategy_id,
+                audusd_sim.id,
+                OrderSide::Buy,
+                UUID4::new(),
+                10,
+            )))
+            .unwrap();
+
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                "SIM:CancelOrder(instrument_id=AUD/USD.SIM, client_order_id=O-1)".to_string(),
+                "SIM:CancelOrder(instrument_id=AUD/USD.SIM, client_order_id=O-2)".to_string(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_batch_cancel_all_or_none_fails_on_unknown_order(
+        mut engine: ExecutionEngine,
+        audusd_sim: CurrencyPair,
+    ) {
+        let calls = CallLog::default();
+        engine
+            .register_client(Box::new(mock_client(&calls)), None)
+            .unwrap();
+        engine.set_batch_atomicity(Venue::from("SIM"), BatchAtomicity::AllOrNone);
+        let order = market_order(&audusd_sim);
+        engine.add_order(order.clone()).unwrap();
+        let order = order.as_order();
+        let cancels = [order.client_order_id(), ClientOrderId::from("O-UNKNOWN")]
+            .into_iter()
+            .map(|client_order_id| {
+                CancelOrder::new(
+                    order.trader_id(),
+                    None,
+                    order.strategy_id(),
+                    audusd_sim.id,
+                    client_order_id,
+                    None,
+                    UUID4::new(),
+                    10,
+                )
+            })
+            .collect();
+
+        let result = engine.execute(TradingCommand::BatchCancelOrders(BatchCancelOrders::new(
+            order.trader_id(),
+            None,
+            order.strategy_id(),
+            audusd_sim.id,
+            cancels,
+            UUID4::new(),
+            10,
+        )));
+
+        assert!(result.is_err());
+        assert!(calls.borrow().is_empty());
+    }
+}
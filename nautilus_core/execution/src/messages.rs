@@ -0,0 +1,452 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter};
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
+        trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    orders::{any::OrderAny, base::Order},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents a command to submit the given order.
+///
+/// The command is routed to `client_id` if given, otherwise to the client registered
+/// for the orders venue (falling back to the default client).
+#[derive(Clone, Debug)]
+pub struct SubmitOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub order: OrderAny,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl SubmitOrder {
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        order: OrderAny,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            strategy_id: order.as_order().strategy_id(),
+            order,
+            command_id,
+            ts_init,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        self.order.as_order().instrument_id()
+    }
+
+    #[must_use]
+    pub fn client_order_id(&self) -> ClientOrderId {
+        self.order.as_order().client_order_id()
+    }
+}
+
+impl Display for SubmitOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SubmitOrder(instrument_id={}, client_order_id={})",
+            self.instrument_id(),
+            self.client_order_id(),
+        )
+    }
+}
+
+/// Represents a command to cancel an open order.
+#[derive(Clone, Copy, Debug)]
+pub struct CancelOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl CancelOrder {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+impl Display for CancelOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CancelOrder(instrument_id={}, client_order_id={})",
+            self.instrument_id, self.client_order_id,
+        )
+    }
+}
+
+/// Represents a command to modify an open order, amending any of its quantity, limit
+/// price and trigger price (those left as `None` are unchanged).
+#[derive(Clone, Copy, Debug)]
+pub struct ModifyOrder {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub quantity: Option<Quantity>,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl ModifyOrder {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            quantity,
+            price,
+            trigger_price,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+impl Display for ModifyOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display = |value: Option<String>| value.unwrap_or_else(|| "None".to_string());
+        write!(
+            f,
+            "ModifyOrder(instrument_id={}, client_order_id={}, quantity={}, price={}, trigger_price={})",
+            self.instrument_id,
+            self.client_order_id,
+            display(self.quantity.map(|q| q.to_string())),
+            display(self.price.map(|p| p.to_string())),
+            display(self.trigger_price.map(|p| p.to_string())),
+        )
+    }
+}
+
+/// Represents a command to submit a list of orders for a single instrument together.
+#[derive(Clone, Debug)]
+pub struct SubmitOrderList {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub order_list_id: OrderListId,
+    pub orders: Vec<OrderAny>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl SubmitOrderList {
+    /// Creates a new [`SubmitOrderList`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `orders` is empty.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        order_list_id: OrderListId,
+        orders: Vec<OrderAny>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        let first = orders
+            .first()
+            .expect("`orders` must contain at least one order")
+            .as_order();
+        Self {
+            trader_id,
+            client_id,
+            strategy_id: first.strategy_id(),
+            instrument_id: first.instrument_id(),
+            order_list_id,
+            orders,
+            command_id,
+            ts_init,
+        }
+    }
+
+    /// Returns a [`SubmitOrder`] command for each order in the list.
+    #[must_use]
+    pub fn split(&self) -> Vec<SubmitOrder> {
+        self.orders
+            .iter()
+            .map(|order| {
+                SubmitOrder::new(
+                    self.trader_id,
+                    self.client_id,
+                    order.clone(),
+                    self.command_id,
+                    self.ts_init,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Display for SubmitOrderList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SubmitOrderList(instrument_id={}, order_list_id={}, orders={})",
+            self.instrument_id,
+            self.order_list_id,
+            self.orders.len(),
+        )
+    }
+}
+
+/// Represents a command to cancel all open orders for an instrument, optionally only
+/// for one side of the market.
+#[derive(Clone, Copy, Debug)]
+pub struct CancelAllOrders {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    /// The side of the orders to cancel (`NoOrderSide` cancels both sides).
+    pub order_side: OrderSide,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl CancelAllOrders {
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            order_side,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+impl Display for CancelAllOrders {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CancelAllOrders(instrument_id={}, order_side={})",
+            self.instrument_id, self.order_side,
+        )
+    }
+}
+
+/// Represents a command to cancel a batch of open orders for a single instrument.
+#[derive(Clone, Debug)]
+pub struct BatchCancelOrders {
+    pub trader_id: TraderId,
+    pub client_id: Option<ClientId>,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub cancels: Vec<CancelOrder>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+impl BatchCancelOrders {
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        client_id: Option<ClientId>,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        cancels: Vec<CancelOrder>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            cancels,
+            command_id,
+            ts_init,
+        }
+    }
+}
+
+impl Display for BatchCancelOrders {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BatchCancelOrders(instrument_id={}, cancels={})",
+            self.instrument_id,
+            self.cancels.len(),
+        )
+    }
+}
+
+/// The atomicity of a batch of order commands at a venue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BatchAtomicity {
+    /// Each command in the batch succeeds or fails independently.
+    #[default]
+    BestEffort,
+    /// The batch fails as a whole if any one command in it would fail.
+    AllOrNone,
+}
+
+#[derive(Clone, Debug)]
+pub enum TradingCommand {
+    SubmitOrder(SubmitOrder),
+    SubmitOrderList(SubmitOrderList),
+    CancelOrder(CancelOrder),
+    ModifyOrder(ModifyOrder),
+    CancelAllOrders(CancelAllOrders),
+    BatchCancelOrders(BatchCancelOrders),
+}
+
+impl TradingCommand {
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::SubmitOrder(cmd) => cmd.instrument_id(),
+            Self::SubmitOrderList(cmd) => cmd.instrument_id,
+            Self::CancelOrder(cmd) => cmd.instrument_id,
+            Self::ModifyOrder(cmd) => cmd.instrument_id,
+            Self::CancelAllOrders(cmd) => cmd.instrument_id,
+            Self::BatchCancelOrders(cmd) => cmd.instrument_id,
+        }
+    }
+
+    #[must_use]
+    pub fn client_id(&self) -> Option<ClientId> {
+        match self {
+            Self::SubmitOrder(cmd) => cmd.client_id,
+            Self::SubmitOrderList(cmd) => cmd.client_id,
+            Self::CancelOrder(cmd) => cmd.client_id,
+            Self::ModifyOrder(cmd) => cmd.client_id,
+            Self::CancelAllOrders(cmd) => cmd.client_id,
+            Self::BatchCancelOrders(cmd) => cmd.client_id,
+        }
+    }
+
+    /// Returns whether the command submits new orders (rather than acting on existing orders).
+    #[must_use]
+    pub fn is_submission(&self) -> bool {
+        matches!(self, Self::SubmitOrder(_) | Self::SubmitOrderList(_))
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::SubmitOrder(cmd) => cmd.ts_init,
+            Self::SubmitOrderList(cmd) => cmd.ts_init,
+            Self::CancelOrder(cmd) => cmd.ts_init,
+            Self::ModifyOrder(cmd) => cmd.ts_init,
+            Self::CancelAllOrders(cmd) => cmd.ts_init,
+            Self::BatchCancelOrders(cmd) => cmd.ts_init,
+        }
+    }
+}
+
+impl Display for TradingCommand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SubmitOrder(cmd) => cmd.fmt(f),
+            Self::SubmitOrderList(cmd) => cmd.fmt(f),
+            Self::CancelOrder(cmd) => cmd.fmt(f),
+            Self::ModifyOrder(cmd) => cmd.fmt(f),
+            Self::CancelAllOrders(cmd) => cmd.fmt(f),
+            Self::BatchCancelOrders(cmd) => cmd.fmt(f),
+        }
+    }
+}
+
+/// Returns the message bus topic order events for the given `strategy_id` are published on.
+#[must_use]
+pub fn order_event_topic(strategy_id: &StrategyId) -> String {
+    format!("events.order.{strategy_id}")
+}
+
+/// Returns the message bus topic account states for the given `account_id` are published on.
+#[must_use]
+pub fn account_state_topic(account_id: &AccountId) -> String {
+    format!("events.account.{account_id}")
+}
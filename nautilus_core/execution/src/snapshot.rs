@@ -0,0 +1,714 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Snapshots of the execution cache state for crash recovery.
+//!
+//! A [`CacheSnapshot`] captures the orders (as their full event history), positions, account
+//! states, instruments and instrument statuses, timers and GTD expirations of a running node.
+//! Instruments and their statuses are sourced from the cache database, as the engines only
+//! hold the instruments they were given. Snapshots are written periodically
+//! (to a file, or to a cache database such as Redis), and restored on startup so that a
+//! restarted node can resume without a full reconciliation against the venue.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use nautilus_common::clock::Clock;
+use nautilus_core::time::UnixNanos;
+use nautilus_infrastructure::cache::CacheDatabase;
+use nautilus_model::{
+    data::status::InstrumentStatus,
+    enums::TimeInForce,
+    events::{account::state::AccountState, order::event::OrderEvent},
+    identifiers::{client_order_id::ClientOrderId, trader_id::TraderId},
+    orders::{any::OrderAny, base::Order},
+    position::Position,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::engine::ExecutionEngine;
+
+/// The cache database key snapshots are saved under.
+pub const CACHE_SNAPSHOT_KEY: &str = "general:cache_snapshot";
+
+/// The cache database key prefix for instrument definitions.
+pub const INSTRUMENTS_KEY_PREFIX: &str = "instruments:";
+
+/// The cache database key prefix for the latest status of each instrument.
+pub const INSTRUMENT_STATUS_KEY_PREFIX: &str = "general:instrument_status:";
+
+/// Represents a raw entry read from the cache database.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub key: String,
+    pub payload: Vec<u8>,
+}
+
+/// Represents the state of an active timer at the time of a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimerSnapshot {
+    pub name: String,
+    pub interval_ns: u64,
+    pub stop_time_ns: Option<UnixNanos>,
+    pub next_time_ns: UnixNanos,
+}
+
+/// Represents a pending GTD (good-till-date) expiration for an open order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GtdExpiration {
+    pub client_order_id: ClientOrderId,
+    pub expire_time_ns: UnixNanos,
+}
+
+impl GtdExpiration {
+    /// Returns the name of the time alert which expires the order.
+    #[must_use]
+    pub fn timer_name(&self) -> String {
+        format!("GTD-EXPIRY:{}", self.client_order_id)
+    }
+}
+
+/// Represents a point-in-time snapshot of the cache state.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CacheSnapshot {
+    pub trader_id: TraderId,
+    pub ts_snapshot: UnixNanos,
+    /// The event history of each order, starting with its `OrderInitialized` event.
+    pub orders: Vec<Vec<OrderEvent>>,
+    pub positions: Vec<Position>,
+    pub accounts: Vec<AccountState>,
+    /// The instrument definitions, as serialized in the cache database.
+    pub instruments: Vec<CacheEntry>,
+    pub instrument_statuses: Vec<InstrumentStatus>,
+    pub timers: Vec<TimerSnapshot>,
+    pub gtd_expirations: Vec<GtdExpiration>,
+}
+
+impl CacheSnapshot {
+    #[must_use]
+    pub fn new(trader_id: TraderId, ts_snapshot: UnixNanos) -> Self {
+        Self {
+            trader_id,
+            ts_snapshot,
+            orders: Vec::new(),
+            positions: Vec::new(),
+            accounts: Vec::new(),
+            instruments: Vec::new(),
+            instrument_statuses: Vec::new(),
+            timers: Vec::new(),
+            gtd_expirations: Vec::new(),
+        }
+    }
+
+    /// Adds the given `order` to the snapshot, along with its GTD expiration (if pending).
+    pub fn add_order(&mut self, order: &OrderAny) {
+        let order = order.as_order();
+        self.orders
+            .push(order.events().into_iter().cloned().collect());
+
+        if order.time_in_force() == TimeInForce::Gtd && !order.is_closed() {
+            if let Some(expire_time_ns) = order.expire_time() {
+                self.gtd_expirations.push(GtdExpiration {
+                    client_order_id: order.client_order_id(),
+                    expire_time_ns,
+                });
+            }
+        }
+    }
+
+    /// Adds the instrument definitions and latest instrument statuses held in the cache
+    /// `database` to the snapshot.
+    pub fn add_instruments<D: CacheDatabase>(&mut self, database: &mut D) -> Result<()> {
+        self.instruments = read_entries(database, INSTRUMENTS_KEY_PREFIX)?;
+        self.instrument_statuses = read_entries(database, INSTRUMENT_STATUS_KEY_PREFIX)?
+            .iter()
+            .map(|entry| serde_json::from_slice(&entry.payload))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// Adds the active timers of the given `clock` to the snapshot.
+    ///
+    /// The clock does not expose timer intervals, so these are provided by the caller as
+    /// `(name, interval_ns, stop_time_ns)` for each timer which should be restored.
+    pub fn add_timers(&mut self, clock: &dyn Clock, timers: &[(&str, u64, Option<UnixNanos>)]) {
+        let active = clock.timer_names();
+        for (name, interval_ns, stop_time_ns) in timers {
+            if !active.contains(name) {
+                continue;
+            }
+            self.timers.push(TimerSnapshot {
+                name: (*name).to_string(),
+                interval_ns: *interval_ns,
+                stop_time_ns: *stop_time_ns,
+                next_time_ns: clock.next_time_ns(name),
+            });
+        }
+    }
+
+    /// Rebuilds the orders in the snapshot by replaying their events.
+    pub fn restore_orders(&self) -> Result<Vec<OrderAny>> {
+        let mut orders = Vec::with_capacity(self.orders.len());
+        for events in &self.orders {
+            let mut events = events.iter().cloned();
+            let Some(OrderEvent::OrderInitialized(init)) = events.next() else {
+                bail!("Invalid order snapshot: first event was not `OrderInitialized`");
+            };
+
+            let mut order = OrderAny::from(init);
+            for event in events {
+                order.apply(event)?;
+            }
+            orders.push(order);
+        }
+        Ok(orders)
+    }
+
+    /// Restores the instrument definitions and statuses in the snapshot to the cache
+    /// `database`, returning the number of instruments restored.
+    pub fn restore_instruments<D: CacheDatabase>(&self, database: &mut D) -> Result<usize> {
+        for entry in &self.instruments {
+            database.insert(entry.key.clone(), Some(vec![entry.payload.clone()]))?;
+        }
+        for status in &self.instrument_statuses {
+            save_instrument_status(database, status)?;
+        }
+        Ok(self.instruments.len())
+    }
+
+    /// Restores the timers and GTD expiration alerts in the snapshot on the given `clock`,
+    /// using its default handler.
+    ///
+    /// Timers which would have fired while the node was down resume from `ts_now`, and
+    /// GTD orders which have already expired are alerted at `ts_now`.
+    pub fn restore_timers(&self, clock: &mut dyn Clock, ts_now: UnixNanos) {
+        for timer in &self.timers {
+            if timer.stop_time_ns.is_some_and(|stop| stop <= ts_now) {
+                continue;
+            }
+
+            let start_time_ns = if timer.next_time_ns > ts_now {
+                timer.next_time_ns.saturating_sub(timer.interval_ns)
+            } else {
+                log::warn!("Timer {} missed its next event during downtime", timer.name);
+                ts_now
+            };
+            clock.set_timer_ns(
+                &timer.name,
+                timer.interval_ns,
+                start_time_ns,
+                timer.stop_time_ns,
+                None,
+            );
+        }
+
+        for expiration in &self.gtd_expirations {
+            clock.set_time_alert_ns(
+                &expiration.timer_name(),
+                expiration.expire_time_ns.max(ts_now),
+                None,
+            );
+        }
+    }
+
+    /// Serializes the snapshot to JSON bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Deserializes a snapshot from JSON bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Writes the snapshot to the file at `path`.
+    ///
+    /// The snapshot is first written to a temporary file which is then renamed, so a crash
+    /// while writing never leaves a partial snapshot in place.
+    pub fn write_file(&self, path: &Path) -> Result<()> {
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, self.to_bytes()?)
+            .with_context(|| format!("Failed to write snapshot to {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Reads a snapshot from the file at `path`.
+    pub fn read_file(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)
+            .with_context(|| format!("Failed to read snapshot from {}", path.display()))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Saves the snapshot to the given cache `database`.
+    pub fn save<D: CacheDatabase>(&self, database: &mut D) -> Result<()> {
+        database.update(CACHE_SNAPSHOT_KEY.to_string(), Some(vec![self.to_bytes()?]))
+    }
+
+    /// Loads the latest snapshot from the given cache `database` (if one was saved).
+    pub fn load<D: CacheDatabase>(database: &mut D) -> Result<Option<Self>> {
+        match database.read(CACHE_SNAPSHOT_KEY)?.first() {
+            Some(bytes) => Ok(Some(Self::from_bytes(bytes)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Saves the given instrument `status` to the cache `database` as the latest status for the
+/// instrument, so that it is included in subsequent snapshots.
+pub fn save_instrument_status<D: CacheDatabase>(
+    database: &mut D,
+    status: &InstrumentStatus,
+) -> Result<()> {
+    let key = format!("{INSTRUMENT_STATUS_KEY_PREFIX}{}", status.instrument_id);
+    database.update(key, Some(vec![serde_json::to_vec(status)?]))
+}
+
+/// Reads every entry under the given key `prefix` from the cache `database`.
+///
+/// Keys may be returned with a database specific prefix (such as the trader key), which is
+/// removed so the entries can be read and restored with the keys used by the cache.
+fn read_entries<D: CacheDatabase>(database: &mut D, prefix: &str) -> Result<Vec<CacheEntry>> {
+    let mut keys: Vec<String> = database
+        .keys(&format!("*{prefix}*"))?
+        .iter()
+        .filter_map(|key| key.find(prefix).map(|i| key[i..].to_string()))
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    let mut entries = Vec::with_capacity(keys.len());
+    for key in keys {
+        if let Some(payload) = database.read(&key)?.into_iter().next() {
+            entries.push(CacheEntry { key, payload });
+        }
+    }
+    Ok(entries)
+}
+
+/// Configuration for [`CacheSnapshotter`] instances.
+#[derive(Clone, Debug)]
+pub struct CacheSnapshotConfig {
+    /// The interval between snapshots.
+    pub interval_ns: u64,
+    /// The path of the snapshot file.
+    pub path: PathBuf,
+    /// The timers to include in each snapshot, as `(name, interval_ns, stop_time_ns)`.
+    pub timers: Vec<(String, u64, Option<UnixNanos>)>,
+}
+
+/// Writes snapshots of the execution engine state, cached instruments and clock timers on an
+/// interval, either to file (`on_timer`) or to the cache database (`on_timer_save`).
+///
+/// The snapshotter holds no timer of its own, `on_timer` (or `on_timer_save`) should be
+/// called from a timer set at (or more frequently than) the snapshot interval.
+pub struct CacheSnapshotter {
+    config: CacheSnapshotConfig,
+    last_snapshot_ns: Option<UnixNanos>,
+}
+
+impl CacheSnapshotter {
+    #[must_use]
+    pub fn new(config: CacheSnapshotConfig) -> Self {
+        assert!(config.interval_ns > 0, "`interval_ns` must be positive");
+        Self {
+            config,
+            last_snapshot_ns: None,
+        }
+    }
+
+    /// Returns the time of the last snapshot written (if any).
+    #[must_use]
+    pub fn last_snapshot_ns(&self) -> Option<UnixNanos> {
+        self.last_snapshot_ns
+    }
+
+    /// Returns whether a snapshot is due at `ts_now`.
+    #[must_use]
+    pub fn is_due(&self, ts_now: UnixNanos) -> bool {
+        self.last_snapshot_ns.map_or(true, |last| {
+            ts_now.saturating_sub(last) >= self.config.interval_ns
+        })
+    }
+
+    /// Writes a snapshot of the given `engine`, the instruments in the cache `database` and
+    /// the `clock` timers to file if one is due, returning whether it was written.
+    pub fn on_timer<D: CacheDatabase>(
+        &mut self,
+        engine: &ExecutionEngine,
+        clock: &dyn Clock,
+        database: &mut D,
+        ts_now: UnixNanos,
+    ) -> Result<bool> {
+        if !self.is_due(ts_now) {
+            return Ok(false);
+        }
+
+        self.snapshot(engine, clock, database, ts_now)?
+            .write_file(&self.config.path)?;
+        self.last_snapshot_ns = Some(ts_now);
+        Ok(true)
+    }
+
+    /// Saves a snapshot of the given `engine`, the instruments in the cache `database` and
+    /// the `clock` timers to the same `database` if one is due, returning whether it was saved.
+    pub fn on_timer_save<D: CacheDatabase>(
+        &mut self,
+        engine: &ExecutionEngine,
+        clock: &dyn Clock,
+        database: &mut D,
+        ts_now: UnixNanos,
+    ) -> Result<bool> {
+        if !self.is_due(ts_now) {
+            return Ok(false);
+        }
+
+        self.snapshot(engine, clock, database, ts_now)?
+            .save(database)?;
+        self.last_snapshot_ns = Some(ts_now);
+        Ok(true)
+    }
+
+    fn snapshot<D: CacheDatabase>(
+        &self,
+        engine: &ExecutionEngine,
+        clock: &dyn Clock,
+        database: &mut D,
+        ts_now: UnixNanos,
+    ) -> Result<CacheSnapshot> {
+        let timers: Vec<_> = self
+            .config
+            .timers
+            .iter()
+            .map(|(name, interval_ns, stop_time_ns)| (name.as_str(), *interval_ns, *stop_time_ns))
+            .collect();
+
+        let mut snapshot = engine.snapshot(ts_now);
+        snapshot.add_instruments(database)?;
+        snapshot.add_timers(clock, &timers);
+        Ok(snapshot)
+    }
+
+    /// Reads the last snapshot written (if the snapshot file exists).
+    pub fn read_last(&self) -> Result<Option<CacheSnapshot>> {
+        if !self.config.path.exists() {
+            return Ok(None);
+        }
+        CacheSnapshot::read_file(&self.config.path).map(Some)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::mpsc::Receiver};
+
+    use nautilus_common::{clock::TestClock, handlers::EventHandler, msgbus::MessageBus};
+    use nautilus_core::uuid::UUID4;
+    use nautilus_infrastructure::cache::DatabaseCommand;
+    use nautilus_model::{
+        enums::{MarketStatus, OrderSide, OrderStatus, OrderType},
+        events::order::{
+            accepted::OrderAcceptedBuilder, initialized::OrderInitializedBuilder,
+            submitted::OrderSubmittedBuilder,
+        },
+        identifiers::{instrument_id::InstrumentId, venue_order_id::VenueOrderId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockCacheDatabase {
+        entries: HashMap<String, Vec<Vec<u8>>>,
+    }
+
+    impl CacheDatabase for MockCacheDatabase {
+        type DatabaseType = Self;
+
+        fn new(
+            _trader_id: TraderId,
+            _instance_id: UUID4,
+            _config: HashMap<String, serde_json::Value>,
+        ) -> Result<Self> {
+            Ok(Self::default())
+        }
+
+        fn flushdb(&mut self) -> Result<()> {
+            self.entries.clear();
+            Ok(())
+        }
+
+        fn keys(&mut self, pattern: &str) -> Result<Vec<String>> {
+            let pattern = pattern.trim_matches('*');
+            Ok(self
+                .entries
+                .keys()
+                .filter(|key| key.contains(pattern))
+                .cloned()
+                .collect())
+        }
+
+        fn read(&mut self, key: &str) -> Result<Vec<Vec<u8>>> {
+            Ok(self.entries.get(key).cloned().unwrap_or_default())
+        }
+
+        fn insert(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            self.update(key, payload)
+        }
+
+        fn update(&mut self, key: String, payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            self.entries.insert(key, payload.unwrap_or_default());
+            Ok(())
+        }
+
+        fn delete(&mut self, key: String, _payload: Option<Vec<Vec<u8>>>) -> Result<()> {
+            self.entries.remove(&key);
+            Ok(())
+        }
+
+        fn handle_messages(
+            _rx: Receiver<DatabaseCommand>,
+            _trader_key: String,
+            _config: HashMap<String, serde_json::Value>,
+        ) {
+        }
+    }
+
+    fn engine() -> ExecutionEngine {
+        ExecutionEngine::new(Rc::new(RefCell::new(MessageBus::new(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            None,
+            None,
+        ))))
+    }
+
+    fn clock_with_timer() -> TestClock {
+        let mut clock = TestClock::new();
+        clock.register_default_handler(EventHandler::new(None, None));
+        clock.set_timer_ns("BARS", 1_000, 0, None, None);
+        clock
+    }
+
+    fn snapshotter(path: PathBuf) -> CacheSnapshotter {
+        CacheSnapshotter::new(CacheSnapshotConfig {
+            interval_ns: 1_000,
+            path,
+            timers: vec![
+                ("BARS".to_string(), 1_000, None),
+                ("INACTIVE".to_string(), 1_000, None),
+            ],
+        })
+    }
+
+    fn accepted_gtd_order(expire_time_ns: UnixNanos) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-1");
+        let init = OrderInitializedBuilder::default()
+            .client_order_id(client_order_id)
+            .order_type(OrderType::Limit)
+            .order_side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .price(Some(Price::from("0.80000")))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(Some(expire_time_ns))
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+        let submitted = OrderSubmittedBuilder::default()
+            .client_order_id(client_order_id)
+            .build()
+            .unwrap();
+        let accepted = OrderAcceptedBuilder::default()
+            .client_order_id(client_order_id)
+            .venue_order_id(VenueOrderId::from("V-1"))
+            .build()
+            .unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_snapshot_round_trip_restores_orders() {
+        let order = accepted_gtd_order(5_000);
+        let mut snapshot = CacheSnapshot::new(TraderId::from("TRADER-001"), 10);
+        snapshot.add_order(&order);
+
+        let restored = CacheSnapshot::from_bytes(&snapshot.to_bytes().unwrap()).unwrap();
+        let orders = restored.restore_orders().unwrap();
+
+        assert_eq!(orders.len(), 1);
+        let order = orders[0].as_order();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(VenueOrderId::from("V-1")));
+        assert_eq!(order.events().len(), 3);
+        assert_eq!(restored.gtd_expirations.len(), 1);
+        assert_eq!(restored.gtd_expirations[0].expire_time_ns, 5_000);
+    }
+
+    #[rstest]
+    fn test_restore_orders_with_invalid_history_fails() {
+        let order = accepted_gtd_order(5_000);
+        let mut snapshot = CacheSnapshot::new(TraderId::from("TRADER-001"), 10);
+        snapshot.add_order(&order);
+        snapshot.orders[0].remove(0);
+
+        assert!(snapshot.restore_orders().is_err());
+    }
+
+    #[rstest]
+    fn test_restore_timers_and_gtd_expirations() {
+        let mut snapshot = CacheSnapshot::new(TraderId::from("TRADER-001"), 10);
+        snapshot.add_order(&accepted_gtd_order(5_000));
+        snapshot.timers.push(TimerSnapshot {
+            name: "BARS".to_string(),
+            interval_ns: 1_000,
+            stop_time_ns: None,
+            next_time_ns: 3_000,
+        });
+        snapshot.timers.push(TimerSnapshot {
+            name: "STOPPED".to_string(),
+            interval_ns: 1_000,
+            stop_time_ns: Some(1_500),
+            next_time_ns: 1_000,
+        });
+        let mut clock = TestClock::new();
+        clock.register_default_handler(EventHandler::new(None, None));
+
+        snapshot.restore_timers(&mut clock, 2_000);
+
+        let mut names = clock.timer_names();
+        names.sort_unstable();
+        assert_eq!(names, vec!["BARS", "GTD-EXPIRY:O-1"]);
+        assert_eq!(clock.next_time_ns("BARS"), 3_000);
+    }
+
+    #[rstest]
+    fn test_snapshot_round_trip_restores_instruments_from_cache() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let status =
+            InstrumentStatus::new(instrument_id, None, MarketStatus::Closed, None, 1, 1).unwrap();
+        let mut database = MockCacheDatabase::default();
+        database
+            .insert(
+                format!("{INSTRUMENTS_KEY_PREFIX}{instrument_id}"),
+                Some(vec![b"AUD/USD".to_vec()]),
+            )
+            .unwrap();
+        save_instrument_status(&mut database, &status).unwrap();
+
+        let mut snapshot = CacheSnapshot::new(TraderId::from("TRADER-001"), 10);
+        snapshot.add_instruments(&mut database).unwrap();
+        let restored = CacheSnapshot::from_bytes(&snapshot.to_bytes().unwrap()).unwrap();
+        let mut restored_database = MockCacheDatabase::default();
+        let count = restored
+            .restore_instruments(&mut restored_database)
+            .unwrap();
+
+        assert_eq!(count, 1);
+        assert_eq!(restored.instrument_statuses, vec![status]);
+        assert_eq!(restored_database.entries, database.entries);
+    }
+
+    #[rstest]
+    fn test_restore_timer_with_next_time_before_interval() {
+        let mut snapshot = CacheSnapshot::new(TraderId::from("TRADER-001"), 10);
+        snapshot.timers.push(TimerSnapshot {
+            name: "BARS".to_string(),
+            interval_ns: 1_000,
+            stop_time_ns: None,
+            next_time_ns: 500,
+        });
+        let mut clock = TestClock::new();
+        clock.register_default_handler(EventHandler::new(None, None));
+
+        snapshot.restore_timers(&mut clock, 100);
+
+        assert_eq!(clock.timer_names(), vec!["BARS"]);
+        assert_eq!(clock.next_time_ns("BARS"), 1_000);
+    }
+
+    #[rstest]
+    fn test_snapshotter_writes_on_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut snapshotter = snapshotter(dir.path().join("snapshot.json"));
+        let engine = engine();
+        let clock = clock_with_timer();
+        let mut database = MockCacheDatabase::default();
+        assert!(snapshotter.read_last().unwrap().is_none());
+
+        assert!(snapshotter
+            .on_timer(&engine, &clock, &mut database, 0)
+            .unwrap());
+        assert!(!snapshotter
+            .on_timer(&engine, &clock, &mut database, 999)
+            .unwrap());
+        assert!(snapshotter
+            .on_timer(&engine, &clock, &mut database, 1_000)
+            .unwrap());
+
+        let snapshot = snapshotter.read_last().unwrap().unwrap();
+        assert_eq!(snapshot.ts_snapshot, 1_000);
+        assert_eq!(snapshotter.last_snapshot_ns(), Some(1_000));
+        assert_eq!(
+            snapshot.timers,
+            vec![TimerSnapshot {
+                name: "BARS".to_string(),
+                interval_ns: 1_000,
+                stop_time_ns: None,
+                next_time_ns: 1_000,
+            }]
+        );
+    }
+
+    #[rstest]
+    fn test_snapshotter_saves_to_database_on_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut snapshotter = snapshotter(dir.path().join("snapshot.json"));
+        let engine = engine();
+        let clock = clock_with_timer();
+        let mut database = MockCacheDatabase::default();
+        assert!(CacheSnapshot::load(&mut database).unwrap().is_none());
+
+        assert!(snapshotter
+            .on_timer_save(&engine, &clock, &mut database, 0)
+            .unwrap());
+        assert!(!snapshotter
+            .on_timer_save(&engine, &clock, &mut database, 999)
+            .unwrap());
+        assert!(snapshotter
+            .on_timer_save(&engine, &clock, &mut database, 1_000)
+            .unwrap());
+
+        let snapshot = CacheSnapshot::load(&mut database).unwrap().unwrap();
+        assert_eq!(snapshot.ts_snapshot, 1_000);
+        assert_eq!(snapshot.timers.len(), 1);
+        assert_eq!(snapshot.timers[0].name, "BARS");
+        assert!(snapshotter.read_last().unwrap().is_none());
+
+        let mut restored_clock = TestClock::new();
+        restored_clock.register_default_handler(EventHandler::new(None, None));
+        snapshot.restore_timers(&mut restored_clock, 500);
+        assert_eq!(restored_clock.timer_names(), vec!["BARS"]);
+        assert_eq!(restored_clock.next_time_ns("BARS"), 1_000);
+    }
+}
@@ -0,0 +1,471 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Rate limiting of trading commands on the execution path.
+
+use std::collections::VecDeque;
+
+use indexmap::IndexMap;
+use nautilus_core::time::UnixNanos;
+use nautilus_model::identifiers::venue::Venue;
+
+use crate::messages::TradingCommand;
+
+/// The action taken for a submission which would exceed the rate limits.
+///
+/// Cancels and modifies are always buffered, as dropping or rejecting them would leave
+/// the order working at the venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrottleOverflowPolicy {
+    /// Buffer the submission until it can be sent within the limits.
+    Buffer,
+    /// Drop the submission (with a warning).
+    Drop,
+    /// Reject the submission, denying the order.
+    Reject,
+}
+
+/// A limit of `limit` commands within any rolling `interval_ns` window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: usize,
+    pub interval_ns: u64,
+}
+
+impl RateLimit {
+    #[must_use]
+    pub fn new(limit: usize, interval_ns: u64) -> Self {
+        assert!(limit > 0, "`limit` must be positive");
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        Self { limit, interval_ns }
+    }
+}
+
+/// The burst and sustained rate limits applied to a single venue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottleLimits {
+    /// The short window limit (e.g. per second).
+    pub burst: RateLimit,
+    /// The long window limit (e.g. per minute).
+    pub sustained: RateLimit,
+}
+
+/// Configuration for [`OrderThrottler`] instances.
+#[derive(Clone, Debug)]
+pub struct OrderThrottlerConfig {
+    /// The limits applied to each venue without an override.
+    pub limits: ThrottleLimits,
+    /// The venue specific limits, overriding `limits`.
+    pub venue_limits: IndexMap<Venue, ThrottleLimits>,
+    /// The action taken for submissions exceeding the limits.
+    pub overflow_policy: ThrottleOverflowPolicy,
+}
+
+/// The result of passing a command through the throttler.
+#[derive(Clone, Debug)]
+pub enum ThrottleOutcome {
+    /// The command is within the limits and should be sent.
+    Ready(TradingCommand),
+    /// The command was buffered, to be released by [`OrderThrottler::drain`].
+    Buffered,
+    /// The command was dropped.
+    Dropped(TradingCommand),
+    /// The command was rejected.
+    Rejected(TradingCommand),
+}
+
+#[derive(Debug, Default)]
+struct VenueThrottle {
+    sent: VecDeque<UnixNanos>,
+    buffer: VecDeque<TradingCommand>,
+}
+
+/// Provides per-venue throttling of trading commands with burst and sustained rate limits.
+///
+/// Commands are rate limited per venue (rather than per client or strategy), as that is
+/// how venues apply their own limits.
+#[derive(Debug)]
+pub struct OrderThrottler {
+    config: OrderThrottlerConfig,
+    venues: IndexMap<Venue, VenueThrottle>,
+    pub sent_count: u64,
+    pub buffered_count: u64,
+    pub dropped_count: u64,
+    pub rejected_count: u64,
+}
+
+impl OrderThrottler {
+    #[must_use]
+    pub fn new(config: OrderThrottlerConfig) -> Self {
+        Self {
+            config,
+            venues: IndexMap::new(),
+            sent_count: 0,
+            buffered_count: 0,
+            dropped_count: 0,
+            rejected_count: 0,
+        }
+    }
+
+    /// Returns the limits applied to the given `venue`.
+    #[must_use]
+    pub fn limits(&self, venue: &Venue) -> ThrottleLimits {
+        self.config
+            .venue_limits
+            .get(venue)
+            .copied()
+            .unwrap_or(self.config.limits)
+    }
+
+    /// Sets the limits applied to each venue without an override.
+    ///
+    /// Takes effect from the next command, with commands already sent counting towards
+    /// the new limits.
+    pub fn set_limits(&mut self, limits: ThrottleLimits) {
+        self.config.limits = limits;
+    }
+
+    /// Sets the limits for the given `venue`, removing any override if `limits` is `None`.
+    pub fn set_venue_limits(&mut self, venue: Venue, limits: Option<ThrottleLimits>) {
+        match limits {
+            Some(limits) => {
+                self.config.venue_limits.insert(venue, limits);
+            }
+            None => {
+                self.config.venue_limits.shift_remove(&venue);
+            }
+        }
+    }
+
+    /// Returns the number of commands currently buffered for the given `venue`.
+    #[must_use]
+    pub fn buffered(&self, venue: &Venue) -> usize {
+        self.venues.get(venue).map_or(0, |v| v.buffer.len())
+    }
+
+    /// Returns the total number of commands currently buffered across all venues.
+    #[must_use]
+    pub fn buffered_total(&self) -> usize {
+        self.venues.values().map(|v| v.buffer.len()).sum()
+    }
+
+    /// Passes the given `command` through the throttler as at `ts_now`.
+    ///
+    /// While commands are buffered for a venue, any new commands for the venue are buffered
+    /// behind them so that commands are always sent in order. The overflow policy applies
+    /// to submissions only, with cancels and modifies always buffered.
+    pub fn send(&mut self, command: TradingCommand, ts_now: UnixNanos) -> ThrottleOutcome {
+        let venue = command.instrument_id().venue;
+        let limits = self.limits(&venue);
+        let throttle = self.venues.entry(venue).or_default();
+
+        if throttle.buffer.is_empty() && throttle.try_acquire(&limits, ts_now) {
+            self.sent_count += 1;
+            return ThrottleOutcome::Ready(command);
+        }
+
+        let overflow_policy = if command.is_submission() {
+            self.config.overflow_policy
+        } else {
+            ThrottleOverflowPolicy::Buffer
+        };
+
+        match overflow_policy {
+            ThrottleOverflowPolicy::Buffer => {
+                throttle.buffer.push_back(command);
+                self.buffered_count += 1;
+                ThrottleOutcome::Buffered
+            }
+            ThrottleOverflowPolicy::Drop => {
+                self.dropped_count += 1;
+                ThrottleOutcome::Dropped(command)
+            }
+            ThrottleOverflowPolicy::Reject => {
+                self.rejected_count += 1;
+                ThrottleOutcome::Rejected(command)
+            }
+        }
+    }
+
+    /// Returns the buffered commands which can now be sent within the limits as at `ts_now`.
+    pub fn drain(&mut self, ts_now: UnixNanos) -> Vec<TradingCommand> {
+        let mut ready = Vec::new();
+        for (venue, throttle) in &mut self.venues {
+            let limits = self
+                .config
+                .venue_limits
+                .get(venue)
+                .copied()
+                .unwrap_or(self.config.limits);
+            while !throttle.buffer.is_empty() && throttle.try_acquire(&limits, ts_now) {
+                // SAFETY: Buffer was checked as not empty above
+                ready.push(throttle.buffer.pop_front().unwrap());
+            }
+        }
+        self.sent_count += ready.len() as u64;
+        ready
+    }
+}
+
+impl VenueThrottle {
+    fn try_acquire(&mut self, limits: &ThrottleLimits, ts_now: UnixNanos) -> bool {
+        let window = limits.burst.interval_ns.max(limits.sustained.interval_ns);
+        while let Some(ts) = self.sent.front() {
+            if ts_now.saturating_sub(*ts) < window {
+                break;
+            }
+            self.sent.pop_front();
+        }
+
+        let within = |limit: &RateLimit| {
+            self.sent
+                .iter()
+                .filter(|ts| ts_now.saturating_sub(**ts) < limit.interval_ns)
+                .count()
+                < limit.limit
+        };
+
+        if within(&limits.burst) && within(&limits.sustained) {
+            self.sent.push_back(ts_now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        enums::OrderSide,
+        identifiers::{
+            client_order_id::ClientOrderId, instrument_id::InstrumentId, strategy_id::StrategyId,
+            trader_id::TraderId,
+        },
+        orders::stubs::TestOrderStubs,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::messages::{CancelOrder, SubmitOrder};
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn config(overflow_policy: ThrottleOverflowPolicy) -> OrderThrottlerConfig {
+        OrderThrottlerConfig {
+            limits: ThrottleLimits {
+                burst: RateLimit::new(2, SECOND),
+                sustained: RateLimit::new(3, 60 * SECOND),
+            },
+            venue_limits: IndexMap::new(),
+            overflow_policy,
+        }
+    }
+
+    fn cancel(instrument_id: &str, client_order_id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            StrategyId::from("S-001"),
+            InstrumentId::from(instrument_id),
+            ClientOrderId::from(client_order_id),
+            None,
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    fn submit(instrument_id: &str, client_order_id: &str) -> TradingCommand {
+        let order = TestOrderStubs::market_order(
+            InstrumentId::from(instrument_id),
+            OrderSide::Buy,
+            Quantity::from(1),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        );
+        TradingCommand::SubmitOrder(SubmitOrder::new(
+            TraderId::from("TRADER-001"),
+            None,
+            order.into(),
+            UUID4::new(),
+            0,
+        ))
+    }
+
+    fn is_ready(outcome: &ThrottleOutcome) -> bool {
+        matches!(outcome, ThrottleOutcome::Ready(_))
+    }
+
+    #[rstest]
+    fn test_burst_and_sustained_limits() {
+        let mut throttler = OrderThrottler::new(config(ThrottleOverflowPolicy::Drop));
+
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-1"), 0)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-2"), 0)
+        ));
+        // Burst limit reached
+        assert!(!is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-3"), 0)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-4"), SECOND)
+        ));
+        // Sustained limit reached
+        assert!(!is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-5"), 2 * SECOND)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-6"), 60 * SECOND)
+        ));
+        assert_eq!(throttler.sent_count, 4);
+        assert_eq!(throttler.dropped_count, 2);
+    }
+
+    #[rstest]
+    fn test_limits_are_per_venue() {
+        let mut config = config(ThrottleOverflowPolicy::Reject);
+        config.venue_limits.insert(
+            Venue::from("BYBIT"),
+            ThrottleLimits {
+                burst: RateLimit::new(1, SECOND),
+                sustained: RateLimit::new(3, 60 * SECOND),
+            },
+        );
+        let mut throttler = OrderThrottler::new(config);
+
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-1"), 0)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-2"), 0)
+        ));
+        assert!(is_ready(&throttler.send(submit("ETHUSDT.BYBIT", "O-3"), 0)));
+        assert!(matches!(
+            throttler.send(submit("ETHUSDT.BYBIT", "O-4"), 0),
+            ThrottleOutcome::Rejected(_)
+        ));
+        assert_eq!(throttler.rejected_count, 1);
+    }
+
+    #[rstest]
+    fn test_buffered_commands_drain_in_order() {
+        let mut throttler = OrderThrottler::new(config(ThrottleOverflowPolicy::Buffer));
+        let venue = Venue::from("BINANCE");
+        for (i, id) in ["O-1", "O-2", "O-3", "O-4"].iter().enumerate() {
+            let outcome = throttler.send(cancel("ETHUSDT.BINANCE", id), 0);
+            assert_eq!(is_ready(&outcome), i < 2);
+        }
+        assert_eq!(throttler.buffered(&venue), 2);
+
+        assert!(throttler.drain(SECOND / 2).is_empty());
+        let drained = throttler.drain(SECOND);
+
+        // Only one more command is permitted by the sustained limit
+        assert_eq!(drained.len(), 1);
+        assert!(
+            matches!(&drained[0], TradingCommand::CancelOrder(cmd) if cmd.client_order_id == ClientOrderId::from("O-3"))
+        );
+        assert_eq!(throttler.buffered(&venue), 1);
+        assert_eq!(throttler.drain(60 * SECOND).len(), 1);
+    }
+
+    #[rstest]
+    #[case(ThrottleOverflowPolicy::Drop)]
+    #[case(ThrottleOverflowPolicy::Reject)]
+    fn test_cancel_is_buffered_regardless_of_overflow_policy(
+        #[case] overflow_policy: ThrottleOverflowPolicy,
+    ) {
+        let mut throttler = OrderThrottler::new(config(overflow_policy));
+        let venue = Venue::from("BINANCE");
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-1"), 0)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-2"), 0)
+        ));
+
+        let outcome = throttler.send(cancel("ETHUSDT.BINANCE", "O-1"), 0);
+
+        assert!(matches!(outcome, ThrottleOutcome::Buffered));
+        assert_eq!(throttler.buffered(&venue), 1);
+        assert_eq!(throttler.dropped_count, 0);
+        assert_eq!(throttler.rejected_count, 0);
+        let drained = throttler.drain(SECOND);
+        assert!(
+            matches!(&drained[..], [TradingCommand::CancelOrder(cmd)] if cmd.client_order_id == ClientOrderId::from("O-1"))
+        );
+    }
+
+    #[rstest]
+    fn test_submit_behind_buffered_cancel_uses_overflow_policy() {
+        let mut throttler = OrderThrottler::new(config(ThrottleOverflowPolicy::Reject));
+        throttler.send(submit("ETHUSDT.BINANCE", "O-1"), 0);
+        throttler.send(submit("ETHUSDT.BINANCE", "O-2"), 0);
+        throttler.send(cancel("ETHUSDT.BINANCE", "O-1"), 0);
+
+        let outcome = throttler.send(submit("ETHUSDT.BINANCE", "O-3"), 0);
+
+        assert!(matches!(outcome, ThrottleOutcome::Rejected(_)));
+        assert_eq!(throttler.buffered(&Venue::from("BINANCE")), 1);
+    }
+
+    #[rstest]
+    fn test_set_limits_applies_to_next_command() {
+        let mut throttler = OrderThrottler::new(config(ThrottleOverflowPolicy::Reject));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-1"), 0)
+        ));
+
+        throttler.set_limits(ThrottleLimits {
+            burst: RateLimit::new(1, SECOND),
+            sustained: RateLimit::new(3, 60 * SECOND),
+        });
+
+        assert!(matches!(
+            throttler.send(submit("ETHUSDT.BINANCE", "O-2"), 0),
+            ThrottleOutcome::Rejected(_)
+        ));
+        assert!(is_ready(
+            &throttler.send(submit("ETHUSDT.BINANCE", "O-3"), SECOND)
+        ));
+    }
+
+    #[rstest]
+    fn test_set_venue_limits_overrides_and_removes() {
+        let mut throttler = OrderThrottler::new(config(ThrottleOverflowPolicy::Reject));
+        let venue = Venue::from("BINANCE");
+        let limits = ThrottleLimits {
+            burst: RateLimit::new(10, SECOND),
+            sustained: RateLimit::new(100, 60 * SECOND),
+        };
+
+        throttler.set_venue_limits(venue, Some(limits));
+        assert_eq!(throttler.limits(&venue), limits);
+
+        throttler.set_venue_limits(venue, None);
+        assert_eq!(
+            throttler.limits(&venue),
+            config(ThrottleOverflowPolicy::Reject).limits
+        );
+    }
+}
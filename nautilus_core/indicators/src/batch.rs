@@ -0,0 +1,354 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Batch computation of bars and indicators over large arrays, for research workloads
+//! and catalog preprocessing.
+//!
+//! These functions are separate from the streaming indicators and operate on whole
+//! columns at once. Reductions are computed over fixed width lanes of independent
+//! accumulators, which the compiler lowers to SIMD instructions on stable Rust.
+//! Batch indicator outputs match the values of the streaming implementations.
+
+use anyhow::{ensure, Result};
+use nautilus_core::time::UnixNanos;
+
+/// The number of independent accumulator lanes used by the reduction kernels.
+const LANES: usize = 8;
+
+/// The number of outputs after which a rolling sum is recomputed exactly, bounding
+/// the floating point drift of adding and removing values.
+const RESYNC_INTERVAL: usize = 1024;
+
+/// Returns the sum of the given `values`.
+#[must_use]
+pub fn sum(values: &[f64]) -> f64 {
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    let mut acc = [0.0; LANES];
+    for chunk in chunks {
+        for (a, v) in acc.iter_mut().zip(chunk) {
+            *a += v;
+        }
+    }
+    acc.iter().sum::<f64>() + remainder.iter().sum::<f64>()
+}
+
+/// Returns the maximum of the given `values` (or `None` if empty).
+#[must_use]
+pub fn max(values: &[f64]) -> Option<f64> {
+    reduce(values, f64::NEG_INFINITY, f64::max)
+}
+
+/// Returns the minimum of the given `values` (or `None` if empty).
+#[must_use]
+pub fn min(values: &[f64]) -> Option<f64> {
+    reduce(values, f64::INFINITY, f64::min)
+}
+
+#[inline]
+fn reduce(values: &[f64], init: f64, f: fn(f64, f64) -> f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let chunks = values.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+
+    let mut acc = [init; LANES];
+    for chunk in chunks {
+        for (a, v) in acc.iter_mut().zip(chunk) {
+            *a = f(*a, *v);
+        }
+    }
+    let result = acc.iter().fold(init, |r, a| f(r, *a));
+    Some(remainder.iter().fold(result, |r, v| f(r, *v)))
+}
+
+/// Represents a batch of OHLCV bars in columnar form.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OhlcvBatch {
+    /// The UNIX timestamps (nanoseconds) when each bar closed.
+    pub ts_event: Vec<UnixNanos>,
+    pub open: Vec<f64>,
+    pub high: Vec<f64>,
+    pub low: Vec<f64>,
+    pub close: Vec<f64>,
+    pub volume: Vec<f64>,
+}
+
+impl OhlcvBatch {
+    /// Returns the number of bars in the batch.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ts_event.len()
+    }
+
+    /// Returns whether the batch contains no bars.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ts_event.is_empty()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ts_event: Vec::with_capacity(capacity),
+            open: Vec::with_capacity(capacity),
+            high: Vec::with_capacity(capacity),
+            low: Vec::with_capacity(capacity),
+            close: Vec::with_capacity(capacity),
+            volume: Vec::with_capacity(capacity),
+        }
+    }
+
+    // Pushes a bar aggregated from the given non-empty segment of ticks.
+    fn push_segment(&mut self, ts_event: UnixNanos, prices: &[f64], sizes: &[f64]) {
+        self.ts_event.push(ts_event);
+        self.open.push(prices[0]);
+        self.high.push(max(prices).unwrap_or(f64::NAN));
+        self.low.push(min(prices).unwrap_or(f64::NAN));
+        self.close.push(prices[prices.len() - 1]);
+        self.volume.push(sum(sizes));
+    }
+}
+
+fn check_ticks(ts_event: &[UnixNanos], prices: &[f64], sizes: &[f64]) -> Result<()> {
+    ensure!(
+        ts_event.len() == prices.len() && prices.len() == sizes.len(),
+        "Tick columns must have equal lengths, was ts_event={}, prices={}, sizes={}",
+        ts_event.len(),
+        prices.len(),
+        sizes.len()
+    );
+    Ok(())
+}
+
+/// Aggregates ticks into bars of `step` ticks each, where each bar closes at the
+/// timestamp of its last tick.
+///
+/// Any trailing ticks which do not complete a bar are not aggregated.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the tick columns have different lengths.
+/// - If `step` is zero.
+pub fn bars_by_count(
+    ts_event: &[UnixNanos],
+    prices: &[f64],
+    sizes: &[f64],
+    step: usize,
+) -> Result<OhlcvBatch> {
+    check_ticks(ts_event, prices, sizes)?;
+    ensure!(step > 0, "Invalid `step` for tick bars, was 0");
+
+    let mut batch = OhlcvBatch::with_capacity(prices.len() / step);
+    for ((ts, prices), sizes) in ts_event
+        .chunks_exact(step)
+        .zip(prices.chunks_exact(step))
+        .zip(sizes.chunks_exact(step))
+    {
+        batch.push_segment(ts[step - 1], prices, sizes);
+    }
+    Ok(batch)
+}
+
+/// Aggregates ticks into time bars of `interval_ns`, where a bar covers the ticks with
+/// timestamps in `[close - interval_ns, close)` and closes on an interval boundary.
+///
+/// Intervals without ticks produce no bar, and the final bar is included even if the
+/// ticks end before its interval closes.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the tick columns have different lengths.
+/// - If `interval_ns` is zero.
+/// - If `ts_event` is not sorted in ascending order.
+pub fn bars_by_time(
+    ts_event: &[UnixNanos],
+    prices: &[f64],
+    sizes: &[f64],
+    interval_ns: u64,
+) -> Result<OhlcvBatch> {
+    check_ticks(ts_event, prices, sizes)?;
+    ensure!(
+        interval_ns > 0,
+        "Invalid `interval_ns` for time bars, was 0"
+    );
+    ensure!(
+        ts_event.windows(2).all(|w| w[0] <= w[1]),
+        "Tick timestamps must be sorted in ascending order"
+    );
+
+    let mut batch = OhlcvBatch::default();
+    let mut start = 0;
+    while start < ts_event.len() {
+        let close = (ts_event[start] / interval_ns + 1) * interval_ns;
+        let end = start + ts_event[start..].partition_point(|ts| *ts < close);
+        batch.push_segment(close, &prices[start..end], &sizes[start..end]);
+        start = end;
+    }
+    Ok(batch)
+}
+
+/// Returns the simple moving average of `values` over `period`, matching the
+/// streaming `SimpleMovingAverage` (averaging the available inputs during warm up).
+///
+/// # Errors
+///
+/// This function returns an error if `period` is zero.
+pub fn sma(values: &[f64], period: usize) -> Result<Vec<f64>> {
+    ensure!(period > 0, "Invalid `period` for SMA, was 0");
+
+    let mut output = Vec::with_capacity(values.len());
+    let mut window_sum = 0.0;
+    for (i, value) in values.iter().enumerate() {
+        window_sum += value;
+        if i >= period {
+            window_sum -= values[i - period];
+        }
+        if i % RESYNC_INTERVAL == RESYNC_INTERVAL - 1 {
+            window_sum = sum(&values[(i + 1).saturating_sub(period)..=i]);
+        }
+        output.push(window_sum / (i + 1).min(period) as f64);
+    }
+    Ok(output)
+}
+
+/// Returns the exponential moving average of `values` over `period`, matching the
+/// streaming `ExponentialMovingAverage` (seeded with the first value).
+///
+/// # Errors
+///
+/// This function returns an error if `period` is zero.
+pub fn ema(values: &[f64], period: usize) -> Result<Vec<f64>> {
+    ensure!(period > 0, "Invalid `period` for EMA, was 0");
+
+    let alpha = 2.0 / (period as f64 + 1.0);
+    let mut output = Vec::with_capacity(values.len());
+    let mut value = values.first().copied().unwrap_or_default();
+    for input in values {
+        value = alpha.mul_add(*input, (1.0 - alpha) * value);
+        output.push(value);
+    }
+    Ok(output)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        average::{ema::ExponentialMovingAverage, sma::SimpleMovingAverage},
+        indicator::MovingAverage,
+    };
+
+    fn stub_values(len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| 100.0 + (i as f64 * 0.37).sin() * 5.0)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(7)]
+    #[case(8)]
+    #[case(1_001)]
+    fn test_reductions_match_sequential(#[case] len: usize) {
+        let values = stub_values(len);
+
+        assert!((sum(&values) - values.iter().sum::<f64>()).abs() < 1e-9);
+        assert_eq!(max(&values), values.iter().copied().reduce(f64::max));
+        assert_eq!(min(&values), values.iter().copied().reduce(f64::min));
+    }
+
+    #[rstest]
+    fn test_bars_by_count() {
+        let ts_event = [1, 2, 3, 4, 5, 6, 7];
+        let prices = [10.0, 12.0, 9.0, 11.0, 11.5, 13.0, 14.0];
+        let sizes = [1.0, 2.0, 1.0, 3.0, 1.0, 1.0, 1.0];
+
+        let batch = bars_by_count(&ts_event, &prices, &sizes, 3).unwrap();
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.ts_event, vec![3, 6]);
+        assert_eq!(batch.open, vec![10.0, 11.0]);
+        assert_eq!(batch.high, vec![12.0, 13.0]);
+        assert_eq!(batch.low, vec![9.0, 11.0]);
+        assert_eq!(batch.close, vec![9.0, 13.0]);
+        assert_eq!(batch.volume, vec![4.0, 5.0]);
+    }
+
+    #[rstest]
+    fn test_bars_by_time_skips_empty_intervals() {
+        let ts_event = [0, 50, 99, 100, 350];
+        let prices = [10.0, 12.0, 11.0, 13.0, 9.0];
+        let sizes = [1.0, 1.0, 1.0, 2.0, 5.0];
+
+        let batch = bars_by_time(&ts_event, &prices, &sizes, 100).unwrap();
+
+        assert_eq!(batch.ts_event, vec![100, 200, 400]);
+        assert_eq!(batch.open, vec![10.0, 13.0, 9.0]);
+        assert_eq!(batch.high, vec![12.0, 13.0, 9.0]);
+        assert_eq!(batch.low, vec![10.0, 13.0, 9.0]);
+        assert_eq!(batch.close, vec![11.0, 13.0, 9.0]);
+        assert_eq!(batch.volume, vec![3.0, 2.0, 5.0]);
+    }
+
+    #[rstest]
+    fn test_bars_with_invalid_inputs() {
+        assert!(bars_by_count(&[1, 2], &[1.0], &[1.0], 1).is_err());
+        assert!(bars_by_count(&[1], &[1.0], &[1.0], 0).is_err());
+        assert!(bars_by_time(&[2, 1], &[1.0, 1.0], &[1.0, 1.0], 10).is_err());
+        assert!(bars_by_time(&[1], &[1.0], &[1.0], 0).is_err());
+    }
+
+    #[rstest]
+    fn test_sma_matches_streaming() {
+        let values = stub_values(3_000);
+        let mut streaming = SimpleMovingAverage::new(20, None).unwrap();
+
+        let batch = sma(&values, 20).unwrap();
+
+        for (value, expected) in values.iter().zip(&batch) {
+            streaming.update_raw(*value);
+            assert!((streaming.value() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[rstest]
+    fn test_ema_matches_streaming() {
+        let values = stub_values(500);
+        let mut streaming = ExponentialMovingAverage::new(10, None).unwrap();
+
+        let batch = ema(&values, 10).unwrap();
+
+        for (value, expected) in values.iter().zip(&batch) {
+            streaming.update_raw(*value);
+            assert_eq!(streaming.value(), *expected);
+        }
+    }
+
+    #[rstest]
+    fn test_indicators_with_zero_period() {
+        assert!(sma(&[1.0], 0).is_err());
+        assert!(ema(&[1.0], 0).is_err());
+    }
+}
@@ -14,8 +14,10 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod average;
+pub mod batch;
 pub mod indicator;
 pub mod momentum;
+pub mod options;
 pub mod ratio;
 
 #[cfg(test)]
@@ -0,0 +1,364 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Black-Scholes-Merton pricing and implied volatility solving for European options.
+
+use std::f64::consts::{PI, SQRT_2};
+
+use anyhow::{bail, Result};
+use nautilus_model::enums::OptionKind;
+
+/// The lower bound of the implied volatility search range.
+pub const MIN_VOL: f64 = 1e-6;
+/// The upper bound of the implied volatility search range.
+pub const MAX_VOL: f64 = 10.0;
+
+const PRICE_TOLERANCE: f64 = 1e-10; // Relative to the option price
+const VOL_TOLERANCE: f64 = 1e-12;
+const MAX_ITERATIONS: usize = 100;
+
+/// Provides the Black-Scholes-Merton model for a European option, with continuous
+/// compounding of the risk-free rate and dividend yield.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlackScholes {
+    pub option_kind: OptionKind,
+    /// The price of the underlying.
+    pub spot: f64,
+    pub strike: f64,
+    /// The time to expiry in years.
+    pub time_to_expiry: f64,
+    /// The risk-free interest rate.
+    pub rate: f64,
+    /// The dividend yield of the underlying (or the foreign rate for currency options).
+    pub dividend_yield: f64,
+}
+
+impl BlackScholes {
+    #[must_use]
+    pub fn new(
+        option_kind: OptionKind,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        rate: f64,
+        dividend_yield: f64,
+    ) -> Self {
+        Self {
+            option_kind,
+            spot,
+            strike,
+            time_to_expiry,
+            rate,
+            dividend_yield,
+        }
+    }
+
+    /// Returns the option price for the given volatility.
+    #[must_use]
+    pub fn price(&self, vol: f64) -> f64 {
+        let (spot, strike) = self.discounted();
+        let (d1, d2) = self.d1_d2(vol);
+        match self.option_kind {
+            OptionKind::Call => spot * norm_cdf(d1) - strike * norm_cdf(d2),
+            OptionKind::Put => strike * norm_cdf(-d2) - spot * norm_cdf(-d1),
+        }
+    }
+
+    /// Returns the sensitivity of the option price to the volatility.
+    #[must_use]
+    pub fn vega(&self, vol: f64) -> f64 {
+        let (spot, _) = self.discounted();
+        let (d1, _) = self.d1_d2(vol);
+        spot * norm_pdf(d1) * self.time_to_expiry.sqrt()
+    }
+
+    /// Returns the no-arbitrage `(lower, upper)` bounds of the option price.
+    #[must_use]
+    pub fn price_bounds(&self) -> (f64, f64) {
+        let (spot, strike) = self.discounted();
+        match self.option_kind {
+            OptionKind::Call => ((spot - strike).max(0.0), spot),
+            OptionKind::Put => ((strike - spot).max(0.0), strike),
+        }
+    }
+
+    /// Returns the volatility at which the model reproduces the given option price.
+    ///
+    /// Newton-Raphson is used from an analytic initial guess, falling back to Brent's
+    /// method over [`MIN_VOL`, `MAX_VOL`] where Newton fails to converge (such as for
+    /// far out-of-the-money options with little vega).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the spot or strike is not positive, or the option has expired.
+    /// - If `price` is outside the no-arbitrage bounds of the option price.
+    /// - If no implied volatility within the search range reproduces `price`.
+    pub fn implied_vol(&self, price: f64) -> Result<f64> {
+        if self.spot <= 0.0 || self.strike <= 0.0 {
+            bail!(
+                "invalid spot {} or strike {} for implied volatility",
+                self.spot,
+                self.strike
+            );
+        }
+        if self.time_to_expiry <= 0.0 {
+            bail!("option expired, time to expiry was {}", self.time_to_expiry);
+        }
+        let (lower, upper) = self.price_bounds();
+        if !price.is_finite() || price <= lower || price >= upper {
+            bail!("price {price} outside no-arbitrage bounds ({lower}, {upper})");
+        }
+
+        let tolerance = PRICE_TOLERANCE * price;
+        match self.newton(price, tolerance) {
+            Some(vol) => Ok(vol),
+            None => self.brent(price, tolerance),
+        }
+    }
+
+    fn discounted(&self) -> (f64, f64) {
+        (
+            self.spot * (-self.dividend_yield * self.time_to_expiry).exp(),
+            self.strike * (-self.rate * self.time_to_expiry).exp(),
+        )
+    }
+
+    fn d1_d2(&self, vol: f64) -> (f64, f64) {
+        let vol_sqrt_t = vol * self.time_to_expiry.sqrt();
+        let drift = self.rate - self.dividend_yield + 0.5 * vol * vol;
+        let d1 = ((self.spot / self.strike).ln() + drift * self.time_to_expiry) / vol_sqrt_t;
+        (d1, d1 - vol_sqrt_t)
+    }
+
+    /// Returns the Manaster-Koehler initial guess, or the Brenner-Subrahmanyam
+    /// approximation for options close to at-the-money.
+    fn initial_guess(&self, price: f64) -> f64 {
+        let (spot, strike) = self.discounted();
+        let t = self.time_to_expiry;
+        let guess = (2.0 * (spot / strike).ln().abs() / t).sqrt();
+        if guess > 0.05 {
+            guess.min(MAX_VOL)
+        } else {
+            ((2.0 * PI / t).sqrt() * price / spot).clamp(0.05, MAX_VOL)
+        }
+    }
+
+    fn newton(&self, price: f64, tolerance: f64) -> Option<f64> {
+        let mut vol = self.initial_guess(price);
+        for _ in 0..MAX_ITERATIONS {
+            let diff = self.price(vol) - price;
+            if diff.abs() < tolerance {
+                return Some(vol);
+            }
+            let vega = self.vega(vol);
+            if vega < 1e-12 {
+                return None;
+            }
+            vol -= diff / vega;
+            if !(MIN_VOL..=MAX_VOL).contains(&vol) {
+                return None;
+            }
+        }
+        None
+    }
+
+    fn brent(&self, price: f64, tolerance: f64) -> Result<f64> {
+        let f = |vol: f64| self.price(vol) - price;
+
+        let (mut a, mut b) = (MIN_VOL, MAX_VOL);
+        let (mut fa, mut fb) = (f(a), f(b));
+        if fa * fb > 0.0 {
+            bail!("implied volatility for price {price} not within [{MIN_VOL}, {MAX_VOL}]");
+        }
+
+        let (mut c, mut fc) = (b, fb);
+        let mut d = b - a;
+        let mut e = d;
+        for _ in 0..MAX_ITERATIONS {
+            if fb * fc > 0.0 {
+                // Keep the root bracketed between b and c
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs() {
+                (a, b, c) = (b, c, b);
+                (fa, fb, fc) = (fb, fc, fb);
+            }
+
+            let tol = 2.0 * f64::EPSILON * b.abs() + 0.5 * VOL_TOLERANCE;
+            let mid = 0.5 * (c - b);
+            if mid.abs() <= tol || fb.abs() < tolerance {
+                return Ok(b);
+            }
+
+            if e.abs() >= tol && fa.abs() > fb.abs() {
+                // Attempt inverse quadratic interpolation (or secant)
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    (2.0 * mid * s, 1.0 - s)
+                } else {
+                    let q = fa / fc;
+                    let r = fb / fc;
+                    (
+                        s * (2.0 * mid * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+                if p > 0.0 {
+                    q = -q;
+                }
+                p = p.abs();
+                if 2.0 * p < (3.0 * mid * q - (tol * q).abs()).min((e * q).abs()) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = mid;
+                    e = d;
+                }
+            } else {
+                // Fall back to bisection
+                d = mid;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+            b += if d.abs() > tol { d } else { tol.copysign(mid) };
+            fb = f(b);
+        }
+
+        bail!("implied volatility for price {price} did not converge")
+    }
+}
+
+/// Returns the standard normal cumulative distribution function at `x`.
+#[must_use]
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// Returns the standard normal probability density function at `x`.
+#[must_use]
+pub fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+}
+
+/// Returns the complementary error function (Chebyshev approximation with a fractional
+/// error below 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let r = t * poly.exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn model(option_kind: OptionKind, strike: f64, time_to_expiry: f64) -> BlackScholes {
+        BlackScholes::new(option_kind, 100.0, strike, time_to_expiry, 0.05, 0.0)
+    }
+
+    #[rstest]
+    fn test_norm_cdf() {
+        assert!((norm_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((norm_cdf(1.96) - 0.975_002_1).abs() < 1e-6);
+        assert!((norm_cdf(-1.96) - 0.024_997_9).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(OptionKind::Call, 10.450_58)]
+    #[case(OptionKind::Put, 5.573_53)]
+    fn test_price(#[case] option_kind: OptionKind, #[case] expected: f64) {
+        let price = model(option_kind, 100.0, 1.0).price(0.2);
+
+        assert!((price - expected).abs() < 1e-4);
+    }
+
+    #[rstest]
+    fn test_put_call_parity() {
+        let call = model(OptionKind::Call, 110.0, 0.5).price(0.3);
+        let put = model(OptionKind::Put, 110.0, 0.5).price(0.3);
+
+        let forward = 100.0 - 110.0 * (-0.05 * 0.5_f64).exp();
+        assert!((call - put - forward).abs() < 1e-6);
+    }
+
+    #[rstest]
+    #[case(OptionKind::Call, 100.0, 1.0, 0.2)]
+    #[case(OptionKind::Put, 100.0, 1.0, 0.2)]
+    #[case(OptionKind::Call, 60.0, 0.25, 0.45)]
+    #[case(OptionKind::Put, 150.0, 2.0, 0.15)]
+    #[case(OptionKind::Call, 130.0, 0.02, 0.8)]
+    #[case(OptionKind::Put, 70.0, 0.1, 0.6)]
+    #[case(OptionKind::Call, 100.0, 1.0, 4.0)]
+    #[case(OptionKind::Call, 200.0, 0.05, 0.3)] // Little vega, solved by Brent's method
+    fn test_implied_vol_round_trip(
+        #[case] option_kind: OptionKind,
+        #[case] strike: f64,
+        #[case] time_to_expiry: f64,
+        #[case] vol: f64,
+    ) {
+        let model = model(option_kind, strike, time_to_expiry);
+        let price = model.price(vol);
+
+        let implied_vol = model.implied_vol(price).unwrap();
+
+        assert!((implied_vol - vol).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_implied_vol_with_price_below_intrinsic() {
+        let model = model(OptionKind::Call, 80.0, 1.0);
+        let (lower, _) = model.price_bounds();
+
+        assert!(model.implied_vol(lower - 0.01).is_err());
+    }
+
+    #[rstest]
+    fn test_implied_vol_with_price_above_spot() {
+        let model = model(OptionKind::Call, 80.0, 1.0);
+
+        assert!(model.implied_vol(100.5).is_err());
+    }
+
+    #[rstest]
+    fn test_implied_vol_when_expired() {
+        let model = model(OptionKind::Put, 100.0, 0.0);
+
+        assert!(model.implied_vol(1.0).is_err());
+    }
+}
@@ -0,0 +1,395 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::quote::QuoteTick, enums::OptionKind, instruments::options_contract::OptionsContract,
+    types::price::Price,
+};
+use pyo3::prelude::*;
+use ustr::Ustr;
+
+use super::implied_vol::BlackScholes;
+
+const NANOSECONDS_IN_YEAR: f64 = 365.0 * 86_400.0 * 1_000_000_000.0;
+
+/// Represents an implied volatility observation for an option on a [`VolSurface`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VolPoint {
+    pub strike: Price,
+    pub expiration_ns: UnixNanos,
+    /// The kind of the option the implied volatility was last solved from.
+    pub option_kind: OptionKind,
+    pub implied_vol: f64,
+    pub ts_event: UnixNanos,
+}
+
+/// Represents an implied volatility surface for the options on a single underlying, as
+/// a grid of expiries by strikes updated from option quotes.
+///
+/// Volatilities between grid points are interpolated linearly in strike within an
+/// expiry, and linearly in total variance (vol² × time) across expiries. Beyond the
+/// quoted strikes and expiries the nearest volatility is extrapolated flat.
+#[derive(Clone, Debug)]
+#[pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")]
+pub struct VolSurface {
+    pub underlying: Ustr,
+    /// The risk-free interest rate used to solve implied volatilities.
+    pub rate: f64,
+    /// The dividend yield of the underlying used to solve implied volatilities.
+    pub dividend_yield: f64,
+    smiles: BTreeMap<UnixNanos, BTreeMap<Price, VolPoint>>,
+}
+
+impl VolSurface {
+    #[must_use]
+    pub fn new(underlying: Ustr, rate: f64, dividend_yield: f64) -> Self {
+        Self {
+            underlying,
+            rate,
+            dividend_yield,
+            smiles: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of points on the surface.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.smiles.values().map(BTreeMap::len).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.smiles.is_empty()
+    }
+
+    /// Returns the expirations on the surface in ascending order.
+    #[must_use]
+    pub fn expirations(&self) -> Vec<UnixNanos> {
+        self.smiles.keys().copied().collect()
+    }
+
+    /// Returns the points for the given expiration in ascending order of strike.
+    #[must_use]
+    pub fn smile(&self, expiration_ns: UnixNanos) -> Vec<&VolPoint> {
+        self.smiles
+            .get(&expiration_ns)
+            .map(|smile| smile.values().collect())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn get_point(&self, strike: Price, expiration_ns: UnixNanos) -> Option<&VolPoint> {
+        self.smiles.get(&expiration_ns)?.get(&strike)
+    }
+
+    /// Updates the surface from the midpoint of the given option quote, returning the
+    /// implied volatility solved for the contract.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the contract is not on the surface's underlying or does not match the quote.
+    /// - If no implied volatility could be solved for the quote midpoint.
+    pub fn update(
+        &mut self,
+        contract: &OptionsContract,
+        quote: &QuoteTick,
+        underlying_px: f64,
+    ) -> Result<f64> {
+        if quote.instrument_id != contract.id {
+            bail!(
+                "quote for {} does not match contract {}",
+                quote.instrument_id,
+                contract.id
+            );
+        }
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        self.update_price(contract, mid, underlying_px, quote.ts_event)
+    }
+
+    /// Updates the surface from the given option price, returning the implied
+    /// volatility solved for the contract.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the contract is not on the surface's underlying.
+    /// - If no implied volatility could be solved for `price`.
+    pub fn update_price(
+        &mut self,
+        contract: &OptionsContract,
+        price: f64,
+        underlying_px: f64,
+        ts_event: UnixNanos,
+    ) -> Result<f64> {
+        if contract.underlying != self.underlying {
+            bail!(
+                "{} underlying {} is not on the {} surface",
+                contract.id,
+                contract.underlying,
+                self.underlying
+            );
+        }
+
+        let model = BlackScholes::new(
+            contract.option_kind,
+            underlying_px,
+            contract.strike_price.as_f64(),
+            year_fraction(ts_event, contract.expiration_ns),
+            self.rate,
+            self.dividend_yield,
+        );
+        let implied_vol = model.implied_vol(price)?;
+
+        self.smiles
+            .entry(contract.expiration_ns)
+            .or_default()
+            .insert(
+                contract.strike_price,
+                VolPoint {
+                    strike: contract.strike_price,
+                    expiration_ns: contract.expiration_ns,
+                    option_kind: contract.option_kind,
+                    implied_vol,
+                    ts_event,
+                },
+            );
+
+        Ok(implied_vol)
+    }
+
+    /// Removes the points for all expirations at or before `ts_now`.
+    pub fn remove_expired(&mut self, ts_now: UnixNanos) {
+        self.smiles
+            .retain(|&expiration_ns, _| expiration_ns > ts_now);
+    }
+
+    /// Returns the implied volatility interpolated from the surface at the given strike
+    /// and expiration (with `None` when there are no unexpired points).
+    #[must_use]
+    pub fn implied_vol(
+        &self,
+        strike: f64,
+        expiration_ns: UnixNanos,
+        ts_now: UnixNanos,
+    ) -> Option<f64> {
+        if expiration_ns <= ts_now {
+            return None;
+        }
+        let before = self.smiles.range(ts_now + 1..=expiration_ns).next_back();
+        let after = self.smiles.range(expiration_ns..).next();
+
+        match (before, after) {
+            (Some((&expiration0, smile0)), Some((&expiration1, smile1)))
+                if expiration0 != expiration1 =>
+            {
+                let t0 = year_fraction(ts_now, expiration0);
+                let t1 = year_fraction(ts_now, expiration1);
+                let t = year_fraction(ts_now, expiration_ns);
+                let variance0 = interpolate_strike(smile0, strike)?.powi(2) * t0;
+                let variance1 = interpolate_strike(smile1, strike)?.powi(2) * t1;
+                let variance = variance0 + (variance1 - variance0) * (t - t0) / (t1 - t0);
+                Some((variance / t).sqrt())
+            }
+            (Some((_, smile)), _) | (None, Some((_, smile))) => interpolate_strike(smile, strike),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the given implied volatility less the surface volatility at the strike and
+    /// expiration, being positive where an option is rich to the surface.
+    #[must_use]
+    pub fn vol_spread(
+        &self,
+        implied_vol: f64,
+        strike: f64,
+        expiration_ns: UnixNanos,
+        ts_now: UnixNanos,
+    ) -> Option<f64> {
+        self.implied_vol(strike, expiration_ns, ts_now)
+            .map(|surface_vol| implied_vol - surface_vol)
+    }
+}
+
+/// Returns the time in years from `ts_now` until `expiration_ns` (negative if expired).
+fn year_fraction(ts_now: UnixNanos, expiration_ns: UnixNanos) -> f64 {
+    (expiration_ns as f64 - ts_now as f64) / NANOSECONDS_IN_YEAR
+}
+
+fn interpolate_strike(smile: &BTreeMap<Price, VolPoint>, strike: f64) -> Option<f64> {
+    let mut lower = None;
+    let mut upper = None;
+    for point in smile.values() {
+        if point.strike.as_f64() <= strike {
+            lower = Some(point);
+        } else {
+            upper = Some(point);
+            break;
+        }
+    }
+
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => {
+            let (k0, k1) = (lower.strike.as_f64(), upper.strike.as_f64());
+            let weight = (strike - k0) / (k1 - k0);
+            Some(lower.implied_vol + (upper.implied_vol - lower.implied_vol) * weight)
+        }
+        (Some(point), None) | (None, Some(point)) => Some(point.implied_vol),
+        (None, None) => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::instrument_id::InstrumentId, instruments::stubs::options_contract_appl,
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const DAY: u64 = 86_400 * 1_000_000_000;
+
+    fn contract(strike: &str, expiration_ns: UnixNanos) -> OptionsContract {
+        let mut contract = options_contract_appl();
+        contract.strike_price = Price::from(strike);
+        contract.expiration_ns = expiration_ns;
+        contract
+    }
+
+    fn option_price(contract: &OptionsContract, vol: f64, ts_now: UnixNanos) -> f64 {
+        BlackScholes::new(
+            contract.option_kind,
+            150.0,
+            contract.strike_price.as_f64(),
+            year_fraction(ts_now, contract.expiration_ns),
+            0.01,
+            0.0,
+        )
+        .price(vol)
+    }
+
+    fn surface_with(points: &[(&str, UnixNanos, f64)]) -> VolSurface {
+        let mut surface = VolSurface::new(Ustr::from("AAPL"), 0.01, 0.0);
+        for &(strike, expiration_ns, vol) in points {
+            let contract = contract(strike, expiration_ns);
+            let price = option_price(&contract, vol, 0);
+            surface.update_price(&contract, price, 150.0, 0).unwrap();
+        }
+        surface
+    }
+
+    #[rstest]
+    fn test_update_from_quote() {
+        let contract = options_contract_appl();
+        let ts_event = contract.expiration_ns - 30 * DAY;
+        let mut surface = VolSurface::new(contract.underlying, 0.01, 0.0);
+        let price = Price::new(option_price(&contract, 0.3, ts_event), 2).unwrap();
+        let quote = QuoteTick::new(
+            contract.id,
+            price,
+            price,
+            Quantity::from(10),
+            Quantity::from(10),
+            ts_event,
+            ts_event,
+        )
+        .unwrap();
+
+        let implied_vol = surface.update(&contract, &quote, 150.0).unwrap();
+
+        assert!((implied_vol - 0.3).abs() < 1e-3);
+        assert_eq!(surface.len(), 1);
+        let point = surface
+            .get_point(contract.strike_price, contract.expiration_ns)
+            .unwrap();
+        assert_eq!(point.implied_vol, implied_vol);
+        assert_eq!(point.ts_event, ts_event);
+    }
+
+    #[rstest]
+    fn test_update_with_other_underlying() {
+        let contract = options_contract_appl();
+        let mut surface = VolSurface::new(Ustr::from("MSFT"), 0.01, 0.0);
+
+        assert!(surface.update_price(&contract, 5.0, 150.0, 0).is_err());
+        assert!(surface.is_empty());
+    }
+
+    #[rstest]
+    fn test_update_with_mismatched_quote() {
+        let contract = options_contract_appl();
+        let mut surface = VolSurface::new(contract.underlying, 0.01, 0.0);
+        let quote = QuoteTick::new(
+            InstrumentId::from("AAPL211217P00150000.OPRA"),
+            Price::from("5.00"),
+            Price::from("5.10"),
+            Quantity::from(10),
+            Quantity::from(10),
+            0,
+            0,
+        )
+        .unwrap();
+
+        assert!(surface.update(&contract, &quote, 150.0).is_err());
+    }
+
+    #[rstest]
+    fn test_interpolates_across_strikes() {
+        let expiration_ns = 90 * DAY;
+        let surface = surface_with(&[
+            ("140.00", expiration_ns, 0.30),
+            ("160.00", expiration_ns, 0.20),
+        ]);
+
+        let vol = |strike| surface.implied_vol(strike, expiration_ns, 0).unwrap();
+
+        assert!((vol(150.0) - 0.25).abs() < 1e-6);
+        assert!((vol(120.0) - 0.30).abs() < 1e-6); // Flat extrapolation
+        assert!((vol(180.0) - 0.20).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_interpolates_total_variance_across_expiries() {
+        let surface = surface_with(&[("150.00", 30 * DAY, 0.40), ("150.00", 90 * DAY, 0.20)]);
+
+        let vol = surface.implied_vol(150.0, 60 * DAY, 0).unwrap();
+
+        // Halfway between the total variances of the 30 and 90 day expiries
+        let variance = (0.40_f64.powi(2) * 30.0 + 0.20_f64.powi(2) * 90.0) / 2.0 / 365.0;
+        assert!((vol - (variance / (60.0 / 365.0)).sqrt()).abs() < 1e-6);
+        assert_eq!(surface.expirations(), vec![30 * DAY, 90 * DAY]);
+    }
+
+    #[rstest]
+    fn test_remove_expired_and_vol_spread() {
+        let mut surface = surface_with(&[("150.00", 30 * DAY, 0.40), ("150.00", 90 * DAY, 0.20)]);
+
+        surface.remove_expired(30 * DAY);
+
+        assert_eq!(surface.expirations(), vec![90 * DAY]);
+        assert!(surface.implied_vol(150.0, 90 * DAY, 90 * DAY).is_none());
+        let spread = surface.vol_spread(0.25, 150.0, 90 * DAY, 30 * DAY).unwrap();
+        assert!((spread - 0.05).abs() < 1e-6);
+    }
+}
@@ -17,6 +17,7 @@ use pyo3::{prelude::*, pymodule};
 
 pub mod average;
 pub mod momentum;
+pub mod options;
 pub mod ratio;
 
 #[pymodule]
@@ -33,5 +34,7 @@ pub fn indicators(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     // momentum
     m.add_class::<crate::momentum::rsi::RelativeStrengthIndex>()?;
     m.add_class::<crate::momentum::aroon::AroonOscillator>()?;
+    // options
+    m.add_class::<crate::options::surface::VolSurface>()?;
     Ok(())
 }
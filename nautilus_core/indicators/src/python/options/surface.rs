@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{python::to_pyvalue_err, time::UnixNanos};
+use nautilus_model::{data::quote::QuoteTick, instruments::options_contract::OptionsContract};
+use pyo3::prelude::*;
+use ustr::Ustr;
+
+use crate::options::surface::VolSurface;
+
+#[pymethods]
+impl VolSurface {
+    #[new]
+    fn py_new(underlying: &str, rate: f64, dividend_yield: f64) -> Self {
+        Self::new(Ustr::from(underlying), rate, dividend_yield)
+    }
+
+    #[getter]
+    #[pyo3(name = "underlying")]
+    fn py_underlying(&self) -> &str {
+        self.underlying.as_str()
+    }
+
+    #[pyo3(name = "expirations")]
+    fn py_expirations(&self) -> Vec<UnixNanos> {
+        self.expirations()
+    }
+
+    #[pyo3(name = "update")]
+    fn py_update(
+        &mut self,
+        contract: OptionsContract,
+        quote: QuoteTick,
+        underlying_px: f64,
+    ) -> PyResult<f64> {
+        self.update(&contract, &quote, underlying_px)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "update_price")]
+    fn py_update_price(
+        &mut self,
+        contract: OptionsContract,
+        price: f64,
+        underlying_px: f64,
+        ts_event: UnixNanos,
+    ) -> PyResult<f64> {
+        self.update_price(&contract, price, underlying_px, ts_event)
+            .map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "remove_expired")]
+    fn py_remove_expired(&mut self, ts_now: UnixNanos) {
+        self.remove_expired(ts_now);
+    }
+
+    #[pyo3(name = "implied_vol")]
+    fn py_implied_vol(
+        &self,
+        strike: f64,
+        expiration_ns: UnixNanos,
+        ts_now: UnixNanos,
+    ) -> Option<f64> {
+        self.implied_vol(strike, expiration_ns, ts_now)
+    }
+
+    #[pyo3(name = "vol_spread")]
+    fn py_vol_spread(
+        &self,
+        implied_vol: f64,
+        strike: f64,
+        expiration_ns: UnixNanos,
+        ts_now: UnixNanos,
+    ) -> Option<f64> {
+        self.vol_spread(implied_vol, strike, expiration_ns, ts_now)
+    }
+
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("VolSurface({})", self.underlying)
+    }
+}
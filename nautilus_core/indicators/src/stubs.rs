@@ -61,6 +61,7 @@ pub fn trade_tick() -> TradeTick {
         price: Price::from("1500.0000"),
         size: Quantity::from("1.00000000"),
         aggressor_side: AggressorSide::Buyer,
+        flags: 0,
         trade_id: TradeId::from("123456789"),
         ts_event: 1,
         ts_init: 0,
@@ -78,11 +79,7 @@ pub fn bar_ethusdt_binance_minute_bid(#[default("1522")] close_price: &str) -> B
         aggregation: BarAggregation::Minute,
         price_type: PriceType::Bid,
     };
-    let bar_type = BarType {
-        instrument_id,
-        spec: bar_spec,
-        aggregation_source: AggregationSource::External,
-    };
+    let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
     Bar {
         bar_type,
         open: Price::from("1500.0"),
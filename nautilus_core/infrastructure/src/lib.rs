@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod cache;
+pub mod replay;
 
 #[cfg(feature = "python")]
 pub mod python;
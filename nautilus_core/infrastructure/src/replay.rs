@@ -0,0 +1,329 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Replay of recorded external message bus streams.
+//!
+//! Messages published externally by the message bus are recorded either to Redis streams
+//! (one stream per topic) or to a JSON lines file. An [`EventReplayer`] loads a recording,
+//! orders the messages by timestamp and re-publishes them as [`BusMessage`]s onto a fresh
+//! message bus, so a production incident can be debugged against the exact event sequence.
+
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use nautilus_common::msgbus::{is_matching, BusMessage, MessageBus};
+use nautilus_core::time::UnixNanos;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+/// Represents a message recorded from an external message bus stream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedMessage {
+    /// The UNIX timestamp (nanoseconds) when the message was recorded.
+    pub ts_recorded: UnixNanos,
+    /// The topic the message was published on.
+    pub topic: String,
+    /// The serialized payload for the message.
+    pub payload: Vec<u8>,
+}
+
+/// Writes the given `messages` to a JSON lines recording file at `path`.
+pub fn write_recording(path: &Path, messages: &[RecordedMessage]) -> Result<()> {
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create recording {}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+    for message in messages {
+        serde_json::to_writer(&mut writer, message)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads the messages from the JSON lines recording file at `path`.
+pub fn read_recording(path: &Path) -> Result<Vec<RecordedMessage>> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open recording {}", path.display()))?;
+    let mut messages = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message = serde_json::from_str(&line)
+            .with_context(|| format!("Invalid message at line {} of {}", i + 1, path.display()))?;
+        messages.push(message);
+    }
+    Ok(messages)
+}
+
+/// Reads the messages recorded to Redis streams under the given `stream_name` prefix.
+///
+/// The timestamp of each message is taken from its stream entry ID, and so has millisecond
+/// resolution.
+#[cfg(feature = "redis")]
+pub fn read_redis_streams(
+    conn: &mut redis::Connection,
+    stream_name: &str,
+) -> Result<Vec<RecordedMessage>> {
+    use redis::Commands;
+
+    let keys: Vec<String> = conn.keys(format!("{stream_name}*"))?;
+    let mut messages = Vec::new();
+    for key in keys {
+        let topic = key[stream_name.len()..].to_string();
+        let entries: Vec<(String, Vec<Vec<u8>>)> = redis::cmd("XRANGE")
+            .arg(&key)
+            .arg("-")
+            .arg("+")
+            .query(conn)?;
+
+        for (id, fields) in entries {
+            let Some(payload) = fields
+                .chunks_exact(2)
+                .find(|pair| pair[0] == b"payload")
+                .map(|pair| pair[1].clone())
+            else {
+                log::warn!("Skipping stream entry {id} on {key}: no payload");
+                continue;
+            };
+            messages.push(RecordedMessage {
+                ts_recorded: parse_stream_id_ns(&id)?,
+                topic: topic.clone(),
+                payload,
+            });
+        }
+    }
+    Ok(messages)
+}
+
+/// Parses the millisecond timestamp from a Redis stream entry ID (`<millis>-<seq>`),
+/// returning it as UNIX nanoseconds.
+#[cfg_attr(not(feature = "redis"), allow(dead_code))]
+fn parse_stream_id_ns(id: &str) -> Result<UnixNanos> {
+    let millis = id.split('-').next().unwrap_or(id);
+    let millis: u64 = millis
+        .parse()
+        .with_context(|| format!("Invalid stream entry ID {id}"))?;
+    Ok(millis * 1_000_000)
+}
+
+/// Configuration for [`EventReplayer`] instances.
+#[derive(Clone, Debug)]
+pub struct ReplayConfig {
+    /// The replay speed relative to the recording (2.0 is twice as fast), or zero to
+    /// replay as fast as possible.
+    pub speed: f64,
+    /// Messages recorded before this time are skipped.
+    pub start: Option<UnixNanos>,
+    /// Messages recorded after this time are skipped.
+    pub end: Option<UnixNanos>,
+    /// If given, only messages with a topic matching this pattern are replayed.
+    pub topic_pattern: Option<String>,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            speed: 0.0,
+            start: None,
+            end: None,
+            topic_pattern: None,
+        }
+    }
+}
+
+/// Replays recorded messages onto a message bus in timestamp order.
+pub struct EventReplayer {
+    config: ReplayConfig,
+    messages: Vec<RecordedMessage>,
+}
+
+impl EventReplayer {
+    #[must_use]
+    pub fn new(config: ReplayConfig, mut messages: Vec<RecordedMessage>) -> Self {
+        assert!(
+            config.speed >= 0.0 && config.speed.is_finite(),
+            "`speed` must be a finite non-negative number"
+        );
+        let pattern = config.topic_pattern.as_deref().map(Ustr::from);
+        messages.retain(|m| {
+            config.start.map_or(true, |start| m.ts_recorded >= start)
+                && config.end.map_or(true, |end| m.ts_recorded <= end)
+                && pattern.map_or(true, |p| is_matching(&Ustr::from(&m.topic), &p))
+        });
+        // Stable sort keeps the recorded order of messages with equal timestamps
+        messages.sort_by_key(|m| m.ts_recorded);
+        Self { config, messages }
+    }
+
+    /// Creates a replayer for the JSON lines recording file at `path`.
+    pub fn from_file(config: ReplayConfig, path: &Path) -> Result<Self> {
+        Ok(Self::new(config, read_recording(path)?))
+    }
+
+    /// Returns the messages to be replayed, in replay order.
+    #[must_use]
+    pub fn messages(&self) -> &[RecordedMessage] {
+        &self.messages
+    }
+
+    /// Returns the wall clock delay to wait before publishing each message.
+    #[must_use]
+    pub fn delays(&self) -> Vec<Duration> {
+        let mut last = self.messages.first().map(|m| m.ts_recorded);
+        self.messages
+            .iter()
+            .map(|m| {
+                let elapsed_ns = m.ts_recorded - last.unwrap_or(m.ts_recorded);
+                last = Some(m.ts_recorded);
+                if self.config.speed == 0.0 {
+                    Duration::ZERO
+                } else {
+                    Duration::from_nanos((elapsed_ns as f64 / self.config.speed) as u64)
+                }
+            })
+            .collect()
+    }
+
+    /// Replays all messages onto the given `msgbus`, returning the count published.
+    ///
+    /// Each message is published on its recorded topic as a [`BusMessage`], pausing between
+    /// messages according to the configured speed.
+    pub fn replay(&self, msgbus: &mut MessageBus) -> usize {
+        for (message, delay) in self.messages.iter().zip(self.delays()) {
+            if !delay.is_zero() {
+                thread::sleep(delay);
+            }
+
+            let bus_message = BusMessage {
+                topic: message.topic.clone(),
+                payload: message.payload.clone(),
+            };
+            msgbus.publish(&message.topic, &bus_message);
+        }
+        self.messages.len()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use nautilus_common::testing::recording_handler_with;
+    use nautilus_core::uuid::UUID4;
+    use nautilus_model::identifiers::trader_id::TraderId;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn message(ts_recorded: UnixNanos, topic: &str, payload: &str) -> RecordedMessage {
+        RecordedMessage {
+            ts_recorded,
+            topic: topic.to_string(),
+            payload: payload.as_bytes().to_vec(),
+        }
+    }
+
+    fn recording() -> Vec<RecordedMessage> {
+        vec![
+            message(3_000, "events.order.S-001", "accepted"),
+            message(1_000, "data.quotes.SIM", "quote"),
+            message(2_000, "events.order.S-001", "submitted"),
+        ]
+    }
+
+    #[rstest]
+    fn test_recording_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.jsonl");
+
+        write_recording(&path, &recording()).unwrap();
+
+        assert_eq!(read_recording(&path).unwrap(), recording());
+    }
+
+    #[rstest]
+    fn test_replayer_orders_and_filters_messages() {
+        let config = ReplayConfig {
+            topic_pattern: Some("events.order.*".to_string()),
+            ..Default::default()
+        };
+        let replayer = EventReplayer::new(config, recording());
+
+        let payloads: Vec<&[u8]> = replayer
+            .messages()
+            .iter()
+            .map(|m| m.payload.as_slice())
+            .collect();
+        assert_eq!(payloads, vec![b"submitted".as_slice(), b"accepted"]);
+    }
+
+    #[rstest]
+    fn test_replayer_delays_scale_with_speed() {
+        let config = ReplayConfig {
+            speed: 2.0,
+            start: Some(2_000),
+            ..Default::default()
+        };
+        let replayer = EventReplayer::new(config, recording());
+
+        assert_eq!(
+            replayer.delays(),
+            vec![Duration::ZERO, Duration::from_nanos(500)]
+        );
+    }
+
+    #[rstest]
+    fn test_replay_publishes_in_timestamp_order() {
+        let (handler, received) = recording_handler_with("recorder", |message: &dyn Any| {
+            message
+                .downcast_ref::<BusMessage>()
+                .map(|message| message.topic.clone())
+        });
+        let mut msgbus = MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None);
+        msgbus.subscribe("*", handler, None);
+
+        let count = EventReplayer::new(ReplayConfig::default(), recording()).replay(&mut msgbus);
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                "data.quotes.SIM".to_string(),
+                "events.order.S-001".to_string(),
+                "events.order.S-001".to_string(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_parse_stream_id_ns() {
+        assert_eq!(
+            parse_stream_id_ns("1700000000000-3").unwrap(),
+            1_700_000_000_000_000_000
+        );
+        assert!(parse_stream_id_ns("bad-0").is_err());
+    }
+}
@@ -69,6 +69,11 @@ impl Display for BarSpecification {
 
 /// Represents a bar type including the instrument ID, bar specification and
 /// aggregation source.
+///
+/// A bar type may also be *composite*, meaning its bars are aggregated from
+/// other bars (rather than from ticks), as described by the `composite_*` fields.
+/// A composite bar type has the string form `{standard}@{step}-{aggregation}-{source}`,
+/// e.g. `AUDUSD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL`.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
@@ -82,6 +87,12 @@ pub struct BarType {
     pub spec: BarSpecification,
     /// The bar types aggregation source.
     pub aggregation_source: AggregationSource,
+    /// The step of the bars being aggregated from (zero if not composite).
+    pub composite_step: usize,
+    /// The aggregation of the bars being aggregated from.
+    pub composite_aggregation: BarAggregation,
+    /// The aggregation source of the bars being aggregated from.
+    pub composite_aggregation_source: AggregationSource,
 }
 
 impl BarType {
@@ -95,8 +106,70 @@ impl BarType {
             instrument_id,
             spec,
             aggregation_source,
+            composite_step: 0,
+            composite_aggregation: spec.aggregation,
+            composite_aggregation_source: aggregation_source,
         }
     }
+
+    /// Creates a new composite [`BarType`] whose bars are aggregated from the bars of
+    /// the bar type formed by `composite_step`, `composite_aggregation` and
+    /// `composite_aggregation_source` (sharing the same instrument and price type).
+    #[must_use]
+    pub fn composite(
+        instrument_id: InstrumentId,
+        spec: BarSpecification,
+        aggregation_source: AggregationSource,
+        composite_step: usize,
+        composite_aggregation: BarAggregation,
+        composite_aggregation_source: AggregationSource,
+    ) -> Self {
+        Self {
+            instrument_id,
+            spec,
+            aggregation_source,
+            composite_step,
+            composite_aggregation,
+            composite_aggregation_source,
+        }
+    }
+
+    /// Returns whether the bar type is aggregated from other bars.
+    #[must_use]
+    pub fn is_composite(&self) -> bool {
+        self.composite_step > 0
+    }
+
+    /// Returns whether the bar type is aggregated directly from ticks (or externally).
+    #[must_use]
+    pub fn is_standard(&self) -> bool {
+        !self.is_composite()
+    }
+
+    /// Returns the standard form of this bar type (without any composite source).
+    #[must_use]
+    pub fn standard(&self) -> Self {
+        Self::new(self.instrument_id, self.spec, self.aggregation_source)
+    }
+
+    /// Returns the bar type this composite bar type is aggregated from, or `None`
+    /// if the bar type is standard.
+    #[must_use]
+    pub fn composite_source(&self) -> Option<Self> {
+        if self.is_standard() {
+            return None;
+        }
+
+        Some(Self::new(
+            self.instrument_id,
+            BarSpecification::new(
+                self.composite_step,
+                self.composite_aggregation,
+                self.spec.price_type,
+            ),
+            self.composite_aggregation_source,
+        ))
+    }
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -111,57 +184,67 @@ impl FromStr for BarType {
     type Err = BarTypeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_error = |token: &str, position: usize| BarTypeParseError {
+            input: s.to_string(),
+            token: token.to_string(),
+            position,
+        };
+
+        let (standard, composite) = match s.split_once('@') {
+            Some((standard, composite)) => (standard, Some(composite)),
+            None => (s, None),
+        };
+
         // TODO: Requires handling some trait related thing
         #[allow(clippy::needless_collect)]
-        let pieces: Vec<&str> = s.rsplitn(5, '-').collect();
+        let pieces: Vec<&str> = standard.rsplitn(5, '-').collect();
         let rev_pieces: Vec<&str> = pieces.into_iter().rev().collect();
         if rev_pieces.len() != 5 {
-            return Err(BarTypeParseError {
-                input: s.to_string(),
-                token: "".to_string(),
-                position: 0,
-            });
+            return Err(parse_error("", 0));
         }
 
         let instrument_id =
-            InstrumentId::from_str(rev_pieces[0]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[0].to_string(),
-                position: 0,
-            })?;
-
-        let step = rev_pieces[1].parse().map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[1].to_string(),
-            position: 1,
-        })?;
+            InstrumentId::from_str(rev_pieces[0]).map_err(|_| parse_error(rev_pieces[0], 0))?;
+        let step = rev_pieces[1]
+            .parse()
+            .map_err(|_| parse_error(rev_pieces[1], 1))?;
         let aggregation =
-            BarAggregation::from_str(rev_pieces[2]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[2].to_string(),
-                position: 2,
-            })?;
-        let price_type = PriceType::from_str(rev_pieces[3]).map_err(|_| BarTypeParseError {
-            input: s.to_string(),
-            token: rev_pieces[3].to_string(),
-            position: 3,
-        })?;
-        let aggregation_source =
-            AggregationSource::from_str(rev_pieces[4]).map_err(|_| BarTypeParseError {
-                input: s.to_string(),
-                token: rev_pieces[4].to_string(),
-                position: 4,
-            })?;
-
-        Ok(BarType {
+            BarAggregation::from_str(rev_pieces[2]).map_err(|_| parse_error(rev_pieces[2], 2))?;
+        let price_type =
+            PriceType::from_str(rev_pieces[3]).map_err(|_| parse_error(rev_pieces[3], 3))?;
+        let aggregation_source = AggregationSource::from_str(rev_pieces[4])
+            .map_err(|_| parse_error(rev_pieces[4], 4))?;
+
+        let spec = BarSpecification::new(step, aggregation, price_type);
+
+        let composite = match composite {
+            Some(composite) => composite,
+            None => return Ok(BarType::new(instrument_id, spec, aggregation_source)),
+        };
+
+        let composite_pieces: Vec<&str> = composite.split('-').collect();
+        if composite_pieces.len() != 3 {
+            return Err(parse_error(composite, 5));
+        }
+
+        let composite_step = composite_pieces[0]
+            .parse()
+            .ok()
+            .filter(|step: &usize| *step > 0)
+            .ok_or_else(|| parse_error(composite_pieces[0], 5))?;
+        let composite_aggregation = BarAggregation::from_str(composite_pieces[1])
+            .map_err(|_| parse_error(composite_pieces[1], 6))?;
+        let composite_aggregation_source = AggregationSource::from_str(composite_pieces[2])
+            .map_err(|_| parse_error(composite_pieces[2], 7))?;
+
+        Ok(BarType::composite(
             instrument_id,
-            spec: BarSpecification {
-                step,
-                aggregation,
-                price_type,
-            },
+            spec,
             aggregation_source,
-        })
+            composite_step,
+            composite_aggregation,
+            composite_aggregation_source,
+        ))
     }
 }
 
@@ -177,7 +260,15 @@ impl Display for BarType {
             f,
             "{}-{}-{}",
             self.instrument_id, self.spec, self.aggregation_source
-        )
+        )?;
+        if self.is_composite() {
+            write!(
+                f,
+                "@{}-{}-{}",
+                self.composite_step, self.composite_aggregation, self.composite_aggregation_source
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -359,11 +450,7 @@ pub mod stubs {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type = BarType {
-            instrument_id,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
         Bar {
             bar_type,
             open: Price::from("1.00001"),
@@ -486,6 +573,79 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("AUDUSD.SIM-1-MINUTE-BID-INTERNAL")]
+    #[case("BRK.B.XNYS-100-TICK-LAST-EXTERNAL")]
+    #[case("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL")]
+    #[case("ETHUSDT-PERP.BINANCE-1-HOUR-LAST-INTERNAL@15-MINUTE-INTERNAL")]
+    fn test_bar_type_string_round_trip(#[case] input: &str) {
+        let bar_type = BarType::from_str(input).unwrap();
+        assert_eq!(bar_type.to_string(), input);
+    }
+
+    #[rstest]
+    fn test_bar_type_parse_composite() {
+        let input = "AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL";
+        let bar_type = BarType::from_str(input).unwrap();
+
+        assert!(bar_type.is_composite());
+        assert!(!bar_type.is_standard());
+        assert_eq!(
+            bar_type.standard(),
+            BarType::from("AUDUSD.SIM-5-MINUTE-BID-INTERNAL")
+        );
+        assert_eq!(
+            bar_type.composite_source(),
+            Some(BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL"))
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_composite_constructor() {
+        let bar_type = BarType::composite(
+            InstrumentId::from("AUDUSD.SIM"),
+            BarSpecification::new(5, BarAggregation::Minute, PriceType::Bid),
+            AggregationSource::Internal,
+            1,
+            BarAggregation::Minute,
+            AggregationSource::External,
+        );
+
+        assert_eq!(
+            bar_type,
+            BarType::from("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL")
+        );
+        assert_ne!(bar_type, bar_type.standard());
+    }
+
+    #[rstest]
+    fn test_bar_type_standard_has_no_composite_source() {
+        let bar_type = BarType::from("AUDUSD.SIM-1-MINUTE-BID-EXTERNAL");
+        assert!(bar_type.is_standard());
+        assert_eq!(bar_type.standard(), bar_type);
+        assert_eq!(bar_type.composite_source(), None);
+    }
+
+    #[rstest]
+    #[case("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE", "1-MINUTE", 5)]
+    #[case("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@0-MINUTE-EXTERNAL", "0", 5)]
+    #[case("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-INVALID-EXTERNAL", "INVALID", 6)]
+    #[case("AUDUSD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-INVALID", "INVALID", 7)]
+    fn test_bar_type_parse_invalid_composite(
+        #[case] input: &str,
+        #[case] token: &str,
+        #[case] position: usize,
+    ) {
+        let result = BarType::from_str(input);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Error parsing `BarType` from '{input}', invalid token: '{token}' at position {position}"
+            )
+        );
+    }
+
     #[rstest]
     fn test_bar_type_equality() {
         let instrument_id1 = InstrumentId {
@@ -501,21 +661,9 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type1 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type2 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type3 = BarType {
-            instrument_id: instrument_id2,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type1 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type2 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type3 = BarType::new(instrument_id2, bar_spec, AggregationSource::External);
         assert_eq!(bar_type1, bar_type1);
         assert_eq!(bar_type1, bar_type2);
         assert_ne!(bar_type1, bar_type3);
@@ -537,21 +685,9 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type1 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type2 = BarType {
-            instrument_id: instrument_id1,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
-        let bar_type3 = BarType {
-            instrument_id: instrument_id2,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type1 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type2 = BarType::new(instrument_id1, bar_spec, AggregationSource::External);
+        let bar_type3 = BarType::new(instrument_id2, bar_spec, AggregationSource::External);
 
         assert!(bar_type1 <= bar_type2);
         assert!(bar_type1 < bar_type3);
@@ -570,11 +706,7 @@ mod tests {
             aggregation: BarAggregation::Minute,
             price_type: PriceType::Bid,
         };
-        let bar_type = BarType {
-            instrument_id,
-            spec: bar_spec,
-            aggregation_source: AggregationSource::External,
-        };
+        let bar_type = BarType::new(instrument_id, bar_spec, AggregationSource::External);
         let bar1 = Bar {
             bar_type,
             open: Price::from("1.00001"),
@@ -32,6 +32,12 @@ use crate::{
     types::{price::Price, quantity::Quantity},
 };
 
+/// Flag set on the last delta of a packet (or of an event group) from the venue.
+pub const F_LAST: u8 = 1 << 7;
+
+/// Flag set on deltas sourced from a snapshot (or replay) rather than a live update.
+pub const F_SNAPSHOT: u8 = 1 << 5;
+
 /// Represents a single change/delta in an order book.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -92,7 +98,7 @@ impl OrderBookDelta {
             instrument_id,
             action: BookAction::Clear,
             order: NULL_ORDER,
-            flags: 32, // TODO: Flags constants
+            flags: F_SNAPSHOT,
             sequence,
             ts_event,
             ts_init,
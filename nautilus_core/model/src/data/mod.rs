@@ -19,13 +19,19 @@ pub mod deltas;
 pub mod depth;
 pub mod order;
 pub mod quote;
+pub mod status;
 pub mod trade;
 
 use nautilus_core::time::UnixNanos;
 
 use self::{
-    bar::Bar, delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
-    quote::QuoteTick, trade::TradeTick,
+    bar::Bar,
+    delta::OrderBookDelta,
+    deltas::OrderBookDeltas,
+    depth::OrderBookDepth10,
+    quote::QuoteTick,
+    status::{InstrumentStatus, VenueStatus},
+    trade::TradeTick,
 };
 
 #[repr(C)]
@@ -86,6 +92,18 @@ impl HasTsInit for TradeTick {
     }
 }
 
+impl HasTsInit for InstrumentStatus {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl HasTsInit for VenueStatus {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 impl HasTsInit for Bar {
     fn get_ts_init(&self) -> UnixNanos {
         self.ts_init
@@ -24,7 +24,9 @@ use std::{
 use anyhow::Result;
 use indexmap::IndexMap;
 use nautilus_core::{
-    correctness::check_u8_equal, python::to_pyvalue_err, serialization::Serializable,
+    correctness::{check_u8_equal, check_usize_equal},
+    python::to_pyvalue_err,
+    serialization::Serializable,
     time::UnixNanos,
 };
 use pyo3::prelude::*;
@@ -95,6 +97,99 @@ impl QuoteTick {
         })
     }
 
+    /// Creates a batch of [`QuoteTick`]s from columnar raw fixed-point values.
+    ///
+    /// This is the fast path for bulk loading (e.g. from Arrow or NumPy buffers),
+    /// avoiding the per-element overhead of constructing ticks individually.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_raw_arrays(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        bid_prices_raw: &[i64],
+        ask_prices_raw: &[i64],
+        bid_sizes_raw: &[u64],
+        ask_sizes_raw: &[u64],
+        ts_events: &[UnixNanos],
+        ts_inits: &[UnixNanos],
+    ) -> Result<Vec<Self>> {
+        let len = ts_events.len();
+        check_usize_equal(
+            bid_prices_raw.len(),
+            len,
+            "bid_prices_raw.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(
+            ask_prices_raw.len(),
+            len,
+            "ask_prices_raw.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(
+            bid_sizes_raw.len(),
+            len,
+            "bid_sizes_raw.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(
+            ask_sizes_raw.len(),
+            len,
+            "ask_sizes_raw.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(ts_inits.len(), len, "ts_inits.len()", "ts_events.len()")?;
+
+        (0..len)
+            .map(|i| {
+                Ok(Self {
+                    instrument_id,
+                    bid_price: Price::from_raw(bid_prices_raw[i], price_precision)?,
+                    ask_price: Price::from_raw(ask_prices_raw[i], price_precision)?,
+                    bid_size: Quantity::from_raw(bid_sizes_raw[i], size_precision)?,
+                    ask_size: Quantity::from_raw(ask_sizes_raw[i], size_precision)?,
+                    ts_event: ts_events[i],
+                    ts_init: ts_inits[i],
+                })
+            })
+            .collect()
+    }
+
+    /// Creates a batch of [`QuoteTick`]s from columnar floating point values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_arrays(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        bid_prices: &[f64],
+        ask_prices: &[f64],
+        bid_sizes: &[f64],
+        ask_sizes: &[f64],
+        ts_events: &[UnixNanos],
+        ts_inits: &[UnixNanos],
+    ) -> Result<Vec<Self>> {
+        let len = ts_events.len();
+        check_usize_equal(bid_prices.len(), len, "bid_prices.len()", "ts_events.len()")?;
+        check_usize_equal(ask_prices.len(), len, "ask_prices.len()", "ts_events.len()")?;
+        check_usize_equal(bid_sizes.len(), len, "bid_sizes.len()", "ts_events.len()")?;
+        check_usize_equal(ask_sizes.len(), len, "ask_sizes.len()", "ts_events.len()")?;
+        check_usize_equal(ts_inits.len(), len, "ts_inits.len()", "ts_events.len()")?;
+
+        (0..len)
+            .map(|i| {
+                Ok(Self {
+                    instrument_id,
+                    bid_price: Price::new(bid_prices[i], price_precision)?,
+                    ask_price: Price::new(ask_prices[i], price_precision)?,
+                    bid_size: Quantity::new(bid_sizes[i], size_precision)?,
+                    ask_size: Quantity::new(ask_sizes[i], size_precision)?,
+                    ts_event: ts_events[i],
+                    ts_init: ts_inits[i],
+                })
+            })
+            .collect()
+    }
+
     /// Returns the metadata for the type, for use with serialization formats.
     pub fn get_metadata(
         instrument_id: &InstrumentId,
@@ -244,7 +339,9 @@ mod tests {
     use rstest::rstest;
 
     use super::stubs::*;
-    use crate::{data::quote::QuoteTick, enums::PriceType};
+    use crate::{
+        data::quote::QuoteTick, enums::PriceType, identifiers::instrument_id::InstrumentId,
+    };
 
     #[rstest]
     fn test_to_string(quote_tick_ethusdt_binance: QuoteTick) {
@@ -269,6 +366,66 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    fn test_from_arrays() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let ticks = QuoteTick::from_arrays(
+            instrument_id,
+            4,
+            8,
+            &[10_000.0, 10_000.5],
+            &[10_001.0, 10_001.5],
+            &[1.0, 2.0],
+            &[3.0, 4.0],
+            &[1, 2],
+            &[3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[1].bid_price.to_string(), "10000.5000");
+        assert_eq!(ticks[1].ask_size.to_string(), "4.00000000");
+        assert_eq!(ticks[1].ts_event, 2);
+        assert_eq!(ticks[1].ts_init, 4);
+    }
+
+    #[rstest]
+    fn test_from_raw_arrays_matches_from_arrays(quote_tick_ethusdt_binance: QuoteTick) {
+        let tick = quote_tick_ethusdt_binance;
+        let ticks = QuoteTick::from_raw_arrays(
+            tick.instrument_id,
+            tick.bid_price.precision,
+            tick.bid_size.precision,
+            &[tick.bid_price.raw],
+            &[tick.ask_price.raw],
+            &[tick.bid_size.raw],
+            &[tick.ask_size.raw],
+            &[tick.ts_event],
+            &[tick.ts_init],
+        )
+        .unwrap();
+
+        assert_eq!(ticks, vec![tick]);
+    }
+
+    #[rstest]
+    fn test_from_arrays_with_mismatched_lengths() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let result = QuoteTick::from_arrays(
+            instrument_id,
+            4,
+            8,
+            &[10_000.0],
+            &[10_001.0, 10_001.5],
+            &[1.0],
+            &[1.0],
+            &[1],
+            &[1],
+        );
+
+        assert!(result.is_err());
+    }
+
     #[rstest]
     fn test_from_pyobject(quote_tick_ethusdt_binance: QuoteTick) {
         pyo3::prepare_freethreaded_python();
@@ -0,0 +1,219 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+use nautilus_core::{serialization::Serializable, time::UnixNanos};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::{
+    enums::{HaltReason, MarketStatus},
+    identifiers::{instrument_id::InstrumentId, venue::Venue},
+};
+
+/// The default trading session name for status updates.
+pub const DEFAULT_TRADING_SESSION: &str = "Regular";
+
+/// Represents an update that indicates a change in a venue market status.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct VenueStatus {
+    /// The venue ID.
+    pub venue: Venue,
+    /// The venue market status.
+    pub status: MarketStatus,
+    /// The UNIX timestamp (nanoseconds) when the status update event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl VenueStatus {
+    #[must_use]
+    pub fn new(
+        venue: Venue,
+        status: MarketStatus,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            venue,
+            status,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for VenueStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "VenueStatus(venue={}, status={})",
+            self.venue, self.status
+        )
+    }
+}
+
+impl Serializable for VenueStatus {}
+
+/// Represents an event that indicates a change in an instrument market status.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct InstrumentStatus {
+    /// The instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The name of the trading session.
+    #[serde(default = "default_trading_session")]
+    pub trading_session: Ustr,
+    /// The instrument market session status.
+    pub status: MarketStatus,
+    /// The halt reason (only applicable for a `HALT` status).
+    #[serde(default = "default_halt_reason")]
+    pub halt_reason: HaltReason,
+    /// The UNIX timestamp (nanoseconds) when the status update event occurred.
+    pub ts_event: UnixNanos,
+    /// The UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl InstrumentStatus {
+    /// Initializes a new [`InstrumentStatus`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `status` is not `HALT` and `halt_reason` is
+    /// other than `NOT_HALTED`.
+    pub fn new(
+        instrument_id: InstrumentId,
+        trading_session: Option<Ustr>,
+        status: MarketStatus,
+        halt_reason: Option<HaltReason>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        let halt_reason = halt_reason.unwrap_or(HaltReason::NotHalted);
+        if status != MarketStatus::Halt && halt_reason != HaltReason::NotHalted {
+            bail!("Invalid `halt_reason` {halt_reason} for status {status} (only valid for HALT)");
+        }
+
+        Ok(Self {
+            instrument_id,
+            trading_session: trading_session.unwrap_or_else(default_trading_session),
+            status,
+            halt_reason,
+            ts_event,
+            ts_init,
+        })
+    }
+}
+
+impl Display for InstrumentStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "InstrumentStatus(instrument_id={}, trading_session={}, status={}, halt_reason={}, ts_event={})",
+            self.instrument_id, self.trading_session, self.status, self.halt_reason, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for InstrumentStatus {}
+
+fn default_trading_session() -> Ustr {
+    Ustr::from(DEFAULT_TRADING_SESSION)
+}
+
+fn default_halt_reason() -> HaltReason {
+    HaltReason::NotHalted
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_instrument_status_defaults() {
+        let status = InstrumentStatus::new(
+            InstrumentId::from("ESH4.GLBX"),
+            None,
+            MarketStatus::Auction,
+            None,
+            1,
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(status.trading_session, Ustr::from("Regular"));
+        assert_eq!(status.halt_reason, HaltReason::NotHalted);
+        assert_eq!(
+            status.to_string(),
+            "InstrumentStatus(instrument_id=ESH4.GLBX, trading_session=Regular, status=AUCTION, halt_reason=NOT_HALTED, ts_event=1)"
+        );
+    }
+
+    #[rstest]
+    fn test_instrument_status_halt_reason_requires_halt() {
+        let result = InstrumentStatus::new(
+            InstrumentId::from("ESH4.GLBX"),
+            None,
+            MarketStatus::Open,
+            Some(HaltReason::Volatility),
+            0,
+            0,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_instrument_status_json_round_trip() {
+        let status = InstrumentStatus::new(
+            InstrumentId::from("ESH4.GLBX"),
+            Some(Ustr::from("Pre-Market")),
+            MarketStatus::Halt,
+            Some(HaltReason::General),
+            1,
+            2,
+        )
+        .unwrap();
+
+        let bytes = status.as_json_bytes().unwrap();
+
+        assert_eq!(InstrumentStatus::from_json_bytes(bytes).unwrap(), status);
+    }
+
+    #[rstest]
+    fn test_venue_status_json_round_trip() {
+        let status = VenueStatus::new(Venue::from("XNAS"), MarketStatus::PreOpen, 1, 2);
+
+        let bytes = status.as_json_bytes().unwrap();
+
+        assert_eq!(
+            status.to_string(),
+            "VenueStatus(venue=XNAS, status=PRE_OPEN)"
+        );
+        assert_eq!(VenueStatus::from_json_bytes(bytes).unwrap(), status);
+    }
+}
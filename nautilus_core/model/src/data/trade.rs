@@ -20,13 +20,17 @@ use std::{
     str::FromStr,
 };
 
+use anyhow::Result;
 use indexmap::IndexMap;
-use nautilus_core::{python::to_pyvalue_err, serialization::Serializable, time::UnixNanos};
+use nautilus_core::{
+    correctness::check_usize_equal, python::to_pyvalue_err, serialization::Serializable,
+    time::UnixNanos,
+};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    enums::{AggressorSide, FromU8},
+    enums::{AggressorSide, FromU8, TradeFlag},
     identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
     types::{price::Price, quantity::Quantity},
 };
@@ -49,6 +53,9 @@ pub struct TradeTick {
     pub size: Quantity,
     /// The trade aggressor side.
     pub aggressor_side: AggressorSide,
+    /// The trade condition flags (bit set of [`TradeFlag`] values).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub flags: u8,
     /// The trade match ID (assigned by the venue).
     pub trade_id: TradeId,
     /// The UNIX timestamp (nanoseconds) when the tick event occurred.
@@ -73,12 +80,136 @@ impl TradeTick {
             price,
             size,
             aggressor_side,
+            flags: 0,
             trade_id,
             ts_event,
             ts_init,
         }
     }
 
+    /// Returns the tick with the given trade condition `flags` set.
+    #[must_use]
+    pub fn with_flags(mut self, flags: u8) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Returns whether the given `flag` is set for the trade.
+    #[must_use]
+    pub fn has_flag(&self, flag: TradeFlag) -> bool {
+        flag.is_set(self.flags)
+    }
+
+    /// Returns whether the trade is a venue aggregation of multiple fills.
+    #[must_use]
+    pub fn is_aggregated(&self) -> bool {
+        self.has_flag(TradeFlag::Aggregated)
+    }
+
+    /// Returns whether the trade is a block trade.
+    #[must_use]
+    pub fn is_block(&self) -> bool {
+        self.has_flag(TradeFlag::Block)
+    }
+
+    /// Returns whether the trade was executed off the order book.
+    #[must_use]
+    pub fn is_off_book(&self) -> bool {
+        self.has_flag(TradeFlag::OffBook)
+    }
+
+    /// Returns whether the trade should be included in bar building, given the
+    /// `exclude_flags` configured to filter out (e.g. block and off-book trades).
+    #[must_use]
+    pub fn is_bar_eligible(&self, exclude_flags: u8) -> bool {
+        self.flags & exclude_flags == 0
+    }
+
+    /// Creates a batch of [`TradeTick`]s from columnar raw fixed-point values.
+    ///
+    /// Intended for bulk loading where the values are already held in contiguous
+    /// buffers, so that no per-tick conversion from Python objects is required.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_raw_arrays(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        prices_raw: &[i64],
+        sizes_raw: &[u64],
+        aggressor_sides: &[AggressorSide],
+        trade_ids: &[TradeId],
+        ts_events: &[UnixNanos],
+        ts_inits: &[UnixNanos],
+    ) -> Result<Vec<Self>> {
+        let len = ts_events.len();
+        check_usize_equal(prices_raw.len(), len, "prices_raw.len()", "ts_events.len()")?;
+        check_usize_equal(sizes_raw.len(), len, "sizes_raw.len()", "ts_events.len()")?;
+        check_usize_equal(
+            aggressor_sides.len(),
+            len,
+            "aggressor_sides.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(trade_ids.len(), len, "trade_ids.len()", "ts_events.len()")?;
+        check_usize_equal(ts_inits.len(), len, "ts_inits.len()", "ts_events.len()")?;
+
+        (0..len)
+            .map(|i| {
+                Ok(Self {
+                    instrument_id,
+                    price: Price::from_raw(prices_raw[i], price_precision)?,
+                    size: Quantity::from_raw(sizes_raw[i], size_precision)?,
+                    aggressor_side: aggressor_sides[i],
+                    flags: 0,
+                    trade_id: trade_ids[i],
+                    ts_event: ts_events[i],
+                    ts_init: ts_inits[i],
+                })
+            })
+            .collect()
+    }
+
+    /// Creates a batch of [`TradeTick`]s from columnar floating point values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_arrays(
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        prices: &[f64],
+        sizes: &[f64],
+        aggressor_sides: &[AggressorSide],
+        trade_ids: &[TradeId],
+        ts_events: &[UnixNanos],
+        ts_inits: &[UnixNanos],
+    ) -> Result<Vec<Self>> {
+        let len = ts_events.len();
+        check_usize_equal(prices.len(), len, "prices.len()", "ts_events.len()")?;
+        check_usize_equal(sizes.len(), len, "sizes.len()", "ts_events.len()")?;
+        check_usize_equal(
+            aggressor_sides.len(),
+            len,
+            "aggressor_sides.len()",
+            "ts_events.len()",
+        )?;
+        check_usize_equal(trade_ids.len(), len, "trade_ids.len()", "ts_events.len()")?;
+        check_usize_equal(ts_inits.len(), len, "ts_inits.len()", "ts_events.len()")?;
+
+        (0..len)
+            .map(|i| {
+                Ok(Self {
+                    instrument_id,
+                    price: Price::new(prices[i], price_precision)?,
+                    size: Quantity::new(sizes[i], size_precision)?,
+                    aggressor_side: aggressor_sides[i],
+                    flags: 0,
+                    trade_id: trade_ids[i],
+                    ts_event: ts_events[i],
+                    ts_init: ts_inits[i],
+                })
+            })
+            .collect()
+    }
+
     /// Returns the metadata for the type, for use with serialization formats.
     pub fn get_metadata(
         instrument_id: &InstrumentId,
@@ -101,6 +232,7 @@ impl TradeTick {
         metadata.insert("trade_id".to_string(), "Utf8".to_string());
         metadata.insert("ts_event".to_string(), "UInt64".to_string());
         metadata.insert("ts_init".to_string(), "UInt64".to_string());
+        metadata.insert("flags".to_string(), "UInt8".to_string());
         metadata
     }
 
@@ -128,6 +260,7 @@ impl TradeTick {
         let trade_id_str = trade_id_obj.getattr("value")?.extract()?;
         let trade_id = TradeId::from_str(trade_id_str).map_err(to_pyvalue_err)?;
 
+        let flags: u8 = obj.getattr("flags")?.extract()?;
         let ts_event: UnixNanos = obj.getattr("ts_event")?.extract()?;
         let ts_init: UnixNanos = obj.getattr("ts_init")?.extract()?;
 
@@ -139,7 +272,8 @@ impl TradeTick {
             trade_id,
             ts_event,
             ts_init,
-        ))
+        )
+        .with_flags(flags))
     }
 }
 
@@ -160,6 +294,10 @@ impl Display for TradeTick {
 
 impl Serializable for TradeTick {}
 
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Stubs
 ////////////////////////////////////////////////////////////////////////////////
@@ -181,6 +319,7 @@ pub mod stubs {
             price: Price::from("10000.0000"),
             size: Quantity::from("1.00000000"),
             aggressor_side: AggressorSide::Buyer,
+            flags: 0,
             trade_id: TradeId::new("123456789").unwrap(),
             ts_event: 0,
             ts_init: 1,
@@ -198,7 +337,11 @@ mod tests {
     use rstest::rstest;
 
     use super::stubs::*;
-    use crate::{data::trade::TradeTick, enums::AggressorSide};
+    use crate::{
+        data::trade::TradeTick,
+        enums::{AggressorSide, TradeFlag},
+        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    };
 
     #[rstest]
     fn test_to_string(stub_trade_tick_ethusdt_buyer: TradeTick) {
@@ -225,6 +368,89 @@ mod tests {
         let tick: TradeTick = serde_json::from_str(raw_string).unwrap();
 
         assert_eq!(tick.aggressor_side, AggressorSide::Buyer);
+        assert_eq!(tick.flags, 0);
+    }
+
+    #[rstest]
+    fn test_flags(stub_trade_tick_ethusdt_buyer: TradeTick) {
+        assert!(stub_trade_tick_ethusdt_buyer.is_bar_eligible(u8::MAX));
+
+        let tick = stub_trade_tick_ethusdt_buyer
+            .with_flags(TradeFlag::Aggregated as u8 | TradeFlag::Block as u8);
+
+        assert!(tick.is_aggregated());
+        assert!(tick.is_block());
+        assert!(!tick.is_off_book());
+        assert!(tick.is_bar_eligible(TradeFlag::OffBook as u8));
+        assert!(!tick.is_bar_eligible(TradeFlag::Block as u8 | TradeFlag::OffBook as u8));
+    }
+
+    #[rstest]
+    fn test_json_serialization_with_flags(stub_trade_tick_ethusdt_buyer: TradeTick) {
+        let tick = stub_trade_tick_ethusdt_buyer.with_flags(TradeFlag::OffBook as u8);
+        let serialized = tick.as_json_bytes().unwrap();
+        let deserialized = TradeTick::from_json_bytes(serialized).unwrap();
+        assert_eq!(deserialized.flags, TradeFlag::OffBook as u8);
+    }
+
+    #[rstest]
+    fn test_from_arrays() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let ticks = TradeTick::from_arrays(
+            instrument_id,
+            4,
+            8,
+            &[10_000.0, 10_000.25],
+            &[1.0, 0.5],
+            &[AggressorSide::Buyer, AggressorSide::Seller],
+            &[TradeId::from("1"), TradeId::from("2")],
+            &[1, 2],
+            &[3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[1].price.to_string(), "10000.2500");
+        assert_eq!(ticks[1].size.to_string(), "0.50000000");
+        assert_eq!(ticks[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(ticks[1].trade_id, TradeId::from("2"));
+    }
+
+    #[rstest]
+    fn test_from_raw_arrays(stub_trade_tick_ethusdt_buyer: TradeTick) {
+        let tick = stub_trade_tick_ethusdt_buyer;
+        let ticks = TradeTick::from_raw_arrays(
+            tick.instrument_id,
+            tick.price.precision,
+            tick.size.precision,
+            &[tick.price.raw],
+            &[tick.size.raw],
+            &[tick.aggressor_side],
+            &[tick.trade_id],
+            &[tick.ts_event],
+            &[tick.ts_init],
+        )
+        .unwrap();
+
+        assert_eq!(ticks, vec![tick]);
+    }
+
+    #[rstest]
+    fn test_from_arrays_with_mismatched_lengths() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let result = TradeTick::from_arrays(
+            instrument_id,
+            4,
+            8,
+            &[10_000.0],
+            &[1.0],
+            &[AggressorSide::Buyer],
+            &[],
+            &[1],
+            &[1],
+        );
+
+        assert!(result.is_err());
     }
 
     #[rstest]
@@ -597,6 +597,10 @@ pub enum MarketStatus {
     /// The market session is closed.
     #[pyo3(name = "CLOSED")]
     Closed = 7,
+    /// The market session is in an auction (call) phase, where orders are collected
+    /// but not matched until the uncross.
+    #[pyo3(name = "AUCTION")]
+    Auction = 8,
 }
 
 /// The reason for a venue or market halt.
@@ -965,6 +969,44 @@ pub enum PriceType {
     Last = 4,
 }
 
+/// The rounding mode for converting a floating point value to a fixed precision price or quantity.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with ties rounded away from zero (the default).
+    #[pyo3(name = "HALF_UP")]
+    HalfUp = 1,
+    /// Round to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+    #[pyo3(name = "HALF_EVEN")]
+    HalfEven = 2,
+    /// Round towards negative infinity.
+    #[pyo3(name = "FLOOR")]
+    Floor = 3,
+    /// Round towards positive infinity.
+    #[pyo3(name = "CEILING")]
+    Ceiling = 4,
+}
+
 /// The 'Time in Force' instruction for an order in the financial market.
 #[repr(C)]
 #[derive(
@@ -1012,6 +1054,60 @@ pub enum TimeInForce {
     AtTheClose = 7,
 }
 
+/// A condition flag for a trade, combined as a bit set in `TradeTick.flags`.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum TradeFlag {
+    /// The trade aggregates multiple fills of a single taker order at the same price (e.g. Binance aggTrade).
+    #[pyo3(name = "AGGREGATED")]
+    Aggregated = 1,
+    /// The trade is a privately negotiated block trade reported to the venue.
+    #[pyo3(name = "BLOCK")]
+    Block = 2,
+    /// The trade was executed away from the central limit order book.
+    #[pyo3(name = "OFF_BOOK")]
+    OffBook = 4,
+}
+
+impl TradeFlag {
+    /// Returns whether the flag is set in the given `flags` bit set.
+    #[must_use]
+    pub fn is_set(self, flags: u8) -> bool {
+        flags & self as u8 != 0
+    }
+}
+
+impl FromU8 for TradeFlag {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(TradeFlag::Aggregated),
+            2 => Some(TradeFlag::Block),
+            4 => Some(TradeFlag::OffBook),
+            _ => None,
+        }
+    }
+}
+
 /// The trading state for a node.
 #[repr(C)]
 #[derive(
@@ -1152,6 +1248,7 @@ enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
 enum_strum_serde!(ContingencyType);
 enum_strum_serde!(CurrencyType);
+enum_strum_serde!(HaltReason);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
 enum_strum_serde!(MarketStatus);
@@ -1162,7 +1259,9 @@ enum_strum_serde!(OrderStatus);
 enum_strum_serde!(OrderType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
+enum_strum_serde!(RoundingMode);
 enum_strum_serde!(TimeInForce);
+enum_strum_serde!(TradeFlag);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
 enum_strum_serde!(TriggerType);
@@ -1186,7 +1285,9 @@ enum_for_python!(OrderStatus);
 enum_for_python!(OrderType);
 enum_for_python!(PositionSide);
 enum_for_python!(PriceType);
+enum_for_python!(RoundingMode);
 enum_for_python!(TimeInForce);
+enum_for_python!(TradeFlag);
 enum_for_python!(TradingState);
 enum_for_python!(TrailingOffsetType);
 enum_for_python!(TriggerType);
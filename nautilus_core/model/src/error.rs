@@ -0,0 +1,91 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+/// Represents an error from constructing or validating a model type.
+///
+/// The message of each variant is the full description of the failed condition.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+pub enum ModelError {
+    /// A precision did not match the expected precision of the type or instrument.
+    #[error("{0}")]
+    PrecisionMismatch(String),
+    /// A value (including a precision) was outside of its valid range.
+    #[error("{0}")]
+    OutOfRange(String),
+    /// An identifier (or code) string was invalid.
+    #[error("{0}")]
+    InvalidIdentifier(String),
+    /// Values which should share a currency had different currencies.
+    #[error("{0}")]
+    CurrencyMismatch(String),
+    /// Any other invalid argument.
+    #[error("{0}")]
+    InvalidArgument(String),
+}
+
+impl ModelError {
+    pub fn precision_mismatch(e: impl Display) -> Self {
+        Self::PrecisionMismatch(e.to_string())
+    }
+
+    pub fn out_of_range(e: impl Display) -> Self {
+        Self::OutOfRange(e.to_string())
+    }
+
+    pub fn invalid_identifier(e: impl Display) -> Self {
+        Self::InvalidIdentifier(e.to_string())
+    }
+
+    pub fn currency_mismatch(e: impl Display) -> Self {
+        Self::CurrencyMismatch(e.to_string())
+    }
+
+    pub fn invalid_argument(e: impl Display) -> Self {
+        Self::InvalidArgument(e.to_string())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_display_is_condition_message() {
+        let error = ModelError::out_of_range("Condition failed: value was 10");
+        assert_eq!(error.to_string(), "Condition failed: value was 10");
+        assert_eq!(
+            error,
+            ModelError::OutOfRange("Condition failed: value was 10".to_string())
+        );
+    }
+
+    #[rstest]
+    fn test_converts_into_anyhow_error() {
+        let result: anyhow::Result<()> =
+            Err(ModelError::invalid_identifier("bad")).map_err(Into::into);
+        let error = result.unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ModelError>(),
+            Some(&ModelError::InvalidIdentifier("bad".to_string()))
+        );
+    }
+}
@@ -17,12 +17,15 @@ use nautilus_core::time::{TimedeltaNanos, UnixNanos};
 
 use crate::{
     enums::{OrderSide, PositionSide},
+    events::order::filled::OrderFilled,
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
         position_id::PositionId, strategy_id::StrategyId, trader_id::TraderId,
     },
+    position::Position,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
+
 #[repr(C)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct PositionClosed {
@@ -52,3 +55,41 @@ pub struct PositionClosed {
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
 }
+
+impl PositionClosed {
+    /// Creates a new [`PositionClosed`] event from the given `position` and the `fill`
+    /// which closed it.
+    #[must_use]
+    pub fn create(position: &Position, fill: &OrderFilled, ts_init: UnixNanos) -> Self {
+        let ts_closed = position.ts_closed.unwrap_or(fill.ts_event);
+        Self {
+            trader_id: position.trader_id,
+            strategy_id: position.strategy_id,
+            instrument_id: position.instrument_id,
+            position_id: position.id,
+            account_id: position.account_id,
+            opening_order_id: position.opening_order_id,
+            closing_order_id: position.closing_order_id.unwrap_or(fill.client_order_id),
+            entry: position.entry,
+            side: position.side,
+            signed_qty: position.signed_qty,
+            quantity: position.quantity,
+            peak_quantity: position.peak_qty,
+            last_qty: fill.last_qty,
+            last_px: fill.last_px,
+            currency: position.quote_currency,
+            avg_px_open: position.avg_px_open,
+            avg_px_closed: position.avg_px_close.unwrap_or(0.0),
+            realized_return: position.realized_return,
+            realized_pnl: position
+                .realized_pnl
+                .unwrap_or_else(|| Money::from_raw(0, position.settlement_currency)),
+            unrealized_pnl: Money::from_raw(0, position.quote_currency),
+            duration: position.duration_ns as TimedeltaNanos,
+            ts_opened: position.ts_opened,
+            ts_closed,
+            ts_event: fill.ts_event,
+            ts_init,
+        }
+    }
+}
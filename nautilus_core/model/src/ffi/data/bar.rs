@@ -94,11 +94,43 @@ pub extern "C" fn bar_type_new(
 ) -> BarType {
     let aggregation_source = AggregationSource::from_repr(aggregation_source as usize)
         .expect("Error converting enum from integer");
-    BarType {
+    BarType::new(instrument_id, spec, aggregation_source)
+}
+
+#[no_mangle]
+pub extern "C" fn bar_type_new_composite(
+    instrument_id: InstrumentId,
+    spec: BarSpecification,
+    aggregation_source: u8,
+    composite_step: usize,
+    composite_aggregation: u8,
+    composite_aggregation_source: u8,
+) -> BarType {
+    let aggregation_source = AggregationSource::from_repr(aggregation_source as usize)
+        .expect("Error converting enum from integer");
+    let composite_aggregation = BarAggregation::from_repr(composite_aggregation as usize)
+        .expect("Error converting enum from integer");
+    let composite_aggregation_source =
+        AggregationSource::from_repr(composite_aggregation_source as usize)
+            .expect("Error converting enum from integer");
+    BarType::composite(
         instrument_id,
         spec,
         aggregation_source,
-    }
+        composite_step,
+        composite_aggregation,
+        composite_aggregation_source,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn bar_type_is_composite(bar_type: &BarType) -> u8 {
+    u8::from(bar_type.is_composite())
+}
+
+#[no_mangle]
+pub extern "C" fn bar_type_is_standard(bar_type: &BarType) -> u8 {
+    u8::from(bar_type.is_standard())
 }
 
 /// Returns any [`BarType`] parsing error from the provided C string pointer.
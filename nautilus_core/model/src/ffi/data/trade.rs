@@ -39,6 +39,7 @@ pub extern "C" fn trade_tick_new(
     trade_id: TradeId,
     ts_event: u64,
     ts_init: u64,
+    flags: u8,
 ) -> TradeTick {
     TradeTick::new(
         instrument_id,
@@ -49,6 +50,7 @@ pub extern "C" fn trade_tick_new(
         ts_event,
         ts_init,
     )
+    .with_flags(flags)
 }
 
 #[no_mangle]
@@ -21,7 +21,8 @@ use crate::enums::{
     AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
     BookType, ContingencyType, CurrencyType, HaltReason, InstrumentClass, InstrumentCloseType,
     LiquiditySide, MarketStatus, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-    PositionSide, PriceType, TimeInForce, TradingState, TrailingOffsetType, TriggerType,
+    PositionSide, PriceType, RoundingMode, TimeInForce, TradingState, TrailingOffsetType,
+    TriggerType,
 };
 
 #[no_mangle]
@@ -384,6 +385,23 @@ pub unsafe extern "C" fn price_type_from_cstr(ptr: *const c_char) -> PriceType {
         .unwrap_or_else(|_| panic!("invalid `PriceType` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn rounding_mode_to_cstr(value: RoundingMode) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rounding_mode_from_cstr(ptr: *const c_char) -> RoundingMode {
+    let value = cstr_to_str(ptr);
+    RoundingMode::from_str(value)
+        .unwrap_or_else(|_| panic!("invalid `RoundingMode` enum string value, was '{value}'"))
+}
+
 #[no_mangle]
 pub extern "C" fn time_in_force_to_cstr(value: TimeInForce) -> *const c_char {
     str_to_cstr(value.as_ref())
@@ -15,7 +15,7 @@
 
 use std::ops::{AddAssign, SubAssign};
 
-use crate::types::price::Price;
+use crate::{enums::RoundingMode, types::price::Price};
 
 // TODO: Document panic
 #[no_mangle]
@@ -24,6 +24,17 @@ pub extern "C" fn price_new(value: f64, precision: u8) -> Price {
     Price::new(value, precision).unwrap()
 }
 
+// TODO: Document panic
+#[no_mangle]
+pub extern "C" fn price_new_with_rounding(
+    value: f64,
+    precision: u8,
+    rounding_mode: RoundingMode,
+) -> Price {
+    // SAFETY: Assumes `value` and `precision` are properly validated
+    Price::new_with_rounding(value, precision, rounding_mode).unwrap()
+}
+
 #[no_mangle]
 pub extern "C" fn price_from_raw(raw: i64, precision: u8) -> Price {
     Price::from_raw(raw, precision).unwrap()
@@ -15,7 +15,7 @@
 
 use std::ops::{AddAssign, SubAssign};
 
-use crate::types::quantity::Quantity;
+use crate::{enums::RoundingMode, types::quantity::Quantity};
 
 // TODO: Document panic
 #[no_mangle]
@@ -24,6 +24,17 @@ pub extern "C" fn quantity_new(value: f64, precision: u8) -> Quantity {
     Quantity::new(value, precision).unwrap()
 }
 
+// TODO: Document panic
+#[no_mangle]
+pub extern "C" fn quantity_new_with_rounding(
+    value: f64,
+    precision: u8,
+    rounding_mode: RoundingMode,
+) -> Quantity {
+    // SAFETY: Assumes `value` and `precision` are properly validated
+    Quantity::new_with_rounding(value, precision, rounding_mode).unwrap()
+}
+
 #[no_mangle]
 pub extern "C" fn quantity_from_raw(raw: u64, precision: u8) -> Quantity {
     Quantity::from_raw(raw, precision).unwrap()
@@ -18,10 +18,11 @@ use std::{
     hash::Hash,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::{check_string_contains, check_valid_string};
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid account ID.
 ///
 /// Must be correctly formatted with two valid strings either side of a hyphen '-'.
@@ -41,9 +42,10 @@ pub struct AccountId {
 }
 
 impl AccountId {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`accountid` value")?;
-        check_string_contains(s, "-", "`traderid` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`accountid` value").map_err(ModelError::invalid_identifier)?;
+        check_string_contains(s, "-", "`traderid` value")
+            .map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
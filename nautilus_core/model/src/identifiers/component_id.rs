@@ -18,10 +18,11 @@ use std::{
     hash::Hash,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid component ID.
 #[repr(C)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -35,8 +36,8 @@ pub struct ComponentId {
 }
 
 impl ComponentId {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`ComponentId` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`ComponentId` value").map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
@@ -19,10 +19,12 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{anyhow, bail, Result};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::identifiers::{symbol::Symbol, venue::Venue};
+use crate::{
+    error::ModelError,
+    identifiers::{symbol::Symbol, venue::Venue},
+};
 
 /// Represents a valid instrument ID.
 ///
@@ -45,28 +47,69 @@ impl InstrumentId {
         Self { symbol, venue }
     }
 
+    /// Creates a new [`InstrumentId`] from the given `symbol` and `venue` strings,
+    /// validating each component.
+    ///
+    /// The symbol may itself contain '.' characters (e.g. `BRK.B`), however the venue
+    /// must not contain any '.' or whitespace characters.
+    pub fn from_parts(symbol: &str, venue: &str) -> Result<Self, ModelError> {
+        check_component_trimmed(symbol, "Symbol")?;
+        check_component_trimmed(venue, "Venue")?;
+        if venue.contains('.') {
+            return Err(ModelError::InvalidIdentifier(format!(
+                "Venue component contained a '.' separator, was '{venue}'"
+            )));
+        }
+        if venue.chars().any(char::is_whitespace) {
+            return Err(ModelError::InvalidIdentifier(format!(
+                "Venue component contained whitespace, was '{venue}'"
+            )));
+        }
+
+        Ok(Self {
+            symbol: Symbol::new(symbol)?,
+            venue: Venue::new(venue)?,
+        })
+    }
+
     pub fn is_synthetic(&self) -> bool {
         self.venue.is_synthetic()
     }
+
+    /// Returns whether the instrument is listed on the given `venue`.
+    #[must_use]
+    pub fn is_venue(&self, venue: &Venue) -> bool {
+        self.venue == *venue
+    }
+
+    /// Returns whether the symbol component contains a '.' (e.g. share classes such as `BRK.B`).
+    #[must_use]
+    pub fn has_composite_symbol(&self) -> bool {
+        self.symbol.value.contains('.')
+    }
+
+    /// Returns a copy of this instrument ID with the symbol qualified by the given `venue`.
+    #[must_use]
+    pub fn with_venue(&self, venue: Venue) -> Self {
+        Self {
+            symbol: self.symbol,
+            venue,
+        }
+    }
 }
 
 impl FromStr for InstrumentId {
-    type Err = anyhow::Error;
+    type Err = ModelError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split on the last '.' so symbols containing dots (e.g. `BRK.B.NYSE`) are preserved
         match s.rsplit_once('.') {
-            Some((symbol_part, venue_part)) => Ok(Self {
-                symbol: Symbol::new(symbol_part)
-                    .map_err(|e| anyhow!(err_message(s, e.to_string())))?,
-                venue: Venue::new(venue_part)
-                    .map_err(|e| anyhow!(err_message(s, e.to_string())))?,
-            }),
-            None => {
-                bail!(err_message(
-                    s,
-                    "Missing '.' separator between symbol and venue components".to_string()
-                ))
-            }
+            Some((symbol_part, venue_part)) => Self::from_parts(symbol_part, venue_part)
+                .map_err(|e| ModelError::InvalidIdentifier(err_message(s, e.to_string()))),
+            None => Err(ModelError::InvalidIdentifier(err_message(
+                s,
+                "Missing '.' separator between symbol and venue components".to_string(),
+            ))),
         }
     }
 }
@@ -113,6 +156,15 @@ fn err_message(s: &str, e: String) -> String {
     format!("Error parsing `InstrumentId` from '{s}': {e}")
 }
 
+fn check_component_trimmed(s: &str, desc: &str) -> Result<(), ModelError> {
+    if s.trim() != s {
+        return Err(ModelError::InvalidIdentifier(format!(
+            "{desc} component had leading or trailing whitespace, was '{s}'"
+        )));
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -123,7 +175,10 @@ mod tests {
     use rstest::rstest;
 
     use super::InstrumentId;
-    use crate::identifiers::stubs::*;
+    use crate::{
+        error::ModelError,
+        identifiers::{stubs::*, venue::Venue},
+    };
 
     #[rstest]
     fn test_instrument_id_parse_success(instrument_id_eth_usdt_binance: InstrumentId) {
@@ -143,6 +198,53 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_instrument_id_parse_composite_symbol() {
+        let id = InstrumentId::from_str("BRK.B.NYSE").unwrap();
+        assert_eq!(id.symbol.to_string(), "BRK.B");
+        assert_eq!(id.venue.to_string(), "NYSE");
+        assert!(id.has_composite_symbol());
+        assert_eq!(id.to_string(), "BRK.B.NYSE");
+    }
+
+    #[rstest]
+    #[case(".NYSE")]
+    #[case("BRK.B.")]
+    #[case("AAPL. XNAS")]
+    #[case(" AAPL.XNAS")]
+    #[case("AAPL.XN AS")]
+    fn test_instrument_id_parse_invalid_components(#[case] input: &str) {
+        let result = InstrumentId::from_str(input);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&format!("Error parsing `InstrumentId` from '{input}'")));
+    }
+
+    #[rstest]
+    fn test_from_parts_rejects_dotted_venue() {
+        let result = InstrumentId::from_parts("AAPL", "XNAS.US");
+        assert!(matches!(result, Err(ModelError::InvalidIdentifier(_))));
+    }
+
+    #[rstest]
+    fn test_with_venue(instrument_id_eth_usdt_binance: InstrumentId) {
+        let venue = Venue::from("BYBIT");
+        let id = instrument_id_eth_usdt_binance.with_venue(venue);
+        assert_eq!(id.to_string(), "ETHUSDT.BYBIT");
+        assert!(id.is_venue(&venue));
+        assert!(!instrument_id_eth_usdt_binance.is_venue(&venue));
+        assert!(!id.has_composite_symbol());
+    }
+
+    #[rstest]
+    fn test_is_synthetic() {
+        let id = InstrumentId::from("BTC-ETH.SYNTH");
+        assert!(id.is_synthetic());
+        assert!(!InstrumentId::from("BTCUSDT.BINANCE").is_synthetic());
+    }
+
     #[rstest]
     fn test_string_reprs() {
         let id = InstrumentId::from("ETH/USDT.BINANCE");
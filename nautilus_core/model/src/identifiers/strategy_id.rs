@@ -15,10 +15,11 @@
 
 use std::fmt::{Debug, Display, Formatter};
 
-use anyhow::Result;
 use nautilus_core::correctness::{check_string_contains, check_valid_string};
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid strategy ID.
 ///
 /// Must be correctly formatted with two valid strings either side of a hyphen.
@@ -41,10 +42,11 @@ pub struct StrategyId {
 }
 
 impl StrategyId {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`StrategyId` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`StrategyId` value").map_err(ModelError::invalid_identifier)?;
         if s != "EXTERNAL" {
-            check_string_contains(s, "-", "`StrategyId` value")?;
+            check_string_contains(s, "-", "`StrategyId` value")
+                .map_err(ModelError::invalid_identifier)?;
         }
 
         Ok(Self {
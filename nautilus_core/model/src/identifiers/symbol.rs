@@ -18,10 +18,11 @@ use std::{
     hash::Hash,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid ticker symbol ID for a tradable financial market instrument.
 #[repr(C)]
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -35,8 +36,8 @@ pub struct Symbol {
 }
 
 impl Symbol {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`Symbol` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`Symbol` value").map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
@@ -83,11 +84,22 @@ impl From<&str> for Symbol {
 mod tests {
     use rstest::rstest;
 
-    use crate::identifiers::{stubs::*, symbol::Symbol};
+    use crate::{
+        error::ModelError,
+        identifiers::{stubs::*, symbol::Symbol},
+    };
 
     #[rstest]
     fn test_string_reprs(symbol_eth_perp: Symbol) {
         assert_eq!(symbol_eth_perp.to_string(), "ETH-PERP");
         assert_eq!(format!("{symbol_eth_perp}"), "ETH-PERP");
     }
+
+    #[rstest]
+    #[case("")]
+    #[case(" ")]
+    fn test_new_with_invalid_value(#[case] value: &str) {
+        let result = Symbol::new(value);
+        assert!(matches!(result, Err(ModelError::InvalidIdentifier(_))));
+    }
 }
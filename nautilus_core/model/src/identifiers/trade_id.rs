@@ -18,10 +18,11 @@ use std::{
     hash::Hash,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid trade match ID (assigned by a trading venue).
 ///
 /// Can correspond to the `TradeID <1003> field` of the FIX protocol.
@@ -40,8 +41,8 @@ pub struct TradeId {
 }
 
 impl TradeId {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`TradeId` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`TradeId` value").map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
@@ -15,10 +15,11 @@
 
 use std::fmt::{Debug, Display, Formatter};
 
-use anyhow::Result;
 use nautilus_core::correctness::{check_string_contains, check_valid_string};
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 /// Represents a valid trader ID.
 ///
 /// Must be correctly formatted with two valid strings either side of a hyphen.
@@ -41,9 +42,10 @@ pub struct TraderId {
 }
 
 impl TraderId {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`TraderId` value")?;
-        check_string_contains(s, "-", "`TraderId` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`TraderId` value").map_err(ModelError::invalid_identifier)?;
+        check_string_contains(s, "-", "`TraderId` value")
+            .map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
@@ -18,10 +18,11 @@ use std::{
     hash::Hash,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::check_valid_string;
 use ustr::Ustr;
 
+use crate::error::ModelError;
+
 pub const SYNTHETIC_VENUE: &str = "SYNTH";
 
 /// Represents a valid trading venue ID.
@@ -37,8 +38,8 @@ pub struct Venue {
 }
 
 impl Venue {
-    pub fn new(s: &str) -> Result<Self> {
-        check_valid_string(s, "`Venue` value")?;
+    pub fn new(s: &str) -> Result<Self, ModelError> {
+        check_valid_string(s, "`Venue` value").map_err(ModelError::invalid_identifier)?;
 
         Ok(Self {
             value: Ustr::from(s),
@@ -0,0 +1,394 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::time::UnixNanos;
+use pyo3::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{check_increment_precision, Instrument};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OrderSide},
+    error::ModelError,
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents an instrument (a selection/runner) in a betting market.
+///
+/// Prices are decimal odds and quantities are the backer's stake. A `BUY` order
+/// side is a *back* bet (betting for the selection), and a `SELL` order side is
+/// a *lay* bet (betting against the selection).
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BettingInstrument {
+    #[pyo3(get)]
+    pub id: InstrumentId,
+    #[pyo3(get)]
+    pub raw_symbol: Symbol,
+    #[pyo3(get)]
+    pub event_type_id: u64,
+    pub event_type_name: Ustr,
+    #[pyo3(get)]
+    pub competition_id: u64,
+    pub competition_name: Ustr,
+    #[pyo3(get)]
+    pub event_id: u64,
+    pub event_name: Ustr,
+    pub event_country_code: Ustr,
+    #[pyo3(get)]
+    pub event_open_date: UnixNanos,
+    pub betting_type: Ustr,
+    pub market_id: Ustr,
+    pub market_name: Ustr,
+    pub market_type: Ustr,
+    #[pyo3(get)]
+    pub market_start_time: UnixNanos,
+    #[pyo3(get)]
+    pub selection_id: u64,
+    pub selection_name: Ustr,
+    #[pyo3(get)]
+    pub selection_handicap: f64,
+    #[pyo3(get)]
+    pub currency: Currency,
+    #[pyo3(get)]
+    pub price_precision: u8,
+    #[pyo3(get)]
+    pub size_precision: u8,
+    #[pyo3(get)]
+    pub price_increment: Price,
+    #[pyo3(get)]
+    pub size_increment: Quantity,
+    #[pyo3(get)]
+    pub max_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub min_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub max_notional: Option<Money>,
+    #[pyo3(get)]
+    pub min_notional: Option<Money>,
+    #[pyo3(get)]
+    pub max_price: Option<Price>,
+    #[pyo3(get)]
+    pub min_price: Option<Price>,
+    #[pyo3(get)]
+    pub ts_event: UnixNanos,
+    #[pyo3(get)]
+    pub ts_init: UnixNanos,
+}
+
+impl BettingInstrument {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        event_type_id: u64,
+        event_type_name: Ustr,
+        competition_id: u64,
+        competition_name: Ustr,
+        event_id: u64,
+        event_name: Ustr,
+        event_country_code: Ustr,
+        event_open_date: UnixNanos,
+        betting_type: Ustr,
+        market_id: Ustr,
+        market_name: Ustr,
+        market_type: Ustr,
+        market_start_time: UnixNanos,
+        selection_id: u64,
+        selection_name: Ustr,
+        selection_handicap: f64,
+        currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self, ModelError> {
+        check_increment_precision(price_precision, price_increment.precision, "price")?;
+        check_increment_precision(size_precision, size_increment.precision, "size")?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            event_type_id,
+            event_type_name,
+            competition_id,
+            competition_name,
+            event_id,
+            event_name,
+            event_country_code,
+            event_open_date,
+            betting_type,
+            market_id,
+            market_name,
+            market_type,
+            market_start_time,
+            selection_id,
+            selection_name,
+            selection_handicap,
+            currency,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+}
+
+impl PartialEq<Self> for BettingInstrument {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BettingInstrument {}
+
+impl Hash for BettingInstrument {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BettingInstrument {
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Alternative
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::SportsBetting
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        Some(Quantity::from(1))
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    /// The notional value of a bet is the backer's stake (independent of the odds).
+    fn calculate_notional_value(
+        &self,
+        quantity: Quantity,
+        _price: Price,
+        _use_quote_for_inverse: Option<bool>,
+    ) -> Money {
+        Money::new(quantity.as_f64(), self.currency).unwrap()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Returns the amount the layer stands to lose (and the backer to win) for a bet
+/// of `quantity` at the decimal odds `price`.
+#[must_use]
+pub fn stake(quantity: Quantity, price: Price) -> Decimal {
+    quantity.as_decimal() * (price.as_decimal() - Decimal::ONE)
+}
+
+/// Returns the maximum loss for a bet, which is the stake for a back (`BUY`) bet,
+/// or the layers stake for a lay (`SELL`) bet.
+///
+/// # Panics
+///
+/// If `side` is `NoOrderSide`.
+#[must_use]
+pub fn liability(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Buy => quantity.as_decimal(),
+        OrderSide::Sell => stake(quantity, price),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` for bet liability, was {side}"),
+    }
+}
+
+/// Returns the payoff for a bet if the selection wins.
+///
+/// # Panics
+///
+/// If `side` is `NoOrderSide`.
+#[must_use]
+pub fn win_payoff(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Buy => stake(quantity, price),
+        OrderSide::Sell => -stake(quantity, price),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` for bet payoff, was {side}"),
+    }
+}
+
+/// Returns the payoff for a bet if the selection loses.
+///
+/// # Panics
+///
+/// If `side` is `NoOrderSide`.
+#[must_use]
+pub fn lose_payoff(quantity: Quantity, side: OrderSide) -> Decimal {
+    match side {
+        OrderSide::Buy => -quantity.as_decimal(),
+        OrderSide::Sell => quantity.as_decimal(),
+        OrderSide::NoOrderSide => panic!("Invalid `OrderSide` for bet payoff, was {side}"),
+    }
+}
+
+/// Returns the exposure of a bet, being the difference between the win and lose payoffs.
+///
+/// # Panics
+///
+/// If `side` is `NoOrderSide`.
+#[must_use]
+pub fn exposure(quantity: Quantity, price: Price, side: OrderSide) -> Decimal {
+    win_payoff(quantity, price, side) - lose_payoff(quantity, side)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    #[rstest]
+    fn test_equality(betting: BettingInstrument) {
+        let cloned = betting.clone();
+        assert_eq!(betting, cloned);
+    }
+
+    #[rstest]
+    fn test_notional_value_is_stake(betting: BettingInstrument) {
+        let notional =
+            betting.calculate_notional_value(Quantity::from("10.0"), Price::from("3.50"), None);
+        assert_eq!(notional, Money::from("10.0 GBP"));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, dec!(10.0))]
+    #[case(OrderSide::Sell, dec!(25.000))]
+    fn test_liability(#[case] side: OrderSide, #[case] expected: Decimal) {
+        let result = liability(Quantity::from("10.0"), Price::from("3.50"), side);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, dec!(25.0), dec!(-10.0), dec!(35.0))]
+    #[case(OrderSide::Sell, dec!(-25.0), dec!(10.0), dec!(-35.0))]
+    fn test_payoffs(
+        #[case] side: OrderSide,
+        #[case] expected_win: Decimal,
+        #[case] expected_lose: Decimal,
+        #[case] expected_exposure: Decimal,
+    ) {
+        let quantity = Quantity::from("10.0");
+        let price = Price::from("3.50");
+        assert_eq!(win_payoff(quantity, price, side), expected_win);
+        assert_eq!(lose_payoff(quantity, side), expected_lose);
+        assert_eq!(exposure(quantity, price, side), expected_exposure);
+    }
+}
@@ -18,14 +18,14 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::Instrument;
+use super::{check_increment_precision, Instrument};
 use crate::{
     enums::{AssetClass, InstrumentClass},
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
@@ -103,7 +103,10 @@ impl CryptoFuture {
         min_price: Option<Price>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Result<Self> {
+    ) -> Result<Self, ModelError> {
+        check_increment_precision(price_precision, price_increment.precision, "price")?;
+        check_increment_precision(size_precision, size_increment.precision, "size")?;
+
         Ok(Self {
             id,
             raw_symbol,
@@ -225,6 +228,14 @@ impl Instrument for CryptoFuture {
         self.ts_init
     }
 
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -18,7 +18,6 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -26,8 +25,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{AssetClass, InstrumentClass},
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
-    instruments::Instrument,
+    instruments::{check_increment_precision, Instrument},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -51,6 +51,9 @@ pub struct CryptoPerpetual {
     pub settlement_currency: Currency,
     #[pyo3(get)]
     pub is_inverse: bool,
+    /// The fixed settlement currency amount per unit of quote currency, for quanto contracts.
+    #[pyo3(get)]
+    pub quanto_multiplier: Option<Decimal>,
     #[pyo3(get)]
     pub price_precision: u8,
     #[pyo3(get)]
@@ -96,6 +99,7 @@ impl CryptoPerpetual {
         quote_currency: Currency,
         settlement_currency: Currency,
         is_inverse: bool,
+        quanto_multiplier: Option<Decimal>,
         price_precision: u8,
         size_precision: u8,
         price_increment: Price,
@@ -113,7 +117,32 @@ impl CryptoPerpetual {
         min_price: Option<Price>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Result<Self> {
+    ) -> Result<Self, ModelError> {
+        let is_quanto = !is_inverse
+            && settlement_currency != base_currency
+            && settlement_currency != quote_currency;
+        match quanto_multiplier {
+            Some(multiplier) if !is_quanto => {
+                return Err(ModelError::InvalidArgument(format!(
+                    "Condition failed: `quanto_multiplier` {multiplier} given for a non-quanto instrument {id}"
+                )))
+            }
+            Some(multiplier) if multiplier <= Decimal::ZERO => {
+                return Err(ModelError::OutOfRange(format!(
+                    "Condition failed: `quanto_multiplier` should be positive, was {multiplier}"
+                )))
+            }
+            None if is_quanto => {
+                return Err(ModelError::InvalidArgument(format!(
+                    "Condition failed: quanto instrument {id} settled in {settlement_currency} requires a `quanto_multiplier`"
+                )))
+            }
+            _ => {}
+        }
+
+        check_increment_precision(price_precision, price_increment.precision, "price")?;
+        check_increment_precision(size_precision, size_increment.precision, "size")?;
+
         Ok(Self {
             id,
             raw_symbol,
@@ -121,6 +150,7 @@ impl CryptoPerpetual {
             quote_currency,
             settlement_currency,
             is_inverse,
+            quanto_multiplier,
             price_precision,
             size_precision,
             price_increment,
@@ -189,6 +219,10 @@ impl Instrument for CryptoPerpetual {
         self.is_inverse
     }
 
+    fn quanto_multiplier(&self) -> Option<Decimal> {
+        self.quanto_multiplier
+    }
+
     fn price_precision(&self) -> u8 {
         self.price_precision
     }
@@ -260,12 +294,122 @@ impl Instrument for CryptoPerpetual {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
-    use crate::instruments::{crypto_perpetual::CryptoPerpetual, stubs::*};
+    use crate::{
+        error::ModelError,
+        identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+        instruments::{crypto_perpetual::CryptoPerpetual, stubs::*, Instrument},
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+
+    fn ethusd_perpetual(
+        settlement_currency: Currency,
+        quanto_multiplier: Option<rust_decimal::Decimal>,
+    ) -> Result<CryptoPerpetual, ModelError> {
+        CryptoPerpetual::new(
+            InstrumentId::from("ETHUSD.BITMEX"),
+            Symbol::from("ETHUSD"),
+            Currency::ETH(),
+            Currency::USD(),
+            settlement_currency,
+            false,
+            quanto_multiplier,
+            2,
+            0,
+            Price::from("0.05"),
+            Quantity::from("1"),
+            dec!(-0.00025),
+            dec!(0.00075),
+            dec!(0.01),
+            dec!(0.0035),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        )
+    }
 
     #[rstest]
     fn test_equality(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let cloned = crypto_perpetual_ethusdt.clone();
         assert_eq!(crypto_perpetual_ethusdt, cloned)
     }
+
+    #[rstest]
+    fn test_quanto_notional_value_in_settlement_currency(ethusd_quanto_bitmex: CryptoPerpetual) {
+        let notional = ethusd_quanto_bitmex.calculate_notional_value(
+            Quantity::from(100),
+            Price::from("2000.00"),
+            None,
+        );
+        assert!(ethusd_quanto_bitmex.is_quanto());
+        assert_eq!(notional, Money::from("0.20000000 BTC"));
+    }
+
+    #[rstest]
+    fn test_quanto_without_multiplier_is_invalid() {
+        assert!(matches!(
+            ethusd_perpetual(Currency::BTC(), None),
+            Err(ModelError::InvalidArgument(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_multiplier_for_non_quanto_is_invalid() {
+        assert!(matches!(
+            ethusd_perpetual(Currency::USD(), Some(dec!(0.000001))),
+            Err(ModelError::InvalidArgument(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_non_positive_quanto_multiplier_is_out_of_range() {
+        assert!(matches!(
+            ethusd_perpetual(Currency::BTC(), Some(dec!(0))),
+            Err(ModelError::OutOfRange(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_non_quanto_is_not_quanto() {
+        let instrument = ethusd_perpetual(Currency::USD(), None).unwrap();
+        assert!(!instrument.is_quanto());
+    }
+
+    #[rstest]
+    fn test_size_increment_precision_mismatch() {
+        let result = CryptoPerpetual::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Symbol::from("ETHUSDT"),
+            Currency::ETH(),
+            Currency::USDT(),
+            Currency::USDT(),
+            false,
+            None,
+            2,
+            0,
+            Price::from("0.01"),
+            Quantity::from("0.001"),
+            dec!(0.0002),
+            dec!(0.0004),
+            dec!(1.0),
+            dec!(0.35),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(ModelError::PrecisionMismatch(_))));
+    }
 }
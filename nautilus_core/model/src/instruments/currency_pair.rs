@@ -18,15 +18,15 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use super::Instrument;
+use super::{check_increment_precision, Instrument};
 use crate::{
     enums::{AssetClass, InstrumentClass},
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -101,7 +101,10 @@ impl CurrencyPair {
         min_price: Option<Price>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Result<Self> {
+    ) -> Result<Self, ModelError> {
+        check_increment_precision(price_precision, price_increment.precision, "price")?;
+        check_increment_precision(size_precision, size_increment.precision, "size")?;
+
         Ok(Self {
             id,
             raw_symbol,
@@ -246,11 +249,38 @@ impl Instrument for CurrencyPair {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{currency_pair::CurrencyPair, stubs::*};
+    use crate::{
+        enums::RoundingMode,
+        instruments::{currency_pair::CurrencyPair, stubs::*, Instrument},
+        types::{price::Price, quantity::Quantity},
+    };
 
     #[rstest]
     fn test_equality(currency_pair_btcusdt: CurrencyPair) {
         let cloned = currency_pair_btcusdt.clone();
         assert_eq!(currency_pair_btcusdt, cloned)
     }
+
+    #[rstest]
+    #[case(RoundingMode::HalfEven, "0.80002")]
+    #[case(RoundingMode::Floor, "0.80001")]
+    #[case(RoundingMode::Ceiling, "0.80002")]
+    fn test_make_price_with_rounding(
+        audusd_sim: CurrencyPair,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let price = audusd_sim
+            .make_price_with_rounding(0.800_015, mode)
+            .unwrap();
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_make_qty_with_rounding(audusd_sim: CurrencyPair) {
+        let qty = audusd_sim
+            .make_qty_with_rounding(100_000.9, RoundingMode::Floor)
+            .unwrap();
+        assert_eq!(qty, Quantity::from(100_000));
+    }
 }
@@ -18,15 +18,15 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::Instrument;
+use super::{check_increment_precision, Instrument};
 use crate::{
     enums::{AssetClass, InstrumentClass},
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
@@ -94,7 +94,9 @@ impl FuturesContract {
         min_price: Option<Price>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Result<Self> {
+    ) -> Result<Self, ModelError> {
+        check_increment_precision(price_precision, price_increment.precision, "price")?;
+
         Ok(Self {
             id,
             raw_symbol,
@@ -212,6 +214,14 @@ impl Instrument for FuturesContract {
         self.ts_init
     }
 
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::any::Any;
+pub mod betting;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
@@ -25,13 +26,13 @@ pub mod synthetic;
 #[cfg(feature = "stubs")]
 pub mod stubs;
 
-use anyhow::Result;
 use nautilus_core::time::UnixNanos;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rust_decimal_macros::dec;
 
 use crate::{
-    enums::{AssetClass, InstrumentClass},
+    enums::{AssetClass, InstrumentClass, RoundingMode},
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
@@ -51,6 +52,17 @@ pub trait Instrument: Any + 'static + Send {
     fn quote_currency(&self) -> Currency;
     fn settlement_currency(&self) -> Currency;
     fn is_inverse(&self) -> bool;
+
+    /// Returns the fixed settlement currency amount per unit of quote currency, if the
+    /// instrument is a quanto (settled in a currency other than its base and quote).
+    fn quanto_multiplier(&self) -> Option<Decimal> {
+        None
+    }
+
+    /// Returns whether the instrument is a quanto contract.
+    fn is_quanto(&self) -> bool {
+        self.quanto_multiplier().is_some()
+    }
     fn price_precision(&self) -> u8;
     fn size_precision(&self) -> u8;
     fn price_increment(&self) -> Price;
@@ -79,18 +91,49 @@ pub trait Instrument: Any + 'static + Send {
     fn ts_event(&self) -> UnixNanos;
     fn ts_init(&self) -> UnixNanos;
 
+    /// Returns the UNIX timestamp (nanoseconds) the instrument becomes tradable, if dated.
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    /// Returns the UNIX timestamp (nanoseconds) the instrument expires, if dated.
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
     /// Creates a new price from the given `value` with the correct price precision for the instrument.
-    fn make_price(&self, value: f64) -> Result<Price> {
+    fn make_price(&self, value: f64) -> Result<Price, ModelError> {
         Price::new(value, self.price_precision())
     }
 
     /// Creates a new quantity from the given `value` with the correct size precision for the instrument.
-    fn make_qty(&self, value: f64) -> Result<Quantity> {
+    fn make_qty(&self, value: f64) -> Result<Quantity, ModelError> {
         Quantity::new(value, self.size_precision())
     }
 
+    /// Creates a new price from the given `value` with the correct price precision for the
+    /// instrument, rounded with the given `mode` (such as to match the venue rounding).
+    fn make_price_with_rounding(
+        &self,
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Price, ModelError> {
+        Price::new_with_rounding(value, self.price_precision(), mode)
+    }
+
+    /// Creates a new quantity from the given `value` with the correct size precision for the
+    /// instrument, rounded with the given `mode` (such as to match the venue rounding).
+    fn make_qty_with_rounding(
+        &self,
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Quantity, ModelError> {
+        Quantity::new_with_rounding(value, self.size_precision(), mode)
+    }
+
     /// Calculates the notional value from the given parameters.
-    /// The `use_quote_for_inverse` flag is only applicable for inverse instruments.
+    /// The `use_quote_for_inverse` flag is only applicable for inverse instruments, and quanto
+    /// instruments are valued in their settlement currency.
     ///
     /// # Panics
     ///
@@ -114,6 +157,13 @@ pub trait Instrument: Any + 'static + Send {
                     .to_owned();
                 (amount, currency)
             }
+        } else if let Some(quanto_multiplier) = self.quanto_multiplier() {
+            // Convert the quote notional into the settlement currency at the fixed quanto rate
+            let amount = quantity.as_f64()
+                * self.multiplier().as_f64()
+                * price.as_f64()
+                * quanto_multiplier.to_f64().unwrap();
+            (amount, self.settlement_currency())
         } else {
             let amount = quantity.as_f64() * self.multiplier().as_f64() * price.as_f64();
             let currency = self.quote_currency().to_owned();
@@ -131,3 +181,17 @@ pub trait Instrument: Any + 'static + Send {
 
     fn as_any(&self) -> &dyn Any;
 }
+
+/// Checks the precision of an instruments price or size `increment` matches the given `precision`.
+pub fn check_increment_precision(
+    precision: u8,
+    increment_precision: u8,
+    param: &str,
+) -> Result<(), ModelError> {
+    if precision != increment_precision {
+        return Err(ModelError::PrecisionMismatch(format!(
+            "Condition failed: `{param}_precision` {precision} was not equal to `{param}_increment` precision {increment_precision}"
+        )));
+    }
+    Ok(())
+}
@@ -23,13 +23,56 @@ use crate::{
     enums::{AssetClass, OptionKind},
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
     instruments::{
-        crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
+        betting::BettingInstrument, crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual,
         currency_pair::CurrencyPair, equity::Equity, futures_contract::FuturesContract,
         options_contract::OptionsContract,
     },
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+////////////////////////////////////////////////////////////////////////////////
+// BettingInstrument
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn betting() -> BettingInstrument {
+    let event_open = Utc.with_ymd_and_hms(2022, 2, 7, 23, 30, 0).unwrap();
+    BettingInstrument::new(
+        InstrumentId::from("1.123456789-50214-None.BETFAIR"),
+        Symbol::from("1.123456789-50214-None"),
+        6423,
+        Ustr::from("American Football"),
+        12282733,
+        Ustr::from("NFL"),
+        29678534,
+        Ustr::from("NFL"),
+        Ustr::from("GB"),
+        event_open.timestamp_nanos_opt().unwrap() as UnixNanos,
+        Ustr::from("ODDS"),
+        Ustr::from("1.123456789"),
+        Ustr::from("AFC Conference Winner"),
+        Ustr::from("SPECIAL"),
+        event_open.timestamp_nanos_opt().unwrap() as UnixNanos,
+        50214,
+        Ustr::from("Kansas City Chiefs"),
+        -9999999.0,
+        Currency::GBP(),
+        2,
+        2,
+        Price::from("0.01"),
+        Quantity::from("0.01"),
+        None,
+        None,
+        None,
+        Some(Money::new(1.0, Currency::GBP()).unwrap()),
+        Some(Price::from("1000.00")),
+        Some(Price::from("1.01")),
+        0,
+        0,
+    )
+    .unwrap()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // CryptoFuture
 ////////////////////////////////////////////////////////////////////////////////
@@ -76,8 +119,9 @@ pub fn crypto_perpetual_ethusdt() -> CryptoPerpetual {
         Currency::from("USDT"),
         Currency::from("USDT"),
         false,
+        None,
         2,
-        0,
+        3,
         Price::from("0.01"),
         Quantity::from("0.001"),
         dec!(0.0002),
@@ -106,6 +150,7 @@ pub fn xbtusd_bitmex() -> CryptoPerpetual {
         Currency::USD(),
         Currency::BTC(),
         true,
+        None,
         1,
         0,
         Price::from("0.5"),
@@ -136,6 +181,38 @@ pub fn ethusdt_bitmex() -> CryptoPerpetual {
         Currency::USD(),
         Currency::ETH(),
         true,
+        None,
+        2,
+        0,
+        Price::from("0.05"),
+        Quantity::from("1"),
+        dec!(-0.00025),
+        dec!(0.00075),
+        dec!(0.01),
+        dec!(0.0035),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Price::from("10000000")),
+        Some(Price::from("0.01")),
+        0,
+        0,
+    )
+    .unwrap()
+}
+
+#[fixture]
+pub fn ethusd_quanto_bitmex() -> CryptoPerpetual {
+    CryptoPerpetual::new(
+        InstrumentId::from("ETHUSD-QUANTO.BITMEX"),
+        Symbol::from("ETHUSD"),
+        Currency::ETH(),
+        Currency::USD(),
+        Currency::BTC(),
+        false,
+        Some(dec!(0.000001)),
         2,
         0,
         Price::from("0.05"),
@@ -24,6 +24,7 @@ use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 
 use crate::{
+    error::ModelError,
     identifiers::{instrument_id::InstrumentId, symbol::Symbol, venue::Venue},
     types::price::Price,
 };
@@ -63,7 +64,7 @@ impl SyntheticInstrument {
         formula: String,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Result<Self, anyhow::Error> {
+    ) -> Result<Self, ModelError> {
         let price_increment = Price::new(10f64.powi(-i32::from(price_precision)), price_precision)?;
 
         // Extract variables from the component instruments
@@ -72,7 +73,8 @@ impl SyntheticInstrument {
             .map(|component| component.to_string())
             .collect();
 
-        let operator_tree = evalexpr::build_operator_tree(&formula)?;
+        let operator_tree =
+            evalexpr::build_operator_tree(&formula).map_err(ModelError::invalid_argument)?;
 
         Ok(SyntheticInstrument {
             id: InstrumentId::new(symbol, Venue::synthetic()),
@@ -133,7 +135,7 @@ impl SyntheticInstrument {
         let result: Value = self.operator_tree.eval_with_context(&self.context)?;
 
         match result {
-            Value::Float(price) => Price::new(price, self.price_precision),
+            Value::Float(price) => Ok(Price::new(price, self.price_precision)?),
             _ => Err(anyhow!(
                 "Failed to evaluate formula to a floating point number"
             )),
@@ -236,4 +238,17 @@ mod tests {
         assert_eq!(price.as_f64(), 75.0);
         assert_eq!(synth.formula, new_formula);
     }
+
+    #[rstest]
+    fn test_new_with_invalid_formula() {
+        let result = SyntheticInstrument::new(
+            Symbol::new("BTC-LTC").unwrap(),
+            2,
+            vec![InstrumentId::from("BTC.BINANCE")],
+            "(BTC.BINANCE +".to_string(),
+            0,
+            0,
+        );
+        assert!(matches!(result, Err(ModelError::InvalidArgument(_))));
+    }
 }
@@ -16,6 +16,7 @@
 pub mod currencies;
 pub mod data;
 pub mod enums;
+pub mod error;
 pub mod events;
 pub mod identifiers;
 pub mod instruments;
@@ -23,6 +24,8 @@ pub mod macros;
 pub mod orderbook;
 pub mod orders;
 pub mod position;
+pub mod reports;
+pub mod trading_hours;
 pub mod types;
 
 #[cfg(feature = "ffi")]
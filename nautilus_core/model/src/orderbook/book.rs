@@ -20,7 +20,10 @@ use thiserror::Error;
 use super::{ladder::BookPrice, level::Level};
 use crate::{
     data::{
-        delta::OrderBookDelta, depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick,
+        delta::{OrderBookDelta, F_LAST, F_SNAPSHOT},
+        depth::OrderBookDepth10,
+        order::{BookOrder, OrderId},
+        quote::QuoteTick,
         trade::TradeTick,
     },
     enums::{BookAction, BookType, OrderSide},
@@ -180,6 +183,46 @@ impl OrderBook {
         }
     }
 
+    /// Returns the current state of the book as a sequence of deltas: a `CLEAR`
+    /// followed by an `ADD` for every resting order (in queue priority within each
+    /// level), all flagged `F_SNAPSHOT`.
+    ///
+    /// Applying the returned deltas to an empty book reproduces this book.
+    #[must_use]
+    pub fn snapshot_deltas(&self, ts_init: UnixNanos) -> Vec<OrderBookDelta> {
+        let mut deltas = vec![OrderBookDelta::clear(
+            self.instrument_id,
+            self.sequence,
+            self.ts_last,
+            ts_init,
+        )];
+
+        let orders = self
+            .bids
+            .levels
+            .values()
+            .chain(self.asks.levels.values())
+            .flat_map(Level::queue);
+
+        for order in orders {
+            deltas.push(OrderBookDelta::new(
+                self.instrument_id,
+                BookAction::Add,
+                *order,
+                F_SNAPSHOT,
+                self.sequence,
+                self.ts_last,
+                ts_init,
+            ));
+        }
+
+        if let Some(last) = deltas.last_mut() {
+            last.flags |= F_LAST;
+        }
+
+        deltas
+    }
+
     pub fn bids(&self) -> Vec<&Level> {
         self.bids.levels.values().collect()
     }
@@ -211,19 +254,61 @@ impl OrderBook {
     }
 
     pub fn best_bid_size(&self) -> Option<Quantity> {
-        match self.bids.top() {
-            Some(top) => top.first().map(|order| order.size),
-            None => None,
-        }
+        self.bids.top().and_then(Self::level_size)
     }
 
     pub fn best_ask_size(&self) -> Option<Quantity> {
-        match self.asks.top() {
-            Some(top) => top.first().map(|order| order.size),
-            None => None,
+        self.asks.top().and_then(Self::level_size)
+    }
+
+    /// Returns the resting order with the given ID on the given side of the book.
+    #[must_use]
+    pub fn get_order(&self, side: OrderSide, order_id: OrderId) -> Option<&BookOrder> {
+        self.ladder(side).get_order(order_id)
+    }
+
+    /// Returns the zero-based queue position of the given order at its price level.
+    ///
+    /// Queue positions are only meaningful for `L3_MBO` books, where each order is
+    /// tracked individually.
+    #[must_use]
+    pub fn queue_position(&self, side: OrderSide, order_id: OrderId) -> Option<usize> {
+        self.ladder(side)
+            .get_level(order_id)?
+            .queue_position(order_id)
+    }
+
+    /// Returns the total size queued ahead of the given order at its price level.
+    #[must_use]
+    pub fn size_ahead(&self, side: OrderSide, order_id: OrderId) -> Option<Quantity> {
+        let level = self.ladder(side).get_level(order_id)?;
+        let precision = level.orders[&order_id].size.precision;
+        Quantity::from_raw(level.size_ahead_raw(order_id)?, precision).ok()
+    }
+
+    /// Returns the orders at the given price on the given side in queue priority.
+    #[must_use]
+    pub fn orders_at(&self, side: OrderSide, price: Price) -> Vec<&BookOrder> {
+        self.ladder(side)
+            .levels
+            .get(&BookPrice::new(price, side))
+            .map(Level::queue)
+            .unwrap_or_default()
+    }
+
+    fn ladder(&self, side: OrderSide) -> &Ladder {
+        match side {
+            OrderSide::Buy => &self.bids,
+            OrderSide::Sell => &self.asks,
+            _ => panic!("{}", BookIntegrityError::NoOrderSide),
         }
     }
 
+    fn level_size(level: &Level) -> Option<Quantity> {
+        let precision = level.first()?.size.precision;
+        Quantity::from_raw(level.size_raw(), precision).ok()
+    }
+
     pub fn spread(&self) -> Option<f64> {
         match (self.best_ask_price(), self.best_bid_price()) {
             (Some(ask), Some(bid)) => Some(ask.as_f64() - bid.as_f64()),
@@ -614,6 +699,57 @@ mod tests {
         assert_eq!(book.best_ask_size(), Some(Quantity::from("2.0")));
         assert!(book.has_ask());
     }
+    #[rstest]
+    fn test_l3_queue_positions() {
+        let mut book = create_stub_book(BookType::L3_MBO);
+        let price = Price::from("1.000");
+        book.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from("1.0"), 7),
+            100,
+            1,
+        );
+        book.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from("2.0"), 3),
+            100,
+            2,
+        );
+        book.add(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from("4.0"), 5),
+            100,
+            3,
+        );
+
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("7.0")));
+        assert_eq!(book.queue_position(OrderSide::Buy, 3), Some(1));
+        assert_eq!(
+            book.size_ahead(OrderSide::Buy, 5),
+            Some(Quantity::from("3.0"))
+        );
+        assert_eq!(book.queue_position(OrderSide::Sell, 3), None);
+
+        // Increasing the size of the first order sends it to the back of the queue
+        book.update(
+            BookOrder::new(OrderSide::Buy, price, Quantity::from("1.5"), 7),
+            200,
+            4,
+        );
+
+        let queue: Vec<u64> = book
+            .orders_at(OrderSide::Buy, price)
+            .iter()
+            .map(|o| o.order_id)
+            .collect();
+        assert_eq!(queue, vec![3, 5, 7]);
+        assert_eq!(
+            book.size_ahead(OrderSide::Buy, 7),
+            Some(Quantity::from("6.0"))
+        );
+        assert_eq!(
+            book.get_order(OrderSide::Buy, 7).unwrap().size,
+            Quantity::from("1.5")
+        );
+    }
+
     #[rstest]
     fn test_spread_with_no_bids_or_asks() {
         let book = create_stub_book(BookType::L3_MBO);
@@ -806,6 +942,39 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap().as_f64(), 100.0);
     }
 
+    #[rstest]
+    fn test_snapshot_deltas_rebuild_book(stub_depth10: OrderBookDepth10) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.apply_depth(stub_depth10);
+
+        let deltas = book.snapshot_deltas(1_000);
+        let mut rebuilt = OrderBook::new(instrument_id, BookType::L2_MBP);
+        for delta in &deltas {
+            rebuilt.apply_delta(*delta);
+        }
+
+        assert_eq!(deltas.len(), 21);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert!(deltas.iter().all(|d| d.flags & F_SNAPSHOT != 0));
+        assert!(deltas.iter().all(|d| d.ts_init == 1_000));
+        assert_eq!(deltas.last().unwrap().flags & F_LAST, F_LAST);
+        assert_eq!(rebuilt.best_bid_price(), book.best_bid_price());
+        assert_eq!(rebuilt.best_ask_price(), book.best_ask_price());
+        assert_eq!(rebuilt.bids().len(), book.bids().len());
+        assert_eq!(rebuilt.asks().len(), book.asks().len());
+    }
+
+    #[rstest]
+    fn test_snapshot_deltas_when_empty() {
+        let book = create_stub_book(BookType::L2_MBP);
+        let deltas = book.snapshot_deltas(0);
+
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas[0].flags, F_SNAPSHOT | F_LAST);
+    }
+
     #[rstest]
     fn test_update_quote_tick_l1() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -162,6 +162,20 @@ impl Ladder {
         }
     }
 
+    /// Returns the order with the given ID, if it is in the ladder.
+    #[must_use]
+    pub fn get_order(&self, order_id: OrderId) -> Option<&BookOrder> {
+        let price = self.cache.get(&order_id)?;
+        self.levels.get(price)?.orders.get(&order_id)
+    }
+
+    /// Returns the level the order with the given ID is queued at, if it is in the ladder.
+    #[must_use]
+    pub fn get_level(&self, order_id: OrderId) -> Option<&Level> {
+        let price = self.cache.get(&order_id)?;
+        self.levels.get(price)
+    }
+
     #[must_use]
     pub fn sizes(&self) -> f64 {
         return self.levels.values().map(|l| l.size()).sum();
@@ -194,7 +208,7 @@ impl Ladder {
                 break;
             }
 
-            for book_order in level.orders.values() {
+            for book_order in level.queue() {
                 let current = book_order.size;
                 if cumulative_denominator + current >= target {
                     // This order has filled us, add fill and return
@@ -625,6 +639,42 @@ mod tests {
         assert_eq!(size3, Quantity::from(200));
     }
 
+    #[rstest]
+    fn test_simulate_order_fills_in_queue_priority() {
+        let mut ladder = Ladder::new(OrderSide::Sell);
+        ladder.add(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(30),
+            9,
+        ));
+        ladder.add(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from(50),
+            2,
+        ));
+
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from(40),
+            10,
+        );
+        let fills = ladder.simulate_fills(&order);
+
+        assert_eq!(
+            fills,
+            vec![
+                (Price::from("100.00"), Quantity::from(30)),
+                (Price::from("100.00"), Quantity::from(10)),
+            ]
+        );
+        assert_eq!(ladder.get_order(2).unwrap().size, Quantity::from(50));
+        assert_eq!(ladder.get_level(9).unwrap().queue_position(2), Some(1));
+        assert!(ladder.get_order(10).is_none());
+    }
+
     #[rstest]
     fn test_simulate_order_fills_sell_with_size_at_limit_of_precision() {
         let mut ladder = Ladder::new(OrderSide::Buy);
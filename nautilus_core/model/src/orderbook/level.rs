@@ -66,6 +66,34 @@ impl Level {
             .and_then(|&id| self.orders.get(&id))
     }
 
+    /// Returns the orders at this level in queue (time) priority order.
+    #[must_use]
+    pub fn queue(&self) -> Vec<&BookOrder> {
+        self.insertion_order
+            .iter()
+            .filter_map(|id| self.orders.get(id))
+            .collect()
+    }
+
+    /// Returns the zero-based queue position of the given order at this level.
+    #[must_use]
+    pub fn queue_position(&self, order_id: OrderId) -> Option<usize> {
+        self.insertion_order.iter().position(|&id| id == order_id)
+    }
+
+    /// Returns the total raw size of the orders queued ahead of the given order.
+    #[must_use]
+    pub fn size_ahead_raw(&self, order_id: OrderId) -> Option<u64> {
+        let position = self.queue_position(order_id)?;
+        Some(
+            self.insertion_order[..position]
+                .iter()
+                .filter_map(|id| self.orders.get(id))
+                .map(|o| o.size.raw)
+                .sum(),
+        )
+    }
+
     pub fn add_bulk(&mut self, orders: Vec<BookOrder>) {
         self.insertion_order
             .extend(orders.iter().map(|o| o.order_id));
@@ -89,8 +117,17 @@ impl Level {
         if order.size.raw == 0 {
             self.orders.remove(&order.order_id);
             self.update_insertion_order();
-        } else {
-            self.orders.insert(order.order_id, order);
+            return;
+        }
+
+        match self.orders.insert(order.order_id, order) {
+            // An increase in size loses queue priority (venue MBO semantics)
+            Some(existing) if order.size > existing.size => {
+                self.insertion_order.retain(|&id| id != order.order_id);
+                self.insertion_order.push(order.order_id);
+            }
+            Some(_) => {}
+            None => self.insertion_order.push(order.order_id),
         }
     }
 
@@ -254,6 +291,60 @@ mod tests {
         assert_eq!(level.exposure(), 20.0);
     }
 
+    #[rstest]
+    fn test_update_order_size_decrease_keeps_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 0);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 1);
+        level.add(order1);
+        level.add(order2);
+
+        level.update(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(5),
+            0,
+        ));
+
+        assert_eq!(level.queue_position(0), Some(0));
+        assert_eq!(level.first().unwrap().size, Quantity::from(5));
+    }
+
+    #[rstest]
+    fn test_update_order_size_increase_loses_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order1 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(10), 0);
+        let order2 = BookOrder::new(OrderSide::Buy, Price::from("1.00"), Quantity::from(20), 1);
+        level.add(order1);
+        level.add(order2);
+
+        level.update(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(15),
+            0,
+        ));
+
+        assert_eq!(level.queue_position(0), Some(1));
+        assert_eq!(level.queue_position(1), Some(0));
+        assert_eq!(level.size_ahead_raw(0), Some(Quantity::from(20).raw));
+        assert_eq!(level.size_ahead_raw(1), Some(0));
+        assert_eq!(level.size_ahead_raw(2), None);
+    }
+
+    #[rstest]
+    fn test_queue_in_time_priority() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Sell));
+        let order1 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(10), 9);
+        let order2 = BookOrder::new(OrderSide::Sell, Price::from("1.00"), Quantity::from(20), 3);
+        level.add(order1);
+        level.add(order2);
+
+        let queue: Vec<u64> = level.queue().iter().map(|o| o.order_id).collect();
+
+        assert_eq!(queue, vec![9, 3]);
+    }
+
     #[rstest]
     fn test_update_order_with_zero_size() {
         let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
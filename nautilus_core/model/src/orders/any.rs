@@ -0,0 +1,489 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use ustr::Ustr;
+
+use super::{
+    base::{Order, OrderCore, OrderError},
+    limit::LimitOrder,
+    limit_if_touched::LimitIfTouchedOrder,
+    market::MarketOrder,
+    market_if_touched::MarketIfTouchedOrder,
+    market_to_limit::MarketToLimitOrder,
+    stop_limit::StopLimitOrder,
+    stop_market::StopMarketOrder,
+    trailing_stop_limit::TrailingStopLimitOrder,
+    trailing_stop_market::TrailingStopMarketOrder,
+};
+use crate::{
+    enums::{
+        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
+        TrailingOffsetType, TriggerType,
+    },
+    events::order::{event::OrderEvent, initialized::OrderInitialized, updated::OrderUpdated},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, order_list_id::OrderListId, position_id::PositionId,
+        strategy_id::StrategyId, symbol::Symbol, trade_id::TradeId, trader_id::TraderId,
+        venue::Venue, venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Wraps any concrete order type, for components which need to own orders of
+/// heterogeneous types (such as matching engines and caches).
+#[derive(Clone, Debug)]
+pub enum OrderAny {
+    Limit(LimitOrder),
+    LimitIfTouched(LimitIfTouchedOrder),
+    Market(MarketOrder),
+    MarketIfTouched(MarketIfTouchedOrder),
+    MarketToLimit(MarketToLimitOrder),
+    StopLimit(StopLimitOrder),
+    StopMarket(StopMarketOrder),
+    TrailingStopLimit(TrailingStopLimitOrder),
+    TrailingStopMarket(TrailingStopMarketOrder),
+}
+
+impl OrderAny {
+    /// Returns a reference to the wrapped order as an [`Order`] trait object.
+    #[must_use]
+    pub fn as_order(&self) -> &dyn Order {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
+    /// Returns a mutable reference to the wrapped order as an [`Order`] trait object.
+    pub fn as_order_mut(&mut self) -> &mut dyn Order {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
+    /// Returns a reference to the [`OrderCore`] shared by all order types.
+    #[must_use]
+    pub fn core(&self) -> &OrderCore {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
+    /// Returns whether the order has been triggered (always `false` for non-trigger orders).
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        match self {
+            Self::LimitIfTouched(order) => order.is_triggered,
+            Self::MarketIfTouched(order) => order.is_triggered,
+            Self::StopLimit(order) => order.is_triggered,
+            Self::StopMarket(order) => order.is_triggered,
+            Self::TrailingStopLimit(order) => order.is_triggered,
+            Self::TrailingStopMarket(order) => order.is_triggered,
+            _ => false,
+        }
+    }
+
+    /// Marks the order as triggered at the given `ts_triggered` (no-op for non-trigger orders).
+    pub fn set_triggered(&mut self, ts_triggered: UnixNanos) {
+        match self {
+            Self::LimitIfTouched(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            Self::MarketIfTouched(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            Self::StopLimit(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            Self::StopMarket(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            Self::TrailingStopLimit(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            Self::TrailingStopMarket(order) => {
+                order.is_triggered = true;
+                order.ts_triggered = Some(ts_triggered);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl From<OrderInitialized> for OrderAny {
+    fn from(event: OrderInitialized) -> Self {
+        match event.order_type {
+            OrderType::Limit => Self::Limit(event.into()),
+            OrderType::LimitIfTouched => Self::LimitIfTouched(event.into()),
+            OrderType::Market => Self::Market(event.into()),
+            OrderType::MarketIfTouched => Self::MarketIfTouched(event.into()),
+            OrderType::MarketToLimit => Self::MarketToLimit(event.into()),
+            OrderType::StopLimit => Self::StopLimit(event.into()),
+            OrderType::StopMarket => Self::StopMarket(event.into()),
+            OrderType::TrailingStopLimit => Self::TrailingStopLimit(event.into()),
+            OrderType::TrailingStopMarket => Self::TrailingStopMarket(event.into()),
+        }
+    }
+}
+
+impl From<LimitOrder> for OrderAny {
+    fn from(order: LimitOrder) -> Self {
+        Self::Limit(order)
+    }
+}
+
+impl From<LimitIfTouchedOrder> for OrderAny {
+    fn from(order: LimitIfTouchedOrder) -> Self {
+        Self::LimitIfTouched(order)
+    }
+}
+
+impl From<MarketOrder> for OrderAny {
+    fn from(order: MarketOrder) -> Self {
+        Self::Market(order)
+    }
+}
+
+impl From<MarketIfTouchedOrder> for OrderAny {
+    fn from(order: MarketIfTouchedOrder) -> Self {
+        Self::MarketIfTouched(order)
+    }
+}
+
+impl From<MarketToLimitOrder> for OrderAny {
+    fn from(order: MarketToLimitOrder) -> Self {
+        Self::MarketToLimit(order)
+    }
+}
+
+impl From<StopLimitOrder> for OrderAny {
+    fn from(order: StopLimitOrder) -> Self {
+        Self::StopLimit(order)
+    }
+}
+
+impl From<StopMarketOrder> for OrderAny {
+    fn from(order: StopMarketOrder) -> Self {
+        Self::StopMarket(order)
+    }
+}
+
+impl From<TrailingStopLimitOrder> for OrderAny {
+    fn from(order: TrailingStopLimitOrder) -> Self {
+        Self::TrailingStopLimit(order)
+    }
+}
+
+impl From<TrailingStopMarketOrder> for OrderAny {
+    fn from(order: TrailingStopMarketOrder) -> Self {
+        Self::TrailingStopMarket(order)
+    }
+}
+
+impl Order for OrderAny {
+    fn status(&self) -> OrderStatus {
+        self.as_order().status()
+    }
+
+    fn trader_id(&self) -> TraderId {
+        self.as_order().trader_id()
+    }
+
+    fn strategy_id(&self) -> StrategyId {
+        self.as_order().strategy_id()
+    }
+
+    fn instrument_id(&self) -> InstrumentId {
+        self.as_order().instrument_id()
+    }
+
+    fn symbol(&self) -> Symbol {
+        self.as_order().symbol()
+    }
+
+    fn venue(&self) -> Venue {
+        self.as_order().venue()
+    }
+
+    fn client_order_id(&self) -> ClientOrderId {
+        self.as_order().client_order_id()
+    }
+
+    fn venue_order_id(&self) -> Option<VenueOrderId> {
+        self.as_order().venue_order_id()
+    }
+
+    fn position_id(&self) -> Option<PositionId> {
+        self.as_order().position_id()
+    }
+
+    fn account_id(&self) -> Option<AccountId> {
+        self.as_order().account_id()
+    }
+
+    fn last_trade_id(&self) -> Option<TradeId> {
+        self.as_order().last_trade_id()
+    }
+
+    fn side(&self) -> OrderSide {
+        self.as_order().side()
+    }
+
+    fn order_type(&self) -> OrderType {
+        self.as_order().order_type()
+    }
+
+    fn quantity(&self) -> Quantity {
+        self.as_order().quantity()
+    }
+
+    fn time_in_force(&self) -> TimeInForce {
+        self.as_order().time_in_force()
+    }
+
+    fn expire_time(&self) -> Option<UnixNanos> {
+        self.as_order().expire_time()
+    }
+
+    fn price(&self) -> Option<Price> {
+        self.as_order().price()
+    }
+
+    fn trigger_price(&self) -> Option<Price> {
+        self.as_order().trigger_price()
+    }
+
+    fn trigger_type(&self) -> Option<TriggerType> {
+        self.as_order().trigger_type()
+    }
+
+    fn liquidity_side(&self) -> Option<LiquiditySide> {
+        self.as_order().liquidity_side()
+    }
+
+    fn is_post_only(&self) -> bool {
+        self.as_order().is_post_only()
+    }
+
+    fn is_reduce_only(&self) -> bool {
+        self.as_order().is_reduce_only()
+    }
+
+    fn is_quote_quantity(&self) -> bool {
+        self.as_order().is_quote_quantity()
+    }
+
+    fn display_qty(&self) -> Option<Quantity> {
+        self.as_order().display_qty()
+    }
+
+    fn limit_offset(&self) -> Option<Price> {
+        self.as_order().limit_offset()
+    }
+
+    fn trailing_offset(&self) -> Option<Price> {
+        self.as_order().trailing_offset()
+    }
+
+    fn trailing_offset_type(&self) -> Option<TrailingOffsetType> {
+        self.as_order().trailing_offset_type()
+    }
+
+    fn emulation_trigger(&self) -> Option<TriggerType> {
+        self.as_order().emulation_trigger()
+    }
+
+    fn trigger_instrument_id(&self) -> Option<InstrumentId> {
+        self.as_order().trigger_instrument_id()
+    }
+
+    fn contingency_type(&self) -> Option<ContingencyType> {
+        self.as_order().contingency_type()
+    }
+
+    fn order_list_id(&self) -> Option<OrderListId> {
+        self.as_order().order_list_id()
+    }
+
+    fn linked_order_ids(&self) -> Option<Vec<ClientOrderId>> {
+        self.as_order().linked_order_ids()
+    }
+
+    fn parent_order_id(&self) -> Option<ClientOrderId> {
+        self.as_order().parent_order_id()
+    }
+
+    fn exec_algorithm_id(&self) -> Option<ExecAlgorithmId> {
+        self.as_order().exec_algorithm_id()
+    }
+
+    fn exec_algorithm_params(&self) -> Option<HashMap<Ustr, Ustr>> {
+        self.as_order().exec_algorithm_params()
+    }
+
+    fn exec_spawn_id(&self) -> Option<ClientOrderId> {
+        self.as_order().exec_spawn_id()
+    }
+
+    fn tags(&self) -> Option<Ustr> {
+        self.as_order().tags()
+    }
+
+    fn filled_qty(&self) -> Quantity {
+        self.as_order().filled_qty()
+    }
+
+    fn leaves_qty(&self) -> Quantity {
+        self.as_order().leaves_qty()
+    }
+
+    fn avg_px(&self) -> Option<f64> {
+        self.as_order().avg_px()
+    }
+
+    fn slippage(&self) -> Option<f64> {
+        self.as_order().slippage()
+    }
+
+    fn init_id(&self) -> UUID4 {
+        self.as_order().init_id()
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.as_order().ts_init()
+    }
+
+    fn ts_last(&self) -> UnixNanos {
+        self.as_order().ts_last()
+    }
+
+    fn apply(&mut self, event: OrderEvent) -> Result<(), OrderError> {
+        self.as_order_mut().apply(event)
+    }
+
+    fn update(&mut self, event: &OrderUpdated) {
+        self.as_order_mut().update(event);
+    }
+
+    fn events(&self) -> Vec<&OrderEvent> {
+        self.as_order().events()
+    }
+
+    fn venue_order_ids(&self) -> Vec<&VenueOrderId> {
+        self.as_order().venue_order_ids()
+    }
+
+    fn trade_ids(&self) -> Vec<&TradeId> {
+        self.as_order().trade_ids()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::events::order::{
+        accepted::OrderAcceptedBuilder, initialized::OrderInitializedBuilder,
+        submitted::OrderSubmittedBuilder,
+    };
+
+    #[rstest]
+    fn test_order_any_from_initialized_dispatches_on_order_type() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap();
+
+        let order = OrderAny::from(init);
+
+        assert!(matches!(order, OrderAny::Limit(_)));
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        assert!(!order.is_triggered());
+    }
+
+    #[rstest]
+    fn test_order_any_apply_delegates_to_order() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let mut order = OrderAny::from(init);
+
+        order
+            .apply(OrderEvent::OrderSubmitted(
+                OrderSubmittedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderAccepted(
+                OrderAcceptedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert!(order.is_open());
+        assert_eq!(order.core().status, OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_order_any_set_triggered() {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::StopMarket)
+            .trigger_price(Some(Price::from("1.00000")))
+            .trigger_type(Some(TriggerType::Default))
+            .build()
+            .unwrap();
+        let mut order = OrderAny::from(init);
+
+        order.set_triggered(1);
+
+        assert!(order.is_triggered());
+    }
+}
@@ -115,6 +115,7 @@ impl OrderStatus {
             (OrderStatus::Accepted, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::Accepted, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Accepted, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Accepted, OrderEvent::OrderUpdated(_)) => OrderStatus::Accepted,
             (OrderStatus::Canceled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,  // Real world possibility
             (OrderStatus::Canceled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,  // Real world possibility
             (OrderStatus::PendingUpdate, OrderEvent::OrderRejected(_)) => OrderStatus::Rejected,
@@ -140,12 +141,14 @@ impl OrderStatus {
             (OrderStatus::Triggered, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::Triggered, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::Triggered, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::Triggered, OrderEvent::OrderUpdated(_)) => OrderStatus::Triggered,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPendingUpdate(_)) => OrderStatus::PendingUpdate,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPendingCancel(_)) => OrderStatus::PendingCancel,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderCanceled(_)) => OrderStatus::Canceled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderExpired(_)) => OrderStatus::Expired,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderPartiallyFilled(_)) => OrderStatus::PartiallyFilled,
             (OrderStatus::PartiallyFilled, OrderEvent::OrderFilled(_)) => OrderStatus::Filled,
+            (OrderStatus::PartiallyFilled, OrderEvent::OrderUpdated(_)) => OrderStatus::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
@@ -468,8 +471,28 @@ impl OrderCore {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
+        let status = self.status;
+        let new_status = match &event {
+            // A rejected (or completed) request returns the order to its status before the request
+            OrderEvent::OrderModifyRejected(_) | OrderEvent::OrderUpdated(_)
+                if status == OrderStatus::PendingUpdate =>
+            {
+                self.previous_status.ok_or(OrderError::NoPreviousState)?
+            }
+            OrderEvent::OrderCancelRejected(_) if status == OrderStatus::PendingCancel => {
+                self.previous_status.ok_or(OrderError::NoPreviousState)?
+            }
+            OrderEvent::OrderModifyRejected(_) | OrderEvent::OrderCancelRejected(_) => status,
+            _ => self.status.transition(&event)?,
+        };
+
+        // Retain the status to return to while a request is pending
+        if !matches!(
+            status,
+            OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+        ) {
+            self.previous_status = Some(status);
+        }
         self.status = new_status;
 
         match &event {
@@ -528,16 +551,12 @@ impl OrderCore {
         // Do nothing else
     }
 
-    fn modify_rejected(&mut self, _event: &OrderModifyRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn modify_rejected(&self, _event: &OrderModifyRejected) {
+        // Do nothing else
     }
 
-    fn cancel_rejected(&mut self, _event: &OrderCancelRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn cancel_rejected(&self, _event: &OrderCancelRejected) {
+        // Do nothing else
     }
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
@@ -567,6 +586,11 @@ impl OrderCore {
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
         self.set_avg_px(event.last_qty, event.last_px);
+
+        // A fill event only transitions to `FILLED` once no quantity remains
+        if self.leaves_qty.is_positive() {
+            self.status = OrderStatus::PartiallyFilled;
+        }
     }
 
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
@@ -656,8 +680,11 @@ mod tests {
     use crate::{
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
-            accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            accepted::OrderAcceptedBuilder, cancel_rejected::OrderCancelRejectedBuilder,
+            denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, modify_rejected::OrderModifyRejectedBuilder,
+            pending_cancel::OrderPendingCancelBuilder, pending_update::OrderPendingUpdateBuilder,
+            submitted::OrderSubmittedBuilder, updated::OrderUpdatedBuilder,
         },
         orders::market::MarketOrder,
     };
@@ -771,4 +798,97 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_partial_fill_then_fill() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let partial = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(40000))
+            .trade_id(TradeId::from("1"))
+            .build()
+            .unwrap();
+        let fill = OrderFilledBuilder::default()
+            .last_qty(Quantity::from(60000))
+            .trade_id(TradeId::from("2"))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order.apply(OrderEvent::OrderFilled(partial)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.leaves_qty(), Quantity::from(60000));
+        assert!(order.is_open());
+
+        order.apply(OrderEvent::OrderFilled(fill)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100000));
+        assert!(order.is_closed());
+    }
+
+    fn accepted_market_order() -> MarketOrder {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        order.apply(OrderEvent::OrderSubmitted(submitted)).unwrap();
+        order.apply(OrderEvent::OrderAccepted(accepted)).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_modify_rejected_returns_to_status_before_request() {
+        let mut order = accepted_market_order();
+        let pending = OrderPendingUpdateBuilder::default().build().unwrap();
+        let rejected = OrderModifyRejectedBuilder::default().build().unwrap();
+
+        order.apply(OrderEvent::OrderPendingUpdate(pending)).unwrap();
+        order.apply(OrderEvent::OrderPendingUpdate(pending)).unwrap();
+        assert!(order.is_pending_update());
+        assert!(order.is_inflight());
+
+        order.apply(OrderEvent::OrderModifyRejected(rejected)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.event_count(), 6);
+    }
+
+    #[rstest]
+    fn test_updated_completes_pending_update() {
+        let mut order = accepted_market_order();
+        let pending = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(50000))
+            .build()
+            .unwrap();
+
+        order.apply(OrderEvent::OrderPendingUpdate(pending)).unwrap();
+        order.apply(OrderEvent::OrderUpdated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.quantity(), Quantity::from(50000));
+        assert_eq!(order.leaves_qty(), Quantity::from(50000));
+    }
+
+    #[rstest]
+    fn test_cancel_rejected_after_pending_update_returns_to_accepted() {
+        let mut order = accepted_market_order();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let pending_cancel = OrderPendingCancelBuilder::default().build().unwrap();
+        let rejected = OrderCancelRejectedBuilder::default().build().unwrap();
+
+        order
+            .apply(OrderEvent::OrderPendingUpdate(pending_update))
+            .unwrap();
+        order
+            .apply(OrderEvent::OrderPendingCancel(pending_cancel))
+            .unwrap();
+        order.apply(OrderEvent::OrderCancelRejected(rejected)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+    }
 }
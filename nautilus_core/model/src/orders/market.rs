@@ -18,7 +18,6 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use anyhow::{bail, Result};
 use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use pyo3::prelude::*;
 use ustr::Ustr;
@@ -29,6 +28,7 @@ use crate::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
         TrailingOffsetType, TriggerType,
     },
+    error::ModelError,
     events::order::{event::OrderEvent, initialized::OrderInitialized, updated::OrderUpdated},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
@@ -43,6 +43,7 @@ use crate::{
     },
 };
 
+#[derive(Clone, Debug)]
 #[cfg_attr(
     feature = "python",
     pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -73,10 +74,12 @@ impl MarketOrder {
         exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
         exec_spawn_id: Option<ClientOrderId>,
         tags: Option<Ustr>,
-    ) -> Result<Self> {
+    ) -> Result<Self, ModelError> {
         check_quantity_positive(quantity)?;
         if time_in_force == TimeInForce::Gtd {
-            bail!("{}", "GTD not supported for Market orders");
+            return Err(ModelError::InvalidArgument(
+                "GTD not supported for Market orders".to_string(),
+            ));
         }
 
         Ok(Self {
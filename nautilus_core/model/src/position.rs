@@ -20,6 +20,7 @@ use std::hash::{Hash, Hasher};
 use anyhow::Result;
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{OrderSide, PositionSide};
@@ -68,6 +69,7 @@ pub struct Position {
     pub size_precision: u8,
     pub multiplier: Quantity,
     pub is_inverse: bool,
+    pub quanto_multiplier: Option<f64>,
     pub base_currency: Option<Currency>,
     pub quote_currency: Currency,
     pub settlement_currency: Currency,
@@ -87,7 +89,7 @@ pub struct Position {
 }
 
 impl Position {
-    pub fn new<T: Instrument>(instrument: T, fill: OrderFilled) -> Result<Self> {
+    pub fn new<T: Instrument + ?Sized>(instrument: &T, fill: OrderFilled) -> Result<Self> {
         assert_eq!(instrument.id(), fill.instrument_id);
         assert!(fill.position_id.is_some());
         assert_ne!(fill.order_side, OrderSide::NoOrderSide);
@@ -114,6 +116,9 @@ impl Position {
             size_precision: instrument.size_precision(),
             multiplier: instrument.multiplier(),
             is_inverse: instrument.is_inverse(),
+            quanto_multiplier: instrument
+                .quanto_multiplier()
+                .and_then(|multiplier| multiplier.to_f64()),
             base_currency: instrument.base_currency(),
             quote_currency: instrument.quote_currency(),
             settlement_currency: instrument.settlement_currency(),
@@ -277,8 +282,17 @@ impl Position {
         self.sell_qty += last_qty_object;
     }
 
+    /// Calculates the quantity weighted average price.
+    ///
+    /// For inverse instruments each contract is worth a fixed amount of quote currency, so the
+    /// average is weighted by base currency value (the harmonic mean of the prices).
     #[must_use]
     pub fn calculate_avg_px(&self, qty: f64, avg_pg: f64, last_px: f64, last_qty: f64) -> f64 {
+        if self.is_inverse {
+            let start_value = qty / avg_pg;
+            let event_value = last_qty / last_px;
+            return (qty + last_qty) / (start_value + event_value);
+        }
         let start_cost = avg_pg * qty;
         let event_cost = last_px * last_qty;
         (start_cost + event_cost) / (qty + last_qty)
@@ -366,7 +380,11 @@ impl Position {
                 * self.multiplier.as_f64()
                 * self.calculate_points_inverse(avg_px_open, avg_px_close)
         } else {
-            quantity * self.multiplier.as_f64() * self.calculate_points(avg_px_open, avg_px_close)
+            let pnl = quantity
+                * self.multiplier.as_f64()
+                * self.calculate_points(avg_px_open, avg_px_close);
+            // Quanto PnL accrues in quote points, paid out in settlement currency at a fixed rate
+            pnl * self.quanto_multiplier.unwrap_or(1.0)
         }
     }
 
@@ -441,6 +459,15 @@ impl Position {
                 self.base_currency.unwrap(),
             )
             .unwrap()
+        } else if let Some(quanto_multiplier) = self.quanto_multiplier {
+            Money::new(
+                self.quantity.as_f64()
+                    * last.as_f64()
+                    * self.multiplier.as_f64()
+                    * quanto_multiplier,
+                self.settlement_currency,
+            )
+            .unwrap()
         } else {
             Money::new(
                 self.quantity.as_f64() * last.as_f64() * self.multiplier.as_f64(),
@@ -597,7 +624,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         position.apply(&fill2);
     }
 
@@ -622,7 +649,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.symbol(), audusd_sim.id.symbol);
         assert_eq!(position.venue(), audusd_sim.id.venue);
         assert!(!position.is_opposite_side(fill.order_side));
@@ -688,7 +715,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.00050").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.symbol(), audusd_sim.id.symbol);
         assert_eq!(position.venue(), audusd_sim.id.venue);
         assert!(!position.is_opposite_side(fill.order_side));
@@ -752,7 +779,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.00048").unwrap();
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.quantity, Quantity::from(50_000));
         assert_eq!(position.peak_qty, Quantity::from(50_000));
         assert_eq!(position.side, PositionSide::Long);
@@ -819,7 +846,7 @@ mod tests {
             None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         position.apply(&fill2);
 
         assert_eq!(position.quantity, Quantity::from(100_000));
@@ -872,7 +899,7 @@ mod tests {
             None,
             Some(1_000_000_000),
         );
-        let mut position = Position::new(audusd_sim, fill).unwrap();
+        let mut position = Position::new(&audusd_sim, fill).unwrap();
 
         let fill2 = OrderFilled::new(
             order.trader_id,
@@ -962,7 +989,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         // create closing from order from different venue but same strategy
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
@@ -1053,7 +1080,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
             &audusd_sim,
@@ -1161,7 +1188,7 @@ mod tests {
             None,
             None,
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
         let last = Price::from("1.0005");
         position.apply(&fill2);
         position.apply(&fill3);
@@ -1227,7 +1254,7 @@ mod tests {
             Some(commission1),
             None,
         );
-        let mut position = Position::new(currency_pair_ethusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_ethusdt, fill1).unwrap();
         let quantity2 = Quantity::from(17);
         let order2 = TestOrderStubs::market_order(
             currency_pair_ethusdt.id,
@@ -1359,7 +1386,7 @@ mod tests {
             Some(commission1),
             Some(1_000_000_000),
         );
-        let mut position = Position::new(audusd_sim, fill1).unwrap();
+        let mut position = Position::new(&audusd_sim, fill1).unwrap();
 
         let fill2 = OrderFilled::new(
             order.trader_id,
@@ -1478,7 +1505,7 @@ mod tests {
             Some(commission1),
             None,
         );
-        let mut position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         let order2 = TestOrderStubs::market_order(
             currency_pair_btcusdt.id,
             OrderSide::Buy,
@@ -1635,7 +1662,7 @@ mod tests {
             None,
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let result = position.calculate_pnl(10500.0, 10500.0, Quantity::from("100000.0"));
         assert_eq!(result, Money::from("0 USDT"));
     }
@@ -1667,7 +1694,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10510.0, Quantity::from("12.0"));
         assert_eq!(pnl, Money::from("120 USDT"));
         assert_eq!(position.realized_pnl, Some(Money::from("-126 USDT")));
@@ -1709,7 +1736,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10480.5, Quantity::from("10.0"));
         assert_eq!(pnl, Money::from("-195 USDT"));
         assert_eq!(position.realized_pnl, Some(Money::from("-126 USDT")));
@@ -1751,7 +1778,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10390.0, Quantity::from("10.15"));
         assert_eq!(pnl, Money::from("1116.5 USDT"));
         assert_eq!(
@@ -1793,7 +1820,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.calculate_pnl(10500.0, 10670.5, Quantity::from("10.0"));
         assert_eq!(pnl, Money::from("-1705 USDT"));
         assert_eq!(
@@ -1831,7 +1858,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.calculate_pnl(10000.0, 11000.0, Quantity::from("100000.0"));
         assert_eq!(pnl, Money::from("-0.90909091 BTC"));
         assert_eq!(
@@ -1868,7 +1895,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(ethusdt_bitmex, fill).unwrap();
+        let position = Position::new(&ethusdt_bitmex, fill).unwrap();
 
         assert_eq!(
             position.unrealized_pnl(Price::from("370.00")),
@@ -1932,7 +1959,7 @@ mod tests {
             Some(commission2),
             None,
         );
-        let mut position = Position::new(currency_pair_btcusdt, fill1).unwrap();
+        let mut position = Position::new(&currency_pair_btcusdt, fill1).unwrap();
         position.apply(&fill2);
         let pnl = position.unrealized_pnl(Price::from("11505.60"));
         assert_eq!(pnl, Money::from("4022.40000000 USDT"));
@@ -1973,7 +2000,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(currency_pair_btcusdt, fill).unwrap();
+        let position = Position::new(&currency_pair_btcusdt, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("10407.15"));
         assert_eq!(pnl, Money::from("582.03640000 USDT"));
         assert_eq!(
@@ -2010,7 +2037,7 @@ mod tests {
             None,
         );
 
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("11505.60"));
         assert_eq!(pnl, Money::from("0.83238969 BTC"));
         assert_eq!(position.realized_pnl, Some(Money::from("-0.00714286 BTC")));
@@ -2040,7 +2067,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(xbtusd_bitmex, fill).unwrap();
+        let position = Position::new(&xbtusd_bitmex, fill).unwrap();
         let pnl = position.unrealized_pnl(Price::from("12506.65"));
 
         assert_eq!(pnl, Money::from("19.30166700 BTC"));
@@ -2048,6 +2075,90 @@ mod tests {
         assert_eq!(position.commissions(), vec![Money::from("0.06048387 BTC")]);
     }
 
+    #[rstest]
+    fn test_inverse_avg_px_and_realized_pnl_bitmex_example(xbtusd_bitmex: CryptoPerpetual) {
+        // BitMEX: buy 1,000 XBTUSD @ 10,000 and 1,000 @ 12,000, then sell 2,000 @ 11,000
+        let fill = |side: OrderSide, qty: &str, px: &str, trade_id: &str| {
+            let order = TestOrderStubs::market_order(
+                xbtusd_bitmex.id,
+                side,
+                Quantity::from(qty),
+                None,
+                None,
+            );
+            TestOrderEventStubs::order_filled(
+                &order,
+                &xbtusd_bitmex,
+                Some(StrategyId::from("S-001")),
+                Some(TradeId::new(trade_id).unwrap()),
+                Some(PositionId::new("P-123456").unwrap()),
+                Some(Price::from(px)),
+                None,
+                Some(Money::from("0 BTC")),
+                None,
+            )
+        };
+        let mut position =
+            Position::new(&xbtusd_bitmex, fill(OrderSide::Buy, "1000", "10000.0", "1")).unwrap();
+        position.apply(&fill(OrderSide::Buy, "1000", "12000.0", "2"));
+
+        // Harmonic mean of entry prices, not the arithmetic 11,000
+        assert!((position.avg_px_open - 10_909.090_909_090_91).abs() < 1e-6);
+        assert_eq!(
+            position.unrealized_pnl(Price::from("11000.0")),
+            Money::from("0.00151515 BTC")
+        );
+
+        position.apply(&fill(OrderSide::Sell, "2000", "11000.0", "3"));
+        assert!(position.is_closed());
+        assert_eq!(position.realized_pnl, Some(Money::from("0.00151515 BTC")));
+    }
+
+    #[rstest]
+    fn test_quanto_pnl_and_notional_in_settlement_currency(ethusd_quanto_bitmex: CryptoPerpetual) {
+        // BitMEX ETHUSD quanto: 0.000001 XBT per 1 USD move per contract
+        let fill = |side: OrderSide, px: &str, trade_id: &str| {
+            let order = TestOrderStubs::market_order(
+                ethusd_quanto_bitmex.id,
+                side,
+                Quantity::from(100),
+                None,
+                None,
+            );
+            let commission =
+                calculate_commission(ethusd_quanto_bitmex, order.quantity, Price::from(px), None)
+                    .unwrap();
+            TestOrderEventStubs::order_filled(
+                &order,
+                &ethusd_quanto_bitmex,
+                Some(StrategyId::from("S-001")),
+                Some(TradeId::new(trade_id).unwrap()),
+                Some(PositionId::new("P-123456").unwrap()),
+                Some(Price::from(px)),
+                None,
+                Some(commission),
+                None,
+            )
+        };
+        let mut position =
+            Position::new(&ethusd_quanto_bitmex, fill(OrderSide::Buy, "2000.00", "1")).unwrap();
+
+        assert_eq!(
+            position.notional_value(Price::from("2000.00")),
+            Money::from("0.20000000 BTC")
+        );
+        assert_eq!(
+            position.unrealized_pnl(Price::from("2100.00")),
+            Money::from("0.01000000 BTC")
+        );
+        assert_eq!(position.commissions(), vec![Money::from("0.00015000 BTC")]);
+
+        position.apply(&fill(OrderSide::Sell, "2100.00", "2"));
+        assert!(position.is_closed());
+        // 0.01 XBT gain less taker fees of 0.00015 and 0.0001575 XBT
+        assert_eq!(position.realized_pnl, Some(Money::from("0.00969250 BTC")));
+    }
+
     #[rstest]
     #[case(OrderSide::Buy, 25, 25.0)]
     #[case(OrderSide::Sell,25,-25.0)]
@@ -2078,7 +2189,7 @@ mod tests {
             Some(commission),
             None,
         );
-        let position = Position::new(audusd_sim, fill).unwrap();
+        let position = Position::new(&audusd_sim, fill).unwrap();
         assert_eq!(position.signed_qty, expected);
     }
 }
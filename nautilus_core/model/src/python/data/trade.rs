@@ -43,6 +43,8 @@ use super::data_to_pycapsule;
 #[pymethods]
 impl TradeTick {
     #[new]
+    #[pyo3(signature = (instrument_id, price, size, aggressor_side, trade_id, ts_event, ts_init, flags=0))]
+    #[allow(clippy::too_many_arguments)]
     fn py_new(
         instrument_id: InstrumentId,
         price: Price,
@@ -51,6 +53,7 @@ impl TradeTick {
         trade_id: TradeId,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
+        flags: u8,
     ) -> Self {
         Self::new(
             instrument_id,
@@ -61,6 +64,7 @@ impl TradeTick {
             ts_event,
             ts_init,
         )
+        .with_flags(flags)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -74,6 +78,7 @@ impl TradeTick {
             &PyString,
             &PyLong,
             &PyLong,
+            &PyLong,
         ) = state.extract(py)?;
         let instrument_id_str: &str = tuple.0.extract()?;
         let price_raw = tuple.1.extract()?;
@@ -90,6 +95,7 @@ impl TradeTick {
         self.trade_id = TradeId::from_str(trade_id_str).map_err(to_pyvalue_err)?;
         self.ts_event = tuple.7.extract()?;
         self.ts_init = tuple.8.extract()?;
+        self.flags = tuple.9.extract()?;
 
         Ok(())
     }
@@ -105,6 +111,7 @@ impl TradeTick {
             self.trade_id.to_string(),
             self.ts_event,
             self.ts_init,
+            self.flags,
         )
             .to_object(_py))
     }
@@ -174,6 +181,35 @@ impl TradeTick {
         self.aggressor_side
     }
 
+    #[getter]
+    #[pyo3(name = "flags")]
+    fn py_flags(&self) -> u8 {
+        self.flags
+    }
+
+    #[getter]
+    #[pyo3(name = "is_aggregated")]
+    fn py_is_aggregated(&self) -> bool {
+        self.is_aggregated()
+    }
+
+    #[getter]
+    #[pyo3(name = "is_block")]
+    fn py_is_block(&self) -> bool {
+        self.is_block()
+    }
+
+    #[getter]
+    #[pyo3(name = "is_off_book")]
+    fn py_is_off_book(&self) -> bool {
+        self.is_off_book()
+    }
+
+    #[pyo3(name = "is_bar_eligible")]
+    fn py_is_bar_eligible(&self, exclude_flags: u8) -> bool {
+        self.is_bar_eligible(exclude_flags)
+    }
+
     #[getter]
     #[pyo3(name = "trade_id")]
     fn py_trade_id(&self) -> TradeId {
@@ -0,0 +1,90 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use pyo3::{create_exception, exceptions::PyValueError, PyErr};
+
+use crate::error;
+
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    ModelError,
+    PyValueError,
+    "Base exception for errors raised when constructing or validating model types."
+);
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    PrecisionMismatchError,
+    ModelError,
+    "Raised when a precision does not match the expected precision."
+);
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    OutOfRangeError,
+    ModelError,
+    "Raised when a value is outside of its valid range."
+);
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    InvalidIdentifierError,
+    ModelError,
+    "Raised when an identifier string is invalid."
+);
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    CurrencyMismatchError,
+    ModelError,
+    "Raised when values which should share a currency have different currencies."
+);
+create_exception!(
+    nautilus_trader.core.nautilus_pyo3.model,
+    InvalidArgumentError,
+    ModelError,
+    "Raised when an argument is invalid."
+);
+
+impl From<error::ModelError> for PyErr {
+    fn from(e: error::ModelError) -> Self {
+        match e {
+            error::ModelError::PrecisionMismatch(msg) => PrecisionMismatchError::new_err(msg),
+            error::ModelError::OutOfRange(msg) => OutOfRangeError::new_err(msg),
+            error::ModelError::InvalidIdentifier(msg) => InvalidIdentifierError::new_err(msg),
+            error::ModelError::CurrencyMismatch(msg) => CurrencyMismatchError::new_err(msg),
+            error::ModelError::InvalidArgument(msg) => InvalidArgumentError::new_err(msg),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{exceptions::PyValueError, prelude::*, prepare_freethreaded_python};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_model_error_converts_to_subclass_of_value_error() {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err: PyErr = error::ModelError::out_of_range("value was 10").into();
+            assert!(err.is_instance_of::<OutOfRangeError>(py));
+            assert!(err.is_instance_of::<ModelError>(py));
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(err.value(py).to_string(), "value was 10");
+        });
+    }
+}
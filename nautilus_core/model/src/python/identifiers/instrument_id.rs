@@ -103,13 +103,34 @@ impl InstrumentId {
     #[staticmethod]
     #[pyo3(name = "from_str")]
     fn py_from_str(value: &str) -> PyResult<InstrumentId> {
-        InstrumentId::from_str(value).map_err(to_pyvalue_err)
+        InstrumentId::from_str(value).map_err(PyErr::from)
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_parts")]
+    fn py_from_parts(symbol: &str, venue: &str) -> PyResult<InstrumentId> {
+        InstrumentId::from_parts(symbol, venue).map_err(PyErr::from)
     }
 
     #[pyo3(name = "is_synthetic")]
     fn py_is_synthetic(&self) -> bool {
         self.is_synthetic()
     }
+
+    #[pyo3(name = "is_venue")]
+    fn py_is_venue(&self, venue: Venue) -> bool {
+        self.is_venue(&venue)
+    }
+
+    #[pyo3(name = "has_composite_symbol")]
+    fn py_has_composite_symbol(&self) -> bool {
+        self.has_composite_symbol()
+    }
+
+    #[pyo3(name = "with_venue")]
+    fn py_with_venue(&self, venue: Venue) -> InstrumentId {
+        self.with_venue(venue)
+    }
 }
 
 impl ToPyObject for InstrumentId {
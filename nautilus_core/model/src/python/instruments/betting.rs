@@ -0,0 +1,227 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::{python::serialization::from_dict_pyo3, time::UnixNanos};
+use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+
+use crate::{
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    instruments::betting::BettingInstrument,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl BettingInstrument {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    fn py_new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        event_type_id: u64,
+        event_type_name: String,
+        competition_id: u64,
+        competition_name: String,
+        event_id: u64,
+        event_name: String,
+        event_country_code: String,
+        event_open_date: UnixNanos,
+        betting_type: String,
+        market_id: String,
+        market_name: String,
+        market_type: String,
+        market_start_time: UnixNanos,
+        selection_id: u64,
+        selection_name: String,
+        selection_handicap: f64,
+        currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+    ) -> PyResult<Self> {
+        Self::new(
+            id,
+            raw_symbol,
+            event_type_id,
+            event_type_name.into(),
+            competition_id,
+            competition_name.into(),
+            event_id,
+            event_name.into(),
+            event_country_code.into(),
+            event_open_date,
+            betting_type.into(),
+            market_id.into(),
+            market_name.into(),
+            market_type.into(),
+            market_start_time,
+            selection_id,
+            selection_name.into(),
+            selection_handicap,
+            currency,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        )
+        .map_err(PyErr::from)
+    }
+
+    #[getter]
+    fn instrument_type(&self) -> &str {
+        "BettingInstrument"
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            _ => panic!("Not implemented"),
+        }
+    }
+
+    #[getter]
+    fn event_type_name(&self) -> &str {
+        self.event_type_name.as_str()
+    }
+
+    #[getter]
+    fn competition_name(&self) -> &str {
+        self.competition_name.as_str()
+    }
+
+    #[getter]
+    fn event_name(&self) -> &str {
+        self.event_name.as_str()
+    }
+
+    #[getter]
+    fn event_country_code(&self) -> &str {
+        self.event_country_code.as_str()
+    }
+
+    #[getter]
+    fn betting_type(&self) -> &str {
+        self.betting_type.as_str()
+    }
+
+    #[getter]
+    fn market_id(&self) -> &str {
+        self.market_id.as_str()
+    }
+
+    #[getter]
+    fn market_name(&self) -> &str {
+        self.market_name.as_str()
+    }
+
+    #[getter]
+    fn market_type(&self) -> &str {
+        self.market_type.as_str()
+    }
+
+    #[getter]
+    fn selection_name(&self) -> &str {
+        self.selection_name.as_str()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[pyo3(name = "to_dict")]
+    fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", stringify!(BettingInstrument))?;
+        dict.set_item("id", self.id.to_string())?;
+        dict.set_item("raw_symbol", self.raw_symbol.to_string())?;
+        dict.set_item("event_type_id", self.event_type_id)?;
+        dict.set_item("event_type_name", self.event_type_name.to_string())?;
+        dict.set_item("competition_id", self.competition_id)?;
+        dict.set_item("competition_name", self.competition_name.to_string())?;
+        dict.set_item("event_id", self.event_id)?;
+        dict.set_item("event_name", self.event_name.to_string())?;
+        dict.set_item("event_country_code", self.event_country_code.to_string())?;
+        dict.set_item("event_open_date", self.event_open_date)?;
+        dict.set_item("betting_type", self.betting_type.to_string())?;
+        dict.set_item("market_id", self.market_id.to_string())?;
+        dict.set_item("market_name", self.market_name.to_string())?;
+        dict.set_item("market_type", self.market_type.to_string())?;
+        dict.set_item("market_start_time", self.market_start_time)?;
+        dict.set_item("selection_id", self.selection_id)?;
+        dict.set_item("selection_name", self.selection_name.to_string())?;
+        dict.set_item("selection_handicap", self.selection_handicap)?;
+        dict.set_item("currency", self.currency.code.to_string())?;
+        dict.set_item("price_precision", self.price_precision)?;
+        dict.set_item("size_precision", self.size_precision)?;
+        dict.set_item("price_increment", self.price_increment.to_string())?;
+        dict.set_item("size_increment", self.size_increment.to_string())?;
+        dict.set_item("ts_event", self.ts_event)?;
+        dict.set_item("ts_init", self.ts_init)?;
+        match self.max_quantity {
+            Some(value) => dict.set_item("max_quantity", value.to_string())?,
+            None => dict.set_item("max_quantity", py.None())?,
+        }
+        match self.min_quantity {
+            Some(value) => dict.set_item("min_quantity", value.to_string())?,
+            None => dict.set_item("min_quantity", py.None())?,
+        }
+        match self.max_notional {
+            Some(value) => dict.set_item("max_notional", value.to_string())?,
+            None => dict.set_item("max_notional", py.None())?,
+        }
+        match self.min_notional {
+            Some(value) => dict.set_item("min_notional", value.to_string())?,
+            None => dict.set_item("min_notional", py.None())?,
+        }
+        match self.max_price {
+            Some(value) => dict.set_item("max_price", value.to_string())?,
+            None => dict.set_item("max_price", py.None())?,
+        }
+        match self.min_price {
+            Some(value) => dict.set_item("min_price", value.to_string())?,
+            None => dict.set_item("min_price", py.None())?,
+        }
+        Ok(dict.into())
+    }
+}
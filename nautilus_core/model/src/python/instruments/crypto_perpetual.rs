@@ -18,10 +18,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
-    time::UnixNanos,
-};
+use nautilus_core::{python::serialization::from_dict_pyo3, time::UnixNanos};
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 
@@ -59,6 +56,7 @@ impl CryptoPerpetual {
         min_notional: Option<Money>,
         max_price: Option<Price>,
         min_price: Option<Price>,
+        quanto_multiplier: Option<Decimal>,
     ) -> PyResult<Self> {
         Self::new(
             id,
@@ -67,6 +65,7 @@ impl CryptoPerpetual {
             quote_currency,
             settlement_currency,
             is_inverse,
+            quanto_multiplier,
             price_precision,
             size_precision,
             price_increment,
@@ -85,7 +84,7 @@ impl CryptoPerpetual {
             ts_event,
             ts_init,
         )
-        .map_err(to_pyvalue_err)
+        .map_err(PyErr::from)
     }
 
     #[getter]
@@ -125,6 +124,10 @@ impl CryptoPerpetual {
             self.settlement_currency.code.to_string(),
         )?;
         dict.set_item("is_inverse", self.is_inverse)?;
+        match self.quanto_multiplier {
+            Some(value) => dict.set_item("quanto_multiplier", value.to_f64())?,
+            None => dict.set_item("quanto_multiplier", py.None())?,
+        }
         dict.set_item("price_precision", self.price_precision)?;
         dict.set_item("size_precision", self.size_precision)?;
         dict.set_item("price_increment", self.price_increment.to_string())?;
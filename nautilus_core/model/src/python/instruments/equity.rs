@@ -18,10 +18,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use nautilus_core::{
-    python::{serialization::from_dict_pyo3, to_pyvalue_err},
-    time::UnixNanos,
-};
+use nautilus_core::{python::serialization::from_dict_pyo3, time::UnixNanos};
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
@@ -66,7 +63,7 @@ impl Equity {
             ts_event,
             ts_init,
         )
-        .map_err(to_pyvalue_err)
+        .map_err(PyErr::from)
     }
 
     #[getter]
@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod betting;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
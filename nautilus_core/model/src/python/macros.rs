@@ -76,7 +76,7 @@ macro_rules! identifier_for_python {
             fn py_new(value: &str) -> PyResult<Self> {
                 match <$ty>::new(value) {
                     Ok(instance) => Ok(instance),
-                    Err(e) => Err(to_pyvalue_err(e)),
+                    Err(e) => Err(e.into()),
                 }
             }
 
@@ -25,12 +25,14 @@ use strum::IntoEnumIterator;
 use crate::enums;
 
 pub mod data;
+pub mod error;
 pub mod events;
 pub mod identifiers;
 pub mod instruments;
 pub mod macros;
 pub mod orders;
 pub mod position;
+pub mod reports;
 pub mod types;
 
 pub const PY_MODULE_MODEL: &str = "nautilus_trader.core.nautilus_pyo3.model";
@@ -228,7 +230,7 @@ mod tests {
 
 /// Loaded as nautilus_pyo3.model
 #[pymodule]
-pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
+pub fn model(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Data
     m.add_class::<crate::data::bar::BarSpecification>()?;
     m.add_class::<crate::data::bar::BarType>()?;
@@ -260,7 +262,9 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<enums::OrderType>()?;
     m.add_class::<enums::PositionSide>()?;
     m.add_class::<enums::PriceType>()?;
+    m.add_class::<enums::RoundingMode>()?;
     m.add_class::<enums::TimeInForce>()?;
+    m.add_class::<enums::TradeFlag>()?;
     m.add_class::<enums::TradingState>()?;
     m.add_class::<enums::TrailingOffsetType>()?;
     m.add_class::<enums::TriggerType>()?;
@@ -294,7 +298,27 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<crate::types::quantity::Quantity>()?;
     m.add_class::<crate::types::balance::AccountBalance>()?;
     m.add_class::<crate::types::balance::MarginBalance>()?;
+    // Errors
+    m.add("ModelError", py.get_type::<error::ModelError>())?;
+    m.add(
+        "PrecisionMismatchError",
+        py.get_type::<error::PrecisionMismatchError>(),
+    )?;
+    m.add("OutOfRangeError", py.get_type::<error::OutOfRangeError>())?;
+    m.add(
+        "InvalidIdentifierError",
+        py.get_type::<error::InvalidIdentifierError>(),
+    )?;
+    m.add(
+        "CurrencyMismatchError",
+        py.get_type::<error::CurrencyMismatchError>(),
+    )?;
+    m.add(
+        "InvalidArgumentError",
+        py.get_type::<error::InvalidArgumentError>(),
+    )?;
     // Instruments
+    m.add_class::<crate::instruments::betting::BettingInstrument>()?;
     m.add_class::<crate::instruments::crypto_future::CryptoFuture>()?;
     m.add_class::<crate::instruments::crypto_perpetual::CryptoPerpetual>()?;
     m.add_class::<crate::instruments::currency_pair::CurrencyPair>()?;
@@ -322,5 +346,10 @@ pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Events - account
     m.add_class::<crate::events::account::state::AccountState>()?;
     m.add_class::<crate::position::Position>()?;
+    // Reports
+    m.add_class::<crate::reports::order::OrderStatusReport>()?;
+    m.add_class::<crate::reports::fill::FillReport>()?;
+    m.add_class::<crate::reports::position::PositionStatusReport>()?;
+    m.add_function(wrap_pyfunction!(reports::py_reconcile_position, m)?)?;
     Ok(())
 }
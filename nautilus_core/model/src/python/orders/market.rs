@@ -15,7 +15,7 @@
 
 use std::collections::HashMap;
 
-use nautilus_core::{python::to_pyvalue_err, time::UnixNanos, uuid::UUID4};
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
 use ustr::Ustr;
@@ -103,7 +103,7 @@ impl MarketOrder {
             exec_spawn_id,
             tags.map(|s| Ustr::from(&s)),
         )
-        .map_err(to_pyvalue_err)
+        .map_err(PyErr::from)
     }
 
     #[staticmethod]
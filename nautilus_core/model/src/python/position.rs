@@ -32,6 +32,7 @@ use crate::identifiers::trade_id::TradeId;
 use crate::identifiers::trader_id::TraderId;
 use crate::identifiers::venue::Venue;
 use crate::identifiers::venue_order_id::VenueOrderId;
+use crate::instruments::betting::BettingInstrument;
 use crate::instruments::crypto_future::CryptoFuture;
 use crate::instruments::crypto_perpetual::CryptoPerpetual;
 use crate::instruments::currency_pair::CurrencyPair;
@@ -52,7 +53,10 @@ impl Position {
         let instrument_type = instrument
             .getattr(py, "instrument_type")?
             .extract::<String>(py)?;
-        if instrument_type == "CryptoFuture" {
+        if instrument_type == "BettingInstrument" {
+            let instrument_rust = instrument.extract::<BettingInstrument>(py)?;
+            Ok(Self::new(instrument_rust, fill).unwrap())
+        } else if instrument_type == "CryptoFuture" {
             let instrument_rust = instrument.extract::<CryptoFuture>(py)?;
             Ok(Self::new(instrument_rust, fill).unwrap())
         } else if instrument_type == "CryptoPerpetual" {
@@ -193,6 +197,12 @@ impl Position {
         self.is_inverse
     }
 
+    #[getter]
+    #[pyo3(name = "quanto_multiplier")]
+    fn py_quanto_multiplier(&self) -> Option<f64> {
+        self.quanto_multiplier
+    }
+
     #[getter]
     #[pyo3(name = "base_currency")]
     fn py_base_currency(&self) -> Option<Currency> {
@@ -393,6 +403,7 @@ impl Position {
         dict.set_item("size_precision", self.size_precision.to_u8())?;
         dict.set_item("multiplier", self.multiplier.to_string())?;
         dict.set_item("is_inverse", self.is_inverse)?;
+        dict.set_item("quanto_multiplier", self.quanto_multiplier)?;
         match self.base_currency {
             Some(base_currency) => {
                 dict.set_item("base_currency", base_currency.code.to_string())?;
@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::{basic::CompareOp, prelude::*};
+
+use crate::{
+    enums::{LiquiditySide, OrderSide},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    reports::fill::FillReport,
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl FillReport {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    fn py_new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        venue_position_id: Option<PositionId>,
+        trade_id: TradeId,
+        order_side: OrderSide,
+        last_qty: Quantity,
+        last_px: Price,
+        commission: Money,
+        liquidity_side: LiquiditySide,
+        report_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new(
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            venue_position_id,
+            trade_id,
+            order_side,
+            last_qty,
+            last_px,
+            commission,
+            liquidity_side,
+            report_id,
+            ts_event,
+            ts_init,
+        )
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "account_id")]
+    fn py_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> Option<ClientOrderId> {
+        self.client_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "venue_order_id")]
+    fn py_venue_order_id(&self) -> VenueOrderId {
+        self.venue_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "venue_position_id")]
+    fn py_venue_position_id(&self) -> Option<PositionId> {
+        self.venue_position_id
+    }
+
+    #[getter]
+    #[pyo3(name = "trade_id")]
+    fn py_trade_id(&self) -> TradeId {
+        self.trade_id
+    }
+
+    #[getter]
+    #[pyo3(name = "order_side")]
+    fn py_order_side(&self) -> OrderSide {
+        self.order_side
+    }
+
+    #[getter]
+    #[pyo3(name = "last_qty")]
+    fn py_last_qty(&self) -> Quantity {
+        self.last_qty
+    }
+
+    #[getter]
+    #[pyo3(name = "last_px")]
+    fn py_last_px(&self) -> Price {
+        self.last_px
+    }
+
+    #[getter]
+    #[pyo3(name = "commission")]
+    fn py_commission(&self) -> Money {
+        self.commission
+    }
+
+    #[getter]
+    #[pyo3(name = "liquidity_side")]
+    fn py_liquidity_side(&self) -> LiquiditySide {
+        self.liquidity_side
+    }
+
+    #[getter]
+    #[pyo3(name = "report_id")]
+    fn py_report_id(&self) -> UUID4 {
+        self.report_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
@@ -0,0 +1,34 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod fill;
+pub mod order;
+pub mod position;
+
+use pyo3::prelude::*;
+
+use crate::{
+    position::Position,
+    reports::{position::PositionStatusReport, reconciliation::reconcile_position},
+};
+
+#[pyfunction]
+#[pyo3(name = "reconcile_position")]
+pub fn py_reconcile_position(
+    report: PositionStatusReport,
+    positions: Vec<Position>,
+) -> Option<f64> {
+    reconcile_position(&report, &positions)
+}
@@ -0,0 +1,222 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::{basic::CompareOp, prelude::*};
+
+use crate::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        venue_order_id::VenueOrderId,
+    },
+    reports::order::OrderStatusReport,
+    types::{price::Price, quantity::Quantity},
+};
+
+#[pymethods]
+impl OrderStatusReport {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (
+        account_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        order_side,
+        order_type,
+        time_in_force,
+        order_status,
+        quantity,
+        filled_qty,
+        report_id,
+        ts_accepted,
+        ts_last,
+        ts_init,
+        price = None,
+        trigger_price = None,
+        avg_px = None,
+    ))]
+    fn py_new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        report_id: UUID4,
+        ts_accepted: UnixNanos,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        avg_px: Option<f64>,
+    ) -> Self {
+        let mut report = Self::new(
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_side,
+            order_type,
+            time_in_force,
+            order_status,
+            quantity,
+            filled_qty,
+            report_id,
+            ts_accepted,
+            ts_last,
+            ts_init,
+        );
+        report.price = price;
+        report.trigger_price = trigger_price;
+        report.avg_px = avg_px;
+        report
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "account_id")]
+    fn py_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "client_order_id")]
+    fn py_client_order_id(&self) -> Option<ClientOrderId> {
+        self.client_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "venue_order_id")]
+    fn py_venue_order_id(&self) -> VenueOrderId {
+        self.venue_order_id
+    }
+
+    #[getter]
+    #[pyo3(name = "order_side")]
+    fn py_order_side(&self) -> OrderSide {
+        self.order_side
+    }
+
+    #[getter]
+    #[pyo3(name = "order_type")]
+    fn py_order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    #[getter]
+    #[pyo3(name = "time_in_force")]
+    fn py_time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    #[getter]
+    #[pyo3(name = "order_status")]
+    fn py_order_status(&self) -> OrderStatus {
+        self.order_status
+    }
+
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "filled_qty")]
+    fn py_filled_qty(&self) -> Quantity {
+        self.filled_qty
+    }
+
+    #[getter]
+    #[pyo3(name = "leaves_qty")]
+    fn py_leaves_qty(&self) -> Quantity {
+        self.leaves_qty()
+    }
+
+    #[getter]
+    #[pyo3(name = "price")]
+    fn py_price(&self) -> Option<Price> {
+        self.price
+    }
+
+    #[getter]
+    #[pyo3(name = "trigger_price")]
+    fn py_trigger_price(&self) -> Option<Price> {
+        self.trigger_price
+    }
+
+    #[getter]
+    #[pyo3(name = "avg_px")]
+    fn py_avg_px(&self) -> Option<f64> {
+        self.avg_px
+    }
+
+    #[getter]
+    #[pyo3(name = "report_id")]
+    fn py_report_id(&self) -> UUID4 {
+        self.report_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_accepted")]
+    fn py_ts_accepted(&self) -> UnixNanos {
+        self.ts_accepted
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_last")]
+    fn py_ts_last(&self) -> UnixNanos {
+        self.ts_last
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    #[getter]
+    #[pyo3(name = "is_open")]
+    fn py_is_open(&self) -> bool {
+        self.is_open()
+    }
+}
@@ -0,0 +1,121 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::{basic::CompareOp, prelude::*};
+
+use crate::{
+    enums::PositionSide,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, position_id::PositionId},
+    reports::position::PositionStatusReport,
+    types::quantity::Quantity,
+};
+
+#[pymethods]
+impl PositionStatusReport {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    fn py_new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        venue_position_id: Option<PositionId>,
+        report_id: UUID4,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new(
+            account_id,
+            instrument_id,
+            position_side,
+            quantity,
+            venue_position_id,
+            report_id,
+            ts_last,
+            ts_init,
+        )
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Eq => self.eq(other).into_py(py),
+            CompareOp::Ne => self.ne(other).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "account_id")]
+    fn py_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    #[getter]
+    #[pyo3(name = "position_side")]
+    fn py_position_side(&self) -> PositionSide {
+        self.position_side
+    }
+
+    #[getter]
+    #[pyo3(name = "quantity")]
+    fn py_quantity(&self) -> Quantity {
+        self.quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "signed_qty")]
+    fn py_signed_qty(&self) -> f64 {
+        self.signed_qty()
+    }
+
+    #[getter]
+    #[pyo3(name = "venue_position_id")]
+    fn py_venue_position_id(&self) -> Option<PositionId> {
+        self.venue_position_id
+    }
+
+    #[getter]
+    #[pyo3(name = "report_id")]
+    fn py_report_id(&self) -> UUID4 {
+        self.report_id
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_last")]
+    fn py_ts_last(&self) -> UnixNanos {
+        self.ts_last
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::python::{serialization::from_dict_pyo3, to_pyvalue_err};
+use nautilus_core::python::serialization::from_dict_pyo3;
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 
 use crate::{
@@ -28,7 +28,7 @@ use crate::{
 impl AccountBalance {
     #[new]
     fn py_new(total: Money, locked: Money, free: Money) -> PyResult<Self> {
-        Self::new(total, locked, free).map_err(to_pyvalue_err)
+        Self::new(total, locked, free).map_err(PyErr::from)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -81,7 +81,7 @@ impl AccountBalance {
 impl MarginBalance {
     #[new]
     fn py_new(initial: Money, maintenance: Money, instrument: InstrumentId) -> PyResult<Self> {
-        Self::new(initial, maintenance, instrument).map_err(to_pyvalue_err)
+        Self::new(initial, maintenance, instrument).map_err(PyErr::from)
     }
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
         match op {
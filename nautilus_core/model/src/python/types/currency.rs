@@ -36,7 +36,7 @@ impl Currency {
         name: &str,
         currency_type: CurrencyType,
     ) -> PyResult<Self> {
-        Self::new(code, precision, iso4217, name, currency_type).map_err(to_pyvalue_err)
+        Self::new(code, precision, iso4217, name, currency_type).map_err(PyErr::from)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
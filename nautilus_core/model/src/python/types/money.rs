@@ -35,7 +35,7 @@ use crate::types::{currency::Currency, money::Money};
 impl Money {
     #[new]
     fn py_new(value: f64, currency: Currency) -> PyResult<Self> {
-        Money::new(value, currency).map_err(to_pyvalue_err)
+        Money::new(value, currency).map_err(PyErr::from)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -334,7 +334,7 @@ impl Money {
     #[staticmethod]
     #[pyo3(name = "zero")]
     fn py_zero(currency: Currency) -> PyResult<Money> {
-        Money::new(0.0, currency).map_err(to_pyvalue_err)
+        Money::new(0.0, currency).map_err(PyErr::from)
     }
 
     #[staticmethod]
@@ -28,13 +28,21 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::{fixed::fixed_i64_to_f64, price::Price};
+use crate::{
+    enums::RoundingMode,
+    types::{fixed::fixed_i64_to_f64, price::Price},
+};
 
 #[pymethods]
 impl Price {
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Price::new(value, precision).map_err(to_pyvalue_err)
+    #[pyo3(signature = (value, precision, rounding_mode = None))]
+    fn py_new(value: f64, precision: u8, rounding_mode: Option<RoundingMode>) -> PyResult<Self> {
+        match rounding_mode {
+            Some(mode) => Price::new_with_rounding(value, precision, mode),
+            None => Price::new(value, precision),
+        }
+        .map_err(PyErr::from)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -332,20 +340,20 @@ impl Price {
     #[staticmethod]
     #[pyo3(name = "from_raw")]
     fn py_from_raw(raw: i64, precision: u8) -> PyResult<Price> {
-        Price::from_raw(raw, precision).map_err(to_pyvalue_err)
+        Price::from_raw(raw, precision).map_err(PyErr::from)
     }
 
     #[staticmethod]
     #[pyo3(name = "zero")]
     #[pyo3(signature = (precision = 0))]
     fn py_zero(precision: u8) -> PyResult<Price> {
-        Price::new(0.0, precision).map_err(to_pyvalue_err)
+        Price::new(0.0, precision).map_err(PyErr::from)
     }
 
     #[staticmethod]
     #[pyo3(name = "from_int")]
     fn py_from_int(value: u64) -> PyResult<Price> {
-        Price::new(value as f64, 0).map_err(to_pyvalue_err)
+        Price::new(value as f64, 0).map_err(PyErr::from)
     }
 
     #[staticmethod]
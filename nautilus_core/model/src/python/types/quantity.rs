@@ -28,13 +28,18 @@ use pyo3::{
 };
 use rust_decimal::{Decimal, RoundingStrategy};
 
-use crate::types::quantity::Quantity;
+use crate::{enums::RoundingMode, types::quantity::Quantity};
 
 #[pymethods]
 impl Quantity {
     #[new]
-    fn py_new(value: f64, precision: u8) -> PyResult<Self> {
-        Quantity::new(value, precision).map_err(to_pyvalue_err)
+    #[pyo3(signature = (value, precision, rounding_mode = None))]
+    fn py_new(value: f64, precision: u8, rounding_mode: Option<RoundingMode>) -> PyResult<Self> {
+        match rounding_mode {
+            Some(mode) => Quantity::new_with_rounding(value, precision, mode),
+            None => Quantity::new(value, precision),
+        }
+        .map_err(PyErr::from)
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -332,20 +337,20 @@ impl Quantity {
     #[staticmethod]
     #[pyo3(name = "from_raw")]
     fn py_from_raw(raw: u64, precision: u8) -> PyResult<Quantity> {
-        Quantity::from_raw(raw, precision).map_err(to_pyvalue_err)
+        Quantity::from_raw(raw, precision).map_err(PyErr::from)
     }
 
     #[staticmethod]
     #[pyo3(name = "zero")]
     #[pyo3(signature = (precision = 0))]
     fn py_zero(precision: u8) -> PyResult<Quantity> {
-        Quantity::new(0.0, precision).map_err(to_pyvalue_err)
+        Quantity::new(0.0, precision).map_err(PyErr::from)
     }
 
     #[staticmethod]
     #[pyo3(name = "from_int")]
     fn py_from_int(value: u64) -> PyResult<Quantity> {
-        Quantity::new(value as f64, 0).map_err(to_pyvalue_err)
+        Quantity::new(value as f64, 0).map_err(PyErr::from)
     }
 
     #[staticmethod]
@@ -0,0 +1,109 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{LiquiditySide, OrderSide},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a single fill of an order, as reported by the venue.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FillReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub venue_position_id: Option<PositionId>,
+    pub trade_id: TradeId,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Money,
+    pub liquidity_side: LiquiditySide,
+    pub report_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl FillReport {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        venue_position_id: Option<PositionId>,
+        trade_id: TradeId,
+        order_side: OrderSide,
+        last_qty: Quantity,
+        last_px: Price,
+        commission: Money,
+        liquidity_side: LiquiditySide,
+        report_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            venue_position_id,
+            trade_id,
+            order_side,
+            last_qty,
+            last_px,
+            commission,
+            liquidity_side,
+            report_id,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for FillReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FillReport(account_id={}, instrument_id={}, venue_order_id={}, trade_id={}, order_side={}, last_qty={}, last_px={}, commission={}, liquidity_side={}, ts_event={})",
+            self.account_id,
+            self.instrument_id,
+            self.venue_order_id,
+            self.trade_id,
+            self.order_side,
+            self.last_qty,
+            self.last_px,
+            self.commission,
+            self.liquidity_side,
+            self.ts_event,
+        )
+    }
+}
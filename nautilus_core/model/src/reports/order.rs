@@ -0,0 +1,188 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an order status at a point in time, as reported by the venue.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub order_status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub avg_px: Option<f64>,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderStatusReport {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        report_id: UUID4,
+        ts_accepted: UnixNanos,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_side,
+            order_type,
+            time_in_force,
+            order_status,
+            quantity,
+            filled_qty,
+            price: None,
+            trigger_price: None,
+            avg_px: None,
+            report_id,
+            ts_accepted,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Returns the quantity remaining open at the venue.
+    #[must_use]
+    pub fn leaves_qty(&self) -> Quantity {
+        self.quantity - self.filled_qty
+    }
+
+    /// Returns whether the order is still open at the venue.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.order_status,
+            OrderStatus::Accepted
+                | OrderStatus::Triggered
+                | OrderStatus::PendingCancel
+                | OrderStatus::PendingUpdate
+                | OrderStatus::PartiallyFilled
+        )
+    }
+}
+
+impl Display for OrderStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OrderStatusReport(account_id={}, instrument_id={}, client_order_id={}, venue_order_id={}, order_side={}, order_type={}, order_status={}, quantity={}, filled_qty={}, ts_last={})",
+            self.account_id,
+            self.instrument_id,
+            self.client_order_id
+                .map_or_else(|| "None".to_string(), |id| format!("{id}")),
+            self.venue_order_id,
+            self.order_side,
+            self.order_type,
+            self.order_status,
+            self.quantity,
+            self.filled_qty,
+            self.ts_last,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn report(order_status: OrderStatus, filled_qty: i64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Some(ClientOrderId::from("O-123456789")),
+            VenueOrderId::from("1"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            0,
+            1,
+            2,
+        )
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Accepted, 0, true)]
+    #[case(OrderStatus::PartiallyFilled, 40, true)]
+    #[case(OrderStatus::Filled, 100, false)]
+    #[case(OrderStatus::Canceled, 40, false)]
+    fn test_order_status_report_is_open(
+        #[case] order_status: OrderStatus,
+        #[case] filled_qty: i64,
+        #[case] expected: bool,
+    ) {
+        let report = report(order_status, filled_qty);
+        assert_eq!(report.is_open(), expected);
+        assert_eq!(report.leaves_qty(), Quantity::from(100 - filled_qty));
+    }
+
+    #[rstest]
+    fn test_order_status_report_display() {
+        let report = report(OrderStatus::Accepted, 0);
+        assert_eq!(
+            format!("{report}"),
+            "OrderStatusReport(account_id=SIM-001, instrument_id=ETHUSDT-PERP.BINANCE, client_order_id=O-123456789, venue_order_id=1, order_side=BUY, order_type=LIMIT, order_status=ACCEPTED, quantity=100, filled_qty=0, ts_last=1)"
+        );
+    }
+}
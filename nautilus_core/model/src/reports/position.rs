@@ -0,0 +1,94 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::PositionSide,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, position_id::PositionId},
+    types::quantity::Quantity,
+};
+
+/// Represents a position status at a point in time, as reported by the venue.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    pub venue_position_id: Option<PositionId>,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PositionStatusReport {
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        venue_position_id: Option<PositionId>,
+        report_id: UUID4,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            position_side,
+            quantity,
+            venue_position_id,
+            report_id,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Returns the reported quantity, negative for short positions.
+    #[must_use]
+    pub fn signed_qty(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Short => -self.quantity.as_f64(),
+            _ => self.quantity.as_f64(),
+        }
+    }
+}
+
+impl Display for PositionStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PositionStatusReport(account_id={}, instrument_id={}, venue_position_id={}, position_side={}, quantity={}, ts_last={})",
+            self.account_id,
+            self.instrument_id,
+            self.venue_position_id
+                .map_or_else(|| "None".to_string(), |id| format!("{id}")),
+            self.position_side,
+            self.quantity,
+            self.ts_last,
+        )
+    }
+}
@@ -0,0 +1,482 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for reconciling cached execution state against venue reports.
+//!
+//! Each function compares the cached state with what the venue reported, producing the
+//! events which would bring the cached state in line (flagged as reconciliation events).
+
+use nautilus_core::{time::UnixNanos, uuid::UUID4};
+use ustr::Ustr;
+
+use super::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport};
+use crate::{
+    enums::{LiquiditySide, OrderStatus},
+    events::order::{
+        accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEvent, expired::OrderExpired,
+        filled::OrderFilled, rejected::OrderRejected, triggered::OrderTriggered,
+    },
+    identifiers::trade_id::TradeId,
+    instruments::Instrument,
+    orders::{any::OrderAny, base::Order},
+    position::Position,
+};
+
+/// Returns the events required to bring the given `order` to the status and filled
+/// quantity of the venue `report`.
+///
+/// The `fills` for the order are applied individually (ignoring those already applied),
+/// with any remaining filled quantity inferred as a single fill priced so the order average
+/// price matches the reported average price. Inference stops at the first event which is
+/// not a valid transition for the order.
+///
+/// # Errors
+///
+/// This function returns an error if an inferred event cannot be constructed.
+pub fn reconcile_order(
+    order: &OrderAny,
+    report: &OrderStatusReport,
+    fills: &[FillReport],
+    instrument: &dyn Instrument,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<OrderEvent>> {
+    let mut events = Vec::new();
+    if order.as_order().status() == report.order_status
+        && order.as_order().filled_qty() == report.filled_qty
+    {
+        return Ok(events); // Already consistent
+    }
+
+    // Events are applied to a copy of the order to validate each transition in turn
+    let mut order = order.clone();
+    let (trader_id, strategy_id, instrument_id, client_order_id) = {
+        let order = order.as_order();
+        (
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+        )
+    };
+
+    if report.order_status == OrderStatus::Rejected {
+        let rejected = OrderRejected::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            report.account_id,
+            Ustr::from("Rejected at venue (reconciliation)"),
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+        )?;
+        apply_event(&mut order, OrderEvent::OrderRejected(rejected), &mut events);
+        return Ok(events);
+    }
+
+    if matches!(
+        order.as_order().status(),
+        OrderStatus::Initialized | OrderStatus::Submitted
+    ) {
+        let accepted = OrderAccepted::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            report.venue_order_id,
+            report.account_id,
+            UUID4::new(),
+            report.ts_accepted,
+            ts_init,
+            true,
+        )?;
+        if !apply_event(&mut order, OrderEvent::OrderAccepted(accepted), &mut events) {
+            return Ok(events);
+        }
+    }
+
+    let mut fills: Vec<&FillReport> = fills.iter().collect();
+    fills.sort_by_key(|fill| fill.ts_event);
+    for fill in fills {
+        if let Some(filled) = reconcile_fill(&order, fill, instrument, ts_init)? {
+            if !apply_event(&mut order, OrderEvent::OrderFilled(filled), &mut events) {
+                return Ok(events);
+            }
+        }
+    }
+
+    if report.filled_qty > order.as_order().filled_qty() {
+        if let Some(filled) = infer_fill(&order, report, instrument, ts_init)? {
+            if !apply_event(&mut order, OrderEvent::OrderFilled(filled), &mut events) {
+                return Ok(events);
+            }
+        }
+    }
+
+    if order.as_order().status() == report.order_status {
+        return Ok(events);
+    }
+
+    let venue_order_id = Some(report.venue_order_id);
+    let account_id = Some(report.account_id);
+    let event = match report.order_status {
+        OrderStatus::Canceled => OrderEvent::OrderCanceled(OrderCanceled::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+            venue_order_id,
+            account_id,
+        )?),
+        OrderStatus::Expired => OrderEvent::OrderExpired(OrderExpired::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+            venue_order_id,
+            account_id,
+        )?),
+        OrderStatus::Triggered => OrderEvent::OrderTriggered(OrderTriggered::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            report.ts_last,
+            ts_init,
+            true,
+            venue_order_id,
+            account_id,
+        )?),
+        _ => return Ok(events),
+    };
+    apply_event(&mut order, event, &mut events);
+
+    Ok(events)
+}
+
+/// Returns the fill event for the given venue `fill` report, or `None` if the trade
+/// has already been applied to the `order`.
+///
+/// # Errors
+///
+/// This function returns an error if the fill event cannot be constructed.
+pub fn reconcile_fill(
+    order: &OrderAny,
+    fill: &FillReport,
+    instrument: &dyn Instrument,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<OrderFilled>> {
+    let order = order.as_order();
+    if order.trade_ids().contains(&&fill.trade_id) {
+        return Ok(None);
+    }
+
+    Ok(Some(OrderFilled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        fill.venue_order_id,
+        fill.account_id,
+        fill.trade_id,
+        order.side(),
+        order.order_type(),
+        fill.last_qty,
+        fill.last_px,
+        instrument.quote_currency(),
+        fill.liquidity_side,
+        UUID4::new(),
+        fill.ts_event,
+        ts_init,
+        true,
+        fill.venue_position_id,
+        Some(fill.commission),
+    )?))
+}
+
+/// Returns the difference between the venue reported position quantity and the net signed
+/// quantity of the cached open `positions` for the same instrument, or `None` if they agree.
+#[must_use]
+pub fn reconcile_position(report: &PositionStatusReport, positions: &[Position]) -> Option<f64> {
+    let cached_qty: f64 = positions
+        .iter()
+        .filter(|position| position.instrument_id == report.instrument_id && position.is_open())
+        .map(|position| position.signed_qty)
+        .sum();
+
+    let diff = report.signed_qty() - cached_qty;
+    if diff.abs() < f64::EPSILON {
+        None
+    } else {
+        Some(diff)
+    }
+}
+
+fn infer_fill(
+    order: &OrderAny,
+    report: &OrderStatusReport,
+    instrument: &dyn Instrument,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<OrderFilled>> {
+    let order = order.as_order();
+    let last_qty = report.filled_qty - order.filled_qty();
+
+    // Price the residual fill so the order average price matches the reported average
+    let px = match (report.avg_px, report.price) {
+        (Some(avg_px), _) => {
+            let filled_notional = order.avg_px().unwrap_or(0.0) * order.filled_qty().as_f64();
+            (avg_px * report.filled_qty.as_f64() - filled_notional) / last_qty.as_f64()
+        }
+        (None, Some(price)) => price.as_f64(),
+        (None, None) => return Ok(None),
+    };
+    let last_px = instrument.make_price(px)?;
+
+    Ok(Some(OrderFilled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        report.venue_order_id,
+        report.account_id,
+        TradeId::from(UUID4::new().to_string().as_str()),
+        order.side(),
+        order.order_type(),
+        last_qty,
+        last_px,
+        instrument.quote_currency(),
+        LiquiditySide::NoLiquiditySide,
+        UUID4::new(),
+        report.ts_last,
+        ts_init,
+        true,
+        None,
+        None,
+    )?))
+}
+
+fn apply_event(order: &mut OrderAny, event: OrderEvent, events: &mut Vec<OrderEvent>) -> bool {
+    if order.as_order_mut().apply(event.clone()).is_err() {
+        return false;
+    }
+    events.push(event);
+    true
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType, PositionSide, TimeInForce},
+        events::order::submitted::OrderSubmitted,
+        identifiers::{
+            account_id::AccountId, instrument_id::InstrumentId, venue_order_id::VenueOrderId,
+        },
+        instruments::{currency_pair::CurrencyPair, stubs::*},
+        orders::stubs::TestOrderStubs,
+        stubs::*,
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+
+    fn submitted_order(instrument: &CurrencyPair) -> OrderAny {
+        let mut order: OrderAny = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            None,
+            None,
+        )
+        .into();
+        let submitted = {
+            let order = order.as_order();
+            OrderSubmitted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                AccountId::from("SIM-001"),
+                UUID4::new(),
+                1,
+                1,
+            )
+            .unwrap()
+        };
+        order
+            .as_order_mut()
+            .apply(OrderEvent::OrderSubmitted(submitted))
+            .unwrap();
+        order
+    }
+
+    fn order_report(order_status: OrderStatus, filled_qty: i64) -> OrderStatusReport {
+        let mut report = OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::from("V-1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100_000),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            2,
+            5,
+            10,
+        );
+        report.avg_px = Some(0.80001);
+        report
+    }
+
+    fn fill_report(trade_id: &str, last_qty: i64) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::from("V-1"),
+            None,
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("0.80000"),
+            Money::new(1.0, Currency::USD()).unwrap(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            3,
+            10,
+        )
+    }
+
+    #[rstest]
+    fn test_reconcile_order_when_consistent(audusd_sim: CurrencyPair) {
+        let order = submitted_order(&audusd_sim);
+        let report = order_report(OrderStatus::Submitted, 0);
+
+        let events = reconcile_order(&order, &report, &[], &audusd_sim, 10).unwrap();
+
+        assert!(events.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_order_rejected(audusd_sim: CurrencyPair) {
+        let order = submitted_order(&audusd_sim);
+        let report = order_report(OrderStatus::Rejected, 0);
+
+        let events = reconcile_order(&order, &report, &[], &audusd_sim, 10).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], OrderEvent::OrderRejected(e) if e.reconciliation == 1));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_fills_then_infers_remaining_fill(audusd_sim: CurrencyPair) {
+        let order = submitted_order(&audusd_sim);
+        let report = order_report(OrderStatus::Filled, 100_000);
+        let fills = [fill_report("T-2", 30_000), fill_report("T-1", 20_000)];
+
+        let events = reconcile_order(&order, &report, &fills, &audusd_sim, 10).unwrap();
+
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], OrderEvent::OrderAccepted(_)));
+        let fills: Vec<(TradeId, Quantity, Price)> = events[1..]
+            .iter()
+            .map(|event| match event {
+                OrderEvent::OrderFilled(fill) => (fill.trade_id, fill.last_qty, fill.last_px),
+                event => panic!("Expected fill, was {event:?}"),
+            })
+            .collect();
+        assert_eq!(fills[0].0, TradeId::from("T-2"));
+        assert_eq!(fills[1].0, TradeId::from("T-1"));
+        assert_eq!(fills[2].1, Quantity::from(50_000));
+        // Residual priced so 50_000 @ 0.80000 and 50_000 @ 0.80002 average 0.80001
+        assert_eq!(fills[2].2, Price::from("0.80002"));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_canceled_after_partial_fill(audusd_sim: CurrencyPair) {
+        let order = submitted_order(&audusd_sim);
+        let report = order_report(OrderStatus::Canceled, 40_000);
+
+        let events = reconcile_order(
+            &order,
+            &report,
+            &[fill_report("T-1", 40_000)],
+            &audusd_sim,
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[2], OrderEvent::OrderCanceled(_)));
+    }
+
+    #[rstest]
+    fn test_reconcile_fill_ignores_applied_trade(audusd_sim: CurrencyPair) {
+        let mut order = submitted_order(&audusd_sim);
+        let fill = fill_report("T-1", 40_000);
+        let filled = reconcile_fill(&order, &fill, &audusd_sim, 10)
+            .unwrap()
+            .unwrap();
+        order
+            .as_order_mut()
+            .apply(OrderEvent::OrderFilled(filled))
+            .unwrap();
+
+        assert!(reconcile_fill(&order, &fill, &audusd_sim, 20)
+            .unwrap()
+            .is_none());
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, 1, None)]
+    #[case(PositionSide::Long, 3, Some(2.0))]
+    #[case(PositionSide::Short, 1, Some(-2.0))]
+    fn test_reconcile_position(
+        test_position_long: Position,
+        #[case] position_side: PositionSide,
+        #[case] quantity: i64,
+        #[case] expected: Option<f64>,
+    ) {
+        let report = PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            test_position_long.instrument_id,
+            position_side,
+            Quantity::from(quantity),
+            None,
+            UUID4::new(),
+            0,
+            0,
+        );
+
+        assert_eq!(reconcile_position(&report, &[test_position_long]), expected);
+    }
+}
@@ -52,6 +52,8 @@ pub fn calculate_commission<T: Instrument>(
     };
     if instrument.is_inverse() && !use_quote_for_inverse.unwrap_or(false) {
         Ok(Money::new(commission, instrument.base_currency().unwrap()).unwrap())
+    } else if instrument.is_quanto() {
+        Ok(Money::new(commission, instrument.settlement_currency()).unwrap())
     } else {
         Ok(Money::new(commission, instrument.quote_currency()).unwrap())
     }
@@ -72,7 +74,7 @@ pub fn test_position_long(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
 
 #[fixture]
@@ -95,5 +97,5 @@ pub fn test_position_short(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
     );
-    Position::new(audusd_sim, order_filled).unwrap()
+    Position::new(&audusd_sim, order_filled).unwrap()
 }
@@ -0,0 +1,446 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{bail, Result};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Timelike, Weekday};
+use chrono_tz::Tz;
+use nautilus_core::{
+    datetime::{local_to_unix_nanos, unix_nanos_to_local, NANOSECONDS_IN_SECOND, WEEKDAYS},
+    time::UnixNanos,
+};
+
+use crate::identifiers::{instrument_id::InstrumentId, venue::Venue};
+
+pub const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+pub const NANOSECONDS_IN_WEEK: u64 = 7 * NANOSECONDS_IN_DAY;
+
+/// The maximum number of weeks to search ahead for the next session.
+const MAX_SEARCH_WEEKS: i64 = 53;
+
+/// Represents a single weekly trading session, in the local time of the timezone of
+/// the [`TradingHours`] it belongs to.
+///
+/// A session with a `close` at or before its `open` is treated as closing on the
+/// following day, which supports sessions spanning midnight (e.g. CME Globex).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TradingSession {
+    /// The day of the week the session opens.
+    pub weekday: Weekday,
+    /// The session open time of day.
+    pub open: NaiveTime,
+    /// The session close time of day.
+    pub close: NaiveTime,
+}
+
+impl TradingSession {
+    #[must_use]
+    pub fn new(weekday: Weekday, open: NaiveTime, close: NaiveTime) -> Self {
+        Self {
+            weekday,
+            open,
+            close,
+        }
+    }
+
+    /// Returns the session as (open, close) nanosecond offsets from Monday 00:00 local time.
+    fn week_offsets(&self) -> (u64, u64) {
+        let day_offset = u64::from(self.weekday.num_days_from_monday()) * NANOSECONDS_IN_DAY;
+        let open = day_offset + time_to_nanos(self.open);
+        let mut close = day_offset + time_to_nanos(self.close);
+        if close <= open {
+            close += NANOSECONDS_IN_DAY;
+        }
+        (open, close)
+    }
+
+    /// Returns the (open, close) UNIX timestamps of the session opening on the local `date`.
+    fn instance(&self, date: NaiveDate, tz: Tz) -> Option<(UnixNanos, UnixNanos)> {
+        let close_date = if self.close <= self.open {
+            date.succ_opt()?
+        } else {
+            date
+        };
+        let open = local_to_unix_nanos(&date.and_time(self.open), tz).ok()?;
+        let close = local_to_unix_nanos(&close_date.and_time(self.close), tz).ok()?;
+        Some((open, close))
+    }
+}
+
+/// Represents the trading hours of a venue or instrument, as a weekly schedule of
+/// sessions with holiday exceptions.
+///
+/// Session times and holiday dates are local to the `timezone`, so sessions keep their
+/// local wall times across DST transitions. A holiday closes every session which *opens*
+/// on that date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingHours {
+    sessions: Vec<TradingSession>,
+    holidays: BTreeSet<NaiveDate>,
+    timezone: Tz,
+}
+
+impl Default for TradingHours {
+    fn default() -> Self {
+        Self {
+            sessions: Vec::new(),
+            holidays: BTreeSet::new(),
+            timezone: Tz::UTC,
+        }
+    }
+}
+
+impl TradingHours {
+    /// Initializes a new [`TradingHours`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any two sessions overlap within the week.
+    pub fn new(
+        sessions: Vec<TradingSession>,
+        holidays: Vec<NaiveDate>,
+        timezone: Tz,
+    ) -> Result<Self> {
+        let mut offsets: Vec<(u64, u64)> =
+            sessions.iter().map(TradingSession::week_offsets).collect();
+        offsets.sort_unstable();
+
+        for pair in offsets.windows(2) {
+            if pair[1].0 < pair[0].1 {
+                bail!("Trading sessions overlap: {:?} and {:?}", pair[0], pair[1]);
+            }
+        }
+        if let (Some(first), Some(last)) = (offsets.first(), offsets.last()) {
+            if offsets.len() > 1 && last.1 > NANOSECONDS_IN_WEEK + first.0 {
+                bail!("Trading sessions overlap across the end of the week");
+            }
+        }
+
+        Ok(Self {
+            sessions,
+            holidays: holidays.into_iter().collect(),
+            timezone,
+        })
+    }
+
+    /// Initializes a new [`TradingHours`] instance with the same session on every weekday.
+    pub fn weekdays(
+        open: NaiveTime,
+        close: NaiveTime,
+        holidays: Vec<NaiveDate>,
+        timezone: Tz,
+    ) -> Result<Self> {
+        let sessions = WEEKDAYS
+            .iter()
+            .map(|weekday| TradingSession::new(*weekday, open, close))
+            .collect();
+        Self::new(sessions, holidays, timezone)
+    }
+
+    #[must_use]
+    pub fn sessions(&self) -> &[TradingSession] {
+        &self.sessions
+    }
+
+    /// Returns the timezone the session times and holidays are local to.
+    #[must_use]
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    #[must_use]
+    pub fn holidays(&self) -> Vec<NaiveDate> {
+        self.holidays.iter().copied().collect()
+    }
+
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns whether the market is open at the given UNIX timestamp (nanoseconds).
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.current_session(ts).is_some()
+    }
+
+    /// Returns the (open, close) of the session in progress at the given timestamp, if any.
+    #[must_use]
+    pub fn current_session(&self, ts: UnixNanos) -> Option<(UnixNanos, UnixNanos)> {
+        let date = self.local_date(ts)?;
+        // Sessions opening on the previous day may still be in progress
+        [date.pred_opt(), Some(date)]
+            .into_iter()
+            .flatten()
+            .flat_map(|date| self.instances(date))
+            .find(|(open, close)| *open <= ts && ts < *close)
+    }
+
+    /// Returns the next session open at or after the given timestamp.
+    ///
+    /// Returns `None` if there are no sessions within the next year.
+    #[must_use]
+    pub fn next_open(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.upcoming_sessions(ts)
+            .map(|(open, _)| open)
+            .find(|open| *open >= ts)
+    }
+
+    /// Returns the next session close at or after the given timestamp.
+    ///
+    /// If a session is in progress this is the close of that session.
+    #[must_use]
+    pub fn next_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.upcoming_sessions(ts)
+            .map(|(_, close)| close)
+            .find(|close| *close >= ts)
+    }
+
+    /// Returns the local date in the trading hours timezone at the given timestamp.
+    fn local_date(&self, ts: UnixNanos) -> Option<NaiveDate> {
+        unix_nanos_to_local(ts, self.timezone)
+            .ok()
+            .map(|local| local.date_naive())
+    }
+
+    /// Returns the session instances opening on the local `date`, in chronological order
+    /// (none if the date is a holiday).
+    fn instances(&self, date: NaiveDate) -> Vec<(UnixNanos, UnixNanos)> {
+        if self.is_holiday(date) {
+            return Vec::new();
+        }
+        let mut instances: Vec<_> = self
+            .sessions
+            .iter()
+            .filter(|session| session.weekday == date.weekday())
+            .filter_map(|session| session.instance(date, self.timezone))
+            .collect();
+        instances.sort_unstable();
+        instances
+    }
+
+    /// Returns session instances in chronological order, starting from the day before `ts`.
+    fn upcoming_sessions(
+        &self,
+        ts: UnixNanos,
+    ) -> impl Iterator<Item = (UnixNanos, UnixNanos)> + '_ {
+        let first_date = self
+            .local_date(ts)
+            .and_then(|date| date.pred_opt().or(Some(date)));
+        (0..=MAX_SEARCH_WEEKS * 7).flat_map(move |days| {
+            first_date
+                .and_then(|date| date.checked_add_signed(Duration::days(days)))
+                .map(|date| self.instances(date))
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Provides a registry of trading hours for venues, with optional per-instrument overrides.
+#[derive(Clone, Debug, Default)]
+pub struct TradingCalendar {
+    venues: HashMap<Venue, TradingHours>,
+    instruments: HashMap<InstrumentId, TradingHours>,
+}
+
+impl TradingCalendar {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_venue(&mut self, venue: Venue, trading_hours: TradingHours) {
+        self.venues.insert(venue, trading_hours);
+    }
+
+    pub fn add_instrument(&mut self, instrument_id: InstrumentId, trading_hours: TradingHours) {
+        self.instruments.insert(instrument_id, trading_hours);
+    }
+
+    /// Returns the trading hours for the given instrument, falling back to its venue.
+    #[must_use]
+    pub fn get(&self, instrument_id: &InstrumentId) -> Option<&TradingHours> {
+        self.instruments
+            .get(instrument_id)
+            .or_else(|| self.venues.get(&instrument_id.venue))
+    }
+
+    /// Returns whether the market for the given instrument is open at `ts`.
+    ///
+    /// Instruments without configured trading hours are considered always open.
+    #[must_use]
+    pub fn is_open(&self, instrument_id: &InstrumentId, ts: UnixNanos) -> bool {
+        self.get(instrument_id)
+            .map_or(true, |hours| hours.is_open(ts))
+    }
+}
+
+fn time_to_nanos(time: NaiveTime) -> u64 {
+    u64::from(time.num_seconds_from_midnight()) * NANOSECONDS_IN_SECOND
+        + u64::from(time.nanosecond())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono_tz::America::New_York;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ts(date: &str, time: &str) -> UnixNanos {
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        let time = NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        date.and_time(time).and_utc().timestamp_nanos_opt().unwrap() as UnixNanos
+    }
+
+    fn hm(time: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(time, "%H:%M").unwrap()
+    }
+
+    fn equity_hours() -> TradingHours {
+        // 2024-01-01 was a Monday
+        TradingHours::weekdays(
+            hm("14:30"),
+            hm("21:00"),
+            vec![NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()],
+            Tz::UTC,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("2024-01-02", "15:00", true)]
+    #[case("2024-01-02", "14:29", false)]
+    #[case("2024-01-02", "21:00", false)]
+    #[case("2024-01-06", "15:00", false)] // Saturday
+    #[case("2024-01-01", "15:00", false)] // Holiday
+    fn test_is_open(#[case] date: &str, #[case] time: &str, #[case] expected: bool) {
+        assert_eq!(equity_hours().is_open(ts(date, time)), expected);
+    }
+
+    #[rstest]
+    fn test_next_open_skips_weekend_and_holidays() {
+        let hours = equity_hours();
+
+        assert_eq!(
+            hours.next_open(ts("2023-12-29", "22:00")),
+            Some(ts("2024-01-02", "14:30"))
+        );
+        assert_eq!(
+            hours.next_open(ts("2024-01-02", "14:30")),
+            Some(ts("2024-01-02", "14:30"))
+        );
+    }
+
+    #[rstest]
+    fn test_next_close_during_session() {
+        let hours = equity_hours();
+
+        assert_eq!(
+            hours.next_close(ts("2024-01-02", "15:00")),
+            Some(ts("2024-01-02", "21:00"))
+        );
+        assert_eq!(
+            hours.next_close(ts("2024-01-02", "22:00")),
+            Some(ts("2024-01-03", "21:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_session_spanning_midnight_and_week() {
+        // FX style: Sunday 22:00 open through to Friday 22:00, with a daily break
+        let mut sessions = vec![TradingSession::new(Weekday::Sun, hm("22:00"), hm("21:00"))];
+        for weekday in [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu] {
+            sessions.push(TradingSession::new(weekday, hm("22:00"), hm("21:00")));
+        }
+        let hours = TradingHours::new(sessions, vec![], Tz::UTC).unwrap();
+
+        assert!(hours.is_open(ts("2024-01-01", "03:00"))); // Monday morning
+        assert!(!hours.is_open(ts("2024-01-02", "21:30"))); // Daily break
+        assert!(!hours.is_open(ts("2024-01-06", "12:00"))); // Saturday
+        assert_eq!(
+            hours.next_close(ts("2024-01-05", "12:00")),
+            Some(ts("2024-01-05", "21:00"))
+        );
+        assert_eq!(
+            hours.next_open(ts("2024-01-05", "21:30")),
+            Some(ts("2024-01-07", "22:00"))
+        );
+    }
+
+    #[rstest]
+    fn test_overlapping_sessions_error() {
+        let sessions = vec![
+            TradingSession::new(Weekday::Mon, hm("09:00"), hm("17:00")),
+            TradingSession::new(Weekday::Mon, hm("16:00"), hm("18:00")),
+        ];
+        assert!(TradingHours::new(sessions, vec![], Tz::UTC).is_err());
+    }
+
+    #[rstest]
+    fn test_local_sessions_follow_dst() {
+        // NYSE hours 09:30-16:00 New York, which is 14:30 UTC in winter (EST)
+        // and 13:30 UTC in summer (EDT), with US DST starting on 2024-03-10
+        let hours = TradingHours::weekdays(
+            hm("09:30"),
+            hm("16:00"),
+            vec![NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()],
+            New_York,
+        )
+        .unwrap();
+
+        assert_eq!(hours.timezone(), New_York);
+        assert!(!hours.is_open(ts("2024-03-08", "14:00")));
+        assert!(hours.is_open(ts("2024-03-08", "14:30")));
+        assert!(hours.is_open(ts("2024-03-11", "14:00")));
+        assert_eq!(
+            hours.next_open(ts("2024-03-08", "22:00")),
+            Some(ts("2024-03-11", "13:30"))
+        );
+        assert_eq!(
+            hours.next_close(ts("2024-03-11", "14:00")),
+            Some(ts("2024-03-11", "20:00"))
+        );
+        // Holidays are local dates
+        assert!(!hours.is_open(ts("2024-07-04", "15:00")));
+    }
+
+    #[rstest]
+    fn test_calendar_instrument_overrides_venue() {
+        let mut calendar = TradingCalendar::new();
+        let instrument_id = InstrumentId::from("ESH4.GLBX");
+        calendar.add_venue(Venue::from("GLBX"), equity_hours());
+
+        assert!(!calendar.is_open(&instrument_id, ts("2024-01-06", "12:00")));
+        assert!(calendar.is_open(&InstrumentId::from("AAPL.XNAS"), 0));
+
+        let sessions = vec![TradingSession::new(Weekday::Sat, hm("00:00"), hm("23:59"))];
+        calendar.add_instrument(
+            instrument_id,
+            TradingHours::new(sessions, vec![], Tz::UTC).unwrap(),
+        );
+
+        assert!(calendar.is_open(&instrument_id, ts("2024-01-06", "12:00")));
+    }
+}
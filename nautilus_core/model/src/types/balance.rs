@@ -15,11 +15,11 @@
 
 use std::fmt::{Display, Formatter};
 
-use anyhow::Result;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    error::ModelError,
     identifiers::instrument_id::InstrumentId,
     types::{currency::Currency, money::Money},
 };
@@ -37,7 +37,12 @@ pub struct AccountBalance {
 }
 
 impl AccountBalance {
-    pub fn new(total: Money, locked: Money, free: Money) -> Result<Self> {
+    pub fn new(total: Money, locked: Money, free: Money) -> Result<Self, ModelError> {
+        if locked.currency != total.currency || free.currency != total.currency {
+            return Err(ModelError::CurrencyMismatch(format!(
+                "Condition failed: balance currencies were not equal, total={total}, locked={locked}, free={free}"
+            )));
+        }
         if total != locked + free {
             panic!(
                 "Total balance is not equal to the sum of locked and free balances: {} != {} + {}",
@@ -82,7 +87,16 @@ pub struct MarginBalance {
 }
 
 impl MarginBalance {
-    pub fn new(initial: Money, maintenance: Money, instrument_id: InstrumentId) -> Result<Self> {
+    pub fn new(
+        initial: Money,
+        maintenance: Money,
+        instrument_id: InstrumentId,
+    ) -> Result<Self, ModelError> {
+        if maintenance.currency != initial.currency {
+            return Err(ModelError::CurrencyMismatch(format!(
+                "Condition failed: margin currencies were not equal, initial={initial}, maintenance={maintenance}"
+            )));
+        }
         Ok(Self {
             initial,
             maintenance,
@@ -117,9 +131,15 @@ impl PartialEq for MarginBalance {
 mod tests {
     use rstest::rstest;
 
-    use crate::types::{
-        balance::{AccountBalance, MarginBalance},
-        stubs::{account_balance_test, margin_balance_test},
+    use crate::{
+        error::ModelError,
+        identifiers::instrument_id::InstrumentId,
+        types::{
+            balance::{AccountBalance, MarginBalance},
+            currency::Currency,
+            money::Money,
+            stubs::{account_balance_test, margin_balance_test},
+        },
     };
 
     #[rstest]
@@ -138,6 +158,15 @@ mod tests {
         )
     }
 
+    #[rstest]
+    fn test_account_balance_currency_mismatch() {
+        let total = Money::new(100.0, Currency::USD()).unwrap();
+        let locked = Money::new(0.0, Currency::USD()).unwrap();
+        let free = Money::new(100.0, Currency::EUR()).unwrap();
+        let result = AccountBalance::new(total, locked, free);
+        assert!(matches!(result, Err(ModelError::CurrencyMismatch(_))));
+    }
+
     #[rstest]
     fn test_margin_balance_equality() {
         let margin_balance_1 = margin_balance_test();
@@ -153,4 +182,13 @@ mod tests {
             display
         )
     }
+
+    #[rstest]
+    fn test_margin_balance_currency_mismatch() {
+        let initial = Money::new(5000.0, Currency::USD()).unwrap();
+        let maintenance = Money::new(20000.0, Currency::EUR()).unwrap();
+        let instrument_id = InstrumentId::from("BTCUSDT.COINBASE");
+        let result = MarginBalance::new(initial, maintenance, instrument_id);
+        assert!(matches!(result, Err(ModelError::CurrencyMismatch(_))));
+    }
 }
@@ -25,7 +25,7 @@ use serde::{Deserialize, Serialize, Serializer};
 use ustr::Ustr;
 
 use super::fixed::check_fixed_precision;
-use crate::{currencies::CURRENCY_MAP, enums::CurrencyType};
+use crate::{currencies::CURRENCY_MAP, enums::CurrencyType, error::ModelError};
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq)]
@@ -48,9 +48,9 @@ impl Currency {
         iso4217: u16,
         name: &str,
         currency_type: CurrencyType,
-    ) -> Result<Self> {
-        check_valid_string(code, "`Currency` code")?;
-        check_valid_string(name, "`Currency` name")?;
+    ) -> Result<Self, ModelError> {
+        check_valid_string(code, "`Currency` code").map_err(ModelError::invalid_identifier)?;
+        check_valid_string(name, "`Currency` name").map_err(ModelError::invalid_argument)?;
         check_fixed_precision(precision)?;
 
         Ok(Self {
@@ -13,14 +13,16 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use anyhow::{bail, Result};
+use crate::{enums::RoundingMode, error::ModelError};
 
 pub const FIXED_PRECISION: u8 = 9;
 pub const FIXED_SCALAR: f64 = 1_000_000_000.0; // 10.0**FIXED_PRECISION
 
-pub fn check_fixed_precision(precision: u8) -> Result<()> {
+pub fn check_fixed_precision(precision: u8) -> Result<(), ModelError> {
     if precision > FIXED_PRECISION {
-        bail!("Condition failed: `precision` was greater than the maximum `FIXED_PRECISION` (9), was {precision}")
+        return Err(ModelError::OutOfRange(format!(
+            "Condition failed: `precision` was greater than the maximum `FIXED_PRECISION` (9), was {precision}"
+        )));
     }
     Ok(())
 }
@@ -43,6 +45,44 @@ pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
     rounded * pow2
 }
 
+/// Returns the given `value` scaled to `precision` and rounded with the given `mode`.
+///
+/// The scaled value is first snapped to the maximum fixed precision, so that the rounding
+/// applies to the decimal value rather than its binary approximation (such as 4.35 being
+/// represented as 4.3499999999999996).
+fn round_scaled(value: f64, precision: u8, mode: RoundingMode) -> f64 {
+    let pow1 = 10_i64.pow(u32::from(precision)) as f64;
+    let pow2 = 10_i64.pow(u32::from(FIXED_PRECISION - precision)) as f64;
+    let scaled = (value * pow1 * pow2).round() / pow2;
+    match mode {
+        RoundingMode::HalfUp => scaled.round(),
+        RoundingMode::HalfEven => {
+            let rounded = scaled.round();
+            if (scaled - scaled.trunc()).abs() == 0.5 && rounded % 2.0 != 0.0 {
+                rounded - scaled.signum()
+            } else {
+                rounded
+            }
+        }
+        RoundingMode::Floor => scaled.floor(),
+        RoundingMode::Ceiling => scaled.ceil(),
+    }
+}
+
+#[must_use]
+pub fn f64_to_fixed_i64_with_rounding(value: f64, precision: u8, mode: RoundingMode) -> i64 {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let pow2 = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
+    round_scaled(value, precision, mode) as i64 * pow2
+}
+
+#[must_use]
+pub fn f64_to_fixed_u64_with_rounding(value: f64, precision: u8, mode: RoundingMode) -> u64 {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let pow2 = 10_u64.pow(u32::from(FIXED_PRECISION - precision));
+    round_scaled(value, precision, mode) as u64 * pow2
+}
+
 #[must_use]
 pub fn fixed_i64_to_f64(value: i64) -> f64 {
     (value as f64) / FIXED_SCALAR
@@ -161,6 +201,54 @@ mod tests {
         assert_eq!(f64_to_fixed_u64(value, precision), expected);
     }
 
+    #[rstest]
+    #[case(2, 4.35, RoundingMode::HalfUp, 4_350_000_000)]
+    #[case(2, 4.35, RoundingMode::Floor, 4_350_000_000)]
+    #[case(2, 4.35, RoundingMode::Ceiling, 4_350_000_000)]
+    #[case(2, 1.005, RoundingMode::HalfUp, 1_010_000_000)]
+    #[case(2, 1.005, RoundingMode::HalfEven, 1_000_000_000)]
+    #[case(2, 1.015, RoundingMode::HalfEven, 1_020_000_000)]
+    #[case(2, 1.006, RoundingMode::HalfEven, 1_010_000_000)]
+    #[case(2, 1.009, RoundingMode::Floor, 1_000_000_000)]
+    #[case(2, 1.001, RoundingMode::Ceiling, 1_010_000_000)]
+    #[case(0, 2.5, RoundingMode::HalfUp, 3_000_000_000)]
+    #[case(0, 2.5, RoundingMode::HalfEven, 2_000_000_000)]
+    #[case(0, 3.5, RoundingMode::HalfEven, 4_000_000_000)]
+    #[case(0, -2.5, RoundingMode::HalfUp, -3_000_000_000)]
+    #[case(0, -2.5, RoundingMode::HalfEven, -2_000_000_000)]
+    #[case(0, -2.5, RoundingMode::Floor, -3_000_000_000)]
+    #[case(0, -2.5, RoundingMode::Ceiling, -2_000_000_000)]
+    #[case(9, 0.000_000_001, RoundingMode::Floor, 1)]
+    fn test_f64_to_fixed_i64_with_rounding(
+        #[case] precision: u8,
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(
+            f64_to_fixed_i64_with_rounding(value, precision, mode),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(3, 0.123_45, RoundingMode::HalfUp, 123_000_000)]
+    #[case(3, 0.123_5, RoundingMode::HalfEven, 124_000_000)]
+    #[case(3, 0.124_5, RoundingMode::HalfEven, 124_000_000)]
+    #[case(3, 0.123_9, RoundingMode::Floor, 123_000_000)]
+    #[case(3, 0.123_1, RoundingMode::Ceiling, 124_000_000)]
+    fn test_f64_to_fixed_u64_with_rounding(
+        #[case] precision: u8,
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            f64_to_fixed_u64_with_rounding(value, precision, mode),
+            expected
+        );
+    }
+
     #[rstest]
     fn test_fixed_i64_to_f64(
         #[values(1, -1, 2, -2, 10, -10, 100, -100, 1_000, -1_000)] value: i64,
@@ -21,7 +21,6 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::Result;
 use nautilus_core::correctness::check_f64_in_range_inclusive;
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -29,9 +28,12 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::FIXED_PRECISION;
-use crate::types::{
-    currency::Currency,
-    fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+use crate::{
+    error::ModelError,
+    types::{
+        currency::Currency,
+        fixed::{f64_to_fixed_i64, fixed_i64_to_f64},
+    },
 };
 
 pub const MONEY_MAX: f64 = 9_223_372_036.0;
@@ -49,8 +51,9 @@ pub struct Money {
 }
 
 impl Money {
-    pub fn new(amount: f64, currency: Currency) -> Result<Self> {
-        check_f64_in_range_inclusive(amount, MONEY_MIN, MONEY_MAX, "`Money` amount")?;
+    pub fn new(amount: f64, currency: Currency) -> Result<Self, ModelError> {
+        check_f64_in_range_inclusive(amount, MONEY_MIN, MONEY_MAX, "`Money` amount")
+            .map_err(ModelError::out_of_range)?;
 
         Ok(Self {
             raw: f64_to_fixed_i64(amount, currency.precision),
@@ -111,7 +114,7 @@ impl FromStr for Money {
         // Parse currency
         let currency = Currency::from_str(parts[1]).map_err(|e: anyhow::Error| e.to_string())?;
 
-        Self::new(amount, currency).map_err(|e: anyhow::Error| e.to_string())
+        Self::new(amount, currency).map_err(|e| e.to_string())
     }
 }
 
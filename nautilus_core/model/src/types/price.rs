@@ -21,7 +21,6 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::Result;
 use nautilus_core::{correctness::check_f64_in_range_inclusive, parsing::precision_from_str};
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -29,7 +28,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use crate::{
+    enums::RoundingMode,
+    error::ModelError,
+    types::fixed::{f64_to_fixed_i64, f64_to_fixed_i64_with_rounding, fixed_i64_to_f64},
+};
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
@@ -52,8 +55,9 @@ pub struct Price {
 }
 
 impl Price {
-    pub fn new(value: f64, precision: u8) -> Result<Self> {
-        check_f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value")?;
+    pub fn new(value: f64, precision: u8) -> Result<Self, ModelError> {
+        check_f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value")
+            .map_err(ModelError::out_of_range)?;
         check_fixed_precision(precision)?;
 
         Ok(Self {
@@ -62,7 +66,24 @@ impl Price {
         })
     }
 
-    pub fn from_raw(raw: i64, precision: u8) -> Result<Self> {
+    /// Creates a new [`Price`] from the given `value`, rounded to `precision` with the given
+    /// rounding `mode`.
+    pub fn new_with_rounding(
+        value: f64,
+        precision: u8,
+        mode: RoundingMode,
+    ) -> Result<Self, ModelError> {
+        check_f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value")
+            .map_err(ModelError::out_of_range)?;
+        check_fixed_precision(precision)?;
+
+        Ok(Self {
+            raw: f64_to_fixed_i64_with_rounding(value, precision, mode),
+            precision,
+        })
+    }
+
+    pub fn from_raw(raw: i64, precision: u8) -> Result<Self, ModelError> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
     }
@@ -122,8 +143,7 @@ impl FromStr for Price {
             .parse::<f64>()
             .map_err(|err| format!("Cannot parse `input` string '{input}' as f64: {err}"))?;
 
-        Self::new(float_from_input, precision_from_str(input))
-            .map_err(|e: anyhow::Error| e.to_string())
+        Self::new(float_from_input, precision_from_str(input)).map_err(|e| e.to_string())
     }
 }
 
@@ -308,6 +328,12 @@ mod tests {
         let _ = Price::new(1.0, 10).unwrap();
     }
 
+    #[rstest]
+    fn test_invalid_precision_is_out_of_range_error() {
+        let result = Price::new(1.0, 10);
+        assert!(matches!(result, Err(ModelError::OutOfRange(_))));
+    }
+
     #[rstest]
     #[should_panic(expected = "Condition failed: `precision` was greater than the maximum ")]
     fn test_invalid_precision_from_raw() {
@@ -349,6 +375,17 @@ mod tests {
         assert!(approx_eq!(f64, price.as_f64(), 0.00812, epsilon = 0.000001));
     }
 
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "1.24")]
+    #[case(RoundingMode::HalfEven, "1.24")]
+    #[case(RoundingMode::Floor, "1.23")]
+    #[case(RoundingMode::Ceiling, "1.24")]
+    fn test_new_with_rounding(#[case] mode: RoundingMode, #[case] expected: &str) {
+        let price = Price::new_with_rounding(1.235, 2, mode).unwrap();
+        assert_eq!(price, Price::from(expected));
+        assert_eq!(price.precision, 2);
+    }
+
     #[rstest]
     fn test_with_maximum_value() {
         let price = Price::new(PRICE_MAX, 9).unwrap();
@@ -21,7 +21,6 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::{bail, Result};
 use nautilus_core::{correctness::check_f64_in_range_inclusive, parsing::precision_from_str};
 use pyo3::prelude::*;
 use rust_decimal::Decimal;
@@ -29,7 +28,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thousands::Separable;
 
 use super::fixed::{check_fixed_precision, FIXED_PRECISION, FIXED_SCALAR};
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use crate::{
+    enums::RoundingMode,
+    error::ModelError,
+    types::fixed::{f64_to_fixed_u64, f64_to_fixed_u64_with_rounding, fixed_u64_to_f64},
+};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
@@ -46,8 +49,9 @@ pub struct Quantity {
 }
 
 impl Quantity {
-    pub fn new(value: f64, precision: u8) -> Result<Self> {
-        check_f64_in_range_inclusive(value, QUANTITY_MIN, QUANTITY_MAX, "`Quantity` value")?;
+    pub fn new(value: f64, precision: u8) -> Result<Self, ModelError> {
+        check_f64_in_range_inclusive(value, QUANTITY_MIN, QUANTITY_MAX, "`Quantity` value")
+            .map_err(ModelError::out_of_range)?;
         check_fixed_precision(precision)?;
 
         Ok(Self {
@@ -56,7 +60,24 @@ impl Quantity {
         })
     }
 
-    pub fn from_raw(raw: u64, precision: u8) -> Result<Self> {
+    /// Creates a new [`Quantity`] from the given `value`, rounded to `precision` with the given
+    /// rounding `mode`.
+    pub fn new_with_rounding(
+        value: f64,
+        precision: u8,
+        mode: RoundingMode,
+    ) -> Result<Self, ModelError> {
+        check_f64_in_range_inclusive(value, QUANTITY_MIN, QUANTITY_MAX, "`Quantity` value")
+            .map_err(ModelError::out_of_range)?;
+        check_fixed_precision(precision)?;
+
+        Ok(Self {
+            raw: f64_to_fixed_u64_with_rounding(value, precision, mode),
+            precision,
+        })
+    }
+
+    pub fn from_raw(raw: u64, precision: u8) -> Result<Self, ModelError> {
         check_fixed_precision(precision)?;
         Ok(Self { raw, precision })
     }
@@ -115,8 +136,7 @@ impl FromStr for Quantity {
             .parse::<f64>()
             .map_err(|e| format!("Cannot parse `input` string '{input}' as f64: {e}"))?;
 
-        Self::new(float_from_input, precision_from_str(input))
-            .map_err(|e: anyhow::Error| e.to_string())
+        Self::new(float_from_input, precision_from_str(input)).map_err(|e| e.to_string())
     }
 }
 
@@ -279,9 +299,11 @@ impl<'de> Deserialize<'de> for Quantity {
     }
 }
 
-pub fn check_quantity_positive(value: Quantity) -> Result<()> {
+pub fn check_quantity_positive(value: Quantity) -> Result<(), ModelError> {
     if !value.is_positive() {
-        bail!("Condition failed: invalid `Quantity`, should be positive and was {value}")
+        return Err(ModelError::OutOfRange(format!(
+            "Condition failed: invalid `Quantity`, should be positive and was {value}"
+        )));
     }
     Ok(())
 }
@@ -341,6 +363,17 @@ mod tests {
         assert!(approx_eq!(f64, qty.as_f64(), 0.00812, epsilon = 0.000001));
     }
 
+    #[rstest]
+    #[case(RoundingMode::HalfUp, "1.24")]
+    #[case(RoundingMode::HalfEven, "1.24")]
+    #[case(RoundingMode::Floor, "1.23")]
+    #[case(RoundingMode::Ceiling, "1.24")]
+    fn test_new_with_rounding(#[case] mode: RoundingMode, #[case] expected: &str) {
+        let qty = Quantity::new_with_rounding(1.235, 2, mode).unwrap();
+        assert_eq!(qty, Quantity::from(expected));
+        assert_eq!(qty.precision, 2);
+    }
+
     #[rstest]
     fn test_zero() {
         let qty = Quantity::zero(8);
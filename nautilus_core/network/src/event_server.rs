@@ -0,0 +1,509 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A WebSocket server for streaming message bus events to dashboards and monitoring UIs.
+//!
+//! Clients authenticate during the handshake with a token, passed either as an
+//! `Authorization: Bearer <token>` header or as a `token` query parameter (for browsers
+//! which cannot set handshake headers). Once connected a client manages its
+//! subscriptions with JSON text messages:
+//!
+//! ```json
+//! {"op": "subscribe", "topic": "events.order.*"}
+//! {"op": "unsubscribe", "topic": "events.order.*"}
+//! ```
+//!
+//! Topics follow message bus pattern matching, and each published event is sent to
+//! every client with a matching subscription as `{"topic": ..., "payload": ...}`.
+//!
+//! Events are either published directly with [`EventServer::publish`], or forwarded from
+//! a [`MessageBus`] topic with [`EventServer::bridge`].
+
+use std::{any::Any, net::SocketAddr, sync::Arc, time::Duration};
+
+use futures_util::{SinkExt, StreamExt};
+use nautilus_common::{
+    handlers::{MessageHandler, SafeAnyCallback},
+    msgbus::{is_matching, MessageBus},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast::{self, error::RecvError},
+    task, time,
+};
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::{
+        handshake::server::{ErrorResponse, Request, Response},
+        http::{header::AUTHORIZATION, StatusCode},
+        Message,
+    },
+};
+use tracing::{debug, error, warn};
+use ustr::Ustr;
+
+/// Configuration for an [`EventServer`].
+#[derive(Debug, Clone)]
+pub struct EventServerConfig {
+    /// The address to bind the server to.
+    pub addr: SocketAddr,
+    /// The tokens accepted for client authentication.
+    pub auth_tokens: Vec<String>,
+    /// The number of events buffered per client before it lags and events are dropped.
+    pub buffer_size: usize,
+    /// The time allowed for a client to complete the WebSocket handshake.
+    pub handshake_timeout: Duration,
+}
+
+impl Default for EventServerConfig {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            auth_tokens: Vec::new(),
+            buffer_size: 10_000,
+            handshake_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ClientRequest {
+    Subscribe { topic: String },
+    Unsubscribe { topic: String },
+}
+
+#[derive(Debug)]
+struct ServerEvent {
+    topic: Ustr,
+    text: String,
+}
+
+/// Provides a WebSocket server which streams published events to authenticated clients
+/// subscribed to matching topics.
+///
+/// Events are JSON encoded once on [`EventServer::publish`] and shared between clients.
+/// The server stops accepting connections, and closes existing ones, when dropped.
+pub struct EventServer {
+    addr: SocketAddr,
+    tx: broadcast::Sender<Arc<ServerEvent>>,
+    accept_task: task::JoinHandle<()>,
+}
+
+impl EventServer {
+    /// Binds and starts a new [`EventServer`] with the given `config`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - No auth tokens are configured.
+    /// - The server cannot bind to the configured address.
+    pub async fn start(config: EventServerConfig) -> anyhow::Result<Self> {
+        if config.auth_tokens.is_empty() {
+            anyhow::bail!("At least one auth token is required for the event server");
+        }
+
+        let listener = TcpListener::bind(config.addr).await?;
+        let addr = listener.local_addr()?;
+        let (tx, _) = broadcast::channel(config.buffer_size);
+        let tokens = Arc::new(config.auth_tokens);
+        let handshake_timeout = config.handshake_timeout;
+
+        let accept_tx = tx.clone();
+        let accept_task = task::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        debug!("Event server connection from {peer}");
+                        task::spawn(handle_connection(
+                            stream,
+                            tokens.clone(),
+                            accept_tx.clone(),
+                            handshake_timeout,
+                        ));
+                    }
+                    Err(e) => error!("Error accepting event server connection: {e}"),
+                }
+            }
+        });
+
+        Ok(Self {
+            addr,
+            tx,
+            accept_task,
+        })
+    }
+
+    /// Returns the address the server is bound to.
+    #[must_use]
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the count of connected clients.
+    #[must_use]
+    pub fn client_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+
+    /// Publishes the `payload` on the given `topic` to all subscribed clients.
+    pub fn publish(&self, topic: &str, payload: &Value) {
+        send_event(&self.tx, topic, payload);
+    }
+
+    /// Subscribes the server to the `topic` on the given message bus, forwarding every
+    /// message of type `T` published on it to subscribed clients.
+    ///
+    /// Message bus handlers do not receive the topic a message was published on, so
+    /// messages are forwarded on the bridged `topic` (which may be a pattern).
+    pub fn bridge<T: Serialize + 'static>(&self, msgbus: &mut MessageBus, topic: &str) {
+        let tx = self.tx.clone();
+        let event_topic = topic.to_string();
+        let callback = move |message: &dyn Any| {
+            if tx.receiver_count() == 0 {
+                return; // No clients to encode for
+            }
+            let Some(message) = message.downcast_ref::<T>() else {
+                return; // Another message type published on the topic
+            };
+            match serde_json::to_value(message) {
+                Ok(payload) => send_event(&tx, &event_topic, &payload),
+                Err(e) => error!("Error encoding event server message on {event_topic}: {e}"),
+            }
+        };
+        let handler = MessageHandler::with_any_callback(
+            Ustr::from(&format!("EventServer-{}-{topic}", self.addr)),
+            SafeAnyCallback {
+                callback: Arc::new(callback),
+            },
+        );
+        msgbus.subscribe(topic, handler, None);
+    }
+}
+
+impl Drop for EventServer {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+fn send_event(tx: &broadcast::Sender<Arc<ServerEvent>>, topic: &str, payload: &Value) {
+    if tx.receiver_count() == 0 {
+        return; // No clients to encode for
+    }
+
+    let event = ServerEvent {
+        topic: Ustr::from(topic),
+        text: json!({ "topic": topic, "payload": payload }).to_string(),
+    };
+    // An error only means every client disconnected since the check
+    let _ = tx.send(Arc::new(event));
+}
+
+/// Compares the given byte slices in time independent of their contents, so a token
+/// cannot be guessed from how long its comparison takes.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn is_authorized(request: &Request, tokens: &[String]) -> bool {
+    let header_token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let query_token = request.uri().query().and_then(|query| {
+        query
+            .split('&')
+            .find_map(|param| param.strip_prefix("token="))
+    });
+
+    header_token.into_iter().chain(query_token).any(|token| {
+        // Compare against every token so the match position is not observable
+        tokens.iter().fold(false, |found, expected| {
+            constant_time_eq(token.as_bytes(), expected.as_bytes()) | found
+        })
+    })
+}
+
+fn handle_request(text: &str, patterns: &mut Vec<Ustr>) -> Value {
+    match serde_json::from_str::<ClientRequest>(text) {
+        Ok(ClientRequest::Subscribe { topic }) => {
+            let pattern = Ustr::from(&topic);
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+            json!({ "op": "subscribed", "topic": topic })
+        }
+        Ok(ClientRequest::Unsubscribe { topic }) => {
+            let pattern = Ustr::from(&topic);
+            patterns.retain(|p| *p != pattern);
+            json!({ "op": "unsubscribed", "topic": topic })
+        }
+        Err(e) => json!({ "op": "error", "message": e.to_string() }),
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    tokens: Arc<Vec<String>>,
+    tx: broadcast::Sender<Arc<ServerEvent>>,
+    handshake_timeout: Duration,
+) {
+    let callback = |request: &Request, response: Response| -> Result<Response, ErrorResponse> {
+        if is_authorized(request, &tokens) {
+            Ok(response)
+        } else {
+            let mut error = ErrorResponse::new(Some("Unauthorized".to_string()));
+            *error.status_mut() = StatusCode::UNAUTHORIZED;
+            Err(error)
+        }
+    };
+
+    let ws_stream = match time::timeout(handshake_timeout, accept_hdr_async(stream, callback)).await
+    {
+        Ok(Ok(ws_stream)) => ws_stream,
+        Ok(Err(e)) => {
+            debug!("Event server handshake rejected: {e}");
+            return;
+        }
+        Err(_) => {
+            debug!("Event server handshake timed out");
+            return;
+        }
+    };
+
+    // Only authenticated clients receive (and are counted for) events
+    let mut rx = tx.subscribe();
+    let (mut writer, mut reader) = ws_stream.split();
+    let mut patterns: Vec<Ustr> = Vec::new();
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Ok(event) => {
+                    if !patterns.iter().any(|pattern| is_matching(&event.topic, pattern)) {
+                        continue;
+                    }
+                    if let Err(e) = writer.send(Message::Text(event.text.clone())).await {
+                        debug!("Event server client disconnected: {e}");
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Event server client lagging, dropped {skipped} events");
+                }
+                Err(RecvError::Closed) => break, // Server dropped
+            },
+            msg = reader.next() => match msg {
+                Some(Ok(Message::Text(text))) => {
+                    let reply = handle_request(&text, &mut patterns);
+                    if let Err(e) = writer.send(Message::Text(reply.to_string())).await {
+                        debug!("Event server client disconnected: {e}");
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {} // Pings are answered by the protocol layer
+                Some(Err(e)) => {
+                    debug!("Event server client error: {e}");
+                    break;
+                }
+            },
+        }
+    }
+
+    let _ = writer.close().await;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::uuid::UUID4;
+    use tokio::io::AsyncReadExt;
+    use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+    use super::*;
+
+    type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    async fn start_server() -> EventServer {
+        let config = EventServerConfig {
+            auth_tokens: vec!["secret".to_string()],
+            ..Default::default()
+        };
+        EventServer::start(config).await.unwrap()
+    }
+
+    async fn next_json(client: &mut Client) -> Value {
+        match client.next().await.unwrap().unwrap() {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("Unexpected message {other:?}"),
+        }
+    }
+
+    async fn subscribe(client: &mut Client, topic: &str) {
+        let request = json!({ "op": "subscribe", "topic": topic }).to_string();
+        client.send(Message::Text(request)).await.unwrap();
+        assert_eq!(next_json(client).await["op"], "subscribed");
+    }
+
+    #[tokio::test]
+    async fn test_start_without_auth_tokens_errors() {
+        let result = EventServer::start(EventServerConfig::default()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connect_without_token_is_rejected() {
+        let server = start_server().await;
+        let url = format!("ws://{}", server.local_addr());
+
+        let result = connect_async(url).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_wrong_token_is_rejected() {
+        let server = start_server().await;
+        let url = format!("ws://{}/?token=wrong", server.local_addr());
+
+        let result = connect_async(url).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_connection_is_not_a_client() {
+        let server = start_server().await;
+        let url = format!("ws://{}/?token=wrong", server.local_addr());
+
+        let _ = connect_async(url).await;
+
+        assert_eq!(server.client_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_handshake_times_out() {
+        let config = EventServerConfig {
+            auth_tokens: vec!["secret".to_string()],
+            handshake_timeout: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let server = EventServer::start(config).await.unwrap();
+        let mut stream = TcpStream::connect(server.local_addr()).await.unwrap();
+
+        // The server closes the connection without a handshake being sent
+        let mut buf = [0; 16];
+        let read = time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+            .await
+            .unwrap();
+
+        assert!(matches!(read, Ok(0) | Err(_)));
+        assert_eq!(server.client_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_bridge_forwards_message_bus_events() {
+        let server = start_server().await;
+        let mut msgbus = MessageBus::new("TRADER-001".into(), UUID4::new(), None, None);
+        server.bridge::<Value>(&mut msgbus, "events.order.S-001");
+        let url = format!("ws://{}/?token=secret", server.local_addr());
+        let (mut client, _) = connect_async(url).await.unwrap();
+        subscribe(&mut client, "events.order.*").await;
+
+        msgbus.publish("events.order.S-001", &"not forwarded");
+        msgbus.publish("events.order.S-001", &json!({ "type": "OrderFilled" }));
+
+        let event = next_json(&mut client).await;
+        assert_eq!(event["topic"], "events.order.S-001");
+        assert_eq!(event["payload"]["type"], "OrderFilled");
+    }
+
+    #[tokio::test]
+    async fn test_client_receives_subscribed_topics() {
+        let server = start_server().await;
+        let url = format!("ws://{}/?token=secret", server.local_addr());
+        let (mut client, _) = connect_async(url).await.unwrap();
+        subscribe(&mut client, "events.order.*").await;
+
+        server.publish("data.quotes.SIM.AUD/USD", &json!({ "bid": "1.0" }));
+        server.publish("events.order.S-001", &json!({ "type": "OrderFilled" }));
+
+        let event = next_json(&mut client).await;
+        assert_eq!(server.client_count(), 1);
+        assert_eq!(event["topic"], "events.order.S-001");
+        assert_eq!(event["payload"]["type"], "OrderFilled");
+    }
+
+    #[tokio::test]
+    async fn test_client_stops_receiving_after_unsubscribe() {
+        let server = start_server().await;
+        let url = format!("ws://{}/?token=secret", server.local_addr());
+        let (mut client, _) = connect_async(url).await.unwrap();
+        subscribe(&mut client, "events.order.*").await;
+        subscribe(&mut client, "events.position.*").await;
+
+        let request = json!({ "op": "unsubscribe", "topic": "events.order.*" }).to_string();
+        client.send(Message::Text(request)).await.unwrap();
+        assert_eq!(next_json(&mut client).await["op"], "unsubscribed");
+        server.publish("events.order.S-001", &json!(1));
+        server.publish("events.position.S-001", &json!(2));
+
+        let event = next_json(&mut client).await;
+        assert_eq!(event["topic"], "events.position.S-001");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_request_returns_error() {
+        let server = start_server().await;
+        let url = format!("ws://{}/?token=secret", server.local_addr());
+        let (mut client, _) = connect_async(url).await.unwrap();
+
+        client
+            .send(Message::Text("{\"op\": \"publish\"}".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(next_json(&mut client).await["op"], "error");
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret1"));
+    }
+
+    #[test]
+    fn test_handle_request_ignores_duplicate_subscriptions() {
+        let mut patterns = Vec::new();
+        let request = json!({ "op": "subscribe", "topic": "events.*" }).to_string();
+
+        handle_request(&request, &mut patterns);
+        handle_request(&request, &mut patterns);
+
+        assert_eq!(patterns, vec![Ustr::from("events.*")]);
+    }
+}
@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use pyo3::{prelude::*, types::PyTuple};
+use tracing::error;
+
+/// A Python callback invoked from Rust tasks.
+///
+/// Without an event loop the callback is called directly on the calling (tokio) thread.
+/// With an event loop the call is scheduled on it with `call_soon_threadsafe`, or for a
+/// coroutine function, the coroutine is submitted with `run_coroutine_threadsafe`, so that
+/// Python handlers always run on the loop which created the client.
+#[derive(Clone, Debug)]
+pub struct PyCallback {
+    callback: PyObject,
+    event_loop: Option<PyObject>,
+    is_coroutine: bool,
+}
+
+impl PyCallback {
+    /// Creates a callback which is called directly.
+    #[must_use]
+    pub fn new(callback: PyObject) -> Self {
+        Self {
+            callback,
+            event_loop: None,
+            is_coroutine: false,
+        }
+    }
+
+    /// Creates a callback which is scheduled on the given `event_loop` (if any).
+    ///
+    /// Falls back to calling directly if the callback cannot be inspected.
+    #[must_use]
+    pub fn with_event_loop(callback: PyObject, event_loop: Option<&PyObject>) -> Self {
+        let Some(event_loop) = event_loop else {
+            return Self::new(callback);
+        };
+
+        Python::with_gil(|py| {
+            let is_coroutine = py
+                .import("asyncio")
+                .and_then(|asyncio| {
+                    asyncio.call_method1("iscoroutinefunction", (callback.as_ref(py),))
+                })
+                .and_then(PyAny::is_true);
+
+            match is_coroutine {
+                Ok(is_coroutine) => Self {
+                    callback: callback.clone_ref(py),
+                    event_loop: Some(event_loop.clone_ref(py)),
+                    is_coroutine,
+                },
+                Err(e) => {
+                    error!("Cannot schedule callback on event loop, will call directly: {e}");
+                    Self::new(callback.clone_ref(py))
+                }
+            }
+        })
+    }
+
+    pub fn call0(&self, py: Python<'_>) -> PyResult<()> {
+        self.call(py, PyTuple::empty(py))
+    }
+
+    pub fn call1(&self, py: Python<'_>, arg: PyObject) -> PyResult<()> {
+        self.call(py, PyTuple::new(py, [arg]))
+    }
+
+    fn call(&self, py: Python<'_>, args: &PyTuple) -> PyResult<()> {
+        let Some(event_loop) = &self.event_loop else {
+            self.callback.call1(py, args)?;
+            return Ok(());
+        };
+
+        if self.is_coroutine {
+            let coroutine = self.callback.call1(py, args)?;
+            py.import("asyncio")?
+                .call_method1("run_coroutine_threadsafe", (coroutine, event_loop))?;
+        } else {
+            let mut call_args = vec![self.callback.clone_ref(py)];
+            call_args.extend(args.iter().map(|arg| arg.into_py(py)));
+            event_loop.call_method1(py, "call_soon_threadsafe", PyTuple::new(py, call_args))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the running asyncio event loop for the current thread.
+pub fn running_loop(py: Python<'_>) -> PyResult<PyObject> {
+    Ok(pyo3_asyncio::get_running_loop(py)?.into_py(py))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use pyo3::{prepare_freethreaded_python, types::PyList};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_direct_callback_is_called_immediately() {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let received = PyList::empty(py);
+            let append: PyObject = received.getattr("append").unwrap().into();
+            let callback = PyCallback::new(append);
+
+            callback.call1(py, 1.into_py(py)).unwrap();
+
+            assert_eq!(received.len(), 1);
+        });
+    }
+
+    #[rstest]
+    fn test_event_loop_callbacks_are_scheduled() {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let code = r#"
+import asyncio
+
+received = []
+
+def handler(data):
+    received.append(data)
+
+async def async_handler(data):
+    received.append(data * 2)
+
+event_loop = asyncio.new_event_loop()
+"#;
+            let module =
+                PyModule::from_code(py, code, "test_callback.py", "test_callback").unwrap();
+            let event_loop: PyObject = module.getattr("event_loop").unwrap().into();
+            let handler = PyCallback::with_event_loop(
+                module.getattr("handler").unwrap().into(),
+                Some(&event_loop),
+            );
+            let async_handler = PyCallback::with_event_loop(
+                module.getattr("async_handler").unwrap().into(),
+                Some(&event_loop),
+            );
+
+            handler.call1(py, 1.into_py(py)).unwrap();
+            async_handler.call1(py, 2.into_py(py)).unwrap();
+            let received: Vec<i64> = module.getattr("received").unwrap().extract().unwrap();
+            assert!(received.is_empty());
+
+            // Run the loop until the scheduled callbacks and coroutine have completed
+            py.run(
+                "event_loop.run_until_complete(asyncio.sleep(0.01))",
+                None,
+                Some(module.dict()),
+            )
+            .unwrap();
+            let received: Vec<i64> = module.getattr("received").unwrap().extract().unwrap();
+            assert_eq!(received, vec![1, 4]);
+        });
+    }
+}
@@ -17,6 +17,8 @@ use pyo3::prelude::*;
 
 use crate::{http, ratelimiter, socket, websocket};
 
+pub mod callback;
+
 /// Loaded as nautilus_pyo3.network
 #[pymodule]
 pub fn network(_: Python<'_>, m: &PyModule) -> PyResult<()> {
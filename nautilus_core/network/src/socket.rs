@@ -16,7 +16,7 @@
 use std::{sync::Arc, time::Duration};
 
 use nautilus_core::python::to_pyruntime_err;
-use pyo3::{prelude::*, PyObject, Python};
+use pyo3::{prelude::*, types::PyBytes, PyObject, Python};
 use tokio::{
     io::{split, AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf},
     net::TcpStream,
@@ -31,6 +31,8 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error};
 
+use crate::python::callback::{running_loop, PyCallback};
+
 type TcpWriter = WriteHalf<MaybeTlsStream<TcpStream>>;
 type SharedTcpWriter = Arc<Mutex<WriteHalf<MaybeTlsStream<TcpStream>>>>;
 type TcpReader = ReadHalf<MaybeTlsStream<TcpStream>>;
@@ -52,17 +54,29 @@ pub struct SocketConfig {
     handler: PyObject,
     /// The optional heartbeat with period and beat message.
     heartbeat: Option<(u64, Vec<u8>)>,
+    /// If handlers should be scheduled on the event loop the client was connected from.
+    use_event_loop: bool,
+    /// The event loop captured on connect (if `use_event_loop`).
+    event_loop: Option<PyObject>,
+}
+
+impl SocketConfig {
+    fn callback(&self, callback: PyObject) -> PyCallback {
+        PyCallback::with_event_loop(callback, self.event_loop.as_ref())
+    }
 }
 
 #[pymethods]
 impl SocketConfig {
     #[new]
+    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None, use_event_loop=false))]
     fn py_new(
         url: String,
         ssl: bool,
         suffix: Vec<u8>,
         handler: PyObject,
         heartbeat: Option<(u64, Vec<u8>)>,
+        use_event_loop: bool,
     ) -> Self {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
         Self {
@@ -71,6 +85,8 @@ impl SocketConfig {
             suffix,
             handler,
             heartbeat,
+            use_event_loop,
+            event_loop: None,
         }
     }
 }
@@ -109,12 +125,14 @@ impl SocketClientInner {
             heartbeat,
             suffix,
             handler,
+            ..
         } = &config;
         let (reader, writer) = Self::tls_connect_with_server(url, *mode).await?;
         let shared_writer = Arc::new(Mutex::new(writer));
 
         // Keep receiving messages from socket pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler.clone(), suffix.clone());
+        let read_task =
+            Self::spawn_read_task(reader, config.callback(handler.clone()), suffix.clone());
 
         // Optionally create heartbeat task
         let heartbeat_task =
@@ -142,7 +160,7 @@ impl SocketClientInner {
     #[must_use]
     pub fn spawn_read_task(
         mut reader: TcpReader,
-        handler: PyObject,
+        handler: PyCallback,
         suffix: Vec<u8>,
     ) -> task::JoinHandle<()> {
         // Keep receiving messages from socket pass them as arguments to handler
@@ -174,9 +192,9 @@ impl SocketClientInner {
                             let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
                             data.truncate(data.len() - suffix.len());
 
-                            if let Err(e) =
-                                Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
-                            {
+                            if let Err(e) = Python::with_gil(|py| {
+                                handler.call1(py, PyBytes::new(py, &data).into_py(py))
+                            }) {
                                 error!("Call to handler failed: {e}");
                                 break;
                             }
@@ -248,6 +266,7 @@ impl SocketClientInner {
             heartbeat,
             suffix,
             handler,
+            ..
         } = &self.config;
         debug!("Reconnecting client");
         let (reader, new_writer) = Self::tls_connect_with_server(url, *mode).await?;
@@ -258,7 +277,11 @@ impl SocketClientInner {
         drop(guard);
 
         debug!("Recreate reader and heartbeat task");
-        self.read_task = Self::spawn_read_task(reader, handler.clone(), suffix.clone());
+        self.read_task = Self::spawn_read_task(
+            reader,
+            self.config.callback(handler.clone()),
+            suffix.clone(),
+        );
         self.heartbeat_task =
             Self::spawn_heartbeat_task(heartbeat.clone(), self.writer.clone(), suffix.clone());
         Ok(())
@@ -310,6 +333,10 @@ impl SocketClient {
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> Result<Self, Error> {
+        let post_connection = post_connection.map(|handler| config.callback(handler));
+        let post_reconnection = post_reconnection.map(|handler| config.callback(handler));
+        let post_disconnection = post_disconnection.map(|handler| config.callback(handler));
+
         let suffix = config.suffix.clone();
         let inner = SocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
@@ -358,8 +385,8 @@ impl SocketClient {
     fn spawn_controller_task(
         mut inner: SocketClientInner,
         disconnect_mode: Arc<Mutex<bool>>,
-        post_reconnection: Option<PyObject>,
-        post_disconnection: Option<PyObject>,
+        post_reconnection: Option<PyCallback>,
+        post_disconnection: Option<PyCallback>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
             let mut disconnect_flag;
@@ -424,12 +451,15 @@ impl SocketClient {
     #[staticmethod]
     #[pyo3(name = "connect")]
     fn py_connect(
-        config: SocketConfig,
+        mut config: SocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
         py: Python<'_>,
     ) -> PyResult<&PyAny> {
+        if config.use_event_loop {
+            config.event_loop = Some(running_loop(py)?);
+        }
         pyo3_asyncio::tokio::future_into_py(py, async move {
             Self::connect(
                 config,
@@ -626,6 +656,8 @@ counter = Counter()",
             mode: Mode::Plain,
             suffix: b"\r\n".to_vec(),
             heartbeat: None,
+            use_event_loop: false,
+            event_loop: None,
         };
         let client: SocketClient = SocketClient::connect(config, None, None, None)
             .await
@@ -20,7 +20,7 @@ use futures_util::{
     SinkExt, StreamExt,
 };
 use hyper::header::HeaderName;
-use nautilus_core::python::to_pyruntime_err;
+use nautilus_core::{metrics, python::to_pyruntime_err};
 use pyo3::{exceptions::PyException, prelude::*, types::PyBytes, PyObject, Python};
 use tokio::{net::TcpStream, sync::Mutex, task, time::sleep};
 use tokio_tungstenite::{
@@ -30,6 +30,8 @@ use tokio_tungstenite::{
 };
 use tracing::{debug, error};
 
+use crate::python::callback::{running_loop, PyCallback};
+
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
@@ -46,17 +48,28 @@ pub struct WebSocketConfig {
     headers: Vec<(String, String)>,
     heartbeat: Option<u64>,
     heartbeat_msg: Option<String>,
+    /// If handlers should be scheduled on the event loop the client was connected from.
+    use_event_loop: bool,
+    event_loop: Option<PyObject>,
+}
+
+impl WebSocketConfig {
+    fn callback(&self, callback: PyObject) -> PyCallback {
+        PyCallback::with_event_loop(callback, self.event_loop.as_ref())
+    }
 }
 
 #[pymethods]
 impl WebSocketConfig {
     #[new]
+    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, use_event_loop=false))]
     fn py_new(
         url: String,
         handler: PyObject,
         headers: Vec<(String, String)>,
         heartbeat: Option<u64>,
         heartbeat_msg: Option<String>,
+        use_event_loop: bool,
     ) -> Self {
         Self {
             url,
@@ -64,6 +77,8 @@ impl WebSocketConfig {
             headers,
             heartbeat,
             heartbeat_msg,
+            use_event_loop,
+            event_loop: None,
         }
     }
 }
@@ -99,12 +114,13 @@ impl WebSocketClientInner {
             heartbeat,
             headers,
             heartbeat_msg,
+            ..
         } = &config;
         let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
         let writer = Arc::new(Mutex::new(writer));
 
         // Keep receiving messages from socket and pass them as arguments to handler
-        let read_task = Self::spawn_read_task(reader, handler.clone());
+        let read_task = Self::spawn_read_task(reader, config.callback(handler.clone()));
 
         let heartbeat_task =
             Self::spawn_heartbeat_task(*heartbeat, heartbeat_msg.clone(), writer.clone());
@@ -165,24 +181,26 @@ impl WebSocketClientInner {
     }
 
     /// Keep receiving messages from socket and pass them as arguments to handler.
-    pub fn spawn_read_task(mut reader: MessageReader, handler: PyObject) -> task::JoinHandle<()> {
+    pub fn spawn_read_task(mut reader: MessageReader, handler: PyCallback) -> task::JoinHandle<()> {
         task::spawn(async move {
             loop {
                 debug!("Receiving message");
                 match reader.next().await {
                     Some(Ok(Message::Binary(data))) => {
                         debug!("Received binary message");
-                        if let Err(e) =
-                            Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
-                        {
+                        record_message_received();
+                        if let Err(e) = Python::with_gil(|py| {
+                            handler.call1(py, PyBytes::new(py, &data).into_py(py))
+                        }) {
                             error!("Call to handler failed: {e}");
                             break;
                         }
                     }
                     Some(Ok(Message::Text(data))) => {
                         debug!("Received text message");
+                        record_message_received();
                         if let Err(e) = Python::with_gil(|py| {
-                            handler.call1(py, (PyBytes::new(py, data.as_bytes()),))
+                            handler.call1(py, PyBytes::new(py, data.as_bytes()).into_py(py))
                         }) {
                             error!("Call to handler failed: {e}");
                             break;
@@ -241,13 +259,20 @@ impl WebSocketClientInner {
     /// Make a new connection with server. Use the new read and write halves
     /// to update self writer and read and heartbeat tasks.
     pub async fn reconnect(&mut self) -> Result<(), Error> {
+        metrics::inc_counter(
+            "nautilus_websocket_reconnects_total",
+            "WebSocket client reconnection attempts",
+            &[("url", &self.config.url)],
+            1,
+        );
         let (new_writer, reader) =
             Self::connect_with_server(&self.config.url, self.config.headers.clone()).await?;
         let mut guard = self.writer.lock().await;
         *guard = new_writer;
         drop(guard);
 
-        self.read_task = Self::spawn_read_task(reader, self.config.handler.clone());
+        self.read_task =
+            Self::spawn_read_task(reader, self.config.callback(self.config.handler.clone()));
         self.heartbeat_task = Self::spawn_heartbeat_task(
             self.config.heartbeat,
             self.config.heartbeat_msg.clone(),
@@ -269,6 +294,15 @@ impl WebSocketClientInner {
     }
 }
 
+fn record_message_received() {
+    metrics::inc_counter(
+        "nautilus_websocket_messages_received_total",
+        "Messages received by WebSocket clients",
+        &[],
+        1,
+    );
+}
+
 impl Drop for WebSocketClientInner {
     fn drop(&mut self) {
         if !self.read_task.is_finished() {
@@ -305,6 +339,10 @@ impl WebSocketClient {
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> Result<Self, Error> {
+        let post_connection = post_connection.map(|handler| config.callback(handler));
+        let post_reconnection = post_reconnection.map(|handler| config.callback(handler));
+        let post_disconnection = post_disconnection.map(|handler| config.callback(handler));
+
         let inner = WebSocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
         let disconnect_mode = Arc::new(Mutex::new(false));
@@ -338,6 +376,12 @@ impl WebSocketClient {
     }
 
     pub async fn send_bytes(&self, data: Vec<u8>) -> Result<(), Error> {
+        metrics::inc_counter(
+            "nautilus_websocket_messages_sent_total",
+            "Messages sent by WebSocket clients",
+            &[],
+            1,
+        );
         let mut guard = self.writer.lock().await;
         guard.send(Message::Binary(data)).await
     }
@@ -358,8 +402,8 @@ impl WebSocketClient {
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         disconnect_mode: Arc<Mutex<bool>>,
-        post_reconnection: Option<PyObject>,
-        post_disconnection: Option<PyObject>,
+        post_reconnection: Option<PyCallback>,
+        post_disconnection: Option<PyCallback>,
     ) -> task::JoinHandle<()> {
         task::spawn(async move {
             let mut disconnect_flag;
@@ -435,12 +479,15 @@ impl WebSocketClient {
     #[staticmethod]
     #[pyo3(name = "connect")]
     fn py_connect(
-        config: WebSocketConfig,
+        mut config: WebSocketConfig,
         post_connection: Option<PyObject>,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
         py: Python<'_>,
     ) -> PyResult<&PyAny> {
+        if config.use_event_loop {
+            config.event_loop = Some(running_loop(py)?);
+        }
         pyo3_asyncio::tokio::future_into_py(py, async move {
             Self::connect(
                 config,
@@ -658,6 +705,7 @@ counter = Counter()",
             vec![(header_key, header_value)],
             None,
             None,
+            false,
         );
         let client = WebSocketClient::connect(config, None, None, None)
             .await
@@ -762,6 +810,7 @@ checker = Checker()",
             vec![(header_key, header_value)],
             Some(1),
             Some("heartbeat message".to_string()),
+            false,
         );
         let client = WebSocketClient::connect(config, None, None, None)
             .await
@@ -0,0 +1,180 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, io::Write, sync::Arc};
+
+use datafusion::{
+    arrow::{
+        array::{ArrayRef, StringBuilder, UInt64Array},
+        datatypes::{DataType, Field, Schema},
+        error::ArrowError,
+        record_batch::RecordBatch,
+    },
+    parquet::arrow::ArrowWriter,
+};
+use nautilus_accounting::event_log::{AccountEventLog, AccountLogEntry};
+
+use super::{DataStreamingError, KEY_ACCOUNT_ID};
+use crate::arrow::{ArrowSchemaProvider, EncodeToArrays, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for AccountLogEntry {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("sequence", DataType::UInt64, false),
+            Field::new("account_id", DataType::Utf8, false),
+            Field::new("kind", DataType::Utf8, false),
+            Field::new("currency", DataType::Utf8, false),
+            // Exact decimal string, as currencies in the log have differing precisions
+            Field::new("amount", DataType::Utf8, false),
+            Field::new("reference", DataType::Utf8, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+impl EncodeToArrays for AccountLogEntry {
+    fn encode_arrays(data: &[Self]) -> Vec<ArrayRef> {
+        let mut sequence_builder = UInt64Array::builder(data.len());
+        let mut account_id_builder = StringBuilder::new();
+        let mut kind_builder = StringBuilder::new();
+        let mut currency_builder = StringBuilder::new();
+        let mut amount_builder = StringBuilder::new();
+        let mut reference_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for entry in data {
+            sequence_builder.append_value(entry.sequence);
+            account_id_builder.append_value(entry.account_id.to_string());
+            kind_builder.append_value(entry.kind.as_str());
+            currency_builder.append_value(entry.currency().code.as_str());
+            amount_builder.append_value(entry.amount.as_decimal().to_string());
+            reference_builder.append_option(entry.reference.as_deref());
+            ts_event_builder.append_value(entry.ts_event);
+            ts_init_builder.append_value(entry.ts_init);
+        }
+
+        vec![
+            Arc::new(sequence_builder.finish()),
+            Arc::new(account_id_builder.finish()),
+            Arc::new(kind_builder.finish()),
+            Arc::new(currency_builder.finish()),
+            Arc::new(amount_builder.finish()),
+            Arc::new(reference_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+        ]
+    }
+}
+
+impl EncodeToRecordBatch for AccountLogEntry {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            Self::encode_arrays(data),
+        )
+    }
+}
+
+/// Writes the full audit trail of the given account event `log` as Parquet to `writer`.
+pub fn write_account_log_parquet<W: Write + Send>(
+    log: &AccountEventLog,
+    writer: W,
+) -> Result<(), DataStreamingError> {
+    let metadata = HashMap::from([(KEY_ACCOUNT_ID.to_string(), log.account_id().to_string())]);
+    let record_batch = AccountLogEntry::encode_batch(&metadata, log.entries())?;
+    let mut writer = ArrowWriter::try_new(writer, record_batch.schema(), None)?;
+    writer.write(&record_batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use datafusion::{
+        arrow::array::{Array, StringArray},
+        parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    };
+    use nautilus_model::{
+        events::account::{state::AccountState, stubs::cash_account_state_million_usd},
+        types::money::Money,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let schema = AccountLogEntry::get_schema(None);
+
+        assert_eq!(schema.fields().len(), 8);
+        assert!(schema.field_with_name("reference").unwrap().is_nullable());
+    }
+
+    #[rstest]
+    fn test_write_account_log_parquet_round_trip(cash_account_state_million_usd: AccountState) {
+        let mut log = AccountEventLog::new(cash_account_state_million_usd.account_id);
+        log.append_state(&cash_account_state_million_usd).unwrap();
+        log.append_adjustment(Money::from("-12.5 USD"), "withdrawal", 10, 11);
+        let path = std::env::temp_dir().join("nautilus_test_account_log.parquet");
+
+        write_account_log_parquet(&log, File::create(&path).unwrap()).unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        let metadata = builder.schema().metadata().clone();
+        let batches: Vec<RecordBatch> = builder.build().unwrap().map(Result::unwrap).collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(metadata[KEY_ACCOUNT_ID], "SIM-001");
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 2);
+        let kinds = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let amounts = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let references = batch
+            .column(5)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(kinds.value(0), "STATE");
+        assert_eq!(kinds.value(1), "ADJUSTMENT");
+        assert_eq!(amounts.value(0), "1000000.00");
+        assert_eq!(amounts.value(1), "-12.50");
+        assert_eq!(references.value(1), "withdrawal");
+        assert!(!references.is_null(0));
+    }
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod account;
 pub mod bar;
 pub mod delta;
 pub mod depth;
@@ -36,6 +37,7 @@ use pyo3::prelude::*;
 use thiserror;
 
 // Define metadata key constants constants
+const KEY_ACCOUNT_ID: &str = "account_id";
 const KEY_BAR_TYPE: &str = "bar_type";
 const KEY_INSTRUMENT_ID: &str = "instrument_id";
 const KEY_PRICE_PRECISION: &str = "price_precision";
@@ -47,6 +49,8 @@ pub enum DataStreamingError {
     ArrowError(#[from] datafusion::arrow::error::ArrowError),
     #[error("I/O error: {0}")]
     IoError(#[from] io::Error),
+    #[error("Parquet error: {0}")]
+    ParquetError(#[from] datafusion::parquet::errors::ParquetError),
     #[error("Python error: {0}")]
     PythonError(#[from] PyErr),
 }
@@ -91,6 +95,14 @@ where
     ) -> Result<RecordBatch, ArrowError>;
 }
 
+pub trait EncodeToArrays
+where
+    Self: Sized + ArrowSchemaProvider,
+{
+    /// Encodes the given `data` into one Arrow array per schema field, in schema order.
+    fn encode_arrays(data: &[Self]) -> Vec<ArrayRef>;
+}
+
 pub trait DecodeFromRecordBatch
 where
     Self: Sized + Into<Data> + ArrowSchemaProvider,
@@ -16,22 +16,20 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, UInt64Array},
+    array::{ArrayRef, Int64Array, UInt64Array},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
 };
-use nautilus_model::{
-    data::quote::QuoteTick,
-    identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
-};
+use nautilus_model::{data::quote::QuoteTick, identifiers::instrument_id::InstrumentId};
 
 use super::{
     extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToArrays, EncodeToRecordBatch,
+};
 
 impl ArrowSchemaProvider for QuoteTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -75,11 +73,8 @@ fn parse_metadata(
     Ok((instrument_id, price_precision, size_precision))
 }
 
-impl EncodeToRecordBatch for QuoteTick {
-    fn encode_batch(
-        metadata: &HashMap<String, String>,
-        data: &[Self],
-    ) -> Result<RecordBatch, ArrowError> {
+impl EncodeToArrays for QuoteTick {
+    fn encode_arrays(data: &[Self]) -> Vec<ArrayRef> {
         let mut bid_price_builder = Int64Array::builder(data.len());
         let mut ask_price_builder = Int64Array::builder(data.len());
         let mut bid_size_builder = UInt64Array::builder(data.len());
@@ -96,23 +91,25 @@ impl EncodeToRecordBatch for QuoteTick {
             ts_init_builder.append_value(quote.ts_init);
         }
 
-        let bid_price_array = bid_price_builder.finish();
-        let ask_price_array = ask_price_builder.finish();
-        let bid_size_array = bid_size_builder.finish();
-        let ask_size_array = ask_size_builder.finish();
-        let ts_event_array = ts_event_builder.finish();
-        let ts_init_array = ts_init_builder.finish();
+        vec![
+            Arc::new(bid_price_builder.finish()),
+            Arc::new(ask_price_builder.finish()),
+            Arc::new(bid_size_builder.finish()),
+            Arc::new(ask_size_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+        ]
+    }
+}
 
+impl EncodeToRecordBatch for QuoteTick {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
         RecordBatch::try_new(
             Self::get_schema(Some(metadata.clone())).into(),
-            vec![
-                Arc::new(bid_price_array),
-                Arc::new(ask_price_array),
-                Arc::new(bid_size_array),
-                Arc::new(ask_size_array),
-                Arc::new(ts_event_array),
-                Arc::new(ts_init_array),
-            ],
+            Self::encode_arrays(data),
         )
     }
 }
@@ -132,32 +129,18 @@ impl DecodeFromRecordBatch for QuoteTick {
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
 
-        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
-            .map(|i| {
-                let bid_price =
-                    Price::from_raw(bid_price_values.value(i), price_precision).unwrap();
-                let ask_price =
-                    Price::from_raw(ask_price_values.value(i), price_precision).unwrap();
-                let bid_size =
-                    Quantity::from_raw(bid_size_values.value(i), size_precision).unwrap();
-                let ask_size =
-                    Quantity::from_raw(ask_size_values.value(i), size_precision).unwrap();
-                let ts_event = ts_event_values.value(i);
-                let ts_init = ts_init_values.value(i);
-
-                Ok(Self {
-                    instrument_id,
-                    bid_price,
-                    ask_price,
-                    bid_size,
-                    ask_size,
-                    ts_event,
-                    ts_init,
-                })
-            })
-            .collect();
-
-        result
+        QuoteTick::from_raw_arrays(
+            instrument_id,
+            price_precision,
+            size_precision,
+            bid_price_values.values(),
+            ask_price_values.values(),
+            bid_size_values.values(),
+            ask_size_values.values(),
+            ts_event_values.values(),
+            ts_init_values.values(),
+        )
+        .map_err(|e| EncodingError::ParseError(stringify!(QuoteTick), e.to_string()))
     }
 }
 
@@ -179,6 +162,7 @@ mod tests {
     use std::{collections::HashMap, sync::Arc};
 
     use datafusion::arrow::record_batch::RecordBatch;
+    use nautilus_model::types::{price::Price, quantity::Quantity};
     use rstest::rstest;
 
     use super::*;
@@ -299,4 +283,45 @@ mod tests {
         let decoded_data = QuoteTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    fn test_encode_arrays_round_trip() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let data = QuoteTick::from_arrays(
+            instrument_id,
+            2,
+            0,
+            &[100.10, 100.75],
+            &[101.50, 100.90],
+            &[1000.0, 750.0],
+            &[500.0, 300.0],
+            &[1, 2],
+            &[3, 4],
+        )
+        .unwrap();
+
+        let arrays = QuoteTick::encode_arrays(&data);
+        let bid_price_values = arrays[0].as_any().downcast_ref::<Int64Array>().unwrap();
+        let ask_price_values = arrays[1].as_any().downcast_ref::<Int64Array>().unwrap();
+        let bid_size_values = arrays[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ask_size_values = arrays[3].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_event_values = arrays[4].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = arrays[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        let decoded = QuoteTick::from_raw_arrays(
+            instrument_id,
+            2,
+            0,
+            bid_price_values.values(),
+            ask_price_values.values(),
+            bid_size_values.values(),
+            ask_size_values.values(),
+            ts_event_values.values(),
+            ts_init_values.values(),
+        )
+        .unwrap();
+
+        assert_eq!(arrays.len(), 6);
+        assert_eq!(decoded, data);
+    }
 }
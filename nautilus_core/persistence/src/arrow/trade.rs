@@ -16,7 +16,7 @@
 use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use datafusion::arrow::{
-    array::{Int64Array, StringArray, StringBuilder, UInt64Array, UInt8Array},
+    array::{ArrayRef, Int64Array, StringArray, StringBuilder, UInt64Array, UInt8Array},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -32,7 +32,9 @@ use super::{
     extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToArrays, EncodeToRecordBatch,
+};
 
 impl ArrowSchemaProvider for TradeTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -43,6 +45,7 @@ impl ArrowSchemaProvider for TradeTick {
             Field::new("trade_id", DataType::Utf8, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
+            Field::new("flags", DataType::UInt8, false),
         ];
 
         match metadata {
@@ -76,17 +79,15 @@ fn parse_metadata(
     Ok((instrument_id, price_precision, size_precision))
 }
 
-impl EncodeToRecordBatch for TradeTick {
-    fn encode_batch(
-        metadata: &HashMap<String, String>,
-        data: &[Self],
-    ) -> Result<RecordBatch, ArrowError> {
+impl EncodeToArrays for TradeTick {
+    fn encode_arrays(data: &[Self]) -> Vec<ArrayRef> {
         let mut price_builder = Int64Array::builder(data.len());
         let mut size_builder = UInt64Array::builder(data.len());
         let mut aggressor_side_builder = UInt8Array::builder(data.len());
         let mut trade_id_builder = StringBuilder::new();
         let mut ts_event_builder = UInt64Array::builder(data.len());
         let mut ts_init_builder = UInt64Array::builder(data.len());
+        let mut flags_builder = UInt8Array::builder(data.len());
 
         for tick in data {
             price_builder.append_value(tick.price.raw);
@@ -95,25 +96,29 @@ impl EncodeToRecordBatch for TradeTick {
             trade_id_builder.append_value(tick.trade_id.to_string());
             ts_event_builder.append_value(tick.ts_event);
             ts_init_builder.append_value(tick.ts_init);
+            flags_builder.append_value(tick.flags);
         }
 
-        let price_array = price_builder.finish();
-        let size_array = size_builder.finish();
-        let aggressor_side_array = aggressor_side_builder.finish();
-        let trade_id_array = trade_id_builder.finish();
-        let ts_event_array = ts_event_builder.finish();
-        let ts_init_array = ts_init_builder.finish();
+        vec![
+            Arc::new(price_builder.finish()),
+            Arc::new(size_builder.finish()),
+            Arc::new(aggressor_side_builder.finish()),
+            Arc::new(trade_id_builder.finish()),
+            Arc::new(ts_event_builder.finish()),
+            Arc::new(ts_init_builder.finish()),
+            Arc::new(flags_builder.finish()),
+        ]
+    }
+}
 
+impl EncodeToRecordBatch for TradeTick {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
         RecordBatch::try_new(
             Self::get_schema(Some(metadata.clone())).into(),
-            vec![
-                Arc::new(price_array),
-                Arc::new(size_array),
-                Arc::new(aggressor_side_array),
-                Arc::new(trade_id_array),
-                Arc::new(ts_event_array),
-                Arc::new(ts_init_array),
-            ],
+            Self::encode_arrays(data),
         )
     }
 }
@@ -133,6 +138,17 @@ impl DecodeFromRecordBatch for TradeTick {
         let trade_id_values = extract_column::<StringArray>(cols, "trade_id", 3, DataType::Utf8)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 4, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 5, DataType::UInt64)?;
+        // Catalogs written before trade flags were introduced have no `flags` column
+        let flags_values = if cols.len() > 6 {
+            Some(extract_column::<UInt8Array>(
+                cols,
+                "flags",
+                6,
+                DataType::UInt8,
+            )?)
+        } else {
+            None
+        };
 
         let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
             .map(|i| {
@@ -149,12 +165,14 @@ impl DecodeFromRecordBatch for TradeTick {
                 let trade_id = TradeId::from(trade_id_values.value(i));
                 let ts_event = ts_event_values.value(i);
                 let ts_init = ts_init_values.value(i);
+                let flags = flags_values.map_or(0, |values| values.value(i));
 
                 Ok(Self {
                     instrument_id,
                     price,
                     size,
                     aggressor_side,
+                    flags,
                     trade_id,
                     ts_event,
                     ts_init,
@@ -187,6 +205,7 @@ mod tests {
         array::{Array, Int64Array, StringArray, UInt64Array, UInt8Array},
         record_batch::RecordBatch,
     };
+    use nautilus_model::enums::TradeFlag;
     use rstest::rstest;
 
     use super::*;
@@ -203,6 +222,7 @@ mod tests {
             Field::new("trade_id", DataType::Utf8, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
+            Field::new("flags", DataType::UInt8, false),
         ];
         let expected_schema = Schema::new_with_metadata(expected_fields, metadata);
         assert_eq!(schema, expected_schema);
@@ -218,6 +238,7 @@ mod tests {
         expected_map.insert("trade_id".to_string(), "Utf8".to_string());
         expected_map.insert("ts_event".to_string(), "UInt64".to_string());
         expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        expected_map.insert("flags".to_string(), "UInt8".to_string());
         assert_eq!(schema_map, expected_map);
     }
 
@@ -232,6 +253,7 @@ mod tests {
             price: Price::from("100.10"),
             size: Quantity::from(1000),
             aggressor_side: AggressorSide::Buyer,
+            flags: 0,
             trade_id: TradeId::new("1").unwrap(),
             ts_event: 1,
             ts_init: 3,
@@ -242,6 +264,7 @@ mod tests {
             price: Price::from("100.50"),
             size: Quantity::from(500),
             aggressor_side: AggressorSide::Seller,
+            flags: TradeFlag::Block as u8,
             trade_id: TradeId::new("2").unwrap(),
             ts_event: 2,
             ts_init: 4,
@@ -258,8 +281,9 @@ mod tests {
         let trade_id_values = columns[3].as_any().downcast_ref::<StringArray>().unwrap();
         let ts_event_values = columns[4].as_any().downcast_ref::<UInt64Array>().unwrap();
         let ts_init_values = columns[5].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let flags_values = columns[6].as_any().downcast_ref::<UInt8Array>().unwrap();
 
-        assert_eq!(columns.len(), 6);
+        assert_eq!(columns.len(), 7);
         assert_eq!(price_values.len(), 2);
         assert_eq!(price_values.value(0), 100_100_000_000);
         assert_eq!(price_values.value(1), 100_500_000_000);
@@ -278,6 +302,8 @@ mod tests {
         assert_eq!(ts_init_values.len(), 2);
         assert_eq!(ts_init_values.value(0), 3);
         assert_eq!(ts_init_values.value(1), 4);
+        assert_eq!(flags_values.value(0), 0);
+        assert_eq!(flags_values.value(1), 2);
     }
 
     #[rstest]
@@ -291,6 +317,7 @@ mod tests {
         let trade_id = StringArray::from(vec!["1", "2"]);
         let ts_event = UInt64Array::from(vec![1, 2]);
         let ts_init = UInt64Array::from(vec![3, 4]);
+        let flags = UInt8Array::from(vec![0, 1]);
 
         let record_batch = RecordBatch::try_new(
             TradeTick::get_schema(Some(metadata.clone())).into(),
@@ -301,11 +328,39 @@ mod tests {
                 Arc::new(trade_id),
                 Arc::new(ts_event),
                 Arc::new(ts_init),
+                Arc::new(flags),
             ],
         )
         .unwrap();
 
         let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
+        assert!(decoded_data[1].is_aggregated());
+    }
+
+    #[rstest]
+    fn test_decode_batch_without_flags_column() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = TradeTick::get_metadata(&instrument_id, 2, 0);
+        let mut fields = TradeTick::get_schema(None).fields().to_vec();
+        fields.pop();
+        let schema = Schema::new_with_metadata(fields, metadata.clone());
+
+        let record_batch = RecordBatch::try_new(
+            schema.into(),
+            vec![
+                Arc::new(Int64Array::from(vec![1_000_000_000_000])),
+                Arc::new(UInt64Array::from(vec![1000])),
+                Arc::new(UInt8Array::from(vec![1])),
+                Arc::new(StringArray::from(vec!["1"])),
+                Arc::new(UInt64Array::from(vec![1])),
+                Arc::new(UInt64Array::from(vec![3])),
+            ],
+        )
+        .unwrap();
+
+        let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
+        assert_eq!(decoded_data.len(), 1);
+        assert_eq!(decoded_data[0].flags, 0);
     }
 }
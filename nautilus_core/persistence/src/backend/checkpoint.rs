@@ -0,0 +1,220 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Book snapshot checkpointing for order book delta streams.
+//!
+//! A checkpointed stream periodically interleaves a full book snapshot (a `CLEAR`
+//! followed by `ADD`s for every resting order) with the original deltas. A reader
+//! which only needs data from some later start time can then begin at the last
+//! `CLEAR` at or before that time, rather than replaying the whole session.
+
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::delta::OrderBookDelta,
+    enums::{BookAction, BookType},
+    identifiers::instrument_id::InstrumentId,
+    orderbook::book::OrderBook,
+};
+
+/// Interleaves periodic book snapshots with a stream of order book deltas.
+///
+/// Checkpoints are aligned to multiples of `interval_ns` since the UNIX epoch, and a
+/// snapshot is emitted immediately before the first delta at or after each boundary.
+pub struct BookSnapshotCheckpointer {
+    book: OrderBook,
+    interval_ns: u64,
+    next_checkpoint_ns: Option<UnixNanos>,
+    snapshot_count: usize,
+}
+
+impl BookSnapshotCheckpointer {
+    /// Creates a new [`BookSnapshotCheckpointer`] instance.
+    ///
+    /// # Panics
+    ///
+    /// If `interval_ns` is zero.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, book_type: BookType, interval_ns: u64) -> Self {
+        assert!(interval_ns > 0, "`interval_ns` must be positive");
+        Self {
+            book: OrderBook::new(instrument_id, book_type),
+            interval_ns,
+            next_checkpoint_ns: None,
+            snapshot_count: 0,
+        }
+    }
+
+    /// Returns the number of snapshots emitted so far.
+    #[must_use]
+    pub fn snapshot_count(&self) -> usize {
+        self.snapshot_count
+    }
+
+    /// Processes the given `delta`, returning it preceded by a book snapshot when a
+    /// checkpoint boundary has been crossed.
+    pub fn process(&mut self, delta: OrderBookDelta) -> Vec<OrderBookDelta> {
+        let mut output = Vec::with_capacity(1);
+
+        match self.next_checkpoint_ns {
+            Some(next_checkpoint_ns) if delta.ts_init >= next_checkpoint_ns => {
+                // A venue `CLEAR` already starts a fresh book, so a snapshot would be redundant
+                let is_empty = !self.book.has_bid() && !self.book.has_ask();
+                if delta.action != BookAction::Clear && !is_empty {
+                    output.extend(self.book.snapshot_deltas(delta.ts_init));
+                    self.snapshot_count += 1;
+                }
+                self.next_checkpoint_ns = Some(self.next_boundary(delta.ts_init));
+            }
+            Some(_) => {}
+            None => self.next_checkpoint_ns = Some(self.next_boundary(delta.ts_init)),
+        }
+
+        self.book.apply_delta(delta);
+        output.push(delta);
+        output
+    }
+
+    /// Processes all `deltas` in order, returning the checkpointed stream.
+    pub fn process_all(&mut self, deltas: &[OrderBookDelta]) -> Vec<OrderBookDelta> {
+        let mut output = Vec::with_capacity(deltas.len());
+        for delta in deltas {
+            output.extend(self.process(*delta));
+        }
+        output
+    }
+
+    fn next_boundary(&self, ts: UnixNanos) -> UnixNanos {
+        (ts / self.interval_ns + 1) * self.interval_ns
+    }
+}
+
+/// Returns the index of the last `CLEAR` delta with `ts_init` at or before `start`.
+///
+/// Replaying `deltas` from the returned index reproduces the book state at `start`.
+/// Returns `None` if no such delta exists, in which case the full stream must be replayed.
+#[must_use]
+pub fn find_checkpoint_index(deltas: &[OrderBookDelta], start: UnixNanos) -> Option<usize> {
+    deltas
+        .iter()
+        .take_while(|delta| delta.ts_init <= start)
+        .enumerate()
+        .filter(|(_, delta)| delta.action == BookAction::Clear)
+        .map(|(i, _)| i)
+        .last()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            delta::{F_LAST, F_SNAPSHOT},
+            order::BookOrder,
+        },
+        enums::OrderSide,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn add_delta(side: OrderSide, price: &str, size: i64, ts: UnixNanos) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("ESH4.GLBX"),
+            BookAction::Add,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), 0),
+            0,
+            ts,
+            ts,
+            ts,
+        )
+    }
+
+    fn stub_deltas() -> Vec<OrderBookDelta> {
+        vec![
+            add_delta(OrderSide::Buy, "100.00", 10, 10),
+            add_delta(OrderSide::Sell, "101.00", 5, 20),
+            add_delta(OrderSide::Buy, "100.50", 3, 105),
+            add_delta(OrderSide::Sell, "100.75", 7, 150),
+            add_delta(OrderSide::Buy, "100.25", 1, 230),
+        ]
+    }
+
+    #[rstest]
+    fn test_process_all_interleaves_snapshots() {
+        let mut checkpointer =
+            BookSnapshotCheckpointer::new(InstrumentId::from("ESH4.GLBX"), BookType::L2_MBP, 100);
+
+        let output = checkpointer.process_all(&stub_deltas());
+
+        // Snapshot at 105 (2 levels) and at 230 (4 levels), each with a leading CLEAR
+        assert_eq!(checkpointer.snapshot_count(), 2);
+        assert_eq!(output.len(), 5 + 3 + 5);
+        assert_eq!(output[2].action, BookAction::Clear);
+        assert_eq!(output[2].ts_init, 105);
+        assert_eq!(output[4].flags, F_SNAPSHOT | F_LAST);
+        assert_eq!(output[5], stub_deltas()[2]);
+        assert_eq!(output[7].action, BookAction::Clear);
+        assert_eq!(output[7].ts_init, 230);
+    }
+
+    #[rstest]
+    fn test_no_snapshot_before_venue_clear() {
+        let instrument_id = InstrumentId::from("ESH4.GLBX");
+        let mut checkpointer = BookSnapshotCheckpointer::new(instrument_id, BookType::L2_MBP, 100);
+        let deltas = vec![
+            add_delta(OrderSide::Buy, "100.00", 10, 10),
+            OrderBookDelta::clear(instrument_id, 0, 120, 120),
+            add_delta(OrderSide::Buy, "99.00", 10, 130),
+        ];
+
+        let output = checkpointer.process_all(&deltas);
+
+        assert_eq!(checkpointer.snapshot_count(), 0);
+        assert_eq!(output, deltas);
+    }
+
+    #[rstest]
+    fn test_replay_from_checkpoint_matches_full_replay() {
+        let instrument_id = InstrumentId::from("ESH4.GLBX");
+        let mut checkpointer = BookSnapshotCheckpointer::new(instrument_id, BookType::L2_MBP, 100);
+        let output = checkpointer.process_all(&stub_deltas());
+
+        let start = 200;
+        let index = find_checkpoint_index(&output, start).unwrap();
+        assert_eq!(output[index].ts_init, 105);
+
+        let mut full = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let mut partial = OrderBook::new(instrument_id, BookType::L2_MBP);
+        for delta in output.iter().filter(|d| d.ts_init <= start) {
+            full.apply_delta(*delta);
+        }
+        for delta in output[index..].iter().filter(|d| d.ts_init <= start) {
+            partial.apply_delta(*delta);
+        }
+
+        assert_eq!(partial.best_bid_price(), full.best_bid_price());
+        assert_eq!(partial.best_ask_price(), full.best_ask_price());
+        assert_eq!(partial.best_bid_size(), full.best_bid_size());
+        assert_eq!(partial.best_ask_size(), full.best_ask_size());
+    }
+
+    #[rstest]
+    fn test_find_checkpoint_index_when_none() {
+        assert_eq!(find_checkpoint_index(&stub_deltas(), 1_000), None);
+    }
+}
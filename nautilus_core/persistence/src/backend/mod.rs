@@ -13,5 +13,6 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod checkpoint;
 pub mod kmerge_batch;
 pub mod session;
@@ -17,14 +17,18 @@ use std::{collections::HashMap, sync::Arc, vec::IntoIter};
 
 use compare::Compare;
 use datafusion::{
+    arrow::array::{Array, UInt64Array},
     error::Result,
     logical_expr::{col, expr::Sort},
     physical_plan::SendableRecordBatchStream,
     prelude::*,
 };
 use futures::StreamExt;
-use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{Data, HasTsInit};
+use nautilus_core::{ffi::cvec::CVec, time::UnixNanos};
+use nautilus_model::{
+    data::{delta::OrderBookDelta, Data, HasTsInit},
+    enums::BookAction,
+};
 use pyo3::prelude::*;
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
@@ -114,6 +118,71 @@ impl DataBackendSession {
     where
         T: DecodeDataFromRecordBatch + Into<Data>,
     {
+        self.register_parquet_file(table_name, file_path)?;
+
+        let default_query = format!("SELECT * FROM {}", &table_name);
+        let sql_query = sql_query.unwrap_or(&default_query);
+        let query = self.runtime.block_on(self.session_ctx.sql(sql_query))?;
+
+        let batch_stream = self.runtime.block_on(query.execute_stream())?;
+
+        self.add_batch_stream::<T>(batch_stream);
+        Ok(())
+    }
+
+    /// Query an order book deltas file starting from the last book checkpoint at or
+    /// before `start`, rather than from the beginning of the file.
+    ///
+    /// A checkpoint is any `CLEAR` delta, which includes the snapshots interleaved by
+    /// a [`BookSnapshotCheckpointer`](super::checkpoint::BookSnapshotCheckpointer). The data from the checkpoint up to `start` is still
+    /// returned so that the book can be rebuilt, followed by all data up to `end` (if given).
+    ///
+    /// Returns the `ts_init` of the checkpoint used, or `None` if the file contains no
+    /// checkpoint at or before `start` (in which case the whole file is replayed).
+    ///
+    /// # Safety
+    ///
+    /// The file data must be ordered by the `ts_init` in ascending order for this
+    /// to work correctly.
+    pub fn add_deltas_file_from_checkpoint(
+        &mut self,
+        table_name: &str,
+        file_path: &str,
+        start: UnixNanos,
+        end: Option<UnixNanos>,
+    ) -> Result<Option<UnixNanos>> {
+        self.register_parquet_file(table_name, file_path)?;
+
+        let checkpoint_query = format!(
+            "SELECT MAX(ts_init) FROM {table_name} WHERE action = {} AND ts_init <= {start}",
+            BookAction::Clear as u8
+        );
+        let checkpoint_df = self
+            .runtime
+            .block_on(self.session_ctx.sql(&checkpoint_query))?;
+        let batches = self.runtime.block_on(checkpoint_df.collect())?;
+        let checkpoint = batches
+            .first()
+            .and_then(|batch| batch.column(0).as_any().downcast_ref::<UInt64Array>())
+            .filter(|array| !array.is_empty() && array.is_valid(0))
+            .map(|array| array.value(0));
+
+        let mut sql_query = format!(
+            "SELECT * FROM {table_name} WHERE ts_init >= {}",
+            checkpoint.unwrap_or(0)
+        );
+        if let Some(end) = end {
+            sql_query.push_str(&format!(" AND ts_init <= {end}"));
+        }
+
+        let query = self.runtime.block_on(self.session_ctx.sql(&sql_query))?;
+        let batch_stream = self.runtime.block_on(query.execute_stream())?;
+
+        self.add_batch_stream::<OrderBookDelta>(batch_stream);
+        Ok(checkpoint)
+    }
+
+    fn register_parquet_file(&mut self, table_name: &str, file_path: &str) -> Result<()> {
         let parquet_options = ParquetReadOptions::<'_> {
             skip_metadata: Some(false),
             file_sort_order: vec![vec![Expr::Sort(Sort {
@@ -127,16 +196,7 @@ impl DataBackendSession {
             table_name,
             file_path,
             parquet_options,
-        ))?;
-
-        let default_query = format!("SELECT * FROM {}", &table_name);
-        let sql_query = sql_query.unwrap_or(&default_query);
-        let query = self.runtime.block_on(self.session_ctx.sql(sql_query))?;
-
-        let batch_stream = self.runtime.block_on(query.execute_stream())?;
-
-        self.add_batch_stream::<T>(batch_stream);
-        Ok(())
+        ))
     }
 
     fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
@@ -0,0 +1,491 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Construction of continuous futures series from individual contract data.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Result};
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, time::UnixNanos};
+use nautilus_model::{
+    data::{bar::Bar, trade::TradeTick},
+    identifiers::instrument_id::InstrumentId,
+    instruments::Instrument,
+    types::price::Price,
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// The rule which determines when a continuous series rolls to the next contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RollRule {
+    /// Roll a fixed number of calendar days before the front contract expires.
+    CalendarDaysBeforeExpiry(u32),
+    /// Roll at the start of the day following the first day on which the next
+    /// contract traded more volume than the front contract (or at expiry if it never does).
+    VolumeCrossover,
+}
+
+/// The method used to back-adjust historical prices across a roll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdjustmentMethod {
+    /// No adjustment, the series contains the raw contract prices.
+    None,
+    /// Add the price difference at each roll to all prior data (Panama canal method).
+    Difference,
+    /// Multiply all prior data by the price ratio at each roll.
+    Ratio,
+}
+
+/// Data which can be included in a continuous futures series.
+pub trait ContinuousData: Clone {
+    fn ts_event(&self) -> UnixNanos;
+    /// The volume traded, used to determine volume crossover rolls.
+    fn volume(&self) -> f64;
+    /// The price used to measure the gap between contracts at a roll.
+    fn reference_price(&self) -> Price;
+    /// Returns a copy relabeled with `instrument_id` and with every price mapped by `adjust`.
+    fn adjusted(
+        &self,
+        instrument_id: InstrumentId,
+        adjust: &dyn Fn(Price) -> Result<Price>,
+    ) -> Result<Self>;
+}
+
+impl ContinuousData for Bar {
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn volume(&self) -> f64 {
+        self.volume.as_f64()
+    }
+
+    fn reference_price(&self) -> Price {
+        self.close
+    }
+
+    fn adjusted(
+        &self,
+        instrument_id: InstrumentId,
+        adjust: &dyn Fn(Price) -> Result<Price>,
+    ) -> Result<Self> {
+        let mut bar_type = self.bar_type;
+        bar_type.instrument_id = instrument_id;
+        Ok(Self::new(
+            bar_type,
+            adjust(self.open)?,
+            adjust(self.high)?,
+            adjust(self.low)?,
+            adjust(self.close)?,
+            self.volume,
+            self.ts_event,
+            self.ts_init,
+        ))
+    }
+}
+
+impl ContinuousData for TradeTick {
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn volume(&self) -> f64 {
+        self.size.as_f64()
+    }
+
+    fn reference_price(&self) -> Price {
+        self.price
+    }
+
+    fn adjusted(
+        &self,
+        instrument_id: InstrumentId,
+        adjust: &dyn Fn(Price) -> Result<Price>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            instrument_id,
+            adjust(self.price)?,
+            self.size,
+            self.aggressor_side,
+            self.trade_id,
+            self.ts_event,
+            self.ts_init,
+        ))
+    }
+}
+
+/// The data for a single futures contract.
+#[derive(Clone, Debug)]
+pub struct ContractSeries<T> {
+    pub instrument_id: InstrumentId,
+    pub expiration_ns: UnixNanos,
+    pub data: Vec<T>,
+}
+
+impl<T: ContinuousData> ContractSeries<T> {
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, expiration_ns: UnixNanos, data: Vec<T>) -> Self {
+        Self {
+            instrument_id,
+            expiration_ns,
+            data,
+        }
+    }
+
+    /// Creates a new [`ContractSeries`] for the given dated `instrument`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instrument has no expiration.
+    pub fn from_instrument(instrument: &dyn Instrument, data: Vec<T>) -> Result<Self> {
+        let expiration_ns = instrument
+            .expiration_ns()
+            .ok_or_else(|| anyhow!("Instrument {} has no expiration", instrument.id()))?;
+        Ok(Self::new(instrument.id(), expiration_ns, data))
+    }
+}
+
+/// Represents an element of a continuous series, tagged with its source contract.
+#[derive(Clone, Debug)]
+pub struct ContinuousItem<T> {
+    /// The (adjusted) data, relabeled with the continuous instrument ID.
+    pub data: T,
+    /// The contract which was active when the data occurred.
+    pub contract_id: InstrumentId,
+}
+
+/// A roll from one contract to the next.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Roll {
+    pub ts_roll: UnixNanos,
+    pub from_contract: InstrumentId,
+    pub to_contract: InstrumentId,
+    /// The last price of the expiring contract before the roll.
+    pub from_price: f64,
+    /// The last price of the new contract before the roll.
+    pub to_price: f64,
+}
+
+/// Builds continuous futures series from individual contract data.
+#[derive(Clone, Debug)]
+pub struct ContinuousFuturesBuilder {
+    /// The instrument ID the continuous series is labeled with (e.g. `ES.c.0.GLBX`).
+    pub continuous_id: InstrumentId,
+    pub roll_rule: RollRule,
+    pub adjustment: AdjustmentMethod,
+}
+
+impl ContinuousFuturesBuilder {
+    #[must_use]
+    pub fn new(
+        continuous_id: InstrumentId,
+        roll_rule: RollRule,
+        adjustment: AdjustmentMethod,
+    ) -> Self {
+        Self {
+            continuous_id,
+            roll_rule,
+            adjustment,
+        }
+    }
+
+    /// Returns the rolls for the given contracts, ordered by expiration.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any two contracts share the same expiration.
+    /// - If a contract has no data before its roll.
+    pub fn rolls<T: ContinuousData>(&self, contracts: &[ContractSeries<T>]) -> Result<Vec<Roll>> {
+        let contracts = sorted_by_expiration(contracts)?;
+        let mut rolls = Vec::with_capacity(contracts.len().saturating_sub(1));
+        let mut ts_prev_roll = 0;
+
+        for pair in contracts.windows(2) {
+            let (front, next) = (pair[0], pair[1]);
+            let ts_roll = self.roll_time(front, next).max(ts_prev_roll);
+
+            let from_price = price_at(front, ts_roll).ok_or_else(|| {
+                anyhow!("No data for {} before roll {ts_roll}", front.instrument_id)
+            })?;
+            let to_price = price_at(next, ts_roll)
+                .or_else(|| next.data.first().map(|d| d.reference_price().as_f64()))
+                .ok_or_else(|| anyhow!("No data for {}", next.instrument_id))?;
+
+            rolls.push(Roll {
+                ts_roll,
+                from_contract: front.instrument_id,
+                to_contract: next.instrument_id,
+                from_price,
+                to_price,
+            });
+            ts_prev_roll = ts_roll;
+        }
+
+        Ok(rolls)
+    }
+
+    /// Builds the continuous series from the given contracts.
+    ///
+    /// Each contract contributes its data from the previous roll (inclusive) up to its
+    /// own roll (exclusive), and prices are back-adjusted so the series is continuous
+    /// with the most recent contract.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the rolls cannot be determined (see [`Self::rolls`]).
+    /// - If an adjusted price is invalid.
+    pub fn build<T: ContinuousData>(
+        &self,
+        contracts: &[ContractSeries<T>],
+    ) -> Result<Vec<ContinuousItem<T>>> {
+        let rolls = self.rolls(contracts)?;
+        let contracts = sorted_by_expiration(contracts)?;
+        let mut output = Vec::new();
+
+        for (i, contract) in contracts.iter().enumerate() {
+            let ts_start = if i == 0 { 0 } else { rolls[i - 1].ts_roll };
+            let ts_end = rolls.get(i).map_or(UnixNanos::MAX, |roll| roll.ts_roll);
+
+            // Adjustments from every later roll apply to this contract's data
+            let (offset, factor) = rolls[i..].iter().fold((0.0, 1.0), |(offset, factor), r| {
+                (
+                    offset + (r.to_price - r.from_price),
+                    factor * (r.to_price / r.from_price),
+                )
+            });
+            let adjustment = self.adjustment;
+            let adjust = move |price: Price| -> Result<Price> {
+                match adjustment {
+                    AdjustmentMethod::None => Ok(price),
+                    AdjustmentMethod::Difference => {
+                        Ok(Price::new(price.as_f64() + offset, price.precision)?)
+                    }
+                    AdjustmentMethod::Ratio => {
+                        Ok(Price::new(price.as_f64() * factor, price.precision)?)
+                    }
+                }
+            };
+
+            let mut data: Vec<&T> = contract
+                .data
+                .iter()
+                .filter(|d| d.ts_event() >= ts_start && d.ts_event() < ts_end)
+                .collect();
+            data.sort_by_key(|d| d.ts_event());
+
+            for item in data {
+                output.push(ContinuousItem {
+                    data: item.adjusted(self.continuous_id, &adjust)?,
+                    contract_id: contract.instrument_id,
+                });
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn roll_time<T: ContinuousData>(
+        &self,
+        front: &ContractSeries<T>,
+        next: &ContractSeries<T>,
+    ) -> UnixNanos {
+        match self.roll_rule {
+            RollRule::CalendarDaysBeforeExpiry(days) => front
+                .expiration_ns
+                .saturating_sub(u64::from(days) * NANOSECONDS_IN_DAY),
+            RollRule::VolumeCrossover => {
+                let front_volume = daily_volume(front);
+                let next_volume = daily_volume(next);
+                next_volume
+                    .iter()
+                    .find(|(day, volume)| {
+                        **volume > front_volume.get(day).copied().unwrap_or(0.0)
+                            && front_volume.contains_key(day)
+                    })
+                    .map(|(day, _)| (day + 1) * NANOSECONDS_IN_DAY)
+                    .filter(|ts| *ts < front.expiration_ns)
+                    .unwrap_or(front.expiration_ns)
+            }
+        }
+    }
+}
+
+fn sorted_by_expiration<T>(contracts: &[ContractSeries<T>]) -> Result<Vec<&ContractSeries<T>>> {
+    let mut sorted: Vec<&ContractSeries<T>> = contracts.iter().collect();
+    sorted.sort_by_key(|c| c.expiration_ns);
+    for pair in sorted.windows(2) {
+        if pair[0].expiration_ns == pair[1].expiration_ns {
+            bail!(
+                "Contracts {} and {} have the same expiration",
+                pair[0].instrument_id,
+                pair[1].instrument_id
+            );
+        }
+    }
+    Ok(sorted)
+}
+
+/// Returns the total volume per UTC day (days since the UNIX epoch).
+fn daily_volume<T: ContinuousData>(contract: &ContractSeries<T>) -> BTreeMap<u64, f64> {
+    let mut volumes = BTreeMap::new();
+    for item in &contract.data {
+        *volumes
+            .entry(item.ts_event() / NANOSECONDS_IN_DAY)
+            .or_insert(0.0) += item.volume();
+    }
+    volumes
+}
+
+/// Returns the last reference price strictly before `ts`.
+fn price_at<T: ContinuousData>(contract: &ContractSeries<T>, ts: UnixNanos) -> Option<f64> {
+    contract
+        .data
+        .iter()
+        .filter(|d| d.ts_event() < ts)
+        .max_by_key(|d| d.ts_event())
+        .map(|d| d.reference_price().as_f64())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{data::bar::BarType, types::quantity::Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    const DAY: u64 = NANOSECONDS_IN_DAY;
+
+    fn bar(instrument_id: &str, close: &str, volume: u64, day: u64) -> Bar {
+        let bar_type = BarType::from_str(&format!("{instrument_id}-1-DAY-LAST-EXTERNAL")).unwrap();
+        let px = Price::from(close);
+        Bar::new(
+            bar_type,
+            px,
+            px,
+            px,
+            px,
+            Quantity::from(volume as i64),
+            day * DAY,
+            day * DAY,
+        )
+    }
+
+    fn contracts() -> Vec<ContractSeries<Bar>> {
+        let front = ContractSeries::new(
+            InstrumentId::from("ESH4.GLBX"),
+            10 * DAY,
+            vec![
+                bar("ESH4.GLBX", "100.00", 1_000, 1),
+                bar("ESH4.GLBX", "101.00", 1_000, 2),
+                bar("ESH4.GLBX", "102.00", 400, 3),
+                bar("ESH4.GLBX", "103.00", 100, 4),
+            ],
+        );
+        let next = ContractSeries::new(
+            InstrumentId::from("ESM4.GLBX"),
+            20 * DAY,
+            vec![
+                bar("ESM4.GLBX", "105.00", 100, 1),
+                bar("ESM4.GLBX", "106.00", 500, 2),
+                bar("ESM4.GLBX", "107.00", 800, 3),
+                bar("ESM4.GLBX", "108.00", 900, 4),
+            ],
+        );
+        // Provided out of order to check sorting
+        vec![next, front]
+    }
+
+    fn builder(roll_rule: RollRule, adjustment: AdjustmentMethod) -> ContinuousFuturesBuilder {
+        ContinuousFuturesBuilder::new(InstrumentId::from("ES.c.0.GLBX"), roll_rule, adjustment)
+    }
+
+    #[rstest]
+    fn test_calendar_roll_without_adjustment() {
+        let builder = builder(
+            RollRule::CalendarDaysBeforeExpiry(7),
+            AdjustmentMethod::None,
+        );
+
+        let series = builder.build(&contracts()).unwrap();
+
+        let closes: Vec<String> = series.iter().map(|i| i.data.close.to_string()).collect();
+        let tags: Vec<String> = series.iter().map(|i| i.contract_id.to_string()).collect();
+        assert_eq!(closes, vec!["100.00", "101.00", "107.00", "108.00"]);
+        assert_eq!(
+            tags,
+            vec!["ESH4.GLBX", "ESH4.GLBX", "ESM4.GLBX", "ESM4.GLBX"]
+        );
+        assert_eq!(
+            series[0].data.bar_type.instrument_id,
+            InstrumentId::from("ES.c.0.GLBX")
+        );
+    }
+
+    #[rstest]
+    fn test_volume_crossover_roll() {
+        let builder = builder(RollRule::VolumeCrossover, AdjustmentMethod::None);
+
+        let rolls = builder.rolls(&contracts()).unwrap();
+
+        // Crossover observed on day 3, so the roll happens at the start of day 4
+        assert_eq!(rolls.len(), 1);
+        assert_eq!(rolls[0].ts_roll, 4 * DAY);
+        assert_eq!(rolls[0].from_price, 102.0);
+        assert_eq!(rolls[0].to_price, 107.0);
+    }
+
+    #[rstest]
+    fn test_difference_back_adjustment() {
+        let builder = builder(RollRule::VolumeCrossover, AdjustmentMethod::Difference);
+
+        let series = builder.build(&contracts()).unwrap();
+
+        let closes: Vec<String> = series.iter().map(|i| i.data.close.to_string()).collect();
+        assert_eq!(closes, vec!["105.00", "106.00", "107.00", "108.00"]);
+    }
+
+    #[rstest]
+    fn test_ratio_back_adjustment() {
+        let builder = builder(
+            RollRule::CalendarDaysBeforeExpiry(7),
+            AdjustmentMethod::Ratio,
+        );
+
+        let series = builder.build(&contracts()).unwrap();
+
+        // Roll at day 3, with a ratio of 106 / 101 from the day 2 closes
+        assert_eq!(series[0].data.close, Price::from("104.95"));
+        assert_eq!(series[2].data.close, Price::from("107.00"));
+    }
+
+    #[rstest]
+    fn test_duplicate_expirations_error() {
+        let mut contracts = contracts();
+        contracts[0].expiration_ns = contracts[1].expiration_ns;
+
+        let builder = builder(RollRule::VolumeCrossover, AdjustmentMethod::None);
+
+        assert!(builder.build(&contracts).is_err());
+    }
+}
@@ -15,6 +15,7 @@
 
 pub mod arrow;
 pub mod backend;
+pub mod continuous;
 pub mod db;
 
 #[cfg(feature = "python")]
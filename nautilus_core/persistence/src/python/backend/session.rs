@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err};
+use nautilus_core::{ffi::cvec::CVec, python::to_pyruntime_err, time::UnixNanos};
 use nautilus_model::data::{
     bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
 };
@@ -83,6 +83,22 @@ impl DataBackendSession {
         }
     }
 
+    /// Query an order book deltas file from the last book checkpoint at or before
+    /// `start`, returning the `ts_init` of the checkpoint used (if any).
+    #[pyo3(name = "add_deltas_file_from_checkpoint")]
+    fn add_deltas_file_from_checkpoint_py(
+        mut slf: PyRefMut<'_, Self>,
+        table_name: &str,
+        file_path: &str,
+        start: UnixNanos,
+        end: Option<UnixNanos>,
+    ) -> PyResult<Option<UnixNanos>> {
+        let _guard = slf.runtime.enter();
+
+        slf.add_deltas_file_from_checkpoint(table_name, file_path, start, end)
+            .map_err(to_pyruntime_err)
+    }
+
     fn to_query_result(mut slf: PyRefMut<'_, Self>) -> DataQueryResult {
         let query_result = slf.get_query_result();
         DataQueryResult::new(query_result, slf.chunk_size)
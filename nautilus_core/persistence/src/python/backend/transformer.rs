@@ -19,9 +19,12 @@ use datafusion::arrow::{
     datatypes::Schema, error::ArrowError, ipc::writer::StreamWriter, record_batch::RecordBatch,
 };
 use nautilus_core::python::to_pyvalue_err;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, is_monotonically_increasing_by_init,
-    quote::QuoteTick, trade::TradeTick,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10,
+        is_monotonically_increasing_by_init, quote::QuoteTick, trade::TradeTick,
+    },
+    enums::BookType,
 };
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError, PyValueError},
@@ -29,7 +32,10 @@ use pyo3::{
     types::{IntoPyDict, PyBytes, PyDict, PyType},
 };
 
-use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+use crate::{
+    arrow::{ArrowSchemaProvider, EncodeToRecordBatch},
+    backend::checkpoint::BookSnapshotCheckpointer,
+};
 
 const ERROR_EMPTY_DATA: &str = "`data` was empty";
 const ERROR_MONOTONICITY: &str = "`data` was not monotonically increasing by the `ts_init` field";
@@ -102,7 +108,7 @@ impl DataTransformer {
     }
 
     /// Transforms the given record `batches` into Python `bytes`.
-    fn record_batch_to_pybytes(
+    pub(crate) fn record_batch_to_pybytes(
         py: Python<'_>,
         batch: RecordBatch,
         schema: Schema,
@@ -222,6 +228,30 @@ impl DataTransformer {
         }
     }
 
+    /// Return Python `bytes` for the given deltas with full book snapshots interleaved
+    /// every `interval_ns`, so that readers can start from the nearest checkpoint.
+    #[staticmethod]
+    pub fn pyo3_order_book_deltas_to_checkpointed_record_batch_bytes(
+        py: Python<'_>,
+        data: Vec<OrderBookDelta>,
+        book_type: BookType,
+        interval_ns: u64,
+    ) -> PyResult<Py<PyBytes>> {
+        if data.is_empty() {
+            return Err(PyValueError::new_err(ERROR_EMPTY_DATA));
+        }
+        if interval_ns == 0 {
+            return Err(PyValueError::new_err("`interval_ns` must be positive"));
+        }
+
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        let instrument_id = data.first().unwrap().instrument_id;
+        let mut checkpointer = BookSnapshotCheckpointer::new(instrument_id, book_type, interval_ns);
+        let checkpointed = checkpointer.process_all(&data);
+
+        Self::pyo3_order_book_deltas_to_record_batch_bytes(py, checkpointed)
+    }
+
     #[staticmethod]
     pub fn pyo3_order_book_depth10_to_record_batch_bytes(
         py: Python<'_>,
@@ -16,11 +16,14 @@
 use std::{collections::HashMap, io::Cursor, str::FromStr};
 
 use datafusion::arrow::ipc::reader::StreamReader;
-use nautilus_core::python::to_pyvalue_err;
+use nautilus_core::{python::to_pyvalue_err, time::UnixNanos};
 use nautilus_model::{data::quote::QuoteTick, identifiers::instrument_id::InstrumentId};
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyBytes};
 
-use crate::arrow::DecodeFromRecordBatch;
+use crate::{
+    arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch},
+    python::backend::transformer::DataTransformer,
+};
 
 #[pyclass]
 pub struct QuoteTickDataWrangler {
@@ -84,4 +87,71 @@ impl QuoteTickDataWrangler {
 
         Ok(quotes)
     }
+
+    fn process_arrays(
+        &self,
+        bid_prices: Vec<f64>,
+        ask_prices: Vec<f64>,
+        bid_sizes: Vec<f64>,
+        ask_sizes: Vec<f64>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Vec<QuoteTick>> {
+        QuoteTick::from_arrays(
+            self.instrument_id,
+            self.price_precision,
+            self.size_precision,
+            &bid_prices,
+            &ask_prices,
+            &bid_sizes,
+            &ask_sizes,
+            &ts_events,
+            &ts_inits,
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn process_raw_arrays(
+        &self,
+        bid_prices_raw: Vec<i64>,
+        ask_prices_raw: Vec<i64>,
+        bid_sizes_raw: Vec<u64>,
+        ask_sizes_raw: Vec<u64>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Vec<QuoteTick>> {
+        QuoteTick::from_raw_arrays(
+            self.instrument_id,
+            self.price_precision,
+            self.size_precision,
+            &bid_prices_raw,
+            &ask_prices_raw,
+            &bid_sizes_raw,
+            &ask_sizes_raw,
+            &ts_events,
+            &ts_inits,
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    /// Encodes the given columns straight to Arrow IPC bytes, without creating
+    /// a Python object per tick (for use by the catalog writer).
+    #[allow(clippy::too_many_arguments)]
+    fn arrays_to_record_batch_bytes(
+        &self,
+        py: Python,
+        bid_prices: Vec<f64>,
+        ask_prices: Vec<f64>,
+        bid_sizes: Vec<f64>,
+        ask_sizes: Vec<f64>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Py<PyBytes>> {
+        let quotes = self.process_arrays(
+            bid_prices, ask_prices, bid_sizes, ask_sizes, ts_events, ts_inits,
+        )?;
+        let batch = QuoteTick::encode_batch(&self.metadata, &quotes).map_err(to_pyvalue_err)?;
+        let schema = QuoteTick::get_schema(Some(self.metadata.clone()));
+        DataTransformer::record_batch_to_pybytes(py, batch, schema)
+    }
 }
@@ -16,11 +16,29 @@
 use std::{collections::HashMap, io::Cursor, str::FromStr};
 
 use datafusion::arrow::ipc::reader::StreamReader;
-use nautilus_core::python::to_pyvalue_err;
-use nautilus_model::{data::trade::TradeTick, identifiers::instrument_id::InstrumentId};
-use pyo3::prelude::*;
+use nautilus_core::{python::to_pyvalue_err, time::UnixNanos};
+use nautilus_model::{
+    data::trade::TradeTick,
+    enums::AggressorSide,
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+};
+use pyo3::{prelude::*, types::PyBytes};
 
-use crate::arrow::DecodeFromRecordBatch;
+use crate::{
+    arrow::{ArrowSchemaProvider, DecodeFromRecordBatch, EncodeToRecordBatch},
+    python::backend::transformer::DataTransformer,
+};
+
+fn parse_aggressor_sides(values: &[u8]) -> PyResult<Vec<AggressorSide>> {
+    values
+        .iter()
+        .map(|value| {
+            AggressorSide::from_repr(*value as usize).ok_or_else(|| {
+                to_pyvalue_err(format!("Invalid `AggressorSide` value, was {value}"))
+            })
+        })
+        .collect()
+}
 
 #[pyclass]
 pub struct TradeTickDataWrangler {
@@ -84,4 +102,81 @@ impl TradeTickDataWrangler {
 
         Ok(ticks)
     }
+
+    fn process_arrays(
+        &self,
+        prices: Vec<f64>,
+        sizes: Vec<f64>,
+        aggressor_sides: Vec<u8>,
+        trade_ids: Vec<&str>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Vec<TradeTick>> {
+        let aggressor_sides = parse_aggressor_sides(&aggressor_sides)?;
+        let trade_ids: Vec<TradeId> = trade_ids.into_iter().map(TradeId::from).collect();
+
+        TradeTick::from_arrays(
+            self.instrument_id,
+            self.price_precision,
+            self.size_precision,
+            &prices,
+            &sizes,
+            &aggressor_sides,
+            &trade_ids,
+            &ts_events,
+            &ts_inits,
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn process_raw_arrays(
+        &self,
+        prices_raw: Vec<i64>,
+        sizes_raw: Vec<u64>,
+        aggressor_sides: Vec<u8>,
+        trade_ids: Vec<&str>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Vec<TradeTick>> {
+        let aggressor_sides = parse_aggressor_sides(&aggressor_sides)?;
+        let trade_ids: Vec<TradeId> = trade_ids.into_iter().map(TradeId::from).collect();
+
+        TradeTick::from_raw_arrays(
+            self.instrument_id,
+            self.price_precision,
+            self.size_precision,
+            &prices_raw,
+            &sizes_raw,
+            &aggressor_sides,
+            &trade_ids,
+            &ts_events,
+            &ts_inits,
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    /// Returns the given trade columns as Arrow IPC stream bytes.
+    #[allow(clippy::too_many_arguments)]
+    fn arrays_to_record_batch_bytes(
+        &self,
+        py: Python,
+        prices: Vec<f64>,
+        sizes: Vec<f64>,
+        aggressor_sides: Vec<u8>,
+        trade_ids: Vec<&str>,
+        ts_events: Vec<UnixNanos>,
+        ts_inits: Vec<UnixNanos>,
+    ) -> PyResult<Py<PyBytes>> {
+        let trades = self.process_arrays(
+            prices,
+            sizes,
+            aggressor_sides,
+            trade_ids,
+            ts_events,
+            ts_inits,
+        )?;
+        let batch = TradeTick::encode_batch(&self.metadata, &trades).map_err(to_pyvalue_err)?;
+        let schema = TradeTick::get_schema(Some(self.metadata.clone()));
+        DataTransformer::record_batch_to_pybytes(py, batch, schema)
+    }
 }
@@ -13,13 +13,23 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use datafusion::parquet::arrow::ArrowWriter;
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
-    trade::TradeTick, Data,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, order::BookOrder,
+        quote::QuoteTick, trade::TradeTick, Data,
+    },
+    enums::{BookAction, BookType, OrderSide},
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
 };
 use nautilus_persistence::{
-    backend::session::{DataBackendSession, DataQueryResult, QueryResult},
+    arrow::{ArrowSchemaProvider, EncodeToRecordBatch},
+    backend::{
+        checkpoint::BookSnapshotCheckpointer,
+        session::{DataBackendSession, DataQueryResult, QueryResult},
+    },
     python::backend::session::NautilusDataType,
 };
 #[cfg(target_os = "linux")]
@@ -299,3 +309,53 @@ fn test_bar_query() {
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
+
+#[rstest]
+fn test_order_book_delta_query_from_checkpoint() {
+    let instrument_id = InstrumentId::from("ESH4.GLBX");
+    let deltas: Vec<OrderBookDelta> = (0..100)
+        .map(|i| {
+            let side = if i % 2 == 0 {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            };
+            let price = if side == OrderSide::Buy {
+                Price::from_raw(100_000_000_000 - i * 1_000_000_000, 2).unwrap()
+            } else {
+                Price::from_raw(101_000_000_000 + i * 1_000_000_000, 2).unwrap()
+            };
+            let order = BookOrder::new(side, price, Quantity::from(10), 0);
+            let ts = (i as u64 + 1) * 10;
+            OrderBookDelta::new(instrument_id, BookAction::Add, order, 0, i as u64, ts, ts)
+        })
+        .collect();
+
+    let mut checkpointer = BookSnapshotCheckpointer::new(instrument_id, BookType::L2_MBP, 250);
+    let checkpointed = checkpointer.process_all(&deltas);
+
+    let metadata = OrderBookDelta::get_metadata(&instrument_id, 2, 0);
+    let batch = OrderBookDelta::encode_batch(&metadata, &checkpointed).unwrap();
+    let file_path = std::env::temp_dir().join("test_deltas_checkpointed.parquet");
+    {
+        let file = std::fs::File::create(&file_path).unwrap();
+        let schema = OrderBookDelta::get_schema(Some(metadata));
+        let mut writer = ArrowWriter::try_new(file, schema.into(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    let mut catalog = DataBackendSession::new(1_000);
+    let checkpoint = catalog
+        .add_deltas_file_from_checkpoint("deltas_cp", file_path.to_str().unwrap(), 800, None)
+        .unwrap();
+    let data: Vec<Data> = catalog.get_query_result().collect();
+
+    // Checkpoints fall on the first deltas at or after 250, 500 and 750
+    assert_eq!(checkpoint, Some(750));
+    assert!(matches!(&data[0], Data::Delta(d) if d.action == BookAction::Clear));
+    assert!(data.len() < checkpointed.len());
+    assert!(is_monotonically_increasing_by_init(&data));
+
+    std::fs::remove_file(file_path).unwrap();
+}
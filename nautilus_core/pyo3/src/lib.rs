@@ -62,6 +62,12 @@ fn nautilus_pyo3(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
     re_export_module_attributes(m, n)?;
 
+    let n = "backtest";
+    let submodule = pyo3::wrap_pymodule!(nautilus_backtest::python::backtest);
+    m.add_wrapped(submodule)?;
+    sys_modules.set_item(format!("{module_name}.{n}"), m.getattr(n)?)?;
+    re_export_module_attributes(m, n)?;
+
     let n = "databento";
     let submodule = pyo3::wrap_pymodule!(databento);
     m.add_wrapped(submodule)?;
@@ -176,11 +176,12 @@ cdef class CashAccount(Account):
         Condition.not_none(last_qty, "last_qty")
         Condition.not_equal(liquidity_side, LiquiditySide.NO_LIQUIDITY_SIDE, "liquidity_side", "NO_LIQUIDITY_SIDE")
 
-        cdef double notional = instrument.notional_value(
+        cdef Money notional_value = instrument.notional_value(
             quantity=last_qty,
             price=last_px,
             use_quote_for_inverse=use_quote_for_inverse,
-        ).as_f64_c()
+        )
+        cdef double notional = notional_value.as_f64_c()
 
         cdef double commission
         if liquidity_side == LiquiditySide.MAKER:
@@ -192,10 +193,8 @@ cdef class CashAccount(Account):
                 f"invalid LiquiditySide, was {liquidity_side_to_str(liquidity_side)}"
             )
 
-        if instrument.is_inverse and not use_quote_for_inverse:
-            return Money(commission, instrument.base_currency)
-        else:
-            return Money(commission, instrument.quote_currency)
+        # In the notional currency (base for inverse, settlement for quanto, otherwise quote)
+        return Money(commission, notional_value.currency)
 
     cpdef Money calculate_balance_locked(
         self,
@@ -16,6 +16,7 @@
 from decimal import Decimal
 
 from nautilus_trader.accounting.accounts.base cimport Account
+from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
@@ -62,6 +63,7 @@ cdef class MarginAccount(Account):
         Quantity quantity,
         Price price,
         bint use_quote_for_inverse=*,
+        OrderSide side=*,
     )
 
     cpdef Money calculate_margin_maint(
@@ -72,3 +74,11 @@ cdef class MarginAccount(Account):
         Price price,
         bint use_quote_for_inverse=*,
     )
+
+    cdef Money _calculate_binary_option_margin(
+        self,
+        Instrument instrument,
+        Quantity quantity,
+        Price price,
+        bint is_short,
+    )
@@ -26,6 +26,7 @@ from nautilus_trader.model.events.order cimport OrderFilled
 from nautilus_trader.model.functions cimport liquidity_side_to_str
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.instruments.binary_option cimport BinaryOption
 from nautilus_trader.model.objects cimport AccountBalance
 from nautilus_trader.model.objects cimport Currency
 from nautilus_trader.model.objects cimport MarginBalance
@@ -488,11 +489,12 @@ cdef class MarginAccount(Account):
         Condition.type(last_px, (Decimal, Price), "last_px")
         Condition.not_equal(liquidity_side, LiquiditySide.NO_LIQUIDITY_SIDE, "liquidity_side", "NO_LIQUIDITY_SIDE")
 
-        cdef double notional = instrument.notional_value(
+        cdef Money notional_value = instrument.notional_value(
             quantity=last_qty,
             price=last_px,
             use_quote_for_inverse=use_quote_for_inverse,
-        ).as_f64_c()
+        )
+        cdef double notional = notional_value.as_f64_c()
 
         cdef double commission
         if liquidity_side == LiquiditySide.MAKER:
@@ -504,10 +506,8 @@ cdef class MarginAccount(Account):
                 f"invalid `LiquiditySide`, was {liquidity_side_to_str(liquidity_side)}"
             )
 
-        if instrument.is_inverse and not use_quote_for_inverse:
-            return Money(commission, instrument.base_currency)
-        else:
-            return Money(commission, instrument.quote_currency)
+        # In the notional currency (base for inverse, settlement for quanto, otherwise quote)
+        return Money(commission, notional_value.currency)
 
     cpdef Money calculate_margin_init(
         self,
@@ -515,6 +515,7 @@ cdef class MarginAccount(Account):
         Quantity quantity,
         Price price,
         bint use_quote_for_inverse=False,
+        OrderSide side=OrderSide.NO_ORDER_SIDE,
     ):
         """
         Calculate the initial (order) margin.
@@ -522,6 +523,9 @@ cdef class MarginAccount(Account):
         Result will be in quote currency for standard instruments, or base
         currency for inverse instruments.
 
+        Binary options are fully collateralized (leverage is not applied), with
+        the margin being the maximum possible loss for the order `side`.
+
         Parameters
         ----------
         instrument : Instrument
@@ -532,6 +536,9 @@ cdef class MarginAccount(Account):
             The order price.
         use_quote_for_inverse : bool
             If inverse instrument calculations use quote currency (instead of base).
+        side : OrderSide, default ``NO_ORDER_SIDE``
+            The order side (only used for binary options, where ``NO_ORDER_SIDE`` is
+            treated as ``BUY``).
 
         Returns
         -------
@@ -542,11 +549,20 @@ cdef class MarginAccount(Account):
         Condition.not_none(quantity, "quantity")
         Condition.not_none(price, "price")
 
-        cdef double notional = instrument.notional_value(
+        if isinstance(instrument, BinaryOption):
+            return self._calculate_binary_option_margin(
+                instrument,
+                quantity,
+                price,
+                is_short=side == OrderSide.SELL,
+            )
+
+        cdef Money notional_value = instrument.notional_value(
             quantity=quantity,
             price=price,
             use_quote_for_inverse=use_quote_for_inverse,
-        ).as_f64_c()
+        )
+        cdef double notional = notional_value.as_f64_c()
 
         cdef double leverage = self._leverages.get(instrument.id, 0.0)
         if leverage == 0.0:
@@ -557,10 +573,8 @@ cdef class MarginAccount(Account):
         cdef double margin = adjusted_notional * float(instrument.margin_init)
         margin += (adjusted_notional * float(instrument.taker_fee) * 2.0)
 
-        if instrument.is_inverse and not use_quote_for_inverse:
-            return Money(margin, instrument.base_currency)
-        else:
-            return Money(margin, instrument.quote_currency)
+        # In the notional currency (base for inverse, settlement for quanto, otherwise quote)
+        return Money(margin, notional_value.currency)
 
     cpdef Money calculate_margin_maint(
         self,
@@ -576,6 +590,9 @@ cdef class MarginAccount(Account):
         Result will be in quote currency for standard instruments, or base
         currency for inverse instruments.
 
+        Binary options are fully collateralized (leverage is not applied), with
+        the margin being the maximum possible loss for the position `side`.
+
         Parameters
         ----------
         instrument : Instrument
@@ -597,11 +614,20 @@ cdef class MarginAccount(Account):
         Condition.not_none(instrument, "instrument")
         Condition.not_none(quantity, "quantity")
 
-        cdef double notional = instrument.notional_value(
+        if isinstance(instrument, BinaryOption):
+            return self._calculate_binary_option_margin(
+                instrument,
+                quantity,
+                price,
+                is_short=side == PositionSide.SHORT,
+            )
+
+        cdef Money notional_value = instrument.notional_value(
             quantity=quantity,
             price=price,
             use_quote_for_inverse=use_quote_for_inverse,
-        ).as_f64_c()
+        )
+        cdef double notional = notional_value.as_f64_c()
 
         cdef double leverage = float(self._leverages.get(instrument.id, 0.0))
         if leverage == 0.0:
@@ -612,10 +638,22 @@ cdef class MarginAccount(Account):
         cdef double margin = adjusted_notional * float(instrument.margin_maint)
         margin += adjusted_notional * float(instrument.taker_fee)
 
-        if instrument.is_inverse and not use_quote_for_inverse:
-            return Money(margin, instrument.base_currency)
-        else:
-            return Money(margin, instrument.quote_currency)
+        # In the notional currency (base for inverse, settlement for quanto, otherwise quote)
+        return Money(margin, notional_value.currency)
+
+    cdef Money _calculate_binary_option_margin(
+        self,
+        Instrument instrument,
+        Quantity quantity,
+        Price price,
+        bint is_short,
+    ):
+        # A long position can lose at most the premium paid, and a short position
+        # at most the difference between the premium received and the payout of 1
+        cdef double px = price.as_f64_c()
+        cdef double max_loss = quantity.as_f64_c() * ((1.0 - px) if is_short else px)
+        cdef double fee = quantity.as_f64_c() * px * float(instrument.taker_fee)
+        return Money(max_loss + fee, instrument.quote_currency)
 
     cpdef list calculate_pnls(
         self,
@@ -15,6 +15,7 @@
 
 from cpython.datetime cimport date
 
+from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.objects cimport Currency
@@ -36,3 +37,26 @@ cdef class RolloverInterestCalculator:
 
     cpdef object get_rate_data(self)
     cpdef object calc_overnight_rate(self, InstrumentId instrument_id, date timestamp)
+
+
+cdef class FinancingCalculator:
+    cdef dict _rates
+
+    cdef readonly int days_in_year
+    """The day count basis for daily accrual.\n\n:returns: `int`"""
+
+    cpdef bint has_rates(self, InstrumentId instrument_id)
+    cpdef double get_rate(self, InstrumentId instrument_id, PositionSide side)
+    cpdef double calc_financing(self, InstrumentId instrument_id, PositionSide side, double notional, int days=*)
+
+
+cdef class BorrowRateCalculator:
+    cdef dict _rates
+
+    cdef readonly int days_in_year
+    """The day count basis for accrual.\n\n:returns: `int`"""
+
+    cpdef bint has_rate(self, Currency currency)
+    cpdef double get_rate(self, Currency currency)
+    cpdef void update_rate(self, Currency currency, double rate)
+    cpdef double calc_borrow_fee(self, Currency currency, double notional, double hours)
@@ -19,6 +19,7 @@ from itertools import permutations
 import pandas as pd
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.functions cimport price_type_to_str
 from nautilus_trader.model.identifiers cimport InstrumentId
@@ -271,3 +272,231 @@ cdef class RolloverInterestCalculator:
             raise RuntimeError(f"cannot find rollover interest rate for {instrument_id} on {date}")  # pragma: no cover
 
         return Decimal(((<double>base_data['Value'] - <double>quote_data['Value']) / 365) / 100)
+
+
+cdef class FinancingCalculator:
+    """
+    Provides overnight financing (swap) calculations for FX and CFD positions.
+
+    Rates are annualized fractions quoted separately for the long and short side
+    of each instrument, where a positive rate credits the holder and a negative
+    rate debits the holder (e.g. -0.025 pays 2.5% per annum on the notional).
+
+    Parameters
+    ----------
+    rates : dict[InstrumentId, tuple[float, float]]
+        The annualized (long, short) financing rates per instrument.
+    days_in_year : int, default 360
+        The day count basis for converting annual rates to a daily accrual.
+
+    Raises
+    ------
+    ValueError
+        If `days_in_year` is not positive (> 0).
+
+    """
+
+    def __init__(self, dict rates not None, int days_in_year=360):
+        Condition.dict_types(rates, InstrumentId, tuple, "rates")
+        Condition.positive_int(days_in_year, "days_in_year")
+
+        self._rates = rates
+        self.days_in_year = days_in_year
+
+    cpdef bint has_rates(self, InstrumentId instrument_id):
+        """
+        Return whether financing rates are defined for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID to check.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return instrument_id in self._rates
+
+    cpdef double get_rate(self, InstrumentId instrument_id, PositionSide side):
+        """
+        Return the annualized financing rate for the given instrument and position side.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the rate.
+        side : PositionSide {``LONG``, ``SHORT``}
+            The position side for the rate.
+
+        Returns
+        -------
+        double
+            Zero if no rates are defined for the instrument or the side is ``FLAT``.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        cdef tuple rates = self._rates.get(instrument_id)
+        if rates is None:
+            return 0.0
+
+        if side == PositionSide.LONG:
+            return rates[0]
+        elif side == PositionSide.SHORT:
+            return rates[1]
+        else:
+            return 0.0
+
+    cpdef double calc_financing(
+        self,
+        InstrumentId instrument_id,
+        PositionSide side,
+        double notional,
+        int days=1,
+    ):
+        """
+        Return the financing amount accrued on the given notional over a number of days.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the calculation.
+        side : PositionSide {``LONG``, ``SHORT``}
+            The position side for the calculation.
+        notional : double
+            The absolute notional value of the position.
+        days : int, default 1
+            The number of days to accrue (e.g. 3 for a weekend rollover).
+
+        Returns
+        -------
+        double
+            Positive when the holder is credited, negative when debited.
+
+        """
+        Condition.not_negative(notional, "notional")
+        Condition.not_negative_int(days, "days")
+
+        return notional * self.get_rate(instrument_id, side) * days / self.days_in_year
+
+
+
+cdef class BorrowRateCalculator:
+    """
+    Provides borrow fee calculations for assets borrowed to hold short positions.
+
+    Rates are annualized fractions per borrowed asset (e.g. 0.05 charges 5% per
+    annum on the value of the borrowed amount), and may be updated as new borrow
+    rates are published by the venue.
+
+    Parameters
+    ----------
+    rates : dict[Currency, float]
+        The initial annualized borrow rates per asset.
+    days_in_year : int, default 365
+        The day count basis for converting annual rates to an hourly accrual.
+
+    Raises
+    ------
+    ValueError
+        If any rate in `rates` is negative (< 0).
+    ValueError
+        If `days_in_year` is not positive (> 0).
+
+    """
+
+    def __init__(self, dict rates not None, int days_in_year=365):
+        Condition.dict_types(rates, Currency, float, "rates")
+        Condition.positive_int(days_in_year, "days_in_year")
+        for rate in rates.values():
+            Condition.not_negative(rate, "rate")
+
+        self._rates = dict(rates)
+        self.days_in_year = days_in_year
+
+    cpdef bint has_rate(self, Currency currency):
+        """
+        Return whether a borrow rate is defined for the given asset.
+
+        Parameters
+        ----------
+        currency : Currency
+            The asset to check.
+
+        Returns
+        -------
+        bool
+
+        """
+        Condition.not_none(currency, "currency")
+
+        return currency in self._rates
+
+    cpdef double get_rate(self, Currency currency):
+        """
+        Return the annualized borrow rate for the given asset.
+
+        Parameters
+        ----------
+        currency : Currency
+            The asset for the rate.
+
+        Returns
+        -------
+        double
+            Zero if no rate is defined for the asset.
+
+        """
+        Condition.not_none(currency, "currency")
+
+        return self._rates.get(currency, 0.0)
+
+    cpdef void update_rate(self, Currency currency, double rate):
+        """
+        Update the annualized borrow rate for the given asset.
+
+        Parameters
+        ----------
+        currency : Currency
+            The asset for the rate.
+        rate : double
+            The annualized borrow rate as a fraction.
+
+        Raises
+        ------
+        ValueError
+            If `rate` is negative (< 0).
+
+        """
+        Condition.not_none(currency, "currency")
+        Condition.not_negative(rate, "rate")
+
+        self._rates[currency] = rate
+
+    cpdef double calc_borrow_fee(self, Currency currency, double notional, double hours):
+        """
+        Return the borrow fee accrued on the given notional over a number of hours.
+
+        Parameters
+        ----------
+        currency : Currency
+            The borrowed asset.
+        notional : double
+            The absolute value of the borrowed amount.
+        hours : double
+            The number of hours to accrue (e.g. 1 for hourly or 24 for daily accrual).
+
+        Returns
+        -------
+        double
+            The (non-negative) fee payable by the borrower.
+
+        """
+        Condition.not_negative(notional, "notional")
+        Condition.not_negative(hours, "hours")
+
+        return notional * self.get_rate(currency) * hours / (self.days_in_year * 24)
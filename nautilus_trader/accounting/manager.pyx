@@ -125,6 +125,7 @@ cdef class AccountsManager:
         else:
             self._update_balance_multi_currency(
                 account=account,
+                instrument=instrument,
                 fill=fill,
                 pnls=pnls,
             )
@@ -302,6 +303,7 @@ cdef class AccountsManager:
                 instrument,
                 order.quantity,
                 order.price if order.has_price_c() else order.trigger_price,
+                side=order.side,
             ).as_f64_c()
 
             if account.base_currency is not None:
@@ -501,42 +503,105 @@ cdef class AccountsManager:
     cdef void _update_balance_multi_currency(
         self,
         Account account,
+        Instrument instrument,
         OrderFilled fill,
         list pnls,
     ):
+        cdef dict pnls_by_currency = {pnl.currency: pnl for pnl in pnls}  # type: dict[Currency, Money]
         cdef list balances = []
 
         cdef Money commission = fill.commission
+        cdef bint commission_applied = False
+        cdef Currency settlement_currency
         cdef AccountBalance balance = None
         cdef AccountBalance new_balance = None
         cdef:
             Money pnl
+            double xrate
             double new_total
             double new_free
             Money total
             Money free
-        for pnl in pnls:
-            currency = pnl.currency
-            if commission.currency != currency and commission._mem.raw != 0:
-                balance = account.balance(commission.currency)
-                if balance is None:
-                    if commission._mem.raw > 0:
-                        self._log.error(
-                            f"Cannot complete transaction: no {commission.currency} "
-                            f"balance to deduct a {commission.to_str()} commission from."
-                        )
-                        return
-                    else:
-                        balance = AccountBalance(
-                            total=Money(0, commission.currency),
-                            locked=Money(0, commission.currency),
-                            free=Money(0, commission.currency),
-                        )
-                balance.total = Money(balance.total.as_f64_c() - commission.as_f64_c(), commission.currency)
-                balance.free = Money(balance.free.as_f64_c() - commission.as_f64_c(), commission.currency)
-                balances.append(balance)
+
+        # Commissions in a currency without a PnL leg (e.g. BNB fees) are applied
+        # exactly once, deducted from their own balance or converted into the
+        # settlement currency when no balance in the fee currency is held
+        if commission._mem.raw != 0 and commission.currency not in pnls_by_currency:
+            balance = account.balance(commission.currency)
+            if balance is None and commission._mem.raw > 0:
+                settlement_currency = instrument.get_settlement_currency()
+                xrate = self._cache.get_xrate(
+                    venue=fill.instrument_id.venue,
+                    from_currency=commission.currency,
+                    to_currency=settlement_currency,
+                    price_type=PriceType.BID if fill.order_side is OrderSide.SELL else PriceType.ASK,
+                )
+                if xrate == 0.0:
+                    self._log.error(
+                        f"Cannot complete transaction: no {commission.currency} "
+                        f"balance to deduct a {commission.to_str()} commission from, "
+                        f"and insufficient data for {commission.currency}/{settlement_currency}."
+                    )
+                    return  # Cannot calculate
+
+                pnl = pnls_by_currency.get(settlement_currency, Money(0, settlement_currency))
+                pnls_by_currency[settlement_currency] = pnl.sub(
+                    Money(commission.as_f64_c() * xrate, settlement_currency),
+                )
             else:
-                pnl = pnl.sub(commission)
+                if balance is None:
+                    # Commission rebate into a currency not yet held
+                    balance = AccountBalance(
+                        total=Money(0, commission.currency),
+                        locked=Money(0, commission.currency),
+                        free=Money(0, commission.currency),
+                    )
+                new_total = balance.total.as_f64_c() - commission.as_f64_c()
+                new_free = balance.free.as_f64_c() - commission.as_f64_c()
+                total = Money(new_total, commission.currency)
+                if new_total < 0:
+                    raise AccountBalanceNegative(
+                        balance=total.as_decimal(),
+                        currency=commission.currency,
+                    )
+                if new_free < 0:
+                    raise AccountMarginExceeded(
+                        balance=total.as_decimal(),
+                        margin=balance.locked.as_decimal(),
+                        currency=commission.currency,
+                    )
+                balances.append(
+                    AccountBalance(
+                        total=total,
+                        locked=balance.locked,
+                        free=Money(new_free, commission.currency),
+                    ),
+                )
+            commission_applied = True
+
+        for pnl in pnls_by_currency.values():
+            currency = pnl.currency
+            if not commission_applied:
+                if commission.currency != currency and commission._mem.raw != 0:
+                    balance = account.balance(commission.currency)
+                    if balance is None:
+                        if commission._mem.raw > 0:
+                            self._log.error(
+                                f"Cannot complete transaction: no {commission.currency} "
+                                f"balance to deduct a {commission.to_str()} commission from."
+                            )
+                            return
+                        else:
+                            balance = AccountBalance(
+                                total=Money(0, commission.currency),
+                                locked=Money(0, commission.currency),
+                                free=Money(0, commission.currency),
+                            )
+                    balance.total = Money(balance.total.as_f64_c() - commission.as_f64_c(), commission.currency)
+                    balance.free = Money(balance.free.as_f64_c() - commission.as_f64_c(), commission.currency)
+                    balances.append(balance)
+                else:
+                    pnl = pnl.sub(commission)
 
             if not balances and pnl._mem.raw == 0:
                 return  # No adjustment
@@ -580,28 +645,10 @@ cdef class AccountsManager:
 
             balances.append(new_balance)
 
-        # TODO(cs): Refactor and consolidate
-        if not pnls and commission._mem.raw != 0:
-            currency = commission.currency
-            balance = account.balance(currency)
-            if balance is None:
-                self._log.error(
-                    "Cannot calculate account state: "
-                    f"no cached balances for {currency}."
-                )
-                return
-
-            new_balance = AccountBalance(
-                total=Money(balance.total.as_f64_c() - commission.as_f64_c(), currency),
-                locked=balance.locked,
-                free=Money(balance.free.as_f64_c() - commission.as_f64_c(), currency),
-            )
-            balances.append(new_balance)
-
         if not balances:
             return  # No adjustment
 
-        # Finally update balances and commissions
+        # Finally update balances and commissions (tracked in their original currency)
         account.update_balances(balances)
         account.update_commissions(commission)
 
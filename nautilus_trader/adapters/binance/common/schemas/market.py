@@ -36,6 +36,7 @@ from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import TradeFlag
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.objects import Price
@@ -224,6 +225,7 @@ class BinanceAggTrade(msgspec.Struct, frozen=True):
             trade_id=TradeId(str(self.a)),
             ts_event=millis_to_nanos(self.T),
             ts_init=ts_init,
+            flags=TradeFlag.AGGREGATED if self.l > self.f else 0,
         )
 
 
@@ -528,6 +530,7 @@ class BinanceAggregatedTradeData(msgspec.Struct, frozen=True):
             trade_id=TradeId(str(self.a)),
             ts_event=millis_to_nanos(self.T),
             ts_init=ts_init,
+            flags=TradeFlag.AGGREGATED if self.l > self.f else 0,
         )
 
 
@@ -28,9 +28,13 @@ class SandboxExecutionClientConfig(LiveExecClientConfig, frozen=True, kw_only=Tr
         The currency for this venue
     balance : int
         The starting balance for this venue
+    use_pyo3 : bool, default False
+        If the Rust sandbox execution client should be used (via the pyo3 bindings)
+        in place of the Cython ``SimulatedExchange``.
 
     """
 
     venue: str
     currency: str
     balance: int
+    use_pyo3: bool = False
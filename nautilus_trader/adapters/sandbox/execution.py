@@ -28,6 +28,7 @@ from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.core.data import Data
 from nautilus_trader.execution.reports import FillReport
 from nautilus_trader.execution.reports import OrderStatusReport
@@ -40,6 +41,21 @@ from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import account_type_to_str
+from nautilus_trader.model.enums import oms_type_to_str
+from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.events import OrderAccepted
+from nautilus_trader.model.events import OrderCanceled
+from nautilus_trader.model.events import OrderCancelRejected
+from nautilus_trader.model.events import OrderExpired
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderInitialized
+from nautilus_trader.model.events import OrderModifyRejected
+from nautilus_trader.model.events import OrderRejected
+from nautilus_trader.model.events import OrderSubmitted
+from nautilus_trader.model.events import OrderTriggered
+from nautilus_trader.model.events import OrderUpdated
+from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import InstrumentId
@@ -49,9 +65,24 @@ from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.model.objects import AccountBalance
 from nautilus_trader.model.objects import Currency
 from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
 from nautilus_trader.portfolio.base import PortfolioFacade
 
 
+_ORDER_EVENT_TYPES = {
+    "OrderAccepted": OrderAccepted,
+    "OrderCanceled": OrderCanceled,
+    "OrderCancelRejected": OrderCancelRejected,
+    "OrderExpired": OrderExpired,
+    "OrderFilled": OrderFilled,
+    "OrderModifyRejected": OrderModifyRejected,
+    "OrderRejected": OrderRejected,
+    "OrderSubmitted": OrderSubmitted,
+    "OrderTriggered": OrderTriggered,
+    "OrderUpdated": OrderUpdated,
+}
+
+
 class SandboxExecutionClient(LiveExecutionClient):
     """
     Provides a sandboxed execution client for testing against.
@@ -208,3 +239,233 @@ class SandboxExecutionClient(LiveExecutionClient):
         elif isinstance(data, Bar):
             self.exchange.process_bar(data)
         self.exchange.process(data.ts_init)
+
+
+class PyO3SandboxExecutionClient(LiveExecutionClient):
+    """
+    Provides a sandboxed execution client backed by the Rust ``SandboxExecutionClient``.
+
+    Market data published on the message bus is passed to the Rust matching engines,
+    with the resulting order events and account states converted back and sent to the
+    execution engine.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    portfolio : PortfolioFacade
+        The read-only portfolio for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    venue : str
+        The venue to generate a sandbox execution client for.
+    currency : str
+        The currency for this venue.
+    balance : int
+        The starting balance for this venue.
+    oms_type : OmsType, default NETTING
+        The order management system type for the venue.
+    account_type : AccountType, default MARGIN
+        The account type for the venue.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        portfolio: PortfolioFacade,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        venue: str,
+        currency: str,
+        balance: int,
+        oms_type: OmsType = OmsType.NETTING,
+        account_type: AccountType = AccountType.MARGIN,
+    ) -> None:
+        self._currency = Currency.from_str(currency)
+        sandbox_venue = Venue(venue)
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(venue),
+            venue=sandbox_venue,
+            oms_type=oms_type,
+            account_type=account_type,
+            base_currency=self._currency,
+            instrument_provider=InstrumentProvider(),
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            config=None,
+        )
+        self._set_account_id(AccountId(f"{venue}-001"))
+        self._inner = nautilus_pyo3.SandboxExecutionClient(
+            client_id=nautilus_pyo3.ClientId(venue),
+            account_id=nautilus_pyo3.AccountId(f"{venue}-001"),
+            venue=nautilus_pyo3.Venue(venue),
+            starting_balances=[nautilus_pyo3.Money.from_str(f"{balance} {currency}")],
+            oms_type=nautilus_pyo3.OmsType.from_str(oms_type_to_str(oms_type)),
+            account_type=nautilus_pyo3.AccountType.from_str(account_type_to_str(account_type)),
+        )
+
+    def connect(self) -> None:
+        """
+        Connect the client.
+        """
+        self._log.info("Connecting...")
+        for instrument in self._cache.instruments(venue=self.venue):
+            pyo3_instrument_type = getattr(nautilus_pyo3, type(instrument).__name__)
+            self._inner.add_instrument(
+                pyo3_instrument_type.from_dict(type(instrument).to_dict(instrument)),
+            )
+        self._msgbus.subscribe("data.*", handler=self.on_data)
+        self._send_account_state_pyo3(
+            self._inner.generate_account_state(self._clock.timestamp_ns()),
+        )
+        self._set_connected(True)
+        self._log.info("Connected.")
+
+    def disconnect(self) -> None:
+        """
+        Disconnect the client.
+        """
+        self._log.info("Disconnecting...")
+        self._msgbus.unsubscribe("data.*", handler=self.on_data)
+        self._set_connected(False)
+        self._log.info("Disconnected.")
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        return None
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        return []
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        return []
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        return []
+
+    def submit_order(self, command):
+        self._submit_order_pyo3(command.order)
+        self._drain_events()
+
+    def submit_order_list(self, command):
+        for order in command.order_list.orders:
+            self._submit_order_pyo3(order)
+        self._drain_events()
+
+    def modify_order(self, command):
+        self._inner.modify_order(
+            trader_id=nautilus_pyo3.TraderId(command.trader_id.value),
+            strategy_id=nautilus_pyo3.StrategyId(command.strategy_id.value),
+            instrument_id=nautilus_pyo3.InstrumentId.from_str(command.instrument_id.value),
+            client_order_id=nautilus_pyo3.ClientOrderId(command.client_order_id.value),
+            quantity=(
+                nautilus_pyo3.Quantity.from_str(str(command.quantity))
+                if command.quantity is not None
+                else None
+            ),
+            price=(
+                nautilus_pyo3.Price.from_str(str(command.price))
+                if command.price is not None
+                else None
+            ),
+            trigger_price=(
+                nautilus_pyo3.Price.from_str(str(command.trigger_price))
+                if command.trigger_price is not None
+                else None
+            ),
+            ts_init=command.ts_init,
+        )
+        self._drain_events()
+
+    def cancel_order(self, command):
+        self._cancel_order_pyo3(command.trader_id, command.strategy_id, command.client_order_id)
+        self._drain_events()
+
+    def cancel_all_orders(self, command):
+        for order in self._cache.orders_open(
+            venue=self.venue,
+            instrument_id=command.instrument_id,
+            strategy_id=command.strategy_id,
+            side=command.order_side,
+        ):
+            self._cancel_order_pyo3(command.trader_id, command.strategy_id, order.client_order_id)
+        self._drain_events()
+
+    def on_data(self, data: Data) -> None:
+        if isinstance(data, OrderBookDelta):
+            self._inner.process_order_book_delta(OrderBookDelta.to_pyo3_list([data])[0])
+        elif isinstance(data, OrderBookDeltas):
+            for delta in OrderBookDelta.to_pyo3_list(data.deltas):
+                self._inner.process_order_book_delta(delta)
+        elif isinstance(data, QuoteTick):
+            self._inner.process_quote_tick(QuoteTick.to_pyo3_list([data])[0])
+        elif isinstance(data, TradeTick):
+            self._inner.process_trade_tick(TradeTick.to_pyo3_list([data])[0])
+        elif isinstance(data, Bar):
+            self._inner.process_bar(Bar.to_pyo3_list([data])[0])
+        else:
+            return
+        self._drain_events()
+
+    def _submit_order_pyo3(self, order: Order) -> None:
+        init = nautilus_pyo3.OrderInitialized.from_dict(OrderInitialized.to_dict(order.init_event))
+        self._inner.submit_order(
+            trader_id=nautilus_pyo3.TraderId(order.trader_id.value),
+            init=init,
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+    def _cancel_order_pyo3(self, trader_id, strategy_id, client_order_id) -> None:
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot cancel order: {client_order_id!r} not found in cache")
+            return
+        self._inner.cancel_order(
+            trader_id=nautilus_pyo3.TraderId(trader_id.value),
+            strategy_id=nautilus_pyo3.StrategyId(strategy_id.value),
+            instrument_id=nautilus_pyo3.InstrumentId.from_str(order.instrument_id.value),
+            client_order_id=nautilus_pyo3.ClientOrderId(client_order_id.value),
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+    def _drain_events(self) -> None:
+        for pyo3_event in self._inner.drain_events():
+            event_type = _ORDER_EVENT_TYPES.get(type(pyo3_event).__name__)
+            if event_type is None:
+                self._log.warning(f"Unsupported sandbox event {pyo3_event!r}")
+                continue
+            self._send_order_event(event_type.from_dict(pyo3_event.to_dict()))
+        for pyo3_state in self._inner.drain_account_states():
+            self._send_account_state_pyo3(pyo3_state)
+
+    def _send_account_state_pyo3(self, pyo3_state) -> None:
+        self._send_account_state(AccountState.from_dict(pyo3_state.to_dict()))
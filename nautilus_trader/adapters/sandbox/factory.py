@@ -16,6 +16,7 @@
 import asyncio
 
 from nautilus_trader.adapters.sandbox.config import SandboxExecutionClientConfig
+from nautilus_trader.adapters.sandbox.execution import PyO3SandboxExecutionClient
 from nautilus_trader.adapters.sandbox.execution import SandboxExecutionClient
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
@@ -38,7 +39,7 @@ class SandboxLiveExecClientFactory(LiveExecClientFactory):
         msgbus: MessageBus,
         cache: Cache,
         clock: LiveClock,
-    ) -> SandboxExecutionClient:
+    ) -> SandboxExecutionClient | PyO3SandboxExecutionClient:
         """
         Create a new Sandbox execution client.
 
@@ -61,10 +62,11 @@ class SandboxLiveExecClientFactory(LiveExecClientFactory):
 
         Returns
         -------
-        SandboxExecutionClient
+        SandboxExecutionClient | PyO3SandboxExecutionClient
 
         """
-        exec_client = SandboxExecutionClient(
+        client_cls = PyO3SandboxExecutionClient if config.use_pyo3 else SandboxExecutionClient
+        exec_client = client_cls(
             loop=loop,
             clock=clock,
             portfolio=portfolio,
@@ -0,0 +1,273 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Transaction cost analysis (TCA) of order execution quality against market benchmarks.
+"""
+
+from __future__ import annotations
+
+import bisect
+from collections import defaultdict
+from os import PathLike
+
+import pandas as pd
+import pyarrow as pa
+import pyarrow.feather as feather
+
+from nautilus_trader.core.datetime import unix_nanos_to_dt
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import order_side_to_str
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.orders import Order
+
+
+class ExecutionQualityAnalyzer:
+    """
+    Provides transaction cost analysis of parent orders against market benchmarks.
+
+    Child orders spawned by an execution algorithm are grouped under their parent
+    (primary) order by `exec_spawn_id`, otherwise each order is its own parent.
+    For each parent order the following are computed over the execution interval,
+    from arrival (the parent order initialization) to the last child fill:
+
+    - Arrival price slippage, against the quote mid (or last trade) at arrival.
+    - Interval VWAP and TWAP slippage, against market trades within the interval.
+    - Participation rate, as filled quantity over market traded volume.
+
+    Slippage is expressed in basis points, where a positive value is a cost
+    (buying above or selling below the benchmark).
+    """
+
+    def __init__(self) -> None:
+        self._quotes: dict[InstrumentId, list[QuoteTick]] = defaultdict(list)
+        self._trades: dict[InstrumentId, list[TradeTick]] = defaultdict(list)
+
+    def add_quote_ticks(self, ticks: list[QuoteTick]) -> None:
+        """
+        Add the given quote ticks as market data for arrival price benchmarks.
+
+        Parameters
+        ----------
+        ticks : list[QuoteTick]
+            The quote ticks to add.
+
+        """
+        for tick in ticks:
+            self._quotes[tick.instrument_id].append(tick)
+        for quotes in self._quotes.values():
+            quotes.sort(key=lambda q: q.ts_event)
+
+    def add_trade_ticks(self, ticks: list[TradeTick]) -> None:
+        """
+        Add the given trade ticks as market data for interval benchmarks.
+
+        Parameters
+        ----------
+        ticks : list[TradeTick]
+            The trade ticks to add.
+
+        """
+        for tick in ticks:
+            self._trades[tick.instrument_id].append(tick)
+        for trades in self._trades.values():
+            trades.sort(key=lambda t: t.ts_event)
+
+    def generate_report(self, orders: list[Order]) -> pd.DataFrame:
+        """
+        Generate an execution quality report with a row per parent order.
+
+        Parent orders with no fills are excluded.
+
+        Parameters
+        ----------
+        orders : list[Order]
+            The orders (parent and child) for the report.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        groups: dict[ClientOrderId, list[Order]] = defaultdict(list)
+        for order in orders:
+            groups[order.exec_spawn_id or order.client_order_id].append(order)
+
+        rows = []
+        for parent_order_id, group in groups.items():
+            row = self._analyze_parent(parent_order_id, group)
+            if row is not None:
+                rows.append(row)
+
+        if not rows:
+            return pd.DataFrame()
+
+        return pd.DataFrame(data=rows).set_index("parent_order_id").sort_index()
+
+    def _analyze_parent(
+        self,
+        parent_order_id: ClientOrderId,
+        orders: list[Order],
+    ) -> dict | None:
+        fills: list[OrderFilled] = [
+            e for o in orders for e in o.events if isinstance(e, OrderFilled)
+        ]
+        if not fills:
+            return None
+
+        instrument_id = orders[0].instrument_id
+        side = orders[0].side
+        side_sign = 1.0 if side == OrderSide.BUY else -1.0
+
+        filled_qty = sum(f.last_qty.as_double() for f in fills)
+        avg_px = sum(f.last_px.as_double() * f.last_qty.as_double() for f in fills) / filled_qty
+        ts_arrival = min(o.ts_init for o in orders)
+        ts_last_fill = max(f.ts_event for f in fills)
+
+        arrival_px = self._arrival_price(instrument_id, ts_arrival)
+        trades = self._interval_trades(instrument_id, ts_arrival, ts_last_fill)
+        market_volume = sum(t.size.as_double() for t in trades)
+        interval_vwap = _vwap(trades)
+        interval_twap = _twap(trades, ts_last_fill)
+
+        return {
+            "parent_order_id": parent_order_id.value,
+            "instrument_id": instrument_id.value,
+            "side": order_side_to_str(side),
+            "child_orders": len(orders),
+            "fills": len(fills),
+            "filled_qty": filled_qty,
+            "avg_px": avg_px,
+            "arrival_px": arrival_px,
+            "arrival_slippage_bps": _slippage_bps(avg_px, arrival_px, side_sign),
+            "interval_vwap": interval_vwap,
+            "vwap_slippage_bps": _slippage_bps(avg_px, interval_vwap, side_sign),
+            "interval_twap": interval_twap,
+            "twap_slippage_bps": _slippage_bps(avg_px, interval_twap, side_sign),
+            "market_volume": market_volume,
+            "participation_rate": filled_qty / market_volume if market_volume else None,
+            "ts_arrival": unix_nanos_to_dt(ts_arrival),
+            "ts_last_fill": unix_nanos_to_dt(ts_last_fill),
+        }
+
+    def _arrival_price(self, instrument_id: InstrumentId, ts_arrival: int) -> float | None:
+        quotes = self._quotes.get(instrument_id, [])
+        idx = bisect.bisect_right([q.ts_event for q in quotes], ts_arrival)
+        if idx > 0:
+            quote = quotes[idx - 1]
+            return (quote.bid_price.as_double() + quote.ask_price.as_double()) / 2.0
+
+        # Fall back to the last trade at or before arrival
+        trades = self._trades.get(instrument_id, [])
+        idx = bisect.bisect_right([t.ts_event for t in trades], ts_arrival)
+        if idx > 0:
+            return trades[idx - 1].price.as_double()
+
+        return None
+
+    def _interval_trades(
+        self,
+        instrument_id: InstrumentId,
+        start: int,
+        end: int,
+    ) -> list[TradeTick]:
+        trades = self._trades.get(instrument_id, [])
+        timestamps = [t.ts_event for t in trades]
+        lo = bisect.bisect_left(timestamps, start)
+        hi = bisect.bisect_right(timestamps, end)
+        return trades[lo:hi]
+
+    @staticmethod
+    def to_arrow(report: pd.DataFrame) -> pa.Table:
+        """
+        Convert the given execution quality `report` to an Arrow table.
+
+        Parameters
+        ----------
+        report : pd.DataFrame
+            The report to convert.
+
+        Returns
+        -------
+        pa.Table
+
+        """
+        return pa.Table.from_pandas(report, preserve_index=True)
+
+    @staticmethod
+    def write_csv(report: pd.DataFrame, path: str | PathLike) -> None:
+        """
+        Write the given execution quality `report` to a CSV file at `path`.
+
+        Parameters
+        ----------
+        report : pd.DataFrame
+            The report to write.
+        path : str or PathLike
+            The file path to write to.
+
+        """
+        report.to_csv(path)
+
+    @staticmethod
+    def write_arrow(report: pd.DataFrame, path: str | PathLike) -> None:
+        """
+        Write the given execution quality `report` to an Arrow IPC (Feather) file at `path`.
+
+        Parameters
+        ----------
+        report : pd.DataFrame
+            The report to write.
+        path : str or PathLike
+            The file path to write to.
+
+        """
+        feather.write_feather(ExecutionQualityAnalyzer.to_arrow(report), path)
+
+
+def _slippage_bps(avg_px: float, benchmark: float | None, side_sign: float) -> float | None:
+    if not benchmark:
+        return None
+    return side_sign * (avg_px - benchmark) / benchmark * 10_000.0
+
+
+def _vwap(trades: list[TradeTick]) -> float | None:
+    volume = sum(t.size.as_double() for t in trades)
+    if not volume:
+        return None
+    return sum(t.price.as_double() * t.size.as_double() for t in trades) / volume
+
+
+def _twap(trades: list[TradeTick], end: int) -> float | None:
+    if not trades:
+        return None
+
+    # Weight each trade price by the time until the next trade (or interval end)
+    total_weight = 0
+    weighted_sum = 0.0
+    for i, trade in enumerate(trades):
+        ts_next = trades[i + 1].ts_event if i + 1 < len(trades) else end
+        weight = ts_next - trade.ts_event
+        total_weight += weight
+        weighted_sum += trade.price.as_double() * weight
+
+    if total_weight == 0:
+        # All trades at a single instant, fall back to a simple average
+        return sum(t.price.as_double() for t in trades) / len(trades)
+
+    return weighted_sum / total_weight
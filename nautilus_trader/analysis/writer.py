@@ -0,0 +1,182 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Report generation of trade blotters, positions and account statements from the cache.
+"""
+
+from __future__ import annotations
+
+from os import PathLike
+from pathlib import Path
+
+import pandas as pd
+import pyarrow as pa
+import pyarrow.feather as feather
+
+from nautilus_trader.analysis.reporter import ReportProvider
+from nautilus_trader.cache.base import CacheFacade
+from nautilus_trader.model.identifiers import AccountId
+
+
+class ReportWriter:
+    """
+    Provides trade blotter, positions and account statement reports generated
+    from the orders, positions and account event history held in the cache.
+
+    Reports contain the same fields as those produced by the `ReportProvider`,
+    and may be exported as Arrow tables, Arrow IPC (Feather) files or CSV files.
+    This can be done at the end of a backtest run, or on demand while live.
+
+    Parameters
+    ----------
+    cache : CacheFacade
+        The cache to generate reports from.
+
+    """
+
+    def __init__(self, cache: CacheFacade) -> None:
+        self._cache = cache
+
+    def generate_fills_blotter(self) -> pd.DataFrame:
+        """
+        Generate a trade blotter with a row per individual fill event.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        return ReportProvider.generate_fills_report(self._cache.orders())
+
+    def generate_positions_report(self) -> pd.DataFrame:
+        """
+        Generate a positions report, including any position snapshots.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        positions = self._cache.positions() + self._cache.position_snapshots()
+        return ReportProvider.generate_positions_report(positions)
+
+    def generate_account_statement(self, account_id: AccountId) -> pd.DataFrame:
+        """
+        Generate an account activity statement with a row per balance of each
+        account state event for the given `account_id`.
+
+        Parameters
+        ----------
+        account_id : AccountId
+            The account ID for the statement.
+
+        Returns
+        -------
+        pd.DataFrame
+
+        """
+        account = self._cache.account(account_id)
+        if account is None:
+            return pd.DataFrame()
+        return ReportProvider.generate_account_report(account)
+
+    def generate_reports(self) -> dict[str, pd.DataFrame]:
+        """
+        Generate all reports keyed by report name.
+
+        The fills blotter is keyed as 'fills', the positions report as 'positions',
+        and each account statement as 'account-{account_id}'.
+
+        Returns
+        -------
+        dict[str, pd.DataFrame]
+
+        """
+        reports = {
+            "fills": self.generate_fills_blotter(),
+            "positions": self.generate_positions_report(),
+        }
+        for account in self._cache.accounts():
+            reports[f"account-{account.id}"] = self.generate_account_statement(account.id)
+
+        return reports
+
+    @staticmethod
+    def to_arrow(report: pd.DataFrame) -> pa.Table:
+        """
+        Convert the given `report` to an Arrow table.
+
+        Parameters
+        ----------
+        report : pd.DataFrame
+            The report to convert.
+
+        Returns
+        -------
+        pa.Table
+
+        """
+        return pa.Table.from_pandas(report, preserve_index=True)
+
+    def write_csv(self, directory: str | PathLike) -> list[Path]:
+        """
+        Write all non-empty reports as CSV files to the given `directory`.
+
+        Parameters
+        ----------
+        directory : str or PathLike
+            The directory to write to (will be created if it does not exist).
+
+        Returns
+        -------
+        list[Path]
+            The paths of the files written.
+
+        """
+        paths: list[Path] = []
+        for name, report in self._non_empty_reports(directory):
+            path = Path(directory) / f"{name}.csv"
+            report.to_csv(path)
+            paths.append(path)
+
+        return paths
+
+    def write_arrow(self, directory: str | PathLike) -> list[Path]:
+        """
+        Write all non-empty reports as Arrow IPC (Feather) files to the given
+        `directory`.
+
+        Parameters
+        ----------
+        directory : str or PathLike
+            The directory to write to (will be created if it does not exist).
+
+        Returns
+        -------
+        list[Path]
+            The paths of the files written.
+
+        """
+        paths: list[Path] = []
+        for name, report in self._non_empty_reports(directory):
+            path = Path(directory) / f"{name}.feather"
+            feather.write_feather(self.to_arrow(report), path)
+            paths.append(path)
+
+        return paths
+
+    def _non_empty_reports(self, directory: str | PathLike) -> list[tuple[str, pd.DataFrame]]:
+        Path(directory).mkdir(parents=True, exist_ok=True)
+        return [(name, r) for name, r in self.generate_reports().items() if not r.empty]
@@ -0,0 +1,147 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import heapq
+import random
+
+from nautilus_trader.backtest.config import DataChaosConfig
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.data import Data
+
+
+class DataChaos:
+    """
+    Provides perturbed delivery of a data stream, simulating real-world feed imperfections
+    with bounded random delays, duplicate messages and small reorderings.
+
+    All randomness is drawn from a generator seeded by the config, so the same config
+    and data reproduce the same delivery.
+
+    Parameters
+    ----------
+    config : DataChaosConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If any of the `config` probabilities is not in the range [0, 1].
+    ValueError
+        If `config.delay_probability` is positive with a `max_delay_ns` of zero.
+    ValueError
+        If `config.reorder_probability` is positive with a `reorder_window` of zero.
+
+    """
+
+    def __init__(self, config: DataChaosConfig) -> None:
+        PyCondition.in_range(config.delay_probability, 0.0, 1.0, "delay_probability")
+        PyCondition.in_range(config.duplicate_probability, 0.0, 1.0, "duplicate_probability")
+        PyCondition.in_range(config.reorder_probability, 0.0, 1.0, "reorder_probability")
+        PyCondition.true(
+            config.delay_probability == 0.0 or config.max_delay_ns > 0,
+            "`max_delay_ns` must be positive to delay data",
+        )
+        PyCondition.true(
+            config.reorder_probability == 0.0 or config.reorder_window > 0,
+            "`reorder_window` must be positive to reorder data",
+        )
+
+        self._config = config
+        self._rng = random.Random(config.seed)
+        self._delayed: list[tuple[int, int, Data]] = []  # Heap of (release time, sequence, data)
+        self._held: list[list] = []  # Pairs of [data points remaining, data]
+        self._sequence = 0
+
+        self.delayed_count = 0
+        self.duplicated_count = 0
+        self.reordered_count = 0
+
+    @property
+    def pending_count(self) -> int:
+        """
+        Return the count of data points received but not yet delivered.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._delayed) + len(self._held)
+
+    def deliver(self, data: Data, ts_now: int) -> list[Data]:
+        """
+        Receive the given data at `ts_now`, returning the data to deliver (in order).
+
+        Any delayed data due at `ts_now` is delivered before the received data.
+
+        Parameters
+        ----------
+        data : Data
+            The data received.
+        ts_now : int
+            The current UNIX timestamp (nanoseconds).
+
+        Returns
+        -------
+        list[Data]
+
+        """
+        delivered: list[Data] = []
+        while self._delayed and self._delayed[0][0] <= ts_now:
+            self._emit(heapq.heappop(self._delayed)[2], delivered)
+
+        copies = 1
+        if self._rng.random() < self._config.duplicate_probability:
+            copies = 2
+            self.duplicated_count += 1
+
+        for _ in range(copies):
+            if self._rng.random() < self._config.delay_probability:
+                ts_release = ts_now + self._rng.randint(1, self._config.max_delay_ns)
+                heapq.heappush(self._delayed, (ts_release, self._sequence, data))
+                self._sequence += 1
+                self.delayed_count += 1
+            elif self._rng.random() < self._config.reorder_probability:
+                self._held.append([self._rng.randint(1, self._config.reorder_window), data])
+                self.reordered_count += 1
+            else:
+                self._emit(data, delivered)
+
+        return delivered
+
+    def flush(self) -> list[Data]:
+        """
+        Return all data not yet delivered (such as at the end of a run), in order of the
+        delayed data release times followed by the held back data.
+
+        Returns
+        -------
+        list[Data]
+
+        """
+        delivered = [heapq.heappop(self._delayed)[2] for _ in range(len(self._delayed))]
+        delivered.extend(data for _, data in self._held)
+        self._held.clear()
+        return delivered
+
+    def _emit(self, data: Data, delivered: list[Data]) -> None:
+        delivered.append(data)
+
+        # Release any held back data once enough later data has been delivered ahead of it
+        for held in self._held:
+            held[0] -= 1
+            if held[0] == 0:
+                delivered.append(held[1])
+        self._held = [held for held in self._held if held[0] > 0]
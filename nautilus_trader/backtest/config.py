@@ -21,6 +21,7 @@ from typing import Any
 from nautilus_trader.common import Environment
 from nautilus_trader.common.config import ImportableActorConfig
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import NonNegativeInt
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.datetime import dt_to_unix_nanos
 from nautilus_trader.data.config import DataEngineConfig
@@ -67,6 +68,7 @@ class BacktestDataConfig(NautilusConfig, frozen=True):
     data_cls: str
     catalog_fs_protocol: str | None = None
     catalog_fs_storage_options: dict | None = None
+    catalog_local_cache_dir: str | None = None
     instrument_id: InstrumentId | None = None
     start_time: str | int | None = None
     end_time: str | int | None = None
@@ -149,6 +151,40 @@ class BacktestDataConfig(NautilusConfig, frozen=True):
         return dt_to_unix_nanos(self.end_time)
 
 
+class DataChaosConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for perturbing the delivery of backtest data to the system
+    (chaos testing), simulating real-world feed imperfections.
+
+    The simulated venues always process the true data stream, only the data delivered
+    to actors and strategies is perturbed.
+
+    Parameters
+    ----------
+    seed : int, default 0
+        The random seed, so a perturbed run can be reproduced.
+    delay_probability : float, default 0.0
+        The probability each data point is delayed (in the range [0, 1]).
+    max_delay_ns : NonNegativeInt, default 0
+        The maximum delay (nanoseconds) for a delayed data point, which is delivered once
+        the backtest reaches a time uniformly drawn from up to this delay after its `ts_init`.
+    duplicate_probability : float, default 0.0
+        The probability each data point is delivered twice (in the range [0, 1]).
+    reorder_probability : float, default 0.0
+        The probability each data point is held back behind later data (in the range [0, 1]).
+    reorder_window : NonNegativeInt, default 0
+        The maximum number of later data points a held back data point is delivered after.
+
+    """
+
+    seed: int = 0
+    delay_probability: float = 0.0
+    max_delay_ns: NonNegativeInt = 0
+    duplicate_probability: float = 0.0
+    reorder_probability: float = 0.0
+    reorder_window: NonNegativeInt = 0
+
+
 class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
     """
     Configuration for ``BacktestEngine`` instances.
@@ -187,6 +223,8 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
         If logging should be bypassed.
     run_analysis : bool, default True
         If post backtest performance analysis should be run.
+    chaos : DataChaosConfig, optional
+        The configuration for perturbing data delivery (chaos testing), if enabled.
 
     """
 
@@ -196,6 +234,7 @@ class BacktestEngineConfig(NautilusKernelConfig, frozen=True):
     risk_engine: RiskEngineConfig = RiskEngineConfig()
     exec_engine: ExecEngineConfig = ExecEngineConfig()
     run_analysis: bool = True
+    chaos: DataChaosConfig | None = None
 
 
 class BacktestRunConfig(NautilusConfig, frozen=True):
@@ -33,6 +33,7 @@ cdef class BacktestEngine:
 
     cdef object _kernel
     cdef DataEngine _data_engine
+    cdef object _chaos
     cdef str _run_config_id
     cdef UUID4 _run_id
     cdef datetime _run_started
@@ -19,6 +19,7 @@ from decimal import Decimal
 import pandas as pd
 
 from nautilus_trader.accounting.error import AccountError
+from nautilus_trader.backtest.chaos import DataChaos
 from nautilus_trader.backtest.results import BacktestResult
 from nautilus_trader.common import Environment
 from nautilus_trader.common.config import InvalidConfiguration
@@ -73,11 +74,15 @@ from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.execution.algorithm cimport ExecAlgorithm
 from nautilus_trader.model.data cimport Bar
+from nautilus_trader.model.data cimport BorrowRate
+from nautilus_trader.model.data cimport CashDividend
 from nautilus_trader.model.data cimport CustomData
+from nautilus_trader.model.data cimport InstrumentClose
 from nautilus_trader.model.data cimport InstrumentStatus
 from nautilus_trader.model.data cimport OrderBookDelta
 from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.data cimport QuoteTick
+from nautilus_trader.model.data cimport StockSplit
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.data cimport VenueStatus
 from nautilus_trader.model.identifiers cimport ClientId
@@ -106,6 +111,21 @@ cdef class BacktestEngine:
     ------
     TypeError
         If `config` is not of type `BacktestEngineConfig`.
+
+    Notes
+    -----
+    Events which share the same nanosecond timestamp are dispatched in a
+    deterministic order, so that repeated runs over the same inputs produce
+    identical results:
+     - Time events strictly prior to the next data point are handled first.
+     - Data is then processed in `ts_init` order, with ties kept in the order
+       the data was added to the engine (the sort is stable). Each data point
+       is passed to its venue (matching and fills) before the data engine, and
+       all queued exchange messages are processed before the next data point.
+     - Once all data at the timestamp has been processed, the time events at
+       that timestamp are handled, ordered by clock (the kernel clock, then
+       actors, strategies and execution algorithms in the order they were
+       added), then by timer name.
     """
 
     def __init__(self, config: BacktestEngineConfig | None = None) -> None:
@@ -141,6 +161,9 @@ cdef class BacktestEngine:
 
         self._data_engine: DataEngine = self._kernel.data_engine
 
+        # Perturbs data delivery for chaos testing (if configured)
+        self._chaos = DataChaos(config.chaos) if config.chaos is not None else None
+
     def __del__(self) -> None:
         if self._accumulator._0 != NULL:
             time_event_accumulator_drop(self._accumulator)
@@ -588,6 +611,8 @@ cdef class BacktestEngine:
             If `data` contains objects which are not a type of `Data`.
         ValueError
             If `instrument_id` for the data is not found in the cache.
+        ValueError
+            If `data` elements are instruments which have not been added through `add_instrument()`.
         ValueError
             If `data` elements do not have an `instrument_id` and `client_id` is ``None``.
         TypeError
@@ -638,6 +663,14 @@ cdef class BacktestEngine:
                     "required source",
                 )
                 data_added_str = f"{first.bar_type} {type(first).__name__}"
+            elif isinstance(first, Instrument):
+                # Instrument updates (new versions) for instruments already added
+                Condition.true(
+                    first.id in self.kernel.cache.instrument_ids(),
+                    f"`Instrument` {first.id} for the given updates not found in the cache. "
+                    "Add the initial version through `add_instrument()` prior to adding updates.",
+                )
+                data_added_str = f"{first.id} {type(first).__name__}"
             else:
                 Condition.not_none(client_id, "client_id")
                 # Check client has been registered
@@ -649,6 +682,7 @@ cdef class BacktestEngine:
         self._data.extend(data)
 
         if sort:
+            # Stable sort so that data with equal `ts_init` keeps insertion order
             self._data = sorted(self._data, key=lambda x: x.ts_init)
 
         self._log.info(
@@ -810,6 +844,9 @@ cdef class BacktestEngine:
         for exchange in self._venues.values():
             exchange.reset()
 
+        if self._chaos is not None:
+            self._chaos = DataChaos(self._config.chaos)
+
         # Reset run IDs
         self._run_config_id = None
         self._run_id = None
@@ -1104,8 +1141,29 @@ cdef class BacktestEngine:
                 elif isinstance(data, InstrumentStatus):
                     venue = self._venues[data.instrument_id.venue]
                     venue.process_instrument_status(data)
+                elif isinstance(data, InstrumentClose):
+                    venue = self._venues[data.instrument_id.venue]
+                    venue.process_instrument_close(data)
+                elif isinstance(data, CashDividend):
+                    venue = self._venues[data.instrument_id.venue]
+                    venue.process_cash_dividend(data)
+                elif isinstance(data, StockSplit):
+                    venue = self._venues[data.instrument_id.venue]
+                    venue.process_stock_split(data)
+                    # Position quantities changed without fills, so recalculate
+                    self._kernel.portfolio.initialize_positions()
+                elif isinstance(data, BorrowRate):
+                    venue = self._venues[data.venue]
+                    venue.process_borrow_rate(data)
+                elif isinstance(data, Instrument):
+                    venue = self._venues[data.id.venue]
+                    venue.add_instrument(data)
 
-                self._data_engine.process(data)
+                if self._chaos is None:
+                    self._data_engine.process(data)
+                else:
+                    for delivered in self._chaos.deliver(data, data.ts_init):
+                        self._data_engine.process(delivered)
 
                 # Process all exchange messages
                 for exchange in self._venues.values():
@@ -1135,6 +1193,11 @@ cdef class BacktestEngine:
         if force_stop:
             return
 
+        if self._chaos is not None:
+            # Deliver any data still delayed or held back
+            for delivered in self._chaos.flush():
+                self._data_engine.process(delivered)
+
         # Process remaining messages
         for exchange in self._venues.values():
             exchange.process(self.kernel.clock.timestamp_ns())
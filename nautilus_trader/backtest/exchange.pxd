@@ -31,10 +31,14 @@ from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
+from nautilus_trader.model.data cimport BorrowRate
+from nautilus_trader.model.data cimport CashDividend
+from nautilus_trader.model.data cimport InstrumentClose
 from nautilus_trader.model.data cimport InstrumentStatus
 from nautilus_trader.model.data cimport OrderBookDelta
 from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.data cimport QuoteTick
+from nautilus_trader.model.data cimport StockSplit
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.data cimport VenueStatus
 from nautilus_trader.model.identifiers cimport InstrumentId
@@ -135,6 +139,10 @@ cdef class SimulatedExchange:
     cpdef void process_bar(self, Bar bar)
     cpdef void process_venue_status(self, VenueStatus data)
     cpdef void process_instrument_status(self, InstrumentStatus data)
+    cpdef void process_instrument_close(self, InstrumentClose data)
+    cpdef void process_cash_dividend(self, CashDividend data)
+    cpdef void process_stock_split(self, StockSplit data)
+    cpdef void process_borrow_rate(self, BorrowRate data)
     cpdef void process(self, uint64_t ts_now)
     cpdef void reset(self)
 
@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 from collections import deque
+from decimal import ROUND_DOWN
 from decimal import Decimal
 from heapq import heappush
 
@@ -26,6 +27,8 @@ from nautilus_trader.backtest.execution_client cimport BacktestExecClient
 from nautilus_trader.backtest.matching_engine cimport OrderMatchingEngine
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.modules cimport FinancingAccrualModule
+from nautilus_trader.backtest.modules cimport FXRolloverInterestModule
 from nautilus_trader.backtest.modules cimport SimulationModule
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Logger
@@ -33,6 +36,7 @@ from nautilus_trader.common.component cimport TestClock
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport BookType
+from nautilus_trader.core.rust.model cimport InstrumentCloseType
 from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.execution.messages cimport BatchCancelOrders
 from nautilus_trader.execution.messages cimport CancelAllOrders
@@ -42,8 +46,12 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.book cimport OrderBook
+from nautilus_trader.model.data cimport BorrowRate
+from nautilus_trader.model.data cimport CashDividend
+from nautilus_trader.model.data cimport InstrumentClose
 from nautilus_trader.model.data cimport InstrumentStatus
 from nautilus_trader.model.data cimport QuoteTick
+from nautilus_trader.model.data cimport StockSplit
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.data cimport VenueStatus
 from nautilus_trader.model.functions cimport account_type_to_str
@@ -51,12 +59,15 @@ from nautilus_trader.model.functions cimport oms_type_to_str
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.instruments.binary_option cimport BinaryOption
 from nautilus_trader.model.instruments.crypto_future cimport CryptoFuture
 from nautilus_trader.model.instruments.crypto_perpetual cimport CryptoPerpetual
 from nautilus_trader.model.objects cimport AccountBalance
 from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
+from nautilus_trader.model.position cimport Position
 from nautilus_trader.portfolio.base cimport PortfolioFacade
 
 
@@ -126,6 +137,8 @@ cdef class SimulatedExchange:
         If `base_currency` and multiple starting balances.
     ValueError
         If `modules` contains a type other than `SimulationModule`.
+    ValueError
+        If `modules` contains both an `FXRolloverInterestModule` and a `FinancingAccrualModule`.
     """
 
     def __init__(
@@ -159,6 +172,13 @@ cdef class SimulatedExchange:
         Condition.not_empty(starting_balances, "starting_balances")
         Condition.list_type(starting_balances, Money, "starting_balances")
         Condition.list_type(modules, SimulationModule, "modules", "SimulationModule")
+        Condition.true(
+            not (
+                any(isinstance(m, FXRolloverInterestModule) for m in modules)
+                and any(isinstance(m, FinancingAccrualModule) for m in modules)
+            ),
+            "both `FXRolloverInterestModule` and `FinancingAccrualModule` book overnight financing for FX positions, use only one",
+        )
         if base_currency:
             Condition.true(len(starting_balances) == 1, "single-currency account has multiple starting currencies")
         if default_leverage and default_leverage > 1 or leverages:
@@ -297,6 +317,11 @@ cdef class SimulatedExchange:
 
         A random and unique 32-bit unsigned integer raw ID will be generated.
 
+        If the instrument was already added then this is treated as a new version
+        of the instrument. The existing matching engine and its open orders are
+        retained, and the engine switches to the version effective at each
+        timestamp as held by the cache.
+
         Parameters
         ----------
         instrument : Instrument
@@ -322,6 +347,13 @@ cdef class SimulatedExchange:
                     f"venue with a `MARGIN` account type.",
                 )
 
+        cdef Instrument current = self.instruments.get(instrument.id)
+        if current is not None:
+            if instrument.ts_event >= current.ts_event:
+                self.instruments[instrument.id] = instrument
+            self._log.info(f"Added instrument {instrument.id} version effective {instrument.ts_event}.")
+            return
+
         self.instruments[instrument.id] = instrument
 
         cdef OrderMatchingEngine matching_engine = OrderMatchingEngine(
@@ -792,6 +824,175 @@ cdef class SimulatedExchange:
 
         matching_engine.process_status(data.status)
 
+    cpdef void process_instrument_close(self, InstrumentClose data):
+        """
+        Process the given instrument close.
+
+        A ``CONTRACT_EXPIRED`` close for a binary option resolves the contract, with
+        open positions settled at 1 if the close price is at least 0.5 (the outcome
+        occurred), otherwise at 0.
+
+        Parameters
+        ----------
+        data : InstrumentClose
+            The instrument close to process.
+
+        """
+        Condition.not_none(data, "data")
+
+        cdef SimulationModule module
+        for module in self.modules:
+            module.pre_process(data)
+
+        if data.close_type != InstrumentCloseType.CONTRACT_EXPIRED:
+            return
+
+        cdef OrderMatchingEngine matching_engine = self._matching_engines.get(data.instrument_id)
+        if matching_engine is None:
+            raise RuntimeError(f"No matching engine found for {data.instrument_id}")
+
+        if not isinstance(matching_engine.instrument, BinaryOption):
+            return
+
+        cdef BinaryOption binary_option = <BinaryOption>matching_engine.instrument
+        matching_engine.settle_positions(
+            binary_option.settlement_price(data.close_price.as_f64_c() >= 0.5),
+        )
+
+    cpdef void process_cash_dividend(self, CashDividend data):
+        """
+        Process the given cash dividend on its ex-date.
+
+        The account is credited the dividend amount per share for each open long
+        position in the instrument, and debited for each open short position.
+
+        Parameters
+        ----------
+        data : CashDividend
+            The cash dividend to process.
+
+        """
+        Condition.not_none(data, "data")
+
+        cdef SimulationModule module
+        for module in self.modules:
+            module.pre_process(data)
+
+        cdef list positions_open = self.cache.positions_open(self.id, data.instrument_id)
+        if not positions_open:
+            return
+
+        cdef object net_qty = sum(p.signed_decimal_qty() for p in positions_open)
+        if net_qty == 0:
+            return
+
+        cdef Money payment = Money(data.amount.as_decimal() * net_qty, data.amount.currency)
+        self._log.info(f"{data}: adjusting account by {payment.to_str()} for {net_qty} shares")
+        self.adjust_account(payment)
+
+    cpdef void process_stock_split(self, StockSplit data):
+        """
+        Process the given stock split on its ex-date.
+
+        Each open position in the instrument has its quantity and average prices
+        adjusted by the split ratio. Any fractional shares are settled as cash in lieu
+        at the (post-split) market price.
+
+        Working orders have their quantity scaled by the split ratio (rounded down) and
+        their prices by its inverse, generating `OrderUpdated` events. Partially filled
+        orders, and orders rounding to a zero quantity, are canceled instead.
+
+        Parameters
+        ----------
+        data : StockSplit
+            The stock split to process.
+
+        """
+        Condition.not_none(data, "data")
+
+        cdef SimulationModule module
+        for module in self.modules:
+            module.pre_process(data)
+
+        cdef list positions_open = self.cache.positions_open(self.id, data.instrument_id)
+
+        cdef:
+            Position position
+            object fraction
+            object cash_in_lieu
+            Price price
+        for position in positions_open:
+            fraction = position.apply_split(data.numerator, data.denominator, data.ts_event)
+            self._log.info(f"{data}: adjusted {position}")
+            if fraction == 0:
+                continue
+
+            if position.is_long_c():
+                price = self.best_bid_price(data.instrument_id)
+            else:
+                price = self.best_ask_price(data.instrument_id)
+                fraction = -fraction
+            if price is None:
+                self._log.error(
+                    f"Cannot settle cash in lieu of {fraction} shares for {position.id}: "
+                    f"no market for {data.instrument_id}",
+                )
+                continue
+
+            cash_in_lieu = price.as_decimal() * fraction * position.multiplier.as_decimal()
+            self.adjust_account(Money(cash_in_lieu, position.settlement_currency))
+
+        cdef OrderMatchingEngine matching_engine = self._matching_engines.get(data.instrument_id)
+        if matching_engine is None:
+            return
+
+        cdef Instrument instrument = matching_engine.instrument
+        cdef object ratio = Decimal(data.numerator) / Decimal(data.denominator)
+        cdef object increment = Decimal(1).scaleb(-instrument.size_precision)
+        cdef:
+            Order order
+            Quantity quantity
+            Price order_price
+            Price trigger_price
+        for order in matching_engine.get_open_orders():
+            quantity = Quantity(
+                (order.quantity.as_decimal() * ratio).quantize(increment, rounding=ROUND_DOWN),
+                instrument.size_precision,
+            )
+            if order.filled_qty._mem.raw > 0 or quantity._mem.raw == 0:
+                self._log.info(f"{data}: canceling {order.client_order_id!r}")
+                matching_engine.cancel_order(order)
+                continue
+
+            order_price = None
+            if order.has_price_c():
+                order_price = instrument.make_price(order.price.as_decimal() / ratio)
+            trigger_price = None
+            if order.has_trigger_price_c():
+                trigger_price = instrument.make_price(order.trigger_price.as_decimal() / ratio)
+
+            self._log.info(f"{data}: adjusting {order.client_order_id!r}")
+            matching_engine.update_order(order, quantity, order_price, trigger_price)
+
+    cpdef void process_borrow_rate(self, BorrowRate data):
+        """
+        Process the given borrow rate update.
+
+        The update is passed to each simulation module, where any borrow fee
+        accrual module applies the new rate from the next accrual.
+
+        Parameters
+        ----------
+        data : BorrowRate
+            The borrow rate to process.
+
+        """
+        Condition.not_none(data, "data")
+
+        cdef SimulationModule module
+        for module in self.modules:
+            module.pre_process(data)
+
     cpdef void process(self, uint64_t ts_now):
         """
         Process the exchange to the gives time.
@@ -113,9 +113,12 @@ cdef class OrderMatchingEngine:
     cdef int _position_count
     cdef int _order_count
     cdef int _execution_count
+    cdef bint _is_settled
 
     cpdef void reset(self)
     cpdef void set_fill_model(self, FillModel fill_model)
+    cpdef void update_instrument(self, Instrument instrument)
+    cdef void _sync_instrument(self, uint64_t timestamp_ns)
 
 # -- QUERIES --------------------------------------------------------------------------------------
 
@@ -164,6 +167,7 @@ cdef class OrderMatchingEngine:
     cdef void _update_market_if_touched_order(self, Order order, Quantity qty, Price trigger_price)
     cdef void _update_limit_if_touched_order(self, Order order, Quantity qty, Price price, Price trigger_price)
     cdef void _update_trailing_stop_order(self, Order order)
+    cdef void _check_binary_option_expiration(self, uint64_t timestamp_ns)
 
 # -- ORDER PROCESSING -----------------------------------------------------------------------------
 
@@ -206,6 +210,7 @@ cdef class OrderMatchingEngine:
     cpdef void cancel_order(self, Order order, bint cancel_contingencies=*)
     cpdef void update_order(self, Order order, Quantity qty, Price price=*, Price trigger_price=*, bint update_contingencies=*)
     cpdef void trigger_stop_order(self, Order order)
+    cpdef void settle_positions(self, Price settlement_px)
     cdef void _cancel_contingent_orders(self, Order order)
     cdef void _update_contingent_orders(self, Order order)
 
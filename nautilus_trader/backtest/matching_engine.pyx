@@ -78,6 +78,7 @@ from nautilus_trader.model.identifiers cimport TradeId
 from nautilus_trader.model.identifiers cimport TraderId
 from nautilus_trader.model.identifiers cimport VenueOrderId
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.instruments.binary_option cimport BinaryOption
 from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -212,6 +213,7 @@ cdef class OrderMatchingEngine:
         self._position_count = 0
         self._order_count = 0
         self._execution_count = 0
+        self._is_settled = False
 
     def __repr__(self) -> str:
         return (
@@ -237,6 +239,7 @@ cdef class OrderMatchingEngine:
         self._position_count = 0
         self._order_count = 0
         self._execution_count = 0
+        self._is_settled = False
 
         self._log.info(f"Reset OrderMatchingEngine {self.instrument.id}.")
 
@@ -256,6 +259,34 @@ cdef class OrderMatchingEngine:
 
         self._log.debug(f"Changed `FillModel` to {self._fill_model}.")
 
+    cpdef void update_instrument(self, Instrument instrument):
+        """
+        Update the matching engine with the given version of its instrument.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument version to use from now on.
+
+        Raises
+        ------
+        ValueError
+            If `instrument.id` is not equal to the matching engines instrument ID.
+
+        """
+        Condition.not_none(instrument, "instrument")
+        Condition.equal(instrument.id, self.instrument.id, "instrument.id", "self.instrument.id")
+
+        self.instrument = instrument
+        self._core.set_price_increment(instrument.price_increment)
+
+        self._log.info(f"Updated instrument {instrument.id} effective {instrument.ts_event}.")
+
+    cdef void _sync_instrument(self, uint64_t timestamp_ns):
+        cdef Instrument instrument = self.cache.instrument_at(self.instrument.id, timestamp_ns)
+        if instrument is not None and instrument is not self.instrument:
+            self.update_instrument(instrument)
+
 # -- QUERIES --------------------------------------------------------------------------------------
 
     cpdef Price best_bid_price(self):
@@ -635,9 +666,15 @@ cdef class OrderMatchingEngine:
         if self._core.order_exists(order.client_order_id):
             return  # Already processed
 
+        self._sync_instrument(self._clock.timestamp_ns())
+
         # Index identifiers
         self._account_ids[order.trader_id] = account_id
 
+        if self._is_settled:
+            self._generate_order_rejected(order, f"{self.instrument.id} has settled")
+            return  # Order rejected
+
         cdef:
             Order parent
             Order contingenct_order
@@ -1143,6 +1180,30 @@ cdef class OrderMatchingEngine:
             trigger_price=new_trigger_price,
         )
 
+    cdef void _check_binary_option_expiration(self, uint64_t timestamp_ns):
+        if self._is_settled or not isinstance(self.instrument, BinaryOption):
+            return
+
+        cdef BinaryOption binary_option = <BinaryOption>self.instrument
+        if binary_option.expiration_ns == 0 or timestamp_ns < binary_option.expiration_ns:
+            return
+
+        # Without a resolution the outcome is implied by the last market price
+        cdef Price last = self._core.last
+        cdef Price bid = self._core.bid
+        cdef Price ask = self._core.ask
+        cdef double implied
+        if last is not None:
+            implied = last.as_f64_c()
+        elif bid is not None and ask is not None:
+            implied = (bid.as_f64_c() + ask.as_f64_c()) / 2.0
+        else:
+            self._log.error(f"Cannot settle {self.instrument.id} at expiration: no market price")
+            return
+
+        self._log.info(f"{self.instrument.id} reached expiration")
+        self.settle_positions(binary_option.settlement_price(implied >= 0.5))
+
 # -- ORDER PROCESSING -----------------------------------------------------------------------------
 
     cpdef void iterate(self, uint64_t timestamp_ns):
@@ -1157,6 +1218,7 @@ cdef class OrderMatchingEngine:
 
         """
         self._clock.set_time(timestamp_ns)
+        self._sync_instrument(timestamp_ns)
 
         cdef Price_t bid
         cdef Price_t ask
@@ -1169,6 +1231,7 @@ cdef class OrderMatchingEngine:
             self._core.set_ask_raw(ask.raw)
 
         self._core.iterate(timestamp_ns)
+        self._check_binary_option_expiration(timestamp_ns)
 
         cdef list orders = self._core.get_orders()
         cdef Order order
@@ -1954,6 +2017,62 @@ cdef class OrderMatchingEngine:
             order.liquidity_side = LiquiditySide.TAKER
             self.fill_limit_order(order)
 
+    cpdef void settle_positions(self, Price settlement_px):
+        """
+        Settle the instrument at the given price.
+
+        All open orders are canceled, and each open position is closed out by a
+        settlement fill at `settlement_px` (without commission). Any orders received
+        after settlement are rejected.
+
+        Parameters
+        ----------
+        settlement_px : Price
+            The settlement price for the instrument.
+
+        """
+        Condition.not_none(settlement_px, "settlement_px")
+
+        if self._is_settled:
+            return  # Already settled
+        self._is_settled = True
+
+        cdef Order order
+        for order in self._core.get_orders():
+            if not order.is_closed_c():
+                self.cancel_order(order)
+
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef list positions_open = self.cache.positions_open(self.venue, self.instrument.id)
+        cdef:
+            Position position
+            MarketOrder settlement_order
+        for position in positions_open:
+            self._log.info(f"Settling {position.id} at {settlement_px}")
+            settlement_order = MarketOrder(
+                trader_id=position.trader_id,
+                strategy_id=position.strategy_id,
+                instrument_id=position.instrument_id,
+                client_order_id=ClientOrderId(f"SETTLE-{self.raw_id}-{position.id}"),
+                order_side=Order.closing_side_c(position.side),
+                quantity=position.quantity,
+                init_id=UUID4(),
+                ts_init=ts_now,
+                reduce_only=True,
+            )
+            self._account_ids.setdefault(position.trader_id, position.account_id)
+            self.cache.add_order(settlement_order, position.id)
+            self._generate_order_accepted(settlement_order)
+            self._generate_order_filled(
+                order=settlement_order,
+                venue_position_id=position.id if self.oms_type == OmsType.HEDGING else None,
+                last_qty=settlement_order.quantity,
+                last_px=settlement_px,
+                quote_currency=self.instrument.quote_currency,
+                commission=Money(0, self.instrument.quote_currency),
+                liquidity_side=LiquiditySide.TAKER,
+            )
+
     cdef void _update_contingent_orders(self, Order order):
         self._log.debug(f"Updating OUO orders from {order.client_order_id}", LogColor.MAGENTA)
         cdef ClientOrderId client_order_id
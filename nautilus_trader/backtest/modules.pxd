@@ -16,6 +16,8 @@
 from cpython.datetime cimport datetime
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.accounting.calculators cimport BorrowRateCalculator
+from nautilus_trader.accounting.calculators cimport FinancingCalculator
 from nautilus_trader.accounting.calculators cimport RolloverInterestCalculator
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.common.actor cimport Actor
@@ -42,3 +44,25 @@ cdef class FXRolloverInterestModule(SimulationModule):
     cdef int _day_number
 
     cdef void _apply_rollover_interest(self, datetime timestamp, int iso_week_day)
+
+
+cdef class FinancingAccrualModule(SimulationModule):
+    cdef FinancingCalculator _calculator
+    cdef object _rollover_time_of_day
+    cdef object _rollover_tz
+    cdef int _triple_day
+    cdef uint64_t _next_rollover_ns
+    cdef dict _financing_totals
+
+    cdef void _set_next_rollover(self, uint64_t ts_now)
+    cdef void _apply_financing(self, datetime rollover)
+
+
+cdef class BorrowFeeAccrualModule(SimulationModule):
+    cdef BorrowRateCalculator _calculator
+    cdef uint64_t _interval_ns
+    cdef double _interval_hours
+    cdef uint64_t _next_accrual_ns
+    cdef dict _borrow_fee_totals
+
+    cdef void _apply_borrow_fees(self)
@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import datetime as dt
+
 import msgspec
 import pandas as pd
 import pytz
@@ -22,13 +24,20 @@ from nautilus_trader.config import ActorConfig
 from cpython.datetime cimport datetime
 from libc.stdint cimport uint64_t
 
+from nautilus_trader.accounting.calculators cimport BorrowRateCalculator
+from nautilus_trader.accounting.calculators cimport FinancingCalculator
 from nautilus_trader.accounting.calculators cimport RolloverInterestCalculator
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.datetime cimport dt_to_unix_nanos
+from nautilus_trader.core.rust.model cimport AccountType
 from nautilus_trader.core.rust.model cimport AssetClass
+from nautilus_trader.core.rust.model cimport InstrumentClass
+from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.book cimport OrderBook
+from nautilus_trader.model.data cimport BorrowRate
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Currency
@@ -234,3 +243,342 @@ cdef class FXRolloverInterestModule(SimulationModule):
         self._rollover_applied = False
         self._rollover_totals = {}
         self._day_number = 0
+
+
+class FinancingAccrualConfig(ActorConfig):
+    """
+    Configuration for ``FinancingAccrualModule`` instances.
+
+    Parameters
+    ----------
+    rates : dict[str, tuple[float, float]]
+        The annualized (long, short) financing rates keyed by instrument ID string.
+        A positive rate credits the position holder, a negative rate debits.
+    rollover_time : str, default "17:00"
+        The local time of day at which financing is booked.
+    rollover_tz : str, default "US/Eastern"
+        The timezone for the `rollover_time`.
+    triple_day : int, default 3
+        The ISO weekday on which three days of financing are booked to cover the
+        weekend (3 = Wednesday for FX spot, typically 5 = Friday for CFDs).
+    days_in_year : int, default 360
+        The day count basis for converting annual rates to a daily accrual.
+
+    """
+
+    rates: dict[str, tuple[float, float]]
+    rollover_time: str = "17:00"
+    rollover_tz: str = "US/Eastern"
+    triple_day: int = 3
+    days_in_year: int = 360
+
+
+cdef class FinancingAccrualModule(SimulationModule):
+    """
+    Provides an overnight financing (swap) accrual simulation module for FX and
+    CFD positions.
+
+    At each weekday rollover the notional of every open FX or CFD position with
+    configured rates is valued at the current mid price, and the accrued financing
+    is applied to the venue account as a balance adjustment (generating an
+    `AccountState` event). No financing is booked on Saturdays or Sundays.
+
+    The module supersedes the ``FXRolloverInterestModule`` for FX positions, and
+    the two cannot be loaded on the same venue.
+
+    Parameters
+    ----------
+    config : FinancingAccrualConfig
+        The configuration for the module.
+
+    Raises
+    ------
+    ValueError
+        If `config.triple_day` is not in range [1, 5].
+
+    """
+
+    def __init__(self, config: FinancingAccrualConfig):
+        super().__init__(config)
+        Condition.in_range_int(config.triple_day, 1, 5, "config.triple_day")
+
+        self._calculator = FinancingCalculator(
+            rates={InstrumentId.from_str_c(k): tuple(v) for k, v in config.rates.items()},
+            days_in_year=config.days_in_year,
+        )
+        self._rollover_time_of_day = dt.time.fromisoformat(config.rollover_time)
+        self._rollover_tz = pytz.timezone(config.rollover_tz)
+        self._triple_day = config.triple_day
+        self._next_rollover_ns = 0  # Initialized on first process
+        self._financing_totals = {}
+
+    cpdef void process(self, uint64_t ts_now):
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            The current UNIX time (nanoseconds) in the simulated exchange.
+
+        """
+        if self._next_rollover_ns == 0:
+            self._set_next_rollover(ts_now)
+            return
+
+        cdef datetime rollover
+        while ts_now >= self._next_rollover_ns:
+            rollover = pd.Timestamp(self._next_rollover_ns, tz="UTC").astimezone(self._rollover_tz)
+            if rollover.isoweekday() < 6:
+                self._apply_financing(rollover)
+            self._set_next_rollover(self._next_rollover_ns)
+
+    cdef void _set_next_rollover(self, uint64_t ts_now):
+        cdef datetime local = pd.Timestamp(ts_now, tz="UTC").astimezone(self._rollover_tz)
+        cdef object day = local.date()
+        cdef datetime rollover
+        while True:
+            rollover = self._rollover_tz.localize(dt.datetime.combine(day, self._rollover_time_of_day))
+            if dt_to_unix_nanos(rollover) > ts_now:
+                break
+            day += dt.timedelta(days=1)
+
+        self._next_rollover_ns = dt_to_unix_nanos(rollover)
+
+    cdef void _apply_financing(self, datetime rollover):
+        cdef int days = 3 if rollover.isoweekday() == self._triple_day else 1
+
+        cdef Position position
+        cdef Instrument instrument
+        cdef OrderBook book
+        cdef Currency currency
+        cdef double notional
+        cdef double financing
+        cdef double xrate
+        for position in self.exchange.cache.positions_open():
+            instrument = self.exchange.instruments.get(position.instrument_id)
+            if instrument is None or not self._calculator.has_rates(instrument.id):
+                continue
+            if instrument.asset_class != AssetClass.FX and instrument.instrument_class != InstrumentClass.CFD:
+                continue  # Only applicable to FX and CFDs
+
+            book = self.exchange.get_book(instrument.id)
+            mid = book.midpoint()
+            if mid is None:
+                mid = book.best_bid_price() or book.best_ask_price()
+            if mid is None:
+                self._log.error(f"Cannot apply financing for {position.id!r}: no market for {instrument.id}")
+                continue
+
+            notional = instrument.notional_value(
+                position.quantity,
+                Price(float(mid), precision=instrument.price_precision),
+            ).as_f64_c()
+            financing = self._calculator.calc_financing(instrument.id, position.side, notional, days)
+            if financing == 0.0:
+                continue
+
+            currency = instrument.get_settlement_currency()
+            if self.exchange.base_currency is not None and currency != self.exchange.base_currency:
+                xrate = self.exchange.cache.get_xrate(
+                    venue=instrument.id.venue,
+                    from_currency=currency,
+                    to_currency=self.exchange.base_currency,
+                    price_type=PriceType.MID,
+                )
+                if xrate == 0.0:
+                    self._log.error(
+                        f"Cannot apply financing for {position.id!r}: "
+                        f"no exchange rate for {currency}/{self.exchange.base_currency}",
+                    )
+                    continue
+                financing *= xrate
+                currency = self.exchange.base_currency
+
+            self._financing_totals[currency] = Money(
+                self._financing_totals.get(currency, 0.0) + financing,
+                currency,
+            )
+            self.exchange.adjust_account(Money(financing, currency))
+
+    cpdef void log_diagnostics(self, Logger logger):
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        logger : Logger
+            The logger to log to.
+
+        """
+        financing_totals = ', '.join([b.to_str() for b in self._financing_totals.values()])
+        logger.info(f"Financing (totals): {financing_totals}")
+
+    cpdef void reset(self):
+        self._next_rollover_ns = 0  # Initialized on first process
+        self._financing_totals = {}
+
+
+class BorrowFeeAccrualConfig(ActorConfig):
+    """
+    Configuration for ``BorrowFeeAccrualModule`` instances.
+
+    Parameters
+    ----------
+    rates : dict[str, float]
+        The initial annualized borrow rates keyed by asset (currency code), which
+        are then updated by any `BorrowRate` data for the venue.
+    accrual_interval : str, default "HOURLY"
+        The interval at which borrow fees are charged {``HOURLY``, ``DAILY``}.
+    days_in_year : int, default 365
+        The day count basis for converting annual rates to each accrual.
+
+    """
+
+    rates: dict[str, float] = {}
+    accrual_interval: str = "HOURLY"
+    days_in_year: int = 365
+
+
+cdef class BorrowFeeAccrualModule(SimulationModule):
+    """
+    Provides a borrow fee accrual simulation module for margined short spot positions.
+
+    Shorting a spot instrument on a margin account borrows the base asset, which
+    accrues interest at the borrow rate for that asset. At each accrual (on the
+    hour or at midnight UTC) the borrowed amount of every open short spot position
+    is valued at the current mid price, and the fee is debited from the venue
+    account as a balance adjustment (generating an `AccountState` event).
+
+    Parameters
+    ----------
+    config : BorrowFeeAccrualConfig
+        The configuration for the module.
+
+    Raises
+    ------
+    ValueError
+        If `config.accrual_interval` is not either ``HOURLY`` or ``DAILY``.
+
+    """
+
+    def __init__(self, config: BorrowFeeAccrualConfig):
+        super().__init__(config)
+        Condition.is_in(config.accrual_interval, ("HOURLY", "DAILY"), "config.accrual_interval", "intervals")
+
+        self._calculator = BorrowRateCalculator(
+            rates={Currency.from_str_c(k): float(v) for k, v in config.rates.items()},
+            days_in_year=config.days_in_year,
+        )
+        self._interval_hours = 1.0 if config.accrual_interval == "HOURLY" else 24.0
+        self._interval_ns = <uint64_t>(self._interval_hours * 60 * 60 * 1_000_000_000)
+        self._next_accrual_ns = 0  # Initialized on first process
+        self._borrow_fee_totals = {}
+
+    cpdef void pre_process(self, Data data):
+        """
+        Pre-process the given data, updating the borrow rate for any `BorrowRate`
+        data for the registered venue.
+
+        Parameters
+        ----------
+        data : Data
+            The data to pre-process.
+
+        """
+        if isinstance(data, BorrowRate) and data.venue == self.exchange.id:
+            self._calculator.update_rate(data.currency, data.rate)
+
+    cpdef void process(self, uint64_t ts_now):
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        ts_now : uint64_t
+            The current UNIX time (nanoseconds) in the simulated exchange.
+
+        """
+        if self._next_accrual_ns == 0:
+            self._next_accrual_ns = (ts_now // self._interval_ns + 1) * self._interval_ns
+            return
+
+        while ts_now >= self._next_accrual_ns:
+            self._apply_borrow_fees()
+            self._next_accrual_ns += self._interval_ns
+
+    cdef void _apply_borrow_fees(self):
+        if self.exchange.account_type != AccountType.MARGIN:
+            return  # Spot assets can only be borrowed on margin
+
+        cdef Position position
+        cdef Instrument instrument
+        cdef OrderBook book
+        cdef Currency borrowed
+        cdef Currency currency
+        cdef double notional
+        cdef double fee
+        cdef double xrate
+        for position in self.exchange.cache.positions_open(venue=self.exchange.id, side=PositionSide.SHORT):
+            instrument = self.exchange.instruments.get(position.instrument_id)
+            if instrument is None or instrument.instrument_class != InstrumentClass.SPOT:
+                continue  # Only applicable to short spot positions
+            borrowed = instrument.get_base_currency()
+            if borrowed is None or not self._calculator.has_rate(borrowed):
+                continue
+
+            book = self.exchange.get_book(instrument.id)
+            mid = book.midpoint()
+            if mid is None:
+                mid = book.best_bid_price() or book.best_ask_price()
+            if mid is None:
+                self._log.error(f"Cannot apply borrow fee for {position.id!r}: no market for {instrument.id}")
+                continue
+
+            notional = instrument.notional_value(
+                position.quantity,
+                Price(float(mid), precision=instrument.price_precision),
+            ).as_f64_c()
+            fee = self._calculator.calc_borrow_fee(borrowed, notional, self._interval_hours)
+            if fee == 0.0:
+                continue
+
+            currency = instrument.get_settlement_currency()
+            if self.exchange.base_currency is not None and currency != self.exchange.base_currency:
+                xrate = self.exchange.cache.get_xrate(
+                    venue=instrument.id.venue,
+                    from_currency=currency,
+                    to_currency=self.exchange.base_currency,
+                    price_type=PriceType.MID,
+                )
+                if xrate == 0.0:
+                    self._log.error(
+                        f"Cannot apply borrow fee for {position.id!r}: "
+                        f"no exchange rate for {currency}/{self.exchange.base_currency}",
+                    )
+                    continue
+                fee *= xrate
+                currency = self.exchange.base_currency
+
+            self._borrow_fee_totals[currency] = Money(
+                self._borrow_fee_totals.get(currency, 0.0) + fee,
+                currency,
+            )
+            self.exchange.adjust_account(Money(-fee, currency))
+
+    cpdef void log_diagnostics(self, Logger logger):
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        logger : Logger
+            The logger to log to.
+
+        """
+        borrow_fee_totals = ', '.join([b.to_str() for b in self._borrow_fee_totals.values()])
+        logger.info(f"Borrow fees (totals): {borrow_fee_totals}")
+
+    cpdef void reset(self):
+        self._next_accrual_ns = 0  # Initialized on first process
+        self._borrow_fee_totals = {}
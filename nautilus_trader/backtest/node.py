@@ -352,6 +352,7 @@ class BacktestNode:
             path=config.catalog_path,
             fs_protocol=config.catalog_fs_protocol,
             fs_storage_options=config.catalog_fs_storage_options,
+            local_cache_dir=config.catalog_local_cache_dir,
         )
 
     @classmethod
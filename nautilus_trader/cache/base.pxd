@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PositionSide
@@ -79,6 +81,8 @@ cdef class CacheFacade:
 # -- INSTRUMENT QUERIES ---------------------------------------------------------------------------
 
     cpdef Instrument instrument(self, InstrumentId instrument_id)
+    cpdef Instrument instrument_at(self, InstrumentId instrument_id, uint64_t ts)
+    cpdef list instrument_versions(self, InstrumentId instrument_id)
     cpdef list instrument_ids(self, Venue venue=*)
     cpdef list instruments(self, Venue venue=*)
 
@@ -109,6 +113,7 @@ cdef class CacheFacade:
     cpdef set actor_ids(self)
     cpdef set strategy_ids(self)
     cpdef set exec_algorithm_ids(self)
+    cpdef set order_tags(self)
 
 # -- ORDER QUERIES --------------------------------------------------------------------------------
 
@@ -122,6 +127,7 @@ cdef class CacheFacade:
     cpdef list orders_emulated(self, Venue venue=*, InstrumentId instrument_id=*, StrategyId strategy_id=*, OrderSide side=*)
     cpdef list orders_inflight(self, Venue venue=*, InstrumentId instrument_id=*, StrategyId strategy_id=*, OrderSide side=*)
     cpdef list orders_for_position(self, PositionId position_id)
+    cpdef list orders_for_tag(self, str tag, Venue venue=*, InstrumentId instrument_id=*, StrategyId strategy_id=*, OrderSide side=*)
     cpdef bint order_exists(self, ClientOrderId client_order_id)
     cpdef bint is_order_open(self, ClientOrderId client_order_id)
     cpdef bint is_order_closed(self, ClientOrderId client_order_id)
@@ -13,6 +13,8 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
@@ -131,6 +133,14 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `instrument` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef Instrument instrument_at(self, InstrumentId instrument_id, uint64_t ts):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `instrument_at` must be implemented in the subclass")  # pragma: no cover
+
+    cpdef list instrument_versions(self, InstrumentId instrument_id):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `instrument_versions` must be implemented in the subclass")  # pragma: no cover
+
     cpdef list instrument_ids(self, Venue venue = None):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `instrument_ids` must be implemented in the subclass")  # pragma: no cover
@@ -221,6 +231,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `exec_algorithm_ids` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef set order_tags(self):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `order_tags` must be implemented in the subclass")  # pragma: no cover
+
 # -- ORDER QUERIES --------------------------------------------------------------------------------
 
     cpdef Order order(self, ClientOrderId client_order_id):
@@ -263,6 +277,10 @@ cdef class CacheFacade:
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `orders_for_position` must be implemented in the subclass")  # pragma: no cover
 
+    cpdef list orders_for_tag(self, str tag, Venue venue = None, InstrumentId instrument_id = None, StrategyId strategy_id = None, OrderSide side = OrderSide.NO_ORDER_SIDE):
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method `orders_for_tag` must be implemented in the subclass")  # pragma: no cover
+
     cpdef bint order_exists(self, ClientOrderId client_order_id):
         """Abstract method (implement in subclass)."""
         raise NotImplementedError("method `order_exists` must be implemented in the subclass")  # pragma: no cover
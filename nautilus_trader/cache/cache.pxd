@@ -65,6 +65,7 @@ cdef class Cache(CacheFacade):
     cdef dict _bars_ask
     cdef dict _currencies
     cdef dict _instruments
+    cdef dict _instrument_versions
     cdef dict _synthetics
     cdef dict _accounts
     cdef dict _orders
@@ -87,6 +88,7 @@ cdef class Cache(CacheFacade):
     cdef dict _index_strategy_positions
     cdef dict _index_exec_algorithm_orders
     cdef dict _index_exec_spawn_orders
+    cdef dict _index_tag_orders
     cdef set _index_orders
     cdef set _index_orders_open
     cdef set _index_orders_closed
@@ -154,6 +156,7 @@ cdef class Cache(CacheFacade):
     cpdef void add_bars(self, list bars)
     cpdef void add_currency(self, Currency currency)
     cpdef void add_instrument(self, Instrument instrument)
+    cdef bint _add_instrument_version(self, list versions, Instrument instrument)
     cpdef void add_synthetic(self, SyntheticInstrument synthetic)
     cpdef void add_account(self, Account account)
     cpdef void add_order(self, Order order, PositionId position_id=*, ClientId client_id=*, bint override=*)
@@ -67,6 +67,24 @@ from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.trading.strategy cimport Strategy
 
 
+cdef list _parse_tags(str tags):
+    if not tags:
+        return []
+    return [tag.strip() for tag in tags.split(",") if tag.strip()]
+
+
+cdef bint _is_same_specification(Instrument a, Instrument b):
+    if type(a) is not type(b):
+        return False
+    to_dict = getattr(type(a), "to_dict", Instrument.base_to_dict)
+    cdef dict a_values = to_dict(a)
+    cdef dict b_values = to_dict(b)
+    for values in (a_values, b_values):
+        values.pop("ts_event", None)
+        values.pop("ts_init", None)
+    return a_values == b_values
+
+
 cdef class Cache(CacheFacade):
     """
     Provides a common object cache for market and execution related data.
@@ -121,6 +139,7 @@ cdef class Cache(CacheFacade):
         self._bars_ask: dict[InstrumentId, Bar] = {}
         self._currencies: dict[str, Currency] = {}
         self._instruments: dict[InstrumentId, Instrument] = {}
+        self._instrument_versions: dict[InstrumentId, list[Instrument]] = {}
         self._synthetics: dict[InstrumentId, SyntheticInstrument] = {}
         self._accounts: dict[AccountId, Account] = {}
         self._orders: dict[ClientOrderId, Order] = {}
@@ -144,6 +163,7 @@ cdef class Cache(CacheFacade):
         self._index_strategy_positions: dict[StrategyId, set[PositionId]] = {}
         self._index_exec_algorithm_orders: dict[ExecAlgorithmId, set[ClientOrderId]] = {}
         self._index_exec_spawn_orders: dict[ClientOrderId: set[ClientOrderId]] = {}
+        self._index_tag_orders: dict[str, set[ClientOrderId]] = {}
         self._index_orders: set[ClientOrderId] = set()
         self._index_orders_open: set[ClientOrderId] = set()
         self._index_orders_closed: set[ClientOrderId] = set()
@@ -214,6 +234,8 @@ cdef class Cache(CacheFacade):
         else:
             self._instruments = {}
 
+        self._instrument_versions = {k: [v] for k, v in self._instruments.items()}
+
         cdef int count = len(self._instruments)
         self._log.info(
             f"Cached {count} instrument{'' if count == 1 else 's'} from database.",
@@ -692,6 +714,7 @@ cdef class Cache(CacheFacade):
         self._index_strategy_positions.clear()
         self._index_exec_algorithm_orders.clear()
         self._index_exec_spawn_orders.clear()
+        self._index_tag_orders.clear()
         self._index_orders.clear()
         self._index_orders_open.clear()
         self._index_orders_closed.clear()
@@ -734,6 +757,7 @@ cdef class Cache(CacheFacade):
 
         if self._drop_instruments_on_reset:
             self._instruments.clear()
+            self._instrument_versions.clear()
 
         self._log.debug(f"Reset cache.")
 
@@ -829,6 +853,12 @@ cdef class Cache(CacheFacade):
             if order.exec_algorithm_id is not None:
                 self._index_exec_algorithms.add(order.exec_algorithm_id)
 
+            # 16: Build _index_tag_orders -> {str, {ClientOrderId}}
+            for tag in _parse_tags(order.tags):
+                if tag not in self._index_tag_orders:
+                    self._index_tag_orders[tag] = set()
+                self._index_tag_orders[tag].add(client_order_id)
+
     cdef void _build_indexes_from_positions(self):
         cdef ClientOrderId client_order_id
         cdef PositionId position_id
@@ -982,6 +1012,7 @@ cdef class Cache(CacheFacade):
             instrument = self._database.load_instrument(instrument_id)
             if instrument is not None:
                 self._instruments[instrument.id] = instrument
+                self._instrument_versions[instrument.id] = [instrument]
 
         return instrument
 
@@ -1321,25 +1352,56 @@ cdef class Cache(CacheFacade):
         """
         Add the given instrument to the cache.
 
+        If the instrument ID is already cached then the instrument is recorded
+        as a new version effective from its `ts_event`, with prior versions
+        retained for `instrument_at` queries. A version with the same `ts_event`
+        as an existing version replaces it, and a version identical in
+        specification to the version it would follow is ignored.
+
         Parameters
         ----------
         instrument : Instrument
             The instrument to add.
 
         """
-        self._instruments[instrument.id] = instrument
+        cdef list versions = self._instrument_versions.get(instrument.id)
+        if versions is None:
+            self._instrument_versions[instrument.id] = [instrument]
+        elif not self._add_instrument_version(versions, instrument):
+            self._log.debug(f"Instrument {instrument.id} unchanged.")
+            return
+
+        self._instruments[instrument.id] = self._instrument_versions[instrument.id][-1]
 
         if isinstance(instrument, (CurrencyPair, CryptoPerpetual)):
             self._xrate_symbols[instrument.id] = (
                 f"{instrument.base_currency}/{instrument.quote_currency}"
             )
 
-        self._log.debug(f"Added instrument {instrument.id}.")
+        self._log.debug(f"Added instrument {instrument.id} effective {instrument.ts_event}.")
 
-        # Update database
-        if self._database is not None:
+        # Update database (only the current version is persisted)
+        if self._database is not None and self._instruments[instrument.id] is instrument:
             self._database.add_instrument(instrument)
 
+    cdef bint _add_instrument_version(self, list versions, Instrument instrument):
+        # Versions are held in ascending `ts_event` order, updates normally arrive last
+        cdef int index = len(versions)
+        while index > 0 and (<Instrument>versions[index - 1]).ts_event > instrument.ts_event:
+            index -= 1
+
+        cdef Instrument previous
+        if index > 0:
+            previous = versions[index - 1]
+            if previous.ts_event == instrument.ts_event:
+                versions[index - 1] = instrument
+                return True
+            if _is_same_specification(previous, instrument):
+                return False
+
+        versions.insert(index, instrument)
+        return True
+
     cpdef void add_synthetic(self, SyntheticInstrument synthetic):
         """
         Add the given synthetic instrument to the cache.
@@ -1418,7 +1480,12 @@ cdef class Cache(CacheFacade):
         """
         Condition.not_none(order, "order")
         if not override:
-            Condition.not_in(order.client_order_id, self._orders, "order.client_order_id", "_orders")
+            if order.client_order_id in self._orders:
+                raise ValueError(
+                    f"Duplicate {order.client_order_id!r} for {order.strategy_id!r}: "
+                    "client order IDs must be unique, and a reused ID would be rejected "
+                    "or misrouted by the venue",
+                )
             Condition.not_in(order.client_order_id, self._index_orders, "order.client_order_id", "_index_orders")
             Condition.not_in(order.client_order_id, self._index_order_position, "order.client_order_id", "_index_order_position")
             Condition.not_in(order.client_order_id, self._index_order_strategy, "order.client_order_id", "_index_order_strategy")
@@ -1470,6 +1537,16 @@ cdef class Cache(CacheFacade):
             else:
                 self._index_exec_spawn_orders[order.exec_spawn_id].add(order.client_order_id)
 
+        # Index: tag -> set[ClientOrderId]
+        cdef str tag
+        cdef set tag_orders
+        for tag in _parse_tags(order.tags):
+            tag_orders = self._index_tag_orders.get(tag)
+            if not tag_orders:
+                self._index_tag_orders[tag] = {order.client_order_id}
+            else:
+                tag_orders.add(order.client_order_id)
+
         # Update emulation
         if order.emulation_trigger == TriggerType.NO_TRIGGER:
             self._index_orders_emulated.discard(order.client_order_id)
@@ -2399,6 +2476,59 @@ cdef class Cache(CacheFacade):
 
         return self._instruments.get(instrument_id)
 
+    cpdef Instrument instrument_at(self, InstrumentId instrument_id, uint64_t ts):
+        """
+        Return the version of the instrument effective at the given timestamp.
+
+        This is the latest version with a `ts_event` at or before `ts`. If `ts`
+        precedes all cached versions then the earliest version is returned.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID of the instrument to return.
+        ts : uint64_t
+            The UNIX timestamp (nanoseconds) for the query.
+
+        Returns
+        -------
+        Instrument or ``None``
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        cdef list versions = self._instrument_versions.get(instrument_id)
+        if not versions:
+            return self._instruments.get(instrument_id)
+
+        cdef Instrument version
+        cdef int i
+        for i in range(len(versions) - 1, -1, -1):
+            version = versions[i]
+            if version.ts_event <= ts:
+                return version
+
+        return versions[0]
+
+    cpdef list instrument_versions(self, InstrumentId instrument_id):
+        """
+        Return all versions of the instrument held by the cache.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID of the versions to return.
+
+        Returns
+        -------
+        list[Instrument]
+            Sorted in ascending order of `ts_event`.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return list(self._instrument_versions.get(instrument_id, []))
+
     cpdef list instrument_ids(self, Venue venue = None):
         """
         Return all instrument IDs held by the cache.
@@ -2926,6 +3056,17 @@ cdef class Cache(CacheFacade):
         """
         return self._index_exec_algorithms.copy()
 
+    cpdef set order_tags(self):
+        """
+        Return all order tags.
+
+        Returns
+        -------
+        set[str]
+
+        """
+        return set(self._index_tag_orders.keys())
+
 # -- ORDER QUERIES --------------------------------------------------------------------------------
 
     cpdef Order order(self, ClientOrderId client_order_id):
@@ -3413,6 +3554,50 @@ cdef class Cache(CacheFacade):
         """
         return len(self.orders(venue, instrument_id, strategy_id, side))
 
+    cpdef list orders_for_tag(
+        self,
+        str tag,
+        Venue venue = None,
+        InstrumentId instrument_id = None,
+        StrategyId strategy_id = None,
+        OrderSide side = OrderSide.NO_ORDER_SIDE,
+    ):
+        """
+        Return all orders carrying the given tag for the given query filters.
+
+        An order carries each of the comma separated values of its `tags`, with
+        surrounding whitespace removed (e.g. "signal-a, leg-1" carries both
+        "signal-a" and "leg-1").
+
+        Parameters
+        ----------
+        tag : str
+            The order tag.
+        venue : Venue, optional
+            The venue ID query filter.
+        instrument_id : InstrumentId, optional
+            The instrument ID query filter.
+        strategy_id : StrategyId, optional
+            The strategy ID query filter.
+        side : OrderSide, default ``NO_ORDER_SIDE`` (no filter)
+            The order side query filter.
+
+        Returns
+        -------
+        list[Order]
+
+        """
+        Condition.valid_string(tag, "tag")
+
+        cdef set query = self._build_order_query_filter_set(venue, instrument_id, strategy_id)
+
+        cdef set tag_order_ids = self._index_tag_orders.get(tag)
+
+        if query is not None and tag_order_ids is not None:
+            tag_order_ids = query.intersection(tag_order_ids)
+
+        return self._get_orders_for_ids(tag_order_ids, side)
+
 # -- ORDER LIST QUERIES ---------------------------------------------------------------------------
 
     cpdef OrderList order_list(self, OrderListId order_list_id):
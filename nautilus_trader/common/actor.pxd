@@ -23,6 +23,7 @@ from nautilus_trader.common.component cimport Clock
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport MessageBus
+from nautilus_trader.common.component cimport TimeEvent
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.model cimport BookType
@@ -58,6 +59,9 @@ cdef class Actor(Component):
     cdef dict _indicators_for_quotes
     cdef dict _indicators_for_trades
     cdef dict _indicators_for_bars
+    cdef object _warmup_state
+    cdef uint64_t _warmup_timeout_ns
+    cdef uint64_t _warmup_start_ns
 
     cdef readonly PortfolioFacade portfolio
     """The read-only portfolio for the actor.\n\n:returns: `PortfolioFacade`"""
@@ -71,6 +75,8 @@ cdef class Actor(Component):
     """The message bus for the actor (if registered).\n\n:returns: `MessageBus` or ``None``"""
     cdef readonly CacheFacade cache
     """The read-only cache for the actor.\n\n:returns: `CacheFacade`"""
+    cdef readonly bint warmup_required
+    """If the actor must complete a warm-up phase each time it starts.\n\n:returns: `bool`"""
 
     cpdef bint indicators_initialized(self)
 
@@ -224,6 +230,16 @@ cdef class Actor(Component):
     cpdef bint has_pending_requests(self)
     cpdef set pending_requests(self)
 
+# -- WARM-UP --------------------------------------------------------------------------------------
+
+    cpdef bint is_warmed_up(self)
+    cpdef void complete_warmup(self)
+    cpdef dict warmup_diagnostics(self)
+    cdef void _init_warmup(self, bint warmup_required, warmup_timeout_secs)
+    cdef void _start_warmup(self)
+    cdef void _reset_warmup(self)
+    cpdef void _warmup_timeout(self, TimeEvent event)
+
 # -- HANDLERS -------------------------------------------------------------------------------------
 
     cpdef void handle_instrument(self, Instrument instrument)
@@ -31,6 +31,7 @@ import cython
 
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ImportableActorConfig
+from nautilus_trader.common.enums import WarmupState
 from nautilus_trader.common.executor import ActorExecutor
 from nautilus_trader.common.executor import TaskId
 from nautilus_trader.persistence.writer import generate_signal_class
@@ -47,12 +48,14 @@ from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport LiveClock
 from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport MessageBus
+from nautilus_trader.common.component cimport TimeEvent
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.core.rust.common cimport ComponentState
 from nautilus_trader.core.rust.common cimport LogColor
 from nautilus_trader.core.rust.common cimport logging_is_initialized
+from nautilus_trader.core.rust.core cimport secs_to_nanos
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.data.messages cimport DataRequest
@@ -127,6 +130,7 @@ cdef class Actor(Component):
 
         # Configuration
         self.config = config
+        self._init_warmup(config.warmup_required, config.warmup_timeout_secs)
 
         self.trader_id = None  # Initialized when registered
         self.msgbus = None     # Initialized when registered
@@ -1038,6 +1042,7 @@ cdef class Actor(Component):
 # -- ACTION IMPLEMENTATIONS -----------------------------------------------------------------------
 
     cpdef void _start(self):
+        self._start_warmup()
         self.on_start()
 
     cpdef void _stop(self):
@@ -1062,6 +1067,7 @@ cdef class Actor(Component):
         self.on_reset()
 
         self._pending_requests.clear()
+        self._reset_warmup()
 
         self._indicators.clear()
         self._indicators_for_quotes.clear()
@@ -2330,6 +2336,109 @@ cdef class Actor(Component):
         """
         return set(self._pending_requests.keys())
 
+# -- WARM-UP --------------------------------------------------------------------------------------
+
+    @property
+    def warmup_state(self):
+        """
+        Return the warm-up state of the actor.
+
+        Returns
+        -------
+        WarmupState
+
+        """
+        return self._warmup_state
+
+    cpdef bint is_warmed_up(self):
+        """
+        Return whether the actor has completed its warm-up (always True if not required).
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._warmup_state == WarmupState.READY
+
+    cpdef void complete_warmup(self):
+        """
+        Complete the warm-up phase, declaring the actor initialized.
+
+        Should be called once any historical data requested on start has been
+        received and indicators are initialized. Strategies are only able to
+        submit orders once the warm-up is complete.
+
+        Has no effect if the actor is not warming up.
+
+        """
+        if self._warmup_state not in (WarmupState.WARMING_UP, WarmupState.TIMED_OUT):
+            return
+
+        cdef str timer_name = f"{self.id}-WARMUP-TIMEOUT"
+        if timer_name in self._clock.timer_names:
+            self._clock.cancel_timer(timer_name)
+
+        cdef uint64_t elapsed_ns = self._clock.timestamp_ns() - self._warmup_start_ns
+        self._warmup_state = WarmupState.READY
+        self._log.info(f"Warm-up completed in {elapsed_ns / 1_000_000_000:.3f}s.", LogColor.GREEN)
+
+    cpdef dict warmup_diagnostics(self):
+        """
+        Return diagnostics for the current warm-up phase.
+
+        Includes the requests still awaiting a response, and any registered
+        indicators which are not yet initialized.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        cdef uint64_t elapsed_ns = 0
+        if self._warmup_start_ns > 0:
+            elapsed_ns = self._clock.timestamp_ns() - self._warmup_start_ns
+
+        return {
+            "state": self._warmup_state.name,
+            "elapsed_secs": elapsed_ns / 1_000_000_000,
+            "pending_requests": sorted(str(request_id) for request_id in self._pending_requests),
+            "uninitialized_indicators": [
+                repr(indicator) for indicator in self._indicators if not indicator.initialized
+            ],
+        }
+
+    cdef void _init_warmup(self, bint warmup_required, warmup_timeout_secs):
+        self.warmup_required = warmup_required
+        self._warmup_timeout_ns = secs_to_nanos(warmup_timeout_secs) if warmup_timeout_secs else 0
+        self._reset_warmup()
+
+    cdef void _start_warmup(self):
+        if not self.warmup_required:
+            return
+
+        self._warmup_state = WarmupState.WARMING_UP
+        self._warmup_start_ns = self._clock.timestamp_ns()
+        self._log.info("Warming up, awaiting `complete_warmup()`...", LogColor.BLUE)
+
+        if self._warmup_timeout_ns > 0:
+            self._clock.set_time_alert_ns(
+                name=f"{self.id}-WARMUP-TIMEOUT",
+                alert_time_ns=self._warmup_start_ns + self._warmup_timeout_ns,
+                callback=self._warmup_timeout,
+            )
+
+    cdef void _reset_warmup(self):
+        self._warmup_state = WarmupState.PENDING if self.warmup_required else WarmupState.READY
+        self._warmup_start_ns = 0
+
+    cpdef void _warmup_timeout(self, TimeEvent event):
+        if self._warmup_state != WarmupState.WARMING_UP:
+            return  # Already completed
+
+        self._warmup_state = WarmupState.TIMED_OUT
+        self._log.error(f"Warm-up timed out: {self.warmup_diagnostics()}.")
+
 # -- HANDLERS -------------------------------------------------------------------------------------
 
     cpdef void handle_instrument(self, Instrument instrument):
@@ -2832,6 +2941,9 @@ cdef class Actor(Component):
         if callback is not None:
             callback(request_id)
 
+        if self._warmup_state == WarmupState.WARMING_UP and not self._pending_requests:
+            self._log.info("Warm-up requests complete, awaiting `complete_warmup()`.", LogColor.BLUE)
+
     cpdef void _handle_indicators_for_quote(self, list indicators, QuoteTick tick):
         cdef Indicator indicator
         for indicator in indicators:
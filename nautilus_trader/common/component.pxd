@@ -380,6 +380,7 @@ cdef class Throttler:
     """If count of messages sent from the throttler.\n\n:returns: `int`"""
 
     cpdef void reset(self)
+    cpdef void set_rate(self, int limit, timedelta interval)
     cpdef double used(self)
     cpdef void send(self, msg)
     cdef int64_t _delta_next(self)
@@ -2260,7 +2260,7 @@ cdef class MessageBus:
 
         self.trader_id = trader_id
         self.serializer = serializer
-        self.has_backing = config.database is not None
+        self.has_backing = config.database is not None or config.ipc_path is not None
         self.snapshot_orders = snapshot_orders
         self.snapshot_positions = snapshot_positions
 
@@ -2285,6 +2285,7 @@ cdef class MessageBus:
         self._log.info(f"{config.use_instance_id=}", LogColor.BLUE)
         self._log.info(f"{config.streams_prefix=}", LogColor.BLUE)
         self._log.info(f"{config.types_filter=}", LogColor.BLUE)
+        self._log.info(f"{config.ipc_path=}", LogColor.BLUE)
 
         # Copy and clear `types_filter` before passing down to the core MessageBus
         cdef list types_filter = copy.copy(config.types_filter)
@@ -2302,7 +2303,7 @@ cdef class MessageBus:
         self._patterns: dict[str, Subscription[:]] = {}
         self._subscriptions: dict[Subscription, list[str]] = {}
         self._correlation_index: dict[UUID4, Callable[[Any], None]] = {}
-        self._has_backing = config.database is not None
+        self._has_backing = config.database is not None or config.ipc_path is not None
         self._publishable_types = _EXTERNAL_PUBLISHABLE_TYPES
         if types_filter is not None:
             self._publishable_types = tuple(o for o in _EXTERNAL_PUBLISHABLE_TYPES if o not in types_filter)
@@ -2969,6 +2970,42 @@ cdef class Throttler:
         self.sent_count = 0
         self.is_limiting = False
 
+    cpdef void set_rate(self, int limit, timedelta interval):
+        """
+        Set the rate limit for the throttler.
+
+        Takes effect for the next message received, any buffered messages are
+        retained. The timestamps of the most recently sent messages are kept so
+        that the new rate is applied without a burst.
+
+        Parameters
+        ----------
+        limit : int
+            The limit setting for the throttling.
+        interval : timedelta
+            The interval setting for the throttling.
+
+        Raises
+        ------
+        ValueError
+            If `limit` is not positive (> 0).
+        ValueError
+            If `interval` is not positive (> 0).
+
+        """
+        Condition.positive_int(limit, "limit")
+        Condition.not_none(interval, "interval")
+        Condition.positive(interval.total_seconds(), "interval.total_seconds()")
+
+        # Most recent timestamps are on the left
+        self._timestamps = deque(list(self._timestamps)[:limit], maxlen=limit)
+        self._interval_ns = secs_to_nanos(interval.total_seconds())
+        self._warm = len(self._timestamps) == limit
+        self.limit = limit
+        self.interval = interval
+
+        self._log.info(f"Set rate {limit}/{interval}.")
+
     cpdef double used(self):
         """
         Return the percentage of maximum rate currently used.
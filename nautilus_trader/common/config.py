@@ -299,6 +299,10 @@ class MessageBusConfig(NautilusConfig, frozen=True):
         many traders to be configured to write to the same streams.
     types_filter : list[type], optional
         A list of serializable types *not* to publish externally.
+    ipc_path : str, optional
+        The Unix domain socket path for streaming externally published messages to
+        other processes on the same host. If set, this transport is used instead of the
+        backing `database` for external publishing.
 
     """
 
@@ -312,6 +316,7 @@ class MessageBusConfig(NautilusConfig, frozen=True):
     use_instance_id: bool = False
     streams_prefix: str = "streams"
     types_filter: list[type] | None = None
+    ipc_path: str | None = None
 
 
 class InstrumentProviderConfig(NautilusConfig, frozen=True):
@@ -374,10 +379,17 @@ class ActorConfig(NautilusConfig, kw_only=True, frozen=True):
     component_id : ComponentId, optional
         The component ID. If ``None`` then the identifier will be taken from
         `type(self).__name__`.
+    warmup_required : bool, default False
+        If the actor starts in a warm-up phase, which lasts until the actor calls
+        `complete_warmup()`.
+    warmup_timeout_secs : PositiveFloat, optional
+        The timeout for the warm-up phase, after which diagnostics are logged.
 
     """
 
     component_id: ComponentId | None = None
+    warmup_required: bool = False
+    warmup_timeout_secs: PositiveFloat | None = None
 
 
 class ImportableActorConfig(NautilusConfig, frozen=True):
@@ -16,6 +16,8 @@
 Defines system level enums for use with framework components.
 """
 
+from enum import Enum
+from enum import unique
 
 from nautilus_trader.common.component import component_state_from_str
 from nautilus_trader.common.component import component_state_to_str
@@ -29,11 +31,28 @@ from nautilus_trader.core.rust.common import LogColor
 from nautilus_trader.core.rust.common import LogLevel
 
 
+@unique
+class WarmupState(Enum):
+    """
+    Represents the warm-up state of an actor or strategy.
+    """
+
+    PENDING = 1
+    """A warm-up is required, and the component has not yet started."""
+    WARMING_UP = 2
+    """The component is warming up, awaiting historical data and initialization."""
+    READY = 3
+    """The warm-up was completed (or is not required)."""
+    TIMED_OUT = 4
+    """The warm-up did not complete within the timeout."""
+
+
 __all__ = [
     "ComponentState",
     "ComponentTrigger",
     "LogColor",
     "LogLevel",
+    "WarmupState",
     "component_state_from_str",
     "component_state_to_str",
     "component_trigger_from_str",
@@ -41,3 +60,4 @@ __all__ = [
     "log_level_from_str",
     "log_level_to_str",
 ]
+
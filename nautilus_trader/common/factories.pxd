@@ -16,6 +16,7 @@
 from decimal import Decimal
 
 from cpython.datetime cimport datetime
+from libc.stdint cimport int64_t
 
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Clock
@@ -33,6 +34,7 @@ from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport OrderListId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.limit cimport LimitOrder
@@ -265,3 +267,9 @@ cdef class OrderFactory:
         str tp_tags=*,
         str sl_tags=*,
     )
+
+    cpdef Price market_to_limit_price(self, Instrument instrument, OrderSide order_side, int offset_ticks=*)
+    cpdef Price peg_primary_price(self, Instrument instrument, OrderSide order_side, int offset_ticks=*)
+    cpdef Price peg_mid_price(self, Instrument instrument, OrderSide order_side, int offset_ticks=*)
+    cdef Price _best_price(self, InstrumentId instrument_id, bint is_bid)
+    cdef Price _tick_price(self, Instrument instrument, int64_t raw, bint round_down, int num_ticks)
@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 from cpython.datetime cimport datetime
+from libc.stdint cimport int64_t
 
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Clock
@@ -28,12 +29,15 @@ from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
 from nautilus_trader.core.rust.model cimport TriggerType
 from nautilus_trader.core.uuid cimport UUID4
+from nautilus_trader.model.book cimport OrderBook
+from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.functions cimport order_type_to_str
 from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport ExecAlgorithmId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport OrderListId
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
@@ -45,6 +49,9 @@ from nautilus_trader.model.orders.market_to_limit cimport MarketToLimitOrder
 from nautilus_trader.model.orders.stop_market cimport StopMarketOrder
 from nautilus_trader.model.orders.trailing_stop_limit cimport TrailingStopLimitOrder
 from nautilus_trader.model.orders.trailing_stop_market cimport TrailingStopMarketOrder
+from nautilus_trader.model.tick_scheme.base cimport TickScheme
+from nautilus_trader.model.tick_scheme.base cimport get_tick_scheme
+from nautilus_trader.model.tick_scheme.implementations.tiered cimport TieredTickScheme
 
 
 cdef class OrderFactory:
@@ -1437,3 +1444,213 @@ cdef class OrderFactory:
             order_list_id=order_list_id,
             orders=[entry_order, sl_order, tp_order],
         )
+
+    cpdef Price market_to_limit_price(
+        self,
+        Instrument instrument,
+        OrderSide order_side,
+        int offset_ticks = 0,
+    ):
+        """
+        Return an aggressive limit price which crosses the current market.
+
+        The price is taken from the far touch (the best ask for ``BUY`` orders,
+        the best bid for ``SELL`` orders) then moved `offset_ticks` further
+        through the book, so the order is marketable with a bounded price.
+
+        The market is taken from the cached order book if one exists with the
+        required side, otherwise from the latest quote tick.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the price.
+        order_side : OrderSide {``BUY``, ``SELL``}
+            The order side for the price.
+        offset_ticks : int, default 0
+            The number of ticks through the far touch.
+
+        Returns
+        -------
+        Price or ``None``
+            ``None`` if there is no market for the required side.
+
+        Raises
+        ------
+        ValueError
+            If `order_side` is ``NO_ORDER_SIDE``.
+        ValueError
+            If `offset_ticks` is negative (< 0).
+
+        """
+        Condition.not_none(instrument, "instrument")
+        Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NO_ORDER_SIDE")
+        Condition.not_negative_int(offset_ticks, "offset_ticks")
+
+        cdef bint is_buy = order_side == OrderSide.BUY
+        cdef Price touch = self._best_price(instrument.id, not is_buy)
+        if touch is None:
+            return None
+
+        return self._tick_price(instrument, touch._mem.raw, not is_buy, offset_ticks)
+
+    cpdef Price peg_primary_price(
+        self,
+        Instrument instrument,
+        OrderSide order_side,
+        int offset_ticks = 0,
+    ):
+        """
+        Return a passive limit price pegged to the near touch.
+
+        The price is taken from the near touch (the best bid for ``BUY`` orders,
+        the best ask for ``SELL`` orders) then moved `offset_ticks` away from the
+        market, so an offset of zero joins the current best price.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the price.
+        order_side : OrderSide {``BUY``, ``SELL``}
+            The order side for the price.
+        offset_ticks : int, default 0
+            The number of ticks behind the near touch.
+
+        Returns
+        -------
+        Price or ``None``
+            ``None`` if there is no market for the required side.
+
+        Raises
+        ------
+        ValueError
+            If `order_side` is ``NO_ORDER_SIDE``.
+        ValueError
+            If `offset_ticks` is negative (< 0).
+
+        """
+        Condition.not_none(instrument, "instrument")
+        Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NO_ORDER_SIDE")
+        Condition.not_negative_int(offset_ticks, "offset_ticks")
+
+        cdef bint is_buy = order_side == OrderSide.BUY
+        cdef Price touch = self._best_price(instrument.id, is_buy)
+        if touch is None:
+            return None
+
+        return self._tick_price(instrument, touch._mem.raw, is_buy, offset_ticks)
+
+    cpdef Price peg_mid_price(
+        self,
+        Instrument instrument,
+        OrderSide order_side,
+        int offset_ticks = 0,
+    ):
+        """
+        Return a limit price pegged to the current mid price.
+
+        Where the mid falls between two ticks the price is rounded to the passive
+        side (down for ``BUY`` orders, up for ``SELL`` orders), then moved
+        `offset_ticks` away from the market.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the price.
+        order_side : OrderSide {``BUY``, ``SELL``}
+            The order side for the price.
+        offset_ticks : int, default 0
+            The number of ticks behind the mid price.
+
+        Returns
+        -------
+        Price or ``None``
+            ``None`` if there is no two-sided market.
+
+        Raises
+        ------
+        ValueError
+            If `order_side` is ``NO_ORDER_SIDE``.
+        ValueError
+            If `offset_ticks` is negative (< 0).
+
+        """
+        Condition.not_none(instrument, "instrument")
+        Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NO_ORDER_SIDE")
+        Condition.not_negative_int(offset_ticks, "offset_ticks")
+
+        cdef Price bid = self._best_price(instrument.id, True)
+        cdef Price ask = self._best_price(instrument.id, False)
+        if bid is None or ask is None:
+            return None
+
+        cdef bint is_buy = order_side == OrderSide.BUY
+        cdef int64_t raw_sum = bid._mem.raw + ask._mem.raw
+        cdef int64_t raw_mid = raw_sum // 2
+        if not is_buy and raw_sum % 2 != 0:
+            raw_mid += 1  # Round the half raw unit up for the ask side
+
+        return self._tick_price(instrument, raw_mid, is_buy, offset_ticks)
+
+    cdef Price _best_price(self, InstrumentId instrument_id, bint is_bid):
+        Condition.not_none(self._cache, "self._cache")
+
+        cdef OrderBook book = self._cache.order_book(instrument_id)
+        cdef Price price = None
+        if book is not None:
+            price = book.best_bid_price() if is_bid else book.best_ask_price()
+            if price is not None:
+                return price
+
+        cdef QuoteTick quote = self._cache.quote_tick(instrument_id)
+        if quote is None:
+            return None
+
+        return quote.bid_price if is_bid else quote.ask_price
+
+    cdef Price _tick_price(
+        self,
+        Instrument instrument,
+        int64_t raw,
+        bint round_down,
+        int num_ticks,
+    ):
+        # Round down to a valid tick (bid side), or up (ask side), then move
+        # `num_ticks` further in the same direction. Variable tick sizes need the
+        # instruments tick scheme, otherwise integer arithmetic on the fixed price
+        # increment avoids any float rounding producing an invalid tick.
+        cdef TickScheme tick_scheme = None
+        if instrument.tick_scheme_name is not None:
+            tick_scheme = get_tick_scheme(instrument.tick_scheme_name)
+
+        cdef Price price
+        cdef double value
+        if isinstance(tick_scheme, TieredTickScheme):
+            value = Price.from_raw_c(raw, instrument.price_precision).as_double()
+            if round_down:
+                price = tick_scheme.next_bid_price(value, num_ticks)
+            else:
+                price = tick_scheme.next_ask_price(value, num_ticks)
+        else:
+            price = Price.from_raw_c(
+                _round_to_increment(raw, instrument.price_increment._mem.raw, round_down, num_ticks),
+                instrument.price_precision,
+            )
+
+        if instrument.max_price is not None and price > instrument.max_price:
+            return instrument.max_price
+        if instrument.min_price is not None and price < instrument.min_price:
+            return instrument.min_price
+        return price
+
+cdef int64_t _round_to_increment(int64_t raw, int64_t increment, bint round_down, int num_ticks):
+    # Explicit floor/ceil as integer division truncates towards zero
+    cdef int64_t remainder = raw % increment
+    if remainder < 0:
+        remainder += increment
+    cdef int64_t floored = raw - remainder
+    if round_down:
+        return floored - num_ticks * increment
+    if remainder != 0:
+        floored += increment
+    return floored + num_ticks * increment
@@ -67,3 +67,22 @@ cdef class TradingStateChanged(RiskEvent):
 
     @staticmethod
     cdef dict to_dict_c(TradingStateChanged obj)
+
+
+cdef class RiskConfigChanged(RiskEvent):
+    cdef readonly str setting
+    """The risk setting which was changed.\n\n:returns: `str`"""
+    cdef readonly str old_value
+    """The value of the setting prior to the change.\n\n:returns: `str`"""
+    cdef readonly str new_value
+    """The value of the setting after the change.\n\n:returns: `str`"""
+    cdef readonly str requested_by
+    """The identity of the requester of the change.\n\n:returns: `str` or ``None``"""
+    cdef readonly UUID4 command_id
+    """The ID of the command which made the change.\n\n:returns: `UUID4`"""
+
+    @staticmethod
+    cdef RiskConfigChanged from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(RiskConfigChanged obj)
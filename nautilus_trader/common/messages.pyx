@@ -417,3 +417,140 @@ cdef class TradingStateChanged(RiskEvent):
 
         """
         return TradingStateChanged.to_dict_c(obj)
+
+
+cdef class RiskConfigChanged(RiskEvent):
+    """
+    Represents an event where a risk setting has been changed at runtime at the `RiskEngine`.
+
+    Provides an audit record of who changed what, and when.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID associated with the event.
+    setting : str
+        The risk setting which was changed.
+    old_value : str
+        The value of the setting prior to the change.
+    new_value : str
+        The value of the setting after the change.
+    requested_by : str, optional with no default so ``None`` must be passed explicitly
+        The identity of the requester of the change.
+    command_id : UUID4
+        The ID of the command which made the change.
+    event_id : UUID4
+        The event ID.
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) when the change occurred.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        str setting not None,
+        str old_value not None,
+        str new_value not None,
+        str requested_by: str | None,
+        UUID4 command_id not None,
+        UUID4 event_id not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ):
+        self.trader_id = trader_id
+        self.setting = setting
+        self.old_value = old_value
+        self.new_value = new_value
+        self.requested_by = requested_by
+        self.command_id = command_id
+        self._event_id = event_id
+        self._ts_event = ts_event
+        self._ts_init = ts_init
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"setting={self.setting}, "
+            f"old_value={self.old_value}, "
+            f"new_value={self.new_value}, "
+            f"requested_by={self.requested_by}, "
+            f"event_id={self._event_id.to_str()})"
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"setting={self.setting}, "
+            f"old_value={self.old_value}, "
+            f"new_value={self.new_value}, "
+            f"requested_by={self.requested_by}, "
+            f"command_id={self.command_id.to_str()}, "
+            f"event_id={self._event_id.to_str()}, "
+            f"ts_event={self._ts_event}, "
+            f"ts_init={self._ts_init})"
+        )
+
+    @staticmethod
+    cdef RiskConfigChanged from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return RiskConfigChanged(
+            trader_id=TraderId(values["trader_id"]),
+            setting=values["setting"],
+            old_value=values["old_value"],
+            new_value=values["new_value"],
+            requested_by=values["requested_by"],
+            command_id=UUID4(values["command_id"]),
+            event_id=UUID4(values["event_id"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(RiskConfigChanged obj):
+        Condition.not_none(obj, "obj")
+
+        return {
+            "type": "RiskConfigChanged",
+            "trader_id": obj.trader_id.to_str(),
+            "setting": obj.setting,
+            "old_value": obj.old_value,
+            "new_value": obj.new_value,
+            "requested_by": obj.requested_by,
+            "command_id": obj.command_id.to_str(),
+            "event_id": obj._event_id.to_str(),
+            "ts_event": obj._ts_event,
+            "ts_init": obj._ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> RiskConfigChanged:
+        """
+        Return a risk config changed event from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        RiskConfigChanged
+
+        """
+        return RiskConfigChanged.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(RiskConfigChanged obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return RiskConfigChanged.to_dict_c(obj)
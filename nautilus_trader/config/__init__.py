@@ -23,6 +23,7 @@ from nautilus_trader.backtest.config import BacktestDataConfig
 from nautilus_trader.backtest.config import BacktestEngineConfig
 from nautilus_trader.backtest.config import BacktestRunConfig
 from nautilus_trader.backtest.config import BacktestVenueConfig
+from nautilus_trader.backtest.config import DataChaosConfig
 from nautilus_trader.cache.config import CacheConfig
 from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import ActorFactory
@@ -51,8 +52,10 @@ from nautilus_trader.execution.config import ExecAlgorithmConfig
 from nautilus_trader.execution.config import ExecAlgorithmFactory
 from nautilus_trader.execution.config import ExecEngineConfig
 from nautilus_trader.execution.config import ImportableExecAlgorithmConfig
+from nautilus_trader.execution.config import SmartOrderRouterConfig
 from nautilus_trader.live.config import ControllerConfig
 from nautilus_trader.live.config import ControllerFactory
+from nautilus_trader.live.config import ControlServerConfig
 from nautilus_trader.live.config import ImportableControllerConfig
 from nautilus_trader.live.config import LiveDataClientConfig
 from nautilus_trader.live.config import LiveDataEngineConfig
@@ -61,9 +64,14 @@ from nautilus_trader.live.config import LiveExecEngineConfig
 from nautilus_trader.live.config import LiveRiskEngineConfig
 from nautilus_trader.live.config import RoutingConfig
 from nautilus_trader.live.config import TradingNodeConfig
+from nautilus_trader.persistence.config import CatalogCompactorConfig
 from nautilus_trader.persistence.config import DataCatalogConfig
+from nautilus_trader.persistence.config import FeatureRecorderConfig
+from nautilus_trader.persistence.config import IlpDataRecorderConfig
 from nautilus_trader.persistence.config import StreamingConfig
+from nautilus_trader.risk.config import PriceBand
 from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.risk.config import StrategyRiskLimits
 from nautilus_trader.system.config import NautilusKernelConfig
 from nautilus_trader.trading.config import ImportableStrategyConfig
 from nautilus_trader.trading.config import StrategyConfig
@@ -78,14 +86,19 @@ __all__ = [
     "BacktestRunConfig",
     "BacktestVenueConfig",
     "CacheConfig",
+    "CatalogCompactorConfig",
     "ControllerConfig",
     "ControllerFactory",
+    "ControlServerConfig",
     "DatabaseConfig",
     "DataCatalogConfig",
+    "DataChaosConfig",
     "DataEngineConfig",
     "ExecAlgorithmConfig",
     "ExecAlgorithmFactory",
     "ExecEngineConfig",
+    "FeatureRecorderConfig",
+    "IlpDataRecorderConfig",
     "ImportableActorConfig",
     "ImportableControllerConfig",
     "ImportableExecAlgorithmConfig",
@@ -101,9 +114,12 @@ __all__ = [
     "OrderEmulatorConfig",
     "PositiveInt",
     "PositiveFloat",
+    "PriceBand",
     "RiskEngineConfig",
+    "SmartOrderRouterConfig",
     "StrategyConfig",
     "StrategyFactory",
+    "StrategyRiskLimits",
     "StreamingConfig",
     "ImportableConfig",
     "LiveDataClientConfig",
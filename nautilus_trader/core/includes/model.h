@@ -313,6 +313,11 @@ typedef enum MarketStatus {
      * The market session is closed.
      */
     CLOSED = 7,
+    /**
+     * The market session is in an auction (call) phase, where orders are collected
+     * but not matched until the uncross.
+     */
+    AUCTION = 8,
 } MarketStatus;
 
 /**
@@ -534,6 +539,28 @@ typedef enum PriceType {
     LAST = 4,
 } PriceType;
 
+/**
+ * The rounding mode for converting a floating point value to a fixed precision price or quantity.
+ */
+typedef enum RoundingMode {
+    /**
+     * Round to the nearest value, with ties rounded away from zero (the default).
+     */
+    HALF_UP = 1,
+    /**
+     * Round to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+     */
+    HALF_EVEN = 2,
+    /**
+     * Round towards negative infinity.
+     */
+    FLOOR = 3,
+    /**
+     * Round towards positive infinity.
+     */
+    CEILING = 4,
+} RoundingMode;
+
 /**
  * The 'Time in Force' instruction for an order in the financial market.
  */
@@ -568,6 +595,24 @@ typedef enum TimeInForce {
     AT_THE_CLOSE = 7,
 } TimeInForce;
 
+/**
+ * A condition flag for a trade, combined as a bit set in `TradeTick.flags`.
+ */
+typedef enum TradeFlag {
+    /**
+     * The trade aggregates multiple fills of a single taker order at the same price (e.g. Binance aggTrade).
+     */
+    AGGREGATED = 1,
+    /**
+     * The trade is a privately negotiated block trade reported to the venue.
+     */
+    BLOCK = 2,
+    /**
+     * The trade was executed away from the central limit order book.
+     */
+    OFF_BOOK = 4,
+} TradeFlag;
+
 /**
  * The trading state for a node.
  */
@@ -892,6 +937,10 @@ typedef struct TradeTick_t {
      * The trade aggressor side.
      */
     enum AggressorSide aggressor_side;
+    /**
+     * The trade condition flags (bit set of [`TradeFlag`] values).
+     */
+    uint8_t flags;
     /**
      * The trade match ID (assigned by the venue).
      */
@@ -928,6 +977,11 @@ typedef struct BarSpecification_t {
 /**
  * Represents a bar type including the instrument ID, bar specification and
  * aggregation source.
+ *
+ * A bar type may also be *composite*, meaning its bars are aggregated from
+ * other bars (rather than from ticks), as described by the `composite_*` fields.
+ * A composite bar type has the string form `{standard}@{step}-{aggregation}-{source}`,
+ * e.g. `AUDUSD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL`.
  */
 typedef struct BarType_t {
     /**
@@ -942,6 +996,18 @@ typedef struct BarType_t {
      * The bar types aggregation source.
      */
     enum AggregationSource aggregation_source;
+    /**
+     * The step of the bars being aggregated from (zero if not composite).
+     */
+    uintptr_t composite_step;
+    /**
+     * The aggregation of the bars being aggregated from.
+     */
+    enum BarAggregation composite_aggregation;
+    /**
+     * The aggregation source of the bars being aggregated from.
+     */
+    enum AggregationSource composite_aggregation_source;
 } BarType_t;
 
 /**
@@ -1300,6 +1366,17 @@ struct BarType_t bar_type_new(struct InstrumentId_t instrument_id,
                               struct BarSpecification_t spec,
                               uint8_t aggregation_source);
 
+struct BarType_t bar_type_new_composite(struct InstrumentId_t instrument_id,
+                                        struct BarSpecification_t spec,
+                                        uint8_t aggregation_source,
+                                        uintptr_t composite_step,
+                                        uint8_t composite_aggregation,
+                                        uint8_t composite_aggregation_source);
+
+uint8_t bar_type_is_composite(const struct BarType_t *bar_type);
+
+uint8_t bar_type_is_standard(const struct BarType_t *bar_type);
+
 /**
  * Returns any [`BarType`] parsing error from the provided C string pointer.
  *
@@ -1459,7 +1536,8 @@ struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
                                   enum AggressorSide aggressor_side,
                                   struct TradeId_t trade_id,
                                   uint64_t ts_event,
-                                  uint64_t ts_init);
+                                  uint64_t ts_init,
+                                  uint8_t flags);
 
 uint8_t trade_tick_eq(const struct TradeTick_t *lhs, const struct TradeTick_t *rhs);
 
@@ -1701,6 +1779,17 @@ const char *price_type_to_cstr(enum PriceType value);
  */
 enum PriceType price_type_from_cstr(const char *ptr);
 
+const char *rounding_mode_to_cstr(enum RoundingMode value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum RoundingMode rounding_mode_from_cstr(const char *ptr);
+
 const char *time_in_force_to_cstr(enum TimeInForce value);
 
 /**
@@ -2191,6 +2280,10 @@ void money_sub_assign(struct Money_t a, struct Money_t b);
 
 struct Price_t price_new(double value, uint8_t precision);
 
+struct Price_t price_new_with_rounding(double value,
+                                       uint8_t precision,
+                                       enum RoundingMode rounding_mode);
+
 struct Price_t price_from_raw(int64_t raw, uint8_t precision);
 
 double price_as_f64(const struct Price_t *price);
@@ -2201,6 +2294,10 @@ void price_sub_assign(struct Price_t a, struct Price_t b);
 
 struct Quantity_t quantity_new(double value, uint8_t precision);
 
+struct Quantity_t quantity_new_with_rounding(double value,
+                                             uint8_t precision,
+                                             enum RoundingMode rounding_mode);
+
 struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
 
 double quantity_as_f64(const struct Quantity_t *qty);
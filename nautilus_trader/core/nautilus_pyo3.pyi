@@ -173,6 +173,177 @@ def is_within_last_24_hours(timestamp_ns: int) -> bool:
 
     """
 
+def unix_nanos_to_local_iso8601(timestamp_ns: int, tz: str) -> str:
+    """
+    Return an ISO 8601 formatted string of the local wall time (including the UTC offset)
+    in the given IANA timezone.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp to convert.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+
+    Returns
+    -------
+    str
+
+    Raises
+    ------
+    ValueError
+        If `tz` is not a valid IANA timezone.
+
+    """
+
+def utc_offset_secs(timestamp_ns: int, tz: str) -> int:
+    """
+    Return the UTC offset (seconds) of the given IANA timezone at the timestamp.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp datum.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If `tz` is not a valid IANA timezone.
+
+    """
+
+def local_to_unix_nanos(
+    year: int,
+    month: int,
+    day: int,
+    hour: int,
+    minute: int,
+    second: int,
+    tz: str,
+) -> int:
+    """
+    Return UNIX nanoseconds for the given local wall time in the IANA timezone.
+
+    A local time skipped by a DST transition resolves to the transition itself, and a local
+    time repeated by a DST transition resolves to its first occurrence.
+
+    Parameters
+    ----------
+    year : int
+        The local year.
+    month : int
+        The local month.
+    day : int
+        The local day.
+    hour : int
+        The local hour.
+    minute : int
+        The local minute.
+    second : int
+        The local second.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If given an invalid local date and time, or `tz` is not a valid IANA timezone.
+
+    """
+
+def next_local_time_nanos(
+    timestamp_ns: int,
+    tz: str,
+    hour: int,
+    minute: int,
+    second: int = 0,
+) -> int:
+    """
+    Return UNIX nanoseconds of the next occurrence of the local wall time in the IANA
+    timezone, strictly after the given timestamp.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp datum.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+    hour : int
+        The local hour.
+    minute : int
+        The local minute.
+    second : int, default 0
+        The local second.
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If given an invalid local time, or `tz` is not a valid IANA timezone.
+
+    """
+
+def add_local_days_nanos(timestamp_ns: int, tz: str, days: int) -> int:
+    """
+    Return UNIX nanoseconds after adding calendar days in the local time of the IANA
+    timezone, preserving the local wall time across DST transitions.
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp datum.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+    days : int
+        The number of calendar days to add (can be negative).
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If `tz` is not a valid IANA timezone.
+
+    """
+
+def local_day_nanos(timestamp_ns: int, tz: str) -> int:
+    """
+    Return the duration (nanoseconds) of the local calendar day containing the timestamp
+    in the IANA timezone (23 or 25 hours on DST transition days).
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX nanoseconds timestamp datum.
+    tz : str
+        The IANA timezone name (such as 'America/New_York').
+
+    Returns
+    -------
+    int
+
+    Raises
+    ------
+    ValueError
+        If `tz` is not a valid IANA timezone.
+
+    """
+
 
 def convert_to_snake_case(s: str) -> str:
     """
@@ -192,6 +363,48 @@ def convert_to_snake_case(s: str) -> str:
 
     """
 
+
+def enable_metrics() -> None:
+    """
+    Enable recording of metrics by instrumented components.
+
+    """
+
+
+def serve_metrics(host: str, port: int) -> int:
+    """
+    Serve the metrics registry on `http://{host}:{port}/metrics` for Prometheus scraping.
+
+    Parameters
+    ----------
+    host : str
+        The host to bind to.
+    port : int
+        The port to bind to (0 for any available port).
+
+    Returns
+    -------
+    int
+        The bound port.
+
+    Raises
+    ------
+    RuntimeError
+        If the address cannot be bound.
+
+    """
+
+
+def render_metrics() -> str:
+    """
+    Render all recorded metrics in the Prometheus text exposition format.
+
+    Returns
+    -------
+    str
+
+    """
+
 ###################################################################################################
 # Common
 ###################################################################################################
@@ -215,6 +428,15 @@ def init_logging(
 # Model
 ###################################################################################################
 
+### Errors
+
+class ModelError(ValueError): ...
+class PrecisionMismatchError(ModelError): ...
+class OutOfRangeError(ModelError): ...
+class InvalidIdentifierError(ModelError): ...
+class CurrencyMismatchError(ModelError): ...
+class InvalidArgumentError(ModelError): ...
+
 ### Accounting
 
 class Position:
@@ -534,8 +756,18 @@ class TradeTick:
         trade_id: TradeId,
         ts_event: int,
         ts_init: int,
+        flags: int = 0,
     ) -> None: ...
     @property
+    def flags(self) -> int: ...
+    @property
+    def is_aggregated(self) -> bool: ...
+    @property
+    def is_block(self) -> bool: ...
+    @property
+    def is_off_book(self) -> bool: ...
+    def is_bar_eligible(self, exclude_flags: int) -> bool: ...
+    @property
     def ts_event(self) -> int: ...
     @property
     def ts_init(self) -> int: ...
@@ -634,6 +866,7 @@ class MarketStatus(Enum):
     REOPEN = "REOPEN"
     PRE_CLOSE = "PRE_CLOSE"
     CLOSED = "CLOSED"
+    AUCTION = "AUCTION"
 
 class HaltReason(Enum):
     NOT_HALTED = "NOT_HALTED"
@@ -692,6 +925,12 @@ class PriceType(Enum):
     MID = "MID"
     LAST = "LAST"
 
+class RoundingMode(Enum):
+    HALF_UP = "HALF_UP"
+    HALF_EVEN = "HALF_EVEN"
+    FLOOR = "FLOOR"
+    CEILING = "CEILING"
+
 class TimeInForce(Enum):
     GTC = "GTC"
     IOC = "IOC"
@@ -701,6 +940,11 @@ class TimeInForce(Enum):
     AT_THE_OPEN = "AT_THE_OPEN"
     AT_THE_CLOSE = "AT_THE_CLOSE"
 
+class TradeFlag(Enum):
+    AGGREGATED = "AGGREGATED"
+    BLOCK = "BLOCK"
+    OFF_BOOK = "OFF_BOOK"
+
 class TradingState(Enum):
     ACTIVE = "ACTIVE"
     HALTED = "HALTED"
@@ -906,7 +1150,12 @@ class Money:
     def to_formatted_str(self) -> str: ...
 
 class Price:
-    def __init__(self, value: float, precision: int) -> None: ...
+    def __init__(
+        self,
+        value: float,
+        precision: int,
+        rounding_mode: RoundingMode | None = None,
+    ) -> None: ...
     @property
     def raw(self) -> int: ...
     @property
@@ -926,7 +1175,12 @@ class Price:
     def to_formatted_str(self) -> str: ...
 
 class Quantity:
-    def __init__(self, value: float, precision: int) -> None: ...
+    def __init__(
+        self,
+        value: float,
+        precision: int,
+        rounding_mode: RoundingMode | None = None,
+    ) -> None: ...
     @property
     def raw(self) -> int: ...
     @property
@@ -976,6 +1230,63 @@ class AccountState:
 
 ### Instruments
 
+class BettingInstrument:
+    def __init__(
+        self,
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        event_type_id: int,
+        event_type_name: str,
+        competition_id: int,
+        competition_name: str,
+        event_id: int,
+        event_name: str,
+        event_country_code: str,
+        event_open_date: int,
+        betting_type: str,
+        market_id: str,
+        market_name: str,
+        market_type: str,
+        market_start_time: int,
+        selection_id: int,
+        selection_name: str,
+        selection_handicap: float,
+        currency: Currency,
+        price_precision: int,
+        size_precision: int,
+        price_increment: Price,
+        size_increment: Quantity,
+        ts_event: int,
+        ts_init: int,
+        max_quantity: Quantity | None = None,
+        min_quantity: Quantity | None = None,
+        max_notional: Money | None = None,
+        min_notional: Money | None = None,
+        max_price: Price | None = None,
+        min_price: Price | None = None,
+    ) -> None: ...
+    @property
+    def id(self) -> InstrumentId: ...
+    @property
+    def market_id(self) -> str: ...
+    @property
+    def selection_id(self) -> int: ...
+    @property
+    def selection_name(self) -> str: ...
+    @property
+    def currency(self) -> Currency: ...
+    @property
+    def price_precision(self) -> int: ...
+    @property
+    def size_precision(self) -> int: ...
+    @property
+    def price_increment(self) -> Price: ...
+    @property
+    def size_increment(self) -> Quantity: ...
+    @classmethod
+    def from_dict(cls, values: dict[str, Any]) -> BettingInstrument: ...
+    def to_dict(self) -> dict[str, Any]: ...
+
 class CryptoFuture:
     def __init__(
         self,
@@ -1044,6 +1355,7 @@ class CryptoPerpetual:
         min_notional: Money | None = None,
         max_price: Price | None = None,
         min_price: Price | None = None,
+        quanto_multiplier: Decimal | None = None,
     ) -> None: ...
     @property
     def id(self) -> InstrumentId: ...
@@ -1238,6 +1550,7 @@ class SyntheticInstrument:
     def quote_currency(self) -> Currency: ...
 
 Instrument: TypeAlias = Union[
+    BettingInstrument,
     CryptoFuture,
     CryptoPerpetual,
     CurrencyPair,
@@ -1576,6 +1889,197 @@ class OrderExpired:
     def from_dict(cls, values: dict[str, str]) -> OrderExpired: ...
     def to_dict(self) -> dict[str, str]: ...
 
+### Reports
+
+class OrderStatusReport:
+    def __init__(
+        self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        report_id: UUID4,
+        ts_accepted: int,
+        ts_last: int,
+        ts_init: int,
+        price: Price | None = None,
+        trigger_price: Price | None = None,
+        avg_px: float | None = None,
+    ) -> None: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId | None: ...
+    @property
+    def venue_order_id(self) -> VenueOrderId: ...
+    @property
+    def order_side(self) -> OrderSide: ...
+    @property
+    def order_type(self) -> OrderType: ...
+    @property
+    def time_in_force(self) -> TimeInForce: ...
+    @property
+    def order_status(self) -> OrderStatus: ...
+    @property
+    def quantity(self) -> Quantity: ...
+    @property
+    def filled_qty(self) -> Quantity: ...
+    @property
+    def leaves_qty(self) -> Quantity: ...
+    @property
+    def price(self) -> Price | None: ...
+    @property
+    def trigger_price(self) -> Price | None: ...
+    @property
+    def avg_px(self) -> float | None: ...
+    @property
+    def report_id(self) -> UUID4: ...
+    @property
+    def ts_accepted(self) -> int: ...
+    @property
+    def ts_last(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+    @property
+    def is_open(self) -> bool: ...
+
+class FillReport:
+    def __init__(
+        self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None,
+        venue_order_id: VenueOrderId,
+        venue_position_id: PositionId | None,
+        trade_id: TradeId,
+        order_side: OrderSide,
+        last_qty: Quantity,
+        last_px: Price,
+        commission: Money,
+        liquidity_side: LiquiditySide,
+        report_id: UUID4,
+        ts_event: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def client_order_id(self) -> ClientOrderId | None: ...
+    @property
+    def venue_order_id(self) -> VenueOrderId: ...
+    @property
+    def venue_position_id(self) -> PositionId | None: ...
+    @property
+    def trade_id(self) -> TradeId: ...
+    @property
+    def order_side(self) -> OrderSide: ...
+    @property
+    def last_qty(self) -> Quantity: ...
+    @property
+    def last_px(self) -> Price: ...
+    @property
+    def commission(self) -> Money: ...
+    @property
+    def liquidity_side(self) -> LiquiditySide: ...
+    @property
+    def report_id(self) -> UUID4: ...
+    @property
+    def ts_event(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+class PositionStatusReport:
+    def __init__(
+        self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        venue_position_id: PositionId | None,
+        report_id: UUID4,
+        ts_last: int,
+        ts_init: int,
+    ) -> None: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    @property
+    def position_side(self) -> PositionSide: ...
+    @property
+    def quantity(self) -> Quantity: ...
+    @property
+    def signed_qty(self) -> float: ...
+    @property
+    def venue_position_id(self) -> PositionId | None: ...
+    @property
+    def report_id(self) -> UUID4: ...
+    @property
+    def ts_last(self) -> int: ...
+    @property
+    def ts_init(self) -> int: ...
+
+def reconcile_position(report: PositionStatusReport, positions: list[Position]) -> float | None: ...
+
+###################################################################################################
+# Backtest
+###################################################################################################
+
+class SandboxExecutionClient:
+    def __init__(
+        self,
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        starting_balances: list[Money],
+        oms_type: OmsType | None = None,
+        account_type: AccountType | None = None,
+        book_type: BookType | None = None,
+    ) -> None: ...
+    @property
+    def client_id(self) -> ClientId: ...
+    @property
+    def account_id(self) -> AccountId: ...
+    def add_instrument(self, instrument: Instrument) -> None: ...
+    def process_order_book_delta(self, delta: OrderBookDelta) -> None: ...
+    def process_quote_tick(self, quote: QuoteTick) -> None: ...
+    def process_trade_tick(self, trade: TradeTick) -> None: ...
+    def process_bar(self, bar: Bar) -> None: ...
+    def submit_order(self, trader_id: TraderId, init: OrderInitialized, ts_init: int) -> None: ...
+    def modify_order(
+        self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        quantity: Quantity | None,
+        price: Price | None,
+        trigger_price: Price | None,
+        ts_init: int,
+    ) -> None: ...
+    def cancel_order(
+        self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        ts_init: int,
+    ) -> None: ...
+    def drain_events(self) -> list[Any]: ...
+    def drain_account_states(self) -> list[AccountState]: ...
+    def generate_account_state(self, ts_event: int) -> AccountState: ...
+    def balances(self) -> list[Money]: ...
+
 ###################################################################################################
 # Infrastructure
 ###################################################################################################
@@ -1638,6 +2142,7 @@ class WebSocketConfig:
         headers: list[tuple[str, str]],
         heartbeat: int | None = None,
         heartbeat_msg: str | None = None,
+        use_event_loop: bool = False,
     ) -> None: ...
 
 class WebSocketClient:
@@ -1677,6 +2182,7 @@ class SocketConfig:
         suffix: bytes,
         handler: Callable[..., Any],
         heartbeat: tuple[int, list[int]] | None = None,
+        use_event_loop: bool = False,
     ) -> None: ...
 
 ###################################################################################################
@@ -1699,6 +2205,13 @@ class DataBackendSession:
         file_path: str,
         sql_query: str | None = None,
     ) -> None: ...
+    def add_deltas_file_from_checkpoint(
+        self,
+        table_name: str,
+        file_path: str,
+        start: int,
+        end: int | None = None,
+    ) -> int | None: ...
     def to_query_result(self) -> DataQueryResult: ...
 
 class QueryResult:
@@ -1718,6 +2231,12 @@ class DataTransformer:
     @staticmethod
     def pyo3_order_book_deltas_to_record_batch_bytes(data: list[OrderBookDelta]) -> bytes: ...
     @staticmethod
+    def pyo3_order_book_deltas_to_checkpointed_record_batch_bytes(
+        data: list[OrderBookDelta],
+        book_type: BookType,
+        interval_ns: int,
+    ) -> bytes: ...
+    @staticmethod
     def pyo3_order_book_depth10_to_record_batch_bytes(data: list[OrderBookDepth10]) -> bytes: ...
     @staticmethod
     def pyo3_quote_ticks_to_record_batch_bytes(data: list[QuoteTick]) -> bytes: ...
@@ -1755,6 +2274,33 @@ class QuoteTickDataWrangler:
     @property
     def size_precision(self) -> int: ...
     def process_record_batch_bytes(self, data: bytes) -> list[QuoteTick]: ...
+    def process_arrays(
+        self,
+        bid_prices: list[float],
+        ask_prices: list[float],
+        bid_sizes: list[float],
+        ask_sizes: list[float],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> list[QuoteTick]: ...
+    def process_raw_arrays(
+        self,
+        bid_prices_raw: list[int],
+        ask_prices_raw: list[int],
+        bid_sizes_raw: list[int],
+        ask_sizes_raw: list[int],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> list[QuoteTick]: ...
+    def arrays_to_record_batch_bytes(
+        self,
+        bid_prices: list[float],
+        ask_prices: list[float],
+        bid_sizes: list[float],
+        ask_sizes: list[float],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> bytes: ...
 
 class TradeTickDataWrangler:
     def __init__(
@@ -1770,6 +2316,33 @@ class TradeTickDataWrangler:
     @property
     def size_precision(self) -> int: ...
     def process_record_batch_bytes(self, data: bytes) -> list[TradeTick]: ...
+    def process_arrays(
+        self,
+        prices: list[float],
+        sizes: list[float],
+        aggressor_sides: list[int],
+        trade_ids: list[str],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> list[TradeTick]: ...
+    def process_raw_arrays(
+        self,
+        prices_raw: list[int],
+        sizes_raw: list[int],
+        aggressor_sides: list[int],
+        trade_ids: list[str],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> list[TradeTick]: ...
+    def arrays_to_record_batch_bytes(
+        self,
+        prices: list[float],
+        sizes: list[float],
+        aggressor_sides: list[int],
+        trade_ids: list[str],
+        ts_events: list[int],
+        ts_inits: list[int],
+    ) -> bytes: ...
 
 class BarDataWrangler:
     def __init__(
@@ -1940,6 +2513,42 @@ class AroonOscillator:
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
+# Options
+
+class VolSurface:
+    def __init__(
+        self,
+        underlying: str,
+        rate: float,
+        dividend_yield: float,
+    ) -> None: ...
+    def __len__(self) -> int: ...
+    @property
+    def underlying(self) -> str: ...
+    def expirations(self) -> list[int]: ...
+    def update(
+        self,
+        contract: OptionsContract,
+        quote: QuoteTick,
+        underlying_px: float,
+    ) -> float: ...
+    def update_price(
+        self,
+        contract: OptionsContract,
+        price: float,
+        underlying_px: float,
+        ts_event: int,
+    ) -> float: ...
+    def remove_expired(self, ts_now: int) -> None: ...
+    def implied_vol(self, strike: float, expiration_ns: int, ts_now: int) -> float | None: ...
+    def vol_spread(
+        self,
+        implied_vol: float,
+        strike: float,
+        expiration_ns: int,
+        ts_now: int,
+    ) -> float | None: ...
+
 
 ###################################################################################################
 # Adapters
@@ -169,6 +169,9 @@ cdef extern from "../includes/model.h":
         PRE_CLOSE # = 6,
         # The market session is closed.
         CLOSED # = 7,
+        # The market session is in an auction (call) phase, where orders are collected
+        # but not matched until the uncross.
+        AUCTION # = 8,
 
     # The order management system (OMS) type for a trading venue or trading strategy.
     cpdef enum OmsType:
@@ -290,6 +293,17 @@ cdef extern from "../includes/model.h":
         # The last price at which a trade was made for an instrument.
         LAST # = 4,
 
+    # The rounding mode for converting a floating point value to a fixed precision price or quantity.
+    cpdef enum RoundingMode:
+        # Round to the nearest value, with ties rounded away from zero (the default).
+        HALF_UP # = 1,
+        # Round to the nearest value, with ties rounded to the nearest even value (banker's rounding).
+        HALF_EVEN # = 2,
+        # Round towards negative infinity.
+        FLOOR # = 3,
+        # Round towards positive infinity.
+        CEILING # = 4,
+
     # The 'Time in Force' instruction for an order in the financial market.
     cpdef enum TimeInForce:
         # Good Till Canceled (GTC) - the order remains active until canceled.
@@ -307,6 +321,15 @@ cdef extern from "../includes/model.h":
         # At the Closing (ATC) - the order is scheduled to be executed at the market's closing.
         AT_THE_CLOSE # = 7,
 
+    # A condition flag for a trade, combined as a bit set in `TradeTick.flags`.
+    cpdef enum TradeFlag:
+        # The trade aggregates multiple fills of a single taker order at the same price (e.g. Binance aggTrade).
+        AGGREGATED # = 1,
+        # The trade is a privately negotiated block trade reported to the venue.
+        BLOCK # = 2,
+        # The trade was executed away from the central limit order book.
+        OFF_BOOK # = 4,
+
     # The trading state for a node.
     cpdef enum TradingState:
         # Normal trading operations.
@@ -485,6 +508,8 @@ cdef extern from "../includes/model.h":
         Quantity_t size;
         # The trade aggressor side.
         AggressorSide aggressor_side;
+        # The trade condition flags (bit set of [`TradeFlag`] values).
+        uint8_t flags;
         # The trade match ID (assigned by the venue).
         TradeId_t trade_id;
         # The UNIX timestamp (nanoseconds) when the tick event occurred.
@@ -504,6 +529,11 @@ cdef extern from "../includes/model.h":
 
     # Represents a bar type including the instrument ID, bar specification and
     # aggregation source.
+    #
+    # A bar type may also be *composite*, meaning its bars are aggregated from
+    # other bars (rather than from ticks), as described by the `composite_*` fields.
+    # A composite bar type has the string form `{standard}@{step}-{aggregation}-{source}`,
+    # e.g. `AUDUSD.SIM-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL`.
     cdef struct BarType_t:
         # The bar types instrument ID.
         InstrumentId_t instrument_id;
@@ -511,6 +541,12 @@ cdef extern from "../includes/model.h":
         BarSpecification_t spec;
         # The bar types aggregation source.
         AggregationSource aggregation_source;
+        # The step of the bars being aggregated from (zero if not composite).
+        uintptr_t composite_step;
+        # The aggregation of the bars being aggregated from.
+        BarAggregation composite_aggregation;
+        # The aggregation source of the bars being aggregated from.
+        AggregationSource composite_aggregation_source;
 
     # Represents an aggregated bar.
     cdef struct Bar_t:
@@ -759,6 +795,17 @@ cdef extern from "../includes/model.h":
                            BarSpecification_t spec,
                            uint8_t aggregation_source);
 
+    BarType_t bar_type_new_composite(InstrumentId_t instrument_id,
+                                     BarSpecification_t spec,
+                                     uint8_t aggregation_source,
+                                     uintptr_t composite_step,
+                                     uint8_t composite_aggregation,
+                                     uint8_t composite_aggregation_source);
+
+    uint8_t bar_type_is_composite(const BarType_t *bar_type);
+
+    uint8_t bar_type_is_standard(const BarType_t *bar_type);
+
     # Returns any [`BarType`] parsing error from the provided C string pointer.
     #
     # # Safety
@@ -901,7 +948,8 @@ cdef extern from "../includes/model.h":
                                AggressorSide aggressor_side,
                                TradeId_t trade_id,
                                uint64_t ts_event,
-                               uint64_t ts_init);
+                               uint64_t ts_init,
+                               uint8_t flags);
 
     uint8_t trade_tick_eq(const TradeTick_t *lhs, const TradeTick_t *rhs);
 
@@ -1099,6 +1147,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     PriceType price_type_from_cstr(const char *ptr);
 
+    const char *rounding_mode_to_cstr(RoundingMode value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    RoundingMode rounding_mode_from_cstr(const char *ptr);
+
     const char *time_in_force_to_cstr(TimeInForce value);
 
     # Returns an enum from a Python string.
@@ -1530,6 +1587,8 @@ cdef extern from "../includes/model.h":
 
     Price_t price_new(double value, uint8_t precision);
 
+    Price_t price_new_with_rounding(double value, uint8_t precision, RoundingMode rounding_mode);
+
     Price_t price_from_raw(int64_t raw, uint8_t precision);
 
     double price_as_f64(const Price_t *price);
@@ -1540,6 +1599,8 @@ cdef extern from "../includes/model.h":
 
     Quantity_t quantity_new(double value, uint8_t precision);
 
+    Quantity_t quantity_new_with_rounding(double value, uint8_t precision, RoundingMode rounding_mode);
+
     Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
 
     double quantity_as_f64(const Quantity_t *qty);
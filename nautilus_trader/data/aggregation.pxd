@@ -55,6 +55,7 @@ cdef class BarBuilder:
     cpdef void update(self, Price price, Quantity size, uint64_t ts_event)
     cpdef void reset(self)
     cpdef Bar build_now(self)
+    cpdef Bar snapshot(self, uint64_t ts_event, uint64_t ts_init)
     cpdef Bar build(self, uint64_t ts_event, uint64_t ts_init)
 
 
@@ -63,6 +64,7 @@ cdef class BarAggregator:
     cdef BarBuilder _builder
     cdef object _handler
     cdef bint _await_partial
+    cdef uint8_t _exclude_trade_flags
 
     cdef readonly BarType bar_type
     """The aggregators bar type.\n\n:returns: `BarType`"""
@@ -99,6 +101,9 @@ cdef class TimeBarAggregator(BarAggregator):
     cdef bint _build_with_no_updates
     cdef bint _timestamp_on_close
     cdef bint _is_left_open
+    cdef object _origin_ns
+    cdef object _partial_handler
+    cdef str _partial_timer_name
 
     cdef readonly timedelta interval
     """The aggregators time interval.\n\n:returns: `timedelta`"""
@@ -106,6 +111,10 @@ cdef class TimeBarAggregator(BarAggregator):
     """The aggregators time interval.\n\n:returns: `uint64_t`"""
     cdef readonly uint64_t next_close_ns
     """The aggregators next closing time.\n\n:returns: `uint64_t`"""
+    cdef readonly timedelta origin
+    """The interval alignment offset from the UNIX epoch (if aligned).\n\n:returns: `timedelta` or ``None``"""
+    cdef readonly timedelta partial_interval
+    """The interval between partial bar emissions (if enabled).\n\n:returns: `timedelta` or ``None``"""
 
     cpdef datetime get_start_time(self)
     cpdef void stop(self)
@@ -113,3 +122,5 @@ cdef class TimeBarAggregator(BarAggregator):
     cdef uint64_t _get_interval_ns(self)
     cpdef void _set_build_timer(self)
     cpdef void _build_bar(self, TimeEvent event)
+    cdef uint64_t _bar_ts_event(self, uint64_t close_ns)
+    cpdef void _build_partial_bar(self, TimeEvent event)
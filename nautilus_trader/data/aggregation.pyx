@@ -18,6 +18,7 @@ from typing import Callable
 
 from cpython.datetime cimport datetime
 from cpython.datetime cimport timedelta
+from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.common.component cimport Clock
@@ -25,6 +26,7 @@ from nautilus_trader.common.component cimport Logger
 from nautilus_trader.common.component cimport TimeEvent
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.datetime cimport dt_to_unix_nanos
+from nautilus_trader.core.datetime cimport unix_nanos_to_dt
 from nautilus_trader.core.rust.core cimport millis_to_nanos
 from nautilus_trader.core.rust.core cimport secs_to_nanos
 from nautilus_trader.model.data cimport Bar
@@ -184,6 +186,37 @@ cdef class BarBuilder:
         """
         return self.build(self.ts_last, self.ts_last)
 
+    cpdef Bar snapshot(self, uint64_t ts_event, uint64_t ts_init):
+        """
+        Return the bar aggregated so far with the given timestamps, without resetting.
+
+        Parameters
+        ----------
+        ts_event : uint64_t
+            The UNIX timestamp (nanoseconds) for the bar event.
+        ts_init : uint64_t
+            The UNIX timestamp (nanoseconds) for the bar initialization.
+
+        Returns
+        -------
+        Bar or ``None``
+            ``None`` if no updates have been aggregated since the last build.
+
+        """
+        if self._open is None:
+            return None
+
+        return Bar(
+            bar_type=self._bar_type,
+            open=self._open,
+            high=self._high,
+            low=self._low,
+            close=self._close,
+            volume=Quantity(self.volume, self.size_precision),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
     cpdef Bar build(self, uint64_t ts_event, uint64_t ts_init):
         """
         Return the aggregated bar with the given closing timestamp, and reset.
@@ -255,6 +288,7 @@ cdef class BarAggregator:
         self.bar_type = bar_type
         self._handler = handler
         self._await_partial = await_partial
+        self._exclude_trade_flags = 0
         self._log = Logger(name=type(self).__name__)
         self._builder = BarBuilder(
             instrument=instrument,
@@ -264,6 +298,18 @@ cdef class BarAggregator:
     def set_await_partial(self, bint value):
         self._await_partial = value
 
+    def set_exclude_trade_flags(self, uint8_t flags):
+        """
+        Set the trade condition flags for which trades will be excluded from aggregation.
+
+        Parameters
+        ----------
+        flags : uint8_t
+            The bit set of ``TradeFlag`` values to exclude (e.g. block and off-book trades).
+
+        """
+        self._exclude_trade_flags = flags
+
     cpdef void handle_quote_tick(self, QuoteTick tick):
         """
         Update the aggregator with the given tick.
@@ -295,6 +341,9 @@ cdef class BarAggregator:
         """
         Condition.not_none(tick, "tick")
 
+        if tick._mem.flags & self._exclude_trade_flags:
+            return  # Excluded trade condition
+
         if not self._await_partial:
             self._apply_update(
                 price=tick.price,
@@ -540,11 +589,24 @@ cdef class TimeBarAggregator(BarAggregator):
         Determines the type of interval used for time aggregation.
         - 'left-open': start time is excluded and end time is included (default).
         - 'right-open': start time is included and end time is excluded.
+    origin : timedelta, optional
+        The offset from the UNIX epoch to align bar intervals to, so that every
+        interval opens at a whole number of intervals from the origin (e.g. the offset
+        of a session open). If ``None`` then intervals align to calendar boundaries.
+    partial_interval : timedelta, optional
+        The interval at which to emit the in-progress bar to the `partial_handler`.
+        If ``None`` then no partial bars are emitted.
+    partial_handler : Callable[[Bar], None], optional
+        The handler for partial bars (required if `partial_interval` is set).
 
     Raises
     ------
     ValueError
         If `instrument.id` != `bar_type.instrument_id`.
+    ValueError
+        If `partial_interval` is not positive.
+    TypeError
+        If `partial_interval` is set and `partial_handler` is not callable.
     """
     def __init__(
         self,
@@ -555,7 +617,14 @@ cdef class TimeBarAggregator(BarAggregator):
         bint build_with_no_updates = True,
         bint timestamp_on_close = True,
         str interval_type = "left-open",
+        timedelta origin = None,
+        timedelta partial_interval = None,
+        partial_handler: Callable[[Bar], None] | None = None,
     ):
+        if partial_interval is not None:
+            Condition.true(partial_interval > timedelta(0), "`partial_interval` was not positive")
+            Condition.callable(partial_handler, "partial_handler")
+
         super().__init__(
             instrument=instrument,
             bar_type=bar_type,
@@ -565,6 +634,8 @@ cdef class TimeBarAggregator(BarAggregator):
         self._clock = clock
         self.interval = self._get_interval()
         self.interval_ns = self._get_interval_ns()
+        self.origin = origin
+        self._origin_ns = None if origin is None else origin // timedelta(microseconds=1) * 1_000
         self._timer_name = None
         self._set_build_timer()
         self.next_close_ns = self._clock.next_time_ns(self._timer_name)
@@ -584,6 +655,19 @@ cdef class TimeBarAggregator(BarAggregator):
                 f"Invalid interval_type: {interval_type}. Must be 'left-open' or 'right-open'.",
             )
 
+        self.partial_interval = partial_interval
+        self._partial_handler = partial_handler
+        self._partial_timer_name = None
+        if partial_interval is not None:
+            self._partial_timer_name = f"{bar_type}|PARTIAL"
+            self._clock.set_timer(
+                name=self._partial_timer_name,
+                interval=partial_interval,
+                start_time=None,
+                stop_time=None,
+                callback=self._build_partial_bar,
+            )
+
     def __str__(self):
         return f"{type(self).__name__}(interval_ns={self.interval_ns}, next_close_ns={self.next_close_ns})"
 
@@ -597,6 +681,12 @@ cdef class TimeBarAggregator(BarAggregator):
             The timestamp (UTC).
 
         """
+        cdef object now_ns
+        if self._origin_ns is not None:
+            # Python ints so the offset can precede the current time or the epoch
+            now_ns = self._clock.timestamp_ns()
+            return unix_nanos_to_dt(now_ns - (now_ns - self._origin_ns) % self.interval_ns)
+
         cdef datetime now = self._clock.utc_now()
         cdef int step = self.bar_type.spec.step
 
@@ -659,6 +749,10 @@ cdef class TimeBarAggregator(BarAggregator):
         self._clock.cancel_timer(str(self.bar_type))
         self._timer_name = None
 
+        if self._partial_timer_name is not None:
+            self._clock.cancel_timer(self._partial_timer_name)
+            self._partial_timer_name = None
+
     cdef timedelta _get_interval(self):
         cdef BarAggregation aggregation = self.bar_type.spec.aggregation
         cdef int step = self.bar_type.spec.step
@@ -715,12 +809,7 @@ cdef class TimeBarAggregator(BarAggregator):
         self._builder.update(price, size, ts_event)
         if self._build_on_next_tick:
             ts_init = ts_event
-
-            # Adjusting the timestamp logic based on interval_type
-            if self._is_left_open:
-                ts_event = self._stored_close_ns if self._timestamp_on_close else self._stored_open_ns
-            else:
-                ts_event = self._stored_open_ns
+            ts_event = self._bar_ts_event(self._stored_close_ns)
 
             self._build_and_send(ts_event=ts_event, ts_init=ts_init)
             # Reset flag and clear stored close
@@ -738,11 +827,7 @@ cdef class TimeBarAggregator(BarAggregator):
             return  # Do not build and emit bar
 
         cdef uint64_t ts_init = event.ts_event
-        cdef uint64_t ts_event
-        if self._is_left_open:
-            ts_event = event.ts_event if self._timestamp_on_close else self._stored_open_ns
-        else:
-            ts_event = self._stored_open_ns
+        cdef uint64_t ts_event = self._bar_ts_event(event.ts_event)
 
         self._build_and_send(ts_event=ts_event, ts_init=ts_init)
 
@@ -751,3 +836,22 @@ cdef class TimeBarAggregator(BarAggregator):
 
         # On receiving this event, timer should now have a new `next_time_ns`
         self.next_close_ns = self._clock.next_time_ns(self._timer_name)
+
+    cdef uint64_t _bar_ts_event(self, uint64_t close_ns):
+        # Adjusting the timestamp logic based on interval_type
+        if self._is_left_open and self._timestamp_on_close:
+            return close_ns
+        else:
+            return self._stored_open_ns
+
+    cpdef void _build_partial_bar(self, TimeEvent event):
+        if self._builder.count == 0:
+            return  # No updates for the in-progress bar
+
+        # Stamped as the complete bar will be, including when its close was deferred to the next tick
+        cdef uint64_t close_ns = self._stored_close_ns if self._build_on_next_tick else self.next_close_ns
+        cdef uint64_t ts_event = self._bar_ts_event(close_ns)
+
+        cdef Bar bar = self._builder.snapshot(ts_event=ts_event, ts_init=event.ts_event)
+        if bar is not None:
+            self._partial_handler(bar)
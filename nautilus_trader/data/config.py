@@ -15,6 +15,7 @@
 
 from __future__ import annotations
 
+from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import NautilusConfig
 
 
@@ -33,8 +34,27 @@ class DataEngineConfig(NautilusConfig, frozen=True):
         Determines the type of interval used for time aggregation.
         - 'left-open': start time is excluded and end time is included (default).
         - 'right-open': start time is included and end time is excluded.
+    time_bars_origin_ms : int, optional
+        The offset (milliseconds) from the UNIX epoch which time bar intervals align to,
+        e.g. 34_200_000 to open intervals on a 09:30 UTC session open, or 0 for plain
+        epoch alignment. If `None` then intervals align to calendar boundaries.
+    time_bars_partial_interval_ms : int, optional
+        The interval (milliseconds) at which time bar aggregators publish the in-progress
+        bar on the `data.partial_bars.{bar_type}` topic. If `None` then no partial bars.
+    bars_exclude_trade_flags : int, default 0
+        The bit set of ``TradeFlag`` values for trades to exclude from bar aggregation
+        (e.g. ``TradeFlag.BLOCK | TradeFlag.OFF_BOOK``).
     validate_data_sequence : bool, default False
         If data objects timestamp sequencing will be validated and handled.
+    bars_backfill_count : int, default 0
+        The number of historical bars to request and deliver to subscribers when a bar
+        type is first subscribed to, before any live bars. If zero then no backfill.
+    bars_backfill_timeout_ms : int, default 10_000
+        The milliseconds to wait for a backfill response before releasing buffered live bars.
+    venue_precision_modes : dict[str, str], default empty dict
+        The precision mode per venue for incoming data e.g. {"BINANCE": "NORMALIZE"}.
+        'NORMALIZE' re-quantizes prices and sizes to the instrument precisions (counting
+        any truncation), 'STRICT' drops data with a greater precision than the instrument.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -43,5 +63,40 @@ class DataEngineConfig(NautilusConfig, frozen=True):
     time_bars_build_with_no_updates: bool = True
     time_bars_timestamp_on_close: bool = True
     time_bars_interval_type: str = "left-open"
+    time_bars_origin_ms: int | None = None
+    time_bars_partial_interval_ms: int | None = None
+    bars_exclude_trade_flags: int = 0
     validate_data_sequence: bool = False
+    bars_backfill_count: int = 0
+    bars_backfill_timeout_ms: int = 10_000
+    venue_precision_modes: dict[str, str] = {}
     debug: bool = False
+
+
+class QuoteConsolidatorConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``QuoteConsolidator`` instances.
+
+    Parameters
+    ----------
+    instrument_ids : list[str]
+        The instrument IDs for the same symbol on each venue to consolidate (one per venue).
+    venue : str, default 'CBBO'
+        The venue for the consolidated instrument ID.
+    symbol : str, optional
+        The symbol for the consolidated instrument ID.
+        If ``None`` then the symbol of the first instrument ID is used.
+    use_order_books : bool, default False
+        If the top of each venues order book is used (subscribing to order book deltas),
+        otherwise each venues quotes are used.
+    stale_threshold_ms : int, optional
+        The age (milliseconds) after which a venues top-of-book is excluded from the
+        consolidation. If ``None`` then venues are never considered stale.
+
+    """
+
+    instrument_ids: list[str]
+    venue: str = "CBBO"
+    symbol: str | None = None
+    use_order_books: bool = False
+    stale_threshold_ms: int | None = None
@@ -0,0 +1,364 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.data import Data
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.data.config import QuoteConsolidatorConfig
+from nautilus_trader.model.data import DataType
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class ConsolidatedQuote(Data):
+    """
+    Represents a consolidated best bid and offer across venues, with venue attribution.
+
+    Parameters
+    ----------
+    quote : QuoteTick
+        The consolidated top-of-book quote.
+    bid_venue : Venue
+        The venue of the best bid (the largest contributor where venues share the price).
+    ask_venue : Venue
+        The venue of the best ask (the largest contributor where venues share the price).
+    venue_count : int
+        The count of venues contributing to the consolidation.
+
+    """
+
+    def __init__(
+        self,
+        quote: QuoteTick,
+        bid_venue: Venue,
+        ask_venue: Venue,
+        venue_count: int,
+    ) -> None:
+        self._quote = quote
+        self._bid_venue = bid_venue
+        self._ask_venue = ask_venue
+        self._venue_count = venue_count
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"{self._quote}, "
+            f"bid_venue={self._bid_venue}, "
+            f"ask_venue={self._ask_venue}, "
+            f"venue_count={self._venue_count})"
+        )
+
+    @property
+    def quote(self) -> QuoteTick:
+        return self._quote
+
+    @property
+    def instrument_id(self) -> InstrumentId:
+        return self._quote.instrument_id
+
+    @property
+    def bid_venue(self) -> Venue:
+        return self._bid_venue
+
+    @property
+    def ask_venue(self) -> Venue:
+        return self._ask_venue
+
+    @property
+    def venue_count(self) -> int:
+        return self._venue_count
+
+    @property
+    def is_crossed(self) -> bool:
+        """
+        Return whether the best bid is above the best ask (a cross-venue arbitrage).
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._quote.bid_price > self._quote.ask_price
+
+    @property
+    def ts_event(self) -> int:
+        return self._quote.ts_event
+
+    @property
+    def ts_init(self) -> int:
+        return self._quote.ts_init
+
+
+class QuoteConsolidator(Actor):
+    """
+    Provides an actor which consolidates the top-of-book for a symbol across venues.
+
+    The latest top-of-book is held for each venue, from either its quotes or its order
+    book. On every change of the consolidated best bid and offer a synthetic `QuoteTick`
+    for the consolidated instrument ID is cached and published on its quotes topic
+    (``data.quotes.{venue}.{symbol}``), and a `ConsolidatedQuote` with venue attribution
+    is published as data (subscribe with ``subscribe_data`` for the `data_type`).
+
+    Sizes at the best price are summed across the venues sharing it, and prices and
+    sizes are expressed at the greatest precision of the contributing venues.
+
+    Parameters
+    ----------
+    config : QuoteConsolidatorConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If `config.instrument_ids` is empty.
+    ValueError
+        If `config.instrument_ids` contains more than one instrument ID for a venue.
+
+    """
+
+    def __init__(self, config: QuoteConsolidatorConfig) -> None:
+        PyCondition.not_empty(config.instrument_ids, "config.instrument_ids")
+        instrument_ids = [InstrumentId.from_str(i) for i in config.instrument_ids]
+        PyCondition.true(
+            len({i.venue for i in instrument_ids}) == len(instrument_ids),
+            "`config.instrument_ids` contained more than one instrument ID for a venue",
+        )
+        super().__init__(config=config)
+
+        symbol = config.symbol or instrument_ids[0].symbol.value
+        self._instrument_ids = instrument_ids
+        self._consolidated_id = InstrumentId(Symbol(symbol), Venue(config.venue))
+        self._data_type = DataType(
+            ConsolidatedQuote,
+            metadata={"instrument_id": self._consolidated_id},
+        )
+        self._topic = f"data.quotes.{self._consolidated_id.venue}.{self._consolidated_id.symbol}"
+        self._use_order_books = config.use_order_books
+        self._stale_threshold_ns = 0
+        if config.stale_threshold_ms is not None:
+            self._stale_threshold_ns = millis_to_nanos(config.stale_threshold_ms)
+
+        # Venue -> (bid_price, bid_size, ask_price, ask_size, ts_event)
+        self._tops: dict[Venue, tuple] = {}
+        self._last: ConsolidatedQuote | None = None
+
+    @property
+    def consolidated_id(self) -> InstrumentId:
+        """
+        Return the instrument ID for the consolidated quotes.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return self._consolidated_id
+
+    @property
+    def data_type(self) -> DataType:
+        """
+        Return the data type for the published `ConsolidatedQuote` data.
+
+        Returns
+        -------
+        DataType
+
+        """
+        return self._data_type
+
+    @property
+    def last(self) -> ConsolidatedQuote | None:
+        """
+        Return the last published consolidated quote (if any).
+
+        Returns
+        -------
+        ConsolidatedQuote or ``None``
+
+        """
+        return self._last
+
+    def on_start(self) -> None:
+        for instrument_id in self._instrument_ids:
+            if self._use_order_books:
+                self.subscribe_order_book_deltas(instrument_id)
+            else:
+                self.subscribe_quote_ticks(instrument_id)
+
+    def on_stop(self) -> None:
+        for instrument_id in self._instrument_ids:
+            if self._use_order_books:
+                self.unsubscribe_order_book_deltas(instrument_id)
+            else:
+                self.unsubscribe_quote_ticks(instrument_id)
+
+    def on_reset(self) -> None:
+        self._tops.clear()
+        self._last = None
+
+    def on_quote_tick(self, tick: QuoteTick) -> None:
+        self.update(
+            venue=tick.instrument_id.venue,
+            bid_price=tick.bid_price,
+            bid_size=tick.bid_size,
+            ask_price=tick.ask_price,
+            ask_size=tick.ask_size,
+            ts_event=tick.ts_event,
+        )
+
+    def on_order_book_deltas(self, deltas: OrderBookDeltas) -> None:
+        book = self.cache.order_book(deltas.instrument_id)
+        if book is None:
+            return  # Book not yet built
+
+        self.update(
+            venue=deltas.instrument_id.venue,
+            bid_price=book.best_bid_price(),
+            bid_size=book.best_bid_size(),
+            ask_price=book.best_ask_price(),
+            ask_size=book.best_ask_size(),
+            ts_event=deltas.ts_event,
+        )
+
+    def update(
+        self,
+        venue: Venue,
+        bid_price: Price | None,
+        bid_size: Quantity | None,
+        ask_price: Price | None,
+        ask_size: Quantity | None,
+        ts_event: int,
+    ) -> None:
+        """
+        Update the top-of-book for the given venue, publishing any consolidated change.
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the update.
+        bid_price : Price, optional
+            The venues best bid price (``None`` if no bids).
+        bid_size : Quantity, optional
+            The venues best bid size (``None`` if no bids).
+        ask_price : Price, optional
+            The venues best ask price (``None`` if no asks).
+        ask_size : Quantity, optional
+            The venues best ask size (``None`` if no asks).
+        ts_event : int
+            The UNIX timestamp (nanoseconds) of the venues top-of-book.
+
+        """
+        self._tops[venue] = (bid_price, bid_size, ask_price, ask_size, ts_event)
+
+        consolidated = self._consolidate(ts_event)
+        if consolidated is None or self._is_unchanged(consolidated):
+            return
+
+        self._last = consolidated
+        self.cache.add_quote_tick(consolidated.quote)
+        self.msgbus.publish(topic=self._topic, msg=consolidated.quote)
+        self.publish_data(self._data_type, consolidated)
+
+    def _consolidate(self, ts_event: int) -> ConsolidatedQuote | None:
+        now_ns = self.clock.timestamp_ns()
+        bid = _BestSide(is_bid=True)
+        ask = _BestSide(is_bid=False)
+        venue_count = 0
+
+        for venue, (bid_price, bid_size, ask_price, ask_size, ts) in self._tops.items():
+            if self._stale_threshold_ns and now_ns > ts + self._stale_threshold_ns:
+                continue  # Stale venue
+            venue_count += 1
+            if bid_price is not None:
+                bid.add(venue, bid_price, bid_size)
+            if ask_price is not None:
+                ask.add(venue, ask_price, ask_size)
+
+        if bid.price is None or ask.price is None:
+            return None  # One side empty across all venues
+
+        price_precision = max(bid.price_precision, ask.price_precision)
+        size_precision = max(bid.size_precision, ask.size_precision)
+        quote = QuoteTick(
+            instrument_id=self._consolidated_id,
+            bid_price=Price(float(bid.price), price_precision),
+            ask_price=Price(float(ask.price), price_precision),
+            bid_size=Quantity(float(bid.size), size_precision),
+            ask_size=Quantity(float(ask.size), size_precision),
+            ts_event=ts_event,
+            ts_init=now_ns,
+        )
+
+        return ConsolidatedQuote(
+            quote=quote,
+            bid_venue=bid.venue,
+            ask_venue=ask.venue,
+            venue_count=venue_count,
+        )
+
+    def _is_unchanged(self, consolidated: ConsolidatedQuote) -> bool:
+        if self._last is None:
+            return False
+
+        last = self._last.quote
+        quote = consolidated.quote
+        return (
+            quote.bid_price == last.bid_price
+            and quote.ask_price == last.ask_price
+            and quote.bid_size == last.bid_size
+            and quote.ask_size == last.ask_size
+            and consolidated.bid_venue == self._last.bid_venue
+            and consolidated.ask_venue == self._last.ask_venue
+        )
+
+
+class _BestSide:
+    def __init__(self, is_bid: bool) -> None:
+        self.is_bid = is_bid
+        self.price: Decimal | None = None
+        self.size = Decimal(0)
+        self.venue: Venue | None = None
+        self.venue_size = Decimal(0)
+        self.price_precision = 0
+        self.size_precision = 0
+
+    def add(self, venue: Venue, price: Price, size: Quantity) -> None:
+        px = price.as_decimal()
+        qty = size.as_decimal()
+        is_better = self.price is None or (px > self.price if self.is_bid else px < self.price)
+        if is_better:
+            self.price = px
+            self.size = qty
+            self.venue = venue
+            self.venue_size = qty
+            self.price_precision = price.precision
+            self.size_precision = size.precision
+        elif px == self.price:
+            self.size += qty
+            self.price_precision = max(self.price_precision, price.precision)
+            self.size_precision = max(self.size_precision, size.precision)
+            if qty > self.venue_size:
+                self.venue = venue
+                self.venue_size = qty
@@ -13,9 +13,13 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from libc.stdint cimport uint8_t
+from libc.stdint cimport uint64_t
+
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport TimeEvent
+from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.data.client cimport DataClient
 from nautilus_trader.data.client cimport MarketDataClient
@@ -26,6 +30,7 @@ from nautilus_trader.data.messages cimport Subscribe
 from nautilus_trader.data.messages cimport Unsubscribe
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport BorrowRate
 from nautilus_trader.model.data cimport CustomData
 from nautilus_trader.model.data cimport DataType
 from nautilus_trader.model.data cimport InstrumentClose
@@ -60,7 +65,15 @@ cdef class DataEngine(Component):
     cdef readonly bint _time_bars_build_with_no_updates
     cdef readonly bint _time_bars_timestamp_on_close
     cdef readonly str _time_bars_interval_type
+    cdef readonly object _time_bars_origin
+    cdef readonly object _time_bars_partial_interval
+    cdef readonly uint8_t _bars_exclude_trade_flags
     cdef readonly bint _validate_data_sequence
+    cdef readonly int _bars_backfill_count
+    cdef readonly uint64_t _bars_backfill_timeout_ns
+    cdef readonly dict[BarType, list] _backfill_pending
+    cdef readonly dict[UUID4, BarType] _backfill_requests
+    cdef readonly object _precision_normalizer
 
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
@@ -147,10 +160,13 @@ cdef class DataEngine(Component):
     cpdef void _handle_quote_tick(self, QuoteTick tick)
     cpdef void _handle_trade_tick(self, TradeTick tick)
     cpdef void _handle_bar(self, Bar bar)
+    cpdef void _handle_partial_bar(self, Bar bar)
     cpdef void _handle_custom_data(self, CustomData data)
     cpdef void _handle_venue_status(self, VenueStatus data)
     cpdef void _handle_instrument_status(self, InstrumentStatus data)
     cpdef void _handle_close_price(self, InstrumentClose data)
+    cpdef void _handle_corporate_action(self, Data data)
+    cpdef void _handle_borrow_rate(self, BorrowRate data)
 
 # -- RESPONSE HANDLERS ----------------------------------------------------------------------------
 
@@ -163,9 +179,14 @@ cdef class DataEngine(Component):
 # -- INTERNAL -------------------------------------------------------------------------------------
 
     cpdef void _internal_update_instruments(self, list instruments)
+    cpdef Data _normalize_precision(self, Data data)
     cpdef void _update_order_book(self, Data data)
     cpdef void _snapshot_order_book(self, TimeEvent snap_event)
     cpdef void _start_bar_aggregator(self, MarketDataClient client, BarType bar_type, bint await_partial)
+    cpdef void _request_bars_backfill(self, MarketDataClient client, BarType bar_type)
+    cpdef void _handle_backfill_response(self, DataResponse response)
+    cpdef void _handle_backfill_timeout(self, TimeEvent event)
+    cpdef void _complete_backfill(self, BarType bar_type, list bars)
     cpdef void _stop_bar_aggregator(self, MarketDataClient client, BarType bar_type)
     cpdef void _update_synthetics_with_quote(self, list synthetics, QuoteTick update)
     cpdef void _update_synthetic_with_quote(self, SyntheticInstrument synthetic, QuoteTick update)
@@ -29,10 +29,12 @@ Alternative implementations can be written on top of the generic engine - which
 just need to override the `execute`, `process`, `send` and `receive` methods.
 """
 
+from operator import attrgetter
 from typing import Callable
 
 from nautilus_trader.common.enums import LogColor
 from nautilus_trader.data.config import DataEngineConfig
+from nautilus_trader.data.precision import PrecisionNormalizer
 from nautilus_trader.persistence.catalog import ParquetDataCatalog
 
 from cpython.datetime cimport datetime
@@ -73,6 +75,8 @@ from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarAggregation
 from nautilus_trader.model.data cimport BarType
+from nautilus_trader.model.data cimport BorrowRate
+from nautilus_trader.model.data cimport CashDividend
 from nautilus_trader.model.data cimport DataType
 from nautilus_trader.model.data cimport InstrumentClose
 from nautilus_trader.model.data cimport InstrumentStatus
@@ -80,6 +84,8 @@ from nautilus_trader.model.data cimport OrderBookDelta
 from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
+from nautilus_trader.model.data cimport StockSplit
+from nautilus_trader.model.data cimport SymbolChange
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.data cimport VenueStatus
 from nautilus_trader.model.identifiers cimport ClientId
@@ -137,13 +143,27 @@ cdef class DataEngine(Component):
         self._synthetic_trade_feeds: dict[InstrumentId, list[SyntheticInstrument]] = {}
         self._subscribed_synthetic_quotes: list[InstrumentId] = []
         self._subscribed_synthetic_trades: list[InstrumentId] = []
+        self._backfill_pending: dict[BarType, list[Bar]] = {}
+        self._backfill_requests: dict[UUID4, BarType] = {}
 
         # Settings
         self.debug = config.debug
         self._time_bars_build_with_no_updates = config.time_bars_build_with_no_updates
         self._time_bars_timestamp_on_close = config.time_bars_timestamp_on_close
         self._time_bars_interval_type = config.time_bars_interval_type
+        self._time_bars_origin = None
+        if config.time_bars_origin_ms is not None:
+            self._time_bars_origin = timedelta(milliseconds=config.time_bars_origin_ms)
+        self._time_bars_partial_interval = None
+        if config.time_bars_partial_interval_ms is not None:
+            self._time_bars_partial_interval = timedelta(milliseconds=config.time_bars_partial_interval_ms)
+        self._bars_exclude_trade_flags = config.bars_exclude_trade_flags
         self._validate_data_sequence = config.validate_data_sequence
+        self._bars_backfill_count = config.bars_backfill_count
+        self._bars_backfill_timeout_ns = millis_to_nanos(config.bars_backfill_timeout_ms)
+        self._precision_normalizer = None
+        if config.venue_precision_modes:
+            self._precision_normalizer = PrecisionNormalizer.from_config(config.venue_precision_modes)
 
         # Counters
         self.command_count = 0
@@ -181,6 +201,32 @@ cdef class DataEngine(Component):
         """
         return self._default_client.id if self._default_client is not None else None
 
+    def precision_truncated_counts(self) -> dict[InstrumentId, int]:
+        """
+        Return the count of data objects truncated to instrument precisions per instrument.
+
+        Returns
+        -------
+        dict[InstrumentId, int]
+
+        """
+        if self._precision_normalizer is None:
+            return {}
+        return self._precision_normalizer.truncated_counts()
+
+    def precision_rejected_counts(self) -> dict[InstrumentId, int]:
+        """
+        Return the count of data objects rejected for exceeding instrument precisions per instrument.
+
+        Returns
+        -------
+        dict[InstrumentId, int]
+
+        """
+        if self._precision_normalizer is None:
+            return {}
+        return self._precision_normalizer.rejected_counts()
+
     def connect(self) -> None:
         """
         Connect the engine by calling connect on all registered clients.
@@ -531,6 +577,8 @@ cdef class DataEngine(Component):
         self._synthetic_trade_feeds.clear()
         self._subscribed_synthetic_quotes.clear()
         self._subscribed_synthetic_trades.clear()
+        self._backfill_pending.clear()
+        self._backfill_requests.clear()
 
         self._clock.cancel_timers()
         self.command_count = 0
@@ -970,6 +1018,7 @@ cdef class DataEngine(Component):
         if bar_type.is_internally_aggregated():
             # Internal aggregation
             if bar_type not in self._bar_aggregators:
+                self._request_bars_backfill(client, bar_type)
                 self._start_bar_aggregator(client, bar_type, await_partial)
         else:
             # External aggregation
@@ -980,6 +1029,7 @@ cdef class DataEngine(Component):
                 return
 
             if bar_type not in client.subscribed_bars():
+                self._request_bars_backfill(client, bar_type)
                 client.subscribe_bars(bar_type)
 
     cpdef void _handle_subscribe_data(
@@ -1336,6 +1386,11 @@ cdef class DataEngine(Component):
     cpdef void _handle_data(self, Data data):
         self.data_count += 1
 
+        if self._precision_normalizer is not None:
+            data = self._normalize_precision(data)
+            if data is None:
+                return  # Rejected
+
         if isinstance(data, OrderBookDelta):
             self._handle_order_book_delta(data)
         elif isinstance(data, OrderBookDeltas):
@@ -1356,12 +1411,17 @@ cdef class DataEngine(Component):
             self._handle_instrument_status(data)
         elif isinstance(data, InstrumentClose):
             self._handle_close_price(data)
+        elif isinstance(data, (CashDividend, StockSplit, SymbolChange)):
+            self._handle_corporate_action(data)
+        elif isinstance(data, BorrowRate):
+            self._handle_borrow_rate(data)
         elif isinstance(data, CustomData):
             self._handle_custom_data(data)
         else:
             self._log.error(f"Cannot handle data: unrecognized type {type(data)} {data}.")
 
     cpdef void _handle_instrument(self, Instrument instrument):
+        cdef Instrument previous = self._cache.instrument(instrument.id)
         self._cache.add_instrument(instrument)
         self._msgbus.publish_c(
             topic=f"data.instrument"
@@ -1370,6 +1430,20 @@ cdef class DataEngine(Component):
             msg=instrument,
         )
 
+        if (
+            previous is not None
+            and instrument.ts_event > previous.ts_event
+            and self._cache.instrument(instrument.id) is instrument
+        ):
+            # A new version now supersedes the previously current instrument
+            self._log.info(f"Instrument {instrument.id} changed effective {instrument.ts_event}.")
+            self._msgbus.publish_c(
+                topic=f"data.instrument_changes"
+                      f".{instrument.id.venue}"
+                      f".{instrument.id.symbol}",
+                msg=instrument,
+            )
+
     cpdef void _handle_order_book_delta(self, OrderBookDelta delta):
         cdef OrderBookDeltas deltas = OrderBookDeltas(
             instrument_id=delta.instrument_id,
@@ -1436,6 +1510,11 @@ cdef class DataEngine(Component):
             Bar last_bar
             list bars
             int i
+        if self._backfill_pending:
+            bars = self._backfill_pending.get(bar_type)
+            if bars is not None:
+                bars.append(bar)
+                return  # Released once backfill completes
         if self._validate_data_sequence:
             last_bar = self._cache.bar(bar_type)
             if last_bar is not None:
@@ -1467,6 +1546,10 @@ cdef class DataEngine(Component):
 
         self._msgbus.publish_c(topic=f"data.bars.{bar_type}", msg=bar)
 
+    cpdef void _handle_partial_bar(self, Bar bar):
+        # Partial bars are neither cached nor published on the completed bars topic
+        self._msgbus.publish_c(topic=f"data.partial_bars.{bar.bar_type}", msg=bar)
+
     cpdef void _handle_venue_status(self, VenueStatus data):
         self._msgbus.publish_c(topic=f"data.status.{data.venue}", msg=data)
 
@@ -1476,6 +1559,19 @@ cdef class DataEngine(Component):
     cpdef void _handle_close_price(self, InstrumentClose data):
         self._msgbus.publish_c(topic=f"data.venue.close_price.{data.instrument_id}", msg=data)
 
+    cpdef void _handle_corporate_action(self, Data data):
+        cdef InstrumentId instrument_id = (<object>data).instrument_id
+        self._msgbus.publish_c(
+            topic=f"data.corporate_actions.{instrument_id.venue}.{instrument_id.symbol}",
+            msg=data,
+        )
+
+    cpdef void _handle_borrow_rate(self, BorrowRate data):
+        self._msgbus.publish_c(
+            topic=f"data.borrow_rates.{data.venue}.{data.currency.code}",
+            msg=data,
+        )
+
     cpdef void _handle_custom_data(self, CustomData data):
         self._msgbus.publish_c(topic=f"data.{data.data_type.topic}", msg=data.data)
 
@@ -1539,6 +1635,24 @@ cdef class DataEngine(Component):
         for instrument in instruments:
             self._handle_instrument(instrument)
 
+    cpdef Data _normalize_precision(self, Data data):
+        cdef InstrumentId instrument_id
+        if isinstance(data, Bar):
+            instrument_id = data.bar_type.instrument_id
+        elif isinstance(data, (QuoteTick, TradeTick, OrderBookDelta, OrderBookDeltas)):
+            instrument_id = data.instrument_id
+        else:
+            return data  # No prices or sizes to normalize
+
+        if self._precision_normalizer.mode(instrument_id.venue) is None:
+            return data
+
+        cdef Instrument instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            return data  # Cannot normalize without precisions
+
+        return self._precision_normalizer.normalize(data, instrument)
+
     cpdef void _update_order_book(self, Data data):
         cdef OrderBook order_book = self._cache.order_book(data.instrument_id)
         if order_book is None:
@@ -1598,6 +1712,9 @@ cdef class DataEngine(Component):
                 build_with_no_updates=self._time_bars_build_with_no_updates,
                 timestamp_on_close=self._time_bars_timestamp_on_close,
                 interval_type=self._time_bars_interval_type,
+                origin=self._time_bars_origin,
+                partial_interval=self._time_bars_partial_interval,
+                partial_handler=self._handle_partial_bar,
             )
         elif bar_type.spec.aggregation == BarAggregation.TICK:
             aggregator = TickBarAggregator(
@@ -1627,6 +1744,9 @@ cdef class DataEngine(Component):
         # Set if awaiting initial partial bar
         aggregator.set_await_partial(await_partial)
 
+        # Set trade conditions to exclude from aggregation
+        aggregator.set_exclude_trade_flags(self._bars_exclude_trade_flags)
+
         # Add aggregator
         self._bar_aggregators[bar_type] = aggregator
         self._log.debug(f"Added {aggregator} for {bar_type} bars.")
@@ -1651,6 +1771,87 @@ cdef class DataEngine(Component):
             )
             self._handle_subscribe_quote_ticks(client, bar_type.instrument_id)
 
+    cpdef void _request_bars_backfill(self, MarketDataClient client, BarType bar_type):
+        if self._bars_backfill_count <= 0 or bar_type in self._backfill_pending:
+            return
+
+        cdef datetime start = None
+        if bar_type.spec.is_time_aggregated() and bar_type.spec.aggregation != BarAggregation.MONTH:
+            start = self._clock.utc_now() - bar_type.spec.timedelta * self._bars_backfill_count
+
+        cdef UUID4 request_id = UUID4()
+        cdef DataRequest request = DataRequest(
+            client_id=client.id,
+            venue=bar_type.instrument_id.venue,
+            data_type=DataType(Bar, metadata={
+                "bar_type": bar_type,
+                "limit": self._bars_backfill_count,
+                "start": start,
+                "end": None,
+            }),
+            callback=self._handle_backfill_response,
+            request_id=request_id,
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+        # Live bars are buffered until the backfill completes or times out
+        self._backfill_pending[bar_type] = []
+        self._backfill_requests[request_id] = bar_type
+        self._clock.set_time_alert_ns(
+            name=f"BarBackfill|{bar_type}",
+            alert_time_ns=self._clock.timestamp_ns() + self._bars_backfill_timeout_ns,
+            callback=self._handle_backfill_timeout,
+        )
+
+        self._log.info(f"Requesting {self._bars_backfill_count} bars backfill for {bar_type}.")
+        self._msgbus.request(endpoint="DataEngine.request", request=request)
+
+    cpdef void _handle_backfill_response(self, DataResponse response):
+        cdef BarType bar_type = self._backfill_requests.pop(response.correlation_id, None)
+        if bar_type is None:
+            return  # Backfill already timed out
+
+        self._complete_backfill(bar_type, response.data)
+
+    cpdef void _handle_backfill_timeout(self, TimeEvent event):
+        cdef BarType bar_type = BarType.from_str(event.name.partition("|")[2])
+
+        cdef UUID4 request_id
+        for request_id in [k for k, v in self._backfill_requests.items() if v == bar_type]:
+            self._backfill_requests.pop(request_id)
+
+        self._log.warning(f"Timed out awaiting bars backfill for {bar_type}.")
+        self._complete_backfill(bar_type, [])
+
+    cpdef void _complete_backfill(self, BarType bar_type, list bars):
+        cdef list live_bars = self._backfill_pending.pop(bar_type, None)
+        if live_bars is None:
+            return  # Already completed
+
+        cdef str timer_name = f"BarBackfill|{bar_type}"
+        if timer_name in self._clock.timer_names:
+            self._clock.cancel_timer(timer_name)
+
+        # Historical bars were cached when the response was handled
+        cdef str topic = f"data.bars.{bar_type}"
+        cdef uint64_t ts_last = 0
+        cdef int count = 0
+        cdef Bar bar
+        for bar in sorted(bars, key=attrgetter("ts_event")):
+            if bar.ts_event <= ts_last:
+                continue  # Duplicate
+            self._msgbus.publish_c(topic=topic, msg=bar)
+            ts_last = bar.ts_event
+            count += 1
+
+        # Live bars already covered by the backfill are dropped at the boundary
+        for bar in live_bars:
+            if bar.ts_event <= ts_last:
+                continue
+            self._handle_bar(bar)
+
+        self._log.info(f"Backfilled {count} bars for {bar_type}.")
+
     cpdef void _stop_bar_aggregator(self, MarketDataClient client, BarType bar_type):
         cdef aggregator = self._bar_aggregators.get(bar_type)
         if aggregator is None:
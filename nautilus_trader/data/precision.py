@@ -0,0 +1,328 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Provides normalization of venue data and orders to instrument precisions.
+"""
+
+from __future__ import annotations
+
+from collections import defaultdict
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.data import Data
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+@unique
+class PrecisionMode(Enum):
+    """
+    Represents the handling of venue data and orders which do not match the
+    declared precisions of their instrument.
+    """
+
+    NORMALIZE = "NORMALIZE"
+    """Incoming data is re-quantized to the instrument precisions (counting any truncation)."""
+    STRICT = "STRICT"
+    """Incoming data with greater precision than the instrument is rejected."""
+
+
+class PrecisionNormalizer:
+    """
+    Provides per venue normalization of prices and sizes to instrument precisions.
+
+    Incoming data with a lower precision than the instrument is always re-expressed
+    at the instrument precision (which is lossless). Data with a greater precision
+    is either rounded (``NORMALIZE``), with the truncation counted and logged, or
+    rejected (``STRICT``).
+
+    Outgoing order prices and quantities are validated against the instrument
+    precisions and increments for all configured venues.
+
+    Parameters
+    ----------
+    modes : dict[Venue, PrecisionMode]
+        The precision modes per venue. Data for other venues is passed through unchanged.
+
+    """
+
+    def __init__(self, modes: dict[Venue, PrecisionMode]) -> None:
+        self._log = Logger(name=type(self).__name__)
+        self._modes = modes
+        self._truncated: defaultdict[InstrumentId, int] = defaultdict(int)
+        self._rejected: defaultdict[InstrumentId, int] = defaultdict(int)
+
+    @classmethod
+    def from_config(cls, modes: dict[str, str]) -> PrecisionNormalizer:
+        """
+        Create a normalizer from the given configuration values.
+
+        Parameters
+        ----------
+        modes : dict[str, str]
+            The precision mode names per venue e.g. {"BINANCE": "NORMALIZE"}.
+
+        Returns
+        -------
+        PrecisionNormalizer
+
+        Raises
+        ------
+        ValueError
+            If a mode name is not a valid `PrecisionMode`.
+
+        """
+        return cls({Venue(venue): PrecisionMode(mode.upper()) for venue, mode in modes.items()})
+
+    def mode(self, venue: Venue) -> PrecisionMode | None:
+        """
+        Return the precision mode for the given venue (if configured).
+
+        Parameters
+        ----------
+        venue : Venue
+            The venue for the mode.
+
+        Returns
+        -------
+        PrecisionMode or ``None``
+
+        """
+        return self._modes.get(venue)
+
+    def truncated_counts(self) -> dict[InstrumentId, int]:
+        """
+        Return the count of data objects which were truncated per instrument.
+
+        Returns
+        -------
+        dict[InstrumentId, int]
+
+        """
+        return dict(self._truncated)
+
+    def rejected_counts(self) -> dict[InstrumentId, int]:
+        """
+        Return the count of data objects which were rejected per instrument.
+
+        Returns
+        -------
+        dict[InstrumentId, int]
+
+        """
+        return dict(self._rejected)
+
+    def normalize(self, data: Data, instrument: Instrument) -> Data | None:
+        """
+        Return the given data normalized to the precisions of the instrument.
+
+        Parameters
+        ----------
+        data : QuoteTick, TradeTick, Bar, OrderBookDelta or OrderBookDeltas
+            The data to normalize.
+        instrument : Instrument
+            The instrument for the data.
+
+        Returns
+        -------
+        Data or ``None``
+            ``None`` if the data was rejected.
+
+        """
+        mode = self._modes.get(instrument.id.venue)
+        if mode is None:
+            return data
+
+        if isinstance(data, QuoteTick):
+            prices = [data.bid_price, data.ask_price]
+            sizes = [data.bid_size, data.ask_size]
+        elif isinstance(data, TradeTick):
+            prices = [data.price]
+            sizes = [data.size]
+        elif isinstance(data, Bar):
+            prices = [data.open, data.high, data.low, data.close]
+            sizes = [data.volume]
+        elif isinstance(data, OrderBookDelta):
+            if data.action == BookAction.CLEAR or data.order is None:
+                return data
+            prices = [data.order.price]
+            sizes = [data.order.size]
+        elif isinstance(data, OrderBookDeltas):
+            return self._normalize_deltas(data, instrument)
+        else:
+            return data
+
+        if all(p.precision == instrument.price_precision for p in prices) and all(
+            s.precision == instrument.size_precision for s in sizes
+        ):
+            return data  # Already at instrument precisions
+
+        if mode == PrecisionMode.STRICT and (
+            any(p.precision > instrument.price_precision for p in prices)
+            or any(s.precision > instrument.size_precision for s in sizes)
+        ):
+            self._rejected[instrument.id] += 1
+            self._log.warning(
+                f"Rejected {type(data).__name__} for {instrument.id}: precision exceeds "
+                f"instrument (price_precision={instrument.price_precision}, "
+                f"size_precision={instrument.size_precision}), {data}",
+            )
+            return None
+
+        new_prices = [instrument.make_price(p.as_decimal()) for p in prices]
+        new_sizes = [instrument.make_qty(s.as_decimal()) for s in sizes]
+        if any(n != o for n, o in zip(new_prices, prices)) or any(
+            n != o for n, o in zip(new_sizes, sizes)
+        ):
+            self._truncated[instrument.id] += 1
+            count = self._truncated[instrument.id]
+            if count == 1:
+                self._log.warning(
+                    f"Truncated {type(data).__name__} for {instrument.id} to instrument precisions "
+                    f"(price_precision={instrument.price_precision}, "
+                    f"size_precision={instrument.size_precision}), {data}; "
+                    "further truncations will be counted",
+                )
+
+        return self._rebuild(data, new_prices, new_sizes)
+
+    def _normalize_deltas(
+        self,
+        deltas: OrderBookDeltas,
+        instrument: Instrument,
+    ) -> OrderBookDeltas | None:
+        normalized: list[OrderBookDelta] = []
+        for delta in deltas.deltas:
+            result = self.normalize(delta, instrument)
+            if result is None:
+                return None  # Do not publish a partial batch
+            normalized.append(result)
+
+        if all(n is o for n, o in zip(normalized, deltas.deltas)):
+            return deltas
+
+        return OrderBookDeltas(instrument_id=deltas.instrument_id, deltas=normalized)
+
+    def _rebuild(self, data: Data, prices: list[Price], sizes: list[Quantity]) -> Data:
+        if isinstance(data, QuoteTick):
+            return QuoteTick(
+                instrument_id=data.instrument_id,
+                bid_price=prices[0],
+                ask_price=prices[1],
+                bid_size=sizes[0],
+                ask_size=sizes[1],
+                ts_event=data.ts_event,
+                ts_init=data.ts_init,
+            )
+        elif isinstance(data, TradeTick):
+            return TradeTick(
+                instrument_id=data.instrument_id,
+                price=prices[0],
+                size=sizes[0],
+                aggressor_side=data.aggressor_side,
+                trade_id=data.trade_id,
+                ts_event=data.ts_event,
+                ts_init=data.ts_init,
+                flags=data.flags,
+            )
+        elif isinstance(data, Bar):
+            return Bar(
+                bar_type=data.bar_type,
+                open=prices[0],
+                high=prices[1],
+                low=prices[2],
+                close=prices[3],
+                volume=sizes[0],
+                ts_event=data.ts_event,
+                ts_init=data.ts_init,
+                is_revision=data.is_revision,
+            )
+        else:  # OrderBookDelta
+            return OrderBookDelta(
+                instrument_id=data.instrument_id,
+                action=data.action,
+                order=BookOrder(
+                    side=data.order.side,
+                    price=prices[0],
+                    size=sizes[0],
+                    order_id=data.order.order_id,
+                ),
+                ts_event=data.ts_event,
+                ts_init=data.ts_init,
+                flags=data.flags,
+                sequence=data.sequence,
+            )
+
+    def check_price(self, instrument: Instrument, price: Price | None) -> str | None:
+        """
+        Check the given order price against the instrument precision and increment.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the check.
+        price : Price, optional
+            The price to check.
+
+        Returns
+        -------
+        str or ``None``
+            The reason the check failed, otherwise ``None``.
+
+        """
+        if price is None or instrument.id.venue not in self._modes:
+            return None
+        if price.precision > instrument.price_precision:
+            return f"PRICE_PRECISION_EXCEEDS_VENUE: price {price} precision {price.precision} > {instrument.price_precision}"
+        if price.as_decimal() % instrument.price_increment.as_decimal() != 0:
+            return f"PRICE_NOT_ON_INCREMENT: price {price} not a multiple of {instrument.price_increment}"
+        return None
+
+    def check_quantity(self, instrument: Instrument, quantity: Quantity | None) -> str | None:
+        """
+        Check the given order quantity against the instrument precision and increment.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the check.
+        quantity : Quantity, optional
+            The quantity to check.
+
+        Returns
+        -------
+        str or ``None``
+            The reason the check failed, otherwise ``None``.
+
+        """
+        if quantity is None or instrument.id.venue not in self._modes:
+            return None
+        if quantity.precision > instrument.size_precision:
+            return f"QUANTITY_PRECISION_EXCEEDS_VENUE: quantity {quantity} precision {quantity.precision} > {instrument.size_precision}"
+        if quantity.as_decimal() % instrument.size_increment.as_decimal() != 0:
+            return f"QUANTITY_NOT_ON_INCREMENT: quantity {quantity} not a multiple of {instrument.size_increment}"
+        return None
@@ -53,6 +53,7 @@ from nautilus_trader.model.events.position cimport PositionEvent
 from nautilus_trader.model.events.position cimport PositionOpened
 from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ClientOrderId
+from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
@@ -130,6 +131,7 @@ cdef class ExecAlgorithm(Actor):
         bint reduce_only=*,
         str tags=*,
         bint reduce_primary=*,
+        InstrumentId instrument_id=*,
     )
 
     cpdef LimitOrder spawn_limit(
@@ -145,6 +147,7 @@ cdef class ExecAlgorithm(Actor):
         TriggerType emulation_trigger=*,
         str tags=*,
         bint reduce_primary=*,
+        InstrumentId instrument_id=*,
     )
 
     cpdef MarketToLimitOrder spawn_market_to_limit(
@@ -69,6 +69,7 @@ from nautilus_trader.model.events.position cimport PositionOpened
 from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport ExecAlgorithmId
+from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
@@ -787,6 +788,7 @@ cdef class ExecAlgorithm(Actor):
         bint reduce_only = False,
         str tags = None,
         bint reduce_primary = True,
+        InstrumentId instrument_id = None,
     ):
         """
         Spawn a new ``MARKET`` order from the given primary order.
@@ -806,6 +808,9 @@ cdef class ExecAlgorithm(Actor):
             contain any arbitrary delimiter if required.
         reduce_primary : bool, default True
             If the primary order quantity should be reduced by the given `quantity`.
+        instrument_id : InstrumentId, optional
+            The instrument for the spawned order, such as the same symbol on another
+            venue when routing. If ``None`` then will be the primary orders instrument.
 
         Returns
         -------
@@ -831,7 +836,7 @@ cdef class ExecAlgorithm(Actor):
         return MarketOrder(
             trader_id=primary.trader_id,
             strategy_id=primary.strategy_id,
-            instrument_id=primary.instrument_id,
+            instrument_id=instrument_id or primary.instrument_id,
             client_order_id=self._spawn_client_order_id(primary),
             order_side=primary.side,
             quantity=quantity,
@@ -861,6 +866,7 @@ cdef class ExecAlgorithm(Actor):
         TriggerType emulation_trigger = TriggerType.NO_TRIGGER,
         str tags = None,
         bint reduce_primary = True,
+        InstrumentId instrument_id = None,
     ):
         """
         Spawn a new ``LIMIT`` order from the given primary order.
@@ -890,6 +896,9 @@ cdef class ExecAlgorithm(Actor):
             contain any arbitrary delimiter if required.
         reduce_primary : bool, default True
             If the primary order quantity should be reduced by the given `quantity`.
+        instrument_id : InstrumentId, optional
+            The instrument for the spawned order, such as the same symbol on another
+            venue when routing. If ``None`` then will be the primary orders instrument.
 
         Returns
         -------
@@ -917,7 +926,7 @@ cdef class ExecAlgorithm(Actor):
         return LimitOrder(
             trader_id=primary.trader_id,
             strategy_id=primary.strategy_id,
-            instrument_id=primary.instrument_id,
+            instrument_id=instrument_id or primary.instrument_id,
             client_order_id=self._spawn_client_order_id(primary),
             order_side=primary.side,
             quantity=quantity,
@@ -1066,6 +1075,7 @@ cdef class ExecAlgorithm(Actor):
         cdef PositionId position_id = None
         cdef ClientId client_id = None
         cdef SubmitOrder command = None
+        cdef bint is_routed = False
 
         if order.is_spawned_c():
             # Handle new spawned order
@@ -1077,8 +1087,11 @@ cdef class ExecAlgorithm(Actor):
                 )
                 return
 
-            position_id = self.cache.position_id(primary.client_order_id)
-            client_id = self.cache.client_id(primary.client_order_id)
+            # Orders routed to another instrument have their client inferred from its venue
+            is_routed = order.instrument_id != primary.instrument_id
+            if not is_routed:
+                position_id = self.cache.position_id(primary.client_order_id)
+                client_id = self.cache.client_id(primary.client_order_id)
 
             if self.cache.order_exists(order.client_order_id):
                 self._log.error(
@@ -1100,7 +1113,7 @@ cdef class ExecAlgorithm(Actor):
                 order=order,
                 command_id=UUID4(),
                 ts_init=self.clock.timestamp_ns(),
-                position_id=primary.position_id,
+                position_id=None if is_routed else primary.position_id,
                 client_id=client_id,
             )
 
@@ -20,6 +20,7 @@ from typing import Any
 import msgspec
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveInt
 from nautilus_trader.common.config import resolve_config_path
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.correctness import PyCondition
@@ -36,6 +37,13 @@ class ExecEngineConfig(NautilusConfig, frozen=True):
         If the cache should be loaded on initialization.
     allow_cash_positions : bool, default True
         If unleveraged spot/cash assets should generate positions.
+    infer_liquidity_side : bool, default True
+        If the liquidity side should be inferred for fills which do not report it
+        (``NO_LIQUIDITY_SIDE``), from the order type and the resting book state
+        (order book or latest quote) at the time of the fill.
+    latency_tracking : bool, default False
+        If order lifecycle latencies should be tracked, from submit command creation
+        through engine receipt, client send and venue acknowledgement to the first fill.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -43,6 +51,8 @@ class ExecEngineConfig(NautilusConfig, frozen=True):
 
     load_cache: bool = True
     allow_cash_positions: bool = True
+    infer_liquidity_side: bool = True
+    latency_tracking: bool = False
     debug: bool = False
 
 
@@ -61,6 +71,34 @@ class ExecAlgorithmConfig(NautilusConfig, kw_only=True, frozen=True):
     exec_algorithm_id: ExecAlgorithmId | None = None
 
 
+class SmartOrderRouterConfig(ExecAlgorithmConfig, frozen=True):
+    """
+    Configuration for ``SmartOrderRouter`` instances.
+
+    Parameters
+    ----------
+    instrument_ids : list[str]
+        The instrument IDs (one per venue) the router may split orders across.
+    exec_algorithm_id : ExecAlgorithmId, optional
+        The execution algorithm ID (will override default which is the class name).
+    fee_rates : dict[str, float], optional
+        The fee rate per venue, used to compare the effective cost of displayed
+        liquidity. If a venue is not specified then the instruments taker fee is used.
+    use_order_books : bool, default False
+        If order book depth should be used for displayed liquidity, otherwise
+        only the top of book from quotes is considered.
+    max_book_levels : int, default 10
+        The maximum order book levels to consider per venue (when `use_order_books`).
+
+    """
+
+    instrument_ids: list[str]
+    exec_algorithm_id: ExecAlgorithmId | None = ExecAlgorithmId("SOR")
+    fee_rates: dict[str, float] | None = None
+    use_order_books: bool = False
+    max_book_levels: PositiveInt = 10
+
+
 class ImportableExecAlgorithmConfig(NautilusConfig, frozen=True):
     """
     Configuration for an execution algorithm instance.
@@ -16,6 +16,7 @@
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.generators cimport PositionIdGenerator
+from nautilus_trader.core.rust.model cimport LiquiditySide
 from nautilus_trader.core.rust.model cimport OmsType
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.execution.algorithm cimport ExecAlgorithm
@@ -50,17 +51,22 @@ cdef class ExecutionEngine(Component):
     cdef readonly dict[Venue, ExecutionClient] _routing_map
     cdef readonly dict[StrategyId, OmsType] _oms_overrides
     cdef readonly dict[InstrumentId, StrategyId] _external_order_claims
+    cdef readonly object _latency_tracker
 
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
     cdef readonly bint allow_cash_positions
     """If unleveraged spot/cash assets should generate positions.\n\n:returns: `bool`"""
+    cdef readonly bint infer_liquidity_side
+    """If the liquidity side should be inferred for fills which do not report it.\n\n:returns: `bool`"""
     cdef readonly int command_count
     """The total count of commands received by the engine.\n\n:returns: `int`"""
     cdef readonly int event_count
     """The total count of events received by the engine.\n\n:returns: `int`"""
     cdef public int report_count
     """The total count of reports received by the engine.\n\n:returns: `int`"""
+    cdef readonly int duplicate_fill_count
+    """The total count of duplicate fills skipped by the engine.\n\n:returns: `int`"""
 
     cpdef int position_id_count(self, StrategyId strategy_id)
     cpdef bint check_integrity(self)
@@ -116,6 +122,8 @@ cdef class ExecutionEngine(Component):
     cpdef void _determine_position_id(self, OrderFilled fill, OmsType oms_type)
     cpdef PositionId _determine_hedging_position_id(self, OrderFilled fill)
     cpdef PositionId _determine_netting_position_id(self, OrderFilled fill)
+    cpdef bint _is_duplicate_fill(self, Order order, OrderFilled fill)
+    cpdef LiquiditySide _infer_liquidity_side(self, Order order, OrderFilled fill)
     cpdef void _apply_event_to_order(self, Order order, OrderEvent event)
     cpdef void _handle_order_fill(self, Order order, OrderFilled fill, OmsType oms_type)
     cpdef Position _open_position(self, Instrument instrument, Position position, OrderFilled fill, OmsType oms_type)
@@ -34,6 +34,9 @@ from decimal import Decimal
 
 from nautilus_trader.common.config import InvalidConfiguration
 from nautilus_trader.execution.config import ExecEngineConfig
+from nautilus_trader.execution.latency import LatencyHistogram
+from nautilus_trader.execution.latency import LatencyStage
+from nautilus_trader.execution.latency import LatencyTracker
 from nautilus_trader.execution.reports import ExecutionMassStatus
 from nautilus_trader.execution.reports import ExecutionReport
 
@@ -54,6 +57,7 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.fsm cimport InvalidStateTrigger
 from nautilus_trader.core.rust.model cimport ContingencyType
 from nautilus_trader.core.rust.model cimport OmsType
+from nautilus_trader.core.rust.model cimport OrderType
 from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.execution.algorithm cimport ExecAlgorithm
@@ -65,6 +69,7 @@ from nautilus_trader.execution.messages cimport ModifyOrder
 from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
+from nautilus_trader.model.book cimport OrderBook
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderDenied
@@ -90,6 +95,15 @@ from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 
 
+cdef inline tuple _fill_key(OrderFilled fill):
+    # Venues may format the same numeric trade ID differently across
+    # streams and snapshots (such as with leading zeros or whitespace)
+    cdef str trade_id = fill.trade_id.to_str().strip()
+    if trade_id.isdigit():
+        trade_id = trade_id.lstrip("0") or "0"
+    return (fill.venue_order_id, trade_id)
+
+
 cdef class ExecutionEngine(Component):
     """
     Provides a high-performance execution engine for the management of many
@@ -146,11 +160,14 @@ cdef class ExecutionEngine(Component):
         # Settings
         self.debug: bool = config.debug
         self.allow_cash_positions: bool = config.allow_cash_positions
+        self.infer_liquidity_side: bool = config.infer_liquidity_side
+        self._latency_tracker: LatencyTracker | None = LatencyTracker() if config.latency_tracking else None
 
         # Counters
         self.command_count: int = 0
         self.event_count: int = 0
         self.report_count: int = 0
+        self.duplicate_fill_count: int = 0
 
         # Register endpoints
         self._msgbus.register(endpoint="ExecEngine.execute", handler=self.execute)
@@ -193,6 +210,47 @@ cdef class ExecutionEngine(Component):
         """
         return self._default_client.id if self._default_client is not None else None
 
+    def latency_histograms(
+        self,
+        Venue venue = None,
+        StrategyId strategy_id = None,
+    ) -> dict[LatencyStage, LatencyHistogram]:
+        """
+        Return the order lifecycle latency histograms per stage, optionally
+        filtered by venue and/or strategy.
+
+        Parameters
+        ----------
+        venue : Venue, optional
+            The venue filter.
+        strategy_id : StrategyId, optional
+            The strategy ID filter.
+
+        Returns
+        -------
+        dict[LatencyStage, LatencyHistogram]
+            Empty if latency tracking is not enabled.
+
+        """
+        if self._latency_tracker is None:
+            return {}
+        return self._latency_tracker.histograms(venue=venue, strategy_id=strategy_id)
+
+    def export_latency_histograms(self) -> dict[str, dict[str, dict[str, object]]]:
+        """
+        Return an export of the order lifecycle latency histograms per stage,
+        keyed by venue and strategy ID in the form "{venue}:{strategy_id}".
+
+        Returns
+        -------
+        dict[str, dict[str, dict[str, object]]]
+            Empty if latency tracking is not enabled.
+
+        """
+        if self._latency_tracker is None:
+            return {}
+        return self._latency_tracker.to_dict()
+
     def connect(self) -> None:
         """
         Connect the engine by calling connect on all registered clients.
@@ -553,6 +611,9 @@ cdef class ExecutionEngine(Component):
         self._cache.reset()
         self._pos_id_generator.reset()
 
+        if self._latency_tracker is not None:
+            self._latency_tracker.reset()
+
         self.command_count = 0
         self.event_count = 0
         self.report_count = 0
@@ -722,6 +783,9 @@ cdef class ExecutionEngine(Component):
             self._log.debug(f"{RECV}{CMD} {command}.", LogColor.MAGENTA)
         self.command_count += 1
 
+        if self._latency_tracker is not None:
+            self._latency_tracker.on_command(command, self._clock.timestamp_ns())
+
         cdef ExecutionClient client = self._clients.get(command.client_id)
         if client is None:
             client = self._routing_map.get(
@@ -891,6 +955,22 @@ cdef class ExecutionEngine(Component):
 
         cdef OmsType oms_type
         if isinstance(event, OrderFilled):
+            if self._is_duplicate_fill(order, event):
+                self.duplicate_fill_count += 1
+                self._log.warning(
+                    f"Duplicate fill {event.trade_id!r} for {event.venue_order_id!r} "
+                    f"already applied to {order.client_order_id!r}, skipping {event}.",
+                )
+                return  # Idempotent for replayed venue messages
+            if (<OrderFilled>event).tags is None:
+                # Propagate the order tags for attribution of the fill
+                (<OrderFilled>event).tags = order.tags
+            if (
+                self.infer_liquidity_side
+                and (<OrderFilled>event).liquidity_side == LiquiditySide.NO_LIQUIDITY_SIDE
+                and not event.reconciliation  # Book state is not at the time of inferred fills
+            ):
+                (<OrderFilled>event).liquidity_side = self._infer_liquidity_side(order, event)
             oms_type = self._determine_oms_type(event)
             self._determine_position_id(event, oms_type)
             self._apply_event_to_order(order, event)
@@ -898,6 +978,9 @@ cdef class ExecutionEngine(Component):
         else:
             self._apply_event_to_order(order, event)
 
+        if self._latency_tracker is not None:
+            self._latency_tracker.on_event(event)
+
     cpdef OmsType _determine_oms_type(self, OrderFilled fill):
         cdef ExecutionClient client
         # Check for strategy OMS override
@@ -959,6 +1042,9 @@ cdef class ExecutionEngine(Component):
 
         cdef Order primary = self._cache.order(order.exec_spawn_id)
         assert primary is not None
+        if primary.instrument_id != order.instrument_id:
+            return  # Spawned order was routed to another instrument
+
         if primary.position_id is None:
             primary.position_id = position_id
             self._cache.add_position_id(
@@ -988,7 +1074,7 @@ cdef class ExecutionEngine(Component):
         if order.exec_spawn_id is not None:
             exec_spawn_orders = self._cache.orders_for_exec_spawn(order.exec_spawn_id)
             for spawned_order in exec_spawn_orders:
-                if spawned_order.position_id is not None:
+                if spawned_order.position_id is not None and spawned_order.instrument_id == order.instrument_id:
                     if self.debug:
                         self._log.debug(f"Found spawned {spawned_order.position_id!r} for {fill}.", LogColor.MAGENTA)
                     # Use position ID for execution spawn
@@ -1003,6 +1089,67 @@ cdef class ExecutionEngine(Component):
     cpdef PositionId _determine_netting_position_id(self, OrderFilled fill):
         return PositionId(f"{fill.instrument_id}-{fill.strategy_id}")
 
+    cpdef bint _is_duplicate_fill(self, Order order, OrderFilled fill):
+        cdef tuple key = _fill_key(fill)
+        cdef OrderEvent event
+        for event in order.events_c():
+            if isinstance(event, OrderFilled) and _fill_key(<OrderFilled>event) == key:
+                return True
+
+        return False
+
+    cpdef LiquiditySide _infer_liquidity_side(self, Order order, OrderFilled fill):
+        # Orders which can only take liquidity
+        if order.order_type in (
+            OrderType.MARKET,
+            OrderType.STOP_MARKET,
+            OrderType.MARKET_IF_TOUCHED,
+            OrderType.TRAILING_STOP_MARKET,
+        ):
+            return LiquiditySide.TAKER
+
+        if order.is_post_only:
+            return LiquiditySide.MAKER
+
+        # Price improvement on the limit price only occurs when crossing the book
+        cdef Price price = order.price if order.has_price_c() else None
+        if price is not None:
+            if (order.side == OrderSide.BUY and fill.last_px < price) or (
+                order.side == OrderSide.SELL and fill.last_px > price
+            ):
+                return LiquiditySide.TAKER
+
+        # Compare the fill price against the resting book state
+        cdef Price best_bid = None
+        cdef Price best_ask = None
+        cdef OrderBook book = self._cache.order_book(fill.instrument_id)
+        cdef QuoteTick quote
+        if book is not None:
+            best_bid = book.best_bid_price()
+            best_ask = book.best_ask_price()
+        else:
+            quote = self._cache.quote_tick(fill.instrument_id)
+            if quote is not None:
+                best_bid = quote.bid_price
+                best_ask = quote.ask_price
+
+        if order.side == OrderSide.BUY:
+            if best_ask is not None and fill.last_px >= best_ask:
+                return LiquiditySide.TAKER
+            if best_bid is not None and fill.last_px <= best_bid:
+                return LiquiditySide.MAKER
+        else:
+            if best_bid is not None and fill.last_px <= best_bid:
+                return LiquiditySide.TAKER
+            if best_ask is not None and fill.last_px >= best_ask:
+                return LiquiditySide.MAKER
+
+        self._log.warning(
+            f"Cannot infer liquidity side for {fill!r}: "
+            "fill price was within the spread or no book state available.",
+        )
+        return LiquiditySide.NO_LIQUIDITY_SIDE
+
     cpdef void _apply_event_to_order(self, Order order, OrderEvent event):
         try:
             order.apply(event)
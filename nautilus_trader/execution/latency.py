@@ -0,0 +1,413 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Provides latency instrumentation across the order lifecycle.
+"""
+
+from __future__ import annotations
+
+import math
+from bisect import bisect_left
+from dataclasses import dataclass
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.execution.messages import SubmitOrderList
+from nautilus_trader.execution.messages import TradingCommand
+from nautilus_trader.model.events import OrderAccepted
+from nautilus_trader.model.events import OrderCanceled
+from nautilus_trader.model.events import OrderDenied
+from nautilus_trader.model.events import OrderEvent
+from nautilus_trader.model.events import OrderExpired
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderRejected
+from nautilus_trader.model.events import OrderSubmitted
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import Venue
+
+
+# Histogram bucket upper bounds (nanoseconds) on a 1-2-5 scale from 1us to 10s
+_BUCKET_BOUNDS_NS: list[int] = [
+    multiplier * 10**exponent for exponent in range(3, 10) for multiplier in (1, 2, 5)
+] + [10**10]
+
+
+@unique
+class LatencyStage(Enum):
+    """
+    Represents a stage of the order lifecycle between two timestamps.
+    """
+
+    ENGINE_RECEIPT = "ENGINE_RECEIPT"
+    """From the submit command being created to its receipt by the execution engine."""
+    CLIENT_SEND = "CLIENT_SEND"
+    """From receipt by the execution engine to the order being sent by the execution client."""
+    VENUE_ACK = "VENUE_ACK"
+    """From the order being sent to its acceptance by the venue."""
+    FILL = "FILL"
+    """From acceptance by the venue to the first fill."""
+
+
+class LatencyHistogram:
+    """
+    Provides a histogram of latencies on fixed bucket bounds from 1us to 10s.
+
+    Latencies above the largest bound are counted in a final overflow bucket.
+    """
+
+    def __init__(self) -> None:
+        self._counts: list[int] = [0] * (len(_BUCKET_BOUNDS_NS) + 1)
+        self._count = 0
+        self._total_ns = 0
+        self._min_ns: int | None = None
+        self._max_ns: int | None = None
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"count={self._count}, "
+            f"min_ns={self._min_ns}, "
+            f"max_ns={self._max_ns}, "
+            f"mean_ns={self.mean_ns})"
+        )
+
+    @property
+    def count(self) -> int:
+        """
+        Return the count of latencies recorded.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._count
+
+    @property
+    def min_ns(self) -> int | None:
+        """
+        Return the minimum latency recorded (nanoseconds).
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        return self._min_ns
+
+    @property
+    def max_ns(self) -> int | None:
+        """
+        Return the maximum latency recorded (nanoseconds).
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        return self._max_ns
+
+    @property
+    def mean_ns(self) -> float | None:
+        """
+        Return the mean latency recorded (nanoseconds).
+
+        Returns
+        -------
+        float or ``None``
+
+        """
+        if self._count == 0:
+            return None
+        return self._total_ns / self._count
+
+    def record(self, latency_ns: int) -> None:
+        """
+        Record the given latency.
+
+        Negative latencies (from clock skew between the host and a venue) are
+        recorded as zero.
+
+        Parameters
+        ----------
+        latency_ns : int
+            The latency to record (nanoseconds).
+
+        """
+        latency_ns = max(latency_ns, 0)
+        self._counts[bisect_left(_BUCKET_BOUNDS_NS, latency_ns)] += 1
+        self._count += 1
+        self._total_ns += latency_ns
+        if self._min_ns is None or latency_ns < self._min_ns:
+            self._min_ns = latency_ns
+        if self._max_ns is None or latency_ns > self._max_ns:
+            self._max_ns = latency_ns
+
+    def percentile(self, q: float) -> int | None:
+        """
+        Return the approximate latency at the given percentile.
+
+        The result is the upper bound of the bucket containing the percentile
+        (capped at the maximum latency recorded).
+
+        Parameters
+        ----------
+        q : float
+            The percentile in the range [0, 100].
+
+        Returns
+        -------
+        int or ``None``
+
+        Raises
+        ------
+        ValueError
+            If `q` is not in range [0, 100].
+
+        """
+        if not 0 <= q <= 100:
+            raise ValueError(f"percentile `q` was not in range [0, 100], was {q}")
+        if self._count == 0:
+            return None
+
+        rank = max(1, math.ceil(self._count * q / 100))  # Nearest rank
+        cumulative = 0
+        for i, count in enumerate(self._counts):
+            cumulative += count
+            if cumulative >= rank:
+                if i == len(_BUCKET_BOUNDS_NS):
+                    return self._max_ns  # Overflow bucket
+                return min(_BUCKET_BOUNDS_NS[i], self._max_ns)
+
+        return self._max_ns  # Unreachable for a consistent histogram
+
+    def buckets(self) -> dict[int | None, int]:
+        """
+        Return the non-empty bucket counts keyed by bucket upper bound (nanoseconds).
+
+        The overflow bucket is keyed by ``None``.
+
+        Returns
+        -------
+        dict[int | None, int]
+
+        """
+        bounds: list[int | None] = [*_BUCKET_BOUNDS_NS, None]
+        return {bound: count for bound, count in zip(bounds, self._counts) if count}
+
+    def _merge(self, other: LatencyHistogram) -> None:
+        for i, count in enumerate(other._counts):
+            self._counts[i] += count
+        self._count += other._count
+        self._total_ns += other._total_ns
+        if other._min_ns is not None and (self._min_ns is None or other._min_ns < self._min_ns):
+            self._min_ns = other._min_ns
+        if other._max_ns is not None and (self._max_ns is None or other._max_ns > self._max_ns):
+            self._max_ns = other._max_ns
+
+    def to_dict(self) -> dict[str, object]:
+        """
+        Return a dictionary representation of the histogram.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return {
+            "count": self._count,
+            "min_ns": self._min_ns,
+            "max_ns": self._max_ns,
+            "mean_ns": self.mean_ns,
+            "p50_ns": self.percentile(50),
+            "p90_ns": self.percentile(90),
+            "p99_ns": self.percentile(99),
+            "buckets": {
+                ("overflow" if bound is None else str(bound)): count
+                for bound, count in self.buckets().items()
+            },
+        }
+
+
+@dataclass
+class _OrderTimestamps:
+    venue: Venue
+    strategy_id: StrategyId
+    created_ns: int
+    engine_received_ns: int
+    sent_ns: int | None = None
+    accepted_ns: int | None = None
+
+
+class LatencyTracker:
+    """
+    Provides latency tracking across the order lifecycle, with histograms per
+    stage for each venue and strategy.
+
+    Timestamps are taken at submit command creation, receipt by the execution
+    engine, the order being sent by the execution client (the `OrderSubmitted`
+    event), acceptance by the venue and the first fill. Venue timestamps are as
+    reported by the venue, so the ``VENUE_ACK`` and ``FILL`` stages include any
+    clock offset between the host and the venue.
+
+    Orders are no longer tracked once filled or otherwise completed.
+    """
+
+    def __init__(self) -> None:
+        self._orders: dict[ClientOrderId, _OrderTimestamps] = {}
+        self._histograms: dict[tuple[Venue, StrategyId], dict[LatencyStage, LatencyHistogram]] = {}
+
+    @property
+    def tracked_order_count(self) -> int:
+        """
+        Return the count of orders currently being tracked.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._orders)
+
+    def on_command(self, command: TradingCommand, ts_received: int) -> None:
+        """
+        Handle the given command received by the execution engine.
+
+        Only submit commands start tracking an order.
+
+        Parameters
+        ----------
+        command : TradingCommand
+            The command received.
+        ts_received : int
+            The UNIX timestamp (nanoseconds) when the engine received the command.
+
+        """
+        if isinstance(command, SubmitOrder):
+            orders = [command.order]
+        elif isinstance(command, SubmitOrderList):
+            orders = command.order_list.orders
+        else:
+            return  # Only tracking submissions
+
+        venue = command.instrument_id.venue
+        for order in orders:
+            self._orders[order.client_order_id] = _OrderTimestamps(
+                venue=venue,
+                strategy_id=command.strategy_id,
+                created_ns=command.ts_init,
+                engine_received_ns=ts_received,
+            )
+            self._record(venue, command.strategy_id, LatencyStage.ENGINE_RECEIPT, ts_received - command.ts_init)
+
+    def on_event(self, event: OrderEvent) -> None:
+        """
+        Handle the given order event processed by the execution engine.
+
+        Parameters
+        ----------
+        event : OrderEvent
+            The order event processed.
+
+        """
+        timestamps = self._orders.get(event.client_order_id)
+        if timestamps is None:
+            return  # Not tracked
+
+        if isinstance(event, OrderSubmitted):
+            timestamps.sent_ns = event.ts_event
+            self._record_stage(timestamps, LatencyStage.CLIENT_SEND, timestamps.engine_received_ns, event.ts_event)
+        elif isinstance(event, OrderAccepted):
+            timestamps.accepted_ns = event.ts_event
+            self._record_stage(timestamps, LatencyStage.VENUE_ACK, timestamps.sent_ns, event.ts_event)
+        elif isinstance(event, OrderFilled):
+            if timestamps.accepted_ns is None:
+                # Filled on arrival without an acknowledgement
+                self._record_stage(timestamps, LatencyStage.VENUE_ACK, timestamps.sent_ns, event.ts_event)
+            self._record_stage(timestamps, LatencyStage.FILL, timestamps.accepted_ns, event.ts_event)
+            self._orders.pop(event.client_order_id, None)
+        elif isinstance(event, (OrderDenied, OrderRejected, OrderCanceled, OrderExpired)):
+            self._orders.pop(event.client_order_id, None)
+
+    def histograms(
+        self,
+        venue: Venue | None = None,
+        strategy_id: StrategyId | None = None,
+    ) -> dict[LatencyStage, LatencyHistogram]:
+        """
+        Return the latency histograms per stage, merged across all venues and
+        strategies matching the given filters.
+
+        Parameters
+        ----------
+        venue : Venue, optional
+            The venue filter.
+        strategy_id : StrategyId, optional
+            The strategy ID filter.
+
+        Returns
+        -------
+        dict[LatencyStage, LatencyHistogram]
+
+        """
+        merged: dict[LatencyStage, LatencyHistogram] = {}
+        for (key_venue, key_strategy_id), stages in self._histograms.items():
+            if venue is not None and key_venue != venue:
+                continue
+            if strategy_id is not None and key_strategy_id != strategy_id:
+                continue
+            for stage, histogram in stages.items():
+                merged.setdefault(stage, LatencyHistogram())._merge(histogram)
+
+        return merged
+
+    def to_dict(self) -> dict[str, dict[str, dict[str, object]]]:
+        """
+        Return a dictionary export of the latency histograms per stage, keyed by
+        venue and strategy ID in the form "{venue}:{strategy_id}".
+
+        Returns
+        -------
+        dict[str, dict[str, dict[str, object]]]
+
+        """
+        return {
+            f"{venue}:{strategy_id}": {stage.value: histogram.to_dict() for stage, histogram in stages.items()}
+            for (venue, strategy_id), stages in self._histograms.items()
+        }
+
+    def reset(self) -> None:
+        """
+        Reset the tracker by clearing all tracked orders and histograms.
+        """
+        self._orders.clear()
+        self._histograms.clear()
+
+    def _record_stage(
+        self,
+        timestamps: _OrderTimestamps,
+        stage: LatencyStage,
+        start_ns: int | None,
+        end_ns: int,
+    ) -> None:
+        if start_ns is None:
+            return  # Prior stage not observed
+        self._record(timestamps.venue, timestamps.strategy_id, stage, end_ns - start_ns)
+
+    def _record(self, venue: Venue, strategy_id: StrategyId, stage: LatencyStage, latency_ns: int) -> None:
+        stages = self._histograms.setdefault((venue, strategy_id), {})
+        stages.setdefault(stage, LatencyHistogram()).record(latency_ns)
@@ -57,6 +57,7 @@ cdef class MatchingCore:
     cdef void set_bid_raw(self, int64_t bid_raw)
     cdef void set_ask_raw(self, int64_t ask_raw)
     cdef void set_last_raw(self, int64_t last_raw)
+    cdef void set_price_increment(self, Price price_increment)
 
     cpdef void reset(self)
     cpdef void add_order(self, Order order)
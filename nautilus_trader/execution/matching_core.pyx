@@ -189,6 +189,11 @@ cdef class MatchingCore:
         self.is_last_initialized = True
         self.last_raw = last_raw
 
+    cdef void set_price_increment(self, Price price_increment):
+        # Raw prices are fixed-point so the market state remains valid
+        self._price_increment = price_increment
+        self._price_precision = price_increment.precision
+
     cpdef void reset(self):
         self._orders.clear()
         self._orders_bid.clear()
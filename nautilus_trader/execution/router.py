@@ -0,0 +1,443 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import ROUND_DOWN
+from decimal import Decimal
+
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.algorithm import ExecAlgorithm
+from nautilus_trader.execution.config import SmartOrderRouterConfig
+from nautilus_trader.execution.messages import CancelOrder
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.events import OrderEvent
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.orders import Order
+
+
+class SmartOrderRouter(ExecAlgorithm):
+    """
+    Provides a smart order router which splits a primary order across venues.
+
+    The displayed liquidity for each configured instrument (one per venue) is ranked
+    by its fee-adjusted price, and the primary order quantity is allocated greedily
+    from the best effective price outwards. A child order is spawned for every venue
+    other than the primary orders own venue, with any residual quantity remaining on
+    the primary order. If no displayed liquidity is available then the primary order
+    is submitted unchanged.
+
+    Fills from all child orders are aggregated back onto the primary order, with the
+    total filled quantity and volume-weighted average price available per route.
+
+    Only ``MARKET`` and ``LIMIT`` primary orders are supported. For ``LIMIT`` orders,
+    liquidity priced through the limit price is not considered.
+
+    Parameters
+    ----------
+    config : SmartOrderRouterConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If `config.instrument_ids` is empty.
+
+    """
+
+    def __init__(self, config: SmartOrderRouterConfig) -> None:
+        PyCondition.not_empty(config.instrument_ids, "config.instrument_ids")
+        super().__init__(config)
+
+        self.instrument_ids: list[InstrumentId] = [
+            InstrumentId.from_str(i) for i in config.instrument_ids
+        ]
+        self.use_order_books = config.use_order_books
+        self.max_book_levels = config.max_book_levels
+
+        self._fee_rates: dict[Venue, Decimal] = {
+            Venue(k): Decimal(str(v)) for k, v in (config.fee_rates or {}).items()
+        }
+        self._routes: dict[ClientOrderId, _Route] = {}
+
+    def on_start(self) -> None:
+        """
+        Actions to be performed when the algorithm component is started.
+        """
+        for instrument_id in self.instrument_ids:
+            if self.use_order_books:
+                self.subscribe_order_book_deltas(instrument_id)
+            else:
+                self.subscribe_quote_ticks(instrument_id)
+
+    def on_stop(self) -> None:
+        """
+        Actions to be performed when the algorithm component is stopped.
+        """
+        for instrument_id in self.instrument_ids:
+            if self.use_order_books:
+                self.unsubscribe_order_book_deltas(instrument_id)
+            else:
+                self.unsubscribe_quote_ticks(instrument_id)
+
+    def on_reset(self) -> None:
+        """
+        Actions to be performed when the algorithm component is reset.
+        """
+        self._routes.clear()
+
+    def fee_rate(self, instrument: Instrument) -> Decimal:
+        """
+        Return the fee rate applied to the displayed liquidity of the given instrument.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument for the fee rate.
+
+        Returns
+        -------
+        Decimal
+
+        """
+        return self._fee_rates.get(instrument.id.venue, instrument.taker_fee)
+
+    def allocate(
+        self,
+        side: OrderSide,
+        quantity: Quantity,
+        limit_price: Price | None = None,
+    ) -> dict[InstrumentId, Quantity]:
+        """
+        Allocate the given quantity across venues based on displayed liquidity and fees.
+
+        Liquidity is taken greedily from the best fee-adjusted price outwards. Any
+        quantity in excess of the total displayed liquidity is allocated to the venue
+        with the best effective price.
+
+        Parameters
+        ----------
+        side : OrderSide {``BUY``, ``SELL``}
+            The order side to allocate for.
+        quantity : Quantity
+            The total quantity to allocate.
+        limit_price : Price, optional
+            The limit price, liquidity priced through this will not be considered.
+
+        Returns
+        -------
+        dict[InstrumentId, Quantity]
+            The allocations ordered from the best effective price (empty if no liquidity).
+
+        """
+        PyCondition.not_equal(side, OrderSide.NO_ORDER_SIDE, "side", "NO_ORDER_SIDE")
+
+        candidates: list[tuple[Decimal, int, Instrument, Decimal]] = []
+        for rank, instrument_id in enumerate(self.instrument_ids):
+            instrument = self.cache.instrument(instrument_id)
+            if instrument is None:
+                self.log.warning(f"Cannot route to {instrument_id}: instrument not found.")
+                continue
+
+            fee_rate = self.fee_rate(instrument)
+            for price, size in self._displayed_levels(instrument, side):
+                if limit_price is not None:
+                    if side == OrderSide.BUY and price > limit_price:
+                        break
+                    if side == OrderSide.SELL and price < limit_price:
+                        break
+                if side == OrderSide.BUY:
+                    effective_px = price.as_decimal() * (1 + fee_rate)
+                else:
+                    effective_px = -price.as_decimal() * (1 - fee_rate)
+                candidates.append((effective_px, rank, instrument, size))
+
+        if not candidates:
+            return {}
+
+        # Best effective price first (sell prices are negated), ties broken by configured order
+        candidates.sort(key=lambda c: (c[0], c[1]))
+
+        remaining: Decimal = quantity.as_decimal()
+        allocated: dict[InstrumentId, Decimal] = {}
+        for _, _, instrument, size in candidates:
+            if remaining <= 0:
+                break
+            fill_qty = self._round_down(min(size, remaining), instrument.size_precision)
+            if fill_qty <= 0:
+                continue
+            instrument_id = instrument.id
+            allocated[instrument_id] = allocated.get(instrument_id, Decimal(0)) + fill_qty
+            remaining -= fill_qty
+
+        if remaining > 0:
+            best_id = candidates[0][2].id
+            allocated[best_id] = allocated.get(best_id, Decimal(0)) + remaining
+
+        return {
+            instrument_id: Quantity(qty, quantity.precision)
+            for instrument_id, qty in allocated.items()
+        }
+
+    def filled_qty(self, primary_id: ClientOrderId) -> Quantity | None:
+        """
+        Return the total filled quantity across all venues for the given primary order.
+
+        Parameters
+        ----------
+        primary_id : ClientOrderId
+            The primary (original) client order ID.
+
+        Returns
+        -------
+        Quantity or ``None``
+
+        """
+        return self.cache.exec_spawn_total_filled_qty(primary_id)
+
+    def avg_px(self, primary_id: ClientOrderId) -> Decimal | None:
+        """
+        Return the volume-weighted average fill price across all venues for the given
+        primary order.
+
+        Parameters
+        ----------
+        primary_id : ClientOrderId
+            The primary (original) client order ID.
+
+        Returns
+        -------
+        Decimal or ``None``
+            Will be ``None`` if the route is unknown or has no fills.
+
+        """
+        route = self._routes.get(primary_id)
+        if route is None or not route.filled_qty:
+            return None
+        return route.notional / route.filled_qty
+
+    def venue_fills(self, primary_id: ClientOrderId) -> dict[Venue, Decimal]:
+        """
+        Return the filled quantity per venue for the given primary order.
+
+        Parameters
+        ----------
+        primary_id : ClientOrderId
+            The primary (original) client order ID.
+
+        Returns
+        -------
+        dict[Venue, Decimal]
+
+        """
+        route = self._routes.get(primary_id)
+        if route is None:
+            return {}
+        return dict(route.venue_fills)
+
+    def on_order(self, order: Order) -> None:
+        """
+        Actions to be performed when running and receives an order.
+
+        Parameters
+        ----------
+        order : Order
+            The order to be handled.
+
+        Warnings
+        --------
+        System method (not intended to be called by user code).
+
+        """
+        PyCondition.not_in(
+            order.client_order_id,
+            self._routes,
+            "order.client_order_id",
+            "self._routes",
+        )
+        self.log.info(repr(order), LogColor.CYAN)
+
+        if order.order_type not in (OrderType.MARKET, OrderType.LIMIT):
+            self.log.error(
+                f"Cannot route order: only implemented for market and limit orders, "
+                f"{order.order_type=}.",
+            )
+            return
+
+        limit_price = order.price if order.order_type == OrderType.LIMIT else None
+        allocations = self.allocate(order.side, order.quantity, limit_price)
+        if not allocations:
+            self.log.warning(
+                f"No displayed liquidity to route {order.client_order_id}, "
+                f"submitting to {order.instrument_id.venue}.",
+            )
+            self.submit_order(order)
+            return
+
+        self.log.info(f"Order routing allocations: {allocations}.", LogColor.BLUE)
+        self._routes[order.client_order_id] = _Route()
+
+        is_primary_allocated = order.instrument_id in allocations
+        for instrument_id, quantity in allocations.items():
+            if instrument_id == order.instrument_id:
+                continue  # Remains on the primary order
+            # The primary order quantity cannot be reduced to zero
+            reduce_primary = quantity < order.quantity
+            spawned_order = self._spawn(order, instrument_id, quantity, reduce_primary)
+            if spawned_order is None:
+                continue
+            self.submit_order(spawned_order)
+
+        if is_primary_allocated:
+            self.submit_order(order)
+            return
+
+        # Primary venue received no allocation, the primary order is closed locally
+        self.execute(
+            CancelOrder(
+                trader_id=order.trader_id,
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=None,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            ),
+        )
+
+    def on_order_event(self, event: OrderEvent) -> None:
+        """
+        Actions to be performed when running and receives an order event.
+
+        Parameters
+        ----------
+        event : OrderEvent
+            The order event to be handled.
+
+        Warnings
+        --------
+        System method (not intended to be called by user code).
+
+        """
+        order = self.cache.order(event.client_order_id)
+        if order is None:
+            return
+
+        primary_id = order.exec_spawn_id or order.client_order_id
+        route = self._routes.get(primary_id)
+        if route is None or route.is_completed:
+            return
+
+        if isinstance(event, OrderFilled):
+            route.on_fill(event)
+
+        if not order.is_closed:
+            return
+
+        if all(o.is_closed for o in self.cache.orders_for_exec_spawn(primary_id)):
+            route.is_completed = True
+            self.log.info(
+                f"Completed routing for {primary_id}: "
+                f"filled_qty={self.filled_qty(primary_id)}, "
+                f"avg_px={self.avg_px(primary_id)}, "
+                f"venue_fills={route.venue_fills}.",
+                LogColor.BLUE,
+            )
+
+    def _displayed_levels(
+        self,
+        instrument: Instrument,
+        side: OrderSide,
+    ) -> list[tuple[Price, Decimal]]:
+        # Liquidity available to an order of the given side (the opposite side of the book)
+        if self.use_order_books:
+            book = self.cache.order_book(instrument.id)
+            if book is None:
+                return []
+            levels = book.asks() if side == OrderSide.BUY else book.bids()
+            return [
+                (level.price, instrument.make_qty(level.size()).as_decimal())
+                for level in levels[: self.max_book_levels]
+            ]
+
+        quote = self.cache.quote_tick(instrument.id)
+        if quote is None:
+            return []
+        if side == OrderSide.BUY:
+            return [(quote.ask_price, quote.ask_size.as_decimal())]
+        return [(quote.bid_price, quote.bid_size.as_decimal())]
+
+    def _spawn(
+        self,
+        primary: Order,
+        instrument_id: InstrumentId,
+        quantity: Quantity,
+        reduce_primary: bool,
+    ) -> Order | None:
+        if primary.order_type == OrderType.MARKET:
+            return self.spawn_market(
+                primary=primary,
+                quantity=quantity,
+                time_in_force=primary.time_in_force,
+                reduce_only=primary.is_reduce_only,
+                tags=primary.tags,
+                reduce_primary=reduce_primary,
+                instrument_id=instrument_id,
+            )
+
+        instrument = self.cache.instrument(instrument_id)
+        if instrument is None:  # pragma: no cover (already checked on allocation)
+            self.log.error(f"Cannot spawn order: instrument {instrument_id} not found.")
+            return None
+
+        return self.spawn_limit(
+            primary=primary,
+            quantity=quantity,
+            price=instrument.make_price(primary.price),
+            time_in_force=primary.time_in_force,
+            expire_time=primary.expire_time,
+            post_only=primary.is_post_only,
+            reduce_only=primary.is_reduce_only,
+            tags=primary.tags,
+            reduce_primary=reduce_primary,
+            instrument_id=instrument_id,
+        )
+
+    def _round_down(self, amount: Decimal, precision: int) -> Decimal:
+        return amount.quantize(Decimal(f"1e-{precision}"), rounding=ROUND_DOWN)
+
+
+class _Route:
+    def __init__(self) -> None:
+        self.filled_qty = Decimal(0)
+        self.notional = Decimal(0)
+        self.venue_fills: dict[Venue, Decimal] = {}
+        self.is_completed = False
+
+    def on_fill(self, fill: OrderFilled) -> None:
+        last_qty = fill.last_qty.as_decimal()
+        self.filled_qty += last_qty
+        self.notional += fill.last_px.as_decimal() * last_qty
+        venue = fill.instrument_id.venue
+        self.venue_fills[venue] = self.venue_fills.get(venue, Decimal(0)) + last_qty
@@ -161,6 +161,33 @@ class LiveExecClientConfig(NautilusConfig, frozen=True):
     routing: RoutingConfig = RoutingConfig()
 
 
+class ControlServerConfig(NautilusConfig, frozen=True):
+    """
+    Configuration for the ``NodeControlServer`` gRPC control-plane service.
+
+    Parameters
+    ----------
+    host : str, default "127.0.0.1"
+        The host address to bind the server to.
+    port : PositiveInt, default 50051
+        The port to bind the server to.
+    auth_token : str, optional
+        The bearer token clients must send in the `authorization` metadata.
+        If ``None`` then requests are not authenticated (only bind to a trusted interface).
+    ssl_certfile : str, optional
+        The path to the PEM encoded server certificate chain (enables TLS).
+    ssl_keyfile : str, optional
+        The path to the PEM encoded server private key (enables TLS).
+
+    """
+
+    host: str = "127.0.0.1"
+    port: PositiveInt = 50051
+    auth_token: str | None = None
+    ssl_certfile: str | None = None
+    ssl_keyfile: str | None = None
+
+
 class ControllerConfig(NautilusConfig, kw_only=True, frozen=True):
     """
     The base model for all trading strategy configurations.
@@ -210,6 +237,9 @@ class TradingNodeConfig(NautilusKernelConfig, frozen=True):
         The execution client configurations.
     heartbeat_interval : PositiveFloat, optional
         The heartbeat interval (seconds) to use for trading node health.
+    control_server : ControlServerConfig, optional
+        The configuration for the gRPC control-plane server (requires `grpcio`).
+        If ``None`` then no control server will be started.
 
     """
 
@@ -221,3 +251,4 @@ class TradingNodeConfig(NautilusKernelConfig, frozen=True):
     data_clients: dict[str, LiveDataClientConfig] = {}
     exec_clients: dict[str, LiveExecClientConfig] = {}
     heartbeat_interval: PositiveFloat | None = None
+    control_server: ControlServerConfig | None = None
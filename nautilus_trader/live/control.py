@@ -0,0 +1,490 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+"""
+Provides an optional gRPC control-plane service for managing a running node.
+
+The service is exposed as ``nautilus_trader.live.NodeControl`` with the unary
+methods listed in `CONTROL_METHODS`. Requests and responses are JSON objects
+(gRPC content-subtype ``json``), so tooling in any language can call the
+service with a JSON codec and no generated stubs.
+
+"""
+
+from __future__ import annotations
+
+import hmac
+from collections.abc import Callable
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.cache.base import CacheFacade
+from nautilus_trader.common.component import Clock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.config import ControlServerConfig
+from nautilus_trader.model.enums import TradingState
+from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.functions import trading_state_from_str
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.risk.messages import SetTradingState
+from nautilus_trader.trading.strategy import Strategy
+from nautilus_trader.trading.trader import Trader
+
+
+CONTROL_SERVICE_NAME = "nautilus_trader.live.NodeControl"
+
+CONTROL_METHODS = (
+    "ListStrategies",
+    "StartStrategy",
+    "StopStrategy",
+    "FlattenPositions",
+    "QueryCache",
+    "SetTradingState",
+)
+
+
+class NodeControlService:
+    """
+    Provides the node control operations exposed by the control-plane server.
+
+    Each operation takes primitive arguments (strings for identifiers) and
+    returns a JSON serializable result, so the service can be called directly
+    or through the `NodeControlServer` transport.
+
+    Parameters
+    ----------
+    trader : Trader
+        The trader for the node.
+    cache : CacheFacade
+        The cache for the node.
+    msgbus : MessageBus
+        The message bus for the node.
+    clock : Clock
+        The clock for the node.
+
+    """
+
+    QUERIES = (
+        "orders",
+        "orders_open",
+        "orders_closed",
+        "positions",
+        "positions_open",
+        "positions_closed",
+        "accounts",
+        "instruments",
+    )
+
+    def __init__(
+        self,
+        trader: Trader,
+        cache: CacheFacade,
+        msgbus: MessageBus,
+        clock: Clock,
+    ) -> None:
+        PyCondition.not_none(trader, "trader")
+        PyCondition.not_none(cache, "cache")
+        PyCondition.not_none(msgbus, "msgbus")
+        PyCondition.not_none(clock, "clock")
+
+        self._trader = trader
+        self._cache = cache
+        self._msgbus = msgbus
+        self._clock = clock
+        self._log = Logger(type(self).__name__)
+
+    def list_strategies(self) -> list[dict[str, str]]:
+        """
+        Return the strategies registered with the trader and their states.
+
+        Returns
+        -------
+        list[dict[str, str]]
+
+        """
+        return [
+            {"strategy_id": strategy_id.value, "state": state}
+            for strategy_id, state in self._trader.strategy_states().items()
+        ]
+
+    def start_strategy(self, strategy_id: str) -> dict[str, str]:
+        """
+        Start the strategy with the given ID.
+
+        Parameters
+        ----------
+        strategy_id : str
+            The strategy ID to start.
+
+        Returns
+        -------
+        dict[str, str]
+
+        Raises
+        ------
+        KeyError
+            If the strategy is not registered with the trader.
+
+        """
+        strategy = self._strategy(strategy_id)
+        self._log.info(f"Starting {strategy.id} from control request.", LogColor.BLUE)
+        self._trader.start_strategy(strategy.id)
+        return {"strategy_id": strategy.id.value, "state": strategy.state.name}
+
+    def stop_strategy(self, strategy_id: str) -> dict[str, str]:
+        """
+        Stop the strategy with the given ID.
+
+        Parameters
+        ----------
+        strategy_id : str
+            The strategy ID to stop.
+
+        Returns
+        -------
+        dict[str, str]
+
+        Raises
+        ------
+        KeyError
+            If the strategy is not registered with the trader.
+
+        """
+        strategy = self._strategy(strategy_id)
+        self._log.info(f"Stopping {strategy.id} from control request.", LogColor.BLUE)
+        self._trader.stop_strategy(strategy.id)
+        return {"strategy_id": strategy.id.value, "state": strategy.state.name}
+
+    def flatten_positions(
+        self,
+        strategy_id: str | None = None,
+        instrument_id: str | None = None,
+        cancel_orders: bool = True,
+    ) -> dict[str, int]:
+        """
+        Close all open positions, optionally filtered by strategy and/or instrument.
+
+        Positions are closed by the strategy which owns them, so the closing
+        orders pass through the normal risk checks.
+
+        Parameters
+        ----------
+        strategy_id : str, optional
+            The strategy ID filter (if ``None`` then all strategies).
+        instrument_id : str, optional
+            The instrument ID filter (if ``None`` then all instruments).
+        cancel_orders : bool, default True
+            If open orders for the same filters should be canceled first.
+
+        Returns
+        -------
+        dict[str, int]
+            The counts of orders canceled and positions closed.
+
+        Raises
+        ------
+        KeyError
+            If `strategy_id` is not registered with the trader.
+
+        """
+        strategies = [self._strategy(strategy_id)] if strategy_id else self._trader.strategies()
+        instrument = InstrumentId.from_str(instrument_id) if instrument_id else None
+
+        orders_canceled = 0
+        positions_closed = 0
+        for strategy in strategies:
+            if cancel_orders:
+                orders = self._cache.orders_open(instrument_id=instrument, strategy_id=strategy.id)
+                if orders:
+                    strategy.cancel_orders(orders)
+                    orders_canceled += len(orders)
+
+            for position in self._cache.positions_open(instrument_id=instrument, strategy_id=strategy.id):
+                strategy.close_position(position, tags="CONTROL-FLATTEN")
+                positions_closed += 1
+
+        self._log.warning(
+            f"Flattening from control request: "
+            f"{orders_canceled} order(s) canceled, {positions_closed} position(s) closing.",
+        )
+
+        return {"orders_canceled": orders_canceled, "positions_closed": positions_closed}
+
+    def query_cache(
+        self,
+        query: str,
+        venue: str | None = None,
+        instrument_id: str | None = None,
+        strategy_id: str | None = None,
+    ) -> list[dict[str, Any]]:
+        """
+        Query the cache, returning the matching objects as dictionaries.
+
+        Parameters
+        ----------
+        query : str
+            The query to run, one of `NodeControlService.QUERIES`.
+        venue : str, optional
+            The venue filter.
+        instrument_id : str, optional
+            The instrument ID filter (not applicable to `accounts` or `instruments`).
+        strategy_id : str, optional
+            The strategy ID filter (not applicable to `accounts` or `instruments`).
+
+        Returns
+        -------
+        list[dict[str, Any]]
+
+        Raises
+        ------
+        ValueError
+            If `query` is not a supported query.
+
+        """
+        PyCondition.is_in(query, self.QUERIES, "query", "QUERIES")
+
+        venue_filter = Venue(venue) if venue else None
+
+        if query == "accounts":
+            return [
+                AccountState.to_dict(account.last_event)
+                for account in self._cache.accounts()
+                if venue_filter is None or account.id.get_issuer() == venue_filter.value
+            ]
+        if query == "instruments":
+            return [type(i).to_dict(i) for i in self._cache.instruments(venue=venue_filter)]
+
+        query_method = getattr(self._cache, query)
+        results = query_method(
+            venue=venue_filter,
+            instrument_id=InstrumentId.from_str(instrument_id) if instrument_id else None,
+            strategy_id=StrategyId(strategy_id) if strategy_id else None,
+        )
+        return [obj.to_dict() for obj in results]
+
+    def set_trading_state(self, state: str, requested_by: str | None = None) -> dict[str, str]:
+        """
+        Request the risk engine trading state be changed.
+
+        The change is sent as a `SetTradingState` command, so it is audited
+        in the same way as any other runtime risk configuration change.
+
+        Parameters
+        ----------
+        state : str
+            The trading state to set {``ACTIVE``, ``HALTED``, ``REDUCING``}.
+        requested_by : str, optional
+            The identity of the requester (recorded for audit).
+
+        Returns
+        -------
+        dict[str, str]
+
+        Raises
+        ------
+        ValueError
+            If `state` is not a valid trading state.
+
+        """
+        PyCondition.valid_string(state, "state")
+        state = state.upper()
+        PyCondition.is_in(state, [s.name for s in TradingState], "state", "TradingState")
+
+        command = SetTradingState(
+            trader_id=self._trader.id,
+            state=trading_state_from_str(state),
+            command_id=UUID4(),
+            ts_init=self._clock.timestamp_ns(),
+            requested_by=requested_by,
+        )
+        self._msgbus.send(endpoint="RiskEngine.execute", msg=command)
+
+        return {"command_id": command.id.value, "state": state}
+
+    def _strategy(self, strategy_id: str) -> Strategy:
+        PyCondition.valid_string(strategy_id, "strategy_id")
+
+        for strategy in self._trader.strategies():
+            if strategy.id.value == strategy_id:
+                return strategy
+
+        raise KeyError(f"Strategy {strategy_id} not found")
+
+
+class NodeControlServer:
+    """
+    Provides a gRPC server exposing a `NodeControlService`.
+
+    Requires the optional `grpcio` package. Requests are handled on the nodes
+    event loop, so operations run in the same thread as the rest of the system.
+
+    Parameters
+    ----------
+    service : NodeControlService
+        The service to expose.
+    config : ControlServerConfig
+        The configuration for the server.
+
+    """
+
+    def __init__(
+        self,
+        service: NodeControlService,
+        config: ControlServerConfig,
+    ) -> None:
+        PyCondition.not_none(service, "service")
+        PyCondition.type(config, ControlServerConfig, "config")
+        PyCondition.true(
+            (config.ssl_certfile is None) == (config.ssl_keyfile is None),
+            "both `ssl_certfile` and `ssl_keyfile` must be set to enable TLS",
+        )
+
+        self._service = service
+        self._config = config
+        self._log = Logger(type(self).__name__)
+        self._server: Any = None
+        self._handlers: dict[str, Callable[..., Any]] = {
+            "ListStrategies": service.list_strategies,
+            "StartStrategy": service.start_strategy,
+            "StopStrategy": service.stop_strategy,
+            "FlattenPositions": service.flatten_positions,
+            "QueryCache": service.query_cache,
+            "SetTradingState": service.set_trading_state,
+        }
+
+    @property
+    def is_running(self) -> bool:
+        """
+        Return whether the server is running.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._server is not None
+
+    async def start(self) -> None:
+        """
+        Start the gRPC server.
+
+        Raises
+        ------
+        ImportError
+            If the `grpcio` package is not installed.
+
+        """
+        grpc = _import_grpc()
+
+        self._server = grpc.aio.server()
+        method_handlers = {
+            name: grpc.unary_unary_rpc_method_handler(
+                self._make_handler(grpc, name, handler),
+                request_deserializer=_decode_request,
+                response_serializer=msgspec.json.encode,
+            )
+            for name, handler in self._handlers.items()
+        }
+        self._server.add_generic_rpc_handlers(
+            (grpc.method_handlers_generic_handler(CONTROL_SERVICE_NAME, method_handlers),),
+        )
+
+        address = f"{self._config.host}:{self._config.port}"
+        if self._config.ssl_certfile is not None:
+            with open(self._config.ssl_keyfile, "rb") as f:
+                private_key = f.read()
+            with open(self._config.ssl_certfile, "rb") as f:
+                certificate_chain = f.read()
+            credentials = grpc.ssl_server_credentials(((private_key, certificate_chain),))
+            self._server.add_secure_port(address, credentials)
+        else:
+            self._server.add_insecure_port(address)
+
+        if self._config.auth_token is None:
+            self._log.warning("Control server authentication is disabled.")
+
+        await self._server.start()
+        self._log.info(f"Control server listening on {address}.", LogColor.BLUE)
+
+    async def stop(self, grace: float | None = None) -> None:
+        """
+        Stop the gRPC server.
+
+        Parameters
+        ----------
+        grace : float, optional
+            The grace period (seconds) for in-flight requests to complete.
+
+        """
+        if self._server is None:
+            return
+
+        await self._server.stop(grace)
+        self._server = None
+        self._log.info("Control server stopped.")
+
+    def _make_handler(self, grpc: Any, name: str, handler: Callable[..., Any]) -> Callable:
+        async def rpc(request: dict[str, Any], context: Any) -> dict[str, Any]:
+            if not self._is_authorized(context):
+                await context.abort(grpc.StatusCode.UNAUTHENTICATED, "invalid or missing token")
+
+            self._log.info(f"Control request {name} {request}.")
+            try:
+                return {"result": handler(**request)}
+            except KeyError as e:
+                await context.abort(grpc.StatusCode.NOT_FOUND, str(e))
+            except (TypeError, ValueError) as e:
+                await context.abort(grpc.StatusCode.INVALID_ARGUMENT, str(e))
+
+        return rpc
+
+    def _is_authorized(self, context: Any) -> bool:
+        if self._config.auth_token is None:
+            return True
+
+        metadata = dict(context.invocation_metadata() or ())
+        authorization = metadata.get("authorization") or ""
+        # Constant time comparison so the token cannot be recovered from response timings
+        return hmac.compare_digest(
+            authorization.encode(),
+            f"Bearer {self._config.auth_token}".encode(),
+        )
+
+
+def _decode_request(data: bytes) -> dict[str, Any]:
+    if not data:
+        return {}
+    request = msgspec.json.decode(data)
+    if not isinstance(request, dict):
+        raise ValueError("control request must be a JSON object")
+    return request
+
+
+def _import_grpc() -> Any:
+    try:
+        import grpc
+    except ImportError as e:
+        raise ImportError(
+            "The control server requires the `grpcio` package, "
+            "install via `pip install -U \"nautilus_trader[grpc]\"`",
+        ) from e
+
+    return grpc
@@ -24,6 +24,8 @@ from nautilus_trader.common.enums import LogColor
 from nautilus_trader.config import TradingNodeConfig
 from nautilus_trader.core.correctness import PyCondition
 from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.control import NodeControlServer
+from nautilus_trader.live.control import NodeControlService
 from nautilus_trader.live.factories import LiveDataClientFactory
 from nautilus_trader.live.factories import LiveExecClientFactory
 from nautilus_trader.live.node_builder import TradingNodeBuilder
@@ -92,6 +94,9 @@ class TradingNode:
         self._task_heartbeats: asyncio.Task | None = None
         self._task_position_snapshots: asyncio.Task | None = None
 
+        # Control plane
+        self._control_server: NodeControlServer | None = None
+
     @property
     def trader_id(self) -> TraderId:
         """
@@ -314,6 +319,8 @@ class TradingNode:
                 self._task_position_snapshots = asyncio.create_task(
                     self.snapshot_open_positions(self._config.snapshot_positions_interval),
                 )
+            if self._config.control_server:
+                await self._start_control_server()
 
             await asyncio.gather(*tasks)
         except asyncio.CancelledError as e:
@@ -404,6 +411,19 @@ class TradingNode:
         except RuntimeError as e:
             self.kernel.logger.exception("Error on stop", e)
 
+    async def _start_control_server(self) -> None:
+        service = NodeControlService(
+            trader=self.kernel.trader,
+            cache=self.kernel.cache,
+            msgbus=self.kernel.msgbus,
+            clock=self.kernel.clock,
+        )
+        self._control_server = NodeControlServer(
+            service=service,
+            config=self._config.control_server,
+        )
+        await self._control_server.start()
+
     async def stop_async(self) -> None:
         """
         Stop the trading node gracefully, asynchronously.
@@ -423,6 +443,11 @@ class TradingNode:
             self._task_position_snapshots.cancel()
             self._task_position_snapshots = None
 
+        if self._control_server:
+            self.kernel.logger.info("Stopping control server...")
+            await self._control_server.stop()
+            self._control_server = None
+
         await self.kernel.stop_async()
 
         self._is_running = False
@@ -26,6 +26,8 @@ from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport QuoteTick
 from nautilus_trader.model.data cimport TradeTick
+from nautilus_trader.model.identifiers cimport ClientOrderId
+from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
@@ -72,3 +74,27 @@ cdef class Level:
 
     @staticmethod
     cdef Level from_mem_c(Level_API mem)
+
+
+cdef class OwnOrderBook:
+    cdef dict _bids
+    cdef dict _asks
+    cdef dict _order_levels
+    cdef uint8_t _size_precision
+
+    cdef readonly InstrumentId instrument_id
+    """The order book instrument ID.\n\n:returns: `InstrumentId`"""
+
+    cpdef void update(self, Order order)
+    cpdef void remove(self, ClientOrderId client_order_id)
+    cpdef void clear(self)
+    cpdef bint contains(self, ClientOrderId client_order_id)
+    cpdef int order_count(self)
+    cpdef list orders(self)
+    cpdef list bid_prices(self)
+    cpdef list ask_prices(self)
+    cpdef list orders_at_level(self, OrderSide side, Price price)
+    cpdef Quantity own_qty_at_price(self, OrderSide side, Price price)
+
+    cdef dict _levels(self, OrderSide side)
+    cdef bint _is_resting(self, Order order)
@@ -86,6 +86,7 @@ from nautilus_trader.model.data cimport OrderBookDepth10
 from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.functions cimport book_type_to_str
 from nautilus_trader.model.functions cimport order_side_to_str
+from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
@@ -759,3 +760,244 @@ cdef class Level:
 
         """
         return level_exposure(&self._mem)
+
+
+cdef class OwnOrderBook:
+    """
+    Provides a view of a strategies own resting orders for a single instrument,
+    organized by side and price level.
+
+    Orders are applied with `update` whenever their state changes, an order is
+    held at its price level while it is open and working on the venue, and is
+    removed once closed. Orders within a level are kept in the order they
+    arrived at that price.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the book.
+
+    """
+
+    def __init__(self, InstrumentId instrument_id not None) -> None:
+        self.instrument_id = instrument_id
+        self._bids: dict[Price, dict[ClientOrderId, Order]] = {}
+        self._asks: dict[Price, dict[ClientOrderId, Order]] = {}
+        self._order_levels: dict[ClientOrderId, tuple[OrderSide, Price]] = {}
+        self._size_precision = 0
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"bid_levels={len(self._bids)}, "
+            f"ask_levels={len(self._asks)}, "
+            f"orders={len(self._order_levels)})"
+        )
+
+    cpdef void update(self, Order order):
+        """
+        Update the book with the current state of the given order.
+
+        Resting orders are added at (or moved to) their current price level,
+        any other order is removed from the book.
+
+        Parameters
+        ----------
+        order : Order
+            The order to update with.
+
+        Raises
+        ------
+        ValueError
+            If `order.instrument_id` is not equal to the books instrument ID.
+
+        """
+        Condition.not_none(order, "order")
+        Condition.equal(order.instrument_id, self.instrument_id, "order.instrument_id", "self.instrument_id")
+
+        if not self._is_resting(order):
+            self.remove(order.client_order_id)
+            return
+
+        cdef Price price = order.price
+        cdef tuple current = self._order_levels.get(order.client_order_id)
+        cdef dict levels = self._levels(order.side)
+        if current is not None:
+            if current[0] == order.side and current[1] == price:
+                # Same level, so keep the orders position within the level
+                levels[price][order.client_order_id] = order
+                return
+            self.remove(order.client_order_id)
+
+        cdef dict level = levels.get(price)
+        if level is None:
+            level = {}
+            levels[price] = level
+
+        level[order.client_order_id] = order
+        self._order_levels[order.client_order_id] = (order.side, price)
+        self._size_precision = order.quantity._mem.precision
+
+    cpdef void remove(self, ClientOrderId client_order_id):
+        """
+        Remove the order with the given client order ID from the book (if found).
+
+        Parameters
+        ----------
+        client_order_id : ClientOrderId
+            The client order ID to remove.
+
+        """
+        Condition.not_none(client_order_id, "client_order_id")
+
+        cdef tuple current = self._order_levels.pop(client_order_id, None)
+        if current is None:
+            return  # Not in book
+
+        cdef dict levels = self._levels(current[0])
+        cdef dict level = levels[current[1]]
+        level.pop(client_order_id)
+        if not level:
+            del levels[current[1]]
+
+    cpdef void clear(self):
+        """
+        Clear all orders from the book.
+        """
+        self._bids.clear()
+        self._asks.clear()
+        self._order_levels.clear()
+
+    cpdef bint contains(self, ClientOrderId client_order_id):
+        """
+        Return a value indicating whether the book contains the given client order ID.
+
+        Parameters
+        ----------
+        client_order_id : ClientOrderId
+            The client order ID to check.
+
+        Returns
+        -------
+        bool
+
+        """
+        return client_order_id in self._order_levels
+
+    cpdef int order_count(self):
+        """
+        Return the count of resting orders in the book.
+
+        Returns
+        -------
+        int
+
+        """
+        return len(self._order_levels)
+
+    cpdef list orders(self):
+        """
+        Return all resting orders in the book, bids then asks, from the top of
+        the book on each side.
+
+        Returns
+        -------
+        list[Order]
+
+        """
+        cdef list orders = []
+        cdef Price price
+        for price in self.bid_prices():
+            orders.extend(self._bids[price].values())
+        for price in self.ask_prices():
+            orders.extend(self._asks[price].values())
+        return orders
+
+    cpdef list bid_prices(self):
+        """
+        Return the bid price levels with resting orders (descending).
+
+        Returns
+        -------
+        list[Price]
+
+        """
+        return sorted(self._bids, reverse=True)
+
+    cpdef list ask_prices(self):
+        """
+        Return the ask price levels with resting orders (ascending).
+
+        Returns
+        -------
+        list[Price]
+
+        """
+        return sorted(self._asks)
+
+    cpdef list orders_at_level(self, OrderSide side, Price price):
+        """
+        Return the resting orders at the given side and price level.
+
+        Parameters
+        ----------
+        side : OrderSide {``BUY``, ``SELL``}
+            The order side for the level.
+        price : Price
+            The price for the level.
+
+        Returns
+        -------
+        list[Order]
+
+        """
+        Condition.not_equal(side, OrderSide.NO_ORDER_SIDE, "side", "NO_ORDER_SIDE")
+        Condition.not_none(price, "price")
+
+        cdef dict level = self._levels(side).get(price)
+        if level is None:
+            return []
+        return list(level.values())
+
+    cpdef Quantity own_qty_at_price(self, OrderSide side, Price price):
+        """
+        Return the total leaves quantity of the resting orders at the given
+        side and price level.
+
+        Parameters
+        ----------
+        side : OrderSide {``BUY``, ``SELL``}
+            The order side for the level.
+        price : Price
+            The price for the level.
+
+        Returns
+        -------
+        Quantity
+
+        """
+        Condition.not_equal(side, OrderSide.NO_ORDER_SIDE, "side", "NO_ORDER_SIDE")
+        Condition.not_none(price, "price")
+
+        cdef dict level = self._levels(side).get(price)
+        if level is None:
+            return Quantity.zero_c(self._size_precision)
+
+        cdef uint64_t raw_qty = 0
+        cdef Order order
+        for order in level.values():
+            raw_qty += order.leaves_qty._mem.raw
+
+        return Quantity.from_raw_c(raw_qty, self._size_precision)
+
+    cdef dict _levels(self, OrderSide side):
+        return self._bids if side == OrderSide.BUY else self._asks
+
+    cdef bint _is_resting(self, Order order):
+        if not order.is_open_c() or not order.has_price_c() or order.is_emulated_c():
+            return False
+        if order.has_trigger_price_c():
+            # Conditional limit orders only rest once triggered
+            return order.is_triggered
+        return True
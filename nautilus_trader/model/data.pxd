@@ -43,6 +43,8 @@ from nautilus_trader.model.data cimport OrderBookDeltas
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport TradeId
 from nautilus_trader.model.identifiers cimport Venue
+from nautilus_trader.model.objects cimport Currency
+from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 
@@ -334,6 +336,78 @@ cdef class InstrumentClose(Data):
     cdef dict to_dict_c(InstrumentClose obj)
 
 
+cdef class CashDividend(Data):
+    cdef readonly InstrumentId instrument_id
+    """The dividend instrument ID.\n\n:returns: `InstrumentId`"""
+    cdef readonly Money amount
+    """The dividend amount per share.\n\n:returns: `Money`"""
+    cdef readonly uint64_t ts_event
+    """The UNIX timestamp (nanoseconds) of the ex-dividend date.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """The UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef CashDividend from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(CashDividend obj)
+
+
+cdef class StockSplit(Data):
+    cdef readonly InstrumentId instrument_id
+    """The split instrument ID.\n\n:returns: `InstrumentId`"""
+    cdef readonly uint64_t numerator
+    """The number of shares held after the split for each `denominator` shares before.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t denominator
+    """The number of shares held before the split for each `numerator` shares after.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_event
+    """The UNIX timestamp (nanoseconds) of the split ex-date.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """The UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef StockSplit from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(StockSplit obj)
+
+
+cdef class SymbolChange(Data):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID prior to the change.\n\n:returns: `InstrumentId`"""
+    cdef readonly InstrumentId new_instrument_id
+    """The instrument ID from the effective date.\n\n:returns: `InstrumentId`"""
+    cdef readonly uint64_t ts_event
+    """The UNIX timestamp (nanoseconds) when the symbol change is effective.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """The UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef SymbolChange from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(SymbolChange obj)
+
+
+cdef class BorrowRate(Data):
+    cdef readonly Venue venue
+    """The venue lending the asset.\n\n:returns: `Venue`"""
+    cdef readonly Currency currency
+    """The borrowed asset.\n\n:returns: `Currency`"""
+    cdef readonly double rate
+    """The annualized borrow rate as a fraction.\n\n:returns: `double`"""
+    cdef readonly uint64_t ts_event
+    """The UNIX timestamp (nanoseconds) when the rate became effective.\n\n:returns: `uint64_t`"""
+    cdef readonly uint64_t ts_init
+    """The UNIX timestamp (nanoseconds) when the object was initialized.\n\n:returns: `uint64_t`"""
+
+    @staticmethod
+    cdef BorrowRate from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(BorrowRate obj)
+
+
 cdef class QuoteTick(Data):
     cdef QuoteTick_t _mem
 
@@ -392,6 +466,7 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        uint8_t flags=*,
     )
 
     @staticmethod
@@ -45,6 +45,7 @@ from nautilus_trader.core.rust.model cimport InstrumentCloseType
 from nautilus_trader.core.rust.model cimport MarketStatus
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.core.rust.model cimport TradeFlag
 from nautilus_trader.core.rust.model cimport bar_eq
 from nautilus_trader.core.rust.model cimport bar_hash
 from nautilus_trader.core.rust.model cimport bar_new
@@ -67,6 +68,7 @@ from nautilus_trader.core.rust.model cimport bar_type_hash
 from nautilus_trader.core.rust.model cimport bar_type_le
 from nautilus_trader.core.rust.model cimport bar_type_lt
 from nautilus_trader.core.rust.model cimport bar_type_new
+from nautilus_trader.core.rust.model cimport bar_type_new_composite
 from nautilus_trader.core.rust.model cimport bar_type_to_cstr
 from nautilus_trader.core.rust.model cimport book_order_debug_to_cstr
 from nautilus_trader.core.rust.model cimport book_order_eq
@@ -120,6 +122,8 @@ from nautilus_trader.model.functions cimport price_type_to_str
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport Symbol
 from nautilus_trader.model.identifiers cimport Venue
+from nautilus_trader.model.objects cimport Currency
+from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 
@@ -634,12 +638,15 @@ cdef class BarType:
             self._mem.spec.step,
             self._mem.spec.aggregation,
             self._mem.spec.price_type,
-            self._mem.aggregation_source
+            self._mem.aggregation_source,
+            self._mem.composite_step,
+            self._mem.composite_aggregation,
+            self._mem.composite_aggregation_source,
         )
 
     def __setstate__(self, state):
         cdef InstrumentId instrument_id = InstrumentId.from_str_c(state[0])
-        self._mem = bar_type_new(
+        self._mem = bar_type_new_composite(
             instrument_id._mem,
             bar_specification_new(
                 state[1],
@@ -647,6 +654,9 @@ cdef class BarType:
                 state[3]
             ),
             state[4],
+            state[5],
+            state[6],
+            state[7],
         )
 
     cdef str to_str(self):
@@ -848,6 +858,9 @@ cdef class Bar(Data):
             self._mem.bar_type.spec.aggregation,
             self._mem.bar_type.spec.price_type,
             self._mem.bar_type.aggregation_source,
+            self._mem.bar_type.composite_step,
+            self._mem.bar_type.composite_aggregation,
+            self._mem.bar_type.composite_aggregation_source,
             self._mem.open.raw,
             self._mem.high.raw,
             self._mem.low.raw,
@@ -862,7 +875,7 @@ cdef class Bar(Data):
     def __setstate__(self, state):
         cdef InstrumentId instrument_id = InstrumentId.from_str_c(state[0])
         self._mem = bar_new_from_raw(
-            bar_type_new(
+            bar_type_new_composite(
                 instrument_id._mem,
                 bar_specification_new(
                     state[1],
@@ -870,16 +883,19 @@ cdef class Bar(Data):
                     state[3],
                 ),
                 state[4],
+                state[5],
+                state[6],
+                state[7],
             ),
-            state[5],
-            state[6],
-            state[7],
             state[8],
             state[9],
             state[10],
             state[11],
             state[12],
             state[13],
+            state[14],
+            state[15],
+            state[16],
         )
 
     def __eq__(self, Bar other) -> bool:
@@ -2980,6 +2996,436 @@ cdef class InstrumentClose(Data):
         return InstrumentClose.to_dict_c(obj)
 
 
+cdef class CashDividend(Data):
+    """
+    Represents a cash dividend paid on an equity instrument.
+
+    Holders of the instrument at the ex-dividend date receive the `amount` per share
+    (and holders of short positions pay it).
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    amount : Money
+        The dividend amount per share.
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) of the ex-dividend date.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+
+    Raises
+    ------
+    ValueError
+        If `amount` is not positive (> 0).
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        Money amount not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        Condition.positive(amount.as_f64_c(), "amount")
+
+        self.instrument_id = instrument_id
+        self.amount = amount
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, CashDividend other) -> bool:
+        return CashDividend.to_dict_c(self) == CashDividend.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(CashDividend.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"amount={self.amount.to_str()}, "
+            f"ts_event={self.ts_event})"
+        )
+
+    @staticmethod
+    cdef CashDividend from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return CashDividend(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            amount=Money.from_str_c(values["amount"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(CashDividend obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "CashDividend",
+            "instrument_id": obj.instrument_id.to_str(),
+            "amount": obj.amount.to_str(),
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> CashDividend:
+        """
+        Return a cash dividend from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        CashDividend
+
+        """
+        return CashDividend.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(CashDividend obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return CashDividend.to_dict_c(obj)
+
+
+cdef class StockSplit(Data):
+    """
+    Represents a stock split (or reverse split) of an equity instrument.
+
+    From the ex-date each `denominator` shares held become `numerator` shares, so a
+    2-for-1 split has a numerator of 2 and a denominator of 1, and a 1-for-10 reverse
+    split has a numerator of 1 and a denominator of 10.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    numerator : uint64_t
+        The number of shares held after the split for each `denominator` shares.
+    denominator : uint64_t
+        The number of shares held before the split for each `numerator` shares.
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) of the split ex-date.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+
+    Raises
+    ------
+    ValueError
+        If `numerator` is not positive (> 0).
+    ValueError
+        If `denominator` is not positive (> 0).
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        uint64_t numerator,
+        uint64_t denominator,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        Condition.positive_int(numerator, "numerator")
+        Condition.positive_int(denominator, "denominator")
+
+        self.instrument_id = instrument_id
+        self.numerator = numerator
+        self.denominator = denominator
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, StockSplit other) -> bool:
+        return StockSplit.to_dict_c(self) == StockSplit.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(StockSplit.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"ratio={self.numerator}:{self.denominator}, "
+            f"ts_event={self.ts_event})"
+        )
+
+    @staticmethod
+    cdef StockSplit from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return StockSplit(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            numerator=values["numerator"],
+            denominator=values["denominator"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(StockSplit obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "StockSplit",
+            "instrument_id": obj.instrument_id.to_str(),
+            "numerator": obj.numerator,
+            "denominator": obj.denominator,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> StockSplit:
+        """
+        Return a stock split from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        StockSplit
+
+        """
+        return StockSplit.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(StockSplit obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return StockSplit.to_dict_c(obj)
+
+
+cdef class SymbolChange(Data):
+    """
+    Represents a change of symbol for an equity instrument (such as on a rename).
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID prior to the change.
+    new_instrument_id : InstrumentId
+        The instrument ID from the effective date.
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) when the symbol change is effective.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+
+    Raises
+    ------
+    ValueError
+        If `new_instrument_id` is equal to `instrument_id`.
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        InstrumentId new_instrument_id not None,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        Condition.not_equal(new_instrument_id, instrument_id, "new_instrument_id", "instrument_id")
+
+        self.instrument_id = instrument_id
+        self.new_instrument_id = new_instrument_id
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, SymbolChange other) -> bool:
+        return SymbolChange.to_dict_c(self) == SymbolChange.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(SymbolChange.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"new_instrument_id={self.new_instrument_id}, "
+            f"ts_event={self.ts_event})"
+        )
+
+    @staticmethod
+    cdef SymbolChange from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return SymbolChange(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            new_instrument_id=InstrumentId.from_str_c(values["new_instrument_id"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(SymbolChange obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "SymbolChange",
+            "instrument_id": obj.instrument_id.to_str(),
+            "new_instrument_id": obj.new_instrument_id.to_str(),
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> SymbolChange:
+        """
+        Return a symbol change from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        SymbolChange
+
+        """
+        return SymbolChange.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(SymbolChange obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return SymbolChange.to_dict_c(obj)
+
+
+cdef class BorrowRate(Data):
+    """
+    Represents an update to the annualized rate charged for borrowing an asset at a venue.
+
+    Borrow rates apply to margined short positions in spot instruments, where the
+    base asset is borrowed in order to be sold.
+
+    Parameters
+    ----------
+    venue : Venue
+        The venue lending the asset.
+    currency : Currency
+        The borrowed asset.
+    rate : double
+        The annualized borrow rate as a fraction (e.g. 0.05 charges 5% per annum).
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) when the rate became effective.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+
+    Raises
+    ------
+    ValueError
+        If `rate` is negative (< 0).
+
+    """
+
+    def __init__(
+        self,
+        Venue venue not None,
+        Currency currency not None,
+        double rate,
+        uint64_t ts_event,
+        uint64_t ts_init,
+    ) -> None:
+        Condition.not_negative(rate, "rate")
+
+        self.venue = venue
+        self.currency = currency
+        self.rate = rate
+        self.ts_event = ts_event
+        self.ts_init = ts_init
+
+    def __eq__(self, BorrowRate other) -> bool:
+        return BorrowRate.to_dict_c(self) == BorrowRate.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(BorrowRate.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"venue={self.venue}, "
+            f"currency={self.currency.code}, "
+            f"rate={self.rate}, "
+            f"ts_event={self.ts_event})"
+        )
+
+    @staticmethod
+    cdef BorrowRate from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return BorrowRate(
+            venue=Venue(values["venue"]),
+            currency=Currency.from_str_c(values["currency"]),
+            rate=values["rate"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(BorrowRate obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "BorrowRate",
+            "venue": obj.venue.to_str(),
+            "currency": obj.currency.code,
+            "rate": obj.rate,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> BorrowRate:
+        """
+        Return a borrow rate from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        BorrowRate
+
+        """
+        return BorrowRate.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(BorrowRate obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return BorrowRate.to_dict_c(obj)
+
+
 cdef class QuoteTick(Data):
     """
     Represents a single quote tick in a financial market.
@@ -3535,6 +3981,9 @@ cdef class TradeTick(Data):
         The UNIX timestamp (nanoseconds) when the tick event occurred.
     ts_init : uint64_t
         The UNIX timestamp (nanoseconds) when the data object was initialized.
+    flags : uint8_t, default 0
+        The trade condition flags (bit set of ``TradeFlag`` values), such as for venue
+        aggregated trades or block trades.
 
     Raises
     ------
@@ -3552,6 +4001,7 @@ cdef class TradeTick(Data):
         TradeId trade_id not None,
         uint64_t ts_event,
         uint64_t ts_init,
+        uint8_t flags = 0,
     ) -> None:
         self._mem = trade_tick_new(
             instrument_id._mem,
@@ -3563,6 +4013,7 @@ cdef class TradeTick(Data):
             trade_id._mem,
             ts_event,
             ts_init,
+            flags,
         )
 
     def __getstate__(self):
@@ -3576,6 +4027,7 @@ cdef class TradeTick(Data):
             self.trade_id.value,
             self.ts_event,
             self.ts_init,
+            self._mem.flags,
         )
 
     def __setstate__(self, state):
@@ -3590,6 +4042,7 @@ cdef class TradeTick(Data):
             TradeId(state[6])._mem,
             state[7],
             state[8],
+            state[9],
         )
 
     def __eq__(self, TradeTick other) -> bool:
@@ -3667,6 +4120,54 @@ cdef class TradeTick(Data):
         """
         return <AggressorSide>self._mem.aggressor_side
 
+    @property
+    def flags(self) -> int:
+        """
+        Return the ticks trade condition flags.
+
+        Returns
+        -------
+        uint8_t
+
+        """
+        return self._mem.flags
+
+    @property
+    def is_aggregated(self) -> bool:
+        """
+        Return whether the trade is a venue aggregation of multiple fills.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._mem.flags & TradeFlag.AGGREGATED != 0
+
+    @property
+    def is_block(self) -> bool:
+        """
+        Return whether the trade is a block trade.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._mem.flags & TradeFlag.BLOCK != 0
+
+    @property
+    def is_off_book(self) -> bool:
+        """
+        Return whether the trade was executed off the order book.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._mem.flags & TradeFlag.OFF_BOOK != 0
+
     @property
     def ts_event(self) -> int:
         """
@@ -3714,6 +4215,7 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        uint8_t flags = 0,
     ):
         cdef TradeTick trade = TradeTick.__new__(TradeTick)
         trade._mem = trade_tick_new(
@@ -3726,6 +4228,7 @@ cdef class TradeTick(Data):
             trade_id._mem,
             ts_event,
             ts_init,
+            flags,
         )
         return trade
 
@@ -3782,6 +4285,7 @@ cdef class TradeTick(Data):
             trade_id=TradeId(values["trade_id"]),
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
+            flags=values.get("flags", 0),
         )
 
     @staticmethod
@@ -3796,6 +4300,7 @@ cdef class TradeTick(Data):
             "trade_id": str(obj.trade_id),
             "ts_event": obj.ts_event,
             "ts_init": obj.ts_init,
+            "flags": obj._mem.flags,
         }
 
     @staticmethod
@@ -3809,6 +4314,7 @@ cdef class TradeTick(Data):
         TradeId trade_id,
         uint64_t ts_event,
         uint64_t ts_init,
+        uint8_t flags = 0,
     ) -> TradeTick:
         """
         Return a trade tick from the given raw values.
@@ -3833,6 +4339,8 @@ cdef class TradeTick(Data):
             The UNIX timestamp (nanoseconds) when the tick event occurred.
         ts_init : uint64_t
             The UNIX timestamp (nanoseconds) when the data object was initialized.
+        flags : uint8_t, default 0
+            The trade condition flags (bit set of ``TradeFlag`` values).
 
         Returns
         -------
@@ -3849,6 +4357,7 @@ cdef class TradeTick(Data):
             trade_id,
             ts_event,
             ts_init,
+            flags,
         )
 
     @staticmethod
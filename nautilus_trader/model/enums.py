@@ -33,7 +33,9 @@ from nautilus_trader.core.rust.model import OrderStatus
 from nautilus_trader.core.rust.model import OrderType
 from nautilus_trader.core.rust.model import PositionSide
 from nautilus_trader.core.rust.model import PriceType
+from nautilus_trader.core.rust.model import RoundingMode
 from nautilus_trader.core.rust.model import TimeInForce
+from nautilus_trader.core.rust.model import TradeFlag
 from nautilus_trader.core.rust.model import TradingState
 from nautilus_trader.core.rust.model import TrailingOffsetType
 from nautilus_trader.core.rust.model import TriggerType
@@ -80,6 +82,8 @@ from nautilus_trader.model.functions import position_side_from_str
 from nautilus_trader.model.functions import position_side_to_str
 from nautilus_trader.model.functions import price_type_from_str
 from nautilus_trader.model.functions import price_type_to_str
+from nautilus_trader.model.functions import rounding_mode_from_str
+from nautilus_trader.model.functions import rounding_mode_to_str
 from nautilus_trader.model.functions import time_in_force_from_str
 from nautilus_trader.model.functions import time_in_force_to_str
 from nautilus_trader.model.functions import trading_state_from_str
@@ -112,7 +116,9 @@ __all__ = [
     "OrderType",
     "PositionSide",
     "PriceType",
+    "RoundingMode",
     "TimeInForce",
+    "TradeFlag",
     "TradingState",
     "TrailingOffsetType",
     "TriggerType",
@@ -158,6 +164,8 @@ __all__ = [
     "position_side_from_str",
     "price_type_to_str",
     "price_type_from_str",
+    "rounding_mode_to_str",
+    "rounding_mode_from_str",
     "time_in_force_to_str",
     "time_in_force_from_str",
     "trading_state_to_str",
@@ -357,6 +357,8 @@ cdef class OrderFilled(OrderEvent):
     """The liquidity side of the event {``MAKER``, ``TAKER``}.\n\n:returns: `LiquiditySide`"""
     cdef readonly dict info
     """The additional fill information.\n\n:returns: `dict[str, object]`"""
+    cdef readonly str tags
+    """The custom user tags of the filled order.\n\n:returns: `str` or ``None``"""
 
     @staticmethod
     cdef OrderFilled from_dict_c(dict values)
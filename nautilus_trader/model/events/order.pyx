@@ -4517,6 +4517,9 @@ cdef class OrderFilled(OrderEvent):
         The additional fill information.
     reconciliation : bool, default False
         If the event was generated during reconciliation.
+    tags : str, optional
+        The custom user tags of the filled order. If ``None`` then these will be
+        assigned from the order by the `ExecutionEngine`.
 
     Raises
     ------
@@ -4548,6 +4551,7 @@ cdef class OrderFilled(OrderEvent):
         uint64_t ts_init,
         bint reconciliation=False,
         dict info = None,
+        str tags = None,
     ):
         Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NONE")
         Condition.positive(last_qty, "last_qty")
@@ -4576,6 +4580,7 @@ cdef class OrderFilled(OrderEvent):
         self.commission = commission
         self.liquidity_side = liquidity_side
         self.info = info
+        self.tags = tags
 
     def __eq__(self, Event other) -> bool:
         return self.id == other.id
@@ -4771,6 +4776,7 @@ cdef class OrderFilled(OrderEvent):
             ts_init=values["ts_init"],
             info=values["info"],
             reconciliation=values.get("reconciliation", False),
+            tags=values.get("tags"),
         )
 
     @staticmethod
@@ -4798,6 +4804,7 @@ cdef class OrderFilled(OrderEvent):
             "ts_init": obj.ts_init,
             "info": obj.info,
             "reconciliation": obj.reconciliation,
+            "tags": obj.tags,
         }
 
     @staticmethod
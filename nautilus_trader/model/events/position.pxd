@@ -84,6 +84,8 @@ cdef class PositionEvent(Event):
     """The UNIX timestamp (nanoseconds) when the position was closed.\n\n:returns: `uint64_t`"""
     cdef readonly uint64_t duration_ns
     """The total open duration (nanoseconds).\n\n:returns: `uint64_t`"""
+    cdef readonly str tags
+    """The custom user tags of the order fill for the event.\n\n:returns: `str` or ``None``"""
 
 
 cdef class PositionOpened(PositionEvent):
@@ -93,6 +93,8 @@ cdef class PositionEvent(Event):
         The UNIX timestamp (nanoseconds) when the event occurred.
     ts_init : uint64_t
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    tags : str, optional
+        The custom user tags of the order fill for the event.
 
     Warnings
     --------
@@ -127,6 +129,7 @@ cdef class PositionEvent(Event):
         uint64_t duration_ns,
         uint64_t ts_event,
         uint64_t ts_init,
+        str tags = None,
     ):
         self.trader_id = trader_id
         self.strategy_id = strategy_id
@@ -151,6 +154,7 @@ cdef class PositionEvent(Event):
         self.ts_opened = ts_opened
         self.ts_closed = ts_closed
         self.duration_ns = duration_ns
+        self.tags = tags
 
         self._event_id = event_id
         self._ts_event = ts_event
@@ -298,6 +302,8 @@ cdef class PositionOpened(PositionEvent):
         The UNIX timestamp (nanoseconds) when the position opened event occurred.
     ts_init : uint64_t
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    tags : str, optional
+        The custom user tags of the order fill for the event.
     """
 
     def __init__(
@@ -321,6 +327,7 @@ cdef class PositionOpened(PositionEvent):
         UUID4 event_id not None,
         uint64_t ts_event,
         uint64_t ts_init,
+        str tags = None,
     ):
         assert side != PositionSide.FLAT  # Design-time check: position side matches event
         super().__init__(
@@ -350,6 +357,7 @@ cdef class PositionOpened(PositionEvent):
             0,
             ts_event,
             ts_init,
+            tags,
         )
 
     @staticmethod
@@ -383,6 +391,7 @@ cdef class PositionOpened(PositionEvent):
             event_id=event_id,
             ts_event=position.ts_opened,
             ts_init=ts_init,
+            tags=fill.tags,
         )
 
     @staticmethod
@@ -408,6 +417,7 @@ cdef class PositionOpened(PositionEvent):
             event_id=UUID4(values["event_id"]),
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
+            tags=values.get("tags"),
         )
 
     @staticmethod
@@ -435,6 +445,7 @@ cdef class PositionOpened(PositionEvent):
             "event_id": obj._event_id.to_str(),
             "ts_event": obj._ts_event,
             "ts_init": obj._ts_init,
+            "tags": obj.tags,
         }
 
     @staticmethod
@@ -549,6 +560,8 @@ cdef class PositionChanged(PositionEvent):
         The UNIX timestamp (nanoseconds) when the position changed event occurred.
     ts_init : uint64_t
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    tags : str, optional
+        The custom user tags of the order fill for the event.
     """
 
     def __init__(
@@ -576,6 +589,7 @@ cdef class PositionChanged(PositionEvent):
         uint64_t ts_opened,
         uint64_t ts_event,
         uint64_t ts_init,
+        str tags = None,
     ):
         assert side != PositionSide.FLAT  # Design-time check: position side matches event
         super().__init__(
@@ -605,6 +619,7 @@ cdef class PositionChanged(PositionEvent):
             0,
             ts_event,
             ts_init,
+            tags,
         )
 
     @staticmethod
@@ -642,6 +657,7 @@ cdef class PositionChanged(PositionEvent):
             ts_opened=position.ts_opened,
             ts_event=position.last_event_c().ts_event,
             ts_init=ts_init,
+            tags=fill.tags,
         )
 
     @staticmethod
@@ -671,6 +687,7 @@ cdef class PositionChanged(PositionEvent):
             ts_opened=values["ts_opened"],
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
+            tags=values.get("tags"),
         )
 
     @staticmethod
@@ -701,6 +718,7 @@ cdef class PositionChanged(PositionEvent):
             "ts_opened": obj.ts_opened,
             "ts_event": obj._ts_event,
             "ts_init": obj._ts_init,
+            "tags": obj.tags,
         }
 
     @staticmethod
@@ -817,6 +835,8 @@ cdef class PositionClosed(PositionEvent):
         The total open duration (nanoseconds).
     ts_init : uint64_t
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    tags : str, optional
+        The custom user tags of the order fill for the event.
     """
 
     def __init__(
@@ -845,6 +865,7 @@ cdef class PositionClosed(PositionEvent):
         uint64_t ts_closed,
         uint64_t duration_ns,
         uint64_t ts_init,
+        str tags = None,
     ):
         assert side == PositionSide.FLAT  # Design-time check: position side matches event
         super().__init__(
@@ -874,6 +895,7 @@ cdef class PositionClosed(PositionEvent):
             duration_ns,
             ts_closed,  # ts_event = ts_closed
             ts_init,
+            tags,
         )
 
     @staticmethod
@@ -912,6 +934,7 @@ cdef class PositionClosed(PositionEvent):
             ts_closed=position.ts_closed,
             duration_ns=position.duration_ns,
             ts_init=ts_init,
+            tags=fill.tags,
         )
 
     @staticmethod
@@ -942,6 +965,7 @@ cdef class PositionClosed(PositionEvent):
             ts_closed=values["ts_closed"],
             duration_ns=values["duration_ns"],
             ts_init=values["ts_init"],
+            tags=values.get("tags"),
         )
 
     @staticmethod
@@ -973,6 +997,7 @@ cdef class PositionClosed(PositionEvent):
             "ts_closed": obj.ts_closed,
             "duration_ns": obj.duration_ns,
             "ts_init": obj._ts_init,
+            "tags": obj.tags,
         }
 
     @staticmethod
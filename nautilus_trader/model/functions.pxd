@@ -33,6 +33,7 @@ from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
 from nautilus_trader.core.rust.model cimport PositionSide
 from nautilus_trader.core.rust.model cimport PriceType
+from nautilus_trader.core.rust.model cimport RoundingMode
 from nautilus_trader.core.rust.model cimport TimeInForce
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TrailingOffsetType
@@ -103,6 +104,8 @@ cpdef str position_side_to_str(PositionSide value)
 
 cpdef PriceType price_type_from_str(str value)
 cpdef str price_type_to_str(PriceType value)
+cpdef RoundingMode rounding_mode_from_str(str value)
+cpdef str rounding_mode_to_str(RoundingMode value)
 
 cpdef TimeInForce time_in_force_from_str(str value)
 cpdef str time_in_force_to_str(TimeInForce value)
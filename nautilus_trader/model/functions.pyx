@@ -57,6 +57,8 @@ from nautilus_trader.core.rust.model cimport position_side_from_cstr
 from nautilus_trader.core.rust.model cimport position_side_to_cstr
 from nautilus_trader.core.rust.model cimport price_type_from_cstr
 from nautilus_trader.core.rust.model cimport price_type_to_cstr
+from nautilus_trader.core.rust.model cimport rounding_mode_from_cstr
+from nautilus_trader.core.rust.model cimport rounding_mode_to_cstr
 from nautilus_trader.core.rust.model cimport time_in_force_from_cstr
 from nautilus_trader.core.rust.model cimport time_in_force_to_cstr
 from nautilus_trader.core.rust.model cimport trading_state_from_cstr
@@ -237,6 +239,14 @@ cpdef str price_type_to_str(PriceType value):
     return cstr_to_pystr(price_type_to_cstr(value))
 
 
+cpdef RoundingMode rounding_mode_from_str(str value):
+    return rounding_mode_from_cstr(pystr_to_cstr(value))
+
+
+cpdef str rounding_mode_to_str(RoundingMode value):
+    return cstr_to_pystr(rounding_mode_to_cstr(value))
+
+
 cpdef TimeInForce time_in_force_from_str(str value):
     return time_in_force_from_cstr(pystr_to_cstr(value))
 
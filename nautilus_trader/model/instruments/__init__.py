@@ -20,6 +20,7 @@ asset class and instrument class.
 from nautilus_trader.model.instruments.base import Instrument
 from nautilus_trader.model.instruments.base import instruments_from_pyo3
 from nautilus_trader.model.instruments.betting import BettingInstrument
+from nautilus_trader.model.instruments.binary_option import BinaryOption
 from nautilus_trader.model.instruments.crypto_future import CryptoFuture
 from nautilus_trader.model.instruments.crypto_perpetual import CryptoPerpetual
 from nautilus_trader.model.instruments.currency_pair import CurrencyPair
@@ -32,6 +33,7 @@ from nautilus_trader.model.instruments.synthetic import SyntheticInstrument
 __all__ = [
     "Instrument",
     "BettingInstrument",
+    "BinaryOption",
     "CryptoFuture",
     "CryptoPerpetual",
     "CurrencyPair",
@@ -78,6 +78,8 @@ cdef class Instrument(Data):
     """The tick scheme name.\n\n:returns: `str` or ``None``"""
     cdef readonly dict info
     """The raw info for the instrument.\n\n:returns: `dict[str, object]`"""
+    cdef public object rounding_mode
+    """The rounding mode for making prices and quantities (``None`` for the default).\n\n:returns: `RoundingMode` or ``None``"""
     cdef readonly uint64_t ts_event
     """The UNIX timestamp (nanoseconds) when the data event occurred.\n\n:returns: `uint64_t`"""
     cdef readonly uint64_t ts_init
@@ -91,10 +93,10 @@ cdef class Instrument(Data):
 
     cpdef Currency get_base_currency(self)
     cpdef Currency get_settlement_currency(self)
-    cpdef Price make_price(self, value)
+    cpdef Price make_price(self, value, rounding_mode=*)
     cpdef Price next_bid_price(self, double value, int num_ticks=*)
     cpdef Price next_ask_price(self, double value, int num_ticks=*)
-    cpdef Quantity make_qty(self, value)
+    cpdef Quantity make_qty(self, value, rounding_mode=*)
     cpdef Money notional_value(self, Quantity quantity, Price price, bint use_quote_for_inverse=*)
     cpdef Quantity calculate_base_quantity(self, Quantity quantity, Price last_px)
 
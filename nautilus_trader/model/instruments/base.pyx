@@ -220,6 +220,7 @@ cdef class Instrument(Data):
         self.maker_fee = maker_fee
         self.taker_fee = taker_fee
         self.info = info
+        self.rounding_mode = None
         self.ts_event = ts_event
         self.ts_init = ts_init
 
@@ -405,7 +406,7 @@ cdef class Instrument(Data):
         else:
             return self.quote_currency
 
-    cpdef Price make_price(self, value):
+    cpdef Price make_price(self, value, rounding_mode=None):
         """
         Return a new price from the given value using the instruments price
         precision.
@@ -414,13 +415,18 @@ cdef class Instrument(Data):
         ----------
         value : integer, float, str or Decimal
             The value of the price.
+        rounding_mode : RoundingMode, optional
+            The rounding mode for the value. If ``None`` then uses the
+            instruments `rounding_mode` (if set).
 
         Returns
         -------
         Price
 
         """
-        return Price(float(value), precision=self.price_precision)
+        if rounding_mode is None:
+            rounding_mode = self.rounding_mode
+        return Price(float(value), precision=self.price_precision, rounding_mode=rounding_mode)
 
     cpdef Price next_bid_price(self, double value, int num_ticks=0):
         """
@@ -476,7 +482,7 @@ cdef class Instrument(Data):
 
         return self._tick_scheme.next_ask_price(value=value, n=num_ticks)
 
-    cpdef Quantity make_qty(self, value):
+    cpdef Quantity make_qty(self, value, rounding_mode=None):
         """
         Return a new quantity from the given value using the instruments size
         precision.
@@ -485,13 +491,18 @@ cdef class Instrument(Data):
         ----------
         value : integer, float, str or Decimal
             The value of the quantity.
+        rounding_mode : RoundingMode, optional
+            The rounding mode for the value. If ``None`` then uses the
+            instruments `rounding_mode` (if set).
 
         Returns
         -------
         Quantity
 
         """
-        return Quantity(float(value), precision=self.size_precision)
+        if rounding_mode is None:
+            rounding_mode = self.rounding_mode
+        return Quantity(float(value), precision=self.size_precision, rounding_mode=rounding_mode)
 
     cpdef Money notional_value(
         self,
@@ -0,0 +1,279 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pandas as pd
+import pytz
+
+from libc.stdint cimport uint64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport AssetClass
+from nautilus_trader.core.rust.model cimport InstrumentClass
+from nautilus_trader.model.functions cimport asset_class_from_str
+from nautilus_trader.model.functions cimport asset_class_to_str
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport Symbol
+from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Currency
+from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.objects cimport Quantity
+
+
+cdef class BinaryOption(Instrument):
+    """
+    Represents a binary option (outcome token) instrument.
+
+    The contract pays one unit of `currency` per contract if the outcome occurs, and
+    nothing otherwise. Prices are therefore bounded between 0 and 1, and represent the
+    implied probability of the outcome (such as for prediction markets like Polymarket).
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID.
+    raw_symbol : Symbol
+        The native/local/raw symbol for the instrument, assigned by the venue.
+    asset_class : AssetClass
+        The binary option asset class.
+    currency : Currency
+        The binary option currency (the contract pays out in this currency).
+    price_precision : int
+        The price decimal precision.
+    size_precision : int
+        The trading size decimal precision.
+    price_increment : Price
+        The minimum price increment (tick size).
+    size_increment : Quantity
+        The minimum size increment.
+    activation_ns : uint64_t
+        The UNIX timestamp (nanoseconds) for contract activation.
+    expiration_ns : uint64_t
+        The UNIX timestamp (nanoseconds) for contract expiration.
+    ts_event : uint64_t
+        The UNIX timestamp (nanoseconds) when the data event occurred.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the data object was initialized.
+    maker_fee : Decimal, default 0
+        The fee rate for liquidity makers as a percentage of order value.
+    taker_fee : Decimal, default 0
+        The fee rate for liquidity takers as a percentage of order value.
+    outcome : str, optional
+        The binary outcome the contract pays out on (such as 'Yes' or 'No').
+    description : str, optional
+        The description of the market the contract is for.
+    max_quantity : Quantity, optional
+        The maximum allowable order quantity.
+    min_quantity : Quantity, optional
+        The minimum allowable order quantity.
+    info : dict[str, object], optional
+        The additional instrument information.
+
+    Raises
+    ------
+    ValueError
+        If `price_increment` is not less than 1.
+    ValueError
+        If `outcome` is not ``None`` and not a valid string.
+    ValueError
+        If `description` is not ``None`` and not a valid string.
+
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        Symbol raw_symbol not None,
+        AssetClass asset_class,
+        Currency currency not None,
+        int price_precision,
+        int size_precision,
+        Price price_increment not None,
+        Quantity size_increment not None,
+        uint64_t activation_ns,
+        uint64_t expiration_ns,
+        uint64_t ts_event,
+        uint64_t ts_init,
+        maker_fee: Decimal = Decimal(0),
+        taker_fee: Decimal = Decimal(0),
+        str outcome = None,
+        str description = None,
+        Quantity max_quantity: Quantity | None = None,
+        Quantity min_quantity: Quantity | None = None,
+        dict info = None,
+    ):
+        Condition.true(price_increment.as_f64_c() < 1.0, "`price_increment` was not less than 1")
+        if outcome is not None:
+            Condition.valid_string(outcome, "outcome")
+        if description is not None:
+            Condition.valid_string(description, "description")
+
+        super().__init__(
+            instrument_id=instrument_id,
+            raw_symbol=raw_symbol,
+            asset_class=asset_class,
+            instrument_class=InstrumentClass.OPTION,
+            quote_currency=currency,
+            is_inverse=False,
+            price_precision=price_precision,
+            size_precision=size_precision,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            multiplier=Quantity.from_int_c(1),
+            lot_size=None,
+            max_quantity=max_quantity,
+            min_quantity=min_quantity,
+            max_notional=None,
+            min_notional=None,
+            max_price=Price(1, price_precision),
+            min_price=Price(0, price_precision),
+            margin_init=Decimal(0),
+            margin_maint=Decimal(0),
+            maker_fee=maker_fee,
+            taker_fee=taker_fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            info=info,
+        )
+        self.activation_ns = activation_ns
+        self.expiration_ns = expiration_ns
+        self.outcome = outcome
+        self.description = description
+
+    @property
+    def activation_utc(self) -> pd.Timestamp:
+        """
+        Return the contract activation timestamp (UTC).
+
+        Returns
+        -------
+        pd.Timestamp
+            tz-aware UTC.
+
+        """
+        return pd.Timestamp(self.activation_ns, tz=pytz.utc)
+
+    @property
+    def expiration_utc(self) -> pd.Timestamp:
+        """
+        Return the contract expiration timestamp (UTC).
+
+        Returns
+        -------
+        pd.Timestamp
+            tz-aware UTC.
+
+        """
+        return pd.Timestamp(self.expiration_ns, tz=pytz.utc)
+
+    cpdef Price settlement_price(self, bint outcome_occurred):
+        """
+        Return the price the contract settles at on resolution.
+
+        Parameters
+        ----------
+        outcome_occurred : bool
+            If the outcome of the contract occurred.
+
+        Returns
+        -------
+        Price
+            1 if the outcome occurred, otherwise 0.
+
+        """
+        return Price(1 if outcome_occurred else 0, self.price_precision)
+
+    @staticmethod
+    cdef BinaryOption from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        cdef str max_q = values.get("max_quantity")
+        cdef str min_q = values.get("min_quantity")
+        return BinaryOption(
+            instrument_id=InstrumentId.from_str_c(values["id"]),
+            raw_symbol=Symbol(values["raw_symbol"]),
+            asset_class=asset_class_from_str(values["asset_class"]),
+            currency=Currency.from_str_c(values["currency"]),
+            price_precision=values["price_precision"],
+            size_precision=values["size_precision"],
+            price_increment=Price.from_str(values["price_increment"]),
+            size_increment=Quantity.from_str(values["size_increment"]),
+            activation_ns=values["activation_ns"],
+            expiration_ns=values["expiration_ns"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+            maker_fee=Decimal(values["maker_fee"]),
+            taker_fee=Decimal(values["taker_fee"]),
+            outcome=values["outcome"],
+            description=values["description"],
+            max_quantity=Quantity.from_str(max_q) if max_q is not None else None,
+            min_quantity=Quantity.from_str(min_q) if min_q is not None else None,
+            info=values.get("info"),
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(BinaryOption obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "BinaryOption",
+            "id": obj.id.to_str(),
+            "raw_symbol": obj.raw_symbol.to_str(),
+            "asset_class": asset_class_to_str(obj.asset_class),
+            "currency": obj.quote_currency.code,
+            "price_precision": obj.price_precision,
+            "price_increment": str(obj.price_increment),
+            "size_precision": obj.size_precision,
+            "size_increment": str(obj.size_increment),
+            "activation_ns": obj.activation_ns,
+            "expiration_ns": obj.expiration_ns,
+            "max_quantity": str(obj.max_quantity) if obj.max_quantity is not None else None,
+            "min_quantity": str(obj.min_quantity) if obj.min_quantity is not None else None,
+            "maker_fee": str(obj.maker_fee),
+            "taker_fee": str(obj.taker_fee),
+            "outcome": obj.outcome,
+            "description": obj.description,
+            "info": obj.info,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> BinaryOption:
+        """
+        Return an instrument from the given initialization values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values to initialize the instrument with.
+
+        Returns
+        -------
+        BinaryOption
+
+        """
+        return BinaryOption.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(BinaryOption obj) -> dict[str, object]:
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return BinaryOption.to_dict_c(obj)
@@ -24,6 +24,8 @@ cdef class CryptoPerpetual(Instrument):
     """The settlement currency for the instrument.\n\n:returns: `Currency`"""
     cdef readonly bint is_quanto
     """If the instrument is quanto.\n\n:returns: `bool`"""
+    cdef readonly object quanto_multiplier
+    """The fixed settlement currency amount per unit of quote currency (quanto only).\n\n:returns: `Decimal` or ``None``"""
 
     @staticmethod
     cdef CryptoPerpetual from_dict_c(dict values)
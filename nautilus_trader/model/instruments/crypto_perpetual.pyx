@@ -80,6 +80,9 @@ cdef class CryptoPerpetual(Instrument):
         The maximum allowable quoted price.
     min_price : Price, optional
         The minimum allowable quoted price.
+    quanto_multiplier : Decimal, optional
+        The fixed settlement currency amount per unit of quote currency, required
+        for quanto instruments (settled in neither the base nor quote currency).
     info : dict[str, object], optional
         The additional instrument information.
 
@@ -111,6 +114,10 @@ cdef class CryptoPerpetual(Instrument):
         If `max_price` is not positive (> 0).
     ValueError
         If `min_price` is negative (< 0).
+    ValueError
+        If `quanto_multiplier` is not positive (> 0).
+    TypeError
+        If `quanto_multiplier` is given for a non-quanto instrument, or is missing for a quanto instrument.
     """
 
     def __init__(
@@ -137,8 +144,20 @@ cdef class CryptoPerpetual(Instrument):
         Money min_notional: Money | None = None,
         Price max_price: Price | None = None,
         Price min_price: Price | None = None,
+        quanto_multiplier: Decimal | None = None,
         dict info = None,
     ):
+        cdef bint is_quanto = (
+            not is_inverse
+            and settlement_currency != base_currency
+            and settlement_currency != quote_currency
+        )
+        if is_quanto:
+            Condition.not_none(quanto_multiplier, "quanto_multiplier")
+            Condition.positive(quanto_multiplier, "quanto_multiplier")
+        else:
+            Condition.none(quanto_multiplier, "quanto_multiplier")
+
         super().__init__(
             instrument_id=instrument_id,
             raw_symbol=raw_symbol,
@@ -169,10 +188,8 @@ cdef class CryptoPerpetual(Instrument):
 
         self.base_currency = base_currency
         self.settlement_currency = settlement_currency
-        if settlement_currency != base_currency and settlement_currency != quote_currency:
-            self.is_quanto = True
-        else:
-            self.is_quanto = False
+        self.is_quanto = is_quanto
+        self.quanto_multiplier = quanto_multiplier
 
     cpdef Currency get_base_currency(self):
         """
@@ -185,6 +202,57 @@ cdef class CryptoPerpetual(Instrument):
         """
         return self.base_currency
 
+    cpdef Currency get_settlement_currency(self):
+        """
+        Return the currency used to settle a trade of the instrument.
+
+        Returns
+        -------
+        Currency
+
+        """
+        if self.is_quanto:
+            return self.settlement_currency
+        return Instrument.get_settlement_currency(self)
+
+    cpdef Money notional_value(
+        self,
+        Quantity quantity,
+        Price price,
+        bint use_quote_for_inverse=False,
+    ):
+        """
+        Calculate the notional value.
+
+        Result will be in quote currency for standard instruments, base
+        currency for inverse instruments, or settlement currency for quanto
+        instruments (converted at the fixed `quanto_multiplier`).
+
+        Parameters
+        ----------
+        quantity : Quantity
+            The total quantity.
+        price : Price
+            The price for the calculation.
+        use_quote_for_inverse : bool
+            If inverse instrument calculations use quote currency (instead of base).
+
+        Returns
+        -------
+        Money
+
+        """
+        if not self.is_quanto:
+            return Instrument.notional_value(self, quantity, price, use_quote_for_inverse)
+
+        Condition.not_none(quantity, "quantity")
+        Condition.not_none(price, "price")
+
+        return Money(
+            quantity.as_f64_c() * float(self.multiplier) * price.as_f64_c() * float(self.quanto_multiplier),
+            self.settlement_currency,
+        )
+
     @staticmethod
     cdef CryptoPerpetual from_dict_c(dict values):
         Condition.not_none(values, "values")
@@ -194,6 +262,7 @@ cdef class CryptoPerpetual(Instrument):
         cdef str min_n = values["min_notional"]
         cdef str max_p = values["max_price"]
         cdef str min_p = values["min_price"]
+        cdef object quanto_m = values.get("quanto_multiplier")  # Can be float from pyo3
         return CryptoPerpetual(
             instrument_id=InstrumentId.from_str_c(values["id"]),
             raw_symbol=Symbol(values["raw_symbol"]),
@@ -211,6 +280,7 @@ cdef class CryptoPerpetual(Instrument):
             min_notional=Money.from_str_c(min_n) if min_n is not None else None,
             max_price=Price.from_str_c(max_p) if max_p is not None else None,
             min_price=Price.from_str_c(min_p) if min_p is not None else None,
+            quanto_multiplier=Decimal(str(quanto_m)) if quanto_m is not None else None,
             margin_init=Decimal(values["margin_init"]),
             margin_maint=Decimal(values["margin_maint"]),
             maker_fee=Decimal(values["maker_fee"]),
@@ -241,6 +311,7 @@ cdef class CryptoPerpetual(Instrument):
             "min_notional": obj.min_notional.to_str() if obj.min_notional is not None else None,
             "max_price": str(obj.max_price) if obj.max_price is not None else None,
             "min_price": str(obj.min_price) if obj.min_price is not None else None,
+            "quanto_multiplier": str(obj.quanto_multiplier) if obj.quanto_multiplier is not None else None,
             "margin_init": str(obj.margin_init),
             "margin_maint": str(obj.margin_maint),
             "maker_fee": str(obj.maker_fee),
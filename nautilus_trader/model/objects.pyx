@@ -41,6 +41,7 @@ from nautilus_trader.core.rust.model cimport PRICE_MAX as RUST_PRICE_MAX
 from nautilus_trader.core.rust.model cimport PRICE_MIN as RUST_PRICE_MIN
 from nautilus_trader.core.rust.model cimport QUANTITY_MAX as RUST_QUANTITY_MAX
 from nautilus_trader.core.rust.model cimport QUANTITY_MIN as RUST_QUANTITY_MIN
+from nautilus_trader.core.rust.model cimport RoundingMode
 from nautilus_trader.core.rust.model cimport currency_code_to_cstr
 from nautilus_trader.core.rust.model cimport currency_exists
 from nautilus_trader.core.rust.model cimport currency_from_cstr
@@ -52,8 +53,10 @@ from nautilus_trader.core.rust.model cimport money_from_raw
 from nautilus_trader.core.rust.model cimport money_new
 from nautilus_trader.core.rust.model cimport price_from_raw
 from nautilus_trader.core.rust.model cimport price_new
+from nautilus_trader.core.rust.model cimport price_new_with_rounding
 from nautilus_trader.core.rust.model cimport quantity_from_raw
 from nautilus_trader.core.rust.model cimport quantity_new
+from nautilus_trader.core.rust.model cimport quantity_new_with_rounding
 from nautilus_trader.core.string cimport cstr_to_pystr
 from nautilus_trader.core.string cimport pystr_to_cstr
 from nautilus_trader.core.string cimport ustr_to_pystr
@@ -92,6 +95,9 @@ cdef class Quantity:
     precision : uint8_t
         The precision for the quantity. Use a precision of 0 for whole numbers
         (no fractional units).
+    rounding_mode : RoundingMode, optional
+        The rounding mode for the value to the precision. If ``None`` then
+        rounds half away from zero (on the binary floating point value).
 
     Raises
     ------
@@ -109,7 +115,7 @@ cdef class Quantity:
     https://www.onixs.biz/fix-dictionary/5.0.SP2/index.html#Qty
     """
 
-    def __init__(self, double value, uint8_t precision) -> None:
+    def __init__(self, double value, uint8_t precision, rounding_mode=None) -> None:
         Condition.true(precision <= 9, f"invalid `precision` greater than max 9, was {precision}")
         if isnan(value):
             raise ValueError(
@@ -124,7 +130,10 @@ cdef class Quantity:
                 f"invalid `value` less than `QUANTITY_MIN` {RUST_QUANTITY_MIN:_}, was {value:_}",
             )
 
-        self._mem = quantity_new(value, precision)
+        if rounding_mode is None:
+            self._mem = quantity_new(value, precision)
+        else:
+            self._mem = quantity_new_with_rounding(value, precision, <RoundingMode>rounding_mode)
 
     def __getstate__(self):
         return self._mem.raw, self._mem.precision
@@ -534,6 +543,9 @@ cdef class Price:
     precision : uint8_t
         The precision for the price. Use a precision of 0 for whole numbers
         (no fractional units).
+    rounding_mode : RoundingMode, optional
+        The rounding mode for the value to the precision. If ``None`` then
+        rounds half away from zero (on the binary floating point value).
 
     Raises
     ------
@@ -551,7 +563,7 @@ cdef class Price:
     https://www.onixs.biz/fix-dictionary/5.0.SP2/index.html#Price
     """
 
-    def __init__(self, double value, uint8_t precision) -> None:
+    def __init__(self, double value, uint8_t precision, rounding_mode=None) -> None:
         Condition.true(precision <= 9, f"invalid `precision` greater than max 9, was {precision}")
         if isnan(value):
             raise ValueError(
@@ -566,7 +578,10 @@ cdef class Price:
                 f"invalid `value` less than `PRICE_MIX` {RUST_PRICE_MIN:_}, was {value:_}",
             )
 
-        self._mem = price_new(value, precision)
+        if rounding_mode is None:
+            self._mem = price_new(value, precision)
+        else:
+            self._mem = price_new_with_rounding(value, precision, <RoundingMode>rounding_mode)
 
     def __getstate__(self):
         return self._mem.raw, self._mem.precision
@@ -71,6 +71,10 @@ cdef class Position:
     """The multiplier for the positions instrument.\n\n:returns: `Quantity`"""
     cdef readonly bint is_inverse
     """If the quantity is expressed in quote currency.\n\n:returns: `bool`"""
+    cdef readonly bint is_quanto
+    """If the position instrument is quanto (settled at a fixed rate).\n\n:returns: `bool`"""
+    cdef readonly double quanto_multiplier
+    """The fixed settlement currency amount per unit of quote currency (1.0 if not quanto).\n\n:returns: `double`"""
     cdef readonly Currency quote_currency
     """The position quote currency.\n\n:returns: `Currency`"""
     cdef readonly Currency base_currency
@@ -117,6 +121,7 @@ cdef class Position:
     cpdef bint is_opposite_side(self, OrderSide side)
 
     cpdef void apply(self, OrderFilled fill)
+    cpdef object apply_split(self, uint64_t numerator, uint64_t denominator, uint64_t ts_event)
 
     cpdef Money notional_value(self, Price last)
     cpdef Money calculate_pnl(self, double avg_px_open, double avg_px_close, Quantity quantity)
@@ -127,6 +132,7 @@ cdef class Position:
     cdef void _check_duplicate_trade_id(self, OrderFilled fill)
     cdef void _handle_buy_order_fill(self, OrderFilled fill)
     cdef void _handle_sell_order_fill(self, OrderFilled fill)
+    cdef Quantity _split_qty(self, Quantity quantity, object ratio)
     cdef double _calculate_avg_px(self, double avg_px, double qty, double last_px, double last_qty)
     cdef double _calculate_avg_px_open_px(self, double last_px, double last_qty)
     cdef double _calculate_avg_px_close_px(self, double last_px, double last_qty)
@@ -13,6 +13,7 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from decimal import ROUND_DOWN
 from decimal import Decimal
 
 from libc.math cimport fabs
@@ -26,6 +27,7 @@ from nautilus_trader.model.functions cimport order_side_to_str
 from nautilus_trader.model.functions cimport position_side_to_str
 from nautilus_trader.model.identifiers cimport TradeId
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.instruments.crypto_perpetual cimport CryptoPerpetual
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 
@@ -92,6 +94,8 @@ cdef class Position:
         self.size_precision = instrument.size_precision
         self.multiplier = instrument.multiplier
         self.is_inverse = instrument.is_inverse
+        self.is_quanto = isinstance(instrument, CryptoPerpetual) and instrument.is_quanto
+        self.quanto_multiplier = float(instrument.quanto_multiplier) if self.is_quanto else 1.0
         self.quote_currency = instrument.quote_currency
         self.base_currency = instrument.get_base_currency()  # Can be None
         self.settlement_currency = instrument.get_settlement_currency()
@@ -494,6 +498,68 @@ cdef class Position:
 
         self.ts_last = fill.ts_event
 
+    cpdef object apply_split(self, uint64_t numerator, uint64_t denominator, uint64_t ts_event):
+        """
+        Adjust the open position for a stock split where each `denominator` shares
+        held become `numerator` shares.
+
+        The quantities are scaled by the split ratio and the average prices by its
+        inverse, so that the cost basis of the position is unchanged. Any fraction
+        of the adjusted quantity which cannot be held at the positions size precision
+        is removed and returned (to be settled as cash in lieu).
+
+        Parameters
+        ----------
+        numerator : uint64_t
+            The number of shares held after the split for each `denominator` shares.
+        denominator : uint64_t
+            The number of shares held before the split for each `numerator` shares.
+        ts_event : uint64_t
+            The UNIX timestamp (nanoseconds) of the split ex-date.
+
+        Returns
+        -------
+        Decimal
+            The fractional quantity removed from the position.
+
+        Raises
+        ------
+        ValueError
+            If `numerator` or `denominator` is not positive (> 0).
+        ValueError
+            If the position is closed.
+
+        """
+        Condition.positive_int(numerator, "numerator")
+        Condition.positive_int(denominator, "denominator")
+        Condition.false(self.side == PositionSide.FLAT, "position was closed")
+
+        cdef object ratio = Decimal(numerator) / Decimal(denominator)
+        cdef object exact_qty = self.quantity.as_decimal() * ratio
+
+        self.quantity = self._split_qty(self.quantity, ratio)
+        self.signed_qty = self.quantity.as_f64_c() if self.side == PositionSide.LONG else -self.quantity.as_f64_c()
+        self.peak_qty = self._split_qty(self.peak_qty, ratio)
+        self._buy_qty = self._split_qty(self._buy_qty, ratio)
+        self._sell_qty = self._split_qty(self._sell_qty, ratio)
+        self.avg_px_open = self.avg_px_open * denominator / numerator
+        self.avg_px_close = self.avg_px_close * denominator / numerator
+
+        if self.quantity._mem.raw == 0:
+            # The whole position was a fraction after a reverse split
+            self.side = PositionSide.FLAT
+            self.ts_closed = ts_event
+            self.duration_ns = self.ts_closed - self.ts_opened
+
+        self.ts_last = ts_event
+
+        return exact_qty - self.quantity.as_decimal()
+
+    cdef Quantity _split_qty(self, Quantity quantity, object ratio):
+        cdef object increment = Decimal(1).scaleb(-self.size_precision)
+        cdef object adjusted = (quantity.as_decimal() * ratio).quantize(increment, rounding=ROUND_DOWN)
+        return Quantity(adjusted, self.size_precision)
+
     cpdef Money notional_value(self, Price last):
         """
         Return the current notional value of the position.
@@ -506,7 +572,7 @@ cdef class Position:
         Returns
         -------
         Money
-            In quote currency.
+            In quote currency (or settlement currency for quanto instruments).
 
         """
         Condition.not_none(last, "last")
@@ -516,6 +582,11 @@ cdef class Position:
                 self.quantity.as_f64_c() * self.multiplier.as_f64_c() * (1.0 / last.as_f64_c()),
                 self.base_currency,
             )
+        elif self.is_quanto:
+            return Money(
+                self.quantity.as_f64_c() * self.multiplier.as_f64_c() * last.as_f64_c() * self.quanto_multiplier,
+                self.settlement_currency,
+            )
         else:
             return Money(
                 self.quantity.as_f64_c() * self.multiplier.as_f64_c() * last.as_f64_c(),
@@ -714,6 +785,10 @@ cdef class Position:
         double last_px,
         double last_qty,
     ):
+        if self.is_inverse:
+            # Weight by base currency value (harmonic mean of prices)
+            return (qty + last_qty) / ((qty / avg_px) + (last_qty / last_px))
+
         cdef double start_cost = avg_px * qty
         cdef double event_cost = last_px * last_qty
         return (start_cost + event_cost) / (qty + last_qty)
@@ -750,5 +825,5 @@ cdef class Position:
             # In base currency
             return quantity * self.multiplier.as_f64_c() * self._calculate_points_inverse(avg_px_open, avg_px_close)
         else:
-            # In quote currency
-            return quantity * self.multiplier.as_f64_c() * self._calculate_points(avg_px_open, avg_px_close)
+            # In quote currency, converted to settlement currency at the fixed rate for quanto
+            return quantity * self.multiplier.as_f64_c() * self._calculate_points(avg_px_open, avg_px_close) * self.quanto_multiplier
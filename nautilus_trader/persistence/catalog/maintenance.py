@@ -0,0 +1,654 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from collections import defaultdict
+from dataclasses import dataclass
+from dataclasses import field
+from enum import Enum
+from enum import unique
+from typing import TYPE_CHECKING
+
+import fsspec
+import numpy as np
+import pyarrow as pa
+import pyarrow.compute as pc
+import pyarrow.parquet as pq
+
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.persistence.funcs import class_to_filename
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+
+
+_TS_COLUMN = "ts_init"
+_ROW_INDEX_COLUMN = "__row_index"
+
+DEFAULT_TARGET_FILE_SIZE = 128 * 1024 * 1024  # 128 MiB
+
+
+@unique
+class CatalogIssueType(Enum):
+    """
+    Represents a type of data catalog consistency issue.
+    """
+
+    UNREADABLE_FILE = "unreadable_file"
+    SCHEMA_DRIFT = "schema_drift"
+    OVERLAPPING_RANGES = "overlapping_ranges"
+    DUPLICATE_ROWS = "duplicate_rows"
+    NON_MONOTONIC_TIMESTAMPS = "non_monotonic_timestamps"
+
+
+@dataclass(frozen=True)
+class CatalogIssue:
+    """
+    Represents a consistency issue found in a data catalog partition.
+    """
+
+    partition: str
+    issue_type: CatalogIssueType
+    files: tuple[str, ...]
+    detail: str
+
+    def __str__(self) -> str:
+        return f"[{self.issue_type.value}] {self.partition}: {self.detail}"
+
+
+@dataclass
+class CatalogConsistencyReport:
+    """
+    Represents the result of a data catalog consistency check.
+    """
+
+    partitions_checked: int = 0
+    files_checked: int = 0
+    issues: list[CatalogIssue] = field(default_factory=list)
+
+    @property
+    def is_consistent(self) -> bool:
+        """
+        Return whether no issues were found.
+
+        Returns
+        -------
+        bool
+
+        """
+        return not self.issues
+
+    @property
+    def partitions(self) -> list[str]:
+        """
+        Return the partitions with at least one issue.
+
+        Returns
+        -------
+        list[str]
+
+        """
+        return list(dict.fromkeys(issue.partition for issue in self.issues))
+
+    def issues_of_type(self, issue_type: CatalogIssueType) -> list[CatalogIssue]:
+        """
+        Return the issues of the given type.
+
+        Parameters
+        ----------
+        issue_type : CatalogIssueType
+            The issue type to filter on.
+
+        Returns
+        -------
+        list[CatalogIssue]
+
+        """
+        return [issue for issue in self.issues if issue.issue_type == issue_type]
+
+    def __str__(self) -> str:
+        lines = [
+            f"Checked {self.files_checked} file(s) in {self.partitions_checked} partition(s), "
+            f"found {len(self.issues)} issue(s)",
+        ]
+        lines.extend(f"  {issue}" for issue in self.issues)
+        return "\n".join(lines)
+
+
+@dataclass(frozen=True)
+class PartitionCompaction:
+    """
+    Represents the compaction of the small files in a data catalog partition.
+    """
+
+    partition: str
+    files_merged: int
+    files_written: int
+    rows_read: int
+    rows_written: int
+
+    @property
+    def duplicates_removed(self) -> int:
+        """
+        Return the count of duplicate rows removed.
+
+        Returns
+        -------
+        int
+
+        """
+        return self.rows_read - self.rows_written
+
+
+def list_partitions(
+    catalog: ParquetDataCatalog,
+    data_cls: type | None = None,
+) -> dict[str, list[str]]:
+    """
+    Return the Parquet files in the catalog grouped by partition directory.
+
+    Parameters
+    ----------
+    catalog : ParquetDataCatalog
+        The catalog to list.
+    data_cls : type, optional
+        The data class to list partitions for. If ``None`` then lists all data classes.
+
+    Returns
+    -------
+    dict[str, list[str]]
+
+    """
+    prefix = f"{catalog.path}/data"
+    if data_cls is not None:
+        prefix += f"/{class_to_filename(data_cls)}"
+    if not catalog.fs.exists(prefix):
+        return {}
+
+    partitions: dict[str, list[str]] = defaultdict(list)
+    for path in sorted(catalog.fs.find(prefix)):
+        if path.endswith(".parquet"):
+            partitions[path.rsplit("/", 1)[0]].append(path)
+    return dict(partitions)
+
+
+def check_catalog(
+    catalog: ParquetDataCatalog,
+    data_cls: type | None = None,
+    check_duplicates: bool = True,
+) -> CatalogConsistencyReport:
+    """
+    Check the Parquet partitions of the catalog for consistency issues.
+
+    Each partition (a directory of Parquet files for a single data class and instrument)
+    is checked for unreadable files, schema drift between files, files with overlapping
+    `ts_init` ranges, non-monotonic `ts_init` values within a file, and duplicate rows.
+
+    Parameters
+    ----------
+    catalog : ParquetDataCatalog
+        The catalog to check.
+    data_cls : type, optional
+        The data class to check. If ``None`` then checks all data classes.
+    check_duplicates : bool, default True
+        If duplicate rows should be checked for (requires reading every partition in full).
+
+    Returns
+    -------
+    CatalogConsistencyReport
+
+    """
+    report = CatalogConsistencyReport()
+    for partition, files in list_partitions(catalog, data_cls).items():
+        report.partitions_checked += 1
+        report.files_checked += len(files)
+        report.issues.extend(_check_partition(catalog.fs, partition, files, check_duplicates))
+    return report
+
+
+def repair_catalog(
+    catalog: ParquetDataCatalog,
+    report: CatalogConsistencyReport | None = None,
+    data_cls: type | None = None,
+    remove_unreadable: bool = False,
+) -> list[str]:
+    """
+    Repair the partitions of the catalog with consistency issues.
+
+    Each affected partition is rewritten as a single file with drifted schemas cast to
+    the partition's reference schema, duplicate rows removed, and rows sorted by `ts_init`
+    (preserving the original order of rows with equal timestamps).
+
+    Files which cannot be read, or whose schema cannot be cast to the reference schema,
+    are left in place (and will be reported by subsequent checks) unless removed.
+
+    Parameters
+    ----------
+    catalog : ParquetDataCatalog
+        The catalog to repair.
+    report : CatalogConsistencyReport, optional
+        The report of issues to repair. If ``None`` then the catalog will be checked.
+    data_cls : type, optional
+        The data class to check (only applicable if `report` is ``None``).
+    remove_unreadable : bool, default False
+        If unreadable files should be deleted.
+
+    Returns
+    -------
+    list[str]
+        The partitions which were rewritten.
+
+    """
+    if report is None:
+        report = check_catalog(catalog, data_cls=data_cls)
+
+    partitions = list_partitions(catalog)
+    repaired: list[str] = []
+    for partition in report.partitions:
+        files = partitions.get(partition, [])
+        unreadable = {
+            path
+            for issue in report.issues_of_type(CatalogIssueType.UNREADABLE_FILE)
+            if issue.partition == partition
+            for path in issue.files
+        }
+        if remove_unreadable:
+            for path in unreadable:
+                catalog.fs.rm(path)
+
+        readable = [path for path in files if path not in unreadable]
+        schemas = {path: _read_schema(catalog.fs, path) for path in readable}
+        reference = _reference_schema([s for s in schemas.values() if s is not None])
+        if reference is None:
+            continue
+
+        tables: list[pa.Table] = []
+        replaced: list[str] = []
+        for path in readable:
+            table = _cast_to_schema(_read_table(catalog.fs, path), reference)
+            if table is None:
+                continue  # Cannot be repaired
+            tables.append(table)
+            replaced.append(path)
+
+        table = dedupe_and_sort(pa.concat_tables(tables))
+        write_partition_files(
+            fs=catalog.fs,
+            partition=partition,
+            tables=[table],
+            replaced=replaced,
+            row_group_size=catalog.max_rows_per_group,
+        )
+        repaired.append(partition)
+
+    return repaired
+
+
+def compact_catalog(
+    catalog: ParquetDataCatalog,
+    data_cls: type | None = None,
+    target_file_size: int = DEFAULT_TARGET_FILE_SIZE,
+    min_files: int = 2,
+) -> list[PartitionCompaction]:
+    """
+    Compact the small Parquet files in each partition of the catalog.
+
+    Compaction is incremental: only files smaller than `target_file_size` are merged, so
+    files written by earlier compactions are not rewritten. The merged rows have duplicate
+    rows removed and are sorted by `ts_init`, then written as files of approximately
+    `target_file_size` (estimated from the on-disk size per row of the merged files).
+
+    Parameters
+    ----------
+    catalog : ParquetDataCatalog
+        The catalog to compact.
+    data_cls : type, optional
+        The data class to compact. If ``None`` then compacts all data classes.
+    target_file_size : int, default 128 MiB
+        The target file size (bytes) for compacted files.
+    min_files : int, default 2
+        The minimum number of small files in a partition for it to be compacted.
+
+    Returns
+    -------
+    list[PartitionCompaction]
+        The compacted partitions.
+
+    Raises
+    ------
+    ValueError
+        If `target_file_size` is not positive.
+    ValueError
+        If `min_files` is not positive.
+
+    """
+    PyCondition.positive_int(target_file_size, "target_file_size")
+    PyCondition.positive_int(min_files, "min_files")
+
+    compactions: list[PartitionCompaction] = []
+    for partition, files in list_partitions(catalog, data_cls).items():
+        sizes = {path: catalog.fs.size(path) for path in files}
+        small = [path for path in files if sizes[path] < target_file_size]
+        if len(small) < min_files:
+            continue
+
+        schemas = {path: _read_schema(catalog.fs, path) for path in small}
+        reference = _reference_schema([s for s in schemas.values() if s is not None])
+        if reference is None:
+            continue
+
+        # Unreadable or drifted files are left for `repair_catalog`
+        merged = [
+            path
+            for path, schema in schemas.items()
+            if schema is not None and schema.equals(reference, check_metadata=False)
+        ]
+        if len(merged) < min_files:
+            continue
+
+        table = pa.concat_tables(
+            [_cast_to_schema(_read_table(catalog.fs, path), reference) for path in merged],
+        )
+        rows_read = table.num_rows
+        table = dedupe_and_sort(table)
+
+        bytes_per_row = sum(sizes[path] for path in merged) / max(rows_read, 1)
+        rows_per_file = max(1, int(target_file_size / max(bytes_per_row, 1)))
+        tables = [
+            table.slice(offset, rows_per_file)
+            for offset in range(0, max(table.num_rows, 1), rows_per_file)
+        ]
+
+        written = write_partition_files(
+            fs=catalog.fs,
+            partition=partition,
+            tables=tables,
+            replaced=merged,
+            row_group_size=catalog.max_rows_per_group,
+        )
+        compactions.append(
+            PartitionCompaction(
+                partition=partition,
+                files_merged=len(merged),
+                files_written=len(written),
+                rows_read=rows_read,
+                rows_written=table.num_rows,
+            ),
+        )
+
+    return compactions
+
+
+def dedupe_and_sort(table: pa.Table) -> pa.Table:
+    """
+    Return the given table with duplicate rows removed and sorted by `ts_init`.
+
+    The first occurrence of each duplicate row is kept, and the sort is stable so rows
+    with equal timestamps (such as order book deltas in a single event) keep their order.
+
+    Parameters
+    ----------
+    table : pa.Table
+        The table to process.
+
+    Returns
+    -------
+    pa.Table
+
+    """
+    if table.num_rows == 0:
+        return table
+
+    indexed = table.append_column(_ROW_INDEX_COLUMN, pa.array(np.arange(table.num_rows)))
+    grouped = indexed.group_by(table.column_names, use_threads=False).aggregate(
+        [(_ROW_INDEX_COLUMN, "min")],
+    )
+    indices = np.sort(grouped.column(f"{_ROW_INDEX_COLUMN}_min").to_numpy())
+    table = table.take(pa.array(indices))
+
+    if _TS_COLUMN in table.column_names:
+        table = table.take(
+            pc.sort_indices(table, sort_keys=[(_TS_COLUMN, "ascending")]),
+        )
+    return table
+
+
+def write_partition_files(
+    fs: fsspec.AbstractFileSystem,
+    partition: str,
+    tables: list[pa.Table],
+    replaced: list[str],
+    row_group_size: int,
+) -> list[str]:
+    """
+    Write the given tables as the files of the partition, replacing the given files.
+
+    The new files are fully written before any replaced file is removed, so an
+    interrupted write will not lose data (though it may leave duplicates). Each file is
+    named by the zero-padded minimum and maximum `ts_init` of its rows, so that rewritten
+    files sort by name in timestamp order (including files from earlier rewrites which
+    were kept).
+
+    Parameters
+    ----------
+    fs : fsspec.AbstractFileSystem
+        The filesystem for the partition.
+    partition : str
+        The partition directory path.
+    tables : list[pa.Table]
+        The tables to write, one per file.
+    replaced : list[str]
+        The existing file paths the new files replace.
+    row_group_size : int
+        The maximum number of rows per row group.
+
+    Returns
+    -------
+    list[str]
+        The written file paths.
+
+    """
+    tmp_paths: list[str] = []
+    for i, table in enumerate(tables):
+        tmp_path = f"{partition}/.rewrite-{i}.parquet.tmp"
+        pq.write_table(table, where=tmp_path, filesystem=fs, row_group_size=row_group_size)
+        tmp_paths.append(tmp_path)
+
+    for path in replaced:
+        fs.rm(path)
+
+    existing = {path.rsplit("/", 1)[-1] for path in fs.ls(partition, detail=False)}
+    written: list[str] = []
+    for tmp_path, table in zip(tmp_paths, tables):
+        stem = _part_stem(table)
+        name = f"{stem}.parquet"
+        i = 1
+        while name in existing:
+            # Files spanning the same range (such as a split of rows with equal timestamps)
+            name = f"{stem}-{i}.parquet"
+            i += 1
+        path = f"{partition}/{name}"
+        fs.mv(tmp_path, path)
+        existing.add(name)
+        written.append(path)
+
+    return written
+
+
+def _part_stem(table: pa.Table) -> str:
+    if _TS_COLUMN not in table.column_names or table.num_rows == 0:
+        return f"part-{0:020d}-{0:020d}"
+    ts_range = pc.min_max(table.column(_TS_COLUMN))
+    return f"part-{ts_range['min'].as_py():020d}-{ts_range['max'].as_py():020d}"
+
+
+def _check_partition(
+    fs: fsspec.AbstractFileSystem,
+    partition: str,
+    files: list[str],
+    check_duplicates: bool,
+) -> list[CatalogIssue]:
+    issues: list[CatalogIssue] = []
+
+    schemas: dict[str, pa.Schema] = {}
+    for path in files:
+        schema = _read_schema(fs, path)
+        if schema is None:
+            issues.append(
+                CatalogIssue(
+                    partition=partition,
+                    issue_type=CatalogIssueType.UNREADABLE_FILE,
+                    files=(path,),
+                    detail=f"cannot read Parquet file {path}",
+                ),
+            )
+            continue
+        schemas[path] = schema
+
+    reference = _reference_schema(list(schemas.values()))
+    if reference is None:
+        return issues
+
+    for path, schema in schemas.items():
+        if not schema.equals(reference, check_metadata=False):
+            issues.append(
+                CatalogIssue(
+                    partition=partition,
+                    issue_type=CatalogIssueType.SCHEMA_DRIFT,
+                    files=(path,),
+                    detail=f"{path} {_schema_diff(reference, schema)}",
+                ),
+            )
+
+    # Timestamp checks
+    ranges: list[tuple[int, int, str]] = []
+    for path, schema in schemas.items():
+        if _TS_COLUMN not in schema.names:
+            continue
+        ts = _read_table(fs, path, columns=[_TS_COLUMN]).column(_TS_COLUMN).to_numpy()
+        if len(ts) == 0:
+            continue
+        decreases = int(np.count_nonzero(ts[1:] < ts[:-1]))
+        if decreases:
+            issues.append(
+                CatalogIssue(
+                    partition=partition,
+                    issue_type=CatalogIssueType.NON_MONOTONIC_TIMESTAMPS,
+                    files=(path,),
+                    detail=f"{path} has {decreases} decreasing `{_TS_COLUMN}` value(s)",
+                ),
+            )
+        ranges.append((int(ts.min()), int(ts.max()), path))
+
+    ranges.sort()
+    for (_, prev_end, prev_path), (start, end, path) in zip(ranges, ranges[1:]):
+        if start < prev_end:
+            issues.append(
+                CatalogIssue(
+                    partition=partition,
+                    issue_type=CatalogIssueType.OVERLAPPING_RANGES,
+                    files=(prev_path, path),
+                    detail=(
+                        f"{path} [{start}, {end}] overlaps {prev_path} ending at {prev_end}"
+                    ),
+                ),
+            )
+
+    if check_duplicates:
+        reference_files = [
+            path for path, schema in schemas.items()
+            if schema.equals(reference, check_metadata=False)
+        ]
+        table = pa.concat_tables([_read_table(fs, path) for path in reference_files])
+        duplicates = table.num_rows - dedupe_and_sort(table).num_rows
+        if duplicates:
+            issues.append(
+                CatalogIssue(
+                    partition=partition,
+                    issue_type=CatalogIssueType.DUPLICATE_ROWS,
+                    files=tuple(reference_files),
+                    detail=f"{duplicates} duplicate row(s)",
+                ),
+            )
+
+    return issues
+
+
+def _read_schema(fs: fsspec.AbstractFileSystem, path: str) -> pa.Schema | None:
+    try:
+        with fs.open(path, "rb") as f:
+            return pq.read_schema(f)
+    except (pa.ArrowInvalid, OSError):
+        return None
+
+
+def _read_table(
+    fs: fsspec.AbstractFileSystem,
+    path: str,
+    columns: list[str] | None = None,
+) -> pa.Table:
+    with fs.open(path, "rb") as f:
+        return pq.read_table(f, columns=columns)
+
+
+def _reference_schema(schemas: list[pa.Schema]) -> pa.Schema | None:
+    # The most common schema in the partition (ties resolved to the earliest file)
+    groups: list[tuple[pa.Schema, int]] = []
+    for schema in schemas:
+        for i, (group_schema, count) in enumerate(groups):
+            if schema.equals(group_schema, check_metadata=False):
+                groups[i] = (group_schema, count + 1)
+                break
+        else:
+            groups.append((schema, 1))
+
+    if not groups:
+        return None
+    return max(groups, key=lambda group: group[1])[0]
+
+
+def _schema_diff(reference: pa.Schema, schema: pa.Schema) -> str:
+    ref_types = {f.name: f.type for f in reference}
+    types = {f.name: f.type for f in schema}
+    parts = []
+    missing = [name for name in ref_types if name not in types]
+    if missing:
+        parts.append(f"missing {missing}")
+    extra = [name for name in types if name not in ref_types]
+    if extra:
+        parts.append(f"unexpected {extra}")
+    changed = [
+        f"{name} ({ref_types[name]} -> {types[name]})"
+        for name in ref_types
+        if name in types and types[name] != ref_types[name]
+    ]
+    if changed:
+        parts.append(f"changed {changed}")
+    if not parts:
+        parts.append("field order or nullability differs")
+    return "schema differs from reference: " + ", ".join(parts)
+
+
+def _cast_to_schema(table: pa.Table, schema: pa.Schema) -> pa.Table | None:
+    if table.schema.equals(schema, check_metadata=False):
+        return table.replace_schema_metadata(schema.metadata)
+    if not set(schema.names).issubset(table.column_names):
+        return None
+    try:
+        return table.select(schema.names).cast(schema)
+    except (pa.ArrowInvalid, pa.ArrowNotImplementedError, ValueError):
+        return None
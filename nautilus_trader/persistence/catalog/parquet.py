@@ -15,10 +15,13 @@
 
 from __future__ import annotations
 
+import hashlib
 import itertools
+import json
 import os
 import pathlib
 import platform
+import tempfile
 from collections import defaultdict
 from collections.abc import Callable
 from collections.abc import Generator
@@ -56,6 +59,12 @@ from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.data import capsule_to_list
 from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.persistence.catalog.base import BaseDataCatalog
+from nautilus_trader.persistence.catalog.maintenance import DEFAULT_TARGET_FILE_SIZE
+from nautilus_trader.persistence.catalog.maintenance import CatalogConsistencyReport
+from nautilus_trader.persistence.catalog.maintenance import PartitionCompaction
+from nautilus_trader.persistence.catalog.maintenance import check_catalog
+from nautilus_trader.persistence.catalog.maintenance import compact_catalog
+from nautilus_trader.persistence.catalog.maintenance import repair_catalog
 from nautilus_trader.persistence.funcs import class_to_filename
 from nautilus_trader.persistence.funcs import combine_filters
 from nautilus_trader.persistence.funcs import urisafe_instrument_id
@@ -73,6 +82,8 @@ class FeatherFile(NamedTuple):
 
 _NAUTILUS_PATH = "NAUTILUS_PATH"
 _DEFAULT_FS_PROTOCOL = "file"
+_LOCAL_FS_PROTOCOLS = ("file", "local")
+_CACHE_FINGERPRINT_KEYS = ("ETag", "etag", "md5Hash", "LastModified", "mtime", "updated", "created")
 
 
 class ParquetDataCatalog(BaseDataCatalog):
@@ -103,6 +114,14 @@ class ParquetDataCatalog(BaseDataCatalog):
         groups.
     show_query_paths : bool, default False
         If globed query paths should be printed to stdout.
+    local_cache_dir : PathLike[str] | str, optional
+        The local directory for caching files read from a remote catalog (any non-local
+        `fs_protocol`), which is required for the Rust backed queries. If ``None`` then a
+        directory under the system temporary directory is used.
+    write_block_size : int, optional
+        The block size (bytes) for writes to a remote catalog. Files are streamed to the
+        filesystem in blocks of this size (such as parts of an S3 multipart upload),
+        rather than buffered in full. If ``None`` then the filesystem's default is used.
 
     Warnings
     --------
@@ -125,6 +144,8 @@ class ParquetDataCatalog(BaseDataCatalog):
         min_rows_per_group: int = 0,
         max_rows_per_group: int = 5000,
         show_query_paths: bool = False,
+        local_cache_dir: PathLike[str] | str | None = None,
+        write_block_size: int | None = None,
     ) -> None:
         self.fs_protocol: str = fs_protocol or _DEFAULT_FS_PROTOCOL
         self.fs_storage_options = fs_storage_options or {}
@@ -138,7 +159,11 @@ class ParquetDataCatalog(BaseDataCatalog):
         self.max_rows_per_group = max_rows_per_group
         self.show_query_paths = show_query_paths
 
-        final_path = str(make_path_posix(str(path)))
+        if self.is_remote and not isinstance(self.fs, MemoryFileSystem):
+            # Object store paths are relative to the bucket (container) so are not made absolute
+            final_path = str(path).rstrip("/")
+        else:
+            final_path = str(make_path_posix(str(path)))
 
         if (
             isinstance(self.fs, MemoryFileSystem)
@@ -149,6 +174,12 @@ class ParquetDataCatalog(BaseDataCatalog):
 
         self.path = str(final_path)
 
+        self.write_block_size = write_block_size
+        if local_cache_dir is None:
+            digest = hashlib.sha256(f"{self.fs_protocol}://{self.path}".encode()).hexdigest()
+            local_cache_dir = Path(tempfile.gettempdir()) / "nautilus_catalog_cache" / digest[:16]
+        self.local_cache_dir = Path(local_cache_dir)
+
     @classmethod
     def from_env(cls) -> ParquetDataCatalog:
         """
@@ -170,14 +201,16 @@ class ParquetDataCatalog(BaseDataCatalog):
         return cls.from_uri(os.environ[_NAUTILUS_PATH] + "/catalog")
 
     @classmethod
-    def from_uri(cls, uri: str) -> ParquetDataCatalog:
+    def from_uri(cls, uri: str, **kwargs: Any) -> ParquetDataCatalog:
         """
         Create a data catalog instance from the given `uri`.
 
         Parameters
         ----------
         uri : str
-            The URI string for the backing path.
+            The URI string for the backing path (such as 's3://bucket/catalog').
+        kwargs : Any
+            The additional keyword arguments for the catalog.
 
         Returns
         -------
@@ -190,8 +223,61 @@ class ParquetDataCatalog(BaseDataCatalog):
         parsed = infer_storage_options(uri)
         path = parsed.pop("path")
         protocol = parsed.pop("protocol")
+        if protocol not in _LOCAL_FS_PROTOCOLS:
+            # Object store URIs have the bucket (container) parsed as the host, which is
+            # only already prefixed to the path for some protocols (such as S3 and GCS)
+            host = parsed.pop("host", None)
+            if host is not None and not path.startswith(host):
+                path = host + path
+            parsed.pop("url_query", None)
         storage_options = parsed.copy()
-        return cls(path=path, fs_protocol=protocol, fs_storage_options=storage_options)
+        return cls(path=path, fs_protocol=protocol, fs_storage_options=storage_options, **kwargs)
+
+    @property
+    def is_remote(self) -> bool:
+        """
+        Return whether the catalog is stored on a non-local filesystem (such as an object store).
+
+        Returns
+        -------
+        bool
+
+        """
+        return self.fs_protocol not in _LOCAL_FS_PROTOCOLS
+
+    def clear_cache(self) -> None:
+        """
+        Remove all files from the local read cache.
+        """
+        if self.local_cache_dir.exists():
+            for path in self.local_cache_dir.iterdir():
+                path.unlink()
+
+    def _local_path(self, path: str) -> str:
+        # Return a local path for the given catalog file, downloading remote files to the
+        # local cache when not already cached (or when changed since they were cached).
+        if not self.is_remote:
+            return path
+
+        info = self.fs.info(path)
+        fingerprint = {"size": info.get("size")}
+        for key in _CACHE_FINGERPRINT_KEYS:
+            if key in info:
+                fingerprint[key] = str(info[key])
+
+        key = hashlib.sha256(f"{self.fs_protocol}://{path}".encode()).hexdigest()
+        local_path = self.local_cache_dir / f"{key}.parquet"
+        meta_path = self.local_cache_dir / f"{key}.json"
+        if local_path.exists() and meta_path.exists():
+            if json.loads(meta_path.read_text()) == fingerprint:
+                return str(local_path)
+
+        self.local_cache_dir.mkdir(parents=True, exist_ok=True)
+        tmp_path = local_path.with_suffix(".tmp")
+        self.fs.get_file(path, str(tmp_path))
+        os.replace(tmp_path, local_path)
+        meta_path.write_text(json.dumps(fingerprint))
+        return str(local_path)
 
     # -- WRITING ----------------------------------------------------------------------------------
 
@@ -268,6 +354,16 @@ class ParquetDataCatalog(BaseDataCatalog):
     ) -> None:
         name = basename_template.format(i=0)
         fs.mkdirs(path, exist_ok=True)
+        if self.is_remote:
+            # Stream row groups to the filesystem as they are encoded (for object stores
+            # the file is uploaded in parts as each block fills)
+            open_kwargs = {}
+            if self.write_block_size is not None:
+                open_kwargs["block_size"] = self.write_block_size
+            with fs.open(f"{path}/{name}.parquet", "wb", **open_kwargs) as f:
+                pq.write_table(table, where=f, row_group_size=self.max_rows_per_group)
+            return
+
         pq.write_table(
             table,
             where=f"{path}/{name}.parquet",
@@ -393,7 +489,6 @@ class ParquetDataCatalog(BaseDataCatalog):
         session: DataBackendSession | None = None,
         **kwargs: Any,
     ) -> DataBackendSession:
-        assert self.fs_protocol == "file", "Only file:// protocol is supported for Rust queries"
         data_type: NautilusDataType = ParquetDataCatalog._nautilus_data_cls_to_data_type(data_cls)
 
         if session is None:
@@ -407,6 +502,10 @@ class ParquetDataCatalog(BaseDataCatalog):
         if self.show_query_paths:
             print(dirs)
 
+        if self.is_remote:
+            # Remote files are read from the local cache by the Rust backend
+            dirs = [path for path in dirs if path.endswith(".parquet")]
+
         for idx, path in enumerate(dirs):
             assert self.fs.exists(path)
             if instrument_ids and not any(urisafe_instrument_id(x) in path for x in instrument_ids):
@@ -422,7 +521,7 @@ class ParquetDataCatalog(BaseDataCatalog):
                 where=where,
             )
 
-            session.add_file(data_type, table, str(path), query)
+            session.add_file(data_type, table, self._local_path(str(path)), query)
 
         return session
 
@@ -614,6 +713,101 @@ class ParquetDataCatalog(BaseDataCatalog):
         objects = [o for objs in [df for df in dfs if df is not None] for o in objs]
         return objects
 
+    # -- MAINTENANCE ------------------------------------------------------------------------------
+
+    def check_consistency(
+        self,
+        data_cls: type | None = None,
+        check_duplicates: bool = True,
+    ) -> CatalogConsistencyReport:
+        """
+        Check the catalog Parquet partitions for consistency issues.
+
+        Reports unreadable files, schema drift, overlapping file time ranges,
+        non-monotonic timestamps and duplicate rows.
+
+        Parameters
+        ----------
+        data_cls : type, optional
+            The data class to check. If ``None`` then checks all data classes.
+        check_duplicates : bool, default True
+            If duplicate rows should be checked for (requires reading every partition in full).
+
+        Returns
+        -------
+        CatalogConsistencyReport
+
+        """
+        return check_catalog(self, data_cls=data_cls, check_duplicates=check_duplicates)
+
+    def repair(
+        self,
+        report: CatalogConsistencyReport | None = None,
+        data_cls: type | None = None,
+        remove_unreadable: bool = False,
+    ) -> list[str]:
+        """
+        Rewrite the catalog partitions with consistency issues.
+
+        Each affected partition is rewritten as a single deduplicated file sorted by `ts_init`.
+
+        Parameters
+        ----------
+        report : CatalogConsistencyReport, optional
+            The report of issues to repair. If ``None`` then the catalog will be checked.
+        data_cls : type, optional
+            The data class to repair (only applicable if `report` is ``None``).
+        remove_unreadable : bool, default False
+            If unreadable files should be deleted.
+
+        Returns
+        -------
+        list[str]
+            The partitions which were rewritten.
+
+        """
+        return repair_catalog(
+            self,
+            report=report,
+            data_cls=data_cls,
+            remove_unreadable=remove_unreadable,
+        )
+
+    def compact(
+        self,
+        data_cls: type | None = None,
+        target_file_size: int = DEFAULT_TARGET_FILE_SIZE,
+        min_files: int = 2,
+    ) -> list[PartitionCompaction]:
+        """
+        Merge the small files in each catalog partition into target-sized files.
+
+        Only files smaller than `target_file_size` are merged, with duplicate rows removed
+        and rows sorted by `ts_init`. This keeps reads fast for partitions which are
+        appended to frequently (such as when converting live recordings).
+
+        Parameters
+        ----------
+        data_cls : type, optional
+            The data class to compact. If ``None`` then compacts all data classes.
+        target_file_size : int, default 128 MiB
+            The target file size (bytes) for compacted files.
+        min_files : int, default 2
+            The minimum number of small files in a partition for it to be compacted.
+
+        Returns
+        -------
+        list[PartitionCompaction]
+            The compacted partitions.
+
+        """
+        return compact_catalog(
+            self,
+            data_cls=data_cls,
+            target_file_size=target_file_size,
+            min_files=min_files,
+        )
+
     # -- OVERLOADED BASE METHODS ------------------------------------------------------------------
 
     def instruments(
@@ -0,0 +1,109 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from datetime import timedelta
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.persistence.catalog.maintenance import PartitionCompaction
+from nautilus_trader.persistence.config import CatalogCompactorConfig
+
+
+class CatalogCompactor(Actor):
+    """
+    Provides an actor which periodically compacts a Parquet data catalog.
+
+    On each interval the small files in each catalog partition are merged into
+    target-sized files, with duplicate rows removed and rows sorted by `ts_init`.
+    This keeps reads fast for catalogs which are appended to throughout a live session.
+
+    Parameters
+    ----------
+    config : CatalogCompactorConfig
+        The configuration for the instance.
+
+    """
+
+    def __init__(self, config: CatalogCompactorConfig) -> None:
+        super().__init__(config=config)
+
+        self._catalog = config.as_catalog()
+        self._interval = timedelta(minutes=config.interval_mins)
+        self._compact_on_start = config.compact_on_start
+        self._data_cls = config.data_cls
+        self._target_file_size = config.target_file_size
+        self._min_files = config.min_files
+        self._timer_name = f"{self.id}-COMPACTION"
+        self._compaction_count = 0
+
+    @property
+    def compaction_count(self) -> int:
+        """
+        Return the count of compactions run.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._compaction_count
+
+    def on_start(self) -> None:
+        if self._compact_on_start:
+            self.compact()
+
+        self.clock.set_timer(
+            name=self._timer_name,
+            interval=self._interval,
+            callback=self._on_compaction_timer,
+        )
+
+    def on_reset(self) -> None:
+        self._compaction_count = 0
+
+    def compact(self) -> list[PartitionCompaction]:
+        """
+        Compact the catalog now.
+
+        Returns
+        -------
+        list[PartitionCompaction]
+            The compacted partitions.
+
+        """
+        compactions = self._catalog.compact(
+            data_cls=self._data_cls,
+            target_file_size=self._target_file_size,
+            min_files=self._min_files,
+        )
+        self._compaction_count += 1
+
+        for compaction in compactions:
+            self.log.info(
+                f"Compacted {compaction.partition}: "
+                f"{compaction.files_merged} file(s) -> {compaction.files_written}, "
+                f"{compaction.duplicates_removed} duplicate row(s) removed",
+            )
+
+        return compactions
+
+    def _on_compaction_timer(self, event: TimeEvent) -> None:
+        try:
+            self.compact()
+        except Exception as e:
+            # Compaction is retried on the next interval
+            self.log.error(f"Error compacting catalog: {e!r}")
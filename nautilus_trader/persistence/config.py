@@ -17,7 +17,9 @@ from __future__ import annotations
 
 import fsspec
 
+from nautilus_trader.common.config import ActorConfig
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveInt
 
 
 class StreamingConfig(NautilusConfig, frozen=True):
@@ -75,9 +77,153 @@ class DataCatalogConfig(NautilusConfig, frozen=True):
         The fsspec file system protocol for the data catalog.
     fs_storage_options : dict, optional
         The fsspec storage options for the data catalog.
+    local_cache_dir : str, optional
+        The local directory for caching files read from a remote data catalog.
+    write_block_size : PositiveInt, optional
+        The block size (bytes) for streaming writes to a remote data catalog.
 
     """
 
     path: str
     fs_protocol: str | None = None
     fs_storage_options: dict | None = None
+    local_cache_dir: str | None = None
+    write_block_size: PositiveInt | None = None
+
+
+class FeatureRecorderConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``FeatureRecorder`` instances.
+
+    Parameters
+    ----------
+    features : dict[str, str]
+        The feature names mapped to the message bus topics (or patterns) they are recorded from.
+        Feature names prefix the columns extracted from each message.
+    path : str
+        The path of the output file.
+    file_format : str, default 'parquet'
+        The output file format, either 'parquet' or 'arrow' (Arrow IPC stream).
+    fs_protocol : str, default 'file'
+        The `fsspec` filesystem protocol for the output.
+    fs_storage_options : dict, optional
+        The `fsspec` storage options.
+    trigger : str, optional
+        The feature name whose updates append a row.
+        If ``None`` then every feature update appends a row.
+    require_all : bool, default True
+        If rows are only appended once every feature has received a value.
+    flush_rows : int, default 1000
+        The number of buffered rows which triggers a write to the output.
+
+    """
+
+    features: dict[str, str]
+    path: str
+    file_format: str = "parquet"
+    fs_protocol: str = "file"
+    fs_storage_options: dict | None = None
+    trigger: str | None = None
+    require_all: bool = True
+    flush_rows: int = 1000
+
+
+class CatalogCompactorConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``CatalogCompactor`` instances.
+
+    Parameters
+    ----------
+    catalog_path : str
+        The path to the data catalog.
+    fs_protocol : str, optional
+        The `fsspec` filesystem protocol for the catalog.
+    fs_storage_options : dict, optional
+        The `fsspec` storage options.
+    interval_mins : PositiveInt, default 60
+        The interval (minutes) between compactions.
+    compact_on_start : bool, default False
+        If the catalog should also be compacted when the actor starts.
+    data_cls : type, optional
+        The data class to compact. If ``None`` then compacts all data classes.
+    target_file_size : PositiveInt, default 134217728 (128 MiB)
+        The target file size (bytes) for compacted files.
+    min_files : PositiveInt, default 2
+        The minimum number of small files in a partition for it to be compacted.
+
+    """
+
+    catalog_path: str
+    fs_protocol: str | None = None
+    fs_storage_options: dict | None = None
+    interval_mins: PositiveInt = 60
+    compact_on_start: bool = False
+    data_cls: type | None = None
+    target_file_size: PositiveInt = 128 * 1024 * 1024
+    min_files: PositiveInt = 2
+
+    def as_catalog(self):
+        from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+
+        return ParquetDataCatalog(
+            path=self.catalog_path,
+            fs_protocol=self.fs_protocol,
+            fs_storage_options=self.fs_storage_options,
+        )
+
+
+class IlpDataRecorderConfig(ActorConfig, frozen=True):
+    """
+    Configuration for ``IlpDataRecorder`` instances.
+
+    Parameters
+    ----------
+    host : str, default 'localhost'
+        The time-series database host.
+    port : PositiveInt, default 9009
+        The database ILP port (9009 is the QuestDB TCP default).
+    protocol : str, default 'tcp'
+        The transport protocol, either 'tcp' or 'http'.
+    http_path : str, default '/write'
+        The HTTP write path including any query parameters
+        (such as '/api/v2/write?org=my-org&bucket=market-data&precision=ns' for InfluxDB).
+    auth_token : str, optional
+        The HTTP authorization token.
+    quote_instrument_ids : list[str], optional
+        The instrument IDs to record quotes for.
+    trade_instrument_ids : list[str], optional
+        The instrument IDs to record trades for.
+    bar_types : list[str], optional
+        The bar types to record bars for.
+    book_instrument_ids : list[str], optional
+        The instrument IDs to record order book snapshots for.
+    book_depth : PositiveInt, default 5
+        The number of levels per side recorded for order book snapshots.
+    book_interval_ms : PositiveInt, default 1000
+        The interval (milliseconds) between order book snapshots.
+    batch_size : PositiveInt, default 1000
+        The maximum number of lines per write.
+    flush_interval_ms : PositiveInt, default 1000
+        The maximum interval (milliseconds) between a line being recorded and written.
+    max_pending_lines : PositiveInt, default 100_000
+        The maximum number of lines waiting to be written, beyond which lines are dropped.
+    reconnect_interval_ms : PositiveInt, default 1000
+        The interval (milliseconds) before retrying after a failed write.
+
+    """
+
+    host: str = "localhost"
+    port: PositiveInt = 9009
+    protocol: str = "tcp"
+    http_path: str = "/write"
+    auth_token: str | None = None
+    quote_instrument_ids: list[str] | None = None
+    trade_instrument_ids: list[str] | None = None
+    bar_types: list[str] | None = None
+    book_instrument_ids: list[str] | None = None
+    book_depth: PositiveInt = 5
+    book_interval_ms: PositiveInt = 1000
+    batch_size: PositiveInt = 1000
+    flush_interval_ms: PositiveInt = 1000
+    max_pending_lines: PositiveInt = 100_000
+    reconnect_interval_ms: PositiveInt = 1000
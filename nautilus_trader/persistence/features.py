@@ -0,0 +1,229 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from typing import Any
+
+import fsspec
+import pyarrow as pa
+import pyarrow.parquet as pq
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.persistence.config import FeatureRecorderConfig
+
+
+_FILE_FORMATS = ("parquet", "arrow")
+_SCALAR_TYPES = (bool, int, float, str)
+
+
+class FeatureRecorder(Actor):
+    """
+    Provides an actor which records messages from the message bus as aligned feature rows.
+
+    Each configured feature is subscribed to its topic, and the latest scalar values of
+    every feature are held so that each appended row is a snapshot of all features as at
+    the time of the triggering update. Rows are buffered and written to a Parquet file or
+    Arrow IPC stream, for building machine learning training datasets from backtests and
+    live runs.
+
+    Messages are flattened into columns named ``{feature}.{field}`` from a dict, from the
+    objects ``to_dict``, or from a ``value`` attribute (such as for indicators). Scalar
+    messages are recorded under the feature name itself.
+
+    Parameters
+    ----------
+    config : FeatureRecorderConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If `config.features` is empty.
+    ValueError
+        If `config.file_format` is not 'parquet' or 'arrow'.
+    ValueError
+        If `config.trigger` is not one of the configured features.
+    ValueError
+        If `config.flush_rows` is not positive.
+
+    """
+
+    def __init__(self, config: FeatureRecorderConfig) -> None:
+        PyCondition.not_empty(config.features, "config.features")
+        PyCondition.is_in(config.file_format, _FILE_FORMATS, "config.file_format", "_FILE_FORMATS")
+        if config.trigger is not None:
+            PyCondition.is_in(config.trigger, config.features, "config.trigger", "config.features")
+        PyCondition.positive_int(config.flush_rows, "config.flush_rows")
+        super().__init__(config=config)
+
+        self._features = dict(config.features)
+        self._path = config.path
+        self._file_format = config.file_format
+        self._fs: fsspec.AbstractFileSystem = fsspec.filesystem(
+            config.fs_protocol,
+            **(config.fs_storage_options or {}),
+        )
+        self._trigger = config.trigger
+        self._require_all = config.require_all
+        self._flush_rows = config.flush_rows
+
+        self._handlers: dict[str, Callable[[Any], None]] = {}
+        self._latest: dict[str, dict[str, Any]] = {}
+        self._rows: list[dict[str, Any]] = []
+        self._schema: pa.Schema | None = None
+        self._dropped_columns: set[str] = set()
+        self._file: Any = None
+        self._writer: pq.ParquetWriter | pa.RecordBatchStreamWriter | None = None
+        self._row_count = 0
+
+    @property
+    def row_count(self) -> int:
+        """
+        Return the count of rows recorded (buffered or written).
+
+        Returns
+        -------
+        int
+
+        """
+        return self._row_count
+
+    def on_start(self) -> None:
+        for name, topic in self._features.items():
+            handler = self._create_handler(name)
+            self._handlers[name] = handler
+            self.msgbus.subscribe(topic=topic, handler=handler)
+
+    def on_stop(self) -> None:
+        for name, handler in self._handlers.items():
+            self.msgbus.unsubscribe(topic=self._features[name], handler=handler)
+        self._handlers.clear()
+        self.flush()
+        self._close()
+
+    def on_reset(self) -> None:
+        self._latest.clear()
+        self._rows.clear()
+        self._row_count = 0
+
+    def record(self, name: str, message: Any) -> None:
+        """
+        Record the given message as the latest value of the feature `name`.
+
+        Appends a row if `name` is the trigger feature (or there is no trigger), and all
+        features have values when `require_all` is set.
+
+        Parameters
+        ----------
+        name : str
+            The feature name for the message.
+        message : Any
+            The message to record.
+
+        """
+        values = self._flatten(name, message)
+        if values is None:
+            self.log.warning(f"Cannot record {type(message).__name__} for feature {name!r}")
+            return
+
+        self._latest[name] = values
+
+        if self._trigger is not None and name != self._trigger:
+            return
+        if self._require_all and len(self._latest) < len(self._features):
+            return  # Not yet aligned
+
+        row: dict[str, Any] = {"ts": self.clock.timestamp_ns()}
+        for feature_values in self._latest.values():
+            row.update(feature_values)
+        self._rows.append(row)
+        self._row_count += 1
+
+        if len(self._rows) >= self._flush_rows:
+            self.flush()
+
+    def flush(self) -> None:
+        """
+        Write all buffered rows to the output.
+
+        The output is opened on the first write, and its schema is fixed by the columns
+        of that write (columns first seen afterwards are dropped).
+
+        """
+        if not self._rows:
+            return
+
+        if self._writer is None:
+            self._schema = pa.Table.from_pylist(self._rows).schema
+            self._open()
+        else:
+            self._check_columns()
+
+        table = pa.Table.from_pylist(self._rows, schema=self._schema)
+        self._rows.clear()
+        self._writer.write_table(table)
+
+    def _create_handler(self, name: str) -> Callable[[Any], None]:
+        def handler(message: Any) -> None:
+            self.record(name, message)
+
+        return handler
+
+    def _flatten(self, name: str, message: Any) -> dict[str, Any] | None:
+        if isinstance(message, _SCALAR_TYPES):
+            return {name: message}
+
+        if isinstance(message, dict):
+            values = message
+        elif hasattr(type(message), "to_dict"):
+            values = type(message).to_dict(message)
+        elif hasattr(message, "value"):
+            values = {"value": message.value}
+        else:
+            return None
+
+        return {
+            f"{name}.{key}": value
+            for key, value in values.items()
+            if value is None or isinstance(value, _SCALAR_TYPES)
+        }
+
+    def _check_columns(self) -> None:
+        known = set(self._schema.names)
+        for row in self._rows:
+            for column in row.keys() - known - self._dropped_columns:
+                self._dropped_columns.add(column)
+                self.log.warning(f"Dropping column {column!r} first seen after the schema was written")
+
+    def _open(self) -> None:
+        self._fs.makedirs(self._fs._parent(self._path), exist_ok=True)
+        self._file = self._fs.open(self._path, "wb")
+        if self._file_format == "parquet":
+            self._writer = pq.ParquetWriter(self._file, self._schema)
+        else:
+            self._writer = pa.ipc.new_stream(self._file, self._schema)
+
+    def _close(self) -> None:
+        if self._writer is not None:
+            self._writer.close()
+            self._writer = None
+        if self._file is not None:
+            self._file.close()
+            self._file = None
+        self._schema = None
+        self._dropped_columns.clear()
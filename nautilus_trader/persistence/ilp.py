@@ -0,0 +1,629 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import http.client
+import math
+import queue
+import socket
+import threading
+import time
+from datetime import timedelta
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.common.component import TimeEvent
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.book import OrderBook
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import aggressor_side_to_str
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.persistence.config import IlpDataRecorderConfig
+
+
+_PROTOCOLS = ("tcp", "http")
+
+IlpFieldValue = bool | int | float | str
+
+
+def _escape(value: str, chars: str) -> str:
+    value = value.replace("\\", "\\\\")
+    for char in chars:
+        value = value.replace(char, f"\\{char}")
+    return value.replace("\n", "\\n")
+
+
+def _format_field_value(value: IlpFieldValue) -> str | None:
+    if isinstance(value, bool):  # Must be checked before `int`
+        return "t" if value else "f"
+    if isinstance(value, int):
+        return f"{value}i"
+    if isinstance(value, float):
+        return repr(value) if math.isfinite(value) else None
+    return '"' + value.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n") + '"'
+
+
+def format_ilp_line(
+    measurement: str,
+    tags: dict[str, str],
+    fields: dict[str, IlpFieldValue],
+    ts_ns: int,
+) -> str:
+    """
+    Return an InfluxDB line protocol (ILP) line for the given values.
+
+    Parameters
+    ----------
+    measurement : str
+        The measurement (table) name.
+    tags : dict[str, str]
+        The tag (symbol) names and values.
+    fields : dict[str, bool | int | float | str]
+        The field (column) names and values. Non-finite floats are omitted.
+    ts_ns : int
+        The UNIX timestamp (nanoseconds) for the line.
+
+    Returns
+    -------
+    str
+
+    Raises
+    ------
+    ValueError
+        If `measurement` is empty.
+    ValueError
+        If `fields` has no values which can be written.
+
+    """
+    PyCondition.valid_string(measurement, "measurement")
+
+    formatted_fields = []
+    for key, value in fields.items():
+        formatted = _format_field_value(value)
+        if formatted is not None:
+            formatted_fields.append(f"{_escape(key, ',= ')}={formatted}")
+    PyCondition.not_empty(formatted_fields, "formatted_fields")
+
+    line = _escape(measurement, ", ")
+    for key, value in tags.items():
+        line += f",{_escape(key, ',= ')}={_escape(value, ',= ')}"
+    return f"{line} {','.join(formatted_fields)} {ts_ns}"
+
+
+class IlpSender:
+    """
+    Provides a batching line protocol (ILP) sender to a time-series database.
+
+    Lines are queued by the caller and written in batches from a background thread,
+    either over a raw TCP socket (QuestDB) or as HTTP write requests (QuestDB and InfluxDB).
+    The queue is bounded: while the database is slow or unreachable the queue fills and
+    further lines are dropped (and counted), so the caller is never blocked.
+
+    Failed batches are retried after `reconnect_interval_ms`, except HTTP client errors
+    (such as malformed lines) which are dropped.
+
+    Parameters
+    ----------
+    host : str
+        The database host.
+    port : int
+        The database ILP port.
+    protocol : str, default 'tcp'
+        The transport protocol, either 'tcp' or 'http'.
+    http_path : str, default '/write'
+        The HTTP write path including any query parameters.
+    auth_token : str, optional
+        The HTTP authorization token.
+    batch_size : int, default 1000
+        The maximum number of lines per write.
+    flush_interval_ms : int, default 1000
+        The maximum interval (milliseconds) between a line being queued and written.
+    max_pending_lines : int, default 100_000
+        The maximum number of queued lines, beyond which lines are dropped.
+    reconnect_interval_ms : int, default 1000
+        The interval (milliseconds) before retrying after a failed write.
+    timeout_secs : float, default 5.0
+        The connection and write timeout (seconds).
+
+    Raises
+    ------
+    ValueError
+        If `protocol` is not 'tcp' or 'http'.
+
+    """
+
+    def __init__(
+        self,
+        host: str,
+        port: int,
+        protocol: str = "tcp",
+        http_path: str = "/write",
+        auth_token: str | None = None,
+        batch_size: int = 1000,
+        flush_interval_ms: int = 1000,
+        max_pending_lines: int = 100_000,
+        reconnect_interval_ms: int = 1000,
+        timeout_secs: float = 5.0,
+    ) -> None:
+        PyCondition.is_in(protocol, _PROTOCOLS, "protocol", "_PROTOCOLS")
+        PyCondition.positive_int(batch_size, "batch_size")
+        PyCondition.positive_int(flush_interval_ms, "flush_interval_ms")
+        PyCondition.positive_int(max_pending_lines, "max_pending_lines")
+        PyCondition.positive_int(reconnect_interval_ms, "reconnect_interval_ms")
+
+        self._host = host
+        self._port = port
+        self._protocol = protocol
+        self._http_path = http_path
+        self._auth_token = auth_token
+        self._batch_size = batch_size
+        self._flush_interval = flush_interval_ms / 1000
+        self._reconnect_interval = reconnect_interval_ms / 1000
+        self._timeout = timeout_secs
+
+        self._queue: queue.Queue[str] = queue.Queue(maxsize=max_pending_lines)
+        self._stop_event = threading.Event()
+        self._thread: threading.Thread | None = None
+        self._socket: socket.socket | None = None
+        self._http: http.client.HTTPConnection | None = None
+        self._lock = threading.Lock()
+        self._sent_count = 0
+        self._dropped_count = 0
+        self._error_count = 0
+        self._last_error: str | None = None
+
+    @property
+    def is_running(self) -> bool:
+        """
+        Return whether the background sender thread is running.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._thread is not None and self._thread.is_alive()
+
+    @property
+    def sent_count(self) -> int:
+        """
+        Return the count of lines written to the database.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._sent_count
+
+    @property
+    def dropped_count(self) -> int:
+        """
+        Return the count of lines dropped (due to backpressure or rejected writes).
+
+        Returns
+        -------
+        int
+
+        """
+        return self._dropped_count
+
+    @property
+    def error_count(self) -> int:
+        """
+        Return the count of failed writes.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._error_count
+
+    @property
+    def last_error(self) -> str | None:
+        """
+        Return the last write error (if any).
+
+        Returns
+        -------
+        str or ``None``
+
+        """
+        return self._last_error
+
+    @property
+    def pending_count(self) -> int:
+        """
+        Return the count of queued lines not yet written.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._queue.qsize()
+
+    def start(self) -> None:
+        """
+        Start the background sender thread.
+        """
+        if self.is_running:
+            return
+        self._stop_event.clear()
+        self._thread = threading.Thread(target=self._run, name="IlpSender", daemon=True)
+        self._thread.start()
+
+    def stop(self, timeout_secs: float = 5.0) -> None:
+        """
+        Stop the background sender thread, first writing any queued lines.
+
+        Lines which cannot be written (such as when the database is unreachable) are dropped.
+
+        Parameters
+        ----------
+        timeout_secs : float, default 5.0
+            The maximum time (seconds) to wait for queued lines to be written.
+
+        """
+        self._stop_event.set()
+        if self._thread is not None:
+            self._thread.join(timeout=timeout_secs)
+            self._thread = None
+        self._close()
+
+    def send(self, line: str) -> bool:
+        """
+        Queue the given line for writing.
+
+        Parameters
+        ----------
+        line : str
+            The ILP line (without a trailing newline).
+
+        Returns
+        -------
+        bool
+            True if queued, or False if dropped as the queue is full.
+
+        """
+        try:
+            self._queue.put_nowait(line)
+            return True
+        except queue.Full:
+            self._add_dropped(1)
+            return False
+
+    def _run(self) -> None:
+        batch: list[str] = []
+        next_flush = time.monotonic() + self._flush_interval
+        while True:
+            stopping = self._stop_event.is_set()
+            if len(batch) < self._batch_size:
+                timeout = 0.0 if stopping else max(0.0, next_flush - time.monotonic())
+                try:
+                    batch.append(self._queue.get(timeout=timeout))
+                    while len(batch) < self._batch_size:
+                        batch.append(self._queue.get_nowait())
+                except queue.Empty:
+                    pass
+
+            now = time.monotonic()
+            if batch and (len(batch) >= self._batch_size or now >= next_flush or stopping):
+                if self._write(batch):
+                    batch = []
+                elif stopping:
+                    self._add_dropped(len(batch) + self._queue.qsize())
+                    return
+                else:
+                    self._stop_event.wait(self._reconnect_interval)
+                next_flush = time.monotonic() + self._flush_interval
+            elif now >= next_flush:
+                next_flush = now + self._flush_interval
+
+            if stopping and not batch and self._queue.empty():
+                return
+
+    def _write(self, batch: list[str]) -> bool:
+        payload = ("\n".join(batch) + "\n").encode()
+        try:
+            if self._protocol == "tcp":
+                self._write_tcp(payload)
+            else:
+                self._write_http(payload)
+        except _RejectedWriteError as e:
+            # Retrying will not succeed, so drop the batch
+            self._on_error(e)
+            self._add_dropped(len(batch))
+            return True
+        except (OSError, http.client.HTTPException) as e:
+            self._on_error(e)
+            self._close()
+            return False
+
+        with self._lock:
+            self._sent_count += len(batch)
+        return True
+
+    def _write_tcp(self, payload: bytes) -> None:
+        if self._socket is None:
+            self._socket = socket.create_connection((self._host, self._port), self._timeout)
+        self._socket.sendall(payload)
+
+    def _write_http(self, payload: bytes) -> None:
+        if self._http is None:
+            self._http = http.client.HTTPConnection(self._host, self._port, timeout=self._timeout)
+        headers = {"Content-Type": "text/plain; charset=utf-8"}
+        if self._auth_token is not None:
+            headers["Authorization"] = f"Token {self._auth_token}"
+        self._http.request("POST", self._http_path, body=payload, headers=headers)
+        response = self._http.getresponse()
+        body = response.read().decode(errors="replace")
+        if response.status < 300:
+            return
+        message = f"HTTP {response.status} {response.reason}: {body.strip()}"
+        if 400 <= response.status < 500 and response.status != 429:
+            raise _RejectedWriteError(message)
+        raise OSError(message)
+
+    def _on_error(self, e: Exception) -> None:
+        with self._lock:
+            self._error_count += 1
+            self._last_error = repr(e)
+
+    def _add_dropped(self, count: int) -> None:
+        with self._lock:
+            self._dropped_count += count
+
+    def _close(self) -> None:
+        if self._socket is not None:
+            self._socket.close()
+            self._socket = None
+        if self._http is not None:
+            self._http.close()
+            self._http = None
+
+
+class _RejectedWriteError(Exception):
+    pass
+
+
+class IlpDataRecorder(Actor):
+    """
+    Provides an actor which records live market data to a time-series database over the
+    InfluxDB line protocol (ILP), such as QuestDB or InfluxDB.
+
+    Subscribed quotes, trades, bars and order book snapshots are written to the
+    `quotes`, `trades`, `bars` and `order_book` measurements respectively, tagged by
+    instrument ID (and bar type), and timestamped by the data's `ts_event`. Each line also
+    has a `latency_ns` field (`ts_init - ts_event`) for monitoring feed latency.
+
+    Lines are written in batches from a background thread. If the database cannot keep
+    up then lines are dropped rather than blocking the node, and a warning is logged
+    with the dropped count on each flush interval.
+
+    Parameters
+    ----------
+    config : IlpDataRecorderConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If `config.protocol` is not 'tcp' or 'http'.
+
+    """
+
+    def __init__(self, config: IlpDataRecorderConfig) -> None:
+        PyCondition.is_in(config.protocol, _PROTOCOLS, "config.protocol", "_PROTOCOLS")
+        super().__init__(config=config)
+
+        self._quote_instrument_ids = [
+            InstrumentId.from_str(x) for x in config.quote_instrument_ids or []
+        ]
+        self._trade_instrument_ids = [
+            InstrumentId.from_str(x) for x in config.trade_instrument_ids or []
+        ]
+        self._bar_types = [BarType.from_str(x) for x in config.bar_types or []]
+        self._book_instrument_ids = [
+            InstrumentId.from_str(x) for x in config.book_instrument_ids or []
+        ]
+        self._book_depth = config.book_depth
+        self._book_interval_ms = config.book_interval_ms
+        self._flush_interval = timedelta(milliseconds=config.flush_interval_ms)
+        self._timer_name = f"{self.id}-STATUS"
+
+        self._sender: IlpSender | None = None
+        self._reported_dropped_count = 0
+        self._reported_error: str | None = None
+
+    @property
+    def lines_sent(self) -> int:
+        """
+        Return the count of lines written to the database.
+
+        Returns
+        -------
+        int
+
+        """
+        return 0 if self._sender is None else self._sender.sent_count
+
+    @property
+    def lines_dropped(self) -> int:
+        """
+        Return the count of lines dropped (due to backpressure or rejected writes).
+
+        Returns
+        -------
+        int
+
+        """
+        return 0 if self._sender is None else self._sender.dropped_count
+
+    def on_start(self) -> None:
+        config: IlpDataRecorderConfig = self.config
+        self._sender = IlpSender(
+            host=config.host,
+            port=config.port,
+            protocol=config.protocol,
+            http_path=config.http_path,
+            auth_token=config.auth_token,
+            batch_size=config.batch_size,
+            flush_interval_ms=config.flush_interval_ms,
+            max_pending_lines=config.max_pending_lines,
+            reconnect_interval_ms=config.reconnect_interval_ms,
+        )
+        self._sender.start()
+        self._reported_dropped_count = 0
+        self._reported_error = None
+
+        for instrument_id in self._quote_instrument_ids:
+            self.subscribe_quote_ticks(instrument_id)
+        for instrument_id in self._trade_instrument_ids:
+            self.subscribe_trade_ticks(instrument_id)
+        for bar_type in self._bar_types:
+            self.subscribe_bars(bar_type)
+        for instrument_id in self._book_instrument_ids:
+            self.subscribe_order_book_snapshots(
+                instrument_id,
+                depth=self._book_depth,
+                interval_ms=self._book_interval_ms,
+            )
+
+        self.clock.set_timer(
+            name=self._timer_name,
+            interval=self._flush_interval,
+            callback=self._on_status_timer,
+        )
+
+    def on_stop(self) -> None:
+        for instrument_id in self._quote_instrument_ids:
+            self.unsubscribe_quote_ticks(instrument_id)
+        for instrument_id in self._trade_instrument_ids:
+            self.unsubscribe_trade_ticks(instrument_id)
+        for bar_type in self._bar_types:
+            self.unsubscribe_bars(bar_type)
+        for instrument_id in self._book_instrument_ids:
+            self.unsubscribe_order_book_snapshots(
+                instrument_id,
+                interval_ms=self._book_interval_ms,
+            )
+
+        if self._sender is not None:
+            self._sender.stop()
+            self._report_status()
+
+    def on_quote_tick(self, tick: QuoteTick) -> None:
+        self._send(
+            "quotes",
+            {"instrument_id": tick.instrument_id.value},
+            {
+                "bid": tick.bid_price.as_double(),
+                "ask": tick.ask_price.as_double(),
+                "bid_size": tick.bid_size.as_double(),
+                "ask_size": tick.ask_size.as_double(),
+            },
+            tick.ts_event,
+            tick.ts_init,
+        )
+
+    def on_trade_tick(self, tick: TradeTick) -> None:
+        self._send(
+            "trades",
+            {
+                "instrument_id": tick.instrument_id.value,
+                "aggressor_side": aggressor_side_to_str(tick.aggressor_side),
+            },
+            {
+                "price": tick.price.as_double(),
+                "size": tick.size.as_double(),
+                "trade_id": tick.trade_id.value,
+            },
+            tick.ts_event,
+            tick.ts_init,
+        )
+
+    def on_bar(self, bar: Bar) -> None:
+        self._send(
+            "bars",
+            {
+                "instrument_id": bar.bar_type.instrument_id.value,
+                "bar_type": str(bar.bar_type),
+            },
+            {
+                "open": bar.open.as_double(),
+                "high": bar.high.as_double(),
+                "low": bar.low.as_double(),
+                "close": bar.close.as_double(),
+                "volume": bar.volume.as_double(),
+            },
+            bar.ts_event,
+            bar.ts_init,
+        )
+
+    def on_order_book(self, order_book: OrderBook) -> None:
+        fields: dict[str, IlpFieldValue] = {}
+        for side, levels in (("bid", order_book.bids()), ("ask", order_book.asks())):
+            for i, level in enumerate(levels[: self._book_depth]):
+                fields[f"{side}_price_{i}"] = level.price.as_double()
+                fields[f"{side}_size_{i}"] = level.size()
+        if not fields:
+            return  # Empty book
+
+        spread = order_book.spread()
+        if spread is not None:
+            fields["spread"] = float(spread)
+
+        self._send(
+            "order_book",
+            {"instrument_id": order_book.instrument_id.value},
+            fields,
+            order_book.ts_last,
+            order_book.ts_init,
+        )
+
+    def _send(
+        self,
+        measurement: str,
+        tags: dict[str, str],
+        fields: dict[str, IlpFieldValue],
+        ts_event: int,
+        ts_init: int,
+    ) -> None:
+        if self._sender is None:
+            return
+        fields["latency_ns"] = ts_init - ts_event
+        self._sender.send(format_ilp_line(measurement, tags, fields, ts_event))
+
+    def _on_status_timer(self, event: TimeEvent) -> None:
+        self._report_status()
+
+    def _report_status(self) -> None:
+        dropped_count = self._sender.dropped_count
+        if dropped_count > self._reported_dropped_count:
+            self.log.warning(
+                f"Dropped {dropped_count - self._reported_dropped_count} ILP line(s) "
+                f"({dropped_count} total, {self._sender.pending_count} pending)",
+            )
+            self._reported_dropped_count = dropped_count
+
+        last_error = self._sender.last_error
+        if last_error is not None and last_error != self._reported_error:
+            self.log.error(f"Error writing ILP lines: {last_error}")
+            self._reported_error = last_error
@@ -16,6 +16,64 @@
 from __future__ import annotations
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveFloat
+from nautilus_trader.common.config import PositiveInt
+
+
+class StrategyRiskLimits(NautilusConfig, frozen=True):
+    """
+    Configuration for the pre-trade risk limits of a single strategy.
+
+    Limits which are ``None`` (or empty) are not checked.
+
+    Parameters
+    ----------
+    max_open_orders : PositiveInt, optional
+        The maximum number of open (and in-flight) orders for the strategy.
+    max_position_size : dict[str, str], default empty dict
+        The maximum absolute position size per instrument ID, including the
+        quantity of any open orders on the same side.
+        The value should be a valid decimal format.
+    max_gross_exposure : str, optional
+        The maximum gross notional exposure across all instruments for the strategy,
+        as a money string e.g. "1_000_000 USD".
+    max_net_exposure : str, optional
+        The maximum absolute net notional exposure across all instruments for the
+        strategy, as a money string e.g. "500_000 USD".
+
+    """
+
+    max_open_orders: PositiveInt | None = None
+    max_position_size: dict[str, str] = {}
+    max_gross_exposure: str | None = None
+    max_net_exposure: str | None = None
+
+
+class PriceBand(NautilusConfig, frozen=True):
+    """
+    Configuration for the price band (fat-finger) check of an instrument class.
+
+    Prices are compared with the current best ask (for buy orders) or best bid
+    (for sell orders), falling back to the last trade price. If no reference
+    price is available then the check is skipped.
+
+    Parameters
+    ----------
+    limit_max_deviation_pct : PositiveFloat, optional
+        The maximum deviation of a limit price from the reference price, as a percentage.
+    limit_max_deviation_ticks : PositiveInt, optional
+        The maximum deviation of a limit price from the reference price, in ticks.
+    stop_max_deviation_pct : PositiveFloat, optional
+        The maximum deviation of a trigger price from the reference price, as a percentage.
+    stop_max_deviation_ticks : PositiveInt, optional
+        The maximum deviation of a trigger price from the reference price, in ticks.
+
+    """
+
+    limit_max_deviation_pct: PositiveFloat | None = None
+    limit_max_deviation_ticks: PositiveInt | None = None
+    stop_max_deviation_pct: PositiveFloat | None = None
+    stop_max_deviation_ticks: PositiveInt | None = None
 
 
 class RiskEngineConfig(NautilusConfig, frozen=True):
@@ -33,6 +91,14 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_notional_per_order : dict[str, int], default empty dict
         The maximum notional value of an order per instrument ID.
         The value should be a valid decimal format.
+    strategy_limits : dict[str, StrategyRiskLimits], default empty dict
+        The pre-trade risk limits per strategy ID.
+    price_bands : dict[str, PriceBand], default empty dict
+        The price bands per instrument class e.g. "SPOT", "FUTURE".
+    venue_precision_modes : dict[str, str], default empty dict
+        The precision mode per venue e.g. {"BINANCE": "STRICT"}. Orders for these venues
+        are denied if a price or quantity exceeds the instrument precision, or is not a
+        multiple of the instrument increment.
     debug : bool, default False
         If debug mode is active (will provide extra debug logging).
 
@@ -42,4 +108,7 @@ class RiskEngineConfig(NautilusConfig, frozen=True):
     max_order_submit_rate: str = "100/00:00:01"
     max_order_modify_rate: str = "100/00:00:01"
     max_notional_per_order: dict[str, int] = {}
+    strategy_limits: dict[str, StrategyRiskLimits] = {}
+    price_bands: dict[str, PriceBand] = {}
+    venue_precision_modes: dict[str, str] = {}
     debug: bool = False
@@ -20,6 +20,8 @@ from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport Throttler
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
+from nautilus_trader.core.rust.model cimport InstrumentClass
+from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -28,18 +30,24 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Currency
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.portfolio.base cimport PortfolioFacade
+from nautilus_trader.risk.messages cimport RiskCommand
 
 
 cdef class RiskEngine(Component):
     cdef readonly PortfolioFacade _portfolio
     cdef readonly Cache _cache
     cdef readonly dict _max_notional_per_order
+    cdef readonly dict _strategy_risk_limits
+    cdef readonly dict _price_bands
+    cdef readonly object _precision_normalizer
     cdef readonly Throttler _order_submit_throttler
     cdef readonly Throttler _order_modify_throttler
 
@@ -60,6 +68,10 @@ cdef class RiskEngine(Component):
     cpdef void process(self, Event event)
     cpdef void set_trading_state(self, TradingState state)
     cpdef void set_max_notional_per_order(self, InstrumentId instrument_id, new_value: Decimal)
+    cpdef void set_max_order_submit_rate(self, str rate)
+    cpdef void set_max_order_modify_rate(self, str rate)
+    cpdef void set_strategy_risk_limits(self, StrategyId strategy_id, limits)
+    cpdef void set_price_band(self, InstrumentClass instrument_class, band)
     cpdef void _log_state(self)
 
 # -- RISK SETTINGS --------------------------------------------------------------------------------
@@ -68,6 +80,10 @@ cdef class RiskEngine(Component):
     cpdef tuple max_order_modify_rate(self)
     cpdef dict max_notionals_per_order(self)
     cpdef object max_notional_per_order(self, InstrumentId instrument_id)
+    cpdef dict strategies_risk_limits(self)
+    cpdef object strategy_risk_limits(self, StrategyId strategy_id)
+    cpdef dict price_bands(self)
+    cpdef object price_band(self, InstrumentClass instrument_class)
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
@@ -80,6 +96,8 @@ cdef class RiskEngine(Component):
     cpdef void _handle_submit_order(self, SubmitOrder command)
     cpdef void _handle_submit_order_list(self, SubmitOrderList command)
     cpdef void _handle_modify_order(self, ModifyOrder command)
+    cpdef void _handle_risk_command(self, RiskCommand command)
+    cdef void _publish_risk_config_changed(self, RiskCommand command, str setting, str old_value, str new_value)
 
 # -- PRE-TRADE CHECKS -----------------------------------------------------------------------------
 
@@ -88,7 +106,12 @@ cdef class RiskEngine(Component):
     cpdef bint _check_order_quantity(self, Instrument instrument, Order order)
     cpdef bint _check_orders_risk(self, Instrument instrument, list orders)
     cpdef str _check_price(self, Instrument instrument, Price price)
+    cpdef str _check_price_band(self, Instrument instrument, OrderSide side, Price price, bint is_trigger)
     cpdef str _check_quantity(self, Instrument instrument, Quantity quantity)
+    cpdef str _check_strategy_limits(self, Instrument instrument, StrategyId strategy_id, list orders)
+    cdef list _strategy_working_orders(self, StrategyId strategy_id, InstrumentId instrument_id, list orders)
+    cdef tuple _strategy_exposure(self, StrategyId strategy_id, list orders, Currency currency)
+    cdef Price _mark_price(self, Instrument instrument)
 
 # -- DENIALS --------------------------------------------------------------------------------------
 
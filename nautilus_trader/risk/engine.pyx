@@ -17,7 +17,10 @@ from decimal import Decimal
 
 import pandas as pd
 
+from nautilus_trader.data.precision import PrecisionNormalizer
+from nautilus_trader.risk.config import PriceBand
 from nautilus_trader.risk.config import RiskEngineConfig
+from nautilus_trader.risk.config import StrategyRiskLimits
 
 from libc.stdint cimport uint64_t
 
@@ -31,6 +34,7 @@ from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.component cimport LogColor
 from nautilus_trader.common.component cimport MessageBus
 from nautilus_trader.common.component cimport Throttler
+from nautilus_trader.common.messages cimport RiskConfigChanged
 from nautilus_trader.common.messages cimport TradingStateChanged
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.message cimport Command
@@ -39,6 +43,7 @@ from nautilus_trader.core.rust.model cimport InstrumentClass
 from nautilus_trader.core.rust.model cimport OrderSide
 from nautilus_trader.core.rust.model cimport OrderStatus
 from nautilus_trader.core.rust.model cimport OrderType
+from nautilus_trader.core.rust.model cimport PriceType
 from nautilus_trader.core.rust.model cimport TradingState
 from nautilus_trader.core.rust.model cimport TriggerType
 from nautilus_trader.core.uuid cimport UUID4
@@ -53,10 +58,13 @@ from nautilus_trader.model.data cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderCancelRejected
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.events.order cimport OrderModifyRejected
+from nautilus_trader.model.functions cimport instrument_class_from_str
+from nautilus_trader.model.functions cimport instrument_class_to_str
 from nautilus_trader.model.functions cimport order_type_to_str
 from nautilus_trader.model.functions cimport trading_state_to_str
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Currency
@@ -67,6 +75,12 @@ from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.model.position cimport Position
 from nautilus_trader.portfolio.base cimport PortfolioFacade
+from nautilus_trader.risk.messages cimport RiskCommand
+from nautilus_trader.risk.messages cimport SetMaxNotionalPerOrder
+from nautilus_trader.risk.messages cimport SetOrderRateLimits
+from nautilus_trader.risk.messages cimport SetPriceBand
+from nautilus_trader.risk.messages cimport SetStrategyRiskLimits
+from nautilus_trader.risk.messages cimport SetTradingState
 
 
 cdef class RiskEngine(Component):
@@ -82,6 +96,14 @@ cdef class RiskEngine(Component):
      - ``REDUCING`` (only new orders or updates which reduce an open position are allowed).
      - ``HALTED`` (all trading commands except cancels are denied).
 
+    Per-strategy limits (max open orders, max position size per instrument and
+    max gross/net exposure) and price bands can be configured.
+
+    Risk settings, throttler rates and the trading state can be changed at runtime
+    (without restarting the node) by sending a `RiskCommand` to the
+    `RiskEngine.execute` endpoint. Each applied change publishes a `RiskConfigChanged`
+    event to the `events.risk` topic as an audit record.
+
     Parameters
     ----------
     portfolio : PortfolioFacade
@@ -133,9 +155,7 @@ cdef class RiskEngine(Component):
         self.event_count = 0
 
         # Throttlers
-        pieces = config.max_order_submit_rate.split("/")
-        order_submit_rate_limit = int(pieces[0])
-        order_submit_rate_interval = pd.to_timedelta(pieces[1])
+        order_submit_rate_limit, order_submit_rate_interval = _parse_rate(config.max_order_submit_rate)
         self._order_submit_throttler = Throttler(
             name="ORDER_SUBMIT_THROTTLER",
             limit=order_submit_rate_limit,
@@ -147,13 +167,11 @@ cdef class RiskEngine(Component):
 
         self._log.info(
             f"Set MAX_ORDER_SUBMIT_RATE: "
-            f"{order_submit_rate_limit}/{str(order_submit_rate_interval).replace('0 days ', '')}.",
+            f"{_format_rate(order_submit_rate_limit, order_submit_rate_interval)}.",
             color=LogColor.BLUE,
         )
 
-        pieces = config.max_order_modify_rate.split("/")
-        order_modify_rate_limit = int(pieces[0])
-        order_modify_rate_interval = pd.to_timedelta(pieces[1])
+        order_modify_rate_limit, order_modify_rate_interval = _parse_rate(config.max_order_modify_rate)
         self._order_modify_throttler = Throttler(
             name="ORDER_MODIFY_THROTTLER",
             limit=order_modify_rate_limit,
@@ -165,12 +183,15 @@ cdef class RiskEngine(Component):
 
         self._log.info(
             f"Set MAX_ORDER_MODIFY_RATE: "
-            f"{order_modify_rate_limit}/{str(order_modify_rate_interval).replace('0 days ', '')}.",
+            f"{_format_rate(order_modify_rate_limit, order_modify_rate_interval)}.",
             color=LogColor.BLUE,
         )
 
         # Risk settings
         self._max_notional_per_order: dict[InstrumentId, Decimal] = {}
+        self._strategy_risk_limits: dict[StrategyId, StrategyRiskLimits] = {}
+        self._price_bands: dict[InstrumentClass, PriceBand] = {}
+        self._precision_normalizer: PrecisionNormalizer | None = None
 
         # Configure
         self._initialize_risk_checks(config)
@@ -188,6 +209,17 @@ cdef class RiskEngine(Component):
         for instrument_id, value in max_notional_config.items():
             self.set_max_notional_per_order(InstrumentId.from_str_c(instrument_id), Decimal(value))
 
+        cdef dict strategy_limits_config = config.strategy_limits
+        for strategy_id, limits in strategy_limits_config.items():
+            self.set_strategy_risk_limits(StrategyId(strategy_id), limits)
+
+        cdef dict price_bands_config = config.price_bands
+        for instrument_class, band in price_bands_config.items():
+            self.set_price_band(instrument_class_from_str(instrument_class), band)
+
+        if config.venue_precision_modes:
+            self._precision_normalizer = PrecisionNormalizer.from_config(config.venue_precision_modes)
+
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void execute(self, Command command):
@@ -303,6 +335,132 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+    cpdef void set_max_order_submit_rate(self, str rate):
+        """
+        Set the maximum rate of submit order commands.
+
+        Parameters
+        ----------
+        rate : str
+            The rate limit per timedelta interval e.g. "100/00:00:01".
+
+        Raises
+        ------
+        ValueError
+            If `rate` is not a valid rate string with a positive limit and interval.
+
+        """
+        limit, interval = _parse_rate(rate)
+        self._order_submit_throttler.set_rate(limit, interval)
+
+        self._log.info(
+            f"Set MAX_ORDER_SUBMIT_RATE: {_format_rate(limit, interval)}.",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void set_max_order_modify_rate(self, str rate):
+        """
+        Set the maximum rate of modify order commands.
+
+        Parameters
+        ----------
+        rate : str
+            The rate limit per timedelta interval e.g. "100/00:00:01".
+
+        Raises
+        ------
+        ValueError
+            If `rate` is not a valid rate string with a positive limit and interval.
+
+        """
+        limit, interval = _parse_rate(rate)
+        self._order_modify_throttler.set_rate(limit, interval)
+
+        self._log.info(
+            f"Set MAX_ORDER_MODIFY_RATE: {_format_rate(limit, interval)}.",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void set_strategy_risk_limits(self, StrategyId strategy_id, limits):
+        """
+        Set the pre-trade risk limits for the given strategy ID.
+
+        Passing limits of ``None`` will clear all limits for the strategy.
+
+        Parameters
+        ----------
+        strategy_id : StrategyId
+            The strategy ID for the limits.
+        limits : StrategyRiskLimits or ``None``
+            The risk limits to set.
+
+        Raises
+        ------
+        TypeError
+            If `limits` is not ``None`` and not of type `StrategyRiskLimits`.
+        decimal.InvalidOperation
+            If any `max_position_size` value is not a valid input for `decimal.Decimal`.
+        ValueError
+            If any `max_position_size` value is not positive.
+        ValueError
+            If `max_gross_exposure` or `max_net_exposure` is not a valid money string.
+
+        """
+        Condition.not_none(strategy_id, "strategy_id")
+        Condition.type_or_none(limits, StrategyRiskLimits, "limits")
+
+        if limits is None:
+            self._strategy_risk_limits.pop(strategy_id, None)
+            self._log.info(f"Cleared STRATEGY_RISK_LIMITS: {strategy_id}.", color=LogColor.BLUE)
+            return
+
+        # Validate limits up front so that pre-trade checks cannot fail on parsing
+        for max_position_size in limits.max_position_size.values():
+            Condition.positive(Decimal(max_position_size), "max_position_size")
+        if limits.max_gross_exposure is not None:
+            Money.from_str_c(limits.max_gross_exposure)
+        if limits.max_net_exposure is not None:
+            Money.from_str_c(limits.max_net_exposure)
+
+        self._strategy_risk_limits[strategy_id] = limits
+
+        self._log.info(
+            f"Set STRATEGY_RISK_LIMITS: {strategy_id} {limits!r}.",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void set_price_band(self, InstrumentClass instrument_class, band):
+        """
+        Set the price band (fat-finger check) for the given instrument class.
+
+        Passing a band of ``None`` will disable the price band check for the
+        instrument class.
+
+        Parameters
+        ----------
+        instrument_class : InstrumentClass
+            The instrument class for the price band.
+        band : PriceBand or ``None``
+            The price band to set.
+
+        Raises
+        ------
+        TypeError
+            If `band` is not ``None`` and not of type `PriceBand`.
+
+        """
+        Condition.type_or_none(band, PriceBand, "band")
+
+        if band is None:
+            self._price_bands.pop(instrument_class, None)
+        else:
+            self._price_bands[instrument_class] = band
+
+        self._log.info(
+            f"Set PRICE_BAND: {instrument_class_to_str(instrument_class)} {band!r}.",
+            color=LogColor.BLUE,
+        )
+
 # -- RISK SETTINGS --------------------------------------------------------------------------------
 
     cpdef tuple max_order_submit_rate(self):
@@ -357,6 +515,50 @@ cdef class RiskEngine(Component):
         """
         return self._max_notional_per_order.get(instrument_id)
 
+    cpdef dict strategies_risk_limits(self):
+        """
+        Return the current pre-trade risk limits for all strategies.
+
+        Returns
+        -------
+        dict[StrategyId, StrategyRiskLimits]
+
+        """
+        return self._strategy_risk_limits.copy()
+
+    cpdef object strategy_risk_limits(self, StrategyId strategy_id):
+        """
+        Return the current pre-trade risk limits for the given strategy ID.
+
+        Returns
+        -------
+        StrategyRiskLimits or ``None``
+
+        """
+        return self._strategy_risk_limits.get(strategy_id)
+
+    cpdef dict price_bands(self):
+        """
+        Return the current price bands for all instrument classes.
+
+        Returns
+        -------
+        dict[InstrumentClass, PriceBand]
+
+        """
+        return self._price_bands.copy()
+
+    cpdef object price_band(self, InstrumentClass instrument_class):
+        """
+        Return the current price band for the given instrument class.
+
+        Returns
+        -------
+        PriceBand or ``None``
+
+        """
+        return self._price_bands.get(instrument_class)
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self):
@@ -398,6 +600,8 @@ cdef class RiskEngine(Component):
             self._handle_submit_order_list(command)
         elif isinstance(command, ModifyOrder):
             self._handle_modify_order(command)
+        elif isinstance(command, RiskCommand):
+            self._handle_risk_command(command)
         else:
             self._log.error(f"Cannot handle command: {command}.")
 
@@ -421,8 +625,8 @@ cdef class RiskEngine(Component):
                     )
                     return  # Denied
 
-        # Get instrument for order
-        cdef Instrument instrument = self._cache.instrument(order.instrument_id)
+        # Get instrument version effective for order
+        cdef Instrument instrument = self._cache.instrument_at(order.instrument_id, command.ts_init)
         if instrument is None:
             self._deny_command(
                 command=command,
@@ -439,6 +643,11 @@ cdef class RiskEngine(Component):
         if not self._check_orders_risk(instrument, [order]):
             return # Denied
 
+        cdef str risk_msg = self._check_strategy_limits(instrument, command.strategy_id, [order])
+        if risk_msg:
+            self._deny_command(command=command, reason=risk_msg)
+            return  # Denied
+
         self._execution_gateway(instrument, command)
 
     cpdef void _handle_submit_order_list(self, SubmitOrderList command):
@@ -447,8 +656,8 @@ cdef class RiskEngine(Component):
             self._send_to_execution(command)
             return
 
-        # Get instrument for orders
-        cdef Instrument instrument = self._cache.instrument(command.instrument_id)
+        # Get instrument version effective for orders
+        cdef Instrument instrument = self._cache.instrument_at(command.instrument_id, command.ts_init)
         if instrument is None:
             self._deny_command(
                 command=command,
@@ -468,6 +677,15 @@ cdef class RiskEngine(Component):
             self._deny_order_list(command.order_list, "OrderList {command.order_list.id.to_str()} DENIED")
             return # Denied
 
+        cdef str risk_msg = self._check_strategy_limits(
+            instrument,
+            command.strategy_id,
+            command.order_list.orders,
+        )
+        if risk_msg:
+            self._deny_order_list(command.order_list, risk_msg)
+            return  # Denied
+
         self._execution_gateway(instrument, command)
 
     cpdef void _handle_modify_order(self, ModifyOrder command):
@@ -493,8 +711,8 @@ cdef class RiskEngine(Component):
             )
             return  # Denied
 
-        # Get instrument for orders
-        cdef Instrument instrument = self._cache.instrument(command.instrument_id)
+        # Get instrument version effective for orders
+        cdef Instrument instrument = self._cache.instrument_at(command.instrument_id, command.ts_init)
         if instrument is None:
             self._reject_modify_order(
                 order=order,
@@ -516,6 +734,17 @@ cdef class RiskEngine(Component):
             self._reject_modify_order(order=order, reason=risk_msg)
             return  # Denied
 
+        # Check price bands
+        risk_msg = self._check_price_band(instrument, order.side, command.price, False)
+        if risk_msg:
+            self._reject_modify_order(order=order, reason=risk_msg)
+            return  # Denied
+
+        risk_msg = self._check_price_band(instrument, order.side, command.trigger_price, True)
+        if risk_msg:
+            self._reject_modify_order(order=order, reason=risk_msg)
+            return  # Denied
+
         # Check quantity
         risk_msg = self._check_quantity(instrument, command.quantity)
         if risk_msg:
@@ -546,6 +775,85 @@ cdef class RiskEngine(Component):
 
         self._order_modify_throttler.send(command)
 
+    cpdef void _handle_risk_command(self, RiskCommand command):
+        # Each change is recorded as (setting, old_value, new_value)
+        cdef list changes = []
+        cdef str setting
+        cdef Throttler throttler
+        try:
+            if isinstance(command, SetTradingState):
+                old_value = trading_state_to_str(self.trading_state)
+                if command.state != self.trading_state:
+                    self.set_trading_state(command.state)
+                changes.append(("trading_state", old_value, trading_state_to_str(self.trading_state)))
+            elif isinstance(command, SetMaxNotionalPerOrder):
+                setting = f"max_notional_per_order[{command.instrument_id}]"
+                old_value = str(self.max_notional_per_order(command.instrument_id))
+                self.set_max_notional_per_order(command.instrument_id, command.max_notional)
+                changes.append((setting, old_value, str(self.max_notional_per_order(command.instrument_id))))
+            elif isinstance(command, SetOrderRateLimits):
+                # Validate both rates before applying either
+                if command.max_order_submit_rate is not None:
+                    _parse_rate(command.max_order_submit_rate)
+                if command.max_order_modify_rate is not None:
+                    _parse_rate(command.max_order_modify_rate)
+                if command.max_order_submit_rate is not None:
+                    throttler = self._order_submit_throttler
+                    old_value = _format_rate(throttler.limit, throttler.interval)
+                    self.set_max_order_submit_rate(command.max_order_submit_rate)
+                    changes.append(("max_order_submit_rate", old_value, _format_rate(throttler.limit, throttler.interval)))
+                if command.max_order_modify_rate is not None:
+                    throttler = self._order_modify_throttler
+                    old_value = _format_rate(throttler.limit, throttler.interval)
+                    self.set_max_order_modify_rate(command.max_order_modify_rate)
+                    changes.append(("max_order_modify_rate", old_value, _format_rate(throttler.limit, throttler.interval)))
+            elif isinstance(command, SetStrategyRiskLimits):
+                setting = f"strategy_limits[{command.strategy_id}]"
+                old_value = repr(self.strategy_risk_limits(command.strategy_id))
+                self.set_strategy_risk_limits(command.strategy_id, command.limits)
+                changes.append((setting, old_value, repr(self.strategy_risk_limits(command.strategy_id))))
+            elif isinstance(command, SetPriceBand):
+                setting = f"price_bands[{instrument_class_to_str(command.instrument_class)}]"
+                old_value = repr(self.price_band(command.instrument_class))
+                self.set_price_band(command.instrument_class, command.band)
+                changes.append((setting, old_value, repr(self.price_band(command.instrument_class))))
+            else:  # pragma: no cover (design-time error)
+                self._log.error(f"Cannot handle command: {command}.")
+                return
+        except (ArithmeticError, TypeError, ValueError) as e:
+            self._log.error(f"Cannot apply {command}: {e}.")
+            return  # Invalid
+
+        for setting, old_value, new_value in changes:
+            if old_value != new_value:
+                self._publish_risk_config_changed(command, setting, old_value, new_value)
+
+    cdef void _publish_risk_config_changed(
+        self,
+        RiskCommand command,
+        str setting,
+        str old_value,
+        str new_value,
+    ):
+        cdef uint64_t ts_now = self._clock.timestamp_ns()
+        cdef RiskConfigChanged event = RiskConfigChanged(
+            trader_id=self.trader_id,
+            setting=setting,
+            old_value=old_value,
+            new_value=new_value,
+            requested_by=command.requested_by,
+            command_id=command.id,
+            event_id=UUID4(),
+            ts_event=ts_now,
+            ts_init=ts_now,
+        )
+
+        self._log.warning(
+            f"{setting} changed from {old_value} to {new_value} "
+            f"(requested_by={command.requested_by}).",
+        )
+        self._msgbus.publish_c(topic="events.risk", msg=event)
+
 # -- PRE-TRADE CHECKS -----------------------------------------------------------------------------
 
     cpdef bint _check_order(self, Instrument instrument, Order order):
@@ -570,6 +878,11 @@ cdef class RiskEngine(Component):
                 self._deny_order(order=order, reason=risk_msg)
                 return False  # Denied
 
+            risk_msg = self._check_price_band(instrument, order.side, order.price, False)
+            if risk_msg:
+                self._deny_order(order=order, reason=risk_msg)
+                return False  # Denied
+
         ########################################################################
         # CHECK TRIGGER
         ########################################################################
@@ -579,6 +892,11 @@ cdef class RiskEngine(Component):
                 self._deny_order(order=order, reason=f"trigger {risk_msg}")
                 return False  # Denied
 
+            risk_msg = self._check_price_band(instrument, order.side, order.trigger_price, True)
+            if risk_msg:
+                self._deny_order(order=order, reason=risk_msg)
+                return False  # Denied
+
         return True  # Passed
 
     cpdef bint _check_order_quantity(self, Instrument instrument, Order order):
@@ -763,6 +1081,56 @@ cdef class RiskEngine(Component):
             if price.raw_int64_c() <= 0:
                 # Check failed
                 return f"price {price} invalid (not positive)"
+        if self._precision_normalizer is not None:
+            return self._precision_normalizer.check_price(instrument, price)
+
+    cpdef str _check_price_band(self, Instrument instrument, OrderSide side, Price price, bint is_trigger):
+        if price is None:
+            # Nothing to check
+            return None
+
+        band = self._price_bands.get(instrument.instrument_class)
+        if band is None:
+            # No band for instrument class
+            return None
+
+        max_pct = band.stop_max_deviation_pct if is_trigger else band.limit_max_deviation_pct
+        max_ticks = band.stop_max_deviation_ticks if is_trigger else band.limit_max_deviation_ticks
+        if max_pct is None and max_ticks is None:
+            # No thresholds for price type
+            return None
+
+        # Buy orders are referenced to the best ask, sell orders to the best bid
+        cdef Price reference = self._cache.price(
+            instrument.id,
+            PriceType.ASK if side == OrderSide.BUY else PriceType.BID,
+        )
+        if reference is None:
+            reference = self._cache.price(instrument.id, PriceType.LAST)
+        if reference is None:
+            self._log.debug(f"Cannot check price band: no reference price for {instrument.id}.")
+            return None
+
+        cdef str label = "trigger price" if is_trigger else "price"
+        deviation = abs(price.as_decimal() - reference.as_decimal())
+
+        if max_pct is not None and reference.raw_int64_c() != 0:
+            deviation_pct = deviation / abs(reference.as_decimal()) * 100
+            if deviation_pct > Decimal(str(max_pct)):
+                # Check failed
+                return (
+                    f"PRICE_OUTSIDE_BAND: {label} {price} deviates {deviation_pct:.2f}% "
+                    f"from reference {reference} (max {max_pct}%)"
+                )
+
+        if max_ticks is not None:
+            deviation_ticks = deviation / instrument.price_increment.as_decimal()
+            if deviation_ticks > max_ticks:
+                # Check failed
+                return (
+                    f"PRICE_OUTSIDE_BAND: {label} {price} deviates {deviation_ticks:f} ticks "
+                    f"from reference {reference} (max {max_ticks} ticks)"
+                )
 
     cpdef str _check_quantity(self, Instrument instrument, Quantity quantity):
         if quantity is None:
@@ -777,6 +1145,194 @@ cdef class RiskEngine(Component):
         if instrument.min_quantity and quantity < instrument.min_quantity:
             # Check failed
             return f"quantity {quantity.to_str()} invalid (< minimum trade size of {instrument.min_quantity})"
+        if self._precision_normalizer is not None:
+            return self._precision_normalizer.check_quantity(instrument, quantity)
+
+    cpdef str _check_strategy_limits(self, Instrument instrument, StrategyId strategy_id, list orders):
+        limits = self._strategy_risk_limits.get(strategy_id)
+        if limits is None:
+            return None  # No limits for strategy
+
+        ########################################################################
+        # CHECK MAX OPEN ORDERS
+        ########################################################################
+        cdef int open_count
+        if limits.max_open_orders is not None:
+            open_count = (
+                self._cache.orders_open_count(strategy_id=strategy_id)
+                + self._cache.orders_inflight_count(strategy_id=strategy_id)
+            )
+            if open_count + len(orders) > limits.max_open_orders:
+                # Check failed
+                return (
+                    f"STRATEGY_MAX_OPEN_ORDERS_EXCEEDED: strategy_id={strategy_id.to_str()}, "
+                    f"max_open_orders={limits.max_open_orders}, "
+                    f"open_orders={open_count}, new_orders={len(orders)}"
+                )
+
+        # Orders which could increase exposure (contingent child orders only ever reduce)
+        cdef list new_orders = [
+            o for o in orders if not o.is_reduce_only and o.parent_order_id is None
+        ]
+        if not new_orders:
+            return None  # Nothing to check
+
+        ########################################################################
+        # CHECK MAX POSITION SIZE
+        ########################################################################
+        cdef:
+            Position position
+            Order order
+            bint has_buy = False
+            bint has_sell = False
+        max_position_value: str | None = limits.max_position_size.get(instrument.id.to_str())
+        if max_position_value is not None:
+            max_position_size = Decimal(max_position_value)
+            net_qty = Decimal(0)
+            for position in self._cache.positions_open(instrument_id=instrument.id, strategy_id=strategy_id):
+                if position.is_long_c():
+                    net_qty += position.quantity.as_decimal()
+                else:
+                    net_qty -= position.quantity.as_decimal()
+
+            long_qty = net_qty
+            short_qty = net_qty
+            for order in self._strategy_working_orders(strategy_id, instrument.id, orders):
+                if order.is_buy_c():
+                    long_qty += order.leaves_qty.as_decimal()
+                else:
+                    short_qty -= order.leaves_qty.as_decimal()
+
+            for order in new_orders:
+                if order.is_buy_c():
+                    has_buy = True
+                else:
+                    has_sell = True
+
+            if has_buy and long_qty > max_position_size:
+                # Check failed
+                return (
+                    f"STRATEGY_MAX_POSITION_SIZE_EXCEEDED: strategy_id={strategy_id.to_str()}, "
+                    f"max_position_size={max_position_size} {instrument.id.to_str()}, "
+                    f"projected_position_size={long_qty}"
+                )
+            if has_sell and -short_qty > max_position_size:
+                # Check failed
+                return (
+                    f"STRATEGY_MAX_POSITION_SIZE_EXCEEDED: strategy_id={strategy_id.to_str()}, "
+                    f"max_position_size={max_position_size} {instrument.id.to_str()}, "
+                    f"projected_position_size={-short_qty}"
+                )
+
+        ########################################################################
+        # CHECK MAX GROSS/NET EXPOSURE
+        ########################################################################
+        cdef Money max_exposure
+        cdef tuple exposure
+        if limits.max_gross_exposure is not None:
+            max_exposure = Money.from_str_c(limits.max_gross_exposure)
+            exposure = self._strategy_exposure(strategy_id, orders, max_exposure.currency)
+            if exposure is None:
+                self._log.warning(
+                    f"Cannot check gross exposure for {strategy_id}: "
+                    f"no prices or exchange rates to {max_exposure.currency}.",
+                )
+            elif exposure[0] > max_exposure.as_f64_c():
+                # Check failed
+                return (
+                    f"STRATEGY_MAX_GROSS_EXPOSURE_EXCEEDED: strategy_id={strategy_id.to_str()}, "
+                    f"max_gross_exposure={max_exposure.to_str()}, "
+                    f"gross_exposure={Money(exposure[0], max_exposure.currency).to_str()}"
+                )
+
+        if limits.max_net_exposure is not None:
+            max_exposure = Money.from_str_c(limits.max_net_exposure)
+            exposure = self._strategy_exposure(strategy_id, orders, max_exposure.currency)
+            if exposure is None:
+                self._log.warning(
+                    f"Cannot check net exposure for {strategy_id}: "
+                    f"no prices or exchange rates to {max_exposure.currency}.",
+                )
+            elif abs(exposure[1]) > max_exposure.as_f64_c():
+                # Check failed
+                return (
+                    f"STRATEGY_MAX_NET_EXPOSURE_EXCEEDED: strategy_id={strategy_id.to_str()}, "
+                    f"max_net_exposure={max_exposure.to_str()}, "
+                    f"net_exposure={Money(exposure[1], max_exposure.currency).to_str()}"
+                )
+
+        return None  # Passed
+
+    cdef list _strategy_working_orders(self, StrategyId strategy_id, InstrumentId instrument_id, list orders):
+        # Return the open, in-flight and new orders for the strategy which could
+        # increase exposure, excluding reduce-only and contingent child orders
+        cdef list working = []
+        cdef Order order
+        for order in (
+            self._cache.orders_open(instrument_id=instrument_id, strategy_id=strategy_id)
+            + self._cache.orders_inflight(instrument_id=instrument_id, strategy_id=strategy_id)
+            + orders
+        ):
+            if order.is_reduce_only or order.parent_order_id is not None:
+                continue
+            working.append(order)
+
+        return working
+
+    cdef tuple _strategy_exposure(self, StrategyId strategy_id, list orders, Currency currency):
+        # Return the (gross, net) notional exposure for the strategy in the given
+        # currency, or ``None`` if any price or exchange rate is unavailable
+        cdef double gross = 0.0
+        cdef double net = 0.0
+        cdef double value
+        cdef double xrate
+        cdef Position position
+        cdef Order order
+        cdef Instrument instrument
+        cdef Price price
+        cdef Money notional
+        for position in self._cache.positions_open(strategy_id=strategy_id):
+            instrument = self._cache.instrument(position.instrument_id)
+            if instrument is None:
+                return None
+            price = self._mark_price(instrument)
+            if price is None:
+                price = instrument.make_price(position.avg_px_open)
+            notional = instrument.notional_value(position.quantity, price)
+            xrate = self._cache.get_xrate(instrument.id.venue, notional.currency, currency)
+            if xrate == 0:
+                return None
+            value = notional.as_f64_c() * xrate
+            gross += value
+            net += value if position.is_long_c() else -value
+
+        for order in self._strategy_working_orders(strategy_id, None, orders):
+            instrument = self._cache.instrument(order.instrument_id)
+            if instrument is None:
+                return None
+            if order.has_price_c():
+                price = order.price
+            elif order.has_trigger_price_c():
+                price = order.trigger_price
+            else:
+                price = self._mark_price(instrument)
+            if price is None:
+                return None
+            notional = instrument.notional_value(order.leaves_qty, price)
+            xrate = self._cache.get_xrate(instrument.id.venue, notional.currency, currency)
+            if xrate == 0:
+                return None
+            value = notional.as_f64_c() * xrate
+            gross += value
+            net += value if order.is_buy_c() else -value
+
+        return gross, net
+
+    cdef Price _mark_price(self, Instrument instrument):
+        cdef Price price = self._cache.price(instrument.id, PriceType.MID)
+        if price is None:
+            price = self._cache.price(instrument.id, PriceType.LAST)
+        return price
 
 # -- DENIALS --------------------------------------------------------------------------------------
 
@@ -915,3 +1471,23 @@ cdef class RiskEngine(Component):
         if self.debug:
             self._log.debug(f"{RECV}{EVT} {event}.", LogColor.MAGENTA)
         self.event_count += 1
+
+
+cdef tuple _parse_rate(str rate):
+    # Parse a rate string of the form "limit/interval" e.g. "100/00:00:01"
+    Condition.valid_string(rate, "rate")
+
+    cdef list pieces = rate.split("/")
+    if len(pieces) != 2:
+        raise ValueError(f"invalid rate {rate!r}, expected the form 'limit/interval'")
+
+    cdef int limit = int(pieces[0])
+    interval = pd.to_timedelta(pieces[1])
+    Condition.positive_int(limit, "limit")
+    Condition.positive(interval.total_seconds(), "interval")
+
+    return limit, interval
+
+
+cdef str _format_rate(int limit, interval):
+    return f"{limit}/{str(interval).replace('0 days ', '')}"
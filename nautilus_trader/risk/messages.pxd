@@ -0,0 +1,61 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.core.message cimport Command
+from nautilus_trader.core.rust.model cimport InstrumentClass
+from nautilus_trader.core.rust.model cimport TradingState
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.identifiers cimport TraderId
+
+
+cdef class RiskCommand(Command):
+    cdef readonly TraderId trader_id
+    """The trader ID associated with the command.\n\n:returns: `TraderId`"""
+    cdef readonly str requested_by
+    """The identity of the requester of the change.\n\n:returns: `str` or ``None``"""
+
+
+cdef class SetTradingState(RiskCommand):
+    cdef readonly TradingState state
+    """The trading state to set.\n\n:returns: `TradingState`"""
+
+
+cdef class SetMaxNotionalPerOrder(RiskCommand):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID for the max notional.\n\n:returns: `InstrumentId`"""
+    cdef readonly object max_notional
+    """The max notional value to set (``None`` disables the check).\n\n:returns: `Decimal` or ``None``"""
+
+
+cdef class SetOrderRateLimits(RiskCommand):
+    cdef readonly str max_order_submit_rate
+    """The maximum rate of submit order commands to set.\n\n:returns: `str` or ``None``"""
+    cdef readonly str max_order_modify_rate
+    """The maximum rate of modify order commands to set.\n\n:returns: `str` or ``None``"""
+
+
+cdef class SetStrategyRiskLimits(RiskCommand):
+    cdef readonly StrategyId strategy_id
+    """The strategy ID for the risk limits.\n\n:returns: `StrategyId`"""
+    cdef readonly object limits
+    """The risk limits to set (``None`` clears any limits).\n\n:returns: `StrategyRiskLimits` or ``None``"""
+
+
+cdef class SetPriceBand(RiskCommand):
+    cdef readonly InstrumentClass instrument_class
+    """The instrument class for the price band.\n\n:returns: `InstrumentClass`"""
+    cdef readonly object band
+    """The price band to set (``None`` disables the check).\n\n:returns: `PriceBand` or ``None``"""
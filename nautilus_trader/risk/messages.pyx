@@ -0,0 +1,351 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+from nautilus_trader.risk.config import PriceBand
+from nautilus_trader.risk.config import StrategyRiskLimits
+
+from libc.stdint cimport uint64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport InstrumentClass
+from nautilus_trader.core.rust.model cimport TradingState
+from nautilus_trader.core.uuid cimport UUID4
+from nautilus_trader.model.functions cimport instrument_class_to_str
+from nautilus_trader.model.functions cimport trading_state_to_str
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.identifiers cimport TraderId
+
+
+cdef class RiskCommand(Command):
+    """
+    The base class for all commands which change risk settings at runtime.
+
+    Send to the `RiskEngine.execute` endpoint, each change applied publishes a
+    `RiskConfigChanged` event to the `events.risk` topic.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    Warnings
+    --------
+    This class should not be used directly, but through a concrete subclass.
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        super().__init__(command_id, ts_init)
+
+        self.trader_id = trader_id
+        self.requested_by = requested_by
+
+
+cdef class SetTradingState(RiskCommand):
+    """
+    Represents a command to set the trading state of the `RiskEngine`.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    state : TradingState
+        The trading state to set.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        TradingState state,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        super().__init__(trader_id, command_id, ts_init, requested_by)
+
+        self.state = state
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"state={trading_state_to_str(self.state)}, "
+            f"requested_by={self.requested_by})"  # Can be None
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"state={trading_state_to_str(self.state)}, "
+            f"requested_by={self.requested_by}, "  # Can be None
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
+
+
+cdef class SetMaxNotionalPerOrder(RiskCommand):
+    """
+    Represents a command to set the maximum notional value per order for an instrument.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    instrument_id : InstrumentId
+        The instrument ID for the max notional.
+    max_notional : integer, float, string or Decimal, optional with no default so ``None`` must be passed explicitly
+        The max notional value to set. If ``None`` then the check is disabled.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        InstrumentId instrument_id not None,
+        max_notional: Decimal | None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        super().__init__(trader_id, command_id, ts_init, requested_by)
+
+        self.instrument_id = instrument_id
+        self.max_notional = max_notional
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"max_notional={self.max_notional}, "  # Can be None
+            f"requested_by={self.requested_by})"  # Can be None
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"instrument_id={self.instrument_id.to_str()}, "
+            f"max_notional={self.max_notional}, "  # Can be None
+            f"requested_by={self.requested_by}, "  # Can be None
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
+
+
+cdef class SetOrderRateLimits(RiskCommand):
+    """
+    Represents a command to set the order submit and/or modify rate limits.
+
+    Rates are strings of the form "limit/interval" e.g. "100/00:00:01", and a
+    rate of ``None`` leaves the current setting unchanged.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    max_order_submit_rate : str, optional with no default so ``None`` must be passed explicitly
+        The maximum rate of submit order commands per timedelta.
+    max_order_modify_rate : str, optional with no default so ``None`` must be passed explicitly
+        The maximum rate of modify order commands per timedelta.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        str max_order_submit_rate: str | None,
+        str max_order_modify_rate: str | None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        super().__init__(trader_id, command_id, ts_init, requested_by)
+
+        self.max_order_submit_rate = max_order_submit_rate
+        self.max_order_modify_rate = max_order_modify_rate
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"max_order_submit_rate={self.max_order_submit_rate}, "  # Can be None
+            f"max_order_modify_rate={self.max_order_modify_rate}, "  # Can be None
+            f"requested_by={self.requested_by})"  # Can be None
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"max_order_submit_rate={self.max_order_submit_rate}, "  # Can be None
+            f"max_order_modify_rate={self.max_order_modify_rate}, "  # Can be None
+            f"requested_by={self.requested_by}, "  # Can be None
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
+
+
+cdef class SetStrategyRiskLimits(RiskCommand):
+    """
+    Represents a command to set the pre-trade risk limits for a strategy.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    strategy_id : StrategyId
+        The strategy ID for the risk limits.
+    limits : StrategyRiskLimits, optional with no default so ``None`` must be passed explicitly
+        The risk limits to set. If ``None`` then any limits for the strategy are cleared.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    Raises
+    ------
+    TypeError
+        If `limits` is not ``None`` and not of type `StrategyRiskLimits`.
+
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        StrategyId strategy_id not None,
+        limits: StrategyRiskLimits | None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        Condition.type_or_none(limits, StrategyRiskLimits, "limits")
+        super().__init__(trader_id, command_id, ts_init, requested_by)
+
+        self.strategy_id = strategy_id
+        self.limits = limits
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"strategy_id={self.strategy_id.to_str()}, "
+            f"limits={self.limits}, "  # Can be None
+            f"requested_by={self.requested_by})"  # Can be None
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"strategy_id={self.strategy_id.to_str()}, "
+            f"limits={self.limits}, "  # Can be None
+            f"requested_by={self.requested_by}, "  # Can be None
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
+
+
+cdef class SetPriceBand(RiskCommand):
+    """
+    Represents a command to set the price band (fat-finger check) for an instrument class.
+
+    Parameters
+    ----------
+    trader_id : TraderId
+        The trader ID for the command.
+    instrument_class : InstrumentClass
+        The instrument class for the price band.
+    band : PriceBand, optional with no default so ``None`` must be passed explicitly
+        The price band to set. If ``None`` then the check is disabled.
+    command_id : UUID4
+        The commands ID.
+    ts_init : uint64_t
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    requested_by : str, optional
+        The identity of the requester of the change (recorded for audit).
+
+    Raises
+    ------
+    TypeError
+        If `band` is not ``None`` and not of type `PriceBand`.
+
+    """
+
+    def __init__(
+        self,
+        TraderId trader_id not None,
+        InstrumentClass instrument_class,
+        band: PriceBand | None,
+        UUID4 command_id not None,
+        uint64_t ts_init,
+        str requested_by = None,
+    ):
+        Condition.type_or_none(band, PriceBand, "band")
+        super().__init__(trader_id, command_id, ts_init, requested_by)
+
+        self.instrument_class = instrument_class
+        self.band = band
+
+    def __str__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_class={instrument_class_to_str(self.instrument_class)}, "
+            f"band={self.band}, "  # Can be None
+            f"requested_by={self.requested_by})"  # Can be None
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"trader_id={self.trader_id.to_str()}, "
+            f"instrument_class={instrument_class_to_str(self.instrument_class)}, "
+            f"band={self.band}, "  # Can be None
+            f"requested_by={self.requested_by}, "  # Can be None
+            f"command_id={self.id.to_str()}, "
+            f"ts_init={self.ts_init})"
+        )
@@ -21,11 +21,15 @@ from nautilus_trader.common.messages import ComponentStateChanged
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.core import nautilus_pyo3
 from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BorrowRate
+from nautilus_trader.model.data import CashDividend
 from nautilus_trader.model.data import InstrumentClose
 from nautilus_trader.model.data import InstrumentStatus
 from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDepth10
 from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import StockSplit
+from nautilus_trader.model.data import SymbolChange
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.data import VenueStatus
 from nautilus_trader.model.events import OrderAccepted
@@ -105,6 +109,44 @@ NAUTILUS_ARROW_SCHEMA = {
         },
         metadata={"type": "InstrumentStatus"},
     ),
+    CashDividend: pa.schema(
+        {
+            "instrument_id": pa.dictionary(pa.int64(), pa.string()),
+            "amount": pa.string(),
+            "ts_event": pa.uint64(),
+            "ts_init": pa.uint64(),
+        },
+        metadata={"type": "CashDividend"},
+    ),
+    StockSplit: pa.schema(
+        {
+            "instrument_id": pa.dictionary(pa.int64(), pa.string()),
+            "numerator": pa.uint64(),
+            "denominator": pa.uint64(),
+            "ts_event": pa.uint64(),
+            "ts_init": pa.uint64(),
+        },
+        metadata={"type": "StockSplit"},
+    ),
+    SymbolChange: pa.schema(
+        {
+            "instrument_id": pa.dictionary(pa.int64(), pa.string()),
+            "new_instrument_id": pa.string(),
+            "ts_event": pa.uint64(),
+            "ts_init": pa.uint64(),
+        },
+        metadata={"type": "SymbolChange"},
+    ),
+    BorrowRate: pa.schema(
+        {
+            "venue": pa.dictionary(pa.int16(), pa.string()),
+            "currency": pa.dictionary(pa.int16(), pa.string()),
+            "rate": pa.float64(),
+            "ts_event": pa.uint64(),
+            "ts_init": pa.uint64(),
+        },
+        metadata={"type": "BorrowRate"},
+    ),
     ComponentStateChanged: pa.schema(
         {
             "trader_id": pa.dictionary(pa.int16(), pa.string()),
@@ -372,6 +414,7 @@ NAUTILUS_ARROW_SCHEMA = {
             "ts_init": pa.uint64(),
             "info": pa.binary(),
             "reconciliation": pa.bool_(),
+            "tags": pa.string(),
         },
     ),
     BinanceBar: pa.schema(
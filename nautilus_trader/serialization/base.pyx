@@ -20,6 +20,7 @@ from nautilus_trader.adapters.binance.common.types import BinanceBar
 from nautilus_trader.adapters.binance.common.types import BinanceTicker
 
 from nautilus_trader.common.messages cimport ComponentStateChanged
+from nautilus_trader.common.messages cimport RiskConfigChanged
 from nautilus_trader.common.messages cimport TradingStateChanged
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -56,6 +57,7 @@ from nautilus_trader.model.events.position cimport PositionClosed
 from nautilus_trader.model.events.position cimport PositionOpened
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.betting cimport BettingInstrument
+from nautilus_trader.model.instruments.binary_option cimport BinaryOption
 from nautilus_trader.model.instruments.crypto_future cimport CryptoFuture
 from nautilus_trader.model.instruments.crypto_perpetual cimport CryptoPerpetual
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
@@ -73,6 +75,7 @@ _OBJECT_TO_DICT_MAP: dict[str, Callable[[None], dict]] = {
     ModifyOrder.__name__: ModifyOrder.to_dict_c,
     ComponentStateChanged.__name__: ComponentStateChanged.to_dict_c,
     TradingStateChanged.__name__: TradingStateChanged.to_dict_c,
+    RiskConfigChanged.__name__: RiskConfigChanged.to_dict_c,
     AccountState.__name__: AccountState.to_dict_c,
     OrderAccepted.__name__: OrderAccepted.to_dict_c,
     OrderCancelRejected.__name__: OrderCancelRejected.to_dict_c,
@@ -96,6 +99,7 @@ _OBJECT_TO_DICT_MAP: dict[str, Callable[[None], dict]] = {
     Instrument.__name__: Instrument.base_to_dict_c,
     SyntheticInstrument.__name__: SyntheticInstrument.to_dict_c,
     BettingInstrument.__name__: BettingInstrument.to_dict_c,
+    BinaryOption.__name__: BinaryOption.to_dict_c,
     Equity.__name__: Equity.to_dict_c,
     FuturesContract.__name__: FuturesContract.to_dict_c,
     OptionsContract.__name__: OptionsContract.to_dict_c,
@@ -123,6 +127,7 @@ _OBJECT_FROM_DICT_MAP: dict[str, Callable[[dict], Any]] = {
     ModifyOrder.__name__: ModifyOrder.from_dict_c,
     ComponentStateChanged.__name__: ComponentStateChanged.from_dict_c,
     TradingStateChanged.__name__: TradingStateChanged.from_dict_c,
+    RiskConfigChanged.__name__: RiskConfigChanged.from_dict_c,
     AccountState.__name__: AccountState.from_dict_c,
     OrderAccepted.__name__: OrderAccepted.from_dict_c,
     OrderCancelRejected.__name__: OrderCancelRejected.from_dict_c,
@@ -146,6 +151,7 @@ _OBJECT_FROM_DICT_MAP: dict[str, Callable[[dict], Any]] = {
     Instrument.__name__: Instrument.base_from_dict_c,
     SyntheticInstrument.__name__: SyntheticInstrument.from_dict_c,
     BettingInstrument.__name__: BettingInstrument.from_dict_c,
+    BinaryOption.__name__: BinaryOption.from_dict_c,
     Equity.__name__: Equity.from_dict_c,
     FuturesContract.__name__: FuturesContract.from_dict_c,
     OptionsContract.__name__: OptionsContract.from_dict_c,
@@ -176,6 +182,7 @@ _EXTERNAL_PUBLISHABLE_TYPES = {
     CancelOrder,
     ComponentStateChanged,
     TradingStateChanged,
+    RiskConfigChanged,
     AccountState,
     OrderAccepted,
     OrderCancelRejected,
@@ -199,6 +206,7 @@ _EXTERNAL_PUBLISHABLE_TYPES = {
     Instrument,
     SyntheticInstrument,
     BettingInstrument,
+    BinaryOption,
     Equity,
     FuturesContract,
     OptionsContract,
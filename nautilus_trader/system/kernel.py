@@ -421,6 +421,8 @@ class NautilusKernel:
                 path=config.catalog.path,
                 fs_protocol=config.catalog.fs_protocol,
                 fs_storage_options=config.catalog.fs_storage_options,
+                local_cache_dir=config.catalog.local_cache_dir,
+                write_block_size=config.catalog.write_block_size,
             )
             self._data_engine.register_catalog(catalog=self._catalog)
 
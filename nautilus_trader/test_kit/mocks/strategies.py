@@ -55,6 +55,10 @@ class MockStrategy(Strategy):
         self.calls.append(inspect.currentframe().f_code.co_name)
         self.store.append(instrument)
 
+    def on_instrument_changed(self, instrument) -> None:
+        self.calls.append(inspect.currentframe().f_code.co_name)
+        self.store.append(instrument)
+
     def on_ticker(self, ticker):
         self.calls.append(inspect.currentframe().f_code.co_name)
         self.store.append(ticker)
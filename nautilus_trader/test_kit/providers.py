@@ -33,6 +33,7 @@ from nautilus_trader.model.currencies import ADA
 from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import ETH
 from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.currencies import USDC
 from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
@@ -44,11 +45,13 @@ from nautilus_trader.model.identifiers import Symbol
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.model.instruments import BettingInstrument
+from nautilus_trader.model.instruments import BinaryOption
 from nautilus_trader.model.instruments import CryptoFuture
 from nautilus_trader.model.instruments import CryptoPerpetual
 from nautilus_trader.model.instruments import CurrencyPair
 from nautilus_trader.model.instruments import Equity
 from nautilus_trader.model.instruments import FuturesContract
+from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.model.instruments import OptionsContract
 from nautilus_trader.model.instruments import SyntheticInstrument
 from nautilus_trader.model.instruments.betting import null_handicap
@@ -383,6 +386,46 @@ class TestInstrumentProvider:
             ts_init=0,
         )
 
+    @staticmethod
+    def ethusd_quanto_bitmex() -> CryptoPerpetual:
+        """
+        Return the BitMEX ETH/USD quanto perpetual swap contract (settled in BTC)
+        for backtesting.
+
+        Returns
+        -------
+        CryptoPerpetual
+
+        """
+        return CryptoPerpetual(
+            instrument_id=InstrumentId(
+                symbol=Symbol("ETHUSD-QUANTO"),
+                venue=Venue("BITMEX"),
+            ),
+            raw_symbol=Symbol("ETHUSD"),
+            base_currency=ETH,
+            quote_currency=USD,
+            settlement_currency=BTC,
+            is_inverse=False,
+            price_precision=2,
+            size_precision=0,
+            price_increment=Price.from_str("0.05"),
+            size_increment=Quantity.from_int(1),
+            max_quantity=Quantity.from_int(10_000_000),
+            min_quantity=Quantity.from_int(1),
+            max_notional=None,
+            min_notional=None,
+            max_price=Price.from_str("1000000.00"),
+            min_price=Price.from_str("0.05"),
+            quanto_multiplier=Decimal("0.000001"),
+            margin_init=Decimal("0.02"),
+            margin_maint=Decimal("0.007"),
+            maker_fee=Decimal("-0.00025"),
+            taker_fee=Decimal("0.00075"),
+            ts_event=0,
+            ts_init=0,
+        )
+
     @staticmethod
     def default_fx_ccy(symbol: str, venue: Venue | None = None) -> CurrencyPair:
         """
@@ -538,6 +581,28 @@ class TestInstrumentProvider:
             ts_init=0,
         )
 
+    @staticmethod
+    def binary_option() -> BinaryOption:
+        raw_symbol = Symbol("0x12a0cb60174abc437bf1178367c72d11f069e1a3add20b148fb0ab4279b772b2-YES")
+        return BinaryOption(
+            instrument_id=InstrumentId(symbol=raw_symbol, venue=Venue("POLYMARKET")),
+            raw_symbol=raw_symbol,
+            asset_class=AssetClass.ALTERNATIVE,
+            currency=USDC,
+            price_precision=3,
+            size_precision=2,
+            price_increment=Price.from_str("0.001"),
+            size_increment=Quantity.from_str("0.01"),
+            activation_ns=0,
+            expiration_ns=pd.Timestamp("2024-01-01", tz=pytz.utc).value,
+            maker_fee=Decimal(0),
+            taker_fee=Decimal(0),
+            outcome="Yes",
+            description="Will the outcome of this market be 'Yes'?",
+            ts_event=0,
+            ts_init=0,
+        )
+
     @staticmethod
     def synthetic_instrument() -> SyntheticInstrument:
         return SyntheticInstrument(
@@ -577,6 +642,31 @@ class TestInstrumentProvider:
             ts_init=0,
         )
 
+    @staticmethod
+    def instrument_version(instrument: Instrument, ts_event: int, **changes: Any) -> Instrument:
+        """
+        Return a new version of the given instrument effective from `ts_event`.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument to derive the new version from.
+        ts_event : int
+            The UNIX timestamp (nanoseconds) the version is effective from.
+        **changes : Any
+            The specification values to change (as per the instruments `to_dict`).
+
+        Returns
+        -------
+        Instrument
+
+        """
+        values = type(instrument).to_dict(instrument)
+        values.update(changes)
+        values["ts_event"] = ts_event
+        values["ts_init"] = ts_event
+        return type(instrument).from_dict(values)
+
 
 def first_friday_two_years_six_months_ago(year: int, month: int) -> dt.date:
     target_year = year - 2
@@ -20,6 +20,7 @@ from typing import Any
 import msgspec
 
 from nautilus_trader.common.config import NautilusConfig
+from nautilus_trader.common.config import PositiveFloat
 from nautilus_trader.common.config import resolve_config_path
 from nautilus_trader.common.config import resolve_path
 from nautilus_trader.core.correctness import PyCondition
@@ -50,6 +51,16 @@ class StrategyConfig(NautilusConfig, kw_only=True, frozen=True):
     manage_gtd_expiry : bool, default False
         If all order GTD time in force expirations should be managed by the strategy.
         If True then will ensure open orders have their GTD timers re-activated on start.
+    manage_own_order_books : bool, default False
+        If the strategy should maintain an `OwnOrderBook` of its resting orders per instrument,
+        updated from order events and available through `own_order_book(...)`.
+    warmup_required : bool, default False
+        If the strategy starts in a warm-up phase, during which order submissions are denied
+        until the strategy calls `complete_warmup()` (typically once the historical data
+        requested on start has been received and its indicators are initialized).
+    warmup_timeout_secs : PositiveFloat, optional
+        The timeout for the warm-up phase. If the warm-up is not completed in time then
+        diagnostics are logged, and order submissions continue to be denied.
 
     """
 
@@ -59,6 +70,9 @@ class StrategyConfig(NautilusConfig, kw_only=True, frozen=True):
     external_order_claims: list[InstrumentId] | None = None
     manage_contingent_orders: bool = False
     manage_gtd_expiry: bool = False
+    manage_own_order_books: bool = False
+    warmup_required: bool = False
+    warmup_timeout_secs: PositiveFloat | None = None
 
 
 class ImportableStrategyConfig(NautilusConfig, frozen=True):
@@ -26,6 +26,7 @@ from nautilus_trader.execution.manager cimport OrderManager
 from nautilus_trader.execution.messages cimport CancelOrder
 from nautilus_trader.execution.messages cimport ModifyOrder
 from nautilus_trader.execution.messages cimport TradingCommand
+from nautilus_trader.model.book cimport OwnOrderBook
 from nautilus_trader.model.data cimport Bar
 from nautilus_trader.model.data cimport BarType
 from nautilus_trader.model.data cimport QuoteTick
@@ -57,6 +58,7 @@ from nautilus_trader.model.identifiers cimport ExecAlgorithmId
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
+from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.identifiers cimport TraderId
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -68,6 +70,7 @@ from nautilus_trader.portfolio.base cimport PortfolioFacade
 
 cdef class Strategy(Actor):
     cdef OrderManager _manager
+    cdef dict _own_order_books
 
     cdef readonly OrderFactory order_factory
     """The order factory for the strategy.\n\n:returns: `OrderFactory`"""
@@ -81,6 +84,8 @@ cdef class Strategy(Actor):
     """If contingent orders should be managed automatically by the strategy.\n\n:returns: `bool`"""
     cdef readonly bint manage_gtd_expiry
     """If all order GTD time in force expirations should be managed automatically by the strategy.\n\n:returns: `bool`"""
+    cdef readonly bint manage_own_order_books
+    """If own order books of resting orders should be maintained by the strategy.\n\n:returns: `bool`"""
 
 # -- REGISTRATION ---------------------------------------------------------------------------------
 
@@ -118,6 +123,7 @@ cdef class Strategy(Actor):
     cpdef void on_position_opened(self, PositionOpened event)
     cpdef void on_position_changed(self, PositionChanged event)
     cpdef void on_position_closed(self, PositionClosed event)
+    cpdef void on_instrument_changed(self, Instrument instrument)
 
 # -- TRADING COMMANDS -----------------------------------------------------------------------------
 
@@ -163,6 +169,15 @@ cdef class Strategy(Actor):
     cdef void _set_gtd_expiry(self, Order order)
     cpdef void _expire_gtd_order(self, TimeEvent event)
 
+# -- OWN ORDER BOOKS ------------------------------------------------------------------------------
+
+    cpdef OwnOrderBook own_order_book(self, InstrumentId instrument_id)
+    cdef void _update_own_order_book(self, Order order)
+
+# -- HANDLERS -------------------------------------------------------------------------------------
+
+    cpdef void handle_instrument_changed(self, Instrument instrument)
+
 # -- EVENTS ---------------------------------------------------------------------------------------
 
     cdef OrderDenied _generate_order_denied(self, Order order, str reason)
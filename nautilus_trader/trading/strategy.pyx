@@ -91,6 +91,7 @@ from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport VALID_LIMIT_ORDER_TYPES
@@ -151,6 +152,8 @@ cdef class Strategy(Actor):
         self.external_order_claims = self._parse_external_order_claims(config.external_order_claims)
         self.manage_contingent_orders = config.manage_contingent_orders
         self.manage_gtd_expiry = config.manage_gtd_expiry
+        self.manage_own_order_books = config.manage_own_order_books
+        self._init_warmup(config.warmup_required, config.warmup_timeout_secs)
 
         # Public components
         self.clock = self._clock
@@ -160,6 +163,7 @@ cdef class Strategy(Actor):
 
         # Order management
         self._manager = None       # Initialized when registered
+        self._own_order_books: dict[InstrumentId, OwnOrderBook] = {}
 
         # Register warning events
         self.register_warning_event(OrderDenied)
@@ -297,6 +301,7 @@ cdef class Strategy(Actor):
         # Required subscriptions
         self._msgbus.subscribe(topic=f"events.order.{self.id}", handler=self.handle_event)
         self._msgbus.subscribe(topic=f"events.position.{self.id}", handler=self.handle_event)
+        self._msgbus.subscribe(topic="data.instrument_changes.*", handler=self.handle_instrument_changed)
 
     cpdef void change_id(self, StrategyId strategy_id):
         """
@@ -333,6 +338,8 @@ cdef class Strategy(Actor):
         self._log.info(f"{self.config.oms_type=}", LogColor.BLUE)
         self._log.info(f"{self.config.external_order_claims=}", LogColor.BLUE)
         self._log.info(f"{self.config.manage_gtd_expiry=}", LogColor.BLUE)
+        self._log.info(f"{self.config.manage_own_order_books=}", LogColor.BLUE)
+        self._log.info(f"{self.config.warmup_required=}", LogColor.BLUE)
 
         cdef set client_order_ids = self.cache.client_order_ids(
             venue=None,
@@ -374,6 +381,11 @@ cdef class Strategy(Actor):
                     if not self._has_gtd_expiry_timer(order.client_order_id):
                         self._set_gtd_expiry(order)
 
+        if self.manage_own_order_books:
+            for order in open_orders:
+                self._update_own_order_book(order)
+
+        self._start_warmup()
         self.on_start()
 
     cpdef void _reset(self):
@@ -388,6 +400,9 @@ cdef class Strategy(Actor):
         if self._manager:
             self._manager.reset()
 
+        self._own_order_books.clear()
+        self._reset_warmup()
+
         self.on_reset()
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
@@ -728,6 +743,24 @@ cdef class Strategy(Actor):
         """
         # Optionally override in subclass
 
+    cpdef void on_instrument_changed(self, Instrument instrument):
+        """
+        Actions to be performed when running and a new version of an instrument
+        supersedes the one previously held in the cache (such as a tick size or
+        lot size change).
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The new version of the instrument.
+
+        Warnings
+        --------
+        System method (not intended to be called by user code).
+
+        """
+        # Optionally override in subclass
+
 # -- TRADING COMMANDS -----------------------------------------------------------------------------
 
     cpdef void submit_order(
@@ -744,7 +777,8 @@ cdef class Strategy(Actor):
         `ExecAlgorithm`, the `OrderEmulator` or the `RiskEngine` (depending whether
         the order is emulated and/or has an `exec_algorithm_id` specified).
 
-        If the client order ID is duplicate, then the order will be denied.
+        If the client order ID is duplicate, or the strategy has not completed a
+        required warm-up, then the order will be denied.
 
         Parameters
         ----------
@@ -784,6 +818,10 @@ cdef class Strategy(Actor):
             self._deny_order(order, f"duplicate {repr(order.client_order_id)}")
             return
 
+        if not self.is_warmed_up():
+            self._deny_order(order, f"warm-up not complete, state={self._warmup_state.name}")
+            return
+
         self.cache.add_order(order, position_id, client_id)
 
         cdef SubmitOrder command = SubmitOrder(
@@ -820,8 +858,8 @@ cdef class Strategy(Actor):
         A `SubmitOrderList` command with be created and sent to **either** the
         `OrderEmulator`, or the `RiskEngine` (depending whether an order is emulated).
 
-        If the order list ID is duplicate, or any client order ID is duplicate,
-        then all orders will be denied.
+        If the order list ID is duplicate, any client order ID is duplicate, or the
+        strategy has not completed a required warm-up, then all orders will be denied.
 
         Parameters
         ----------
@@ -866,6 +904,13 @@ cdef class Strategy(Actor):
             )
             return
 
+        if not self.is_warmed_up():
+            self._deny_order_list(
+                order_list,
+                reason=f"warm-up not complete, state={self._warmup_state.name}",
+            )
+            return
+
         self.cache.add_order_list(order_list)
 
         # Check for duplicate client order IDs
@@ -1492,8 +1537,67 @@ cdef class Strategy(Actor):
         self._log.info(f"Expiring GTD order {order.client_order_id}.", LogColor.BLUE)
         self.cancel_order(order)
 
+# -- OWN ORDER BOOKS ------------------------------------------------------------------------------
+
+    cpdef OwnOrderBook own_order_book(self, InstrumentId instrument_id):
+        """
+        Return the own order book of the strategies resting orders for the given
+        instrument ID (if found).
+
+        Own order books are only maintained when `manage_own_order_books` is
+        enabled in the strategy configuration.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the book.
+
+        Returns
+        -------
+        OwnOrderBook or ``None``
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self._own_order_books.get(instrument_id)
+
+    cdef void _update_own_order_book(self, Order order):
+        cdef OwnOrderBook own_book = self._own_order_books.get(order.instrument_id)
+        if own_book is None:
+            own_book = OwnOrderBook(order.instrument_id)
+            self._own_order_books[order.instrument_id] = own_book
+
+        own_book.update(order)
+
     # -- HANDLERS -------------------------------------------------------------------------------------
 
+    cpdef void handle_instrument_changed(self, Instrument instrument):
+        """
+        Handle the given changed instrument.
+
+        If state is ``RUNNING`` then passes to `on_instrument_changed`.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The new version of the instrument.
+
+        Warnings
+        --------
+        System method (not intended to be called by user code).
+
+        """
+        Condition.not_none(instrument, "instrument")
+
+        if self._fsm.state != ComponentState.RUNNING:
+            return
+
+        try:
+            self.on_instrument_changed(instrument)
+        except Exception as e:
+            self.log.exception(f"Error on handling {repr(instrument)}", e)
+            raise
+
     cpdef void handle_event(self, Event event):
         """
         Handle the given event.
@@ -1523,6 +1627,11 @@ cdef class Strategy(Actor):
             if order is not None and order.is_closed_c() and self._has_gtd_expiry_timer(order.client_order_id):
                 self.cancel_gtd_expiry(order)
 
+        if self.manage_own_order_books and isinstance(event, OrderEvent):
+            order = self.cache.order(event.client_order_id)
+            if order is not None:
+                self._update_own_order_book(order)
+
         if self._fsm.state != ComponentState.RUNNING:
             return
 
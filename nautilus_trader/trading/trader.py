@@ -23,11 +23,14 @@ A running instance could be either a test/backtest or live implementation - the
 
 import asyncio
 from collections.abc import Callable
+from os import PathLike
+from pathlib import Path
 from typing import Any
 
 import pandas as pd
 
 from nautilus_trader.analysis.reporter import ReportProvider
+from nautilus_trader.analysis.writer import ReportWriter
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.actor import Actor
 from nautilus_trader.common.component import Clock
@@ -828,3 +831,26 @@ class Trader(Component):
         if account is None:
             return pd.DataFrame()
         return ReportProvider.generate_account_report(account)
+
+    def write_reports(self, directory: str | PathLike, arrow: bool = False) -> list[Path]:
+        """
+        Write the fills blotter, positions report and account statements to the
+        given `directory`.
+
+        Parameters
+        ----------
+        directory : str or PathLike
+            The directory to write to (will be created if it does not exist).
+        arrow : bool, default False
+            If reports should be written as Arrow IPC (Feather) files, otherwise CSV.
+
+        Returns
+        -------
+        list[Path]
+            The paths of the files written.
+
+        """
+        writer = ReportWriter(self._cache)
+        if arrow:
+            return writer.write_arrow(directory)
+        return writer.write_csv(directory)
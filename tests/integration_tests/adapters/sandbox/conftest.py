@@ -15,6 +15,7 @@
 
 import pytest
 
+from nautilus_trader.adapters.sandbox.execution import PyO3SandboxExecutionClient
 from nautilus_trader.adapters.sandbox.execution import SandboxExecutionClient
 from nautilus_trader.model.events import AccountState
 from nautilus_trader.model.identifiers import AccountId
@@ -51,6 +52,29 @@ def exec_client(
     )
 
 
+@pytest.fixture()
+def pyo3_exec_client(
+    instrument,
+    event_loop,
+    portfolio,
+    msgbus,
+    cache,
+    clock,
+    venue,
+):
+    cache.add_instrument(instrument)
+    return PyO3SandboxExecutionClient(
+        loop=event_loop,
+        portfolio=portfolio,
+        msgbus=msgbus,
+        cache=cache,
+        clock=clock,
+        venue=venue.value,
+        currency="USD",
+        balance=100_000,
+    )
+
+
 @pytest.fixture()
 def instrument():
     return TestInstrumentProvider.equity("AAPL", "SANDBOX")
@@ -171,3 +171,22 @@ async def test_cancel_order_fail(exec_client, cache, strategy, instrument, event
     assert client_order_id not in client_order_ids
     venue_order_ids = [o.venue_order_id for o in strategy.cache.orders()]
     assert venue_order_id not in venue_order_ids
+
+
+@pytest.mark.asyncio()
+async def test_pyo3_client_submit_order_success(pyo3_exec_client, cache, instrument, events):
+    # Arrange
+    pyo3_exec_client.connect()
+    order = TestExecStubs.limit_order(instrument_id=instrument.id)
+    cache.add_order(order, None)
+
+    # Act
+    pyo3_exec_client.submit_order(TestCommandStubs.submit_order_command(order))
+    pyo3_exec_client.on_data(_make_quote_tick(instrument))
+
+    # Assert
+    order_events = [type(event) for event in events]
+    assert OrderSubmitted in order_events
+    assert OrderAccepted in order_events
+    assert OrderFilled in order_events
+    assert pyo3_exec_client.is_connected
@@ -19,12 +19,15 @@ from decimal import Decimal
 import pandas as pd
 import pytest
 
+from nautilus_trader.accounting.calculators import BorrowRateCalculator
 from nautilus_trader.accounting.calculators import ExchangeRateCalculator
+from nautilus_trader.accounting.calculators import FinancingCalculator
 from nautilus_trader.accounting.calculators import RolloverInterestCalculator
 from nautilus_trader.model.currencies import AUD
 from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import JPY
 from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import PriceType
 from nautilus_trader.test_kit.stubs.data import UNIX_EPOCH
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
@@ -264,3 +267,103 @@ class TestRolloverInterestCalculator:
 
         with pytest.raises(RuntimeError):
             calculator.calc_overnight_rate(AUDUSD_SIM, datetime.date(3000, 1, 1))
+
+
+class TestFinancingCalculator:
+    def setup(self):
+        # Fixture Setup
+        self.calculator = FinancingCalculator(
+            rates={AUDUSD_SIM: (-0.036, 0.018)},
+            days_in_year=360,
+        )
+
+    def test_get_rate_for_instrument_without_rates_returns_zero(self):
+        # Arrange, Act
+        rate = self.calculator.get_rate(USDJPY_SIM, PositionSide.LONG)
+
+        # Assert
+        assert not self.calculator.has_rates(USDJPY_SIM)
+        assert rate == 0.0
+
+    @pytest.mark.parametrize(
+        ("side", "expected"),
+        [
+            [PositionSide.LONG, -0.036],
+            [PositionSide.SHORT, 0.018],
+            [PositionSide.FLAT, 0.0],
+        ],
+    )
+    def test_get_rate_by_position_side(self, side, expected):
+        # Arrange, Act
+        rate = self.calculator.get_rate(AUDUSD_SIM, side)
+
+        # Assert
+        assert rate == expected
+
+    def test_calc_financing_for_long_position_debits(self):
+        # Arrange, Act
+        financing = self.calculator.calc_financing(AUDUSD_SIM, PositionSide.LONG, 100_000.0)
+
+        # Assert
+        assert financing == pytest.approx(-10.0)
+
+    def test_calc_financing_for_short_position_over_weekend_credits(self):
+        # Arrange, Act
+        financing = self.calculator.calc_financing(
+            AUDUSD_SIM,
+            PositionSide.SHORT,
+            100_000.0,
+            days=3,
+        )
+
+        # Assert
+        assert financing == pytest.approx(15.0)
+
+
+class TestBorrowRateCalculator:
+    def setup(self):
+        # Fixture Setup
+        self.calculator = BorrowRateCalculator(rates={BTC: 0.10}, days_in_year=365)
+
+    def test_get_rate_for_asset_without_rate_returns_zero(self):
+        # Arrange, Act
+        rate = self.calculator.get_rate(USD)
+
+        # Assert
+        assert not self.calculator.has_rate(USD)
+        assert rate == 0.0
+
+    def test_update_rate(self):
+        # Arrange, Act
+        self.calculator.update_rate(BTC, 0.25)
+        self.calculator.update_rate(USD, 0.05)
+
+        # Assert
+        assert self.calculator.get_rate(BTC) == 0.25
+        assert self.calculator.get_rate(USD) == 0.05
+
+    def test_update_rate_with_negative_rate_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.calculator.update_rate(BTC, -0.01)
+
+    @pytest.mark.parametrize(
+        ("hours", "expected"),
+        [
+            [1.0, 10.0],
+            [24.0, 240.0],
+        ],
+    )
+    def test_calc_borrow_fee(self, hours, expected):
+        # Arrange, Act
+        fee = self.calculator.calc_borrow_fee(BTC, 876_000.0, hours)
+
+        # Assert
+        assert fee == pytest.approx(expected)
+
+    def test_calc_borrow_fee_for_asset_without_rate_returns_zero(self):
+        # Arrange, Act
+        fee = self.calculator.calc_borrow_fee(USD, 876_000.0, 1.0)
+
+        # Assert
+        assert fee == 0.0
@@ -21,6 +21,8 @@ from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.currencies import USDC
+from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import StrategyId
@@ -36,6 +38,7 @@ AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
 USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
 ADABTC_BINANCE = TestInstrumentProvider.adabtc_binance()
 BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
+BINARY_OPTION = TestInstrumentProvider.binary_option()
 
 
 class TestMarginAccount:
@@ -240,3 +243,50 @@ class TestMarginAccount:
 
         # Assert
         assert result == Money(0.00042500, BTC)
+
+    @pytest.mark.parametrize(
+        ("side", "expected"),
+        [
+            [OrderSide.NO_ORDER_SIDE, Money(65.00, USDC)],
+            [OrderSide.BUY, Money(65.00, USDC)],
+            [OrderSide.SELL, Money(35.00, USDC)],
+        ],
+    )
+    def test_calculate_margin_init_for_binary_option_is_max_loss(self, side, expected):
+        # Arrange
+        account = TestExecStubs.margin_account()
+        account.set_default_leverage(Decimal(10))
+
+        # Act
+        result = account.calculate_margin_init(
+            instrument=BINARY_OPTION,
+            quantity=Quantity.from_int(100),
+            price=Price.from_str("0.650"),
+            side=side,
+        )
+
+        # Assert
+        assert result == expected
+
+    @pytest.mark.parametrize(
+        ("side", "expected"),
+        [
+            [PositionSide.LONG, Money(20.00, USDC)],
+            [PositionSide.SHORT, Money(80.00, USDC)],
+        ],
+    )
+    def test_calculate_margin_maint_for_binary_option_is_max_loss(self, side, expected):
+        # Arrange
+        account = TestExecStubs.margin_account()
+        account.set_default_leverage(Decimal(10))
+
+        # Act
+        result = account.calculate_margin_maint(
+            instrument=BINARY_OPTION,
+            side=side,
+            quantity=Quantity.from_int(100),
+            price=Price.from_str("0.200"),
+        )
+
+        # Assert
+        assert result == expected
@@ -0,0 +1,170 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pyarrow.feather as feather
+import pytest
+
+from nautilus_trader.analysis.tca import ExecutionQualityAnalyzer
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import ExecAlgorithmId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.orders import MarketOrder
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class TestExecutionQualityAnalyzer:
+    def setup(self):
+        # Fixture Setup
+        self.order_factory = OrderFactory(
+            trader_id=TraderId("TESTER-000"),
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+        self.analyzer = ExecutionQualityAnalyzer()
+        self.analyzer.add_quote_ticks(
+            [
+                TestDataStubs.quote_tick(AUDUSD_SIM, bid_price=1.0, ask_price=1.0002, ts_event=0),
+            ],
+        )
+        self.analyzer.add_trade_ticks(
+            [
+                TestDataStubs.trade_tick(AUDUSD_SIM, price=1.0004, size=300_000, ts_event=50),
+                TestDataStubs.trade_tick(AUDUSD_SIM, price=1.0, size=100_000, ts_event=0),
+                TestDataStubs.trade_tick(AUDUSD_SIM, price=1.0002, size=100_000, ts_event=100),
+            ],
+        )
+
+    def _fill(self, order, last_px: str, ts_filled_ns: int) -> None:
+        order.apply(TestEventStubs.order_submitted(order))
+        order.apply(TestEventStubs.order_accepted(order))
+        order.apply(
+            TestEventStubs.order_filled(
+                order,
+                instrument=AUDUSD_SIM,
+                last_px=Price.from_str(last_px),
+                ts_filled_ns=ts_filled_ns,
+            ),
+        )
+
+    def test_generate_report_with_no_orders_returns_empty_dataframe(self):
+        # Arrange, Act
+        report = self.analyzer.generate_report([])
+
+        # Assert
+        assert report.empty
+
+    def test_generate_report_excludes_unfilled_orders(self):
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        # Act
+        report = self.analyzer.generate_report([order])
+
+        # Assert
+        assert report.empty
+
+    def test_generate_report_groups_child_fills_under_parent(self):
+        # Arrange
+        primary = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            exec_algorithm_id=ExecAlgorithmId("TWAP"),
+        )
+        child = MarketOrder(
+            trader_id=primary.trader_id,
+            strategy_id=primary.strategy_id,
+            instrument_id=AUDUSD_SIM.id,
+            client_order_id=ClientOrderId(f"{primary.client_order_id.value}-E1"),
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(100_000),
+            init_id=UUID4(),
+            ts_init=10,
+            exec_algorithm_id=ExecAlgorithmId("TWAP"),
+            exec_spawn_id=primary.client_order_id,
+        )
+        self._fill(primary, "1.00020", ts_filled_ns=50)
+        self._fill(child, "1.00040", ts_filled_ns=100)
+
+        # Act
+        report = self.analyzer.generate_report([primary, child])
+
+        # Assert
+        assert len(report) == 1
+        row = report.loc[primary.client_order_id.value]
+        assert row["side"] == "BUY"
+        assert row["child_orders"] == 2
+        assert row["fills"] == 2
+        assert row["filled_qty"] == 200_000
+        assert row["avg_px"] == pytest.approx(1.0003)
+        assert row["arrival_px"] == pytest.approx(1.0001)
+        assert row["arrival_slippage_bps"] == pytest.approx(2.0 / 1.0001)
+        assert row["interval_vwap"] == pytest.approx(1.00028)
+        assert row["vwap_slippage_bps"] == pytest.approx(0.2 / 1.00028)
+        assert row["interval_twap"] == pytest.approx(1.0002)
+        assert row["market_volume"] == 500_000
+        assert row["participation_rate"] == pytest.approx(0.4)
+
+    def test_sell_slippage_below_benchmark_is_a_cost(self):
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+        self._fill(order, "0.99990", ts_filled_ns=100)
+
+        # Act
+        report = self.analyzer.generate_report([order])
+
+        # Assert
+        assert report.iloc[0]["arrival_slippage_bps"] == pytest.approx(2.0 / 1.0001)
+
+    def test_write_csv_and_arrow(self, tmp_path):
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._fill(order, "1.00020", ts_filled_ns=100)
+        report = self.analyzer.generate_report([order])
+
+        # Act
+        ExecutionQualityAnalyzer.write_csv(report, tmp_path / "tca.csv")
+        ExecutionQualityAnalyzer.write_arrow(report, tmp_path / "tca.arrow")
+
+        # Assert
+        csv = (tmp_path / "tca.csv").read_text()
+        assert csv.startswith("parent_order_id,instrument_id,side")
+        table = feather.read_table(tmp_path / "tca.arrow")
+        assert table.num_rows == 1
+        assert "participation_rate" in table.column_names
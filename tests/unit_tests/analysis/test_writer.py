@@ -0,0 +1,171 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.analysis.writer import ReportWriter
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import PositionId
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.position import Position
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class TestReportWriter:
+    def setup(self):
+        # Fixture Setup
+        self.cache = TestComponentStubs.cache()
+        self.order_factory = OrderFactory(
+            trader_id=TraderId("TESTER-000"),
+            strategy_id=StrategyId("S-001"),
+            clock=TestClock(),
+        )
+        self.writer = ReportWriter(self.cache)
+
+    def _fill_round_trip(self) -> None:
+        order1 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        order2 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+        position_id = PositionId("P-1")
+        self.cache.add_order(order1, position_id)
+        self.cache.add_order(order2, position_id)
+
+        fill1 = TestEventStubs.order_filled(
+            order1,
+            instrument=AUDUSD_SIM,
+            position_id=position_id,
+            last_px=Price.from_str("1.00000"),
+        )
+        fill2 = TestEventStubs.order_filled(
+            order2,
+            instrument=AUDUSD_SIM,
+            position_id=position_id,
+            last_px=Price.from_str("1.00010"),
+        )
+        order1.apply(TestEventStubs.order_submitted(order1))
+        order1.apply(TestEventStubs.order_accepted(order1))
+        order1.apply(fill1)
+        order2.apply(TestEventStubs.order_submitted(order2))
+        order2.apply(TestEventStubs.order_accepted(order2))
+        order2.apply(fill2)
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill1)
+        position.apply(fill2)
+        self.cache.add_position(position, OmsType.NETTING)
+
+    def test_generate_reports_with_empty_cache_returns_empty_reports(self):
+        # Arrange, Act
+        reports = self.writer.generate_reports()
+
+        # Assert
+        assert list(reports) == ["fills", "positions"]
+        assert all(report.empty for report in reports.values())
+
+    def test_generate_account_statement_for_unknown_account_returns_empty_dataframe(self):
+        # Arrange, Act
+        report = self.writer.generate_account_statement(AccountId("SIM-999"))
+
+        # Assert
+        assert report.empty
+
+    def test_generate_fills_blotter_and_positions_report(self):
+        # Arrange
+        self._fill_round_trip()
+
+        # Act
+        blotter = self.writer.generate_fills_blotter()
+        positions = self.writer.generate_positions_report()
+
+        # Assert
+        assert len(blotter) == 2
+        assert blotter.index.name == "client_order_id"
+        assert list(blotter["order_side"]) == ["BUY", "SELL"]
+        assert list(blotter["last_px"]) == ["1.00000", "1.00010"]
+        assert len(positions) == 1
+        assert positions.index[0] == "P-1"
+        assert positions.iloc[0]["side"] == "FLAT"
+
+    def test_generate_reports_includes_account_statements(self):
+        # Arrange
+        account = TestExecStubs.margin_account()
+        self.cache.add_account(account)
+
+        # Act
+        reports = self.writer.generate_reports()
+
+        # Assert
+        statement = reports[f"account-{account.id}"]
+        assert len(statement) == 1
+        assert statement.iloc[0]["account_id"] == account.id.value
+        assert statement.iloc[0]["currency"] == "USD"
+
+    def test_to_arrow_preserves_index_and_columns(self):
+        # Arrange
+        self._fill_round_trip()
+        blotter = self.writer.generate_fills_blotter()
+
+        # Act
+        table = ReportWriter.to_arrow(blotter)
+
+        # Assert
+        assert table.num_rows == 2
+        assert "client_order_id" in table.column_names
+        assert "trade_id" in table.column_names
+
+    def test_write_csv_writes_non_empty_reports(self, tmp_path):
+        # Arrange
+        self._fill_round_trip()
+        self.cache.add_account(TestExecStubs.margin_account())
+
+        # Act
+        paths = self.writer.write_csv(tmp_path / "reports")
+
+        # Assert
+        assert sorted(p.name for p in paths) == [
+            "account-SIM-000.csv",
+            "fills.csv",
+            "positions.csv",
+        ]
+        assert all(p.exists() for p in paths)
+        assert (tmp_path / "reports" / "fills.csv").read_text().startswith("client_order_id,")
+
+    def test_write_arrow_writes_non_empty_reports(self, tmp_path):
+        # Arrange
+        self._fill_round_trip()
+
+        # Act
+        paths = self.writer.write_arrow(tmp_path)
+
+        # Assert
+        assert sorted(p.name for p in paths) == ["fills.feather", "positions.feather"]
+        assert all(p.exists() for p in paths)
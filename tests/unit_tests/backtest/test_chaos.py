@@ -0,0 +1,117 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.backtest.chaos import DataChaos
+from nautilus_trader.backtest.config import DataChaosConfig
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+def _quotes(count: int, interval_ns: int = 100) -> list:
+    return [
+        TestDataStubs.quote_tick(ts_event=i * interval_ns, ts_init=i * interval_ns)
+        for i in range(count)
+    ]
+
+
+def _deliver_all(chaos: DataChaos, data: list) -> list:
+    delivered = []
+    for item in data:
+        delivered.extend(chaos.deliver(item, item.ts_init))
+    delivered.extend(chaos.flush())
+    return delivered
+
+
+class TestDataChaos:
+    def test_default_config_delivers_data_unchanged(self):
+        # Arrange
+        chaos = DataChaos(DataChaosConfig())
+        quotes = _quotes(10)
+
+        # Act
+        delivered = _deliver_all(chaos, quotes)
+
+        # Assert
+        assert delivered == quotes
+        assert chaos.pending_count == 0
+
+    def test_duplicate_probability_one_delivers_each_data_point_twice(self):
+        # Arrange
+        chaos = DataChaos(DataChaosConfig(duplicate_probability=1.0))
+        quotes = _quotes(3)
+
+        # Act
+        delivered = _deliver_all(chaos, quotes)
+
+        # Assert
+        assert delivered == [quotes[0], quotes[0], quotes[1], quotes[1], quotes[2], quotes[2]]
+        assert chaos.duplicated_count == 3
+
+    def test_delayed_data_delivered_once_delay_has_elapsed(self):
+        # Arrange
+        chaos = DataChaos(DataChaosConfig(delay_probability=1.0, max_delay_ns=50))
+        quotes = _quotes(3, interval_ns=100)
+
+        # Act
+        first = chaos.deliver(quotes[0], quotes[0].ts_init)
+        second = chaos.deliver(quotes[1], quotes[1].ts_init)
+        third = chaos.deliver(quotes[2], quotes[2].ts_init)
+
+        # Assert
+        assert first == []
+        assert second == [quotes[0]]
+        assert third == [quotes[1]]
+        assert chaos.pending_count == 1
+        assert chaos.flush() == [quotes[2]]
+
+    def test_same_seed_reproduces_delivery(self):
+        # Arrange
+        config = DataChaosConfig(
+            seed=7,
+            delay_probability=0.2,
+            max_delay_ns=250,
+            duplicate_probability=0.1,
+            reorder_probability=0.2,
+            reorder_window=3,
+        )
+        quotes = _quotes(200)
+
+        # Act
+        chaos1 = DataChaos(config)
+        chaos2 = DataChaos(config)
+        delivered1 = _deliver_all(chaos1, quotes)
+        delivered2 = _deliver_all(chaos2, quotes)
+
+        # Assert
+        assert delivered1 == delivered2
+        assert delivered1 != quotes
+        assert len(delivered1) == len(quotes) + chaos1.duplicated_count
+        assert {id(q) for q in delivered1} == {id(q) for q in quotes}
+        assert chaos1.delayed_count > 0
+        assert chaos1.reordered_count > 0
+
+    @pytest.mark.parametrize(
+        "config",
+        [
+            DataChaosConfig(duplicate_probability=1.5),
+            DataChaosConfig(delay_probability=0.5),
+            DataChaosConfig(reorder_probability=0.5),
+        ],
+    )
+    def test_invalid_config_raises(self, config):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            DataChaos(config)
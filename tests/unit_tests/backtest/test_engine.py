@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 import sys
+from datetime import timedelta
 from decimal import Decimal
 from pathlib import Path
 
@@ -28,6 +29,7 @@ from nautilus_trader.config import ImportableControllerConfig
 from nautilus_trader.config import InvalidConfiguration
 from nautilus_trader.config import LoggingConfig
 from nautilus_trader.config import StreamingConfig
+from nautilus_trader.core.datetime import secs_to_nanos
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.examples.strategies.ema_cross import EMACross
 from nautilus_trader.examples.strategies.ema_cross import EMACrossConfig
@@ -44,6 +46,7 @@ from nautilus_trader.model.data import DataType
 from nautilus_trader.model.data import InstrumentStatus
 from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import BarAggregation
@@ -52,6 +55,7 @@ from nautilus_trader.model.enums import MarketStatus
 from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.events import OrderFilled
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.model.objects import Money
@@ -78,6 +82,47 @@ GBPUSD_SIM = TestInstrumentProvider.default_fx_ccy("GBP/USD")
 USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
 
 
+class EventRecordingActor(Actor):
+    def __init__(self, records: list[str]) -> None:
+        super().__init__()
+        self.records = records
+
+    def on_start(self) -> None:
+        self.clock.set_timer("ZZZ_ACTOR", timedelta(seconds=1), callback=self.record_timer)
+
+    def record_timer(self, event) -> None:
+        self.records.append(f"{event.ts_event} TIMER {event.name}")
+
+
+class EventRecordingStrategy(Strategy):
+    def __init__(self, records: list[str]) -> None:
+        super().__init__()
+        self.records = records
+
+    def on_start(self) -> None:
+        # Timers set out of name order, all firing together
+        for name in ("TIMER_C", "TIMER_A", "TIMER_B"):
+            self.clock.set_timer(name, timedelta(seconds=1), callback=self.record_timer)
+        self.subscribe_quote_ticks(AUDUSD_SIM.id)
+        self.subscribe_quote_ticks(GBPUSD_SIM.id)
+
+    def record_timer(self, event) -> None:
+        self.records.append(f"{event.ts_event} TIMER {event.name}")
+
+    def on_quote_tick(self, tick: QuoteTick) -> None:
+        self.records.append(f"{tick.ts_init} DATA {tick.instrument_id}")
+        if tick.instrument_id == AUDUSD_SIM.id:
+            order = self.order_factory.market(
+                instrument_id=tick.instrument_id,
+                order_side=OrderSide.BUY,
+                quantity=Quantity.from_int(100_000),
+            )
+            self.submit_order(order)
+
+    def on_order_filled(self, event: OrderFilled) -> None:
+        self.records.append(f"{event.ts_event} FILL {event.client_order_id}")
+
+
 class TestBacktestEngine:
     def setup(self):
         # Fixture Setup
@@ -624,6 +669,34 @@ class TestBacktestEngineData:
         assert self.engine.data == data
 
 
+    def test_add_instrument_updates_when_instrument_not_added_raises_value_error(self):
+        # Arrange
+        updated = TestInstrumentProvider.instrument_version(USDJPY_SIM, ts_event=1_000)
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.engine.add_data(data=[updated])
+
+    def test_add_instrument_updates_applies_versions_on_run(self):
+        # Arrange
+        updated = TestInstrumentProvider.instrument_version(
+            USDJPY_SIM,
+            ts_event=1_000,
+            price_precision=2,
+            price_increment="0.01",
+        )
+        self.engine.add_instrument(USDJPY_SIM)
+
+        # Act
+        self.engine.add_data(data=[updated])
+        self.engine.run()
+
+        # Assert
+        assert self.engine.data == [updated]
+        assert self.engine.cache.instrument(USDJPY_SIM.id) is updated
+        assert self.engine.cache.instrument_versions(USDJPY_SIM.id) == [USDJPY_SIM, updated]
+        assert self.engine.cache.instrument_at(USDJPY_SIM.id, 999) is USDJPY_SIM
+
 class TestBacktestWithAddedBars:
     def setup(self):
         # Fixture Setup
@@ -741,3 +814,68 @@ class TestBacktestWithAddedBars:
             1_011_166.89,
             USD,
         )
+
+
+class TestBacktestEngineEventOrdering:
+    def run_recorded(self) -> list[str]:
+        engine = BacktestEngine(
+            BacktestEngineConfig(logging=LoggingConfig(bypass_logging=True)),
+        )
+        engine.add_venue(
+            venue=Venue("SIM"),
+            oms_type=OmsType.HEDGING,
+            account_type=AccountType.MARGIN,
+            base_currency=USD,
+            starting_balances=[Money(1_000_000, USD)],
+        )
+        engine.add_instrument(AUDUSD_SIM)
+        engine.add_instrument(GBPUSD_SIM)
+
+        # Data for both instruments shares every timestamp
+        timestamps = [secs_to_nanos(secs) for secs in range(1, 4)]
+        engine.add_data(
+            [
+                TestDataStubs.quote_tick(AUDUSD_SIM, 0.70000, 0.70010, ts_event=ts, ts_init=ts)
+                for ts in timestamps
+            ],
+        )
+        engine.add_data(
+            [
+                TestDataStubs.quote_tick(GBPUSD_SIM, 1.30000, 1.30010, ts_event=ts, ts_init=ts)
+                for ts in timestamps
+            ],
+        )
+
+        records: list[str] = []
+        engine.add_actor(EventRecordingActor(records))
+        engine.add_strategy(EventRecordingStrategy(records))
+        engine.run()
+        engine.dispose()
+
+        return records
+
+    def test_events_at_same_timestamp_processed_in_documented_order(self):
+        # Arrange, Act
+        records = self.run_recorded()
+
+        # Assert
+        ts = secs_to_nanos(2)
+        assert [r for r in records if r.startswith(str(ts))] == [
+            f"{ts} DATA AUD/USD.SIM",
+            f"{ts} FILL O-19700101-0000-001-None-2",
+            f"{ts} DATA GBP/USD.SIM",
+            f"{ts} TIMER ZZZ_ACTOR",
+            f"{ts} TIMER TIMER_A",
+            f"{ts} TIMER TIMER_B",
+            f"{ts} TIMER TIMER_C",
+        ]
+
+    def test_replaying_tied_timestamps_is_byte_identical(self):
+        # Arrange
+        expected = "\n".join(self.run_recorded()).encode()
+
+        # Act
+        results = ["\n".join(self.run_recorded()).encode() for _ in range(5)]
+
+        # Assert
+        assert all(result == expected for result in results)
@@ -36,6 +36,7 @@ from nautilus_trader.execution.messages import CancelOrder
 from nautilus_trader.execution.messages import ModifyOrder
 from nautilus_trader.model.currencies import JPY
 from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import StockSplit
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggressorSide
@@ -1802,6 +1803,76 @@ class TestSimulatedExchange:
         assert len(self.exchange.get_open_orders()) == 0
         assert order.avg_px == 90.005
 
+    def test_process_stock_split_adjusts_working_orders(self) -> None:
+        # Arrange: Prepare market
+        tick = TestDataStubs.quote_tick(
+            instrument=USDJPY_SIM,
+            bid_price=90.002,
+            ask_price=90.005,
+        )
+        self.data_engine.process(tick)
+        self.exchange.process_quote_tick(tick)
+
+        order = self.strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+        self.strategy.submit_order(order)
+        self.exchange.process(0)
+
+        split = StockSplit(
+            instrument_id=USDJPY_SIM.id,
+            numerator=2,
+            denominator=1,
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        self.exchange.process_stock_split(split)
+
+        # Assert
+        assert order.status == OrderStatus.ACCEPTED
+        assert order.quantity == Quantity.from_int(200_000)
+        assert order.price == Price.from_str("45.000")
+        assert isinstance(order.last_event, OrderUpdated)
+
+    def test_process_stock_split_cancels_orders_rounding_to_zero_quantity(self) -> None:
+        # Arrange: Prepare market
+        tick = TestDataStubs.quote_tick(
+            instrument=USDJPY_SIM,
+            bid_price=90.002,
+            ask_price=90.005,
+        )
+        self.data_engine.process(tick)
+        self.exchange.process_quote_tick(tick)
+
+        order = self.strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+        self.strategy.submit_order(order)
+        self.exchange.process(0)
+
+        split = StockSplit(
+            instrument_id=USDJPY_SIM.id,
+            numerator=1,
+            denominator=1_000_000,
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        self.exchange.process_stock_split(split)
+
+        # Assert
+        assert order.status == OrderStatus.CANCELED
+        assert len(self.exchange.get_open_orders()) == 0
+
     def test_modify_stop_market_order_when_price_inside_market_then_rejects_modify(
         self,
     ) -> None:
@@ -27,10 +27,15 @@ from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderRejected
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orders import MarketOrder
+from nautilus_trader.model.position import Position
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.component import TestComponentStubs
 from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
 from nautilus_trader.test_kit.stubs.execution import TestExecStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
 
@@ -83,6 +88,50 @@ class TestOrderMatchingEngine:
         # Assert
         assert True
 
+    def test_update_instrument_with_different_instrument_id_raises(self) -> None:
+        # Arrange
+        instrument = TestInstrumentProvider.btcusdt_perp_binance()
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.matching_engine.update_instrument(instrument)
+
+    def test_update_instrument(self) -> None:
+        # Arrange
+        updated = TestInstrumentProvider.instrument_version(
+            self.instrument,
+            ts_event=1_000,
+            price_precision=1,
+            price_increment="0.1",
+        )
+
+        # Act
+        self.matching_engine.update_instrument(updated)
+
+        # Assert
+        assert self.matching_engine.instrument is updated
+
+    def test_iterate_applies_instrument_version_effective_at_timestamp(self) -> None:
+        # Arrange
+        updated = TestInstrumentProvider.instrument_version(
+            self.instrument,
+            ts_event=1_000,
+            price_precision=1,
+            price_increment="0.1",
+        )
+        self.cache.add_instrument(updated)
+
+        # Act
+        self.matching_engine.iterate(999)
+        instrument_before = self.matching_engine.instrument
+        self.matching_engine.iterate(1_000)
+        instrument_after = self.matching_engine.instrument
+
+        # Assert
+        assert instrument_before is self.instrument
+        assert instrument_after is updated
+        assert instrument_after.price_increment == Price.from_str("0.1")
+
     def test_process_venue_status(self) -> None:
         self.matching_engine.process_status(MarketStatus.CLOSED)
         self.matching_engine.process_status(MarketStatus.PRE_OPEN)
@@ -124,3 +173,89 @@ class TestOrderMatchingEngine:
         # Assert
         assert self.matching_engine.msgbus.sent_count == 1
         assert isinstance(messages[0], OrderFilled)
+
+
+class TestOrderMatchingEngineBinaryOption:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.trader_id = TestIdStubs.trader_id()
+
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+        self.instrument = TestInstrumentProvider.binary_option()
+        self.cache = TestComponentStubs.cache()
+        self.cache.add_instrument(self.instrument)
+
+        self.matching_engine = OrderMatchingEngine(
+            instrument=self.instrument,
+            raw_id=0,
+            fill_model=FillModel(),
+            book_type=BookType.L1_MBP,
+            oms_type=OmsType.NETTING,
+            reject_stop_orders=True,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.messages: list[Any] = []
+        self.msgbus.register("ExecEngine.process", self.messages.append)
+
+    def _open_position(self, order_side: OrderSide) -> Position:
+        order = TestExecStubs.market_order(
+            instrument_id=self.instrument.id,
+            order_side=order_side,
+            quantity=Quantity.from_int(100),
+        )
+        fill = TestEventStubs.order_filled(
+            order,
+            self.instrument,
+            last_px=Price.from_str("0.600"),
+        )
+        position = Position(instrument=self.instrument, fill=fill)
+        self.cache.add_position(position, OmsType.NETTING)
+        return position
+
+    def test_expiration_settles_long_position_at_one_when_market_implies_outcome(self) -> None:
+        # Arrange
+        self._open_position(OrderSide.BUY)
+        quote = TestDataStubs.quote_tick(
+            instrument=self.instrument,
+            bid_price=0.950,
+            ask_price=0.960,
+        )
+        self.matching_engine.process_quote_tick(quote)
+
+        # Act
+        self.matching_engine.iterate(self.instrument.expiration_ns)
+
+        # Assert
+        fill = self.messages[-1]
+        assert isinstance(fill, OrderFilled)
+        assert fill.order_side == OrderSide.SELL
+        assert fill.last_qty == Quantity.from_int(100)
+        assert fill.last_px == Price.from_str("1.000")
+        assert fill.commission.as_double() == 0
+
+    def test_settle_positions_at_zero_then_rejects_new_orders(self) -> None:
+        # Arrange
+        self._open_position(OrderSide.SELL)
+
+        # Act
+        self.matching_engine.settle_positions(self.instrument.settlement_price(False))
+        order = TestExecStubs.market_order(
+            instrument_id=self.instrument.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(10),
+        )
+        self.matching_engine.process_order(order, TestIdStubs.account_id())
+
+        # Assert
+        fill = self.messages[-2]
+        assert isinstance(fill, OrderFilled)
+        assert fill.order_side == OrderSide.BUY
+        assert fill.last_px == Price.from_str("0.000")
+        assert isinstance(self.messages[-1], OrderRejected)
@@ -14,8 +14,13 @@
 # -------------------------------------------------------------------------------------------------
 
 import pandas as pd
+import pytest
 
 from nautilus_trader.backtest.engine import BacktestEngine
+from nautilus_trader.backtest.modules import BorrowFeeAccrualConfig
+from nautilus_trader.backtest.modules import BorrowFeeAccrualModule
+from nautilus_trader.backtest.modules import FinancingAccrualConfig
+from nautilus_trader.backtest.modules import FinancingAccrualModule
 from nautilus_trader.backtest.modules import FXRolloverInterestConfig
 from nautilus_trader.backtest.modules import FXRolloverInterestModule
 from nautilus_trader.backtest.modules import SimulationModule
@@ -68,6 +73,65 @@ class TestSimulationModules:
         [venue] = engine.list_venues()
         assert venue
 
+    def test_financing_accrual_module(self):
+        # Arrange
+        config = FinancingAccrualConfig(
+            rates={str(USDJPY_SIM.id): (-0.02, 0.01)},
+            rollover_time="17:00",
+            triple_day=3,
+        )
+        module = FinancingAccrualModule(config)
+        engine = self.create_engine(modules=[module])
+
+        # Act
+        engine.run()
+
+        # Assert
+        [venue] = engine.list_venues()
+        assert venue
+        assert engine.portfolio.account(venue).balance_total(USD) == Money(1_000_000, USD)
+
+    def test_financing_accrual_module_with_invalid_triple_day_raises(self):
+        # Arrange
+        config = FinancingAccrualConfig(rates={}, triple_day=6)
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            FinancingAccrualModule(config)
+
+    def test_borrow_fee_accrual_module(self):
+        # Arrange
+        config = BorrowFeeAccrualConfig(rates={"USD": 0.05}, accrual_interval="HOURLY")
+        module = BorrowFeeAccrualModule(config)
+        engine = self.create_engine(modules=[module])
+
+        # Act
+        engine.run()
+
+        # Assert
+        [venue] = engine.list_venues()
+        assert venue
+        assert engine.portfolio.account(venue).balance_total(USD) == Money(1_000_000, USD)
+
+    def test_borrow_fee_accrual_module_with_invalid_interval_raises(self):
+        # Arrange
+        config = BorrowFeeAccrualConfig(accrual_interval="WEEKLY")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            BorrowFeeAccrualModule(config)
+
+    def test_rollover_interest_and_financing_accrual_modules_together_raises(self):
+        # Arrange
+        modules = [
+            FXRolloverInterestModule(FXRolloverInterestConfig(pd.DataFrame(columns=["LOCATION"]))),
+            FinancingAccrualModule(FinancingAccrualConfig(rates={})),
+        ]
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.create_engine(modules=modules)
+
     def test_python_module(self):
         # Arrange
         class PythonModule(SimulationModule):
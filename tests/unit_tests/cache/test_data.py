@@ -189,6 +189,130 @@ class TestCache:
         # Assert
         assert result == []
 
+    def test_instrument_at_when_no_instrument_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 0) is None
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == []
+
+    def test_add_instrument_with_later_ts_event_adds_new_version(self):
+        # Arrange
+        updated = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=1_000,
+            price_precision=4,
+            price_increment="0.0001",
+        )
+        self.cache.add_instrument(AUDUSD_SIM)
+
+        # Act
+        self.cache.add_instrument(updated)
+
+        # Assert
+        assert self.cache.instrument(AUDUSD_SIM.id) is updated
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == [AUDUSD_SIM, updated]
+        assert self.cache.instruments() == [updated]
+
+    def test_instrument_at_returns_version_effective_at_timestamp(self):
+        # Arrange
+        updated1 = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=1_000,
+            price_precision=4,
+            price_increment="0.0001",
+        )
+        updated2 = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=2_000,
+            lot_size="100",
+        )
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.cache.add_instrument(updated1)
+        self.cache.add_instrument(updated2)
+
+        # Act, Assert
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 0) is AUDUSD_SIM
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 999) is AUDUSD_SIM
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 1_000) is updated1
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 1_999) is updated1
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 2_000) is updated2
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 10_000) is updated2
+
+    def test_instrument_at_before_earliest_version_returns_earliest_version(self):
+        # Arrange
+        instrument = TestInstrumentProvider.instrument_version(AUDUSD_SIM, ts_event=1_000)
+        self.cache.add_instrument(instrument)
+
+        # Act
+        result = self.cache.instrument_at(AUDUSD_SIM.id, 0)
+
+        # Assert
+        assert result is instrument
+
+    def test_add_instrument_with_earlier_ts_event_keeps_current_version(self):
+        # Arrange
+        current = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=2_000,
+            price_precision=4,
+            price_increment="0.0001",
+        )
+        prior = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=1_000,
+            lot_size="100",
+        )
+        self.cache.add_instrument(current)
+
+        # Act
+        self.cache.add_instrument(prior)
+
+        # Assert
+        assert self.cache.instrument(AUDUSD_SIM.id) is current
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == [prior, current]
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 1_500) is prior
+
+    def test_add_instrument_with_same_ts_event_replaces_version(self):
+        # Arrange
+        corrected = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=0,
+            lot_size="100",
+        )
+        self.cache.add_instrument(AUDUSD_SIM)
+
+        # Act
+        self.cache.add_instrument(corrected)
+
+        # Assert
+        assert self.cache.instrument(AUDUSD_SIM.id) is corrected
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == [corrected]
+
+    def test_add_instrument_with_unchanged_specification_does_not_add_version(self):
+        # Arrange
+        reloaded = TestInstrumentProvider.instrument_version(AUDUSD_SIM, ts_event=1_000)
+        self.cache.add_instrument(AUDUSD_SIM)
+
+        # Act
+        self.cache.add_instrument(reloaded)
+
+        # Assert
+        assert self.cache.instrument(AUDUSD_SIM.id) is AUDUSD_SIM
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == [AUDUSD_SIM]
+
+    def test_reset_drops_instrument_versions(self):
+        # Arrange
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.cache.add_instrument(
+            TestInstrumentProvider.instrument_version(AUDUSD_SIM, ts_event=1_000, lot_size="100"),
+        )
+
+        # Act
+        self.cache.reset()
+
+        # Assert
+        assert self.cache.instrument_versions(AUDUSD_SIM.id) == []
+        assert self.cache.instrument_at(AUDUSD_SIM.id, 1_000) is None
+
     def test_synthetic_ids_when_one_synthetic_instrument_returns_expected_list(self):
         # Arrange
         synthetic = TestInstrumentProvider.synthetic_instrument()
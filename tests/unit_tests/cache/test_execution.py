@@ -296,6 +296,10 @@ class TestCache:
         # Arrange, Act, Assert
         assert self.cache.orders_for_exec_algorithm(ExecAlgorithmId("UNKNOWN")) == []
 
+    def test_orders_for_tag_when_not_found(self):
+        # Arrange, Act, Assert
+        assert self.cache.orders_for_tag("UNKNOWN") == []
+
     def test_exec_spawn_total_quantity_when_not_found(self):
         # Arrange, Act, Assert
         assert self.cache.exec_spawn_total_quantity(ClientOrderId("O-UNKNOWN")) is None
@@ -413,6 +417,46 @@ class TestCache:
         )
         assert order not in self.cache.orders_for_exec_algorithm(ExecAlgorithmId("UnknownAlgo"))
 
+    def test_add_order_with_duplicate_client_order_id_raises(self):
+        # Arrange
+        order = self.strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self.cache.add_order(order)
+
+        # Act, Assert
+        with pytest.raises(ValueError, match="Duplicate ClientOrderId"):
+            self.cache.add_order(order)
+
+    def test_add_order_with_tags_indexes_each_tag(self):
+        # Arrange
+        order1 = self.strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            tags="signal-a, leg-1",
+        )
+        order2 = self.strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            tags="signal-a,leg-2",
+        )
+
+        # Act
+        self.cache.add_order(order1)
+        self.cache.add_order(order2)
+
+        # Assert
+        assert self.cache.order_tags() == {"signal-a", "leg-1", "leg-2"}
+        assert self.cache.orders_for_tag("signal-a") == [order1, order2]
+        assert self.cache.orders_for_tag("signal-a", side=OrderSide.SELL) == [order2]
+        assert self.cache.orders_for_tag("leg-1", instrument_id=AUDUSD_SIM.id) == [order1]
+        assert self.cache.orders_for_tag("leg-1", strategy_id=StrategyId("S-ZX1")) == []
+        assert self.cache.orders_for_tag("signal-a, leg-1") == []
+
     def test_add_emulated_limit_order(self):
         # Arrange
         order = self.strategy.order_factory.limit(
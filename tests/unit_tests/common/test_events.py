@@ -20,6 +20,7 @@ import pytest
 from nautilus_trader.common.component import TimeEvent
 from nautilus_trader.common.enums import ComponentState
 from nautilus_trader.common.messages import ComponentStateChanged
+from nautilus_trader.common.messages import RiskConfigChanged
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ActorConfig
 from nautilus_trader.core.uuid import UUID4
@@ -120,3 +121,30 @@ class TestCommonEvents:
             repr(event)
             == f"TradingStateChanged(trader_id=TESTER-000, state=HALTED, config={{'max_order_submit_rate': '100/00:00:01'}}, event_id={uuid}, ts_init=0)"  # noqa
         )
+
+    def test_risk_config_changed(self):
+        # Arrange
+        uuid = UUID4()
+        command_id = UUID4()
+        event = RiskConfigChanged(
+            trader_id=TestIdStubs.trader_id(),
+            setting="max_order_submit_rate",
+            old_value="100/00:00:01",
+            new_value="10/00:00:01",
+            requested_by="ops-desk",
+            command_id=command_id,
+            event_id=uuid,
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert RiskConfigChanged.from_dict(RiskConfigChanged.to_dict(event)) == event
+        assert (
+            str(event)
+            == f"RiskConfigChanged(trader_id=TESTER-000, setting=max_order_submit_rate, old_value=100/00:00:01, new_value=10/00:00:01, requested_by=ops-desk, event_id={uuid})"  # noqa
+        )
+        assert (
+            repr(event)
+            == f"RiskConfigChanged(trader_id=TESTER-000, setting=max_order_submit_rate, old_value=100/00:00:01, new_value=10/00:00:01, requested_by=ops-desk, command_id={command_id}, event_id={uuid}, ts_event=1, ts_init=2)"  # noqa
+        )
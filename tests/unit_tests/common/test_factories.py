@@ -13,16 +13,23 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pytest
+
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.factories import OrderFactory
+from nautilus_trader.model.enums import BookType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import OrderListId
+from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
 
 
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
 ETHUSDT_PERP_BINANCE = TestInstrumentProvider.ethusdt_perp_binance()
 
 
@@ -96,3 +103,121 @@ class TestOrderFactory:
 
         # Assert
         assert len(order_list) == 3
+
+
+class TestOrderFactoryPricing:
+    def setup(self):
+        # Fixture Setup
+        self.cache = TestComponentStubs.cache()
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.cache.add_instrument(ETHUSDT_PERP_BINANCE)
+
+        self.order_factory = OrderFactory(
+            trader_id=TestIdStubs.trader_id(),
+            strategy_id=TestIdStubs.strategy_id(),
+            clock=TestClock(),
+            cache=self.cache,
+        )
+
+    def test_pricing_with_no_market_returns_none(self):
+        # Arrange, Act, Assert
+        assert self.order_factory.market_to_limit_price(AUDUSD_SIM, OrderSide.BUY) is None
+        assert self.order_factory.peg_primary_price(AUDUSD_SIM, OrderSide.BUY) is None
+        assert self.order_factory.peg_mid_price(AUDUSD_SIM, OrderSide.BUY) is None
+
+    def test_pricing_with_negative_offset_raises_value_error(self):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00003))
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.order_factory.market_to_limit_price(AUDUSD_SIM, OrderSide.BUY, offset_ticks=-1)
+
+    @pytest.mark.parametrize(
+        ("side", "offset_ticks", "expected"),
+        [
+            [OrderSide.BUY, 0, "1.00003"],
+            [OrderSide.BUY, 2, "1.00005"],
+            [OrderSide.SELL, 0, "1.00000"],
+            [OrderSide.SELL, 2, "0.99998"],
+        ],
+    )
+    def test_market_to_limit_price(self, side: OrderSide, offset_ticks: int, expected: str):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00003))
+
+        # Act
+        price = self.order_factory.market_to_limit_price(AUDUSD_SIM, side, offset_ticks)
+
+        # Assert
+        assert price == Price.from_str(expected)
+        assert price.precision == AUDUSD_SIM.price_precision
+
+    @pytest.mark.parametrize(
+        ("side", "offset_ticks", "expected"),
+        [
+            [OrderSide.BUY, 0, "1.00000"],
+            [OrderSide.BUY, 1, "0.99999"],
+            [OrderSide.SELL, 0, "1.00003"],
+            [OrderSide.SELL, 1, "1.00004"],
+        ],
+    )
+    def test_peg_primary_price(self, side: OrderSide, offset_ticks: int, expected: str):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00003))
+
+        # Act
+        price = self.order_factory.peg_primary_price(AUDUSD_SIM, side, offset_ticks)
+
+        # Assert
+        assert price == Price.from_str(expected)
+
+    @pytest.mark.parametrize(
+        ("side", "offset_ticks", "expected"),
+        [
+            [OrderSide.BUY, 0, "1.00001"],
+            [OrderSide.BUY, 1, "1.00000"],
+            [OrderSide.SELL, 0, "1.00002"],
+            [OrderSide.SELL, 1, "1.00003"],
+        ],
+    )
+    def test_peg_mid_price_rounds_to_passive_tick(self, side: OrderSide, offset_ticks: int, expected: str):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00003))
+
+        # Act
+        price = self.order_factory.peg_mid_price(AUDUSD_SIM, side, offset_ticks)
+
+        # Assert
+        assert price == Price.from_str(expected)
+
+    def test_peg_mid_price_when_mid_on_tick(self):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(AUDUSD_SIM, 1.00000, 1.00004))
+
+        # Act
+        buy_price = self.order_factory.peg_mid_price(AUDUSD_SIM, OrderSide.BUY)
+        sell_price = self.order_factory.peg_mid_price(AUDUSD_SIM, OrderSide.SELL)
+
+        # Assert
+        assert buy_price == Price.from_str("1.00002")
+        assert sell_price == Price.from_str("1.00002")
+
+    def test_pricing_uses_order_book_when_available(self):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick(ETHUSDT_PERP_BINANCE, 1990.00, 1990.10))
+        book = TestDataStubs.make_book(
+            instrument=ETHUSDT_PERP_BINANCE,
+            book_type=BookType.L2_MBP,
+            bids=[(2000.00, 10.0)],
+            asks=[(2000.50, 10.0)],
+        )
+        self.cache.add_order_book(book)
+
+        # Act
+        aggressive = self.order_factory.market_to_limit_price(ETHUSDT_PERP_BINANCE, OrderSide.BUY, 1)
+        mid = self.order_factory.peg_mid_price(ETHUSDT_PERP_BINANCE, OrderSide.SELL)
+
+        # Assert
+        assert aggressive == Price.from_str("2000.51")
+        assert mid == Price.from_str("2000.25")
@@ -15,6 +15,8 @@
 
 from datetime import timedelta
 
+import pytest
+
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.component import Throttler
 
@@ -78,6 +80,41 @@ class TestBufferingThrottler:
         assert self.throttler.recv_count == 6
         assert self.throttler.sent_count == 5
 
+    def test_set_rate_changes_limit_and_interval(self):
+        # Arrange, Act
+        self.throttler.set_rate(2, timedelta(seconds=2))
+
+        self.throttler.send("MESSAGE")
+        self.throttler.send("MESSAGE")
+        self.throttler.send("MESSAGE")
+
+        # Assert
+        assert self.throttler.limit == 2
+        assert self.throttler.interval == timedelta(seconds=2)
+        assert self.throttler.is_limiting
+        assert self.handler == ["MESSAGE"] * 2
+        assert self.throttler.qsize == 1
+
+    def test_set_rate_when_lowered_below_recent_sends_then_throttles_next_message(self):
+        # Arrange
+        self.throttler.send("MESSAGE")
+        self.throttler.send("MESSAGE")
+        self.throttler.send("MESSAGE")
+
+        # Act
+        self.throttler.set_rate(2, timedelta(seconds=1))
+        self.throttler.send("MESSAGE")
+
+        # Assert
+        assert self.throttler.is_limiting
+        assert self.handler == ["MESSAGE"] * 3
+        assert self.throttler.qsize == 1
+
+    def test_set_rate_with_invalid_limit_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.throttler.set_rate(0, timedelta(seconds=1))
+
     def test_used_when_sent_to_limit_returns_one(self):
         # Arrange
         item = "MESSAGE"
@@ -15,7 +15,13 @@
 
 import pytest
 
+from nautilus_trader.core.nautilus_pyo3 import add_local_days_nanos
 from nautilus_trader.core.nautilus_pyo3 import convert_to_snake_case
+from nautilus_trader.core.nautilus_pyo3 import local_day_nanos
+from nautilus_trader.core.nautilus_pyo3 import local_to_unix_nanos
+from nautilus_trader.core.nautilus_pyo3 import next_local_time_nanos
+from nautilus_trader.core.nautilus_pyo3 import unix_nanos_to_local_iso8601
+from nautilus_trader.core.nautilus_pyo3 import utc_offset_secs
 
 
 @pytest.mark.parametrize(
@@ -51,3 +57,53 @@ def test_convert_to_snake_case(input: str, expected: str) -> None:
 
     # Assert
     assert result == expected
+
+
+# 2024-03-10 07:00:00 UTC (03:00 EDT, immediately after the New York spring forward transition)
+NY_SPRING_FORWARD_NS = 1_710_054_000_000_000_000
+HOUR_NS = 3_600_000_000_000
+
+
+def test_unix_nanos_to_local_iso8601() -> None:
+    # Arrange, Act
+    result = unix_nanos_to_local_iso8601(NY_SPRING_FORWARD_NS, "America/New_York")
+
+    # Assert
+    assert result == "2024-03-10T03:00:00.000000000-04:00"
+    assert utc_offset_secs(NY_SPRING_FORWARD_NS, "America/New_York") == -14_400
+
+
+def test_local_to_unix_nanos_when_skipped_by_dst_resolves_to_transition() -> None:
+    # Arrange, Act
+    result = local_to_unix_nanos(2024, 3, 10, 2, 30, 0, "America/New_York")
+
+    # Assert
+    assert result == NY_SPRING_FORWARD_NS
+
+
+def test_next_local_time_nanos() -> None:
+    # Arrange, Act
+    result = next_local_time_nanos(NY_SPRING_FORWARD_NS, "America/New_York", 16, 0)
+
+    # Assert
+    assert result == NY_SPRING_FORWARD_NS + 13 * HOUR_NS
+
+
+def test_add_local_days_nanos_across_dst() -> None:
+    # Arrange
+    start = local_to_unix_nanos(2024, 3, 9, 9, 30, 0, "America/New_York")
+
+    # Act
+    result = add_local_days_nanos(start, "America/New_York", 1)
+
+    # Assert
+    assert result - start == 23 * HOUR_NS
+    assert local_day_nanos(start, "America/New_York") == 24 * HOUR_NS
+    assert local_day_nanos(result, "America/New_York") == 23 * HOUR_NS
+
+
+def test_invalid_timezone_raises_value_error() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        utc_offset_secs(0, "Mars/Olympus_Mons")
+
@@ -35,6 +35,7 @@ from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import BarAggregation
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import TradeFlag
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
@@ -250,6 +251,35 @@ class TestBarBuilder:
         assert bar2.close == Price.from_str("1.00002")
         assert bar2.volume == Quantity.from_str("3.0")
 
+    def test_snapshot_when_no_updates_returns_none(self):
+        # Arrange
+        bar_type = TestDataStubs.bartype_btcusdt_binance_100tick_last()
+        builder = BarBuilder(BTCUSDT_BINANCE, bar_type)
+
+        # Act, Assert
+        assert builder.snapshot(0, 0) is None
+
+    def test_snapshot_returns_bar_without_resetting(self):
+        # Arrange
+        bar_type = TestDataStubs.bartype_btcusdt_binance_100tick_last()
+        builder = BarBuilder(BTCUSDT_BINANCE, bar_type)
+        builder.update(Price.from_str("1.00001"), Quantity.from_str("1.0"), 0)
+        builder.update(Price.from_str("1.00003"), Quantity.from_str("1.0"), 0)
+
+        # Act
+        partial = builder.snapshot(60_000_000_000, 30_000_000_000)
+        builder.update(Price.from_str("1.00002"), Quantity.from_str("1.0"), 0)
+        bar = builder.build_now()
+
+        # Assert
+        assert partial.close == Price.from_str("1.00003")
+        assert partial.volume == Quantity.from_str("2.0")
+        assert partial.ts_event == 60_000_000_000
+        assert partial.ts_init == 30_000_000_000
+        assert bar.open == Price.from_str("1.00001")
+        assert bar.close == Price.from_str("1.00002")
+        assert bar.volume == Quantity.from_str("3.0")
+
 
 class TestTickBarAggregator:
     def test_handle_quote_tick_when_count_below_threshold_updates(self):
@@ -363,6 +393,49 @@ class TestTickBarAggregator:
         assert handler[0].close == Price.from_str("1.000015")
         assert handler[0].volume == Quantity.from_int(3)
 
+    def test_handle_trade_tick_with_excluded_flags_does_not_update(self):
+        # Arrange
+        handler = []
+        instrument = AUDUSD_SIM
+        bar_spec = BarSpecification(1, BarAggregation.TICK, PriceType.LAST)
+        bar_type = BarType(instrument.id, bar_spec)
+        aggregator = TickBarAggregator(
+            instrument,
+            bar_type,
+            handler.append,
+        )
+        aggregator.set_exclude_trade_flags(TradeFlag.BLOCK | TradeFlag.OFF_BOOK)
+
+        block_trade = TradeTick(
+            instrument_id=instrument.id,
+            price=Price.from_str("1.00001"),
+            size=Quantity.from_int(1_000_000),
+            aggressor_side=AggressorSide.NO_AGGRESSOR,
+            trade_id=TradeId("123456"),
+            ts_event=0,
+            ts_init=0,
+            flags=TradeFlag.BLOCK,
+        )
+        aggregated_trade = TradeTick(
+            instrument_id=instrument.id,
+            price=Price.from_str("1.00002"),
+            size=Quantity.from_int(1),
+            aggressor_side=AggressorSide.BUYER,
+            trade_id=TradeId("123457"),
+            ts_event=0,
+            ts_init=0,
+            flags=TradeFlag.AGGREGATED,
+        )
+
+        # Act
+        aggregator.handle_trade_tick(block_trade)
+        aggregator.handle_trade_tick(aggregated_trade)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].open == Price.from_str("1.00002")
+        assert handler[0].volume == Quantity.from_int(1)
+
     def test_handle_trade_tick_when_count_at_threshold_sends_bar_to_handler(self):
         # Arrange
         handler = []
@@ -1145,6 +1218,164 @@ class TestTimeBarAggregator:
         # Assert
         assert aggregator.next_close_ns == expected
 
+    def test_instantiate_with_epoch_origin_aligns_to_whole_intervals_from_epoch(self):
+        # Arrange
+        clock = TestClock()
+        clock.set_time(pd.Timestamp(1970, 1, 1, 2, 10).value)
+        handler = []
+        bar_spec = BarSpecification(13, BarAggregation.MINUTE, PriceType.MID)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+
+        # Act
+        aggregator = TimeBarAggregator(
+            AUDUSD_SIM,
+            bar_type,
+            handler.append,
+            clock,
+            origin=timedelta(0),
+        )
+
+        # Assert
+        assert aggregator.origin == timedelta(0)
+        assert aggregator.next_close_ns == pd.Timestamp(1970, 1, 1, 2, 23).value
+
+    def test_instantiate_with_session_origin_aligns_to_session_open(self):
+        # Arrange
+        clock = TestClock()
+        clock.set_time(pd.Timestamp(1970, 1, 1, 2, 10).value)
+        handler = []
+        bar_spec = BarSpecification(1, BarAggregation.HOUR, PriceType.MID)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+
+        # Act
+        aggregator = TimeBarAggregator(
+            AUDUSD_SIM,
+            bar_type,
+            handler.append,
+            clock,
+            origin=timedelta(hours=9, minutes=30),
+        )
+
+        # Assert
+        assert aggregator.next_close_ns == pd.Timestamp(1970, 1, 1, 2, 30).value
+
+    def test_instantiate_with_partial_interval_and_no_partial_handler_raises(self):
+        # Arrange
+        clock = TestClock()
+        handler = []
+        bar_spec = BarSpecification(1, BarAggregation.MINUTE, PriceType.MID)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+
+        # Act, Assert
+        with pytest.raises(TypeError):
+            TimeBarAggregator(
+                AUDUSD_SIM,
+                bar_type,
+                handler.append,
+                clock,
+                partial_interval=timedelta(seconds=10),
+            )
+
+    def test_partial_interval_emits_in_progress_bars_to_partial_handler(self):
+        # Arrange
+        clock = TestClock()
+        handler = []
+        partial_handler = []
+        bar_spec = BarSpecification(1, BarAggregation.MINUTE, PriceType.LAST)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+        aggregator = TimeBarAggregator(
+            AUDUSD_SIM,
+            bar_type,
+            handler.append,
+            clock,
+            partial_interval=timedelta(seconds=10),
+            partial_handler=partial_handler.append,
+        )
+
+        tick1 = TestDataStubs.trade_tick(
+            instrument=AUDUSD_SIM,
+            price=1.00001,
+            ts_event=1_000_000_000,
+        )
+        tick2 = TestDataStubs.trade_tick(
+            instrument=AUDUSD_SIM,
+            price=1.00005,
+            ts_event=15_000_000_000,
+        )
+
+        # Act
+        aggregator.handle_trade_tick(tick1)
+        for event in clock.advance_time(10_000_000_000):
+            event.handle()
+        aggregator.handle_trade_tick(tick2)
+        for event in clock.advance_time(20_000_000_000):
+            event.handle()
+
+        # Assert
+        assert handler == []
+        assert aggregator.partial_interval == timedelta(seconds=10)
+        assert len(partial_handler) == 2
+        assert partial_handler[0].close == Price.from_str("1.00001")
+        assert partial_handler[0].ts_event == 60_000_000_000
+        assert partial_handler[0].ts_init == 10_000_000_000
+        assert partial_handler[1].high == Price.from_str("1.00005")
+        assert partial_handler[1].ts_init == 20_000_000_000
+
+    def test_partial_bars_stamped_as_complete_bar_for_right_open_intervals(self):
+        # Arrange
+        clock = TestClock()
+        handler = []
+        partial_handler = []
+        bar_spec = BarSpecification(1, BarAggregation.MINUTE, PriceType.LAST)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+        aggregator = TimeBarAggregator(
+            AUDUSD_SIM,
+            bar_type,
+            handler.append,
+            clock,
+            timestamp_on_close=True,
+            interval_type="right-open",
+            partial_interval=timedelta(seconds=10),
+            partial_handler=partial_handler.append,
+        )
+
+        tick = TestDataStubs.trade_tick(
+            instrument=AUDUSD_SIM,
+            price=1.00001,
+            ts_event=1_000_000_000,
+        )
+
+        # Act
+        aggregator.handle_trade_tick(tick)
+        for event in clock.advance_time(60_000_000_000):
+            event.handle()
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].ts_event == 0
+        assert partial_handler
+        assert all(bar.ts_event == handler[0].ts_event for bar in partial_handler)
+
+    def test_stop_cancels_partial_bar_timer(self):
+        # Arrange
+        clock = TestClock()
+        bar_spec = BarSpecification(1, BarAggregation.MINUTE, PriceType.MID)
+        bar_type = BarType(AUDUSD_SIM.id, bar_spec)
+        aggregator = TimeBarAggregator(
+            AUDUSD_SIM,
+            bar_type,
+            [].append,
+            clock,
+            partial_interval=timedelta(seconds=10),
+            partial_handler=[].append,
+        )
+
+        # Act
+        aggregator.stop()
+
+        # Assert
+        assert clock.timer_names == []
+
     def test_update_timer_with_test_clock_sends_single_bar_to_handler(self):
         # Arrange
         clock = TestClock()
@@ -0,0 +1,167 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.data.config import QuoteConsolidatorConfig
+from nautilus_trader.data.consolidation import QuoteConsolidator
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+BINANCE = Venue("BINANCE")
+BYBIT = Venue("BYBIT")
+OKX = Venue("OKX")
+
+
+def _quote(venue: Venue, bid: str, ask: str, bid_size: str = "1", ask_size: str = "1", ts: int = 0):
+    return QuoteTick(
+        instrument_id=InstrumentId.from_str(f"BTCUSDT.{venue}"),
+        bid_price=Price.from_str(bid),
+        ask_price=Price.from_str(ask),
+        bid_size=Quantity.from_str(bid_size),
+        ask_size=Quantity.from_str(ask_size),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+class TestQuoteConsolidator:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.clock = TestClock()
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.quotes: list = []
+        self.consolidated: list = []
+
+    def _create_consolidator(self, **kwargs) -> QuoteConsolidator:
+        config = QuoteConsolidatorConfig(
+            instrument_ids=["BTCUSDT.BINANCE", "BTCUSDT.BYBIT", "BTCUSDT.OKX"],
+            **kwargs,
+        )
+        consolidator = QuoteConsolidator(config=config)
+        consolidator.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.msgbus.subscribe(topic="data.quotes.CBBO.BTCUSDT", handler=self.quotes.append)
+        self.msgbus.subscribe(
+            topic=f"data.{consolidator.data_type.topic}",
+            handler=self.consolidated.append,
+        )
+        return consolidator
+
+    def test_instantiate_with_duplicate_venue_raises(self) -> None:
+        # Arrange
+        config = QuoteConsolidatorConfig(instrument_ids=["BTCUSDT.BINANCE", "ETHUSDT.BINANCE"])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            QuoteConsolidator(config=config)
+
+    def test_consolidates_best_bid_and_ask_across_venues(self) -> None:
+        # Arrange
+        consolidator = self._create_consolidator()
+
+        # Act
+        consolidator.on_quote_tick(_quote(BINANCE, "100.00", "100.20", bid_size="2"))
+        consolidator.on_quote_tick(_quote(BYBIT, "100.05", "100.30", bid_size="3"))
+        consolidator.on_quote_tick(_quote(OKX, "99.90", "100.10", ask_size="4"))
+
+        # Assert
+        last = consolidator.last
+        assert consolidator.consolidated_id == InstrumentId.from_str("BTCUSDT.CBBO")
+        assert last.quote.bid_price == Price.from_str("100.05")
+        assert last.quote.ask_price == Price.from_str("100.10")
+        assert last.quote.bid_size == Quantity.from_str("3")
+        assert last.quote.ask_size == Quantity.from_str("4")
+        assert last.bid_venue == BYBIT
+        assert last.ask_venue == OKX
+        assert last.venue_count == 3
+        assert not last.is_crossed
+        assert self.quotes[-1] == last.quote
+        assert self.consolidated[-1] == last
+        assert self.cache.quote_tick(consolidator.consolidated_id) == last.quote
+
+    def test_venues_at_best_price_sum_sizes_and_attribute_largest(self) -> None:
+        # Arrange
+        consolidator = self._create_consolidator()
+
+        # Act
+        consolidator.on_quote_tick(_quote(BINANCE, "100.00", "100.20", bid_size="2"))
+        consolidator.on_quote_tick(_quote(BYBIT, "100.00", "100.30", bid_size="5"))
+
+        # Assert
+        assert consolidator.last.quote.bid_size == Quantity.from_str("7")
+        assert consolidator.last.bid_venue == BYBIT
+
+    def test_unchanged_consolidated_quote_is_not_republished(self) -> None:
+        # Arrange
+        consolidator = self._create_consolidator()
+        consolidator.on_quote_tick(_quote(BINANCE, "100.00", "100.20"))
+
+        # Act
+        consolidator.on_quote_tick(_quote(BYBIT, "99.00", "101.00"))  # Behind the best
+
+        # Assert
+        assert len(self.quotes) == 1
+        assert len(self.consolidated) == 1
+
+    def test_crossed_venues_are_flagged(self) -> None:
+        # Arrange
+        consolidator = self._create_consolidator()
+
+        # Act
+        consolidator.on_quote_tick(_quote(BINANCE, "100.00", "100.20"))
+        consolidator.on_quote_tick(_quote(BYBIT, "100.30", "100.40"))
+
+        # Assert
+        assert consolidator.last.is_crossed
+        assert consolidator.last.bid_venue == BYBIT
+        assert consolidator.last.ask_venue == BINANCE
+
+    def test_stale_venues_are_excluded(self) -> None:
+        # Arrange
+        consolidator = self._create_consolidator(stale_threshold_ms=1_000)
+        consolidator.on_quote_tick(_quote(BINANCE, "100.10", "100.20", ts=0))
+
+        # Act
+        self.clock.set_time(2_000_000_000)
+        consolidator.on_quote_tick(_quote(BYBIT, "100.00", "100.30", ts=2_000_000_000))
+
+        # Assert
+        assert consolidator.last.bid_venue == BYBIT
+        assert consolidator.last.quote.bid_price == Price.from_str("100.00")
+        assert consolidator.last.venue_count == 1
@@ -732,6 +732,50 @@ class TestDataEngine:
         assert handler1 == [ETHUSDT_BINANCE]
         assert handler2 == [ETHUSDT_BINANCE]
 
+    def test_process_instrument_with_new_version_publishes_instrument_change(self):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        handler = []
+        self.msgbus.subscribe(topic="data.instrument_changes.*", handler=handler.append)
+
+        updated = TestInstrumentProvider.instrument_version(
+            ETHUSDT_BINANCE,
+            ts_event=1_000,
+            price_precision=1,
+            price_increment="0.1",
+        )
+
+        # Act
+        self.data_engine.process(ETHUSDT_BINANCE)
+        self.data_engine.process(updated)
+
+        # Assert
+        assert handler == [updated]
+        assert self.cache.instrument(ETHUSDT_BINANCE.id) is updated
+        assert self.cache.instrument_at(ETHUSDT_BINANCE.id, 999) is ETHUSDT_BINANCE
+
+    def test_process_instrument_with_unchanged_specification_does_not_publish_instrument_change(
+        self,
+    ):
+        # Arrange
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+
+        handler = []
+        self.msgbus.subscribe(topic="data.instrument_changes.*", handler=handler.append)
+
+        reloaded = TestInstrumentProvider.instrument_version(ETHUSDT_BINANCE, ts_event=1_000)
+
+        # Act
+        self.data_engine.process(ETHUSDT_BINANCE)
+        self.data_engine.process(reloaded)
+
+        # Assert
+        assert handler == []
+        assert self.cache.instrument(ETHUSDT_BINANCE.id) is ETHUSDT_BINANCE
+
     def test_execute_subscribe_order_book_snapshots_then_adds_handler(self):
         # Arrange
         self.data_engine.register_client(self.binance_client)
@@ -1326,6 +1370,76 @@ class TestDataEngine:
         # Assert
         assert handler == [tick]
 
+    def test_process_quote_tick_with_precision_normalize_mode_rounds_to_instrument_precision(self):
+        # Arrange
+        msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+        data_engine = DataEngine(
+            msgbus=msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=DataEngineConfig(venue_precision_modes={"BINANCE": "NORMALIZE"}),
+        )
+        data_engine.process(ETHUSDT_BINANCE)
+
+        handler = []
+        msgbus.subscribe(topic="data.quotes.BINANCE.ETHUSDT", handler=handler.append)
+
+        tick = QuoteTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            bid_price=Price.from_str("100.004"),
+            ask_price=Price.from_str("100.01"),
+            bid_size=Quantity.from_str("1.00000"),
+            ask_size=Quantity.from_str("1.00000"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        data_engine.process(tick)
+
+        # Assert
+        assert len(handler) == 1
+        assert handler[0].bid_price == Price.from_str("100.00")
+        assert handler[0].bid_price.precision == ETHUSDT_BINANCE.price_precision
+        assert data_engine.precision_truncated_counts() == {ETHUSDT_BINANCE.id: 1}
+
+    def test_process_quote_tick_with_precision_strict_mode_drops_tick(self):
+        # Arrange
+        msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+        data_engine = DataEngine(
+            msgbus=msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=DataEngineConfig(venue_precision_modes={"BINANCE": "STRICT"}),
+        )
+        data_engine.process(ETHUSDT_BINANCE)
+
+        handler = []
+        msgbus.subscribe(topic="data.quotes.BINANCE.ETHUSDT", handler=handler.append)
+
+        tick = QuoteTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            bid_price=Price.from_str("100.004"),
+            ask_price=Price.from_str("100.01"),
+            bid_size=Quantity.from_str("1.00000"),
+            ask_size=Quantity.from_str("1.00000"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        data_engine.process(tick)
+
+        # Assert
+        assert handler == []
+        assert data_engine.precision_rejected_counts() == {ETHUSDT_BINANCE.id: 1}
+
     def test_process_quote_tick_when_subscribers_then_sends_to_registered_handlers(
         self,
     ):
@@ -2359,3 +2473,146 @@ class TestDataEngine:
     #     assert len(handler[0].data) == 21
     #     assert handler[0].data[0].ts_init == 1637971200000000000
     #     assert handler[0].data[-1].ts_init == 1638058200000000000
+
+
+class TestDataEngineBarsBackfill:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+        )
+        self.cache = TestComponentStubs.cache()
+
+        config = DataEngineConfig(
+            bars_backfill_count=2,
+            bars_backfill_timeout_ms=5_000,
+        )
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=config,
+        )
+
+        self.binance_client = BacktestMarketDataClient(
+            client_id=ClientId(BINANCE.value),
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.data_engine.register_client(self.binance_client)
+        self.binance_client.start()
+        self.data_engine.process(ETHUSDT_BINANCE)
+
+        bar_spec = BarSpecification(1000, BarAggregation.TICK, PriceType.MID)
+        self.bar_type = BarType(ETHUSDT_BINANCE.id, bar_spec)
+
+        self.handler = []
+        self.msgbus.subscribe(topic=f"data.bars.{self.bar_type}", handler=self.handler.append)
+
+    def _bar(self, ts: int) -> Bar:
+        return Bar(
+            self.bar_type,
+            Price.from_str("1051.00000"),
+            Price.from_str("1055.00000"),
+            Price.from_str("1050.00000"),
+            Price.from_str("1052.00000"),
+            Quantity.from_int(100),
+            ts,
+            ts,
+        )
+
+    def _subscribe(self) -> None:
+        subscribe = Subscribe(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": self.bar_type}),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+        self.data_engine.execute(subscribe)
+
+    def test_subscribe_bars_requests_backfill_and_buffers_live_bars(self):
+        # Arrange
+        self._subscribe()
+
+        # Act
+        self.data_engine.process(self._bar(3))
+
+        # Assert
+        assert self.data_engine.request_count == 1
+        assert len(self.data_engine._backfill_requests) == 1
+        assert self.handler == []
+
+    def test_backfill_response_delivers_history_before_live_bars_with_dedup(self):
+        # Arrange
+        self._subscribe()
+        request_id = next(iter(self.data_engine._backfill_requests))
+        live_duplicate = self._bar(2)
+        live_bar = self._bar(3)
+        self.data_engine.process(live_duplicate)
+        self.data_engine.process(live_bar)
+
+        history = [self._bar(1), self._bar(2)]
+        response = DataResponse(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": self.bar_type}),
+            data=history,
+            correlation_id=request_id,
+            response_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.data_engine.response(response)
+        next_bar = self._bar(4)
+        self.data_engine.process(next_bar)
+
+        # Assert
+        assert self.handler == [history[0], history[1], live_bar, next_bar]
+        assert self.cache.bar(self.bar_type) == next_bar
+        assert self.cache.bar_count(self.bar_type) == 4
+        assert self.data_engine._backfill_pending == {}
+        assert "BarBackfill|" + str(self.bar_type) not in self.clock.timer_names
+
+    def test_backfill_response_out_of_order_is_delivered_sorted(self):
+        # Arrange
+        self._subscribe()
+        request_id = next(iter(self.data_engine._backfill_requests))
+        live_bar = self._bar(3)
+        self.data_engine.process(live_bar)
+
+        history = [self._bar(2), self._bar(1)]
+        response = DataResponse(
+            client_id=ClientId(BINANCE.value),
+            venue=BINANCE,
+            data_type=DataType(Bar, metadata={"bar_type": self.bar_type}),
+            data=history,
+            correlation_id=request_id,
+            response_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.data_engine.response(response)
+
+        # Assert
+        assert self.handler == [history[1], history[0], live_bar]
+
+    def test_backfill_timeout_releases_buffered_live_bars(self):
+        # Arrange
+        self._subscribe()
+        live_bar = self._bar(3)
+        self.data_engine.process(live_bar)
+
+        # Act
+        events = self.clock.advance_time(5_000_000_000)
+        events[0].handle()
+
+        # Assert
+        assert self.handler == [live_bar]
+        assert self.data_engine._backfill_requests == {}
+        assert self.data_engine._backfill_pending == {}
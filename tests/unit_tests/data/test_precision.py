@@ -0,0 +1,206 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.data.precision import PrecisionMode
+from nautilus_trader.data.precision import PrecisionNormalizer
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+ETHUSDT_BINANCE = TestInstrumentProvider.ethusdt_binance()
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+XBTUSD_BITMEX = TestInstrumentProvider.xbtusd_bitmex()
+
+
+def _quote(bid: str, ask: str, size: str) -> QuoteTick:
+    return QuoteTick(
+        instrument_id=ETHUSDT_BINANCE.id,
+        bid_price=Price.from_str(bid),
+        ask_price=Price.from_str(ask),
+        bid_size=Quantity.from_str(size),
+        ask_size=Quantity.from_str(size),
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+class TestPrecisionNormalizer:
+    def test_from_config_with_invalid_mode_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            PrecisionNormalizer.from_config({"BINANCE": "ROUND_DOWN"})
+
+    def test_from_config_parses_modes_case_insensitively(self):
+        # Arrange, Act
+        normalizer = PrecisionNormalizer.from_config({"BINANCE": "normalize"})
+
+        # Assert
+        assert normalizer.mode(Venue("BINANCE")) == PrecisionMode.NORMALIZE
+        assert normalizer.mode(Venue("SIM")) is None
+
+    def test_normalize_when_venue_not_configured_returns_data_unchanged(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.NORMALIZE})
+        tick = TestDataStubs.quote_tick(instrument=AUDUSD_SIM)
+
+        # Act
+        result = normalizer.normalize(tick, AUDUSD_SIM)
+
+        # Assert
+        assert result is tick
+
+    def test_normalize_when_precisions_match_returns_data_unchanged(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.NORMALIZE})
+        tick = _quote("100.10", "100.20", "1.00000")
+
+        # Act
+        result = normalizer.normalize(tick, ETHUSDT_BINANCE)
+
+        # Assert
+        assert result is tick
+        assert normalizer.truncated_counts() == {}
+
+    def test_normalize_lower_precision_is_lossless(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.STRICT})
+        tick = _quote("100.1", "100.2", "1")
+
+        # Act
+        result = normalizer.normalize(tick, ETHUSDT_BINANCE)
+
+        # Assert
+        assert result.bid_price == Price.from_str("100.10")
+        assert result.bid_price.precision == 2
+        assert result.bid_size.precision == 5
+        assert normalizer.truncated_counts() == {}
+        assert normalizer.rejected_counts() == {}
+
+    def test_normalize_greater_precision_truncates_and_counts(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.NORMALIZE})
+        tick = _quote("100.101", "100.209", "1.000001")
+
+        # Act
+        result1 = normalizer.normalize(tick, ETHUSDT_BINANCE)
+        result2 = normalizer.normalize(tick, ETHUSDT_BINANCE)
+
+        # Assert
+        assert result1.bid_price == Price.from_str("100.10")
+        assert result1.ask_price == Price.from_str("100.21")
+        assert result1.bid_size == Quantity.from_str("1.00000")
+        assert result2 == result1
+        assert normalizer.truncated_counts() == {ETHUSDT_BINANCE.id: 2}
+
+    def test_normalize_greater_precision_when_strict_rejects(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.STRICT})
+        trade = TradeTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            price=Price.from_str("100.101"),
+            size=Quantity.from_str("1.00000"),
+            aggressor_side=AggressorSide.BUYER,
+            trade_id=TradeId("1"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        result = normalizer.normalize(trade, ETHUSDT_BINANCE)
+
+        # Assert
+        assert result is None
+        assert normalizer.rejected_counts() == {ETHUSDT_BINANCE.id: 1}
+
+    def test_normalize_order_book_deltas(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.NORMALIZE})
+        clear = OrderBookDelta.clear(ETHUSDT_BINANCE.id, 0, 0, 1)
+        add = OrderBookDelta(
+            instrument_id=ETHUSDT_BINANCE.id,
+            action=BookAction.ADD,
+            order=BookOrder(
+                side=OrderSide.BUY,
+                price=Price.from_str("100.1"),
+                size=Quantity.from_str("2.5"),
+                order_id=0,
+            ),
+            ts_event=0,
+            ts_init=0,
+            sequence=2,
+        )
+        deltas = OrderBookDeltas(ETHUSDT_BINANCE.id, [clear, add])
+
+        # Act
+        result = normalizer.normalize(deltas, ETHUSDT_BINANCE)
+
+        # Assert
+        assert result is not deltas
+        assert result.deltas[0] is clear
+        assert result.deltas[1].order.price.precision == 2
+        assert result.deltas[1].order.size.precision == 5
+        assert result.deltas[1].sequence == 2
+
+    @pytest.mark.parametrize(
+        ("price", "expected"),
+        [
+            ["100.10", None],
+            ["100.1", None],
+            ["100.105", "PRICE_PRECISION_EXCEEDS_VENUE: price 100.105 precision 3 > 2"],
+        ],
+    )
+    def test_check_price(self, price, expected):
+        # Arrange
+        normalizer = PrecisionNormalizer({Venue("BINANCE"): PrecisionMode.STRICT})
+
+        # Act
+        result = normalizer.check_price(ETHUSDT_BINANCE, Price.from_str(price))
+
+        # Assert
+        assert result == expected
+
+    def test_check_price_not_on_increment(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({XBTUSD_BITMEX.id.venue: PrecisionMode.STRICT})
+
+        # Act
+        result = normalizer.check_price(XBTUSD_BITMEX, Price.from_str("10000.3"))
+
+        # Assert
+        assert result == "PRICE_NOT_ON_INCREMENT: price 10000.3 not a multiple of 0.5"
+
+    def test_check_quantity_exceeding_precision(self):
+        # Arrange
+        normalizer = PrecisionNormalizer({XBTUSD_BITMEX.id.venue: PrecisionMode.STRICT})
+
+        # Act
+        result = normalizer.check_quantity(XBTUSD_BITMEX, Quantity.from_str("100.5"))
+
+        # Assert
+        assert result == "QUANTITY_PRECISION_EXCEEDS_VENUE: quantity 100.5 precision 1 > 0"
@@ -25,6 +25,7 @@ from nautilus_trader.config import StrategyConfig
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.latency import LatencyStage
 from nautilus_trader.execution.messages import CancelOrder
 from nautilus_trader.execution.messages import ModifyOrder
 from nautilus_trader.execution.messages import SubmitOrder
@@ -35,6 +36,7 @@ from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import LiquiditySide
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import PositionSide
@@ -42,6 +44,7 @@ from nautilus_trader.model.enums import TriggerType
 from nautilus_trader.model.events import OrderCanceled
 from nautilus_trader.model.events import OrderDenied
 from nautilus_trader.model.events import OrderUpdated
+from nautilus_trader.model.events import PositionOpened
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import InstrumentId
@@ -60,6 +63,7 @@ from nautilus_trader.risk.engine import RiskEngine
 from nautilus_trader.test_kit.mocks.cache_database import MockCacheDatabase
 from nautilus_trader.test_kit.mocks.exec_clients import MockExecutionClient
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
 from nautilus_trader.test_kit.stubs.events import TestEventStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
 from nautilus_trader.trading.strategy import Strategy
@@ -1166,6 +1170,55 @@ class TestExecutionEngine:
         assert self.cache.positions_open_count() == 1
         assert self.cache.positions_closed_count() == 0
 
+    def test_handle_order_fill_event_propagates_order_tags(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            tags="signal-a",
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order)
+
+        position_events = []
+        self.msgbus.subscribe(topic="events.position*", handler=position_events.append)
+
+        fill = TestEventStubs.order_filled(order, AUDUSD_SIM)
+
+        # Act
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+        self.exec_engine.process(fill)
+
+        # Assert
+        assert fill.tags == "signal-a"
+        assert self.cache.order(order.client_order_id).last_event.tags == "signal-a"
+        assert self.cache.orders_for_tag("signal-a") == [order]
+        assert len(position_events) == 1
+        assert isinstance(position_events[0], PositionOpened)
+        assert position_events[0].tags == "signal-a"
+
     def test_handle_multiple_partial_fill_events(self) -> None:
         # Arrange
         self.exec_engine.start()
@@ -2276,3 +2329,306 @@ class TestExecutionEngine:
         assert not bracket.orders[0].is_quote_quantity
         assert not bracket.orders[1].is_quote_quantity
         assert not bracket.orders[2].is_quote_quantity
+
+    def _submit_and_accept(self, order) -> None:
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy_id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+        self.risk_engine.execute(submit_order)
+        self.exec_engine.process(TestEventStubs.order_submitted(order))
+        self.exec_engine.process(TestEventStubs.order_accepted(order))
+
+    def test_fill_without_liquidity_side_for_market_order_infers_taker(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ),
+        )
+
+        # Assert
+        assert order.liquidity_side == LiquiditySide.TAKER
+
+    def test_fill_without_liquidity_side_for_post_only_order_infers_maker(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+            post_only=True,
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                last_px=Price.from_str("0.80000"),
+                liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ),
+        )
+
+        # Assert
+        assert order.liquidity_side == LiquiditySide.MAKER
+
+    @pytest.mark.parametrize(
+        ("side", "price", "last_px", "expected"),
+        [
+            [OrderSide.BUY, "0.80000", "0.80000", LiquiditySide.MAKER],  # At best bid
+            [OrderSide.BUY, "0.80010", "0.80010", LiquiditySide.TAKER],  # At best ask
+            [OrderSide.BUY, "0.80020", "0.80010", LiquiditySide.TAKER],  # Price improvement
+            [OrderSide.SELL, "0.80010", "0.80010", LiquiditySide.MAKER],  # At best ask
+            [OrderSide.SELL, "0.80000", "0.80000", LiquiditySide.TAKER],  # At best bid
+            [OrderSide.BUY, "0.80005", "0.80005", LiquiditySide.NO_LIQUIDITY_SIDE],  # In spread
+        ],
+    )
+    def test_fill_without_liquidity_side_for_limit_order_infers_from_quote(
+        self,
+        side: OrderSide,
+        price: str,
+        last_px: str,
+        expected: LiquiditySide,
+    ) -> None:
+        # Arrange
+        self.exec_engine.start()
+        self.cache.add_quote_tick(
+            TestDataStubs.quote_tick(AUDUSD_SIM, bid_price=0.80000, ask_price=0.80010),
+        )
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            side,
+            Quantity.from_int(100_000),
+            Price.from_str(price),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                last_px=Price.from_str(last_px),
+                liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ),
+        )
+
+        # Assert
+        assert order.liquidity_side == expected
+
+    def test_fill_without_liquidity_side_and_no_book_state_is_not_inferred(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.80000"),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                last_px=Price.from_str("0.80000"),
+                liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ),
+        )
+
+        # Assert
+        assert order.liquidity_side == LiquiditySide.NO_LIQUIDITY_SIDE
+
+    def test_fill_with_reported_liquidity_side_is_not_overridden(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                liquidity_side=LiquiditySide.MAKER,
+            ),
+        )
+
+        # Assert
+        assert order.liquidity_side == LiquiditySide.MAKER
+
+    def test_fill_without_liquidity_side_when_inference_disabled(self) -> None:
+        # Arrange
+        self.msgbus.deregister("ExecEngine.execute", self.exec_engine.execute)
+        self.msgbus.deregister("ExecEngine.process", self.exec_engine.process)
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=ExecEngineConfig(infer_liquidity_side=False),
+        )
+        self.exec_engine.register_client(self.exec_client)
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ),
+        )
+
+        # Assert
+        assert not self.exec_engine.infer_liquidity_side
+        assert order.liquidity_side == LiquiditySide.NO_LIQUIDITY_SIDE
+
+    def test_latency_histograms_when_tracking_enabled(self) -> None:
+        # Arrange
+        self.msgbus.deregister("ExecEngine.execute", self.exec_engine.execute)
+        self.msgbus.deregister("ExecEngine.process", self.exec_engine.process)
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=ExecEngineConfig(latency_tracking=True),
+        )
+        self.exec_engine.register_client(self.exec_client)
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+
+        # Act
+        self.exec_engine.process(TestEventStubs.order_filled(order=order, instrument=AUDUSD_SIM))
+
+        # Assert
+        histograms = self.exec_engine.latency_histograms(venue=AUDUSD_SIM.id.venue)
+        assert set(histograms) == set(LatencyStage)
+        assert all(histogram.count == 1 for histogram in histograms.values())
+        assert list(self.exec_engine.export_latency_histograms()) == [f"SIM:{self.strategy_id}"]
+
+    def test_latency_histograms_when_tracking_disabled(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        # Act
+        self._submit_and_accept(order)
+
+        # Assert
+        assert self.exec_engine.latency_histograms() == {}
+        assert self.exec_engine.export_latency_histograms() == {}
+
+    @pytest.mark.parametrize(
+        ("trade_id", "duplicate_trade_id"),
+        [
+            ["E-1", "E-1"],
+            ["000123", "123"],  # Normalized numeric trade IDs
+            ["123", " 123 "],
+        ],
+    )
+    def test_duplicate_fill_is_not_applied(self, trade_id: str, duplicate_trade_id: str) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                trade_id=TradeId(trade_id),
+                last_qty=Quantity.from_int(50_000),
+            ),
+        )
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                trade_id=TradeId(duplicate_trade_id),
+                last_qty=Quantity.from_int(50_000),
+            ),
+        )
+
+        # Assert
+        position = self.cache.positions_open()[0]
+        assert self.exec_engine.duplicate_fill_count == 1
+        assert order.status == OrderStatus.PARTIALLY_FILLED
+        assert order.filled_qty == Quantity.from_int(50_000)
+        assert position.quantity == Quantity.from_int(50_000)
+
+    def test_fill_with_new_trade_id_is_applied(self) -> None:
+        # Arrange
+        self.exec_engine.start()
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+        self._submit_and_accept(order)
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                trade_id=TradeId("123"),
+                last_qty=Quantity.from_int(50_000),
+            ),
+        )
+
+        # Act
+        self.exec_engine.process(
+            TestEventStubs.order_filled(
+                order=order,
+                instrument=AUDUSD_SIM,
+                trade_id=TradeId("1230"),
+                last_qty=Quantity.from_int(50_000),
+            ),
+        )
+
+        # Assert
+        position = self.cache.positions_open()[0]
+        assert self.exec_engine.duplicate_fill_count == 0
+        assert order.status == OrderStatus.FILLED
+        assert position.quantity == Quantity.from_int(100_000)
@@ -0,0 +1,228 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.latency import LatencyHistogram
+from nautilus_trader.execution.latency import LatencyStage
+from nautilus_trader.execution.latency import LatencyTracker
+from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.model.events import OrderAccepted
+from nautilus_trader.model.events import OrderSubmitted
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class TestLatencyHistogram:
+    def test_empty_histogram(self):
+        # Arrange
+        histogram = LatencyHistogram()
+
+        # Act, Assert
+        assert histogram.count == 0
+        assert histogram.min_ns is None
+        assert histogram.max_ns is None
+        assert histogram.mean_ns is None
+        assert histogram.percentile(50) is None
+        assert histogram.buckets() == {}
+
+    def test_record_latencies(self):
+        # Arrange
+        histogram = LatencyHistogram()
+
+        # Act
+        histogram.record(1_500)
+        histogram.record(1_800)
+        histogram.record(40_000)
+        histogram.record(-100)  # Clock skew
+
+        # Assert
+        assert histogram.count == 4
+        assert histogram.min_ns == 0
+        assert histogram.max_ns == 40_000
+        assert histogram.mean_ns == 10_825
+        assert histogram.buckets() == {1_000: 1, 2_000: 2, 50_000: 1}
+
+    @pytest.mark.parametrize(
+        ("q", "expected"),
+        [
+            [0, 2_000],
+            [50, 2_000],
+            [75, 2_000],
+            [90, 40_000],
+            [100, 40_000],
+        ],
+    )
+    def test_percentile(self, q, expected):
+        # Arrange
+        histogram = LatencyHistogram()
+        for latency_ns in (1_500, 1_800, 1_900, 40_000):
+            histogram.record(latency_ns)
+
+        # Act, Assert
+        assert histogram.percentile(q) == expected
+
+    def test_percentile_with_invalid_q_raises(self):
+        # Arrange
+        histogram = LatencyHistogram()
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            histogram.percentile(101)
+
+    def test_overflow_bucket(self):
+        # Arrange
+        histogram = LatencyHistogram()
+
+        # Act
+        histogram.record(30_000_000_000)
+
+        # Assert
+        assert histogram.buckets() == {None: 1}
+        assert histogram.percentile(99) == 30_000_000_000
+        assert histogram.to_dict()["buckets"] == {"overflow": 1}
+
+
+class TestLatencyTracker:
+    def setup(self):
+        # Fixture Setup
+        self.tracker = LatencyTracker()
+
+    def _submit(self, order, ts_init: int, ts_received: int) -> None:
+        command = SubmitOrder(
+            trader_id=TestIdStubs.trader_id(),
+            strategy_id=order.strategy_id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=ts_init,
+        )
+        self.tracker.on_command(command, ts_received)
+
+    def _submitted(self, order, ts_event: int) -> OrderSubmitted:
+        return OrderSubmitted(
+            trader_id=order.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            account_id=TestIdStubs.account_id(),
+            ts_event=ts_event,
+            event_id=UUID4(),
+            ts_init=ts_event,
+        )
+
+    def _accepted(self, order, ts_event: int) -> OrderAccepted:
+        return OrderAccepted(
+            trader_id=order.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=TestIdStubs.venue_order_id(),
+            account_id=TestIdStubs.account_id(),
+            ts_event=ts_event,
+            event_id=UUID4(),
+            ts_init=ts_event,
+        )
+
+    def test_order_lifecycle_records_each_stage(self):
+        # Arrange
+        order = TestExecStubs.limit_order(instrument_id=AUDUSD_SIM.id)
+
+        # Act
+        self._submit(order, ts_init=1_000, ts_received=3_000)
+        self.tracker.on_event(self._submitted(order, ts_event=8_000))
+        self.tracker.on_event(self._accepted(order, ts_event=1_008_000))
+        self.tracker.on_event(
+            TestEventStubs.order_filled(order, instrument=AUDUSD_SIM, ts_filled_ns=51_008_000),
+        )
+
+        # Assert
+        histograms = self.tracker.histograms()
+        assert histograms[LatencyStage.ENGINE_RECEIPT].max_ns == 2_000
+        assert histograms[LatencyStage.CLIENT_SEND].max_ns == 5_000
+        assert histograms[LatencyStage.VENUE_ACK].max_ns == 1_000_000
+        assert histograms[LatencyStage.FILL].max_ns == 50_000_000
+        assert self.tracker.tracked_order_count == 0
+
+    def test_fill_without_acceptance_records_venue_ack_to_fill(self):
+        # Arrange
+        order = TestExecStubs.limit_order(instrument_id=AUDUSD_SIM.id)
+        self._submit(order, ts_init=0, ts_received=0)
+        self.tracker.on_event(self._submitted(order, ts_event=1_000))
+
+        # Act
+        self.tracker.on_event(
+            TestEventStubs.order_filled(order, instrument=AUDUSD_SIM, ts_filled_ns=5_000),
+        )
+
+        # Assert
+        histograms = self.tracker.histograms()
+        assert histograms[LatencyStage.VENUE_ACK].max_ns == 4_000
+        assert LatencyStage.FILL not in histograms
+
+    def test_rejected_order_is_no_longer_tracked(self):
+        # Arrange
+        order = TestExecStubs.limit_order(instrument_id=AUDUSD_SIM.id)
+        self._submit(order, ts_init=0, ts_received=0)
+
+        # Act
+        self.tracker.on_event(TestEventStubs.order_rejected(order))
+
+        # Assert
+        assert self.tracker.tracked_order_count == 0
+
+    def test_histograms_filtered_by_venue_and_strategy(self):
+        # Arrange
+        order1 = TestExecStubs.limit_order(
+            instrument_id=AUDUSD_SIM.id,
+            strategy_id=StrategyId("S-001"),
+            client_order_id=TestIdStubs.client_order_id(1),
+        )
+        order2 = TestExecStubs.limit_order(
+            instrument_id=AUDUSD_SIM.id,
+            strategy_id=StrategyId("S-002"),
+            client_order_id=TestIdStubs.client_order_id(2),
+        )
+
+        # Act
+        self._submit(order1, ts_init=0, ts_received=1_000)
+        self._submit(order2, ts_init=0, ts_received=3_000)
+
+        # Assert
+        assert self.tracker.histograms()[LatencyStage.ENGINE_RECEIPT].count == 2
+        assert self.tracker.histograms(strategy_id=StrategyId("S-001"))[LatencyStage.ENGINE_RECEIPT].max_ns == 1_000
+        assert self.tracker.histograms(venue=Venue("SIM"))[LatencyStage.ENGINE_RECEIPT].mean_ns == 2_000
+        assert self.tracker.histograms(venue=Venue("BINANCE")) == {}
+        assert list(self.tracker.to_dict()) == ["SIM:S-001", "SIM:S-002"]
+
+    def test_reset(self):
+        # Arrange
+        order = TestExecStubs.limit_order(instrument_id=AUDUSD_SIM.id)
+        self._submit(order, ts_init=0, ts_received=1_000)
+
+        # Act
+        self.tracker.reset()
+
+        # Assert
+        assert self.tracker.tracked_order_count == 0
+        assert self.tracker.histograms() == {}
@@ -0,0 +1,408 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.backtest.exchange import SimulatedExchange
+from nautilus_trader.backtest.execution_client import BacktestExecClient
+from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.models import LatencyModel
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.config import DataEngineConfig
+from nautilus_trader.config import ExecEngineConfig
+from nautilus_trader.config import RiskEngineConfig
+from nautilus_trader.config import SmartOrderRouterConfig
+from nautilus_trader.config import StrategyConfig
+from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.router import SmartOrderRouter
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import ExecAlgorithmId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+from nautilus_trader.trading.strategy import Strategy
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD", Venue("SIM"))
+AUDUSD_ALT = TestInstrumentProvider.default_fx_ccy("AUD/USD", Venue("ALT"))
+
+
+class TestSmartOrderRouter:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.clock = TestClock()
+        self.trader_id = TestIdStubs.trader_id()
+
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+        )
+
+        self.cache = Cache()
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.cache.add_instrument(AUDUSD_ALT)
+
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=DataEngineConfig(debug=True),
+        )
+
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=ExecEngineConfig(debug=True),
+        )
+
+        self.risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=RiskEngineConfig(debug=True),
+        )
+
+        self.exchanges: dict[Venue, SimulatedExchange] = {}
+        for instrument in (AUDUSD_SIM, AUDUSD_ALT):
+            exchange = SimulatedExchange(
+                venue=instrument.id.venue,
+                oms_type=OmsType.NETTING,
+                account_type=AccountType.MARGIN,
+                base_currency=USD,
+                starting_balances=[Money(1_000_000, USD)],
+                default_leverage=Decimal(50),
+                leverages={},
+                instruments=[instrument],
+                modules=[],
+                fill_model=FillModel(),
+                portfolio=self.portfolio,
+                msgbus=self.msgbus,
+                cache=self.cache,
+                clock=self.clock,
+                latency_model=LatencyModel(0),
+            )
+            exec_client = BacktestExecClient(
+                exchange=exchange,
+                msgbus=self.msgbus,
+                cache=self.cache,
+                clock=self.clock,
+            )
+
+            # Wire up components
+            self.exec_engine.register_client(exec_client)
+            exchange.register_client(exec_client)
+            exchange.reset()
+            self.exchanges[instrument.id.venue] = exchange
+
+        self.strategy = Strategy(StrategyConfig())
+        self.strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.data_engine.start()
+        self.risk_engine.start()
+        self.exec_engine.start()
+        self.strategy.start()
+
+    def create_router(self, **kwargs) -> SmartOrderRouter:
+        config = SmartOrderRouterConfig(
+            instrument_ids=[AUDUSD_SIM.id.value, AUDUSD_ALT.id.value],
+            fee_rates=kwargs.pop("fee_rates", {"SIM": 0.0, "ALT": 0.0}),
+            **kwargs,
+        )
+        router = SmartOrderRouter(config)
+        router.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        router.start()
+        return router
+
+    def process_quotes(self) -> None:
+        sim_quote = TestDataStubs.quote_tick(
+            instrument=AUDUSD_SIM,
+            bid_price=1.00000,
+            ask_price=1.00010,
+            bid_size=100_000,
+            ask_size=100_000,
+        )
+        alt_quote = TestDataStubs.quote_tick(
+            instrument=AUDUSD_ALT,
+            bid_price=0.99995,
+            ask_price=1.00005,
+            bid_size=50_000,
+            ask_size=50_000,
+        )
+        for quote in (sim_quote, alt_quote):
+            self.data_engine.process(quote)
+            self.exchanges[quote.instrument_id.venue].process_quote_tick(quote)
+
+    def process_exchanges(self) -> None:
+        for exchange in self.exchanges.values():
+            exchange.process(0)
+
+    def test_instantiate_with_no_instrument_ids_raises_value_error(self) -> None:
+        # Arrange
+        config = SmartOrderRouterConfig(instrument_ids=[])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            SmartOrderRouter(config)
+
+    def test_default_exec_algorithm_id(self) -> None:
+        # Arrange, Act
+        router = self.create_router()
+
+        # Assert
+        assert router.id == ExecAlgorithmId("SOR")
+
+    def test_fee_rate_defaults_to_instrument_taker_fee(self) -> None:
+        # Arrange
+        router = self.create_router(fee_rates={"SIM": 0.0001})
+
+        # Act, Assert
+        assert router.fee_rate(AUDUSD_SIM) == Decimal("0.0001")
+        assert router.fee_rate(AUDUSD_ALT) == AUDUSD_ALT.taker_fee
+
+    def test_allocate_with_no_liquidity_returns_empty(self) -> None:
+        # Arrange
+        router = self.create_router()
+
+        # Act
+        allocations = router.allocate(OrderSide.BUY, Quantity.from_int(100_000))
+
+        # Assert
+        assert allocations == {}
+
+    def test_allocate_buy_takes_best_offer_first(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        # Act
+        allocations = router.allocate(OrderSide.BUY, Quantity.from_int(120_000))
+
+        # Assert
+        assert list(allocations.items()) == [
+            (AUDUSD_ALT.id, Quantity.from_int(50_000)),
+            (AUDUSD_SIM.id, Quantity.from_int(70_000)),
+        ]
+
+    def test_allocate_sell_takes_best_bid_first(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        # Act
+        allocations = router.allocate(OrderSide.SELL, Quantity.from_int(120_000))
+
+        # Assert
+        assert list(allocations.items()) == [
+            (AUDUSD_SIM.id, Quantity.from_int(100_000)),
+            (AUDUSD_ALT.id, Quantity.from_int(20_000)),
+        ]
+
+    def test_allocate_with_fees_prefers_lower_effective_price(self) -> None:
+        # Arrange
+        router = self.create_router(fee_rates={"SIM": 0.0, "ALT": 0.001})
+        self.process_quotes()
+
+        # Act
+        allocations = router.allocate(OrderSide.BUY, Quantity.from_int(120_000))
+
+        # Assert
+        assert list(allocations.items()) == [
+            (AUDUSD_SIM.id, Quantity.from_int(100_000)),
+            (AUDUSD_ALT.id, Quantity.from_int(20_000)),
+        ]
+
+    def test_allocate_quantity_exceeding_liquidity_allocates_excess_to_best_venue(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        # Act
+        allocations = router.allocate(OrderSide.BUY, Quantity.from_int(200_000))
+
+        # Assert
+        assert allocations == {
+            AUDUSD_ALT.id: Quantity.from_int(100_000),
+            AUDUSD_SIM.id: Quantity.from_int(100_000),
+        }
+
+    def test_allocate_with_limit_price_excludes_liquidity_through_limit(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        # Act
+        allocations = router.allocate(
+            OrderSide.BUY,
+            Quantity.from_int(120_000),
+            limit_price=Price.from_str("1.00005"),
+        )
+
+        # Assert
+        assert allocations == {AUDUSD_ALT.id: Quantity.from_int(120_000)}
+
+    def test_allocate_from_order_books_walks_levels(self) -> None:
+        # Arrange
+        router = self.create_router(use_order_books=True)
+        self.cache.add_order_book(
+            TestDataStubs.order_book(instrument_id=AUDUSD_SIM.id, ask_price=15.0),
+        )
+        self.cache.add_order_book(
+            TestDataStubs.order_book(instrument_id=AUDUSD_ALT.id, ask_price=14.5),
+        )
+
+        # Act
+        allocations = router.allocate(OrderSide.BUY, Quantity.from_int(45))
+
+        # Assert
+        assert allocations == {
+            AUDUSD_ALT.id: Quantity.from_int(30),
+            AUDUSD_SIM.id: Quantity.from_int(15),
+        }
+
+    def test_route_market_order_splits_across_venues_and_aggregates_fills(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        order = self.strategy.order_factory.market(
+            instrument_id=AUDUSD_SIM.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(120_000),
+            exec_algorithm_id=router.id,
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+        self.process_exchanges()
+
+        # Assert
+        spawned = self.cache.order(ClientOrderId(f"{order.client_order_id.value}-E1"))
+        assert spawned.instrument_id == AUDUSD_ALT.id
+        assert spawned.quantity == Quantity.from_int(50_000)
+        assert spawned.status == OrderStatus.FILLED
+        assert order.quantity == Quantity.from_int(70_000)
+        assert order.status == OrderStatus.FILLED
+        assert router.filled_qty(order.client_order_id) == Quantity.from_int(120_000)
+        assert router.venue_fills(order.client_order_id) == {
+            Venue("SIM"): Decimal(70_000),
+            Venue("ALT"): Decimal(50_000),
+        }
+        assert router.avg_px(order.client_order_id) == (
+            Decimal("1.00010") * 70_000 + Decimal("1.00005") * 50_000
+        ) / 120_000
+
+    def test_route_order_with_no_primary_venue_allocation_cancels_primary(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        order = self.strategy.order_factory.market(
+            instrument_id=AUDUSD_SIM.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(30_000),
+            exec_algorithm_id=router.id,
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+        self.process_exchanges()
+
+        # Assert
+        spawned = self.cache.order(ClientOrderId(f"{order.client_order_id.value}-E1"))
+        assert spawned.instrument_id == AUDUSD_ALT.id
+        assert spawned.quantity == Quantity.from_int(30_000)
+        assert spawned.status == OrderStatus.FILLED
+        assert order.status == OrderStatus.CANCELED
+        assert router.filled_qty(order.client_order_id) == Quantity.from_int(30_000)
+
+    def test_route_limit_order_spawns_limit_orders_at_primary_price(self) -> None:
+        # Arrange
+        router = self.create_router()
+        self.process_quotes()
+
+        order = self.strategy.order_factory.limit(
+            instrument_id=AUDUSD_SIM.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(120_000),
+            price=Price.from_str("1.00010"),
+            exec_algorithm_id=router.id,
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+
+        # Assert
+        spawned = self.cache.order(ClientOrderId(f"{order.client_order_id.value}-E1"))
+        assert spawned.instrument_id == AUDUSD_ALT.id
+        assert spawned.price == Price.from_str("1.00010")
+        assert spawned.quantity == Quantity.from_int(50_000)
+        assert order.quantity == Quantity.from_int(70_000)
+
+    def test_route_order_with_no_liquidity_submits_primary(self) -> None:
+        # Arrange
+        router = self.create_router()
+
+        order = self.strategy.order_factory.limit(
+            instrument_id=AUDUSD_SIM.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(100_000),
+            price=Price.from_str("1.00000"),
+            exec_algorithm_id=router.id,
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+        self.process_exchanges()
+
+        # Assert
+        assert order.quantity == Quantity.from_int(100_000)
+        assert order.status == OrderStatus.ACCEPTED
+        assert self.cache.orders_for_exec_spawn(order.client_order_id) == [order]
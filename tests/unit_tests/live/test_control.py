@@ -0,0 +1,256 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import msgspec
+import pytest
+
+from nautilus_trader.backtest.engine import BacktestEngine
+from nautilus_trader.backtest.engine import BacktestEngineConfig
+from nautilus_trader.config import ControlServerConfig
+from nautilus_trader.config import LoggingConfig
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.live.control import CONTROL_SERVICE_NAME
+from nautilus_trader.live.control import NodeControlServer
+from nautilus_trader.live.control import NodeControlService
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import TradingState
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Money
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.trading.strategy import Strategy
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class EntryStrategy(Strategy):
+    """
+    Opens a position and rests a limit order on the first quote.
+    """
+
+    def on_start(self) -> None:
+        self.subscribe_quote_ticks(AUDUSD_SIM.id)
+
+    def on_quote_tick(self, tick: QuoteTick) -> None:
+        if self.cache.orders(strategy_id=self.id):
+            return
+
+        self.submit_order(
+            self.order_factory.market(
+                instrument_id=AUDUSD_SIM.id,
+                order_side=OrderSide.BUY,
+                quantity=AUDUSD_SIM.make_qty(100_000),
+            ),
+        )
+        self.submit_order(
+            self.order_factory.limit(
+                instrument_id=AUDUSD_SIM.id,
+                order_side=OrderSide.BUY,
+                quantity=AUDUSD_SIM.make_qty(100_000),
+                price=AUDUSD_SIM.make_price(0.50000),
+            ),
+        )
+
+
+class TestNodeControlService:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.engine = BacktestEngine(
+            BacktestEngineConfig(logging=LoggingConfig(bypass_logging=True)),
+        )
+        self.engine.add_venue(
+            venue=Venue("SIM"),
+            oms_type=OmsType.HEDGING,
+            account_type=AccountType.MARGIN,
+            base_currency=USD,
+            starting_balances=[Money(1_000_000, USD)],
+        )
+        self.engine.add_instrument(AUDUSD_SIM)
+
+        self.strategy = EntryStrategy()
+        self.engine.add_strategy(self.strategy)
+
+        self.service = NodeControlService(
+            trader=self.engine.kernel.trader,
+            cache=self.engine.cache,
+            msgbus=self.engine.kernel.msgbus,
+            clock=self.engine.kernel.clock,
+        )
+
+    def teardown(self) -> None:
+        self.engine.dispose()
+
+    def run_quotes(self, *secs: int) -> None:
+        self.engine.clear_data()
+        self.engine.add_data(
+            [
+                TestDataStubs.quote_tick(
+                    AUDUSD_SIM,
+                    0.70000,
+                    0.70010,
+                    ts_event=secs_to_nanos(s),
+                    ts_init=secs_to_nanos(s),
+                )
+                for s in secs
+            ],
+        )
+        self.engine.run(streaming=True)
+
+    def test_list_strategies_returns_ids_and_states(self):
+        # Arrange, Act
+        result = self.service.list_strategies()
+
+        # Assert
+        assert result == [{"strategy_id": self.strategy.id.value, "state": "READY"}]
+
+    def test_start_and_stop_strategy(self):
+        # Arrange, Act
+        started = self.service.start_strategy(self.strategy.id.value)
+        stopped = self.service.stop_strategy(self.strategy.id.value)
+
+        # Assert
+        assert started == {"strategy_id": self.strategy.id.value, "state": "RUNNING"}
+        assert stopped == {"strategy_id": self.strategy.id.value, "state": "STOPPED"}
+
+    def test_start_unknown_strategy_raises_key_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(KeyError):
+            self.service.start_strategy("UNKNOWN-001")
+
+    def test_flatten_positions_cancels_orders_and_closes_positions(self):
+        # Arrange
+        self.run_quotes(1, 2)
+        assert len(self.engine.cache.positions_open()) == 1
+        assert len(self.engine.cache.orders_open()) == 1
+
+        # Act
+        result = self.service.flatten_positions(instrument_id=AUDUSD_SIM.id.value)
+        self.run_quotes(3)
+
+        # Assert
+        assert result == {"orders_canceled": 1, "positions_closed": 1}
+        assert self.engine.cache.positions_open() == []
+        assert self.engine.cache.orders_open() == []
+
+    def test_flatten_positions_with_no_positions_does_nothing(self):
+        # Arrange, Act
+        result = self.service.flatten_positions()
+
+        # Assert
+        assert result == {"orders_canceled": 0, "positions_closed": 0}
+
+    def test_query_cache_positions_open(self):
+        # Arrange
+        self.run_quotes(1, 2)
+
+        # Act
+        result = self.service.query_cache("positions_open", strategy_id=self.strategy.id.value)
+
+        # Assert
+        assert len(result) == 1
+        assert result[0]["instrument_id"] == AUDUSD_SIM.id.value
+        assert msgspec.json.decode(msgspec.json.encode(result)) == result
+
+    def test_query_cache_accounts_and_instruments(self):
+        # Arrange
+        self.run_quotes(1)
+
+        # Act
+        accounts = self.service.query_cache("accounts", venue="SIM")
+        instruments = self.service.query_cache("instruments")
+
+        # Assert
+        assert len(accounts) == 1
+        assert accounts[0]["account_id"] == "SIM-001"
+        assert [i["id"] for i in instruments] == [AUDUSD_SIM.id.value]
+
+    def test_query_cache_with_unknown_query_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.service.query_cache("everything")
+
+    def test_set_trading_state_sends_command_to_risk_engine(self):
+        # Arrange, Act
+        result = self.service.set_trading_state("halted", requested_by="ops")
+
+        # Assert
+        assert result["state"] == "HALTED"
+        assert self.engine.kernel.risk_engine.trading_state == TradingState.HALTED
+
+    def test_set_trading_state_with_invalid_state_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.service.set_trading_state("PAUSED")
+        assert self.engine.kernel.risk_engine.trading_state == TradingState.ACTIVE
+
+
+class TestNodeControlServer:
+    def test_instantiate_with_only_certfile_raises_value_error(self):
+        # Arrange
+        config = ControlServerConfig(ssl_certfile="server.pem")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            NodeControlServer(service=object(), config=config)
+
+    @pytest.mark.asyncio()
+    async def test_round_trip_with_authentication(self):
+        # Arrange
+        grpc = pytest.importorskip("grpc")
+
+        engine = BacktestEngine(BacktestEngineConfig(logging=LoggingConfig(bypass_logging=True)))
+        service = NodeControlService(
+            trader=engine.kernel.trader,
+            cache=engine.cache,
+            msgbus=engine.kernel.msgbus,
+            clock=engine.kernel.clock,
+        )
+        config = ControlServerConfig(port=50981, auth_token="secret")
+        server = NodeControlServer(service=service, config=config)
+        await server.start()
+
+        # Act
+        async with grpc.aio.insecure_channel(f"127.0.0.1:{config.port}") as channel:
+            set_trading_state = channel.unary_unary(
+                f"/{CONTROL_SERVICE_NAME}/SetTradingState",
+                request_serializer=msgspec.json.encode,
+                response_deserializer=msgspec.json.decode,
+            )
+            response = await set_trading_state(
+                {"state": "REDUCING"},
+                metadata=(("authorization", "Bearer secret"),),
+            )
+            with pytest.raises(grpc.aio.AioRpcError) as e:
+                await set_trading_state({"state": "HALTED"})
+            with pytest.raises(grpc.aio.AioRpcError) as e_wrong_token:
+                await set_trading_state(
+                    {"state": "HALTED"},
+                    metadata=(("authorization", "Bearer wrong"),),
+                )
+
+        await server.stop()
+        engine.dispose()
+
+        # Assert
+        assert response["result"]["state"] == "REDUCING"
+        assert e.value.code() == grpc.StatusCode.UNAUTHENTICATED
+        assert e_wrong_token.value.code() == grpc.StatusCode.UNAUTHENTICATED
+        assert engine.kernel.risk_engine.trading_state == TradingState.REDUCING
+        assert not server.is_running
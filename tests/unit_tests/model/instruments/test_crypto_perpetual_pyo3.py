@@ -41,8 +41,9 @@ def test_to_dict():
         "quote_currency": "USDT",
         "settlement_currency": "USDT",
         "is_inverse": False,
+        "quanto_multiplier": None,
         "price_precision": 2,
-        "size_precision": 0,
+        "size_precision": 3,
         "price_increment": "0.01",
         "size_increment": "0.001",
         "lot_size": None,
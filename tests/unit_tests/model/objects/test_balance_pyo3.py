@@ -12,8 +12,14 @@
 #  See the License for the specific language governing permissions and
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
+
+import pytest
+
 from nautilus_trader.core.nautilus_pyo3 import AccountBalance
+from nautilus_trader.core.nautilus_pyo3 import Currency
+from nautilus_trader.core.nautilus_pyo3 import CurrencyMismatchError
 from nautilus_trader.core.nautilus_pyo3 import MarginBalance
+from nautilus_trader.core.nautilus_pyo3 import Money
 from nautilus_trader.test_kit.rust.types_pyo3 import TestTypesProviderPyo3
 
 
@@ -51,6 +57,16 @@ def test_account_balance_to_from_dict():
     }
 
 
+def test_account_balance_with_mismatched_currencies_raises_currency_mismatch_error():
+    # Arrange
+    usd = Currency.from_str("USD")
+    eur = Currency.from_str("EUR")
+
+    # Act, Assert
+    with pytest.raises(CurrencyMismatchError):
+        AccountBalance(Money(100.0, usd), Money(0.0, usd), Money(100.0, eur))
+
+
 ################################################################################
 # Margin balance
 ################################################################################
@@ -19,6 +19,7 @@ from decimal import Decimal
 
 import pytest
 
+from nautilus_trader.core.nautilus_pyo3 import OutOfRangeError
 from nautilus_trader.core.nautilus_pyo3 import Price
 
 
@@ -43,6 +44,11 @@ class TestPrice:
         with pytest.raises(ValueError):
             Price(1.0, precision=10)
 
+    def test_instantiate_with_precision_over_maximum_raises_out_of_range_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(OutOfRangeError):
+            Price(1.0, precision=10)
+
     def test_instantiate_with_value_exceeding_positive_limit_raises_value_error(self):
         # Arrange, Act, Assert
         with pytest.raises(ValueError):
@@ -19,6 +19,7 @@ from decimal import Decimal
 
 import pytest
 
+from nautilus_trader.model.enums import RoundingMode
 from nautilus_trader.model.objects import Quantity
 
 
@@ -78,6 +79,23 @@ class TestQuantity:
         assert result.raw == 1_230_000_000
         assert str(result) == "1.23"
 
+    @pytest.mark.parametrize(
+        ("value", "rounding_mode", "expected"),
+        [
+            [1.235, RoundingMode.HALF_UP, "1.24"],
+            [1.235, RoundingMode.HALF_EVEN, "1.24"],
+            [1.245, RoundingMode.HALF_EVEN, "1.24"],
+            [1.239, RoundingMode.FLOOR, "1.23"],
+            [1.231, RoundingMode.CEILING, "1.24"],
+        ],
+    )
+    def test_instantiate_with_rounding_mode(self, value, rounding_mode, expected):
+        # Arrange, Act
+        result = Quantity(value, precision=2, rounding_mode=rounding_mode)
+
+        # Assert
+        assert str(result) == expected
+
     @pytest.mark.parametrize(
         ("value", "precision", "expected"),
         [
@@ -298,6 +298,18 @@ class TestBarType:
         # Assert
         assert unpickled == bar_type
 
+    def test_composite_bar_type_pickle(self):
+        # Arrange
+        bar_type = BarType.from_str("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL")
+
+        # Act
+        pickled = pickle.dumps(bar_type)
+        unpickled = pickle.loads(pickled)  # noqa S301 (pickle is safe here)
+
+        # Assert
+        assert unpickled == bar_type
+        assert str(unpickled) == "AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL"
+
     def test_bar_type_hash_str_and_repr(self):
         # Arrange
         instrument_id = InstrumentId(Symbol("AUD/USD"), Venue("SIM"))
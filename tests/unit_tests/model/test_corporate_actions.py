@@ -0,0 +1,92 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import CashDividend
+from nautilus_trader.model.data import StockSplit
+from nautilus_trader.model.data import SymbolChange
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.objects import Money
+
+
+AAPL_XNAS = InstrumentId.from_str("AAPL.XNAS")
+
+
+class TestCorporateActions:
+    def test_cash_dividend(self):
+        # Arrange
+        dividend = CashDividend(
+            instrument_id=AAPL_XNAS,
+            amount=Money(0.24, USD),
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert CashDividend.from_dict(CashDividend.to_dict(dividend)) == dividend
+        assert repr(dividend) == "CashDividend(instrument_id=AAPL.XNAS, amount=0.24 USD, ts_event=1)"
+
+    def test_cash_dividend_with_zero_amount_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            CashDividend(
+                instrument_id=AAPL_XNAS,
+                amount=Money(0, USD),
+                ts_event=0,
+                ts_init=0,
+            )
+
+    def test_stock_split(self):
+        # Arrange
+        split = StockSplit(
+            instrument_id=AAPL_XNAS,
+            numerator=4,
+            denominator=1,
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert StockSplit.from_dict(StockSplit.to_dict(split)) == split
+        assert repr(split) == "StockSplit(instrument_id=AAPL.XNAS, ratio=4:1, ts_event=1)"
+
+    def test_stock_split_with_zero_denominator_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            StockSplit(
+                instrument_id=AAPL_XNAS,
+                numerator=2,
+                denominator=0,
+                ts_event=0,
+                ts_init=0,
+            )
+
+    def test_symbol_change(self):
+        # Arrange
+        change = SymbolChange(
+            instrument_id=InstrumentId.from_str("FB.XNAS"),
+            new_instrument_id=InstrumentId.from_str("META.XNAS"),
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert SymbolChange.from_dict(SymbolChange.to_dict(change)) == change
+        assert (
+            repr(change)
+            == "SymbolChange(instrument_id=FB.XNAS, new_instrument_id=META.XNAS, ts_event=1)"
+        )
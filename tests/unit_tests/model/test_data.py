@@ -13,11 +13,16 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pytest
+
 from nautilus_trader.core.data import Data
+from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import BorrowRate
 from nautilus_trader.model.data import CustomData
 from nautilus_trader.model.data import DataType
 from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.trading.filters import NewsEvent
 from nautilus_trader.trading.filters import NewsImpact
 
@@ -153,3 +158,30 @@ class TestDataType:
         assert isinstance(hash(data_type), int)
         assert str(data_type) == "Data{'category': 1, 'code': 0}"
         assert repr(data_type) == "DataType(type=Data, metadata={'category': 1, 'code': 0})"
+
+
+class TestBorrowRate:
+    def test_borrow_rate(self):
+        # Arrange
+        borrow_rate = BorrowRate(
+            venue=Venue("BINANCE"),
+            currency=BTC,
+            rate=0.05,
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert BorrowRate.from_dict(BorrowRate.to_dict(borrow_rate)) == borrow_rate
+        assert repr(borrow_rate) == "BorrowRate(venue=BINANCE, currency=BTC, rate=0.05, ts_event=1)"
+
+    def test_borrow_rate_with_negative_rate_raises(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            BorrowRate(
+                venue=Venue("BINANCE"),
+                currency=BTC,
+                rate=-0.01,
+                ts_event=0,
+                ts_init=0,
+            )
@@ -601,6 +601,7 @@ class TestMarketStatus:
             [MarketStatus.OPEN, "OPEN"],
             [MarketStatus.PAUSE, "PAUSE"],
             [MarketStatus.PRE_CLOSE, "PRE_CLOSE"],
+            [MarketStatus.AUCTION, "AUCTION"],
         ],
     )
     def test_market_status_to_str(self, enum, expected):
@@ -618,6 +619,7 @@ class TestMarketStatus:
             ["OPEN", MarketStatus.OPEN],
             ["PAUSE", MarketStatus.PAUSE],
             ["PRE_CLOSE", MarketStatus.PRE_CLOSE],
+            ["AUCTION", MarketStatus.AUCTION],
         ],
     )
     def test_market_status_from_str(self, string, expected):
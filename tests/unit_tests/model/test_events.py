@@ -688,6 +688,37 @@ class TestModelEvents:
         )
 
 
+    def test_order_filled_event_with_tags_to_from_dict(self):
+        # Arrange
+        event = OrderFilled(
+            trader_id=TraderId("TRADER-001"),
+            strategy_id=StrategyId("SCALPER-001"),
+            instrument_id=InstrumentId(Symbol("BTCUSDT"), Venue("BINANCE")),
+            client_order_id=ClientOrderId("O-2020872378423"),
+            venue_order_id=VenueOrderId("123456"),
+            account_id=AccountId("SIM-000"),
+            trade_id=TradeId("1"),
+            position_id=PositionId("2"),
+            order_side=OrderSide.BUY,
+            order_type=OrderType.LIMIT,
+            last_qty=Quantity.from_str("0.561000"),
+            last_px=Price.from_str("15600.12445"),
+            currency=USDT,
+            commission=Money(12.20000000, USDT),
+            liquidity_side=LiquiditySide.MAKER,
+            ts_event=0,
+            event_id=UUID4(),
+            ts_init=0,
+            tags="signal-a,leg-1",
+        )
+
+        # Act
+        result = OrderFilled.from_dict(OrderFilled.to_dict(event))
+
+        # Assert
+        assert result == event
+        assert result.tags == "signal-a,leg-1"
+
 class TestPositionEvents:
     def setup(self):
         # Fixture Setup
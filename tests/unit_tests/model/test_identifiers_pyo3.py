@@ -20,6 +20,7 @@ import pytest
 from nautilus_trader.core.nautilus_pyo3 import AccountId
 from nautilus_trader.core.nautilus_pyo3 import ExecAlgorithmId
 from nautilus_trader.core.nautilus_pyo3 import InstrumentId
+from nautilus_trader.core.nautilus_pyo3 import InvalidIdentifierError
 from nautilus_trader.core.nautilus_pyo3 import Symbol
 from nautilus_trader.core.nautilus_pyo3 import TraderId
 from nautilus_trader.core.nautilus_pyo3 import Venue
@@ -207,6 +208,12 @@ def test_instrument_id_from_str_when_invalid(input: str, expected_err: str) -> N
     assert str(exc_info.value) == expected_err
 
 
+def test_invalid_identifier_raises_invalid_identifier_error() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(InvalidIdentifierError):
+        TraderId("TESTER")
+
+
 def test_exec_algorithm_id() -> None:
     # Arrange
     exec_algorithm_id1 = ExecAlgorithmId("VWAP")
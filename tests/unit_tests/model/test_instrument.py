@@ -22,8 +22,10 @@ from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import ETH
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.currencies import USDT
+from nautilus_trader.model.enums import RoundingMode
 from nautilus_trader.model.enums import option_kind_from_str
 from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments import BinaryOption
 from nautilus_trader.model.instruments import CryptoFuture
 from nautilus_trader.model.instruments import CryptoPerpetual
 from nautilus_trader.model.instruments import Equity
@@ -46,9 +48,11 @@ XBTUSD_BITMEX = TestInstrumentProvider.xbtusd_bitmex()
 BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
 BTCUSDT_220325 = TestInstrumentProvider.btcusdt_future_binance()
 ETHUSD_BITMEX = TestInstrumentProvider.ethusd_bitmex()
+ETHUSD_QUANTO_BITMEX = TestInstrumentProvider.ethusd_quanto_bitmex()
 AAPL_EQUITY = TestInstrumentProvider.equity(symbol="AAPL", venue="XNAS")
 ES_FUTURE = TestInstrumentProvider.es_future(expiry_year=2023, expiry_month=12)
 AAPL_OPTION = TestInstrumentProvider.aapl_option()
+BINARY_OPTION = TestInstrumentProvider.binary_option()
 
 
 class TestInstrument:
@@ -178,6 +182,7 @@ class TestInstrument:
             "min_notional": "1.00 USD",
             "max_price": "1000000.0",
             "min_price": "0.5",
+            "quanto_multiplier": None,
             "margin_init": "0.01",
             "margin_maint": "0.0035",
             "maker_fee": "-0.00025",
@@ -300,6 +305,69 @@ class TestInstrument:
             "underlying": "AAPL",
         }
 
+    def test_binary_option_instrument_to_dict(self):
+        # Arrange, Act
+        result = BinaryOption.to_dict(BINARY_OPTION)
+
+        # Assert
+        assert BinaryOption.from_dict(result) == BINARY_OPTION
+        assert result == {
+            "type": "BinaryOption",
+            "id": "0x12a0cb60174abc437bf1178367c72d11f069e1a3add20b148fb0ab4279b772b2-YES.POLYMARKET",
+            "raw_symbol": "0x12a0cb60174abc437bf1178367c72d11f069e1a3add20b148fb0ab4279b772b2-YES",
+            "asset_class": "ALTERNATIVE",
+            "currency": "USDC",
+            "activation_ns": 0,
+            "expiration_ns": 1704067200000000000,
+            "price_increment": "0.001",
+            "price_precision": 3,
+            "size_increment": "0.01",
+            "size_precision": 2,
+            "max_quantity": None,
+            "min_quantity": None,
+            "maker_fee": "0",
+            "taker_fee": "0",
+            "outcome": "Yes",
+            "description": "Will the outcome of this market be 'Yes'?",
+            "info": None,
+            "ts_event": 0,
+            "ts_init": 0,
+        }
+
+    def test_binary_option_price_bounds(self):
+        # Arrange, Act, Assert
+        assert BINARY_OPTION.min_price == Price.from_str("0.000")
+        assert BINARY_OPTION.max_price == Price.from_str("1.000")
+        assert BINARY_OPTION.multiplier == Quantity.from_int(1)
+
+    @pytest.mark.parametrize(
+        ("outcome_occurred", "expected"),
+        [
+            [True, Price.from_str("1.000")],
+            [False, Price.from_str("0.000")],
+        ],
+    )
+    def test_binary_option_settlement_price(self, outcome_occurred, expected):
+        # Arrange, Act
+        result = BINARY_OPTION.settlement_price(outcome_occurred)
+
+        # Assert
+        assert result == expected
+
+    def test_binary_option_settlement_pnl(self):
+        # Arrange
+        entry = Price.from_str("0.650")
+        quantity = Quantity.from_int(100)
+
+        # Act
+        won = BINARY_OPTION.notional_value(quantity, BINARY_OPTION.settlement_price(True))
+        lost = BINARY_OPTION.notional_value(quantity, BINARY_OPTION.settlement_price(False))
+        cost = BINARY_OPTION.notional_value(quantity, entry)
+
+        # Assert
+        assert won - cost == Decimal("35.00")
+        assert lost - cost == Decimal("-65.00")
+
     @pytest.mark.parametrize(
         ("value", "expected_str"),
         [
@@ -350,6 +418,52 @@ class TestInstrument:
         # Assert
         assert str(qty) == expected_str
 
+    @pytest.mark.parametrize(
+        ("rounding_mode", "expected_str"),
+        [
+            [RoundingMode.HALF_UP, "1.23457"],
+            [RoundingMode.HALF_EVEN, "1.23456"],
+            [RoundingMode.FLOOR, "1.23456"],
+            [RoundingMode.CEILING, "1.23457"],
+        ],
+    )
+    def test_make_price_with_rounding_mode(self, rounding_mode, expected_str):
+        # Arrange, Act
+        price = AUDUSD_SIM.make_price(1.234565, rounding_mode=rounding_mode)
+
+        # Assert
+        assert str(price) == expected_str
+
+    @pytest.mark.parametrize(
+        ("rounding_mode", "expected_str"),
+        [
+            [RoundingMode.HALF_UP, "1.234568"],
+            [RoundingMode.HALF_EVEN, "1.234568"],
+            [RoundingMode.FLOOR, "1.234567"],
+            [RoundingMode.CEILING, "1.234568"],
+        ],
+    )
+    def test_make_qty_with_rounding_mode(self, rounding_mode, expected_str):
+        # Arrange, Act
+        qty = BTCUSDT_BINANCE.make_qty(1.2345675, rounding_mode=rounding_mode)
+
+        # Assert
+        assert str(qty) == expected_str
+
+    def test_make_price_uses_instrument_rounding_mode(self):
+        # Arrange
+        instrument = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+        instrument.rounding_mode = RoundingMode.FLOOR
+
+        # Act
+        price1 = instrument.make_price(1.234569)
+        price2 = instrument.make_price(1.234561, rounding_mode=RoundingMode.CEILING)
+
+        # Assert
+        assert instrument.rounding_mode == RoundingMode.FLOOR
+        assert str(price1) == "1.23456"
+        assert str(price2) == "1.23457"
+
     @pytest.mark.parametrize(
         ("instrument", "expected"),
         [
@@ -370,6 +484,7 @@ class TestInstrument:
             [BTCUSDT_BINANCE, USDT],
             [XBTUSD_BITMEX, BTC],
             [ETHUSD_BITMEX, ETH],
+            [ETHUSD_QUANTO_BITMEX, BTC],
         ],
     )
     def test_settlement_currency_for_various_instruments(self, instrument, expected):
@@ -410,6 +525,46 @@ class TestInstrument:
         # Assert
         assert result == expected
 
+    def test_calculate_notional_value_for_quanto(self):
+        # Arrange
+        instrument = TestInstrumentProvider.ethusd_quanto_bitmex()
+
+        # Act
+        result = instrument.notional_value(
+            Quantity.from_int(100),
+            Price.from_str("2000.00"),
+        )
+
+        # Assert
+        assert instrument.is_quanto
+        assert result == Money(0.20000000, BTC)
+
+    def test_quanto_without_multiplier_raises(self):
+        # Arrange
+        values = CryptoPerpetual.to_dict(ETHUSD_QUANTO_BITMEX)
+        values["quanto_multiplier"] = None
+
+        # Act, Assert
+        with pytest.raises(TypeError):
+            CryptoPerpetual.from_dict(values)
+
+    def test_quanto_multiplier_for_non_quanto_raises(self):
+        # Arrange
+        values = CryptoPerpetual.to_dict(TestInstrumentProvider.ethusdt_perp_binance())
+        values["quanto_multiplier"] = "0.000001"
+
+        # Act, Assert
+        with pytest.raises(TypeError):
+            CryptoPerpetual.from_dict(values)
+
+    def test_quanto_instrument_to_dict_round_trip(self):
+        # Arrange, Act
+        result = CryptoPerpetual.to_dict(ETHUSD_QUANTO_BITMEX)
+
+        # Assert
+        assert result["quanto_multiplier"] == "0.000001"
+        assert CryptoPerpetual.from_dict(result).quanto_multiplier == Decimal("0.000001")
+
     def test_calculate_base_quantity_audusd(self):
         # Arrange
         instrument = TestInstrumentProvider.default_fx_ccy("AUD/USD")
@@ -21,17 +21,21 @@ import pytest
 
 from nautilus_trader.adapters.databento.loaders import DatabentoDataLoader
 from nautilus_trader.model.book import OrderBook
+from nautilus_trader.model.book import OwnOrderBook
 from nautilus_trader.model.data import BookOrder
 from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDeltas
 from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import BookType
 from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.events import TestEventStubs
+from nautilus_trader.test_kit.stubs.execution import TestExecStubs
 from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
 from tests import TEST_DATA_DIR
 
@@ -712,3 +716,186 @@ class TestOrderBook:
         assert len(book.asks()) == 565
         assert book.best_bid_price() == Price.from_str("4810.00")
         assert book.best_ask_price() == Price.from_str("4810.25")
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class TestOwnOrderBook:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.own_book = OwnOrderBook(AUDUSD_SIM.id)
+        self.order_count = 0
+
+    def accepted_order(
+        self,
+        side: OrderSide,
+        price: str,
+        quantity: int = 100_000,
+    ):
+        self.order_count += 1
+        return TestExecStubs.make_accepted_order(
+            instrument_id=AUDUSD_SIM.id,
+            order_side=side,
+            price=Price.from_str(price),
+            quantity=Quantity.from_int(quantity),
+            client_order_id=ClientOrderId(f"O-{self.order_count}"),
+        )
+
+    def test_instantiate(self) -> None:
+        # Arrange, Act, Assert
+        assert self.own_book.instrument_id == AUDUSD_SIM.id
+        assert self.own_book.order_count() == 0
+        assert self.own_book.bid_prices() == []
+        assert self.own_book.ask_prices() == []
+        assert repr(self.own_book) == (
+            "OwnOrderBook(instrument_id=AUD/USD.SIM, bid_levels=0, ask_levels=0, orders=0)"
+        )
+
+    def test_update_with_order_not_yet_accepted_does_not_add(self) -> None:
+        # Arrange
+        order = TestExecStubs.make_submitted_order(
+            instrument_id=AUDUSD_SIM.id,
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(100_000),
+        )
+
+        # Act
+        self.own_book.update(order)
+
+        # Assert
+        assert not self.own_book.contains(order.client_order_id)
+        assert self.own_book.order_count() == 0
+
+    def test_update_with_order_for_other_instrument_raises_value_error(self) -> None:
+        # Arrange
+        order = TestExecStubs.make_accepted_order(
+            instrument_id=TestIdStubs.gbpusd_id(),
+            price=Price.from_str("1.00000"),
+            quantity=Quantity.from_int(100_000),
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.own_book.update(order)
+
+    def test_update_with_resting_orders_groups_by_price_level(self) -> None:
+        # Arrange
+        bid1 = self.accepted_order(OrderSide.BUY, "0.99990")
+        bid2 = self.accepted_order(OrderSide.BUY, "0.99980")
+        ask1 = self.accepted_order(OrderSide.SELL, "1.00010")
+        ask2 = self.accepted_order(OrderSide.SELL, "1.00010", quantity=50_000)
+        ask3 = self.accepted_order(OrderSide.SELL, "1.00020")
+
+        # Act
+        for order in (bid2, ask3, ask1, bid1, ask2):
+            self.own_book.update(order)
+
+        # Assert
+        assert self.own_book.order_count() == 5
+        assert self.own_book.bid_prices() == [Price.from_str("0.99990"), Price.from_str("0.99980")]
+        assert self.own_book.ask_prices() == [Price.from_str("1.00010"), Price.from_str("1.00020")]
+        assert self.own_book.orders_at_level(OrderSide.SELL, Price.from_str("1.00010")) == [ask1, ask2]
+        assert self.own_book.own_qty_at_price(OrderSide.SELL, Price.from_str("1.00010")) == Quantity.from_int(150_000)
+        assert self.own_book.own_qty_at_price(OrderSide.BUY, Price.from_str("0.99990")) == Quantity.from_int(100_000)
+        assert self.own_book.orders() == [bid1, bid2, ask1, ask2, ask3]
+
+    def test_queries_for_empty_level(self) -> None:
+        # Arrange
+        self.own_book.update(self.accepted_order(OrderSide.BUY, "0.99990"))
+
+        # Act, Assert
+        assert self.own_book.orders_at_level(OrderSide.SELL, Price.from_str("0.99990")) == []
+        assert self.own_book.own_qty_at_price(OrderSide.BUY, Price.from_str("0.99980")) == Quantity.zero()
+
+    def test_update_with_partial_fill_reduces_own_qty(self) -> None:
+        # Arrange
+        order = self.accepted_order(OrderSide.BUY, "0.99990")
+        self.own_book.update(order)
+
+        fill = TestEventStubs.order_filled(
+            order=order,
+            instrument=AUDUSD_SIM,
+            last_qty=Quantity.from_int(40_000),
+            last_px=Price.from_str("0.99990"),
+        )
+        order.apply(fill)
+
+        # Act
+        self.own_book.update(order)
+
+        # Assert
+        assert self.own_book.own_qty_at_price(OrderSide.BUY, Price.from_str("0.99990")) == Quantity.from_int(60_000)
+
+    def test_update_with_filled_order_removes_level(self) -> None:
+        # Arrange
+        order = self.accepted_order(OrderSide.BUY, "0.99990")
+        self.own_book.update(order)
+        order.apply(TestEventStubs.order_filled(order=order, instrument=AUDUSD_SIM))
+
+        # Act
+        self.own_book.update(order)
+
+        # Assert
+        assert not self.own_book.contains(order.client_order_id)
+        assert self.own_book.bid_prices() == []
+
+    def test_update_with_modified_price_moves_order_to_new_level(self) -> None:
+        # Arrange
+        order = self.accepted_order(OrderSide.SELL, "1.00010")
+        self.own_book.update(order)
+
+        updated = TestEventStubs.order_updated(
+            order=order,
+            quantity=order.quantity,
+            price=Price.from_str("1.00030"),
+        )
+        order.apply(updated)
+
+        # Act
+        self.own_book.update(order)
+
+        # Assert
+        assert self.own_book.ask_prices() == [Price.from_str("1.00030")]
+        assert self.own_book.orders_at_level(OrderSide.SELL, Price.from_str("1.00030")) == [order]
+
+    def test_update_at_same_price_keeps_position_within_level(self) -> None:
+        # Arrange
+        order1 = self.accepted_order(OrderSide.SELL, "1.00010")
+        order2 = self.accepted_order(OrderSide.SELL, "1.00010")
+        self.own_book.update(order1)
+        self.own_book.update(order2)
+
+        updated = TestEventStubs.order_updated(
+            order=order1,
+            quantity=Quantity.from_int(50_000),
+            price=order1.price,
+        )
+        order1.apply(updated)
+
+        # Act
+        self.own_book.update(order1)
+
+        # Assert
+        assert self.own_book.orders_at_level(OrderSide.SELL, Price.from_str("1.00010")) == [order1, order2]
+        assert self.own_book.own_qty_at_price(OrderSide.SELL, Price.from_str("1.00010")) == Quantity.from_int(150_000)
+
+    def test_remove_and_clear(self) -> None:
+        # Arrange
+        order1 = self.accepted_order(OrderSide.BUY, "0.99990")
+        order2 = self.accepted_order(OrderSide.SELL, "1.00010")
+        self.own_book.update(order1)
+        self.own_book.update(order2)
+
+        # Act
+        self.own_book.remove(order1.client_order_id)
+
+        # Assert
+        assert self.own_book.orders() == [order2]
+
+        # Act
+        self.own_book.clear()
+
+        # Assert
+        assert self.own_book.order_count() == 0
+        assert self.own_book.ask_prices() == []
@@ -50,6 +50,7 @@ BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
 ETHUSDT_BINANCE = TestInstrumentProvider.ethusdt_binance()
 XBTUSD_BITMEX = TestInstrumentProvider.xbtusd_bitmex()
 ETHUSD_BITMEX = TestInstrumentProvider.ethusd_bitmex()
+ETHUSD_QUANTO_BITMEX = TestInstrumentProvider.ethusd_quanto_bitmex()
 
 
 class TestPosition:
@@ -414,6 +415,60 @@ class TestPosition:
         assert position.commissions() == [Money(2.00, USD)]
         assert repr(position) == "Position(LONG 100_000 AUD/USD.SIM, id=P-123456)"
 
+    def test_position_apply_split_scales_quantity_and_average_price(self) -> None:
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            last_px=Price.from_str("1.00001"),
+        )
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill)
+
+        # Act
+        remainder = position.apply_split(2, 1, ts_event=1)
+
+        # Assert
+        assert remainder == Decimal(0)
+        assert position.quantity == Quantity.from_int(200_000)
+        assert position.peak_qty == Quantity.from_int(200_000)
+        assert position.signed_qty == 200_000.0
+        assert position.avg_px_open == 0.500005
+        assert position.is_long
+
+    def test_position_apply_split_returns_fractional_remainder(self) -> None:
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_001),
+        )
+
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            last_px=Price.from_str("1.00001"),
+        )
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill)
+
+        # Act
+        remainder = position.apply_split(3, 2, ts_event=1)
+
+        # Assert
+        assert remainder == Decimal("0.5")
+        assert position.quantity == Quantity.from_int(150_001)
+        assert position.signed_qty == -150_001.0
+        assert position.is_short
+
     def test_position_filled_with_sell_order(self) -> None:
         # Arrange
         order = self.order_factory.market(
@@ -1292,6 +1347,30 @@ class TestPosition:
         assert position.realized_pnl == Money(-0.00750000, BTC)
         assert position.notional_value(Price.from_str("11000.00")) == Money(9.09090909, BTC)
 
+    def test_inverse_avg_px_open_and_realized_pnl_bitmex_example(self) -> None:
+        # Arrange: buy 1,000 XBTUSD @ 10,000 and 1,000 @ 12,000, then sell 2,000 @ 11,000
+        fills = []
+        for side, px in ((OrderSide.BUY, "10000.0"), (OrderSide.BUY, "12000.0")):
+            order = self.order_factory.market(XBTUSD_BITMEX.id, side, Quantity.from_int(1_000))
+            fills.append(
+                TestEventStubs.order_filled(
+                    order,
+                    instrument=XBTUSD_BITMEX,
+                    position_id=PositionId("P-123456"),
+                    strategy_id=StrategyId("S-001"),
+                    last_px=Price.from_str(px),
+                ),
+            )
+
+        position = Position(instrument=XBTUSD_BITMEX, fill=fills[0])
+
+        # Act
+        position.apply(fills[1])
+
+        # Assert: harmonic mean of entry prices rather than the arithmetic 11,000
+        assert position.avg_px_open == pytest.approx(10_909.090909090909)
+        assert position.unrealized_pnl(Price.from_str("11000.0")) == Money(0.00151515, BTC)
+
     def test_calculate_pnl_for_inverse2(self) -> None:
         # Arrange
         order = self.order_factory.market(
@@ -1314,6 +1393,50 @@ class TestPosition:
         assert position.unrealized_pnl(Price.from_str("370.00")) == Money(4.27745208, ETH)
         assert position.notional_value(Price.from_str("370.00")) == Money(270.27027027, ETH)
 
+    def test_calculate_pnl_for_quanto(self) -> None:
+        # Arrange
+        order1 = self.order_factory.market(
+            ETHUSD_QUANTO_BITMEX.id,
+            OrderSide.BUY,
+            Quantity.from_int(100),
+        )
+
+        order2 = self.order_factory.market(
+            ETHUSD_QUANTO_BITMEX.id,
+            OrderSide.SELL,
+            Quantity.from_int(100),
+        )
+
+        fill1 = TestEventStubs.order_filled(
+            order1,
+            instrument=ETHUSD_QUANTO_BITMEX,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("2000.00"),
+        )
+
+        fill2 = TestEventStubs.order_filled(
+            order2,
+            instrument=ETHUSD_QUANTO_BITMEX,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("2100.00"),
+        )
+
+        position = Position(instrument=ETHUSD_QUANTO_BITMEX, fill=fill1)
+
+        # Act, Assert
+        assert position.settlement_currency == BTC
+        assert position.notional_value(Price.from_str("2000.00")) == Money(0.20000000, BTC)
+        assert position.unrealized_pnl(Price.from_str("2100.00")) == Money(0.01000000, BTC)
+        assert position.commissions() == [Money(0.00015000, BTC)]
+
+        position.apply(fill2)
+
+        # 0.01 BTC gain less taker fees of 0.00015 and 0.0001575 BTC
+        assert position.is_closed
+        assert position.realized_pnl == Money(0.00969250, BTC)
+
     def test_calculate_unrealized_pnl_for_long(self) -> None:
         # Arrange
         order1 = self.order_factory.market(
@@ -113,6 +113,7 @@ def test_position_to_from_dict():
         "peak_qty": "100000",
         "price_precision": 5,
         "quantity": "100000",
+        "quanto_multiplier": None,
         "quote_currency": "USD",
         "realized_pnl": "-2.00 USD",
         "realized_return": 0.0,
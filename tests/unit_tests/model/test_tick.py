@@ -19,6 +19,7 @@ from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.data import TradeTick
 from nautilus_trader.model.enums import AggressorSide
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import TradeFlag
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import Symbol
 from nautilus_trader.model.identifiers import TradeId
@@ -264,8 +265,30 @@ class TestTradeTick:
             "trade_id": "123456789",
             "ts_event": 1,
             "ts_init": 2,
+            "flags": 0,
         }
 
+    def test_trade_flags(self):
+        # Arrange, Act
+        tick = TradeTick(
+            instrument_id=AUDUSD_SIM.id,
+            price=Price.from_str("1.00000"),
+            size=Quantity.from_int(10_000),
+            aggressor_side=AggressorSide.BUYER,
+            trade_id=TradeId("123456789"),
+            ts_event=1,
+            ts_init=2,
+            flags=TradeFlag.AGGREGATED | TradeFlag.BLOCK,
+        )
+
+        # Assert
+        assert tick.flags == 3
+        assert tick.is_aggregated
+        assert tick.is_block
+        assert not tick.is_off_book
+        assert TradeTick.from_dict(TradeTick.to_dict(tick)) == tick
+        assert pickle.loads(pickle.dumps(tick)).flags == 3  # noqa
+
     def test_from_dict_returns_expected_tick(self):
         # Arrange
         tick = TradeTick(
@@ -384,3 +384,80 @@ def test_list_live_runs(
 
     # Assert
     assert result == ["abc"]
+
+
+def test_from_uri_local_path() -> None:
+    # Arrange, Act
+    catalog = ParquetDataCatalog.from_uri("/tmp/catalog")
+
+    # Assert
+    assert catalog.fs_protocol == "file"
+    assert catalog.path == "/tmp/catalog"
+    assert not catalog.is_remote
+
+
+def test_remote_catalog_rust_query_reads_from_local_cache(
+    catalog_memory: ParquetDataCatalog,
+    tmp_path,
+) -> None:
+    # Arrange
+    catalog_memory.local_cache_dir = tmp_path
+    quotes = [TestDataStubs.quote_tick(ts_event=i, ts_init=i) for i in range(3)]
+    catalog_memory.write_data(quotes)
+
+    # Act
+    result = catalog_memory.quote_ticks()
+
+    # Assert
+    assert catalog_memory.is_remote
+    assert [q.ts_init for q in result] == [0, 1, 2]
+    assert len(list(tmp_path.glob("*.parquet"))) == 1
+
+
+def test_remote_catalog_local_cache_refreshed_when_file_changes(
+    catalog_memory: ParquetDataCatalog,
+    tmp_path,
+) -> None:
+    # Arrange
+    catalog_memory.local_cache_dir = tmp_path
+    catalog_memory.write_data([TestDataStubs.quote_tick(ts_event=1, ts_init=1)])
+    catalog_memory.quote_ticks()
+
+    # Act
+    quotes = [TestDataStubs.quote_tick(ts_event=i, ts_init=i) for i in range(2, 5)]
+    catalog_memory.write_data(quotes)  # Overwrites `part-0.parquet`
+    result = catalog_memory.quote_ticks()
+
+    # Assert
+    assert [q.ts_init for q in result] == [2, 3, 4]
+    assert len(list(tmp_path.glob("*.parquet"))) == 1
+
+
+def test_remote_catalog_clear_cache(
+    catalog_memory: ParquetDataCatalog,
+    tmp_path,
+) -> None:
+    # Arrange
+    catalog_memory.local_cache_dir = tmp_path
+    catalog_memory.write_data([TestDataStubs.quote_tick()])
+    catalog_memory.quote_ticks()
+
+    # Act
+    catalog_memory.clear_cache()
+
+    # Assert
+    assert list(tmp_path.iterdir()) == []
+
+
+def test_remote_catalog_streaming_write_with_block_size(
+    catalog_memory: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    catalog_memory.write_block_size = 1024
+    quotes = [TestDataStubs.quote_tick(ts_event=i, ts_init=i) for i in range(1000)]
+
+    # Act
+    catalog_memory.write_data(quotes)
+
+    # Assert
+    assert len(catalog_memory.quote_ticks()) == 1000
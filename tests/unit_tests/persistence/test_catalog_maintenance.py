@@ -0,0 +1,306 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.persistence.catalog.maintenance import CatalogIssueType
+from nautilus_trader.persistence.catalog.maintenance import dedupe_and_sort
+from nautilus_trader.persistence.catalog.maintenance import list_partitions
+from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+
+
+def _quotes(timestamps: list[int]) -> list[QuoteTick]:
+    return [
+        TestDataStubs.quote_tick(bid_price=1.0 + ts / 10_000, ts_event=ts, ts_init=ts)
+        for ts in timestamps
+    ]
+
+
+def _partition(catalog: ParquetDataCatalog) -> str:
+    partitions = list_partitions(catalog, QuoteTick)
+    assert len(partitions) == 1
+    return next(iter(partitions))
+
+
+def test_check_consistency_when_empty_catalog_returns_consistent(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange, Act
+    report = catalog.check_consistency()
+
+    # Assert
+    assert report.is_consistent
+    assert report.partitions_checked == 0
+    assert report.files_checked == 0
+
+
+def test_check_consistency_when_consistent_returns_no_issues(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    catalog.write_data(_quotes([1, 2, 3]), basename_template="a-{i}")
+    catalog.write_data(_quotes([4, 5, 6]), basename_template="b-{i}")
+
+    # Act
+    report = catalog.check_consistency(QuoteTick)
+
+    # Assert
+    assert report.is_consistent
+    assert report.partitions_checked == 1
+    assert report.files_checked == 2
+
+
+def test_check_consistency_detects_overlapping_ranges_and_duplicates(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    catalog.write_data(_quotes([1, 2, 3, 4]), basename_template="a-{i}")
+    catalog.write_data(_quotes([3, 4, 5]), basename_template="b-{i}")
+
+    # Act
+    report = catalog.check_consistency(QuoteTick)
+
+    # Assert
+    overlaps = report.issues_of_type(CatalogIssueType.OVERLAPPING_RANGES)
+    duplicates = report.issues_of_type(CatalogIssueType.DUPLICATE_ROWS)
+    assert len(overlaps) == 1
+    assert len(duplicates) == 1
+    assert duplicates[0].detail == "2 duplicate row(s)"
+    assert report.partitions == [_partition(catalog)]
+
+
+def test_check_consistency_detects_non_monotonic_timestamps(
+    catalog: ParquetDataCatalog,
+) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, [1, 2, 3])
+    table = pq.read_table(f"{partition}/part-0.parquet", filesystem=catalog.fs)
+    pq.write_table(table.take([2, 0, 1]), f"{partition}/part-0.parquet", filesystem=catalog.fs)
+
+    # Act
+    report = catalog.check_consistency(QuoteTick)
+
+    # Assert
+    issues = report.issues_of_type(CatalogIssueType.NON_MONOTONIC_TIMESTAMPS)
+    assert len(issues) == 1
+    assert "1 decreasing" in issues[0].detail
+
+
+def test_check_consistency_detects_schema_drift(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, [1, 2, 3])
+    catalog.write_data(_quotes([4, 5]), basename_template="b-{i}")
+    catalog.write_data(_quotes([6, 7]), basename_template="c-{i}")
+    table = pq.read_table(f"{partition}/c-0.parquet", filesystem=catalog.fs)
+    _drift_ts_event_type(catalog, f"{partition}/c-0.parquet")
+
+    # Act
+    report = catalog.check_consistency(QuoteTick)
+
+    # Assert
+    issues = report.issues_of_type(CatalogIssueType.SCHEMA_DRIFT)
+    assert len(issues) == 1
+    assert issues[0].files == (f"{partition}/c-0.parquet",)
+    assert "ts_event (uint64 -> int64)" in issues[0].detail
+
+
+def test_check_consistency_detects_unreadable_file(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, [1, 2, 3])
+    with catalog.fs.open(f"{partition}/corrupt.parquet", "wb") as f:
+        f.write(b"not a parquet file")
+
+    # Act
+    report = catalog.check_consistency(QuoteTick)
+
+    # Assert
+    issues = report.issues_of_type(CatalogIssueType.UNREADABLE_FILE)
+    assert len(issues) == 1
+    assert issues[0].files == (f"{partition}/corrupt.parquet",)
+
+
+def test_repair_rewrites_partition_deduplicated_and_sorted(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    catalog.write_data(_quotes([3, 4, 5]), basename_template="b-{i}")
+    catalog.write_data(_quotes([1, 2, 3, 4]), basename_template="a-{i}")
+
+    # Act
+    repaired = catalog.repair()
+
+    # Assert
+    partition = _partition(catalog)
+    assert repaired == [partition]
+    assert catalog.fs.ls(partition, detail=False) == [f"{partition}/{_part_name(1, 5)}"]
+    assert catalog.check_consistency().is_consistent
+    assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2, 3, 4, 5]
+
+
+def test_repair_casts_drifted_schema_to_reference(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, [1, 2, 3])
+    catalog.write_data(_quotes([4, 5]), basename_template="b-{i}")
+    catalog.write_data(_quotes([6, 7]), basename_template="c-{i}")
+    table = pq.read_table(f"{partition}/c-0.parquet", filesystem=catalog.fs)
+    _drift_ts_event_type(catalog, f"{partition}/c-0.parquet")
+
+    # Act
+    catalog.repair()
+
+    # Assert
+    assert catalog.check_consistency().is_consistent
+    assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2, 3, 4, 5, 6, 7]
+
+
+def test_repair_leaves_unreadable_file_unless_removed(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, [1, 2, 3])
+    with catalog.fs.open(f"{partition}/corrupt.parquet", "wb") as f:
+        f.write(b"not a parquet file")
+
+    # Act
+    catalog.repair()
+    report_before_removal = catalog.check_consistency()
+    catalog.repair(remove_unreadable=True)
+
+    # Assert
+    assert not report_before_removal.is_consistent
+    assert catalog.check_consistency().is_consistent
+    assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2, 3]
+
+
+def test_compact_merges_small_files_deduplicated_and_sorted(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    catalog.write_data(_quotes([5, 6]), basename_template="c-{i}")
+    catalog.write_data(_quotes([1, 2, 3]), basename_template="a-{i}")
+    catalog.write_data(_quotes([3, 4]), basename_template="b-{i}")
+
+    # Act
+    compactions = catalog.compact()
+
+    # Assert
+    partition = _partition(catalog)
+    assert len(compactions) == 1
+    assert compactions[0].partition == partition
+    assert compactions[0].files_merged == 3
+    assert compactions[0].files_written == 1
+    assert compactions[0].duplicates_removed == 1
+    assert catalog.fs.ls(partition, detail=False) == [f"{partition}/{_part_name(1, 6)}"]
+    assert catalog.check_consistency().is_consistent
+    assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2, 3, 4, 5, 6]
+
+
+def test_compact_splits_output_into_target_sized_files(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    catalog.write_data(_quotes(list(range(0, 500))), basename_template="a-{i}")
+    catalog.write_data(_quotes(list(range(500, 1000))), basename_template="b-{i}")
+    partition = _partition(catalog)
+    total_size = sum(catalog.fs.size(path) for path in catalog.fs.ls(partition, detail=False))
+
+    # Act
+    compactions = catalog.compact(target_file_size=total_size // 4 + 1)
+
+    # Assert
+    assert compactions[0].files_written == 4
+    assert catalog.check_consistency().is_consistent
+    assert [q.ts_init for q in catalog.quote_ticks()] == list(range(1000))
+
+
+def test_compact_when_single_small_file_does_nothing(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    catalog.write_data(_quotes([1, 2, 3]))
+
+    # Act
+    compactions = catalog.compact()
+
+    # Assert
+    assert compactions == []
+
+
+def test_compact_leaves_files_at_target_size(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    partition = _partition_with_quotes(catalog, list(range(100)))
+    target_file_size = catalog.fs.size(f"{partition}/part-0.parquet")
+    catalog.write_data(_quotes([100]), basename_template="b-{i}")
+    catalog.write_data(_quotes([101]), basename_template="c-{i}")
+
+    # Act
+    compactions = catalog.compact(target_file_size=target_file_size)
+
+    # Assert
+    files = catalog.fs.ls(partition, detail=False)
+    assert compactions[0].files_merged == 2
+    assert f"{partition}/part-0.parquet" in files
+    assert f"{partition}/b-0.parquet" not in files
+    assert f"{partition}/c-0.parquet" not in files
+    assert [q.ts_init for q in catalog.quote_ticks()] == list(range(102))
+
+
+def test_compact_names_files_in_timestamp_order(catalog: ParquetDataCatalog) -> None:
+    # Arrange
+    catalog.write_data(_quotes(list(range(100, 150))), basename_template="a-{i}")
+    catalog.write_data(_quotes(list(range(150, 200))), basename_template="b-{i}")
+    catalog.compact()
+    partition = _partition(catalog)
+    target_file_size = catalog.fs.size(f"{partition}/{_part_name(100, 199)}")
+    catalog.write_data(_quotes([1, 2]), basename_template="c-{i}")
+    catalog.write_data(_quotes([3]), basename_template="d-{i}")
+
+    # Act
+    compactions = catalog.compact(target_file_size=target_file_size)
+
+    # Assert
+    files = catalog.fs.ls(partition, detail=False)
+    assert compactions[0].files_merged == 2
+    assert sorted(files) == [
+        f"{partition}/{_part_name(1, 3)}",
+        f"{partition}/{_part_name(100, 199)}",
+    ]
+    assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2, 3, *range(100, 200)]
+
+
+def test_dedupe_and_sort_keeps_order_of_equal_timestamps() -> None:
+    # Arrange
+    table = pa.table(
+        {
+            "value": [3, 1, 2, 1, 0],
+            "ts_init": [2, 1, 1, 1, 0],
+        },
+    )
+
+    # Act
+    result = dedupe_and_sort(table)
+
+    # Assert
+    assert result.column("value").to_pylist() == [0, 1, 2, 3]
+    assert result.column("ts_init").to_pylist() == [0, 1, 1, 2]
+
+
+def _part_name(ts_min: int, ts_max: int) -> str:
+    return f"part-{ts_min:020d}-{ts_max:020d}.parquet"
+
+
+def _partition_with_quotes(catalog: ParquetDataCatalog, timestamps: list[int]) -> str:
+    catalog.write_data(_quotes(timestamps))
+    return _partition(catalog)
+
+
+def _drift_ts_event_type(catalog: ParquetDataCatalog, path: str) -> None:
+    table = pq.read_table(path, filesystem=catalog.fs)
+    index = table.schema.get_field_index("ts_event")
+    table = table.cast(table.schema.set(index, pa.field("ts_event", pa.int64())))
+    pq.write_table(table, path, filesystem=catalog.fs)
@@ -0,0 +1,109 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.persistence.catalog.parquet import ParquetDataCatalog
+from nautilus_trader.persistence.compaction import CatalogCompactor
+from nautilus_trader.persistence.config import CatalogCompactorConfig
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+class TestCatalogCompactor:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.clock = TestClock()
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+    def _create_compactor(self, catalog: ParquetDataCatalog, **kwargs) -> CatalogCompactor:
+        config = CatalogCompactorConfig(
+            catalog_path=str(catalog.path),
+            fs_protocol="file",
+            **kwargs,
+        )
+        compactor = CatalogCompactor(config=config)
+        compactor.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return compactor
+
+    def _write_small_files(self, catalog: ParquetDataCatalog) -> None:
+        catalog.write_data(
+            [TestDataStubs.quote_tick(ts_event=1, ts_init=1)],
+            basename_template="a-{i}",
+        )
+        catalog.write_data(
+            [TestDataStubs.quote_tick(ts_event=2, ts_init=2)],
+            basename_template="b-{i}",
+        )
+
+    def test_compact_on_start(self, catalog: ParquetDataCatalog) -> None:
+        # Arrange
+        self._write_small_files(catalog)
+        compactor = self._create_compactor(catalog, compact_on_start=True)
+
+        # Act
+        compactor.start()
+
+        # Assert
+        assert compactor.compaction_count == 1
+        assert len(catalog.fs.glob(f"{catalog.path}/data/**/*.parquet")) == 1
+
+    def test_compacts_on_interval(self, catalog: ParquetDataCatalog) -> None:
+        # Arrange
+        compactor = self._create_compactor(catalog, interval_mins=1)
+        compactor.start()
+        self._write_small_files(catalog)
+
+        # Act
+        for event in self.clock.advance_time(secs_to_nanos(59)):
+            event.handle()
+        files_before = len(catalog.fs.glob(f"{catalog.path}/data/**/*.parquet"))
+
+        for event in self.clock.advance_time(secs_to_nanos(60)):
+            event.handle()
+
+        # Assert
+        assert files_before == 2
+        assert compactor.compaction_count == 1
+        assert len(catalog.fs.glob(f"{catalog.path}/data/**/*.parquet")) == 1
+        assert [q.ts_init for q in catalog.quote_ticks()] == [1, 2]
+
+    def test_stop_cancels_compaction_timer(self, catalog: ParquetDataCatalog) -> None:
+        # Arrange
+        compactor = self._create_compactor(catalog, interval_mins=1)
+        compactor.start()
+
+        # Act
+        compactor.stop()
+
+        # Assert
+        assert self.clock.timer_names == []
@@ -0,0 +1,114 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.persistence.config import FeatureRecorderConfig
+from nautilus_trader.persistence.features import FeatureRecorder
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+class TestFeatureRecorder:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.clock = TestClock()
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+    def _create_recorder(self, **kwargs) -> FeatureRecorder:
+        config = FeatureRecorderConfig(
+            features={"quote": "data.quotes.*", "ema": "features.ema"},
+            **kwargs,
+        )
+        recorder = FeatureRecorder(config=config)
+        recorder.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return recorder
+
+    def test_instantiate_with_unknown_trigger_raises(self, tmp_path) -> None:
+        # Arrange
+        config = FeatureRecorderConfig(
+            features={"ema": "features.ema"},
+            path=str(tmp_path / "features.parquet"),
+            trigger="rsi",
+        )
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            FeatureRecorder(config=config)
+
+    def test_rows_are_aligned_once_all_features_have_values(self, tmp_path) -> None:
+        # Arrange
+        path = str(tmp_path / "features.parquet")
+        recorder = self._create_recorder(path=path, trigger="quote")
+        recorder.start()
+        quote = TestDataStubs.quote_tick()
+
+        # Act
+        self.msgbus.publish("data.quotes.SIM.AUD/USD", quote)  # Not yet aligned
+        self.msgbus.publish("features.ema", {"value": 1.5, "initialized": True})
+        self.msgbus.publish("features.ema", {"value": 1.6, "initialized": True})  # Not a trigger
+        self.clock.set_time(1_000)
+        self.msgbus.publish("data.quotes.SIM.AUD/USD", quote)
+        recorder.stop()
+
+        # Assert
+        table = pq.read_table(path)
+        assert recorder.row_count == 1
+        assert table.num_rows == 1
+        assert table.column("ts").to_pylist() == [1_000]
+        assert table.column("ema.value").to_pylist() == [1.6]
+        assert table.column("quote.bid_price").to_pylist() == [str(quote.bid_price)]
+
+    def test_arrow_stream_appends_row_per_update_without_trigger(self, tmp_path) -> None:
+        # Arrange
+        path = str(tmp_path / "features.arrow")
+        recorder = self._create_recorder(
+            path=path,
+            file_format="arrow",
+            require_all=False,
+            flush_rows=2,
+        )
+        recorder.start()
+
+        # Act
+        for value in (1.0, 2.0, 3.0):
+            self.msgbus.publish("features.ema", value)
+        recorder.stop()
+
+        # Assert
+        with pa.OSFile(path, "rb") as f:
+            table = pa.ipc.open_stream(f).read_all()
+        assert table.num_rows == 3
+        assert table.column("ema").to_pylist() == [1.0, 2.0, 3.0]
@@ -0,0 +1,329 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import http.server
+import socket
+import threading
+
+import pytest
+
+from nautilus_trader.backtest.data_client import BacktestMarketDataClient
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.persistence.config import IlpDataRecorderConfig
+from nautilus_trader.persistence.ilp import IlpDataRecorder
+from nautilus_trader.persistence.ilp import IlpSender
+from nautilus_trader.persistence.ilp import format_ilp_line
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+from nautilus_trader.test_kit.stubs.component import TestComponentStubs
+from nautilus_trader.test_kit.stubs.data import TestDataStubs
+from nautilus_trader.test_kit.stubs.identifiers import TestIdStubs
+
+
+AUDUSD_SIM = TestInstrumentProvider.default_fx_ccy("AUD/USD")
+
+
+class _TcpServer:
+    def __init__(self) -> None:
+        self._server = socket.create_server(("127.0.0.1", 0))
+        self.port: int = self._server.getsockname()[1]
+        self._received = bytearray()
+        self._thread = threading.Thread(target=self._run, daemon=True)
+        self._thread.start()
+
+    def _run(self) -> None:
+        conn, _ = self._server.accept()
+        with conn:
+            while chunk := conn.recv(65536):
+                self._received.extend(chunk)
+        self._server.close()
+
+    def lines(self) -> list[str]:
+        # Waits for the sender to close the connection
+        self._thread.join(timeout=5.0)
+        return self._received.decode().splitlines()
+
+
+class _HttpServer:
+    def __init__(self, status: int = 204) -> None:
+        self.requests: list[tuple[str, str | None, str]] = []
+        requests = self.requests
+
+        class Handler(http.server.BaseHTTPRequestHandler):
+            def do_POST(self) -> None:
+                body = self.rfile.read(int(self.headers["Content-Length"])).decode()
+                requests.append((self.path, self.headers.get("Authorization"), body))
+                self.send_response(status)
+                self.send_header("Content-Length", "0")
+                self.end_headers()
+
+            def log_message(self, *args) -> None:
+                pass
+
+        self._server = http.server.HTTPServer(("127.0.0.1", 0), Handler)
+        self.port: int = self._server.server_address[1]
+        threading.Thread(target=self._server.serve_forever, daemon=True).start()
+
+    def close(self) -> None:
+        self._server.shutdown()
+        self._server.server_close()
+
+
+def _closed_port() -> int:
+    with socket.create_server(("127.0.0.1", 0)) as server:
+        return server.getsockname()[1]
+
+
+class TestFormatIlpLine:
+    def test_format_line(self) -> None:
+        # Arrange, Act
+        line = format_ilp_line(
+            "quotes",
+            {"instrument_id": "AUD/USD.SIM"},
+            {"bid": 1.5, "count": 3, "live": True, "note": "ok"},
+            1_000,
+        )
+
+        # Assert
+        assert line == 'quotes,instrument_id=AUD/USD.SIM bid=1.5,count=3i,live=t,note="ok" 1000'
+
+    def test_format_line_escapes_special_characters(self) -> None:
+        # Arrange, Act
+        line = format_ilp_line(
+            "my table",
+            {"tag key": "a,b=c"},
+            {"field,key": 'say "hi"\\'},
+            0,
+        )
+
+        # Assert
+        assert line == 'my\\ table,tag\\ key=a\\,b\\=c field\\,key="say \\"hi\\"\\\\" 0'
+
+    def test_format_line_omits_non_finite_floats(self) -> None:
+        # Arrange, Act
+        line = format_ilp_line("quotes", {}, {"bid": float("nan"), "ask": 2.0}, 0)
+
+        # Assert
+        assert line == "quotes ask=2.0 0"
+
+    def test_format_line_with_no_writable_fields_raises(self) -> None:
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            format_ilp_line("quotes", {}, {"bid": float("inf")}, 0)
+
+
+class TestIlpSender:
+    def test_send_over_tcp_writes_lines(self) -> None:
+        # Arrange
+        server = _TcpServer()
+        sender = IlpSender(host="127.0.0.1", port=server.port, batch_size=2)
+        sender.start()
+
+        # Act
+        for i in range(5):
+            sender.send(f"test value={i}i {i}")
+        sender.stop()
+
+        # Assert
+        assert server.lines() == [f"test value={i}i {i}" for i in range(5)]
+        assert sender.sent_count == 5
+        assert sender.dropped_count == 0
+        assert not sender.is_running
+
+    def test_send_over_http_writes_batches(self) -> None:
+        # Arrange
+        server = _HttpServer()
+        sender = IlpSender(
+            host="127.0.0.1",
+            port=server.port,
+            protocol="http",
+            http_path="/api/v2/write?bucket=test&precision=ns",
+            auth_token="secret",
+        )
+        sender.start()
+
+        # Act
+        sender.send("test value=1i 1")
+        sender.send("test value=2i 2")
+        sender.stop()
+        server.close()
+
+        # Assert
+        assert server.requests == [
+            (
+                "/api/v2/write?bucket=test&precision=ns",
+                "Token secret",
+                "test value=1i 1\ntest value=2i 2\n",
+            ),
+        ]
+        assert sender.sent_count == 2
+
+    def test_http_client_error_drops_batch(self) -> None:
+        # Arrange
+        server = _HttpServer(status=400)
+        sender = IlpSender(host="127.0.0.1", port=server.port, protocol="http")
+        sender.start()
+
+        # Act
+        sender.send("bad line")
+        sender.stop()
+        server.close()
+
+        # Assert
+        assert len(server.requests) == 1
+        assert sender.sent_count == 0
+        assert sender.dropped_count == 1
+        assert sender.error_count == 1
+        assert "HTTP 400" in sender.last_error
+
+    def test_send_when_queue_full_drops_line(self) -> None:
+        # Arrange
+        sender = IlpSender(host="127.0.0.1", port=9009, max_pending_lines=2)
+
+        # Act
+        results = [sender.send(f"test value={i}i {i}") for i in range(3)]
+
+        # Assert
+        assert results == [True, True, False]
+        assert sender.pending_count == 2
+        assert sender.dropped_count == 1
+
+    def test_stop_when_unreachable_drops_pending_lines(self) -> None:
+        # Arrange
+        sender = IlpSender(host="127.0.0.1", port=_closed_port(), reconnect_interval_ms=10)
+        sender.start()
+
+        # Act
+        sender.send("test value=1i 1")
+        sender.send("test value=2i 2")
+        sender.stop()
+
+        # Assert
+        assert sender.sent_count == 0
+        assert sender.dropped_count == 2
+        assert sender.error_count >= 1
+
+
+class TestIlpDataRecorder:
+    def setup(self) -> None:
+        # Fixture Setup
+        self.clock = TestClock()
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.data_client = BacktestMarketDataClient(
+            client_id=ClientId("SIM"),
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.data_engine.register_client(self.data_client)
+        self.data_engine.process(AUDUSD_SIM)
+        self.cache.add_instrument(AUDUSD_SIM)
+        self.data_engine.start()
+        self.server = _TcpServer()
+
+    def _create_recorder(self, **kwargs) -> IlpDataRecorder:
+        config = IlpDataRecorderConfig(host="127.0.0.1", port=self.server.port, **kwargs)
+        recorder = IlpDataRecorder(config=config)
+        recorder.register_base(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        return recorder
+
+    def test_instantiate_with_invalid_protocol_raises(self) -> None:
+        # Arrange
+        config = IlpDataRecorderConfig(protocol="udp")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            IlpDataRecorder(config=config)
+
+    def test_start_subscribes_and_stop_unsubscribes(self) -> None:
+        # Arrange
+        recorder = self._create_recorder(
+            quote_instrument_ids=[AUDUSD_SIM.id.value],
+            trade_instrument_ids=[AUDUSD_SIM.id.value],
+        )
+
+        # Act
+        recorder.start()
+        subscribed_quotes = self.data_engine.subscribed_quote_ticks()
+        subscribed_trades = self.data_engine.subscribed_trade_ticks()
+        recorder.stop()
+
+        # Assert
+        assert subscribed_quotes == [AUDUSD_SIM.id]
+        assert subscribed_trades == [AUDUSD_SIM.id]
+        assert self.data_engine.subscribed_quote_ticks() == []
+        assert self.data_engine.subscribed_trade_ticks() == []
+
+    def test_records_market_data(self) -> None:
+        # Arrange
+        recorder = self._create_recorder(
+            quote_instrument_ids=[AUDUSD_SIM.id.value],
+            trade_instrument_ids=[AUDUSD_SIM.id.value],
+            book_depth=2,
+        )
+        recorder.start()
+
+        # Act
+        self.data_engine.process(
+            TestDataStubs.quote_tick(bid_price=1.0, ask_price=1.1, ts_event=1, ts_init=3),
+        )
+        self.data_engine.process(TestDataStubs.trade_tick(ts_event=4, ts_init=4))
+        recorder.on_bar(TestDataStubs.bar_5decimal())
+        recorder.on_order_book(TestDataStubs.order_book(bid_levels=3, ask_levels=3))
+        recorder.stop()
+
+        # Assert
+        lines = self.server.lines()
+        assert len(lines) == 4
+        assert lines[0] == (
+            "quotes,instrument_id=AUD/USD.SIM "
+            "bid=1.0,ask=1.1,bid_size=100000.0,ask_size=100000.0,latency_ns=2i 1"
+        )
+        assert lines[1] == (
+            "trades,instrument_id=AUD/USD.SIM,aggressor_side=BUYER "
+            'price=1.0,size=100000.0,trade_id="123456",latency_ns=0i 4'
+        )
+        assert lines[2].startswith(
+            "bars,instrument_id=AUD/USD.SIM,bar_type=AUD/USD.SIM-1-MINUTE-BID-EXTERNAL "
+            "open=1.00002,high=1.00004,low=1.00001,close=1.00003,volume=1000000.0",
+        )
+        assert lines[3].startswith("order_book,instrument_id=AUD/USD.SIM bid_price_0=10.0,")
+        assert "bid_price_1=" in lines[3]
+        assert "bid_price_2=" not in lines[3]
+        assert "spread=5.0" in lines[3]
+        assert recorder.lines_sent == 4
+        assert recorder.lines_dropped == 0
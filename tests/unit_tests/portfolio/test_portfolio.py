@@ -17,24 +17,29 @@ from decimal import Decimal
 
 import pytest
 
+from nautilus_trader.accounting.accounts.base import Account
 from nautilus_trader.accounting.factory import AccountFactory
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.model.currencies import BNB
 from nautilus_trader.model.currencies import BTC
 from nautilus_trader.model.currencies import ETH
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import LiquiditySide
 from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.events import OrderFilled
 from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.identifiers import Venue
 from nautilus_trader.model.identifiers import VenueOrderId
 from nautilus_trader.model.objects import AccountBalance
@@ -297,6 +302,86 @@ class TestPortfolio:
         assert account.balances_total()[BTC] == Money(10.00000000, BTC)
         assert account.balances_total()[USDT] == Money(100_000.00000000, USDT)
 
+    def _multi_currency_cash_account_fill(self, commission: Money) -> Account:
+        AccountFactory.register_calculated_account("BINANCE")
+
+        account_id = AccountId("BINANCE-000")
+        state = AccountState(
+            account_id=account_id,
+            account_type=AccountType.CASH,
+            base_currency=None,  # Multi-currency account
+            reported=True,
+            balances=[
+                AccountBalance(
+                    Money(10.00000000, BTC),
+                    Money(0.00000000, BTC),
+                    Money(10.00000000, BTC),
+                ),
+                AccountBalance(
+                    Money(100_000.00000000, USDT),
+                    Money(0.00000000, USDT),
+                    Money(100_000.00000000, USDT),
+                ),
+                AccountBalance(
+                    Money(10.00000000, BNB),
+                    Money(0.00000000, BNB),
+                    Money(10.00000000, BNB),
+                ),
+            ],
+            margins=[],
+            info={},
+            event_id=UUID4(),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        self.portfolio.update_account(state)
+
+        order = self.order_factory.market(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("1.000000"),
+        )
+
+        self.cache.add_order(order, position_id=None)
+
+        self.exec_engine.process(TestEventStubs.order_submitted(order, account_id=account_id))
+
+        fill = OrderFilled(
+            trader_id=self.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=BTCUSDT_BINANCE.id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId("1"),
+            account_id=account_id,
+            trade_id=TradeId("E-1"),
+            position_id=None,
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=order.quantity,
+            last_px=Price.from_str("10000.00"),
+            currency=USDT,
+            commission=commission,
+            liquidity_side=LiquiditySide.TAKER,
+            event_id=UUID4(),
+            ts_event=0,
+            ts_init=0,
+        )
+        self.exec_engine.process(fill)
+
+        return self.portfolio.account(BINANCE)
+
+    def test_fill_with_commission_in_third_currency_deducts_commission_once(self):
+        # Arrange, Act
+        account = self._multi_currency_cash_account_fill(Money(0.01000000, BNB))
+
+        # Assert
+        assert account.balances_total()[BTC] == Money(11.00000000, BTC)
+        assert account.balances_total()[USDT] == Money(90_000.00000000, USDT)
+        assert account.balances_total()[BNB] == Money(9.99000000, BNB)
+        assert account.commission(BNB) == Money(0.01000000, BNB)
+        assert account.commission(USDT) is None
+
     def test_update_orders_open_cash_account(self):
         # Arrange
         AccountFactory.register_calculated_account("BINANCE")
@@ -20,9 +20,12 @@ import pytest
 
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
+from nautilus_trader.common.messages import RiskConfigChanged
 from nautilus_trader.common.messages import TradingStateChanged
 from nautilus_trader.config import ExecEngineConfig
+from nautilus_trader.config import PriceBand
 from nautilus_trader.config import RiskEngineConfig
+from nautilus_trader.config import StrategyRiskLimits
 from nautilus_trader.core.message import Event
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.execution.emulator import OrderEmulator
@@ -35,6 +38,7 @@ from nautilus_trader.model.currencies import GBP
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.data import QuoteTick
 from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import InstrumentClass
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import TradingState
@@ -54,6 +58,11 @@ from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orders.list import OrderList
 from nautilus_trader.portfolio.portfolio import Portfolio
 from nautilus_trader.risk.engine import RiskEngine
+from nautilus_trader.risk.messages import SetMaxNotionalPerOrder
+from nautilus_trader.risk.messages import SetOrderRateLimits
+from nautilus_trader.risk.messages import SetPriceBand
+from nautilus_trader.risk.messages import SetStrategyRiskLimits
+from nautilus_trader.risk.messages import SetTradingState
 from nautilus_trader.test_kit.mocks.exec_clients import MockExecutionClient
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 from nautilus_trader.test_kit.stubs.component import TestComponentStubs
@@ -154,6 +163,54 @@ class TestRiskEngineWithCashAccount:
         assert risk_engine.max_notionals_per_order() == {GBPUSD_SIM.id: Decimal("2000000")}
         assert risk_engine.max_notional_per_order(GBPUSD_SIM.id) == 2_000_000
 
+    def test_config_risk_engine_with_strategy_limits(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+        self.msgbus.deregister("RiskEngine.process", self.risk_engine.process)
+
+        limits = StrategyRiskLimits(
+            max_open_orders=10,
+            max_position_size={"GBP/USD.SIM": "1000000"},
+            max_gross_exposure="2_000_000 USD",
+            max_net_exposure="1_000_000 USD",
+        )
+        config = RiskEngineConfig(strategy_limits={"S-001": limits})
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.strategies_risk_limits() == {StrategyId("S-001"): limits}
+        assert risk_engine.strategy_risk_limits(StrategyId("S-001")) == limits
+
+    def test_config_risk_engine_with_price_bands(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+        self.msgbus.deregister("RiskEngine.process", self.risk_engine.process)
+
+        band = PriceBand(limit_max_deviation_pct=5.0, stop_max_deviation_ticks=100)
+        config = RiskEngineConfig(price_bands={"SPOT": band})
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.price_bands() == {InstrumentClass.SPOT: band}
+        assert risk_engine.price_band(InstrumentClass.SPOT) == band
+        assert risk_engine.price_band(InstrumentClass.FUTURE) is None
+
     def test_risk_engine_on_stop(self):
         # Arrange, Act
         self.risk_engine.start()
@@ -237,6 +294,234 @@ class TestRiskEngineWithCashAccount:
         assert max_notionals == {AUDUSD_SIM.id: Decimal("1000000")}
         assert max_notional == Decimal(1_000_000)
 
+    def test_strategy_risk_limits_when_no_risk_config_returns_none(self):
+        # Arrange, Act
+        result = self.risk_engine.strategy_risk_limits(StrategyId("S-001"))
+
+        # Assert
+        assert result is None
+        assert self.risk_engine.strategies_risk_limits() == {}
+
+    def test_set_strategy_risk_limits_changes_setting(self):
+        # Arrange
+        limits = StrategyRiskLimits(max_open_orders=5)
+
+        # Act
+        self.risk_engine.set_strategy_risk_limits(StrategyId("S-001"), limits)
+
+        # Assert
+        assert self.risk_engine.strategy_risk_limits(StrategyId("S-001")) == limits
+
+    def test_set_strategy_risk_limits_with_none_clears_setting(self):
+        # Arrange
+        limits = StrategyRiskLimits(max_open_orders=5)
+        self.risk_engine.set_strategy_risk_limits(StrategyId("S-001"), limits)
+
+        # Act
+        self.risk_engine.set_strategy_risk_limits(StrategyId("S-001"), None)
+
+        # Assert
+        assert self.risk_engine.strategy_risk_limits(StrategyId("S-001")) is None
+
+    def test_set_strategy_risk_limits_with_invalid_exposure_raises_value_error(self):
+        # Arrange
+        limits = StrategyRiskLimits(max_gross_exposure="1000000")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            self.risk_engine.set_strategy_risk_limits(StrategyId("S-001"), limits)
+
+    def test_set_strategy_risk_limits_command_changes_setting(self):
+        # Arrange
+        limits = StrategyRiskLimits(max_open_orders=5)
+        command = SetStrategyRiskLimits(
+            trader_id=self.trader_id,
+            strategy_id=StrategyId("S-001"),
+            limits=limits,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.msgbus.send(endpoint="RiskEngine.execute", msg=command)
+
+        # Assert
+        assert self.risk_engine.command_count == 1
+        assert self.risk_engine.strategy_risk_limits(StrategyId("S-001")) == limits
+
+    def test_set_trading_state_command_changes_state_and_publishes_audit_event(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetTradingState(
+            trader_id=self.trader_id,
+            state=TradingState.HALTED,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            requested_by="ops-desk",
+        )
+
+        # Act
+        self.msgbus.send(endpoint="RiskEngine.execute", msg=command)
+
+        # Assert
+        assert self.risk_engine.trading_state == TradingState.HALTED
+        assert len(handler) == 2
+        assert type(handler[0]) is TradingStateChanged
+        audit = handler[1]
+        assert type(audit) is RiskConfigChanged
+        assert audit.setting == "trading_state"
+        assert audit.old_value == "ACTIVE"
+        assert audit.new_value == "HALTED"
+        assert audit.requested_by == "ops-desk"
+        assert audit.command_id == command.id
+        assert audit.ts_event == self.clock.timestamp_ns()
+
+    def test_set_trading_state_command_when_no_change_publishes_nothing(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetTradingState(
+            trader_id=self.trader_id,
+            state=TradingState.ACTIVE,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.trading_state == TradingState.ACTIVE
+        assert handler == []
+
+    def test_set_max_notional_per_order_command_changes_setting(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetMaxNotionalPerOrder(
+            trader_id=self.trader_id,
+            instrument_id=AUDUSD_SIM.id,
+            max_notional=Decimal(1_000_000),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            requested_by="ops-desk",
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.max_notional_per_order(AUDUSD_SIM.id) == Decimal(1_000_000)
+        assert len(handler) == 1
+        assert handler[0].setting == "max_notional_per_order[AUD/USD.SIM]"
+        assert handler[0].old_value == "None"
+        assert handler[0].new_value == "1000000"
+
+    def test_set_max_notional_per_order_command_when_invalid_then_no_change(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetMaxNotionalPerOrder(
+            trader_id=self.trader_id,
+            instrument_id=AUDUSD_SIM.id,
+            max_notional=Decimal(-1),
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.max_notional_per_order(AUDUSD_SIM.id) is None
+        assert handler == []
+
+    def test_set_order_rate_limits_command_changes_throttler_rates(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetOrderRateLimits(
+            trader_id=self.trader_id,
+            max_order_submit_rate="10/00:00:01",
+            max_order_modify_rate=None,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+            requested_by="ops-desk",
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.max_order_submit_rate() == (10, timedelta(seconds=1))
+        assert self.risk_engine.max_order_modify_rate() == (100, timedelta(seconds=1))
+        assert len(handler) == 1
+        assert handler[0].setting == "max_order_submit_rate"
+        assert handler[0].old_value == "100/00:00:01"
+        assert handler[0].new_value == "10/00:00:01"
+        assert handler[0].requested_by == "ops-desk"
+
+    @pytest.mark.parametrize(
+        "rate",
+        [
+            "",
+            "10",
+            "0/00:00:01",
+            "10/00:00:00",
+            "ten/00:00:01",
+        ],
+    )
+    def test_set_order_rate_limits_command_when_invalid_then_no_change(self, rate: str):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        command = SetOrderRateLimits(
+            trader_id=self.trader_id,
+            max_order_submit_rate="10/00:00:01",
+            max_order_modify_rate=rate,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.max_order_submit_rate() == (100, timedelta(seconds=1))
+        assert self.risk_engine.max_order_modify_rate() == (100, timedelta(seconds=1))
+        assert handler == []
+
+    def test_set_price_band_command_changes_setting(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="events.risk*", handler=handler.append)
+
+        band = PriceBand(limit_max_deviation_pct=5.0)
+        command = SetPriceBand(
+            trader_id=self.trader_id,
+            instrument_class=InstrumentClass.SPOT,
+            band=band,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(command)
+
+        # Assert
+        assert self.risk_engine.price_band(InstrumentClass.SPOT) == band
+        assert len(handler) == 1
+        assert handler[0].setting == "price_bands[SPOT]"
+        assert handler[0].old_value == "None"
+        assert handler[0].requested_by is None
+
     def test_given_random_command_then_logs_and_continues(self):
         # Arrange
         random = TradingCommand(
@@ -580,6 +865,66 @@ class TestRiskEngineWithCashAccount:
         assert order.status == OrderStatus.DENIED
         assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
 
+    def test_submit_order_checks_instrument_version_effective_at_command_time(self):
+        # Arrange
+        self.exec_engine.start()
+        self.cache.add_instrument(
+            TestInstrumentProvider.instrument_version(
+                AUDUSD_SIM,
+                ts_event=1_000,
+                price_precision=4,
+                price_increment="0.0001",
+            ),
+        )
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order1 = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00001"),
+        )
+        order2 = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00001"),  # <- invalid price precision after tick size change
+        )
+
+        submit_order1 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order1,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+        submit_order2 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=1_000,
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order1)
+        self.risk_engine.execute(submit_order2)
+
+        # Assert
+        assert order1.status == OrderStatus.INITIALIZED
+        assert order2.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 1
+
     def test_submit_order_when_invalid_negative_price_and_not_option_then_denies(self):
         # Arrange
         self.exec_engine.start()
@@ -673,60 +1018,769 @@ class TestRiskEngineWithCashAccount:
             Price.from_str("1.00000"),
         )
 
-        submit_order = SubmitOrder(
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_invalid_quantity_exceeds_maximum_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(1_000_000_000),  # <- invalid quantity fat finger!
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_invalid_quantity_less_than_minimum_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(1),  # <- invalid quantity
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_market_order_and_no_market_then_logs_warning(self):
+        # Arrange
+        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(10_000_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 1  # <-- Command reaches engine with warning
+
+    @pytest.mark.parametrize(("order_side"), [OrderSide.BUY, OrderSide.SELL])
+    def test_submit_order_when_less_than_min_notional_for_instrument_then_denies(
+        self,
+        order_side: OrderSide,
+    ) -> None:
+        # Arrange
+        exec_client = MockExecutionClient(
+            client_id=ClientId("BITMEX"),
+            venue=XBTUSD_BITMEX.id.venue,
+            account_type=AccountType.CASH,
+            base_currency=USD,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.portfolio.update_account(TestEventStubs.cash_account_state(AccountId("BITMEX-001")))
+        self.exec_engine.register_client(exec_client)
+
+        self.cache.add_instrument(XBTUSD_BITMEX)
+        quote = TestDataStubs.quote_tick(
+            instrument=XBTUSD_BITMEX,
+            bid_price=50_000.00,
+            ask_price=50_001.00,
+        )
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            XBTUSD_BITMEX.id,
+            order_side,
+            Quantity.from_str("0.1"),  # <-- Less than min notional ($1 USD)
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    @pytest.mark.parametrize(("order_side"), [OrderSide.BUY, OrderSide.SELL])
+    def test_submit_order_when_greater_than_max_notional_for_instrument_then_denies(
+        self,
+        order_side: OrderSide,
+    ) -> None:
+        # Arrange
+        exec_client = MockExecutionClient(
+            client_id=ClientId("BITMEX"),
+            venue=XBTUSD_BITMEX.id.venue,
+            account_type=AccountType.CASH,
+            base_currency=USD,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        self.portfolio.update_account(TestEventStubs.cash_account_state(AccountId("BITMEX-001")))
+        self.exec_engine.register_client(exec_client)
+
+        self.cache.add_instrument(XBTUSD_BITMEX)
+        quote = TestDataStubs.quote_tick(
+            instrument=XBTUSD_BITMEX,
+            bid_price=50_000.00,
+            ask_price=50_001.00,
+        )
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            XBTUSD_BITMEX.id,
+            order_side,
+            Quantity.from_int(11_000_000),  # <-- Greater than max notional ($10 million USD)
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_buy_market_order_and_over_max_notional_then_denies(self):
+        # Arrange
+        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
+
+        # Initialize market
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(10_000_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_sell_market_order_and_over_max_notional_then_denies(self):
+        # Arrange
+        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
+
+        # Initialize market
+        quote = QuoteTick(
+            instrument_id=AUDUSD_SIM.id,
+            bid_price=Price.from_str("0.75000"),
+            ask_price=Price.from_str("0.75005"),
+            bid_size=Quantity.from_int(5_000_000),
+            ask_size=Quantity.from_int(5_000_000),
+            ts_event=0,
+            ts_init=0,
+        )
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(10_000_000),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+
+    def test_submit_order_when_over_strategy_max_open_orders_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_open_orders=1),
+        )
+
+        order1 = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.99000"),
+        )
+
+        order2 = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.98000"),
+        )
+
+        submit_order1 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order1,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order1)
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+
+        submit_order2 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order2)
+
+        # Assert
+        assert order1.status == OrderStatus.ACCEPTED
+        assert order2.status == OrderStatus.DENIED
+        assert order2.last_event.reason.startswith("STRATEGY_MAX_OPEN_ORDERS_EXCEEDED")
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_other_strategy_has_limits_then_sends_to_client(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            StrategyId("OTHER-001"),
+            StrategyRiskLimits(max_open_orders=1, max_gross_exposure="1 USD"),
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("0.99000"),
+        )
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_list_when_over_strategy_max_open_orders_then_denies_all(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_open_orders=2),
+        )
+
+        bracket = strategy.order_factory.bracket(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            sl_trigger_price=Price.from_str("1.00000"),
+            tp_price=Price.from_str("1.00010"),
+        )
+
+        submit_bracket = SubmitOrderList(
+            self.trader_id,
+            strategy.id,
+            bracket,
+            UUID4(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_bracket)
+
+        # Assert
+        for order in bracket.orders:
+            assert order.status == OrderStatus.DENIED
+            assert order.last_event.reason.startswith("STRATEGY_MAX_OPEN_ORDERS_EXCEEDED")
+        assert self.exec_engine.command_count == 0
+
+    def test_submit_order_when_over_strategy_max_position_size_then_denies(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_position_size={AUDUSD_SIM.id.value: "150000"}),
+        )
+
+        order1 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        order2 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order1 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order1,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order1)
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+        self.exec_engine.process(TestEventStubs.order_filled(order1, AUDUSD_SIM))
+
+        submit_order2 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order2)
+
+        # Assert
+        assert order1.status == OrderStatus.FILLED
+        assert order2.status == OrderStatus.DENIED
+        assert order2.last_event.reason == (
+            f"STRATEGY_MAX_POSITION_SIZE_EXCEEDED: strategy_id={strategy.id}, "
+            "max_position_size=150000 AUD/USD.SIM, projected_position_size=200000"
+        )
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_reducing_position_over_strategy_max_position_size_then_sends(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order1 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(200_000),
+        )
+
+        order2 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order1 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order1,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order1)
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+        self.exec_engine.process(TestEventStubs.order_filled(order1, AUDUSD_SIM))
+
+        # Limit lowered below the current position
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_position_size={AUDUSD_SIM.id.value: "150000"}),
+        )
+
+        submit_order2 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order2)
+
+        # Assert
+        assert order2.status == OrderStatus.INITIALIZED
+        assert self.exec_engine.command_count == 2
+
+    def test_submit_order_when_over_strategy_max_gross_exposure_then_denies(self):
+        # Arrange
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_gross_exposure="150_000 USD"),
+        )
+
+        order1 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        order2 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order1 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order1,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        self.risk_engine.execute(submit_order1)
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+        self.exec_engine.process(TestEventStubs.order_filled(order1, AUDUSD_SIM))
+
+        submit_order2 = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order2)
+
+        # Assert
+        assert order2.status == OrderStatus.DENIED
+        assert order2.last_event.reason == (
+            f"STRATEGY_MAX_GROSS_EXPOSURE_EXCEEDED: strategy_id={strategy.id}, "
+            "max_gross_exposure=150_000.00 USD, gross_exposure=200_000.00 USD"
+        )
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_over_strategy_max_net_exposure_then_denies(self):
+        # Arrange
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_strategy_risk_limits(
+            strategy.id,
+            StrategyRiskLimits(max_net_exposure="150_000 USD"),
+        )
+
+        order1 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        order2 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+        )
+
+        order3 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        submit_order1 = SubmitOrder(
             trader_id=self.trader_id,
             strategy_id=strategy.id,
             position_id=None,
-            order=order,
+            order=order1,
             command_id=UUID4(),
             ts_init=self.clock.timestamp_ns(),
         )
 
-        # Act
-        self.risk_engine.execute(submit_order)
-
-        # Assert
-        assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
-
-    def test_submit_order_when_invalid_quantity_exceeds_maximum_then_denies(self):
-        # Arrange
-        self.exec_engine.start()
+        self.risk_engine.execute(submit_order1)
+        self.exec_engine.process(TestEventStubs.order_submitted(order1))
+        self.exec_engine.process(TestEventStubs.order_accepted(order1))
+        self.exec_engine.process(TestEventStubs.order_filled(order1, AUDUSD_SIM))
 
-        strategy = Strategy()
-        strategy.register(
+        submit_order2 = SubmitOrder(
             trader_id=self.trader_id,
-            portfolio=self.portfolio,
-            msgbus=self.msgbus,
-            cache=self.cache,
-            clock=self.clock,
-        )
-
-        order = strategy.order_factory.limit(
-            AUDUSD_SIM.id,
-            OrderSide.BUY,
-            Quantity.from_int(1_000_000_000),  # <- invalid quantity fat finger!
-            Price.from_str("1.00000"),
+            strategy_id=strategy.id,
+            position_id=None,
+            order=order2,
+            command_id=UUID4(),
+            ts_init=self.clock.timestamp_ns(),
         )
 
-        submit_order = SubmitOrder(
+        submit_order3 = SubmitOrder(
             trader_id=self.trader_id,
             strategy_id=strategy.id,
             position_id=None,
-            order=order,
+            order=order3,
             command_id=UUID4(),
             ts_init=self.clock.timestamp_ns(),
         )
 
         # Act
-        self.risk_engine.execute(submit_order)
+        self.risk_engine.execute(submit_order2)  # <-- Reduces net exposure
+        self.risk_engine.execute(submit_order3)
 
         # Assert
-        assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order2.status == OrderStatus.INITIALIZED
+        assert order3.status == OrderStatus.DENIED
+        assert order3.last_event.reason.startswith("STRATEGY_MAX_NET_EXPOSURE_EXCEEDED")
+        assert self.exec_engine.command_count == 2
 
-    def test_submit_order_when_invalid_quantity_less_than_minimum_then_denies(self):
+    def test_submit_order_when_limit_price_outside_band_pct_then_denies(self):
         # Arrange
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=5.0),
+        )
+
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
+
         self.exec_engine.start()
 
         strategy = Strategy()
@@ -741,8 +1795,8 @@ class TestRiskEngineWithCashAccount:
         order = strategy.order_factory.limit(
             AUDUSD_SIM.id,
             OrderSide.BUY,
-            Quantity.from_int(1),  # <- invalid quantity
-            Price.from_str("1.00000"),
+            Quantity.from_int(100_000),
+            Price.from_str("1.10000"),
         )
 
         submit_order = SubmitOrder(
@@ -759,11 +1813,20 @@ class TestRiskEngineWithCashAccount:
 
         # Assert
         assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order.last_event.reason == (
+            "PRICE_OUTSIDE_BAND: price 1.10000 deviates 10.00% from reference 1.00000 (max 5.0%)"
+        )
+        assert self.exec_engine.command_count == 0
 
-    def test_submit_order_when_market_order_and_no_market_then_logs_warning(self):
+    def test_submit_order_when_limit_price_within_band_pct_then_sends_to_client(self):
         # Arrange
-        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=5.0),
+        )
+
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
 
         self.exec_engine.start()
 
@@ -776,10 +1839,11 @@ class TestRiskEngineWithCashAccount:
             clock=self.clock,
         )
 
-        order = strategy.order_factory.market(
+        order = strategy.order_factory.limit(
             AUDUSD_SIM.id,
-            OrderSide.BUY,
-            Quantity.from_int(10_000_000),
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            Price.from_str("1.04000"),
         )
 
         submit_order = SubmitOrder(
@@ -795,32 +1859,17 @@ class TestRiskEngineWithCashAccount:
         self.risk_engine.execute(submit_order)
 
         # Assert
-        assert self.exec_engine.command_count == 1  # <-- Command reaches engine with warning
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_engine.command_count == 1
 
-    @pytest.mark.parametrize(("order_side"), [OrderSide.BUY, OrderSide.SELL])
-    def test_submit_order_when_less_than_min_notional_for_instrument_then_denies(
-        self,
-        order_side: OrderSide,
-    ) -> None:
+    def test_submit_order_when_limit_price_outside_band_ticks_then_denies(self):
         # Arrange
-        exec_client = MockExecutionClient(
-            client_id=ClientId("BITMEX"),
-            venue=XBTUSD_BITMEX.id.venue,
-            account_type=AccountType.CASH,
-            base_currency=USD,
-            msgbus=self.msgbus,
-            cache=self.cache,
-            clock=self.clock,
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_ticks=10),
         )
-        self.portfolio.update_account(TestEventStubs.cash_account_state(AccountId("BITMEX-001")))
-        self.exec_engine.register_client(exec_client)
 
-        self.cache.add_instrument(XBTUSD_BITMEX)
-        quote = TestDataStubs.quote_tick(
-            instrument=XBTUSD_BITMEX,
-            bid_price=50_000.00,
-            ask_price=50_001.00,
-        )
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
         self.cache.add_quote_tick(quote)
 
         self.exec_engine.start()
@@ -834,10 +1883,11 @@ class TestRiskEngineWithCashAccount:
             clock=self.clock,
         )
 
-        order = strategy.order_factory.market(
-            XBTUSD_BITMEX.id,
-            order_side,
-            Quantity.from_str("0.1"),  # <-- Less than min notional ($1 USD)
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            Price.from_str("0.99980"),
         )
 
         submit_order = SubmitOrder(
@@ -854,32 +1904,19 @@ class TestRiskEngineWithCashAccount:
 
         # Assert
         assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order.last_event.reason == (
+            "PRICE_OUTSIDE_BAND: price 0.99980 deviates 20 ticks from reference 1.00000 (max 10 ticks)"
+        )
+        assert self.exec_engine.command_count == 0
 
-    @pytest.mark.parametrize(("order_side"), [OrderSide.BUY, OrderSide.SELL])
-    def test_submit_order_when_greater_than_max_notional_for_instrument_then_denies(
-        self,
-        order_side: OrderSide,
-    ) -> None:
+    def test_submit_order_when_trigger_price_outside_limit_band_only_then_sends_to_client(self):
         # Arrange
-        exec_client = MockExecutionClient(
-            client_id=ClientId("BITMEX"),
-            venue=XBTUSD_BITMEX.id.venue,
-            account_type=AccountType.CASH,
-            base_currency=USD,
-            msgbus=self.msgbus,
-            cache=self.cache,
-            clock=self.clock,
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=1.0),
         )
-        self.portfolio.update_account(TestEventStubs.cash_account_state(AccountId("BITMEX-001")))
-        self.exec_engine.register_client(exec_client)
 
-        self.cache.add_instrument(XBTUSD_BITMEX)
-        quote = TestDataStubs.quote_tick(
-            instrument=XBTUSD_BITMEX,
-            bid_price=50_000.00,
-            ask_price=50_001.00,
-        )
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
         self.cache.add_quote_tick(quote)
 
         self.exec_engine.start()
@@ -893,10 +1930,11 @@ class TestRiskEngineWithCashAccount:
             clock=self.clock,
         )
 
-        order = strategy.order_factory.market(
-            XBTUSD_BITMEX.id,
-            order_side,
-            Quantity.from_int(11_000_000),  # <-- Greater than max notional ($10 million USD)
+        order = strategy.order_factory.stop_market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.10000"),
         )
 
         submit_order = SubmitOrder(
@@ -912,14 +1950,16 @@ class TestRiskEngineWithCashAccount:
         self.risk_engine.execute(submit_order)
 
         # Assert
-        assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_engine.command_count == 1
 
-    def test_submit_order_when_buy_market_order_and_over_max_notional_then_denies(self):
+    def test_submit_order_when_trigger_price_outside_stop_band_then_denies(self):
         # Arrange
-        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=20.0, stop_max_deviation_pct=5.0),
+        )
 
-        # Initialize market
         quote = TestDataStubs.quote_tick(AUDUSD_SIM)
         self.cache.add_quote_tick(quote)
 
@@ -934,10 +1974,12 @@ class TestRiskEngineWithCashAccount:
             clock=self.clock,
         )
 
-        order = strategy.order_factory.market(
+        order = strategy.order_factory.stop_limit(
             AUDUSD_SIM.id,
             OrderSide.BUY,
-            Quantity.from_int(10_000_000),
+            Quantity.from_int(100_000),
+            price=Price.from_str("1.10000"),
+            trigger_price=Price.from_str("1.10000"),
         )
 
         submit_order = SubmitOrder(
@@ -954,23 +1996,15 @@ class TestRiskEngineWithCashAccount:
 
         # Assert
         assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order.last_event.reason.startswith("PRICE_OUTSIDE_BAND: trigger price 1.10000")
+        assert self.exec_engine.command_count == 0
 
-    def test_submit_order_when_sell_market_order_and_over_max_notional_then_denies(self):
+    def test_submit_order_when_no_reference_price_for_band_then_sends_to_client(self):
         # Arrange
-        self.risk_engine.set_max_notional_per_order(AUDUSD_SIM.id, 1_000_000)
-
-        # Initialize market
-        quote = QuoteTick(
-            instrument_id=AUDUSD_SIM.id,
-            bid_price=Price.from_str("0.75000"),
-            ask_price=Price.from_str("0.75005"),
-            bid_size=Quantity.from_int(5_000_000),
-            ask_size=Quantity.from_int(5_000_000),
-            ts_event=0,
-            ts_init=0,
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=1.0),
         )
-        self.cache.add_quote_tick(quote)
 
         self.exec_engine.start()
 
@@ -983,10 +2017,11 @@ class TestRiskEngineWithCashAccount:
             clock=self.clock,
         )
 
-        order = strategy.order_factory.market(
+        order = strategy.order_factory.limit(
             AUDUSD_SIM.id,
-            OrderSide.SELL,
-            Quantity.from_int(10_000_000),
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.10000"),
         )
 
         submit_order = SubmitOrder(
@@ -1002,8 +2037,8 @@ class TestRiskEngineWithCashAccount:
         self.risk_engine.execute(submit_order)
 
         # Assert
-        assert order.status == OrderStatus.DENIED
-        assert self.exec_engine.command_count == 0  # <-- Command never reaches engine
+        assert order.status == OrderStatus.INITIALIZED
+        assert self.exec_engine.command_count == 1
 
     def test_submit_order_when_market_order_and_over_free_balance_then_denies(self):
         # Arrange - Initialize market
@@ -1417,6 +2452,47 @@ class TestRiskEngineWithCashAccount:
         assert self.risk_engine.command_count == 101
         assert self.exec_engine.command_count == 100  # <-- Does not send last submit event
 
+    def test_submit_order_beyond_lowered_rate_limit_then_denies_order(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        self.risk_engine.set_max_order_submit_rate("5/00:00:01")
+
+        # Act
+        order = None
+        for _ in range(6):
+            order = strategy.order_factory.market(
+                AUDUSD_SIM.id,
+                OrderSide.BUY,
+                Quantity.from_int(100_000),
+            )
+
+            submit_order = SubmitOrder(
+                trader_id=self.trader_id,
+                strategy_id=strategy.id,
+                position_id=None,
+                order=order,
+                command_id=UUID4(),
+                ts_init=self.clock.timestamp_ns(),
+            )
+
+            self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert order
+        assert order.status == OrderStatus.DENIED
+        assert order.last_event.reason == "Exceeded MAX_ORDER_SUBMIT_RATE"
+        assert self.exec_engine.command_count == 5  # <-- Does not send last submit event
+
     def test_submit_order_list_when_trading_halted_then_denies_orders(self):
         # Arrange
         self.exec_engine.start()
@@ -1855,6 +2931,57 @@ class TestRiskEngineWithCashAccount:
         assert self.risk_engine.command_count == 102
         assert self.exec_engine.command_count == 101  # <-- Does not send last modify event
 
+    def test_modify_order_when_price_outside_band_then_rejects(self):
+        # Arrange
+        self.risk_engine.set_price_band(
+            InstrumentClass.SPOT,
+            PriceBand(limit_max_deviation_pct=5.0),
+        )
+
+        quote = TestDataStubs.quote_tick(AUDUSD_SIM)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("1.00000"),
+        )
+
+        strategy.submit_order(order)
+
+        modify = ModifyOrder(
+            self.trader_id,
+            strategy.id,
+            order.instrument_id,
+            order.client_order_id,
+            VenueOrderId("1"),
+            order.quantity,
+            Price.from_str("1.20000"),
+            None,
+            UUID4(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(modify)
+
+        # Assert
+        assert isinstance(order.last_event, OrderModifyRejected)
+        assert order.last_event.reason.startswith("PRICE_OUTSIDE_BAND")
+        assert self.exec_engine.command_count == 1  # <-- Does not send modify
+
     def test_modify_order_with_default_settings_then_sends_to_client(self):
         # Arrange
         self.exec_engine.start()
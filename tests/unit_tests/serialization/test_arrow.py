@@ -23,14 +23,23 @@ from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.common.messages import ComponentStateChanged
 from nautilus_trader.common.messages import TradingStateChanged
+from nautilus_trader.model.currencies import BTC
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data import BorrowRate
+from nautilus_trader.model.data import CashDividend
 from nautilus_trader.model.data import OrderBookDelta
 from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import StockSplit
+from nautilus_trader.model.data import SymbolChange
 from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.events import AccountState
+from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.position import Position
@@ -462,6 +471,56 @@ class TestArrowSerializer:
         df = self.catalog.instruments()
         assert len(df) == 1
 
+    @pytest.mark.parametrize(
+        "action",
+        [
+            CashDividend(
+                instrument_id=InstrumentId.from_str("AAPL.XNAS"),
+                amount=Money(0.24, USD),
+                ts_event=0,
+                ts_init=0,
+            ),
+            StockSplit(
+                instrument_id=InstrumentId.from_str("AAPL.XNAS"),
+                numerator=4,
+                denominator=1,
+                ts_event=0,
+                ts_init=0,
+            ),
+            SymbolChange(
+                instrument_id=InstrumentId.from_str("FB.XNAS"),
+                new_instrument_id=InstrumentId.from_str("META.XNAS"),
+                ts_event=0,
+                ts_init=0,
+            ),
+        ],
+    )
+    def test_serialize_and_deserialize_corporate_actions(self, action):
+        serialized = ArrowSerializer.serialize(action)
+        deserialized = ArrowSerializer.deserialize(data_cls=type(action), batch=serialized)
+
+        # Assert
+        assert deserialized == [action]
+        self._test_serialization(obj=action)
+
+    def test_serialize_and_deserialize_borrow_rate(self):
+        # Arrange
+        borrow_rate = BorrowRate(
+            venue=Venue("BINANCE"),
+            currency=BTC,
+            rate=0.05,
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        serialized = ArrowSerializer.serialize(borrow_rate)
+        deserialized = ArrowSerializer.deserialize(data_cls=BorrowRate, batch=serialized)
+
+        # Assert
+        assert deserialized == [borrow_rate]
+        self._test_serialization(obj=borrow_rate)
+
     @pytest.mark.parametrize("obj", nautilus_objects())
     def test_serialize_and_deserialize_all(self, obj):
         # Arrange, Act, Assert
@@ -29,9 +29,11 @@ from nautilus_trader.backtest.models import LatencyModel
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.enums import ComponentState
+from nautilus_trader.common.enums import WarmupState
 from nautilus_trader.config import ImportableStrategyConfig
 from nautilus_trader.config import StrategyConfig
 from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.datetime import secs_to_nanos
 from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.execution.engine import ExecutionEngine
@@ -191,6 +193,9 @@ class TestStrategy:
             "external_order_claims": None,
             "manage_contingent_orders": False,
             "manage_gtd_expiry": False,
+            "manage_own_order_books": False,
+            "warmup_required": False,
+            "warmup_timeout_secs": None,
         }
 
     def test_strategy_to_importable_config(self) -> None:
@@ -219,6 +224,9 @@ class TestStrategy:
             "external_order_claims": ["ETHUSDT-PERP.DYDX"],
             "manage_contingent_orders": True,
             "manage_gtd_expiry": True,
+            "manage_own_order_books": False,
+            "warmup_required": False,
+            "warmup_timeout_secs": None,
         }
 
     def test_strategy_equality(self) -> None:
@@ -532,6 +540,53 @@ class TestStrategy:
         assert len(strategy.registered_indicators) == 1
         assert ema in strategy.registered_indicators
 
+    def test_instrument_change_when_running_sends_to_on_instrument_changed(self) -> None:
+        # Arrange
+        bar_type = TestDataStubs.bartype_audusd_1min_bid()
+        strategy = MockStrategy(bar_type)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        updated = TestInstrumentProvider.instrument_version(
+            AUDUSD_SIM,
+            ts_event=1_000,
+            price_precision=4,
+            price_increment="0.0001",
+        )
+
+        # Act
+        self.data_engine.process(updated)
+
+        # Assert
+        assert strategy.calls == ["on_start", "on_instrument_changed"]
+        assert strategy.store == [updated]
+
+    def test_handle_instrument_changed_when_not_running_does_not_send_to_on_instrument_changed(
+        self,
+    ) -> None:
+        # Arrange
+        bar_type = TestDataStubs.bartype_audusd_1min_bid()
+        strategy = MockStrategy(bar_type)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        # Act
+        strategy.handle_instrument_changed(AUDUSD_SIM)
+
+        # Assert
+        assert strategy.calls == []
+
     def test_handle_quote_tick_updates_indicator_registered_for_quote_ticks(self) -> None:
         # Arrange
         strategy = Strategy()
@@ -1128,6 +1183,181 @@ class TestStrategy:
         assert entry.status == OrderStatus.ACCEPTED
         assert entry in strategy.cache.orders_open()
 
+    def test_warmup_state_when_not_required_is_ready(self) -> None:
+        # Arrange
+        strategy = Strategy()
+
+        # Act, Assert
+        assert not strategy.warmup_required
+        assert strategy.warmup_state == WarmupState.READY
+        assert strategy.is_warmed_up()
+
+    def test_start_when_warmup_required_begins_warming_up(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        assert strategy.warmup_state == WarmupState.PENDING
+
+        # Act
+        strategy.start()
+
+        # Assert
+        assert strategy.warmup_state == WarmupState.WARMING_UP
+        assert not strategy.is_warmed_up()
+
+    def test_submit_order_when_warming_up_then_denies(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        order = strategy.order_factory.market(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        # Act
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Assert
+        assert order.status == OrderStatus.DENIED
+        assert not strategy.cache.is_order_open(order.client_order_id)
+
+    def test_submit_order_list_when_warming_up_then_denies(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        bracket = strategy.order_factory.bracket(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            entry_price=Price.from_str("80.000"),
+            sl_trigger_price=Price.from_str("90.000"),
+            tp_price=Price.from_str("90.500"),
+            entry_order_type=OrderType.LIMIT,
+        )
+
+        # Act
+        strategy.submit_order_list(bracket)
+        self.exchange.process(0)
+
+        # Assert
+        assert all(order.status == OrderStatus.DENIED for order in bracket.orders)
+
+    def test_submit_order_after_complete_warmup_successfully_submits(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        order = strategy.order_factory.market(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        # Act
+        strategy.complete_warmup()
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Assert
+        assert strategy.warmup_state == WarmupState.READY
+        assert order.status == OrderStatus.FILLED
+
+    def test_warmup_timeout_when_not_completed_then_times_out(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True, warmup_timeout_secs=10.0))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        # Act
+        events = strategy.clock.advance_time(to_time_ns=secs_to_nanos(10))
+        for event in events:
+            event.handle()
+
+        # Assert
+        diagnostics = strategy.warmup_diagnostics()
+        assert strategy.warmup_state == WarmupState.TIMED_OUT
+        assert not strategy.is_warmed_up()
+        assert diagnostics["state"] == "TIMED_OUT"
+        assert diagnostics["elapsed_secs"] == 10.0
+        assert diagnostics["pending_requests"] == []
+        assert diagnostics["uninitialized_indicators"] == []
+
+    def test_complete_warmup_cancels_timeout_timer(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True, warmup_timeout_secs=10.0))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+
+        # Act
+        strategy.complete_warmup()
+
+        # Assert
+        assert strategy.warmup_state == WarmupState.READY
+        assert f"{strategy.id}-WARMUP-TIMEOUT" not in strategy.clock.timer_names
+
+    def test_reset_when_warmup_required_returns_to_pending(self) -> None:
+        # Arrange
+        strategy = Strategy(StrategyConfig(warmup_required=True))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+        strategy.start()
+        strategy.complete_warmup()
+        strategy.stop()
+
+        # Act
+        strategy.reset()
+
+        # Assert
+        assert strategy.warmup_state == WarmupState.PENDING
+
     def test_submit_order_list_with_managed_gtd_starts_timer(self) -> None:
         # Arrange
         config = StrategyConfig(manage_gtd_expiry=True)
@@ -1223,6 +1453,189 @@ class TestStrategy:
         # Assert
         assert strategy.clock.timer_count == 0
 
+    def test_own_order_book_when_not_managed_returns_none(self) -> None:
+        # Arrange
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+
+        # Act
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Assert
+        assert strategy.own_order_book(USDJPY_SIM.id) is None
+
+    def test_own_order_book_tracks_resting_orders(self) -> None:
+        # Arrange
+        config = StrategyConfig(manage_own_order_books=True)
+        strategy = Strategy(config)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        bid1 = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+        bid2 = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(50_000),
+            Price.from_str("90.000"),
+        )
+        bid3 = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("89.990"),
+        )
+        ask1 = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            Price.from_str("90.010"),
+        )
+        market = strategy.order_factory.market(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+        )
+
+        # Act
+        for order in (bid1, bid2, bid3, ask1, market):
+            strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Assert
+        own_book = strategy.own_order_book(USDJPY_SIM.id)
+        assert market.status == OrderStatus.FILLED
+        assert own_book.order_count() == 4
+        assert not own_book.contains(market.client_order_id)
+        assert own_book.bid_prices() == [Price.from_str("90.000"), Price.from_str("89.990")]
+        assert own_book.ask_prices() == [Price.from_str("90.010")]
+        assert own_book.orders_at_level(OrderSide.BUY, Price.from_str("90.000")) == [bid1, bid2]
+        assert own_book.own_qty_at_price(OrderSide.BUY, Price.from_str("90.000")) == Quantity.from_int(150_000)
+
+    def test_own_order_book_when_order_canceled_removes_order(self) -> None:
+        # Arrange
+        config = StrategyConfig(manage_own_order_books=True)
+        strategy = Strategy(config)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Act
+        strategy.cancel_order(order)
+        self.exchange.process(0)
+
+        # Assert
+        own_book = strategy.own_order_book(USDJPY_SIM.id)
+        assert order.status == OrderStatus.CANCELED
+        assert own_book.order_count() == 0
+        assert own_book.bid_prices() == []
+
+    def test_own_order_book_when_order_modified_moves_level(self) -> None:
+        # Arrange
+        config = StrategyConfig(manage_own_order_books=True)
+        strategy = Strategy(config)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100_000),
+            Price.from_str("90.000"),
+        )
+
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Act
+        strategy.modify_order(
+            order=order,
+            quantity=Quantity.from_int(110_000),
+            price=Price.from_str("89.999"),
+        )
+        self.exchange.process(0)
+
+        # Assert
+        own_book = strategy.own_order_book(USDJPY_SIM.id)
+        assert own_book.bid_prices() == [Price.from_str("89.999")]
+        assert own_book.own_qty_at_price(OrderSide.BUY, Price.from_str("89.999")) == Quantity.from_int(110_000)
+
+    def test_start_when_manage_own_order_books_loads_open_orders(self) -> None:
+        # Arrange
+        config = StrategyConfig(manage_own_order_books=True)
+        strategy = Strategy(config)
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+        )
+
+        order = strategy.order_factory.limit(
+            USDJPY_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100_000),
+            Price.from_str("90.010"),
+        )
+
+        strategy.submit_order(order)
+        self.exchange.process(0)
+
+        strategy.start()
+        strategy.stop()
+        strategy.reset()  # <-- Simulate restart
+        assert strategy.own_order_book(USDJPY_SIM.id) is None
+
+        # Act
+        strategy.start()
+
+        # Assert
+        own_book = strategy.own_order_book(USDJPY_SIM.id)
+        assert own_book.orders() == [order]
+
     def test_cancel_order(self) -> None:
         # Arrange
         strategy = Strategy()